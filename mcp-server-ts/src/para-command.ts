@@ -0,0 +1,108 @@
+/**
+ * Running the para binary, and reading the finish report it leaves behind
+ */
+
+import { exec } from "child_process";
+import { existsSync, readFileSync, rmSync } from "fs";
+import { join } from "path";
+import { ErrorCode, McpError } from "@modelcontextprotocol/sdk/types.js";
+
+export interface ParaCommandOptions {
+  // Directory para runs in; the server's working directory if not given
+  cwd?: string;
+  // JSON result para may leave behind; when it exists after the run, its
+  // content is the result, also if para failed
+  resultFile?: string;
+}
+
+// Mirrors core::session::finish_report in the para binary
+export const FINISH_REPORT_VERSION = 1;
+export const FINISH_REPORT_PATH = join(".para", "finish_result.json");
+
+export interface FinishReport {
+  version: number;
+  result: "success" | "success_with_integration_failure" | "conflicts" | "failed";
+  session?: string;
+  final_branch?: string;
+  integration_error?: string;
+  conflicted_files: string[];
+}
+
+// Abstracts command execution to handle timeouts, environment setup, and error handling
+export async function runParaCommand(
+  binary: string,
+  args: string[],
+  options: ParaCommandOptions = {}
+): Promise<string> {
+  return new Promise((resolve, reject) => {
+    // Prevent shell injection and argument splitting issues
+    const quotedArgs = args.map(arg => {
+      if (arg.includes(' ') && !arg.startsWith('"') && !arg.startsWith("'")) {
+        return `"${arg.replace(/"/g, '\\"')}"`;
+      }
+      return arg;
+    });
+
+    const command = `${binary} ${quotedArgs.join(' ')}`;
+
+    // Prevent para from blocking on user prompts in automated contexts
+    const env = {
+      ...process.env,
+      PARA_NON_INTERACTIVE: '1',
+      CI: '1'  // Many CLIs respect this as well
+    };
+
+    const child = exec(command, { env, cwd: options.cwd }, (error, stdout, stderr) => {
+      clearTimeout(timeout);
+
+      if (options.resultFile && existsSync(options.resultFile)) {
+        resolve(readFileSync(options.resultFile, "utf8").trim());
+        return;
+      }
+
+      if (error) {
+        reject(new McpError(ErrorCode.InternalError, `Para command failed: ${error.message}`));
+        return;
+      }
+
+      if (stderr && !stderr.includes('warning')) {
+        console.error(`Para command warning: ${stderr}`);
+      }
+
+      resolve(stdout.trim());
+    });
+
+    // Prevent hanging on complex operations while allowing time for worktree setup
+    const timeout = setTimeout(() => {
+      child.kill();
+      reject(new McpError(ErrorCode.InternalError, `Command timed out after 30 seconds: ${args.join(' ')}`));
+    }, 30000);
+  });
+}
+
+// A finished session's worktree is usually removed, so a successful finish
+// often leaves no report; its branch is taken from the output instead
+export function parseFinishReport(output: string): FinishReport {
+  if (output.startsWith("{")) {
+    return JSON.parse(output) as FinishReport;
+  }
+  return {
+    version: FINISH_REPORT_VERSION,
+    result: "success",
+    final_branch: output.match(/Feature branch: (\S+)/)?.[1],
+    conflicted_files: [],
+  };
+}
+
+// Run `para finish` in `worktree` and return what became of it
+export async function finishSession(
+  binary: string,
+  args: string[],
+  worktree: string
+): Promise<FinishReport> {
+  const resultFile = join(worktree, FINISH_REPORT_PATH);
+  // A report left from an earlier attempt must not pass for this one
+  rmSync(resultFile, { force: true });
+  const output = await runParaCommand(binary, args, { cwd: worktree, resultFile });
+  return parseFinishReport(output);
+}
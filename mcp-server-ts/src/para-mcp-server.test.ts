@@ -1,4 +1,8 @@
-import { execSync } from 'child_process';
+import { exec, execSync } from 'child_process';
+import { mkdirSync, mkdtempSync, writeFileSync } from 'fs';
+import { tmpdir } from 'os';
+import { join } from 'path';
+import { FINISH_REPORT_PATH, finishSession } from './para-command';
 
 // Prevent actual binary execution during tests
 jest.mock('child_process', () => ({
@@ -7,6 +11,22 @@ jest.mock('child_process', () => ({
 }));
 
 const mockExecSync = execSync as jest.MockedFunction<typeof execSync>;
+const mockExec = exec as unknown as jest.Mock;
+
+type ExecCallback = (error: Error | null, stdout: string, stderr: string) => void;
+
+// Stands in for the para binary: optionally leaves `report` in the worktree,
+// then exits the way `error` says
+function stubPara(error: Error | null, stdout: string, report?: object): void {
+  mockExec.mockImplementation((_command: string, options: { cwd: string }, callback: ExecCallback) => {
+    if (report) {
+      mkdirSync(join(options.cwd, '.para'), { recursive: true });
+      writeFileSync(join(options.cwd, FINISH_REPORT_PATH), JSON.stringify(report));
+    }
+    process.nextTick(() => callback(error, stdout, ''));
+    return { kill: jest.fn() };
+  });
+}
 
 // Isolate server logic from external MCP SDK dependencies
 jest.mock('@modelcontextprotocol/sdk/server/index.js', () => ({
@@ -360,6 +380,55 @@ describe('Para MCP Server', () => {
     });
   });
 
+  describe('Finish Report', () => {
+    const failure = new Error('Command failed: para finish "Add feature"');
+
+    test('should return the report of a finish that conflicted', async () => {
+      const worktree = mkdtempSync(join(tmpdir(), 'para-finish-'));
+      const report = {
+        version: 1,
+        result: 'conflicts',
+        session: 'auth',
+        integration_error: "Integration would conflict: Rebasing 'para/auth' onto 'main' hit conflicts",
+        conflicted_files: ['src/lib.rs'],
+      };
+      stubPara(failure, '', report);
+
+      const result = await finishSession('para', ['finish', 'Add feature'], worktree);
+
+      expect(mockExec).toHaveBeenCalledWith(
+        'para finish "Add feature"',
+        expect.objectContaining({ cwd: worktree }),
+        expect.any(Function)
+      );
+      expect(result).toEqual(report);
+    });
+
+    test('should read the branch of a finish that removed its worktree', async () => {
+      const worktree = mkdtempSync(join(tmpdir(), 'para-finish-'));
+      stubPara(null, 'Session finished successfully\n  Feature branch: para/auth\n');
+
+      const result = await finishSession('para', ['finish', 'Add feature'], worktree);
+
+      expect(result).toEqual({
+        version: 1,
+        result: 'success',
+        final_branch: 'para/auth',
+        conflicted_files: [],
+      });
+    });
+
+    test('should not pass off an earlier report as the result of a failed finish', async () => {
+      const worktree = mkdtempSync(join(tmpdir(), 'para-finish-'));
+      mkdirSync(join(worktree, '.para'));
+      writeFileSync(join(worktree, FINISH_REPORT_PATH), '{"version":1,"result":"success"}');
+      stubPara(failure, '');
+
+      await expect(finishSession('para', ['finish', 'Add feature'], worktree))
+        .rejects.toThrow('Para command failed');
+    });
+  });
+
   describe('Resource Definitions', () => {
     test('should define current-session resource', () => {
       const expectedResource = {
@@ -14,7 +14,8 @@ import {
   ReadResourceRequestSchema,
   McpError,
 } from "@modelcontextprotocol/sdk/types.js";
-import { exec, execSync } from "child_process";
+import { execSync } from "child_process";
+import { finishSession, runParaCommand as runPara } from "./para-command.js";
 
 interface ParaStartArgs {
   name?: string;
@@ -58,11 +59,15 @@ interface ParaResumeArgs {
 
 interface ParaRecoverArgs {
   session_name?: string;
+  dry_run?: boolean;
+  json?: boolean;
 }
 
 interface ParaCancelArgs {
   session_name?: string;
   force?: boolean;
+  dry_run?: boolean;
+  json?: boolean;
 }
 
 interface ParaStatusShowArgs {
@@ -138,47 +143,8 @@ const server = new Server({
   }
 });
 
-// Abstracts command execution to handle timeouts, environment setup, and error handling
 async function runParaCommand(args: string[]): Promise<string> {
-  return new Promise((resolve, reject) => {
-    // Prevent shell injection and argument splitting issues
-    const quotedArgs = args.map(arg => {
-      if (arg.includes(' ') && !arg.startsWith('"') && !arg.startsWith("'")) {
-        return `"${arg.replace(/"/g, '\\"')}"`;
-      }
-      return arg;
-    });
-
-    const command = `${PARA_BINARY} ${quotedArgs.join(' ')}`;
-    
-    // Prevent para from blocking on user prompts in automated contexts
-    const env = {
-      ...process.env,
-      PARA_NON_INTERACTIVE: '1',
-      CI: '1'  // Many CLIs respect this as well
-    };
-
-    const child = exec(command, { env }, (error, stdout, stderr) => {
-      clearTimeout(timeout);
-      
-      if (error) {
-        reject(new McpError(ErrorCode.InternalError, `Para command failed: ${error.message}`));
-        return;
-      }
-      
-      if (stderr && !stderr.includes('warning')) {
-        console.error(`Para command warning: ${stderr}`);
-      }
-      
-      resolve(stdout.trim());
-    });
-
-    // Prevent hanging on complex operations while allowing time for worktree setup
-    const timeout = setTimeout(() => {
-      child.kill();
-      reject(new McpError(ErrorCode.InternalError, `Command timed out after 30 seconds: ${args.join(' ')}`));
-    }, 30000);
-  });
+  return runPara(PARA_BINARY, args);
 }
 
 server.setRequestHandler(ListToolsRequestSchema, async () => {
@@ -253,7 +219,7 @@ server.setRequestHandler(ListToolsRequestSchema, async () => {
       },
       {
         name: "para_finish",
-        description: "Complete session and create feature branch for review. Creates commit and branch from session work. Agents typically use CLI 'para finish' command instead.",
+        description: "Complete session and create feature branch for review. Creates commit and branch from session work. Agents typically use CLI 'para finish' command instead.\n\nReturns a finish report: { version, result: success | success_with_integration_failure | conflicts | failed, session, final_branch, integration_error, conflicted_files }. Para also leaves it in <worktree>/.para/finish_result.json while the worktree exists.",
         inputSchema: {
           type: "object",
           properties: {
@@ -346,6 +312,14 @@ server.setRequestHandler(ListToolsRequestSchema, async () => {
             session_name: {
               type: "string",
               description: "Name of the session to recover (optional, shows list if not provided)"
+            },
+            dry_run: {
+              type: "boolean",
+              description: "Only check that the archived session can be restored and report the branch and worktree it would get"
+            },
+            json: {
+              type: "boolean",
+              description: "Return the result as a JSON object (requires session_name)"
             }
           },
           required: []
@@ -395,6 +369,14 @@ server.setRequestHandler(ListToolsRequestSchema, async () => {
             force: {
               type: "boolean",
               description: "Force cancellation without confirmation prompts"
+            },
+            dry_run: {
+              type: "boolean",
+              description: "Only check that the session can be cancelled and report the archive branch it would get, without changing anything"
+            },
+            json: {
+              type: "boolean",
+              description: "Return the result as a JSON object"
             }
           },
           required: []
@@ -402,7 +384,7 @@ server.setRequestHandler(ListToolsRequestSchema, async () => {
       },
       {
         name: "para_status_show",
-        description: "Monitor agent progress across para sessions. Get agent-reported status including current task, test results (whole codebase health), todo progress, and blocked status. Use this to coordinate parallel development and identify agents needing assistance.\n\nOUTPUT INCLUDES:\n- current_task: What the agent is currently working on\n- test_status: passed/failed/unknown (reflects ALL tests in codebase)\n- is_blocked: Whether agent needs help\n- todo_percentage: Progress through tasks\n- last_update: When status was last reported\n\nORCHESTRATOR USAGE:\n- Monitor all agents: para_status_show()\n- Check specific agent: para_status_show(session: 'agent-name')\n- Get structured data: para_status_show(json: true)\n\nREAD-ONLY: This tool only reads status. Agents update their own status via CLI.",
+        description: "Monitor agent progress across para sessions. Get agent-reported status including current task, test results (whole codebase health), todo progress, and blocked status. Use this to coordinate parallel development and identify agents needing assistance.\n\nOUTPUT INCLUDES:\n- current_task: What the agent is currently working on\n- test_status: passed/failed/unknown (reflects ALL tests in codebase)\n- is_blocked: Whether agent needs help\n- todo_percentage: Progress through tasks\n- updated_at: When status was last reported (age_seconds and stale tell how old it is)\n\nORCHESTRATOR USAGE:\n- Monitor all agents: para_status_show()\n- Check specific agent: para_status_show(session: 'agent-name')\n- Get structured data: para_status_show(json: true)\n\nREAD-ONLY: This tool only reads status. Agents update their own status via CLI.",
         inputSchema: {
           type: "object",
           properties: {
@@ -493,7 +475,8 @@ server.setRequestHandler(CallToolRequestSchema, async (request) => {
           if (finishArgs.branch) {
             cmdArgs.push("--branch", finishArgs.branch);
           }
-          result = await runParaCommand(cmdArgs);
+          const report = await finishSession(PARA_BINARY, cmdArgs, process.cwd());
+          result = JSON.stringify(report, null, 2);
         }
         break;
 
@@ -554,6 +537,12 @@ server.setRequestHandler(CallToolRequestSchema, async (request) => {
           if (recoverArgs.session_name) {
             cmdArgs.push(recoverArgs.session_name);
           }
+          if (recoverArgs.dry_run) {
+            cmdArgs.push("--dry-run");
+          }
+          if (recoverArgs.json) {
+            cmdArgs.push("--json");
+          }
           result = await runParaCommand(cmdArgs);
         }
         break;
@@ -581,6 +570,12 @@ server.setRequestHandler(CallToolRequestSchema, async (request) => {
           if (cancelArgs.force) {
             cmdArgs.push("--force");
           }
+          if (cancelArgs.dry_run) {
+            cmdArgs.push("--dry-run");
+          }
+          if (cancelArgs.json) {
+            cmdArgs.push("--json");
+          }
           result = await runParaCommand(cmdArgs);
         }
         break;
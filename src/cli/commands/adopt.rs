@@ -0,0 +1,17 @@
+use crate::cli::context::CommandContext;
+use crate::cli::parser::AdoptArgs;
+use crate::utils::output::path_link;
+use crate::utils::Result;
+
+pub fn execute(ctx: &CommandContext, args: AdoptArgs) -> Result<()> {
+    let mut session_manager = ctx.session_manager();
+    let session = session_manager.adopt_branch(&args.branch, args.name)?;
+
+    println!(
+        "✅ Adopted branch '{}' as session '{}'",
+        session.branch, session.name
+    );
+    println!("   Worktree: {}", path_link(&session.worktree_path));
+    println!("   Continue with: para resume {}", session.name);
+    Ok(())
+}
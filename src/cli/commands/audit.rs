@@ -0,0 +1,57 @@
+use crate::cli::context::CommandContext;
+use crate::cli::parser::{AuditArgs, AuditCommands};
+use crate::core::git::audit::{self, AuditEntry, REPO_LOG_NAME};
+use crate::core::session::recovery::parse_since;
+use crate::utils::Result;
+use chrono::{Local, Utc};
+
+pub fn execute(ctx: &CommandContext, args: AuditArgs) -> Result<()> {
+    match args.command {
+        AuditCommands::Show { session, since } => show(ctx, session.as_deref(), since),
+    }
+}
+
+fn show(ctx: &CommandContext, session: Option<&str>, since: Option<String>) -> Result<()> {
+    let since = since
+        .as_deref()
+        .map(|value| parse_since(value, Utc::now()))
+        .transpose()?;
+    let session_manager = ctx.session_manager();
+    let audit_dir = audit::audit_dir(session_manager.state_dir());
+    let name = session.unwrap_or(REPO_LOG_NAME);
+    let entries = audit::read_entries(&audit_dir, name, since)?;
+
+    if entries.is_empty() {
+        if !ctx.config().git.audit_log {
+            println!("Audit logging is disabled; enable it with git.audit_log in the config");
+        } else {
+            match session {
+                Some(session) => println!("No git commands logged for session '{session}'"),
+                None => println!("No git commands logged for the main repository"),
+            }
+        }
+        return Ok(());
+    }
+
+    for entry in &entries {
+        println!("{}", format_entry(entry));
+    }
+    Ok(())
+}
+
+fn format_entry(entry: &AuditEntry) -> String {
+    let exit = entry
+        .exit_code
+        .map(|code| code.to_string())
+        .unwrap_or_else(|| "-".to_string());
+    format!(
+        "{}  exit {exit:>2}  {:>6}ms  {}  ({})",
+        entry
+            .timestamp
+            .with_timezone(&Local)
+            .format("%Y-%m-%d %H:%M:%S"),
+        entry.duration_ms,
+        entry.argv.join(" "),
+        entry.cwd.display()
+    )
+}
@@ -1,29 +1,69 @@
+use crate::cli::commands::common::{
+    ensure_worktree_not_open, is_non_interactive, unregister_from_daemon,
+};
+use crate::cli::context::CommandContext;
+use crate::cli::json_output::{JsonOutput, SCHEMA_VERSION};
 use crate::cli::parser::CancelArgs;
 use crate::config::Config;
-use crate::core::git::{GitOperations, GitService, SessionEnvironment};
-use crate::core::session::SessionManager;
+use crate::core::git::protected::ensure_not_protected;
+use crate::core::git::shared_branch::DetachedWorktrees;
+use crate::core::git::ArchiveRef;
+use crate::core::git::SessionEnvironment;
+use crate::core::notifications::{notify, NotificationEvent};
+use crate::core::session::{
+    context, notes, pin, retry, stack, SessionManager, SessionOperation, SessionState,
+};
+use crate::core::vcs::VcsBackend;
+use crate::core::version;
 use crate::platform::get_platform_manager;
 use crate::utils::{ParaError, Result};
-use std::env;
+use serde::{Deserialize, Serialize};
 use std::io::{self, Write};
-
-fn is_non_interactive() -> bool {
-    env::var("PARA_NON_INTERACTIVE").is_ok()
-        || env::var("CI").is_ok()
-        || !atty::is(atty::Stream::Stdin)
+use std::path::{Path, PathBuf};
+
+/// Result of `para cancel --json`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CancelOutput {
+    pub schema_version: u32,
+    pub session: String,
+    pub archived_branch: String,
+    /// Worktree deleted by `--force`; without it the worktree stays on disk
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub removed_worktree: Option<PathBuf>,
+    /// Whether the archived branch still holds the session's commits
+    pub branch_kept: bool,
+    /// Nothing was changed; the fields say what cancelling would do
+    #[serde(skip_serializing_if = "std::ops::Not::not", default)]
+    pub dry_run: bool,
 }
 
-pub fn execute(config: Config, args: CancelArgs) -> Result<()> {
+pub fn execute(ctx: &CommandContext, args: CancelArgs) -> Result<()> {
     validate_cancel_args(&args)?;
 
-    let git_service = GitService::discover()?;
-    let mut session_manager = SessionManager::new(&config);
+    let json = JsonOutput::start(args.json);
+    let output = cancel_in(ctx, &args)?;
+    json.finish(&output)
+}
 
-    let session_name = detect_session_name(&args, &git_service, &session_manager)?;
+/// [`execute`] without printing the JSON result
+fn cancel_in(ctx: &CommandContext, args: &CancelArgs) -> Result<CancelOutput> {
+    let config = ctx.config();
+    let vcs = ctx.vcs()?;
+    let vcs = vcs.as_ref();
+    let mut session_manager = ctx.session_manager();
+    let session_name = detect_session_name(args, vcs, &session_manager, ctx.current_dir())?;
 
     let session_state = session_manager.load_state(&session_name)?;
+    version::check_session(&session_state, true)?;
+    check_stacked_children(&session_manager, &session_state, args.force)?;
+    if args.dry_run {
+        return cancel_dry_run(config, vcs, &session_state, args);
+    }
+    if session_state.pinned && !args.force {
+        confirm_cancel_pinned(&session_name)?;
+    }
 
-    let has_uncommitted = git_service.repository().has_uncommitted_changes()?;
+    let has_uncommitted = vcs.has_uncommitted_changes()?;
     if has_uncommitted && !args.force {
         confirm_cancel_with_changes(&session_name)?;
     } else if has_uncommitted && args.force {
@@ -31,23 +71,199 @@ pub fn execute(config: Config, args: CancelArgs) -> Result<()> {
             "WARNING: Force canceling session '{session_name}' with uncommitted changes. Your work will be archived."
         );
     }
+    ensure_worktree_not_open(config, &session_state, args.close_ide, args.force)?;
+
+    let archived_branch = cancel_and_archive_session(
+        config,
+        vcs,
+        &mut session_manager,
+        &session_name,
+        args.force,
+        args.force_rename,
+        args.allow_protected,
+    )?
+    .branch;
+
+    if let Some(git_service) = vcs.git() {
+        let archive_manager =
+            crate::core::session::archive::ArchiveManager::new(config, git_service);
+        if let Ok((old_removed, limit_removed)) = archive_manager.auto_cleanup() {
+            if old_removed > 0 || limit_removed > 0 {
+                eprintln!(
+                    "Archive cleanup: removed {old_removed} old archives, {limit_removed} for limit"
+                );
+            }
+        }
+    }
 
-    // Use session manager's cancel method which handles Docker cleanup
-    session_manager.cancel_session(&session_name, args.force)?;
+    println!(
+        "Session '{}' has been cancelled and archived as '{}'",
+        session_state.name, archived_branch
+    );
+    println!(
+        "To recover this session later, use: para recover {}",
+        session_state.name
+    );
+    println!("The archived branch is: {archived_branch}");
 
-    let archived_branch = git_service.archive_branch_with_session_name(
+    Ok(CancelOutput {
+        schema_version: SCHEMA_VERSION,
+        branch_kept: vcs.branch_exists(&archived_branch)?,
+        removed_worktree: (args.force && !session_state.worktree_path.exists())
+            .then(|| session_state.worktree_path.clone()),
+        session: session_state.name,
+        archived_branch,
+        dry_run: false,
+    })
+}
+
+/// `para cancel --dry-run`: check what would stop the cancel and report the
+/// archive the session would get, without changing anything
+fn cancel_dry_run(
+    config: &Config,
+    vcs: &dyn VcsBackend,
+    session_state: &SessionState,
+    args: &CancelArgs,
+) -> Result<CancelOutput> {
+    ensure_not_protected(
+        &session_state.worktree_path,
+        &config.git.protected_branches,
+        args.allow_protected,
+        "cancel",
+    )?;
+    let archive = vcs.archive_session_branch_dry_run(
         &session_state.branch,
         &session_state.name,
         &config.git.branch_prefix,
+        Some(&session_state.worktree_path),
     )?;
 
-    let archive_manager = crate::core::session::archive::ArchiveManager::new(&config, &git_service);
-    if let Ok((old_removed, limit_removed)) = archive_manager.auto_cleanup() {
-        if old_removed > 0 || limit_removed > 0 {
-            eprintln!(
-                "Archive cleanup: removed {old_removed} old archives, {limit_removed} for limit"
-            );
+    if session_state.pinned && !args.force {
+        println!(
+            "Session '{}' is pinned; cancelling it asks first",
+            session_state.name
+        );
+    }
+    if !args.force && vcs.has_uncommitted_changes()? {
+        println!(
+            "Session '{}' has uncommitted changes; cancelling it asks first",
+            session_state.name
+        );
+    }
+    println!(
+        "Would cancel session '{}' and archive it as '{}'",
+        session_state.name, archive.branch
+    );
+
+    Ok(CancelOutput {
+        schema_version: SCHEMA_VERSION,
+        session: session_state.name.clone(),
+        archived_branch: archive.branch,
+        removed_worktree: (args.force && session_state.worktree_path.exists())
+            .then(|| session_state.worktree_path.clone()),
+        branch_kept: true,
+        dry_run: true,
+    })
+}
+
+/// Move the session's notes next to the archive entry of `archive`
+fn archive_session_notes(
+    session_manager: &SessionManager,
+    session_name: &str,
+    archive: &ArchiveRef,
+) {
+    if let Err(e) = notes::archive_notes(
+        session_manager.state_dir(),
+        session_name,
+        &archive.timestamp,
+        false,
+    ) {
+        eprintln!("Warning: Failed to archive session notes: {e}");
+    }
+}
+
+/// Keep the archived branch of a pinned session out of archive retention
+fn archive_pin(session_manager: &SessionManager, session_name: &str, archive: &ArchiveRef) {
+    if let Err(e) = pin::archive_pin(
+        session_manager.state_dir(),
+        &archive.timestamp,
+        session_name,
+    ) {
+        eprintln!("Warning: Failed to keep the archive of pinned session: {e}");
+    }
+}
+
+/// Cancel a session and move its branch into the archive.
+///
+/// Shared by `para cancel` and `para clean --sessions`: stops the container of
+/// docker sessions, removes the session state (and the worktree when `force`
+/// is set), closes the IDE window and raises the cancellation notification.
+/// Other worktrees on the session branch abort the cancel unless
+/// `force_rename` is set, and so does a protected branch checked out in the
+/// session worktree unless `allow_protected` is set. Returns the archive the
+/// branch was moved to.
+pub(crate) fn cancel_and_archive_session(
+    config: &Config,
+    vcs: &dyn VcsBackend,
+    session_manager: &mut SessionManager,
+    session_name: &str,
+    force: bool,
+    force_rename: bool,
+    allow_protected: bool,
+) -> Result<ArchiveRef> {
+    let session_state = session_manager.load_state(session_name)?;
+    ensure_not_protected(
+        &session_state.worktree_path,
+        &config.git.protected_branches,
+        allow_protected,
+        "cancel",
+    )?;
+    let _operation = session_manager.begin_operation(session_name, SessionOperation::Cancelling)?;
+
+    let detached = match vcs.git() {
+        Some(git_service) => DetachedWorktrees::release(
+            git_service.repository(),
+            &session_state.branch,
+            Some(&session_state.worktree_path),
+            force_rename,
+        )?,
+        None => DetachedWorktrees::default(),
+    };
+
+    // Keep the context files so recovering the session brings them back
+    context::keep_context(
+        session_manager.state_dir(),
+        &session_state.name,
+        &session_state.worktree_path,
+    );
+    // and the task so the session can still be retried
+    retry::keep_task(session_manager.state_dir(), &session_state);
+
+    // Use session manager's cancel method which handles Docker cleanup
+    let archived = session_manager
+        .cancel_session(session_name, force)
+        .and_then(|_| {
+            vcs.archive_session_branch(
+                &session_state.branch,
+                &session_state.name,
+                &config.git.branch_prefix,
+                Some(&session_state.worktree_path),
+            )
+        });
+    let archive = match archived {
+        Ok(archive) => {
+            detached.reattach(&archive.branch);
+            archive
         }
+        Err(e) => {
+            detached.reattach(&session_state.branch);
+            return Err(e);
+        }
+    };
+    unregister_from_daemon(&session_state.name);
+    archive_session_notes(session_manager, &session_state.name, &archive);
+    if session_state.pinned {
+        archive_pin(session_manager, &session_state.name, &archive);
     }
 
     if config.is_real_ide_environment() {
@@ -55,43 +271,39 @@ pub fn execute(config: Config, args: CancelArgs) -> Result<()> {
         if let Err(e) = platform.close_ide_window(
             &session_state.name,
             &config.ide.name,
-            config.get_state_dir(),
+            &config.resolved_state_dir().to_string_lossy(),
         ) {
             eprintln!("Warning: Failed to close IDE window: {e}");
         }
     }
 
-    println!(
-        "Session '{}' has been cancelled and archived as '{}'",
-        session_state.name, archived_branch
-    );
-    println!(
-        "To recover this session later, use: para recover {}",
-        session_state.name
+    notify(
+        config,
+        NotificationEvent::Cancelled {
+            session: session_state.name,
+        },
     );
-    println!("The archived branch is: {archived_branch}");
 
-    Ok(())
+    Ok(archive)
 }
 
 fn detect_session_name(
     args: &CancelArgs,
-    git_service: &GitService,
+    vcs: &dyn VcsBackend,
     session_manager: &SessionManager,
+    current_dir: &Path,
 ) -> Result<String> {
-    if let Some(ref session_name) = args.session {
-        if !session_manager.session_exists(session_name) {
-            return Err(ParaError::session_not_found(session_name));
-        }
-        return Ok(session_name.clone());
+    if let Some(ref branch) = args.session_branch {
+        return Ok(session_manager.resolve_session_by_branch(branch)?.name);
     }
 
-    let current_dir = env::current_dir()
-        .map_err(|e| ParaError::file_operation(format!("Failed to get current directory: {e}")))?;
+    if let Some(ref session_name) = args.session {
+        return Ok(session_manager.resolve_session(session_name)?.name);
+    }
 
-    match git_service.validate_session_environment(&current_dir)? {
+    match vcs.session_environment(current_dir)? {
         SessionEnvironment::Worktree { branch, .. } => {
-            if let Some(session) = session_manager.find_session_by_path(&current_dir)? {
+            if let Some(session) = session_manager.find_session_by_path(current_dir)? {
                 return Ok(session.name);
             }
 
@@ -111,6 +323,35 @@ fn detect_session_name(
     }
 }
 
+/// Sessions stacked on this one lose their base when it is cancelled, so that
+/// takes `--force`
+pub(crate) fn check_stacked_children(
+    session_manager: &SessionManager,
+    session: &SessionState,
+    force: bool,
+) -> Result<()> {
+    let children = stack::active_children(session_manager, session);
+    if children.is_empty() {
+        return Ok(());
+    }
+
+    let names: Vec<&str> = children.iter().map(|child| child.name.as_str()).collect();
+    if !force {
+        return Err(ParaError::invalid_args(format!(
+            "Session '{}' has stacked sessions built on its branch: {}. \
+             Finish or cancel them first, or pass --force",
+            session.name,
+            names.join(", ")
+        )));
+    }
+    eprintln!(
+        "WARNING: Cancelling session '{}' leaves its stacked sessions without their base: {}",
+        session.name,
+        names.join(", ")
+    );
+    Ok(())
+}
+
 fn confirm_cancel_with_changes(session_name: &str) -> Result<()> {
     if is_non_interactive() {
         return Err(ParaError::invalid_args(
@@ -119,9 +360,27 @@ fn confirm_cancel_with_changes(session_name: &str) -> Result<()> {
         ));
     }
 
-    print!(
-        "Session '{session_name}' has uncommitted changes. Are you sure you want to cancel? This will archive the session but preserve your work. [y/N]: "
-    );
+    ask_to_cancel(&format!(
+        "Session '{session_name}' has uncommitted changes. Are you sure you want to cancel? This will archive the session but preserve your work."
+    ))
+}
+
+fn confirm_cancel_pinned(session_name: &str) -> Result<()> {
+    if is_non_interactive() {
+        return Err(ParaError::invalid_args(format!(
+            "Session '{session_name}' is pinned. Pass --force to cancel it anyway, \
+             or unpin it first with 'para unpin {session_name}'."
+        )));
+    }
+
+    ask_to_cancel(&format!(
+        "Session '{session_name}' is pinned. Cancel it anyway?"
+    ))
+}
+
+/// Ask `question` and abort the cancel unless the answer is yes
+fn ask_to_cancel(question: &str) -> Result<()> {
+    print!("{question} [y/N]: ");
     io::stdout()
         .flush()
         .map_err(|e| ParaError::file_operation(format!("Failed to flush stdout: {e}")))?;
@@ -154,7 +413,9 @@ fn validate_cancel_args(args: &CancelArgs) -> Result<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::session::SessionState;
+    use crate::core::git::GitOperations;
+    use crate::core::session::SessionStatus;
+    use crate::core::vcs::fake::FakeVcsBackend;
     use crate::test_utils::test_helpers::*;
     use tempfile::TempDir;
 
@@ -163,12 +424,24 @@ mod tests {
         let args = CancelArgs {
             session: None,
             force: false,
+            force_rename: false,
+            allow_protected: false,
+            session_branch: None,
+            close_ide: false,
+            json: false,
+            dry_run: false,
         };
         assert!(validate_cancel_args(&args).is_ok());
 
         let args = CancelArgs {
             session: Some("valid-session".to_string()),
             force: false,
+            force_rename: false,
+            allow_protected: false,
+            session_branch: None,
+            close_ide: false,
+            json: false,
+            dry_run: false,
         };
         assert!(validate_cancel_args(&args).is_ok());
     }
@@ -178,6 +451,12 @@ mod tests {
         let args = CancelArgs {
             session: Some(String::new()),
             force: false,
+            force_rename: false,
+            allow_protected: false,
+            session_branch: None,
+            close_ide: false,
+            json: false,
+            dry_run: false,
         };
         let result = validate_cancel_args(&args);
         assert!(result.is_err());
@@ -187,9 +466,9 @@ mod tests {
     #[test]
     fn test_detect_session_name_explicit() {
         let temp_dir = TempDir::new().unwrap();
-        let git_temp = TempDir::new().unwrap();
-        let _guard = TestEnvironmentGuard::new(&git_temp, &temp_dir).unwrap();
-        let (_git_temp, git_service) = setup_test_repo();
+        let repo_dir = TempDir::new().unwrap();
+        let _guard = TestEnvironmentGuard::new(&repo_dir, &temp_dir).unwrap();
+        let vcs = FakeVcsBackend::new(repo_dir.path());
 
         let config = create_test_config_with_dir(&temp_dir);
         let session_manager = SessionManager::new(&config);
@@ -197,7 +476,7 @@ mod tests {
         let session_state = SessionState::new(
             "test-session".to_string(),
             "test-branch".to_string(),
-            git_service.repository().root.join("test-worktree"),
+            vcs.root().join("test-worktree"),
         );
         session_manager
             .save_state(&session_state)
@@ -206,9 +485,15 @@ mod tests {
         let args = CancelArgs {
             session: Some("test-session".to_string()),
             force: false,
+            force_rename: false,
+            allow_protected: false,
+            session_branch: None,
+            close_ide: false,
+            json: false,
+            dry_run: false,
         };
 
-        let result = detect_session_name(&args, &git_service, &session_manager);
+        let result = detect_session_name(&args, &vcs, &session_manager, vcs.root());
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), "test-session");
     }
@@ -216,9 +501,9 @@ mod tests {
     #[test]
     fn test_detect_session_name_nonexistent() {
         let temp_dir = TempDir::new().unwrap();
-        let git_temp = TempDir::new().unwrap();
-        let _guard = TestEnvironmentGuard::new(&git_temp, &temp_dir).unwrap();
-        let (_git_temp, git_service) = setup_test_repo();
+        let repo_dir = TempDir::new().unwrap();
+        let _guard = TestEnvironmentGuard::new(&repo_dir, &temp_dir).unwrap();
+        let vcs = FakeVcsBackend::new(repo_dir.path());
 
         let config = create_test_config_with_dir(&temp_dir);
         let session_manager = SessionManager::new(&config);
@@ -226,19 +511,67 @@ mod tests {
         let args = CancelArgs {
             session: Some("nonexistent-session".to_string()),
             force: false,
+            force_rename: false,
+            allow_protected: false,
+            session_branch: None,
+            close_ide: false,
+            json: false,
+            dry_run: false,
         };
 
-        let result = detect_session_name(&args, &git_service, &session_manager);
+        let result = detect_session_name(&args, &vcs, &session_manager, vcs.root());
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("not found"));
     }
 
+    #[test]
+    fn test_detect_session_name_never_guesses() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo_dir = TempDir::new().unwrap();
+        let _guard = TestEnvironmentGuard::new(&repo_dir, &temp_dir).unwrap();
+        let vcs = FakeVcsBackend::new(repo_dir.path());
+
+        let config = create_test_config_with_dir(&temp_dir);
+        let session_manager = SessionManager::new(&config);
+        for name in ["auth-fix", "auth-docs"] {
+            let session_state = SessionState::new(
+                name.to_string(),
+                format!("test/{name}"),
+                vcs.root().join(name),
+            );
+            session_manager.save_state(&session_state).unwrap();
+        }
+
+        let args_for = |session: &str| CancelArgs {
+            session: Some(session.to_string()),
+            force: false,
+            force_rename: false,
+            allow_protected: false,
+            session_branch: None,
+            close_ide: false,
+            json: false,
+            dry_run: false,
+        };
+
+        // A unique match resolves to the full name
+        let result = detect_session_name(&args_for("fix"), &vcs, &session_manager, vcs.root());
+        assert_eq!(result.unwrap(), "auth-fix");
+
+        // Several matches are refused, listing the candidates
+        let err = detect_session_name(&args_for("auth"), &vcs, &session_manager, vcs.root())
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("auth-docs, auth-fix"));
+        assert!(session_manager.session_exists("auth-fix"));
+        assert!(session_manager.session_exists("auth-docs"));
+    }
+
     #[test]
     fn test_detect_session_name_from_main_repo() {
         let temp_dir = TempDir::new().unwrap();
-        let git_temp = TempDir::new().unwrap();
-        let _guard = TestEnvironmentGuard::new(&git_temp, &temp_dir).unwrap();
-        let (_git_temp, git_service) = setup_test_repo();
+        let repo_dir = TempDir::new().unwrap();
+        let _guard = TestEnvironmentGuard::new(&repo_dir, &temp_dir).unwrap();
+        let vcs = FakeVcsBackend::new(repo_dir.path());
 
         let config = create_test_config_with_dir(&temp_dir);
         let session_manager = SessionManager::new(&config);
@@ -246,22 +579,69 @@ mod tests {
         let args = CancelArgs {
             session: None,
             force: false,
+            force_rename: false,
+            allow_protected: false,
+            session_branch: None,
+            close_ide: false,
+            json: false,
+            dry_run: false,
         };
 
-        std::env::set_current_dir(&git_service.repository().root)
-            .expect("Failed to change to repo root");
-
-        let result = detect_session_name(&args, &git_service, &session_manager);
+        let result = detect_session_name(&args, &vcs, &session_manager, vcs.root());
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("main repository"));
     }
 
+    #[test]
+    fn test_detect_session_name_from_worktree() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo_dir = TempDir::new().unwrap();
+        let vcs = FakeVcsBackend::new(repo_dir.path());
+
+        let config = create_test_config_with_dir(&temp_dir);
+        let session_manager = SessionManager::new(&config);
+        let worktree_path = vcs
+            .create_worktree("para/by-branch", &vcs.root().join("subtrees/elsewhere"))
+            .unwrap();
+        session_manager
+            .save_state(&SessionState::new(
+                "by-branch".to_string(),
+                "para/by-branch".to_string(),
+                vcs.root().join("subtrees/by-branch"),
+            ))
+            .unwrap();
+
+        let args = CancelArgs {
+            session: None,
+            force: false,
+            force_rename: false,
+            allow_protected: false,
+            session_branch: None,
+            close_ide: false,
+            json: false,
+            dry_run: false,
+        };
+
+        // Found by the worktree's branch when its path is not the recorded one
+        let nested = worktree_path.join("src");
+        let result = detect_session_name(&args, &vcs, &session_manager, &nested);
+        assert_eq!(result.unwrap(), "by-branch");
+
+        let other = vcs
+            .create_worktree("para/unknown", &vcs.root().join("subtrees/unknown"))
+            .unwrap();
+        let err = detect_session_name(&args, &vcs, &session_manager, &other)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("branch: para/unknown"));
+    }
+
     #[test]
     fn test_detect_session_name_invalid_directory() {
         let temp_dir = TempDir::new().unwrap();
-        let git_temp = TempDir::new().unwrap();
-        let _guard = TestEnvironmentGuard::new(&git_temp, &temp_dir).unwrap();
-        let (_git_temp, git_service) = setup_test_repo();
+        let repo_dir = TempDir::new().unwrap();
+        let _guard = TestEnvironmentGuard::new(&repo_dir, &temp_dir).unwrap();
+        let vcs = FakeVcsBackend::new(repo_dir.path());
 
         let config = create_test_config_with_dir(&temp_dir);
         let session_manager = SessionManager::new(&config);
@@ -269,12 +649,17 @@ mod tests {
         let args = CancelArgs {
             session: None,
             force: false,
+            force_rename: false,
+            allow_protected: false,
+            session_branch: None,
+            close_ide: false,
+            json: false,
+            dry_run: false,
         };
 
         let invalid_dir = TempDir::new().expect("Failed to create invalid dir");
-        std::env::set_current_dir(invalid_dir.path()).expect("Failed to change to invalid dir");
 
-        let result = detect_session_name(&args, &git_service, &session_manager);
+        let result = detect_session_name(&args, &vcs, &session_manager, invalid_dir.path());
         assert!(result.is_err());
         assert!(result
             .unwrap_err()
@@ -341,15 +726,24 @@ mod tests {
         let args = CancelArgs {
             session: Some("test-force-session".to_string()),
             force: true,
+            force_rename: false,
+            allow_protected: false,
+            session_branch: None,
+            close_ide: false,
+            json: false,
+            dry_run: false,
         };
 
         // This should not error even with uncommitted changes
-        let result = execute(config.clone(), args);
+        let result = execute(
+            &CommandContext::in_dir(config.clone(), git_service.repository().root.clone()),
+            args,
+        );
 
         // The actual execute might fail due to git operations in test environment,
         // but we're testing that it doesn't fail due to uncommitted changes
-        if result.is_err() {
-            let error_msg = result.unwrap_err().to_string();
+        if let Err(e) = result {
+            let error_msg = e.to_string();
             assert!(!error_msg.contains("uncommitted changes"));
         }
     }
@@ -384,18 +778,399 @@ mod tests {
         let args = CancelArgs {
             session: Some("test-force-noninteractive".to_string()),
             force: true,
+            force_rename: false,
+            allow_protected: false,
+            session_branch: None,
+            close_ide: false,
+            json: false,
+            dry_run: false,
         };
 
         // This should work even in non-interactive mode with force flag
-        let result = execute(config, args);
+        let result = execute(
+            &CommandContext::in_dir(config, git_service.repository().root.clone()),
+            args,
+        );
 
         // The actual execute might fail due to git operations,
         // but should not fail due to non-interactive mode
-        if result.is_err() {
-            let error_msg = result.unwrap_err().to_string();
+        if let Err(e) = result {
+            let error_msg = e.to_string();
             assert!(!error_msg.contains("non-interactive mode"));
         }
 
         std::env::remove_var("PARA_NON_INTERACTIVE");
     }
+
+    #[test]
+    fn test_cancel_refuses_when_main_is_checked_out_in_session_worktree() {
+        let temp_dir = TempDir::new().unwrap();
+        let (_git_temp, git_service) = setup_test_repo();
+        let config = create_test_config_with_dir(&temp_dir);
+        let mut session_manager = SessionManager::new(&config);
+
+        let worktrees = TempDir::new().unwrap();
+        let worktree_path = worktrees.path().join("hijacked");
+        git_service
+            .create_worktree("para/hijacked", &worktree_path)
+            .unwrap();
+        session_manager
+            .save_state(&SessionState::new(
+                "hijacked".to_string(),
+                "para/hijacked".to_string(),
+                worktree_path.clone(),
+            ))
+            .unwrap();
+
+        // main is also checked out in the main repo, so the agent had to force it
+        let checkout = std::process::Command::new("git")
+            .current_dir(&worktree_path)
+            .args(["checkout", "--ignore-other-worktrees", "main"])
+            .output()
+            .unwrap();
+        assert!(checkout.status.success());
+
+        let err = cancel_and_archive_session(
+            &config,
+            &git_service,
+            &mut session_manager,
+            "hijacked",
+            true,
+            false,
+            false,
+        )
+        .unwrap_err()
+        .to_string();
+
+        assert!(err.contains("Refusing to cancel"));
+        assert!(err.contains("'main'"));
+        assert!(session_manager.session_exists("hijacked"));
+        assert!(git_service.branch_exists("para/hijacked").unwrap());
+        assert!(worktree_path.exists());
+    }
+
+    #[test]
+    fn test_cancel_with_stacked_children_requires_force() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = create_test_config_with_dir(&temp_dir);
+        let session_manager = SessionManager::new(&config);
+
+        let mut parent = SessionState::new(
+            "parent".to_string(),
+            "test/parent".to_string(),
+            temp_dir.path().join("parent"),
+        );
+        parent.stacked_children = vec!["child".to_string(), "finished".to_string()];
+        session_manager.save_state(&parent).unwrap();
+        for (name, status) in [
+            ("child", SessionStatus::Active),
+            ("finished", SessionStatus::Review),
+        ] {
+            let mut child = SessionState::new(
+                name.to_string(),
+                format!("test/{name}"),
+                temp_dir.path().join(name),
+            );
+            child.stacked_on = Some("parent".to_string());
+            child.status = status;
+            session_manager.save_state(&child).unwrap();
+        }
+
+        let err = check_stacked_children(&session_manager, &parent, false).unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("child"), "{message}");
+        assert!(!message.contains("finished"), "{message}");
+        assert!(message.contains("--force"), "{message}");
+        assert!(check_stacked_children(&session_manager, &parent, true).is_ok());
+
+        // Nothing to guard once the child is done
+        let mut child = session_manager.load_state("child").unwrap();
+        child.status = SessionStatus::Review;
+        session_manager.save_state(&child).unwrap();
+        assert!(check_stacked_children(&session_manager, &parent, false).is_ok());
+    }
+
+    #[test]
+    fn test_cancelling_pinned_session_needs_confirmation() {
+        std::env::set_var("PARA_NON_INTERACTIVE", "1");
+
+        let temp_dir = TempDir::new().unwrap();
+        let (_git_temp, git_service) = setup_test_repo();
+        let config = create_test_config_with_dir(&temp_dir);
+        let repo_root = git_service.repository().root.clone();
+        let mut session_manager = SessionManager::for_repository(&config, &repo_root);
+        session_manager
+            .create_session_with_all_flags("perf".to_string(), None, false, false, None)
+            .unwrap();
+        pin::set_pinned(&session_manager, "perf", true).unwrap();
+
+        let args = |force| CancelArgs {
+            session: Some("perf".to_string()),
+            force,
+            force_rename: false,
+            allow_protected: false,
+            session_branch: None,
+            close_ide: false,
+            json: false,
+            dry_run: false,
+        };
+        let ctx = CommandContext::in_dir(config.clone(), repo_root.clone());
+        let message = cancel_in(&ctx, &args(false)).unwrap_err().to_string();
+        assert!(message.contains("is pinned"), "{message}");
+        assert!(message.contains("--force"), "{message}");
+        assert!(session_manager.session_exists("perf"));
+
+        let output = cancel_in(&ctx, &args(true)).unwrap();
+        assert!(!session_manager.session_exists("perf"));
+        let archive = ArchiveRef::parse(&output.archived_branch, "test")
+            .unwrap()
+            .unwrap();
+        assert!(pin::is_archive_pinned(
+            session_manager.state_dir(),
+            &archive.timestamp,
+            "perf"
+        ));
+
+        std::env::remove_var("PARA_NON_INTERACTIVE");
+    }
+
+    #[test]
+    fn test_cancel_dry_run_changes_nothing() {
+        let temp_dir = TempDir::new().unwrap();
+        let (_git_temp, git_service) = setup_test_repo();
+        let config = create_test_config_with_dir(&temp_dir);
+        let repo_root = git_service.repository().root.clone();
+        let mut session_manager = SessionManager::for_repository(&config, &repo_root);
+        let session = session_manager
+            .create_session_with_all_flags("trial".to_string(), None, false, false, None)
+            .unwrap();
+        pin::set_pinned(&session_manager, "trial", true).unwrap();
+
+        let args = CancelArgs {
+            session: Some("trial".to_string()),
+            force: true,
+            force_rename: false,
+            allow_protected: false,
+            session_branch: None,
+            close_ide: false,
+            dry_run: true,
+            json: false,
+        };
+        let ctx = CommandContext::in_dir(config.clone(), repo_root.clone());
+        let output = cancel_in(&ctx, &args).unwrap();
+
+        assert!(output.dry_run);
+        assert_eq!(output.removed_worktree, Some(session.worktree_path.clone()));
+        let archive = ArchiveRef::parse(&output.archived_branch, "test")
+            .unwrap()
+            .unwrap();
+        assert_eq!(archive.session, "trial");
+        assert!(session_manager.session_exists("trial"));
+        assert!(session.worktree_path.exists());
+        assert!(git_service.branch_exists(&session.branch).unwrap());
+        assert!(!git_service.branch_exists(&archive.branch).unwrap());
+
+        // What would stop the real cancel stops the dry run too
+        let extra = temp_dir.path().join("extra");
+        let status = std::process::Command::new("git")
+            .current_dir(&repo_root)
+            .args(["worktree", "add", "--quiet", "-f"])
+            .arg(&extra)
+            .arg(&session.branch)
+            .status()
+            .unwrap();
+        assert!(status.success());
+        let err = cancel_in(&ctx, &args).unwrap_err();
+        assert!(err.to_string().contains("checked out"), "{err}");
+    }
+
+    #[test]
+    fn test_cancel_archives_session_notes() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo_dir = TempDir::new().unwrap();
+        let vcs = FakeVcsBackend::new(repo_dir.path());
+        let config = create_test_config_with_dir(&temp_dir);
+        let mut session_manager = SessionManager::new(&config);
+
+        let worktree_path = vcs
+            .root()
+            .join(&config.directories.subtrees_dir)
+            .join("noted");
+        vcs.create_worktree("para/noted", &worktree_path).unwrap();
+        session_manager
+            .save_state(&SessionState::new(
+                "noted".to_string(),
+                "para/noted".to_string(),
+                worktree_path,
+            ))
+            .unwrap();
+        let state_dir = session_manager.state_dir().clone();
+        notes::append_note(&state_dir, "noted", "waiting on review", chrono::Utc::now()).unwrap();
+
+        // Without --force the worktree is kept, so no git layout is needed
+        let archive = cancel_and_archive_session(
+            &config,
+            &vcs,
+            &mut session_manager,
+            "noted",
+            false,
+            false,
+            false,
+        )
+        .unwrap();
+
+        assert!(!vcs.branch_exists("para/noted").unwrap());
+        assert!(vcs.branch_exists(&archive.branch).unwrap());
+        assert!(!notes::notes_file(&state_dir, "noted").exists());
+        let note = notes::latest_archived_note(&state_dir, &archive.timestamp, "noted").unwrap();
+        assert!(note.ends_with("waiting on review"));
+    }
+
+    #[test]
+    fn test_cancel_and_recover_keep_resume_context() {
+        use crate::core::session::recovery::{RecoveryOptions, SessionRecovery};
+
+        let temp_dir = TempDir::new().unwrap();
+        let (_git_temp, git_service) = setup_test_repo();
+        let config = create_test_config_with_dir(&temp_dir);
+        let mut session_manager = SessionManager::new(&config);
+
+        let worktree_path = git_service
+            .repository()
+            .root
+            .join(&config.directories.subtrees_dir)
+            .join("resumable");
+        git_service
+            .create_worktree("para/resumable", &worktree_path)
+            .unwrap();
+        session_manager
+            .save_state(&SessionState::new(
+                "resumable".to_string(),
+                "para/resumable".to_string(),
+                worktree_path.clone(),
+            ))
+            .unwrap();
+        let context_dir = context::worktree_context_dir(&worktree_path, "resumable");
+        std::fs::create_dir_all(&context_dir).unwrap();
+        std::fs::write(
+            context_dir.join("resume_context.md"),
+            "# Resume Context\nPick up the OAuth refactor\n",
+        )
+        .unwrap();
+
+        cancel_and_archive_session(
+            &config,
+            &git_service,
+            &mut session_manager,
+            "resumable",
+            true,
+            false,
+            false,
+        )
+        .unwrap();
+        assert!(!worktree_path.exists());
+
+        let recovery = SessionRecovery::new(&config, &git_service, &session_manager);
+        let result = recovery
+            .recover_session(
+                "resumable",
+                RecoveryOptions {
+                    force_overwrite: false,
+                    preserve_original_name: true,
+                    replace_live_branch: false,
+                },
+            )
+            .unwrap();
+
+        let restored = context::worktree_context_dir(&result.worktree_path, "resumable")
+            .join("resume_context.md");
+        assert!(std::fs::read_to_string(restored)
+            .unwrap()
+            .contains("Pick up the OAuth refactor"));
+    }
+
+    #[test]
+    fn test_cancel_refuses_session_written_by_newer_para() {
+        let temp_dir = TempDir::new().unwrap();
+        let (_git_temp, git_service) = setup_test_repo();
+        let config = create_test_config_with_dir(&temp_dir);
+        let session_manager = SessionManager::new(&config);
+
+        let mut session = SessionState::new(
+            "future".to_string(),
+            "test/future".to_string(),
+            temp_dir.path().join("future"),
+        );
+        session.para_version = "999.0.0".to_string();
+        session_manager.save_state(&session).unwrap();
+
+        let args = CancelArgs {
+            session: Some("future".to_string()),
+            force: true,
+            force_rename: false,
+            allow_protected: false,
+            session_branch: None,
+            close_ide: false,
+            json: false,
+            dry_run: false,
+        };
+        let ctx = CommandContext::in_dir(config, git_service.repository().root.clone());
+        let message = cancel_in(&ctx, &args).unwrap_err().to_string();
+        assert!(message.contains("para 999.0.0"), "{message}");
+        assert!(message.contains("--ignore-version"), "{message}");
+        assert_eq!(
+            session_manager.load_state("future").unwrap().para_version,
+            "999.0.0"
+        );
+    }
+
+    #[test]
+    fn test_cancel_json_output_round_trips() {
+        use crate::cli::json_output::render;
+
+        let temp_dir = TempDir::new().unwrap();
+        let (_git_temp, git_service) = setup_test_repo();
+        let config = create_test_config_with_dir(&temp_dir);
+        let session_manager = SessionManager::new(&config);
+
+        let worktree_path = git_service
+            .repository()
+            .root
+            .join(&config.directories.subtrees_dir)
+            .join("scripted");
+        git_service
+            .create_worktree("test/scripted", &worktree_path)
+            .unwrap();
+        session_manager
+            .save_state(&SessionState::new(
+                "scripted".to_string(),
+                "test/scripted".to_string(),
+                worktree_path.clone(),
+            ))
+            .unwrap();
+
+        let args = CancelArgs {
+            session: Some("scripted".to_string()),
+            force: true,
+            force_rename: false,
+            allow_protected: false,
+            session_branch: None,
+            close_ide: false,
+            json: true,
+            dry_run: false,
+        };
+        let ctx = CommandContext::in_dir(config, git_service.repository().root.clone());
+        let output = cancel_in(&ctx, &args).unwrap();
+
+        assert_eq!(output.session, "scripted");
+        assert!(output.archived_branch.starts_with("test/archived/"));
+        assert!(git_service.branch_exists(&output.archived_branch).unwrap());
+        assert!(output.branch_kept);
+        assert_eq!(output.removed_worktree, Some(worktree_path));
+
+        let line = render(&output).unwrap();
+        assert!(line.contains("\"schema_version\":1"), "{line}");
+        let parsed: CancelOutput = serde_json::from_str(&line).unwrap();
+        assert_eq!(parsed, output);
+    }
 }
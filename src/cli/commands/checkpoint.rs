@@ -0,0 +1,82 @@
+use crate::cli::context::CommandContext;
+use crate::cli::parser::CheckpointArgs;
+use crate::core::checkpoint::{create_checkpoint, CheckpointOutcome};
+use crate::core::session::{SessionManager, SessionState};
+use crate::utils::{ParaError, Result};
+use chrono::Utc;
+use std::path::Path;
+
+pub fn execute(ctx: &CommandContext, args: CheckpointArgs) -> Result<()> {
+    let session_manager = ctx.session_manager();
+    let session = detect_session(&args, &session_manager, ctx.current_dir())?;
+
+    match checkpoint_session(&session)? {
+        CheckpointOutcome::Created(message) => {
+            println!("✅ Checkpointed session '{}': {message}", session.name)
+        }
+        _ => println!("Nothing to checkpoint in session '{}'", session.name),
+    }
+    Ok(())
+}
+
+/// Commit the session's pending work right away, even while files are changing
+fn checkpoint_session(session: &SessionState) -> Result<CheckpointOutcome> {
+    if !session.worktree_path.exists() {
+        return Err(ParaError::fs_error(format!(
+            "Worktree for session '{}' no longer exists: {}",
+            session.name,
+            session.worktree_path.display()
+        )));
+    }
+    create_checkpoint(&session.worktree_path, Utc::now(), false)
+}
+
+fn detect_session(
+    args: &CheckpointArgs,
+    session_manager: &SessionManager,
+    current_dir: &Path,
+) -> Result<SessionState> {
+    if let Some(ref session_name) = args.session {
+        return session_manager.resolve_session(session_name);
+    }
+
+    session_manager
+        .find_session_by_path(current_dir)?
+        .ok_or_else(|| {
+            ParaError::invalid_args(
+                "Not inside a session worktree. Use 'para checkpoint <session-name>' to checkpoint a specific session.",
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::test_helpers::*;
+    use std::fs;
+
+    #[test]
+    fn test_checkpoint_session_commits_pending_work() {
+        let (temp, git_service) = setup_test_repo();
+        let session = SessionState::new(
+            "feature".to_string(),
+            "para/feature".to_string(),
+            temp.path().to_path_buf(),
+        );
+
+        assert_eq!(
+            checkpoint_session(&session).unwrap(),
+            CheckpointOutcome::Clean
+        );
+
+        // Just-written files are committed too: manual checkpoints skip the quiet period
+        fs::write(temp.path().join("notes.md"), "progress").unwrap();
+        match checkpoint_session(&session).unwrap() {
+            CheckpointOutcome::Created(message) => {
+                assert!(message.starts_with("wip: auto-checkpoint"))
+            }
+            other => panic!("expected a checkpoint, got {other:?}"),
+        }
+        assert!(!git_service.repository().has_uncommitted_changes().unwrap());
+    }
+}
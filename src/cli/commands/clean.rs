@@ -1,22 +1,78 @@
+use crate::cli::commands::cancel::cancel_and_archive_session;
+use crate::cli::commands::common::{
+    ensure_worktree_not_open, is_non_interactive, unregister_from_daemon,
+};
+use crate::cli::context::CommandContext;
 use crate::cli::parser::CleanArgs;
 use crate::config::Config;
+use crate::core::cleanup::{self, CleanupKind};
 use crate::core::docker::cleanup::ContainerCleaner;
+use crate::core::docker::resources::{
+    self, DanglingResource, DockerCli, ResourceKind, SystemDockerCli,
+};
+use crate::core::gc;
+use crate::core::git::protected::ensure_not_protected;
 use crate::core::git::{GitOperations, GitService};
-use crate::utils::Result;
-use dialoguer::Confirm;
+use crate::core::idle::format_idle_duration;
+use crate::core::session::{integration_backup, orphans, pin, SessionManager, SessionState};
+use crate::core::trash::{self, TrashEntry};
+use crate::ui::theme;
+use crate::utils::progress::StepReporter;
+use crate::utils::{ArchiveBranchParser, Result};
+use chrono::{DateTime, Utc};
+use dialoguer::{Confirm, MultiSelect};
 use std::fs;
-use std::path::PathBuf;
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
 
-pub fn execute(config: Config, args: CleanArgs) -> Result<()> {
-    let git_service = GitService::discover()?;
+pub fn execute(ctx: &CommandContext, args: CleanArgs) -> Result<()> {
+    execute_in(ctx.git_service()?.clone(), ctx.config().clone(), args)
+}
+
+/// Clean the repository of `git_service` rather than the current directory's
+pub(crate) fn execute_in(git_service: GitService, config: Config, args: CleanArgs) -> Result<()> {
+    let dry_run = args.dry_run;
 
     let cleaner = SessionCleaner::new(git_service, config);
-    cleaner.execute_clean(args)
+    cleaner.execute_clean(args)?;
+
+    // Compacting logs is cheap and never destructive to sessions, so do it on every clean
+    if !dry_run {
+        if let Some(report) = gc::collect_garbage_quietly(&cleaner.config) {
+            if report.bytes_reclaimed > 0 {
+                println!(
+                    "🗜️  Compacted state directory ({})",
+                    gc::format_bytes(report.bytes_reclaimed)
+                );
+            }
+        }
+        prune_integration_backups(&cleaner);
+    }
+
+    Ok(())
+}
+
+/// Delete backups taken before `finish --onto-session` once they are past the retention
+fn prune_integration_backups(cleaner: &SessionCleaner) {
+    let max_age_days = gc::RetentionPolicy::from_config(&cleaner.config).max_age_days;
+    match integration_backup::prune_expired(
+        cleaner.session_manager.state_dir(),
+        cleaner.git_service.repository(),
+        max_age_days,
+    ) {
+        Ok(0) => {}
+        Ok(removed) => println!(
+            "{}",
+            theme::cleanup(format!("Removed {removed} expired integration backup(s)"))
+        ),
+        Err(e) => eprintln!("Warning: Failed to prune integration backups: {e}"),
+    }
 }
 
 struct SessionCleaner {
     git_service: GitService,
     config: crate::config::Config,
+    session_manager: SessionManager,
 }
 
 #[derive(Debug, Default)]
@@ -26,52 +82,241 @@ struct CleanupResults {
     old_archives_removed: usize,
     stale_status_files_removed: usize,
     orphaned_containers_removed: usize,
+    docker_resources_removed: usize,
+    expired_trash_deleted: usize,
+    /// Per-session outcome: the archived branch name or the error message
+    session_results: Vec<(CleanupItem, std::result::Result<String, String>)>,
+    /// Items deselected in the `--interactive` checklist, labeled as listed there
+    skipped: Vec<String>,
     errors: Vec<String>,
 }
 
+/// A cleanup target that is handled and reported individually
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum CleanupItem {
+    /// Active session that will be cancelled and archived (`--sessions`)
+    ActiveSession { name: String },
+    /// Para branch without a session or archive entry, deleted by default
+    OrphanedParaBranch {
+        branch: String,
+        /// Commit date of the branch tip
+        tip_date: Option<DateTime<Utc>>,
+        /// Commits not on the main branch
+        commit_count: usize,
+    },
+}
+
+impl CleanupItem {
+    /// The session or branch the item refers to
+    fn name(&self) -> &str {
+        match self {
+            CleanupItem::ActiveSession { name } => name,
+            CleanupItem::OrphanedParaBranch { branch, .. } => branch,
+        }
+    }
+}
+
 impl SessionCleaner {
     fn new(git_service: GitService, config: crate::config::Config) -> Self {
         Self {
+            session_manager: SessionManager::for_git_service(&config, &git_service),
             git_service,
             config,
         }
     }
 
-    fn is_non_interactive() -> bool {
-        std::env::var("PARA_NON_INTERACTIVE").is_ok()
-            || std::env::var("CI").is_ok()
-            || !atty::is(atty::Stream::Stdin)
-    }
-
     fn execute_clean(&self, args: CleanArgs) -> Result<()> {
-        let cleanup_plan = self.analyze_cleanup(&args)?;
+        if args.list_trash {
+            return self.list_trash();
+        }
+        if args.empty_trash {
+            return self.empty_trash(&args);
+        }
+
+        let mut cleanup_plan = self.analyze_cleanup(&args)?;
+
+        if args.dry_run {
+            if cleanup_plan.is_empty() {
+                println!(
+                    "{}",
+                    theme::cleanup("Nothing to clean - your Para environment is already tidy!")
+                );
+                return Ok(());
+            }
+            let forced_kinds = self.allowed_kinds(&args, true)?;
+            self.show_dry_run_report(&cleanup_plan, &forced_kinds);
+            return Ok(());
+        }
+
+        let allowed = self.allowed_kinds(&args, args.force)?;
+        for (kind, count) in cleanup_plan.retain_kinds(&allowed) {
+            println!("⏭️  Skipping {count} {kind} item(s): not enabled for this run");
+        }
 
         if cleanup_plan.is_empty() {
-            println!("🧹 Nothing to clean - your Para environment is already tidy!");
+            println!(
+                "{}",
+                theme::cleanup("Nothing to clean - your Para environment is already tidy!")
+            );
             return Ok(());
         }
 
-        if args.dry_run {
-            self.show_dry_run_report(&cleanup_plan);
+        let mut skipped = Vec::new();
+        if !args.force {
+            if uses_checklist(&args, is_non_interactive()) {
+                let entries = self.plan_entries(&cleanup_plan);
+                let Some(selected) = choose_entries(&entries) else {
+                    println!("Cleanup cancelled");
+                    return Ok(());
+                };
+                skipped = entries
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, _)| !selected.contains(index))
+                    .map(|(_, entry)| entry.label())
+                    .collect();
+                cleanup_plan = cleanup_plan.select(&selected);
+                if cleanup_plan.is_empty() {
+                    println!("Nothing selected, cleanup cancelled");
+                    return Ok(());
+                }
+            } else if cleanup_plan.has_artifacts() && !self.confirm_cleanup(&cleanup_plan)? {
+                println!("Cleanup cancelled");
+                return Ok(());
+            }
+
+            // Cancelling sessions always needs its own, stronger confirmation
+            if !cleanup_plan.active_sessions.is_empty()
+                && !self.confirm_active_sessions(&cleanup_plan)?
+            {
+                println!("Cleanup cancelled");
+                return Ok(());
+            }
+        }
+
+        let mut results = self.perform_cleanup(cleanup_plan, &args)?;
+        results.skipped = skipped;
+        self.show_results(&results);
+
+        Ok(())
+    }
+
+    /// State directory of the repository, which holds the trashed files
+    fn state_dir(&self) -> PathBuf {
+        self.session_manager.state_dir().clone()
+    }
+
+    fn trash_entries(&self) -> Result<Vec<TrashEntry>> {
+        trash::list(
+            &self.state_dir(),
+            &self.git_service,
+            self.config.get_branch_prefix(),
+        )
+    }
+
+    fn list_trash(&self) -> Result<()> {
+        let entries = self.trash_entries()?;
+        if entries.is_empty() {
+            println!("{}", theme::cleanup("The trash is empty"));
             return Ok(());
         }
 
-        if !args.force && !self.confirm_cleanup(&cleanup_plan)? {
-            println!("Cleanup cancelled");
+        let retention_days = self.config.get_trash_retention_days();
+        let now = chrono::Utc::now().naive_utc();
+        println!(
+            "{}",
+            theme::cleanup(format!("Trash ({} items)", entries.len()))
+        );
+        println!("==============\n");
+        for entry in &entries {
+            let expiry = if entry.is_expired(now, retention_days) {
+                "expired, deleted by the next clean".to_string()
+            } else {
+                format!(
+                    "deleted after {}",
+                    entry.expires_at(retention_days).format("%Y-%m-%d %H:%M")
+                )
+            };
+            println!("  {} ({}, {expiry})", entry.id(), entry.kind_label());
+        }
+        println!("\nRestore an item with: para restore-trash <id|session>");
+        Ok(())
+    }
+
+    fn empty_trash(&self, args: &CleanArgs) -> Result<()> {
+        let entries = self.trash_entries()?;
+        if entries.is_empty() {
+            println!("{}", theme::cleanup("The trash is empty"));
             return Ok(());
         }
 
-        let results = self.perform_cleanup(cleanup_plan)?;
-        self.show_results(&results);
+        println!("Permanently delete {} trashed item(s):", entries.len());
+        for entry in &entries {
+            println!(
+                "  {}",
+                theme::cleanup(format!("{} ({})", entry.id(), entry.kind_label()))
+            );
+        }
+        if args.dry_run {
+            return Ok(());
+        }
+        if !args.force {
+            if is_non_interactive() {
+                return Err(crate::utils::ParaError::invalid_args(
+                    "Cannot empty the trash in non-interactive mode. Use --force to skip confirmation.",
+                ));
+            }
+            let confirmed = Confirm::new()
+                .with_prompt("Delete them? This cannot be undone")
+                .default(false)
+                .interact()
+                .unwrap_or(false);
+            if !confirmed {
+                println!("Cleanup cancelled");
+                return Ok(());
+            }
+        }
 
+        let mut deleted = 0;
+        for entry in &entries {
+            match trash::purge(entry, &self.git_service) {
+                Ok(()) => deleted += 1,
+                Err(e) => eprintln!("Failed to delete {}: {e}", entry.id()),
+            }
+        }
+        println!(
+            "{}",
+            theme::success(format!("Deleted {deleted} trashed item(s)"))
+        );
         Ok(())
     }
 
+    /// Trash past `cleanup.trash_retention_days`
+    fn find_expired_trash(&self) -> Result<Vec<TrashEntry>> {
+        let retention_days = self.config.get_trash_retention_days();
+        let now = chrono::Utc::now().naive_utc();
+        Ok(self
+            .trash_entries()?
+            .into_iter()
+            .filter(|entry| entry.is_expired(now, retention_days))
+            .collect())
+    }
+
+    /// Kinds this run may remove, from `cleanup.enabled_kinds`, `--only` and `--skip`
+    fn allowed_kinds(&self, args: &CleanArgs, force: bool) -> Result<Vec<CleanupKind>> {
+        cleanup::allowed_kinds(
+            self.config.get_cleanup_enabled_kinds(),
+            force,
+            &args.only,
+            &args.skip,
+        )
+    }
+
     fn analyze_cleanup(&self, args: &CleanArgs) -> Result<CleanupPlan> {
         let mut plan = CleanupPlan::new();
 
-        // Find stale branches (branches without corresponding state files)
-        plan.stale_branches = self.find_stale_branches()?;
+        // Find para branches that no session or archive accounts for
+        plan.stale_branches = self.find_orphaned_branches()?;
 
         // Find orphaned state files (state files without corresponding branches)
         plan.orphaned_state_files = self.find_orphaned_state_files()?;
@@ -82,34 +327,60 @@ impl SessionCleaner {
         // Find stale status files (status files older than threshold)
         plan.stale_status_files = self.find_stale_status_files()?;
 
+        // Trash from earlier cleans that is past its retention
+        plan.expired_trash = self.find_expired_trash()?;
+
         // Find orphaned containers if requested
         if args.containers {
             plan.orphaned_containers = self.find_orphaned_containers()?;
         }
 
+        if args.docker {
+            plan.dangling_docker_resources = self.find_dangling_docker_resources(&SystemDockerCli);
+        }
+
+        // Active sessions are only ever included when explicitly requested
+        if args.sessions {
+            plan.active_sessions = self.find_active_sessions()?;
+        }
+
         Ok(plan)
     }
 
-    fn find_stale_branches(&self) -> Result<Vec<String>> {
-        let mut stale_branches = Vec::new();
-        let prefix = format!("{}/", self.config.git.branch_prefix);
-        let state_dir = PathBuf::from(&self.config.directories.state_dir);
-
-        // Get all branches with our prefix
-        let all_branches = self.git_service.branch_manager().list_branches()?;
+    /// Dangling para-labeled Docker resources; skipped with a note when docker is unavailable
+    fn find_dangling_docker_resources(&self, docker: &dyn DockerCli) -> Vec<DanglingResource> {
+        if !docker.is_available() {
+            println!("Note: Docker is not available, skipping Docker resource cleanup");
+            return Vec::new();
+        }
 
-        for branch_info in all_branches {
-            if branch_info.name.starts_with(&prefix) && !branch_info.name.contains("/archived/") {
-                let session_id = branch_info.name.strip_prefix(&prefix).unwrap_or("");
-                let state_file = state_dir.join(format!("{session_id}.state"));
+        resources::find_dangling_resources(docker).unwrap_or_else(|e| {
+            println!("Note: Could not list Docker resources, skipping: {e}");
+            Vec::new()
+        })
+    }
 
-                if !state_file.exists() {
-                    stale_branches.push(branch_info.name);
-                }
-            }
-        }
+    fn find_active_sessions(&self) -> Result<Vec<CleanupItem>> {
+        Ok(self
+            .session_manager
+            .list_sessions()?
+            .into_iter()
+            .filter(|session| !session.pinned)
+            .map(|session| CleanupItem::ActiveSession { name: session.name })
+            .collect())
+    }
 
-        Ok(stale_branches)
+    fn find_orphaned_branches(&self) -> Result<Vec<CleanupItem>> {
+        Ok(
+            orphans::find_orphaned_branches(&self.session_manager, &self.git_service)?
+                .into_iter()
+                .map(|orphan| CleanupItem::OrphanedParaBranch {
+                    branch: orphan.name,
+                    tip_date: orphan.tip_date,
+                    commit_count: orphan.commit_count,
+                })
+                .collect(),
+        )
     }
 
     fn find_orphaned_state_files(&self) -> Result<Vec<PathBuf>> {
@@ -125,7 +396,7 @@ impl SessionCleaner {
         for state_file in state_files {
             let session_id = self.extract_session_id(&state_file)?;
 
-            if self.is_session_orphaned(&session_id)? {
+            if self.is_session_orphaned(&state_file, &session_id)? {
                 orphaned_files.push(state_file.clone());
                 orphaned_files.extend(self.find_related_files(&state_dir, &session_id));
             }
@@ -169,15 +440,27 @@ impl SessionCleaner {
         Ok(session_id.to_string())
     }
 
-    fn is_session_orphaned(&self, session_id: &str) -> Result<bool> {
-        let branch_name = format!("{}/{}", self.config.git.branch_prefix, session_id);
+    fn is_session_orphaned(&self, state_file: &Path, session_id: &str) -> Result<bool> {
+        // Recovered sessions are on branches without the prefix, so prefer the
+        // branch the state file records
+        let branch_name = fs::read_to_string(state_file)
+            .ok()
+            .and_then(|content| serde_json::from_str::<SessionState>(&content).ok())
+            .map(|state| state.branch)
+            .unwrap_or_else(|| format!("{}/{}", self.config.git.branch_prefix, session_id));
         Ok(!self.git_service.branch_exists(&branch_name)?)
     }
 
     fn find_related_files(&self, state_dir: &std::path::Path, session_id: &str) -> Vec<PathBuf> {
         let mut related_files = Vec::new();
 
-        for suffix in &[".prompt", ".launch", ".status.json"] {
+        for suffix in &[
+            ".prompt",
+            ".launch",
+            ".status.json",
+            ".status.history.jsonl",
+            ".setup.log",
+        ] {
             let related_file = state_dir.join(format!("{session_id}{suffix}"));
             if related_file.exists() {
                 related_files.push(related_file);
@@ -204,7 +487,11 @@ impl SessionCleaner {
         for status in Status::load_all(&state_dir).map_err(|e| {
             crate::utils::ParaError::file_operation(format!("Failed to load status files: {e}"))
         })? {
-            if status.is_stale(stale_threshold_hours) {
+            let pinned = self
+                .session_manager
+                .load_state(&status.session_name)
+                .is_ok_and(|session| session.pinned);
+            if status.is_stale(stale_threshold_hours) && !pinned {
                 stale_sessions.push(status.session_name);
             }
         }
@@ -227,7 +514,9 @@ impl SessionCleaner {
         let mut old_archives = Vec::new();
 
         for branch in archived_branches {
-            if self.is_archive_older_than_cutoff(&branch, cutoff_date)? {
+            if self.is_archive_older_than_cutoff(&branch, cutoff_date)?
+                && !self.is_archive_pinned(&branch)
+            {
                 old_archives.push(branch);
             }
         }
@@ -235,6 +524,14 @@ impl SessionCleaner {
         Ok(old_archives)
     }
 
+    /// Archived branches of pinned sessions are kept past the retention
+    fn is_archive_pinned(&self, branch: &str) -> bool {
+        matches!(
+            ArchiveBranchParser::parse_archive_branch(branch, &self.config.git.branch_prefix),
+            Ok(Some(info)) if pin::is_archive_pinned(&self.state_dir(), &info.timestamp, &info.session_name)
+        )
+    }
+
     fn is_archive_older_than_cutoff(
         &self,
         branch: &str,
@@ -259,11 +556,12 @@ impl SessionCleaner {
     }
 
     fn parse_archive_timestamp(&self, timestamp: &str) -> Result<chrono::NaiveDateTime> {
-        chrono::NaiveDateTime::parse_from_str(timestamp, "%Y%m%d-%H%M%S").map_err(|e| {
-            crate::utils::ParaError::invalid_args(format!(
-                "Invalid timestamp format '{timestamp}': {e}"
-            ))
-        })
+        chrono::NaiveDateTime::parse_from_str(timestamp, crate::utils::ARCHIVE_TIMESTAMP_FORMAT)
+            .map_err(|e| {
+                crate::utils::ParaError::invalid_args(format!(
+                    "Invalid timestamp format '{timestamp}': {e}"
+                ))
+            })
     }
 
     fn find_orphaned_containers(&self) -> Result<Vec<String>> {
@@ -306,22 +604,35 @@ impl SessionCleaner {
         Ok(orphaned)
     }
 
-    fn show_dry_run_report(&self, plan: &CleanupPlan) {
-        println!("🧹 Para Cleanup - Dry Run");
+    /// `forced_kinds` are the kinds `--force` would remove; the others are marked
+    fn show_dry_run_report(&self, plan: &CleanupPlan, forced_kinds: &[CleanupKind]) {
+        println!("{}", theme::cleanup("Para Cleanup - Dry Run"));
         println!("========================\n");
+        let skipped = |kind: CleanupKind| {
+            if forced_kinds.contains(&kind) {
+                String::new()
+            } else {
+                format!(" [skipped with --force: {kind} not enabled]")
+            }
+        };
 
         if !plan.stale_branches.is_empty() {
-            println!("Stale Branches ({}):", plan.stale_branches.len());
-            for branch in &plan.stale_branches {
-                println!("  🌿 {branch}");
+            println!(
+                "Orphaned Para Branches ({}){}:",
+                plan.stale_branches.len(),
+                skipped(CleanupKind::StaleBranch)
+            );
+            for item in &plan.stale_branches {
+                println!("  {}", theme::branch(item.name()));
             }
             println!();
         }
 
         if !plan.orphaned_state_files.is_empty() {
             println!(
-                "Orphaned State Files ({}):",
-                plan.orphaned_state_files.len()
+                "Orphaned State Files ({}), move to trash{}:",
+                plan.orphaned_state_files.len(),
+                skipped(CleanupKind::OrphanedStateFile)
             );
             for file in &plan.orphaned_state_files {
                 println!("  📝 {}", file.display());
@@ -331,15 +642,36 @@ impl SessionCleaner {
 
         if !plan.old_archives.is_empty() {
             let days = self.config.session.auto_cleanup_days.unwrap_or(30);
-            println!("Old Archives (older than {days} days):");
+            println!(
+                "Old Archives (older than {days} days), move to trash{}:",
+                skipped(CleanupKind::OldArchive)
+            );
             for archive in &plan.old_archives {
                 println!("  📦 {archive}");
             }
             println!();
         }
 
+        if !plan.expired_trash.is_empty() {
+            println!(
+                "Expired Trash ({}), permanently delete:",
+                plan.expired_trash.len()
+            );
+            for entry in &plan.expired_trash {
+                println!(
+                    "  {}",
+                    theme::cleanup(format!("{} ({})", entry.id(), entry.kind_label()))
+                );
+            }
+            println!();
+        }
+
         if !plan.stale_status_files.is_empty() {
-            println!("Stale Status Files ({}):", plan.stale_status_files.len());
+            println!(
+                "Stale Status Files ({}){}:",
+                plan.stale_status_files.len(),
+                skipped(CleanupKind::StaleStatusFile)
+            );
             for session in &plan.stale_status_files {
                 println!("  📊 {session}.status.json");
             }
@@ -348,30 +680,179 @@ impl SessionCleaner {
 
         if !plan.orphaned_containers.is_empty() {
             println!(
-                "Orphaned Docker Containers ({}):",
-                plan.orphaned_containers.len()
+                "Orphaned Docker Containers ({}){}:",
+                plan.orphaned_containers.len(),
+                skipped(CleanupKind::OrphanedContainer)
             );
             for container in &plan.orphaned_containers {
-                println!("  🐳 {container}");
+                println!("  {}", theme::container(container));
+            }
+            println!();
+        }
+
+        if !plan.dangling_docker_resources.is_empty() {
+            println!(
+                "Dangling Docker Resources ({}){}:",
+                plan.dangling_docker_resources.len(),
+                skipped(CleanupKind::DockerResource)
+            );
+            for resource in &plan.dangling_docker_resources {
+                let size = resource.size.as_deref().unwrap_or("size unknown");
+                let size = match resource.kind {
+                    ResourceKind::Network => String::new(),
+                    _ => format!(" ({size})"),
+                };
+                println!(
+                    "  {}",
+                    theme::container(format!("{} {}{size}", resource.kind.label(), resource.name))
+                );
             }
             println!();
         }
+
+        if !plan.active_sessions.is_empty() {
+            println!(
+                "Active Sessions to cancel and archive ({}){}:",
+                plan.active_sessions.len(),
+                skipped(CleanupKind::ActiveSession)
+            );
+            for item in &plan.active_sessions {
+                println!("  🛑 {}", item.name());
+            }
+            println!();
+        }
+    }
+
+    /// The plan's items in the order [`CleanupPlan::select`] counts them, with
+    /// what is known about each
+    fn plan_entries(&self, plan: &CleanupPlan) -> Vec<PlanEntry> {
+        let glyphs = theme::current();
+        let mut entries = Vec::new();
+        for item in &plan.stale_branches {
+            let detail = match item {
+                CleanupItem::OrphanedParaBranch {
+                    tip_date,
+                    commit_count,
+                    ..
+                } => Some(match tip_date {
+                    Some(date) => format!("{commit_count} commits, last {} ago", age(*date)),
+                    None => format!("{commit_count} commits"),
+                }),
+                CleanupItem::ActiveSession { .. } => None,
+            };
+            entries.push(PlanEntry::new(
+                glyphs.branch,
+                "orphaned branch",
+                item.name(),
+                detail,
+            ));
+        }
+        for file in &plan.orphaned_state_files {
+            let detail = fs::metadata(file).ok().map(|metadata| {
+                let size = gc::format_bytes(metadata.len());
+                match metadata.modified() {
+                    Ok(modified) => format!("{size}, {} old", age(modified.into())),
+                    Err(_) => size,
+                }
+            });
+            let name = file.display().to_string();
+            entries.push(PlanEntry::new(
+                glyphs.cleanup,
+                "orphaned state file",
+                &name,
+                detail,
+            ));
+        }
+        for archive in &plan.old_archives {
+            let detail = self
+                .extract_archive_timestamp(archive)
+                .and_then(|timestamp| self.parse_archive_timestamp(&timestamp))
+                .ok()
+                .map(|archived_at| format!("archived {} ago", age(archived_at.and_utc())));
+            entries.push(PlanEntry::new(
+                glyphs.cleanup,
+                "old archive",
+                archive,
+                detail,
+            ));
+        }
+        for entry in &plan.expired_trash {
+            let detail = format!(
+                "{}, trashed {} ago",
+                entry.kind_label(),
+                age(entry.trashed_at.and_utc())
+            );
+            entries.push(PlanEntry::new(
+                glyphs.cleanup,
+                "expired trash",
+                &entry.id(),
+                Some(detail),
+            ));
+        }
+        for session in &plan.stale_status_files {
+            let name = format!("{session}.status.json");
+            entries.push(PlanEntry::new(
+                glyphs.cleanup,
+                "stale status file",
+                &name,
+                None,
+            ));
+        }
+        for container in &plan.orphaned_containers {
+            entries.push(PlanEntry::new(
+                glyphs.container,
+                "orphaned container",
+                container,
+                None,
+            ));
+        }
+        for resource in &plan.dangling_docker_resources {
+            let detail = match resource.kind {
+                ResourceKind::Network => resource.kind.label().to_string(),
+                _ => format!(
+                    "{}, {}",
+                    resource.kind.label(),
+                    resource.size.as_deref().unwrap_or("size unknown")
+                ),
+            };
+            entries.push(PlanEntry::new(
+                glyphs.container,
+                "docker resource",
+                &resource.name,
+                Some(detail),
+            ));
+        }
+        for item in &plan.active_sessions {
+            entries.push(PlanEntry::new(
+                glyphs.warning,
+                "active session",
+                item.name(),
+                None,
+            ));
+        }
+        entries
     }
 
     fn confirm_cleanup(&self, plan: &CleanupPlan) -> Result<bool> {
-        println!("🧹 Para Cleanup");
+        println!("{}", theme::cleanup("Para Cleanup"));
         println!("===============\n");
 
         let mut total_items = 0;
 
         if !plan.stale_branches.is_empty() {
-            println!("  🌿 {} stale branches", plan.stale_branches.len());
+            println!(
+                "  {}",
+                theme::branch(format!(
+                    "{} orphaned para branches",
+                    plan.stale_branches.len()
+                ))
+            );
             total_items += plan.stale_branches.len();
         }
 
         if !plan.orphaned_state_files.is_empty() {
             println!(
-                "  📝 {} orphaned state files",
+                "  📝 {} orphaned state files (move to trash)",
                 plan.orphaned_state_files.len()
             );
             total_items += plan.orphaned_state_files.len();
@@ -380,13 +861,24 @@ impl SessionCleaner {
         if !plan.old_archives.is_empty() {
             let days = self.config.session.auto_cleanup_days.unwrap_or(30);
             println!(
-                "  📦 {} archived sessions (older than {} days)",
+                "  📦 {} archived sessions (older than {} days, move to trash)",
                 plan.old_archives.len(),
                 days
             );
             total_items += plan.old_archives.len();
         }
 
+        if !plan.expired_trash.is_empty() {
+            println!(
+                "  {}",
+                theme::cleanup(format!(
+                    "{} expired trash items (permanently delete)",
+                    plan.expired_trash.len()
+                ))
+            );
+            total_items += plan.expired_trash.len();
+        }
+
         if !plan.stale_status_files.is_empty() {
             println!("  📊 {} stale status files", plan.stale_status_files.len());
             total_items += plan.stale_status_files.len();
@@ -394,18 +886,32 @@ impl SessionCleaner {
 
         if !plan.orphaned_containers.is_empty() {
             println!(
-                "  🐳 {} orphaned Docker containers",
-                plan.orphaned_containers.len()
+                "  {}",
+                theme::container(format!(
+                    "{} orphaned Docker containers",
+                    plan.orphaned_containers.len()
+                ))
             );
             total_items += plan.orphaned_containers.len();
         }
 
+        if !plan.dangling_docker_resources.is_empty() {
+            println!(
+                "  {}",
+                theme::container(format!(
+                    "{} dangling Docker resources",
+                    plan.dangling_docker_resources.len()
+                ))
+            );
+            total_items += plan.dangling_docker_resources.len();
+        }
+
         if total_items == 0 {
             println!("No items to clean");
             return Ok(false);
         }
 
-        if Self::is_non_interactive() {
+        if is_non_interactive() {
             return Err(crate::utils::ParaError::invalid_args(
                 "Cannot perform cleanup in non-interactive mode. Use --force flag to skip confirmation prompts."
             ));
@@ -418,12 +924,57 @@ impl SessionCleaner {
             .unwrap_or(false))
     }
 
-    fn perform_cleanup(&self, plan: CleanupPlan) -> Result<CleanupResults> {
-        let mut results = CleanupResults::default();
+    fn confirm_active_sessions(&self, plan: &CleanupPlan) -> Result<bool> {
+        println!("\n{}", theme::warning("Active Sessions"));
+        println!("==================\n");
+        println!("The following sessions will be CANCELLED:");
+        for item in &plan.active_sessions {
+            println!("  🛑 {}", item.name());
+        }
+        println!("\nWorktrees are removed and branches archived. Uncommitted work is");
+        println!("committed to the session branch first and can be restored with 'para recover'.");
+
+        if is_non_interactive() {
+            return Err(crate::utils::ParaError::invalid_args(
+                "Cannot cancel active sessions in non-interactive mode. Use --force --sessions to skip confirmation.",
+            ));
+        }
+
+        print!(
+            "Type 'yes' to cancel all {} sessions: ",
+            plan.active_sessions.len()
+        );
+        io::stdout().flush().map_err(|e| {
+            crate::utils::ParaError::file_operation(format!("Failed to flush stdout: {e}"))
+        })?;
 
-        // Clean stale branches
-        for branch in plan.stale_branches {
-            match self.git_service.delete_branch(&branch, true) {
+        read_yes_confirmation(&mut io::stdin().lock())
+    }
+
+    fn perform_cleanup(&self, plan: CleanupPlan, args: &CleanArgs) -> Result<CleanupResults> {
+        let mut results = CleanupResults::default();
+        let total_steps = [
+            plan.stale_branches.is_empty(),
+            plan.orphaned_state_files.is_empty(),
+            plan.old_archives.is_empty(),
+            plan.stale_status_files.is_empty(),
+            plan.expired_trash.is_empty(),
+            plan.orphaned_containers.is_empty(),
+            plan.dangling_docker_resources.is_empty(),
+            plan.active_sessions.is_empty(),
+        ]
+        .iter()
+        .filter(|empty| !**empty)
+        .count();
+        let mut progress = StepReporter::new(total_steps);
+
+        // Clean orphaned para branches
+        if !plan.stale_branches.is_empty() {
+            progress.step("Removing orphaned para branches");
+        }
+        for item in plan.stale_branches {
+            let branch = item.name();
+            match self.git_service.delete_branch(branch, true) {
                 Ok(_) => results.stale_branches_removed += 1,
                 Err(e) => results
                     .errors
@@ -431,31 +982,59 @@ impl SessionCleaner {
             }
         }
 
-        // Clean orphaned state files
+        // Delete expired trash before trashing anything new
+        if !plan.expired_trash.is_empty() {
+            progress.step("Deleting expired trash");
+        }
+        for entry in plan.expired_trash {
+            match trash::purge(&entry, &self.git_service) {
+                Ok(()) => results.expired_trash_deleted += 1,
+                Err(e) => results
+                    .errors
+                    .push(format!("Failed to delete trash {}: {e}", entry.id())),
+            }
+        }
+
+        let state_dir = self.state_dir();
+        let now = chrono::Utc::now().naive_utc();
+
+        // Move orphaned state files to the trash
+        if !plan.orphaned_state_files.is_empty() {
+            progress.step("Moving orphaned state files to the trash");
+        }
         for file_path in plan.orphaned_state_files {
-            match fs::remove_file(&file_path) {
+            match trash::trash_file(&state_dir, &file_path, now) {
                 Ok(_) => results.orphaned_state_files_removed += 1,
                 Err(e) => results.errors.push(format!(
-                    "Failed to remove file {}: {}",
+                    "Failed to trash file {}: {}",
                     file_path.display(),
                     e
                 )),
             }
         }
 
-        // Clean old archives
+        // Move old archives to the trash
+        if !plan.old_archives.is_empty() {
+            progress.step("Moving old archives to the trash");
+        }
         for archive_branch in plan.old_archives {
-            match self.git_service.delete_branch(&archive_branch, true) {
+            match trash::trash_branch(
+                &self.git_service,
+                self.config.get_branch_prefix(),
+                &archive_branch,
+                now,
+            ) {
                 Ok(_) => results.old_archives_removed += 1,
                 Err(e) => results
                     .errors
-                    .push(format!("Failed to remove archive {archive_branch}: {e}")),
+                    .push(format!("Failed to trash archive {archive_branch}: {e}")),
             }
         }
 
         // Clean stale status files
         if !plan.stale_status_files.is_empty() {
             use crate::core::status::Status;
+            progress.step("Removing stale status files");
             let state_dir = PathBuf::from(&self.config.directories.state_dir);
 
             for session_name in plan.stale_status_files {
@@ -474,14 +1053,27 @@ impl SessionCleaner {
         // Clean orphaned containers
         if !plan.orphaned_containers.is_empty() {
             use std::process::Command;
+            progress.step("Removing orphaned containers");
 
             for container_name in plan.orphaned_containers {
                 match Command::new("docker")
-                    .args(["rm", "-f", &container_name])
+                    .args(["rm", "-f", "-v", &container_name])
                     .output()
                 {
                     Ok(output) if output.status.success() => {
                         results.orphaned_containers_removed += 1;
+                        if let Some(session_name) =
+                            ContainerCleaner::parse_session_from_container(&container_name)
+                        {
+                            unregister_from_daemon(&session_name);
+                            resources::report_session_cleanup(
+                                &session_name,
+                                resources::cleanup_session_resources(
+                                    &SystemDockerCli,
+                                    &session_name,
+                                ),
+                            );
+                        }
                     }
                     Ok(output) => {
                         let error = String::from_utf8_lossy(&output.stderr);
@@ -498,58 +1090,201 @@ impl SessionCleaner {
             }
         }
 
+        if !plan.dangling_docker_resources.is_empty() {
+            progress.step("Removing dangling Docker resources");
+            let (removed, errors) = resources::remove_dangling_resources(
+                &SystemDockerCli,
+                &plan.dangling_docker_resources,
+            );
+            results.docker_resources_removed += removed;
+            results.errors.extend(errors);
+        }
+
+        // Cancel active sessions last so the artifact scan above saw them intact
+        if !plan.active_sessions.is_empty() {
+            progress.step("Cancelling active sessions");
+            let mut session_manager = self.session_manager.clone();
+
+            for item in plan.active_sessions {
+                let name = item.name();
+                let outcome = self
+                    .cancel_active_session(&mut session_manager, name, args)
+                    .map_err(|e| e.to_string());
+                results.session_results.push((item, outcome));
+            }
+
+            if let Err(e) = self.git_service.worktree_manager().prune_worktrees() {
+                results
+                    .errors
+                    .push(format!("Failed to prune worktrees: {e}"));
+            }
+        }
+
+        progress.finish();
         Ok(results)
     }
 
+    fn cancel_active_session(
+        &self,
+        session_manager: &mut SessionManager,
+        session_name: &str,
+        args: &CleanArgs,
+    ) -> Result<String> {
+        let session = session_manager.load_state(session_name)?;
+        // Check before saving work so nothing gets committed onto a protected branch
+        ensure_not_protected(
+            &session.worktree_path,
+            &self.config.git.protected_branches,
+            false,
+            "cancel",
+        )?;
+        ensure_worktree_not_open(&self.config, &session, args.close_ide, args.force)?;
+        self.save_uncommitted_work(&session.worktree_path)?;
+
+        cancel_and_archive_session(
+            &self.config,
+            &self.git_service,
+            session_manager,
+            session_name,
+            true,
+            false,
+            false,
+        )
+        .map(|archive| archive.branch)
+    }
+
+    /// Commit any uncommitted changes so they survive in the archived branch
+    fn save_uncommitted_work(&self, worktree_path: &Path) -> Result<()> {
+        // Only touch real worktrees, never fall back to the main repository
+        if !worktree_path.join(".git").exists() {
+            return Ok(());
+        }
+
+        let worktree_git = GitService::discover_from(worktree_path)?;
+        let repo = worktree_git.repository();
+        if repo.has_uncommitted_changes()? {
+            repo.stage_all_changes()?;
+            repo.commit("Save uncommitted work before para clean")?;
+        }
+
+        Ok(())
+    }
+
     fn show_results(&self, results: &CleanupResults) {
-        println!("🧹 Cleanup Complete");
+        println!("{}", theme::cleanup("Cleanup Complete"));
         println!("==================\n");
 
         if results.stale_branches_removed > 0 {
             println!(
-                "  ✅ Removed {} stale branches",
-                results.stale_branches_removed
+                "  {}",
+                theme::success(format!(
+                    "Removed {} orphaned para branches",
+                    results.stale_branches_removed
+                ))
             );
         }
 
         if results.orphaned_state_files_removed > 0 {
             println!(
-                "  ✅ Removed {} orphaned state files",
-                results.orphaned_state_files_removed
+                "  {}",
+                theme::success(format!(
+                    "Moved {} orphaned state files to the trash",
+                    results.orphaned_state_files_removed
+                ))
             );
         }
 
         if results.old_archives_removed > 0 {
             println!(
-                "  ✅ Removed {} old archived sessions",
-                results.old_archives_removed
+                "  {}",
+                theme::success(format!(
+                    "Moved {} old archived sessions to the trash",
+                    results.old_archives_removed
+                ))
+            );
+        }
+
+        if results.expired_trash_deleted > 0 {
+            println!(
+                "  {}",
+                theme::success(format!(
+                    "Permanently deleted {} expired trash items",
+                    results.expired_trash_deleted
+                ))
             );
         }
 
         if results.stale_status_files_removed > 0 {
             println!(
-                "  ✅ Removed {} stale status files",
-                results.stale_status_files_removed
+                "  {}",
+                theme::success(format!(
+                    "Removed {} stale status files",
+                    results.stale_status_files_removed
+                ))
             );
         }
 
         if results.orphaned_containers_removed > 0 {
             println!(
-                "  ✅ Removed {} orphaned Docker containers",
-                results.orphaned_containers_removed
+                "  {}",
+                theme::success(format!(
+                    "Removed {} orphaned Docker containers",
+                    results.orphaned_containers_removed
+                ))
+            );
+        }
+
+        if results.docker_resources_removed > 0 {
+            println!(
+                "  {}",
+                theme::success(format!(
+                    "Removed {} dangling Docker resources",
+                    results.docker_resources_removed
+                ))
             );
         }
 
+        if !results.session_results.is_empty() {
+            println!("\n  Active sessions:");
+            for (item, outcome) in &results.session_results {
+                let name = item.name();
+                match outcome {
+                    Ok(archived_branch) => {
+                        println!(
+                            "    {}",
+                            theme::success(format!(
+                                "Cancelled {name} (archived as {archived_branch})"
+                            ))
+                        )
+                    }
+                    Err(e) => println!(
+                        "    {}",
+                        theme::error(format!("Failed to cancel {name}: {e}"))
+                    ),
+                }
+            }
+        }
+
         if !results.errors.is_empty() {
-            println!("\n⚠️  Some items couldn't be cleaned:");
+            println!("\n{}", theme::warning("Some items couldn't be cleaned:"));
             for error in &results.errors {
                 println!("  • {error}");
             }
         }
 
+        if !results.skipped.is_empty() {
+            println!("\n  ⏭️  Kept {} deselected items:", results.skipped.len());
+            for label in &results.skipped {
+                println!("    {label}");
+            }
+        }
+
         if results.stale_branches_removed == 0
             && results.orphaned_state_files_removed == 0
             && results.old_archives_removed == 0
+            && results.expired_trash_deleted == 0
+            && results.session_results.is_empty()
+            && results.skipped.is_empty()
         {
             println!("✨ Your Para environment was already clean!");
         }
@@ -558,11 +1293,91 @@ impl SessionCleaner {
 
 #[derive(Debug)]
 struct CleanupPlan {
-    stale_branches: Vec<String>,
+    stale_branches: Vec<CleanupItem>,
     orphaned_state_files: Vec<PathBuf>,
     old_archives: Vec<String>,
     stale_status_files: Vec<String>,
+    /// Trash past its retention. Not limited by kind: the items were
+    /// approved when they were trashed
+    expired_trash: Vec<TrashEntry>,
     orphaned_containers: Vec<String>,
+    dangling_docker_resources: Vec<DanglingResource>,
+    active_sessions: Vec<CleanupItem>,
+}
+
+/// One line of the `--interactive` checklist
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PlanEntry {
+    icon: &'static str,
+    kind: &'static str,
+    name: String,
+    /// Age, size or commit count, when known
+    detail: Option<String>,
+}
+
+impl PlanEntry {
+    fn new(icon: &'static str, kind: &'static str, name: &str, detail: Option<String>) -> Self {
+        Self {
+            icon,
+            kind,
+            name: name.to_string(),
+            detail,
+        }
+    }
+
+    fn label(&self) -> String {
+        let text = match self.detail {
+            Some(ref detail) => format!("{}: {} ({detail})", self.kind, self.name),
+            None => format!("{}: {}", self.kind, self.name),
+        };
+        theme::themed(self.icon, text)
+    }
+}
+
+/// Compact time since `since`, e.g. `5h` or `3d`
+fn age(since: DateTime<Utc>) -> String {
+    format_idle_duration(Utc::now() - since)
+}
+
+/// `--interactive` shows the checklist only on a terminal; otherwise the
+/// single confirmation applies as without it
+fn uses_checklist(args: &CleanArgs, non_interactive: bool) -> bool {
+    args.interactive && !args.force && !non_interactive
+}
+
+/// Let the user deselect items; `None` when the checklist was dismissed
+fn choose_entries(entries: &[PlanEntry]) -> Option<Vec<usize>> {
+    println!("{}", theme::cleanup("Para Cleanup"));
+    println!("===============\n");
+    let labels: Vec<String> = entries.iter().map(PlanEntry::label).collect();
+    MultiSelect::new()
+        .with_prompt("Select the items to clean (space toggles, enter confirms)")
+        .items(&labels)
+        .defaults(&vec![true; labels.len()])
+        .interact_opt()
+        .unwrap_or(None)
+}
+
+/// Keep the items whose position, counted across every kind of the plan, is
+/// in `selected`; `next` is the position of the first of `items`
+fn keep_selected<T>(items: Vec<T>, next: &mut usize, selected: &[usize]) -> Vec<T> {
+    items
+        .into_iter()
+        .filter(|_| {
+            let index = *next;
+            *next += 1;
+            selected.contains(&index)
+        })
+        .collect()
+}
+
+/// Only an explicit "yes" confirms cancelling active sessions
+fn read_yes_confirmation(input: &mut dyn BufRead) -> Result<bool> {
+    let mut line = String::new();
+    input.read_line(&mut line).map_err(|e| {
+        crate::utils::ParaError::file_operation(format!("Failed to read input: {e}"))
+    })?;
+    Ok(line.trim().eq_ignore_ascii_case("yes"))
 }
 
 impl CleanupPlan {
@@ -572,22 +1387,138 @@ impl CleanupPlan {
             orphaned_state_files: Vec::new(),
             old_archives: Vec::new(),
             stale_status_files: Vec::new(),
+            expired_trash: Vec::new(),
             orphaned_containers: Vec::new(),
+            dangling_docker_resources: Vec::new(),
+            active_sessions: Vec::new(),
         }
     }
 
     fn is_empty(&self) -> bool {
-        self.stale_branches.is_empty()
+        !self.has_artifacts() && self.active_sessions.is_empty()
+    }
+
+    fn len(&self, kind: CleanupKind) -> usize {
+        match kind {
+            CleanupKind::StaleBranch => self.stale_branches.len(),
+            CleanupKind::OrphanedStateFile => self.orphaned_state_files.len(),
+            CleanupKind::OldArchive => self.old_archives.len(),
+            CleanupKind::StaleStatusFile => self.stale_status_files.len(),
+            CleanupKind::OrphanedContainer => self.orphaned_containers.len(),
+            CleanupKind::DockerResource => self.dangling_docker_resources.len(),
+            CleanupKind::ActiveSession => self.active_sessions.len(),
+        }
+    }
+
+    fn clear(&mut self, kind: CleanupKind) {
+        match kind {
+            CleanupKind::StaleBranch => self.stale_branches.clear(),
+            CleanupKind::OrphanedStateFile => self.orphaned_state_files.clear(),
+            CleanupKind::OldArchive => self.old_archives.clear(),
+            CleanupKind::StaleStatusFile => self.stale_status_files.clear(),
+            CleanupKind::OrphanedContainer => self.orphaned_containers.clear(),
+            CleanupKind::DockerResource => self.dangling_docker_resources.clear(),
+            CleanupKind::ActiveSession => self.active_sessions.clear(),
+        }
+    }
+
+    /// Drop every kind not in `allowed`; returns the non-empty kinds dropped
+    /// with their item counts
+    fn retain_kinds(&mut self, allowed: &[CleanupKind]) -> Vec<(CleanupKind, usize)> {
+        let mut dropped = Vec::new();
+        for kind in CleanupKind::ALL {
+            if allowed.contains(&kind) {
+                continue;
+            }
+            let count = self.len(kind);
+            if count > 0 {
+                dropped.push((kind, count));
+                self.clear(kind);
+            }
+        }
+        dropped
+    }
+
+    /// The items at positions `selected` of [`SessionCleaner::plan_entries`]
+    fn select(self, selected: &[usize]) -> CleanupPlan {
+        let mut next = 0;
+        CleanupPlan {
+            stale_branches: keep_selected(self.stale_branches, &mut next, selected),
+            orphaned_state_files: keep_selected(self.orphaned_state_files, &mut next, selected),
+            old_archives: keep_selected(self.old_archives, &mut next, selected),
+            expired_trash: keep_selected(self.expired_trash, &mut next, selected),
+            stale_status_files: keep_selected(self.stale_status_files, &mut next, selected),
+            orphaned_containers: keep_selected(self.orphaned_containers, &mut next, selected),
+            dangling_docker_resources: keep_selected(
+                self.dangling_docker_resources,
+                &mut next,
+                selected,
+            ),
+            active_sessions: keep_selected(self.active_sessions, &mut next, selected),
+        }
+    }
+
+    /// Whether the plan contains anything besides active sessions
+    fn has_artifacts(&self) -> bool {
+        !(self.stale_branches.is_empty()
             && self.orphaned_state_files.is_empty()
             && self.old_archives.is_empty()
             && self.stale_status_files.is_empty()
+            && self.expired_trash.is_empty()
             && self.orphaned_containers.is_empty()
+            && self.dangling_docker_resources.is_empty())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_utils::test_helpers::*;
+    use std::io::Cursor;
+    use tempfile::TempDir;
+
+    fn clean_args(force: bool, sessions: bool) -> CleanArgs {
+        CleanArgs {
+            force,
+            dry_run: false,
+            backups: false,
+            containers: false,
+            sessions,
+            docker: false,
+            close_ide: false,
+            only: Vec::new(),
+            skip: Vec::new(),
+            empty_trash: false,
+            list_trash: false,
+            interactive: false,
+        }
+    }
+
+    fn create_sessions(git_service: &GitService, config: &Config, names: &[&str]) -> Vec<PathBuf> {
+        let session_manager = SessionManager::new(config);
+        names
+            .iter()
+            .map(|name| {
+                let branch = format!("{}/{}", config.git.branch_prefix, name);
+                let worktree_path = git_service
+                    .repository()
+                    .root
+                    .join(&config.directories.subtrees_dir)
+                    .join(name);
+                git_service
+                    .create_worktree(&branch, &worktree_path)
+                    .expect("Failed to create worktree");
+                session_manager
+                    .save_state(&SessionState::new(
+                        name.to_string(),
+                        branch,
+                        worktree_path.clone(),
+                    ))
+                    .expect("Failed to save state");
+                worktree_path
+            })
+            .collect()
+    }
 
     #[test]
     fn test_cleanup_plan_creation() {
@@ -614,10 +1545,487 @@ mod tests {
             dry_run: false,
             backups: false,
             containers: false,
+            sessions: false,
+            docker: false,
+            close_ide: false,
+            only: Vec::new(),
+            skip: Vec::new(),
+            empty_trash: false,
+            list_trash: false,
+            interactive: false,
         };
 
         assert!(!args.force);
         assert!(!args.dry_run);
         assert!(!args.backups);
     }
+
+    #[test]
+    fn test_plan_includes_active_sessions_only_with_flag() {
+        let temp_dir = TempDir::new().unwrap();
+        let (_git_temp, git_service) = setup_test_repo();
+        let config = create_test_config_with_dir(&temp_dir);
+        create_sessions(&git_service, &config, &["alpha", "beta", "gamma"]);
+
+        let cleaner = SessionCleaner::new(git_service, config);
+
+        let plan = cleaner.analyze_cleanup(&clean_args(false, false)).unwrap();
+        assert!(plan.active_sessions.is_empty());
+        assert!(plan.is_empty());
+
+        let plan = cleaner.analyze_cleanup(&clean_args(false, true)).unwrap();
+        let mut names: Vec<_> = plan.active_sessions.iter().map(CleanupItem::name).collect();
+        names.sort();
+        assert_eq!(names, vec!["alpha", "beta", "gamma"]);
+        assert!(!plan.has_artifacts());
+        assert!(!plan.is_empty());
+    }
+
+    #[test]
+    fn test_pinned_sessions_are_left_out_of_clean() {
+        use crate::core::status::{Status, TestStatus};
+
+        let temp_dir = TempDir::new().unwrap();
+        let (_git_temp, git_service) = setup_test_repo();
+        let mut config = create_test_config_with_dir(&temp_dir);
+        config.session.auto_cleanup_days = Some(1);
+        create_sessions(&git_service, &config, &["alpha", "perf"]);
+        let session_manager = SessionManager::new(&config);
+        pin::set_pinned(&session_manager, "perf", true).unwrap();
+        let state_dir = session_manager.state_dir().clone();
+
+        for name in ["alpha", "perf"] {
+            let mut status =
+                Status::new(name.to_string(), "Waiting".to_string(), TestStatus::Unknown);
+            status.updated_at = Utc::now() - chrono::Duration::days(3);
+            status.save(&state_dir).unwrap();
+        }
+
+        let repo = git_service.repository();
+        let main = repo.get_current_branch().unwrap();
+        for name in ["perf-old", "scratch"] {
+            crate::core::git::repository::execute_git_command(
+                repo,
+                &[
+                    "branch",
+                    &format!("test/archived/20230101-120000/{name}"),
+                    &main,
+                ],
+            )
+            .unwrap();
+        }
+        pin::archive_pin(&state_dir, "20230101-120000", "perf-old").unwrap();
+
+        let cleaner = SessionCleaner::new(git_service, config);
+        let plan = cleaner.analyze_cleanup(&clean_args(false, true)).unwrap();
+        let active: Vec<_> = plan.active_sessions.iter().map(CleanupItem::name).collect();
+        assert_eq!(active, vec!["alpha"]);
+        assert_eq!(plan.stale_status_files, vec!["alpha".to_string()]);
+        assert_eq!(
+            plan.old_archives,
+            vec!["test/archived/20230101-120000/scratch".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_plan_lists_orphaned_para_branches() {
+        let temp_dir = TempDir::new().unwrap();
+        let (_git_temp, git_service) = setup_test_repo();
+        let config = create_test_config_with_dir(&temp_dir);
+        create_sessions(&git_service, &config, &["alpha"]);
+        crate::core::git::repository::execute_git_command(
+            git_service.repository(),
+            &["branch", "test/lost"],
+        )
+        .unwrap();
+
+        let cleaner = SessionCleaner::new(git_service, config);
+        let plan = cleaner.analyze_cleanup(&clean_args(true, false)).unwrap();
+        let names: Vec<_> = plan.stale_branches.iter().map(CleanupItem::name).collect();
+        assert_eq!(names, vec!["test/lost"]);
+
+        let results = cleaner
+            .perform_cleanup(plan, &clean_args(true, false))
+            .unwrap();
+        assert_eq!(results.stale_branches_removed, 1);
+        assert!(!cleaner
+            .git_service
+            .branch_manager()
+            .branch_exists("test/lost")
+            .unwrap());
+        assert!(cleaner
+            .git_service
+            .branch_manager()
+            .branch_exists("test/alpha")
+            .unwrap());
+    }
+
+    #[test]
+    fn test_session_confirmation_requires_typed_yes() {
+        assert!(read_yes_confirmation(&mut Cursor::new("yes\n")).unwrap());
+        assert!(read_yes_confirmation(&mut Cursor::new("  YES \n")).unwrap());
+        assert!(!read_yes_confirmation(&mut Cursor::new("y\n")).unwrap());
+        assert!(!read_yes_confirmation(&mut Cursor::new("\n")).unwrap());
+        assert!(!read_yes_confirmation(&mut Cursor::new("")).unwrap());
+    }
+
+    #[test]
+    fn test_force_alone_keeps_active_sessions() {
+        let temp_dir = TempDir::new().unwrap();
+        let (_git_temp, git_service) = setup_test_repo();
+        let config = create_test_config_with_dir(&temp_dir);
+        let worktrees = create_sessions(&git_service, &config, &["alpha", "beta"]);
+
+        let cleaner = SessionCleaner::new(git_service, config.clone());
+        cleaner.execute_clean(clean_args(true, false)).unwrap();
+
+        let session_manager = SessionManager::new(&config);
+        assert_eq!(session_manager.list_sessions().unwrap().len(), 2);
+        assert!(worktrees.iter().all(|path| path.exists()));
+    }
+
+    #[test]
+    fn test_recovered_session_state_is_not_orphaned() {
+        let temp_dir = TempDir::new().unwrap();
+        let (_git_temp, git_service) = setup_test_repo();
+        let config = create_test_config_with_dir(&temp_dir);
+
+        // Recovery restores sessions onto branches without the prefix
+        let worktree_path = git_service
+            .repository()
+            .root
+            .join(&config.directories.subtrees_dir)
+            .join("recovered");
+        git_service
+            .create_worktree("recovered", &worktree_path)
+            .unwrap();
+        SessionManager::new(&config)
+            .save_state(&SessionState::new(
+                "recovered".to_string(),
+                "recovered".to_string(),
+                worktree_path,
+            ))
+            .unwrap();
+
+        let cleaner = SessionCleaner::new(git_service, config.clone());
+        let plan = cleaner.analyze_cleanup(&clean_args(true, false)).unwrap();
+        assert!(plan.orphaned_state_files.is_empty());
+    }
+
+    /// A state file for `name` whose branch is gone
+    fn write_orphaned_state(config: &Config, name: &str) -> PathBuf {
+        let session_manager = SessionManager::new(config);
+        session_manager
+            .save_state(&SessionState::new(
+                name.to_string(),
+                format!("{}/{name}", config.git.branch_prefix),
+                PathBuf::from("/nonexistent").join(name),
+            ))
+            .unwrap();
+        session_manager.state_dir().join(format!("{name}.state"))
+    }
+
+    #[test]
+    fn test_trashed_state_files_expire_on_a_later_clean() {
+        let temp_dir = TempDir::new().unwrap();
+        let (_git_temp, git_service) = setup_test_repo();
+        let mut config = create_test_config_with_dir(&temp_dir);
+        let state_file = write_orphaned_state(&config, "renamed");
+
+        let cleaner = SessionCleaner::new(git_service, config.clone());
+        cleaner.execute_clean(clean_args(true, false)).unwrap();
+        assert!(!state_file.exists());
+        let trashed = cleaner.trash_entries().unwrap();
+        assert_eq!(trashed.len(), 1);
+        assert_eq!(trashed[0].original, "renamed.state");
+
+        // Within the retention a clean leaves the trash alone
+        cleaner.execute_clean(clean_args(true, false)).unwrap();
+        assert_eq!(cleaner.trash_entries().unwrap(), trashed);
+
+        config.cleanup = Some(crate::config::CleanupConfig {
+            enabled_kinds: None,
+            trash_retention_days: Some(0),
+        });
+        let cleaner = SessionCleaner::new(cleaner.git_service, config);
+        let plan = cleaner.analyze_cleanup(&clean_args(true, false)).unwrap();
+        assert_eq!(plan.expired_trash, trashed);
+        cleaner.execute_clean(clean_args(true, false)).unwrap();
+        assert!(cleaner.trash_entries().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_restore_trashed_state_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let (_git_temp, git_service) = setup_test_repo();
+        let config = create_test_config_with_dir(&temp_dir);
+        write_orphaned_state(&config, "renamed");
+
+        let cleaner = SessionCleaner::new(git_service, config.clone());
+        cleaner.execute_clean(clean_args(true, false)).unwrap();
+        let session_manager = SessionManager::new(&config);
+        assert!(session_manager.load_state("renamed").is_err());
+
+        let entries = cleaner.trash_entries().unwrap();
+        for entry in trash::select(&entries, "renamed").unwrap() {
+            trash::restore(entry, &cleaner.state_dir(), &cleaner.git_service).unwrap();
+        }
+        let restored = session_manager.load_state("renamed").unwrap();
+        assert_eq!(restored.branch, "test/renamed");
+        assert!(cleaner.trash_entries().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_force_sessions_cancels_and_archives_all() {
+        let temp_dir = TempDir::new().unwrap();
+        let (_git_temp, git_service) = setup_test_repo();
+        let config = create_test_config_with_dir(&temp_dir);
+        let worktrees = create_sessions(&git_service, &config, &["alpha", "beta", "dirty"]);
+
+        // Leave uncommitted work in one of the sessions
+        fs::write(worktrees[2].join("wip.txt"), "work in progress").unwrap();
+
+        let repo_root = git_service.repository().root.clone();
+        let cleaner = SessionCleaner::new(git_service, config.clone());
+        let args = clean_args(true, true);
+        let plan = cleaner.analyze_cleanup(&args).unwrap();
+        let results = cleaner.perform_cleanup(plan, &args).unwrap();
+
+        assert_eq!(results.session_results.len(), 3);
+        for (item, outcome) in &results.session_results {
+            assert!(outcome.is_ok(), "{item:?} failed: {outcome:?}");
+        }
+
+        let session_manager = SessionManager::new(&config);
+        assert!(session_manager.list_sessions().unwrap().is_empty());
+        assert!(worktrees.iter().all(|path| !path.exists()));
+
+        let git_service = GitService::discover_from(&repo_root).unwrap();
+        let archived = git_service
+            .branch_manager()
+            .list_archived_branches(&config.git.branch_prefix)
+            .unwrap();
+        assert_eq!(archived.len(), 3);
+
+        // The dirty session's work is preserved in its archived branch
+        let dirty_archive = archived
+            .iter()
+            .find(|branch| branch.ends_with("/dirty"))
+            .expect("dirty session should be archived");
+        let content = crate::core::git::repository::execute_git_command(
+            git_service.repository(),
+            &["show", &format!("{dirty_archive}:wip.txt")],
+        )
+        .unwrap();
+        assert_eq!(content.trim(), "work in progress");
+    }
+
+    fn mixed_plan() -> CleanupPlan {
+        let mut plan = CleanupPlan::new();
+        plan.stale_branches = vec![CleanupItem::OrphanedParaBranch {
+            branch: "test/lost".to_string(),
+            tip_date: None,
+            commit_count: 2,
+        }];
+        plan.orphaned_state_files = vec![PathBuf::from("gone.state")];
+        plan.old_archives = vec!["test/archived/20240101-000000/old".to_string()];
+        plan.stale_status_files = vec!["quiet".to_string()];
+        plan.active_sessions = vec![CleanupItem::ActiveSession {
+            name: "alpha".to_string(),
+        }];
+        plan
+    }
+
+    #[test]
+    fn test_plan_filtering_by_kind() {
+        let temp_dir = TempDir::new().unwrap();
+        let (_git_temp, git_service) = setup_test_repo();
+        let mut config = create_test_config_with_dir(&temp_dir);
+        config.cleanup = Some(crate::config::CleanupConfig {
+            enabled_kinds: Some(vec!["stale_branch".to_string(), "old_archive".to_string()]),
+            trash_retention_days: None,
+        });
+        let cleaner = SessionCleaner::new(git_service, config);
+        let kinds_left = |plan: &CleanupPlan| -> Vec<CleanupKind> {
+            CleanupKind::ALL
+                .into_iter()
+                .filter(|kind| plan.len(*kind) > 0)
+                .collect()
+        };
+
+        // Forced runs only touch the configured kinds
+        let mut plan = mixed_plan();
+        let allowed = cleaner
+            .allowed_kinds(&clean_args(true, true), true)
+            .unwrap();
+        let dropped = plan.retain_kinds(&allowed);
+        assert_eq!(
+            kinds_left(&plan),
+            vec![CleanupKind::StaleBranch, CleanupKind::OldArchive]
+        );
+        assert_eq!(
+            dropped,
+            vec![
+                (CleanupKind::OrphanedStateFile, 1),
+                (CleanupKind::StaleStatusFile, 1),
+                (CleanupKind::ActiveSession, 1)
+            ]
+        );
+
+        // Interactive runs still offer everything
+        let mut plan = mixed_plan();
+        let allowed = cleaner
+            .allowed_kinds(&clean_args(false, true), false)
+            .unwrap();
+        assert!(plan.retain_kinds(&allowed).is_empty());
+        assert_eq!(kinds_left(&plan).len(), 5);
+
+        // --only replaces the config
+        let mut args = clean_args(true, true);
+        args.only = vec![CleanupKind::StaleStatusFile, CleanupKind::ActiveSession];
+        let mut plan = mixed_plan();
+        plan.retain_kinds(&cleaner.allowed_kinds(&args, true).unwrap());
+        assert_eq!(
+            kinds_left(&plan),
+            vec![CleanupKind::StaleStatusFile, CleanupKind::ActiveSession]
+        );
+
+        // --skip narrows whatever the config or --only allows
+        args.skip = vec![CleanupKind::ActiveSession];
+        let mut plan = mixed_plan();
+        plan.retain_kinds(&cleaner.allowed_kinds(&args, true).unwrap());
+        assert_eq!(kinds_left(&plan), vec![CleanupKind::StaleStatusFile]);
+
+        let mut args = clean_args(true, true);
+        args.skip = vec![CleanupKind::OldArchive];
+        let mut plan = mixed_plan();
+        plan.retain_kinds(&cleaner.allowed_kinds(&args, true).unwrap());
+        assert_eq!(kinds_left(&plan), vec![CleanupKind::StaleBranch]);
+    }
+
+    #[test]
+    fn test_select_keeps_the_checked_items() {
+        let temp_dir = TempDir::new().unwrap();
+        let (_git_temp, git_service) = setup_test_repo();
+        let config = create_test_config_with_dir(&temp_dir);
+        let cleaner = SessionCleaner::new(git_service, config);
+
+        let plan = mixed_plan();
+        let entries = cleaner.plan_entries(&plan);
+        let names: Vec<_> = entries.iter().map(|entry| entry.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "test/lost",
+                "gone.state",
+                "test/archived/20240101-000000/old",
+                "quiet.status.json",
+                "alpha"
+            ]
+        );
+        assert_eq!(
+            entries[0].label(),
+            theme::branch("orphaned branch: test/lost (2 commits)")
+        );
+        assert!(entries[2].label().contains("archived"));
+
+        // Everything checked leaves the plan as it was
+        let all: Vec<usize> = (0..entries.len()).collect();
+        let kept = mixed_plan().select(&all);
+        assert_eq!(cleaner.plan_entries(&kept), entries);
+
+        // Unchecked items drop out of their own kind only
+        let kept = mixed_plan().select(&[0, 2, 4]);
+        assert_eq!(kept.stale_branches, plan.stale_branches);
+        assert!(kept.orphaned_state_files.is_empty());
+        assert_eq!(kept.old_archives, plan.old_archives);
+        assert!(kept.stale_status_files.is_empty());
+        assert_eq!(kept.active_sessions, plan.active_sessions);
+
+        assert!(mixed_plan().select(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_interactive_without_terminal_falls_back_to_confirmation() {
+        let mut args = clean_args(false, false);
+        args.interactive = true;
+        assert!(uses_checklist(&args, false));
+        assert!(!uses_checklist(&args, true));
+        assert!(!uses_checklist(&clean_args(false, false), false));
+
+        let temp_dir = TempDir::new().unwrap();
+        let (_git_temp, git_service) = setup_test_repo();
+        let config = create_test_config_with_dir(&temp_dir);
+        crate::core::git::repository::execute_git_command(
+            git_service.repository(),
+            &["branch", "test/lost"],
+        )
+        .unwrap();
+        let cleaner = SessionCleaner::new(git_service, config);
+
+        // Without a terminal the single confirmation refuses as before
+        std::env::set_var("PARA_NON_INTERACTIVE", "1");
+        let result = cleaner.execute_clean(args);
+        std::env::remove_var("PARA_NON_INTERACTIVE");
+        let message = result.unwrap_err().to_string();
+        assert!(message.contains("non-interactive mode"), "{message}");
+        assert!(cleaner
+            .git_service
+            .branch_manager()
+            .branch_exists("test/lost")
+            .unwrap());
+    }
+
+    #[test]
+    fn test_force_respects_enabled_kinds() {
+        let temp_dir = TempDir::new().unwrap();
+        let (_git_temp, git_service) = setup_test_repo();
+        let mut config = create_test_config_with_dir(&temp_dir);
+        config.cleanup = Some(crate::config::CleanupConfig {
+            enabled_kinds: Some(vec!["old_archive".to_string()]),
+            trash_retention_days: None,
+        });
+        crate::core::git::repository::execute_git_command(
+            git_service.repository(),
+            &["branch", "test/lost"],
+        )
+        .unwrap();
+
+        let cleaner = SessionCleaner::new(git_service, config);
+        cleaner.execute_clean(clean_args(true, false)).unwrap();
+        let branch_exists = |cleaner: &SessionCleaner| {
+            cleaner
+                .git_service
+                .branch_manager()
+                .branch_exists("test/lost")
+                .unwrap()
+        };
+        assert!(branch_exists(&cleaner));
+
+        let mut args = clean_args(true, false);
+        args.only = vec![CleanupKind::StaleBranch];
+        cleaner.execute_clean(args).unwrap();
+        assert!(!branch_exists(&cleaner));
+    }
+
+    #[test]
+    fn test_dangling_docker_resources_via_mock() {
+        use crate::core::docker::mock::MockDockerClient;
+
+        let temp_dir = TempDir::new().unwrap();
+        let (_git_temp, git_service) = setup_test_repo();
+        let config = create_test_config_with_dir(&temp_dir);
+        let cleaner = SessionCleaner::new(git_service, config);
+
+        let docker = MockDockerClient::new();
+        docker
+            .create_labeled_volume("para-old-cache", &resources::session_label("old"))
+            .unwrap();
+        let dangling = cleaner.find_dangling_docker_resources(&docker);
+        assert_eq!(dangling.len(), 1);
+        assert_eq!(dangling[0].name, "para-old-cache");
+
+        docker.set_available(false);
+        assert!(cleaner.find_dangling_docker_resources(&docker).is_empty());
+    }
 }
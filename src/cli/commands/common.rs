@@ -1,10 +1,98 @@
-use crate::utils::{ParaError, Result};
+use crate::config::templates::SessionTemplate;
+use crate::config::Config;
+use crate::core::git::apply::{self, StartingChanges};
+use crate::core::git::{GitOperations, GitRepository, GitService, MergeMode};
+use crate::core::ide::is_workspace_open;
+use crate::core::session::conflicts;
+use crate::core::session::{SessionManager, SessionState};
+use crate::platform::{get_platform_manager, PlatformManager};
+use crate::utils::{secret_scan, ParaError, Result};
 use std::fs;
-use std::path::Path;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
 
 /// Template content for CLAUDE.local.md
 const CLAUDE_LOCAL_TEMPLATE: &str = include_str!("../../templates/claude_local.md");
 
+/// Check if we're running in non-interactive mode (e.g., from MCP server or CI)
+pub fn is_non_interactive() -> bool {
+    std::env::var("PARA_NON_INTERACTIVE").is_ok()
+        || std::env::var("CI").is_ok()
+        || !atty::is(atty::Stream::Stdin)
+}
+
+/// Reject `--dangerously-skip-permissions` when `security.forbid_skip_permissions` is set
+pub fn check_skip_permissions_allowed(config: &Config, skip_permissions: bool) -> Result<()> {
+    if skip_permissions && config.forbids_skip_permissions() {
+        return Err(ParaError::invalid_args(
+            "--dangerously-skip-permissions is forbidden by this configuration (security.forbid_skip_permissions)",
+        ));
+    }
+    Ok(())
+}
+
+/// Look for credentials in a prompt before it is written to `destinations`.
+///
+/// Findings are printed as warnings, naming only the kind of secret and its
+/// line. With `security.block_secrets_in_prompts` they are an error instead,
+/// unless `--allow-secrets` was given.
+pub fn check_prompt_secrets(
+    config: &Config,
+    prompt: &str,
+    allow_secrets: bool,
+    destinations: &[String],
+) -> Result<()> {
+    if allow_secrets {
+        return Ok(());
+    }
+    let findings = secret_scan::scan(prompt);
+    if findings.is_empty() {
+        return Ok(());
+    }
+
+    let found = findings
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ");
+    if config.blocks_secrets_in_prompts() {
+        return Err(ParaError::invalid_args(format!(
+            "The prompt looks like it contains secrets: {found}. \
+            Remove them, or pass --allow-secrets if this is intended (security.block_secrets_in_prompts)"
+        )));
+    }
+
+    eprintln!("⚠️  The prompt looks like it contains secrets: {found}");
+    eprintln!("   It will be written in plain text to:");
+    for destination in destinations {
+        eprintln!("   - {destination}");
+    }
+    eprintln!("   Pass --allow-secrets to silence this warning.");
+    Ok(())
+}
+
+/// Make sure the repository has a commit to branch sessions from.
+///
+/// A freshly initialized repository has no HEAD commit, so worktree creation
+/// would fail with confusing git errors. With `--allow-empty-repo` an empty
+/// initial commit is created instead.
+pub fn ensure_repository_has_commits(repo: &GitRepository, allow_empty_repo: bool) -> Result<()> {
+    if repo.has_commits() {
+        return Ok(());
+    }
+
+    if !allow_empty_repo {
+        return Err(ParaError::git_operation(
+            "This repository has no commits yet. Para sessions branch from an existing commit.\n\
+            Create an initial commit first, or pass --allow-empty-repo to let para create an empty one.",
+        ));
+    }
+
+    repo.create_initial_commit()?;
+    println!("📝 Created an empty initial commit so sessions have a starting point");
+    Ok(())
+}
+
 /// Create CLAUDE.local.md file with instructions for AI agents
 pub fn create_claude_local_md(session_path: &Path, session_name: &str) -> Result<()> {
     // Ensure the session path exists
@@ -22,7 +110,779 @@ pub fn create_claude_local_md(session_path: &Path, session_name: &str) -> Result
 
     // Write the file (overwrite if exists)
     fs::write(&claude_local_path, content)
-        .map_err(|e| ParaError::fs_error(format!("Failed to write CLAUDE.local.md: {e}")))?;
+        .map_err(|e| ParaError::from_io("write", &claude_local_path, e))?;
+
+    Ok(())
+}
+
+/// With `session.conflict_hotspots`, add the files that conflicted before when
+/// syncing with the session's base to its CLAUDE.local.md
+pub fn add_conflict_hotspots(
+    config: &Config,
+    state_dir: &Path,
+    session: &SessionState,
+) -> Result<()> {
+    if !config.session.conflict_hotspots {
+        return Ok(());
+    }
+    let base = match session.parent_branch {
+        Some(ref parent) => parent.clone(),
+        None => GitRepository::discover_from(&session.worktree_path)?.get_main_branch()?,
+    };
+    let files = conflicts::hotspots(state_dir, &base, &session.worktree_path);
+    if files.is_empty() {
+        return Ok(());
+    }
+
+    let path = session.worktree_path.join("CLAUDE.local.md");
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .map_err(|e| ParaError::from_io("open", &path, e))?;
+    file.write_all(conflicts::hotspot_section(&files).as_bytes())
+        .map_err(|e| ParaError::from_io("write", &path, e))
+}
+
+/// File name of CLAUDE.local.md templates, in the repo's `.para/` or the user config dir
+pub const CLAUDE_LOCAL_TEMPLATE_FILE: &str = "claude.local.md.tmpl";
+
+const CLAUDE_LOCAL_PLACEHOLDERS: &[&str] = &[
+    "session_name",
+    "branch",
+    "base_branch",
+    "task",
+    "worktree_path",
+];
+
+/// Session details substituted into a CLAUDE.local.md template
+#[derive(Debug, Clone)]
+pub struct ClaudeLocalContext {
+    pub session_name: String,
+    pub branch: String,
+    pub base_branch: String,
+    pub task: Option<String>,
+    pub worktree_path: PathBuf,
+}
+
+impl ClaudeLocalContext {
+    /// Context for a worktree without saved session state; the base branch is
+    /// the repository's main branch
+    pub fn new(session_name: &str, branch: &str, worktree_path: &Path) -> Self {
+        Self {
+            session_name: session_name.to_string(),
+            branch: branch.to_string(),
+            base_branch: GitRepository::discover_from(worktree_path)
+                .and_then(|repo| repo.get_main_branch())
+                .unwrap_or_default(),
+            task: None,
+            worktree_path: worktree_path.to_path_buf(),
+        }
+    }
+
+    pub fn for_session(session: &SessionState) -> Self {
+        let mut context = Self::new(&session.name, &session.branch, &session.worktree_path);
+        if let Some(ref parent) = session.parent_branch {
+            context.base_branch = parent.clone();
+        }
+        context.task = session.task_description.clone();
+        context
+    }
+
+    /// Add instructions given on resume after the original task
+    pub fn with_additional_task(mut self, extra: Option<&str>) -> Self {
+        if let Some(extra) = extra {
+            self.task = Some(match self.task.take() {
+                Some(task) => format!("{task}\n\n{extra}"),
+                None => extra.to_string(),
+            });
+        }
+        self
+    }
+
+    fn value(&self, placeholder: &str) -> Option<String> {
+        match placeholder {
+            "session_name" => Some(self.session_name.clone()),
+            "branch" => Some(self.branch.clone()),
+            "base_branch" => Some(self.base_branch.clone()),
+            "task" => Some(self.task.clone().unwrap_or_default()),
+            "worktree_path" => Some(self.worktree_path.display().to_string()),
+            _ => None,
+        }
+    }
+}
+
+/// Render `template`, failing on unknown or unterminated `{{placeholders}}`
+pub fn render_claude_local_template(
+    template: &str,
+    context: &ClaudeLocalContext,
+) -> Result<String> {
+    let mut rendered = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find("{{") {
+        rendered.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let end = after.find("}}").ok_or_else(|| {
+            ParaError::invalid_args("unterminated '{{' in CLAUDE.local.md template")
+        })?;
+        let name = after[..end].trim();
+        let value = context.value(name).ok_or_else(|| {
+            ParaError::invalid_args(format!(
+                "unknown placeholder '{{{{{name}}}}}', expected one of: {}",
+                CLAUDE_LOCAL_PLACEHOLDERS.join(", ")
+            ))
+        })?;
+        rendered.push_str(&value);
+        rest = &after[end + 2..];
+    }
+    rendered.push_str(rest);
+    Ok(rendered)
+}
+
+/// The repo template `.para/claude.local.md.tmpl`, else the user one in `config_dir`
+pub fn find_claude_local_template(repo_root: Option<&Path>, config_dir: &Path) -> Option<PathBuf> {
+    repo_root
+        .map(|root| root.join(".para").join(CLAUDE_LOCAL_TEMPLATE_FILE))
+        .into_iter()
+        .chain(std::iter::once(config_dir.join(CLAUDE_LOCAL_TEMPLATE_FILE)))
+        .find(|path| path.is_file())
+}
+
+/// Write CLAUDE.local.md for a session from the configured template, using
+/// the built-in content when there is no template or it cannot be rendered
+pub fn write_claude_local_md(context: &ClaudeLocalContext) -> Result<()> {
+    let repo_root = crate::utils::get_main_repository_root_from(Some(&context.worktree_path)).ok();
+    let config_dir = crate::config::defaults::get_default_config_dir();
+    write_claude_local_md_with_template(
+        context,
+        find_claude_local_template(repo_root.as_deref(), &config_dir).as_deref(),
+    )
+}
+
+fn write_claude_local_md_with_template(
+    context: &ClaudeLocalContext,
+    template_path: Option<&Path>,
+) -> Result<()> {
+    let Some(template_path) = template_path else {
+        return create_claude_local_md(&context.worktree_path, &context.session_name);
+    };
+
+    let rendered = fs::read_to_string(template_path)
+        .map_err(|e| ParaError::fs_error(e.to_string()))
+        .and_then(|template| render_claude_local_template(&template, context));
+
+    match rendered {
+        Ok(content) => {
+            if !context.worktree_path.exists() {
+                return Err(ParaError::fs_error(format!(
+                    "Session path does not exist: {}",
+                    context.worktree_path.display()
+                )));
+            }
+            let claude_local_path = context.worktree_path.join("CLAUDE.local.md");
+            fs::write(&claude_local_path, content)
+                .map_err(|e| ParaError::from_io("write", &claude_local_path, e))
+        }
+        Err(e) => {
+            eprintln!(
+                "⚠️  Ignoring CLAUDE.local.md template {}: {e}. Using the built-in content.",
+                template_path.display()
+            );
+            create_claude_local_md(&context.worktree_path, &context.session_name)
+        }
+    }
+}
+
+/// Record a `--merge-mode` chosen at creation, replacing the configured default
+pub fn record_merge_mode(
+    session_manager: &SessionManager,
+    session: &mut SessionState,
+    merge_mode: Option<MergeMode>,
+) -> Result<()> {
+    match merge_mode {
+        Some(merge_mode) if merge_mode != session.merge_mode => {
+            session.merge_mode = merge_mode;
+            session_manager.save_state(session)
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Look up a `--template`, listing the configured templates when it is unknown
+pub fn find_template<'a>(config: &'a Config, name: &str) -> Result<&'a SessionTemplate> {
+    config.template(name).ok_or_else(|| {
+        if config.templates.is_empty() {
+            ParaError::invalid_args(format!(
+                "Unknown template '{name}': no templates are configured. Add them under \"templates\" in the para config or .para/config.json"
+            ))
+        } else {
+            let names: Vec<&str> = config.templates.keys().map(String::as_str).collect();
+            ParaError::invalid_args(format!(
+                "Unknown template '{name}'. Available templates: {}",
+                names.join(", ")
+            ))
+        }
+    })
+}
+
+/// Record the `--template` a session was started from
+pub fn record_template(
+    session_manager: &SessionManager,
+    session: &mut SessionState,
+    template: Option<&str>,
+) -> Result<()> {
+    match template {
+        Some(template) => {
+            session.template = Some(template.to_string());
+            session_manager.save_state(session)
+        }
+        None => Ok(()),
+    }
+}
+
+/// Record a `--no-auto-commit` opt-out, or ask the daemon to take WIP
+/// checkpoints of the session when `git.auto_commit_interval_minutes` is set.
+/// Daemon failures only warn; the session works without checkpoints.
+pub fn setup_auto_checkpoints(
+    config: &Config,
+    session_manager: &SessionManager,
+    session: &SessionState,
+    no_auto_commit: bool,
+) -> Result<()> {
+    if no_auto_commit {
+        let mut state = session.clone();
+        state.no_auto_commit = Some(true);
+        return session_manager.save_state(&state);
+    }
+
+    let Some(interval) = config.get_auto_checkpoint_interval() else {
+        return Ok(());
+    };
+    if session.skips_auto_commit() {
+        return Ok(());
+    }
+
+    if let Err(e) = crate::core::daemon::client::register_checkpoint_session(
+        &session.name,
+        &session.worktree_path,
+        session_manager.state_dir(),
+        interval,
+    ) {
+        eprintln!("Warning: Failed to enable automatic checkpoints: {e}");
+    }
+    Ok(())
+}
+
+/// Tell the daemon to stop watching and checkpointing a session that is going
+/// away. Without a running daemon there is nothing to stop.
+pub fn unregister_from_daemon(session_name: &str) {
+    if let Err(e) = crate::core::daemon::client::unregister_session(session_name) {
+        eprintln!("Warning: Failed to unregister session from the daemon: {e}");
+    }
+}
+
+/// Copy untracked files matching `session.copy_untracked` and `--copy` into a new worktree
+pub fn copy_untracked_into_worktree(
+    config: &Config,
+    repo_root: &Path,
+    worktree_path: &Path,
+    cli_patterns: &[String],
+    allow_large: bool,
+) -> Result<()> {
+    let mut patterns = config.session.copy_untracked.clone();
+    for pattern in cli_patterns {
+        if !patterns.contains(pattern) {
+            patterns.push(pattern.clone());
+        }
+    }
 
+    let report = crate::core::session::untracked::copy_untracked_files(
+        repo_root,
+        worktree_path,
+        &patterns,
+        allow_large,
+    )?;
+    report.print_summary();
     Ok(())
 }
+
+/// Point an absolute `core.hooksPath` in the main checkout at the new
+/// worktree, unless `--no-hook-fixup` was given. Problems only print a note.
+pub fn fix_worktree_hooks_path(repo_root: &Path, worktree_path: &Path, skip: bool) {
+    if skip {
+        return;
+    }
+    match crate::core::git::hooks::fixup_hooks_path(repo_root, worktree_path) {
+        Ok(fixup) => {
+            if let Some(message) = fixup.message() {
+                println!("{message}");
+            }
+        }
+        Err(e) => eprintln!("Note: could not check core.hooksPath for the worktree: {e}"),
+    }
+}
+
+/// Apply `--apply-patch`/`--apply-from` to a just-created session. When the
+/// changes do not apply the session is discarded again, so a failed start
+/// leaves no worktree, branch or state behind.
+pub fn apply_starting_changes(
+    git_service: &GitService,
+    session_manager: &SessionManager,
+    session: &SessionState,
+    apply_patch: Option<&Path>,
+    apply_from: Option<&str>,
+) -> Result<()> {
+    let Some(changes) = StartingChanges::from_args(apply_patch, apply_from) else {
+        return Ok(());
+    };
+
+    let base = session
+        .base_commit
+        .clone()
+        .or_else(|| session.parent_branch.clone())
+        .unwrap_or_else(|| "HEAD".to_string());
+    let whitespace = session_manager.config().git.apply_whitespace;
+    match apply::apply_starting_changes(&session.worktree_path, &base, &changes, whitespace) {
+        Ok(applied) => {
+            println!("📋 Applied {} as uncommitted changes", changes.describe());
+            if applied.ignored_whitespace {
+                println!(
+                    "   Whitespace and line-ending differences were ignored on a second attempt"
+                );
+            }
+            Ok(())
+        }
+        Err(e) => {
+            discard_new_session(git_service, session_manager, session);
+            Err(ParaError::git_operation(format!(
+                "Session '{}' was not created: {e}",
+                session.name
+            )))
+        }
+    }
+}
+
+/// Remove everything a session creation left behind, warning on leftovers
+fn discard_new_session(
+    git_service: &GitService,
+    session_manager: &SessionManager,
+    session: &SessionState,
+) {
+    let cleanup = [
+        git_service
+            .worktree_manager()
+            .force_remove_worktree(&session.worktree_path),
+        git_service.delete_branch(&session.branch, true),
+        session_manager.delete_state(&session.name),
+    ];
+    for result in cleanup {
+        if let Err(e) = result {
+            eprintln!(
+                "Warning: Failed to clean up session '{}': {e}",
+                session.name
+            );
+        }
+    }
+}
+
+/// Check that no IDE still has the session worktree open before it is removed.
+/// Closing the editor afterwards would leave it on a deleted directory and
+/// lose unsaved buffers.
+pub fn ensure_worktree_not_open(
+    config: &Config,
+    session: &SessionState,
+    close_ide: bool,
+    force: bool,
+) -> Result<()> {
+    if !config.is_real_ide_environment() {
+        return Ok(());
+    }
+    let Some(holders) = is_workspace_open(&session.worktree_path) else {
+        return Ok(());
+    };
+    release_open_worktree(
+        config,
+        get_platform_manager().as_ref(),
+        &session.name,
+        &holders,
+        close_ide,
+        force,
+    )
+}
+
+/// Deal with a worktree that `holders` have open: close the IDE window with
+/// `close_ide`, only warn with `force`, and otherwise ask (or fail when
+/// non-interactive).
+pub fn release_open_worktree(
+    config: &Config,
+    platform: &dyn PlatformManager,
+    session_name: &str,
+    holders: &[String],
+    close_ide: bool,
+    force: bool,
+) -> Result<()> {
+    let holders = holders.join(", ");
+    if close_ide {
+        println!("Closing the IDE window of session '{session_name}' ({holders})");
+        let state_dir = config.resolved_state_dir();
+        return platform.close_ide_window(
+            session_name,
+            &config.ide.name,
+            &state_dir.to_string_lossy(),
+        );
+    }
+    if force {
+        eprintln!(
+            "WARNING: Session '{session_name}' is still open in {holders}. Unsaved changes there will be lost."
+        );
+        return Ok(());
+    }
+    if is_non_interactive() {
+        return Err(ParaError::invalid_args(format!(
+            "Session '{session_name}' is still open in {holders}. \
+             Close it first, or pass --close-ide to close the IDE window or --force to continue anyway."
+        )));
+    }
+
+    print!("Session '{session_name}' is still open in {holders}. Unsaved changes there will be lost. Continue? [y/N]: ");
+    io::stdout()
+        .flush()
+        .map_err(|e| ParaError::file_operation(format!("Failed to flush stdout: {e}")))?;
+    let mut input = String::new();
+    io::stdin()
+        .read_line(&mut input)
+        .map_err(|e| ParaError::file_operation(format!("Failed to read input: {e}")))?;
+    match input.trim().to_lowercase().as_str() {
+        "y" | "yes" => Ok(()),
+        _ => Err(ParaError::invalid_args(format!(
+            "Aborted: session '{session_name}' is still open in {holders}"
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock_platform::MockPlatformManager;
+    use crate::test_utils::test_helpers::{
+        create_test_config_with_dir, git, setup_empty_test_repo, setup_test_repo,
+    };
+    use std::process::Command;
+    use tempfile::TempDir;
+
+    /// Patch taking README.md to `content`, leaving the repository unchanged
+    fn readme_patch(repo: &Path, patch_dir: &Path, content: &str) -> PathBuf {
+        fs::write(repo.join("README.md"), content).unwrap();
+        let patch = patch_dir.join("readme.patch");
+        fs::write(&patch, git(repo, &["diff"]) + "\n").unwrap();
+        git(repo, &["checkout", "--", "README.md"]);
+        patch
+    }
+
+    #[test]
+    fn test_prompt_secrets_warn_block_and_bypass() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = create_test_config_with_dir(&temp_dir);
+        let prompt = "Deploy with AWS_SECRET_ACCESS_KEY=abcd1234efgh5678";
+        let destinations = vec!["CLAUDE.local.md".to_string()];
+
+        assert!(check_prompt_secrets(&config, prompt, false, &destinations).is_ok());
+
+        config.security = Some(crate::config::SecurityConfig {
+            block_secrets_in_prompts: true,
+            ..Default::default()
+        });
+        let err = check_prompt_secrets(&config, prompt, false, &destinations)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("secret assignment (line 1)"), "{err}");
+        assert!(err.contains("--allow-secrets"), "{err}");
+        assert!(!err.contains("abcd1234efgh5678"), "{err}");
+
+        assert!(check_prompt_secrets(&config, prompt, true, &destinations).is_ok());
+        assert!(check_prompt_secrets(&config, "Fix the flaky test", false, &destinations).is_ok());
+    }
+
+    #[test]
+    fn test_empty_repository_requires_initial_commit() {
+        let (_temp_dir, git_service) = setup_empty_test_repo();
+        let repo = git_service.repository();
+
+        let err = ensure_repository_has_commits(repo, false).unwrap_err();
+        assert!(err.to_string().contains("no commits yet"));
+        assert!(err.to_string().contains("--allow-empty-repo"));
+        assert!(!repo.has_commits());
+    }
+
+    #[test]
+    fn test_allow_empty_repo_creates_initial_commit() {
+        let (_temp_dir, git_service) = setup_empty_test_repo();
+        let repo = git_service.repository();
+
+        ensure_repository_has_commits(repo, true).unwrap();
+        assert!(repo.has_commits());
+
+        // Worktrees can now be created from HEAD
+        let worktree_path = repo.root.join("subtrees").join("first");
+        git_service
+            .create_worktree("para/first", &worktree_path)
+            .expect("Failed to create worktree after initial commit");
+        assert!(worktree_path.exists());
+    }
+
+    fn claude_local_context(worktree_path: &Path, task: Option<&str>) -> ClaudeLocalContext {
+        ClaudeLocalContext {
+            session_name: "auth-flow".to_string(),
+            branch: "para/auth-flow".to_string(),
+            base_branch: "main".to_string(),
+            task: task.map(str::to_string),
+            worktree_path: worktree_path.to_path_buf(),
+        }
+    }
+
+    #[test]
+    fn test_render_claude_local_template_with_and_without_task() {
+        let template = "# {{session_name}} on {{branch}} (from {{ base_branch }})\n\
+                        Path: {{worktree_path}}\nTask: {{task}}\n";
+        let path = Path::new("/work/auth-flow");
+
+        let rendered = render_claude_local_template(
+            template,
+            &claude_local_context(path, Some("Add OAuth login")),
+        )
+        .unwrap();
+        assert_eq!(
+            rendered,
+            "# auth-flow on para/auth-flow (from main)\nPath: /work/auth-flow\nTask: Add OAuth login\n"
+        );
+
+        let context = claude_local_context(path, None);
+        let rendered = render_claude_local_template(template, &context).unwrap();
+        assert!(rendered.ends_with("Task: \n"));
+
+        let resumed = render_claude_local_template(
+            "{{task}}",
+            &context
+                .with_additional_task(Some("Add tests"))
+                .with_additional_task(Some("Fix lint")),
+        )
+        .unwrap();
+        assert_eq!(resumed, "Add tests\n\nFix lint");
+    }
+
+    #[test]
+    fn test_render_claude_local_template_rejects_unknown_placeholder() {
+        let context = claude_local_context(Path::new("/work"), None);
+        let err = render_claude_local_template("{{build_command}}", &context)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("unknown placeholder '{{build_command}}'"));
+
+        assert!(render_claude_local_template("{{task", &context).is_err());
+    }
+
+    #[test]
+    fn test_claude_local_template_lookup_and_fallback() {
+        let repo = tempfile::TempDir::new().unwrap();
+        let config_dir = tempfile::TempDir::new().unwrap();
+        let worktree = tempfile::TempDir::new().unwrap();
+        let context = claude_local_context(worktree.path(), Some("Add OAuth login"));
+        let written = || fs::read_to_string(worktree.path().join("CLAUDE.local.md")).unwrap();
+
+        // No template anywhere: built-in content
+        assert_eq!(
+            find_claude_local_template(Some(repo.path()), config_dir.path()),
+            None
+        );
+        write_claude_local_md_with_template(&context, None).unwrap();
+        assert!(written().contains("Para Session Status Commands"));
+
+        // The user template applies until the repo provides one
+        let user_template = config_dir.path().join(CLAUDE_LOCAL_TEMPLATE_FILE);
+        fs::write(&user_template, "user: {{task}}").unwrap();
+        let found = find_claude_local_template(Some(repo.path()), config_dir.path());
+        assert_eq!(found.as_deref(), Some(user_template.as_path()));
+
+        let repo_template = repo.path().join(".para").join(CLAUDE_LOCAL_TEMPLATE_FILE);
+        fs::create_dir_all(repo_template.parent().unwrap()).unwrap();
+        fs::write(&repo_template, "Run `cargo test` for {{session_name}}").unwrap();
+        let found = find_claude_local_template(Some(repo.path()), config_dir.path());
+        assert_eq!(found.as_deref(), Some(repo_template.as_path()));
+
+        write_claude_local_md_with_template(&context, found.as_deref()).unwrap();
+        assert_eq!(written(), "Run `cargo test` for auth-flow");
+
+        // A malformed template falls back instead of failing session creation
+        fs::write(&repo_template, "{{unknown}}").unwrap();
+        write_claude_local_md_with_template(&context, Some(&repo_template)).unwrap();
+        assert!(written().contains("Para Session Status Commands"));
+        assert!(written().contains("auth-flow"));
+    }
+
+    #[test]
+    fn test_apply_patch_leaves_changes_uncommitted() {
+        let (git_temp, git_service) = setup_test_repo();
+        let temp_dir = TempDir::new().unwrap();
+        let config = create_test_config_with_dir(&temp_dir);
+        let patch = readme_patch(git_temp.path(), temp_dir.path(), "# Retried\n");
+
+        let mut session_manager = SessionManager::for_repository(&config, git_temp.path());
+        let session = session_manager
+            .create_session_with_all_flags("retry".to_string(), None, false, false, None)
+            .unwrap();
+        apply_starting_changes(&git_service, &session_manager, &session, Some(&patch), None)
+            .unwrap();
+
+        let worktree = &session.worktree_path;
+        assert_eq!(
+            fs::read_to_string(worktree.join("README.md")).unwrap(),
+            "# Retried\n"
+        );
+        assert_eq!(
+            git(worktree, &["rev-parse", "HEAD"]),
+            session.base_commit.as_deref().unwrap()
+        );
+        assert!(!git(worktree, &["status", "--porcelain"]).is_empty());
+    }
+
+    #[test]
+    fn test_apply_from_branch_brings_its_diff() {
+        let (git_temp, git_service) = setup_test_repo();
+        let temp_dir = TempDir::new().unwrap();
+        let config = create_test_config_with_dir(&temp_dir);
+        let repo = git_temp.path();
+
+        git(repo, &["checkout", "-q", "-b", "attempt-1"]);
+        fs::write(repo.join("attempt.txt"), "first try\n").unwrap();
+        git(repo, &["add", "attempt.txt"]);
+        git(repo, &["commit", "-q", "-m", "First attempt"]);
+        git(repo, &["checkout", "-q", "main"]);
+
+        let mut session_manager = SessionManager::for_repository(&config, repo);
+        let session = session_manager
+            .create_session_with_all_flags("retry".to_string(), None, false, false, None)
+            .unwrap();
+        apply_starting_changes(
+            &git_service,
+            &session_manager,
+            &session,
+            None,
+            Some("attempt-1"),
+        )
+        .unwrap();
+
+        let worktree = &session.worktree_path;
+        assert_eq!(
+            fs::read_to_string(worktree.join("attempt.txt")).unwrap(),
+            "first try\n"
+        );
+        assert_eq!(
+            git(worktree, &["log", "-1", "--format=%s"]),
+            "Initial commit"
+        );
+    }
+
+    #[test]
+    fn test_conflicting_patch_discards_new_session() {
+        let (git_temp, git_service) = setup_test_repo();
+        let temp_dir = TempDir::new().unwrap();
+        let config = create_test_config_with_dir(&temp_dir);
+        let repo = git_temp.path();
+        let patch = readme_patch(repo, temp_dir.path(), "# Attempt\n");
+
+        // The session starts from a README the patch no longer fits
+        fs::write(repo.join("README.md"), "# Rewritten\n").unwrap();
+        git(repo, &["commit", "-q", "-am", "Rewrite README"]);
+
+        let mut session_manager = SessionManager::for_repository(&config, repo);
+        let session = session_manager
+            .create_session_with_all_flags("retry".to_string(), None, false, false, None)
+            .unwrap();
+        let err =
+            apply_starting_changes(&git_service, &session_manager, &session, Some(&patch), None)
+                .unwrap_err()
+                .to_string();
+
+        assert!(err.contains("Session 'retry' was not created"), "{err}");
+        assert!(
+            err.contains("README.md (conflicts with the session base)"),
+            "{err}"
+        );
+        assert!(!session_manager.session_exists("retry"));
+        assert!(!session.worktree_path.exists());
+        assert!(!git_service.branch_exists(&session.branch).unwrap());
+        assert_eq!(git_service.list_worktrees().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_conflict_hotspots_in_claude_local_md() {
+        let (git_temp, git_service) = setup_test_repo();
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = create_test_config_with_dir(&temp_dir);
+        let repo = git_temp.path();
+
+        // An earlier session's sync conflicted in README.md and was resolved
+        let mut session_manager = SessionManager::for_repository(&config, repo);
+        let state_dir = session_manager.state_dir().clone();
+        git(repo, &["checkout", "-q", "-b", "para/earlier"]);
+        fs::write(repo.join("README.md"), "# Earlier\n").unwrap();
+        git(repo, &["commit", "-q", "-am", "Earlier README"]);
+        git(repo, &["checkout", "-q", "main"]);
+        fs::write(repo.join("README.md"), "# Main\n").unwrap();
+        git(repo, &["commit", "-q", "-am", "Main README"]);
+        git(repo, &["checkout", "-q", "para/earlier"]);
+        assert!(Command::new("git")
+            .current_dir(repo)
+            .args(["rebase", "main"])
+            .output()
+            .is_ok_and(|output| !output.status.success()));
+        let files = vec!["README.md".to_string()];
+        conflicts::capture_pending(
+            git_service.repository(),
+            &state_dir,
+            "earlier",
+            "main",
+            &files,
+        )
+        .unwrap();
+        fs::write(repo.join("README.md"), "# Main and earlier\n").unwrap();
+        conflicts::record_resolutions(git_service.repository(), &state_dir, "earlier").unwrap();
+        git(repo, &["rebase", "--abort"]);
+        git(repo, &["checkout", "-q", "main"]);
+
+        let session = session_manager
+            .create_session_with_all_flags("next".to_string(), None, false, false, None)
+            .unwrap();
+        let claude_local = session.worktree_path.join("CLAUDE.local.md");
+        create_claude_local_md(&session.worktree_path, "next").unwrap();
+        add_conflict_hotspots(&config, &state_dir, &session).unwrap();
+        assert!(!fs::read_to_string(&claude_local)
+            .unwrap()
+            .contains("Known conflict hotspots"));
+
+        config.session.conflict_hotspots = true;
+        add_conflict_hotspots(&config, &state_dir, &session).unwrap();
+        let content = fs::read_to_string(&claude_local).unwrap();
+        assert!(content.contains("## Known conflict hotspots"));
+        assert!(content.contains("- `README.md`"));
+    }
+
+    #[test]
+    fn test_release_open_worktree_closes_or_warns() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = create_test_config_with_dir(&temp_dir);
+        let holders = vec!["Cursor".to_string()];
+
+        let platform = MockPlatformManager::new();
+        release_open_worktree(&config, &platform, "auth", &holders, true, false).unwrap();
+        assert_eq!(
+            *platform.close_calls.lock().unwrap(),
+            vec![("auth".to_string(), config.ide.name.clone())]
+        );
+
+        let platform = MockPlatformManager::new();
+        release_open_worktree(&config, &platform, "auth", &holders, false, true).unwrap();
+        assert!(platform.close_calls.lock().unwrap().is_empty());
+    }
+}
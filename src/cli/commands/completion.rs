@@ -1,10 +1,36 @@
+use crate::cli::commands::init::{detect_shell, home_dir, powershell_dir};
 use crate::cli::completion::generators::ShellCompletionGenerator;
-use crate::cli::parser::{CompletionArgs, Shell};
-use crate::utils::Result;
+use crate::cli::parser::{CompletionArgs, CompletionCommands, Shell};
+use crate::utils::{ParaError, Result};
+use clap::ValueEnum;
+use std::fs;
+use std::path::{Path, PathBuf};
 
 pub fn execute(args: CompletionArgs) -> Result<()> {
+    match args.command {
+        Some(CompletionCommands::Generate { shell }) => {
+            let completion_script = ShellCompletionGenerator::generate_enhanced_completion(shell)?;
+            println!("{completion_script}");
+            return Ok(());
+        }
+        Some(CompletionCommands::Install { shell }) => {
+            let shell = match shell {
+                Some(shell) => shell,
+                None => detect_shell()?,
+            };
+            return install(shell);
+        }
+        None => {}
+    }
+
+    let Some(shell_name) = args.shell else {
+        return Err(ParaError::invalid_args(
+            "Specify a shell: para completion <bash|zsh|fish|powershell>, or use 'para completion generate' / 'para completion install'",
+        ));
+    };
+
     // Handle special case: user typed "para completion init"
-    if args.shell == "init" {
+    if shell_name == "init" {
         println!("It looks like you want to set up completions automatically!");
         println!();
         println!("Run this command instead:");
@@ -15,16 +41,11 @@ pub fn execute(args: CompletionArgs) -> Result<()> {
     }
 
     // Parse the shell string into Shell enum
-    let shell = match args.shell.to_lowercase().as_str() {
-        "bash" => Shell::Bash,
-        "zsh" => Shell::Zsh,
-        "fish" => Shell::Fish,
-        _ => {
-            eprintln!("Error: '{}' is not a supported shell", args.shell);
-            eprintln!("Supported shells: bash, zsh, fish");
-            eprintln!("For automatic setup, use: para init");
-            return Ok(());
-        }
+    let Ok(shell) = Shell::from_str(&shell_name, true) else {
+        eprintln!("Error: '{shell_name}' is not a supported shell");
+        eprintln!("Supported shells: bash, zsh, fish, powershell");
+        eprintln!("For automatic setup, use: para init");
+        return Ok(());
     };
 
     // Check if user wants the raw completion script
@@ -68,6 +89,9 @@ pub fn execute(args: CompletionArgs) -> Result<()> {
             println!("   PARA_COMPLETION_SCRIPT=1 para completion fish > ~/.config/fish/completions/para.fish");
             println!("   # Restart your shell or run: source ~/.config/fish/config.fish");
         }
+        Shell::PowerShell => {
+            println!("   para completion install powershell");
+        }
     }
     println!();
     println!("For detailed options, run:");
@@ -76,16 +100,70 @@ pub fn execute(args: CompletionArgs) -> Result<()> {
     Ok(())
 }
 
+fn install(shell: Shell) -> Result<()> {
+    let (path, rc_line) = install_completion_script(&shell, &home_dir()?)?;
+    println!("✓ Installed {shell:?} completions to {}", path.display());
+
+    match rc_line {
+        Some((rc_file, line)) => {
+            println!();
+            println!("Add this line to {rc_file} if it is not there yet:");
+            println!("   {line}");
+        }
+        None => println!("Completions load automatically in new shells."),
+    }
+    Ok(())
+}
+
+/// Conventional per-user location for the completion script. Bash (with
+/// bash-completion) and fish load scripts from there on their own.
+fn completion_script_path(shell: &Shell, home: &Path) -> PathBuf {
+    match shell {
+        Shell::Bash => home.join(".local/share/bash-completion/completions/para"),
+        Shell::Zsh => home.join(".zsh/completions/_para"),
+        Shell::Fish => home.join(".config/fish/completions/para.fish"),
+        Shell::PowerShell => powershell_dir(home).join("para-completion.ps1"),
+    }
+}
+
+/// Write the completion script for `shell` under `home`. Returns its path and,
+/// for shells that do not pick it up automatically, the startup file and the
+/// line that loads it.
+fn install_completion_script(
+    shell: &Shell,
+    home: &Path,
+) -> Result<(PathBuf, Option<(&'static str, String)>)> {
+    let path = completion_script_path(shell, home);
+    let script = ShellCompletionGenerator::generate_enhanced_completion(shell.clone())?;
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| {
+            ParaError::fs_error(format!("Failed to create {}: {e}", parent.display()))
+        })?;
+    }
+    fs::write(&path, script)
+        .map_err(|e| ParaError::fs_error(format!("Failed to write {}: {e}", path.display())))?;
+
+    let rc_line = match shell {
+        Shell::Bash | Shell::Fish => None,
+        Shell::Zsh => Some(("~/.zshrc", format!("source {}", path.display()))),
+        Shell::PowerShell => Some(("$PROFILE", format!(". \"{}\"", path.display()))),
+    };
+    Ok((path, rc_line))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::cli::parser::CompletionArgs;
     use std::env;
+    use tempfile::TempDir;
 
     #[test]
     fn test_completion_init_redirect() {
         let args = CompletionArgs {
-            shell: "init".to_string(),
+            command: None,
+            shell: Some("init".to_string()),
         };
 
         let result = execute(args);
@@ -100,7 +178,8 @@ mod tests {
         env::set_var("PARA_COMPLETION_SCRIPT", "1");
 
         let args = CompletionArgs {
-            shell: "bash".to_string(),
+            command: None,
+            shell: Some("bash".to_string()),
         };
 
         let result = execute(args);
@@ -121,7 +200,8 @@ mod tests {
         env::set_var("PARA_COMPLETION_SCRIPT", "1");
 
         let args = CompletionArgs {
-            shell: "zsh".to_string(),
+            command: None,
+            shell: Some("zsh".to_string()),
         };
 
         let result = execute(args);
@@ -142,7 +222,8 @@ mod tests {
         env::set_var("PARA_COMPLETION_SCRIPT", "1");
 
         let args = CompletionArgs {
-            shell: "fish".to_string(),
+            command: None,
+            shell: Some("fish".to_string()),
         };
 
         let result = execute(args);
@@ -158,7 +239,8 @@ mod tests {
     #[test]
     fn test_completion_invalid_shell() {
         let args = CompletionArgs {
-            shell: "powershell".to_string(),
+            command: None,
+            shell: Some("tcsh".to_string()),
         };
 
         let result = execute(args);
@@ -173,7 +255,8 @@ mod tests {
         env::set_var("PARA_COMPLETION_SCRIPT", "1");
 
         let args = CompletionArgs {
-            shell: "BASH".to_string(),
+            command: None,
+            shell: Some("BASH".to_string()),
         };
 
         let result = execute(args);
@@ -194,7 +277,8 @@ mod tests {
         env::set_var("PARA_COMPLETION_HELP", "1");
 
         let args = CompletionArgs {
-            shell: "bash".to_string(),
+            command: None,
+            shell: Some("bash".to_string()),
         };
 
         let result = execute(args);
@@ -210,7 +294,8 @@ mod tests {
     #[test]
     fn test_completion_default_behavior() {
         let args = CompletionArgs {
-            shell: "bash".to_string(),
+            command: None,
+            shell: Some("bash".to_string()),
         };
 
         let result = execute(args);
@@ -228,7 +313,8 @@ mod tests {
             env::set_var("PARA_COMPLETION_SCRIPT", "1");
 
             let args = CompletionArgs {
-                shell: shell.to_string(),
+                command: None,
+                shell: Some(shell.to_string()),
             };
 
             let result = execute(args);
@@ -241,4 +327,28 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_install_completion_script_locations() {
+        let home = TempDir::new().unwrap();
+
+        let (fish_path, fish_rc) = install_completion_script(&Shell::Fish, home.path()).unwrap();
+        assert_eq!(
+            fish_path,
+            home.path().join(".config/fish/completions/para.fish")
+        );
+        assert!(fish_rc.is_none());
+        assert!(fs::read_to_string(&fish_path)
+            .unwrap()
+            .contains("(__para_sessions)"));
+
+        let (ps_path, ps_rc) = install_completion_script(&Shell::PowerShell, home.path()).unwrap();
+        assert!(ps_path.starts_with(powershell_dir(home.path())));
+        let (rc_file, line) = ps_rc.unwrap();
+        assert_eq!(rc_file, "$PROFILE");
+        assert!(line.contains("para-completion.ps1"));
+        assert!(fs::read_to_string(&ps_path)
+            .unwrap()
+            .contains("Get-ParaDynamicCompletions"));
+    }
 }
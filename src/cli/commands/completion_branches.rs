@@ -1,5 +1,6 @@
 use crate::config::Config;
 use crate::core::git::{GitOperations, GitService};
+use crate::core::session::{SessionManager, SessionStatus};
 use crate::utils::Result;
 
 pub fn execute() -> Result<()> {
@@ -35,6 +36,29 @@ pub fn execute() -> Result<()> {
     Ok(())
 }
 
+/// Branches of para sessions that have not been cancelled, for `--branch` selectors
+pub fn execute_para_branches() -> Result<()> {
+    let Ok(config) = Config::load_or_create() else {
+        // Silent failure for completion compatibility
+        return Ok(());
+    };
+
+    if let Ok(sessions) = SessionManager::new(&config).list_sessions() {
+        let mut branches: Vec<String> = sessions
+            .into_iter()
+            .filter(|s| !matches!(s.status, SessionStatus::Cancelled))
+            .map(|s| s.branch)
+            .collect();
+        branches.sort();
+        branches.dedup();
+        for branch in branches {
+            println!("{branch}");
+        }
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
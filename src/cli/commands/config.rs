@@ -1,7 +1,17 @@
-use crate::cli::parser::{ConfigArgs, ConfigCommands, ProjectConfigCommands};
+use crate::cli::commands::common::{find_template, is_non_interactive};
+use crate::cli::parser::{
+    ConfigArgs, ConfigCommands, NotifyCommands, ProjectConfigCommands, TemplateCommands,
+};
+use crate::config::concurrent::ConfigSnapshot;
+use crate::config::keys;
+use crate::config::overrides::parse_config_value;
+use crate::config::portable::{diff_configs, export_config, import_config, PortablePaths};
+use crate::config::wizard::WizardSection;
 use crate::config::{self, ConfigManager, ProjectConfig};
+use crate::core::git::MergeMode;
+use crate::core::session::{SessionManager, SessionState};
 use crate::utils::{ParaError, Result};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
 pub fn execute(args: ConfigArgs) -> Result<()> {
@@ -12,13 +22,29 @@ pub fn execute(args: ConfigArgs) -> Result<()> {
         Some(ConfigCommands::Edit) => execute_edit(),
         Some(ConfigCommands::Reset) => execute_reset(),
         Some(ConfigCommands::Set { path, value }) => execute_set(&path, &value),
+        Some(ConfigCommands::Get { path }) => execute_get(&path),
+        Some(ConfigCommands::Keys { quiet }) => execute_keys(quiet),
+        Some(ConfigCommands::Session {
+            session,
+            merge_mode,
+        }) => execute_session(&session, merge_mode),
         Some(ConfigCommands::Project { command }) => execute_project(command),
-        None => execute_default(),
+        Some(ConfigCommands::Notify { command }) => execute_notify(command),
+        Some(ConfigCommands::Templates { command }) => execute_templates(command),
+        Some(ConfigCommands::Path) => execute_path(),
+        Some(ConfigCommands::Doctor { fix, dry_run }) => execute_doctor(fix, dry_run),
+        Some(ConfigCommands::Export { output }) => execute_export(output.as_deref()),
+        Some(ConfigCommands::Import { file, yes }) => execute_import(&file, yes),
+        None => execute_wizard(&args.section),
     }
 }
 
 fn execute_setup() -> Result<()> {
-    config::run_config_wizard()
+    execute_wizard(&[])
+}
+
+fn execute_wizard(sections: &[WizardSection]) -> Result<()> {
+    config::run_config_wizard(sections)
         .map_err(|e| ParaError::config_error(format!("Configuration wizard failed: {e}")))?;
     println!("✅ Configuration wizard completed successfully");
     Ok(())
@@ -31,6 +57,89 @@ fn execute_auto() -> Result<()> {
     Ok(())
 }
 
+fn execute_path() -> Result<()> {
+    let location = config::path::ConfigEnv::current().resolve();
+    println!("{}", location.path.display());
+    match location.migrate_to {
+        Some(target) => eprintln!(
+            "Source: {} (copied to {} the next time para loads its config)",
+            location.source,
+            target.display()
+        ),
+        None => eprintln!("Source: {}", location.source),
+    }
+    if !location.path.exists() {
+        eprintln!("The file does not exist yet; para creates it on first use");
+    }
+    Ok(())
+}
+
+fn execute_doctor(fix: bool, dry_run: bool) -> Result<()> {
+    use crate::config::defaults::{get_config_file_path, is_command_available};
+    use crate::config::doctor::{self, Problem};
+
+    let path = get_config_file_path();
+    if !path.exists() {
+        println!("No config file at {}; nothing to check", path.display());
+        return Ok(());
+    }
+    let snapshot = ConfigSnapshot::read(&path)
+        .map_err(|e| ParaError::config_error(format!("Failed to read {}: {e}", path.display())))?;
+    let problems = doctor::diagnose(snapshot.value(), &is_command_available);
+    if problems.is_empty() {
+        println!("✅ {} has no problems", path.display());
+        return Ok(());
+    }
+
+    println!("{}:", path.display());
+    for problem in &problems {
+        match problem.fix() {
+            Some(repair) if fix => println!("  ⚠️  {problem}\n      fix: {repair}"),
+            None if fix => println!("  ⚠️  {problem}\n      fix by hand"),
+            _ => println!("  ⚠️  {problem}"),
+        }
+    }
+    if !fix {
+        println!("Run 'para config doctor --fix' to repair them");
+        return Ok(());
+    }
+    if dry_run {
+        println!("Dry run, nothing changed");
+        return Ok(());
+    }
+
+    let backup = path.with_file_name(format!(
+        "{}.bak-{}",
+        path.file_name()
+            .map(|name| name.to_string_lossy())
+            .unwrap_or_default(),
+        chrono::Local::now().format(crate::utils::ARCHIVE_TIMESTAMP_FORMAT)
+    ));
+    std::fs::copy(&path, &backup).map_err(|e| ParaError::from_io("back up config", &backup, e))?;
+
+    for problem in &problems {
+        if let Problem::MissingDirectory { dir, .. } = problem {
+            std::fs::create_dir_all(dir)
+                .map_err(|e| ParaError::from_io("create directory", dir, e))?;
+        }
+    }
+    let mut value = snapshot.value().clone();
+    doctor::apply_fixes(&mut value, &problems);
+    ConfigManager::save_value_checked(value, &snapshot)
+        .map_err(|e| ParaError::config_error(format!("Failed to save configuration: {e}")))?;
+
+    let unfixed = problems.iter().filter(|p| p.fix().is_none()).count();
+    println!(
+        "✅ Repaired {} problem(s), backup at {}",
+        problems.len() - unfixed,
+        backup.display()
+    );
+    if unfixed > 0 {
+        println!("{unfixed} problem(s) need fixing by hand");
+    }
+    Ok(())
+}
+
 fn execute_show() -> Result<()> {
     match ConfigManager::load_or_create() {
         Ok(config) => {
@@ -65,12 +174,6 @@ fn execute_edit() -> Result<()> {
     Ok(())
 }
 
-fn is_non_interactive() -> bool {
-    std::env::var("PARA_NON_INTERACTIVE").is_ok()
-        || std::env::var("CI").is_ok()
-        || !atty::is(atty::Stream::Stdin)
-}
-
 fn execute_reset() -> Result<()> {
     use dialoguer::{theme::ColorfulTheme, Confirm};
 
@@ -101,13 +204,6 @@ fn execute_reset() -> Result<()> {
     Ok(())
 }
 
-fn execute_default() -> Result<()> {
-    config::run_config_wizard()
-        .map_err(|e| ParaError::config_error(format!("Configuration wizard failed: {e}")))?;
-    println!("✅ Configuration wizard completed successfully");
-    Ok(())
-}
-
 fn execute_set(path: &str, value: &str) -> Result<()> {
     let config_path = std::path::PathBuf::from(
         ConfigManager::get_config_path()
@@ -115,34 +211,82 @@ fn execute_set(path: &str, value: &str) -> Result<()> {
     );
 
     // Load existing config as JSON value for manipulation
-    let config_content = std::fs::read_to_string(&config_path)
+    if !config_path.exists() {
+        return Err(ParaError::config_error(format!(
+            "Failed to read config file: {} does not exist",
+            config_path.display()
+        )));
+    }
+    let snapshot = ConfigSnapshot::read(&config_path)
         .map_err(|e| ParaError::config_error(format!("Failed to read config file: {e}")))?;
-
-    let mut json_value: serde_json::Value = serde_json::from_str(&config_content)
-        .map_err(|e| ParaError::config_error(format!("Invalid JSON in config file: {e}")))?;
+    let mut json_value = snapshot.value().clone();
 
     // Parse the path and set the value
     set_json_value(&mut json_value, path, value)?;
 
-    // Write back to file
-    let updated_json = serde_json::to_string_pretty(&json_value)
-        .map_err(|e| ParaError::config_error(format!("Failed to serialize config: {e}")))?;
-
-    std::fs::write(&config_path, updated_json)
+    // Write back to file, keeping changes saved since it was read
+    ConfigManager::save_value_checked(json_value, &snapshot)
         .map_err(|e| ParaError::config_error(format!("Failed to write config file: {e}")))?;
 
     println!("✅ Configuration updated: {path} = {value}");
     Ok(())
 }
 
+fn execute_get(path: &str) -> Result<()> {
+    if !keys::is_known(path) {
+        return Err(ParaError::config_error(keys::unknown_key_message(path)));
+    }
+    let config = ConfigManager::load_or_create()
+        .map_err(|e| ParaError::config_error(format!("Failed to load configuration: {e}")))?;
+    let json = serde_json::to_value(&config)
+        .map_err(|e| ParaError::config_error(format!("Failed to serialize config: {e}")))?;
+
+    match path
+        .split('.')
+        .try_fold(&json, |current, part| current.get(part))
+    {
+        None | Some(serde_json::Value::Null) => eprintln!("'{path}' is not set"),
+        Some(serde_json::Value::String(value)) => println!("{value}"),
+        Some(value) => println!("{value}"),
+    }
+    Ok(())
+}
+
+fn execute_keys(quiet: bool) -> Result<()> {
+    if quiet {
+        for key in keys::CONFIG_KEYS {
+            println!("{}", key.path);
+        }
+        return Ok(());
+    }
+
+    let width = keys::CONFIG_KEYS
+        .iter()
+        .map(|key| key.path.len())
+        .max()
+        .unwrap_or(0);
+    for key in keys::CONFIG_KEYS {
+        let scope = if key.repo_overridable {
+            "  (repo-overridable)"
+        } else {
+            ""
+        };
+        println!("{:<width$}  {}{scope}", key.path, key.kind);
+    }
+    Ok(())
+}
+
 fn set_json_value(json_value: &mut serde_json::Value, path: &str, value: &str) -> Result<()> {
     if path.trim().is_empty() {
         return Err(ParaError::config_error("Empty path provided".to_string()));
     }
+    if !keys::is_known(path) {
+        return Err(ParaError::config_error(keys::unknown_key_message(path)));
+    }
 
     let path_parts: Vec<&str> = path.split('.').collect();
 
-    // Navigate to the parent object
+    // Navigate to the parent object; optional sections that are unset are created
     let mut current = json_value;
     for part in &path_parts[..path_parts.len() - 1] {
         current = current
@@ -150,8 +294,11 @@ fn set_json_value(json_value: &mut serde_json::Value, path: &str, value: &str) -
             .ok_or_else(|| {
                 ParaError::config_error(format!("Path component '{part}' is not an object"))
             })?
-            .get_mut(*part)
-            .ok_or_else(|| ParaError::config_error(format!("Path component '{part}' not found")))?;
+            .entry(part.to_string())
+            .or_insert_with(|| serde_json::Value::Object(Default::default()));
+        if current.is_null() {
+            *current = serde_json::Value::Object(Default::default());
+        }
     }
 
     // Set the final value
@@ -167,27 +314,170 @@ fn set_json_value(json_value: &mut serde_json::Value, path: &str, value: &str) -
     Ok(())
 }
 
-fn parse_config_value(value: &str) -> serde_json::Value {
-    // Simple type heuristics
-    match value {
-        "true" => serde_json::Value::Bool(true),
-        "false" => serde_json::Value::Bool(false),
-        _ => {
-            // Try to parse as number
-            if let Ok(int_val) = value.parse::<i64>() {
-                serde_json::Value::Number(serde_json::Number::from(int_val))
-            } else if let Ok(float_val) = value.parse::<f64>() {
-                if let Some(num) = serde_json::Number::from_f64(float_val) {
-                    serde_json::Value::Number(num)
-                } else {
-                    serde_json::Value::String(value.to_string())
-                }
-            } else {
-                // Default to string
-                serde_json::Value::String(value.to_string())
+fn execute_export(output: Option<&Path>) -> Result<()> {
+    let config = ConfigManager::load_or_create()
+        .map_err(|e| ParaError::config_error(format!("Failed to load configuration: {e}")))?;
+    let exported = export_config(&config, &PortablePaths::detect())
+        .map_err(|e| ParaError::config_error(format!("Failed to export configuration: {e}")))?;
+
+    for path in &exported.excluded {
+        eprintln!("Warning: Left out '{path}' because it looks like a secret");
+    }
+
+    let json = serde_json::to_string_pretty(&exported.json)
+        .map_err(|e| ParaError::config_error(format!("Failed to serialize config: {e}")))?;
+    match output {
+        Some(path) => {
+            std::fs::write(path, format!("{json}\n")).map_err(|e| {
+                ParaError::fs_error(format!("Failed to write {}: {e}", path.display()))
+            })?;
+            println!("✅ Configuration exported to {}", path.display());
+        }
+        None => println!("{json}"),
+    }
+    Ok(())
+}
+
+fn execute_import(file: &Path, yes: bool) -> Result<()> {
+    use dialoguer::{theme::ColorfulTheme, Confirm};
+
+    let content = std::fs::read_to_string(file)
+        .map_err(|e| ParaError::fs_error(format!("Failed to read {}: {e}", file.display())))?;
+    let imported: serde_json::Value = serde_json::from_str(&content)
+        .map_err(|e| ParaError::config_error(format!("Invalid JSON in {}: {e}", file.display())))?;
+
+    let (current, snapshot) = ConfigManager::load_for_update()
+        .map_err(|e| ParaError::config_error(format!("Failed to load configuration: {e}")))?;
+    let new_config = import_config(&current, imported, &PortablePaths::detect())
+        .map_err(|e| ParaError::config_error(format!("Failed to import configuration: {e}")))?;
+    new_config
+        .validate()
+        .map_err(|e| ParaError::config_error(format!("Imported configuration is invalid: {e}")))?;
+
+    let diff = diff_configs(&current, &new_config)
+        .map_err(|e| ParaError::config_error(format!("Failed to compare configurations: {e}")))?;
+    if diff.is_empty() {
+        println!("Configuration already matches {}", file.display());
+        return Ok(());
+    }
+
+    println!("Changes from {}:", file.display());
+    for line in &diff {
+        println!("  {line}");
+    }
+
+    if !yes {
+        if is_non_interactive() {
+            return Err(ParaError::invalid_args(
+                "Cannot confirm import in non-interactive mode. Use --yes to apply it.",
+            ));
+        }
+        if !Confirm::with_theme(&ColorfulTheme::default())
+            .with_prompt("Apply these changes?")
+            .default(false)
+            .interact()
+            .map_err(|e| ParaError::config_error(format!("Failed to read input: {e}")))?
+        {
+            println!("❌ Import cancelled");
+            return Ok(());
+        }
+    }
+
+    ConfigManager::save_checked(&new_config, &snapshot)
+        .map_err(|e| ParaError::config_error(format!("Failed to save configuration: {e}")))?;
+    println!("✅ Configuration imported from {}", file.display());
+    Ok(())
+}
+
+fn execute_notify(command: NotifyCommands) -> Result<()> {
+    match command {
+        NotifyCommands::Test => execute_notify_test(),
+    }
+}
+
+fn execute_notify_test() -> Result<()> {
+    use crate::core::notifications::{notify_blocking, NotificationEvent};
+
+    let config = ConfigManager::load_or_create()
+        .map_err(|e| ParaError::config_error(format!("Failed to load configuration: {e}")))?;
+
+    let status = notify_blocking(&config, NotificationEvent::Test)?;
+    if status.success() {
+        println!("✅ Test notification sent");
+        Ok(())
+    } else {
+        Err(ParaError::config_error(format!(
+            "Notification command exited with status {}",
+            status.code().unwrap_or(-1)
+        )))
+    }
+}
+
+fn execute_templates(command: TemplateCommands) -> Result<()> {
+    let config = ConfigManager::load_with_project_config()
+        .map_err(|e| ParaError::config_error(format!("Failed to load configuration: {e}")))?;
+
+    match command {
+        TemplateCommands::List => {
+            if config.templates.is_empty() {
+                println!("No templates configured. Add them under \"templates\" in the para config or .para/config.json");
+            }
+            for line in template_list_lines(&config) {
+                println!("{line}");
             }
         }
+        TemplateCommands::Show { name } => {
+            let template = find_template(&config, &name)?;
+            let json = serde_json::to_string_pretty(template).map_err(|e| {
+                ParaError::config_error(format!("Failed to serialize template: {e}"))
+            })?;
+            println!("{json}");
+        }
     }
+    Ok(())
+}
+
+fn template_list_lines(config: &config::Config) -> Vec<String> {
+    let width = config.templates.keys().map(String::len).max().unwrap_or(0);
+    config
+        .templates
+        .iter()
+        .map(|(name, template)| match &template.description {
+            Some(description) => format!("{name:<width$}  {description}"),
+            None => name.clone(),
+        })
+        .collect()
+}
+
+fn execute_session(session: &str, merge_mode: Option<MergeMode>) -> Result<()> {
+    let config = ConfigManager::load_or_create()
+        .map_err(|e| ParaError::config_error(format!("Failed to load configuration: {e}")))?;
+    let session_manager = SessionManager::new(&config);
+
+    let state = match merge_mode {
+        Some(merge_mode) => {
+            let state = set_session_merge_mode(&session_manager, session, merge_mode)?;
+            println!(
+                "✅ Session '{}' will finish with merge mode '{merge_mode}'",
+                state.name
+            );
+            state
+        }
+        None => session_manager.resolve_session(session)?,
+    };
+    println!("merge_mode: {}", state.merge_mode);
+    Ok(())
+}
+
+fn set_session_merge_mode(
+    session_manager: &SessionManager,
+    session: &str,
+    merge_mode: MergeMode,
+) -> Result<SessionState> {
+    let mut state = session_manager.resolve_session(session)?;
+    state.merge_mode = merge_mode;
+    session_manager.save_state(&state)?;
+    Ok(state)
 }
 
 fn execute_project(command: Option<ProjectConfigCommands>) -> Result<()> {
@@ -218,6 +508,8 @@ fn execute_project_init() -> Result<()> {
             allowed_domains: vec![],
         }),
         ide: None,
+        git: None,
+        templates: Default::default(),
     };
 
     // Save it
@@ -291,6 +583,8 @@ fn execute_project_set(path: &str, value: &str) -> Result<()> {
         Ok(None) => ProjectConfig {
             sandbox: None,
             ide: None,
+            git: None,
+            templates: Default::default(),
         },
         Err(e) => {
             return Err(ParaError::config_error(format!(
@@ -469,6 +763,14 @@ mod tests {
         // Test empty path
         let result = set_json_value(&mut json_value, "", "value");
         assert!(result.is_err());
+
+        // Typos are rejected with a suggestion
+        let err = set_json_value(&mut json_value, "git.auto_stag", "false").unwrap_err();
+        assert!(err.to_string().contains("Did you mean git.auto_stage?"));
+
+        // Unset optional sections are created for known keys
+        set_json_value(&mut json_value, "notifications.timeout_secs", "5").unwrap();
+        assert_eq!(json_value["notifications"]["timeout_secs"], 5);
     }
 
     #[test]
@@ -0,0 +1,97 @@
+use crate::cli::context::CommandContext;
+use crate::cli::parser::{ConflictsArgs, ConflictsCommands};
+use crate::core::session::conflicts::{self, ConflictRecord};
+use crate::utils::Result;
+use chrono::Local;
+
+pub fn execute(ctx: &CommandContext, args: ConflictsArgs) -> Result<()> {
+    match args.command {
+        ConflictsCommands::Show { file } => show(ctx, file.as_deref()),
+    }
+}
+
+fn show(ctx: &CommandContext, file: Option<&str>) -> Result<()> {
+    let session_manager = ctx.session_manager();
+    let records = conflicts::load_records(session_manager.state_dir(), file);
+
+    if records.is_empty() {
+        match file {
+            Some(file) => println!("No conflicts recorded for '{file}'"),
+            None => println!("No conflicts recorded"),
+        }
+        return Ok(());
+    }
+
+    for record in &records {
+        println!("{}", format_record(record));
+    }
+    Ok(())
+}
+
+fn format_record(record: &ConflictRecord) -> String {
+    let mut text = format!(
+        "{}  {}  session {} onto {} ({} onto {})",
+        record
+            .timestamp
+            .with_timezone(&Local)
+            .format("%Y-%m-%d %H:%M:%S"),
+        record.file,
+        record.session,
+        record.base_branch,
+        short_sha(&record.session_sha),
+        short_sha(&record.base_sha)
+    );
+    let others: Vec<&str> = record
+        .files
+        .iter()
+        .filter(|file| **file != record.file)
+        .map(String::as_str)
+        .collect();
+    if !others.is_empty() {
+        text.push_str(&format!("\n  Together with: {}", others.join(", ")));
+    }
+    if record.resolution.is_empty() {
+        text.push_str("\n  Resolved by deleting the file");
+    } else {
+        for line in record.resolution.lines() {
+            text.push_str(&format!("\n  {line}"));
+        }
+    }
+    text.push('\n');
+    text
+}
+
+fn short_sha(sha: &str) -> &str {
+    &sha[..sha.len().min(8)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    #[test]
+    fn test_format_record() {
+        let record = ConflictRecord {
+            timestamp: Utc::now(),
+            session: "auth".to_string(),
+            base_branch: "main".to_string(),
+            base_sha: "0123456789abcdef".to_string(),
+            session_sha: "fedcba9876543210".to_string(),
+            file: "src/lib.rs".to_string(),
+            files: vec!["src/lib.rs".to_string(), "Cargo.toml".to_string()],
+            resolution: "@@ -1,5 +1 @@\n-<<<<<<< HEAD\n+merged".to_string(),
+        };
+        let text = format_record(&record);
+        assert!(text.contains("src/lib.rs  session auth onto main (fedcba98 onto 01234567)"));
+        assert!(text.contains("\n  Together with: Cargo.toml\n"));
+        assert!(text.ends_with("\n  -<<<<<<< HEAD\n  +merged\n"));
+
+        let deleted = ConflictRecord {
+            resolution: String::new(),
+            files: vec!["src/lib.rs".to_string()],
+            ..record
+        };
+        assert!(format_record(&deleted).ends_with("\n  Resolved by deleting the file\n"));
+    }
+}
@@ -1,17 +1,25 @@
 //! Daemon command implementation
 
+use crate::cli::context::CommandContext;
 use crate::cli::parser::{DaemonArgs, DaemonCommands};
+use crate::config::Config;
+use crate::core::daemon::client::prefetch_now;
+use crate::core::daemon::prefetch::{daemon_prefetch_path, load_statuses};
 use crate::core::daemon::server::{is_daemon_running, DaemonServer};
-use crate::core::daemon::{daemon_pid_path, daemon_socket_path, DaemonCommand, DaemonResponse};
+use crate::core::daemon::{daemon_socket_path, DaemonCommand, DaemonPidFile, DaemonResponse};
+use crate::core::docker::cleanup::ContainerCleaner;
+use crate::core::network::NetworkContext;
 use crate::utils::Result;
 use std::io::Write;
 use std::os::unix::net::UnixStream;
 
-pub fn execute(args: DaemonArgs) -> Result<()> {
+pub fn execute(ctx: &CommandContext, args: DaemonArgs) -> Result<()> {
     match args.command {
         DaemonCommands::Start => start_daemon(),
         DaemonCommands::Stop => stop_daemon(),
-        DaemonCommands::Status => check_status(),
+        DaemonCommands::Status => check_status(ctx.config()),
+        DaemonCommands::Prefetch => request_prefetch(ctx.config()),
+        DaemonCommands::Cleanup { now } => run_cleanup(ctx.config(), now),
     }
 }
 
@@ -77,7 +85,102 @@ fn stop_daemon() -> Result<()> {
     }
 }
 
-fn check_status() -> Result<()> {
+fn run_cleanup(config: &Config, now: bool) -> Result<()> {
+    let cleaner = ContainerCleaner::new(config.clone());
+
+    if !now && !cleaner.should_run_cleanup_at(chrono::Utc::now()) {
+        println!(
+            "Container cleanup ran recently; next run after {} (use --now to force)",
+            format_next_cleanup(config, &cleaner)
+        );
+        return Ok(());
+    }
+
+    let report = cleaner
+        .cleanup_now()
+        .map_err(|e| crate::utils::ParaError::docker_error(e.to_string()))?;
+
+    match report {
+        None => println!("Another container cleanup is already running"),
+        Some(report) if report.is_empty() => println!("No orphaned containers found"),
+        Some(report) => {
+            for container in &report.containers_removed {
+                println!("Removed container {container}");
+            }
+            for volume in &report.volumes_removed {
+                println!("Removed volume {volume}");
+            }
+            for network in &report.networks_removed {
+                println!("Removed network {network}");
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn format_next_cleanup(config: &Config, cleaner: &ContainerCleaner) -> String {
+    let interval = chrono::Duration::from_std(config.get_container_cleanup_interval())
+        .unwrap_or(chrono::Duration::MAX);
+    cleaner
+        .last_cleanup()
+        .and_then(|last| last.checked_add_signed(interval))
+        .map(|next| {
+            next.with_timezone(&chrono::Local)
+                .format("%Y-%m-%d %H:%M:%S")
+                .to_string()
+        })
+        .unwrap_or_else(|| "now".to_string())
+}
+
+fn print_last_cleanup(config: &Config) {
+    let cleaner = ContainerCleaner::new(config.clone());
+    match cleaner.last_cleanup() {
+        Some(last) => println!(
+            "Last container cleanup: {} (next after {})",
+            last.with_timezone(&chrono::Local)
+                .format("%Y-%m-%d %H:%M:%S"),
+            format_next_cleanup(config, &cleaner)
+        ),
+        None => println!("Last container cleanup: never"),
+    }
+}
+
+fn request_prefetch(config: &Config) -> Result<()> {
+    if config.get_auto_fetch_interval().is_none() {
+        return Err(crate::utils::ParaError::invalid_args(
+            "Prefetching needs git.auto_fetch. Enable it with 'para config set git.auto_fetch true'",
+        ));
+    }
+    NetworkContext::current().require_online("para daemon prefetch")?;
+    let repo_root = crate::utils::get_main_repository_root()?;
+    prefetch_now(&repo_root)
+        .map_err(|e| crate::utils::ParaError::worktree_operation(e.to_string()))?;
+    println!(
+        "Asked the daemon to fetch the base branches of {}",
+        repo_root.display()
+    );
+    Ok(())
+}
+
+fn print_prefetch_status(config: &Config) {
+    if config.get_auto_fetch_interval().is_none() {
+        println!("Prefetch: off (git.auto_fetch is disabled)");
+        return;
+    }
+    let statuses = load_statuses(&daemon_prefetch_path());
+    if statuses.is_empty() {
+        println!("Prefetch: no base branches fetched yet");
+        return;
+    }
+    println!("Prefetch:");
+    let now = chrono::Utc::now();
+    for status in statuses {
+        println!("  {}", status.describe(now));
+    }
+}
+
+fn check_status(config: &Config) -> Result<()> {
     if is_daemon_running() {
         // Try to ping
         match send_daemon_command(&DaemonCommand::Ping) {
@@ -85,8 +188,11 @@ fn check_status() -> Result<()> {
                 println!("Para daemon is running");
 
                 // Show PID
-                if let Ok(pid_str) = std::fs::read_to_string(daemon_pid_path()) {
-                    println!("PID: {}", pid_str.trim());
+                if let Some(pid_file) = DaemonPidFile::read() {
+                    println!("PID: {}", pid_file.pid);
+                    if let Some(version) = pid_file.version {
+                        println!("Version: {version}");
+                    }
                 }
 
                 // Show socket path
@@ -100,6 +206,9 @@ fn check_status() -> Result<()> {
         println!("Para daemon is not running");
     }
 
+    print_last_cleanup(config);
+    print_prefetch_status(config);
+
     Ok(())
 }
 
@@ -67,6 +67,25 @@ mod integration_tests {
                 sandbox_no_network: false,
                 allowed_domains: vec![],
             },
+            base: None,
+            fetch: false,
+            allow_empty_repo: false,
+            no_stdin: false,
+            copy: vec![],
+            copy_large: false,
+            no_auto_commit: false,
+            merge_mode: None,
+            remote: None,
+            apply_patch: None,
+            apply_from: None,
+            template: None,
+            allow_secrets: false,
+            retry: None,
+            cancel_old: false,
+            no_hook_fixup: false,
+            plan_only: false,
+            defer_setup: false,
+            prompt_is_file: false,
         };
 
         // Verify the args would include the flag
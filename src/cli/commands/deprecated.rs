@@ -0,0 +1,64 @@
+//! `para integrate` and `para continue` from the older CLI
+//!
+//! Both still run, mapped onto the commands that replaced them, so existing
+//! scripts keep working. Each prints a one-line note naming the replacement
+//! unless `ui.hide_deprecations` is set.
+
+use super::{finish, sync};
+use crate::cli::context::CommandContext;
+use crate::cli::parser::{IntegrateArgs, SyncArgs};
+use crate::config::Config;
+use crate::ui::theme;
+use crate::utils::Result;
+
+pub fn execute_integrate(ctx: &CommandContext, args: IntegrateArgs) -> Result<()> {
+    print_note(ctx.config(), "integrate", "para finish \"<message>\"");
+    let args = args.into_finish_args();
+    args.validate()?;
+    finish::execute(ctx, args)
+}
+
+pub fn execute_continue(ctx: &CommandContext, args: SyncArgs) -> Result<()> {
+    print_note(
+        ctx.config(),
+        "continue",
+        "git rebase --continue, then para sync",
+    );
+    sync::execute_continue(ctx, args)
+}
+
+fn print_note(config: &Config, command: &str, replacement: &str) {
+    if let Some(note) = deprecation_note(config, command, replacement) {
+        eprintln!("{note}");
+    }
+}
+
+fn deprecation_note(config: &Config, command: &str, replacement: &str) -> Option<String> {
+    (!config.should_hide_deprecations()).then(|| {
+        theme::warning(format!(
+            "'para {command}' is deprecated, use '{replacement}' instead (hide this note with ui.hide_deprecations)"
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::UiConfig;
+    use crate::test_utils::test_helpers::create_test_config;
+
+    #[test]
+    fn test_deprecation_note() {
+        let mut config = create_test_config();
+        let note = deprecation_note(&config, "integrate", "para finish \"<message>\"").unwrap();
+        assert!(note.ends_with(
+            "'para integrate' is deprecated, use 'para finish \"<message>\"' instead (hide this note with ui.hide_deprecations)"
+        ));
+
+        config.ui = Some(UiConfig {
+            hide_deprecations: true,
+            ..Default::default()
+        });
+        assert_eq!(deprecation_note(&config, "integrate", "para finish"), None);
+    }
+}
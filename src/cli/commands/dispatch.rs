@@ -1,13 +1,34 @@
-use crate::cli::commands::common::create_claude_local_md;
+use crate::cli::commands::cancel::{cancel_and_archive_session, check_stacked_children};
+use crate::cli::commands::common::{
+    add_conflict_hotspots, apply_starting_changes, check_prompt_secrets,
+    check_skip_permissions_allowed, copy_untracked_into_worktree, ensure_repository_has_commits,
+    fix_worktree_hooks_path, record_merge_mode, record_template, setup_auto_checkpoints,
+    write_claude_local_md, ClaudeLocalContext,
+};
+use crate::cli::context::CommandContext;
 use crate::cli::parser::DispatchArgs;
+use crate::config::templates::prefix_prompt;
 use crate::config::Config;
-use crate::core::git::{GitOperations, GitService};
+use crate::core::daemon::prefetch;
+use crate::core::git::identity::apply_worktree_identity;
+use crate::core::git::repository::execute_git_command;
+use crate::core::git::{BranchManager, GitRepository, GitService};
+use crate::core::ide::ensure_remote_supports_container;
+use crate::core::logs;
+use crate::core::network::NetworkContext;
 use crate::core::sandbox::config::SandboxResolver;
-use crate::core::session::{SessionManager, SessionState};
+use crate::core::session::retry::{self, RetrySource};
+use crate::core::session::rollback::CreationRollback;
+use crate::core::session::{stack, SessionManager, SessionPaths, SessionState, SessionStatus};
+use crate::ui::theme;
+use crate::utils::ci;
+use crate::utils::output::path_link;
+use crate::utils::progress::StepReporter;
 use crate::utils::{names::*, ParaError, Result};
 use std::fs;
 use std::io::{self, IsTerminal, Read};
 use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 
 /// Determine which setup script to use based on priority order
 fn get_setup_script_path(
@@ -88,17 +109,24 @@ fn get_setup_script_path(
 }
 
 /// Run a setup script for a regular worktree session
-fn run_worktree_setup_script(
+pub(crate) fn run_worktree_setup_script(
     script_path: &Path,
     session_name: &str,
     worktree_path: &Path,
+    state_dir: &Path,
 ) -> Result<()> {
     use std::process::Command;
 
-    println!("🔧 Running setup script: {}", script_path.display());
+    println!(
+        "{}",
+        theme::action(format!("Running setup script: {}", script_path.display()))
+    );
 
     // Security warning
-    eprintln!("⚠️  Warning: Setup scripts run with your full user permissions!");
+    eprintln!(
+        "{}",
+        theme::warning("Warning: Setup scripts run with your full user permissions!")
+    );
     eprintln!("   Only run scripts from trusted sources.");
     eprintln!("   Script: {}", script_path.display());
 
@@ -110,33 +138,278 @@ fn run_worktree_setup_script(
     cmd.env("PARA_WORKSPACE", worktree_path);
     cmd.env("PARA_SESSION", session_name);
 
-    let status = cmd
-        .status()
-        .map_err(|e| ParaError::ide_error(format!("Failed to execute setup script: {e}")))?;
+    // Captured for `para logs`
+    let log_path = logs::setup_log_path(state_dir, session_name);
+    let status = logs::run_logged(&mut cmd, &log_path)
+        .map_err(|e| ParaError::setup_script(script_path, format!("could not run it: {e}")))?;
 
     if !status.success() {
-        return Err(ParaError::ide_error(format!(
-            "Setup script failed with exit code: {}",
-            status.code().unwrap_or(-1)
-        )));
+        return Err(ParaError::setup_script(
+            script_path,
+            format!(
+                "exit code {}, output in {}",
+                status.code().unwrap_or(-1),
+                log_path.display()
+            ),
+        ));
     }
 
-    println!("✅ Setup script completed successfully");
+    println!("{}", theme::success("Setup script completed successfully"));
     Ok(())
 }
 
-pub fn execute(config: Config, args: DispatchArgs) -> Result<()> {
+/// Starting point for a session created with `--base`
+#[derive(Debug, Clone, PartialEq)]
+struct SessionBase {
+    /// Ref as the user named it, e.g. `origin/main`
+    symbolic: String,
+    /// Commit the ref resolved to when the session was created
+    commit: String,
+}
+
+/// Whether the base is fetched before it is resolved
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FetchMode {
+    Off,
+    /// `git.auto_fetch`: fetch and prune, falling back to the local ref with a
+    /// warning when the fetch fails
+    Auto,
+    /// `--fetch`: the fetch must succeed
+    Required,
+}
+
+impl FetchMode {
+    fn new(fetch: bool, auto_fetch: bool) -> Self {
+        match (fetch, auto_fetch) {
+            (true, _) => FetchMode::Required,
+            (false, true) => FetchMode::Auto,
+            (false, false) => FetchMode::Off,
+        }
+    }
+}
+
+/// Resolve `--base` (optionally fetching it first) to a pinned commit.
+///
+/// The remote to fetch from is `remote` (from `--remote`), else the one named
+/// in `base` such as `upstream/main`, else `default_remote`, else origin.
+fn resolve_session_base(
+    repo: &GitRepository,
+    base: &str,
+    fetch: FetchMode,
+    remote: Option<&str>,
+    default_remote: Option<&str>,
+    network: NetworkContext,
+) -> Result<SessionBase> {
+    if base == "HEAD" && repo.get_current_branch()? == "HEAD" {
+        return Err(ParaError::invalid_args(
+            "HEAD is detached. Pass a branch name or commit to --base instead",
+        ));
+    }
+
+    let local_base = || -> Result<SessionBase> {
+        let commit = repo
+            .resolve_commit(base)
+            .map_err(|_| unknown_base_error(repo, base))?;
+        Ok(SessionBase {
+            symbolic: base.to_string(),
+            commit,
+        })
+    };
+
+    match fetch {
+        FetchMode::Off => return local_base(),
+        FetchMode::Auto => {
+            return auto_fetch_base(repo, base, remote, default_remote, network)
+                .map_or_else(local_base, Ok)
+        }
+        FetchMode::Required => network.require_online("--fetch")?,
+    }
+
+    let (remote, reference) = fetch_source(repo, base, remote, default_remote)?;
+    repo.require_remote(remote)?;
+
+    repo.fetch(remote, reference)?;
+
+    let symbolic = format!("{remote}/{reference}");
+    let commit = repo
+        .resolve_commit(&symbolic)
+        .or_else(|_| repo.resolve_commit("FETCH_HEAD"))
+        .map_err(|_| unknown_base_error(repo, base))?;
+
+    Ok(SessionBase { symbolic, commit })
+}
+
+/// Remote and ref to fetch for `base`; accepts both `main` and `origin/main`
+fn fetch_source<'a>(
+    repo: &GitRepository,
+    base: &'a str,
+    remote: Option<&'a str>,
+    default_remote: Option<&'a str>,
+) -> Result<(&'a str, &'a str)> {
+    let remotes = repo.list_remotes()?;
+    let named = base
+        .split_once('/')
+        .filter(|(prefix, _)| remotes.iter().any(|r| r == prefix));
+    match (remote, named) {
+        (Some(remote), Some((prefix, _))) if prefix != remote => Err(ParaError::invalid_args(
+            format!("--base '{base}' names remote '{prefix}' but --remote is '{remote}'"),
+        )),
+        (_, Some((prefix, reference))) => Ok((prefix, reference)),
+        (Some(remote), None) => Ok((remote, base)),
+        (None, None) => Ok((default_remote.unwrap_or("origin"), base)),
+    }
+}
+
+/// The fetched remote ref for `base` with `git.auto_fetch`, or `None` to use
+/// the local ref. A local branch is only replaced by its remote counterpart
+/// when it is behind it, so unpushed local commits are never skipped.
+fn auto_fetch_base(
+    repo: &GitRepository,
+    base: &str,
+    remote: Option<&str>,
+    default_remote: Option<&str>,
+    network: NetworkContext,
+) -> Option<SessionBase> {
+    if network.require_online("git.auto_fetch").is_err() {
+        return None;
+    }
+    let (remote, reference) = fetch_source(repo, base, remote, default_remote).ok()?;
+    // Repositories without the remote have nothing to fetch
+    repo.require_remote(remote).ok()?;
+    // A base the daemon fetched within the interval needs no second fetch
+    if !prefetch::is_fresh(&repo.root, remote, reference) {
+        if let Err(e) = repo.fetch_prune(remote, reference) {
+            eprintln!("Warning: Automatic fetch failed, using the local '{base}': {e}");
+            return None;
+        }
+    }
+
+    let symbolic = format!("{remote}/{reference}");
+    let commit = repo.resolve_commit(&symbolic).ok()?;
+    let names_remote = base == symbolic;
+    if !names_remote {
+        if let Ok(local) = repo.resolve_commit(base) {
+            let behind =
+                execute_git_command(repo, &["merge-base", "--is-ancestor", &local, &commit])
+                    .is_ok();
+            if !behind {
+                return None;
+            }
+        }
+    }
+    Some(SessionBase { symbolic, commit })
+}
+
+fn unknown_base_error(repo: &GitRepository, base: &str) -> ParaError {
+    let similar = BranchManager::new(repo)
+        .similar_branches(base, 5)
+        .unwrap_or_default();
+
+    if similar.is_empty() {
+        ParaError::invalid_args(format!(
+            "Unknown base '{base}': no branch or commit with that name"
+        ))
+    } else {
+        ParaError::invalid_args(format!(
+            "Unknown base '{base}'. Similar branches: {}",
+            similar.join(", ")
+        ))
+    }
+}
+
+pub fn execute(ctx: &CommandContext, args: DispatchArgs) -> Result<()> {
+    let config = ctx.config();
     args.validate()?;
 
-    let (session_name, prompt) = args.resolve_prompt_and_session()?;
+    // `--retry <session>` reruns that session's task with its settings
+    let retry_source = match args.retry.as_deref() {
+        Some(original) => Some(RetrySource::load(&ctx.session_manager(), original)?),
+        None => None,
+    };
+    let args = match &retry_source {
+        Some(source) => inherit_retry_settings(args, &source.state),
+        None => args,
+    };
+    check_skip_permissions_allowed(config, args.dangerously_skip_permissions)?;
+    ensure_remote_supports_container(config, args.container)?;
+    if args.plan_only && args.container {
+        return Err(ParaError::invalid_args(
+            "--plan-only is not supported for container sessions",
+        ));
+    }
+
+    let (session_name, prompt) = match &retry_source {
+        Some(source) => (
+            Some(retry::retry_name(
+                &ctx.session_manager(),
+                &source.state.name,
+            )?),
+            source.prompt(args.retry_instructions().as_deref()),
+        ),
+        None => args.resolve_prompt_and_session()?,
+    };
 
-    validate_claude_code_ide(&config)?;
+    validate_claude_code_ide(config)?;
 
-    let git_service = GitService::discover()
+    let git_service = ctx
+        .git_service()
         .map_err(|e| ParaError::git_error(format!("Failed to discover git repository: {e}")))?;
+    ensure_repository_has_commits(git_service.repository(), args.allow_empty_repo)?;
     let repo_root = git_service.repository().root.clone();
 
-    let session_manager = SessionManager::new(&config);
+    // `--base <session>` stacks the new session on that session's branch
+    let stack_parent = match args.base.as_deref() {
+        Some(base) if ctx.session_manager().session_exists(base) => {
+            if args.fetch {
+                return Err(ParaError::invalid_args(format!(
+                    "--fetch cannot be used when --base names the session '{base}'"
+                )));
+            }
+            Some(stack::stack_target(&ctx.session_manager(), base)?)
+        }
+        _ => None,
+    };
+    let base_ref = stack_parent
+        .as_ref()
+        .map(|parent| parent.branch.as_str())
+        .or(args.base.as_deref());
+
+    // Stacked sessions start from the parent's local branch, which has no remote
+    let fetch = FetchMode::new(args.fetch, config.git.auto_fetch && stack_parent.is_none());
+    let session_base = match base_ref {
+        Some(base) => Some(resolve_session_base(
+            git_service.repository(),
+            base,
+            fetch,
+            args.remote.as_deref(),
+            config.git.default_remote.as_deref(),
+            NetworkContext::current(),
+        )?),
+        // Without --base the session starts from the current branch, unless
+        // auto_fetch finds that its remote counterpart is ahead
+        None if fetch == FetchMode::Auto => {
+            let repo = git_service.repository();
+            match repo.get_current_branch()?.as_str() {
+                "HEAD" => None,
+                branch => auto_fetch_base(
+                    repo,
+                    branch,
+                    args.remote.as_deref(),
+                    config.git.default_remote.as_deref(),
+                    NetworkContext::current(),
+                )
+                .filter(|fetched| {
+                    fetched.commit != repo.resolve_commit(branch).unwrap_or_default()
+                }),
+            }
+        }
+        None => None,
+    };
+
+    // Concurrent dispatches must not pick the same name or add worktrees at the
+    // same time, so the lock is held until the session state is saved
+    let session_manager = ctx.session_manager();
+    let creation_lock = session_manager.lock_creation()?;
     let session_name = match session_name {
         Some(name) => {
             validate_session_name(&name)?;
@@ -145,20 +418,47 @@ pub fn execute(config: Config, args: DispatchArgs) -> Result<()> {
             }
             name
         }
-        None => {
-            let existing_sessions = session_manager
-                .list_sessions()?
-                .into_iter()
-                .map(|s| s.name)
-                .collect::<Vec<String>>();
-            generate_unique_name(&existing_sessions)
-        }
+        None => session_manager.generate_session_name()?,
     };
 
+    let state_dir = session_manager.state_dir();
+    check_prompt_secrets(
+        config,
+        &prompt,
+        args.allow_secrets,
+        &[
+            state_dir
+                .join(format!("{session_name}.task"))
+                .display()
+                .to_string(),
+            state_dir
+                .join(format!("{session_name}.state"))
+                .display()
+                .to_string(),
+            "CLAUDE.local.md in the session worktree".to_string(),
+        ],
+    )?;
+
+    session_manager.check_storage(&repo_root)?;
+
     let branch_name = generate_friendly_branch_name(config.get_branch_prefix(), &session_name);
     let session_id = session_name.clone();
 
-    let mut session_manager = SessionManager::new(&config);
+    // Anything created below is removed again if a step fails before the
+    // session is fully set up, e.g. because the disk filled up
+    let mut rollback = CreationRollback::new(git_service, state_dir, &session_name);
+    rollback.track_branch(&branch_name);
+    rollback.track_state_files();
+
+    // Create the session branch up front so the worktree picks it up instead of HEAD
+    if let Some(base) = &session_base {
+        git_service
+            .branch_manager()
+            .create_branch_from_commit(&branch_name, &base.commit)?;
+    }
+
+    let mut session_manager = ctx.session_manager();
+    let mut progress = StepReporter::new(if args.plan_only { 2 } else { 3 });
 
     // Track whether we're using Docker and network isolation settings
     let (is_container, network_isolation, _allowed_domains) = if args.container {
@@ -182,6 +482,8 @@ pub fn execute(config: Config, args: DispatchArgs) -> Result<()> {
             args.docker_image.clone(),
             !args.no_forward_keys,
         );
+        rollback.checkpoint()?;
+        progress.step("Creating container session");
         let session = session_manager.create_docker_session_with_flags(
             session_id.clone(),
             &docker_manager,
@@ -189,19 +491,50 @@ pub fn execute(config: Config, args: DispatchArgs) -> Result<()> {
             &args.docker_args,
             args.dangerously_skip_permissions,
         )?;
+        let mut session = match &session_base {
+            Some(base) => {
+                let mut session = session;
+                session.parent_branch = Some(base.symbolic.clone());
+                session.base_commit = Some(base.commit.clone());
+                session_manager.save_state(&session)?;
+                session
+            }
+            None => session,
+        };
+        drop(creation_lock);
+        rollback.track_worktree(&session.worktree_path);
 
         // Write task file
         let state_dir = session_manager.state_dir();
         let task_file = state_dir.join(format!("{session_id}.task"));
         fs::write(&task_file, &prompt)
-            .map_err(|e| ParaError::fs_error(format!("Failed to write task file: {e}")))?;
+            .map_err(|e| ParaError::from_io("write task file", &task_file, e))?;
+
+        rollback.checkpoint()?;
+        progress.step("Preparing worktree");
+        copy_untracked_into_worktree(
+            config,
+            &repo_root,
+            &session.worktree_path,
+            &args.copy,
+            args.copy_large,
+        )?;
+        fix_worktree_hooks_path(&repo_root, &session.worktree_path, args.no_hook_fixup);
+        record_merge_mode(&session_manager, &mut session, args.merge_mode)?;
+        record_template(&session_manager, &mut session, args.template.as_deref())?;
+        setup_auto_checkpoints(config, &session_manager, &session, args.no_auto_commit)?;
 
         // Create CLAUDE.local.md in the session directory
-        create_claude_local_md(&session.worktree_path, &session.name)?;
+        let mut claude_local = ClaudeLocalContext::for_session(&session);
+        claude_local.task.get_or_insert_with(|| prompt.clone());
+        write_claude_local_md(&claude_local)?;
+        add_conflict_hotspots(config, session_manager.state_dir(), &session)?;
+        rollback.checkpoint()?;
+        rollback.commit();
 
         // Run setup script if specified
         if let Some(setup_script) =
-            get_setup_script_path(&args.setup_script, &repo_root, &config, true)
+            get_setup_script_path(&args.setup_script, &repo_root, config, true)
         {
             docker_manager
                 .run_setup_script(&session.name, &setup_script)
@@ -209,6 +542,7 @@ pub fn execute(config: Config, args: DispatchArgs) -> Result<()> {
         }
 
         // Launch IDE connected to container with initial prompt
+        progress.step("Launching IDE");
         docker_manager
             .launch_container_ide(&session, Some(&prompt), args.dangerously_skip_permissions)
             .map_err(|e| ParaError::docker_error(format!("Failed to launch IDE: {e}")))?;
@@ -217,7 +551,7 @@ pub fn execute(config: Config, args: DispatchArgs) -> Result<()> {
         if let Err(e) = crate::core::daemon::client::register_container_session(
             &session.name,
             &session.worktree_path,
-            &config,
+            config,
         ) {
             eprintln!("Warning: Failed to register with daemon: {e}");
             // Continue anyway - daemon might not be running
@@ -226,8 +560,8 @@ pub fn execute(config: Config, args: DispatchArgs) -> Result<()> {
         (true, network_isolation, allowed_domains)
     } else {
         // Create regular worktree session
-        let subtrees_path = repo_root.join(&config.directories.subtrees_dir);
-        let session_path = subtrees_path.join(&session_id);
+        let subtrees_path = config.subtrees_path(&repo_root)?;
+        let session_path = SessionPaths::worktree_path(config, &repo_root, &session_id)?;
 
         if !subtrees_path.exists() {
             fs::create_dir_all(&subtrees_path).map_err(|e| {
@@ -235,18 +569,27 @@ pub fn execute(config: Config, args: DispatchArgs) -> Result<()> {
             })?;
         }
 
-        // Get the current branch as the parent branch
-        let parent_branch = git_service
-            .repository()
-            .get_current_branch()
-            .unwrap_or_else(|_| "main".to_string());
+        // The parent branch is the --base ref or otherwise the current branch
+        let parent_branch = match &session_base {
+            Some(base) => base.symbolic.clone(),
+            None => git_service
+                .repository()
+                .get_current_branch()
+                .unwrap_or_else(|_| "main".to_string()),
+        };
 
-        git_service
+        progress.step("Creating worktree");
+        let session_path = rollback
             .create_worktree(&branch_name, &session_path)
             .map_err(|e| ParaError::git_error(format!("Failed to create worktree: {e}")))?;
+        apply_worktree_identity(
+            &session_path,
+            config.git.author_name.as_deref(),
+            config.git.author_email.as_deref(),
+        )?;
 
         // Resolve sandbox settings using the resolver
-        let resolver = SandboxResolver::new(&config);
+        let resolver = SandboxResolver::new(config);
         let sandbox_settings = resolver.resolve_with_network(
             args.sandbox_args.sandbox,
             args.sandbox_args.no_sandbox,
@@ -269,36 +612,88 @@ pub fn execute(config: Config, args: DispatchArgs) -> Result<()> {
             },
         );
 
+        let setup_script = get_setup_script_path(&args.setup_script, &repo_root, config, false);
+        if args.plan_only {
+            session_state.status = SessionStatus::Planned;
+        }
+        if args.defer_setup {
+            // `para launch` may run from another directory
+            session_state.deferred_setup_script = setup_script
+                .clone()
+                .map(|script| fs::canonicalize(&script).unwrap_or(script));
+        }
         session_state.task_description = Some(prompt.clone());
+        session_state.template = args.template.clone();
+        session_state.merge_mode = args
+            .merge_mode
+            .unwrap_or_else(|| config.get_default_merge_mode());
+        session_state.base_commit = match &session_base {
+            Some(base) => Some(base.commit.clone()),
+            None => git_service
+                .repository()
+                .resolve_commit(&session_state.branch)
+                .ok(),
+        };
+        rollback.checkpoint()?;
         session_manager.save_state(&session_state)?;
+        drop(creation_lock);
+        apply_starting_changes(
+            git_service,
+            &session_manager,
+            &session_state,
+            args.apply_patch.as_deref(),
+            args.apply_from.as_deref(),
+        )?;
 
         // Write task file
         let state_dir = session_manager.state_dir();
         let task_file = state_dir.join(format!("{session_id}.task"));
         fs::write(&task_file, &prompt)
-            .map_err(|e| ParaError::fs_error(format!("Failed to write task file: {e}")))?;
+            .map_err(|e| ParaError::from_io("write task file", &task_file, e))?;
 
-        create_claude_local_md(&session_state.worktree_path, &session_state.name)?;
+        rollback.checkpoint()?;
+        progress.step("Preparing worktree");
+        copy_untracked_into_worktree(
+            config,
+            &repo_root,
+            &session_state.worktree_path,
+            &args.copy,
+            args.copy_large,
+        )?;
+        fix_worktree_hooks_path(&repo_root, &session_state.worktree_path, args.no_hook_fixup);
+        setup_auto_checkpoints(
+            config,
+            &session_manager,
+            &session_state,
+            args.no_auto_commit,
+        )?;
+
+        write_claude_local_md(&ClaudeLocalContext::for_session(&session_state))?;
+        add_conflict_hotspots(config, session_manager.state_dir(), &session_state)?;
+        rollback.checkpoint()?;
+        rollback.commit();
 
         // Run setup script if specified
-        if let Some(setup_script) =
-            get_setup_script_path(&args.setup_script, &repo_root, &config, false)
-        {
+        if let Some(setup_script) = setup_script.filter(|_| !args.defer_setup) {
             run_worktree_setup_script(
                 &setup_script,
                 &session_state.name,
                 &session_state.worktree_path,
+                session_manager.state_dir(),
             )?;
         }
 
-        create_launch_metadata(&config, &session_state.worktree_path)?;
-        launch_claude_code(
-            &config,
-            &session_state.worktree_path,
-            &prompt,
-            args.dangerously_skip_permissions,
-            &sandbox_settings,
-        )?;
+        if !args.plan_only {
+            progress.step("Launching IDE");
+            create_launch_metadata(config, &session_state.worktree_path)?;
+            launch_claude_code(
+                config,
+                &session_state.worktree_path,
+                &prompt,
+                args.dangerously_skip_permissions,
+                &sandbox_settings,
+            )?;
+        }
 
         (
             sandbox_settings.enabled && sandbox_settings.profile == "standard-proxied",
@@ -307,6 +702,15 @@ pub fn execute(config: Config, args: DispatchArgs) -> Result<()> {
         )
     };
 
+    progress.finish();
+
+    if let Some(parent) = &stack_parent {
+        stack::link(&session_manager, &parent.name, &session_id)?;
+    }
+    if let Some(source) = &retry_source {
+        retry::record(&session_manager, &session_id, &source.state.name)?;
+    }
+
     // Get session state for display
     let session_state = session_manager
         .list_sessions()?
@@ -314,10 +718,20 @@ pub fn execute(config: Config, args: DispatchArgs) -> Result<()> {
         .find(|s| s.name == session_id)
         .ok_or_else(|| ParaError::session_not_found(&session_id))?;
 
-    println!(
-        "✅ Created session '{}' with Claude Code",
-        session_state.name
-    );
+    if args.plan_only {
+        println!(
+            "{}",
+            theme::success(format!("Planned session '{}'", session_state.name))
+        );
+    } else {
+        println!(
+            "{}",
+            theme::success(format!(
+                "Created session '{}' with Claude Code",
+                session_state.name
+            ))
+        );
+    }
     if is_container {
         println!("   Container: para-{}", session_state.name);
 
@@ -332,23 +746,113 @@ pub fn execute(config: Config, args: DispatchArgs) -> Result<()> {
 
         // Show network isolation warning if it's disabled
         if !network_isolation {
-            println!("   ⚠️  Network isolation: OFF (use --allow-domains to enable)");
+            println!(
+                "   {}",
+                theme::warning("Network isolation: OFF (use --allow-domains to enable)")
+            );
+            ci::annotate_warning("Network isolation is off for this container session", None);
         }
 
         // Show API key warning if forwarding keys to custom images
         if !args.no_forward_keys && args.docker_image.is_some() {
             println!(
-                "   ⚠️  API keys: Forwarding to custom image (use --no-forward-keys to disable)"
+                "   {}",
+                theme::warning(
+                    "API keys: Forwarding to custom image (use --no-forward-keys to disable)"
+                )
             );
             println!("      Security: Only use trusted images when forwarding API keys");
         }
     }
     println!("   Branch: {}", session_state.branch);
-    println!("   Worktree: {}", session_state.worktree_path.display());
+    if let Some(base) = &session_base {
+        println!("   Base: {} ({})", base.symbolic, &base.commit[..7]);
+    }
+    if let Some(parent) = &stack_parent {
+        println!("   Stacked on: {}", parent.name);
+    }
+    if let Some(source) = &retry_source {
+        println!("   Retry of: {}", source.state.name);
+    }
+    println!("   Worktree: {}", path_link(&session_state.worktree_path));
+    if args.plan_only {
+        if let Some(script) = &session_state.deferred_setup_script {
+            println!("   Setup script (deferred): {}", script.display());
+        }
+        println!("   Start it with: para launch {}", session_state.name);
+    }
+
+    ci::set_outputs(&[
+        ("session", &session_state.name),
+        ("branch", &session_state.branch),
+    ]);
+
+    if let Some(source) = retry_source.filter(|_| args.cancel_old) {
+        cancel_retried_session(
+            config,
+            git_service,
+            &mut session_manager,
+            &source.state.name,
+        );
+    }
 
     Ok(())
 }
 
+/// Use the retried session's settings for the options not given on the
+/// command line
+fn inherit_retry_settings(mut args: DispatchArgs, original: &SessionState) -> DispatchArgs {
+    args.container |= original.is_container();
+    args.dangerously_skip_permissions |= original.skips_permissions();
+    args.no_auto_commit |= original.no_auto_commit.unwrap_or(false);
+    args.merge_mode = args.merge_mode.or(Some(original.merge_mode));
+    args.template = args.template.take().or_else(|| original.template.clone());
+
+    let sandbox = &mut args.sandbox_args;
+    if !sandbox.sandbox && !sandbox.no_sandbox {
+        match original.sandbox_enabled {
+            Some(true) => sandbox.sandbox = true,
+            Some(false) => sandbox.no_sandbox = true,
+            None => {}
+        }
+    }
+    sandbox.sandbox_profile = sandbox
+        .sandbox_profile
+        .take()
+        .or_else(|| original.sandbox_profile.clone());
+    args
+}
+
+/// `--cancel-old`: the new session is already running, so a failed cancel
+/// only warns
+fn cancel_retried_session(
+    config: &Config,
+    git_service: &GitService,
+    session_manager: &mut SessionManager,
+    name: &str,
+) {
+    if !session_manager.session_exists(name) {
+        println!("   Session '{name}' is already cancelled");
+        return;
+    }
+    let cancelled = session_manager.load_state(name).and_then(|session| {
+        check_stacked_children(session_manager, &session, false)?;
+        cancel_and_archive_session(
+            config,
+            git_service,
+            session_manager,
+            name,
+            false,
+            false,
+            false,
+        )
+    });
+    match cancelled {
+        Ok(archive) => println!("   Cancelled '{name}', archived as '{}'", archive.branch),
+        Err(e) => eprintln!("Warning: Failed to cancel session '{name}': {e}"),
+    }
+}
+
 fn validate_claude_code_ide(config: &Config) -> Result<()> {
     if (config.ide.command.to_lowercase() == "claude"
         || config.ide.command.to_lowercase() == "claude-code")
@@ -363,7 +867,7 @@ fn validate_claude_code_ide(config: &Config) -> Result<()> {
     )))
 }
 
-fn launch_claude_code(
+pub(crate) fn launch_claude_code(
     config: &Config,
     session_path: &Path,
     prompt: &str,
@@ -388,10 +892,8 @@ fn launch_claude_code(
     crate::core::claude_launcher::launch_claude_with_context(config, session_path, options)
 }
 
-fn create_launch_metadata(config: &Config, session_path: &Path) -> Result<()> {
-    let state_dir = std::env::current_dir()
-        .unwrap_or_else(|_| PathBuf::from("."))
-        .join(".para_state");
+pub(crate) fn create_launch_metadata(config: &Config, session_path: &Path) -> Result<()> {
+    let state_dir = config.resolved_state_dir();
 
     fs::create_dir_all(&state_dir)
         .map_err(|e| ParaError::fs_error(format!("Failed to create state directory: {e}")))?;
@@ -413,43 +915,142 @@ fn create_launch_metadata(config: &Config, session_path: &Path) -> Result<()> {
     Ok(())
 }
 
-impl DispatchArgs {
-    pub fn resolve_prompt_and_session(&self) -> Result<(Option<String>, String)> {
-        // Priority order:
-        // 1. File flag (highest priority)
-        // 2. Explicit arguments
-        // 3. Stdin input (lowest priority)
+/// How long to wait for piped input when a prompt was also given on the command line
+const STDIN_GRACE_PERIOD_MS: i32 = 500;
 
-        // If we have a --file argument, use it directly without checking stdin
-        // This prevents blocking in non-terminal environments like MCP
-        if self.file.is_some() {
-            return self.resolve_prompt_and_session_no_stdin();
-        }
+/// Read piped stdin once per process; later calls return the same content.
+///
+/// When the command line already carries a prompt, stdin is only read if it
+/// becomes readable within a short grace period. Callers like the MCP server
+/// keep stdin open without ever writing to it, and must not block.
+pub(crate) fn read_piped_stdin(has_other_input: bool) -> Result<Option<String>> {
+    static PIPED_INPUT: OnceLock<Option<String>> = OnceLock::new();
 
-        // If we have explicit arguments, use them instead of checking stdin
-        // This fixes the MCP issue where stdin is not a terminal but we have valid args
-        if self.name_or_prompt.is_some() || self.prompt.is_some() {
-            return self.resolve_prompt_and_session_no_stdin();
-        }
+    if let Some(input) = PIPED_INPUT.get() {
+        return Ok(input.clone());
+    }
 
-        // Only check stdin if we don't have file flag or explicit arguments
-        if !io::stdin().is_terminal() {
+    let input =
+        if io::stdin().is_terminal() || (has_other_input && !stdin_ready(STDIN_GRACE_PERIOD_MS)) {
+            None
+        } else {
             let mut buffer = String::new();
             io::stdin().read_to_string(&mut buffer).map_err(|e| {
                 ParaError::file_operation(format!("Failed to read from stdin: {e}"))
             })?;
+            Some(buffer)
+        };
+
+    Ok(PIPED_INPUT.get_or_init(|| input).clone())
+}
+
+fn stdin_ready(timeout_ms: i32) -> bool {
+    let mut fds = libc::pollfd {
+        fd: libc::STDIN_FILENO,
+        events: libc::POLLIN,
+        revents: 0,
+    };
+    // Readable covers both pending data and a closed pipe (EOF)
+    unsafe { libc::poll(&mut fds, 1, timeout_ms) > 0 }
+}
+
+/// Join prompt parts into one prompt. A single part is used as-is; several
+/// parts each get a `--- <source> ---` header so they stay distinguishable.
+pub(crate) fn combine_prompt_parts(parts: Vec<(String, String)>) -> String {
+    if parts.len() == 1 {
+        return parts
+            .into_iter()
+            .next()
+            .map(|(_, text)| text)
+            .unwrap_or_default();
+    }
+
+    parts
+        .iter()
+        .map(|(source, text)| format!("--- {source} ---\n{}", text.trim_end()))
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+impl DispatchArgs {
+    /// Resolve the session name and prompt from positional arguments, `--file`
+    /// and piped stdin.
+    ///
+    /// Without piped input, `--file` takes precedence over positional prompts.
+    /// With piped input, every source is combined with stdin last, and the first
+    /// positional is the session name unless it looks like a file path or prompt text.
+    /// A template's prompt prefix goes in front of the result.
+    pub fn resolve_prompt_and_session(&self) -> Result<(Option<String>, String)> {
+        let stdin = if self.no_stdin {
+            None
+        } else {
+            read_piped_stdin(self.has_command_line_input())?
+        };
+
+        self.resolve_with_stdin(stdin)
+    }
+
+    /// Extra instructions for `--retry`, from the positional arguments
+    fn retry_instructions(&self) -> Option<String> {
+        let parts: Vec<&str> = [&self.name_or_prompt, &self.prompt]
+            .into_iter()
+            .flatten()
+            .map(String::as_str)
+            .collect();
+        (!parts.is_empty()).then(|| parts.join("\n\n"))
+    }
+
+    /// Whether a positional argument names the prompt file rather than holding
+    /// the prompt. Text from `--prompt` is always the prompt itself.
+    fn names_prompt_file(&self, arg: &str) -> bool {
+        !self.literal_prompt && is_likely_file_path(arg, self.prompt_is_file)
+    }
 
-            if buffer.trim().is_empty() {
+    fn has_command_line_input(&self) -> bool {
+        self.name_or_prompt.is_some() || self.prompt.is_some() || self.file.is_some()
+    }
+
+    fn resolve_with_stdin(&self, stdin: Option<String>) -> Result<(Option<String>, String)> {
+        let (session, prompt) = self.combine_sources(stdin)?;
+        Ok((
+            session,
+            prefix_prompt(self.prompt_prefix.as_deref(), prompt),
+        ))
+    }
+
+    fn combine_sources(&self, stdin: Option<String>) -> Result<(Option<String>, String)> {
+        let stdin = match stdin {
+            Some(input) if !input.trim().is_empty() => input,
+            Some(_) if !self.has_command_line_input() => {
                 return Err(ParaError::invalid_args("Piped input is empty"));
             }
+            _ => return self.resolve_prompt_and_session_no_stdin(),
+        };
+
+        let mut session = None;
+        let mut parts = Vec::new();
 
-            // When using stdin, the first positional argument (if any) is the session name
-            return Ok((self.name_or_prompt.clone(), buffer));
+        let positionals = [&self.name_or_prompt, &self.prompt];
+        for (index, arg) in positionals.into_iter().flatten().enumerate() {
+            if self.names_prompt_file(arg) {
+                parts.push((arg.clone(), read_detected_prompt_file(arg)?));
+            } else if index == 0 && validate_session_name(arg).is_ok() {
+                session = Some(arg.clone());
+            } else {
+                parts.push(("prompt".to_string(), arg.clone()));
+            }
+        }
+
+        if let Some(ref file_path) = self.file {
+            parts.push((
+                file_path.display().to_string(),
+                read_prompt_file(file_path)?,
+            ));
         }
 
-        // No file, no explicit args, no stdin input - fall back to no_stdin method
-        // which will return appropriate error
-        self.resolve_prompt_and_session_no_stdin()
+        parts.push(("stdin".to_string(), stdin));
+
+        Ok((session, combine_prompt_parts(parts)))
     }
 
     fn resolve_prompt_and_session_no_stdin(&self) -> Result<(Option<String>, String)> {
@@ -466,26 +1067,19 @@ impl DispatchArgs {
             }
 
             (Some(arg), None, None) => {
-                if is_likely_file_path(arg) {
-                    let prompt = read_file_content(Path::new(arg))?;
-                    if prompt.trim().is_empty() {
-                        return Err(ParaError::file_not_found(format!("file is empty: {arg}")));
-                    }
-                    Ok((None, prompt))
+                if self.names_prompt_file(arg) {
+                    Ok((None, read_detected_prompt_file(arg)?))
                 } else {
                     Ok((None, arg.clone()))
                 }
             }
 
             (Some(session), Some(prompt_or_file), None) => {
-                if is_likely_file_path(prompt_or_file) {
-                    let prompt = read_file_content(Path::new(prompt_or_file))?;
-                    if prompt.trim().is_empty() {
-                        return Err(ParaError::file_not_found(format!(
-                            "file is empty: {prompt_or_file}"
-                        )));
-                    }
-                    Ok((Some(session.clone()), prompt))
+                if self.names_prompt_file(prompt_or_file) {
+                    Ok((
+                        Some(session.clone()),
+                        read_detected_prompt_file(prompt_or_file)?,
+                    ))
                 } else {
                     Ok((Some(session.clone()), prompt_or_file.clone()))
                 }
@@ -502,7 +1096,28 @@ impl DispatchArgs {
     }
 }
 
-fn is_likely_file_path(input: &str) -> bool {
+/// Read a prompt file, rejecting empty files
+fn read_prompt_file(path: &Path) -> Result<String> {
+    let prompt = read_file_content(path)?;
+    if prompt.trim().is_empty() {
+        return Err(ParaError::file_not_found(format!(
+            "file is empty: {}",
+            path.display()
+        )));
+    }
+    Ok(prompt)
+}
+
+/// Read a prompt file that a positional argument was detected as, saying so
+/// since prompt text can name a file by accident
+fn read_detected_prompt_file(arg: &str) -> Result<String> {
+    eprintln!("Note: reading the prompt from the file '{arg}' (pass text with --prompt)");
+    read_prompt_file(Path::new(arg))
+}
+
+/// An argument is a prompt file if a file of that name exists, or if
+/// `--prompt-is-file` was given and it looks like a path
+fn is_likely_file_path(input: &str, prompt_is_file: bool) -> bool {
     if input.is_empty() {
         return false;
     }
@@ -511,6 +1126,16 @@ fn is_likely_file_path(input: &str) -> bool {
         return true;
     }
 
+    prompt_is_file && looks_like_path(input)
+}
+
+/// A single word with a directory separator or a prompt file extension,
+/// e.g. `tasks/auth.md`, but not a URL
+fn looks_like_path(input: &str) -> bool {
+    if input.contains(char::is_whitespace) {
+        return false;
+    }
+
     if input.starts_with("http://")
         || input.starts_with("https://")
         || input.starts_with("ftp://")
@@ -522,19 +1147,8 @@ fn is_likely_file_path(input: &str) -> bool {
         return false;
     }
 
-    if input.contains('/') {
-        if (input.contains(" http://")
-            || input.contains(" https://")
-            || input.contains(" ftp://")
-            || input.contains(" ssh://"))
-            && input.contains(' ')
-        {
-            return false;
-        }
-        return true;
-    }
-
-    input.ends_with(".txt")
+    input.contains('/')
+        || input.ends_with(".txt")
         || input.ends_with(".md")
         || input.ends_with(".rst")
         || input.ends_with(".org")
@@ -591,7 +1205,9 @@ fn read_file_content(path: &Path) -> Result<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::cli::commands::common::create_claude_local_md;
     use crate::cli::parser::SandboxArgs;
+    use crate::core::git::MergeMode;
     use std::fs;
     use tempfile::TempDir;
 
@@ -601,34 +1217,140 @@ mod tests {
         file_path
     }
 
+    /// Arguments of a plain `dispatch` run, to override with `..inline_args()`
+    fn inline_args() -> DispatchArgs {
+        DispatchArgs {
+            name_or_prompt: None,
+            prompt: None,
+            file: None,
+            dangerously_skip_permissions: false,
+            container: false,
+            allow_domains: None,
+            docker_args: vec![],
+            setup_script: None,
+            docker_image: None,
+            no_forward_keys: false,
+            sandbox_args: SandboxArgs {
+                sandbox: false,
+                no_sandbox: false,
+                sandbox_profile: None,
+                sandbox_no_network: false,
+                allowed_domains: vec![],
+            },
+            base: None,
+            fetch: false,
+            allow_empty_repo: false,
+            no_stdin: false,
+            copy: vec![],
+            copy_large: false,
+            no_auto_commit: false,
+            merge_mode: None,
+            remote: None,
+            apply_patch: None,
+            apply_from: None,
+            template: None,
+            prompt_prefix: None,
+            allow_secrets: false,
+            retry: None,
+            cancel_old: false,
+            no_hook_fixup: false,
+            plan_only: false,
+            defer_setup: false,
+            prompt_is_file: false,
+            literal_prompt: false,
+        }
+    }
+
     #[test]
     fn test_is_likely_file_path() {
-        // File paths with separators
-        assert!(is_likely_file_path("path/to/file"));
-        assert!(is_likely_file_path("./file.txt"));
-        assert!(is_likely_file_path("../file.md"));
-
-        // Common file extensions
-        assert!(is_likely_file_path("prompt.txt"));
-        assert!(is_likely_file_path("requirements.md"));
-        assert!(is_likely_file_path("task.prompt"));
-        assert!(is_likely_file_path("template.tmpl"));
-
-        // URLs should not be file paths
-        assert!(!is_likely_file_path("http://example.com"));
-        assert!(!is_likely_file_path("https://github.com/user/repo"));
-        assert!(!is_likely_file_path("ftp://server.com"));
-
-        // Text with URLs should not be file paths
-        assert!(!is_likely_file_path(
-            "Check out https://example.com for more info"
-        ));
-        assert!(!is_likely_file_path("Visit http://test.com or see docs"));
+        // Path-like arguments that do not exist are files only with --prompt-is-file
+        for path in [
+            "path/to/file",
+            "./file.txt",
+            "../file.md",
+            "prompt.txt",
+            "requirements.md",
+            "task.prompt",
+            "template.tmpl",
+        ] {
+            assert!(!is_likely_file_path(path, false), "{path}");
+            assert!(is_likely_file_path(path, true), "{path}");
+        }
 
-        // Regular prompts should not be file paths
-        assert!(!is_likely_file_path("implement user authentication"));
-        assert!(!is_likely_file_path("add login form"));
-        assert!(!is_likely_file_path(""));
+        // URLs and prose are never file paths
+        for text in [
+            "http://example.com",
+            "https://github.com/user/repo",
+            "ftp://server.com",
+            "Check out https://example.com for more info",
+            "Visit http://test.com or see docs",
+            "implement user authentication",
+            "add login form",
+            "",
+        ] {
+            assert!(!is_likely_file_path(text, false), "{text}");
+            assert!(!is_likely_file_path(text, true), "{text}");
+        }
+    }
+
+    #[test]
+    fn test_prose_with_slashes_is_a_prompt() {
+        for prompt in [
+            "fix bug in src/main.rs",
+            "support and/or in the query parser",
+            "notes.txt",
+        ] {
+            assert!(!is_likely_file_path(prompt, false), "{prompt}");
+            let args = DispatchArgs {
+                name_or_prompt: Some(prompt.to_string()),
+                ..inline_args()
+            };
+            assert_eq!(
+                args.resolve_with_stdin(None).unwrap(),
+                (None, prompt.to_string())
+            );
+        }
+        // With the hint, prose stays prose but a missing path is an error
+        assert!(!is_likely_file_path("fix bug in src/main.rs", true));
+        let args = DispatchArgs {
+            name_or_prompt: Some("tasks/missing.md".to_string()),
+            prompt_is_file: true,
+            ..inline_args()
+        };
+        let error = args.resolve_with_stdin(None).unwrap_err().to_string();
+        assert!(error.contains("file not found"), "{error}");
+    }
+
+    #[test]
+    fn test_existing_file_without_extension_is_detected() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = create_test_file(&temp_dir, "TASK", "implement the importer");
+        let path = file_path.to_str().unwrap();
+        assert!(is_likely_file_path(path, false));
+
+        let args = DispatchArgs {
+            name_or_prompt: Some("importer".to_string()),
+            prompt: Some(path.to_string()),
+            ..inline_args()
+        };
+        assert_eq!(
+            args.resolve_with_stdin(None).unwrap(),
+            (
+                Some("importer".to_string()),
+                "implement the importer".to_string()
+            )
+        );
+
+        // Text from --prompt is never read as a file
+        let args = DispatchArgs {
+            name_or_prompt: Some(path.to_string()),
+            literal_prompt: true,
+            ..inline_args()
+        };
+        assert_eq!(
+            args.resolve_with_stdin(None).unwrap(),
+            (None, path.to_string())
+        );
     }
 
     #[test]
@@ -651,6 +1373,27 @@ mod tests {
                 sandbox_no_network: false,
                 allowed_domains: vec![],
             },
+            base: None,
+            fetch: false,
+            allow_empty_repo: false,
+            no_stdin: false,
+            copy: vec![],
+            copy_large: false,
+            no_auto_commit: false,
+            merge_mode: None,
+            remote: None,
+            apply_patch: None,
+            apply_from: None,
+            template: None,
+            prompt_prefix: None,
+            allow_secrets: false,
+            retry: None,
+            cancel_old: false,
+            no_hook_fixup: false,
+            plan_only: false,
+            defer_setup: false,
+            prompt_is_file: false,
+            literal_prompt: false,
         };
 
         let result = args.resolve_prompt_and_session_no_stdin().unwrap();
@@ -678,6 +1421,27 @@ mod tests {
                 sandbox_no_network: false,
                 allowed_domains: vec![],
             },
+            base: None,
+            fetch: false,
+            allow_empty_repo: false,
+            no_stdin: false,
+            copy: vec![],
+            copy_large: false,
+            no_auto_commit: false,
+            merge_mode: None,
+            remote: None,
+            apply_patch: None,
+            apply_from: None,
+            template: None,
+            prompt_prefix: None,
+            allow_secrets: false,
+            retry: None,
+            cancel_old: false,
+            no_hook_fixup: false,
+            plan_only: false,
+            defer_setup: false,
+            prompt_is_file: false,
+            literal_prompt: false,
         };
 
         let result = args.resolve_prompt_and_session_no_stdin().unwrap();
@@ -708,6 +1472,27 @@ mod tests {
                 sandbox_no_network: false,
                 allowed_domains: vec![],
             },
+            base: None,
+            fetch: false,
+            allow_empty_repo: false,
+            no_stdin: false,
+            copy: vec![],
+            copy_large: false,
+            no_auto_commit: false,
+            merge_mode: None,
+            remote: None,
+            apply_patch: None,
+            apply_from: None,
+            template: None,
+            prompt_prefix: None,
+            allow_secrets: false,
+            retry: None,
+            cancel_old: false,
+            no_hook_fixup: false,
+            plan_only: false,
+            defer_setup: false,
+            prompt_is_file: false,
+            literal_prompt: false,
         };
 
         let result = args.resolve_prompt_and_session_no_stdin().unwrap();
@@ -739,6 +1524,27 @@ mod tests {
                 sandbox_no_network: false,
                 allowed_domains: vec![],
             },
+            base: None,
+            fetch: false,
+            allow_empty_repo: false,
+            no_stdin: false,
+            copy: vec![],
+            copy_large: false,
+            no_auto_commit: false,
+            merge_mode: None,
+            remote: None,
+            apply_patch: None,
+            apply_from: None,
+            template: None,
+            prompt_prefix: None,
+            allow_secrets: false,
+            retry: None,
+            cancel_old: false,
+            no_hook_fixup: false,
+            plan_only: false,
+            defer_setup: false,
+            prompt_is_file: false,
+            literal_prompt: false,
         };
 
         let result = args.resolve_prompt_and_session_no_stdin().unwrap();
@@ -770,6 +1576,27 @@ mod tests {
                 sandbox_no_network: false,
                 allowed_domains: vec![],
             },
+            base: None,
+            fetch: false,
+            allow_empty_repo: false,
+            no_stdin: false,
+            copy: vec![],
+            copy_large: false,
+            no_auto_commit: false,
+            merge_mode: None,
+            remote: None,
+            apply_patch: None,
+            apply_from: None,
+            template: None,
+            prompt_prefix: None,
+            allow_secrets: false,
+            retry: None,
+            cancel_old: false,
+            no_hook_fixup: false,
+            plan_only: false,
+            defer_setup: false,
+            prompt_is_file: false,
+            literal_prompt: false,
         };
 
         let result = args.resolve_prompt_and_session_no_stdin().unwrap();
@@ -800,6 +1627,27 @@ mod tests {
                 sandbox_no_network: false,
                 allowed_domains: vec![],
             },
+            base: None,
+            fetch: false,
+            allow_empty_repo: false,
+            no_stdin: false,
+            copy: vec![],
+            copy_large: false,
+            no_auto_commit: false,
+            merge_mode: None,
+            remote: None,
+            apply_patch: None,
+            apply_from: None,
+            template: None,
+            prompt_prefix: None,
+            allow_secrets: false,
+            retry: None,
+            cancel_old: false,
+            no_hook_fixup: false,
+            plan_only: false,
+            defer_setup: false,
+            prompt_is_file: false,
+            literal_prompt: false,
         };
 
         let result = args.resolve_prompt_and_session_no_stdin();
@@ -827,6 +1675,27 @@ mod tests {
                 sandbox_no_network: false,
                 allowed_domains: vec![],
             },
+            base: None,
+            fetch: false,
+            allow_empty_repo: false,
+            no_stdin: false,
+            copy: vec![],
+            copy_large: false,
+            no_auto_commit: false,
+            merge_mode: None,
+            remote: None,
+            apply_patch: None,
+            apply_from: None,
+            template: None,
+            prompt_prefix: None,
+            allow_secrets: false,
+            retry: None,
+            cancel_old: false,
+            no_hook_fixup: false,
+            plan_only: false,
+            defer_setup: false,
+            prompt_is_file: false,
+            literal_prompt: false,
         };
 
         let result = args.resolve_prompt_and_session_no_stdin();
@@ -873,6 +1742,27 @@ mod tests {
                 sandbox_no_network: false,
                 allowed_domains: vec![],
             },
+            base: None,
+            fetch: false,
+            allow_empty_repo: false,
+            no_stdin: false,
+            copy: vec![],
+            copy_large: false,
+            no_auto_commit: false,
+            merge_mode: None,
+            remote: None,
+            apply_patch: None,
+            apply_from: None,
+            template: None,
+            prompt_prefix: None,
+            allow_secrets: false,
+            retry: None,
+            cancel_old: false,
+            no_hook_fixup: false,
+            plan_only: false,
+            defer_setup: false,
+            prompt_is_file: false,
+            literal_prompt: false,
         };
 
         // The resolve_prompt_and_session method checks stdin, but when --file is provided
@@ -909,6 +1799,27 @@ mod tests {
                 sandbox_no_network: false,
                 allowed_domains: vec![],
             },
+            base: None,
+            fetch: false,
+            allow_empty_repo: false,
+            no_stdin: false,
+            copy: vec![],
+            copy_large: false,
+            no_auto_commit: false,
+            merge_mode: None,
+            remote: None,
+            apply_patch: None,
+            apply_from: None,
+            template: None,
+            prompt_prefix: None,
+            allow_secrets: false,
+            retry: None,
+            cancel_old: false,
+            no_hook_fixup: false,
+            plan_only: false,
+            defer_setup: false,
+            prompt_is_file: false,
+            literal_prompt: false,
         };
 
         // Test the no_stdin method directly to avoid stdin detection issues in tests
@@ -954,6 +1865,27 @@ mod tests {
                 sandbox_no_network: false,
                 allowed_domains: vec![],
             },
+            base: None,
+            fetch: false,
+            allow_empty_repo: false,
+            no_stdin: false,
+            copy: vec![],
+            copy_large: false,
+            no_auto_commit: false,
+            merge_mode: None,
+            remote: None,
+            apply_patch: None,
+            apply_from: None,
+            template: None,
+            prompt_prefix: None,
+            allow_secrets: false,
+            retry: None,
+            cancel_old: false,
+            no_hook_fixup: false,
+            plan_only: false,
+            defer_setup: false,
+            prompt_is_file: false,
+            literal_prompt: false,
         };
 
         // This should work with explicit args regardless of stdin status
@@ -996,6 +1928,27 @@ mod tests {
                 sandbox_no_network: false,
                 allowed_domains: vec![],
             },
+            base: None,
+            fetch: false,
+            allow_empty_repo: false,
+            no_stdin: false,
+            copy: vec![],
+            copy_large: false,
+            no_auto_commit: false,
+            merge_mode: None,
+            remote: None,
+            apply_patch: None,
+            apply_from: None,
+            template: None,
+            prompt_prefix: None,
+            allow_secrets: false,
+            retry: None,
+            cancel_old: false,
+            no_hook_fixup: false,
+            plan_only: false,
+            defer_setup: false,
+            prompt_is_file: false,
+            literal_prompt: false,
         };
 
         let result = args_with_file
@@ -1023,6 +1976,27 @@ mod tests {
                 sandbox_no_network: false,
                 allowed_domains: vec![],
             },
+            base: None,
+            fetch: false,
+            allow_empty_repo: false,
+            no_stdin: false,
+            copy: vec![],
+            copy_large: false,
+            no_auto_commit: false,
+            merge_mode: None,
+            remote: None,
+            apply_patch: None,
+            apply_from: None,
+            template: None,
+            prompt_prefix: None,
+            allow_secrets: false,
+            retry: None,
+            cancel_old: false,
+            no_hook_fixup: false,
+            plan_only: false,
+            defer_setup: false,
+            prompt_is_file: false,
+            literal_prompt: false,
         };
 
         let result = args_explicit.resolve_prompt_and_session_no_stdin().unwrap();
@@ -1053,6 +2027,27 @@ mod tests {
                 sandbox_no_network: false,
                 allowed_domains: vec![],
             },
+            base: None,
+            fetch: false,
+            allow_empty_repo: false,
+            no_stdin: false,
+            copy: vec![],
+            copy_large: false,
+            no_auto_commit: false,
+            merge_mode: None,
+            remote: None,
+            apply_patch: None,
+            apply_from: None,
+            template: None,
+            prompt_prefix: None,
+            allow_secrets: false,
+            retry: None,
+            cancel_old: false,
+            no_hook_fixup: false,
+            plan_only: false,
+            defer_setup: false,
+            prompt_is_file: false,
+            literal_prompt: false,
         };
 
         // The current implementation has a logical flaw:
@@ -1082,18 +2077,18 @@ mod tests {
     #[test]
     fn test_file_extension_detection() {
         // Test all supported extensions
-        assert!(is_likely_file_path("file.txt"));
-        assert!(is_likely_file_path("file.md"));
-        assert!(is_likely_file_path("file.rst"));
-        assert!(is_likely_file_path("file.org"));
-        assert!(is_likely_file_path("file.prompt"));
-        assert!(is_likely_file_path("file.tmpl"));
-        assert!(is_likely_file_path("file.template"));
+        assert!(looks_like_path("file.txt"));
+        assert!(looks_like_path("file.md"));
+        assert!(looks_like_path("file.rst"));
+        assert!(looks_like_path("file.org"));
+        assert!(looks_like_path("file.prompt"));
+        assert!(looks_like_path("file.tmpl"));
+        assert!(looks_like_path("file.template"));
 
         // Test unsupported extensions
-        assert!(!is_likely_file_path("file.jpg"));
-        assert!(!is_likely_file_path("file.pdf"));
-        assert!(!is_likely_file_path("file.exe"));
+        assert!(!looks_like_path("file.jpg"));
+        assert!(!looks_like_path("file.pdf"));
+        assert!(!looks_like_path("file.exe"));
     }
 
     #[test]
@@ -1108,6 +2103,7 @@ mod tests {
                     name: "".to_string(),
                     command: "".to_string(),
                 },
+                remote: None,
             },
             directories: crate::config::defaults::default_directory_config(),
             git: crate::config::defaults::default_git_config(),
@@ -1115,6 +2111,13 @@ mod tests {
             docker: None,
             setup_script: None,
             sandbox: None,
+            notifications: None,
+            security: None,
+            status: None,
+            templates: Default::default(),
+            cleanup: None,
+            vcs: None,
+            ui: None,
         };
 
         let result = validate_claude_code_ide(&config);
@@ -1134,6 +2137,7 @@ mod tests {
                     name: "".to_string(),
                     command: "".to_string(),
                 },
+                remote: None,
             },
             directories: crate::config::defaults::default_directory_config(),
             git: crate::config::defaults::default_git_config(),
@@ -1141,6 +2145,13 @@ mod tests {
             docker: None,
             setup_script: None,
             sandbox: None,
+            notifications: None,
+            security: None,
+            status: None,
+            templates: Default::default(),
+            cleanup: None,
+            vcs: None,
+            ui: None,
         };
 
         let result = validate_claude_code_ide(&config);
@@ -1160,6 +2171,7 @@ mod tests {
                     name: "cursor".to_string(),
                     command: "cursor".to_string(),
                 },
+                remote: None,
             },
             directories: crate::config::defaults::default_directory_config(),
             git: crate::config::defaults::default_git_config(),
@@ -1167,6 +2179,13 @@ mod tests {
             docker: None,
             setup_script: None,
             sandbox: None,
+            notifications: None,
+            security: None,
+            status: None,
+            templates: Default::default(),
+            cleanup: None,
+            vcs: None,
+            ui: None,
         };
 
         let result = validate_claude_code_ide(&config);
@@ -1185,6 +2204,7 @@ mod tests {
                     name: "".to_string(),
                     command: "".to_string(),
                 },
+                remote: None,
             },
             directories: crate::config::defaults::default_directory_config(),
             git: crate::config::defaults::default_git_config(),
@@ -1192,6 +2212,13 @@ mod tests {
             docker: None,
             setup_script: None,
             sandbox: None,
+            notifications: None,
+            security: None,
+            status: None,
+            templates: Default::default(),
+            cleanup: None,
+            vcs: None,
+            ui: None,
         };
 
         let result = validate_claude_code_ide(&config);
@@ -1211,6 +2238,7 @@ mod tests {
                     name: "".to_string(),
                     command: "".to_string(),
                 },
+                remote: None,
             },
             directories: crate::config::defaults::default_directory_config(),
             git: crate::config::defaults::default_git_config(),
@@ -1218,6 +2246,13 @@ mod tests {
             docker: None,
             setup_script: None,
             sandbox: None,
+            notifications: None,
+            security: None,
+            status: None,
+            templates: Default::default(),
+            cleanup: None,
+            vcs: None,
+            ui: None,
         };
 
         let result = validate_claude_code_ide(&config);
@@ -1360,6 +2395,7 @@ mod tests {
             setup_script: Some("config-script.sh".to_string()),
             default_image: None,
             forward_env_keys: None,
+            cleanup_interval_minutes: None,
         });
 
         // CLI arg should take priority
@@ -1382,6 +2418,7 @@ mod tests {
             setup_script: Some("config-script.sh".to_string()),
             default_image: None,
             forward_env_keys: None,
+            cleanup_interval_minutes: None,
         });
 
         // Default script should be found when no CLI arg
@@ -1404,6 +2441,7 @@ mod tests {
             setup_script: Some("scripts/config-script.sh".to_string()),
             default_image: None,
             forward_env_keys: None,
+            cleanup_interval_minutes: None,
         });
 
         // Config script should be found when no CLI arg or default
@@ -1425,6 +2463,7 @@ mod tests {
             setup_script: Some(abs_script.to_string_lossy().to_string()),
             default_image: None,
             forward_env_keys: None,
+            cleanup_interval_minutes: None,
         });
 
         // Absolute path in config should work
@@ -1456,4 +2495,719 @@ mod tests {
         let result = get_setup_script_path(&Some(non_existent), repo_root, &config, false);
         assert_eq!(result, None);
     }
+
+    #[test]
+    fn test_setup_script_output_is_captured_for_logs() {
+        let temp_dir = TempDir::new().unwrap();
+        let worktree = temp_dir.path().join("worktree");
+        let state_dir = temp_dir.path().join("state");
+        fs::create_dir_all(&worktree).unwrap();
+        let script = temp_dir.path().join("setup.sh");
+        fs::write(
+            &script,
+            "echo \"installing in $PARA_SESSION\"\necho 'warning: slow mirror' >&2\n",
+        )
+        .unwrap();
+
+        run_worktree_setup_script(&script, "auth", &worktree, &state_dir).unwrap();
+        let log = fs::read_to_string(logs::setup_log_path(&state_dir, "auth")).unwrap();
+        assert!(log.contains("installing in auth\n"));
+        assert!(log.contains("warning: slow mirror\n"));
+
+        // A failing run replaces the log and points at it
+        fs::write(&script, "echo 'npm ERR! missing'\nexit 2\n").unwrap();
+        let err = run_worktree_setup_script(&script, "auth", &worktree, &state_dir)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("exit code 2, output in"), "{err}");
+        assert_eq!(
+            fs::read_to_string(logs::setup_log_path(&state_dir, "auth")).unwrap(),
+            "npm ERR! missing\n"
+        );
+    }
+
+    /// Local repo with an `origin` bare remote plus a second clone that can push to it
+    fn setup_repo_with_remote() -> (TempDir, TempDir, GitService, PathBuf) {
+        use crate::test_utils::test_helpers::setup_test_repo;
+        use std::process::Command;
+
+        let (repo_dir, git_service) = setup_test_repo();
+        let remote_dir = TempDir::new().unwrap();
+        let bare = remote_dir.path().join("origin.git");
+        let upstream = remote_dir.path().join("upstream");
+
+        let run = |dir: &Path, args: &[&str]| {
+            let status = Command::new("git")
+                .current_dir(dir)
+                .args(args)
+                .output()
+                .unwrap()
+                .status;
+            assert!(status.success(), "git {args:?} failed");
+        };
+        run(
+            repo_dir.path(),
+            &["clone", "--bare", ".", &bare.to_string_lossy()],
+        );
+        run(
+            repo_dir.path(),
+            &["remote", "add", "origin", &bare.to_string_lossy()],
+        );
+        run(
+            remote_dir.path(),
+            &[
+                "clone",
+                &bare.to_string_lossy(),
+                &upstream.to_string_lossy(),
+            ],
+        );
+        run(&upstream, &["config", "user.name", "Upstream"]);
+        run(&upstream, &["config", "user.email", "upstream@example.com"]);
+
+        (repo_dir, remote_dir, git_service, upstream)
+    }
+
+    fn push_upstream_commit(upstream: &Path, message: &str) -> String {
+        use std::process::Command;
+
+        fs::write(upstream.join(format!("{message}.txt")), message).unwrap();
+        for args in [
+            vec!["add", "."],
+            vec!["commit", "-m", message],
+            vec!["push", "origin", "HEAD:main"],
+        ] {
+            let output = Command::new("git")
+                .current_dir(upstream)
+                .args(&args)
+                .output()
+                .unwrap();
+            assert!(output.status.success(), "git {args:?} failed");
+        }
+
+        let output = Command::new("git")
+            .current_dir(upstream)
+            .args(["rev-parse", "HEAD"])
+            .output()
+            .unwrap();
+        String::from_utf8_lossy(&output.stdout).trim().to_string()
+    }
+
+    #[test]
+    fn test_fetch_base_pins_resolved_sha() {
+        let (_repo_dir, _remote_dir, git_service, upstream) = setup_repo_with_remote();
+        let repo = git_service.repository();
+        let local_main = repo.resolve_commit("main").unwrap();
+
+        let first = push_upstream_commit(&upstream, "first");
+        let base = resolve_session_base(
+            repo,
+            "main",
+            FetchMode::Required,
+            None,
+            None,
+            NetworkContext::online(),
+        )
+        .unwrap();
+        assert_eq!(base.symbolic, "origin/main");
+        assert_eq!(base.commit, first);
+
+        git_service
+            .branch_manager()
+            .create_branch_from_commit("para/pinned", &base.commit)
+            .unwrap();
+
+        // Origin moves on; the session keeps the commit it was created from
+        let second = push_upstream_commit(&upstream, "second");
+        let later = resolve_session_base(
+            repo,
+            "origin/main",
+            FetchMode::Required,
+            None,
+            None,
+            NetworkContext::online(),
+        )
+        .unwrap();
+        assert_eq!(later.symbolic, "origin/main");
+        assert_eq!(later.commit, second);
+        assert_ne!(later.commit, base.commit);
+        assert_eq!(repo.resolve_commit("para/pinned").unwrap(), first);
+
+        // Local branches are never touched
+        assert_eq!(repo.get_current_branch().unwrap(), "main");
+        assert_eq!(repo.resolve_commit("main").unwrap(), local_main);
+    }
+
+    #[test]
+    fn test_fetch_base_from_selected_remote() {
+        use std::process::Command;
+
+        let (repo_dir, remote_dir, git_service, origin_clone) = setup_repo_with_remote();
+        let repo = git_service.repository();
+
+        // A second bare remote, as in a fork with `origin` and `upstream`
+        let fork = remote_dir.path().join("upstream.git");
+        let fork_clone = remote_dir.path().join("upstream-clone");
+        for (dir, args) in [
+            (
+                repo_dir.path(),
+                vec!["clone", "--bare", ".", &*fork.to_string_lossy()],
+            ),
+            (
+                repo_dir.path(),
+                vec!["remote", "add", "upstream", &*fork.to_string_lossy()],
+            ),
+            (
+                remote_dir.path(),
+                vec![
+                    "clone",
+                    &*fork.to_string_lossy(),
+                    &*fork_clone.to_string_lossy(),
+                ],
+            ),
+            (&fork_clone, vec!["config", "user.name", "Upstream"]),
+            (
+                &fork_clone,
+                vec!["config", "user.email", "upstream@example.com"],
+            ),
+        ] {
+            let output = Command::new("git")
+                .current_dir(dir)
+                .args(&args)
+                .output()
+                .unwrap();
+            assert!(output.status.success(), "git {args:?} failed");
+        }
+
+        let on_origin = push_upstream_commit(&origin_clone, "origin-change");
+        let on_upstream = push_upstream_commit(&fork_clone, "upstream-change");
+        let online = NetworkContext::online();
+
+        let base = resolve_session_base(
+            repo,
+            "main",
+            FetchMode::Required,
+            Some("upstream"),
+            None,
+            online,
+        )
+        .unwrap();
+        assert_eq!(base.symbolic, "upstream/main");
+        assert_eq!(base.commit, on_upstream);
+
+        // git.default_remote applies when neither --remote nor --base names one
+        let base = resolve_session_base(
+            repo,
+            "main",
+            FetchMode::Required,
+            None,
+            Some("upstream"),
+            online,
+        )
+        .unwrap();
+        assert_eq!(base.commit, on_upstream);
+        let base = resolve_session_base(
+            repo,
+            "origin/main",
+            FetchMode::Required,
+            None,
+            Some("upstream"),
+            online,
+        )
+        .unwrap();
+        assert_eq!(base.commit, on_origin);
+
+        let err = resolve_session_base(
+            repo,
+            "origin/main",
+            FetchMode::Required,
+            Some("upstream"),
+            None,
+            online,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("--remote is 'upstream'"));
+
+        let err = resolve_session_base(
+            repo,
+            "main",
+            FetchMode::Required,
+            Some("fork"),
+            None,
+            online,
+        )
+        .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Unknown remote 'fork'. Configured remotes: origin, upstream"));
+    }
+
+    #[test]
+    fn test_base_without_fetch_uses_local_ref() {
+        let (_repo_dir, git_service) = crate::test_utils::test_helpers::setup_test_repo();
+        let repo = git_service.repository();
+        let head = repo.resolve_commit("HEAD").unwrap();
+
+        let base = resolve_session_base(
+            repo,
+            "main",
+            FetchMode::Off,
+            None,
+            None,
+            NetworkContext::online(),
+        )
+        .unwrap();
+        assert_eq!(base.symbolic, "main");
+        assert_eq!(base.commit, head);
+
+        let by_sha = resolve_session_base(
+            repo,
+            &head[..10],
+            FetchMode::Off,
+            None,
+            None,
+            NetworkContext::online(),
+        )
+        .unwrap();
+        assert_eq!(by_sha.commit, head);
+    }
+
+    #[test]
+    fn test_offline_base_never_touches_unreachable_remote() {
+        let (_repo_dir, git_service) = crate::test_utils::test_helpers::setup_test_repo();
+        let repo = git_service.repository();
+        crate::core::git::repository::execute_git_command(
+            repo,
+            &[
+                "remote",
+                "add",
+                "origin",
+                "https://unreachable.invalid/para.git",
+            ],
+        )
+        .unwrap();
+        let head = repo.resolve_commit("HEAD").unwrap();
+
+        let base = resolve_session_base(
+            repo,
+            "main",
+            FetchMode::Off,
+            None,
+            None,
+            NetworkContext::offline(),
+        )
+        .unwrap();
+        assert_eq!(base.commit, head);
+
+        let started = std::time::Instant::now();
+        let err = resolve_session_base(
+            repo,
+            "main",
+            FetchMode::Required,
+            None,
+            None,
+            NetworkContext::offline(),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("offline mode"));
+        assert!(started.elapsed() < std::time::Duration::from_secs(1));
+        assert!(repo.resolve_commit("origin/main").is_err());
+    }
+
+    #[test]
+    fn test_auto_fetch_sees_new_remote_commits() {
+        let (_repo_dir, _remote_dir, git_service, upstream) = setup_repo_with_remote();
+        let repo = git_service.repository();
+        let local_main = repo.resolve_commit("main").unwrap();
+        let online = NetworkContext::online();
+
+        let pushed = push_upstream_commit(&upstream, "pushed");
+
+        // Without auto_fetch the stale local main is used
+        let base = resolve_session_base(repo, "main", FetchMode::Off, None, None, online).unwrap();
+        assert_eq!(base.commit, local_main);
+
+        let base = resolve_session_base(repo, "main", FetchMode::Auto, None, None, online).unwrap();
+        assert_eq!(base.symbolic, "origin/main");
+        assert_eq!(base.commit, pushed);
+        assert_eq!(repo.resolve_commit("main").unwrap(), local_main);
+
+        // Offline, auto_fetch quietly keeps the local ref
+        push_upstream_commit(&upstream, "offline");
+        let base = resolve_session_base(
+            repo,
+            "main",
+            FetchMode::Auto,
+            None,
+            None,
+            NetworkContext::offline(),
+        )
+        .unwrap();
+        assert_eq!(base.commit, local_main);
+    }
+
+    #[test]
+    fn test_auto_fetch_keeps_local_commits_and_survives_failures() {
+        let (repo_dir, _remote_dir, git_service, upstream) = setup_repo_with_remote();
+        let repo = git_service.repository();
+        let online = NetworkContext::online();
+
+        // A local main with unpushed work is not replaced by origin/main
+        push_upstream_commit(&upstream, "remote-only");
+        fs::write(repo_dir.path().join("local.txt"), "local").unwrap();
+        repo.stage_all_changes().unwrap();
+        repo.commit("Local work").unwrap();
+        let local_main = repo.resolve_commit("main").unwrap();
+        let base = resolve_session_base(repo, "main", FetchMode::Auto, None, None, online).unwrap();
+        assert_eq!(base.symbolic, "main");
+        assert_eq!(base.commit, local_main);
+
+        // An unreachable remote only warns
+        crate::core::git::repository::execute_git_command(
+            repo,
+            &["remote", "set-url", "origin", "/nonexistent/origin.git"],
+        )
+        .unwrap();
+        let base = resolve_session_base(repo, "main", FetchMode::Auto, None, None, online).unwrap();
+        assert_eq!(base.commit, local_main);
+        assert!(
+            resolve_session_base(repo, "main", FetchMode::Required, None, None, online).is_err()
+        );
+    }
+
+    #[test]
+    fn test_unknown_base_lists_similar_branches() {
+        let (_repo_dir, git_service) = crate::test_utils::test_helpers::setup_test_repo();
+        let repo = git_service.repository();
+
+        let err = resolve_session_base(
+            repo,
+            "mian",
+            FetchMode::Off,
+            None,
+            None,
+            NetworkContext::online(),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("Similar branches: main"));
+
+        let err = resolve_session_base(
+            repo,
+            "zzzzzzzzzzzz",
+            FetchMode::Off,
+            None,
+            None,
+            NetworkContext::online(),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("no branch or commit"));
+    }
+
+    #[test]
+    fn test_detached_head_base_is_rejected() {
+        let (_repo_dir, git_service) = crate::test_utils::test_helpers::setup_test_repo();
+        let repo = git_service.repository();
+        let head = repo.resolve_commit("HEAD").unwrap();
+        repo.checkout_branch(&head).unwrap();
+
+        let err = resolve_session_base(
+            repo,
+            "HEAD",
+            FetchMode::Off,
+            None,
+            None,
+            NetworkContext::online(),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("detached"));
+    }
+
+    fn prompt_args(
+        name_or_prompt: Option<&str>,
+        prompt: Option<&str>,
+        file: Option<&Path>,
+    ) -> DispatchArgs {
+        DispatchArgs {
+            name_or_prompt: name_or_prompt.map(str::to_string),
+            prompt: prompt.map(str::to_string),
+            file: file.map(Path::to_path_buf),
+            no_stdin: false,
+            dangerously_skip_permissions: false,
+            container: false,
+            allow_domains: None,
+            docker_args: vec![],
+            setup_script: None,
+            docker_image: None,
+            no_forward_keys: false,
+            allow_empty_repo: false,
+            base: None,
+            fetch: false,
+            sandbox_args: SandboxArgs {
+                sandbox: false,
+                no_sandbox: false,
+                sandbox_profile: None,
+                sandbox_no_network: false,
+                allowed_domains: vec![],
+            },
+            copy: vec![],
+            copy_large: false,
+            no_auto_commit: false,
+            merge_mode: None,
+            remote: None,
+            apply_patch: None,
+            apply_from: None,
+            template: None,
+            prompt_prefix: None,
+            allow_secrets: false,
+            retry: None,
+            cancel_old: false,
+            no_hook_fixup: false,
+            plan_only: false,
+            defer_setup: false,
+            prompt_is_file: false,
+            literal_prompt: false,
+        }
+    }
+
+    fn resolve(args: &DispatchArgs, stdin: Option<&str>) -> Result<(Option<String>, String)> {
+        args.resolve_with_stdin(stdin.map(str::to_string))
+    }
+
+    #[test]
+    fn test_retry_inherits_settings_and_appends_instructions() {
+        let mut original = SessionState::new(
+            "auth".to_string(),
+            "para/auth".to_string(),
+            PathBuf::from("/tmp/auth"),
+        );
+        original.merge_mode = MergeMode::Rebase;
+        original.dangerous_skip_permissions = Some(true);
+        original.sandbox_enabled = Some(true);
+        original.sandbox_profile = Some("standard".to_string());
+        original.no_auto_commit = Some(true);
+        original.template = Some("bugfix".to_string());
+        let source = RetrySource {
+            state: original,
+            task: "Add login".to_string(),
+        };
+
+        let mut args = prompt_args(Some("keep the session store"), None, None);
+        args.retry = Some("auth".to_string());
+        let args = inherit_retry_settings(args, &source.state);
+        assert_eq!(args.merge_mode, Some(MergeMode::Rebase));
+        assert!(args.dangerously_skip_permissions);
+        assert!(args.no_auto_commit);
+        assert!(!args.container);
+        assert!(args.sandbox_args.sandbox);
+        assert_eq!(
+            args.sandbox_args.sandbox_profile.as_deref(),
+            Some("standard")
+        );
+        assert_eq!(args.template.as_deref(), Some("bugfix"));
+        assert_eq!(
+            source.prompt(args.retry_instructions().as_deref()),
+            "Add login\n\nAdditional instructions for this retry:\nkeep the session store"
+        );
+
+        // Options given for the retry win over the original's
+        let mut args = prompt_args(None, None, None);
+        args.merge_mode = Some(MergeMode::Squash);
+        args.sandbox_args.no_sandbox = true;
+        let args = inherit_retry_settings(args, &source.state);
+        assert_eq!(args.merge_mode, Some(MergeMode::Squash));
+        assert!(!args.sandbox_args.sandbox);
+        assert_eq!(
+            source.prompt(args.retry_instructions().as_deref()),
+            "Add login"
+        );
+    }
+
+    #[test]
+    fn test_template_prompt_prefix_goes_first() {
+        let mut args = prompt_args(Some("login-fix"), Some("Fix the login crash"), None);
+        args.prompt_prefix = Some("Write a failing test first.".to_string());
+        assert_eq!(
+            resolve(&args, None).unwrap(),
+            (
+                Some("login-fix".to_string()),
+                "Write a failing test first.\n\nFix the login crash".to_string()
+            )
+        );
+
+        let mut args = prompt_args(None, None, None);
+        args.prompt_prefix = Some("Write a failing test first.".to_string());
+        assert_eq!(
+            resolve(&args, Some("design doc")).unwrap(),
+            (
+                None,
+                "Write a failing test first.\n\ndesign doc".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_combine_prompt_parts() {
+        assert_eq!(
+            combine_prompt_parts(vec![("stdin".to_string(), "only\n".to_string())]),
+            "only\n"
+        );
+        assert_eq!(
+            combine_prompt_parts(vec![
+                ("guide.md".to_string(), "rules\n".to_string()),
+                ("stdin".to_string(), "design".to_string()),
+            ]),
+            "--- guide.md ---\nrules\n\n--- stdin ---\ndesign"
+        );
+    }
+
+    #[test]
+    fn test_stdin_combination_matrix() {
+        let temp_dir = TempDir::new().unwrap();
+        let guide = create_test_file(&temp_dir, "guide.md", "guidelines");
+        let guide_arg = guide.to_str().unwrap();
+        let guide_section = format!("--- {guide_arg} ---\nguidelines");
+        let stdin = Some("design doc");
+        let stdin_section = "--- stdin ---\ndesign doc";
+
+        // stdin only
+        assert_eq!(
+            resolve(&prompt_args(None, None, None), stdin).unwrap(),
+            (None, "design doc".to_string())
+        );
+
+        // stdin + session name
+        assert_eq!(
+            resolve(&prompt_args(Some("my-session"), None, None), stdin).unwrap(),
+            (Some("my-session".to_string()), "design doc".to_string())
+        );
+
+        // stdin + positional file path
+        assert_eq!(
+            resolve(&prompt_args(Some(guide_arg), None, None), stdin).unwrap(),
+            (None, format!("{guide_section}\n\n{stdin_section}"))
+        );
+
+        // stdin + positional prompt text
+        assert_eq!(
+            resolve(&prompt_args(Some("fix the tests"), None, None), stdin).unwrap(),
+            (
+                None,
+                format!("--- prompt ---\nfix the tests\n\n{stdin_section}")
+            )
+        );
+
+        // stdin + session + inline prompt
+        assert_eq!(
+            resolve(
+                &prompt_args(Some("my-session"), Some("fix it"), None),
+                stdin
+            )
+            .unwrap(),
+            (
+                Some("my-session".to_string()),
+                format!("--- prompt ---\nfix it\n\n{stdin_section}")
+            )
+        );
+
+        // stdin + session + positional file
+        assert_eq!(
+            resolve(
+                &prompt_args(Some("my-session"), Some(guide_arg), None),
+                stdin
+            )
+            .unwrap(),
+            (
+                Some("my-session".to_string()),
+                format!("{guide_section}\n\n{stdin_section}")
+            )
+        );
+
+        // stdin + --file
+        assert_eq!(
+            resolve(&prompt_args(None, None, Some(&guide)), stdin).unwrap(),
+            (None, format!("{guide_section}\n\n{stdin_section}"))
+        );
+
+        // stdin + session + --file
+        assert_eq!(
+            resolve(&prompt_args(Some("my-session"), None, Some(&guide)), stdin).unwrap(),
+            (
+                Some("my-session".to_string()),
+                format!("{guide_section}\n\n{stdin_section}")
+            )
+        );
+
+        // stdin + session + inline prompt + --file
+        assert_eq!(
+            resolve(
+                &prompt_args(Some("my-session"), Some("fix it"), Some(&guide)),
+                stdin
+            )
+            .unwrap(),
+            (
+                Some("my-session".to_string()),
+                format!("--- prompt ---\nfix it\n\n{guide_section}\n\n{stdin_section}")
+            )
+        );
+    }
+
+    #[test]
+    fn test_empty_or_missing_stdin_falls_back_to_command_line() {
+        let temp_dir = TempDir::new().unwrap();
+        let guide = create_test_file(&temp_dir, "guide.md", "guidelines");
+
+        for stdin in [None, Some(""), Some("  \n")] {
+            // --file alone
+            assert_eq!(
+                resolve(&prompt_args(None, None, Some(&guide)), stdin).unwrap(),
+                (None, "guidelines".to_string())
+            );
+
+            // session + --file
+            assert_eq!(
+                resolve(&prompt_args(Some("my-session"), None, Some(&guide)), stdin).unwrap(),
+                (Some("my-session".to_string()), "guidelines".to_string())
+            );
+
+            // session + inline prompt
+            assert_eq!(
+                resolve(
+                    &prompt_args(Some("my-session"), Some("fix it"), None),
+                    stdin
+                )
+                .unwrap(),
+                (Some("my-session".to_string()), "fix it".to_string())
+            );
+        }
+
+        // Empty stdin with nothing else is an error
+        let err = resolve(&prompt_args(None, None, None), Some(""))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Piped input is empty"));
+
+        // No stdin and nothing else is an error
+        assert!(resolve(&prompt_args(None, None, None), None).is_err());
+    }
+
+    #[test]
+    fn test_stdin_with_empty_file_is_rejected() {
+        let temp_dir = TempDir::new().unwrap();
+        let empty = create_test_file(&temp_dir, "empty.md", "");
+
+        let err = resolve(&prompt_args(None, None, Some(&empty)), Some("design doc"))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("file is empty"));
+    }
+
+    #[test]
+    fn test_no_stdin_flag_skips_reading() {
+        let mut args = prompt_args(Some("my-session"), Some("fix it"), None);
+        args.no_stdin = true;
+
+        assert_eq!(
+            args.resolve_prompt_and_session().unwrap(),
+            (Some("my-session".to_string()), "fix it".to_string())
+        );
+    }
 }
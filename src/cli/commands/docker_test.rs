@@ -23,6 +23,25 @@ mod tests {
                 sandbox_no_network: false,
                 allowed_domains: vec![],
             },
+            base: None,
+            fetch: false,
+            allow_empty_repo: false,
+            no_stdin: false,
+            copy: vec![],
+            copy_large: false,
+            no_auto_commit: false,
+            merge_mode: None,
+            remote: None,
+            apply_patch: None,
+            apply_from: None,
+            template: None,
+            allow_secrets: false,
+            retry: None,
+            cancel_old: false,
+            no_hook_fixup: false,
+            plan_only: false,
+            defer_setup: false,
+            prompt_is_file: false,
         };
 
         assert_eq!(args.docker_image, Some("custom:latest".to_string()));
@@ -50,6 +69,25 @@ mod tests {
                 sandbox_no_network: false,
                 allowed_domains: vec![],
             },
+            base: None,
+            fetch: false,
+            allow_empty_repo: false,
+            no_stdin: false,
+            copy: vec![],
+            copy_large: false,
+            no_auto_commit: false,
+            merge_mode: None,
+            remote: None,
+            apply_patch: None,
+            apply_from: None,
+            template: None,
+            allow_secrets: false,
+            retry: None,
+            cancel_old: false,
+            no_hook_fixup: false,
+            plan_only: false,
+            defer_setup: false,
+            prompt_is_file: false,
         };
 
         assert_eq!(args.docker_image, Some("python:3.11".to_string()));
@@ -77,6 +115,25 @@ mod tests {
                 sandbox_no_network: false,
                 allowed_domains: vec![],
             },
+            base: None,
+            fetch: false,
+            allow_empty_repo: false,
+            no_stdin: false,
+            copy: vec![],
+            copy_large: false,
+            no_auto_commit: false,
+            merge_mode: None,
+            remote: None,
+            apply_patch: None,
+            apply_from: None,
+            template: None,
+            allow_secrets: false,
+            retry: None,
+            cancel_old: false,
+            no_hook_fixup: false,
+            plan_only: false,
+            defer_setup: false,
+            prompt_is_file: false,
         };
 
         assert!(args.no_forward_keys);
@@ -100,6 +157,25 @@ mod tests {
                 sandbox_no_network: false,
                 allowed_domains: vec![],
             },
+            base: None,
+            fetch: false,
+            allow_empty_repo: false,
+            no_stdin: false,
+            copy: vec![],
+            copy_large: false,
+            no_auto_commit: false,
+            merge_mode: None,
+            remote: None,
+            apply_patch: None,
+            apply_from: None,
+            template: None,
+            allow_secrets: false,
+            retry: None,
+            cancel_old: false,
+            no_hook_fixup: false,
+            plan_only: false,
+            defer_setup: false,
+            prompt_is_file: false,
         };
 
         assert!(agent_args.no_forward_keys);
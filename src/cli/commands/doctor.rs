@@ -0,0 +1,39 @@
+use crate::cli::context::CommandContext;
+use crate::config::defaults::get_config_file_path;
+use crate::config::ConfigManager;
+use crate::core::daemon::server::is_daemon_running;
+use crate::core::daemon::DaemonPidFile;
+use crate::core::version::{version_skew, PARA_VERSION};
+use crate::utils::Result;
+
+pub fn execute(ctx: &CommandContext) -> Result<()> {
+    println!("para {PARA_VERSION}");
+
+    let daemon = is_daemon_running().then(DaemonPidFile::read).flatten();
+    let config = ConfigManager::written_by(&get_config_file_path());
+    let sessions = ctx.session_manager().list_sessions()?;
+
+    let mut problems = version_skew(
+        daemon
+            .as_ref()
+            .and_then(|pid_file| pid_file.version.as_deref()),
+        config.as_deref(),
+        &sessions,
+    );
+    if daemon.is_some_and(|pid_file| pid_file.version.is_none()) {
+        problems.insert(
+            0,
+            "The daemon runs a para from before versions were recorded; restart it with 'para daemon stop'"
+                .to_string(),
+        );
+    }
+
+    if problems.is_empty() {
+        println!("✅ No version skew between the CLI, daemon, config and sessions");
+        return Ok(());
+    }
+    for problem in &problems {
+        println!("⚠️  {problem}");
+    }
+    Ok(())
+}
@@ -0,0 +1,634 @@
+//! `para examples`: a cheatsheet of command lines for common workflows
+//!
+//! The examples live in [`TOPICS`], grouped by workflow. Every command line
+//! that starts with `para` must parse with the real CLI definition, and every
+//! related command must exist, which the tests check so examples cannot drift
+//! from renamed subcommands or flags.
+
+use crate::cli::commands::list::table::color_enabled;
+use crate::cli::parser::{Cli, ExamplesArgs};
+use crate::config::defaults::get_config_file_path;
+use crate::ui::theme::{self, themed, ThemePreset};
+use crate::utils::names::edit_distance;
+use crate::utils::{ParaError, Result};
+use clap::CommandFactory;
+
+/// A group of examples for one workflow
+#[derive(Debug)]
+pub struct Topic {
+    /// What `para examples <topic>` is called with
+    pub name: &'static str,
+    pub summary: &'static str,
+    pub examples: &'static [Example],
+}
+
+#[derive(Debug)]
+pub struct Example {
+    pub title: &'static str,
+    pub description: &'static str,
+    /// Command lines to run in order, as typed in a shell
+    pub commands: &'static [&'static str],
+    /// Subcommands to read up on, e.g. `config doctor`
+    pub related: &'static [&'static str],
+}
+
+pub const TOPICS: &[Topic] = &[
+    Topic {
+        name: "dispatch",
+        summary: "Start agent sessions from prompts and task files",
+        examples: &[
+            Example {
+                title: "Dispatch an agent with a prompt file",
+                description: "Start a session and hand the task written in a file to the agent. \
+Longer tasks are easier to review and reuse in a file than on the command line.",
+                commands: &["para start -f tasks/auth.md"],
+                related: &["start", "list"],
+            },
+            Example {
+                title: "Dispatch a named session with an inline prompt",
+                description: "Name the session instead of getting a generated name, so its \
+branch and worktree are easy to find.",
+                commands: &["para start auth-api -p \"implement JWT authentication for the API\""],
+                related: &["start"],
+            },
+            Example {
+                title: "Start from the latest remote branch",
+                description: "Fetch the base branch first and start from the fetched commit, \
+leaving the local branch untouched.",
+                commands: &["para start -p \"fix the flaky login test\" --base main --fetch"],
+                related: &["start", "sync"],
+            },
+            Example {
+                title: "Prepare a session now and launch the agent later",
+                description: "Create the worktree, branch and task file without opening the \
+IDE, e.g. to review the setup first.",
+                commands: &[
+                    "para start -f tasks/refactor.md --plan-only",
+                    "para launch <session>",
+                ],
+                related: &["start", "launch"],
+            },
+            Example {
+                title: "Retry a session with extra instructions",
+                description: "Start a new session with the task of an earlier one plus more \
+instructions, and cancel the old session once the new one runs.",
+                commands: &[
+                    "para start --retry <session> --cancel-old -p \"reuse the existing HTTP client\"",
+                ],
+                related: &["start", "cancel"],
+            },
+        ],
+    },
+    Topic {
+        name: "finish",
+        summary: "Turn a session into a branch or pull request",
+        examples: &[
+            Example {
+                title: "Finish a session and open a pull request",
+                description: "Commit the session's changes to a branch with a name of your \
+choice, push it and open a pull request for it.",
+                commands: &[
+                    "para finish \"Add JWT authentication\" --branch feature/jwt-auth",
+                    "git push -u origin feature/jwt-auth",
+                    "gh pr create --fill --head feature/jwt-auth",
+                ],
+                related: &["finish"],
+            },
+            Example {
+                title: "Check whether a session merges cleanly",
+                description: "Preview the merge into the base branch without committing \
+anything. Lists the conflicting files and exits with code 6 on conflicts.",
+                commands: &["para finish --check"],
+                related: &["finish", "sync"],
+            },
+            Example {
+                title: "Keep the agent's commits instead of squashing them",
+                description: "Finish with the session's own commits; pending changes become \
+one more commit on top.",
+                commands: &["para finish \"Add JWT authentication\" --merge-mode preserve"],
+                related: &["finish"],
+            },
+            Example {
+                title: "Commit only the staged changes",
+                description: "Finish with what is staged and leave unstaged and untracked \
+changes in the worktree.",
+                commands: &[
+                    "git add src/auth.rs",
+                    "para finish \"Fix token expiry check\" --staged-only",
+                ],
+                related: &["finish"],
+            },
+            Example {
+                title: "Finish a pushed session branch in CI",
+                description: "Integrate a branch an agent pushed into its base branch from a \
+plain clone, without session state or a worktree.",
+                commands: &["para finish \"Add JWT authentication\" --remote-branch para/auth --base main"],
+                related: &["finish"],
+            },
+        ],
+    },
+    Topic {
+        name: "recover",
+        summary: "Bring back cancelled sessions and trashed items",
+        examples: &[
+            Example {
+                title: "Recover a cancelled session",
+                description: "Pick a cancelled session from the archive, or name it, and \
+restore its branch and worktree.",
+                commands: &["para recover", "para recover <session>"],
+                related: &["recover", "list"],
+            },
+            Example {
+                title: "Preview a recovery",
+                description: "Check that an archive can be restored and show the branch and \
+worktree it would get, without changing anything.",
+                commands: &["para recover <session> --dry-run"],
+                related: &["recover"],
+            },
+            Example {
+                title: "Recover every session cancelled recently",
+                description: "Restore all sessions archived within a duration. Sessions whose \
+name or branch is taken are skipped.",
+                commands: &["para recover --all --since 12h"],
+                related: &["recover"],
+            },
+            Example {
+                title: "Restore an item removed by clean",
+                description: "List the trash with the id of every item and put one back in \
+place before it expires.",
+                commands: &["para clean --list-trash", "para restore-trash <id>"],
+                related: &["clean", "restore-trash"],
+            },
+        ],
+    },
+    Topic {
+        name: "cleanup",
+        summary: "Remove finished work, stale branches and old archives",
+        examples: &[
+            Example {
+                title: "Preview a cleanup",
+                description: "Show the orphaned branches, state files and old archives a \
+cleanup would remove.",
+                commands: &["para clean --dry-run"],
+                related: &["clean", "gc"],
+            },
+            Example {
+                title: "Pick what to clean",
+                description: "Show a checklist of every item and only clean the ones left \
+checked.",
+                commands: &["para clean --interactive"],
+                related: &["clean"],
+            },
+            Example {
+                title: "Cancel all sessions at once",
+                description: "Archive every session that is not pinned, committing \
+uncommitted work to the session branches first.",
+                commands: &["para clean --sessions --force"],
+                related: &["clean", "pin"],
+            },
+            Example {
+                title: "Cancel a single session",
+                description: "Archive a session; it can be brought back with para recover.",
+                commands: &["para cancel <session>"],
+                related: &["cancel", "recover"],
+            },
+        ],
+    },
+    Topic {
+        name: "containers",
+        summary: "Run agents in Docker containers",
+        examples: &[
+            Example {
+                title: "Run an agent in a Docker container",
+                description: "Isolate the agent from the host by running the session in a \
+container.",
+                commands: &["para start --container -p \"implement the export feature\""],
+                related: &["start"],
+            },
+            Example {
+                title: "Limit a container's network access",
+                description: "Only let the container reach the listed domains.",
+                commands: &[
+                    "para start --container --allow-domains github.com,crates.io -p \"update dependencies\"",
+                ],
+                related: &["start"],
+            },
+            Example {
+                title: "Use a custom image for a container session",
+                description: "Run the session in an image that has the project's toolchain \
+installed.",
+                commands: &["para start --container --docker-image rust:1.80 -f tasks/build.md"],
+                related: &["start"],
+            },
+            Example {
+                title: "Set up authentication for containers",
+                description: "Log in once so container sessions can use Claude.",
+                commands: &["para auth setup", "para auth status"],
+                related: &["auth"],
+            },
+            Example {
+                title: "Remove orphaned containers",
+                description: "Clean up containers, volumes and images left behind by \
+sessions that no longer exist.",
+                commands: &["para clean --containers --docker --dry-run"],
+                related: &["clean"],
+            },
+        ],
+    },
+    Topic {
+        name: "conflicts",
+        summary: "Resolve conflicts with the base branch",
+        examples: &[
+            Example {
+                title: "Sync a session and resolve conflicts",
+                description: "Rebase the session onto the latest base branch. If the rebase \
+stops on conflicts, resolve them in the worktree, continue the rebase and sync again to \
+record the new base.",
+                commands: &[
+                    "para sync",
+                    "git add -A",
+                    "git rebase --continue",
+                    "para sync",
+                ],
+                related: &["sync"],
+            },
+            Example {
+                title: "Give up on a conflicted sync",
+                description: "Abort the rebase and leave the session branch as it was.",
+                commands: &["git rebase --abort"],
+                related: &["sync"],
+            },
+            Example {
+                title: "See how a file's conflicts were resolved before",
+                description: "Show earlier conflict resolutions of a file, with the sessions \
+and commits involved.",
+                commands: &["para conflicts show --file src/api/routes.rs"],
+                related: &["conflicts show"],
+            },
+        ],
+    },
+];
+
+pub fn execute(args: ExamplesArgs) -> Result<()> {
+    theme::init(configured_theme());
+    let color = color_enabled(args.no_color);
+    let query = match (args.search, args.topic) {
+        (Some(term), _) => term,
+        (None, Some(topic)) => match find_topic(&topic) {
+            Some(topic) => {
+                println!("{}: {}\n", topic.name, topic.summary);
+                print_examples(topic.examples.iter(), color);
+                return Ok(());
+            }
+            None => topic,
+        },
+        (None, None) => {
+            print_topics();
+            return Ok(());
+        }
+    };
+
+    let matches = search(&query);
+    if matches.is_empty() {
+        let names: Vec<&str> = TOPICS.iter().map(|topic| topic.name).collect();
+        return Err(ParaError::invalid_args(format!(
+            "No examples match '{query}'. Topics: {}",
+            names.join(", ")
+        )));
+    }
+    println!("{} example(s) matching '{query}':\n", matches.len());
+    print_examples(matches.into_iter(), color);
+    Ok(())
+}
+
+/// `ui.theme` from the config file. The cheatsheet works without a valid
+/// config, so nothing else of it is read or checked.
+fn configured_theme() -> ThemePreset {
+    std::fs::read_to_string(get_config_file_path())
+        .ok()
+        .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok())
+        .and_then(|config| config.pointer("/ui/theme").cloned())
+        .and_then(|theme| serde_json::from_value(theme).ok())
+        .unwrap_or_default()
+}
+
+fn print_topics() {
+    let width = TOPICS
+        .iter()
+        .map(|topic| topic.name.len())
+        .max()
+        .unwrap_or(0);
+    println!("Topics:");
+    for topic in TOPICS {
+        println!(
+            "  {:width$}  {} ({} examples)",
+            topic.name,
+            topic.summary,
+            topic.examples.len()
+        );
+    }
+    println!(
+        "\n{}",
+        themed(
+            theme::current().hint,
+            "Run 'para examples <topic>' to show a topic, or 'para examples --search <term>' to search all examples"
+        )
+    );
+}
+
+fn print_examples<'a>(examples: impl Iterator<Item = &'a Example>, color: bool) {
+    for example in examples {
+        if color {
+            println!("\x1b[1m{}\x1b[0m", example.title);
+        } else {
+            println!("{}", example.title);
+        }
+        println!("   {}", example.description);
+        for command in example.commands {
+            println!("   $ {}", highlight(command, color));
+        }
+        if !example.related.is_empty() {
+            let related: Vec<String> = example
+                .related
+                .iter()
+                .map(|command| format!("para {command}"))
+                .collect();
+            println!("   See also: {}", related.join(", "));
+        }
+        println!();
+    }
+}
+
+/// The topic called `name`, or the only one whose name starts with it
+pub fn find_topic(name: &str) -> Option<&'static Topic> {
+    let name = name.trim().to_lowercase();
+    if name.is_empty() {
+        return None;
+    }
+    if let Some(topic) = TOPICS.iter().find(|topic| topic.name == name) {
+        return Some(topic);
+    }
+    let mut prefixed = TOPICS.iter().filter(|topic| topic.name.starts_with(&name));
+    match (prefixed.next(), prefixed.next()) {
+        (Some(topic), None) => Some(topic),
+        _ => None,
+    }
+}
+
+/// Examples matching every word of `query`, best match first
+pub fn search(query: &str) -> Vec<&'static Example> {
+    let words: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+    if words.is_empty() {
+        return Vec::new();
+    }
+
+    let mut scored: Vec<(usize, &'static Example)> = TOPICS
+        .iter()
+        .flat_map(|topic| topic.examples.iter().map(move |example| (topic, example)))
+        .filter_map(|(topic, example)| {
+            words
+                .iter()
+                .map(|word| word_score(topic, example, word))
+                .sum::<Option<usize>>()
+                .map(|score| (score, example))
+        })
+        .collect();
+    // Stable, so equal matches keep the order of the registry
+    scored.sort_by_key(|(score, _)| *score);
+    scored.into_iter().map(|(_, example)| example).collect()
+}
+
+/// How well `word` matches `example`, lower is better: a match in the title
+/// beats one in the description or topic name, which beats a close spelling
+fn word_score(topic: &Topic, example: &Example, word: &str) -> Option<usize> {
+    let title = example.title.to_lowercase();
+    let description = example.description.to_lowercase();
+    if title.contains(word) {
+        return Some(0);
+    }
+    if description.contains(word) || topic.name.contains(word) {
+        return Some(1);
+    }
+
+    let threshold = word.chars().count() / 4;
+    if threshold == 0 {
+        return None;
+    }
+    [title.as_str(), description.as_str(), topic.name]
+        .iter()
+        .flat_map(|text| text.split(|c: char| !c.is_alphanumeric()))
+        .filter(|candidate| !candidate.is_empty())
+        .map(|candidate| edit_distance(word, candidate))
+        .filter(|distance| *distance <= threshold)
+        .min()
+        .map(|distance| 1 + distance)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Style {
+    Program,
+    Subcommand,
+    Flag,
+    Quoted,
+    Placeholder,
+}
+
+impl Style {
+    fn code(self) -> &'static str {
+        match self {
+            Style::Program => "1",
+            Style::Subcommand => "36",
+            Style::Flag => "33",
+            Style::Quoted => "32",
+            Style::Placeholder => "35",
+        }
+    }
+}
+
+/// `line` with its program, para subcommands, flags, quoted values and
+/// placeholders colored, or `line` itself without colors
+fn highlight(line: &str, color: bool) -> String {
+    if !color {
+        return line.to_string();
+    }
+    styled_words(line)
+        .into_iter()
+        .map(|(word, style)| match style {
+            Some(style) => format!("\x1b[{}m{word}\x1b[0m", style.code()),
+            None => word.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn styled_words(line: &str) -> Vec<(&str, Option<Style>)> {
+    let words = split_words(line);
+    let is_para = words.first() == Some(&"para");
+    let mut command = None;
+    let mut styled = Vec::with_capacity(words.len());
+
+    for (index, word) in words.into_iter().enumerate() {
+        // Only the words right after `para` can name subcommands
+        let subcommand = command
+            .take()
+            .and_then(|parent: clap::Command| parent.find_subcommand(word).cloned());
+        let style = if index == 0 {
+            Some(Style::Program)
+        } else if subcommand.is_some() {
+            Some(Style::Subcommand)
+        } else if word.starts_with('-') && word.len() > 1 {
+            Some(Style::Flag)
+        } else if word.starts_with('"') || word.starts_with('\'') {
+            Some(Style::Quoted)
+        } else if word.starts_with('<') && word.ends_with('>') {
+            Some(Style::Placeholder)
+        } else {
+            None
+        };
+        command = if index == 0 {
+            is_para.then(Cli::command)
+        } else {
+            subcommand
+        };
+        styled.push((word, style));
+    }
+    styled
+}
+
+/// Words of a command line, keeping quoted words and their quotes together
+fn split_words(line: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+    let mut start = None;
+    let mut quote = None;
+    for (index, c) in line.char_indices() {
+        match quote {
+            Some(open) if c == open => quote = None,
+            Some(_) => {}
+            None if c == '"' || c == '\'' => {
+                quote = Some(c);
+                start.get_or_insert(index);
+            }
+            None if c.is_whitespace() => {
+                if let Some(start) = start.take() {
+                    words.push(&line[start..index]);
+                }
+            }
+            None => {
+                start.get_or_insert(index);
+            }
+        }
+    }
+    if let Some(start) = start {
+        words.push(&line[start..]);
+    }
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    fn all_examples() -> impl Iterator<Item = &'static Example> {
+        TOPICS.iter().flat_map(|topic| topic.examples.iter())
+    }
+
+    #[test]
+    fn test_every_para_command_line_parses() {
+        for example in all_examples() {
+            for line in example
+                .commands
+                .iter()
+                .filter(|line| line.starts_with("para "))
+            {
+                let args: Vec<&str> = split_words(line)
+                    .into_iter()
+                    .map(|word| word.trim_matches(|c| c == '"' || c == '\''))
+                    .collect();
+                if let Err(e) = Cli::try_parse_from(&args) {
+                    panic!("'{line}' in '{}' does not parse:\n{e}", example.title);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_related_commands_exist() {
+        for example in all_examples() {
+            for related in example.related {
+                let mut command = Cli::command();
+                for name in related.split_whitespace() {
+                    command = command
+                        .find_subcommand(name)
+                        .cloned()
+                        .unwrap_or_else(|| panic!("'para {related}' in '{}'", example.title));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_registry_is_well_formed() {
+        let mut names: Vec<&str> = TOPICS.iter().map(|topic| topic.name).collect();
+        names.sort();
+        names.dedup();
+        assert_eq!(names.len(), TOPICS.len(), "topic names must be unique");
+        for topic in TOPICS {
+            assert!(!topic.examples.is_empty(), "{} has no examples", topic.name);
+            for example in topic.examples {
+                assert!(!example.commands.is_empty(), "{}", example.title);
+            }
+        }
+    }
+
+    #[test]
+    fn test_find_topic_by_name_or_unique_prefix() {
+        assert_eq!(find_topic("Finish").map(|t| t.name), Some("finish"));
+        assert_eq!(find_topic("rec").map(|t| t.name), Some("recover"));
+        // `con` starts both containers and conflicts
+        assert!(find_topic("con").is_none());
+        assert!(find_topic("merge").is_none());
+    }
+
+    #[test]
+    fn test_search_matches_words_and_close_spellings() {
+        let titles = |query| -> Vec<&str> { search(query).iter().map(|e| e.title).collect() };
+
+        assert_eq!(
+            titles("prompt file")[0],
+            "Dispatch an agent with a prompt file"
+        );
+        assert!(titles("recovr").contains(&"Recover a cancelled session"));
+        assert!(titles("pull request").contains(&"Finish a session and open a pull request"));
+        // Every example of a topic matches its name
+        assert_eq!(titles("containers").len(), 5);
+        assert!(titles("kubernetes").is_empty());
+        assert!(titles("  ").is_empty());
+    }
+
+    #[test]
+    fn test_highlight_styles_command_words() {
+        let styles: Vec<(&str, Option<Style>)> =
+            styled_words("para conflicts show --file \"src/a b.rs\" <session> extra");
+        assert_eq!(
+            styles,
+            vec![
+                ("para", Some(Style::Program)),
+                ("conflicts", Some(Style::Subcommand)),
+                ("show", Some(Style::Subcommand)),
+                ("--file", Some(Style::Flag)),
+                ("\"src/a b.rs\"", Some(Style::Quoted)),
+                ("<session>", Some(Style::Placeholder)),
+                ("extra", None),
+            ]
+        );
+        // Only para's own subcommands are colored as such
+        assert_eq!(styled_words("git rebase --continue")[1], ("rebase", None));
+
+        assert_eq!(highlight("para sync", false), "para sync");
+        assert_eq!(
+            highlight("para sync", true),
+            "\x1b[1mpara\x1b[0m \x1b[36msync\x1b[0m"
+        );
+    }
+}
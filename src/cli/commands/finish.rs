@@ -1,13 +1,34 @@
+use crate::cli::commands::common::{is_non_interactive, unregister_from_daemon};
+use crate::cli::context::CommandContext;
+use crate::cli::json_output::{JsonOutput, SCHEMA_VERSION};
 use crate::cli::parser::FinishArgs;
 use crate::config::Config;
+use crate::core::git::integration_preview::{preview_integration, IntegrationPreview};
+use crate::core::git::protected::{ensure_not_detached, ensure_not_protected};
+use crate::core::git::remote_finish::{
+    finish_remote_branch, RemoteFinishOutcome, RemoteFinishRequest, REMOTE,
+};
+use crate::core::git::shared_branch::DetachedWorktrees;
+use crate::core::git::validation::{self, RepoOperation};
 use crate::core::git::{
     FinishRequest, FinishResult, GitOperations, GitRepository, GitService, SessionEnvironment,
+    StagedSplit, WorktreeStatus,
+};
+use crate::core::notifications::{notify, NotificationEvent};
+use crate::core::session::finish_report::{self, FinishReport};
+use crate::core::session::{
+    context, integration_backup, notes, stack, summary, SessionManager, SessionOperation,
+    SessionState, SessionStatus,
 };
-use crate::core::session::{SessionManager, SessionState, SessionStatus};
 use crate::core::status::{Status, TestStatus};
+use crate::core::vcs::{require_git, VcsBackend};
+use crate::core::version;
 use crate::platform::get_platform_manager;
-use crate::utils::{ParaError, Result};
-use std::env;
+use crate::ui::theme;
+use crate::utils::{ci, ParaError, Result};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
 
 struct FinishContext<'a> {
     session_info: Option<SessionState>,
@@ -15,11 +36,62 @@ struct FinishContext<'a> {
     current_dir: &'a std::path::Path,
     feature_branch: &'a str,
     session_manager: &'a mut SessionManager,
-    git_service: &'a GitService,
+    vcs: &'a dyn VcsBackend,
     config: &'a Config,
     args: &'a FinishArgs,
 }
 
+/// Result of `para finish --json`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FinishOutput {
+    pub schema_version: u32,
+    pub result: FinishOutcome,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub session: Option<String>,
+    /// Branch holding the finished work; not set for `--check`
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub final_branch: Option<String>,
+    /// Commit at the tip of `final_branch`
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub commit: Option<String>,
+    /// Branch `--check` compared against, or `--base` of `--remote-branch`
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub base_branch: Option<String>,
+    /// Whether the work was committed onto another session (`--onto-session`)
+    pub integrated: bool,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub integrated_into: Option<String>,
+    /// Files `--check` or `--remote-branch` found conflicting with the base branch
+    pub conflicted_files: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FinishOutcome {
+    /// `FinishResult::Success`
+    Success,
+    /// `--check` found no conflicts
+    Clean,
+    /// `--check` found conflicts
+    Conflicts,
+}
+
+impl FinishOutput {
+    fn new(result: FinishOutcome, session: Option<&SessionState>) -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            result,
+            session: session.map(|s| s.name.clone()),
+            final_branch: None,
+            commit: None,
+            base_branch: None,
+            integrated: false,
+            integrated_into: None,
+            conflicted_files: Vec::new(),
+        }
+    }
+}
+
 fn cleanup_session_state(
     session_manager: &mut SessionManager,
     session_info: Option<SessionState>,
@@ -39,6 +111,29 @@ fn cleanup_session_state(
         }
     }
 
+    // Keep a copy of the notes with the finished session's history
+    if let Some(ref session_state) = session_info {
+        let timestamp = Utc::now()
+            .format(notes::ARCHIVE_TIMESTAMP_FORMAT)
+            .to_string();
+        if let Err(e) = notes::archive_notes(
+            session_manager.state_dir(),
+            &session_state.name,
+            &timestamp,
+            true,
+        ) {
+            eprintln!("Warning: Failed to archive session notes: {e}");
+        }
+
+        if config.should_preserve_on_finish() {
+            context::keep_context(
+                session_manager.state_dir(),
+                &session_state.name,
+                &session_state.worktree_path,
+            );
+        }
+    }
+
     // Then update session status to Review
     if let Some(session_state) = session_info {
         session_manager.update_session_status(&session_state.name, SessionStatus::Review)?;
@@ -62,13 +157,10 @@ fn handle_finish_success(final_branch: String, ctx: &mut FinishContext) -> Resul
 
     // Destroy container if this is a container session
     if let Some(ref session) = ctx.session_info {
+        unregister_from_daemon(&session.name);
         if session.is_container() {
-            // Use CLI-only approach - default parameters for container cleanup
-            let docker_manager = crate::core::docker::DockerManager::new(
-                ctx.config.clone(),
-                false,  // network_isolation
-                vec![], // allowed_domains
-            );
+            let docker_manager =
+                crate::core::docker::DockerManager::for_session(ctx.config.clone(), session);
             if let Err(e) = docker_manager.destroy_session_container(session) {
                 eprintln!("Warning: Failed to destroy container: {e}");
             }
@@ -83,18 +175,20 @@ fn handle_finish_success(final_branch: String, ctx: &mut FinishContext) -> Resul
     )?;
 
     if let Some(ref path) = worktree_path {
-        if path != &ctx.git_service.repository().root && !ctx.config.should_preserve_on_finish() {
-            if let Ok(worktree_repo) = GitRepository::discover_from(path) {
-                if worktree_repo.has_uncommitted_changes().unwrap_or(false) {
+        if path != ctx.vcs.root() && !ctx.config.should_preserve_on_finish() {
+            if path.exists() {
+                let status = ctx.vcs.worktree_status(path).unwrap_or_default();
+                if !status.is_clean() {
                     eprintln!(
-                        "Warning: Preserving worktree at {} due to uncommitted changes",
-                        path.display()
+                        "Warning: Preserving worktree at {} due to uncommitted changes ({})",
+                        path.display(),
+                        status.summary()
                     );
                     return Ok(());
                 }
             }
 
-            if let Err(e) = ctx.git_service.remove_worktree(path) {
+            if let Err(e) = ctx.vcs.remove_worktree(path) {
                 eprintln!(
                     "Warning: Failed to remove worktree at {}: {}",
                     path.display(),
@@ -104,27 +198,166 @@ fn handle_finish_success(final_branch: String, ctx: &mut FinishContext) -> Resul
         }
     }
 
-    println!("✓ Session finished successfully");
+    println!("{}", theme::success("Session finished successfully"));
     println!("  Feature branch: {final_branch}");
     println!("  Commit message: {}", ctx.args.message);
+    if let (Some(session), Some(git_service)) = (&ctx.session_info, ctx.vcs.git()) {
+        let finished = SessionState {
+            status: SessionStatus::Review,
+            ..session.clone()
+        };
+        if let Ok(summary) = summary::gather(
+            git_service.repository(),
+            ctx.session_manager.state_dir(),
+            &finished,
+            &final_branch,
+            Utc::now(),
+        ) {
+            println!("  Summary: {}", summary::render_line(&summary));
+        }
+    }
+
+    if let Some(ref session) = ctx.session_info {
+        offer_stacked_rebase(ctx.session_manager, session, &final_branch);
+    }
+
+    let session_name = ctx
+        .session_info
+        .as_ref()
+        .map(|s| s.name.clone())
+        .unwrap_or_else(|| ctx.feature_branch.to_string());
+    notify(
+        ctx.config,
+        NotificationEvent::Finished {
+            session: session_name,
+            branch: final_branch,
+        },
+    );
 
     Ok(())
 }
 
 fn initialize_finish_environment(
     args: &FinishArgs,
-) -> Result<(GitService, std::path::PathBuf, SessionEnvironment)> {
+    ctx: &CommandContext,
+) -> Result<(Box<dyn VcsBackend>, SessionEnvironment)> {
     args.validate()?;
 
-    let git_service = GitService::discover()
+    let vcs = ctx
+        .vcs()
         .map_err(|e| ParaError::git_error(format!("Failed to discover git repository: {e}")))?;
 
-    let current_dir = env::current_dir()
-        .map_err(|e| ParaError::fs_error(format!("Failed to get current directory: {e}")))?;
+    let session_env = vcs.session_environment(ctx.current_dir())?;
 
-    let session_env = git_service.validate_session_environment(&current_dir)?;
+    Ok((vcs, session_env))
+}
+
+/// `para finish --check`: report whether the session branch merges cleanly into its base
+fn check_integration(
+    vcs: &dyn VcsBackend,
+    session_info: Option<&SessionState>,
+    feature_branch: &str,
+    staged: Option<&StagedSplit>,
+) -> Result<FinishOutput> {
+    let repo = require_git(vcs, "finish --check")?.repository();
+    let base = match session_info.and_then(|s| s.parent_branch.clone()) {
+        Some(parent) => parent,
+        None => repo.get_main_branch()?,
+    };
+
+    let worktree_dirty = session_info
+        .filter(|s| s.worktree_path.exists())
+        .and_then(|s| GitService::discover_from(&s.worktree_path).ok())
+        .and_then(|service| service.has_uncommitted_changes().ok())
+        .unwrap_or(false);
+    if let Some(split) = staged {
+        print_staged_split(split);
+    } else if worktree_dirty {
+        println!(
+            "{}",
+            theme::hint("Uncommitted changes in the session worktree are not part of this check")
+        );
+    }
+
+    let mut output = match preview_integration(repo, feature_branch, &base)? {
+        IntegrationPreview::Clean => {
+            println!(
+                "{}",
+                theme::success(format!(
+                    "clean: '{feature_branch}' merges into '{base}' without conflicts"
+                ))
+            );
+            FinishOutput::new(FinishOutcome::Clean, session_info)
+        }
+        IntegrationPreview::Conflicts(paths) => {
+            println!(
+                "{}",
+                theme::warning(format!(
+                    "'{feature_branch}' would conflict with '{base}' in {} file(s):",
+                    paths.len()
+                ))
+            );
+            for path in &paths {
+                println!("  {path}");
+            }
+            let mut output = FinishOutput::new(FinishOutcome::Conflicts, session_info);
+            output.conflicted_files = paths;
+            output
+        }
+    };
+    output.base_branch = Some(base);
+    Ok(output)
+}
 
-    Ok((git_service, current_dir, session_env))
+/// `para finish --remote-branch`: finish a pushed session branch in a plain
+/// clone, without session state or a worktree to clean up
+fn finish_remote(
+    repo: &GitRepository,
+    branch: &str,
+    args: &FinishArgs,
+    config: &Config,
+) -> Result<FinishOutput> {
+    let base = args.base.clone().unwrap_or_default();
+    let request = RemoteFinishRequest {
+        branch: branch.to_string(),
+        base: base.clone(),
+        commit_message: args.message.clone(),
+        merge_mode: args
+            .merge_mode
+            .unwrap_or_else(|| config.get_default_merge_mode()),
+    };
+    let mut output = match finish_remote_branch(repo, &request)? {
+        RemoteFinishOutcome::Finished { commit } => {
+            println!(
+                "{}",
+                theme::success(format!(
+                    "Finished '{branch}' onto '{base}' at {} and pushed both to {REMOTE}",
+                    &commit[..commit.len().min(7)]
+                ))
+            );
+            let mut output = FinishOutput::new(FinishOutcome::Success, None);
+            output.final_branch = Some(branch.to_string());
+            output.commit = Some(commit);
+            output
+        }
+        RemoteFinishOutcome::Conflicts(paths) => {
+            println!(
+                "{}",
+                theme::warning(format!(
+                    "'{branch}' conflicts with '{base}' in {} file(s); nothing was pushed:",
+                    paths.len()
+                ))
+            );
+            for path in &paths {
+                println!("  {path}");
+            }
+            let mut output = FinishOutput::new(FinishOutcome::Conflicts, None);
+            output.conflicted_files = paths;
+            output
+        }
+    };
+    output.base_branch = Some(base);
+    Ok(output)
 }
 
 fn resolve_session_info(
@@ -133,9 +366,14 @@ fn resolve_session_info(
     session_manager: &mut SessionManager,
     current_dir: &std::path::Path,
 ) -> Result<(Option<SessionState>, bool)> {
-    let (session_info, is_worktree_env) = match &args.session {
+    if let Some(ref branch) = args.session_branch {
+        let session_state = session_manager.resolve_session_by_branch(branch)?;
+        return Ok((Some(session_state), false));
+    }
+
+    let (session_info, is_worktree_env) = match args.session_id() {
         Some(session_id) => {
-            let session_state = session_manager.load_state(session_id)?;
+            let session_state = session_manager.resolve_session(&session_id)?;
             (Some(session_state), false)
         }
         None => match session_env {
@@ -179,11 +417,38 @@ fn determine_feature_branch(
     }
 }
 
+/// The session's recorded merge mode applies unless `--merge-mode` overrides it
+fn build_finish_request(
+    session_info: Option<&SessionState>,
+    feature_branch: &str,
+    args: &FinishArgs,
+    config: &Config,
+) -> FinishRequest {
+    let mut request = match session_info {
+        Some(session) => session.finish_request(args.message.clone(), args.branch.clone()),
+        None => FinishRequest {
+            feature_branch: feature_branch.to_string(),
+            commit_message: args.message.clone(),
+            target_branch_name: args.branch.clone(),
+            merge_mode: config.get_default_merge_mode(),
+            parent_branch: None,
+            base_commit: None,
+            stage_all: true,
+        },
+    };
+    if let Some(merge_mode) = args.merge_mode {
+        request.merge_mode = merge_mode;
+    }
+    request.stage_all = config.should_auto_stage() && !args.staged_only;
+    request
+}
+
 fn perform_pre_finish_operations(
     session_info: &Option<SessionState>,
     feature_branch: &str,
     config: &Config,
-    git_service: &GitService,
+    vcs: &dyn VcsBackend,
+    staged: Option<&StagedSplit>,
 ) -> Result<()> {
     println!("Finishing session: {feature_branch}");
     let session_id = session_info
@@ -206,14 +471,23 @@ fn perform_pre_finish_operations(
             &config.ide.name
         };
 
-        if let Err(e) = platform.close_ide_window(&session_id, ide_to_close, config.get_state_dir())
+        let state_dir = config.resolved_state_dir();
+        if let Err(e) =
+            platform.close_ide_window(&session_id, ide_to_close, &state_dir.to_string_lossy())
         {
             eprintln!("Warning: Failed to close IDE window: {e}");
         }
     }
 
-    if !is_container_session && config.should_auto_stage() {
-        if let Err(e) = git_service.stage_all_changes() {
+    if let Some(split) = staged {
+        print_staged_split(split);
+    } else if !is_container_session && config.should_auto_stage() {
+        if let Ok(status) = vcs.worktree_status(vcs.root()) {
+            if !status.is_clean() {
+                println!("  Uncommitted changes: {}", staging_summary(&status));
+            }
+        }
+        if let Err(e) = vcs.stage_all_changes() {
             eprintln!("Warning: Auto-staging failed: {e}. Please stage changes manually.");
             return Err(e);
         }
@@ -222,6 +496,38 @@ fn perform_pre_finish_operations(
     Ok(())
 }
 
+/// `--staged-only`: the files the commit takes and the ones it leaves behind
+fn print_staged_split(split: &StagedSplit) {
+    println!("  Committing {} staged file(s):", split.staged.len());
+    for path in &split.staged {
+        println!("    {path}");
+    }
+    if !split.left_behind.is_empty() {
+        println!(
+            "  Leaving {} file(s) in the worktree:",
+            split.left_behind.len()
+        );
+        for path in &split.left_behind {
+            println!("    {path}");
+        }
+    }
+}
+
+/// Staged and left-behind files of the worktree the session is finished in
+fn staged_split(vcs: &dyn VcsBackend, worktree: Option<&Path>) -> Result<StagedSplit> {
+    require_git(vcs, "finish --staged-only")?;
+    GitRepository::discover_from(worktree.unwrap_or(vcs.root()))?.staged_split()
+}
+
+/// What auto-staging is about to pick up
+fn staging_summary(status: &WorktreeStatus) -> String {
+    if status.untracked > 0 {
+        format!("{} (will be staged unless excluded)", status.summary())
+    } else {
+        status.summary()
+    }
+}
+
 fn handle_container_finish(
     session_info: &SessionState,
     _args: &FinishArgs,
@@ -244,7 +550,10 @@ fn handle_container_finish(
     // For host-initiated finish, we proceed with normal git operations
     // The watcher would have already handled container-initiated finishes
     println!(
-        "⚠️  Note: For container sessions, agents should use 'para finish' inside the container."
+        "{}",
+        theme::warning(
+            "Note: For container sessions, agents should use 'para finish' inside the container."
+        )
     );
     println!("   This will create a signal file that the host processes automatically.");
 
@@ -255,15 +564,193 @@ fn handle_container_finish(
     ))
 }
 
-pub fn execute(config: Config, args: FinishArgs) -> Result<()> {
-    let (git_service, current_dir, session_env) = initialize_finish_environment(&args)?;
-    let mut session_manager = SessionManager::new(&config);
+pub fn execute(ctx: &CommandContext, args: FinishArgs) -> Result<()> {
+    let json = JsonOutput::start(args.json);
+    if let Some(group) = ci::group_start("Finishing session") {
+        println!("{group}");
+    }
+    let (output, report) = finish_reported(ctx, args);
+    if let Some(group) = ci::group_end() {
+        println!("{group}");
+    }
+    let output = match output {
+        Ok(output) => output,
+        Err(e) => {
+            // What became of the attempt is the JSON result of a failed finish
+            if let Some(ref report) = report {
+                json.finish(report)?;
+            }
+            return Err(e);
+        }
+    };
+    json.finish(&output)?;
+    set_ci_outputs(&output);
+
+    if output.result == FinishOutcome::Conflicts {
+        return Err(ParaError::integration_conflict_in(
+            format!(
+                "{} file(s) conflict with '{}'",
+                output.conflicted_files.len(),
+                output.base_branch.unwrap_or_default()
+            ),
+            output.conflicted_files,
+        ));
+    }
+    Ok(())
+}
+
+/// Session, branch and commit of a finish for later steps of a CI job
+fn set_ci_outputs(output: &FinishOutput) {
+    let Some(ref branch) = output.final_branch else {
+        return;
+    };
+    ci::set_outputs(&[
+        ("session", output.session.as_deref().unwrap_or_default()),
+        ("branch", branch),
+        ("commit", output.commit.as_deref().unwrap_or_default()),
+    ]);
+}
+
+/// [`execute`] without printing the JSON result
+#[cfg(test)]
+pub(crate) fn execute_in(ctx: &CommandContext, args: FinishArgs) -> Result<FinishOutput> {
+    finish_reported(ctx, args).0
+}
+
+/// Finish, and write the [`FinishReport`] into the session worktree when
+/// the finish was not run by a person at a terminal
+fn finish_reported(
+    ctx: &CommandContext,
+    args: FinishArgs,
+) -> (Result<FinishOutput>, Option<FinishReport>) {
+    let mut attempt = FinishAttempt::new(args.json || is_non_interactive());
+    let result = finish_in(ctx, args, &mut attempt);
+    let report = attempt.conclude(&result);
+    (result, report)
+}
+
+/// Where the report of a finish goes and what it says so far
+#[derive(Default)]
+struct FinishAttempt {
+    /// Whether the finish was not run by a person at a terminal
+    reported: bool,
+    worktree: Option<PathBuf>,
+    session: Option<String>,
+    report: Option<FinishReport>,
+}
+
+impl FinishAttempt {
+    fn new(reported: bool) -> Self {
+        Self {
+            reported,
+            ..Self::default()
+        }
+    }
+
+    /// Remove the report of the previous attempt in `worktree`, where this
+    /// attempt's report goes
+    fn start(&mut self, worktree: &Path, session: Option<&SessionState>) -> Result<()> {
+        if !self.reported {
+            return Ok(());
+        }
+        finish_report::clear(worktree)?;
+        self.worktree = Some(worktree.to_path_buf());
+        self.session = session.map(|s| s.name.clone());
+        Ok(())
+    }
+
+    /// The report of the attempt that ended with `result`, written into the
+    /// worktree if it is still there
+    fn conclude<T>(self, result: &Result<T>) -> Option<FinishReport> {
+        let worktree = self.worktree?;
+        let report = match (self.report, result) {
+            (Some(report), _) => report,
+            (None, Err(e)) => FinishReport::failed(self.session.as_deref(), e),
+            (None, Ok(_)) => return None,
+        };
+        // A finished session's worktree is usually gone, and stays gone
+        if worktree.exists() {
+            if let Err(e) = finish_report::write(&worktree, &report) {
+                eprintln!("Warning: Failed to write finish report: {e}");
+            }
+        }
+        Some(report)
+    }
+}
+
+fn finish_in(
+    ctx: &CommandContext,
+    args: FinishArgs,
+    attempt: &mut FinishAttempt,
+) -> Result<FinishOutput> {
+    let config = ctx.config();
+    let current_dir = ctx.current_dir().to_path_buf();
+    let (mut vcs, session_env) = initialize_finish_environment(&args, ctx)?;
+    if let Some(ref branch) = args.remote_branch {
+        let repo = require_git(vcs.as_ref(), "finish --remote-branch")?.repository();
+        return finish_remote(repo, branch, &args, config);
+    }
+    let mut session_manager = ctx.session_manager();
 
     let (session_info, is_worktree_env) =
         resolve_session_info(&args, &session_env, &mut session_manager, &current_dir)?;
+    if let Some(ref session) = session_info {
+        version::check_session(session, !args.check)?;
+    }
+
+    // A bare repository has no working tree to check the branch out in, so
+    // the session is finished inside its own worktree
+    if let Some(session) = session_info.as_ref().filter(|s| !s.is_container()) {
+        if vcs.git().is_some_and(|git| git.repository().is_bare) {
+            vcs = Box::new(GitService::discover_from(&session.worktree_path)?);
+        }
+    }
 
     let feature_branch = determine_feature_branch(&session_info, &session_env)?;
 
+    let session_worktree = if is_worktree_env {
+        Some(current_dir.clone())
+    } else {
+        session_info.as_ref().map(|s| s.worktree_path.clone())
+    };
+    if !session_info.as_ref().is_some_and(|s| s.is_container()) {
+        preflight_finish(vcs.as_ref(), session_worktree.as_deref(), &args)?;
+    }
+
+    let staged = if args.staged_only && !session_info.as_ref().is_some_and(|s| s.is_container()) {
+        Some(staged_split(vcs.as_ref(), session_worktree.as_deref())?)
+    } else {
+        None
+    };
+
+    if args.check {
+        return check_integration(
+            vcs.as_ref(),
+            session_info.as_ref(),
+            &feature_branch,
+            staged.as_ref(),
+        );
+    }
+    if staged.as_ref().is_some_and(|split| split.staged.is_empty()) {
+        return Err(ParaError::invalid_args(
+            "Nothing is staged. Stage what the commit should contain with 'git add', or finish without --staged-only",
+        ));
+    }
+
+    if let Some(ref path) = session_worktree {
+        attempt.start(path, session_info.as_ref())?;
+    }
+    let stack_parent = args
+        .onto_session
+        .as_deref()
+        .map(|name| resolve_stack_parent(&session_manager, session_info.as_ref(), name))
+        .transpose()?;
+
+    let _operation = session_info
+        .as_ref()
+        .map(|s| session_manager.begin_operation(&s.name, SessionOperation::Finishing))
+        .transpose()?;
+
     // Check if this is a container session
     let is_container_session = session_info
         .as_ref()
@@ -278,58 +765,210 @@ pub fn execute(config: Config, args: FinishArgs) -> Result<()> {
         ));
     }
 
+    if let (Some(path), Some(session)) = (&session_worktree, &session_info) {
+        ensure_not_detached(path, &session.name, &session.branch, "finish")?;
+    }
+
+    let mut integrated_into = None;
     let result = if is_container_session {
         // Handle container finish differently
         if let Some(ref session) = session_info {
-            handle_container_finish(session, &args, &config)?
+            handle_container_finish(session, &args, config)?
         } else {
             return Err(ParaError::invalid_args("Container session info not found"));
         }
     } else {
         // Traditional worktree finish
-        perform_pre_finish_operations(&session_info, &feature_branch, &config, &git_service)?;
+        if let Some(ref path) = session_worktree {
+            ensure_not_protected(
+                path,
+                &config.git.protected_branches,
+                args.allow_protected,
+                "finish",
+            )?;
+        }
 
-        let finish_request = FinishRequest {
-            feature_branch: feature_branch.clone(),
-            commit_message: args.message.clone(),
-            target_branch_name: args.branch.clone(),
+        perform_pre_finish_operations(
+            &session_info,
+            &feature_branch,
+            config,
+            vcs.as_ref(),
+            staged.as_ref(),
+        )?;
+
+        let finish_request =
+            build_finish_request(session_info.as_ref(), &feature_branch, &args, config);
+
+        // Only a rename moves the branch away from worktrees that share it
+        let detached = match vcs.git() {
+            Some(git_service) if args.branch.is_some() => DetachedWorktrees::release(
+                git_service.repository(),
+                &feature_branch,
+                session_worktree.as_deref(),
+                args.force_rename,
+            )?,
+            _ => DetachedWorktrees::default(),
         };
 
-        git_service.finish_session(finish_request)?
+        match vcs.finish_session(finish_request) {
+            Ok(FinishResult::Success { final_branch }) => {
+                detached.reattach(&final_branch);
+                if let Some(ref parent) = stack_parent {
+                    let session_name = session_info
+                        .as_ref()
+                        .map(|s| s.name.clone())
+                        .unwrap_or_else(|| final_branch.replace('/', "-"));
+                    let (applied, record) = integration_backup::integrate_with_backup(
+                        &session_manager,
+                        require_git(vcs.as_ref(), "finish --onto-session")?.repository(),
+                        &session_name,
+                        parent,
+                        &final_branch,
+                        &args.message,
+                        config.git.apply_whitespace,
+                    )
+                    .inspect_err(|e| {
+                        attempt.report = Some(FinishReport::integration_failed(
+                            attempt.session.as_deref(),
+                            &final_branch,
+                            e,
+                        ));
+                    })?;
+                    if let Some(ref session) = session_info {
+                        stack::link(&session_manager, &parent.name, &session.name)?;
+                    }
+                    integrated_into = Some(parent.name.clone());
+                    println!(
+                        "{}",
+                        theme::success(format!(
+                            "Integrated into session '{}' (branch {})",
+                            parent.name, parent.branch
+                        ))
+                    );
+                    println!(
+                        "  Backup of {} before integrating: {} (undo with para rollback-integration {})",
+                        parent.branch, record.backup_branch, session_name
+                    );
+                    if applied.ignored_whitespace {
+                        println!("  Whitespace and line-ending differences were ignored on a second attempt");
+                    }
+                }
+                FinishResult::Success { final_branch }
+            }
+            Err(e) => {
+                detached.reattach(&feature_branch);
+                return Err(e);
+            }
+        }
     };
 
+    let mut output = FinishOutput::new(FinishOutcome::Success, session_info.as_ref());
+    output.integrated = integrated_into.is_some();
+    output.integrated_into = integrated_into;
+
     let mut ctx = FinishContext {
         session_info,
         is_worktree_env,
         current_dir: &current_dir,
         feature_branch: &feature_branch,
         session_manager: &mut session_manager,
-        git_service: &git_service,
-        config: &config,
+        vcs: vcs.as_ref(),
+        config,
         args: &args,
     };
 
     match result {
         FinishResult::Success { final_branch } => {
+            // Resolved before the worktree is cleaned up
+            output.commit = vcs
+                .git()
+                .and_then(|git| git.repository().resolve_commit(&final_branch).ok());
+            output.final_branch = Some(final_branch.clone());
+            attempt.report = Some(FinishReport::success(
+                attempt.session.as_deref(),
+                &final_branch,
+            ));
             handle_finish_success(final_branch, &mut ctx)?;
         }
     }
 
-    Ok(())
+    Ok(output)
 }
 
-fn update_final_status(session_state: &SessionState, config: &Config) -> Result<()> {
-    let state_dir = if std::path::Path::new(&config.directories.state_dir).is_absolute() {
-        std::path::PathBuf::from(&config.directories.state_dir)
-    } else {
-        // Get the main repository root for state directory
-        if let Ok(root) = crate::utils::get_main_repository_root() {
-            root.join(&config.directories.state_dir)
-        } else {
-            // Fallback to current directory
-            std::env::current_dir()?.join(&config.directories.state_dir)
+/// The session `--onto-session` names, which must be another active session
+/// Index lock and shallow clone checks on the repository the session is
+/// finished in
+fn preflight_finish(
+    vcs: &dyn VcsBackend,
+    worktree: Option<&Path>,
+    args: &FinishArgs,
+) -> Result<()> {
+    if vcs.git().is_none() {
+        return Ok(());
+    }
+    let repo = GitRepository::discover_from(worktree.unwrap_or(vcs.root()))?;
+    validation::preflight(&repo, RepoOperation::Finish, args.preflight.options())
+}
+
+fn resolve_stack_parent(
+    session_manager: &SessionManager,
+    session_info: Option<&SessionState>,
+    name: &str,
+) -> Result<SessionState> {
+    if session_info.is_some_and(|session| session.name == name) {
+        return Err(ParaError::invalid_args(
+            "A session cannot be finished onto itself",
+        ));
+    }
+    stack::stack_target(session_manager, name)
+}
+
+/// Offer to move the active sessions stacked on the finished session onto
+/// the branch it was finished as
+fn offer_stacked_rebase(
+    session_manager: &SessionManager,
+    session: &SessionState,
+    final_branch: &str,
+) {
+    let children = stack::active_children(session_manager, session);
+    if children.is_empty() {
+        return;
+    }
+
+    let names: Vec<&str> = children.iter().map(|child| child.name.as_str()).collect();
+    println!(
+        "{}",
+        theme::hint(format!(
+            "Stacked on this session: {}. Their branches still build on its unfinished commits",
+            names.join(", ")
+        ))
+    );
+    let rebase = !is_non_interactive()
+        && dialoguer::Confirm::new()
+            .with_prompt(format!("Rebase them onto '{final_branch}'?"))
+            .default(true)
+            .interact()
+            .unwrap_or(false);
+    if !rebase {
+        println!(
+            "  Rebase each one later with: git rebase --onto {final_branch} <its base commit>"
+        );
+        return;
+    }
+
+    for child in &children {
+        match stack::rebase_child(session_manager, child, final_branch) {
+            Ok(()) => println!(
+                "  {}",
+                theme::success(format!("Rebased '{}' onto '{final_branch}'", child.name))
+            ),
+            Err(e) => eprintln!("  Warning: Failed to rebase '{}': {e}", child.name),
         }
-    };
+    }
+}
+
+fn update_final_status(session_state: &SessionState, config: &Config) -> Result<()> {
+    let state_dir = config.resolved_state_dir();
 
     // Load existing status or create new one
     let status = match Status::load(&state_dir, &session_state.name)
@@ -348,9 +987,7 @@ fn update_final_status(session_state: &SessionState, config: &Config) -> Result<
             existing_status.blocked_reason = None;
 
             // Set todos to 100% if they exist
-            if let Some(total) = existing_status.todos_total {
-                existing_status.todos_completed = Some(total);
-            }
+            existing_status.complete_all_todos();
 
             existing_status
         }
@@ -375,7 +1012,9 @@ fn update_final_status(session_state: &SessionState, config: &Config) -> Result<
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::git::{GitRepository, MergeMode};
     use crate::core::session::SessionState;
+    use crate::core::vcs::fake::FakeVcsBackend;
     use crate::test_utils::test_helpers::*;
     use tempfile::TempDir;
 
@@ -385,6 +1024,17 @@ mod tests {
             message: "Test commit message".to_string(),
             branch: None,
             session: None,
+            force_rename: false,
+            allow_protected: false,
+            session_branch: None,
+            check: false,
+            merge_mode: None,
+            onto_session: None,
+            json: false,
+            remote_branch: None,
+            base: None,
+            preflight: Default::default(),
+            staged_only: false,
         };
         assert!(valid_args.validate().is_ok());
 
@@ -392,6 +1042,17 @@ mod tests {
             message: "".to_string(),
             branch: None,
             session: None,
+            force_rename: false,
+            allow_protected: false,
+            session_branch: None,
+            check: false,
+            merge_mode: None,
+            onto_session: None,
+            json: false,
+            remote_branch: None,
+            base: None,
+            preflight: Default::default(),
+            staged_only: false,
         };
         assert!(empty_message_args.validate().is_err());
 
@@ -399,6 +1060,17 @@ mod tests {
             message: "   ".to_string(),
             branch: None,
             session: None,
+            force_rename: false,
+            allow_protected: false,
+            session_branch: None,
+            check: false,
+            merge_mode: None,
+            onto_session: None,
+            json: false,
+            remote_branch: None,
+            base: None,
+            preflight: Default::default(),
+            staged_only: false,
         };
         assert!(whitespace_message_args.validate().is_err());
 
@@ -406,6 +1078,17 @@ mod tests {
             message: "Test message".to_string(),
             branch: Some("-invalid-branch".to_string()),
             session: None,
+            force_rename: false,
+            allow_protected: false,
+            session_branch: None,
+            check: false,
+            merge_mode: None,
+            onto_session: None,
+            json: false,
+            remote_branch: None,
+            base: None,
+            preflight: Default::default(),
+            staged_only: false,
         };
         assert!(invalid_branch_args.validate().is_err());
 
@@ -413,6 +1096,17 @@ mod tests {
             message: "Test message".to_string(),
             branch: Some("custom-branch-name".to_string()),
             session: None,
+            force_rename: false,
+            allow_protected: false,
+            session_branch: None,
+            check: false,
+            merge_mode: None,
+            onto_session: None,
+            json: false,
+            remote_branch: None,
+            base: None,
+            preflight: Default::default(),
+            staged_only: false,
         };
         assert!(short_flag_valid_args.validate().is_ok());
     }
@@ -422,10 +1116,10 @@ mod tests {
         let temp_dir = TempDir::new().unwrap();
         let git_temp = TempDir::new().unwrap();
         let _guard = TestEnvironmentGuard::new(&git_temp, &temp_dir).unwrap();
-        let (_git_temp, git_service) = setup_test_repo();
+        let vcs = FakeVcsBackend::new(git_temp.path());
 
-        let main_env = git_service
-            .validate_session_environment(&git_service.repository().root)
+        let main_env = vcs
+            .session_environment(vcs.root())
             .expect("Failed to validate main repo");
         match main_env {
             SessionEnvironment::MainRepository => {}
@@ -433,12 +1127,11 @@ mod tests {
         }
 
         let worktree_path = git_temp.path().join("test-worktree");
-        git_service
-            .create_worktree("test-branch", &worktree_path)
+        vcs.create_worktree("test-branch", &worktree_path)
             .expect("Failed to create worktree");
 
-        let worktree_env = git_service
-            .validate_session_environment(&worktree_path)
+        let worktree_env = vcs
+            .session_environment(&worktree_path)
             .expect("Failed to validate worktree");
         match worktree_env {
             SessionEnvironment::Worktree { branch, .. } => {
@@ -453,11 +1146,11 @@ mod tests {
         let temp_dir = TempDir::new().unwrap();
         let git_temp = TempDir::new().unwrap();
         let _guard = TestEnvironmentGuard::new(&git_temp, &temp_dir).unwrap();
-        let (_git_temp, git_service) = setup_test_repo();
+        let vcs = FakeVcsBackend::new(git_temp.path());
 
         let config = create_test_config_with_dir(&temp_dir);
         let session_manager = SessionManager::new(&config);
-        let repo_path = git_service.repository().root.clone();
+        let repo_path = vcs.root().to_path_buf();
 
         let session_state = SessionState::new(
             "test-session".to_string(),
@@ -476,18 +1169,152 @@ mod tests {
         assert_eq!(loaded_state.branch, "test-branch");
     }
 
+    #[test]
+    fn test_finish_runs_through_backend() {
+        let temp_dir = TempDir::new().unwrap();
+        let git_temp = TempDir::new().unwrap();
+        let _guard = TestEnvironmentGuard::new(&git_temp, &temp_dir).unwrap();
+        let vcs = FakeVcsBackend::new(git_temp.path());
+
+        let mut config = create_test_config_with_dir(&temp_dir);
+        config.git.auto_stage = true;
+        let mut session_manager = SessionManager::new(&config);
+        let worktree_path = vcs
+            .create_worktree("test/backend", &vcs.root().join("subtrees/backend"))
+            .unwrap();
+        vcs.add_commit("test/backend", "WIP");
+        let mut session_state = SessionState::new(
+            "backend".to_string(),
+            "test/backend".to_string(),
+            worktree_path.clone(),
+        );
+        session_state.parent_branch = Some("main".to_string());
+        session_manager.save_state(&session_state).unwrap();
+        vcs.set_status(
+            vcs.root(),
+            WorktreeStatus {
+                modified: 2,
+                ..Default::default()
+            },
+        );
+
+        let session_info = Some(session_state);
+        perform_pre_finish_operations(&session_info, "test/backend", &config, &vcs, None).unwrap();
+        assert_eq!(vcs.worktree_status(vcs.root()).unwrap().staged, 2);
+
+        let args = FinishArgs {
+            message: "Add backend".to_string(),
+            branch: Some("feature/backend".to_string()),
+            session: None,
+            force_rename: false,
+            allow_protected: false,
+            session_branch: None,
+            check: false,
+            merge_mode: None,
+            onto_session: None,
+            json: false,
+            remote_branch: None,
+            base: None,
+            preflight: Default::default(),
+            staged_only: false,
+        };
+        let request = build_finish_request(session_info.as_ref(), "test/backend", &args, &config);
+        let FinishResult::Success { final_branch } = vcs.finish_session(request).unwrap();
+        assert_eq!(final_branch, "feature/backend");
+        assert_eq!(
+            vcs.commits("feature/backend").unwrap(),
+            vec!["Initial commit", "Add backend"]
+        );
+        assert!(!vcs.branch_exists("test/backend").unwrap());
+
+        let mut ctx = FinishContext {
+            session_info,
+            is_worktree_env: false,
+            current_dir: vcs.root(),
+            feature_branch: "test/backend",
+            session_manager: &mut session_manager,
+            vcs: &vcs,
+            config: &config,
+            args: &args,
+        };
+        handle_finish_success(final_branch, &mut ctx).unwrap();
+
+        assert!(!worktree_path.exists());
+        assert_eq!(vcs.list_worktrees().unwrap().len(), 1);
+        let finished = session_manager.load_state("backend").unwrap();
+        assert!(matches!(finished.status, SessionStatus::Review));
+    }
+
+    #[test]
+    fn test_dirty_worktree_is_kept_after_finish() {
+        let temp_dir = TempDir::new().unwrap();
+        let git_temp = TempDir::new().unwrap();
+        let _guard = TestEnvironmentGuard::new(&git_temp, &temp_dir).unwrap();
+        let vcs = FakeVcsBackend::new(git_temp.path());
+
+        let config = create_test_config_with_dir(&temp_dir);
+        let mut session_manager = SessionManager::new(&config);
+        let worktree_path = vcs
+            .create_worktree("test/dirty", &vcs.root().join("subtrees/dirty"))
+            .unwrap();
+        let session_state = SessionState::new(
+            "dirty".to_string(),
+            "test/dirty".to_string(),
+            worktree_path.clone(),
+        );
+        session_manager.save_state(&session_state).unwrap();
+        vcs.set_status(
+            &worktree_path,
+            WorktreeStatus {
+                untracked: 1,
+                ..Default::default()
+            },
+        );
+
+        let args = FinishArgs {
+            message: "Finish dirty".to_string(),
+            branch: None,
+            session: None,
+            force_rename: false,
+            allow_protected: false,
+            session_branch: None,
+            check: false,
+            merge_mode: None,
+            onto_session: None,
+            json: false,
+            remote_branch: None,
+            base: None,
+            preflight: Default::default(),
+            staged_only: false,
+        };
+        let mut ctx = FinishContext {
+            session_info: Some(session_state),
+            is_worktree_env: false,
+            current_dir: vcs.root(),
+            feature_branch: "test/dirty",
+            session_manager: &mut session_manager,
+            vcs: &vcs,
+            config: &config,
+            args: &args,
+        };
+        handle_finish_success("test/dirty".to_string(), &mut ctx).unwrap();
+
+        assert!(worktree_path.exists());
+        assert_eq!(vcs.list_worktrees().unwrap().len(), 2);
+    }
+
     #[test]
     fn test_cleanup_session_state_fallback() {
         // Use setup_test_repo to create a proper git repository
         let temp_dir = TempDir::new().unwrap();
         let git_temp = TempDir::new().unwrap();
         let _guard = TestEnvironmentGuard::new(&git_temp, &temp_dir).unwrap();
-        let (_git_temp, git_service) = setup_test_repo();
+        let vcs = FakeVcsBackend::new(git_temp.path());
 
         // Create config using the git repo path
         let config = create_test_config_with_dir(&temp_dir);
         let mut session_manager = SessionManager::new(&config);
-        let repo_path = git_service.repository().root.clone();
+        let repo_path = vcs.root().to_path_buf();
 
         // Create a session state that would normally be found by path
         let session_state = SessionState::new(
@@ -563,12 +1390,12 @@ mod tests {
         let temp_dir = TempDir::new().unwrap();
         let git_temp = TempDir::new().unwrap();
         let _guard = TestEnvironmentGuard::new(&git_temp, &temp_dir).unwrap();
-        let (_git_temp, git_service) = setup_test_repo();
+        let vcs = FakeVcsBackend::new(git_temp.path());
 
         let mut config = create_test_config_with_dir(&temp_dir);
         config.session.preserve_on_finish = true; // Enable preserve mode for this test
         let mut session_manager = SessionManager::new(&config);
-        let repo_path = git_service.repository().root.clone();
+        let repo_path = vcs.root().to_path_buf();
 
         let session_state = SessionState::new(
             "preserve-test-session".to_string(),
@@ -603,12 +1430,12 @@ mod tests {
         let temp_dir = TempDir::new().unwrap();
         let git_temp = TempDir::new().unwrap();
         let _guard = TestEnvironmentGuard::new(&git_temp, &temp_dir).unwrap();
-        let (_git_temp, git_service) = setup_test_repo();
+        let vcs = FakeVcsBackend::new(git_temp.path());
 
         let mut config = create_test_config_with_dir(&temp_dir);
         config.session.preserve_on_finish = true;
         let mut session_manager = SessionManager::new(&config);
-        let repo_path = git_service.repository().root.clone();
+        let repo_path = vcs.root().to_path_buf();
 
         let session_state = SessionState::new(
             "lifecycle-test-session".to_string(),
@@ -653,12 +1480,12 @@ mod tests {
         let temp_dir = TempDir::new().unwrap();
         let git_temp = TempDir::new().unwrap();
         let _guard = TestEnvironmentGuard::new(&git_temp, &temp_dir).unwrap();
-        let (_git_temp, git_service) = setup_test_repo();
+        let vcs = FakeVcsBackend::new(git_temp.path());
 
         let mut config = create_test_config_with_dir(&temp_dir);
         config.session.preserve_on_finish = true;
         let mut session_manager = SessionManager::new(&config);
-        let repo_path = git_service.repository().root.clone();
+        let repo_path = vcs.root().to_path_buf();
 
         let worktree_path = repo_path.join("test-worktree");
 
@@ -697,12 +1524,12 @@ mod tests {
         let temp_dir = TempDir::new().unwrap();
         let git_temp = TempDir::new().unwrap();
         let _guard = TestEnvironmentGuard::new(&git_temp, &temp_dir).unwrap();
-        let (_git_temp, git_service) = setup_test_repo();
+        let vcs = FakeVcsBackend::new(git_temp.path());
 
         let mut config = create_test_config_with_dir(&temp_dir);
         config.session.preserve_on_finish = false; // No preservation
         let mut session_manager = SessionManager::new(&config);
-        let repo_path = git_service.repository().root.clone();
+        let repo_path = vcs.root().to_path_buf();
 
         let session_state = SessionState::new(
             "no-preserve-test".to_string(),
@@ -737,11 +1564,11 @@ mod tests {
         let temp_dir = TempDir::new().unwrap();
         let git_temp = TempDir::new().unwrap();
         let _guard = TestEnvironmentGuard::new(&git_temp, &temp_dir).unwrap();
-        let (_git_temp, git_service) = setup_test_repo();
+        let vcs = FakeVcsBackend::new(git_temp.path());
 
         let config = create_test_config_with_dir(&temp_dir);
         let mut session_manager = SessionManager::new(&config);
-        let repo_path = git_service.repository().root.clone();
+        let repo_path = vcs.root().to_path_buf();
         let state_dir = std::path::Path::new(&config.directories.state_dir);
 
         let session_state = SessionState::new(
@@ -797,11 +1624,11 @@ mod tests {
         let temp_dir = TempDir::new().unwrap();
         let git_temp = TempDir::new().unwrap();
         let _guard = TestEnvironmentGuard::new(&git_temp, &temp_dir).unwrap();
-        let (_git_temp, git_service) = setup_test_repo();
+        let vcs = FakeVcsBackend::new(git_temp.path());
 
         let config = create_test_config_with_dir(&temp_dir);
         let mut session_manager = SessionManager::new(&config);
-        let repo_path = git_service.repository().root.clone();
+        let repo_path = vcs.root().to_path_buf();
         let state_dir = std::path::Path::new(&config.directories.state_dir);
 
         let session_state = SessionState::new(
@@ -849,11 +1676,11 @@ mod tests {
         let temp_dir = TempDir::new().unwrap();
         let git_temp = TempDir::new().unwrap();
         let _guard = TestEnvironmentGuard::new(&git_temp, &temp_dir).unwrap();
-        let (_git_temp, git_service) = setup_test_repo();
+        let vcs = FakeVcsBackend::new(git_temp.path());
 
         let config = create_test_config_with_dir(&temp_dir);
         let mut session_manager = SessionManager::new(&config);
-        let repo_path = git_service.repository().root.clone();
+        let repo_path = vcs.root().to_path_buf();
         let state_dir = std::path::Path::new(&config.directories.state_dir);
 
         // Test 1: Failed status should be preserved
@@ -1147,4 +1974,636 @@ mod tests {
 
         println!("✓ State directory fix verified - IDE closing will use correct path: {expected_state_dir}");
     }
+
+    #[test]
+    fn test_finish_request_uses_recorded_merge_mode_unless_overridden() {
+        let mut config = create_test_config();
+        config.git.default_merge_mode = Some(MergeMode::Rebase);
+        let mut session = SessionState::new(
+            "agent".to_string(),
+            "para/agent".to_string(),
+            std::path::PathBuf::from("/tmp/agent"),
+        );
+        session.merge_mode = MergeMode::PreserveCommits;
+        let mut args = FinishArgs {
+            message: "Done".to_string(),
+            branch: None,
+            session: None,
+            force_rename: false,
+            allow_protected: false,
+            session_branch: None,
+            check: false,
+            merge_mode: None,
+            onto_session: None,
+            json: false,
+            remote_branch: None,
+            base: None,
+            preflight: Default::default(),
+            staged_only: false,
+        };
+
+        let request = build_finish_request(Some(&session), "para/agent", &args, &config);
+        assert_eq!(request.merge_mode, MergeMode::PreserveCommits);
+
+        let request = build_finish_request(None, "para/agent", &args, &config);
+        assert_eq!(request.merge_mode, MergeMode::Rebase);
+
+        args.merge_mode = Some(MergeMode::Squash);
+        let request = build_finish_request(Some(&session), "para/agent", &args, &config);
+        assert_eq!(request.merge_mode, MergeMode::Squash);
+    }
+
+    #[test]
+    fn test_interactive_finish_leaves_no_report() {
+        let worktree = TempDir::new().unwrap();
+        let mut attempt = FinishAttempt::new(false);
+        attempt.start(worktree.path(), None).unwrap();
+
+        let report = attempt.conclude::<()>(&Err(ParaError::git_operation("failed")));
+
+        assert_eq!(report, None);
+        assert!(!finish_report::report_path(worktree.path()).exists());
+    }
+
+    #[test]
+    fn test_config_override_disables_auto_stage_for_one_finish() {
+        use crate::cli::parser::{Cli, Commands};
+        use crate::config::overrides::apply_overrides;
+        use clap::Parser;
+
+        let (git_temp, git_service) = setup_test_repo();
+        let config = create_test_config_with_dir(&git_temp);
+        let mut session_manager =
+            SessionManager::for_repository(&config, &git_service.repository().root);
+        let session = session_manager
+            .create_session_with_all_flags("override".to_string(), None, false, false, None)
+            .unwrap();
+
+        let worktree = &session.worktree_path;
+        std::fs::write(worktree.join("README.md"), "# Staged by hand").unwrap();
+        std::fs::write(worktree.join("scratch.txt"), "not for the commit").unwrap();
+        let session_repo = GitRepository::discover_from(worktree).unwrap();
+        crate::core::git::repository::execute_git_command(&session_repo, &["add", "README.md"])
+            .unwrap();
+
+        let cli = Cli::try_parse_from([
+            "para",
+            "-c",
+            "git.auto_stage=false",
+            "finish",
+            "Update readme",
+        ])
+        .unwrap();
+        let Some(Commands::Finish(args)) = cli.command else {
+            panic!("expected finish");
+        };
+        let overridden = apply_overrides(&config, &cli.config_overrides).unwrap();
+        execute_in(&CommandContext::in_dir(overridden, worktree.clone()), args).unwrap();
+
+        let repo = git_service.repository();
+        let files = crate::core::git::repository::execute_git_command(
+            repo,
+            &["show", "--name-only", "--format=", &session.branch],
+        )
+        .unwrap();
+        assert_eq!(files.trim(), "README.md");
+        assert!(config.git.auto_stage);
+    }
+
+    #[test]
+    fn test_staged_only_commits_the_index_and_keeps_the_rest() {
+        use crate::cli::parser::{Cli, Commands};
+        use crate::core::git::repository::execute_git_command;
+        use clap::Parser;
+
+        let (git_temp, git_service) = setup_test_repo();
+        let mut config = create_test_config_with_dir(&git_temp);
+        config.session.preserve_on_finish = true;
+        let mut session_manager =
+            SessionManager::for_repository(&config, &git_service.repository().root);
+        let session = session_manager
+            .create_session_with_all_flags("staged".to_string(), None, false, false, None)
+            .unwrap();
+        let worktree = &session.worktree_path;
+        let session_repo = GitRepository::discover_from(worktree).unwrap();
+        let parse = |argv: &[&str]| {
+            let Some(Commands::Finish(args)) = Cli::try_parse_from(argv).unwrap().command else {
+                panic!("expected finish");
+            };
+            args
+        };
+        let ctx = CommandContext::in_dir(config.clone(), worktree.clone());
+
+        std::fs::write(worktree.join("scratch.txt"), "not for the commit").unwrap();
+        let err =
+            execute_in(&ctx, parse(&["para", "finish", "--staged-only", "Nothing"])).unwrap_err();
+        assert!(err.to_string().contains("Nothing is staged"), "{err}");
+
+        std::fs::write(worktree.join("feature.txt"), "feature").unwrap();
+        std::fs::write(worktree.join("README.md"), "# Staged by hand").unwrap();
+        execute_git_command(&session_repo, &["add", "feature.txt", "README.md"]).unwrap();
+        std::fs::write(worktree.join("README.md"), "# Staged by hand, then more").unwrap();
+
+        let args = parse(&["para", "finish", "--staged-only", "Add feature"]);
+        assert!(args.staged_only);
+        execute_in(&ctx, args).unwrap();
+
+        let files = execute_git_command(
+            git_service.repository(),
+            &["show", "--name-only", "--format=", &session.branch],
+        )
+        .unwrap();
+        assert_eq!(files, "README.md\nfeature.txt");
+        let committed = execute_git_command(
+            git_service.repository(),
+            &["show", &format!("{}:README.md", session.branch)],
+        )
+        .unwrap();
+        assert_eq!(committed, "# Staged by hand");
+
+        // The session keeps its worktree with the changes that were left out
+        assert_eq!(
+            std::fs::read_to_string(worktree.join("README.md")).unwrap(),
+            "# Staged by hand, then more"
+        );
+        assert!(worktree.join("scratch.txt").exists());
+        let split = session_repo.staged_split().unwrap();
+        assert!(split.staged.is_empty());
+        assert_eq!(split.left_behind, ["README.md", "scratch.txt"]);
+    }
+
+    #[test]
+    fn test_json_output_round_trips() {
+        use crate::cli::json_output::render;
+        use crate::cli::parser::{Cli, Commands};
+        use clap::Parser;
+
+        let (git_temp, git_service) = setup_test_repo();
+        let config = create_test_config_with_dir(&git_temp);
+        let mut session_manager =
+            SessionManager::for_repository(&config, &git_service.repository().root);
+        let session = session_manager
+            .create_session_with_all_flags("jsonout".to_string(), None, false, false, None)
+            .unwrap();
+        std::fs::write(session.worktree_path.join("feature.txt"), "feature").unwrap();
+
+        let cli = Cli::try_parse_from(["para", "finish", "--json", "Add feature"]).unwrap();
+        let Some(Commands::Finish(args)) = cli.command else {
+            panic!("expected finish");
+        };
+        assert!(args.json);
+        let ctx = CommandContext::in_dir(config, session.worktree_path.clone());
+        let output = execute_in(&ctx, args).unwrap();
+
+        assert_eq!(output.result, FinishOutcome::Success);
+        assert_eq!(output.session.as_deref(), Some("jsonout"));
+        assert!(git_service
+            .branch_exists(output.final_branch.as_deref().unwrap())
+            .unwrap());
+        assert!(!output.integrated);
+        assert!(output.conflicted_files.is_empty());
+
+        let line = render(&output).unwrap();
+        assert!(line.contains("\"schema_version\":1"), "{line}");
+        assert!(line.contains("\"result\":\"success\""), "{line}");
+        let parsed: FinishOutput = serde_json::from_str(&line).unwrap();
+        assert_eq!(parsed, output);
+    }
+
+    fn finish_json(worktree: &Path, config: &Config, argv: &[&str]) -> Result<FinishOutput> {
+        use crate::cli::parser::{Cli, Commands};
+        use clap::Parser;
+
+        let cli = Cli::try_parse_from(["para", "finish", "--json"].iter().chain(argv)).unwrap();
+        let Some(Commands::Finish(args)) = cli.command else {
+            panic!("expected finish");
+        };
+        execute_in(
+            &CommandContext::in_dir(config.clone(), worktree.to_path_buf()),
+            args,
+        )
+    }
+
+    #[test]
+    fn test_finish_report_records_success() {
+        let (git_temp, git_service) = setup_test_repo();
+        let mut config = create_test_config_with_dir(&git_temp);
+        config.session.preserve_on_finish = true;
+        let manager = SessionManager::for_repository(&config, &git_service.repository().root);
+        let session = start_session(&git_service, &manager, "reported", "main");
+        let worktree = &session.worktree_path;
+        std::fs::create_dir_all(worktree.join(".para")).unwrap();
+        std::fs::write(finish_report::report_path(worktree), "left from before").unwrap();
+        std::fs::write(worktree.join("feature.txt"), "feature").unwrap();
+
+        let output = finish_json(worktree, &config, &["Add feature"]).unwrap();
+
+        let json: serde_json::Value = serde_json::from_str(
+            &std::fs::read_to_string(finish_report::report_path(worktree)).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "version": finish_report::FINISH_REPORT_VERSION,
+                "result": "success",
+                "session": "reported",
+                "final_branch": output.final_branch.unwrap(),
+                "conflicted_files": [],
+            })
+        );
+        assert_eq!(git(worktree, &["status", "--porcelain"]), "");
+    }
+
+    #[test]
+    fn test_finish_report_records_integration_failure() {
+        let (git_temp, git_service) = setup_test_repo();
+        let config = create_test_config_with_dir(&git_temp);
+        let manager = SessionManager::for_repository(&config, &git_service.repository().root);
+        let parent = start_session(&git_service, &manager, "parent", "main");
+        let child = start_session(&git_service, &manager, "child", &parent.branch);
+        std::fs::write(child.worktree_path.join("child.txt"), "child").unwrap();
+        std::fs::write(parent.worktree_path.join("scratch.txt"), "uncommitted").unwrap();
+
+        let err = finish_json(
+            &child.worktree_path,
+            &config,
+            &["--onto-session", "parent", "Add child"],
+        )
+        .unwrap_err();
+
+        let report = finish_report::read(&child.worktree_path).unwrap().unwrap();
+        assert_eq!(
+            report.result,
+            finish_report::FinishReportResult::SuccessWithIntegrationFailure
+        );
+        assert_eq!(report.session.as_deref(), Some("child"));
+        let final_branch = report.final_branch.unwrap();
+        assert!(git_service.branch_exists(&final_branch).unwrap());
+        assert_eq!(report.integration_error, Some(err.to_string()));
+        assert!(err.to_string().contains("uncommitted changes"), "{err}");
+        assert!(report.conflicted_files.is_empty());
+    }
+
+    #[test]
+    fn test_finish_report_records_conflicts() {
+        let (git_temp, git_service) = setup_test_repo();
+        let config = create_test_config_with_dir(&git_temp);
+        let manager = SessionManager::for_repository(&config, &git_service.repository().root);
+        let session = start_session(&git_service, &manager, "conflicted", "main");
+        commit_file(
+            &session.worktree_path,
+            "README.md",
+            "# Session",
+            "Edit readme",
+        );
+        // The state directory is inside the test repository, so only the
+        // tracked file is committed
+        std::fs::write(git_temp.path().join("README.md"), "# Main").unwrap();
+        git(git_temp.path(), &["commit", "-qam", "Edit readme on main"]);
+
+        let err = finish_json(
+            &session.worktree_path,
+            &config,
+            &["--merge-mode", "rebase", "Edit readme"],
+        )
+        .unwrap_err();
+
+        let report = finish_report::read(&session.worktree_path)
+            .unwrap()
+            .unwrap();
+        assert_eq!(report.result, finish_report::FinishReportResult::Conflicts);
+        assert_eq!(report.conflicted_files, ["README.md"]);
+        assert_eq!(report.final_branch, None);
+        assert_eq!(report.integration_error, Some(err.to_string()));
+    }
+
+    #[test]
+    fn test_check_conflict_error_names_files_for_ci() {
+        let (git_temp, git_service) = setup_test_repo();
+        let config = create_test_config_with_dir(&git_temp);
+        let repo = git_service.repository();
+        let mut session_manager = SessionManager::for_repository(&config, &repo.root);
+        let session = session_manager
+            .create_session_with_all_flags("clash".to_string(), None, false, false, None)
+            .unwrap();
+        let worktree = GitRepository::discover_from(&session.worktree_path).unwrap();
+        std::fs::write(session.worktree_path.join("shared.txt"), "session").unwrap();
+        worktree.stage_all_changes().unwrap();
+        worktree.commit("Session change").unwrap();
+        std::fs::write(repo.root.join("shared.txt"), "main").unwrap();
+        for args in [
+            &["add", "shared.txt"][..],
+            &["commit", "-q", "-m", "Main change"],
+        ] {
+            crate::core::git::repository::execute_git_command(repo, args).unwrap();
+        }
+
+        let ctx = CommandContext::in_dir(config, session.worktree_path.clone());
+        let args = FinishArgs {
+            message: String::new(),
+            branch: None,
+            session: None,
+            force_rename: false,
+            allow_protected: false,
+            session_branch: None,
+            check: true,
+            merge_mode: None,
+            onto_session: None,
+            json: false,
+            remote_branch: None,
+            base: None,
+            preflight: Default::default(),
+            staged_only: false,
+        };
+        let error = execute(&ctx, args).unwrap_err();
+        assert_eq!(error.paths(), vec!["shared.txt"]);
+        assert_eq!(
+            crate::utils::ci::error_annotations(&error),
+            vec!["::error file=shared.txt::Integration would conflict: 1 file(s) conflict with 'main'"]
+        );
+    }
+
+    #[test]
+    fn test_finish_discovers_its_repository_once() {
+        use crate::core::git::discoveries;
+
+        let (git_temp, git_service) = setup_test_repo();
+        let config = create_test_config_with_dir(&git_temp);
+        let mut session_manager =
+            SessionManager::for_repository(&config, &git_service.repository().root);
+        let session = session_manager
+            .create_session_with_all_flags("discover".to_string(), None, false, false, None)
+            .unwrap();
+        std::fs::write(session.worktree_path.join("feature.txt"), "feature").unwrap();
+
+        discoveries::take();
+        let ctx = CommandContext::in_dir(config, session.worktree_path.clone());
+        let args = FinishArgs {
+            message: "Add feature".to_string(),
+            branch: None,
+            session: None,
+            force_rename: false,
+            allow_protected: false,
+            session_branch: None,
+            check: false,
+            merge_mode: None,
+            onto_session: None,
+            json: false,
+            remote_branch: None,
+            base: None,
+            preflight: Default::default(),
+            staged_only: false,
+        };
+        execute_in(&ctx, args).unwrap();
+        assert_eq!(discoveries::take_distinct().len(), 1);
+    }
+
+    /// Files in a bare repository other than its refs, objects and logs
+    fn bare_repository_files(bare: &std::path::Path) -> std::collections::BTreeSet<String> {
+        fn walk(dir: &std::path::Path, base: &std::path::Path, out: &mut Vec<String>) {
+            for entry in std::fs::read_dir(dir).unwrap().flatten() {
+                let path = entry.path();
+                if path.is_dir() {
+                    walk(&path, base, out);
+                } else {
+                    out.push(path.strip_prefix(base).unwrap().display().to_string());
+                }
+            }
+        }
+        let mut files = Vec::new();
+        walk(bare, bare, &mut files);
+        files
+            .into_iter()
+            .filter(|file| {
+                !["objects", "refs", "logs", "worktrees"]
+                    .iter()
+                    .any(|internal| file.starts_with(internal))
+                    && !["packed-refs", "ORIG_HEAD", "FETCH_HEAD"].contains(&file.as_str())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_bare_repository_session_lifecycle() {
+        use std::process::Command;
+
+        let temp = TempDir::new().unwrap();
+        let bare = temp.path().join("app.git");
+        let seed = temp.path().join("seed");
+        let git = |dir: &std::path::Path, args: &[&str]| {
+            let output = Command::new("git")
+                .current_dir(dir)
+                .args(args)
+                .output()
+                .unwrap();
+            assert!(
+                output.status.success(),
+                "git {args:?}: {}",
+                String::from_utf8_lossy(&output.stderr)
+            );
+            String::from_utf8_lossy(&output.stdout).trim().to_string()
+        };
+        git(
+            temp.path(),
+            &["init", "--quiet", "--bare", "-b", "main", "app.git"],
+        );
+        git(&bare, &["config", "user.name", "Test"]);
+        git(&bare, &["config", "user.email", "test@example.com"]);
+        git(temp.path(), &["clone", "--quiet", "app.git", "seed"]);
+        std::fs::write(seed.join("README.md"), "# App").unwrap();
+        git(&seed, &["add", "."]);
+        git(
+            &seed,
+            &[
+                "-c",
+                "user.name=Test",
+                "-c",
+                "user.email=test@example.com",
+                "commit",
+                "--quiet",
+                "-m",
+                "Initial commit",
+            ],
+        );
+        git(&seed, &["push", "--quiet", "origin", "HEAD:main"]);
+        let before = bare_repository_files(&bare);
+
+        let mut config = create_test_config_with_dir(&temp);
+        let mut session_manager = SessionManager::for_repository(&config, &bare);
+        assert!(session_manager
+            .create_session_with_all_flags("nope".to_string(), None, false, false, None)
+            .is_err());
+
+        config.directories.bare_root = Some("{repo}.para".to_string());
+        let mut session_manager = SessionManager::for_repository(&config, &bare);
+        let session = session_manager
+            .create_session_with_all_flags("bare".to_string(), None, false, false, None)
+            .unwrap();
+        assert!(session
+            .worktree_path
+            .starts_with(temp.path().join("app.para").join("subtrees")));
+        assert!(GitRepository::discover_from(&bare)
+            .unwrap()
+            .checkout_branch(&session.branch)
+            .is_err());
+
+        std::fs::write(session.worktree_path.join("feature.txt"), "feature").unwrap();
+        let args = FinishArgs {
+            message: "Add feature".to_string(),
+            branch: None,
+            session: Some("bare".to_string()),
+            force_rename: false,
+            allow_protected: false,
+            session_branch: None,
+            check: false,
+            merge_mode: None,
+            onto_session: None,
+            json: false,
+            remote_branch: None,
+            base: None,
+            preflight: Default::default(),
+            staged_only: false,
+        };
+        let output = execute_in(&CommandContext::in_dir(config, bare.clone()), args).unwrap();
+        let final_branch = output.final_branch.unwrap();
+
+        assert_eq!(
+            git(&bare, &["log", "-1", "--format=%s", &final_branch]),
+            "Add feature"
+        );
+        assert_eq!(
+            git(&bare, &["show", &format!("{final_branch}:feature.txt")]),
+            "feature"
+        );
+        assert_eq!(git(&bare, &["rev-parse", "--abbrev-ref", "HEAD"]), "main");
+        assert_eq!(bare_repository_files(&bare), before);
+    }
+
+    #[test]
+    fn test_session_lifecycle_under_path_with_spaces_and_accents() {
+        use crate::cli::parser::{CancelArgs, Cli, Commands};
+        use clap::Parser;
+        use std::process::Command;
+
+        let temp = TempDir::new().unwrap();
+        let home = temp.path().join("José María");
+        let repo = home.join("Bob's dev repo");
+        std::fs::create_dir_all(&repo).unwrap();
+        std::fs::write(repo.join("README.md"), "# App").unwrap();
+        let git = |args: &[&str]| {
+            let output = Command::new("git")
+                .current_dir(&repo)
+                .args(args)
+                .output()
+                .unwrap();
+            assert!(
+                output.status.success(),
+                "git {args:?}: {}",
+                String::from_utf8_lossy(&output.stderr)
+            );
+            String::from_utf8_lossy(&output.stdout).trim().to_string()
+        };
+        git(&["init", "--quiet", "-b", "main"]);
+        git(&["config", "user.name", "Test"]);
+        git(&["config", "user.email", "test@example.com"]);
+        git(&["add", "."]);
+        git(&["commit", "--quiet", "-m", "Initial commit"]);
+
+        let mut config = create_test_config_with_dir(&temp);
+        config.directories.state_dir = home.join("para state").to_string_lossy().to_string();
+        config.ide.name = "claude".to_string();
+        config.ide.command = "claude".to_string();
+        config.ide.wrapper.enabled = true;
+        config.ide.wrapper.name = "code".to_string();
+        config.ide.wrapper.command = "echo".to_string();
+        let ctx = CommandContext::in_dir(config.clone(), repo.clone());
+        let start = |args: &[&str]| {
+            let cli =
+                Cli::try_parse_from(["para", "start", "--no-stdin"].iter().chain(args)).unwrap();
+            let Some(Commands::Start(args)) = cli.command else {
+                unreachable!()
+            };
+            crate::cli::commands::unified_start::execute(&ctx, args).unwrap();
+        };
+
+        start(&["started"]);
+        let prompt = "Add a greeting, don't 'quote' it";
+        start(&["dispatched", "-p", prompt]);
+
+        // The task runs the agent with the prompt, read back from its file
+        let session_manager = SessionManager::for_repository(&config, &repo);
+        let dispatched = session_manager.load_state("dispatched").unwrap();
+        let tasks =
+            std::fs::read_to_string(dispatched.worktree_path.join(".vscode/tasks.json")).unwrap();
+        let tasks: serde_json::Value = serde_json::from_str(&tasks).unwrap();
+        let command = tasks["tasks"][0]["command"].as_str().unwrap();
+        let printed = Command::new("sh")
+            .current_dir(&dispatched.worktree_path)
+            .arg("-c")
+            .arg(command.replacen("claude", "printf %s", 1))
+            .output()
+            .unwrap();
+        assert_eq!(String::from_utf8_lossy(&printed.stdout), prompt);
+
+        let started = session_manager.load_state("started").unwrap();
+        assert!(started.worktree_path.starts_with(&repo));
+        std::fs::write(started.worktree_path.join("naïve.txt"), "feature").unwrap();
+        let args = FinishArgs {
+            message: "Add feature".to_string(),
+            branch: None,
+            session: Some("started".to_string()),
+            force_rename: false,
+            allow_protected: false,
+            session_branch: None,
+            check: false,
+            merge_mode: None,
+            onto_session: None,
+            json: false,
+            remote_branch: None,
+            base: None,
+            preflight: Default::default(),
+            staged_only: false,
+        };
+        let output = execute_in(
+            &CommandContext::in_dir(config.clone(), started.worktree_path.clone()),
+            args,
+        )
+        .unwrap();
+        let final_branch = output.final_branch.unwrap();
+        assert_eq!(
+            git(&["show", &format!("{final_branch}:naïve.txt")]),
+            "feature"
+        );
+
+        let args = CancelArgs {
+            session: Some("dispatched".to_string()),
+            force: true,
+            force_rename: false,
+            allow_protected: false,
+            session_branch: None,
+            close_ide: false,
+            json: false,
+            dry_run: false,
+        };
+        crate::cli::commands::cancel::execute(&ctx, args).unwrap();
+        assert!(!session_manager.session_exists("dispatched"));
+        assert!(!dispatched.worktree_path.exists());
+    }
+
+    #[test]
+    fn test_config_override_unknown_key_is_rejected() {
+        use crate::cli::parser::Cli;
+        use crate::config::overrides::apply_overrides;
+        use clap::Parser;
+
+        let cli =
+            Cli::try_parse_from(["para", "-c", "git.autostage=false", "finish", "msg"]).unwrap();
+        let err = apply_overrides(&create_test_config(), &cli.config_overrides)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Unknown config key 'git.autostage'"), "{err}");
+
+        assert!(Cli::try_parse_from(["para", "-c", "git.auto_stage", "finish", "msg"]).is_err());
+    }
 }
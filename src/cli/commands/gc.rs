@@ -0,0 +1,33 @@
+use crate::cli::context::CommandContext;
+use crate::core::gc::{self, RetentionPolicy};
+use crate::utils::Result;
+
+pub fn execute(ctx: &CommandContext) -> Result<()> {
+    let session_manager = ctx.session_manager();
+    let policy = RetentionPolicy::from_config(ctx.config());
+    let report = gc::collect_garbage(session_manager.state_dir(), &policy)?;
+
+    if report.is_empty() {
+        println!("🧹 Nothing to collect - state directory is already compact");
+        return Ok(());
+    }
+
+    if report.logs_truncated > 0 {
+        println!(
+            "✂️  Truncated {} log file(s), dropping {} entries",
+            report.logs_truncated, report.entries_removed
+        );
+    }
+    if report.logs_rotated > 0 {
+        println!("🔄 Rotated para.log");
+    }
+    if report.orphaned_files_removed > 0 {
+        println!(
+            "🗑️  Removed {} leftover session file(s)",
+            report.orphaned_files_removed
+        );
+    }
+    println!("✅ Reclaimed {}", gc::format_bytes(report.bytes_reclaimed));
+
+    Ok(())
+}
@@ -4,15 +4,10 @@ use std::env;
 use std::fs;
 use std::path::{Path, PathBuf};
 
+use crate::cli::commands::common::is_non_interactive;
 use crate::cli::parser::Shell;
 use crate::utils::{ParaError, Result};
 
-fn is_non_interactive() -> bool {
-    std::env::var("PARA_NON_INTERACTIVE").is_ok()
-        || std::env::var("CI").is_ok()
-        || !atty::is(atty::Stream::Stdin)
-}
-
 pub fn execute() -> Result<()> {
     println!("Initializing para shell completions...\n");
 
@@ -39,13 +34,14 @@ pub fn execute() -> Result<()> {
         Shell::Bash => println!("  source ~/.bashrc"),
         Shell::Zsh => println!("  source ~/.zshrc"),
         Shell::Fish => println!("  source ~/.config/fish/config.fish"),
+        Shell::PowerShell => println!("  . $PROFILE"),
     }
     println!("\nOr restart your terminal.");
 
     Ok(())
 }
 
-fn detect_shell() -> Result<Shell> {
+pub fn detect_shell() -> Result<Shell> {
     if let Ok(shell_env) = env::var("SHELL") {
         if let Some(shell) = parse_shell_from_path(&shell_env) {
             return Ok(shell);
@@ -65,7 +61,7 @@ fn detect_shell() -> Result<Shell> {
     }
 
     println!("Unable to detect shell automatically.");
-    let shells = vec!["bash", "zsh", "fish"];
+    let shells = vec!["bash", "zsh", "fish", "powershell"];
     let selection = Select::new()
         .with_prompt("Please select your shell")
         .items(&shells)
@@ -76,6 +72,7 @@ fn detect_shell() -> Result<Shell> {
         0 => Ok(Shell::Bash),
         1 => Ok(Shell::Zsh),
         2 => Ok(Shell::Fish),
+        3 => Ok(Shell::PowerShell),
         _ => unreachable!(),
     }
 }
@@ -86,27 +83,42 @@ fn parse_shell_from_path(path: &str) -> Option<Shell> {
         "bash" => Some(Shell::Bash),
         "zsh" => Some(Shell::Zsh),
         "fish" => Some(Shell::Fish),
+        "pwsh" | "pwsh.exe" | "powershell" | "powershell.exe" => Some(Shell::PowerShell),
         _ => None,
     }
 }
 
-fn get_shell_config_path(shell: &Shell) -> Result<PathBuf> {
+pub fn home_dir() -> Result<PathBuf> {
     // Use directories crate to get home directory in a cross-platform way
-    let home_path = directories::BaseDirs::new()
+    Ok(directories::BaseDirs::new()
         .ok_or_else(|| ParaError::config_error("Unable to determine home directory"))?
         .home_dir()
-        .to_path_buf();
+        .to_path_buf())
+}
+
+/// Directory holding the current user's PowerShell profile
+pub fn powershell_dir(home_path: &Path) -> PathBuf {
+    if cfg!(windows) {
+        home_path.join("Documents").join("PowerShell")
+    } else {
+        home_path.join(".config").join("powershell")
+    }
+}
+
+fn get_shell_config_path(shell: &Shell) -> Result<PathBuf> {
+    let home_path = home_dir()?;
 
     let config_path = match shell {
         Shell::Bash => home_path.join(".bashrc"),
         Shell::Zsh => home_path.join(".zshrc"),
         Shell::Fish => home_path.join(".config/fish/config.fish"),
+        Shell::PowerShell => powershell_dir(&home_path).join("Microsoft.PowerShell_profile.ps1"),
     };
 
-    if shell == &Shell::Fish {
+    if matches!(shell, Shell::Fish | Shell::PowerShell) {
         if let Some(parent) = config_path.parent() {
             fs::create_dir_all(parent).map_err(|e| {
-                ParaError::fs_error(format!("Failed to create fish config directory: {e}"))
+                ParaError::fs_error(format!("Failed to create {shell:?} config directory: {e}"))
             })?;
         }
     }
@@ -150,18 +162,21 @@ fn install_completion(config_path: &Path, shell: &Shell) -> Result<()> {
         Shell::Fish => {
             "\n# >>> para completion initialize >>>\n# Add timeout protection to prevent shell startup blocking\nif command -v timeout >/dev/null 2>&1\n    eval \"$(timeout 5 env PARA_COMPLETION_SCRIPT=1 para completion fish 2>/dev/null || echo '# Para completion failed to load')\"\nelse\n    # Fallback for systems without timeout command\n    eval \"$(PARA_COMPLETION_SCRIPT=1 para completion fish 2>/dev/null || echo '# Para completion failed to load')\"\nend\n# <<< para completion initialize <<<\n".to_string()
         }
+        Shell::PowerShell => {
+            "\n# >>> para completion initialize >>>\npara completion generate powershell 2>$null | Out-String | Invoke-Expression\n# <<< para completion initialize <<<\n".to_string()
+        }
         _ => {
             format!(
                 "\n# >>> para completion initialize >>>\n# Add timeout protection to prevent shell startup blocking\nif command -v timeout >/dev/null 2>&1; then\n    eval \"$(timeout 5 PARA_COMPLETION_SCRIPT=1 para completion {} 2>/dev/null || echo '# Para completion failed to load')\"\nelse\n    eval \"$(PARA_COMPLETION_SCRIPT=1 para completion {} 2>/dev/null || echo '# Para completion failed to load')\"\nfi\n# <<< para completion initialize <<<\n",
                 match shell {
                     Shell::Bash => "bash",
-                    Shell::Zsh => "zsh", 
-                    Shell::Fish => unreachable!(),
+                    Shell::Zsh => "zsh",
+                    Shell::Fish | Shell::PowerShell => unreachable!(),
                 },
                 match shell {
                     Shell::Bash => "bash",
                     Shell::Zsh => "zsh",
-                    Shell::Fish => unreachable!(),
+                    Shell::Fish | Shell::PowerShell => unreachable!(),
                 }
             )
         }
@@ -0,0 +1,169 @@
+use crate::cli::commands::common::check_skip_permissions_allowed;
+use crate::cli::commands::dispatch::{
+    create_launch_metadata, launch_claude_code, run_worktree_setup_script,
+};
+use crate::cli::context::CommandContext;
+use crate::cli::parser::LaunchArgs;
+use crate::config::Config;
+use crate::core::sandbox::config::SandboxResolver;
+use crate::core::session::{SessionManager, SessionState, SessionStatus};
+use crate::core::version;
+use crate::utils::{ParaError, Result};
+use std::fs;
+
+pub fn execute(ctx: &CommandContext, args: LaunchArgs) -> Result<()> {
+    let session_manager = ctx.session_manager();
+    let session_state = session_manager.resolve_session(&args.session)?;
+    version::check_session(&session_state, true)?;
+    launch_planned_session(ctx.config(), &session_manager, session_state)
+}
+
+/// Run the deferred setup of a `--plan-only` session and launch Claude with
+/// its task, as dispatch would have done
+pub(crate) fn launch_planned_session(
+    config: &Config,
+    session_manager: &SessionManager,
+    mut session_state: SessionState,
+) -> Result<()> {
+    let name = session_state.name.clone();
+    if session_state.status != SessionStatus::Planned {
+        return Err(ParaError::invalid_args(format!(
+            "Session '{name}' has already been launched. Use 'para resume {name}' to reopen it"
+        )));
+    }
+    if !session_state.worktree_path.exists() {
+        return Err(ParaError::fs_error(format!(
+            "Worktree of session '{name}' does not exist: {}",
+            session_state.worktree_path.display()
+        )));
+    }
+    let skip_permissions = session_state.dangerous_skip_permissions.unwrap_or(false);
+    check_skip_permissions_allowed(config, skip_permissions)?;
+
+    // Saved right away so a failed launch does not run the script again
+    if let Some(setup_script) = session_state.deferred_setup_script.take() {
+        run_worktree_setup_script(
+            &setup_script,
+            &name,
+            &session_state.worktree_path,
+            session_manager.state_dir(),
+        )?;
+        session_manager.save_state(&session_state)?;
+    }
+
+    let task_file = session_manager.state_dir().join(format!("{name}.task"));
+    let prompt = match fs::read_to_string(&task_file) {
+        Ok(task) => task,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            session_state.task_description.clone().unwrap_or_default()
+        }
+        Err(e) => return Err(ParaError::from_io("read task file", &task_file, e)),
+    };
+
+    let sandbox_enabled = session_state.sandbox_enabled.unwrap_or(false);
+    let sandbox_settings = SandboxResolver::new(config).resolve_with_network(
+        sandbox_enabled,
+        !sandbox_enabled,
+        session_state.sandbox_profile.clone(),
+        false,
+        Vec::new(),
+    );
+
+    create_launch_metadata(config, &session_state.worktree_path)?;
+    launch_claude_code(
+        config,
+        &session_state.worktree_path,
+        &prompt,
+        skip_permissions,
+        &sandbox_settings,
+    )?;
+
+    session_state.update_status(SessionStatus::Active);
+    session_manager.save_state(&session_state)?;
+    println!("🚀 Launched session '{name}'");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::parser::{Cli, Commands};
+    use crate::test_utils::test_helpers::*;
+    use clap::Parser;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_planned_session_launches_with_its_task() {
+        let (git_temp, git_service) = setup_test_repo();
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = create_test_config_with_dir(&temp_dir);
+        // Dispatch requires Claude in wrapper mode; the wrapper is still `echo`
+        config.ide.command = "claude".to_string();
+        config.ide.wrapper.enabled = true;
+        let repo_root = git_service.repository().root.clone();
+        let setup_script = git_temp.path().join("setup.sh");
+        fs::write(&setup_script, "touch \"$PARA_WORKSPACE/.setup-ran\"\n").unwrap();
+
+        let cli = Cli::try_parse_from([
+            "para",
+            "start",
+            "planned",
+            "--prompt",
+            "Add rate limiting to the API",
+            "--plan-only",
+            "--defer-setup",
+            "--setup-script",
+            setup_script.to_str().unwrap(),
+        ])
+        .unwrap();
+        let Some(Commands::Start(args)) = cli.command else {
+            panic!("expected the start command");
+        };
+        let ctx = CommandContext::in_dir(config.clone(), repo_root.clone());
+        crate::cli::commands::unified_start::execute(&ctx, args).unwrap();
+
+        let session_manager = ctx.session_manager();
+        let planned = session_manager.load_state("planned").unwrap();
+        assert_eq!(planned.status, SessionStatus::Planned);
+        assert!(planned.deferred_setup_script.is_some());
+        let worktree = planned.worktree_path.clone();
+        assert!(worktree.join("CLAUDE.local.md").exists());
+        assert!(!worktree.join(".claude_prompt_temp").exists());
+        assert!(!worktree.join(".setup-ran").exists());
+
+        // `para resume` of a planned session adds no instructions, it launches
+        let resume =
+            Cli::try_parse_from(["para", "resume", "planned", "--prompt", "more"]).unwrap();
+        let Some(Commands::Resume(resume_args)) = resume.command else {
+            panic!("expected the resume command");
+        };
+        let error = crate::cli::commands::resume::execute(&ctx, resume_args).unwrap_err();
+        assert!(error.to_string().contains("para launch planned"));
+
+        execute(
+            &ctx,
+            LaunchArgs {
+                session: "planned".to_string(),
+            },
+        )
+        .unwrap();
+
+        let launched = session_manager.load_state("planned").unwrap();
+        assert_eq!(launched.status, SessionStatus::Active);
+        assert!(launched.deferred_setup_script.is_none());
+        assert!(worktree.join(".setup-ran").exists());
+        assert_eq!(
+            fs::read_to_string(worktree.join(".claude_prompt_temp")).unwrap(),
+            "Add rate limiting to the API"
+        );
+
+        let error = execute(
+            &ctx,
+            LaunchArgs {
+                session: "planned".to_string(),
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(error, ParaError::InvalidArgs { .. }));
+    }
+}
@@ -0,0 +1,123 @@
+use crate::cli::context::CommandContext;
+use crate::cli::parser::{LinkArgs, LinkCommands};
+use crate::core::session::links::{add_link, remove_link, LinkChange};
+use crate::core::session::SessionManager;
+use crate::utils::{ParaError, Result};
+
+pub fn execute(ctx: &CommandContext, args: LinkArgs) -> Result<()> {
+    let session_manager = ctx.session_manager();
+    match args.command {
+        LinkCommands::Add {
+            session,
+            url,
+            label,
+        } => add(&session_manager, &session, &label, &url),
+        LinkCommands::Remove { session, link } => remove(&session_manager, &session, &link),
+        LinkCommands::List { session, json } => list(&session_manager, &session, json),
+    }
+}
+
+fn add(session_manager: &SessionManager, session: &str, label: &str, url: &str) -> Result<()> {
+    let mut state = session_manager.resolve_session(session)?;
+    let change = add_link(&mut state.links, label, url)?;
+    if change != LinkChange::Unchanged {
+        session_manager.save_state(&state)?;
+    }
+
+    let label = label.trim();
+    match change {
+        LinkChange::Added => println!("🔗 Added {label} to session '{}'", state.name),
+        LinkChange::Updated => println!("🔗 Pointed {label} of session '{}' at {url}", state.name),
+        LinkChange::Unchanged => {
+            println!("Session '{}' already links {label} to {url}", state.name)
+        }
+    }
+    Ok(())
+}
+
+fn remove(session_manager: &SessionManager, session: &str, link: &str) -> Result<()> {
+    let mut state = session_manager.resolve_session(session)?;
+    if remove_link(&mut state.links, link) == 0 {
+        println!("Session '{}' has no link '{link}'", state.name);
+        return Ok(());
+    }
+    session_manager.save_state(&state)?;
+    println!("Removed link '{link}' from session '{}'", state.name);
+    Ok(())
+}
+
+fn list(session_manager: &SessionManager, session: &str, json: bool) -> Result<()> {
+    let state = session_manager.resolve_session(session)?;
+    if json {
+        let json = serde_json::to_string_pretty(&state.links)
+            .map_err(|e| ParaError::config_error(format!("Failed to serialize links: {e}")))?;
+        println!("{json}");
+    } else if state.links.is_empty() {
+        println!("No links for session '{}'", state.name);
+    } else {
+        for link in &state.links {
+            println!("{}: {}", link.label, link.url);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::session::SessionState;
+    use crate::test_utils::test_helpers::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_links_are_saved_with_the_session() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = create_test_config_with_dir(&temp_dir);
+        let session_manager = SessionManager::new(&config);
+        session_manager
+            .save_state(&SessionState::new(
+                "auth".to_string(),
+                "para/auth".to_string(),
+                temp_dir.path().join("auth"),
+            ))
+            .unwrap();
+        let ctx = CommandContext::in_dir(config, temp_dir.path().to_path_buf());
+        let run = |command| execute(&ctx, LinkArgs { command });
+
+        run(LinkCommands::Add {
+            session: "auth".to_string(),
+            url: "https://github.com/acme/app/pull/42".to_string(),
+            label: "PR".to_string(),
+        })
+        .unwrap();
+        run(LinkCommands::Add {
+            session: "auth".to_string(),
+            url: "https://github.com/acme/app/pull/42".to_string(),
+            label: "PR".to_string(),
+        })
+        .unwrap();
+        let links = session_manager.load_state("auth").unwrap().links;
+        assert_eq!(links.len(), 1);
+        assert_eq!(links[0].label, "PR");
+
+        let error = run(LinkCommands::Add {
+            session: "auth".to_string(),
+            url: "jira/APP-7".to_string(),
+            label: "Ticket".to_string(),
+        })
+        .unwrap_err();
+        assert!(matches!(error, ParaError::InvalidArgs { .. }));
+
+        run(LinkCommands::Remove {
+            session: "auth".to_string(),
+            link: "PR".to_string(),
+        })
+        .unwrap();
+        run(LinkCommands::Remove {
+            session: "auth".to_string(),
+            link: "PR".to_string(),
+        })
+        .unwrap();
+        assert!(session_manager.load_state("auth").unwrap().links.is_empty());
+    }
+}
@@ -1,6 +1,13 @@
-use crate::core::git::{GitOperations, GitService};
+use crate::cli::parser::ListSort;
+use crate::core::git::repository::short_sha;
+use crate::core::git::{GitOperations, GitService, WorktreeRef};
+use crate::core::idle::{idle_for, session_last_activity};
+use crate::core::session::notes::{latest_archived_note, latest_note};
+use crate::core::session::sync::ahead_behind;
 use crate::core::session::{SessionManager, SessionStatus as UnifiedSessionStatus};
-use crate::utils::Result;
+use crate::core::status::Status;
+use crate::ui::monitor::activity::detect_session_activity;
+use crate::utils::{ArchiveBranchParser, Result};
 use chrono::{DateTime, Utc};
 use std::path::{Path, PathBuf};
 
@@ -20,21 +27,25 @@ pub fn list_active_sessions(
             _ => {}
         }
 
-        let has_uncommitted_changes = if session_state.worktree_path.exists() {
-            if let Some(service) = git_service_for_path(&session_state.worktree_path) {
-                service.has_uncommitted_changes().ok()
-            } else {
-                Some(false)
-            }
+        let service = if session_state.worktree_path.exists() {
+            git_service_for_path(&session_state.worktree_path)
         } else {
-            Some(false)
+            None
+        };
+        let (has_uncommitted_changes, worktree_status) = match &service {
+            Some(service) => {
+                let status = service.repository().worktree_status().ok();
+                (status.map(|status| !status.is_clean()), status)
+            }
+            None => (Some(false), None),
         };
 
         let is_current = std::env::current_dir()
             .map(|cwd| cwd.starts_with(&session_state.worktree_path))
             .unwrap_or(false);
 
-        let status = determine_unified_session_status(&session_state, git_service)?;
+        let status =
+            determine_unified_session_status(&session_state, git_service, service.as_ref())?;
 
         let (session_type, container_status) = match &session_state.session_type {
             crate::core::session::SessionType::Container { .. } => {
@@ -49,13 +60,36 @@ pub fn list_active_sessions(
             branch: session_state.branch.clone(),
             worktree_path: session_state.worktree_path.clone(),
             base_branch: "main".to_string(),
-            merge_mode: "squash".to_string(),
+            merge_mode: session_state.merge_mode.to_string(),
             status,
-            last_modified: Some(session_state.created_at),
+            last_modified: session_created_at(&session_state, session_manager.state_dir()),
             has_uncommitted_changes,
+            worktree_status,
             is_current,
             session_type,
             container_status,
+            container: session_state.container.clone(),
+            skip_permissions: session_state.skips_permissions(),
+            pinned: session_state.pinned,
+            operation: session_manager.current_operation(&session_state.name),
+            note: latest_note(session_manager.state_dir(), &session_state.name),
+            base_commit: session_state.base_commit.clone(),
+            idle_for: None,
+            todos: Status::load(session_manager.state_dir(), &session_state.name)
+                .ok()
+                .flatten()
+                .and_then(|status| status.todo_fraction()),
+            stacked_on: session_state.stacked_on.clone(),
+            stacked_children: session_state.stacked_children.clone(),
+            retry_of: session_state.retry_of.clone(),
+            link_labels: session_state
+                .links
+                .iter()
+                .map(|link| link.label.clone())
+                .collect(),
+            ahead_behind: ahead_behind(git_service.repository(), &session_state),
+            para_version: Some(session_state.para_version.clone())
+                .filter(|version| !version.is_empty()),
         };
 
         sessions.push(session_info);
@@ -70,6 +104,106 @@ pub fn list_active_sessions(
     Ok(sessions)
 }
 
+/// When the session was created. State files from early versions have no
+/// creation time, so the modification time of the state file stands in.
+pub fn session_created_at(
+    session_state: &crate::core::session::SessionState,
+    state_dir: &Path,
+) -> Option<DateTime<Utc>> {
+    if session_state.has_created_at() {
+        return Some(session_state.created_at);
+    }
+    std::fs::metadata(state_dir.join(format!("{}.state", session_state.name)))
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .map(DateTime::<Utc>::from)
+}
+
+/// Keep sessions created within `since..=until`, judged by their creation time
+/// before any activity timestamps replace it. Sessions without a known creation
+/// time are dropped once a bound is given.
+pub fn filter_sessions_by_creation(
+    sessions: &mut Vec<SessionInfo>,
+    since: Option<DateTime<Utc>>,
+    until: Option<DateTime<Utc>>,
+) {
+    if since.is_none() && until.is_none() {
+        return;
+    }
+    sessions.retain(|session| {
+        session.last_modified.is_some_and(|created| {
+            since.is_none_or(|since| created >= since) && until.is_none_or(|until| created <= until)
+        })
+    });
+}
+
+/// Order sessions for display. Shared by every output format.
+pub fn sort_sessions(
+    sessions: &mut [SessionInfo],
+    sort: ListSort,
+    reverse: bool,
+    state_dir: &Path,
+) {
+    match sort {
+        ListSort::Name => sessions.sort_by(|a, b| a.session_id.cmp(&b.session_id)),
+        ListSort::Created => super::formatters::sort_sessions_by_date(sessions),
+        ListSort::Activity => sort_sessions_by_activity(sessions, state_dir),
+        ListSort::Status => {
+            super::formatters::sort_sessions_by_date(sessions);
+            sessions.sort_by_key(|session| status_rank(&session.status));
+        }
+    }
+    if reverse {
+        sessions.reverse();
+    }
+}
+
+fn status_rank(status: &SessionStatus) -> u8 {
+    match status {
+        SessionStatus::Missing => 0,
+        SessionStatus::Diverged | SessionStatus::Detached(_) => 1,
+        SessionStatus::Dirty => 2,
+        SessionStatus::Untracked => 3,
+        SessionStatus::Planned => 4,
+        SessionStatus::Active => 5,
+        SessionStatus::Archived => 6,
+    }
+}
+
+/// Replace each session's timestamp with its last activity and order by it,
+/// using the same sources as the monitor's last activity column
+pub fn sort_sessions_by_activity(sessions: &mut [SessionInfo], state_dir: &Path) {
+    for session in sessions.iter_mut() {
+        let status_updated = Status::load(state_dir, &session.session_id)
+            .ok()
+            .flatten()
+            .map(|status| status.updated_at);
+        if let Some(activity) =
+            detect_session_activity(&session.worktree_path, &session.branch, status_updated)
+        {
+            session.last_modified = Some(activity.at);
+        }
+    }
+    super::formatters::sort_sessions_by_date(sessions);
+}
+
+/// Flag sessions without activity for at least `threshold_hours`; pinned
+/// sessions are never idle
+pub fn mark_idle_sessions(
+    sessions: &mut [SessionInfo],
+    session_manager: &SessionManager,
+    threshold_hours: u64,
+    now: DateTime<Utc>,
+) {
+    for session in sessions.iter_mut().filter(|session| !session.pinned) {
+        let Ok(state) = session_manager.load_state(&session.session_id) else {
+            continue;
+        };
+        let last_activity = session_last_activity(&state, session_manager.state_dir());
+        session.idle_for = idle_for(last_activity, threshold_hours, now);
+    }
+}
+
 pub fn list_archived_sessions(
     session_manager: &SessionManager,
     git_service: &GitService,
@@ -104,8 +238,11 @@ pub fn collect_finished_sessions(session_manager: &SessionManager) -> Result<Vec
                 let has_uncommitted_changes =
                     determine_uncommitted_changes(&session_state.worktree_path);
 
-                let session_info =
+                let mut session_info =
                     create_session_info_from_state(&session_state, has_uncommitted_changes);
+                session_info.last_modified =
+                    session_created_at(&session_state, session_manager.state_dir());
+                session_info.note = latest_note(session_manager.state_dir(), &session_state.name);
                 sessions.push(session_info);
             }
             _ => {}
@@ -143,13 +280,28 @@ pub fn create_session_info_from_state(
         branch: session_state.branch.clone(),
         worktree_path: session_state.worktree_path.clone(),
         base_branch: "main".to_string(),
-        merge_mode: "squash".to_string(),
+        merge_mode: session_state.merge_mode.to_string(),
         status: SessionStatus::Archived,
         last_modified: Some(session_state.created_at),
         has_uncommitted_changes,
         is_current: false,
         session_type,
         container_status,
+        container: session_state.container.clone(),
+        skip_permissions: session_state.skips_permissions(),
+        operation: None,
+        base_commit: session_state.base_commit.clone(),
+        note: None,
+        idle_for: None,
+        todos: None,
+        stacked_on: None,
+        stacked_children: Vec::new(),
+        ahead_behind: None,
+        retry_of: None,
+        worktree_status: None,
+        link_labels: Vec::new(),
+        para_version: None,
+        pinned: false,
     }
 }
 
@@ -168,7 +320,18 @@ pub fn collect_archived_branch_sessions(
             extract_session_id_from_archived_branch(&branch_name, branch_prefix)
         {
             if !seen_session_ids.contains(&session_id) {
-                let session_info = create_session_info_from_branch(&session_id, &branch_name);
+                let mut session_info = create_session_info_from_branch(&session_id, &branch_name);
+                session_info.note =
+                    ArchiveBranchParser::parse_archive_branch(&branch_name, branch_prefix)
+                        .ok()
+                        .flatten()
+                        .and_then(|archive| {
+                            latest_archived_note(
+                                session_manager.state_dir(),
+                                &archive.timestamp,
+                                &session_id,
+                            )
+                        });
                 sessions.push(session_info);
             }
         }
@@ -190,12 +353,30 @@ pub fn create_session_info_from_branch(session_id: &str, branch_name: &str) -> S
         is_current: false,
         session_type: SessionType::Worktree,
         container_status: None,
+        skip_permissions: false,
+        operation: None,
+        base_commit: None,
+        note: None,
+        idle_for: None,
+        todos: None,
+        stacked_on: None,
+        stacked_children: Vec::new(),
+        ahead_behind: None,
+        retry_of: None,
+        worktree_status: None,
+        container: None,
+        link_labels: Vec::new(),
+        para_version: None,
+        pinned: false,
     }
 }
 
+/// `worktree_service` is the service for the session worktree, if it could be
+/// discovered
 pub fn determine_unified_session_status(
     session_state: &crate::core::session::SessionState,
     git_service: &GitService,
+    worktree_service: Option<&GitService>,
 ) -> Result<SessionStatus> {
     if !session_state.worktree_path.exists() {
         return Ok(SessionStatus::Missing);
@@ -209,12 +390,28 @@ pub fn determine_unified_session_status(
     if !worktree_exists {
         return Ok(SessionStatus::Missing);
     }
+    if session_state.status == UnifiedSessionStatus::Planned {
+        return Ok(SessionStatus::Planned);
+    }
 
-    if let Some(service) = git_service_for_path(&session_state.worktree_path) {
-        if let Ok(is_clean) = service.is_clean_working_tree() {
-            if !is_clean {
+    if let Some(service) = worktree_service {
+        match service.repository().head_ref() {
+            Ok(WorktreeRef::Detached(sha)) => {
+                return Ok(SessionStatus::Detached(short_sha(&sha).to_string()));
+            }
+            Ok(WorktreeRef::Branch(branch)) if branch != session_state.branch => {
+                return Ok(SessionStatus::Diverged);
+            }
+            _ => {}
+        }
+
+        if let Ok(worktree_status) = service.repository().worktree_status() {
+            if worktree_status.has_tracked_changes() {
                 return Ok(SessionStatus::Dirty);
             }
+            if worktree_status.is_untracked_only() {
+                return Ok(SessionStatus::Untracked);
+            }
         }
     }
 
@@ -341,6 +538,21 @@ mod tests {
                     is_current: false,
                     session_type: SessionType::Worktree,
                     container_status: None,
+                    skip_permissions: false,
+                    operation: None,
+                    base_commit: None,
+                    note: None,
+                    idle_for: None,
+                    todos: None,
+                    stacked_children: Vec::new(),
+                    stacked_on: None,
+                    ahead_behind: None,
+                    retry_of: None,
+                    worktree_status: None,
+                    container: None,
+                    link_labels: Vec::new(),
+                    para_version: None,
+                    pinned: false,
                 };
                 sessions.push(session_info);
             }
@@ -608,4 +820,117 @@ mod tests {
         assert_eq!(session_info.has_uncommitted_changes, None);
         assert!(!session_info.is_current);
     }
+
+    #[test]
+    fn test_sort_sessions_by_activity() {
+        use filetime::{set_file_mtime, FileTime};
+
+        let worktrees = TempDir::new().unwrap();
+        let state_dir = TempDir::new().unwrap();
+        let mut sessions = Vec::new();
+        for (name, mtime) in [("quiet", 1_000), ("busy", 3_000), ("reported", 2_000)] {
+            let path = worktrees.path().join(name);
+            fs::create_dir_all(&path).unwrap();
+            fs::write(path.join("file.txt"), name).unwrap();
+            set_file_mtime(path.join("file.txt"), FileTime::from_unix_time(mtime, 0)).unwrap();
+
+            let mut info = create_session_info_from_branch(name, &format!("para/{name}"));
+            info.worktree_path = path;
+            sessions.push(info);
+        }
+
+        // The status update makes "reported" the most recent session
+        let mut status = Status::new(
+            "reported".to_string(),
+            "Writing tests".to_string(),
+            crate::core::status::TestStatus::Unknown,
+        );
+        status.updated_at = DateTime::from_timestamp(4_000, 0).unwrap();
+        status.save(state_dir.path()).unwrap();
+
+        sort_sessions_by_activity(&mut sessions, state_dir.path());
+
+        let order: Vec<&str> = sessions.iter().map(|s| s.session_id.as_str()).collect();
+        assert_eq!(order, vec!["reported", "busy", "quiet"]);
+        assert_eq!(
+            sessions[0].last_modified,
+            DateTime::from_timestamp(4_000, 0)
+        );
+    }
+
+    #[test]
+    fn test_session_created_at_falls_back_to_state_file_mtime() {
+        use crate::core::session::SessionState;
+        use filetime::{set_file_mtime, FileTime};
+
+        let state_dir = TempDir::new().unwrap();
+        let state_file = state_dir.path().join("legacy.state");
+        fs::write(
+            &state_file,
+            r#"{"name":"legacy","branch":"para/legacy","worktree_path":"/tmp/legacy","status":"Active","task_description":null,"last_activity":null,"git_stats":null}"#,
+        )
+        .unwrap();
+        set_file_mtime(&state_file, FileTime::from_unix_time(5_000, 0)).unwrap();
+
+        let legacy: SessionState =
+            serde_json::from_str(&fs::read_to_string(&state_file).unwrap()).unwrap();
+        assert!(!legacy.has_created_at());
+        assert_eq!(
+            session_created_at(&legacy, state_dir.path()),
+            DateTime::from_timestamp(5_000, 0)
+        );
+
+        let current = SessionState::new(
+            "legacy".to_string(),
+            "para/legacy".to_string(),
+            PathBuf::from("/tmp/legacy"),
+        );
+        assert_eq!(
+            session_created_at(&current, state_dir.path()),
+            Some(current.created_at)
+        );
+    }
+
+    #[test]
+    fn test_filter_and_sort_sessions() {
+        let state_dir = TempDir::new().unwrap();
+        let mut sessions: Vec<SessionInfo> = [
+            ("old", 1_000, SessionStatus::Active),
+            ("bravo", 2_000, SessionStatus::Dirty),
+            ("alpha", 3_000, SessionStatus::Active),
+            ("charlie", 4_000, SessionStatus::Missing),
+            ("new", 5_000, SessionStatus::Active),
+        ]
+        .into_iter()
+        .map(|(name, created, status)| {
+            let mut info = create_session_info_from_branch(name, &format!("para/{name}"));
+            info.last_modified = DateTime::from_timestamp(created, 0);
+            info.status = status;
+            info
+        })
+        .collect();
+        sessions.push(create_session_info_from_branch("unknown", "para/unknown"));
+
+        filter_sessions_by_creation(
+            &mut sessions,
+            DateTime::from_timestamp(2_000, 0),
+            DateTime::from_timestamp(4_000, 0),
+        );
+        let names = |sessions: &[SessionInfo]| -> Vec<String> {
+            sessions.iter().map(|s| s.session_id.clone()).collect()
+        };
+        assert_eq!(names(&sessions), vec!["bravo", "alpha", "charlie"]);
+
+        sort_sessions(&mut sessions, ListSort::Name, false, state_dir.path());
+        assert_eq!(names(&sessions), vec!["alpha", "bravo", "charlie"]);
+
+        sort_sessions(&mut sessions, ListSort::Created, false, state_dir.path());
+        assert_eq!(names(&sessions), vec!["charlie", "alpha", "bravo"]);
+
+        sort_sessions(&mut sessions, ListSort::Created, true, state_dir.path());
+        assert_eq!(names(&sessions), vec!["bravo", "alpha", "charlie"]);
+
+        sort_sessions(&mut sessions, ListSort::Status, false, state_dir.path());
+        assert_eq!(names(&sessions), vec!["charlie", "bravo", "alpha"]);
+    }
 }
@@ -1,5 +1,15 @@
+use super::table::{
+    allocate_widths, color_enabled, pad, paint, terminal_width, truncate_end, truncate_middle,
+    Align, Color, ColumnSpec, COLUMN_GAP,
+};
 use crate::cli::parser::ListArgs;
-use crate::utils::Result;
+use crate::core::git::WorktreeStatus;
+use crate::core::idle::format_idle_duration;
+use crate::core::session::{ContainerConfig, OperationRecord, SKIP_PERMISSIONS_MARKER};
+use crate::core::version;
+use crate::ui::theme;
+use crate::utils::output::{file_url, hyperlinks_enabled, link_if};
+use crate::utils::{ParaError, Result};
 use chrono::{DateTime, Utc};
 use std::path::PathBuf;
 
@@ -9,21 +19,59 @@ pub struct SessionInfo {
     pub branch: String,
     pub worktree_path: PathBuf,
     pub base_branch: String,
+    /// Commit the session branch was created from
+    pub base_commit: Option<String>,
     pub merge_mode: String,
     pub status: SessionStatus,
     pub last_modified: Option<DateTime<Utc>>,
     pub has_uncommitted_changes: Option<bool>,
+    /// Breakdown of the uncommitted changes, when the worktree could be read
+    pub worktree_status: Option<WorktreeStatus>,
     pub is_current: bool,
     pub session_type: SessionType,
     pub container_status: Option<String>,
+    /// Docker options the container was started with
+    pub container: Option<ContainerConfig>,
+    /// Launched with `--dangerously-skip-permissions`
+    pub skip_permissions: bool,
+    /// Kept with `para pin`
+    pub pinned: bool,
+    /// Finish or cancel currently running on the session
+    pub operation: Option<OperationRecord>,
+    /// Most recent line of the session's `para note` notes
+    pub note: Option<String>,
+    /// Time without activity, once it reaches `session.idle_alert_hours`
+    pub idle_for: Option<chrono::Duration>,
+    /// Completed todos of the agent's status, e.g. `3/7`
+    pub todos: Option<String>,
+    /// Session this one was started from with `--base <session>`
+    pub stacked_on: Option<String>,
+    pub stacked_children: Vec<String>,
+    /// Session whose task this one reruns, from `dispatch --retry`
+    pub retry_of: Option<String>,
+    /// Labels of the session's `para link` links
+    pub link_labels: Vec<String>,
+    /// Commits on the session branch not on its parent branch, and the reverse
+    pub ahead_behind: Option<(usize, usize)>,
+    /// Para release that created the session, if recorded
+    pub para_version: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum SessionStatus {
     Active,
+    /// Changes to tracked files
     Dirty,
+    /// Only untracked files, such as build output
+    Untracked,
     Missing,
     Archived,
+    /// Prepared with `dispatch --plan-only`, Claude not launched yet
+    Planned,
+    /// The worktree has a different branch checked out than the session recorded
+    Diverged,
+    /// HEAD of the worktree is detached at the given short commit hash
+    Detached(String),
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -37,8 +85,12 @@ impl SessionStatus {
         match self {
             SessionStatus::Active => "active",
             SessionStatus::Dirty => "dirty",
+            SessionStatus::Untracked => "untracked",
             SessionStatus::Missing => "missing",
             SessionStatus::Archived => "archived",
+            SessionStatus::Planned => "planned",
+            SessionStatus::Diverged => "diverged",
+            SessionStatus::Detached(_) => "detached",
         }
     }
 
@@ -46,19 +98,37 @@ impl SessionStatus {
         match self {
             SessionStatus::Active => "✓",
             SessionStatus::Dirty => "●",
+            SessionStatus::Untracked => "○",
             SessionStatus::Missing => "✗",
             SessionStatus::Archived => "📦",
+            SessionStatus::Planned => "◇",
+            SessionStatus::Diverged => "⚠",
+            SessionStatus::Detached(_) => "⚠",
+        }
+    }
+
+    /// `as_str`, with the commit of a detached HEAD: `detached@<short-sha>`
+    pub fn label(&self) -> String {
+        match self {
+            SessionStatus::Detached(sha) => format!("detached@{sha}"),
+            other => other.as_str().to_string(),
         }
     }
 }
 
 pub fn display_sessions(sessions: &[SessionInfo], args: &ListArgs) -> Result<()> {
+    if let Some(ref format) = args.format {
+        let template = FormatTemplate::parse(format)?;
+        print!("{}", template.render_all(sessions));
+        return Ok(());
+    }
+
     let result = if args.quiet {
         display_quiet_sessions(sessions)
     } else if args.verbose {
-        display_verbose_sessions(sessions)
+        display_verbose_sessions(sessions, hyperlinks_enabled(args.no_color))
     } else {
-        display_compact_sessions(sessions)
+        display_compact_sessions(sessions, &TableOptions::from_args(args))
     };
 
     if !args.quiet && result.is_ok() {
@@ -75,31 +145,193 @@ pub fn display_quiet_sessions(sessions: &[SessionInfo]) -> Result<()> {
     Ok(())
 }
 
-pub fn display_compact_sessions(sessions: &[SessionInfo]) -> Result<()> {
-    println!(
-        "{:<2} {:<30} {:<20} {:<15}",
-        "St", "Session", "Branch", "Status"
-    );
-    println!("{}", "-".repeat(70));
+/// How the compact table fits the terminal
+#[derive(Debug, Clone, Copy)]
+pub struct TableOptions {
+    pub width: usize,
+    pub color: bool,
+    /// Make worktree paths clickable
+    pub links: bool,
+    pub full_paths: bool,
+}
 
-    for session in sessions {
-        let current_marker = if session.is_current { "*" } else { " " };
-        let status_indicator = session.status.symbol();
+impl TableOptions {
+    pub fn from_args(args: &ListArgs) -> Self {
+        Self {
+            width: terminal_width(),
+            color: color_enabled(args.no_color),
+            links: hyperlinks_enabled(args.no_color),
+            full_paths: args.full_paths,
+        }
+    }
+}
 
-        println!(
-            "{}{} {:<30} {:<20} {:<15}",
+const COMPACT_COLUMNS: [ColumnSpec; 6] = [
+    ColumnSpec {
+        header: "Session",
+        min: 10,
+        weight: 3,
+        align: Align::Left,
+    },
+    ColumnSpec {
+        header: "Branch",
+        min: 10,
+        weight: 3,
+        align: Align::Left,
+    },
+    ColumnSpec {
+        header: "Status",
+        min: 8,
+        weight: 1,
+        align: Align::Left,
+    },
+    ColumnSpec {
+        header: "Ahead",
+        min: 5,
+        weight: 0,
+        align: Align::Right,
+    },
+    ColumnSpec {
+        header: "Behind",
+        min: 6,
+        weight: 0,
+        align: Align::Right,
+    },
+    ColumnSpec {
+        header: "Path",
+        min: 16,
+        weight: 4,
+        align: Align::Left,
+    },
+];
+const STATUS_COLUMN: usize = 2;
+const PATH_COLUMN: usize = 5;
+
+/// Current, status and permissions markers in front of every row, e.g. `*✓ ⚠ `
+const MARKER_WIDTH: usize = 5;
+
+pub fn display_compact_sessions(sessions: &[SessionInfo], options: &TableOptions) -> Result<()> {
+    for line in compact_table(sessions, options) {
+        println!("{line}");
+    }
+    Ok(())
+}
+
+/// Lines of the compact table
+fn compact_table(sessions: &[SessionInfo], options: &TableOptions) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut specs = COMPACT_COLUMNS;
+    if options.full_paths {
+        specs[PATH_COLUMN].weight = 0;
+    }
+
+    let rows: Vec<[String; 6]> = sessions.iter().map(compact_cells).collect();
+    let natural: Vec<usize> = specs
+        .iter()
+        .enumerate()
+        .map(|(i, spec)| {
+            rows.iter()
+                .map(|row| row[i].chars().count())
+                .fold(spec.header.len(), usize::max)
+        })
+        .collect();
+    let widths = allocate_widths(&specs, &natural, options.width.saturating_sub(MARKER_WIDTH));
+    let row_width = MARKER_WIDTH + widths.iter().sum::<usize>() + (widths.len() - 1) * COLUMN_GAP;
+
+    let header: Vec<String> = specs
+        .iter()
+        .zip(&widths)
+        .map(|(spec, &width)| pad(&truncate_end(spec.header, width), width, spec.align))
+        .collect();
+    lines.push(format!(
+        "{:<2} {:<1} {}",
+        "St",
+        SKIP_PERMISSIONS_MARKER,
+        header.join(" ").trim_end()
+    ));
+    lines.push("-".repeat(row_width));
+
+    for (session, cells) in sessions.iter().zip(&rows) {
+        let current_marker = if session.is_current { "*" } else { " " };
+        let permissions_marker = if session.skip_permissions {
+            SKIP_PERMISSIONS_MARKER
+        } else {
+            " "
+        };
+
+        let columns: Vec<String> = cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| {
+                let text = if i == PATH_COLUMN {
+                    // The last column, so it needs no padding
+                    let text = truncate_middle(cell, widths[i]);
+                    if options.links {
+                        return link_if(true, &text, &file_url(&session.worktree_path));
+                    }
+                    text
+                } else {
+                    truncate_end(cell, widths[i])
+                };
+                let padded = pad(&text, widths[i], specs[i].align);
+                if i == STATUS_COLUMN {
+                    paint(&padded, status_color(session), options.color)
+                } else {
+                    padded
+                }
+            })
+            .collect();
+
+        lines.push(format!(
+            "{}{} {:<1} {}",
             current_marker,
-            status_indicator,
-            truncate_string(&session.session_id, 30),
-            truncate_string(&session.branch, 20),
-            session.status.as_str()
-        );
+            session.status.symbol(),
+            permissions_marker,
+            columns.join(" ").trim_end()
+        ));
     }
 
-    Ok(())
+    if sessions.iter().any(|s| s.skip_permissions) {
+        lines.push(String::new());
+        lines.push(format!(
+            "{SKIP_PERMISSIONS_MARKER} = launched with --dangerously-skip-permissions"
+        ));
+    }
+
+    lines
 }
 
-pub fn display_verbose_sessions(sessions: &[SessionInfo]) -> Result<()> {
+fn compact_cells(session: &SessionInfo) -> [String; 6] {
+    let count = |value: Option<usize>| value.map_or_else(|| "-".to_string(), |n| n.to_string());
+    [
+        session.session_id.clone(),
+        session.branch.clone(),
+        session_status_text(session),
+        count(session.ahead_behind.map(|(ahead, _)| ahead)),
+        count(session.ahead_behind.map(|(_, behind)| behind)),
+        session.worktree_path.display().to_string(),
+    ]
+}
+
+/// Green for healthy sessions, yellow when missing, detached or idle, red when diverged
+fn status_color(session: &SessionInfo) -> Option<Color> {
+    if session.operation.is_some() {
+        return None;
+    }
+    match session.status {
+        SessionStatus::Diverged => Some(Color::Red),
+        SessionStatus::Missing | SessionStatus::Detached(_) => Some(Color::Yellow),
+        _ if session.idle_for.is_some() => Some(Color::Yellow),
+        SessionStatus::Active => Some(Color::Green),
+        SessionStatus::Dirty
+        | SessionStatus::Untracked
+        | SessionStatus::Archived
+        | SessionStatus::Planned => None,
+    }
+}
+
+/// `links` makes worktree paths clickable
+pub fn display_verbose_sessions(sessions: &[SessionInfo], links: bool) -> Result<()> {
     for (i, session) in sessions.iter().enumerate() {
         if i > 0 {
             println!();
@@ -111,10 +343,26 @@ pub fn display_verbose_sessions(sessions: &[SessionInfo]) -> Result<()> {
         println!(
             "  Status: {} {}",
             session.status.symbol(),
-            session.status.as_str()
+            session_status_text(session)
         );
         println!("  Branch: {}", session.branch);
         println!("  Base Branch: {}", session.base_branch);
+        if let Some(ref parent) = session.stacked_on {
+            println!("  Stacked On: {parent} → {}", session.session_id);
+        }
+        if !session.stacked_children.is_empty() {
+            println!(
+                "  Stacked Sessions: {} → {}",
+                session.session_id,
+                session.stacked_children.join(", ")
+            );
+        }
+        if let Some(ref original) = session.retry_of {
+            println!("  Retry Of: {original} → {}", session.session_id);
+        }
+        if let Some(ref base_commit) = session.base_commit {
+            println!("  Base Commit: {base_commit}");
+        }
         println!("  Merge Mode: {}", session.merge_mode);
         println!(
             "  Type: {}",
@@ -126,11 +374,42 @@ pub fn display_verbose_sessions(sessions: &[SessionInfo]) -> Result<()> {
         if let Some(container_status) = &session.container_status {
             println!("  Container: {container_status}");
         }
+        if let Some(container) = &session.container {
+            println!(
+                "  Image: {}",
+                container.image.as_deref().unwrap_or("default")
+            );
+            println!(
+                "  Network Isolation: {}",
+                if container.network_isolation {
+                    "on"
+                } else {
+                    "off"
+                }
+            );
+        }
+        if session.skip_permissions {
+            println!(
+                "  Permissions: {SKIP_PERMISSIONS_MARKER} skipped (--dangerously-skip-permissions)"
+            );
+        }
+        if session.pinned {
+            println!("  Pinned: yes");
+        }
 
         if session.status != SessionStatus::Archived {
-            println!("  Worktree: {}", session.worktree_path.display());
+            println!(
+                "  Worktree: {}",
+                link_if(
+                    links,
+                    &session.worktree_path.display().to_string(),
+                    &file_url(&session.worktree_path)
+                )
+            );
 
-            if let Some(has_changes) = session.has_uncommitted_changes {
+            if let Some(status) = session.worktree_status.filter(|s| !s.is_clean()) {
+                println!("  Uncommitted Changes: {}", status.summary());
+            } else if let Some(has_changes) = session.has_uncommitted_changes {
                 println!(
                     "  Uncommitted Changes: {}",
                     if has_changes { "yes" } else { "no" }
@@ -144,16 +423,184 @@ pub fn display_verbose_sessions(sessions: &[SessionInfo]) -> Result<()> {
                 modified.format("%Y-%m-%d %H:%M:%S UTC")
             );
         }
+        if let Some(ref todos) = session.todos {
+            println!("  Todos: {todos}");
+        }
+        if let Some(ref note) = session.note {
+            println!("  Note: {note}");
+        }
+        if !session.link_labels.is_empty() {
+            println!("  Links: {}", session.link_labels.join(", "));
+        }
+        if let Some(ref version) = session.para_version {
+            if version::is_newer_than_running(version) {
+                println!(
+                    "  Para Version: {version} (newer than this para {})",
+                    version::PARA_VERSION
+                );
+            } else {
+                println!("  Para Version: {version}");
+            }
+        }
     }
 
     Ok(())
 }
 
-fn truncate_string(s: &str, max_len: usize) -> String {
-    if s.len() <= max_len {
-        s.to_string()
+/// Placeholders accepted by `para list --format`
+pub const FORMAT_PLACEHOLDERS: &[&str] = &[
+    "name",
+    "branch",
+    "base",
+    "status",
+    "path",
+    "last_modified",
+    "type",
+    "note",
+];
+
+#[derive(Debug, Clone, PartialEq)]
+enum TemplateSegment {
+    Literal(String),
+    Placeholder(String),
+}
+
+/// A parsed `--format` template that renders one line per session.
+///
+/// Placeholders are written as `{name}`; the escapes `\t`, `\n` and `\\` are
+/// honored so tab-separated output can be requested from any shell.
+#[derive(Debug, Clone)]
+pub struct FormatTemplate {
+    segments: Vec<TemplateSegment>,
+}
+
+impl FormatTemplate {
+    pub fn parse(template: &str) -> Result<Self> {
+        Self::parse_with(template, FORMAT_PLACEHOLDERS)
+    }
+
+    /// Parse a template for another command, accepting `placeholders`
+    pub fn parse_with(template: &str, placeholders: &[&str]) -> Result<Self> {
+        let mut segments = Vec::new();
+        let mut literal = String::new();
+        let mut chars = template.chars();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' => match chars.next() {
+                    Some('t') => literal.push('\t'),
+                    Some('n') => literal.push('\n'),
+                    Some('\\') => literal.push('\\'),
+                    Some(other) => {
+                        literal.push('\\');
+                        literal.push(other);
+                    }
+                    None => literal.push('\\'),
+                },
+                '{' => {
+                    let mut name = String::new();
+                    let mut closed = false;
+                    for next in chars.by_ref() {
+                        if next == '}' {
+                            closed = true;
+                            break;
+                        }
+                        name.push(next);
+                    }
+
+                    if !closed {
+                        return Err(ParaError::invalid_args(format!(
+                            "Unclosed placeholder '{{{name}' in format template"
+                        )));
+                    }
+
+                    if !placeholders.contains(&name.as_str()) {
+                        return Err(ParaError::invalid_args(format!(
+                            "Unknown placeholder '{{{name}}}' in format template. Valid placeholders: {}",
+                            placeholders
+                                .iter()
+                                .map(|p| format!("{{{p}}}"))
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        )));
+                    }
+
+                    if !literal.is_empty() {
+                        segments.push(TemplateSegment::Literal(std::mem::take(&mut literal)));
+                    }
+                    segments.push(TemplateSegment::Placeholder(name));
+                }
+                _ => literal.push(c),
+            }
+        }
+
+        if !literal.is_empty() {
+            segments.push(TemplateSegment::Literal(literal));
+        }
+
+        Ok(Self { segments })
+    }
+
+    /// Render a single session without a trailing newline
+    pub fn render(&self, session: &SessionInfo) -> String {
+        self.render_with(|name| placeholder_value(session, name))
+    }
+
+    /// Render with the placeholder values `value` gives
+    pub fn render_with(&self, value: impl Fn(&str) -> String) -> String {
+        self.segments
+            .iter()
+            .map(|segment| match segment {
+                TemplateSegment::Literal(text) => text.clone(),
+                TemplateSegment::Placeholder(name) => value(name),
+            })
+            .collect()
+    }
+
+    /// Render every session on its own line; empty input renders nothing
+    pub fn render_all(&self, sessions: &[SessionInfo]) -> String {
+        sessions
+            .iter()
+            .map(|session| format!("{}\n", self.render(session)))
+            .collect()
+    }
+}
+
+fn placeholder_value(session: &SessionInfo, name: &str) -> String {
+    match name {
+        "name" => session.session_id.clone(),
+        "branch" => session.branch.clone(),
+        "base" => session.base_branch.clone(),
+        "status" => session.status.label(),
+        "path" => session.worktree_path.display().to_string(),
+        "last_modified" => session
+            .last_modified
+            .map(|modified| modified.to_rfc3339())
+            .unwrap_or_default(),
+        "type" => match session.session_type {
+            SessionType::Container => "container".to_string(),
+            SessionType::Worktree => "worktree".to_string(),
+        },
+        "note" => session.note.clone().unwrap_or_default(),
+        _ => String::new(),
+    }
+}
+
+/// Status column text; a running finish or cancel takes precedence
+fn session_status_text(session: &SessionInfo) -> String {
+    let text = match (&session.operation, session.idle_for) {
+        (Some(operation), _) => operation.describe(Utc::now()),
+        (None, Some(idle_for)) => format!(
+            "{}, idle {}",
+            session.status.label(),
+            format_idle_duration(idle_for)
+        ),
+        (None, None) => session.status.label(),
+    };
+    if session.pinned {
+        format!("{text} {}", theme::pin_marker())
     } else {
-        format!("{}...", &s[..max_len.saturating_sub(3)])
+        text
     }
 }
 
@@ -189,6 +636,21 @@ mod tests {
             is_current,
             session_type: SessionType::Worktree,
             container_status: None,
+            skip_permissions: false,
+            operation: None,
+            base_commit: None,
+            note: None,
+            idle_for: None,
+            todos: None,
+            stacked_children: Vec::new(),
+            stacked_on: None,
+            ahead_behind: None,
+            retry_of: None,
+            worktree_status: None,
+            container: None,
+            link_labels: Vec::new(),
+            para_version: None,
+            pinned: false,
         }
     }
 
@@ -196,22 +658,68 @@ mod tests {
     fn test_session_status_display() {
         assert_eq!(SessionStatus::Active.as_str(), "active");
         assert_eq!(SessionStatus::Dirty.as_str(), "dirty");
+        assert_eq!(SessionStatus::Untracked.as_str(), "untracked");
         assert_eq!(SessionStatus::Missing.as_str(), "missing");
         assert_eq!(SessionStatus::Archived.as_str(), "archived");
 
         assert_eq!(SessionStatus::Active.symbol(), "✓");
         assert_eq!(SessionStatus::Dirty.symbol(), "●");
+        assert_eq!(SessionStatus::Untracked.symbol(), "○");
         assert_eq!(SessionStatus::Missing.symbol(), "✗");
         assert_eq!(SessionStatus::Archived.symbol(), "📦");
     }
 
     #[test]
-    fn test_truncate_string() {
-        assert_eq!(truncate_string("short", 10), "short");
-        assert_eq!(truncate_string("exactly_ten", 11), "exactly_ten");
-        assert_eq!(truncate_string("this_is_too_long", 10), "this_is...");
-        assert_eq!(truncate_string("abc", 3), "abc");
-        assert_eq!(truncate_string("abcd", 3), "...");
+    fn test_session_status_text_shows_running_operation() {
+        let mut session =
+            create_test_session_info("auth", "para/auth", SessionStatus::Active, false);
+        assert_eq!(session_status_text(&session), "active");
+
+        session.operation = Some(OperationRecord {
+            operation: crate::core::session::SessionOperation::Cancelling,
+            pid: std::process::id(),
+            started_at: Utc::now() - chrono::Duration::seconds(5),
+        });
+        assert!(session_status_text(&session).starts_with("Cancelling… "));
+    }
+
+    #[test]
+    fn test_session_status_text_shows_idle_badge() {
+        let mut session =
+            create_test_session_info("auth", "para/auth", SessionStatus::Dirty, false);
+        session.idle_for = Some(chrono::Duration::hours(75));
+        assert_eq!(session_status_text(&session), "dirty, idle 3d");
+    }
+
+    #[test]
+    fn test_session_status_text_marks_pinned_sessions() {
+        let mut session =
+            create_test_session_info("perf", "para/perf", SessionStatus::Active, false);
+        session.pinned = true;
+        assert_eq!(
+            session_status_text(&session),
+            format!("active {}", theme::pin_marker())
+        );
+    }
+
+    #[test]
+    fn test_compact_cells_and_status_colors() {
+        let mut session =
+            create_test_session_info("feature", "para/feature", SessionStatus::Active, false);
+        session.ahead_behind = Some((3, 12));
+        let cells = compact_cells(&session);
+        assert_eq!(cells[3], "3");
+        assert_eq!(cells[4], "12");
+        session.ahead_behind = None;
+        assert_eq!(compact_cells(&session)[3], "-");
+
+        assert_eq!(status_color(&session), Some(Color::Green));
+        session.idle_for = Some(chrono::Duration::hours(30));
+        assert_eq!(status_color(&session), Some(Color::Yellow));
+        session.status = SessionStatus::Missing;
+        assert_eq!(status_color(&session), Some(Color::Yellow));
+        session.status = SessionStatus::Diverged;
+        assert_eq!(status_color(&session), Some(Color::Red));
     }
 
     #[test]
@@ -237,12 +745,59 @@ mod tests {
         ];
 
         // This should not panic
-        let result = display_compact_sessions(&sessions);
+        for width in [40, 80, 200] {
+            let options = TableOptions {
+                width,
+                color: true,
+                full_paths: width == 40,
+                links: false,
+            };
+            assert!(display_compact_sessions(&sessions, &options).is_ok());
+        }
+        let result = display_compact_sessions(
+            &[],
+            &TableOptions {
+                width: 80,
+                color: false,
+                full_paths: false,
+                links: false,
+            },
+        );
         assert!(result.is_ok());
 
         Ok(())
     }
 
+    #[test]
+    fn test_compact_table_links_only_paths() {
+        let sessions = vec![create_test_session_info(
+            "linked",
+            "para/linked",
+            SessionStatus::Active,
+            false,
+        )];
+        let options = |links| TableOptions {
+            width: 200,
+            color: false,
+            links,
+            full_paths: true,
+        };
+
+        let linked = compact_table(&sessions, &options(true));
+        assert!(linked[2]
+            .ends_with("\x1b]8;;file:///path/to/linked\x1b\\/path/to/linked\x1b]8;;\x1b\\"));
+        assert_eq!(linked[2].matches("\x1b]8;;").count(), 2);
+
+        let plain = compact_table(&sessions, &options(false));
+        assert!(plain.iter().all(|line| !line.contains('\x1b')));
+        assert!(plain[2].ends_with("/path/to/linked"));
+
+        // --no-color and scripted output never get links
+        assert!(!hyperlinks_enabled(true));
+        let template = FormatTemplate::parse("{name}\t{path}").unwrap();
+        assert_eq!(template.render_all(&sessions), "linked\t/path/to/linked\n");
+    }
+
     #[test]
     fn test_display_verbose_sessions() -> Result<()> {
         let sessions = vec![{
@@ -258,7 +813,7 @@ mod tests {
         }];
 
         // This should not panic
-        let result = display_verbose_sessions(&sessions);
+        let result = display_verbose_sessions(&sessions, false);
         assert!(result.is_ok());
 
         Ok(())
@@ -313,4 +868,42 @@ mod tests {
         assert_eq!(sessions[2].session_id, "earliest");
         assert_eq!(sessions[3].session_id, "none"); // None should be last
     }
+
+    #[test]
+    fn test_format_template_placeholders_and_escapes() {
+        let session = create_test_session_info("auth", "para/auth", SessionStatus::Dirty, false);
+
+        let template = FormatTemplate::parse("{name}\\t{branch}\\t{path}").unwrap();
+        assert_eq!(template.render(&session), "auth\tpara/auth\t/path/to/auth");
+
+        let template = FormatTemplate::parse("{status}\\n{base} \\\\ {type}").unwrap();
+        assert_eq!(template.render(&session), "dirty\nmain \\ worktree");
+
+        let template = FormatTemplate::parse("[{last_modified}]").unwrap();
+        assert_eq!(template.render(&session), "[]");
+    }
+
+    #[test]
+    fn test_format_template_unknown_placeholder() {
+        let err = FormatTemplate::parse("{name} {labelz}")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Unknown placeholder '{labelz}'"));
+        assert!(err.contains("{name}, {branch}, {base}"));
+
+        let err = FormatTemplate::parse("{name").unwrap_err().to_string();
+        assert!(err.contains("Unclosed placeholder"));
+    }
+
+    #[test]
+    fn test_format_template_render_all() {
+        let template = FormatTemplate::parse("{name}").unwrap();
+        assert_eq!(template.render_all(&[]), "");
+
+        let sessions = vec![
+            create_test_session_info("one", "para/one", SessionStatus::Active, false),
+            create_test_session_info("two", "para/two", SessionStatus::Active, false),
+        ];
+        assert_eq!(template.render_all(&sessions), "one\ntwo\n");
+    }
 }
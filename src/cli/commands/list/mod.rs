@@ -1,28 +1,63 @@
+use crate::cli::context::CommandContext;
 use crate::cli::parser::ListArgs;
-use crate::config::Config;
 use crate::core::git::GitService;
+use crate::core::session::orphans::find_orphaned_branches;
+use crate::core::session::recovery::parse_time_bound;
 use crate::core::session::SessionManager;
 use crate::utils::Result;
+use chrono::Utc;
 
 pub mod analyzer;
 pub mod formatters;
+pub mod table;
 pub mod test_utils;
 
 pub use analyzer::*;
 pub use formatters::*;
 
-pub fn execute(config: Config, args: ListArgs) -> Result<()> {
-    let session_manager = SessionManager::new(&config);
+pub fn execute(ctx: &CommandContext, args: ListArgs) -> Result<()> {
+    // Reject bad templates before doing any git work
+    if let Some(ref format) = args.format {
+        FormatTemplate::parse(format)?;
+    }
+    let now = Utc::now();
+    let since = args
+        .since
+        .as_deref()
+        .map(|value| parse_time_bound("--since", value, now))
+        .transpose()?;
+    let until = args
+        .until
+        .as_deref()
+        .map(|value| parse_time_bound("--until", value, now))
+        .transpose()?;
+
+    let git_service = ctx.git_service()?;
+    let session_manager = ctx.session_manager();
+    if args.orphaned {
+        return list_orphaned_branches(&session_manager, git_service, args.quiet);
+    }
 
-    let git_service = GitService::discover()?;
-    let sessions = if args.archived {
-        list_archived_sessions(&session_manager, &git_service)?
+    let mut sessions = if args.archived {
+        list_archived_sessions(&session_manager, git_service)?
     } else {
-        list_active_sessions(&session_manager, &git_service)?
+        let mut sessions = list_active_sessions(&session_manager, git_service)?;
+        if let Some(hours) = ctx.config().session.idle_alert_hours {
+            mark_idle_sessions(&mut sessions, &session_manager, hours, now);
+        }
+        sessions
     };
 
+    filter_sessions_by_creation(&mut sessions, since, until);
+    sort_sessions(
+        &mut sessions,
+        args.sort,
+        args.reverse,
+        session_manager.state_dir(),
+    );
+
     if sessions.is_empty() {
-        if !args.quiet {
+        if !args.quiet && args.format.is_none() {
             if args.archived {
                 println!("No archived sessions found.");
             } else {
@@ -36,10 +71,48 @@ pub fn execute(config: Config, args: ListArgs) -> Result<()> {
     Ok(())
 }
 
+/// Para branches without a session, with hints on what to do with them
+fn list_orphaned_branches(
+    session_manager: &SessionManager,
+    git_service: &GitService,
+    quiet: bool,
+) -> Result<()> {
+    let orphans = find_orphaned_branches(session_manager, git_service)?;
+    if quiet {
+        for orphan in &orphans {
+            println!("{}", orphan.name);
+        }
+        return Ok(());
+    }
+    if orphans.is_empty() {
+        println!("No orphaned para branches found.");
+        return Ok(());
+    }
+
+    let width = orphans.iter().map(|o| o.name.len()).max().unwrap_or(0);
+    println!("Orphaned para branches ({}):", orphans.len());
+    for orphan in &orphans {
+        let tip = orphan
+            .tip_date
+            .map(|date| date.format("%Y-%m-%d %H:%M").to_string())
+            .unwrap_or_else(|| "unknown".to_string());
+        let commits = match orphan.commit_count {
+            1 => "1 commit".to_string(),
+            n => format!("{n} commits"),
+        };
+        println!("  {:<width$}  {tip}  {commits}", orphan.name);
+    }
+    println!();
+    println!(
+        "Delete them with 'para clean', or turn one into a session with 'para adopt <branch>'."
+    );
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::cli::parser::ListArgs;
+    use crate::cli::parser::{ListArgs, ListSort};
     use crate::test_utils::test_helpers::*;
     use tempfile::TempDir;
 
@@ -63,6 +136,14 @@ mod tests {
             verbose: false,
             archived: false,
             quiet: false,
+            format: None,
+            sort: ListSort::Created,
+            reverse: false,
+            since: None,
+            until: None,
+            no_color: false,
+            full_paths: false,
+            orphaned: false,
         };
 
         let result = display_sessions(&sessions, &args);
@@ -121,4 +202,40 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_list_discovers_each_repository_once() {
+        use crate::core::git::discoveries;
+
+        let (git_temp, git_service) = setup_test_repo();
+        let config = create_test_config_with_dir(&git_temp);
+        let repo_root = git_service.repository().root.clone();
+        let mut session_manager = SessionManager::for_repository(&config, &repo_root);
+        let session = session_manager
+            .create_session_with_all_flags("listed".to_string(), None, false, false, None)
+            .unwrap();
+
+        discoveries::take();
+        let ctx = CommandContext::in_dir(config, repo_root.clone());
+        let args = ListArgs {
+            verbose: true,
+            archived: false,
+            quiet: false,
+            format: None,
+            sort: ListSort::Created,
+            reverse: false,
+            since: None,
+            until: None,
+            no_color: true,
+            full_paths: false,
+            orphaned: false,
+        };
+        execute(&ctx, args).unwrap();
+
+        // The repository once, plus each session worktree for its status
+        let roots = discoveries::take_distinct();
+        assert_eq!(roots.len(), 2, "{roots:?}");
+        assert!(roots.contains(&repo_root.canonicalize().unwrap()));
+        assert!(roots.contains(&session.worktree_path.canonicalize().unwrap()));
+    }
 }
@@ -0,0 +1,234 @@
+//! Column layout for the compact `para list` table
+//!
+//! Columns start at the width of their longest cell. When the row does not
+//! fit the terminal, columns give up space in proportion to their weight but
+//! never shrink below their minimum. Paths lose their middle instead of their
+//! end. Colors are only used when stdout is a terminal, and never with
+//! `--no-color` or `NO_COLOR` set.
+
+/// Width used when the terminal size is unknown, e.g. when piped
+const FALLBACK_WIDTH: usize = 100;
+
+/// Spaces between two columns
+pub const COLUMN_GAP: usize = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Align {
+    Left,
+    Right,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ColumnSpec {
+    pub header: &'static str,
+    /// Narrowest the column is shrunk to
+    pub min: usize,
+    /// Share of the space taken when the row is too wide; 0 never shrinks
+    pub weight: usize,
+    pub align: Align,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Green,
+    Yellow,
+    Red,
+}
+
+impl Color {
+    fn code(self) -> &'static str {
+        match self {
+            Color::Green => "32",
+            Color::Yellow => "33",
+            Color::Red => "31",
+        }
+    }
+}
+
+/// Terminal width from `COLUMNS`, then the terminal itself, then a fallback
+pub fn terminal_width() -> usize {
+    std::env::var("COLUMNS")
+        .ok()
+        .and_then(|columns| columns.trim().parse().ok())
+        .filter(|width| *width > 0)
+        .or_else(|| {
+            crossterm::terminal::size()
+                .ok()
+                .map(|(width, _)| usize::from(width))
+                .filter(|width| *width > 0)
+        })
+        .unwrap_or(FALLBACK_WIDTH)
+}
+
+pub fn color_enabled(no_color: bool) -> bool {
+    !no_color && std::env::var_os("NO_COLOR").is_none() && atty::is(atty::Stream::Stdout)
+}
+
+/// Widths for columns whose longest cells are `natural` wide, so that the
+/// columns and the gaps between them fit in `available` characters. If even
+/// the minimums do not fit, the row is as narrow as the minimums allow.
+pub fn allocate_widths(specs: &[ColumnSpec], natural: &[usize], available: usize) -> Vec<usize> {
+    let gaps = specs.len().saturating_sub(1) * COLUMN_GAP;
+    let budget = available.saturating_sub(gaps);
+    let mut widths = natural.to_vec();
+    let floor = |i: usize| specs[i].min.min(natural[i]);
+
+    let mut excess = widths.iter().sum::<usize>().saturating_sub(budget);
+    while excess > 0 {
+        let shrinkable: Vec<usize> = (0..specs.len())
+            .filter(|&i| specs[i].weight > 0 && widths[i] > floor(i))
+            .collect();
+        let total_weight: usize = shrinkable.iter().map(|&i| specs[i].weight).sum();
+        if total_weight == 0 {
+            break;
+        }
+
+        let mut shrunk = 0;
+        for &i in &shrinkable {
+            let share = (excess * specs[i].weight).div_ceil(total_weight);
+            let cut = share.min(widths[i] - floor(i)).min(excess - shrunk);
+            widths[i] -= cut;
+            shrunk += cut;
+        }
+        if shrunk == 0 {
+            break;
+        }
+        excess -= shrunk;
+    }
+    widths
+}
+
+/// Cut `text` to `width` characters, marking the cut with `…`
+pub fn truncate_end(text: &str, width: usize) -> String {
+    if text.chars().count() <= width {
+        return text.to_string();
+    }
+    if width == 0 {
+        return String::new();
+    }
+    let kept: String = text.chars().take(width - 1).collect();
+    format!("{kept}…")
+}
+
+/// Cut the middle out of `text` so it fits `width` characters. Paths keep as
+/// many whole trailing components as fit, e.g. `…/subtrees/para/name`.
+pub fn truncate_middle(text: &str, width: usize) -> String {
+    let length = text.chars().count();
+    if length <= width {
+        return text.to_string();
+    }
+    if width <= 1 {
+        return "…".chars().take(width).collect();
+    }
+
+    // The first separator whose tail fits leaves the longest tail
+    if let Some(index) = text
+        .match_indices('/')
+        .map(|(index, _)| index)
+        .find(|&index| text[index..].chars().count() < width)
+    {
+        return format!("…{}", &text[index..]);
+    }
+
+    let tail_len = (width - 1) / 2;
+    let head: String = text.chars().take(width - 1 - tail_len).collect();
+    let tail: String = text.chars().skip(length - tail_len).collect();
+    format!("{head}…{tail}")
+}
+
+/// Pad `text` to `width` characters
+pub fn pad(text: &str, width: usize, align: Align) -> String {
+    let fill = " ".repeat(width.saturating_sub(text.chars().count()));
+    match align {
+        Align::Left => format!("{text}{fill}"),
+        Align::Right => format!("{fill}{text}"),
+    }
+}
+
+/// Wrap already padded `text` in the escape codes for `color`
+pub fn paint(text: &str, color: Option<Color>, enabled: bool) -> String {
+    match color {
+        Some(color) if enabled => format!("\x1b[{}m{text}\x1b[0m", color.code()),
+        _ => text.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPECS: [ColumnSpec; 3] = [
+        ColumnSpec {
+            header: "Session",
+            min: 10,
+            weight: 2,
+            align: Align::Left,
+        },
+        ColumnSpec {
+            header: "Ahead",
+            min: 5,
+            weight: 0,
+            align: Align::Right,
+        },
+        ColumnSpec {
+            header: "Path",
+            min: 12,
+            weight: 4,
+            align: Align::Left,
+        },
+    ];
+
+    #[test]
+    fn test_allocate_widths_across_terminal_widths() {
+        let natural = [30, 5, 60];
+
+        // Wide terminals keep every natural width
+        assert_eq!(allocate_widths(&SPECS, &natural, 200), vec![30, 5, 60]);
+        assert_eq!(allocate_widths(&SPECS, &natural, 97), vec![30, 5, 60]);
+
+        // Narrower ones shrink by weight; fixed columns keep their width
+        assert_eq!(allocate_widths(&SPECS, &natural, 80), vec![24, 5, 49]);
+        assert_eq!(allocate_widths(&SPECS, &natural, 60), vec![17, 5, 36]);
+
+        // Never below the minimums, even if the row overflows
+        assert_eq!(allocate_widths(&SPECS, &natural, 30), vec![10, 5, 13]);
+        assert_eq!(allocate_widths(&SPECS, &natural, 20), vec![10, 5, 12]);
+        assert_eq!(allocate_widths(&SPECS, &natural, 0), vec![10, 5, 12]);
+    }
+
+    #[test]
+    fn test_allocate_widths_keeps_narrow_columns() {
+        // A column narrower than its minimum is not widened
+        assert_eq!(allocate_widths(&SPECS, &[4, 5, 40], 30), vec![4, 5, 19]);
+    }
+
+    #[test]
+    fn test_truncate_middle_keeps_path_tail() {
+        let path = "/home/user/project/.para/worktrees/subtrees/para/very-long-name";
+        assert_eq!(truncate_middle(path, 80), path);
+        assert_eq!(truncate_middle(path, 32), "…/subtrees/para/very-long-name");
+        assert_eq!(truncate_middle(path, 21), "…/para/very-long-name");
+        assert_eq!(truncate_middle(path, 20), "…/very-long-name");
+        assert_eq!(truncate_middle(path, 16), "…/very-long-name");
+
+        // Once the last component does not fit, both of its ends are kept
+        assert_eq!(truncate_middle(path, 9), "/hom…name");
+        assert_eq!(truncate_middle("abcdefghij", 5), "ab…ij");
+        assert_eq!(truncate_middle("abcdefghij", 1), "…");
+        assert_eq!(truncate_middle("abcdefghij", 0), "");
+        for width in 0..70 {
+            assert!(truncate_middle(path, width).chars().count() <= width);
+        }
+    }
+
+    #[test]
+    fn test_truncate_end_and_pad() {
+        assert_eq!(truncate_end("feature-branch", 20), "feature-branch");
+        assert_eq!(truncate_end("feature-branch", 8), "feature…");
+        assert_eq!(truncate_end("feature-branch", 0), "");
+        assert_eq!(pad("3", 5, Align::Right), "    3");
+        assert_eq!(pad("név", 5, Align::Left), "név  ");
+        assert_eq!(paint("ok", Some(Color::Green), true), "\x1b[32mok\x1b[0m");
+        assert_eq!(paint("ok", Some(Color::Green), false), "ok");
+    }
+}
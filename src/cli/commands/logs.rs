@@ -0,0 +1,151 @@
+use crate::cli::context::CommandContext;
+use crate::cli::parser::LogsArgs;
+use crate::core::docker::resources::SystemDockerCli;
+use crate::core::logs::{self, LogContent, LogSection, LogSource};
+use crate::core::session::{manifest, SessionState, SessionType};
+use crate::utils::{ParaError, Result};
+use std::fs;
+use std::path::Path;
+use std::sync::mpsc;
+use std::thread;
+
+pub fn execute(ctx: &CommandContext, args: LogsArgs) -> Result<()> {
+    let session_manager = ctx.session_manager();
+    let session = match args.session {
+        Some(ref name) => session_manager.resolve_session(name)?,
+        None => {
+            let name = manifest::current_session(&session_manager, ctx.current_dir())?.ok_or_else(
+                || ParaError::invalid_args("Not in a para session directory. Name the session"),
+            )?;
+            session_manager.load_state(&name)?
+        }
+    };
+
+    let sources = selected_sources(&args.source);
+    let state_dir = session_manager.state_dir();
+    for section in logs::collect(state_dir, &session, &sources, &SystemDockerCli) {
+        print!("{}", format_section(&section));
+    }
+
+    if args.follow {
+        follow(state_dir, &session, &sources);
+    }
+    Ok(())
+}
+
+/// `requested` in the order given without repeats, or every source
+fn selected_sources(requested: &[LogSource]) -> Vec<LogSource> {
+    if requested.is_empty() {
+        return LogSource::ALL.to_vec();
+    }
+    let mut sources = Vec::new();
+    for source in requested {
+        if !sources.contains(source) {
+            sources.push(*source);
+        }
+    }
+    sources
+}
+
+fn format_section(section: &LogSection) -> String {
+    match &section.content {
+        LogContent::Text(text) => {
+            let mut out = format!("==> {} <==\n{text}", section.source.name());
+            if !text.is_empty() && !text.ends_with('\n') {
+                out.push('\n');
+            }
+            out.push('\n');
+            out
+        }
+        LogContent::Absent(reason) => {
+            format!("==> {} <== (absent: {reason})\n\n", section.source.name())
+        }
+    }
+}
+
+/// Print new output of `sources` as it is written, prefixed with its source
+fn follow(state_dir: &Path, session: &SessionState, sources: &[LogSource]) {
+    let (sender, receiver) = mpsc::channel::<(LogSource, String)>();
+    for &source in sources {
+        let (lines, source_lines) = mpsc::channel();
+        let sender = sender.clone();
+        thread::spawn(move || {
+            for line in source_lines {
+                if sender.send((source, line)).is_err() {
+                    break;
+                }
+            }
+        });
+
+        match source {
+            LogSource::Setup => {
+                let path = logs::setup_log_path(state_dir, &session.name);
+                let offset = file_len(&path);
+                thread::spawn(move || logs::follow_file(path, offset, |_| true, lines));
+            }
+            LogSource::Daemon => {
+                let path = state_dir.join(logs::LOG_FILE);
+                let offset = file_len(&path);
+                let name = session.name.clone();
+                thread::spawn(move || {
+                    logs::follow_file(
+                        path,
+                        offset,
+                        |line| logs::is_session_entry(line, &name),
+                        lines,
+                    )
+                });
+            }
+            LogSource::Container => {
+                if matches!(session.session_type, SessionType::Container { .. }) {
+                    let name = session.name.clone();
+                    thread::spawn(move || logs::follow_container(&name, lines));
+                }
+            }
+        }
+    }
+    drop(sender);
+
+    for (source, line) in receiver {
+        println!("[{}] {line}", source.name());
+    }
+}
+
+fn file_len(path: &Path) -> u64 {
+    fs::metadata(path).map(|meta| meta.len()).unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_selected_sources() {
+        assert_eq!(selected_sources(&[]), LogSource::ALL.to_vec());
+        assert_eq!(
+            selected_sources(&[LogSource::Daemon, LogSource::Setup, LogSource::Daemon]),
+            vec![LogSource::Daemon, LogSource::Setup]
+        );
+    }
+
+    #[test]
+    fn test_format_section() {
+        let text = LogSection {
+            source: LogSource::Setup,
+            content: LogContent::Text("npm install\ndone".to_string()),
+        };
+        assert_eq!(
+            format_section(&text),
+            "==> setup <==\nnpm install\ndone\n\n"
+        );
+
+        let absent = LogSection {
+            source: LogSource::Container,
+            content: LogContent::Absent("not a container session".to_string()),
+        };
+        assert_eq!(
+            format_section(&absent),
+            "==> container <== (absent: not a container session)\n\n"
+        );
+    }
+}
@@ -0,0 +1,340 @@
+//! Registering the para MCP server with desktop clients
+//!
+//! Claude Desktop and Cursor don't read a repository's `.mcp.json`; they load
+//! MCP servers from their own config files. `para mcp init --claude-desktop`
+//! and `--cursor` merge the para entry into those files, keeping every other
+//! server and a backup of the file as it was.
+
+use super::strategies::McpServerConfig;
+use crate::ui::theme;
+use crate::utils::{ParaError, Result};
+use serde_json::{json, Value};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Name of the server entry para adds under `mcpServers`
+const SERVER_NAME: &str = "para";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum McpClient {
+    ClaudeDesktop,
+    Cursor,
+}
+
+impl McpClient {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            McpClient::ClaudeDesktop => "Claude Desktop",
+            McpClient::Cursor => "Cursor",
+        }
+    }
+
+    /// The client's global MCP config file
+    pub fn config_path(&self, dirs: &ClientDirs) -> PathBuf {
+        match self {
+            McpClient::ClaudeDesktop => dirs
+                .app_config
+                .join("Claude")
+                .join("claude_desktop_config.json"),
+            McpClient::Cursor => dirs.home.join(".cursor").join("mcp.json"),
+        }
+    }
+}
+
+/// Base directories the client config paths are built from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientDirs {
+    pub home: PathBuf,
+    /// Per-user application config: `~/Library/Application Support` on macOS,
+    /// `%APPDATA%` on Windows and `$XDG_CONFIG_HOME` (or `~/.config`) elsewhere
+    pub app_config: PathBuf,
+}
+
+impl ClientDirs {
+    pub fn detect() -> Result<Self> {
+        let home = std::env::var_os("HOME")
+            .or_else(|| std::env::var_os("USERPROFILE"))
+            .map(PathBuf::from)
+            .ok_or_else(|| ParaError::config_error("Cannot determine the home directory"))?;
+
+        let app_config = if cfg!(target_os = "macos") {
+            home.join("Library").join("Application Support")
+        } else if cfg!(windows) {
+            std::env::var_os("APPDATA")
+                .map(PathBuf::from)
+                .unwrap_or_else(|| home.join("AppData").join("Roaming"))
+        } else {
+            std::env::var_os("XDG_CONFIG_HOME")
+                .map(PathBuf::from)
+                .filter(|dir| dir.is_absolute())
+                .unwrap_or_else(|| home.join(".config"))
+        };
+
+        Ok(Self { home, app_config })
+    }
+}
+
+/// What registering the server did to a client config file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InstallOutcome {
+    /// The file did not exist and was created
+    Created,
+    /// The file was changed; `backup` holds its previous content
+    Updated { backup: PathBuf },
+    /// The file already had this exact para entry
+    Unchanged,
+}
+
+/// Add or replace the para entry in the client config at `path`
+pub fn install_server(path: &Path, server: &McpServerConfig) -> Result<InstallOutcome> {
+    let existing = match fs::read_to_string(path) {
+        Ok(content) => Some(content),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+        Err(e) => return Err(ParaError::from_io("read", path, e)),
+    };
+
+    let mut config = match existing.as_deref().map(str::trim) {
+        None | Some("") => json!({}),
+        Some(content) => serde_json::from_str(content).map_err(|e| {
+            ParaError::invalid_config(format!(
+                "{} is not valid JSON, not changing it: {e}",
+                path.display()
+            ))
+        })?,
+    };
+    let Some(root) = config.as_object_mut() else {
+        return Err(ParaError::invalid_config(format!(
+            "{} does not contain a JSON object, not changing it",
+            path.display()
+        )));
+    };
+
+    let servers = root
+        .entry("mcpServers")
+        .or_insert_with(|| json!({}))
+        .as_object_mut()
+        .ok_or_else(|| {
+            ParaError::invalid_config(format!(
+                "mcpServers in {} is not an object, not changing it",
+                path.display()
+            ))
+        })?;
+
+    let entry = server_entry(server);
+    if servers.get(SERVER_NAME) == Some(&entry) {
+        return Ok(InstallOutcome::Unchanged);
+    }
+    servers.insert(SERVER_NAME.to_string(), entry);
+
+    let outcome = match existing {
+        Some(content) => {
+            let backup = backup_path(path);
+            fs::write(&backup, content).map_err(|e| ParaError::from_io("back up", path, e))?;
+            InstallOutcome::Updated { backup }
+        }
+        None => {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).map_err(|e| ParaError::from_io("create", parent, e))?;
+            }
+            InstallOutcome::Created
+        }
+    };
+
+    let formatted = serde_json::to_string_pretty(&config)?;
+    fs::write(path, format!("{formatted}\n")).map_err(|e| ParaError::from_io("write", path, e))?;
+    Ok(outcome)
+}
+
+fn server_entry(server: &McpServerConfig) -> Value {
+    json!({
+        "command": server.command,
+        "args": server.args,
+    })
+}
+
+/// `<file>.para-backup-<timestamp>` next to `path`, so earlier backups are kept
+fn backup_path(path: &Path) -> PathBuf {
+    let timestamp = chrono::Local::now().format("%Y%m%d-%H%M%S");
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(format!(".para-backup-{timestamp}"));
+    let backup = path.with_file_name(&name);
+    if !backup.exists() {
+        return backup;
+    }
+    (1..)
+        .map(|n| {
+            let mut numbered = name.clone();
+            numbered.push(format!("-{n}"));
+            path.with_file_name(numbered)
+        })
+        .find(|candidate| !candidate.exists())
+        .expect("a free backup name")
+}
+
+/// Register `server` with `client` and report what changed
+pub fn configure_client(
+    client: McpClient,
+    dirs: &ClientDirs,
+    server: &McpServerConfig,
+) -> Result<()> {
+    let path = client.config_path(dirs);
+    match install_server(&path, server)? {
+        InstallOutcome::Created => {
+            println!(
+                "{}",
+                theme::success(format!(
+                    "Created {} with the para MCP server",
+                    path.display()
+                ))
+            );
+            println!("   To revert: rm '{}'", path.display());
+        }
+        InstallOutcome::Updated { backup } => {
+            println!(
+                "{}",
+                theme::success(format!(
+                    "Added the para MCP server to {} ({})",
+                    client.display_name(),
+                    path.display()
+                ))
+            );
+            println!("   Backup of the previous file: {}", backup.display());
+            println!(
+                "   To revert: mv '{}' '{}'",
+                backup.display(),
+                path.display()
+            );
+        }
+        InstallOutcome::Unchanged => {
+            println!(
+                "{}",
+                theme::success(format!(
+                    "{} already has the para MCP server ({})",
+                    client.display_name(),
+                    path.display()
+                ))
+            );
+        }
+    }
+    println!("   Restart {} to load the change.", client.display_name());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn test_dirs(temp_dir: &TempDir) -> ClientDirs {
+        ClientDirs {
+            home: temp_dir.path().join("home"),
+            app_config: temp_dir.path().join("app-config"),
+        }
+    }
+
+    fn server(command: &str) -> McpServerConfig {
+        McpServerConfig {
+            command: command.to_string(),
+            args: vec!["--stdio".to_string()],
+            description: "test".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_client_config_paths() {
+        let temp_dir = TempDir::new().unwrap();
+        let dirs = test_dirs(&temp_dir);
+        assert_eq!(
+            McpClient::ClaudeDesktop.config_path(&dirs),
+            dirs.app_config
+                .join("Claude")
+                .join("claude_desktop_config.json")
+        );
+        assert_eq!(
+            McpClient::Cursor.config_path(&dirs),
+            dirs.home.join(".cursor").join("mcp.json")
+        );
+    }
+
+    #[test]
+    fn test_install_creates_missing_config() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = McpClient::Cursor.config_path(&test_dirs(&temp_dir));
+
+        let outcome = install_server(&path, &server("para-mcp-server")).unwrap();
+        assert_eq!(outcome, InstallOutcome::Created);
+
+        let written: Value = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(written["mcpServers"]["para"]["command"], "para-mcp-server");
+        assert_eq!(written["mcpServers"]["para"]["args"], json!(["--stdio"]));
+    }
+
+    #[test]
+    fn test_install_merges_without_clobbering_and_backs_up() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = McpClient::ClaudeDesktop.config_path(&test_dirs(&temp_dir));
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        let original = r#"{
+  "globalShortcut": "Ctrl+Space",
+  "mcpServers": {
+    "filesystem": {"command": "npx", "args": ["-y", "server-filesystem"]}
+  }
+}"#;
+        fs::write(&path, original).unwrap();
+
+        let InstallOutcome::Updated { backup } =
+            install_server(&path, &server("para-mcp-server")).unwrap()
+        else {
+            panic!("expected the config to be updated");
+        };
+        assert_eq!(fs::read_to_string(&backup).unwrap(), original);
+
+        let written: Value = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(written["globalShortcut"], "Ctrl+Space");
+        assert_eq!(written["mcpServers"]["filesystem"]["command"], "npx");
+        assert_eq!(written["mcpServers"]["para"]["command"], "para-mcp-server");
+
+        // Running again changes nothing and makes no new backup
+        assert_eq!(
+            install_server(&path, &server("para-mcp-server")).unwrap(),
+            InstallOutcome::Unchanged
+        );
+        let backups = fs::read_dir(path.parent().unwrap())
+            .unwrap()
+            .flatten()
+            .filter(|entry| {
+                entry
+                    .file_name()
+                    .to_string_lossy()
+                    .contains(".para-backup-")
+            })
+            .count();
+        assert_eq!(backups, 1);
+
+        // A moved server replaces the old entry, with a second backup
+        let InstallOutcome::Updated { backup: second } =
+            install_server(&path, &server("/opt/para/bin/para-mcp-server")).unwrap()
+        else {
+            panic!("expected the config to be updated");
+        };
+        assert_ne!(second, backup);
+        let written: Value = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(
+            written["mcpServers"]["para"]["command"],
+            "/opt/para/bin/para-mcp-server"
+        );
+    }
+
+    #[test]
+    fn test_install_refuses_invalid_config() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("mcp.json");
+        fs::write(&path, "{ not json").unwrap();
+
+        assert!(install_server(&path, &server("para-mcp-server")).is_err());
+        assert_eq!(fs::read_to_string(&path).unwrap(), "{ not json");
+
+        fs::write(&path, r#"{"mcpServers": []}"#).unwrap();
+        assert!(install_server(&path, &server("para-mcp-server")).is_err());
+    }
+}
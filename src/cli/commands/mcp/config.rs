@@ -1,3 +1,4 @@
+use crate::ui::theme;
 use crate::utils::{ParaError, Result};
 use std::fs;
 use std::process::Command;
@@ -46,17 +47,23 @@ pub fn find_mcp_server() -> Result<McpServerConfig> {
         format!(
             "No para MCP server found. Claude Code won't be able to connect to Para tools.\n\
             Tried strategies: {strategies_tried}\n\n\
-            📋 Install options (choose one):\n\n\
-            🔧 For development in this repo:\n  \
+            {}\n\n\
+            {}\n  \
             cd mcp-server-ts && npm install && npm run build\n\n\
-            🏠 For production use:\n  \
+            {}\n  \
             brew install 2mawi2/tap/para  # (includes MCP server)\n\n\
-            🛠️  Manual installation:\n  \
+            {}\n  \
             just install  # (builds and installs to ~/.local/bin)\n\n\
-            ⚡ Quick check:\n  \
+            {}\n  \
             Run 'which para-mcp-server' to see if it's in your PATH\n  \
             Check 'node mcp-server-ts/build/para-mcp-server.js --help' for TypeScript server\n\n\
-            💡 After installing, run 'para mcp init --claude-code' again to update the configuration."
+            {}",
+            theme::hint("Install options (choose one):"),
+            theme::action("For development in this repo:"),
+            theme::action("For production use:"),
+            theme::action("Manual installation:"),
+            theme::action("Quick check:"),
+            theme::hint("After installing, run 'para mcp init --claude-code' again to update the configuration.")
         )
     ))
 }
@@ -123,11 +130,14 @@ pub fn check_claude_code_availability() {
         Ok(output) => {
             if output.status.success() {
                 let version_output = String::from_utf8_lossy(&output.stdout);
-                println!("✅ Claude Code detected: {}", version_output.trim());
+                println!(
+                    "{}",
+                    theme::success(format!("Claude Code detected: {}", version_output.trim()))
+                );
             }
         }
         Err(_) => {
-            println!("ℹ️  Claude Code not found in PATH");
+            println!("{}", theme::hint("Claude Code not found in PATH"));
             println!(
                 "   If you plan to use Claude Code, install it from: https://claude.ai/download"
             );
@@ -1,11 +1,14 @@
+use crate::ui::theme;
 use crate::utils::Result;
 use clap::{Args, Subcommand};
 
+pub mod clients;
 pub mod config;
 pub mod strategies;
 pub mod utils;
 
-use config::{check_claude_code_availability, create_mcp_json};
+use clients::{configure_client, ClientDirs, McpClient};
+use config::{check_claude_code_availability, create_mcp_json, find_mcp_server};
 use utils::add_to_gitignore;
 
 #[derive(Args)]
@@ -21,7 +24,28 @@ pub enum McpSubcommand {
 }
 
 #[derive(Args)]
-pub struct McpInitArgs {}
+pub struct McpInitArgs {
+    /// Register the server in Claude Desktop's global config instead of .mcp.json
+    #[arg(long)]
+    pub claude_desktop: bool,
+
+    /// Register the server in Cursor's global mcp.json instead of .mcp.json
+    #[arg(long)]
+    pub cursor: bool,
+}
+
+impl McpInitArgs {
+    fn clients(&self) -> Vec<McpClient> {
+        let mut clients = Vec::new();
+        if self.claude_desktop {
+            clients.push(McpClient::ClaudeDesktop);
+        }
+        if self.cursor {
+            clients.push(McpClient::Cursor);
+        }
+        clients
+    }
+}
 
 pub fn handle_mcp_command(cmd: McpCommand) -> Result<()> {
     match cmd.command {
@@ -29,22 +53,39 @@ pub fn handle_mcp_command(cmd: McpCommand) -> Result<()> {
     }
 }
 
-fn handle_mcp_init(_args: McpInitArgs) -> Result<()> {
-    println!("🔧 Setting up Para MCP integration...");
+fn handle_mcp_init(args: McpInitArgs) -> Result<()> {
+    let clients = args.clients();
+    if !clients.is_empty() {
+        return init_clients(&clients, &ClientDirs::detect()?);
+    }
+
+    println!("{}", theme::action("Setting up Para MCP integration..."));
 
     // Always create .mcp.json first
     let created = create_mcp_json()?;
     if created {
-        println!("✓ Created .mcp.json with Para MCP server configuration");
+        println!(
+            "{}",
+            theme::success("Created .mcp.json with Para MCP server configuration")
+        );
     } else {
-        println!("✓ .mcp.json already exists with Para configuration");
+        println!(
+            "{}",
+            theme::success(".mcp.json already exists with Para configuration")
+        );
     }
 
     // Automatically add .mcp.json to .gitignore if it's not already there
     match add_to_gitignore(".mcp.json") {
-        Ok(true) => println!("✓ Added .mcp.json to .gitignore (contains user-specific paths)"),
-        Ok(false) => println!("✓ .mcp.json already in .gitignore"),
-        Err(e) => println!("⚠️  Could not update .gitignore: {e}"),
+        Ok(true) => println!(
+            "{}",
+            theme::success("Added .mcp.json to .gitignore (contains user-specific paths)")
+        ),
+        Ok(false) => println!("{}", theme::success(".mcp.json already in .gitignore")),
+        Err(e) => println!(
+            "{}",
+            theme::warning(format!("Could not update .gitignore: {e}"))
+        ),
     }
     println!();
 
@@ -56,13 +97,28 @@ fn handle_mcp_init(_args: McpInitArgs) -> Result<()> {
     println!("   The .mcp.json file has been created with Para server settings.");
     println!("   This enables Para tools in IDEs that support MCP.");
     println!();
-    println!("💡 To use Para tools:");
+    println!("{}", theme::hint("To use Para tools:"));
     println!("   - In Claude Code: Tools will appear automatically after restart");
     println!("   - In other IDEs: Check their MCP documentation");
 
     Ok(())
 }
 
+fn init_clients(clients: &[McpClient], dirs: &ClientDirs) -> Result<()> {
+    let server = find_mcp_server()?;
+    for client in clients {
+        println!(
+            "{}",
+            theme::action(format!(
+                "Configuring the para MCP server for {}...",
+                client.display_name()
+            ))
+        );
+        configure_client(*client, dirs, &server)?;
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -141,7 +197,14 @@ mod tests {
         }
 
         // Should parse with no flags
-        assert!(TestArgs::try_parse_from(["test"]).is_ok());
+        let args = TestArgs::try_parse_from(["test"]).unwrap();
+        assert!(args.mcp.clients().is_empty());
+
+        let args = TestArgs::try_parse_from(["test", "--claude-desktop", "--cursor"]).unwrap();
+        assert_eq!(
+            args.mcp.clients(),
+            vec![McpClient::ClaudeDesktop, McpClient::Cursor]
+        );
     }
 
     #[test]
@@ -1,24 +1,46 @@
+pub mod adopt;
+pub mod audit;
 pub mod auth;
 pub mod cancel;
+pub mod checkpoint;
 pub mod clean;
 pub mod common;
 pub mod completion;
 pub mod completion_branches;
 pub mod completion_sessions;
 pub mod config;
+pub mod conflicts;
 pub mod daemon;
+pub mod deprecated;
 pub mod dispatch;
+pub mod doctor;
+pub mod examples;
 pub mod finish;
+pub mod gc;
 pub mod init;
+pub mod launch;
+pub mod link;
 pub mod list;
+pub mod logs;
 pub mod mcp;
 pub mod monitor;
+pub mod note;
+pub mod pin;
 pub mod proxy;
 pub mod recover;
+pub mod restore_trash;
 pub mod resume;
+pub mod rollback_integration;
+pub mod self_update;
+pub mod selftest;
 pub mod start;
 pub mod status;
+pub mod statusline;
+pub mod summary;
+pub mod sync;
 pub mod unified_start;
+pub mod upgrade_state;
+pub mod which;
 
 #[cfg(test)]
 mod dangerous_flag_integration_test;
@@ -1,6 +1,8 @@
+use crate::cli::context::CommandContext;
 use crate::cli::parser::MonitorArgs;
+use crate::core::repo_registry::{load_repos, registry_path};
 use crate::ui::monitor::MonitorCoordinator;
-use crate::utils::Result;
+use crate::utils::{get_main_repository_root, ParaError, Result};
 use anyhow::Result as AnyhowResult;
 use crossterm::{
     event::{self, DisableMouseCapture, EnableMouseCapture, Event},
@@ -12,16 +14,15 @@ use ratatui::{
     Terminal,
 };
 use std::io;
+use std::path::PathBuf;
 
 pub struct App {
     coordinator: MonitorCoordinator,
 }
 
 impl App {
-    pub fn new(config: crate::config::Config) -> Self {
-        Self {
-            coordinator: MonitorCoordinator::new(config),
-        }
+    pub fn new(coordinator: MonitorCoordinator) -> Self {
+        Self { coordinator }
     }
 
     pub fn run(&mut self) -> AnyhowResult<()> {
@@ -55,6 +56,8 @@ impl App {
                 self.coordinator.refresh_sessions();
                 self.coordinator.mark_refreshed();
             }
+            let operations_changed = self.coordinator.poll_operations();
+            let sessions_changed = self.coordinator.poll_events();
 
             // Poll for events with timeout for refresh
             if event::poll(std::time::Duration::from_millis(100))? {
@@ -81,8 +84,8 @@ impl App {
                     }
                     _ => {}
                 }
-            } else if should_refresh {
-                // Only redraw if we refreshed sessions
+            } else if should_refresh || operations_changed || sessions_changed {
+                // Only redraw if sessions changed or a job progressed
                 terminal.draw(|f| self.coordinator.render(f))?;
             }
         }
@@ -90,8 +93,32 @@ impl App {
     }
 }
 
-pub fn execute(config: crate::config::Config, _args: MonitorArgs) -> Result<()> {
-    let mut app = App::new(config);
+pub fn execute(ctx: &CommandContext, args: MonitorArgs) -> Result<()> {
+    let config = ctx.config().clone();
+    let coordinator = if args.all_repos {
+        MonitorCoordinator::for_repos(config, &all_repo_roots()?)
+    } else {
+        MonitorCoordinator::new(config)
+    };
+    let mut app = App::new(coordinator);
     app.run()
         .map_err(|e| crate::utils::ParaError::ide_error(format!("Monitor UI error: {e}")))
 }
+
+/// Registered repositories, plus the current one if it has not been recorded yet
+fn all_repo_roots() -> Result<Vec<PathBuf>> {
+    let mut roots = load_repos(&registry_path());
+    if let Ok(current) = get_main_repository_root() {
+        if !roots.contains(&current) {
+            roots.push(current);
+        }
+    }
+
+    if roots.is_empty() {
+        return Err(ParaError::invalid_args(format!(
+            "No repositories recorded in {} yet. Run para inside a repository first.",
+            registry_path().display()
+        )));
+    }
+    Ok(roots)
+}
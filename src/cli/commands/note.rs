@@ -0,0 +1,45 @@
+use crate::cli::context::CommandContext;
+use crate::cli::parser::NoteArgs;
+use crate::core::session::notes::{append_note, notes_file, read_notes};
+use crate::utils::{ParaError, Result};
+use chrono::Utc;
+use std::process::Command;
+
+pub fn execute(ctx: &CommandContext, args: NoteArgs) -> Result<()> {
+    let session_manager = ctx.session_manager();
+    let session = session_manager.resolve_session(&args.session)?.name;
+    let state_dir = session_manager.state_dir();
+
+    if args.show {
+        match read_notes(state_dir, &session) {
+            Some(notes) if !notes.trim().is_empty() => print!("{notes}"),
+            _ => println!("No notes for session '{session}'"),
+        }
+        return Ok(());
+    }
+
+    if args.text.is_empty() {
+        return edit_notes(&notes_file(state_dir, &session));
+    }
+
+    append_note(state_dir, &session, &args.text.join(" "), Utc::now())?;
+    println!("📝 Added note to session '{session}'");
+    Ok(())
+}
+
+fn edit_notes(path: &std::path::Path) -> Result<()> {
+    let editor = std::env::var("EDITOR").unwrap_or_else(|_| "vi".to_string());
+
+    let status = Command::new(&editor)
+        .arg(path)
+        .status()
+        .map_err(|e| ParaError::invalid_args(format!("Failed to launch editor '{editor}': {e}")))?;
+
+    if !status.success() {
+        return Err(ParaError::invalid_args(format!(
+            "Editor exited with non-zero status: {}",
+            status.code().unwrap_or(-1)
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,20 @@
+use crate::cli::context::CommandContext;
+use crate::cli::parser::PinArgs;
+use crate::core::session::pin::set_pinned;
+use crate::ui::theme;
+use crate::utils::Result;
+
+/// `para pin` with `pinned`, `para unpin` without
+pub fn execute(ctx: &CommandContext, args: PinArgs, pinned: bool) -> Result<()> {
+    let session_manager = ctx.session_manager();
+    let session = session_manager.resolve_session(&args.session)?.name;
+    let changed = set_pinned(&session_manager, &session, pinned)?;
+
+    match (pinned, changed) {
+        (true, true) => println!("{}", theme::pinned(format!("Pinned session '{session}'"))),
+        (true, false) => println!("Session '{session}' is already pinned"),
+        (false, true) => println!("Unpinned session '{session}'"),
+        (false, false) => println!("Session '{session}' is not pinned"),
+    }
+    Ok(())
+}
@@ -1,32 +1,149 @@
+use crate::cli::commands::common::is_non_interactive;
+use crate::cli::context::CommandContext;
+use crate::cli::json_output::{JsonOutput, SCHEMA_VERSION};
 use crate::cli::parser::RecoverArgs;
 use crate::config::Config;
+use crate::core::docker::DockerManager;
 use crate::core::git::GitService;
-use crate::core::session::archive::ArchiveManager;
-use crate::core::session::recovery::{RecoveryOptions, SessionRecovery};
+use crate::core::session::recovery::{
+    parse_since, BulkRecoveryEntry, BulkRecoveryOutcome, RecoveryOptions, RecoveryResult,
+    RestorePlan, SessionRecovery, PICKER_LIMIT,
+};
 use crate::core::session::SessionManager;
+use crate::utils::names::resolve_session_name;
+use crate::utils::output::path_link;
+use crate::utils::progress::StepReporter;
 use crate::utils::{ParaError, Result};
 use dialoguer::{Confirm, Select};
-use std::env;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Result of `para recover <session> --json`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecoverOutput {
+    pub schema_version: u32,
+    /// False when the recovery was declined at a prompt
+    pub recovered: bool,
+    pub session: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub branch: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub worktree_path: Option<PathBuf>,
+    /// How the archived branch was restored
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub restore: Option<RestorePlan>,
+    /// Archive a diverged live branch was moved to with `--replace`
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub replaced_archive: Option<String>,
+    /// Nothing was changed; `branch` and `worktree_path` are what recovering
+    /// would restore
+    #[serde(skip_serializing_if = "std::ops::Not::not", default)]
+    pub dry_run: bool,
+}
+
+impl RecoverOutput {
+    fn new(session: &str, result: Option<RecoveryResult>) -> Self {
+        match result {
+            Some(result) => Self {
+                schema_version: SCHEMA_VERSION,
+                recovered: true,
+                session: result.session_name,
+                branch: Some(result.branch_name),
+                worktree_path: Some(result.worktree_path),
+                restore: Some(result.restore),
+                replaced_archive: result.replaced_archive,
+                dry_run: false,
+            },
+            None => Self {
+                schema_version: SCHEMA_VERSION,
+                recovered: false,
+                session: session.to_string(),
+                branch: None,
+                worktree_path: None,
+                restore: None,
+                replaced_archive: None,
+                dry_run: false,
+            },
+        }
+    }
 
-/// Check if we're running in non-interactive mode (e.g., from MCP server)
-fn is_non_interactive() -> bool {
-    env::var("PARA_NON_INTERACTIVE").is_ok()
-        || env::var("CI").is_ok()
-        || !atty::is(atty::Stream::Stdin)
+    fn dry_run(result: RecoveryResult) -> Self {
+        Self {
+            recovered: false,
+            dry_run: true,
+            ..Self::new(&result.session_name.clone(), Some(result))
+        }
+    }
 }
 
-pub fn execute(config: Config, args: RecoverArgs) -> Result<()> {
+pub fn execute(ctx: &CommandContext, args: RecoverArgs) -> Result<()> {
     validate_recover_args(&args)?;
 
-    let git_service = GitService::discover()?;
-    let session_manager = SessionManager::new(&config);
+    let config = ctx.config();
+    let git_service = ctx.git_service()?;
+    let session_manager = ctx.session_manager();
+
+    if args.all {
+        return recover_all_sessions(config, git_service, &session_manager, &args);
+    }
 
     match args.session {
+        Some(session_name) if args.dry_run => {
+            let json = JsonOutput::start(args.json);
+            let result = recover_session_dry_run(
+                config,
+                git_service,
+                &session_manager,
+                &session_name,
+                args.replace,
+            )?;
+            json.finish(&RecoverOutput::dry_run(result))
+        }
         Some(session_name) => {
-            recover_specific_session(&config, &git_service, &session_manager, &session_name)
+            let json = JsonOutput::start(args.json);
+            let result = recover_specific_session(
+                config,
+                git_service,
+                &session_manager,
+                &session_name,
+                args.yes,
+                args.replace,
+            )?;
+            json.finish(&RecoverOutput::new(&session_name, result))
         }
-        None => list_recoverable_sessions(&config, &git_service, &session_manager),
+        None => list_recoverable_sessions(
+            config,
+            git_service,
+            &session_manager,
+            args.yes,
+            args.replace,
+        ),
+    }
+}
+
+/// Resolve `query` against active sessions and archived ones
+fn resolve_recover_name(
+    session_recovery: &SessionRecovery,
+    session_manager: &SessionManager,
+    query: &str,
+) -> Result<String> {
+    if session_recovery.is_active_session(query) {
+        return Ok(query.to_string());
     }
+    let mut names: Vec<String> = session_manager
+        .list_sessions()?
+        .into_iter()
+        .map(|session| session.name)
+        .chain(
+            session_recovery
+                .list_recoverable_sessions()?
+                .into_iter()
+                .map(|info| info.original_session_name),
+        )
+        .collect();
+    names.sort();
+    names.dedup();
+    resolve_session_name(query, &names)
 }
 
 fn recover_specific_session(
@@ -34,27 +151,91 @@ fn recover_specific_session(
     git_service: &GitService,
     session_manager: &SessionManager,
     session_name: &str,
-) -> Result<()> {
+    yes: bool,
+    replace: bool,
+) -> Result<Option<RecoveryResult>> {
     let session_recovery = SessionRecovery::new(config, git_service, session_manager);
-
-    let recovery_options = match determine_recovery_options(&session_recovery, session_name)? {
-        Some(options) => options,
-        None => return Ok(()), // User cancelled recovery
+    let session_name = &resolve_recover_name(&session_recovery, session_manager, session_name)?;
+
+    let recovery_options = match determine_recovery_options(&session_recovery, session_name, yes)? {
+        Some(options) => RecoveryOptions {
+            replace_live_branch: replace,
+            ..options
+        },
+        None => return Ok(None), // User cancelled recovery
     };
 
+    let mut progress = StepReporter::new(1);
+    progress.step(format!("Recovering session '{session_name}'"));
     let result = session_recovery.recover_session_unified(session_name, recovery_options)?;
+    progress.finish();
+
     display_recovery_result(&result);
-    Ok(())
+    restart_session_container(config, session_manager, &result.session_name);
+    Ok(Some(result))
+}
+
+/// `para recover <session> --dry-run`: check that the archive can be restored
+/// and report the branch and worktree it would get
+fn recover_session_dry_run(
+    config: &Config,
+    git_service: &GitService,
+    session_manager: &SessionManager,
+    session_name: &str,
+    replace: bool,
+) -> Result<RecoveryResult> {
+    let session_recovery = SessionRecovery::new(config, git_service, session_manager);
+    let session_name = &resolve_recover_name(&session_recovery, session_manager, session_name)?;
+    if session_recovery.is_active_session(session_name) {
+        return Err(ParaError::invalid_args(format!(
+            "Session '{session_name}' is active; --dry-run only checks archived sessions"
+        )));
+    }
+
+    let options = RecoveryOptions {
+        replace_live_branch: replace,
+        ..RecoveryOptions::default()
+    };
+    let result = session_recovery.recover_session_dry_run(session_name, options)?;
+    print_restore_plan(&result, session_name);
+    println!(
+        "Would recover session '{}' on branch '{}' in {}",
+        result.session_name,
+        result.branch_name,
+        path_link(&result.worktree_path)
+    );
+    Ok(result)
+}
+
+/// Bring a recovered container session's container back up with the docker
+/// options it was dispatched with. Failures only warn: the worktree is back.
+fn restart_session_container(
+    config: &Config,
+    session_manager: &SessionManager,
+    session_name: &str,
+) {
+    let Ok(session_state) = session_manager.load_state(session_name) else {
+        return;
+    };
+    if !session_state.is_container() {
+        return;
+    }
+    let docker_manager = DockerManager::for_session(config.clone(), &session_state);
+    if let Err(e) = docker_manager.start_session_container(&session_state) {
+        eprintln!("⚠️  Could not start the container of '{session_name}': {e}");
+    }
 }
 
 fn determine_recovery_options(
     session_recovery: &SessionRecovery,
     session_name: &str,
+    yes: bool,
 ) -> Result<Option<RecoveryOptions>> {
     if is_non_interactive() {
         return Ok(Some(RecoveryOptions {
             force_overwrite: true,
             preserve_original_name: true,
+            replace_live_branch: false,
         }));
     }
 
@@ -62,21 +243,23 @@ fn determine_recovery_options(
         return Ok(Some(RecoveryOptions {
             force_overwrite: false,
             preserve_original_name: true,
+            replace_live_branch: false,
         }));
     }
 
-    handle_inactive_session_recovery(session_recovery, session_name)
+    handle_inactive_session_recovery(session_recovery, session_name, yes)
 }
 
 fn handle_inactive_session_recovery(
     session_recovery: &SessionRecovery,
     session_name: &str,
+    yes: bool,
 ) -> Result<Option<RecoveryOptions>> {
     match session_recovery.validate_recovery(session_name) {
         Ok(validation) if !validation.can_recover => {
             handle_conflicted_recovery(validation, session_name)
         }
-        Ok(validation) => handle_recoverable_session(validation, session_name),
+        Ok(validation) => handle_recoverable_session(validation, session_name, yes),
         Err(_) => Err(ParaError::session_not_found(format!(
             "No session found for '{session_name}'"
         ))),
@@ -110,12 +293,14 @@ fn handle_conflicted_recovery(
     Ok(Some(RecoveryOptions {
         force_overwrite: true,
         preserve_original_name: true,
+        replace_live_branch: false,
     }))
 }
 
 fn handle_recoverable_session(
     validation: crate::core::session::recovery::RecoveryValidation,
     session_name: &str,
+    yes: bool,
 ) -> Result<Option<RecoveryOptions>> {
     if !validation.warnings.is_empty() {
         println!("⚠ Warnings for session '{session_name}':");
@@ -124,11 +309,12 @@ fn handle_recoverable_session(
         }
     }
 
-    if !Confirm::new()
-        .with_prompt(format!("Recover session '{session_name}'?"))
-        .default(true)
-        .interact()
-        .unwrap_or(false)
+    if !yes
+        && !Confirm::new()
+            .with_prompt(format!("Recover session '{session_name}'?"))
+            .default(true)
+            .interact()
+            .unwrap_or(false)
     {
         return Ok(None); // User cancelled
     }
@@ -136,16 +322,30 @@ fn handle_recoverable_session(
     Ok(Some(RecoveryOptions {
         force_overwrite: false,
         preserve_original_name: true,
+        replace_live_branch: false,
     }))
 }
 
-fn display_recovery_result(result: &crate::core::session::recovery::RecoveryResult) {
+/// Say what happened about a live branch named like the session
+fn print_restore_plan(result: &RecoveryResult, session_name: &str) {
+    if let Some(note) = result.restore.describe(session_name) {
+        println!("ℹ️  {note}");
+    }
+    if let Some(archive) = &result.replaced_archive {
+        println!("  Previous '{session_name}' archived as {archive}");
+    }
+}
+
+fn display_recovery_result(result: &RecoveryResult) {
+    if result.restore != RestorePlan::Original {
+        print_restore_plan(result, &result.session_name);
+    }
     println!(
         "✅ Session '{}' recovered successfully",
         result.session_name
     );
     println!("  Branch: {}", result.branch_name);
-    println!("  Worktree: {}", result.worktree_path.display());
+    println!("  Worktree: {}", path_link(&result.worktree_path));
     println!(
         "  💡 To open in your IDE, run: para resume {}",
         result.session_name
@@ -156,90 +356,192 @@ fn list_recoverable_sessions(
     config: &crate::config::Config,
     git_service: &GitService,
     session_manager: &SessionManager,
+    yes: bool,
+    replace: bool,
 ) -> Result<()> {
-    let archive_manager = ArchiveManager::new(config, git_service);
     let session_recovery = SessionRecovery::new(config, git_service, session_manager);
+    let options = RecoveryOptions {
+        replace_live_branch: replace,
+        ..RecoveryOptions::default()
+    };
+    let candidates = session_recovery.archive_candidates(PICKER_LIMIT)?;
 
-    let archives = archive_manager.list_archives()?;
-
-    if archives.is_empty() {
+    if candidates.is_empty() {
         println!("No recoverable sessions found.");
         return Ok(());
     }
 
-    println!("Recoverable sessions:");
-    for (i, archive) in archives.iter().enumerate() {
-        println!(
-            "  {}: {} (archived: {})",
-            i + 1,
-            archive.session_name,
-            archive.archived_at
-        );
-    }
+    let now = chrono::Utc::now();
+    let labels: Vec<String> = candidates.iter().map(|c| c.describe(now)).collect();
 
     if is_non_interactive() {
+        println!("Recoverable sessions:");
+        for label in &labels {
+            println!("  {label}");
+        }
         return Err(ParaError::invalid_args(
             "Cannot interactively select session to recover in non-interactive mode. \
              Please specify a session name: para recover <session-name>",
         ));
     }
 
-    if !Confirm::new()
-        .with_prompt("Recover a session?")
-        .default(false)
-        .interact()
-        .unwrap_or(false)
+    let Some(index) = Select::new()
+        .with_prompt(format!(
+            "Select session to recover (newest {} archives)",
+            candidates.len()
+        ))
+        .items(&labels)
+        .default(0)
+        .interact_opt()
+        .unwrap_or(None)
+    else {
+        return Ok(());
+    };
+    let candidate = &candidates[index];
+    let session_name = &candidate.info.original_session_name;
+
+    println!();
+    println!("Archive {} contains:", candidate.info.archived_branch);
+    match session_recovery.archive_diff_stat(candidate) {
+        Ok(stat) if stat.trim().is_empty() => {
+            println!("  (no changes compared to the default branch)")
+        }
+        Ok(stat) => {
+            for line in stat.lines() {
+                println!("  {line}");
+            }
+        }
+        Err(e) => println!("  (could not summarize changes: {e})"),
+    }
+    println!();
+
+    if !yes
+        && !Confirm::new()
+            .with_prompt(format!("Restore session '{session_name}'?"))
+            .default(true)
+            .interact()
+            .unwrap_or(false)
     {
+        println!("Recovery cancelled");
         return Ok(());
     }
 
-    let session_names: Vec<&str> = archives.iter().map(|a| a.session_name.as_str()).collect();
-    let selection = Select::new()
-        .with_prompt("Select session to recover")
-        .items(&session_names)
-        .interact();
-
-    if let Ok(index) = selection {
-        let selected_session = &archives[index].session_name;
-
-        let recovery_options = RecoveryOptions {
-            force_overwrite: false,
-            preserve_original_name: true,
-        };
-
-        match session_recovery.recover_session(selected_session, recovery_options) {
-            Ok(result) => {
+    match session_recovery.recover_archive(&candidate.info, options.clone()) {
+        Ok(result) => {
+            display_recovery_result(&result);
+        }
+        Err(e) => {
+            eprintln!("❌ Failed to recover session: {e}");
+
+            if Confirm::new()
+                .with_prompt("Try force recovery?")
+                .default(false)
+                .interact()
+                .unwrap_or(false)
+            {
+                let force_options = RecoveryOptions {
+                    force_overwrite: true,
+                    ..options
+                };
+
+                let result = session_recovery.recover_archive(&candidate.info, force_options)?;
+                println!(
+                    "✅ Session '{}' force recovered successfully",
+                    result.session_name
+                );
                 display_recovery_result(&result);
             }
-            Err(e) => {
-                eprintln!("❌ Failed to recover session: {e}");
-
-                if Confirm::new()
-                    .with_prompt("Try force recovery?")
-                    .default(false)
-                    .interact()
-                    .unwrap_or(false)
-                {
-                    let force_options = RecoveryOptions {
-                        force_overwrite: true,
-                        preserve_original_name: true,
-                    };
-
-                    let result =
-                        session_recovery.recover_session(selected_session, force_options)?;
-                    println!(
-                        "✅ Session '{}' force recovered successfully",
-                        result.session_name
-                    );
-                    display_recovery_result(&result);
-                }
-            }
         }
     }
 
     Ok(())
 }
 
+fn recover_all_sessions(
+    config: &Config,
+    git_service: &GitService,
+    session_manager: &SessionManager,
+    args: &RecoverArgs,
+) -> Result<()> {
+    let since = args
+        .since
+        .as_deref()
+        .map(|value| parse_since(value, chrono::Utc::now()))
+        .transpose()?;
+
+    let session_recovery = SessionRecovery::new(config, git_service, session_manager);
+    let entries = if args.dry_run {
+        session_recovery.recover_all(since, true)?
+    } else {
+        let mut progress = StepReporter::new(1);
+        progress.step("Recovering archived sessions");
+        let entries = session_recovery.recover_all(since, false)?;
+        progress.finish();
+        entries
+    };
+
+    if entries.is_empty() {
+        println!("No recoverable sessions found.");
+        return Ok(());
+    }
+
+    display_bulk_recovery(&entries, args.dry_run);
+
+    let failed = entries
+        .iter()
+        .filter(|e| matches!(e.outcome, BulkRecoveryOutcome::Failed(_)))
+        .count();
+    if failed > 0 {
+        return Err(ParaError::invalid_args(format!(
+            "{failed} session(s) could not be recovered"
+        )));
+    }
+
+    Ok(())
+}
+
+fn display_bulk_recovery(entries: &[BulkRecoveryEntry], dry_run: bool) {
+    println!(
+        "{:<30} {:<20} {:<15} Details",
+        "Session", "Archived", "Result"
+    );
+    println!("{}", "-".repeat(80));
+
+    for entry in entries {
+        let archived = entry
+            .archived_at
+            .map(|at| at.format("%Y-%m-%d %H:%M").to_string())
+            .unwrap_or_else(|| "unknown".to_string());
+        let (result, details) = match &entry.outcome {
+            BulkRecoveryOutcome::Restored(result) => {
+                ("restored", result.worktree_path.display().to_string())
+            }
+            BulkRecoveryOutcome::WouldRestore => ("would restore", entry.archived_branch.clone()),
+            BulkRecoveryOutcome::Skipped(reason) => ("skipped", reason.clone()),
+            BulkRecoveryOutcome::Failed(error) => ("failed", error.clone()),
+        };
+        println!(
+            "{:<30} {:<20} {:<15} {}",
+            entry.session_name, archived, result, details
+        );
+    }
+
+    let count = |pred: fn(&BulkRecoveryOutcome) -> bool| {
+        entries.iter().filter(|e| pred(&e.outcome)).count()
+    };
+    let skipped = count(|o| matches!(o, BulkRecoveryOutcome::Skipped(_)));
+    let failed = count(|o| matches!(o, BulkRecoveryOutcome::Failed(_)));
+
+    println!();
+    if dry_run {
+        let pending = count(|o| matches!(o, BulkRecoveryOutcome::WouldRestore));
+        println!("Dry run: {pending} would be restored, {skipped} skipped");
+    } else {
+        let restored = count(|o| matches!(o, BulkRecoveryOutcome::Restored(_)));
+        println!("{restored} restored, {skipped} skipped, {failed} failed");
+    }
+}
+
 fn validate_recover_args(args: &RecoverArgs) -> Result<()> {
     if let Some(ref session) = args.session {
         if session.is_empty() {
@@ -255,6 +557,7 @@ fn validate_recover_args(args: &RecoverArgs) -> Result<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::git::GitOperations;
     use crate::core::session::{SessionManager, SessionState};
     use crate::test_utils::test_helpers::*;
     use tempfile::TempDir;
@@ -373,4 +676,74 @@ mod tests {
         assert!(error_msg.contains("missing branch"));
         assert!(error_msg.contains("cannot recover"));
     }
+
+    #[test]
+    fn test_recover_json_output_round_trips() {
+        use crate::cli::commands::cancel::cancel_and_archive_session;
+        use crate::cli::json_output::render;
+
+        let temp_dir = TempDir::new().unwrap();
+        let (_git_temp, git_service) = setup_test_repo();
+        let config = create_test_config_with_dir(&temp_dir);
+        let mut session_manager = SessionManager::new(&config);
+
+        let worktree_path = git_service
+            .repository()
+            .root
+            .join(&config.directories.subtrees_dir)
+            .join("revived");
+        git_service
+            .create_worktree("test/revived", &worktree_path)
+            .unwrap();
+        session_manager
+            .save_state(&SessionState::new(
+                "revived".to_string(),
+                "test/revived".to_string(),
+                worktree_path.clone(),
+            ))
+            .unwrap();
+        cancel_and_archive_session(
+            &config,
+            &git_service,
+            &mut session_manager,
+            "revived",
+            true,
+            false,
+            false,
+        )
+        .unwrap();
+
+        let result = recover_specific_session(
+            &config,
+            &git_service,
+            &session_manager,
+            "revived",
+            true,
+            false,
+        )
+        .unwrap();
+        let output = RecoverOutput::new("revived", result);
+
+        assert!(output.recovered);
+        assert_eq!(output.session, "revived");
+        assert!(git_service
+            .branch_exists(output.branch.as_deref().unwrap())
+            .unwrap());
+        assert!(output.worktree_path.as_ref().unwrap().exists());
+
+        let line = render(&output).unwrap();
+        let parsed: RecoverOutput = serde_json::from_str(&line).unwrap();
+        assert_eq!(parsed, output);
+
+        let declined = RecoverOutput::new("revived", None);
+        let line = render(&declined).unwrap();
+        assert_eq!(
+            line,
+            r#"{"schema_version":1,"recovered":false,"session":"revived"}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<RecoverOutput>(&line).unwrap(),
+            declined
+        );
+    }
 }
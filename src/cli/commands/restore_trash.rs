@@ -0,0 +1,17 @@
+use crate::cli::context::CommandContext;
+use crate::cli::parser::RestoreTrashArgs;
+use crate::core::trash;
+use crate::utils::Result;
+
+pub fn execute(ctx: &CommandContext, args: RestoreTrashArgs) -> Result<()> {
+    let git_service = ctx.git_service()?;
+    let session_manager = ctx.session_manager();
+    let state_dir = session_manager.state_dir();
+
+    let entries = trash::list(state_dir, git_service, ctx.config().get_branch_prefix())?;
+    for entry in trash::select(&entries, &args.item)? {
+        let restored = trash::restore(entry, state_dir, git_service)?;
+        println!("♻️  Restored {} to {restored}", entry.id());
+    }
+    Ok(())
+}
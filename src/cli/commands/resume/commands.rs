@@ -0,0 +1,233 @@
+//! Commands run in a session before `para resume` opens it
+//!
+//! The commands of `session.on_resume` run first, then each `--run` in the
+//! order given. A command runs through `sh -c` in the session worktree, or
+//! with `docker exec` in `/workspace` of a container session, with
+//! `PARA_SESSION` and `PARA_WORKSPACE` set like for setup scripts. Their
+//! combined output replaces the session's setup log, so `para logs` shows
+//! what the last resume ran.
+
+use crate::cli::parser::ResumeArgs;
+use crate::config::Config;
+use crate::core::logs;
+use crate::ui::theme;
+use crate::utils::{ParaError, Result};
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::Path;
+use std::process::Command;
+
+/// Where the commands of a resume run
+pub enum RunTarget<'a> {
+    Worktree(&'a Path),
+    Container,
+}
+
+/// Commands to run for `args`: the configured ones, then `--run`
+pub fn resume_commands<'a>(config: &'a Config, args: &'a ResumeArgs) -> Vec<&'a str> {
+    config
+        .session
+        .on_resume
+        .iter()
+        .chain(&args.run)
+        .map(String::as_str)
+        .collect()
+}
+
+/// Run the resume commands of `session` in order, stopping at the first that
+/// fails unless `--run-ignore-errors` was given
+pub fn run_resume_commands(
+    config: &Config,
+    args: &ResumeArgs,
+    session: &str,
+    target: RunTarget<'_>,
+) -> Result<()> {
+    let commands = resume_commands(config, args);
+    if commands.is_empty() {
+        return Ok(());
+    }
+    run_commands(
+        &commands,
+        &config.resolved_state_dir(),
+        session,
+        &target,
+        args.run_ignore_errors,
+    )
+}
+
+fn run_commands(
+    commands: &[&str],
+    state_dir: &Path,
+    session: &str,
+    target: &RunTarget<'_>,
+    ignore_errors: bool,
+) -> Result<()> {
+    let log_path = logs::setup_log_path(state_dir, session);
+    fs::create_dir_all(state_dir)
+        .map_err(|e| ParaError::from_io("create state directory", state_dir, e))?;
+    let mut log =
+        File::create(&log_path).map_err(|e| ParaError::from_io("create log file", &log_path, e))?;
+
+    for command in commands {
+        println!("{}", theme::action(format!("Running: {command}")));
+        let _ = writeln!(log, "$ {command}");
+
+        let failure = match logs::run_logged_to(&mut shell_command(command, session, target), &log)
+        {
+            Ok(status) if status.success() => None,
+            Ok(status) => Some(format!(
+                "exit code {}, output in {}",
+                status.code().unwrap_or(-1),
+                log_path.display()
+            )),
+            Err(e) => Some(format!("could not run it: {e}")),
+        };
+
+        if let Some(message) = failure {
+            if !ignore_errors {
+                return Err(ParaError::resume_command(*command, message));
+            }
+            eprintln!(
+                "{}",
+                theme::warning(format!(
+                    "Command `{command}` failed ({message}), continuing"
+                ))
+            );
+        }
+    }
+    Ok(())
+}
+
+fn shell_command(command: &str, session: &str, target: &RunTarget<'_>) -> Command {
+    match target {
+        RunTarget::Worktree(worktree) => {
+            let mut cmd = Command::new("sh");
+            cmd.args(["-c", command])
+                .current_dir(worktree)
+                .env("PARA_WORKSPACE", worktree)
+                .env("PARA_SESSION", session);
+            cmd
+        }
+        RunTarget::Container => {
+            let mut cmd = Command::new("docker");
+            cmd.args([
+                "exec",
+                "-e",
+                "PARA_WORKSPACE=/workspace",
+                "-e",
+                &format!("PARA_SESSION={session}"),
+                "-w",
+                "/workspace",
+                &format!("para-{session}"),
+                "sh",
+                "-c",
+                command,
+            ]);
+            cmd
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn run(temp: &TempDir, commands: &[&str], ignore_errors: bool) -> Result<()> {
+        let worktree = temp.path().join("worktree");
+        fs::create_dir_all(&worktree).unwrap();
+        run_commands(
+            commands,
+            &temp.path().join("state"),
+            "auth",
+            &RunTarget::Worktree(&worktree),
+            ignore_errors,
+        )
+    }
+
+    #[test]
+    fn test_commands_run_in_order_in_the_worktree() {
+        let temp = TempDir::new().unwrap();
+        run(
+            &temp,
+            &[
+                "echo first >> order",
+                "echo \"$PARA_SESSION\" >> order",
+                "test \"$PARA_WORKSPACE\" = \"$(pwd)\" && echo third >> order",
+            ],
+            false,
+        )
+        .unwrap();
+
+        let order = fs::read_to_string(temp.path().join("worktree/order")).unwrap();
+        assert_eq!(order, "first\nauth\nthird\n");
+        let log = fs::read_to_string(temp.path().join("state/auth.setup.log")).unwrap();
+        assert!(log.starts_with("$ echo first >> order\n"));
+    }
+
+    #[test]
+    fn test_failing_command_aborts_unless_errors_are_ignored() {
+        let temp = TempDir::new().unwrap();
+        let commands = ["echo failing; exit 4", "touch after"];
+
+        let error = run(&temp, &commands, false).unwrap_err();
+        assert!(matches!(error, ParaError::ResumeCommand { .. }));
+        assert!(error.to_string().contains("exit code 4"));
+        assert!(!temp.path().join("worktree/after").exists());
+        let log = fs::read_to_string(temp.path().join("state/auth.setup.log")).unwrap();
+        assert_eq!(log, "$ echo failing; exit 4\nfailing\n");
+
+        run(&temp, &commands, true).unwrap();
+        assert!(temp.path().join("worktree/after").exists());
+    }
+
+    #[test]
+    fn test_configured_commands_run_before_run_flags() {
+        let mut config = crate::test_utils::test_helpers::create_test_config();
+        config.session.on_resume = vec!["npm install".to_string()];
+        let args = ResumeArgs {
+            session: None,
+            session_branch: None,
+            prompt: None,
+            file: None,
+            dangerously_skip_permissions: false,
+            allow_secrets: false,
+            run: vec!["make db".to_string(), "make seed".to_string()],
+            run_ignore_errors: false,
+            sandbox_args: crate::cli::parser::SandboxArgs {
+                sandbox: false,
+                no_sandbox: false,
+                sandbox_profile: None,
+                sandbox_no_network: false,
+                allowed_domains: vec![],
+            },
+        };
+        assert_eq!(
+            resume_commands(&config, &args),
+            vec!["npm install", "make db", "make seed"]
+        );
+    }
+
+    #[test]
+    fn test_container_commands_go_through_docker_exec() {
+        let cmd = shell_command("make db", "auth", &RunTarget::Container);
+        assert_eq!(cmd.get_program(), "docker");
+        let args: Vec<_> = cmd.get_args().map(|a| a.to_str().unwrap()).collect();
+        assert_eq!(
+            args,
+            [
+                "exec",
+                "-e",
+                "PARA_WORKSPACE=/workspace",
+                "-e",
+                "PARA_SESSION=auth",
+                "-w",
+                "/workspace",
+                "para-auth",
+                "sh",
+                "-c",
+                "make db",
+            ]
+        );
+    }
+}
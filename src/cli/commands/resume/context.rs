@@ -1,4 +1,5 @@
 use crate::cli::parser::ResumeArgs;
+use crate::ui::theme;
 use crate::utils::{ParaError, Result};
 use std::env;
 use std::fs;
@@ -38,7 +39,7 @@ pub fn process_resume_context(args: &ResumeArgs) -> Result<Option<String>> {
                 .map_err(|e| ParaError::fs_error(format!("Failed to read file: {e}")))?;
 
             if content.trim().is_empty() {
-                println!("⚠️  Warning: File is empty");
+                println!("{}", theme::warning("Warning: File is empty"));
             }
 
             Ok(Some(content))
@@ -93,6 +94,10 @@ mod tests {
                 sandbox_no_network: false,
                 allowed_domains: vec![],
             },
+            session_branch: None,
+            allow_secrets: false,
+            run: vec![],
+            run_ignore_errors: false,
         };
 
         let result = process_resume_context(&args).unwrap();
@@ -120,6 +125,10 @@ mod tests {
                 sandbox_no_network: false,
                 allowed_domains: vec![],
             },
+            session_branch: None,
+            allow_secrets: false,
+            run: vec![],
+            run_ignore_errors: false,
         };
 
         let result = process_resume_context(&args).unwrap();
@@ -143,6 +152,10 @@ mod tests {
                 sandbox_no_network: false,
                 allowed_domains: vec![],
             },
+            session_branch: None,
+            allow_secrets: false,
+            run: vec![],
+            run_ignore_errors: false,
         };
 
         let result = process_resume_context(&args).unwrap();
@@ -163,6 +176,10 @@ mod tests {
                 sandbox_no_network: false,
                 allowed_domains: vec![],
             },
+            session_branch: None,
+            allow_secrets: false,
+            run: vec![],
+            run_ignore_errors: false,
         };
 
         let result = process_resume_context(&args);
@@ -191,6 +208,10 @@ mod tests {
                 sandbox_no_network: false,
                 allowed_domains: vec![],
             },
+            session_branch: None,
+            allow_secrets: false,
+            run: vec![],
+            run_ignore_errors: false,
         };
 
         let result = process_resume_context(&args);
@@ -233,6 +254,10 @@ mod tests {
                 sandbox_no_network: false,
                 allowed_domains: vec![],
             },
+            session_branch: None,
+            allow_secrets: false,
+            run: vec![],
+            run_ignore_errors: false,
         };
 
         // Process should succeed but with empty content
@@ -1,33 +1,57 @@
+use crate::cli::commands::common::check_prompt_secrets;
+use crate::cli::context::CommandContext;
 use crate::cli::parser::ResumeArgs;
 use crate::config::Config;
-use crate::core::git::GitService;
-use crate::core::session::SessionManager;
 use crate::utils::{ParaError, Result};
 
 mod claude_session;
+mod commands;
 mod context;
 mod repair;
 mod session;
-mod task_transform;
 
 // Public API is exposed through the execute function only
 
 /// Main entry point - orchestrates resume logic
-pub fn execute(config: Config, args: ResumeArgs) -> Result<()> {
+pub fn execute(ctx: &CommandContext, args: ResumeArgs) -> Result<()> {
+    let config = ctx.config();
     args.validate()?;
     validate_resume_args(&args)?;
+    check_resume_context_secrets(config, &args)?;
 
-    let git_service = GitService::discover()?;
-    let session_manager = SessionManager::new(&config);
+    let git_service = ctx.git_service()?;
+    let session_manager = ctx.session_manager();
+
+    if let Some(ref branch) = args.session_branch {
+        let session = session_manager.resolve_session_by_branch(branch)?;
+        return session::resume_specific_session(config, git_service, &session.name, &args);
+    }
 
     match &args.session {
         Some(session_name) => {
-            session::resume_specific_session(&config, &git_service, session_name, &args)
+            session::resume_specific_session(config, git_service, session_name, &args)
         }
-        None => session::detect_and_resume_session(&config, &git_service, &session_manager, &args),
+        None => session::detect_and_resume_session(config, git_service, &session_manager, &args),
     }
 }
 
+/// Scan the extra instructions once, before any session is touched
+fn check_resume_context_secrets(config: &Config, args: &ResumeArgs) -> Result<()> {
+    let Some(resume_context) = context::process_resume_context(args)? else {
+        return Ok(());
+    };
+    let session = args.session.as_deref().unwrap_or("<session>");
+    check_prompt_secrets(
+        config,
+        &resume_context,
+        args.allow_secrets,
+        &[
+            format!(".para/sessions/{session}/resume_context.md in the session worktree"),
+            "CLAUDE.local.md in the session worktree".to_string(),
+        ],
+    )
+}
+
 fn validate_resume_args(args: &ResumeArgs) -> Result<()> {
     if let Some(ref session) = args.session {
         if session.is_empty() {
@@ -60,6 +84,10 @@ mod tests {
                 sandbox_no_network: false,
                 allowed_domains: vec![],
             },
+            session_branch: None,
+            allow_secrets: false,
+            run: vec![],
+            run_ignore_errors: false,
         };
         assert!(args.validate().is_ok());
 
@@ -75,6 +103,10 @@ mod tests {
                 sandbox_no_network: false,
                 allowed_domains: vec![],
             },
+            session_branch: None,
+            allow_secrets: false,
+            run: vec![],
+            run_ignore_errors: false,
         };
         assert!(args.validate().is_ok());
 
@@ -91,6 +123,10 @@ mod tests {
                 sandbox_no_network: false,
                 allowed_domains: vec![],
             },
+            session_branch: None,
+            allow_secrets: false,
+            run: vec![],
+            run_ignore_errors: false,
         };
         assert!(args.validate().is_err());
         assert!(args
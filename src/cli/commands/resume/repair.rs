@@ -1,6 +1,9 @@
-use crate::core::git::{GitOperations, GitService};
+use crate::cli::commands::common::is_non_interactive;
+use crate::core::git::repository::{execute_git_command, short_sha};
+use crate::core::git::{GitOperations, GitRepository, GitService, WorktreeRef};
 use crate::core::session::state::SessionState;
-use crate::core::session::SessionManager;
+use crate::core::session::{SessionManager, SessionPaths};
+use crate::ui::theme;
 use crate::utils::{ParaError, Result};
 
 /// Handle worktree path repairs and recovery
@@ -19,6 +22,18 @@ pub fn repair_worktree_path(
         {
             session_state.worktree_path = wt.path.clone();
             session_manager.save_state(session_state)?;
+        } else if let Some(path) = SessionPaths::candidates(
+            session_manager.config(),
+            &git_service.repository().root,
+            session_name,
+        )?
+        .into_iter()
+        .find(|path| path.join(".git").exists())
+        {
+            // The worktree moved to where either layout puts it, e.g. with a
+            // detached HEAD that the branch match above misses
+            session_state.worktree_path = path;
+            session_manager.save_state(session_state)?;
         } else if let Some(wt) = git_service.list_worktrees()?.into_iter().find(|w| {
             w.path
                 .file_name()
@@ -38,3 +53,162 @@ pub fn repair_worktree_path(
     }
     Ok(())
 }
+
+/// How a detached HEAD was re-attached
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Reattached {
+    /// The session branch contains the commit and is checked out again
+    Switched,
+    /// The commit is not on the session branch; a new branch keeps it
+    Rescued(String),
+}
+
+/// Offer to re-attach the session worktree when its HEAD is detached.
+/// Non-interactive runs leave the worktree alone and print how to do it.
+pub fn offer_reattach(session_state: &SessionState) -> Result<()> {
+    let Ok(worktree) = GitRepository::discover_from(&session_state.worktree_path) else {
+        return Ok(());
+    };
+    let Ok(WorktreeRef::Detached(sha)) = worktree.head_ref() else {
+        return Ok(());
+    };
+
+    let branch = &session_state.branch;
+    println!(
+        "{}",
+        theme::warning(format!(
+            "HEAD of the session worktree is detached at {}",
+            short_sha(&sha)
+        ))
+    );
+    let reattach = !is_non_interactive()
+        && dialoguer::Confirm::new()
+            .with_prompt(format!("Re-attach it to '{branch}'?"))
+            .default(true)
+            .interact()
+            .unwrap_or(false);
+    if !reattach {
+        println!("  Re-attach it later with: git switch {branch}");
+        return Ok(());
+    }
+
+    match reattach_head(&worktree, branch, &sha) {
+        Ok(Reattached::Switched) => println!("🔗 Switched back to '{branch}'"),
+        Ok(Reattached::Rescued(rescue)) => println!(
+            "🔗 {} is not on '{branch}'; created '{rescue}' to keep it",
+            short_sha(&sha)
+        ),
+        Err(e) => eprintln!("Warning: Could not re-attach HEAD: {e}"),
+    }
+    Ok(())
+}
+
+/// Check out `branch` when it contains `sha`, otherwise a new branch at `sha`
+pub fn reattach_head(worktree: &GitRepository, branch: &str, sha: &str) -> Result<Reattached> {
+    let branch_ref = format!("refs/heads/{branch}");
+    let contained =
+        execute_git_command(worktree, &["rev-parse", "--verify", "--quiet", &branch_ref]).is_ok()
+            && execute_git_command(worktree, &["merge-base", "--is-ancestor", sha, &branch_ref])
+                .is_ok();
+    if contained {
+        execute_git_command(worktree, &["switch", branch])?;
+        return Ok(Reattached::Switched);
+    }
+
+    let rescue = format!("{branch}-rescue-{}", short_sha(sha));
+    execute_git_command(worktree, &["switch", "-c", &rescue, sha])?;
+    Ok(Reattached::Rescued(rescue))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::WorktreeLayout;
+    use crate::test_utils::test_helpers::{create_test_config_with_dir, setup_test_repo};
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn detach_worktree() -> (TempDir, TempDir, GitRepository) {
+        let (git_temp, git_service) = setup_test_repo();
+        let worktrees = TempDir::new().unwrap();
+        let worktree_path = worktrees.path().join("session");
+        git_service
+            .create_worktree("para/detached", &worktree_path)
+            .unwrap();
+        let worktree = GitRepository::discover_from(&worktree_path).unwrap();
+        execute_git_command(&worktree, &["checkout", "--detach"]).unwrap();
+        (git_temp, worktrees, worktree)
+    }
+
+    fn head_sha(worktree: &GitRepository) -> String {
+        execute_git_command(worktree, &["rev-parse", "HEAD"]).unwrap()
+    }
+
+    #[test]
+    fn test_reattach_switches_back_to_containing_branch() {
+        let (_git_temp, _worktrees, worktree) = detach_worktree();
+        let sha = head_sha(&worktree);
+
+        let outcome = reattach_head(&worktree, "para/detached", &sha).unwrap();
+        assert_eq!(outcome, Reattached::Switched);
+        assert_eq!(
+            worktree.head_ref().unwrap(),
+            WorktreeRef::Branch("para/detached".to_string())
+        );
+    }
+
+    #[test]
+    fn test_reattach_rescues_commits_made_while_detached() {
+        let (_git_temp, _worktrees, worktree) = detach_worktree();
+        fs::write(worktree.root.join("detached.txt"), "work").unwrap();
+        execute_git_command(&worktree, &["add", "detached.txt"]).unwrap();
+        execute_git_command(&worktree, &["commit", "-m", "Detached work"]).unwrap();
+        let sha = head_sha(&worktree);
+
+        let outcome = reattach_head(&worktree, "para/detached", &sha).unwrap();
+        let rescue = format!("para/detached-rescue-{}", short_sha(&sha));
+        assert_eq!(outcome, Reattached::Rescued(rescue.clone()));
+        assert_eq!(worktree.head_ref().unwrap(), WorktreeRef::Branch(rescue));
+        assert_eq!(head_sha(&worktree), sha);
+    }
+
+    #[test]
+    fn test_mixed_layouts_resolve_through_state() {
+        let (_git_temp, git_service) = setup_test_repo();
+        let state_temp = TempDir::new().unwrap();
+        let mut config = create_test_config_with_dir(&state_temp);
+        config.directories.layout = WorktreeLayout::ByPrefix;
+        let root = git_service.repository().root.clone();
+
+        // Created before the layout was switched to flat
+        let old_path = SessionPaths::worktree_path(&config, &root, "old").unwrap();
+        git_service.create_worktree("test/old", &old_path).unwrap();
+        config.directories.layout = WorktreeLayout::Flat;
+        let session_manager = SessionManager::new(&config);
+        let mut state =
+            SessionState::new("old".to_string(), "test/old".to_string(), old_path.clone());
+        session_manager.save_state(&state).unwrap();
+
+        repair_worktree_path(&mut state, &git_service, &session_manager, "old").unwrap();
+        assert_eq!(state.worktree_path, old_path);
+        assert_ne!(
+            SessionPaths::worktree_path(&config, &root, "old").unwrap(),
+            old_path
+        );
+
+        // A detached worktree in the other layout is still found for a stale path
+        let worktree = GitRepository::discover_from(&old_path).unwrap();
+        execute_git_command(&worktree, &["checkout", "--detach"]).unwrap();
+        let mut stale = SessionState::new(
+            "old".to_string(),
+            "test/old".to_string(),
+            root.join("moved-away"),
+        );
+        repair_worktree_path(&mut stale, &git_service, &session_manager, "old").unwrap();
+        assert_eq!(stale.worktree_path, old_path);
+        assert_eq!(
+            session_manager.load_state("old").unwrap().worktree_path,
+            old_path
+        );
+    }
+}
@@ -1,19 +1,29 @@
-use crate::cli::commands::common::create_claude_local_md;
+use crate::cli::commands::common::{
+    check_skip_permissions_allowed, write_claude_local_md, ClaudeLocalContext,
+};
+use crate::cli::commands::launch::launch_planned_session;
 use crate::cli::parser::ResumeArgs;
 use crate::config::Config;
+use crate::core::claude_launcher::{launch_claude_with_context, ClaudeLaunchOptions};
+use crate::core::docker::DockerManager;
 use crate::core::git::{GitOperations, GitService, SessionEnvironment};
+use crate::core::ide::launch_tasks::LaunchBackend;
 use crate::core::ide::{IdeManager, LaunchOptions};
+use crate::core::sandbox::config::{SandboxResolver, SandboxSettings};
 use crate::core::session::state::SessionState;
-use crate::core::session::{SessionManager, SessionStatus};
+use crate::core::session::{manifest, SessionManager, SessionStatus};
+use crate::core::version;
+use crate::ui::theme;
+use crate::utils::output::path_link;
 use crate::utils::{ParaError, Result};
 use dialoguer::Select;
 use std::env;
 use std::path::Path;
 
 use super::claude_session::find_claude_session;
+use super::commands::{run_resume_commands, RunTarget};
 use super::context::{process_resume_context, save_resume_context};
-use super::repair::repair_worktree_path;
-use super::task_transform::transform_claude_tasks_file;
+use super::repair::{offer_reattach, repair_worktree_path};
 
 /// Session-specific resume operations
 pub fn resume_specific_session(
@@ -24,22 +34,42 @@ pub fn resume_specific_session(
 ) -> Result<()> {
     let mut session_manager = SessionManager::new(config);
 
-    // Try to validate and load existing session
-    if let Some(mut session_state) = validate_session_exists(&session_manager, session_name)? {
+    // Sessions without state are still found by their worktree below
+    let resolved = match session_manager.resolve_session(session_name) {
+        Err(e @ ParaError::SessionNotFound { .. }) => Err(e),
+        other => Ok(other?),
+    };
+
+    if let Ok(mut session_state) = resolved {
+        version::check_session(&session_state, false)?;
+        let session_name = session_state.name.clone();
         // Repair worktree path if needed
         repair_worktree_path(
             &mut session_state,
             git_service,
             &session_manager,
-            session_name,
+            &session_name,
         )?;
 
-        // Prepare session files
-        prepare_session_files(&session_state.worktree_path, &session_state.name)?;
+        if session_state.status == SessionStatus::Planned {
+            if process_resume_context(args)?.is_some() {
+                return Err(ParaError::invalid_args(format!(
+                    "Session '{session_name}' has not been launched yet. Run 'para launch {session_name}' before adding instructions"
+                )));
+            }
+            return launch_planned_session(config, &session_manager, session_state);
+        }
+        offer_reattach(&session_state)?;
 
         // Handle resume context and get processed content
         let processed_context = process_resume_context(args)?;
 
+        // Prepare session files
+        prepare_session_files(
+            &ClaudeLocalContext::for_session(&session_state)
+                .with_additional_task(processed_context.as_deref()),
+        )?;
+
         // If session is in Review state and we have a task/prompt, transition back to Active
         if matches!(session_state.status, SessionStatus::Review) && processed_context.is_some() {
             session_manager.update_session_status(&session_state.name, SessionStatus::Active)?;
@@ -49,27 +79,33 @@ pub fn resume_specific_session(
         if let Some(ref context) = processed_context {
             save_resume_context(&session_state.worktree_path, &session_state.name, context)?;
         }
+        refresh_manifest(&session_manager, &session_name);
+
+        if session_state.is_container() {
+            resume_container_session(config, &session_state, args, processed_context.as_deref())?;
+            println!(
+                "{}",
+                theme::success(format!("Resumed session '{session_name}'"))
+            );
+            println!("   Worktree: {}", path_link(&session_state.worktree_path));
+            return Ok(());
+        }
 
         // Launch IDE with prompt if provided
-        launch_ide_for_session_with_state(
+        launch_ide_for_session(
             config,
             &session_state.worktree_path,
             args,
             processed_context.as_ref(),
             Some(&session_state),
         )?;
-        println!("✅ Resumed session '{session_name}'");
-    } else {
-        // Fallback: maybe the state file was timestamped (e.g. test4_20250611-XYZ)
-        if let Some(candidate) = session_manager
-            .list_sessions()?
-            .into_iter()
-            .find(|s| s.name.starts_with(session_name))
-        {
-            // recurse with the full name
-            return resume_specific_session(config, git_service, &candidate.name, args);
-        }
-        // original branch/path heuristic
+        println!(
+            "{}",
+            theme::success(format!("Resumed session '{session_name}'"))
+        );
+        println!("   Worktree: {}", path_link(&session_state.worktree_path));
+    } else if let Err(not_found) = resolved {
+        // Branch/path heuristic
         let worktrees = git_service.list_worktrees()?;
         let matching_worktree = worktrees
             .iter()
@@ -82,7 +118,7 @@ pub fn resume_specific_session(
                         .map(|name| name.contains(session_name))
                         .unwrap_or(false)
             })
-            .ok_or_else(|| ParaError::session_not_found(session_name.to_string()))?;
+            .ok_or(not_found)?;
 
         // Try to find session from matching worktree
         let session_opt = session_manager.list_sessions()?.into_iter().find(|s| {
@@ -94,11 +130,22 @@ pub fn resume_specific_session(
             .map(|s| s.name.clone())
             .unwrap_or_else(|| session_name.to_string());
 
-        // Prepare session files using extracted function
-        prepare_session_files(&matching_worktree.path, &session_name_for_files)?;
-
         // Handle resume context and get processed content
         let processed_context = process_resume_context(args)?;
+
+        // Prepare session files using extracted function
+        let claude_local = match session_opt {
+            Some(ref session) => ClaudeLocalContext {
+                worktree_path: matching_worktree.path.clone(),
+                ..ClaudeLocalContext::for_session(session)
+            },
+            None => ClaudeLocalContext::new(
+                &session_name_for_files,
+                &matching_worktree.branch,
+                &matching_worktree.path,
+            ),
+        };
+        prepare_session_files(&claude_local.with_additional_task(processed_context.as_deref()))?;
         if let Some(ref context) = processed_context {
             save_resume_context(&matching_worktree.path, &session_name_for_files, context)?;
         }
@@ -111,8 +158,11 @@ pub fn resume_specific_session(
             session_opt.as_ref(),
         )?;
         println!(
-            "✅ Resumed session at '{}'",
-            matching_worktree.path.display()
+            "{}",
+            theme::success(format!(
+                "Resumed session at '{}'",
+                path_link(&matching_worktree.path)
+            ))
         );
     }
 
@@ -142,7 +192,13 @@ pub fn detect_and_resume_session(
                 .find(|s| s.worktree_path == current_dir || s.branch == branch);
 
             if let Some(ref session) = session_opt {
-                create_claude_local_md(&current_dir, &session.name)?;
+                let claude_local = ClaudeLocalContext {
+                    worktree_path: current_dir.clone(),
+                    ..ClaudeLocalContext::for_session(session)
+                };
+                write_claude_local_md(
+                    &claude_local.with_additional_task(processed_context.as_deref()),
+                )?;
 
                 // If session is in Review state and we have a task/prompt, transition back to Active
                 if matches!(session.status, SessionStatus::Review) && processed_context.is_some() {
@@ -155,16 +211,17 @@ pub fn detect_and_resume_session(
                 if let Some(ref context) = processed_context {
                     save_resume_context(&current_dir, &session.name, context)?;
                 }
+                refresh_manifest(session_manager, &session.name);
             }
 
-            launch_ide_for_session_with_state(
+            launch_ide_for_session(
                 config,
                 &current_dir,
                 args,
                 processed_context.as_ref(),
                 session_opt.as_ref(),
             )?;
-            println!("✅ Resumed current session");
+            println!("{}", theme::success("Resumed current session"));
             Ok(())
         }
         SessionEnvironment::MainRepository => {
@@ -233,12 +290,15 @@ pub fn list_and_select_session(
             )));
         }
 
-        // Ensure CLAUDE.local.md exists for the session
-        create_claude_local_md(&session.worktree_path, &session.name)?;
-
         // Process and save resume context if provided
         let processed_context = process_resume_context(args)?;
 
+        // Ensure CLAUDE.local.md exists for the session
+        write_claude_local_md(
+            &ClaudeLocalContext::for_session(session)
+                .with_additional_task(processed_context.as_deref()),
+        )?;
+
         // If session is in Review state and we have a task/prompt, transition back to Active
         if matches!(session.status, SessionStatus::Review) && processed_context.is_some() {
             let mut session_manager = SessionManager::new(config);
@@ -257,73 +317,204 @@ pub fn list_and_select_session(
             processed_context.as_ref(),
             Some(session),
         )?;
-        println!("✅ Resumed session '{}'", session.name);
+        println!(
+            "{}",
+            theme::success(format!("Resumed session '{}'", session.name))
+        );
+        println!("   Worktree: {}", path_link(&session.worktree_path));
     }
 
     Ok(())
 }
 
 /// Validate session exists and return state if found
-pub fn validate_session_exists(
-    session_manager: &SessionManager,
-    session_name: &str,
-) -> Result<Option<SessionState>> {
-    if session_manager.session_exists(session_name) {
-        let session_state = session_manager.load_state(session_name)?;
-        Ok(Some(session_state))
-    } else {
-        Ok(None)
+/// Rewrite the session's `.para/session.json` from its current state
+fn refresh_manifest(session_manager: &SessionManager, session_name: &str) {
+    if let Ok(session_state) = session_manager.load_state(session_name) {
+        if let Err(e) = manifest::sync(&session_state, session_manager.state_dir()) {
+            eprintln!("Warning: Failed to update the session manifest: {e}");
+        }
     }
 }
 
-fn prepare_session_files(worktree_path: &Path, session_name: &str) -> Result<()> {
+fn prepare_session_files(claude_local: &ClaudeLocalContext) -> Result<()> {
     // Ensure CLAUDE.local.md exists for the session
-    create_claude_local_md(worktree_path, session_name)?;
+    write_claude_local_md(claude_local)?;
     Ok(())
 }
 
+/// Determine the permission mode for a resumed session.
+///
+/// A session created with `--dangerously-skip-permissions` keeps that mode on
+/// resume; the flag only has to be passed again when the state doesn't record
+/// it, in which case it is recorded from now on.
+/// Start a container session's container again, with the docker options it
+/// was dispatched with, run the resume commands in it and connect the IDE
+fn resume_container_session(
+    config: &Config,
+    session_state: &SessionState,
+    args: &ResumeArgs,
+    prompt: Option<&str>,
+) -> Result<()> {
+    let docker_manager = DockerManager::for_session(config.clone(), session_state);
+    if let Some(container) = &session_state.container {
+        let isolation = if container.network_isolation {
+            "on"
+        } else {
+            "off"
+        };
+        println!(
+            "{}",
+            theme::container(format!("Network isolation: {isolation}"))
+        );
+    }
+    docker_manager
+        .start_session_container(session_state)
+        .map_err(|e| ParaError::docker_error(format!("Failed to start container: {e}")))?;
+    run_resume_commands(config, args, &session_state.name, RunTarget::Container)?;
+
+    let skip_permissions = resolve_skip_permissions(config, Some(session_state), args)?;
+    docker_manager
+        .launch_container_ide(session_state, prompt, skip_permissions)
+        .map_err(|e| ParaError::docker_error(format!("Failed to launch IDE: {e}")))
+}
+
+fn resolve_skip_permissions(
+    config: &Config,
+    session_state: Option<&SessionState>,
+    args: &ResumeArgs,
+) -> Result<bool> {
+    let recorded = session_state.is_some_and(|s| s.skips_permissions());
+    let skip_permissions = recorded || args.dangerously_skip_permissions;
+
+    check_skip_permissions_allowed(config, skip_permissions)?;
+
+    if recorded && !args.dangerously_skip_permissions {
+        println!(
+            "{}",
+            theme::warning(
+                "Resuming with --dangerously-skip-permissions (recorded for this session)"
+            )
+        );
+    }
+
+    if skip_permissions && !recorded {
+        if let Some(state) = session_state {
+            let session_manager = SessionManager::new(config);
+            let mut current = session_manager.load_state(&state.name)?;
+            current.dangerous_skip_permissions = Some(true);
+            session_manager.save_state(&current)?;
+        }
+    }
+
+    Ok(skip_permissions)
+}
+
+/// Determine the sandbox mode for a resumed session.
+///
+/// Sandbox flags passed to `resume` are resolved like on `start` and recorded
+/// in the session state, so a session can be moved in or out of the sandbox.
+/// Without flags the recorded mode is replayed instead of the current config.
+fn resolve_sandbox_mode(
+    config: &Config,
+    session_state: Option<&SessionState>,
+    args: &ResumeArgs,
+) -> Result<SandboxSettings> {
+    let sandbox_args = &args.sandbox_args;
+    let has_flags = sandbox_args.sandbox
+        || sandbox_args.no_sandbox
+        || sandbox_args.sandbox_profile.is_some()
+        || sandbox_args.sandbox_no_network;
+    let resolver = SandboxResolver::new(config);
+
+    let settings = match session_state {
+        Some(state) if !has_flags => {
+            let enabled = state.sandbox_enabled.unwrap_or(false);
+            resolver.resolve_with_network(
+                enabled,
+                !enabled,
+                state.sandbox_profile.clone(),
+                false,
+                sandbox_args.allowed_domains.clone(),
+            )
+        }
+        _ => resolver.resolve_with_network(
+            sandbox_args.sandbox,
+            sandbox_args.no_sandbox,
+            sandbox_args.sandbox_profile.clone(),
+            sandbox_args.sandbox_no_network,
+            sandbox_args.allowed_domains.clone(),
+        ),
+    };
+
+    if has_flags {
+        if let Some(state) = session_state {
+            let session_manager = SessionManager::new(config);
+            let mut current = session_manager.load_state(&state.name)?;
+            current.sandbox_enabled = Some(settings.enabled);
+            current.sandbox_profile = settings.enabled.then(|| settings.profile.clone());
+            session_manager.save_state(&current)?;
+        }
+    }
+
+    Ok(settings)
+}
+
+/// Launches Claude Code; replaced in tests to observe the launch options
+type ClaudeLauncher<'a> = dyn FnMut(&Config, &Path, ClaudeLaunchOptions) -> Result<()> + 'a;
+
 fn launch_ide_for_session(
     config: &Config,
     path: &Path,
     args: &ResumeArgs,
     processed_context: Option<&String>,
-    _session_state: Option<&SessionState>,
+    session_state: Option<&SessionState>,
 ) -> Result<()> {
-    launch_ide_for_session_with_state(config, path, args, processed_context, None)
+    let session_name = match session_state {
+        Some(state) => state.name.clone(),
+        None => path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default(),
+    };
+    run_resume_commands(config, args, &session_name, RunTarget::Worktree(path))?;
+
+    launch_ide_for_session_with(
+        config,
+        path,
+        args,
+        processed_context,
+        session_state,
+        &mut launch_claude_with_context,
+    )
 }
 
-fn launch_ide_for_session_with_state(
+fn launch_ide_for_session_with(
     config: &Config,
     path: &Path,
     args: &ResumeArgs,
     processed_context: Option<&String>,
     session_state: Option<&SessionState>,
+    launch_claude: &mut ClaudeLauncher<'_>,
 ) -> Result<()> {
     let ide_manager = IdeManager::new(config);
 
-    // Determine if we should skip permissions:
-    // 1. If the session was originally created with dangerous flag, respect it
-    // 2. If the user explicitly passes the flag during resume, respect it
-    // 3. Otherwise, don't skip permissions
-    let skip_permissions = session_state
-        .and_then(|s| s.dangerous_skip_permissions)
-        .unwrap_or(false)
-        || args.dangerously_skip_permissions;
+    let skip_permissions = resolve_skip_permissions(config, session_state, args)?;
 
     // For Claude Code in wrapper mode, check for existing session
     if config.ide.name == "claude" && config.ide.wrapper.enabled {
+        let sandbox = resolve_sandbox_mode(config, session_state, args)?;
+        if sandbox.enabled {
+            println!("🔒 Sandbox: enabled (profile '{}')", sandbox.profile);
+        } else {
+            println!("🔓 Sandbox: disabled");
+        }
+
         let mut launch_options = LaunchOptions {
             skip_permissions,
-            // Pass raw CLI args, not resolved settings - let claude_launcher resolve them
-            sandbox_override: if args.sandbox_args.sandbox {
-                Some(true)
-            } else if args.sandbox_args.no_sandbox {
-                Some(false)
-            } else {
-                None
-            },
-            sandbox_profile: args.sandbox_args.sandbox_profile.clone(),
-            network_sandbox: args.sandbox_args.sandbox_no_network,
+            sandbox_override: Some(sandbox.enabled),
+            sandbox_profile: sandbox.enabled.then(|| sandbox.profile.clone()),
+            network_sandbox: sandbox.network_sandbox,
             allowed_domains: args.sandbox_args.allowed_domains.clone(),
             ..Default::default()
         };
@@ -332,7 +523,7 @@ fn launch_ide_for_session_with_state(
         match find_claude_session(path) {
             Ok(Some(claude_session)) => {
                 if claude_session.id.is_empty() {
-                    println!("⚠️  Found Claude session but ID is empty");
+                    println!("{}", theme::warning("Found Claude session but ID is empty"));
                     launch_options.continue_conversation = true;
                 } else {
                     println!("🔗 Found existing Claude session: {}", claude_session.id);
@@ -340,28 +531,39 @@ fn launch_ide_for_session_with_state(
 
                     // Include prompt from processed context (file or inline prompt)
                     if let Some(_context) = processed_context {
-                        println!("▶ resuming Claude Code session with prompt...");
+                        println!(
+                            "{}",
+                            theme::action("resuming Claude Code session with prompt...")
+                        );
                     } else {
-                        println!("▶ resuming Claude Code session with conversation history...");
+                        println!(
+                            "{}",
+                            theme::action(
+                                "resuming Claude Code session with conversation history..."
+                            )
+                        );
                     }
                 }
             }
             Ok(None) => {
                 // No existing session found, use continuation flag
-                println!("▶ starting new Claude Code session...");
+                println!("{}", theme::action("starting new Claude Code session..."));
                 launch_options.continue_conversation = true;
 
-                // Update existing tasks.json to include -c flag
-                transform_claude_tasks_file(path)?;
+                // Make an existing launch task continue instead of resending its prompt
+                LaunchBackend::for_config(config).transform_for_resume(path)?;
             }
             Err(e) => {
-                println!("⚠️  Error finding Claude session: {e}");
+                println!(
+                    "{}",
+                    theme::warning(format!("Error finding Claude session: {e}"))
+                );
                 launch_options.continue_conversation = true;
             }
         }
 
         // Launch with shared claude launcher
-        let claude_options = crate::core::claude_launcher::ClaudeLaunchOptions {
+        let claude_options = ClaudeLaunchOptions {
             skip_permissions: launch_options.skip_permissions,
             session_id: launch_options.claude_session_id.clone(),
             continue_conversation: launch_options.continue_conversation,
@@ -371,7 +573,7 @@ fn launch_ide_for_session_with_state(
             network_sandbox: launch_options.network_sandbox,
             allowed_domains: launch_options.allowed_domains.clone(),
         };
-        crate::core::claude_launcher::launch_claude_with_context(config, path, claude_options)
+        launch_claude(config, path, claude_options)
     } else {
         ide_manager.launch(path, skip_permissions)
     }
@@ -382,6 +584,7 @@ mod tests {
     use super::*;
     use crate::cli::parser::SandboxArgs;
     use crate::core::session::state::SessionState;
+    use crate::core::session::SessionPaths;
     use crate::test_utils::test_helpers::*;
     use std::fs;
     use tempfile::TempDir;
@@ -406,12 +609,9 @@ mod tests {
         // create timestamped session state only
         let session_full = "test4_20250611-131147".to_string();
         let branch_name = "para/test-branch".to_string();
-        let worktree_path = git_service
-            .repository()
-            .root
-            .join(&config.directories.subtrees_dir)
-            .join(&config.git.branch_prefix)
-            .join(&session_full);
+        let worktree_path =
+            SessionPaths::worktree_path(&config, &git_service.repository().root, &session_full)
+                .unwrap();
 
         git_service
             .create_worktree(&branch_name, &worktree_path)
@@ -433,6 +633,10 @@ mod tests {
                 sandbox_no_network: false,
                 allowed_domains: vec![],
             },
+            session_branch: None,
+            allow_secrets: false,
+            run: vec![],
+            run_ignore_errors: false,
         };
         resume_specific_session(&config, &git_service, "test4", &args).unwrap();
     }
@@ -458,12 +662,9 @@ mod tests {
         // Create a test session
         let session_name = "test-prompt-session".to_string();
         let branch_name = "para/test-prompt-branch".to_string();
-        let worktree_path = git_service
-            .repository()
-            .root
-            .join(&config.directories.subtrees_dir)
-            .join(&config.git.branch_prefix)
-            .join(&session_name);
+        let worktree_path =
+            SessionPaths::worktree_path(&config, &git_service.repository().root, &session_name)
+                .unwrap();
 
         git_service
             .create_worktree(&branch_name, &worktree_path)
@@ -485,6 +686,10 @@ mod tests {
                 sandbox_no_network: false,
                 allowed_domains: vec![],
             },
+            session_branch: None,
+            allow_secrets: false,
+            run: vec![],
+            run_ignore_errors: false,
         };
 
         // Execute resume (with echo IDE it won't actually launch anything)
@@ -520,12 +725,9 @@ mod tests {
         // Create a test session
         let session_name = "test-file-session".to_string();
         let branch_name = "para/test-file-branch".to_string();
-        let worktree_path = git_service
-            .repository()
-            .root
-            .join(&config.directories.subtrees_dir)
-            .join(&config.git.branch_prefix)
-            .join(&session_name);
+        let worktree_path =
+            SessionPaths::worktree_path(&config, &git_service.repository().root, &session_name)
+                .unwrap();
 
         git_service
             .create_worktree(&branch_name, &worktree_path)
@@ -556,6 +758,10 @@ mod tests {
                 sandbox_no_network: false,
                 allowed_domains: vec![],
             },
+            session_branch: None,
+            allow_secrets: false,
+            run: vec![],
+            run_ignore_errors: false,
         };
 
         // Execute resume
@@ -592,12 +798,9 @@ mod tests {
         // Create a test session
         let session_name = "test-compat-session".to_string();
         let branch_name = "para/test-compat-branch".to_string();
-        let worktree_path = git_service
-            .repository()
-            .root
-            .join(&config.directories.subtrees_dir)
-            .join(&config.git.branch_prefix)
-            .join(&session_name);
+        let worktree_path =
+            SessionPaths::worktree_path(&config, &git_service.repository().root, &session_name)
+                .unwrap();
 
         git_service
             .create_worktree(&branch_name, &worktree_path)
@@ -619,6 +822,10 @@ mod tests {
                 sandbox_no_network: false,
                 allowed_domains: vec![],
             },
+            session_branch: None,
+            allow_secrets: false,
+            run: vec![],
+            run_ignore_errors: false,
         };
 
         // Execute resume - should work exactly as before
@@ -656,12 +863,9 @@ mod tests {
         // Create a test session in Review state
         let session_name = "test-review-session".to_string();
         let branch_name = "para/test-review-branch".to_string();
-        let worktree_path = git_service
-            .repository()
-            .root
-            .join(&config.directories.subtrees_dir)
-            .join(&config.git.branch_prefix)
-            .join(&session_name);
+        let worktree_path =
+            SessionPaths::worktree_path(&config, &git_service.repository().root, &session_name)
+                .unwrap();
 
         git_service
             .create_worktree(&branch_name, &worktree_path)
@@ -688,6 +892,10 @@ mod tests {
                 sandbox_no_network: false,
                 allowed_domains: vec![],
             },
+            session_branch: None,
+            allow_secrets: false,
+            run: vec![],
+            run_ignore_errors: false,
         };
 
         // Execute resume
@@ -727,12 +935,9 @@ mod tests {
         // Create a test session in Review state
         let session_name = "test-review-no-prompt".to_string();
         let branch_name = "para/test-review-no-prompt-branch".to_string();
-        let worktree_path = git_service
-            .repository()
-            .root
-            .join(&config.directories.subtrees_dir)
-            .join(&config.git.branch_prefix)
-            .join(&session_name);
+        let worktree_path =
+            SessionPaths::worktree_path(&config, &git_service.repository().root, &session_name)
+                .unwrap();
 
         git_service
             .create_worktree(&branch_name, &worktree_path)
@@ -755,6 +960,10 @@ mod tests {
                 sandbox_no_network: false,
                 allowed_domains: vec![],
             },
+            session_branch: None,
+            allow_secrets: false,
+            run: vec![],
+            run_ignore_errors: false,
         };
 
         // Execute resume
@@ -877,6 +1086,10 @@ mod tests {
                 sandbox_no_network: false,
                 allowed_domains: vec![],
             },
+            session_branch: None,
+            allow_secrets: false,
+            run: vec![],
+            run_ignore_errors: false,
         };
 
         // In a real test, we'd mock the IDE launch, but here we verify the logic
@@ -885,9 +1098,8 @@ mod tests {
             .load_state("test-dangerous-session")
             .unwrap();
 
-        // This is the logic from launch_ide_for_session
-        let skip_permissions = loaded_session.dangerous_skip_permissions.unwrap_or(false)
-            || args.dangerously_skip_permissions;
+        let skip_permissions =
+            resolve_skip_permissions(&config, Some(&loaded_session), &args).unwrap();
 
         assert!(
             skip_permissions,
@@ -917,13 +1129,88 @@ mod tests {
                 sandbox_no_network: false,
                 allowed_domains: vec![],
             },
+            session_branch: None,
+            allow_secrets: false,
+            run: vec![],
+            run_ignore_errors: false,
         };
 
         let loaded_safe = session_manager.load_state("test-safe-session").unwrap();
-        let skip_permissions_2 = loaded_safe.dangerous_skip_permissions.unwrap_or(false)
-            || args_with_flag.dangerously_skip_permissions;
+        let skip_permissions_2 =
+            resolve_skip_permissions(&config, Some(&loaded_safe), &args_with_flag).unwrap();
 
         assert!(skip_permissions_2, "Should use dangerous flag from args");
+
+        // The explicitly passed flag is recorded, so later resumes keep it
+        let reloaded_safe = session_manager.load_state("test-safe-session").unwrap();
+        assert_eq!(reloaded_safe.dangerous_skip_permissions, Some(true));
+        let args_without_flag = ResumeArgs {
+            dangerously_skip_permissions: false,
+            ..args_with_flag
+        };
+        assert!(
+            resolve_skip_permissions(&config, Some(&reloaded_safe), &args_without_flag).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_forbid_skip_permissions_rejects_resume() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = create_test_config_with_dir(&temp_dir);
+        config.security = Some(crate::config::SecurityConfig {
+            forbid_skip_permissions: true,
+            ..Default::default()
+        });
+
+        let session_manager = SessionManager::new(&config);
+        let recorded = SessionState::with_parent_branch_and_flags(
+            "recorded-session".to_string(),
+            "test/recorded".to_string(),
+            temp_dir.path().join("recorded"),
+            "main".to_string(),
+            true,
+        );
+        session_manager.save_state(&recorded).unwrap();
+
+        let args = ResumeArgs {
+            session: Some("recorded-session".to_string()),
+            prompt: None,
+            file: None,
+            dangerously_skip_permissions: false,
+            sandbox_args: SandboxArgs {
+                sandbox: false,
+                no_sandbox: false,
+                sandbox_profile: None,
+                sandbox_no_network: false,
+                allowed_domains: vec![],
+            },
+            session_branch: None,
+            allow_secrets: false,
+            run: vec![],
+            run_ignore_errors: false,
+        };
+
+        // A session recorded with the flag cannot be resumed in a locked-down environment
+        let result = resolve_skip_permissions(&config, Some(&recorded), &args);
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("security.forbid_skip_permissions"));
+
+        // Neither can the flag be passed explicitly
+        let args_with_flag = ResumeArgs {
+            dangerously_skip_permissions: true,
+            ..args
+        };
+        assert!(resolve_skip_permissions(&config, None, &args_with_flag).is_err());
+
+        // Sessions without the flag resume normally
+        let args_without_flag = ResumeArgs {
+            dangerously_skip_permissions: false,
+            ..args_with_flag
+        };
+        assert!(!resolve_skip_permissions(&config, None, &args_without_flag).unwrap());
     }
 
     #[test]
@@ -949,12 +1236,9 @@ mod tests {
         // Create a test session
         let session_name = "test-sandbox-args".to_string();
         let branch_name = "para/test-sandbox".to_string();
-        let worktree_path = git_service
-            .repository()
-            .root
-            .join(&config.directories.subtrees_dir)
-            .join(&config.git.branch_prefix)
-            .join(&session_name);
+        let worktree_path =
+            SessionPaths::worktree_path(&config, &git_service.repository().root, &session_name)
+                .unwrap();
 
         git_service
             .create_worktree(&branch_name, &worktree_path)
@@ -976,6 +1260,10 @@ mod tests {
                 sandbox_no_network: true,                         // CLI arg: enable network sandbox
                 allowed_domains: vec!["api.claude.ai".to_string()], // CLI allowed domains
             },
+            session_branch: None,
+            allow_secrets: false,
+            run: vec![],
+            run_ignore_errors: false,
         };
 
         // Execute resume - this should now pass raw CLI args to claude_launcher
@@ -989,4 +1277,89 @@ mod tests {
         // The key verification is that the function completes without errors
         // The real test is that it doesn't do double resolution anymore
     }
+
+    #[test]
+    fn test_resume_with_no_sandbox_updates_state_and_launch() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = create_test_config_with_dir(&temp_dir);
+        config.ide.name = "claude".to_string();
+        config.ide.wrapper.enabled = true;
+
+        let session_manager = SessionManager::new(&config);
+        let worktree_path = temp_dir.path().join("sandboxed");
+        fs::create_dir_all(&worktree_path).unwrap();
+        let state = SessionState::with_all_flags(
+            "sandboxed".to_string(),
+            "test/sandboxed".to_string(),
+            worktree_path.clone(),
+            "main".to_string(),
+            false,
+            true,
+            Some("standard".to_string()),
+        );
+        session_manager.save_state(&state).unwrap();
+
+        let mut launched = Vec::new();
+        let mut launcher = |_: &Config, _: &Path, options: ClaudeLaunchOptions| {
+            launched.push(options);
+            Ok(())
+        };
+
+        // Without flags the recorded mode is replayed, whatever the config says
+        let args = ResumeArgs {
+            session: Some("sandboxed".to_string()),
+            prompt: None,
+            file: None,
+            dangerously_skip_permissions: false,
+            sandbox_args: SandboxArgs {
+                sandbox: false,
+                no_sandbox: false,
+                sandbox_profile: None,
+                sandbox_no_network: false,
+                allowed_domains: vec![],
+            },
+            session_branch: None,
+            allow_secrets: false,
+            run: vec![],
+            run_ignore_errors: false,
+        };
+        launch_ide_for_session_with(
+            &config,
+            &worktree_path,
+            &args,
+            None,
+            Some(&state),
+            &mut launcher,
+        )
+        .unwrap();
+
+        // --no-sandbox relaunches outside the sandbox and is recorded
+        let no_sandbox_args = ResumeArgs {
+            sandbox_args: SandboxArgs {
+                no_sandbox: true,
+                ..args.sandbox_args
+            },
+            ..args
+        };
+        launch_ide_for_session_with(
+            &config,
+            &worktree_path,
+            &no_sandbox_args,
+            None,
+            Some(&state),
+            &mut launcher,
+        )
+        .unwrap();
+
+        assert_eq!(launched.len(), 2);
+        assert_eq!(launched[0].sandbox_override, Some(true));
+        assert_eq!(launched[0].sandbox_profile.as_deref(), Some("standard"));
+        assert_eq!(launched[1].sandbox_override, Some(false));
+        assert_eq!(launched[1].sandbox_profile, None);
+        assert!(!launched[1].network_sandbox);
+
+        let updated = session_manager.load_state("sandboxed").unwrap();
+        assert_eq!(updated.sandbox_enabled, Some(false));
+        assert_eq!(updated.sandbox_profile, None);
+    }
 }
@@ -0,0 +1,26 @@
+use crate::cli::context::CommandContext;
+use crate::cli::parser::RollbackIntegrationArgs;
+use crate::core::session::integration_backup;
+use crate::utils::Result;
+
+pub fn execute(ctx: &CommandContext, args: RollbackIntegrationArgs) -> Result<()> {
+    let session_manager = ctx.session_manager();
+    let record = integration_backup::rollback(
+        &session_manager,
+        ctx.git_service()?,
+        args.session.as_deref(),
+    )?;
+
+    println!(
+        "✅ Reset '{}' to {} from before '{}' was integrated",
+        record.base_branch,
+        &record.pre_integration_sha[..record.pre_integration_sha.len().min(8)],
+        record.session
+    );
+    println!(
+        "   Session '{}' is active again on branch '{}'",
+        record.session, record.session_branch
+    );
+    println!("   Continue with: para resume {}", record.session);
+    Ok(())
+}
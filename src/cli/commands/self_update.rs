@@ -0,0 +1,94 @@
+use crate::cli::parser::SelfUpdateArgs;
+use crate::core::http::{CurlTransport, HttpClient};
+use crate::core::network::NetworkContext;
+use crate::core::self_update::{check_for_update, install_release, InstallMethod, UpdateCheck};
+use crate::utils::{ParaError, Result};
+
+pub fn execute(args: SelfUpdateArgs) -> Result<()> {
+    NetworkContext::current().require_online("checking for para updates")?;
+    let client = HttpClient::new(CurlTransport::default());
+    let (method, exe) = InstallMethod::current()?;
+    let check = check_for_update(&client, env!("CARGO_PKG_VERSION"))?;
+
+    let replaces_binary = matches!(method, InstallMethod::Binary { .. });
+    if args.check || !check.update_available() || !replaces_binary {
+        println!("{}", check_report(&check, &method));
+        return Ok(());
+    }
+
+    if method == (InstallMethod::Binary { writable: false }) {
+        return Err(ParaError::fs_error(format!(
+            "Cannot replace {}: its directory is not writable. Rerun with permission to write there, or download para {} from {}",
+            exe.display(),
+            check.latest.version(),
+            check.latest.html_url
+        )));
+    }
+
+    println!(
+        "⬇️  Updating para {} → {}",
+        check.current,
+        check.latest.version()
+    );
+    install_release(&client, &check.latest, &exe)?;
+    println!(
+        "✅ Updated {} to para {}",
+        exe.display(),
+        check.latest.version()
+    );
+    Ok(())
+}
+
+/// What `--check` prints, and what installs para cannot update itself see
+fn check_report(check: &UpdateCheck, method: &InstallMethod) -> String {
+    if !check.update_available() {
+        return format!("para {} is up to date", check.current);
+    }
+    format!(
+        "para {} is available (installed: {})\n   Release: {}\n   Update with: {}",
+        check.latest.version(),
+        check.current,
+        check.latest.html_url,
+        method.upgrade_command(&check.latest)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::http::MockTransport;
+    use crate::core::self_update::LATEST_RELEASE_URL;
+    use std::collections::HashMap;
+
+    fn check_against(tag: &str, current: &str) -> UpdateCheck {
+        let body = format!(
+            r#"{{"tag_name": "{tag}", "html_url": "https://github.com/2mawi2/para/releases/tag/{tag}"}}"#
+        );
+        let client = HttpClient::new(MockTransport(HashMap::from([(
+            LATEST_RELEASE_URL.to_string(),
+            body.into_bytes(),
+        )])));
+        check_for_update(&client, current).unwrap()
+    }
+
+    #[test]
+    fn test_check_report() {
+        let newer = check_against("v1.2.0", "1.1.38");
+        assert_eq!(
+            check_report(&newer, &InstallMethod::Homebrew),
+            "para 1.2.0 is available (installed: 1.1.38)\n   Release: https://github.com/2mawi2/para/releases/tag/v1.2.0\n   Update with: brew upgrade 2mawi2/tap/para"
+        );
+        assert!(check_report(&newer, &InstallMethod::Cargo)
+            .ends_with("cargo install --git https://github.com/2mawi2/para --tag v1.2.0 --force"));
+        assert!(
+            check_report(&newer, &InstallMethod::Binary { writable: true })
+                .ends_with("Update with: para self-update")
+        );
+
+        let same = check_against("v1.1.38", "1.1.38");
+        assert_eq!(
+            check_report(&same, &InstallMethod::Homebrew),
+            "para 1.1.38 is up to date"
+        );
+    }
+}
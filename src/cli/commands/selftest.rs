@@ -0,0 +1,310 @@
+//! `para selftest`: run the session lifecycle in a throwaway repository
+//!
+//! Meant for packagers and CI to check that an installed binary works on the
+//! host. Everything happens below a fresh temporary directory with an
+//! explicitly built config, so the user's config, state and repositories are
+//! never read or written.
+
+use crate::cli::commands::cancel::cancel_and_archive_session;
+use crate::cli::commands::clean;
+use crate::cli::parser::CleanArgs;
+use crate::config::Config;
+use crate::core::git::command::{self, GitCommandOptions};
+use crate::core::git::{GitOperations, GitService};
+use crate::core::session::recovery::{RecoveryOptions, SessionRecovery};
+use crate::core::session::{SessionManager, SessionState, SessionStatus};
+use crate::utils::progress::format_elapsed;
+use crate::utils::{ParaError, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+const FINISH_SESSION: &str = "selftest-finish";
+const CANCEL_SESSION: &str = "selftest-cancel";
+const COMMIT_MESSAGE: &str = "selftest: add feature";
+
+pub fn execute() -> Result<()> {
+    let root = std::env::temp_dir().join(format!("para-selftest-{}", uuid::Uuid::new_v4()));
+    println!("🧪 Running para self-test in {}", root.display());
+
+    let result = run(&root);
+    if let Err(e) = fs::remove_dir_all(&root) {
+        eprintln!("⚠️  Failed to remove {}: {e}", root.display());
+    }
+
+    match result {
+        Ok(()) => {
+            println!("✅ Self-test passed");
+            Ok(())
+        }
+        Err(e) => Err(ParaError::invalid_args(format!("Self-test failed: {e}"))),
+    }
+}
+
+/// Run every lifecycle step below `root`, stopping at the first failure
+pub fn run(root: &Path) -> Result<()> {
+    let repo_path = step("Create throwaway repository", || init_repository(root))?;
+    let config = step("Write isolated config", || write_config(root))?;
+    let git_service = GitService::discover_from(&repo_path)?;
+    let mut session_manager = SessionManager::for_repository(&config, &repo_path);
+
+    let session = step("Start session", || {
+        start_session(&mut session_manager, FINISH_SESSION)
+    })?;
+    step("Write files in the session", || {
+        write_and_commit(&session, "feature.txt", "feature\n")?;
+        fs::write(session.worktree_path.join("pending.txt"), "pending\n")
+            .map_err(|e| ParaError::fs_error(format!("Failed to write pending.txt: {e}")))
+    })?;
+    step("Finish session", || {
+        finish_session(&git_service, &mut session_manager, &session)
+    })?;
+    step("Verify squashed commit", || {
+        verify_squashed(&git_service, &session)
+    })?;
+
+    step("Cancel session", || {
+        let session = start_session(&mut session_manager, CANCEL_SESSION)?;
+        write_and_commit(&session, "cancelled.txt", "cancelled\n")?;
+        cancel_and_archive_session(
+            &config,
+            &git_service,
+            &mut session_manager,
+            CANCEL_SESSION,
+            true,
+            false,
+            false,
+        )?;
+        ensure(
+            !session_manager.session_exists(CANCEL_SESSION),
+            "cancelled session still has state",
+        )?;
+        Ok(())
+    })?;
+    step("Recover session", || {
+        SessionRecovery::new(&config, &git_service, &session_manager)
+            .recover_session(CANCEL_SESSION, RecoveryOptions::default())?;
+        let recovered = session_manager.load_state(CANCEL_SESSION)?;
+        ensure(
+            recovered.worktree_path.join("cancelled.txt").exists(),
+            "recovered worktree is missing cancelled.txt",
+        )?;
+        let subject = git(&repo_path, &["log", "-1", "--format=%s", &recovered.branch])?;
+        ensure(
+            subject == "wip: cancelled.txt",
+            &format!("recovered branch {} lost its commits", recovered.branch),
+        )
+    })?;
+    step("Clean", || {
+        clean::execute_in(
+            GitService::discover_from(&repo_path)?,
+            config.clone(),
+            CleanArgs {
+                force: true,
+                dry_run: false,
+                backups: false,
+                containers: false,
+                docker: false,
+                sessions: true,
+                close_ide: false,
+                only: Vec::new(),
+                skip: Vec::new(),
+                empty_trash: false,
+                list_trash: false,
+                interactive: false,
+            },
+        )?;
+        ensure(
+            session_manager.list_sessions()?.is_empty(),
+            "sessions are left after clean",
+        )?;
+        ensure(
+            git_service.list_worktrees()?.len() == 1,
+            "session worktrees are left after clean",
+        )?;
+        // The finished work survives in the archive
+        let archived = git_service
+            .branch_manager()
+            .list_archived_branches(&config.git.branch_prefix)?;
+        let finished = archived
+            .iter()
+            .find(|branch| branch.ends_with(&format!("/{FINISH_SESSION}")))
+            .ok_or_else(|| ParaError::git_operation("finished branch was not archived"))?;
+        verify_branch_files(&repo_path, finished)
+    })?;
+
+    Ok(())
+}
+
+fn step<T>(name: &str, f: impl FnOnce() -> Result<T>) -> Result<T> {
+    let started = Instant::now();
+    let result = f();
+    let elapsed = format_elapsed(started.elapsed());
+    match &result {
+        Ok(_) => println!("  ✅ {name} ({elapsed})"),
+        Err(e) => println!("  ❌ {name} ({elapsed}): {e}"),
+    }
+    result.map_err(|e| ParaError::invalid_args(format!("{name}: {e}")))
+}
+
+fn ensure(condition: bool, failure: &str) -> Result<()> {
+    if condition {
+        Ok(())
+    } else {
+        Err(ParaError::invalid_args(failure))
+    }
+}
+
+fn git(dir: &Path, args: &[&str]) -> Result<String> {
+    let output = command::output(dir, args, GitCommandOptions::default())?;
+    if !output.status.success() {
+        return Err(ParaError::git_operation(format!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+fn init_repository(root: &Path) -> Result<PathBuf> {
+    let repo = root.join("repo");
+    fs::create_dir_all(&repo)
+        .map_err(|e| ParaError::fs_error(format!("Failed to create {}: {e}", repo.display())))?;
+    fs::write(repo.join("README.md"), "# para self-test\n")
+        .map_err(|e| ParaError::fs_error(format!("Failed to write README.md: {e}")))?;
+
+    for args in [
+        &["init", "--quiet"][..],
+        &["symbolic-ref", "HEAD", "refs/heads/main"],
+        &["config", "user.name", "para selftest"],
+        &["config", "user.email", "selftest@para.invalid"],
+        &["config", "commit.gpgsign", "false"],
+        &["add", "README.md"],
+        &["commit", "--quiet", "-m", "Initial commit"],
+    ] {
+        git(&repo, args)?;
+    }
+    Ok(repo)
+}
+
+fn write_config(root: &Path) -> Result<Config> {
+    let mut config = crate::config::defaults::default_config();
+    config.ide.name = "echo".to_string();
+    config.ide.command = "echo".to_string();
+    config.ide.wrapper.enabled = false;
+    config.ide.wrapper.command = "echo".to_string();
+    config.directories.state_dir = root.join("state").to_string_lossy().to_string();
+    config.directories.subtrees_dir = ".para/worktrees".to_string();
+    config.git.branch_prefix = "para".to_string();
+    config.git.auto_commit_interval_minutes = None;
+    config.git.audit_log = false;
+
+    // Creating the state directory up front keeps the session manager from
+    // looking for a repository at the current directory
+    fs::create_dir_all(&config.directories.state_dir)
+        .map_err(|e| ParaError::fs_error(format!("Failed to create state directory: {e}")))?;
+    let json = serde_json::to_string_pretty(&config)
+        .map_err(|e| ParaError::config_error(format!("Failed to serialize config: {e}")))?;
+    fs::write(root.join("config.json"), json)
+        .map_err(|e| ParaError::fs_error(format!("Failed to write config.json: {e}")))?;
+    Ok(config)
+}
+
+fn start_session(session_manager: &mut SessionManager, name: &str) -> Result<SessionState> {
+    let session = session_manager.create_session_with_all_flags(
+        name.to_string(),
+        None,
+        false,
+        false,
+        None,
+    )?;
+    ensure(
+        session.worktree_path.is_dir(),
+        "session worktree was not created",
+    )?;
+    Ok(session)
+}
+
+fn write_and_commit(session: &SessionState, file: &str, content: &str) -> Result<()> {
+    fs::write(session.worktree_path.join(file), content)
+        .map_err(|e| ParaError::fs_error(format!("Failed to write {file}: {e}")))?;
+    git(&session.worktree_path, &["add", file])?;
+    git(
+        &session.worktree_path,
+        &["commit", "--quiet", "-m", &format!("wip: {file}")],
+    )?;
+    Ok(())
+}
+
+fn finish_session(
+    git_service: &GitService,
+    session_manager: &mut SessionManager,
+    session: &SessionState,
+) -> Result<()> {
+    GitService::discover_from(&session.worktree_path)?
+        .finish_session(session.finish_request(COMMIT_MESSAGE.to_string(), None))?;
+    git_service.remove_worktree(&session.worktree_path)?;
+    session_manager.update_session_status(&session.name, SessionStatus::Review)
+}
+
+fn verify_squashed(git_service: &GitService, session: &SessionState) -> Result<()> {
+    let repo = &git_service.repository().root;
+    let base = session
+        .base_commit
+        .as_deref()
+        .ok_or_else(|| ParaError::invalid_args("session has no base commit"))?;
+
+    let count = git(
+        repo,
+        &[
+            "rev-list",
+            "--count",
+            &format!("{base}..{}", session.branch),
+        ],
+    )?;
+    ensure(
+        count == "1",
+        &format!("expected 1 commit on {}, found {count}", session.branch),
+    )?;
+    let subject = git(repo, &["log", "-1", "--format=%s", &session.branch])?;
+    ensure(
+        subject == COMMIT_MESSAGE,
+        &format!("unexpected commit message '{subject}'"),
+    )?;
+    verify_branch_files(repo, &session.branch)
+}
+
+fn verify_branch_files(repo: &Path, branch: &str) -> Result<()> {
+    let files = git(repo, &["ls-tree", "--name-only", branch])?;
+    let files: Vec<&str> = files.lines().collect();
+    ensure(
+        files == ["README.md", "feature.txt", "pending.txt"],
+        &format!("unexpected files on {branch}: {}", files.join(", ")),
+    )?;
+    let feature = git(repo, &["show", &format!("{branch}:feature.txt")])?;
+    ensure(
+        feature == "feature",
+        &format!("unexpected feature.txt on {branch}"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_selftest_lifecycle_passes() {
+        let temp = TempDir::new().unwrap();
+        run(temp.path()).unwrap();
+
+        let config: Config =
+            serde_json::from_str(&fs::read_to_string(temp.path().join("config.json")).unwrap())
+                .unwrap();
+        assert!(config
+            .directories
+            .state_dir
+            .starts_with(&*temp.path().to_string_lossy()));
+    }
+}
@@ -1,10 +1,19 @@
-use crate::cli::commands::common::create_claude_local_md;
+use crate::cli::commands::common::{
+    apply_starting_changes, check_skip_permissions_allowed, copy_untracked_into_worktree,
+    ensure_repository_has_commits, fix_worktree_hooks_path, record_merge_mode, record_template,
+    setup_auto_checkpoints, write_claude_local_md, ClaudeLocalContext,
+};
+use crate::cli::context::CommandContext;
 use crate::cli::parser::StartArgs;
 use crate::config::Config;
-use crate::core::ide::IdeManager;
+use crate::core::ide::{ensure_remote_supports_container, IdeManager};
+use crate::core::logs;
 use crate::core::sandbox::config::SandboxResolver;
+use crate::core::session::rollback::CreationRollback;
 use crate::core::session::SessionManager;
-use crate::utils::{generate_unique_name, validate_session_name, Result};
+use crate::ui::theme;
+use crate::utils::output::path_link;
+use crate::utils::{validate_session_name, Result};
 use std::path::{Path, PathBuf};
 
 /// Determine which setup script to use based on priority order
@@ -90,13 +99,20 @@ fn run_worktree_setup_script(
     script_path: &Path,
     session_name: &str,
     worktree_path: &Path,
+    state_dir: &Path,
 ) -> Result<()> {
     use std::process::Command;
 
-    println!("🔧 Running setup script: {}", script_path.display());
+    println!(
+        "{}",
+        theme::action(format!("Running setup script: {}", script_path.display()))
+    );
 
     // Security warning
-    eprintln!("⚠️  Warning: Setup scripts run with your full user permissions!");
+    eprintln!(
+        "{}",
+        theme::warning("Warning: Setup scripts run with your full user permissions!")
+    );
     eprintln!("   Only run scripts from trusted sources.");
     eprintln!("   Script: {}", script_path.display());
 
@@ -108,32 +124,43 @@ fn run_worktree_setup_script(
     cmd.env("PARA_WORKSPACE", worktree_path);
     cmd.env("PARA_SESSION", session_name);
 
-    let status = cmd.status().map_err(|e| {
-        crate::utils::ParaError::ide_error(format!("Failed to execute setup script: {e}"))
+    // Captured for `para logs`
+    let log_path = logs::setup_log_path(state_dir, session_name);
+    let status = logs::run_logged(&mut cmd, &log_path).map_err(|e| {
+        crate::utils::ParaError::setup_script(script_path, format!("could not run it: {e}"))
     })?;
 
     if !status.success() {
-        return Err(crate::utils::ParaError::ide_error(format!(
-            "Setup script failed with exit code: {}",
-            status.code().unwrap_or(-1)
-        )));
+        return Err(crate::utils::ParaError::setup_script(
+            script_path,
+            format!(
+                "exit code {}, output in {}",
+                status.code().unwrap_or(-1),
+                log_path.display()
+            ),
+        ));
     }
 
-    println!("✅ Setup script completed successfully");
+    println!("{}", theme::success("Setup script completed successfully"));
     Ok(())
 }
 
-pub fn execute(config: Config, args: StartArgs) -> Result<()> {
+pub fn execute(ctx: &CommandContext, args: StartArgs) -> Result<()> {
+    let config = ctx.config();
     args.validate()?;
+    check_skip_permissions_allowed(config, args.dangerously_skip_permissions)?;
+    ensure_remote_supports_container(config, args.container)?;
 
-    let git_service = crate::core::git::GitService::discover().map_err(|e| {
+    let git_service = ctx.git_service().map_err(|e| {
         crate::utils::ParaError::git_error(format!("Failed to discover git repository: {e}"))
     })?;
+    ensure_repository_has_commits(git_service.repository(), args.allow_empty_repo)?;
     let repo_root = git_service.repository().root.clone();
 
-    let mut session_manager = SessionManager::new(&config);
+    let mut session_manager = ctx.session_manager();
 
     let session_name = determine_session_name(&args, &session_manager)?;
+    session_manager.check_storage(&repo_root)?;
 
     // Track whether we're using Docker and network isolation settings
     let (is_container, network_isolation, _allowed_domains) = if args.container {
@@ -157,20 +184,35 @@ pub fn execute(config: Config, args: StartArgs) -> Result<()> {
             args.docker_image.clone(),
             !args.no_forward_keys,
         );
-        let session = session_manager.create_docker_session_with_flags(
+        let mut session = session_manager.create_docker_session_with_flags(
             session_name.clone(),
             &docker_manager,
             None,
             &args.docker_args,
             args.dangerously_skip_permissions,
         )?;
+        let rollback =
+            CreationRollback::for_session(git_service, session_manager.state_dir(), &session);
+
+        copy_untracked_into_worktree(
+            config,
+            &repo_root,
+            &session.worktree_path,
+            &args.copy,
+            args.copy_large,
+        )?;
+        fix_worktree_hooks_path(&repo_root, &session.worktree_path, args.no_hook_fixup);
+        record_merge_mode(&session_manager, &mut session, args.merge_mode)?;
+        record_template(&session_manager, &mut session, args.template.as_deref())?;
+        setup_auto_checkpoints(config, &session_manager, &session, args.no_auto_commit)?;
 
         // Create CLAUDE.local.md in the session directory
-        create_claude_local_md(&session.worktree_path, &session.name)?;
+        write_claude_local_md(&ClaudeLocalContext::for_session(&session))?;
+        rollback.commit();
 
         // Run setup script if specified
         if let Some(setup_script) =
-            get_setup_script_path(&args.setup_script, &repo_root, &config, true)
+            get_setup_script_path(&args.setup_script, &repo_root, config, true)
         {
             docker_manager
                 .run_setup_script(&session.name, &setup_script)
@@ -192,7 +234,7 @@ pub fn execute(config: Config, args: StartArgs) -> Result<()> {
         if let Err(e) = crate::core::daemon::client::register_container_session(
             &session.name,
             &session.worktree_path,
-            &config,
+            config,
         ) {
             eprintln!("Warning: Failed to register with daemon: {e}");
             // Continue anyway - daemon might not be running
@@ -201,7 +243,7 @@ pub fn execute(config: Config, args: StartArgs) -> Result<()> {
         (true, network_isolation, allowed_domains)
     } else {
         // Resolve sandbox settings using the resolver
-        let resolver = SandboxResolver::new(&config);
+        let resolver = SandboxResolver::new(config);
         let sandbox_settings = resolver.resolve_with_network(
             args.sandbox_args.sandbox,
             args.sandbox_args.no_sandbox,
@@ -211,7 +253,7 @@ pub fn execute(config: Config, args: StartArgs) -> Result<()> {
         );
 
         // Create regular worktree session with sandbox settings
-        let session = session_manager.create_session_with_all_flags(
+        let mut session = session_manager.create_session_with_all_flags(
             session_name.clone(),
             None,
             args.dangerously_skip_permissions,
@@ -222,17 +264,44 @@ pub fn execute(config: Config, args: StartArgs) -> Result<()> {
                 None
             },
         )?;
+        let rollback =
+            CreationRollback::for_session(git_service, session_manager.state_dir(), &session);
+        apply_starting_changes(
+            git_service,
+            &session_manager,
+            &session,
+            args.apply_patch.as_deref(),
+            args.apply_from.as_deref(),
+        )?;
 
-        create_claude_local_md(&session.worktree_path, &session.name)?;
+        copy_untracked_into_worktree(
+            config,
+            &repo_root,
+            &session.worktree_path,
+            &args.copy,
+            args.copy_large,
+        )?;
+        fix_worktree_hooks_path(&repo_root, &session.worktree_path, args.no_hook_fixup);
+        record_merge_mode(&session_manager, &mut session, args.merge_mode)?;
+        record_template(&session_manager, &mut session, args.template.as_deref())?;
+        setup_auto_checkpoints(config, &session_manager, &session, args.no_auto_commit)?;
+
+        write_claude_local_md(&ClaudeLocalContext::for_session(&session))?;
+        rollback.commit();
 
         // Run setup script if specified
         if let Some(setup_script) =
-            get_setup_script_path(&args.setup_script, &repo_root, &config, false)
+            get_setup_script_path(&args.setup_script, &repo_root, config, false)
         {
-            run_worktree_setup_script(&setup_script, &session.name, &session.worktree_path)?;
+            run_worktree_setup_script(
+                &setup_script,
+                &session.name,
+                &session.worktree_path,
+                session_manager.state_dir(),
+            )?;
         }
 
-        let ide_manager = IdeManager::new(&config);
+        let ide_manager = IdeManager::new(config);
         let launch_options = crate::core::ide::LaunchOptions {
             skip_permissions: args.dangerously_skip_permissions,
             sandbox_override: Some(sandbox_settings.enabled),
@@ -256,7 +325,10 @@ pub fn execute(config: Config, args: StartArgs) -> Result<()> {
         .find(|s| s.name == session_name)
         .ok_or_else(|| crate::utils::ParaError::session_not_found(&session_name))?;
 
-    println!("✅ Session '{session_name}' started successfully");
+    println!(
+        "{}",
+        theme::success(format!("Session '{session_name}' started successfully"))
+    );
     if is_container {
         println!("   Container: para-{session_name}");
 
@@ -271,19 +343,25 @@ pub fn execute(config: Config, args: StartArgs) -> Result<()> {
 
         // Show network isolation warning if it's disabled
         if !network_isolation {
-            println!("   ⚠️  Network isolation: OFF (use --allow-domains to enable)");
+            println!(
+                "   {}",
+                theme::warning("Network isolation: OFF (use --allow-domains to enable)")
+            );
         }
 
         // Show API key warning if forwarding keys to custom images
         if !args.no_forward_keys && args.docker_image.is_some() {
             println!(
-                "   ⚠️  API keys: Forwarding to custom image (use --no-forward-keys to disable)"
+                "   {}",
+                theme::warning(
+                    "API keys: Forwarding to custom image (use --no-forward-keys to disable)"
+                )
             );
             println!("      Security: Only use trusted images when forwarding API keys");
         }
     }
     println!("   Branch: {}", session_state.branch);
-    println!("   Worktree: {}", session_state.worktree_path.display());
+    println!("   Worktree: {}", path_link(&session_state.worktree_path));
     println!("   IDE: {} launched", config.ide.name);
 
     Ok(())
@@ -295,14 +373,7 @@ fn determine_session_name(args: &StartArgs, session_manager: &SessionManager) ->
             validate_session_name(name)?;
             Ok(name.clone())
         }
-        None => {
-            let existing_sessions = session_manager
-                .list_sessions()?
-                .into_iter()
-                .map(|s| s.name)
-                .collect::<Vec<String>>();
-            Ok(generate_unique_name(&existing_sessions))
-        }
+        None => session_manager.generate_session_name(),
     }
 }
 
@@ -327,6 +398,7 @@ mod tests {
                     name: "test-wrapper".to_string(),
                     command: "echo".to_string(),
                 },
+                remote: None,
             },
             directories: DirectoryConfig {
                 subtrees_dir: temp_dir
@@ -339,20 +411,47 @@ mod tests {
                     .join(".para_state")
                     .to_string_lossy()
                     .to_string(),
+                bare_root: None,
+                layout: Default::default(),
             },
             git: GitConfig {
                 branch_prefix: "test".to_string(),
                 auto_stage: true,
                 auto_commit: false,
+                author_email: None,
+                author_name: None,
+                protected_branches: Vec::new(),
+                auto_commit_interval_minutes: None,
+                default_merge_mode: None,
+                command_timeout_secs: None,
+                audit_log: false,
+                default_remote: None,
+                auto_fetch_interval_minutes: None,
+                auto_fetch: false,
+                apply_whitespace: Default::default(),
+                behind_warning_commits: None,
             },
             session: SessionConfig {
                 default_name_format: "%Y%m%d-%H%M%S".to_string(),
                 preserve_on_finish: false,
                 auto_cleanup_days: Some(7),
+                retention: None,
+                copy_untracked: Vec::new(),
+                idle_action: Default::default(),
+                idle_alert_hours: None,
+                conflict_hotspots: false,
+                on_resume: Vec::new(),
             },
             docker: None,
             setup_script: None,
             sandbox: None,
+            notifications: None,
+            security: None,
+            status: None,
+            templates: Default::default(),
+            cleanup: None,
+            vcs: None,
+            ui: None,
         }
     }
 
@@ -378,6 +477,15 @@ mod tests {
                 sandbox_no_network: false,
                 allowed_domains: vec![],
             },
+            allow_empty_repo: false,
+            copy: vec![],
+            copy_large: false,
+            no_auto_commit: false,
+            merge_mode: None,
+            apply_patch: None,
+            apply_from: None,
+            template: None,
+            no_hook_fixup: false,
         };
 
         let result = determine_session_name(&args, &session_manager).unwrap();
@@ -387,8 +495,9 @@ mod tests {
     #[test]
     fn test_determine_session_name_auto_generate() {
         let temp_dir = TempDir::new().unwrap();
+        let (git_temp, _git_service) = crate::test_utils::test_helpers::setup_test_repo();
         let config = create_simple_test_config(&temp_dir);
-        let session_manager = SessionManager::new(&config);
+        let session_manager = SessionManager::for_repository(&config, git_temp.path());
 
         let args = StartArgs {
             name: None,
@@ -406,6 +515,15 @@ mod tests {
                 sandbox_no_network: false,
                 allowed_domains: vec![],
             },
+            allow_empty_repo: false,
+            copy: vec![],
+            copy_large: false,
+            no_auto_commit: false,
+            merge_mode: None,
+            apply_patch: None,
+            apply_from: None,
+            template: None,
+            no_hook_fixup: false,
         };
 
         let result = determine_session_name(&args, &session_manager).unwrap();
@@ -1,23 +1,38 @@
-use crate::cli::parser::{StatusArgs, StatusCommands};
-use crate::config::Config;
-use crate::core::session::SessionManager;
-use crate::core::status::{DiffStats, Status};
-use crate::utils::{get_main_repository_root, ParaError, Result};
+use crate::cli::context::CommandContext;
+use crate::cli::parser::{StatusArgs, StatusCommands, TodoCommands};
+use crate::core::notifications::{notify, NotificationEvent};
+use crate::core::session::links::SessionLink;
+use crate::core::session::{manifest, SessionManager, SessionState};
+use crate::core::status::{
+    format_status_age, is_status_outdated, parse_todo_list, DiffStats, Status, TestStatus,
+    WaitCondition,
+};
+use crate::ui::monitor::activity::detect_last_activity;
+use crate::utils::{ParaError, Result};
 use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant};
 
-pub fn execute(config: Config, args: StatusArgs) -> Result<()> {
+pub fn execute(ctx: &CommandContext, args: StatusArgs) -> Result<()> {
     match args.command {
-        Some(StatusCommands::Show { session, json }) => show_status(config, session, json),
-        Some(StatusCommands::Summary { json }) => show_summary(config, json),
-        Some(StatusCommands::Cleanup { dry_run }) => cleanup_status(config, dry_run),
+        Some(StatusCommands::Show { session, json }) => show_status(ctx, session, json),
+        Some(StatusCommands::Summary { json }) => show_summary(ctx, json),
+        Some(StatusCommands::Cleanup { dry_run }) => cleanup_status(ctx, dry_run),
+        Some(StatusCommands::Wait {
+            session,
+            until,
+            interval,
+            timeout,
+        }) => wait_for_status(ctx, &session, &until, interval, timeout),
+        Some(StatusCommands::Todo { command }) => execute_todo(ctx, command),
         None => {
             // Handle the original update status functionality
-            update_status(config, args)
+            update_status(ctx, args)
         }
     }
 }
 
-fn update_status(config: Config, args: StatusArgs) -> Result<()> {
+fn update_status(ctx: &CommandContext, args: StatusArgs) -> Result<()> {
     // Validate required arguments for update
     let task = args.task.ok_or_else(|| {
         ParaError::invalid_args("Task description is required when updating status")
@@ -28,31 +43,8 @@ fn update_status(config: Config, args: StatusArgs) -> Result<()> {
     })?;
 
     // Detect session from current directory or use provided session name
-    let session_manager = SessionManager::new(&config);
-
-    let session_name = match args.session {
-        Some(name) => name,
-        None => {
-            // Try to detect session from current directory
-            let current_dir = std::env::current_dir().map_err(|e| {
-                ParaError::fs_error(format!("Failed to get current directory: {e}"))
-            })?;
-
-            match session_manager.find_session_by_path(&current_dir)? {
-                Some(session) => session.name,
-                None => {
-                    return Err(ParaError::invalid_args(
-                        "Not in a para session directory. Use --session to specify session name.",
-                    ));
-                }
-            }
-        }
-    };
-
-    // Verify session exists
-    if !session_manager.session_exists(&session_name) {
-        return Err(ParaError::session_not_found(&session_name));
-    }
+    let session_manager = ctx.session_manager();
+    let session_name = detect_status_session(&session_manager, ctx.current_dir(), args.session)?;
 
     // Check if session is in Review state
     if let Ok(session_state) = session_manager.load_state(&session_name) {
@@ -76,8 +68,15 @@ fn update_status(config: Config, args: StatusArgs) -> Result<()> {
         Err(_) => None, // Session not found or error loading
     };
 
+    let state_dir = status_state_dir(ctx)?;
+    let previous = Status::load(&state_dir, &session_name).ok().flatten();
+
     // Create status object
     let mut status = Status::new(session_name.clone(), task, test_status);
+    // Todos persist across updates; ids of unchanged items stay the same
+    if let Some(previous) = previous {
+        status = status.with_todo_list(previous.todos);
+    }
 
     // Add diff stats if available
     if let Some(stats) = diff_stats {
@@ -86,9 +85,9 @@ fn update_status(config: Config, args: StatusArgs) -> Result<()> {
 
     // Handle optional todos
     if let Some(todos_str) = args.todos {
-        let (completed, total) =
-            Status::parse_todos(&todos_str).map_err(|e| ParaError::invalid_args(e.to_string()))?;
-        status = status.with_todos(completed, total);
+        let todos =
+            parse_todo_list(&todos_str).map_err(|e| ParaError::invalid_args(e.to_string()))?;
+        status.set_todos(todos);
     }
 
     // Handle blocked state
@@ -99,22 +98,128 @@ fn update_status(config: Config, args: StatusArgs) -> Result<()> {
     }
 
     // Save status to file in the main repository's state directory
-    let state_dir = if Path::new(&config.directories.state_dir).is_absolute() {
-        // If state_dir is already absolute (e.g., in tests), use it directly
-        PathBuf::from(&config.directories.state_dir)
-    } else {
-        // Otherwise, resolve it relative to the main repo root
-        let repo_root = get_main_repository_root()
-            .map_err(|e| ParaError::git_error(format!("Not in a para repository: {e}")))?;
-        repo_root.join(&config.directories.state_dir)
-    };
-
     status
         .save(&state_dir)
         .map_err(|e| ParaError::config_error(e.to_string()))?;
 
     println!("Status updated for session '{session_name}'");
 
+    if let Some(reason) = status.blocked_reason {
+        notify(
+            ctx.config(),
+            NotificationEvent::Blocked {
+                session: session_name,
+                reason,
+            },
+        );
+    }
+
+    Ok(())
+}
+
+/// The session named by `--session`, or the one whose worktree contains the
+/// current directory
+fn detect_status_session(
+    session_manager: &SessionManager,
+    current_dir: &Path,
+    session: Option<String>,
+) -> Result<String> {
+    let session_name = match session {
+        Some(name) => return Ok(session_manager.resolve_session(&name)?.name),
+        None => {
+            // Inside a container the state directory may not be visible, so
+            // the worktree's manifest is trusted as is
+            if let Some(manifest) = manifest::find(current_dir) {
+                return Ok(manifest.name);
+            }
+
+            match session_manager.find_session_by_path(current_dir)? {
+                Some(session) => session.name,
+                None => {
+                    return Err(ParaError::invalid_args(
+                        "Not in a para session directory. Use --session to specify session name.",
+                    ));
+                }
+            }
+        }
+    };
+
+    if !session_manager.session_exists(&session_name) {
+        return Err(ParaError::session_not_found(&session_name));
+    }
+    Ok(session_name)
+}
+
+/// Status files live in the main repository's state directory
+fn status_state_dir(ctx: &CommandContext) -> Result<PathBuf> {
+    let state_dir = &ctx.config().directories.state_dir;
+    if Path::new(state_dir).is_absolute() {
+        // If state_dir is already absolute (e.g., in tests), use it directly
+        return Ok(PathBuf::from(state_dir));
+    }
+    // Otherwise, resolve it relative to the main repo root
+    ctx.git_service()
+        .map_err(|e| ParaError::git_error(format!("Not in a para repository: {e}")))?;
+    Ok(ctx.session_manager().state_dir().clone())
+}
+
+fn execute_todo(ctx: &CommandContext, command: TodoCommands) -> Result<()> {
+    let session_manager = ctx.session_manager();
+    let state_dir = status_state_dir(ctx)?;
+    let load = |session: Option<String>| -> Result<Status> {
+        let session_name = detect_status_session(&session_manager, ctx.current_dir(), session)?;
+        let status = Status::load(&state_dir, &session_name)
+            .map_err(|e| ParaError::config_error(e.to_string()))?;
+        Ok(status.unwrap_or_else(|| Status::new(session_name, String::new(), TestStatus::Unknown)))
+    };
+    let save = |status: &mut Status| -> Result<()> {
+        status.updated_at = chrono::Utc::now();
+        status
+            .save(&state_dir)
+            .map_err(|e| ParaError::config_error(e.to_string()))
+    };
+
+    match command {
+        TodoCommands::Add { text, session } => {
+            let text = text.trim();
+            if text.is_empty() {
+                return Err(ParaError::invalid_args("Todo text cannot be empty"));
+            }
+            let mut status = load(session)?;
+            let id = status.add_todo(text);
+            save(&mut status)?;
+            println!("Added todo {id}: {text}");
+        }
+        TodoCommands::Done { id, session } => {
+            let mut status = load(session)?;
+            let text = status
+                .complete_todo(id)
+                .map_err(|e| ParaError::invalid_args(e.to_string()))?
+                .text
+                .clone();
+            save(&mut status)?;
+            println!(
+                "Completed todo {id}: {text} ({})",
+                status.todo_fraction().unwrap_or_default()
+            );
+        }
+        TodoCommands::List { session, json } => {
+            let status = load(session)?;
+            if json {
+                println!("{}", serde_json::to_string_pretty(&status.todos)?);
+            } else if status.todos.is_empty() {
+                println!("No todos for session '{}'", status.session_name);
+            } else {
+                for item in &status.todos {
+                    let mark = if item.done { "x" } else { " " };
+                    println!("[{mark}] {:>3}  {}", item.id, item.text);
+                }
+                if let Some(fraction) = status.todo_fraction() {
+                    println!("\n{fraction} done");
+                }
+            }
+        }
+    }
     Ok(())
 }
 
@@ -126,34 +231,51 @@ fn calculate_diff_stats_for_session(
         .or(Ok(None))
 }
 
+/// `status show --json` output: the agent status plus its age, the
+/// session's permission mode and its links
+#[derive(serde::Serialize)]
+struct StatusJson<'a> {
+    #[serde(flatten)]
+    status: &'a Status,
+    age_seconds: i64,
+    stale: bool,
+    dangerous_skip_permissions: bool,
+    #[serde(skip_serializing_if = "<[SessionLink]>::is_empty")]
+    links: &'a [SessionLink],
+}
+
+impl<'a> StatusJson<'a> {
+    fn new(
+        status: &'a Status,
+        session_state: Option<&'a SessionState>,
+        stale_after_minutes: u64,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Self {
+        Self {
+            status,
+            age_seconds: status.age(now).num_seconds(),
+            stale: is_status_outdated(status.updated_at, now, stale_after_minutes),
+            dangerous_skip_permissions: session_state.is_some_and(|s| s.skips_permissions()),
+            links: session_state.map_or(&[], |s| s.links.as_slice()),
+        }
+    }
+}
+
 struct StatusDisplayHandler {
     session_manager: SessionManager,
     state_dir: PathBuf,
+    stale_after_minutes: u64,
 }
 
 impl StatusDisplayHandler {
-    fn new(config: Config) -> Result<Self> {
-        let state_dir = Self::resolve_state_directory(&config)?;
-        let session_manager = SessionManager::new(&config);
-
+    fn new(ctx: &CommandContext) -> Result<Self> {
         Ok(Self {
-            session_manager,
-            state_dir,
+            state_dir: status_state_dir(ctx)?,
+            session_manager: ctx.session_manager(),
+            stale_after_minutes: ctx.config().get_status_stale_after_minutes(),
         })
     }
 
-    fn resolve_state_directory(config: &Config) -> Result<PathBuf> {
-        if Path::new(&config.directories.state_dir).is_absolute() {
-            // If state_dir is already absolute (e.g., in tests), use it directly
-            Ok(PathBuf::from(&config.directories.state_dir))
-        } else {
-            // Otherwise, resolve it relative to the main repo root
-            let repo_root = get_main_repository_root()
-                .map_err(|e| ParaError::git_error(format!("Not in a para repository: {e}")))?;
-            Ok(repo_root.join(&config.directories.state_dir))
-        }
-    }
-
     fn show_specific_session(&self, session_name: &str, json: bool) -> Result<()> {
         let mut status = Status::load(&self.state_dir, session_name)
             .map_err(|e| ParaError::config_error(e.to_string()))?;
@@ -178,11 +300,8 @@ impl StatusDisplayHandler {
                                 s.test_status = test_status;
                             }
                         }
-                        if let Some(todos) = container_status.todos {
-                            if let Ok((completed, total)) = Status::parse_todos(&todos) {
-                                s.todos_completed = Some(completed);
-                                s.todos_total = Some(total);
-                            }
+                        if !container_status.todos.is_empty() {
+                            s.set_todos(container_status.todos);
                         }
                         s.blocked_reason = if container_status.blocked {
                             Some(s.current_task.clone())
@@ -193,7 +312,7 @@ impl StatusDisplayHandler {
                         if let Ok(parsed_time) =
                             chrono::DateTime::parse_from_rfc3339(&container_status.timestamp)
                         {
-                            s.last_update = parsed_time.with_timezone(&chrono::Utc);
+                            s.updated_at = parsed_time.with_timezone(&chrono::Utc);
                         }
 
                         // Calculate diff stats on the host
@@ -209,17 +328,24 @@ impl StatusDisplayHandler {
 
         match status {
             Some(mut s) => {
+                let session_state = self.session_manager.load_state(session_name).ok();
                 // Try to enrich with diff stats if we have session state
-                if let Ok(session_state) = self.session_manager.load_state(session_name) {
-                    if let Ok(Some(diff_stats)) = calculate_diff_stats_for_session(&session_state) {
+                if let Some(ref session_state) = session_state {
+                    if let Ok(Some(diff_stats)) = calculate_diff_stats_for_session(session_state) {
                         s = s.with_diff_stats(diff_stats);
                     }
                 }
 
                 if json {
-                    self.output_json(&s)?;
+                    self.output_json(&StatusJson::new(
+                        &s,
+                        session_state.as_ref(),
+                        self.stale_after_minutes,
+                        chrono::Utc::now(),
+                    ))?;
                 } else {
-                    display_status(&s);
+                    let links = session_state.map(|s| s.links).unwrap_or_default();
+                    display_status(&s, &links, self.stale_after_minutes);
                 }
             }
             None => {
@@ -235,6 +361,7 @@ impl StatusDisplayHandler {
     fn show_all_sessions(&self, json: bool) -> Result<()> {
         let sessions = self.session_manager.list_sessions()?;
         let mut statuses = Vec::new();
+        let mut session_states = Vec::new();
 
         for session_state in sessions {
             if let Some(mut status) = Status::load(&self.state_dir, &session_state.name)
@@ -245,15 +372,24 @@ impl StatusDisplayHandler {
                     status = status.with_diff_stats(diff_stats);
                 }
                 statuses.push(status);
+                session_states.push(session_state);
             }
         }
 
+        let now = chrono::Utc::now();
         if json {
-            self.output_json(&statuses)?;
+            let output: Vec<StatusJson> = statuses
+                .iter()
+                .zip(&session_states)
+                .map(|(status, session_state)| {
+                    StatusJson::new(status, Some(session_state), self.stale_after_minutes, now)
+                })
+                .collect();
+            self.output_json(&output)?;
         } else if statuses.is_empty() {
             println!("No session statuses found.");
         } else {
-            display_all_statuses(&statuses);
+            display_all_statuses(&statuses, self.stale_after_minutes);
         }
 
         Ok(())
@@ -267,16 +403,102 @@ impl StatusDisplayHandler {
     }
 }
 
-fn show_status(config: Config, session: Option<String>, json: bool) -> Result<()> {
-    let handler = StatusDisplayHandler::new(config)?;
+fn show_status(ctx: &CommandContext, session: Option<String>, json: bool) -> Result<()> {
+    let handler = StatusDisplayHandler::new(ctx)?;
 
     match session {
-        Some(session_name) => handler.show_specific_session(&session_name, json),
+        Some(query) => {
+            // A status can outlive its session, so an unknown name is shown as is
+            let session_name = match handler.session_manager.resolve_session(&query) {
+                Ok(session) => session.name,
+                Err(ParaError::SessionNotFound { .. }) => query,
+                Err(e) => return Err(e),
+            };
+            handler.show_specific_session(&session_name, json)
+        }
         None => handler.show_all_sessions(json),
     }
 }
 
-fn display_status(status: &Status) {
+fn wait_for_status(
+    ctx: &CommandContext,
+    session_name: &str,
+    until: &str,
+    interval: u64,
+    timeout: Option<u64>,
+) -> Result<()> {
+    let condition = WaitCondition::parse(until)?;
+    if interval == 0 {
+        return Err(ParaError::invalid_args(
+            "--interval must be at least 1 second",
+        ));
+    }
+
+    let handler = StatusDisplayHandler::new(ctx)?;
+    let session_state = handler.session_manager.resolve_session(session_name)?;
+
+    let status = wait_until(
+        &handler.state_dir,
+        &session_state.name,
+        Some(&session_state.worktree_path),
+        &condition,
+        Duration::from_secs(interval),
+        timeout.map(Duration::from_secs),
+    )?;
+
+    handler.output_json(&status)
+}
+
+/// Poll the status file (and worktree activity for idle conditions) until
+/// `condition` holds, returning the status that satisfied it.
+fn wait_until(
+    state_dir: &Path,
+    session_name: &str,
+    worktree_path: Option<&Path>,
+    condition: &WaitCondition,
+    interval: Duration,
+    timeout: Option<Duration>,
+) -> Result<Option<Status>> {
+    let started = Instant::now();
+
+    loop {
+        let status = Status::load(state_dir, session_name)
+            .map_err(|e| ParaError::config_error(e.to_string()))?;
+        let last_activity = match condition {
+            WaitCondition::IdleFor(_) => worktree_path.and_then(detect_last_activity),
+            _ => None,
+        };
+
+        if condition.is_met(status.as_ref(), last_activity, chrono::Utc::now()) {
+            return Ok(status);
+        }
+
+        let sleep_for = match timeout {
+            Some(timeout) => {
+                let remaining = timeout.saturating_sub(started.elapsed());
+                if remaining.is_zero() {
+                    return Err(ParaError::timeout(format!(
+                        "session '{session_name}' did not meet the condition within {}s",
+                        timeout.as_secs()
+                    )));
+                }
+                interval.min(remaining)
+            }
+            None => interval,
+        };
+        thread::sleep(sleep_for);
+    }
+}
+
+fn display_status(status: &Status, links: &[SessionLink], stale_after_minutes: u64) {
+    let now = chrono::Utc::now();
+    if is_status_outdated(status.updated_at, now, stale_after_minutes) {
+        println!(
+            "⚠️  This status was reported {} and may be out of date (stale after {stale_after_minutes} minutes)",
+            format_status_age(status.age(now))
+        );
+    }
+
     println!("Session: {}", status.session_name);
     println!("Task: {}", status.current_task);
     println!("Tests: {}", status.test_status);
@@ -286,6 +508,10 @@ fn display_status(status: &Status) {
 
     if let Some(todos) = status.format_todos() {
         println!("Progress: {todos}");
+        for item in &status.todos {
+            let mark = if item.done { "x" } else { " " };
+            println!("  [{mark}] {}. {}", item.id, item.text);
+        }
     }
 
     if status.is_blocked {
@@ -295,22 +521,31 @@ fn display_status(status: &Status) {
         }
     }
 
+    if !links.is_empty() {
+        println!("Links:");
+        for link in links {
+            println!("  {}: {}", link.label, link.url);
+        }
+    }
+
     println!(
-        "Last Update: {}",
-        status.last_update.format("%Y-%m-%d %H:%M:%S UTC")
+        "Last Update: {} ({})",
+        status.updated_at.format("%Y-%m-%d %H:%M:%S UTC"),
+        format_status_age(status.age(now))
     );
 }
 
-fn display_all_statuses(statuses: &[Status]) {
+fn display_all_statuses(statuses: &[Status], stale_after_minutes: u64) {
+    let now = chrono::Utc::now();
     // Sort by last update time (most recent first)
     let mut sorted_statuses = statuses.to_vec();
-    sorted_statuses.sort_by(|a, b| b.last_update.cmp(&a.last_update));
+    sorted_statuses.sort_by_key(|s| std::cmp::Reverse(s.updated_at));
 
     println!(
-        "{:<20} {:<40} {:<10} {:<15} {:<10}",
-        "Session", "Current Task", "Tests", "Progress", "Status"
+        "{:<20} {:<40} {:<10} {:<15} {:<10} {:<16}",
+        "Session", "Current Task", "Tests", "Progress", "Status", "Updated"
     );
-    println!("{}", "-".repeat(100));
+    println!("{}", "-".repeat(116));
 
     for status in sorted_statuses {
         let task = if status.current_task.len() > 38 {
@@ -325,24 +560,25 @@ fn display_all_statuses(statuses: &[Status]) {
         } else {
             "Active"
         };
+        let mut updated = format_status_age(status.age(now));
+        if is_status_outdated(status.updated_at, now, stale_after_minutes) {
+            updated.push_str(" (stale)");
+        }
 
         println!(
-            "{:<20} {:<40} {:<10} {:<15} {:<10}",
+            "{:<20} {:<40} {:<10} {:<15} {:<10} {:<16}",
             status.session_name,
             task,
             status.test_status.to_string(),
             progress,
-            status_str
+            status_str,
+            updated
         );
     }
 }
 
-fn show_summary(config: Config, json: bool) -> Result<()> {
-    let state_dir = if Path::new(&config.directories.state_dir).is_absolute() {
-        PathBuf::from(&config.directories.state_dir)
-    } else {
-        get_main_repository_root()?.join(".para").join("state")
-    };
+fn show_summary(ctx: &CommandContext, json: bool) -> Result<()> {
+    let state_dir = status_state_dir(ctx)?;
 
     // Use 24 hours as the default stale threshold
     let stale_threshold_hours = 24;
@@ -377,12 +613,8 @@ fn show_summary(config: Config, json: bool) -> Result<()> {
     Ok(())
 }
 
-fn cleanup_status(config: Config, dry_run: bool) -> Result<()> {
-    let state_dir = if Path::new(&config.directories.state_dir).is_absolute() {
-        PathBuf::from(&config.directories.state_dir)
-    } else {
-        get_main_repository_root()?.join(".para").join("state")
-    };
+fn cleanup_status(ctx: &CommandContext, dry_run: bool) -> Result<()> {
+    let state_dir = status_state_dir(ctx)?;
 
     // Use 24 hours as the default stale threshold
     let stale_threshold_hours = 24;
@@ -400,7 +632,7 @@ fn cleanup_status(config: Config, dry_run: bool) -> Result<()> {
                 println!(
                     "  📊 {}.status.json - last updated: {}",
                     status.session_name,
-                    status.last_update.format("%Y-%m-%d %H:%M:%S UTC")
+                    status.updated_at.format("%Y-%m-%d %H:%M:%S UTC")
                 );
                 stale_count += 1;
             }
@@ -470,7 +702,10 @@ mod tests {
             session: Some("test-session".to_string()),
         };
 
-        let result = execute(config.clone(), args);
+        let result = execute(
+            &CommandContext::in_dir(config.clone(), git_temp.path().to_path_buf()),
+            args,
+        );
         assert!(result.is_ok());
 
         // Verify status was saved
@@ -519,7 +754,10 @@ mod tests {
             session: Some("blocked-session".to_string()),
         };
 
-        let result = execute(config.clone(), args);
+        let result = execute(
+            &CommandContext::in_dir(config.clone(), git_temp.path().to_path_buf()),
+            args,
+        );
         assert!(result.is_ok());
 
         // Verify blocked status
@@ -560,9 +798,6 @@ mod tests {
         );
         session_manager.save_state(&session_state).unwrap();
 
-        // Change to worktree directory
-        std::env::set_current_dir(&worktree_path).unwrap();
-
         // Update status without specifying session (should auto-detect)
         let args = StatusArgs {
             command: None,
@@ -573,7 +808,10 @@ mod tests {
             session: None,
         };
 
-        let result = execute(config.clone(), args);
+        let result = execute(
+            &CommandContext::in_dir(config.clone(), worktree_path.clone()),
+            args,
+        );
         assert!(result.is_ok());
 
         // Verify status was saved for the correct session
@@ -628,7 +866,10 @@ mod tests {
             session: None,
         };
 
-        let result = execute(config, args);
+        let result = execute(
+            &CommandContext::in_dir(config, git_temp.path().to_path_buf()),
+            args,
+        );
         assert!(result.is_ok());
     }
 
@@ -680,7 +921,10 @@ mod tests {
             session: None,
         };
 
-        let result = execute(config, args);
+        let result = execute(
+            &CommandContext::in_dir(config, git_temp.path().to_path_buf()),
+            args,
+        );
         assert!(result.is_ok());
     }
 
@@ -708,7 +952,10 @@ mod tests {
             session: Some("nonexistent-session".to_string()),
         };
 
-        let result = execute(config, args);
+        let result = execute(
+            &CommandContext::in_dir(config, git_temp.path().to_path_buf()),
+            args,
+        );
         assert!(result.is_err());
         assert!(result
             .unwrap_err()
@@ -749,7 +996,10 @@ mod tests {
             session: Some("test-session".to_string()),
         };
 
-        let result = execute(config, args);
+        let result = execute(
+            &CommandContext::in_dir(config, git_temp.path().to_path_buf()),
+            args,
+        );
         assert!(result.is_err());
         assert!(result
             .unwrap_err()
@@ -757,6 +1007,82 @@ mod tests {
             .contains("Test status must be"));
     }
 
+    #[test]
+    fn test_todo_commands_track_completion_across_updates() {
+        let (git_temp, _git_service) = setup_test_repo();
+        let state_dir = git_temp.path().join(".para").join("state");
+        let mut config = create_test_config();
+        config.directories.state_dir = state_dir.to_string_lossy().to_string();
+
+        let session_manager = SessionManager::new(&config);
+        session_manager
+            .save_state(&crate::core::session::SessionState::new(
+                "todo-session".to_string(),
+                "test/todo".to_string(),
+                git_temp.path().join("worktree"),
+            ))
+            .unwrap();
+        let session = || Some("todo-session".to_string());
+        let todo = |command| {
+            execute(
+                &CommandContext::in_dir(config.clone(), git_temp.path().to_path_buf()),
+                StatusArgs {
+                    command: Some(StatusCommands::Todo { command }),
+                    task: None,
+                    tests: None,
+                    todos: None,
+                    blocked: false,
+                    session: None,
+                },
+            )
+        };
+
+        for text in ["parse input", "write tests"] {
+            todo(TodoCommands::Add {
+                text: text.to_string(),
+                session: session(),
+            })
+            .unwrap();
+        }
+        todo(TodoCommands::Done {
+            id: 2,
+            session: session(),
+        })
+        .unwrap();
+        assert!(todo(TodoCommands::Done {
+            id: 7,
+            session: session(),
+        })
+        .is_err());
+
+        // A regular update without --todos keeps the list
+        execute(
+            &CommandContext::in_dir(config.clone(), git_temp.path().to_path_buf()),
+            StatusArgs {
+                command: None,
+                task: Some("Testing".to_string()),
+                tests: Some("passed".to_string()),
+                todos: None,
+                blocked: false,
+                session: session(),
+            },
+        )
+        .unwrap();
+
+        let status = Status::load(&state_dir, "todo-session").unwrap().unwrap();
+        assert_eq!(status.current_task, "Testing");
+        let items: Vec<_> = status
+            .todos
+            .iter()
+            .map(|i| (i.id, i.text.as_str(), i.done))
+            .collect();
+        assert_eq!(
+            items,
+            vec![(1, "parse input", false), (2, "write tests", true)]
+        );
+        assert_eq!(status.todo_fraction(), Some("1/2".to_string()));
+    }
+
     #[test]
     fn test_status_update_invalid_todos() {
         let (git_temp, _git_service) = setup_test_repo();
@@ -785,12 +1111,15 @@ mod tests {
             command: None,
             task: Some("Test task".to_string()),
             tests: Some("passed".to_string()),
-            todos: Some("invalid-format".to_string()),
+            todos: Some("3/7/10".to_string()),
             blocked: false,
             session: Some("test-session".to_string()),
         };
 
-        let result = execute(config, args);
+        let result = execute(
+            &CommandContext::in_dir(config, git_temp.path().to_path_buf()),
+            args,
+        );
         assert!(result.is_err());
         assert!(result
             .unwrap_err()
@@ -823,7 +1152,10 @@ mod tests {
             session: Some("test-session".to_string()),
         };
 
-        let result = execute(config.clone(), args);
+        let result = execute(
+            &CommandContext::in_dir(config.clone(), git_temp.path().to_path_buf()),
+            args,
+        );
         assert!(result.is_err());
         assert!(result
             .unwrap_err()
@@ -840,7 +1172,10 @@ mod tests {
             session: Some("test-session".to_string()),
         };
 
-        let result = execute(config.clone(), args);
+        let result = execute(
+            &CommandContext::in_dir(config.clone(), git_temp.path().to_path_buf()),
+            args,
+        );
         assert!(result.is_err());
         assert!(result
             .unwrap_err()
@@ -893,27 +1228,27 @@ mod tests {
             "Task 1".to_string(),
             crate::core::status::TestStatus::Passed,
         );
-        status1.last_update = now - Duration::hours(2); // 2 hours ago
+        status1.updated_at = now - Duration::hours(2); // 2 hours ago
 
         let mut status2 = Status::new(
             "session2".to_string(),
             "Task 2".to_string(),
             crate::core::status::TestStatus::Failed,
         );
-        status2.last_update = now - Duration::minutes(30); // 30 minutes ago
+        status2.updated_at = now - Duration::minutes(30); // 30 minutes ago
 
         let mut status3 = Status::new(
             "session3".to_string(),
             "Task 3".to_string(),
             crate::core::status::TestStatus::Unknown,
         );
-        status3.last_update = now; // now
+        status3.updated_at = now; // now
 
         let statuses = vec![status1, status2, status3];
 
         // Test the sorting logic that display_all_statuses uses
         let mut sorted_statuses = statuses.clone();
-        sorted_statuses.sort_by(|a, b| b.last_update.cmp(&a.last_update));
+        sorted_statuses.sort_by_key(|s| std::cmp::Reverse(s.updated_at));
 
         // Should be sorted by most recent first
         assert_eq!(sorted_statuses[0].session_name, "session3"); // now
@@ -1017,6 +1352,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_status_json_includes_permission_mode_and_links() {
+        let status = Status::new(
+            "risky".to_string(),
+            "Refactoring".to_string(),
+            crate::core::status::TestStatus::Unknown,
+        );
+        let now = status.updated_at + chrono::Duration::minutes(90);
+        let mut session_state = SessionState::new(
+            "risky".to_string(),
+            "para/risky".to_string(),
+            PathBuf::from("/tmp/risky"),
+        );
+        session_state.dangerous_skip_permissions = Some(true);
+        let value =
+            serde_json::to_value(StatusJson::new(&status, Some(&session_state), 60, now)).unwrap();
+
+        assert_eq!(value["session_name"], "risky");
+        assert_eq!(value["current_task"], "Refactoring");
+        assert_eq!(value["dangerous_skip_permissions"], true);
+        assert_eq!(value["age_seconds"], 90 * 60);
+        assert_eq!(value["stale"], true);
+        assert!(value["updated_at"].is_string());
+        assert!(value.get("links").is_none());
+
+        session_state.links.push(SessionLink {
+            label: "PR".to_string(),
+            url: "https://github.com/acme/app/pull/42".to_string(),
+        });
+        let value =
+            serde_json::to_value(StatusJson::new(&status, Some(&session_state), 60, now)).unwrap();
+        assert_eq!(value["links"][0]["label"], "PR");
+        let value = serde_json::to_value(StatusJson::new(&status, None, 60, now)).unwrap();
+        assert_eq!(value["dangerous_skip_permissions"], false);
+    }
+
     #[test]
     fn test_show_status_json_output_single_session() {
         let (git_temp, _git_service) = setup_test_repo();
@@ -1049,7 +1420,11 @@ mod tests {
         status.save(&state_dir).unwrap();
 
         // Test JSON output
-        let result = show_status(config, Some("json-test".to_string()), true);
+        let result = show_status(
+            &CommandContext::in_dir(config, git_temp.path().to_path_buf()),
+            Some("json-test".to_string()),
+            true,
+        );
         assert!(result.is_ok());
     }
 
@@ -1088,7 +1463,11 @@ mod tests {
         }
 
         // Test JSON output for all sessions
-        let result = show_status(config, None, true);
+        let result = show_status(
+            &CommandContext::in_dir(config, git_temp.path().to_path_buf()),
+            None,
+            true,
+        );
         assert!(result.is_ok());
     }
 
@@ -1107,7 +1486,11 @@ mod tests {
         config.directories.state_dir = state_dir.to_string_lossy().to_string();
 
         // Try to show status for nonexistent session
-        let result = show_status(config, Some("nonexistent".to_string()), false);
+        let result = show_status(
+            &CommandContext::in_dir(config, git_temp.path().to_path_buf()),
+            Some("nonexistent".to_string()),
+            false,
+        );
         assert!(result.is_ok()); // Should not error, just show no status found
     }
 
@@ -1126,7 +1509,11 @@ mod tests {
         config.directories.state_dir = state_dir.to_string_lossy().to_string();
 
         // Try to show JSON status for nonexistent session
-        let result = show_status(config, Some("nonexistent".to_string()), true);
+        let result = show_status(
+            &CommandContext::in_dir(config, git_temp.path().to_path_buf()),
+            Some("nonexistent".to_string()),
+            true,
+        );
         assert!(result.is_ok()); // Should not error, just show nothing for JSON
     }
 
@@ -1145,7 +1532,11 @@ mod tests {
         config.directories.state_dir = state_dir.to_string_lossy().to_string();
 
         // Show all sessions when no sessions exist
-        let result = show_status(config, None, false);
+        let result = show_status(
+            &CommandContext::in_dir(config, git_temp.path().to_path_buf()),
+            None,
+            false,
+        );
         assert!(result.is_ok());
     }
 
@@ -1164,7 +1555,11 @@ mod tests {
         config.directories.state_dir = state_dir.to_string_lossy().to_string();
 
         // Show all sessions as JSON when no sessions exist
-        let result = show_status(config, None, true);
+        let result = show_status(
+            &CommandContext::in_dir(config, git_temp.path().to_path_buf()),
+            None,
+            true,
+        );
         assert!(result.is_ok());
     }
 
@@ -1199,7 +1594,11 @@ mod tests {
         status.save(&state_dir).unwrap();
 
         // Test that show_status works with absolute path
-        let result = show_status(config, Some("abs-path-test".to_string()), false);
+        let result = show_status(
+            &CommandContext::in_dir(config, git_temp.path().to_path_buf()),
+            Some("abs-path-test".to_string()),
+            false,
+        );
         assert!(result.is_ok());
     }
 
@@ -1236,7 +1635,11 @@ mod tests {
         status.save(&state_dir).unwrap();
 
         // Test that show_status works with resolved path
-        let result = show_status(config, Some("rel-path-test".to_string()), false);
+        let result = show_status(
+            &CommandContext::in_dir(config, git_temp.path().to_path_buf()),
+            Some("rel-path-test".to_string()),
+            false,
+        );
         assert!(result.is_ok());
     }
 
@@ -1249,11 +1652,12 @@ mod tests {
         // Use relative path that will require git repo detection
         config.directories.state_dir = ".para/state".to_string();
 
-        // Change to a non-git directory
-        std::env::set_current_dir(temp_dir.path()).unwrap();
-
         // This should fail because we're not in a git repository
-        let result = show_status(config, Some("test".to_string()), false);
+        let result = show_status(
+            &CommandContext::in_dir(config, temp_dir.path().to_path_buf()),
+            Some("test".to_string()),
+            false,
+        );
         assert!(result.is_err());
         assert!(result
             .unwrap_err()
@@ -1295,11 +1699,120 @@ mod tests {
             session: Some("review-session".to_string()),
         };
 
-        let result = execute(config.clone(), args);
+        let result = execute(
+            &CommandContext::in_dir(config.clone(), git_temp.path().to_path_buf()),
+            args,
+        );
         assert!(result.is_err());
         assert!(result
             .unwrap_err()
             .to_string()
             .contains("Cannot update status for sessions in Review state"));
     }
+
+    #[test]
+    fn test_wait_returns_when_status_changes_in_another_thread() {
+        let state_dir = TempDir::new().unwrap();
+        Status::new(
+            "waiter".to_string(),
+            "Writing tests".to_string(),
+            crate::core::status::TestStatus::Failed,
+        )
+        .save(state_dir.path())
+        .unwrap();
+
+        let writer_dir = state_dir.path().to_path_buf();
+        let writer = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(300));
+            Status::new(
+                "waiter".to_string(),
+                "All green".to_string(),
+                crate::core::status::TestStatus::Passed,
+            )
+            .save(&writer_dir)
+            .unwrap();
+        });
+
+        let started = Instant::now();
+        let status = wait_until(
+            state_dir.path(),
+            "waiter",
+            None,
+            &WaitCondition::TestsPassed,
+            Duration::from_millis(50),
+            Some(Duration::from_secs(10)),
+        )
+        .unwrap()
+        .unwrap();
+        writer.join().unwrap();
+
+        assert_eq!(status.test_status, crate::core::status::TestStatus::Passed);
+        assert_eq!(status.current_task, "All green");
+        assert!(started.elapsed() >= Duration::from_millis(300));
+    }
+
+    #[test]
+    fn test_wait_times_out_with_distinct_exit_code() {
+        let state_dir = TempDir::new().unwrap();
+
+        let err = wait_until(
+            state_dir.path(),
+            "never",
+            None,
+            &WaitCondition::Blocked,
+            Duration::from_millis(20),
+            Some(Duration::from_millis(100)),
+        )
+        .unwrap_err();
+
+        assert!(matches!(err, ParaError::Timeout { .. }));
+        assert_eq!(err.exit_code(), crate::utils::error::TIMEOUT_EXIT_CODE);
+    }
+
+    #[test]
+    fn test_status_discovers_the_repository_once() {
+        use crate::core::git::discoveries;
+
+        let (_git_temp, git_service) = setup_test_repo();
+        let mut config = create_test_config();
+        config.directories.state_dir = ".para-status-test".to_string();
+        let mut session_manager =
+            SessionManager::for_repository(&config, &git_service.repository().root);
+        let session = session_manager
+            .create_session_with_all_flags("reporting".to_string(), None, false, false, None)
+            .unwrap();
+
+        discoveries::take();
+        let ctx = CommandContext::in_dir(config.clone(), session.worktree_path.clone());
+        let args = StatusArgs {
+            command: None,
+            task: Some("Wiring the context".to_string()),
+            tests: Some("passed".to_string()),
+            todos: None,
+            blocked: false,
+            session: None,
+        };
+        execute(&ctx, args).unwrap();
+        assert_eq!(discoveries::take_distinct().len(), 1);
+
+        let ctx = CommandContext::in_dir(config, session.worktree_path.clone());
+        let args = StatusArgs {
+            command: Some(StatusCommands::Show {
+                session: None,
+                json: true,
+            }),
+            task: None,
+            tests: None,
+            todos: None,
+            blocked: false,
+            session: None,
+        };
+        execute(&ctx, args).unwrap();
+        assert_eq!(discoveries::take_distinct().len(), 1);
+
+        let status = Status::load(session_manager.state_dir(), "reporting")
+            .unwrap()
+            .unwrap();
+        assert_eq!(status.current_task, "Wiring the context");
+    }
 }
@@ -0,0 +1,125 @@
+use crate::cli::commands::list::table::truncate_end;
+use crate::cli::commands::list::FormatTemplate;
+use crate::cli::parser::StatuslineArgs;
+use crate::core::status::TestStatus;
+use crate::core::statusline::{self, SessionLine, StatuslineOptions};
+use crate::utils::{ParaError, Result};
+
+pub const DEFAULT_FORMAT: &str = "{name}{dirty} {ahead}{behind} {tests} {blocked}";
+
+/// Placeholders accepted by `para statusline --format`
+pub const STATUSLINE_PLACEHOLDERS: &[&str] = &[
+    "name", "branch", "dirty", "ahead", "behind", "tests", "blocked",
+];
+
+pub fn execute(args: StatuslineArgs) -> Result<()> {
+    let template = FormatTemplate::parse_with(
+        args.format.as_deref().unwrap_or(DEFAULT_FORMAT),
+        STATUSLINE_PLACEHOLDERS,
+    )?;
+    let current_dir = std::env::current_dir()
+        .map_err(|e| ParaError::fs_error(format!("Failed to get current directory: {e}")))?;
+
+    // Outside a session the line stays empty, so tmux shows nothing
+    if let Some(line) = statusline::collect(&current_dir, &StatuslineOptions::default()) {
+        println!("{}", render(&template, &line, args.max_width));
+    }
+    Ok(())
+}
+
+/// `line` through `template`, with the spaces around empty fields collapsed
+/// and cut to `max_width` characters
+pub fn render(template: &FormatTemplate, line: &SessionLine, max_width: Option<usize>) -> String {
+    let rendered = template.render_with(|name| placeholder_value(line, name));
+    let collapsed = rendered
+        .split(' ')
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    match max_width {
+        Some(width) => truncate_end(&collapsed, width),
+        None => collapsed,
+    }
+}
+
+fn placeholder_value(line: &SessionLine, name: &str) -> String {
+    let git = line.git.unwrap_or_default();
+    match name {
+        "name" => line.name.clone(),
+        "branch" => line.branch.clone(),
+        "dirty" if git.dirty => "*".to_string(),
+        "ahead" if git.ahead > 0 => format!("↑{}", git.ahead),
+        "behind" if git.behind > 0 => format!("↓{}", git.behind),
+        "tests" => match line.test_status {
+            Some(TestStatus::Passed) => "✓".to_string(),
+            Some(TestStatus::Failed) => "✗".to_string(),
+            _ => String::new(),
+        },
+        "blocked" if line.blocked => "blocked".to_string(),
+        _ => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::statusline::GitSummary;
+
+    fn line() -> SessionLine {
+        SessionLine {
+            name: "auth".to_string(),
+            branch: "para/auth".to_string(),
+            git: Some(GitSummary {
+                dirty: true,
+                ahead: 2,
+                behind: 1,
+            }),
+            test_status: Some(TestStatus::Failed),
+            blocked: true,
+        }
+    }
+
+    fn default_template() -> FormatTemplate {
+        FormatTemplate::parse_with(DEFAULT_FORMAT, STATUSLINE_PLACEHOLDERS).unwrap()
+    }
+
+    #[test]
+    fn test_default_format() {
+        assert_eq!(
+            render(&default_template(), &line(), None),
+            "auth* ↑2↓1 ✗ blocked"
+        );
+
+        // Missing fields leave no gaps, also when git ran out of time
+        let quiet = SessionLine {
+            git: None,
+            test_status: None,
+            blocked: false,
+            ..line()
+        };
+        assert_eq!(render(&default_template(), &quiet, None), "auth");
+    }
+
+    #[test]
+    fn test_templated_format() {
+        let template =
+            FormatTemplate::parse_with("[{branch}] {tests}{dirty}", STATUSLINE_PLACEHOLDERS)
+                .unwrap();
+        assert_eq!(render(&template, &line(), None), "[para/auth] ✗*");
+
+        let err = FormatTemplate::parse_with("{name} {path}", STATUSLINE_PLACEHOLDERS)
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(err.contains("Unknown placeholder '{path}'"));
+    }
+
+    #[test]
+    fn test_max_width_truncates_with_ellipsis() {
+        assert_eq!(render(&default_template(), &line(), Some(10)), "auth* ↑2↓…");
+        assert_eq!(
+            render(&default_template(), &line(), Some(40)),
+            "auth* ↑2↓1 ✗ blocked"
+        );
+    }
+}
@@ -0,0 +1,33 @@
+use crate::cli::context::CommandContext;
+use crate::cli::parser::SummaryArgs;
+use crate::core::session::{manifest, summary};
+use crate::utils::{ParaError, Result};
+use chrono::Utc;
+
+pub fn execute(ctx: &CommandContext, args: SummaryArgs) -> Result<()> {
+    let session_manager = ctx.session_manager();
+    let session = match args.session {
+        Some(ref name) => session_manager.resolve_session(name)?,
+        None => {
+            let name = manifest::current_session(&session_manager, ctx.current_dir())?.ok_or_else(
+                || ParaError::invalid_args("Not in a para session directory. Name the session"),
+            )?;
+            session_manager.load_state(&name)?
+        }
+    };
+
+    let git_service = ctx.git_service()?;
+    let summary = summary::gather(
+        git_service.repository(),
+        session_manager.state_dir(),
+        &session,
+        &session.branch,
+        Utc::now(),
+    )?;
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&summary)?);
+    } else {
+        print!("{}", summary::render_markdown(&summary));
+    }
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use crate::cli::context::CommandContext;
+use crate::cli::parser::SyncArgs;
+use crate::core::git::validation::{preflight, RepoOperation};
+use crate::core::git::GitRepository;
+use crate::core::session::sync::{continue_sync, sync_session, SyncOutcome};
+use crate::core::session::{SessionManager, SessionState};
+use crate::core::version;
+use crate::utils::{ParaError, Result};
+use std::path::Path;
+
+pub fn execute(ctx: &CommandContext, args: SyncArgs) -> Result<()> {
+    let session_manager = ctx.session_manager();
+    let session = detect_session(&args, &session_manager, ctx.current_dir())?;
+    version::check_session(&session, true)?;
+
+    preflight_sync(&session, &args)?;
+
+    report(sync_session(&session_manager, &session)?, &session)
+}
+
+/// Continue a sync that stopped on conflicts, for `para continue`
+pub fn execute_continue(ctx: &CommandContext, args: SyncArgs) -> Result<()> {
+    let session_manager = ctx.session_manager();
+    let session = detect_session(&args, &session_manager, ctx.current_dir())?;
+    version::check_session(&session, true)?;
+
+    preflight_sync(&session, &args)?;
+
+    report(continue_sync(&session_manager, &session)?, &session)
+}
+
+fn preflight_sync(session: &SessionState, args: &SyncArgs) -> Result<()> {
+    // A missing worktree is reported by the sync itself
+    if !session.worktree_path.exists() {
+        return Ok(());
+    }
+    let repo = GitRepository::discover_from(&session.worktree_path)?;
+    preflight(&repo, RepoOperation::Sync, args.preflight.options())
+}
+
+fn report(outcome: SyncOutcome, session: &SessionState) -> Result<()> {
+    let message = outcome.describe(&session.name);
+    match outcome {
+        SyncOutcome::Conflicted { .. } => Err(ParaError::git_operation(message)),
+        SyncOutcome::Rebased { .. } => {
+            println!("✅ {message}");
+            Ok(())
+        }
+        SyncOutcome::UpToDate { .. } => {
+            println!("{message}");
+            Ok(())
+        }
+    }
+}
+
+fn detect_session(
+    args: &SyncArgs,
+    session_manager: &SessionManager,
+    current_dir: &Path,
+) -> Result<SessionState> {
+    if let Some(ref session_name) = args.session {
+        return session_manager.resolve_session(session_name);
+    }
+
+    session_manager
+        .find_session_by_path(current_dir)?
+        .ok_or_else(|| {
+            ParaError::invalid_args(
+                "Not inside a session worktree. Use 'para sync <session-name>' to sync a specific session.",
+            )
+        })
+}
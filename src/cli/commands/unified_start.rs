@@ -1,6 +1,9 @@
+use crate::cli::commands::common::find_template;
+use crate::cli::commands::dispatch::{combine_prompt_parts, read_piped_stdin};
+use crate::cli::context::CommandContext;
 use crate::cli::parser::UnifiedStartArgs;
+use crate::config::templates::SessionTemplate;
 use crate::config::Config;
-use crate::core::git::GitService;
 use crate::core::session::SessionManager;
 use crate::utils::{ParaError, Result};
 use std::path::PathBuf;
@@ -56,12 +59,37 @@ pub fn determine_intent(
     }
 }
 
+/// Non-empty piped stdin, unless `--no-stdin` was given
+fn piped_input(args: &UnifiedStartArgs) -> Result<Option<String>> {
+    if args.no_stdin {
+        return Ok(None);
+    }
+
+    let has_other_input = args.name.is_some() || args.prompt.is_some() || args.file.is_some();
+    Ok(read_piped_stdin(has_other_input)?.filter(|input| !input.trim().is_empty()))
+}
+
 /// Resolve prompt content from various sources (inline, file, stdin)
 fn resolve_prompt_content(args: &UnifiedStartArgs) -> Result<Option<String>> {
-    // Priority order:
+    // Without piped input the priority order is:
     // 1. --file flag (highest priority)
     // 2. Inline prompt argument
-    // 3. Stdin (if available and no other input)
+    //
+    // With piped input all sources are combined, stdin last.
+    if let Some(stdin) = piped_input(args)? {
+        let mut parts = Vec::new();
+        if let Some(prompt) = &args.prompt {
+            parts.push(("prompt".to_string(), prompt.clone()));
+        }
+        if let Some(file_path) = &args.file {
+            parts.push((
+                file_path.display().to_string(),
+                read_prompt_file(file_path)?,
+            ));
+        }
+        parts.push(("stdin".to_string(), stdin));
+        return Ok(Some(combine_prompt_parts(parts)));
+    }
 
     if let Some(file_path) = &args.file {
         let content = read_prompt_file(file_path)?;
@@ -72,22 +100,6 @@ fn resolve_prompt_content(args: &UnifiedStartArgs) -> Result<Option<String>> {
         return Ok(Some(prompt.clone()));
     }
 
-    // Check for stdin input only if no other input provided
-    if args.name.is_none()
-        && args.prompt.is_none()
-        && !std::io::IsTerminal::is_terminal(&std::io::stdin())
-    {
-        use std::io::Read;
-        let mut buffer = String::new();
-        std::io::stdin()
-            .read_to_string(&mut buffer)
-            .map_err(|e| ParaError::file_operation(format!("Failed to read from stdin: {e}")))?;
-
-        if !buffer.trim().is_empty() {
-            return Ok(Some(buffer));
-        }
-    }
-
     Ok(None)
 }
 
@@ -122,52 +134,154 @@ fn read_prompt_file(path: &PathBuf) -> Result<String> {
 }
 
 /// Main entry point for unified start command
-pub fn execute(config: Config, args: UnifiedStartArgs) -> Result<()> {
+pub fn execute(ctx: &CommandContext, args: UnifiedStartArgs) -> Result<()> {
     args.validate()?;
 
-    let _git_service = GitService::discover()
+    ctx.git_service()
         .map_err(|e| ParaError::git_error(format!("Failed to discover git repository: {e}")))?;
-    let session_manager = SessionManager::new(&config);
+    if args.retry.is_some() {
+        return create_retry_session(ctx, args);
+    }
+    let config = ctx.config();
+    let session_manager = ctx.session_manager();
 
     let intent = determine_intent(&args, &session_manager)?;
 
+    let mut args = args;
+    let template = match &args.template {
+        Some(name) => Some(find_template(config, name)?.clone()),
+        None => None,
+    };
+    if let Some(template) = &template {
+        let with_prompt = matches!(intent, StartIntent::NewWithAgent { .. });
+        apply_template(&mut args, template, with_prompt);
+    }
+    let prompt_prefix = template.and_then(|t| t.prompt_prefix);
+
     match intent {
-        StartIntent::NewInteractive { name } => create_interactive_session(config, args, name),
+        StartIntent::NewInteractive { name } => create_interactive_session(ctx, args, name),
         StartIntent::NewWithAgent { name, prompt } => {
-            create_agent_session(config, args, name, prompt)
+            create_agent_session(ctx, args, name, prompt, prompt_prefix)
+        }
+    }
+}
+
+/// Use the template's values for the options not given on the command line.
+/// Base options only apply to sessions started with a prompt.
+fn apply_template(args: &mut UnifiedStartArgs, template: &SessionTemplate, with_prompt: bool) {
+    if with_prompt && args.base.is_none() {
+        args.base = template.base.clone();
+        args.fetch |= template.fetch.unwrap_or(false);
+        args.remote = args.remote.take().or_else(|| template.remote.clone());
+    } else if !with_prompt && args.base.is_none() && template.base.is_some() {
+        eprintln!(
+            "Note: The template's base only applies to sessions started with a prompt or file"
+        );
+    }
+
+    args.merge_mode = args.merge_mode.or(template.merge_mode);
+    args.setup_script = args
+        .setup_script
+        .take()
+        .or_else(|| template.setup_script.clone());
+    if args.copy.is_empty() {
+        args.copy = template.copy.clone();
+    }
+    args.no_auto_commit |= template.no_auto_commit.unwrap_or(false);
+    args.dangerously_skip_permissions |= template.dangerously_skip_permissions.unwrap_or(false);
+
+    args.container |= template.container.unwrap_or(false);
+    args.docker_image = args
+        .docker_image
+        .take()
+        .or_else(|| template.docker_image.clone());
+    if args.docker_args.is_empty() {
+        args.docker_args = template.docker_args.clone();
+    }
+    args.allow_domains = args
+        .allow_domains
+        .take()
+        .or_else(|| template.allow_domains.clone());
+    args.no_forward_keys |= template.no_forward_keys.unwrap_or(false);
+
+    let sandbox = &mut args.sandbox_args;
+    if !sandbox.sandbox && !sandbox.no_sandbox {
+        match template.sandbox {
+            Some(true) => sandbox.sandbox = true,
+            Some(false) => sandbox.no_sandbox = true,
+            None => {}
         }
     }
+    sandbox.sandbox_profile = sandbox
+        .sandbox_profile
+        .take()
+        .or_else(|| template.sandbox_profile.clone());
+    sandbox.sandbox_no_network |= template.sandbox_no_network.unwrap_or(false);
+    if sandbox.allowed_domains.is_empty() {
+        sandbox.allowed_domains = template.allowed_domains.clone();
+    }
 }
 
 /// Create a new interactive session (equivalent to old 'start' command)
 fn create_interactive_session(
-    config: Config,
+    ctx: &CommandContext,
     args: UnifiedStartArgs,
     name: Option<String>,
 ) -> Result<()> {
+    if args.base.is_some() {
+        return Err(ParaError::invalid_args(
+            "--base is only supported when starting a session with a prompt or file",
+        ));
+    }
+    if args.plan_only {
+        return Err(ParaError::invalid_args(
+            "--plan-only needs a task: give a prompt or --file",
+        ));
+    }
+
     // Delegate to existing start command for backward compatibility
     let start_args = args.to_start_args(name);
-    crate::cli::commands::start::execute(config, start_args)
+    crate::cli::commands::start::execute(ctx, start_args)
 }
 
 /// Create a new session with an AI agent (equivalent to old 'dispatch' command)
 fn create_agent_session(
-    config: Config,
+    ctx: &CommandContext,
     args: UnifiedStartArgs,
     name: Option<String>,
     prompt: String,
+    prompt_prefix: Option<String>,
 ) -> Result<()> {
     // Validate Claude Code IDE requirement for dispatch
-    validate_claude_code_ide(&config)?;
+    validate_claude_code_ide(ctx.config())?;
 
     // Delegate to existing dispatch command for agent functionality
-    // When we have a file, don't pass the prompt content as it will be resolved from the file
-    let dispatch_args = if args.file.is_some() {
+    // When we have a file, don't pass the prompt content as it will be resolved from the file.
+    // Piped input is cached, so dispatch combines it with the same sources again.
+    let mut dispatch_args = if piped_input(&args)?.is_some() {
+        args.to_dispatch_args(name, args.prompt.clone())
+    } else if args.file.is_some() {
         args.to_dispatch_args(name, None)
     } else {
         args.to_dispatch_args(name, Some(prompt))
     };
-    crate::cli::commands::dispatch::execute(config, dispatch_args)
+    dispatch_args.prompt_prefix = prompt_prefix;
+    crate::cli::commands::dispatch::execute(ctx, dispatch_args)
+}
+
+/// Rerun another session's task; dispatch derives the name and settings from
+/// it, and the positional argument and --prompt become extra instructions
+fn create_retry_session(ctx: &CommandContext, args: UnifiedStartArgs) -> Result<()> {
+    validate_claude_code_ide(ctx.config())?;
+
+    let instructions: Vec<String> = [&args.name, &args.prompt]
+        .into_iter()
+        .flatten()
+        .cloned()
+        .collect();
+    let instructions = (!instructions.is_empty()).then(|| instructions.join("\n\n"));
+    let dispatch_args = args.to_dispatch_args(None, instructions);
+    crate::cli::commands::dispatch::execute(ctx, dispatch_args)
 }
 
 /// Validate that Claude Code is configured in wrapper mode (required for dispatch)
@@ -189,6 +303,7 @@ fn validate_claude_code_ide(config: &Config) -> Result<()> {
 mod tests {
     use super::*;
     use crate::cli::parser::SandboxArgs;
+    use crate::core::session::migration::STATE_SCHEMA_VERSION;
     use crate::core::session::{SessionState, SessionType};
     use crate::test_utils::test_helpers::*;
     use tempfile::TempDir;
@@ -212,6 +327,25 @@ mod tests {
                 sandbox_no_network: false,
                 allowed_domains: vec![],
             },
+            base: None,
+            fetch: false,
+            allow_empty_repo: false,
+            no_stdin: false,
+            copy: vec![],
+            copy_large: false,
+            no_auto_commit: false,
+            merge_mode: None,
+            remote: None,
+            apply_patch: None,
+            apply_from: None,
+            template: None,
+            allow_secrets: false,
+            retry: None,
+            cancel_old: false,
+            no_hook_fixup: false,
+            plan_only: false,
+            defer_setup: false,
+            prompt_is_file: false,
         }
     }
 
@@ -355,10 +489,23 @@ mod tests {
             git_stats: None,
             session_type: SessionType::Worktree,
             parent_branch: Some("main".to_string()),
+            base_commit: None,
             is_docker: None,
             dangerous_skip_permissions: None,
             sandbox_enabled: Some(false),
             sandbox_profile: None,
+            no_auto_commit: None,
+            merge_mode: crate::core::git::MergeMode::default(),
+            template: None,
+            stacked_children: Vec::new(),
+            stacked_on: None,
+            retry_of: None,
+            schema_version: STATE_SCHEMA_VERSION,
+            para_version: String::new(),
+            container: None,
+            links: Vec::new(),
+            deferred_setup_script: None,
+            pinned: false,
         };
         session_manager.save_state(&session_state).unwrap();
 
@@ -400,10 +547,23 @@ mod tests {
             git_stats: None,
             session_type: SessionType::Worktree,
             parent_branch: Some("main".to_string()),
+            base_commit: None,
             is_docker: None,
             dangerous_skip_permissions: None,
             sandbox_enabled: Some(false),
             sandbox_profile: None,
+            no_auto_commit: None,
+            merge_mode: crate::core::git::MergeMode::default(),
+            template: None,
+            stacked_children: Vec::new(),
+            stacked_on: None,
+            retry_of: None,
+            schema_version: STATE_SCHEMA_VERSION,
+            para_version: String::new(),
+            container: None,
+            links: Vec::new(),
+            deferred_setup_script: None,
+            pinned: false,
         };
         session_manager.save_state(&session_state).unwrap();
 
@@ -660,4 +820,79 @@ mod tests {
         let content = resolve_prompt_content(&args).unwrap();
         assert_eq!(content, Some("File wins".to_string()));
     }
+
+    fn bugfix_template() -> SessionTemplate {
+        SessionTemplate {
+            base: Some("release".to_string()),
+            merge_mode: Some(crate::core::git::MergeMode::PreserveCommits),
+            setup_script: Some(PathBuf::from("scripts/bugfix-setup.sh")),
+            copy: vec![".env".to_string()],
+            no_auto_commit: Some(true),
+            sandbox: Some(true),
+            sandbox_profile: Some("restrictive".to_string()),
+            prompt_prefix: Some("Write a failing test first.".to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_template_fills_only_missing_options() {
+        let mut args = create_test_args();
+        args.base = Some("main".to_string());
+        args.copy = vec!["local.settings.json".to_string()];
+        args.sandbox_args.no_sandbox = true;
+
+        apply_template(&mut args, &bugfix_template(), true);
+
+        // Explicit flags win
+        assert_eq!(args.base.as_deref(), Some("main"));
+        assert_eq!(args.copy, vec!["local.settings.json".to_string()]);
+        assert!(!args.sandbox_args.sandbox);
+        // Everything else comes from the template
+        assert_eq!(
+            args.merge_mode,
+            Some(crate::core::git::MergeMode::PreserveCommits)
+        );
+        assert_eq!(
+            args.setup_script,
+            Some(PathBuf::from("scripts/bugfix-setup.sh"))
+        );
+        assert!(args.no_auto_commit);
+        assert_eq!(
+            args.sandbox_args.sandbox_profile.as_deref(),
+            Some("restrictive")
+        );
+
+        let mut args = create_test_args();
+        apply_template(&mut args, &bugfix_template(), true);
+        assert_eq!(args.base.as_deref(), Some("release"));
+        assert!(args.sandbox_args.sandbox);
+
+        // Interactive sessions cannot use a base
+        let mut args = create_test_args();
+        apply_template(&mut args, &bugfix_template(), false);
+        assert_eq!(args.base, None);
+    }
+
+    #[test]
+    fn test_unknown_template_lists_configured_ones() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = create_test_config_with_dir(&temp_dir);
+
+        let err = find_template(&config, "bugfix").unwrap_err().to_string();
+        assert!(err.contains("no templates are configured"), "{err}");
+
+        config
+            .templates
+            .insert("bugfix".to_string(), bugfix_template());
+        config
+            .templates
+            .insert("spike".to_string(), SessionTemplate::default());
+        assert!(find_template(&config, "bugfix").is_ok());
+        let err = find_template(&config, "bugfx").unwrap_err().to_string();
+        assert!(
+            err.contains("Unknown template 'bugfx'. Available templates: bugfix, spike"),
+            "{err}"
+        );
+    }
 }
@@ -0,0 +1,69 @@
+use crate::cli::context::CommandContext;
+use crate::cli::parser::UpgradeStateArgs;
+use crate::core::session::migration::{self, StateUpgrade};
+use crate::utils::Result;
+
+pub fn execute(ctx: &CommandContext, args: UpgradeStateArgs) -> Result<()> {
+    let session_manager = ctx.session_manager();
+    let state_dir = session_manager.state_dir();
+
+    if let Some(backup) = args.restore {
+        let restored = migration::restore_backup(state_dir, &backup)?;
+        println!(
+            "✅ Restored {} from {}",
+            state_dir.display(),
+            restored.display()
+        );
+        return Ok(());
+    }
+
+    let upgrades = session_manager.upgrade_states(args.dry_run)?;
+    if upgrades.is_empty() {
+        println!("No session state files in {}", state_dir.display());
+    }
+    for upgrade in &upgrades {
+        println!("{}", describe(upgrade));
+        if let Some(migration) = &upgrade.migration {
+            for change in &migration.changes {
+                println!("    {change}");
+            }
+            for warning in &migration.warnings {
+                println!("    ⚠️  {warning}");
+            }
+        }
+    }
+
+    let upgraded = upgrades.iter().filter(|u| u.migration.is_some()).count();
+    let backups = migration::list_backups(state_dir);
+    if args.dry_run {
+        println!("\nDry run: {upgraded} state file(s) would be upgraded");
+        if !backups.is_empty() {
+            println!("Backups (restore with --restore <name>):");
+            for backup in &backups {
+                if let Some(name) = backup.file_name() {
+                    println!("  {}", name.to_string_lossy());
+                }
+            }
+        }
+    } else if upgraded > 0 {
+        println!("\n✅ Upgraded {upgraded} state file(s)");
+        if let Some(backup) = backups.last() {
+            println!("   Backup: {}", backup.display());
+        }
+    } else if !upgrades.is_empty() {
+        println!("\n✅ All state files are up to date");
+    }
+    Ok(())
+}
+
+fn describe(upgrade: &StateUpgrade) -> String {
+    let name = upgrade
+        .file
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_default();
+    match &upgrade.migration {
+        Some(migration) => format!("  {name}: v{} → v{}", migration.from, migration.to),
+        None => format!("  {name}: v{} (up to date)", upgrade.version),
+    }
+}
@@ -0,0 +1,34 @@
+use crate::cli::context::CommandContext;
+use crate::cli::parser::WhichArgs;
+use crate::core::session::manifest::{self, SessionManifest};
+use crate::utils::{ParaError, Result};
+use std::fs;
+
+pub fn execute(ctx: &CommandContext, args: WhichArgs) -> Result<()> {
+    let current_dir = ctx.current_dir();
+
+    if args.manifest {
+        if let Some(path) = manifest::find_path(current_dir) {
+            let content =
+                fs::read_to_string(&path).map_err(|e| ParaError::from_io("read", &path, e))?;
+            print!("{content}");
+            return Ok(());
+        }
+    }
+
+    let session_manager = ctx.session_manager();
+    let session_name = manifest::current_session(&session_manager, current_dir)?
+        .ok_or_else(|| ParaError::invalid_args("Not in a para session directory"))?;
+
+    if args.manifest {
+        // Sessions created before manifests existed get one rendered from their state
+        let session = session_manager.load_state(&session_name)?;
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&SessionManifest::from_state(&session))?
+        );
+    } else {
+        println!("{session_name}");
+    }
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use crate::cli::parser::{Cli, Shell};
+use crate::cli::parser::{Cli, Shell, DEPRECATED_COMMANDS};
 use crate::utils::{ParaError, Result};
 use clap::CommandFactory;
 use clap_complete::{generate, shells};
@@ -7,13 +7,20 @@ pub struct ShellCompletionGenerator;
 
 impl ShellCompletionGenerator {
     pub fn generate_basic_completion(shell: Shell) -> Result<String> {
-        let mut cmd = Cli::command();
+        // Deprecated commands are hidden from `--help` but still completed,
+        // their descriptions marked as deprecated
+        let mut cmd = DEPRECATED_COMMANDS
+            .iter()
+            .fold(Cli::command(), |cmd, name| {
+                cmd.mut_subcommand(name, |sub| sub.hide(false))
+            });
         let mut buf = Vec::new();
 
         match shell {
             Shell::Bash => generate(shells::Bash, &mut cmd, "para", &mut buf),
             Shell::Zsh => generate(shells::Zsh, &mut cmd, "para", &mut buf),
             Shell::Fish => generate(shells::Fish, &mut cmd, "para", &mut buf),
+            Shell::PowerShell => generate(shells::PowerShell, &mut cmd, "para", &mut buf),
         }
 
         String::from_utf8(buf)
@@ -22,17 +29,44 @@ impl ShellCompletionGenerator {
 
     pub fn generate_enhanced_completion(shell: Shell) -> Result<String> {
         let basic = Self::generate_basic_completion(shell.clone())?;
-        let dynamic = Self::generate_dynamic_completion(shell)?;
 
-        Ok(format!("{basic}\n\n{dynamic}"))
+        match shell {
+            Shell::Bash => Ok(format!("{basic}\n\n{}", Self::generate_bash_dynamic())),
+            Shell::Zsh => Ok(format!("{basic}\n\n{}", Self::generate_zsh_dynamic())),
+            Shell::Fish => Ok(format!("{basic}\n\n{}", Self::generate_fish_dynamic())),
+            Shell::PowerShell => Self::hook_powershell_dynamic(&basic),
+        }
     }
 
-    fn generate_dynamic_completion(shell: Shell) -> Result<String> {
-        match shell {
-            Shell::Bash => Ok(Self::generate_bash_dynamic()),
-            Shell::Zsh => Ok(Self::generate_zsh_dynamic()),
-            Shell::Fish => Ok(Self::generate_fish_dynamic()),
+    /// PowerShell allows one native completer per command, so instead of
+    /// registering a second one the dynamic lookup is spliced into clap's
+    /// completer and runs before its static completions
+    fn hook_powershell_dynamic(basic: &str) -> Result<String> {
+        const REGISTER: &str = "Register-ArgumentCompleter -Native -CommandName 'para'";
+        const STATIC_COMPLETIONS: &str = "    $completions = @(switch ($command) {";
+        if !basic.contains(REGISTER) || !basic.contains(STATIC_COMPLETIONS) {
+            return Err(ParaError::invalid_args(
+                "Unexpected PowerShell completion script layout from clap_complete",
+            ));
         }
+
+        let dynamic_lookup = r#"    $dynamic = @(Get-ParaDynamicCompletions $commandElements $wordToComplete)
+    if ($dynamic.Count -gt 0) {
+        return $dynamic
+    }
+
+"#;
+        Ok(basic
+            .replacen(
+                REGISTER,
+                &format!("{}\n{REGISTER}", Self::generate_powershell_dynamic()),
+                1,
+            )
+            .replacen(
+                STATIC_COMPLETIONS,
+                &format!("{dynamic_lookup}{STATIC_COMPLETIONS}"),
+                1,
+            ))
     }
 
     fn generate_bash_dynamic() -> String {
@@ -81,17 +115,52 @@ _para_complete_branches() {
     fi
 }
 
+_para_complete_para_branches() {
+    local branches
+    # Avoid recursive calls during completion generation
+    if command -v para >/dev/null 2>&1 && [[ -z "$PARA_COMPLETION_SCRIPT" ]]; then
+        if command -v timeout >/dev/null 2>&1; then
+            branches=$(timeout 2 env PARA_COMPLETION_SCRIPT=1 para _completion_branches --para 2>/dev/null || true)
+        else
+            branches=$(PARA_COMPLETION_SCRIPT=1 para _completion_branches --para 2>/dev/null || true)
+        fi
+        if [[ -n "$branches" ]]; then
+            COMPREPLY=($(compgen -W "$branches" -- "$1"))
+        fi
+    fi
+}
+
+_para_complete_remotes() {
+    local remotes
+    if git rev-parse --git-dir >/dev/null 2>&1; then
+        remotes=$(git remote 2>/dev/null)
+        if [[ -n "$remotes" ]]; then
+            COMPREPLY=($(compgen -W "$remotes" -- "$1"))
+        fi
+    fi
+}
 
 _para_complete_shells() {
-    local shells="bash zsh fish"
+    local shells="bash zsh fish powershell"
     COMPREPLY=($(compgen -W "$shells" -- "$1"))
 }
 
 _para_complete_config_commands() {
-    local config_commands="setup auto show edit reset"
+    local config_commands="setup auto show edit reset set get keys"
     COMPREPLY=($(compgen -W "$config_commands" -- "$1"))
 }
 
+_para_complete_config_keys() {
+    local keys
+    # Avoid recursive calls during completion generation
+    if command -v para >/dev/null 2>&1 && [[ -z "$PARA_COMPLETION_SCRIPT" ]]; then
+        keys=$(PARA_COMPLETION_SCRIPT=1 para config keys --quiet 2>/dev/null || true)
+        if [[ -n "$keys" ]]; then
+            COMPREPLY=($(compgen -W "$keys" -S "$2" -- "$1"))
+        fi
+    fi
+}
+
 _para_complete_task_files() {
     local task_files
     task_files=$(find . -maxdepth 1 \( -name "TASK_*.md" -o -name "*.md" -o -name "*.txt" \) 2>/dev/null | sed 's|^\./||')
@@ -124,13 +193,34 @@ _para_completion() {
             return 0
             ;;
         --branch)
-            _para_complete_branches "${cur}"
+            # cancel and resume select a session by branch, finish renames it
+            case "${words[1]}" in
+                cancel|resume)
+                    _para_complete_para_branches "${cur}"
+                    ;;
+                *)
+                    _para_complete_branches "${cur}"
+                    ;;
+            esac
+            return 0
+            ;;
+        --session-branch)
+            _para_complete_para_branches "${cur}"
             return 0
             ;;
-        --target)
+        --target|--apply-from)
             _para_complete_branches "${cur}"
             return 0
             ;;
+        --remote)
+            _para_complete_remotes "${cur}"
+            return 0
+            ;;
+        -c|--config-override)
+            _para_complete_config_keys "${cur}" "="
+            compopt -o nospace 2>/dev/null
+            return 0
+            ;;
     esac
 
     # Command-specific completions
@@ -147,7 +237,16 @@ _para_completion() {
             return 0
             ;;
         config)
-            _para_complete_config_commands "${cur}"
+            case "${words[2]}" in
+                set|get)
+                    if [[ $cword -eq 3 ]]; then
+                        _para_complete_config_keys "${cur}"
+                    fi
+                    ;;
+                *)
+                    _para_complete_config_commands "${cur}"
+                    ;;
+            esac
             return 0
             ;;
         dispatch)
@@ -210,6 +309,26 @@ _para_branches() {
     fi
 }
 
+_para_session_branches() {
+    local branches
+    # Avoid recursive calls during completion generation
+    if [[ -z "$PARA_COMPLETION_SCRIPT" ]]; then
+        if command -v timeout >/dev/null 2>&1; then
+            branches=(${(f)"$(timeout 2 env PARA_COMPLETION_SCRIPT=1 para _completion_branches --para 2>/dev/null || true)"})
+        else
+            branches=(${(f)"$(PARA_COMPLETION_SCRIPT=1 para _completion_branches --para 2>/dev/null || true)"})
+        fi
+        _describe 'session branches' branches
+    fi
+}
+
+_para_remotes() {
+    local remotes
+    if git rev-parse --git-dir >/dev/null 2>&1; then
+        remotes=(${(f)"$(git remote 2>/dev/null)"})
+        _describe 'git remotes' remotes
+    fi
+}
 
 _para_shells() {
     local shells
@@ -217,6 +336,7 @@ _para_shells() {
         'bash:Bash shell completion'
         'zsh:Zsh shell completion'
         'fish:Fish shell completion'
+        'powershell:PowerShell completion'
     )
     _describe 'shell types' shells
 }
@@ -229,10 +349,22 @@ _para_config_commands() {
         'show:Show current configuration'
         'edit:Edit configuration file'
         'reset:Reset configuration to defaults'
+        'set:Set configuration value'
+        'get:Print one configuration value'
+        'keys:List every configuration key'
     )
     _describe 'config commands' config_commands
 }
 
+_para_config_keys() {
+    local keys
+    # Avoid recursive calls during completion generation
+    if [[ -z "$PARA_COMPLETION_SCRIPT" ]]; then
+        keys=(${(f)"$(PARA_COMPLETION_SCRIPT=1 para config keys --quiet 2>/dev/null || true)"})
+        _describe 'config keys' keys "$@"
+    fi
+}
+
 _para_task_files() {
     local task_files
     task_files=(${(f)"$(find . -maxdepth 1 \( -name "TASK_*.md" -o -name "*.md" -o -name "*.txt" \) 2>/dev/null | sed 's|^\./||')"})
@@ -244,6 +376,11 @@ _para() {
     local context state line
     typeset -A opt_args
 
+    if [[ $words[CURRENT-1] == (-c|--config-override) ]]; then
+        _para_config_keys -S '='
+        return 0
+    fi
+
     _arguments \
         '1: :_para_commands' \
         '*::arg:->args' \
@@ -253,7 +390,14 @@ _para() {
         args)
             case $words[1] in
                 resume|cancel)
-                    _para_sessions
+                    case $words[CURRENT-1] in
+                        --branch|--session-branch)
+                            _para_session_branches
+                            ;;
+                        *)
+                            _para_sessions
+                            ;;
+                    esac
                     ;;
                 recover)
                     _para_archived_sessions
@@ -263,6 +407,9 @@ _para() {
                         --branch)
                             _para_branches
                             ;;
+                        --session-branch)
+                            _para_session_branches
+                            ;;
                         *)
                             if [[ $CURRENT -eq 4 ]]; then
                                 _para_sessions
@@ -270,11 +417,33 @@ _para() {
                             ;;
                     esac
                     ;;
+                start)
+                    case $words[CURRENT-1] in
+                        --base|--apply-from)
+                            _para_branches
+                            ;;
+                        --apply-patch)
+                            _files
+                            ;;
+                        --remote)
+                            _para_remotes
+                            ;;
+                    esac
+                    ;;
                 dispatch)
                     case $words[CURRENT-1] in
                         --file|-f)
                             _para_task_files
                             ;;
+                        --apply-from)
+                            _para_branches
+                            ;;
+                        --apply-patch)
+                            _files
+                            ;;
+                        --remote)
+                            _para_remotes
+                            ;;
                         *)
                             if [[ $words[CURRENT] == --* ]]; then
                                 _arguments '--file[Read prompt from file]:file:_files'
@@ -286,7 +455,16 @@ _para() {
                     _para_shells
                     ;;
                 config)
-                    _para_config_commands
+                    case $words[2] in
+                        set|get)
+                            if [[ $CURRENT -eq 3 ]]; then
+                                _para_config_keys
+                            fi
+                            ;;
+                        *)
+                            _para_config_commands
+                            ;;
+                    esac
                     ;;
             esac
             ;;
@@ -323,9 +501,9 @@ function __para_sessions
     if test -z "$PARA_COMPLETION_SCRIPT"
         # Use timeout to prevent hanging on file system operations
         if command -v timeout >/dev/null 2>&1
-            timeout 2 env PARA_COMPLETION_SCRIPT=1 para list --quiet 2>/dev/null | string match -r '^[a-zA-Z0-9_-]*' 2>/dev/null
+            timeout 2 env PARA_COMPLETION_SCRIPT=1 para _completion_sessions 2>/dev/null
         else
-            PARA_COMPLETION_SCRIPT=1 para list --quiet 2>/dev/null | string match -r '^[a-zA-Z0-9_-]*' 2>/dev/null
+            PARA_COMPLETION_SCRIPT=1 para _completion_sessions 2>/dev/null
         end
     end
 end
@@ -343,8 +521,31 @@ function __para_archived_sessions
 end
 
 function __para_branches
-    if git rev-parse --git-dir >/dev/null 2>&1
-        git branch -a 2>/dev/null | sed 's/^[* ]*//' | grep -v '^remotes/origin/HEAD' | sed 's|^remotes/origin/||' | sort -u
+    # Avoid recursive calls during completion generation
+    if test -z "$PARA_COMPLETION_SCRIPT"
+        if command -v timeout >/dev/null 2>&1
+            timeout 2 env PARA_COMPLETION_SCRIPT=1 para _completion_branches 2>/dev/null
+        else
+            PARA_COMPLETION_SCRIPT=1 para _completion_branches 2>/dev/null
+        end
+    end
+end
+
+function __para_session_branches
+    # Avoid recursive calls during completion generation
+    if test -z "$PARA_COMPLETION_SCRIPT"
+        if command -v timeout >/dev/null 2>&1
+            timeout 2 env PARA_COMPLETION_SCRIPT=1 para _completion_branches --para 2>/dev/null
+        else
+            PARA_COMPLETION_SCRIPT=1 para _completion_branches --para 2>/dev/null
+        end
+    end
+end
+
+function __para_config_keys
+    # Avoid recursive calls during completion generation
+    if test -z "$PARA_COMPLETION_SCRIPT"
+        PARA_COMPLETION_SCRIPT=1 para config keys --quiet 2>/dev/null
     end
 end
 
@@ -371,17 +572,41 @@ complete -f -c para -n "__fish_para_using_subcommand finish; and __para_finish_n
 # para finish --branch <branch>
 complete -f -c para -n "__fish_para_using_subcommand finish" -l branch -a "(__para_branches)" -d "Custom branch name"
 
+# para finish --session-branch <branch>, para cancel|resume --branch <branch>
+complete -f -c para -n "__fish_para_using_subcommand finish" -l session-branch -a "(__para_session_branches)" -d "Session branch"
+complete -f -c para -n "__fish_para_using_subcommand cancel resume" -l branch -a "(__para_session_branches)" -d "Session branch"
+
+# para start --base <branch>
+complete -f -c para -n "__fish_para_using_subcommand start" -l base -a "(__para_branches)" -d "Base branch"
+
+# para start|dispatch --apply-from <branch>
+complete -f -c para -n "__fish_para_using_subcommand start dispatch" -l apply-from -a "(__para_branches)" -d "Branch to start from"
+
+# 3. REMOTE COMPLETIONS
+# para start|dispatch --remote <remote>
+complete -f -c para -n "__fish_para_using_subcommand start dispatch" -l remote -a "(git remote 2>/dev/null)" -d "Git remote"
+
 # 4. FILE COMPLETIONS
 # para dispatch --file <file>
 complete -c para -n "__fish_para_using_subcommand dispatch" -s f -l file -F -d "Prompt file"
 
 # 5. SHELL COMPLETIONS  
 # para completion <shell>
-complete -f -c para -n "__fish_para_using_subcommand completion" -a "bash zsh fish" -d "Shell type"
+complete -f -c para -n "__fish_para_using_subcommand completion" -a "bash zsh fish powershell" -d "Shell type"
 
 # 6. CONFIG SUBCOMMAND COMPLETIONS
 # para config <subcommand>
-complete -f -c para -n "__fish_para_using_subcommand config" -a "setup auto show edit reset" -d "Config operation"
+complete -f -c para -n "__fish_para_using_subcommand config" -a "setup auto show edit reset set get keys" -d "Config operation"
+
+# para config set|get <key>
+function __para_config_needs_key
+    set -l cmd (commandline -opc)
+    test (count $cmd) -eq 3; and contains -- $cmd[3] set get
+end
+complete -f -c para -n "__fish_para_using_subcommand config; and __para_config_needs_key" -a "(__para_config_keys)" -d "Config key"
+
+# para -c <key>=<value>
+complete -f -c para -s c -l config-override -a "(__para_config_keys | string replace -r '\$' '=')" -d "Config key"
 
 # 7. SPECIAL COMPLETIONS FOR TASK FILES
 # Enhanced file completion for dispatch that prioritizes .md files and TASK_* files
@@ -393,6 +618,81 @@ complete -c para -n "__fish_para_using_subcommand dispatch" -s f -l file -a "(__
 "#.to_string()
     }
 
+    fn generate_powershell_dynamic() -> String {
+        r#"
+# Para completion helper functions for PowerShell
+function Get-ParaCompletionValues([string]$Kind) {
+    # Avoid recursive calls during completion generation
+    if ($env:PARA_COMPLETION_SCRIPT) { return }
+    $env:PARA_COMPLETION_SCRIPT = '1'
+    try {
+        switch ($Kind) {
+            'sessions' { para _completion_sessions 2>$null }
+            'archived' { para list --archived --quiet 2>$null | ForEach-Object { ($_ -split '\s+')[0] } }
+            'branches' { para _completion_branches 2>$null }
+            'session-branches' { para _completion_branches --para 2>$null }
+            'remotes' { git remote 2>$null }
+            'config-keys' { para config keys --quiet 2>$null }
+        }
+    } finally {
+        Remove-Item Env:PARA_COMPLETION_SCRIPT -ErrorAction SilentlyContinue
+    }
+}
+
+function Get-ParaDynamicCompletions($CommandElements, [string]$WordToComplete) {
+    if ($CommandElements.Count -lt 2 -or $WordToComplete.StartsWith('-')) { return }
+    $subcommand = "$($CommandElements[1])"
+    $previousIndex = if ($WordToComplete) { $CommandElements.Count - 2 } else { $CommandElements.Count - 1 }
+    $previous = "$($CommandElements[$previousIndex])"
+
+    # -c <key>=<value>
+    if ($previous -in '-c', '--config-override') {
+        return Get-ParaCompletionValues 'config-keys' |
+            Where-Object { $_ -like "$WordToComplete*" } |
+            ForEach-Object {
+                [System.Management.Automation.CompletionResult]::new("$_=", $_, [System.Management.Automation.CompletionResultType]::ParameterValue, $_)
+            }
+    }
+
+    $kind = switch ($subcommand) {
+        { $_ -in 'resume', 'cancel' } {
+            if ($previous -in '--branch', '--session-branch') { 'session-branches' }
+            elseif ($previousIndex -eq 1) { 'sessions' }
+            break
+        }
+        'recover' {
+            if ($previousIndex -eq 1) { 'archived' }
+            break
+        }
+        'finish' {
+            # para finish <message> <session>
+            if ($previous -eq '--branch') { 'branches' }
+            elseif ($previous -eq '--session-branch') { 'session-branches' }
+            elseif ($previousIndex -ge 2 -and -not $previous.StartsWith('-')) { 'sessions' }
+            break
+        }
+        { $_ -in 'start', 'dispatch' } {
+            if ($previous -in '--base', '--apply-from') { 'branches' }
+            elseif ($previous -eq '--remote') { 'remotes' }
+            break
+        }
+        'config' {
+            # para config set|get <key>
+            if ($previousIndex -eq 2 -and $previous -in 'set', 'get') { 'config-keys' }
+            break
+        }
+    }
+    if (-not $kind) { return }
+
+    Get-ParaCompletionValues $kind |
+        Where-Object { $_ -like "$WordToComplete*" } |
+        ForEach-Object {
+            [System.Management.Automation.CompletionResult]::new($_, $_, [System.Management.Automation.CompletionResultType]::ParameterValue, $_)
+        }
+}
+"#.to_string()
+    }
+
     pub fn get_installation_instructions(shell: Shell) -> String {
         match shell {
             Shell::Bash => r#"# Installation instructions for Bash completion:
@@ -440,6 +740,17 @@ para completion fish | sudo tee /usr/share/fish/vendor_completions.d/para.fish
 
 # Fish will automatically load the completion on next shell start"#
                 .to_string(),
+            Shell::PowerShell => r#"# Installation instructions for PowerShell completion:
+
+# Option 1: Install for current user and load it from your profile
+para completion install powershell
+
+# Option 2: Add to your $PROFILE
+Add-Content $PROFILE 'para completion generate powershell | Out-String | Invoke-Expression'
+
+# Then reload your profile:
+. $PROFILE"#
+                .to_string(),
         }
     }
 }
@@ -0,0 +1,1121 @@
+# Print an optspec for argparse to handle cmd's options that are independent of any subcommand.
+function __fish_para_global_optspecs
+	string join \n offline ci= state-dir= config= ignore-version c/config-override= h/help V/version
+end
+
+function __fish_para_needs_command
+	# Figure out if the current invocation already has a command.
+	set -l cmd (commandline -opc)
+	set -e cmd[1]
+	argparse -s (__fish_para_global_optspecs) -- $cmd 2>/dev/null
+	or return
+	if set -q argv[1]
+		# Also print the command, so this can be used to figure out what it is.
+		echo $argv[1]
+		return 1
+	end
+	return 0
+end
+
+function __fish_para_using_subcommand
+	set -l cmd (__fish_para_needs_command)
+	test -z "$cmd"
+	and return 1
+	contains -- $cmd[1] $argv
+end
+
+complete -c para -n "__fish_para_needs_command" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_needs_command" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_needs_command" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_needs_command" -s c -l config-override -d 'Override a config value for this command only (repeatable, not saved)' -r
+complete -c para -n "__fish_para_needs_command" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_needs_command" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_needs_command" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_needs_command" -s V -l version -d 'Print version'
+complete -c para -n "__fish_para_needs_command" -f -a "start" -d 'Create new para sessions (interactive or AI-assisted)'
+complete -c para -n "__fish_para_needs_command" -f -a "finish" -d 'Complete session and create feature branch for review'
+complete -c para -n "__fish_para_needs_command" -f -a "rollback-integration" -d 'Undo finishing a session onto another session\'s branch'
+complete -c para -n "__fish_para_needs_command" -f -a "cancel" -d 'Cancel session (moves to archive)'
+complete -c para -n "__fish_para_needs_command" -f -a "clean" -d 'Remove stale para artifacts (and optionally all active sessions)'
+complete -c para -n "__fish_para_needs_command" -f -a "restore-trash" -d 'Put an item `para clean` moved to the trash back in place'
+complete -c para -n "__fish_para_needs_command" -f -a "gc" -d 'Compact logs and remove leftover files in the state directory'
+complete -c para -n "__fish_para_needs_command" -f -a "doctor" -d 'Report version skew between the CLI, daemon, config and sessions'
+complete -c para -n "__fish_para_needs_command" -f -a "upgrade-state" -d 'Upgrade all session state files to the current format, or restore a backup'
+complete -c para -n "__fish_para_needs_command" -f -a "list" -d 'List active sessions'
+complete -c para -n "__fish_para_needs_command" -f -a "resume" -d 'Resume session in IDE'
+complete -c para -n "__fish_para_needs_command" -f -a "recover" -d 'Recover cancelled session from archive'
+complete -c para -n "__fish_para_needs_command" -f -a "adopt" -d 'Turn an existing branch into a session'
+complete -c para -n "__fish_para_needs_command" -f -a "checkpoint" -d 'Commit pending work in a session as a WIP checkpoint'
+complete -c para -n "__fish_para_needs_command" -f -a "sync" -d 'Rebase a session onto the latest commit of the branch it was started from'
+complete -c para -n "__fish_para_needs_command" -f -a "note" -d 'Add, edit or show notes about a session'
+complete -c para -n "__fish_para_needs_command" -f -a "link" -d 'Attach links to PRs, tickets and docs to a session'
+complete -c para -n "__fish_para_needs_command" -f -a "pin" -d 'Keep a session out of stale and idle detection and bulk cleanups'
+complete -c para -n "__fish_para_needs_command" -f -a "unpin" -d 'Undo `para pin`'
+complete -c para -n "__fish_para_needs_command" -f -a "launch" -d 'Launch Claude in a session prepared with --plan-only'
+complete -c para -n "__fish_para_needs_command" -f -a "self-update" -d 'Check for a newer para release and update to it'
+complete -c para -n "__fish_para_needs_command" -f -a "audit" -d 'Show the git commands para ran on the repository'
+complete -c para -n "__fish_para_needs_command" -f -a "conflicts" -d 'Show how earlier merge conflicts were resolved'
+complete -c para -n "__fish_para_needs_command" -f -a "which" -d 'Print the session the current directory belongs to'
+complete -c para -n "__fish_para_needs_command" -f -a "statusline" -d 'Print a one-line summary of the current session for status bars'
+complete -c para -n "__fish_para_needs_command" -f -a "summary" -d 'Summarize a session\'s task, commits, changes and status history'
+complete -c para -n "__fish_para_needs_command" -f -a "logs" -d 'Show the setup script, container and daemon logs of a session'
+complete -c para -n "__fish_para_needs_command" -f -a "examples" -d 'Show example command lines for common workflows'
+complete -c para -n "__fish_para_needs_command" -f -a "config" -d 'Setup configuration'
+complete -c para -n "__fish_para_needs_command" -f -a "completion" -d 'Generate shell completion script'
+complete -c para -n "__fish_para_needs_command" -f -a "init" -d 'Initialize shell completions automatically'
+complete -c para -n "__fish_para_needs_command" -f -a "mcp" -d 'Setup Model Context Protocol (MCP) integration'
+complete -c para -n "__fish_para_needs_command" -f -a "_completion_sessions" -d 'Legacy completion endpoint for sessions (hidden)'
+complete -c para -n "__fish_para_needs_command" -f -a "_completion_branches" -d 'Legacy completion endpoint for branches (hidden)'
+complete -c para -n "__fish_para_needs_command" -f -a "monitor" -d 'Monitor and manage active sessions in real-time (interactive TUI with mouse support)'
+complete -c para -n "__fish_para_needs_command" -f -a "status" -d 'Update session status (for agents to communicate progress)'
+complete -c para -n "__fish_para_needs_command" -f -a "auth" -d 'Manage Docker container authentication'
+complete -c para -n "__fish_para_needs_command" -f -a "daemon" -d 'Manage para daemon (internal use)'
+complete -c para -n "__fish_para_needs_command" -f -a "proxy" -d 'Run network proxy for sandboxing (internal use)'
+complete -c para -n "__fish_para_needs_command" -f -a "selftest" -d 'Run the session lifecycle in a throwaway repository to check the installation'
+complete -c para -n "__fish_para_needs_command" -f -a "integrate" -d '[deprecated] Finish the current session; use `para finish`'
+complete -c para -n "__fish_para_needs_command" -f -a "continue" -d '[deprecated] Carry on with a sync stopped on conflicts; use `git rebase --continue` and `para sync`'
+complete -c para -n "__fish_para_needs_command" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c para -n "__fish_para_using_subcommand start" -s p -l prompt -d 'Prompt for AI-assisted session' -r
+complete -c para -n "__fish_para_using_subcommand start" -s f -l file -l prompt-file -d 'Read prompt or context from specified file' -r -F
+complete -c para -n "__fish_para_using_subcommand start" -l allow-domains -d 'Enable network isolation and allow access to specified domains (comma-separated)' -r
+complete -c para -n "__fish_para_using_subcommand start" -l docker-args -d 'Additional Docker arguments to pass through' -r
+complete -c para -n "__fish_para_using_subcommand start" -l setup-script -d 'Path to setup script to run after session creation' -r -F
+complete -c para -n "__fish_para_using_subcommand start" -l docker-image -d 'Custom Docker image to use (e.g., \'ubuntu:22.04\')' -r
+complete -c para -n "__fish_para_using_subcommand start" -l copy -d 'Copy untracked files matching GLOB (e.g. \'.env\', \'**/local.settings.json\') from the main worktree into the session (repeatable)' -r
+complete -c para -n "__fish_para_using_subcommand start" -l merge-mode -d 'Merge mode recorded for the session and used by finish (default: git.default_merge_mode or squash)' -r -f -a "squash\t'Collapse everything since the session base into one commit'
+preserve\t'Keep the session\'s commits as they are'
+rebase\t'Keep the commits but replay them on top of the parent branch'"
+complete -c para -n "__fish_para_using_subcommand start" -l apply-patch -d 'Apply this patch file (git apply --3way) to the new session as uncommitted work' -r -F
+complete -c para -n "__fish_para_using_subcommand start" -l apply-from -d 'Apply the diff of BRANCH against the session\'s base to the new session as uncommitted work' -r
+complete -c para -n "__fish_para_using_subcommand start" -l base -d 'Create the session from this branch, commit or session instead of the current branch (a session name stacks the new session on it)' -r
+complete -c para -n "__fish_para_using_subcommand start" -l remote -d 'Remote to fetch --base from (default: git.default_remote, then origin)' -r
+complete -c para -n "__fish_para_using_subcommand start" -l retry -d 'Start a new session with the task and settings of SESSION (active or cancelled); the positional argument or --prompt adds extra instructions' -r
+complete -c para -n "__fish_para_using_subcommand start" -l sandbox-profile -d 'Sandbox profile to use: permissive (default) or restrictive' -r
+complete -c para -n "__fish_para_using_subcommand start" -l allowed-domains -d 'Additional domains allowed through network proxy (e.g., npmjs.org,pypi.org)' -r
+complete -c para -n "__fish_para_using_subcommand start" -l template -d 'Use the options of a template from the config as defaults (see \'para config templates list\')' -r
+complete -c para -n "__fish_para_using_subcommand start" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand start" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand start" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand start" -l prompt-is-file -d 'Read the prompt from the file named by --prompt, which is otherwise taken as text'
+complete -c para -n "__fish_para_using_subcommand start" -l no-stdin -d 'Ignore piped stdin, for scripts that cannot control their stdin'
+complete -c para -n "__fish_para_using_subcommand start" -l allow-secrets -d 'Don\'t warn about or block prompts that look like they contain secrets (tokens, keys, passwords)'
+complete -c para -n "__fish_para_using_subcommand start" -s d -l dangerously-skip-permissions -d 'Skip IDE permission warnings (dangerous)'
+complete -c para -n "__fish_para_using_subcommand start" -s c -l container -d 'Run session in Docker container'
+complete -c para -n "__fish_para_using_subcommand start" -l no-forward-keys -d 'Disable automatic API key forwarding to Docker containers'
+complete -c para -n "__fish_para_using_subcommand start" -l allow-empty-repo -d 'Create an empty initial commit if the repository has no commits yet'
+complete -c para -n "__fish_para_using_subcommand start" -l copy-large -d 'Also copy gitignored files larger than 1 MiB when copying untracked files'
+complete -c para -n "__fish_para_using_subcommand start" -l no-hook-fixup -d 'Don\'t point an absolute core.hooksPath inside the repository at the new worktree'
+complete -c para -n "__fish_para_using_subcommand start" -l no-auto-commit -d 'Disable automatic WIP checkpoint commits for this session'
+complete -c para -n "__fish_para_using_subcommand start" -l fetch -d 'Fetch --base from the remote first and start from the fetched commit'
+complete -c para -n "__fish_para_using_subcommand start" -l cancel-old -d 'Cancel the --retry session after the new session has started'
+complete -c para -n "__fish_para_using_subcommand start" -l plan-only -d 'Create the worktree, state and task file but do not launch Claude; start it later with \'para launch\''
+complete -c para -n "__fish_para_using_subcommand start" -l defer-setup -d 'With --plan-only, run the setup script when the session is launched instead of now'
+complete -c para -n "__fish_para_using_subcommand start" -s s -l sandbox -d 'Enable sandboxing for Claude CLI (overrides config)'
+complete -c para -n "__fish_para_using_subcommand start" -l no-sandbox -d 'Disable sandboxing for Claude CLI (overrides config)'
+complete -c para -n "__fish_para_using_subcommand start" -l sandbox-no-network -d 'Enable sandboxing with network isolation via proxy'
+complete -c para -n "__fish_para_using_subcommand start" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand start" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand start" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand finish" -s b -l branch -d 'Rename feature branch to specified name' -r
+complete -c para -n "__fish_para_using_subcommand finish" -l session-branch -d 'Finish the session whose branch is BRANCH (--branch renames the branch here)' -r
+complete -c para -n "__fish_para_using_subcommand finish" -l merge-mode -d 'Shape the session\'s commits this way instead of using its recorded merge mode' -r -f -a "squash\t'Collapse everything since the session base into one commit'
+preserve\t'Keep the session\'s commits as they are'
+rebase\t'Keep the commits but replay them on top of the parent branch'"
+complete -c para -n "__fish_para_using_subcommand finish" -l onto-session -d 'Commit the session\'s changes onto the branch of active session SESSION (stacked sessions)' -r
+complete -c para -n "__fish_para_using_subcommand finish" -l remote-branch -d 'Finish branch BRANCH from origin in a plain clone (e.g. in CI) and push it and --base' -r
+complete -c para -n "__fish_para_using_subcommand finish" -l base -d 'Branch on origin that --remote-branch is finished onto and pushed to' -r
+complete -c para -n "__fish_para_using_subcommand finish" -l wait -d 'If another git process holds the repository\'s index.lock, wait up to SECONDS for it instead of failing' -r
+complete -c para -n "__fish_para_using_subcommand finish" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand finish" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand finish" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand finish" -l force-rename -d 'Temporarily detach other worktrees that have the session branch checked out'
+complete -c para -n "__fish_para_using_subcommand finish" -l allow-protected -d 'Proceed even if a protected branch (e.g. main) is checked out in the session worktree'
+complete -c para -n "__fish_para_using_subcommand finish" -l check -d 'Check whether the session merges cleanly into its base branch without changing anything (exit code 6 on conflicts)'
+complete -c para -n "__fish_para_using_subcommand finish" -l staged-only -d 'Commit only the staged changes; unstaged and untracked changes stay in the worktree'
+complete -c para -n "__fish_para_using_subcommand finish" -l json -d 'Print the result as one JSON object on stdout; everything else goes to stderr'
+complete -c para -n "__fish_para_using_subcommand finish" -l unshallow -d 'In a shallow clone, fetch the missing history from the remote first instead of failing'
+complete -c para -n "__fish_para_using_subcommand finish" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand finish" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand finish" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand rollback-integration" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand rollback-integration" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand rollback-integration" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand rollback-integration" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand rollback-integration" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand rollback-integration" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand cancel" -l branch -l session-branch -d 'Cancel the session whose branch is BRANCH' -r
+complete -c para -n "__fish_para_using_subcommand cancel" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand cancel" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand cancel" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand cancel" -s f -l force -d 'Force cancellation even with uncommitted changes, active stacked sessions or a pin (destructive)'
+complete -c para -n "__fish_para_using_subcommand cancel" -l force-rename -d 'Temporarily detach other worktrees that have the session branch checked out'
+complete -c para -n "__fish_para_using_subcommand cancel" -l allow-protected -d 'Proceed even if a protected branch (e.g. main) is checked out in the session worktree'
+complete -c para -n "__fish_para_using_subcommand cancel" -l close-ide -d 'Close the IDE window first if it still has the session open'
+complete -c para -n "__fish_para_using_subcommand cancel" -l dry-run -d 'Check whether the session can be cancelled and show its archive branch without changing anything'
+complete -c para -n "__fish_para_using_subcommand cancel" -l json -d 'Print the result as one JSON object on stdout; everything else goes to stderr'
+complete -c para -n "__fish_para_using_subcommand cancel" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand cancel" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand cancel" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand clean" -l only -d 'Only remove these kinds of items this run (comma-separated, overrides cleanup.enabled_kinds)' -r -f -a "stale_branch\t'Para branch without a session or archive entry'
+orphaned_state_file\t'State file whose session branch is gone'
+old_archive\t'Archived session older than `session.auto_cleanup_days`'
+stale_status_file\t'Agent status file that has not been updated for a day'
+orphaned_container\t'Para container without a session (`--containers`)'
+docker_resource\t'Dangling para-labeled volume, network or image (`--docker`)'
+active_session\t'Active session to cancel and archive (`--sessions`)'"
+complete -c para -n "__fish_para_using_subcommand clean" -l skip -d 'Leave these kinds of items alone this run (comma-separated)' -r -f -a "stale_branch\t'Para branch without a session or archive entry'
+orphaned_state_file\t'State file whose session branch is gone'
+old_archive\t'Archived session older than `session.auto_cleanup_days`'
+stale_status_file\t'Agent status file that has not been updated for a day'
+orphaned_container\t'Para container without a session (`--containers`)'
+docker_resource\t'Dangling para-labeled volume, network or image (`--docker`)'
+active_session\t'Active session to cancel and archive (`--sessions`)'"
+complete -c para -n "__fish_para_using_subcommand clean" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand clean" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand clean" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand clean" -s f -l force -d 'Skip confirmation prompts'
+complete -c para -n "__fish_para_using_subcommand clean" -s i -l interactive -d 'Choose the items to clean from a checklist instead of confirming all of them'
+complete -c para -n "__fish_para_using_subcommand clean" -l dry-run -d 'Only show what would be cleaned (dry run)'
+complete -c para -n "__fish_para_using_subcommand clean" -l backups -d 'Also remove archived sessions'
+complete -c para -n "__fish_para_using_subcommand clean" -l containers -d 'Clean orphaned Docker containers'
+complete -c para -n "__fish_para_using_subcommand clean" -l docker -d 'Also prune dangling para-labeled Docker volumes, networks and images'
+complete -c para -n "__fish_para_using_subcommand clean" -l sessions -d 'Also cancel and archive every active session that is not pinned (requires typing \'yes\', or --force)'
+complete -c para -n "__fish_para_using_subcommand clean" -l close-ide -d 'Close the IDE window of sessions that are still open before cancelling them'
+complete -c para -n "__fish_para_using_subcommand clean" -l empty-trash -d 'Permanently delete everything in the trash now, whatever its age'
+complete -c para -n "__fish_para_using_subcommand clean" -l list-trash -d 'List trashed state files and archive branches and when they expire'
+complete -c para -n "__fish_para_using_subcommand clean" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand clean" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand clean" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand restore-trash" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand restore-trash" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand restore-trash" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand restore-trash" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand restore-trash" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand restore-trash" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand gc" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand gc" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand gc" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand gc" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand gc" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand gc" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand doctor" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand doctor" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand doctor" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand doctor" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand doctor" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand doctor" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand upgrade-state" -l restore -d 'Replace the state directory with a backup made before a migration' -r
+complete -c para -n "__fish_para_using_subcommand upgrade-state" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand upgrade-state" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand upgrade-state" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand upgrade-state" -l dry-run -d 'Only show which state files would be upgraded and the available backups'
+complete -c para -n "__fish_para_using_subcommand upgrade-state" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand upgrade-state" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand upgrade-state" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand list" -l format -d 'Format each session with a template, e.g. \'{name}\\t{branch}\\t{path}\' Placeholders: {name}, {branch}, {base}, {status}, {path}, {last_modified}, {type}, {note}' -r
+complete -c para -n "__fish_para_using_subcommand list" -l sort -d 'Sort sessions by name, creation time, most recent activity or status' -r -f -a "name\t'Alphabetically by session name'
+created\t'Newest session first'
+activity\t'Most recent file change, commit or status update first'
+status\t'Sessions needing attention (dirty, diverged, missing) first'"
+complete -c para -n "__fish_para_using_subcommand list" -l since -d 'Only show sessions created since a duration ago (30m, 12h, 3d, 2w) or a date (2024-03-01)' -r
+complete -c para -n "__fish_para_using_subcommand list" -l until -d 'Only show sessions created until a duration ago (30m, 12h, 3d, 2w) or a date (2024-03-01)' -r
+complete -c para -n "__fish_para_using_subcommand list" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand list" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand list" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand list" -s v -l verbose -d 'Show verbose session information'
+complete -c para -n "__fish_para_using_subcommand list" -s a -l archived -d 'Show archived sessions'
+complete -c para -n "__fish_para_using_subcommand list" -l orphaned -d 'Show para branches that have no session or archive entry'
+complete -c para -n "__fish_para_using_subcommand list" -s q -l quiet -d 'Quiet output for completion'
+complete -c para -n "__fish_para_using_subcommand list" -l reverse -d 'Reverse the sort order'
+complete -c para -n "__fish_para_using_subcommand list" -l no-color -d 'Disable colors (also disabled by NO_COLOR or when not on a terminal)'
+complete -c para -n "__fish_para_using_subcommand list" -l full-paths -d 'Show worktree paths in full instead of shortening them to fit the terminal'
+complete -c para -n "__fish_para_using_subcommand list" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand list" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand list" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand resume" -l branch -l session-branch -d 'Resume the session whose branch is BRANCH' -r
+complete -c para -n "__fish_para_using_subcommand resume" -s p -l prompt -d 'Additional prompt or instructions for the resumed session' -r
+complete -c para -n "__fish_para_using_subcommand resume" -s f -l file -d 'Read additional instructions from specified file' -r -F
+complete -c para -n "__fish_para_using_subcommand resume" -l run -d 'Run CMD in the session before opening it, after session.on_resume (repeatable)' -r
+complete -c para -n "__fish_para_using_subcommand resume" -l sandbox-profile -d 'Sandbox profile to use: permissive (default) or restrictive' -r
+complete -c para -n "__fish_para_using_subcommand resume" -l allowed-domains -d 'Additional domains allowed through network proxy (e.g., npmjs.org,pypi.org)' -r
+complete -c para -n "__fish_para_using_subcommand resume" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand resume" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand resume" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand resume" -l dangerously-skip-permissions -d 'Skip IDE permission warnings (DANGEROUS: Only use for automated scripts)'
+complete -c para -n "__fish_para_using_subcommand resume" -l allow-secrets -d 'Don\'t warn about or block prompts that look like they contain secrets (tokens, keys, passwords)'
+complete -c para -n "__fish_para_using_subcommand resume" -l run-ignore-errors -d 'Open the session even if a resume command fails'
+complete -c para -n "__fish_para_using_subcommand resume" -s s -l sandbox -d 'Enable sandboxing for Claude CLI (overrides config)'
+complete -c para -n "__fish_para_using_subcommand resume" -l no-sandbox -d 'Disable sandboxing for Claude CLI (overrides config)'
+complete -c para -n "__fish_para_using_subcommand resume" -l sandbox-no-network -d 'Enable sandboxing with network isolation via proxy'
+complete -c para -n "__fish_para_using_subcommand resume" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand resume" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand resume" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand recover" -l since -d 'Only recover sessions archived within a duration (30m, 12h, 3d, 2w) or since a date (2024-03-01)' -r
+complete -c para -n "__fish_para_using_subcommand recover" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand recover" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand recover" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand recover" -l all -d 'Recover every archived session'
+complete -c para -n "__fish_para_using_subcommand recover" -l dry-run -d 'Only show what would be recovered (dry run)'
+complete -c para -n "__fish_para_using_subcommand recover" -s y -l yes -d 'Restore without asking for confirmation'
+complete -c para -n "__fish_para_using_subcommand recover" -l replace -d 'When a branch with the session\'s name has diverged from the archive, archive it and restore under the original name instead of <name>-recovered-N'
+complete -c para -n "__fish_para_using_subcommand recover" -l json -d 'Print the result as one JSON object on stdout; everything else goes to stderr'
+complete -c para -n "__fish_para_using_subcommand recover" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand recover" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand recover" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand adopt" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand adopt" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand adopt" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand adopt" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand adopt" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand adopt" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand checkpoint" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand checkpoint" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand checkpoint" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand checkpoint" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand checkpoint" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand checkpoint" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand sync" -l wait -d 'If another git process holds the repository\'s index.lock, wait up to SECONDS for it instead of failing' -r
+complete -c para -n "__fish_para_using_subcommand sync" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand sync" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand sync" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand sync" -l unshallow -d 'In a shallow clone, fetch the missing history from the remote first instead of failing'
+complete -c para -n "__fish_para_using_subcommand sync" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand sync" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand sync" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand note" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand note" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand note" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand note" -l show -d 'Print the session\'s notes instead of adding one'
+complete -c para -n "__fish_para_using_subcommand note" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand note" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand note" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand link; and not __fish_seen_subcommand_from add remove list help" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand link; and not __fish_seen_subcommand_from add remove list help" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand link; and not __fish_seen_subcommand_from add remove list help" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand link; and not __fish_seen_subcommand_from add remove list help" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand link; and not __fish_seen_subcommand_from add remove list help" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand link; and not __fish_seen_subcommand_from add remove list help" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand link; and not __fish_seen_subcommand_from add remove list help" -f -a "add" -d 'Add a link to a session, or point an existing label at a new URL'
+complete -c para -n "__fish_para_using_subcommand link; and not __fish_seen_subcommand_from add remove list help" -f -a "remove" -d 'Remove a link by label or URL'
+complete -c para -n "__fish_para_using_subcommand link; and not __fish_seen_subcommand_from add remove list help" -f -a "list" -d 'List a session\'s links'
+complete -c para -n "__fish_para_using_subcommand link; and not __fish_seen_subcommand_from add remove list help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c para -n "__fish_para_using_subcommand link; and __fish_seen_subcommand_from add" -s l -l label -d 'Label for the link, e.g. PR or JIRA-123' -r
+complete -c para -n "__fish_para_using_subcommand link; and __fish_seen_subcommand_from add" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand link; and __fish_seen_subcommand_from add" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand link; and __fish_seen_subcommand_from add" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand link; and __fish_seen_subcommand_from add" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand link; and __fish_seen_subcommand_from add" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand link; and __fish_seen_subcommand_from add" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand link; and __fish_seen_subcommand_from remove" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand link; and __fish_seen_subcommand_from remove" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand link; and __fish_seen_subcommand_from remove" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand link; and __fish_seen_subcommand_from remove" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand link; and __fish_seen_subcommand_from remove" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand link; and __fish_seen_subcommand_from remove" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand link; and __fish_seen_subcommand_from list" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand link; and __fish_seen_subcommand_from list" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand link; and __fish_seen_subcommand_from list" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand link; and __fish_seen_subcommand_from list" -l json -d 'Output as JSON'
+complete -c para -n "__fish_para_using_subcommand link; and __fish_seen_subcommand_from list" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand link; and __fish_seen_subcommand_from list" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand link; and __fish_seen_subcommand_from list" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand link; and __fish_seen_subcommand_from help" -f -a "add" -d 'Add a link to a session, or point an existing label at a new URL'
+complete -c para -n "__fish_para_using_subcommand link; and __fish_seen_subcommand_from help" -f -a "remove" -d 'Remove a link by label or URL'
+complete -c para -n "__fish_para_using_subcommand link; and __fish_seen_subcommand_from help" -f -a "list" -d 'List a session\'s links'
+complete -c para -n "__fish_para_using_subcommand link; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c para -n "__fish_para_using_subcommand pin" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand pin" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand pin" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand pin" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand pin" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand pin" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand unpin" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand unpin" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand unpin" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand unpin" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand unpin" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand unpin" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand launch" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand launch" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand launch" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand launch" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand launch" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand launch" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand self-update" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand self-update" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand self-update" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand self-update" -l check -d 'Only report whether a newer release exists and how to install it'
+complete -c para -n "__fish_para_using_subcommand self-update" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand self-update" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand self-update" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand audit; and not __fish_seen_subcommand_from show help" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand audit; and not __fish_seen_subcommand_from show help" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand audit; and not __fish_seen_subcommand_from show help" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand audit; and not __fish_seen_subcommand_from show help" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand audit; and not __fish_seen_subcommand_from show help" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand audit; and not __fish_seen_subcommand_from show help" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand audit; and not __fish_seen_subcommand_from show help" -f -a "show" -d 'Show logged git commands for a session'
+complete -c para -n "__fish_para_using_subcommand audit; and not __fish_seen_subcommand_from show help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c para -n "__fish_para_using_subcommand audit; and __fish_seen_subcommand_from show" -l since -d 'Only show commands run within a duration (30m, 12h, 3d, 2w) or since a date (2024-03-01)' -r
+complete -c para -n "__fish_para_using_subcommand audit; and __fish_seen_subcommand_from show" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand audit; and __fish_seen_subcommand_from show" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand audit; and __fish_seen_subcommand_from show" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand audit; and __fish_seen_subcommand_from show" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand audit; and __fish_seen_subcommand_from show" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand audit; and __fish_seen_subcommand_from show" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand audit; and __fish_seen_subcommand_from help" -f -a "show" -d 'Show logged git commands for a session'
+complete -c para -n "__fish_para_using_subcommand audit; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c para -n "__fish_para_using_subcommand conflicts; and not __fish_seen_subcommand_from show help" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand conflicts; and not __fish_seen_subcommand_from show help" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand conflicts; and not __fish_seen_subcommand_from show help" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand conflicts; and not __fish_seen_subcommand_from show help" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand conflicts; and not __fish_seen_subcommand_from show help" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand conflicts; and not __fish_seen_subcommand_from show help" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand conflicts; and not __fish_seen_subcommand_from show help" -f -a "show" -d 'Show recorded conflict resolutions, oldest first'
+complete -c para -n "__fish_para_using_subcommand conflicts; and not __fish_seen_subcommand_from show help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c para -n "__fish_para_using_subcommand conflicts; and __fish_seen_subcommand_from show" -l file -d 'Only show conflicts in this file, relative to the repository root' -r
+complete -c para -n "__fish_para_using_subcommand conflicts; and __fish_seen_subcommand_from show" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand conflicts; and __fish_seen_subcommand_from show" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand conflicts; and __fish_seen_subcommand_from show" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand conflicts; and __fish_seen_subcommand_from show" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand conflicts; and __fish_seen_subcommand_from show" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand conflicts; and __fish_seen_subcommand_from show" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand conflicts; and __fish_seen_subcommand_from help" -f -a "show" -d 'Show recorded conflict resolutions, oldest first'
+complete -c para -n "__fish_para_using_subcommand conflicts; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c para -n "__fish_para_using_subcommand which" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand which" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand which" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand which" -l manifest -d 'Print the session\'s .para/session.json manifest'
+complete -c para -n "__fish_para_using_subcommand which" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand which" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand which" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand statusline" -l format -d 'Format the line with a template (default: \'{name}{dirty} {ahead}{behind} {tests} {blocked}\') Placeholders: {name}, {branch}, {dirty}, {ahead}, {behind}, {tests}, {blocked}' -r
+complete -c para -n "__fish_para_using_subcommand statusline" -l max-width -d 'Cut the line to N characters, ending in …' -r
+complete -c para -n "__fish_para_using_subcommand statusline" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand statusline" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand statusline" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand statusline" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand statusline" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand statusline" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand summary" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand summary" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand summary" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand summary" -l json -d 'Print the summary as JSON instead of Markdown'
+complete -c para -n "__fish_para_using_subcommand summary" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand summary" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand summary" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand logs" -l source -d 'Only show this source; repeat for several' -r -f -a "setup\t'Output of the setup script'
+container\t'Output of the session container'
+daemon\t'Daemon entries about the session in para.log'"
+complete -c para -n "__fish_para_using_subcommand logs" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand logs" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand logs" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand logs" -s f -l follow -d 'Keep printing new output until interrupted'
+complete -c para -n "__fish_para_using_subcommand logs" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand logs" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand logs" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand examples" -l search -d 'Search the titles and descriptions of all examples; close spellings match too' -r
+complete -c para -n "__fish_para_using_subcommand examples" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand examples" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand examples" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand examples" -l no-color -d 'Disable colors (also disabled by NO_COLOR or when not on a terminal)'
+complete -c para -n "__fish_para_using_subcommand examples" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand examples" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand examples" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand config; and not __fish_seen_subcommand_from setup auto show edit reset set get keys session project notify templates path doctor export import help" -l section -d 'Only run this wizard section against the current config (repeatable)' -r -f -a "ide\t'IDE wrapper Claude Code runs in'
+directories\t'Subtrees and state directories'
+git\t'Branch prefix and staging'
+session\t'Preserving and cleaning up finished sessions'
+sandbox\t'Sandboxing of Claude Code'
+docker\t'Docker image and setup script'"
+complete -c para -n "__fish_para_using_subcommand config; and not __fish_seen_subcommand_from setup auto show edit reset set get keys session project notify templates path doctor export import help" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand config; and not __fish_seen_subcommand_from setup auto show edit reset set get keys session project notify templates path doctor export import help" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand config; and not __fish_seen_subcommand_from setup auto show edit reset set get keys session project notify templates path doctor export import help" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand config; and not __fish_seen_subcommand_from setup auto show edit reset set get keys session project notify templates path doctor export import help" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand config; and not __fish_seen_subcommand_from setup auto show edit reset set get keys session project notify templates path doctor export import help" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand config; and not __fish_seen_subcommand_from setup auto show edit reset set get keys session project notify templates path doctor export import help" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand config; and not __fish_seen_subcommand_from setup auto show edit reset set get keys session project notify templates path doctor export import help" -f -a "setup" -d 'Interactive configuration wizard'
+complete -c para -n "__fish_para_using_subcommand config; and not __fish_seen_subcommand_from setup auto show edit reset set get keys session project notify templates path doctor export import help" -f -a "auto" -d 'Auto-detect and configure IDE'
+complete -c para -n "__fish_para_using_subcommand config; and not __fish_seen_subcommand_from setup auto show edit reset set get keys session project notify templates path doctor export import help" -f -a "show" -d 'Show current configuration'
+complete -c para -n "__fish_para_using_subcommand config; and not __fish_seen_subcommand_from setup auto show edit reset set get keys session project notify templates path doctor export import help" -f -a "edit" -d 'Edit configuration file'
+complete -c para -n "__fish_para_using_subcommand config; and not __fish_seen_subcommand_from setup auto show edit reset set get keys session project notify templates path doctor export import help" -f -a "reset" -d 'Reset configuration to defaults'
+complete -c para -n "__fish_para_using_subcommand config; and not __fish_seen_subcommand_from setup auto show edit reset set get keys session project notify templates path doctor export import help" -f -a "set" -d 'Set configuration value using JSON path'
+complete -c para -n "__fish_para_using_subcommand config; and not __fish_seen_subcommand_from setup auto show edit reset set get keys session project notify templates path doctor export import help" -f -a "get" -d 'Print one configuration value'
+complete -c para -n "__fish_para_using_subcommand config; and not __fish_seen_subcommand_from setup auto show edit reset set get keys session project notify templates path doctor export import help" -f -a "keys" -d 'List every configuration key with its type'
+complete -c para -n "__fish_para_using_subcommand config; and not __fish_seen_subcommand_from setup auto show edit reset set get keys session project notify templates path doctor export import help" -f -a "session" -d 'Show or change settings recorded for one session'
+complete -c para -n "__fish_para_using_subcommand config; and not __fish_seen_subcommand_from setup auto show edit reset set get keys session project notify templates path doctor export import help" -f -a "project" -d 'Manage project-level configuration'
+complete -c para -n "__fish_para_using_subcommand config; and not __fish_seen_subcommand_from setup auto show edit reset set get keys session project notify templates path doctor export import help" -f -a "notify" -d 'Manage session event notifications'
+complete -c para -n "__fish_para_using_subcommand config; and not __fish_seen_subcommand_from setup auto show edit reset set get keys session project notify templates path doctor export import help" -f -a "templates" -d 'List or show session templates for \'para start --template\''
+complete -c para -n "__fish_para_using_subcommand config; and not __fish_seen_subcommand_from setup auto show edit reset set get keys session project notify templates path doctor export import help" -f -a "path" -d 'Print where the user config is read from and why'
+complete -c para -n "__fish_para_using_subcommand config; and not __fish_seen_subcommand_from setup auto show edit reset set get keys session project notify templates path doctor export import help" -f -a "doctor" -d 'Check the config file for unknown, renamed and missing keys'
+complete -c para -n "__fish_para_using_subcommand config; and not __fish_seen_subcommand_from setup auto show edit reset set get keys session project notify templates path doctor export import help" -f -a "export" -d 'Export configuration with portable paths for use on another machine'
+complete -c para -n "__fish_para_using_subcommand config; and not __fish_seen_subcommand_from setup auto show edit reset set get keys session project notify templates path doctor export import help" -f -a "import" -d 'Import configuration exported with \'para config export\''
+complete -c para -n "__fish_para_using_subcommand config; and not __fish_seen_subcommand_from setup auto show edit reset set get keys session project notify templates path doctor export import help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from setup" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from setup" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from setup" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from setup" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from setup" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from setup" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from auto" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from auto" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from auto" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from auto" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from auto" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from auto" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from show" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from show" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from show" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from show" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from show" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from show" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from edit" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from edit" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from edit" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from edit" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from edit" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from edit" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from reset" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from reset" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from reset" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from reset" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from reset" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from reset" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from set" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from set" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from set" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from set" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from set" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from set" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from get" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from get" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from get" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from get" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from get" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from get" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from keys" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from keys" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from keys" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from keys" -s q -l quiet -d 'Print only the key names'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from keys" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from keys" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from keys" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from session" -l merge-mode -d 'Change the merge mode finish uses for this session' -r -f -a "squash\t'Collapse everything since the session base into one commit'
+preserve\t'Keep the session\'s commits as they are'
+rebase\t'Keep the commits but replay them on top of the parent branch'"
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from session" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from session" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from session" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from session" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from session" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from session" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from project" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from project" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from project" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from project" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from project" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from project" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from project" -f -a "init" -d 'Initialize project configuration'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from project" -f -a "show" -d 'Show project configuration'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from project" -f -a "edit" -d 'Edit project configuration'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from project" -f -a "set" -d 'Set project configuration value'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from project" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from notify" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from notify" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from notify" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from notify" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from notify" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from notify" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from notify" -f -a "test" -d 'Run the configured notification command with a test event'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from notify" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from templates" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from templates" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from templates" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from templates" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from templates" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from templates" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from templates" -f -a "list" -d 'List the templates from the user and project config'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from templates" -f -a "show" -d 'Show the options a template sets'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from templates" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from path" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from path" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from path" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from path" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from path" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from path" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from doctor" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from doctor" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from doctor" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from doctor" -l fix -d 'Repair what can be repaired safely'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from doctor" -l dry-run -d 'Print the repairs without making them'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from doctor" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from doctor" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from doctor" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from export" -s o -l output -d 'Write to a file instead of stdout' -r -F
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from export" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from export" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from export" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from export" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from export" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from export" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from import" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from import" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from import" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from import" -s y -l yes -d 'Apply without confirmation'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from import" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from import" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from import" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from help" -f -a "setup" -d 'Interactive configuration wizard'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from help" -f -a "auto" -d 'Auto-detect and configure IDE'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from help" -f -a "show" -d 'Show current configuration'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from help" -f -a "edit" -d 'Edit configuration file'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from help" -f -a "reset" -d 'Reset configuration to defaults'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from help" -f -a "set" -d 'Set configuration value using JSON path'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from help" -f -a "get" -d 'Print one configuration value'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from help" -f -a "keys" -d 'List every configuration key with its type'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from help" -f -a "session" -d 'Show or change settings recorded for one session'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from help" -f -a "project" -d 'Manage project-level configuration'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from help" -f -a "notify" -d 'Manage session event notifications'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from help" -f -a "templates" -d 'List or show session templates for \'para start --template\''
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from help" -f -a "path" -d 'Print where the user config is read from and why'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from help" -f -a "doctor" -d 'Check the config file for unknown, renamed and missing keys'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from help" -f -a "export" -d 'Export configuration with portable paths for use on another machine'
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from help" -f -a "import" -d 'Import configuration exported with \'para config export\''
+complete -c para -n "__fish_para_using_subcommand config; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c para -n "__fish_para_using_subcommand completion; and not __fish_seen_subcommand_from generate install help" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand completion; and not __fish_seen_subcommand_from generate install help" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand completion; and not __fish_seen_subcommand_from generate install help" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand completion; and not __fish_seen_subcommand_from generate install help" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand completion; and not __fish_seen_subcommand_from generate install help" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand completion; and not __fish_seen_subcommand_from generate install help" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand completion; and not __fish_seen_subcommand_from generate install help" -a "generate" -d 'Print the completion script for a shell'
+complete -c para -n "__fish_para_using_subcommand completion; and not __fish_seen_subcommand_from generate install help" -a "install" -d 'Write the completion script to the shell\'s per-user completion directory'
+complete -c para -n "__fish_para_using_subcommand completion; and not __fish_seen_subcommand_from generate install help" -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c para -n "__fish_para_using_subcommand completion; and __fish_seen_subcommand_from generate" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand completion; and __fish_seen_subcommand_from generate" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand completion; and __fish_seen_subcommand_from generate" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand completion; and __fish_seen_subcommand_from generate" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand completion; and __fish_seen_subcommand_from generate" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand completion; and __fish_seen_subcommand_from generate" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand completion; and __fish_seen_subcommand_from install" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand completion; and __fish_seen_subcommand_from install" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand completion; and __fish_seen_subcommand_from install" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand completion; and __fish_seen_subcommand_from install" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand completion; and __fish_seen_subcommand_from install" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand completion; and __fish_seen_subcommand_from install" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand completion; and __fish_seen_subcommand_from help" -f -a "generate" -d 'Print the completion script for a shell'
+complete -c para -n "__fish_para_using_subcommand completion; and __fish_seen_subcommand_from help" -f -a "install" -d 'Write the completion script to the shell\'s per-user completion directory'
+complete -c para -n "__fish_para_using_subcommand completion; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c para -n "__fish_para_using_subcommand init" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand init" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand init" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand init" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand init" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand init" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand mcp; and not __fish_seen_subcommand_from init help" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand mcp; and not __fish_seen_subcommand_from init help" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand mcp; and not __fish_seen_subcommand_from init help" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand mcp; and not __fish_seen_subcommand_from init help" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand mcp; and not __fish_seen_subcommand_from init help" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand mcp; and not __fish_seen_subcommand_from init help" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand mcp; and not __fish_seen_subcommand_from init help" -f -a "init" -d 'Initialize MCP integration for Para'
+complete -c para -n "__fish_para_using_subcommand mcp; and not __fish_seen_subcommand_from init help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c para -n "__fish_para_using_subcommand mcp; and __fish_seen_subcommand_from init" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand mcp; and __fish_seen_subcommand_from init" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand mcp; and __fish_seen_subcommand_from init" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand mcp; and __fish_seen_subcommand_from init" -l claude-desktop -d 'Register the server in Claude Desktop\'s global config instead of .mcp.json'
+complete -c para -n "__fish_para_using_subcommand mcp; and __fish_seen_subcommand_from init" -l cursor -d 'Register the server in Cursor\'s global mcp.json instead of .mcp.json'
+complete -c para -n "__fish_para_using_subcommand mcp; and __fish_seen_subcommand_from init" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand mcp; and __fish_seen_subcommand_from init" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand mcp; and __fish_seen_subcommand_from init" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand mcp; and __fish_seen_subcommand_from help" -f -a "init" -d 'Initialize MCP integration for Para'
+complete -c para -n "__fish_para_using_subcommand mcp; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c para -n "__fish_para_using_subcommand _completion_sessions" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand _completion_sessions" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand _completion_sessions" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand _completion_sessions" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand _completion_sessions" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand _completion_sessions" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand _completion_branches" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand _completion_branches" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand _completion_branches" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand _completion_branches" -l para -d 'List the branches of active para sessions instead of other branches'
+complete -c para -n "__fish_para_using_subcommand _completion_branches" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand _completion_branches" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand _completion_branches" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand monitor" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand monitor" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand monitor" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand monitor" -l all-repos -d 'Show sessions from all repositories recorded in the para config directory'
+complete -c para -n "__fish_para_using_subcommand monitor" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand monitor" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand monitor" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand status; and not __fish_seen_subcommand_from show summary cleanup todo wait help" -l tests -d 'Test status: passed, failed, or unknown' -r
+complete -c para -n "__fish_para_using_subcommand status; and not __fish_seen_subcommand_from show summary cleanup todo wait help" -l todos -d 'Todo list as comma-separated items (\'[x] \' marks done items) or progress as \'completed/total\' (e.g., \'3/7\')' -r
+complete -c para -n "__fish_para_using_subcommand status; and not __fish_seen_subcommand_from show summary cleanup todo wait help" -l session -d 'Session name (auto-detected if not provided)' -r
+complete -c para -n "__fish_para_using_subcommand status; and not __fish_seen_subcommand_from show summary cleanup todo wait help" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand status; and not __fish_seen_subcommand_from show summary cleanup todo wait help" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand status; and not __fish_seen_subcommand_from show summary cleanup todo wait help" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand status; and not __fish_seen_subcommand_from show summary cleanup todo wait help" -l blocked -d 'Mark session as blocked'
+complete -c para -n "__fish_para_using_subcommand status; and not __fish_seen_subcommand_from show summary cleanup todo wait help" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand status; and not __fish_seen_subcommand_from show summary cleanup todo wait help" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand status; and not __fish_seen_subcommand_from show summary cleanup todo wait help" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand status; and not __fish_seen_subcommand_from show summary cleanup todo wait help" -a "show" -d 'Show status of one or all sessions'
+complete -c para -n "__fish_para_using_subcommand status; and not __fish_seen_subcommand_from show summary cleanup todo wait help" -a "summary" -d 'Generate a summary of all status files'
+complete -c para -n "__fish_para_using_subcommand status; and not __fish_seen_subcommand_from show summary cleanup todo wait help" -a "cleanup" -d 'Clean up stale status files'
+complete -c para -n "__fish_para_using_subcommand status; and not __fish_seen_subcommand_from show summary cleanup todo wait help" -a "todo" -d 'Add, complete and list the session\'s todos'
+complete -c para -n "__fish_para_using_subcommand status; and not __fish_seen_subcommand_from show summary cleanup todo wait help" -a "wait" -d 'Block until a session\'s status meets a condition'
+complete -c para -n "__fish_para_using_subcommand status; and not __fish_seen_subcommand_from show summary cleanup todo wait help" -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c para -n "__fish_para_using_subcommand status; and __fish_seen_subcommand_from show" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand status; and __fish_seen_subcommand_from show" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand status; and __fish_seen_subcommand_from show" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand status; and __fish_seen_subcommand_from show" -l json -d 'Output as JSON'
+complete -c para -n "__fish_para_using_subcommand status; and __fish_seen_subcommand_from show" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand status; and __fish_seen_subcommand_from show" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand status; and __fish_seen_subcommand_from show" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand status; and __fish_seen_subcommand_from summary" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand status; and __fish_seen_subcommand_from summary" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand status; and __fish_seen_subcommand_from summary" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand status; and __fish_seen_subcommand_from summary" -l json -d 'Output as JSON'
+complete -c para -n "__fish_para_using_subcommand status; and __fish_seen_subcommand_from summary" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand status; and __fish_seen_subcommand_from summary" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand status; and __fish_seen_subcommand_from summary" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand status; and __fish_seen_subcommand_from cleanup" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand status; and __fish_seen_subcommand_from cleanup" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand status; and __fish_seen_subcommand_from cleanup" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand status; and __fish_seen_subcommand_from cleanup" -l dry-run -d 'Show what would be cleaned without removing'
+complete -c para -n "__fish_para_using_subcommand status; and __fish_seen_subcommand_from cleanup" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand status; and __fish_seen_subcommand_from cleanup" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand status; and __fish_seen_subcommand_from cleanup" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand status; and __fish_seen_subcommand_from todo" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand status; and __fish_seen_subcommand_from todo" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand status; and __fish_seen_subcommand_from todo" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand status; and __fish_seen_subcommand_from todo" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand status; and __fish_seen_subcommand_from todo" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand status; and __fish_seen_subcommand_from todo" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand status; and __fish_seen_subcommand_from todo" -f -a "add" -d 'Add a todo to the session\'s list'
+complete -c para -n "__fish_para_using_subcommand status; and __fish_seen_subcommand_from todo" -f -a "done" -d 'Mark a todo as done'
+complete -c para -n "__fish_para_using_subcommand status; and __fish_seen_subcommand_from todo" -f -a "list" -d 'List the session\'s todos'
+complete -c para -n "__fish_para_using_subcommand status; and __fish_seen_subcommand_from todo" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c para -n "__fish_para_using_subcommand status; and __fish_seen_subcommand_from wait" -l until -d 'tests-passed, blocked, idle-for:<minutes> or task-contains:<text>' -r
+complete -c para -n "__fish_para_using_subcommand status; and __fish_seen_subcommand_from wait" -l interval -d 'Seconds between checks' -r
+complete -c para -n "__fish_para_using_subcommand status; and __fish_seen_subcommand_from wait" -l timeout -d 'Give up after this many seconds (exits with code 124)' -r
+complete -c para -n "__fish_para_using_subcommand status; and __fish_seen_subcommand_from wait" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand status; and __fish_seen_subcommand_from wait" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand status; and __fish_seen_subcommand_from wait" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand status; and __fish_seen_subcommand_from wait" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand status; and __fish_seen_subcommand_from wait" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand status; and __fish_seen_subcommand_from wait" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand status; and __fish_seen_subcommand_from help" -f -a "show" -d 'Show status of one or all sessions'
+complete -c para -n "__fish_para_using_subcommand status; and __fish_seen_subcommand_from help" -f -a "summary" -d 'Generate a summary of all status files'
+complete -c para -n "__fish_para_using_subcommand status; and __fish_seen_subcommand_from help" -f -a "cleanup" -d 'Clean up stale status files'
+complete -c para -n "__fish_para_using_subcommand status; and __fish_seen_subcommand_from help" -f -a "todo" -d 'Add, complete and list the session\'s todos'
+complete -c para -n "__fish_para_using_subcommand status; and __fish_seen_subcommand_from help" -f -a "wait" -d 'Block until a session\'s status meets a condition'
+complete -c para -n "__fish_para_using_subcommand status; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c para -n "__fish_para_using_subcommand auth; and not __fish_seen_subcommand_from setup cleanup status reauth help" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand auth; and not __fish_seen_subcommand_from setup cleanup status reauth help" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand auth; and not __fish_seen_subcommand_from setup cleanup status reauth help" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand auth; and not __fish_seen_subcommand_from setup cleanup status reauth help" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand auth; and not __fish_seen_subcommand_from setup cleanup status reauth help" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand auth; and not __fish_seen_subcommand_from setup cleanup status reauth help" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand auth; and not __fish_seen_subcommand_from setup cleanup status reauth help" -f -a "setup" -d 'Set up container authentication interactively'
+complete -c para -n "__fish_para_using_subcommand auth; and not __fish_seen_subcommand_from setup cleanup status reauth help" -f -a "cleanup" -d 'Remove authentication artifacts'
+complete -c para -n "__fish_para_using_subcommand auth; and not __fish_seen_subcommand_from setup cleanup status reauth help" -f -a "status" -d 'Check authentication status'
+complete -c para -n "__fish_para_using_subcommand auth; and not __fish_seen_subcommand_from setup cleanup status reauth help" -f -a "reauth" -d 'Re-authenticate (cleanup and setup in one command)'
+complete -c para -n "__fish_para_using_subcommand auth; and not __fish_seen_subcommand_from setup cleanup status reauth help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c para -n "__fish_para_using_subcommand auth; and __fish_seen_subcommand_from setup" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand auth; and __fish_seen_subcommand_from setup" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand auth; and __fish_seen_subcommand_from setup" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand auth; and __fish_seen_subcommand_from setup" -l force -d 'Force re-authentication even if credentials exist'
+complete -c para -n "__fish_para_using_subcommand auth; and __fish_seen_subcommand_from setup" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand auth; and __fish_seen_subcommand_from setup" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand auth; and __fish_seen_subcommand_from setup" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand auth; and __fish_seen_subcommand_from cleanup" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand auth; and __fish_seen_subcommand_from cleanup" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand auth; and __fish_seen_subcommand_from cleanup" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand auth; and __fish_seen_subcommand_from cleanup" -l dry-run -d 'Show what would be removed without actually removing'
+complete -c para -n "__fish_para_using_subcommand auth; and __fish_seen_subcommand_from cleanup" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand auth; and __fish_seen_subcommand_from cleanup" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand auth; and __fish_seen_subcommand_from cleanup" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand auth; and __fish_seen_subcommand_from status" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand auth; and __fish_seen_subcommand_from status" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand auth; and __fish_seen_subcommand_from status" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand auth; and __fish_seen_subcommand_from status" -l verbose -d 'Show detailed authentication information'
+complete -c para -n "__fish_para_using_subcommand auth; and __fish_seen_subcommand_from status" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand auth; and __fish_seen_subcommand_from status" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand auth; and __fish_seen_subcommand_from status" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand auth; and __fish_seen_subcommand_from reauth" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand auth; and __fish_seen_subcommand_from reauth" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand auth; and __fish_seen_subcommand_from reauth" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand auth; and __fish_seen_subcommand_from reauth" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand auth; and __fish_seen_subcommand_from reauth" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand auth; and __fish_seen_subcommand_from reauth" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand auth; and __fish_seen_subcommand_from help" -f -a "setup" -d 'Set up container authentication interactively'
+complete -c para -n "__fish_para_using_subcommand auth; and __fish_seen_subcommand_from help" -f -a "cleanup" -d 'Remove authentication artifacts'
+complete -c para -n "__fish_para_using_subcommand auth; and __fish_seen_subcommand_from help" -f -a "status" -d 'Check authentication status'
+complete -c para -n "__fish_para_using_subcommand auth; and __fish_seen_subcommand_from help" -f -a "reauth" -d 'Re-authenticate (cleanup and setup in one command)'
+complete -c para -n "__fish_para_using_subcommand auth; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c para -n "__fish_para_using_subcommand daemon; and not __fish_seen_subcommand_from start stop status prefetch cleanup help" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand daemon; and not __fish_seen_subcommand_from start stop status prefetch cleanup help" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand daemon; and not __fish_seen_subcommand_from start stop status prefetch cleanup help" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand daemon; and not __fish_seen_subcommand_from start stop status prefetch cleanup help" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand daemon; and not __fish_seen_subcommand_from start stop status prefetch cleanup help" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand daemon; and not __fish_seen_subcommand_from start stop status prefetch cleanup help" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand daemon; and not __fish_seen_subcommand_from start stop status prefetch cleanup help" -f -a "start" -d 'Start the daemon'
+complete -c para -n "__fish_para_using_subcommand daemon; and not __fish_seen_subcommand_from start stop status prefetch cleanup help" -f -a "stop" -d 'Stop the daemon'
+complete -c para -n "__fish_para_using_subcommand daemon; and not __fish_seen_subcommand_from start stop status prefetch cleanup help" -f -a "status" -d 'Check daemon status'
+complete -c para -n "__fish_para_using_subcommand daemon; and not __fish_seen_subcommand_from start stop status prefetch cleanup help" -f -a "prefetch" -d 'Fetch the base branches of this repository\'s sessions now'
+complete -c para -n "__fish_para_using_subcommand daemon; and not __fish_seen_subcommand_from start stop status prefetch cleanup help" -f -a "cleanup" -d 'Remove orphaned para containers and their volumes and networks'
+complete -c para -n "__fish_para_using_subcommand daemon; and not __fish_seen_subcommand_from start stop status prefetch cleanup help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c para -n "__fish_para_using_subcommand daemon; and __fish_seen_subcommand_from start" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand daemon; and __fish_seen_subcommand_from start" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand daemon; and __fish_seen_subcommand_from start" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand daemon; and __fish_seen_subcommand_from start" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand daemon; and __fish_seen_subcommand_from start" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand daemon; and __fish_seen_subcommand_from start" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand daemon; and __fish_seen_subcommand_from stop" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand daemon; and __fish_seen_subcommand_from stop" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand daemon; and __fish_seen_subcommand_from stop" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand daemon; and __fish_seen_subcommand_from stop" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand daemon; and __fish_seen_subcommand_from stop" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand daemon; and __fish_seen_subcommand_from stop" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand daemon; and __fish_seen_subcommand_from status" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand daemon; and __fish_seen_subcommand_from status" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand daemon; and __fish_seen_subcommand_from status" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand daemon; and __fish_seen_subcommand_from status" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand daemon; and __fish_seen_subcommand_from status" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand daemon; and __fish_seen_subcommand_from status" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand daemon; and __fish_seen_subcommand_from prefetch" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand daemon; and __fish_seen_subcommand_from prefetch" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand daemon; and __fish_seen_subcommand_from prefetch" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand daemon; and __fish_seen_subcommand_from prefetch" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand daemon; and __fish_seen_subcommand_from prefetch" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand daemon; and __fish_seen_subcommand_from prefetch" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand daemon; and __fish_seen_subcommand_from cleanup" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand daemon; and __fish_seen_subcommand_from cleanup" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand daemon; and __fish_seen_subcommand_from cleanup" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand daemon; and __fish_seen_subcommand_from cleanup" -l now -d 'Run even if the cleanup interval has not elapsed'
+complete -c para -n "__fish_para_using_subcommand daemon; and __fish_seen_subcommand_from cleanup" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand daemon; and __fish_seen_subcommand_from cleanup" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand daemon; and __fish_seen_subcommand_from cleanup" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand daemon; and __fish_seen_subcommand_from help" -f -a "start" -d 'Start the daemon'
+complete -c para -n "__fish_para_using_subcommand daemon; and __fish_seen_subcommand_from help" -f -a "stop" -d 'Stop the daemon'
+complete -c para -n "__fish_para_using_subcommand daemon; and __fish_seen_subcommand_from help" -f -a "status" -d 'Check daemon status'
+complete -c para -n "__fish_para_using_subcommand daemon; and __fish_seen_subcommand_from help" -f -a "prefetch" -d 'Fetch the base branches of this repository\'s sessions now'
+complete -c para -n "__fish_para_using_subcommand daemon; and __fish_seen_subcommand_from help" -f -a "cleanup" -d 'Remove orphaned para containers and their volumes and networks'
+complete -c para -n "__fish_para_using_subcommand daemon; and __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c para -n "__fish_para_using_subcommand proxy" -l port -d 'Port to run the proxy on' -r
+complete -c para -n "__fish_para_using_subcommand proxy" -l allowed-domains -d 'Additional domains to allow (comma-separated)' -r
+complete -c para -n "__fish_para_using_subcommand proxy" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand proxy" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand proxy" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand proxy" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand proxy" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand proxy" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand selftest" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand selftest" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand selftest" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand selftest" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand selftest" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand selftest" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand integrate" -l wait -d 'If another git process holds the repository\'s index.lock, wait up to SECONDS for it instead of failing' -r
+complete -c para -n "__fish_para_using_subcommand integrate" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand integrate" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand integrate" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand integrate" -l unshallow -d 'In a shallow clone, fetch the missing history from the remote first instead of failing'
+complete -c para -n "__fish_para_using_subcommand integrate" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand integrate" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand integrate" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand continue" -l wait -d 'If another git process holds the repository\'s index.lock, wait up to SECONDS for it instead of failing' -r
+complete -c para -n "__fish_para_using_subcommand continue" -l ci -d 'Add CI workflow commands to the output: github (default in GitHub Actions) or none' -r -f -a "github\t'GitHub Actions workflow commands'
+none\t'Plain output, also inside GitHub Actions'"
+complete -c para -n "__fish_para_using_subcommand continue" -l state-dir -d 'Keep session state in PATH instead of the configured state directory' -r -F
+complete -c para -n "__fish_para_using_subcommand continue" -l config -d 'Read and write the user config at PATH instead of the default location' -r -F
+complete -c para -n "__fish_para_using_subcommand continue" -l unshallow -d 'In a shallow clone, fetch the missing history from the remote first instead of failing'
+complete -c para -n "__fish_para_using_subcommand continue" -l offline -d 'Skip fetches and image pulls; fail fast on operations that need the network'
+complete -c para -n "__fish_para_using_subcommand continue" -l ignore-version -d 'Change sessions written by a newer version of para anyway'
+complete -c para -n "__fish_para_using_subcommand continue" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c para -n "__fish_para_using_subcommand help; and not __fish_seen_subcommand_from start finish rollback-integration cancel clean restore-trash gc doctor upgrade-state list resume recover adopt checkpoint sync note link pin unpin launch self-update audit conflicts which statusline summary logs examples config completion init mcp _completion_sessions _completion_branches monitor status auth daemon proxy selftest integrate continue help" -f -a "start" -d 'Create new para sessions (interactive or AI-assisted)'
+complete -c para -n "__fish_para_using_subcommand help; and not __fish_seen_subcommand_from start finish rollback-integration cancel clean restore-trash gc doctor upgrade-state list resume recover adopt checkpoint sync note link pin unpin launch self-update audit conflicts which statusline summary logs examples config completion init mcp _completion_sessions _completion_branches monitor status auth daemon proxy selftest integrate continue help" -f -a "finish" -d 'Complete session and create feature branch for review'
+complete -c para -n "__fish_para_using_subcommand help; and not __fish_seen_subcommand_from start finish rollback-integration cancel clean restore-trash gc doctor upgrade-state list resume recover adopt checkpoint sync note link pin unpin launch self-update audit conflicts which statusline summary logs examples config completion init mcp _completion_sessions _completion_branches monitor status auth daemon proxy selftest integrate continue help" -f -a "rollback-integration" -d 'Undo finishing a session onto another session\'s branch'
+complete -c para -n "__fish_para_using_subcommand help; and not __fish_seen_subcommand_from start finish rollback-integration cancel clean restore-trash gc doctor upgrade-state list resume recover adopt checkpoint sync note link pin unpin launch self-update audit conflicts which statusline summary logs examples config completion init mcp _completion_sessions _completion_branches monitor status auth daemon proxy selftest integrate continue help" -f -a "cancel" -d 'Cancel session (moves to archive)'
+complete -c para -n "__fish_para_using_subcommand help; and not __fish_seen_subcommand_from start finish rollback-integration cancel clean restore-trash gc doctor upgrade-state list resume recover adopt checkpoint sync note link pin unpin launch self-update audit conflicts which statusline summary logs examples config completion init mcp _completion_sessions _completion_branches monitor status auth daemon proxy selftest integrate continue help" -f -a "clean" -d 'Remove stale para artifacts (and optionally all active sessions)'
+complete -c para -n "__fish_para_using_subcommand help; and not __fish_seen_subcommand_from start finish rollback-integration cancel clean restore-trash gc doctor upgrade-state list resume recover adopt checkpoint sync note link pin unpin launch self-update audit conflicts which statusline summary logs examples config completion init mcp _completion_sessions _completion_branches monitor status auth daemon proxy selftest integrate continue help" -f -a "restore-trash" -d 'Put an item `para clean` moved to the trash back in place'
+complete -c para -n "__fish_para_using_subcommand help; and not __fish_seen_subcommand_from start finish rollback-integration cancel clean restore-trash gc doctor upgrade-state list resume recover adopt checkpoint sync note link pin unpin launch self-update audit conflicts which statusline summary logs examples config completion init mcp _completion_sessions _completion_branches monitor status auth daemon proxy selftest integrate continue help" -f -a "gc" -d 'Compact logs and remove leftover files in the state directory'
+complete -c para -n "__fish_para_using_subcommand help; and not __fish_seen_subcommand_from start finish rollback-integration cancel clean restore-trash gc doctor upgrade-state list resume recover adopt checkpoint sync note link pin unpin launch self-update audit conflicts which statusline summary logs examples config completion init mcp _completion_sessions _completion_branches monitor status auth daemon proxy selftest integrate continue help" -f -a "doctor" -d 'Report version skew between the CLI, daemon, config and sessions'
+complete -c para -n "__fish_para_using_subcommand help; and not __fish_seen_subcommand_from start finish rollback-integration cancel clean restore-trash gc doctor upgrade-state list resume recover adopt checkpoint sync note link pin unpin launch self-update audit conflicts which statusline summary logs examples config completion init mcp _completion_sessions _completion_branches monitor status auth daemon proxy selftest integrate continue help" -f -a "upgrade-state" -d 'Upgrade all session state files to the current format, or restore a backup'
+complete -c para -n "__fish_para_using_subcommand help; and not __fish_seen_subcommand_from start finish rollback-integration cancel clean restore-trash gc doctor upgrade-state list resume recover adopt checkpoint sync note link pin unpin launch self-update audit conflicts which statusline summary logs examples config completion init mcp _completion_sessions _completion_branches monitor status auth daemon proxy selftest integrate continue help" -f -a "list" -d 'List active sessions'
+complete -c para -n "__fish_para_using_subcommand help; and not __fish_seen_subcommand_from start finish rollback-integration cancel clean restore-trash gc doctor upgrade-state list resume recover adopt checkpoint sync note link pin unpin launch self-update audit conflicts which statusline summary logs examples config completion init mcp _completion_sessions _completion_branches monitor status auth daemon proxy selftest integrate continue help" -f -a "resume" -d 'Resume session in IDE'
+complete -c para -n "__fish_para_using_subcommand help; and not __fish_seen_subcommand_from start finish rollback-integration cancel clean restore-trash gc doctor upgrade-state list resume recover adopt checkpoint sync note link pin unpin launch self-update audit conflicts which statusline summary logs examples config completion init mcp _completion_sessions _completion_branches monitor status auth daemon proxy selftest integrate continue help" -f -a "recover" -d 'Recover cancelled session from archive'
+complete -c para -n "__fish_para_using_subcommand help; and not __fish_seen_subcommand_from start finish rollback-integration cancel clean restore-trash gc doctor upgrade-state list resume recover adopt checkpoint sync note link pin unpin launch self-update audit conflicts which statusline summary logs examples config completion init mcp _completion_sessions _completion_branches monitor status auth daemon proxy selftest integrate continue help" -f -a "adopt" -d 'Turn an existing branch into a session'
+complete -c para -n "__fish_para_using_subcommand help; and not __fish_seen_subcommand_from start finish rollback-integration cancel clean restore-trash gc doctor upgrade-state list resume recover adopt checkpoint sync note link pin unpin launch self-update audit conflicts which statusline summary logs examples config completion init mcp _completion_sessions _completion_branches monitor status auth daemon proxy selftest integrate continue help" -f -a "checkpoint" -d 'Commit pending work in a session as a WIP checkpoint'
+complete -c para -n "__fish_para_using_subcommand help; and not __fish_seen_subcommand_from start finish rollback-integration cancel clean restore-trash gc doctor upgrade-state list resume recover adopt checkpoint sync note link pin unpin launch self-update audit conflicts which statusline summary logs examples config completion init mcp _completion_sessions _completion_branches monitor status auth daemon proxy selftest integrate continue help" -f -a "sync" -d 'Rebase a session onto the latest commit of the branch it was started from'
+complete -c para -n "__fish_para_using_subcommand help; and not __fish_seen_subcommand_from start finish rollback-integration cancel clean restore-trash gc doctor upgrade-state list resume recover adopt checkpoint sync note link pin unpin launch self-update audit conflicts which statusline summary logs examples config completion init mcp _completion_sessions _completion_branches monitor status auth daemon proxy selftest integrate continue help" -f -a "note" -d 'Add, edit or show notes about a session'
+complete -c para -n "__fish_para_using_subcommand help; and not __fish_seen_subcommand_from start finish rollback-integration cancel clean restore-trash gc doctor upgrade-state list resume recover adopt checkpoint sync note link pin unpin launch self-update audit conflicts which statusline summary logs examples config completion init mcp _completion_sessions _completion_branches monitor status auth daemon proxy selftest integrate continue help" -f -a "link" -d 'Attach links to PRs, tickets and docs to a session'
+complete -c para -n "__fish_para_using_subcommand help; and not __fish_seen_subcommand_from start finish rollback-integration cancel clean restore-trash gc doctor upgrade-state list resume recover adopt checkpoint sync note link pin unpin launch self-update audit conflicts which statusline summary logs examples config completion init mcp _completion_sessions _completion_branches monitor status auth daemon proxy selftest integrate continue help" -f -a "pin" -d 'Keep a session out of stale and idle detection and bulk cleanups'
+complete -c para -n "__fish_para_using_subcommand help; and not __fish_seen_subcommand_from start finish rollback-integration cancel clean restore-trash gc doctor upgrade-state list resume recover adopt checkpoint sync note link pin unpin launch self-update audit conflicts which statusline summary logs examples config completion init mcp _completion_sessions _completion_branches monitor status auth daemon proxy selftest integrate continue help" -f -a "unpin" -d 'Undo `para pin`'
+complete -c para -n "__fish_para_using_subcommand help; and not __fish_seen_subcommand_from start finish rollback-integration cancel clean restore-trash gc doctor upgrade-state list resume recover adopt checkpoint sync note link pin unpin launch self-update audit conflicts which statusline summary logs examples config completion init mcp _completion_sessions _completion_branches monitor status auth daemon proxy selftest integrate continue help" -f -a "launch" -d 'Launch Claude in a session prepared with --plan-only'
+complete -c para -n "__fish_para_using_subcommand help; and not __fish_seen_subcommand_from start finish rollback-integration cancel clean restore-trash gc doctor upgrade-state list resume recover adopt checkpoint sync note link pin unpin launch self-update audit conflicts which statusline summary logs examples config completion init mcp _completion_sessions _completion_branches monitor status auth daemon proxy selftest integrate continue help" -f -a "self-update" -d 'Check for a newer para release and update to it'
+complete -c para -n "__fish_para_using_subcommand help; and not __fish_seen_subcommand_from start finish rollback-integration cancel clean restore-trash gc doctor upgrade-state list resume recover adopt checkpoint sync note link pin unpin launch self-update audit conflicts which statusline summary logs examples config completion init mcp _completion_sessions _completion_branches monitor status auth daemon proxy selftest integrate continue help" -f -a "audit" -d 'Show the git commands para ran on the repository'
+complete -c para -n "__fish_para_using_subcommand help; and not __fish_seen_subcommand_from start finish rollback-integration cancel clean restore-trash gc doctor upgrade-state list resume recover adopt checkpoint sync note link pin unpin launch self-update audit conflicts which statusline summary logs examples config completion init mcp _completion_sessions _completion_branches monitor status auth daemon proxy selftest integrate continue help" -f -a "conflicts" -d 'Show how earlier merge conflicts were resolved'
+complete -c para -n "__fish_para_using_subcommand help; and not __fish_seen_subcommand_from start finish rollback-integration cancel clean restore-trash gc doctor upgrade-state list resume recover adopt checkpoint sync note link pin unpin launch self-update audit conflicts which statusline summary logs examples config completion init mcp _completion_sessions _completion_branches monitor status auth daemon proxy selftest integrate continue help" -f -a "which" -d 'Print the session the current directory belongs to'
+complete -c para -n "__fish_para_using_subcommand help; and not __fish_seen_subcommand_from start finish rollback-integration cancel clean restore-trash gc doctor upgrade-state list resume recover adopt checkpoint sync note link pin unpin launch self-update audit conflicts which statusline summary logs examples config completion init mcp _completion_sessions _completion_branches monitor status auth daemon proxy selftest integrate continue help" -f -a "statusline" -d 'Print a one-line summary of the current session for status bars'
+complete -c para -n "__fish_para_using_subcommand help; and not __fish_seen_subcommand_from start finish rollback-integration cancel clean restore-trash gc doctor upgrade-state list resume recover adopt checkpoint sync note link pin unpin launch self-update audit conflicts which statusline summary logs examples config completion init mcp _completion_sessions _completion_branches monitor status auth daemon proxy selftest integrate continue help" -f -a "summary" -d 'Summarize a session\'s task, commits, changes and status history'
+complete -c para -n "__fish_para_using_subcommand help; and not __fish_seen_subcommand_from start finish rollback-integration cancel clean restore-trash gc doctor upgrade-state list resume recover adopt checkpoint sync note link pin unpin launch self-update audit conflicts which statusline summary logs examples config completion init mcp _completion_sessions _completion_branches monitor status auth daemon proxy selftest integrate continue help" -f -a "logs" -d 'Show the setup script, container and daemon logs of a session'
+complete -c para -n "__fish_para_using_subcommand help; and not __fish_seen_subcommand_from start finish rollback-integration cancel clean restore-trash gc doctor upgrade-state list resume recover adopt checkpoint sync note link pin unpin launch self-update audit conflicts which statusline summary logs examples config completion init mcp _completion_sessions _completion_branches monitor status auth daemon proxy selftest integrate continue help" -f -a "examples" -d 'Show example command lines for common workflows'
+complete -c para -n "__fish_para_using_subcommand help; and not __fish_seen_subcommand_from start finish rollback-integration cancel clean restore-trash gc doctor upgrade-state list resume recover adopt checkpoint sync note link pin unpin launch self-update audit conflicts which statusline summary logs examples config completion init mcp _completion_sessions _completion_branches monitor status auth daemon proxy selftest integrate continue help" -f -a "config" -d 'Setup configuration'
+complete -c para -n "__fish_para_using_subcommand help; and not __fish_seen_subcommand_from start finish rollback-integration cancel clean restore-trash gc doctor upgrade-state list resume recover adopt checkpoint sync note link pin unpin launch self-update audit conflicts which statusline summary logs examples config completion init mcp _completion_sessions _completion_branches monitor status auth daemon proxy selftest integrate continue help" -f -a "completion" -d 'Generate shell completion script'
+complete -c para -n "__fish_para_using_subcommand help; and not __fish_seen_subcommand_from start finish rollback-integration cancel clean restore-trash gc doctor upgrade-state list resume recover adopt checkpoint sync note link pin unpin launch self-update audit conflicts which statusline summary logs examples config completion init mcp _completion_sessions _completion_branches monitor status auth daemon proxy selftest integrate continue help" -f -a "init" -d 'Initialize shell completions automatically'
+complete -c para -n "__fish_para_using_subcommand help; and not __fish_seen_subcommand_from start finish rollback-integration cancel clean restore-trash gc doctor upgrade-state list resume recover adopt checkpoint sync note link pin unpin launch self-update audit conflicts which statusline summary logs examples config completion init mcp _completion_sessions _completion_branches monitor status auth daemon proxy selftest integrate continue help" -f -a "mcp" -d 'Setup Model Context Protocol (MCP) integration'
+complete -c para -n "__fish_para_using_subcommand help; and not __fish_seen_subcommand_from start finish rollback-integration cancel clean restore-trash gc doctor upgrade-state list resume recover adopt checkpoint sync note link pin unpin launch self-update audit conflicts which statusline summary logs examples config completion init mcp _completion_sessions _completion_branches monitor status auth daemon proxy selftest integrate continue help" -f -a "_completion_sessions" -d 'Legacy completion endpoint for sessions (hidden)'
+complete -c para -n "__fish_para_using_subcommand help; and not __fish_seen_subcommand_from start finish rollback-integration cancel clean restore-trash gc doctor upgrade-state list resume recover adopt checkpoint sync note link pin unpin launch self-update audit conflicts which statusline summary logs examples config completion init mcp _completion_sessions _completion_branches monitor status auth daemon proxy selftest integrate continue help" -f -a "_completion_branches" -d 'Legacy completion endpoint for branches (hidden)'
+complete -c para -n "__fish_para_using_subcommand help; and not __fish_seen_subcommand_from start finish rollback-integration cancel clean restore-trash gc doctor upgrade-state list resume recover adopt checkpoint sync note link pin unpin launch self-update audit conflicts which statusline summary logs examples config completion init mcp _completion_sessions _completion_branches monitor status auth daemon proxy selftest integrate continue help" -f -a "monitor" -d 'Monitor and manage active sessions in real-time (interactive TUI with mouse support)'
+complete -c para -n "__fish_para_using_subcommand help; and not __fish_seen_subcommand_from start finish rollback-integration cancel clean restore-trash gc doctor upgrade-state list resume recover adopt checkpoint sync note link pin unpin launch self-update audit conflicts which statusline summary logs examples config completion init mcp _completion_sessions _completion_branches monitor status auth daemon proxy selftest integrate continue help" -f -a "status" -d 'Update session status (for agents to communicate progress)'
+complete -c para -n "__fish_para_using_subcommand help; and not __fish_seen_subcommand_from start finish rollback-integration cancel clean restore-trash gc doctor upgrade-state list resume recover adopt checkpoint sync note link pin unpin launch self-update audit conflicts which statusline summary logs examples config completion init mcp _completion_sessions _completion_branches monitor status auth daemon proxy selftest integrate continue help" -f -a "auth" -d 'Manage Docker container authentication'
+complete -c para -n "__fish_para_using_subcommand help; and not __fish_seen_subcommand_from start finish rollback-integration cancel clean restore-trash gc doctor upgrade-state list resume recover adopt checkpoint sync note link pin unpin launch self-update audit conflicts which statusline summary logs examples config completion init mcp _completion_sessions _completion_branches monitor status auth daemon proxy selftest integrate continue help" -f -a "daemon" -d 'Manage para daemon (internal use)'
+complete -c para -n "__fish_para_using_subcommand help; and not __fish_seen_subcommand_from start finish rollback-integration cancel clean restore-trash gc doctor upgrade-state list resume recover adopt checkpoint sync note link pin unpin launch self-update audit conflicts which statusline summary logs examples config completion init mcp _completion_sessions _completion_branches monitor status auth daemon proxy selftest integrate continue help" -f -a "proxy" -d 'Run network proxy for sandboxing (internal use)'
+complete -c para -n "__fish_para_using_subcommand help; and not __fish_seen_subcommand_from start finish rollback-integration cancel clean restore-trash gc doctor upgrade-state list resume recover adopt checkpoint sync note link pin unpin launch self-update audit conflicts which statusline summary logs examples config completion init mcp _completion_sessions _completion_branches monitor status auth daemon proxy selftest integrate continue help" -f -a "selftest" -d 'Run the session lifecycle in a throwaway repository to check the installation'
+complete -c para -n "__fish_para_using_subcommand help; and not __fish_seen_subcommand_from start finish rollback-integration cancel clean restore-trash gc doctor upgrade-state list resume recover adopt checkpoint sync note link pin unpin launch self-update audit conflicts which statusline summary logs examples config completion init mcp _completion_sessions _completion_branches monitor status auth daemon proxy selftest integrate continue help" -f -a "integrate" -d '[deprecated] Finish the current session; use `para finish`'
+complete -c para -n "__fish_para_using_subcommand help; and not __fish_seen_subcommand_from start finish rollback-integration cancel clean restore-trash gc doctor upgrade-state list resume recover adopt checkpoint sync note link pin unpin launch self-update audit conflicts which statusline summary logs examples config completion init mcp _completion_sessions _completion_branches monitor status auth daemon proxy selftest integrate continue help" -f -a "continue" -d '[deprecated] Carry on with a sync stopped on conflicts; use `git rebase --continue` and `para sync`'
+complete -c para -n "__fish_para_using_subcommand help; and not __fish_seen_subcommand_from start finish rollback-integration cancel clean restore-trash gc doctor upgrade-state list resume recover adopt checkpoint sync note link pin unpin launch self-update audit conflicts which statusline summary logs examples config completion init mcp _completion_sessions _completion_branches monitor status auth daemon proxy selftest integrate continue help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c para -n "__fish_para_using_subcommand help; and __fish_seen_subcommand_from link" -f -a "add" -d 'Add a link to a session, or point an existing label at a new URL'
+complete -c para -n "__fish_para_using_subcommand help; and __fish_seen_subcommand_from link" -f -a "remove" -d 'Remove a link by label or URL'
+complete -c para -n "__fish_para_using_subcommand help; and __fish_seen_subcommand_from link" -f -a "list" -d 'List a session\'s links'
+complete -c para -n "__fish_para_using_subcommand help; and __fish_seen_subcommand_from audit" -f -a "show" -d 'Show logged git commands for a session'
+complete -c para -n "__fish_para_using_subcommand help; and __fish_seen_subcommand_from conflicts" -f -a "show" -d 'Show recorded conflict resolutions, oldest first'
+complete -c para -n "__fish_para_using_subcommand help; and __fish_seen_subcommand_from config" -f -a "setup" -d 'Interactive configuration wizard'
+complete -c para -n "__fish_para_using_subcommand help; and __fish_seen_subcommand_from config" -f -a "auto" -d 'Auto-detect and configure IDE'
+complete -c para -n "__fish_para_using_subcommand help; and __fish_seen_subcommand_from config" -f -a "show" -d 'Show current configuration'
+complete -c para -n "__fish_para_using_subcommand help; and __fish_seen_subcommand_from config" -f -a "edit" -d 'Edit configuration file'
+complete -c para -n "__fish_para_using_subcommand help; and __fish_seen_subcommand_from config" -f -a "reset" -d 'Reset configuration to defaults'
+complete -c para -n "__fish_para_using_subcommand help; and __fish_seen_subcommand_from config" -f -a "set" -d 'Set configuration value using JSON path'
+complete -c para -n "__fish_para_using_subcommand help; and __fish_seen_subcommand_from config" -f -a "get" -d 'Print one configuration value'
+complete -c para -n "__fish_para_using_subcommand help; and __fish_seen_subcommand_from config" -f -a "keys" -d 'List every configuration key with its type'
+complete -c para -n "__fish_para_using_subcommand help; and __fish_seen_subcommand_from config" -f -a "session" -d 'Show or change settings recorded for one session'
+complete -c para -n "__fish_para_using_subcommand help; and __fish_seen_subcommand_from config" -f -a "project" -d 'Manage project-level configuration'
+complete -c para -n "__fish_para_using_subcommand help; and __fish_seen_subcommand_from config" -f -a "notify" -d 'Manage session event notifications'
+complete -c para -n "__fish_para_using_subcommand help; and __fish_seen_subcommand_from config" -f -a "templates" -d 'List or show session templates for \'para start --template\''
+complete -c para -n "__fish_para_using_subcommand help; and __fish_seen_subcommand_from config" -f -a "path" -d 'Print where the user config is read from and why'
+complete -c para -n "__fish_para_using_subcommand help; and __fish_seen_subcommand_from config" -f -a "doctor" -d 'Check the config file for unknown, renamed and missing keys'
+complete -c para -n "__fish_para_using_subcommand help; and __fish_seen_subcommand_from config" -f -a "export" -d 'Export configuration with portable paths for use on another machine'
+complete -c para -n "__fish_para_using_subcommand help; and __fish_seen_subcommand_from config" -f -a "import" -d 'Import configuration exported with \'para config export\''
+complete -c para -n "__fish_para_using_subcommand help; and __fish_seen_subcommand_from completion" -f -a "generate" -d 'Print the completion script for a shell'
+complete -c para -n "__fish_para_using_subcommand help; and __fish_seen_subcommand_from completion" -f -a "install" -d 'Write the completion script to the shell\'s per-user completion directory'
+complete -c para -n "__fish_para_using_subcommand help; and __fish_seen_subcommand_from mcp" -f -a "init" -d 'Initialize MCP integration for Para'
+complete -c para -n "__fish_para_using_subcommand help; and __fish_seen_subcommand_from status" -f -a "show" -d 'Show status of one or all sessions'
+complete -c para -n "__fish_para_using_subcommand help; and __fish_seen_subcommand_from status" -f -a "summary" -d 'Generate a summary of all status files'
+complete -c para -n "__fish_para_using_subcommand help; and __fish_seen_subcommand_from status" -f -a "cleanup" -d 'Clean up stale status files'
+complete -c para -n "__fish_para_using_subcommand help; and __fish_seen_subcommand_from status" -f -a "todo" -d 'Add, complete and list the session\'s todos'
+complete -c para -n "__fish_para_using_subcommand help; and __fish_seen_subcommand_from status" -f -a "wait" -d 'Block until a session\'s status meets a condition'
+complete -c para -n "__fish_para_using_subcommand help; and __fish_seen_subcommand_from auth" -f -a "setup" -d 'Set up container authentication interactively'
+complete -c para -n "__fish_para_using_subcommand help; and __fish_seen_subcommand_from auth" -f -a "cleanup" -d 'Remove authentication artifacts'
+complete -c para -n "__fish_para_using_subcommand help; and __fish_seen_subcommand_from auth" -f -a "status" -d 'Check authentication status'
+complete -c para -n "__fish_para_using_subcommand help; and __fish_seen_subcommand_from auth" -f -a "reauth" -d 'Re-authenticate (cleanup and setup in one command)'
+complete -c para -n "__fish_para_using_subcommand help; and __fish_seen_subcommand_from daemon" -f -a "start" -d 'Start the daemon'
+complete -c para -n "__fish_para_using_subcommand help; and __fish_seen_subcommand_from daemon" -f -a "stop" -d 'Stop the daemon'
+complete -c para -n "__fish_para_using_subcommand help; and __fish_seen_subcommand_from daemon" -f -a "status" -d 'Check daemon status'
+complete -c para -n "__fish_para_using_subcommand help; and __fish_seen_subcommand_from daemon" -f -a "prefetch" -d 'Fetch the base branches of this repository\'s sessions now'
+complete -c para -n "__fish_para_using_subcommand help; and __fish_seen_subcommand_from daemon" -f -a "cleanup" -d 'Remove orphaned para containers and their volumes and networks'
+
+
+
+# Para completion helper functions for fish shell
+function __para_sessions
+    # Avoid recursive calls during completion generation
+    if test -z "$PARA_COMPLETION_SCRIPT"
+        # Use timeout to prevent hanging on file system operations
+        if command -v timeout >/dev/null 2>&1
+            timeout 2 env PARA_COMPLETION_SCRIPT=1 para _completion_sessions 2>/dev/null
+        else
+            PARA_COMPLETION_SCRIPT=1 para _completion_sessions 2>/dev/null
+        end
+    end
+end
+
+function __para_archived_sessions
+    # Avoid recursive calls during completion generation
+    if test -z "$PARA_COMPLETION_SCRIPT"
+        # Use timeout to prevent hanging on file system operations
+        if command -v timeout >/dev/null 2>&1
+            timeout 2 env PARA_COMPLETION_SCRIPT=1 para list --archived --quiet 2>/dev/null | string match -r '^[a-zA-Z0-9_-]*' 2>/dev/null
+        else
+            PARA_COMPLETION_SCRIPT=1 para list --archived --quiet 2>/dev/null | string match -r '^[a-zA-Z0-9_-]*' 2>/dev/null
+        end
+    end
+end
+
+function __para_branches
+    # Avoid recursive calls during completion generation
+    if test -z "$PARA_COMPLETION_SCRIPT"
+        if command -v timeout >/dev/null 2>&1
+            timeout 2 env PARA_COMPLETION_SCRIPT=1 para _completion_branches 2>/dev/null
+        else
+            PARA_COMPLETION_SCRIPT=1 para _completion_branches 2>/dev/null
+        end
+    end
+end
+
+function __para_session_branches
+    # Avoid recursive calls during completion generation
+    if test -z "$PARA_COMPLETION_SCRIPT"
+        if command -v timeout >/dev/null 2>&1
+            timeout 2 env PARA_COMPLETION_SCRIPT=1 para _completion_branches --para 2>/dev/null
+        else
+            PARA_COMPLETION_SCRIPT=1 para _completion_branches --para 2>/dev/null
+        end
+    end
+end
+
+function __para_config_keys
+    # Avoid recursive calls during completion generation
+    if test -z "$PARA_COMPLETION_SCRIPT"
+        PARA_COMPLETION_SCRIPT=1 para config keys --quiet 2>/dev/null
+    end
+end
+
+# Enhanced Para Dynamic Completions
+
+# 1. SESSION COMPLETIONS
+# para resume <session-name>
+complete -f -c para -n "__fish_para_using_subcommand resume" -a "(__para_sessions)" -d "Active session"
+
+# para cancel <session-name>  
+complete -f -c para -n "__fish_para_using_subcommand cancel" -a "(__para_sessions)" -d "Active session"
+
+# para recover <session-name>
+complete -f -c para -n "__fish_para_using_subcommand recover" -a "(__para_archived_sessions)" -d "Archived session"
+
+# para finish [message] [session-name] - session name is second argument after message
+function __para_finish_needs_session
+    set -l cmd (commandline -opc)
+    test (count $cmd) -ge 3
+end
+complete -f -c para -n "__fish_para_using_subcommand finish; and __para_finish_needs_session" -a "(__para_sessions)" -d "Session to finish"
+
+# 2. BRANCH COMPLETIONS  
+# para finish --branch <branch>
+complete -f -c para -n "__fish_para_using_subcommand finish" -l branch -a "(__para_branches)" -d "Custom branch name"
+
+# para finish --session-branch <branch>, para cancel|resume --branch <branch>
+complete -f -c para -n "__fish_para_using_subcommand finish" -l session-branch -a "(__para_session_branches)" -d "Session branch"
+complete -f -c para -n "__fish_para_using_subcommand cancel resume" -l branch -a "(__para_session_branches)" -d "Session branch"
+
+# para start --base <branch>
+complete -f -c para -n "__fish_para_using_subcommand start" -l base -a "(__para_branches)" -d "Base branch"
+
+# para start|dispatch --apply-from <branch>
+complete -f -c para -n "__fish_para_using_subcommand start dispatch" -l apply-from -a "(__para_branches)" -d "Branch to start from"
+
+# 3. REMOTE COMPLETIONS
+# para start|dispatch --remote <remote>
+complete -f -c para -n "__fish_para_using_subcommand start dispatch" -l remote -a "(git remote 2>/dev/null)" -d "Git remote"
+
+# 4. FILE COMPLETIONS
+# para dispatch --file <file>
+complete -c para -n "__fish_para_using_subcommand dispatch" -s f -l file -F -d "Prompt file"
+
+# 5. SHELL COMPLETIONS  
+# para completion <shell>
+complete -f -c para -n "__fish_para_using_subcommand completion" -a "bash zsh fish powershell" -d "Shell type"
+
+# 6. CONFIG SUBCOMMAND COMPLETIONS
+# para config <subcommand>
+complete -f -c para -n "__fish_para_using_subcommand config" -a "setup auto show edit reset set get keys" -d "Config operation"
+
+# para config set|get <key>
+function __para_config_needs_key
+    set -l cmd (commandline -opc)
+    test (count $cmd) -eq 3; and contains -- $cmd[3] set get
+end
+complete -f -c para -n "__fish_para_using_subcommand config; and __para_config_needs_key" -a "(__para_config_keys)" -d "Config key"
+
+# para -c <key>=<value>
+complete -f -c para -s c -l config-override -a "(__para_config_keys | string replace -r '\$' '=')" -d "Config key"
+
+# 7. SPECIAL COMPLETIONS FOR TASK FILES
+# Enhanced file completion for dispatch that prioritizes .md files and TASK_* files
+function __para_task_files
+    # Prioritize TASK_* files and .md files
+    find . -maxdepth 1 \( -name "TASK_*.md" -o -name "*.md" -o -name "*.txt" \) 2>/dev/null | sed 's|^\./||'
+end
+complete -c para -n "__fish_para_using_subcommand dispatch" -s f -l file -a "(__para_task_files)" -d "Task or prompt file"
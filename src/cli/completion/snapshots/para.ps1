@@ -0,0 +1,1983 @@
+
+using namespace System.Management.Automation
+using namespace System.Management.Automation.Language
+
+
+# Para completion helper functions for PowerShell
+function Get-ParaCompletionValues([string]$Kind) {
+    # Avoid recursive calls during completion generation
+    if ($env:PARA_COMPLETION_SCRIPT) { return }
+    $env:PARA_COMPLETION_SCRIPT = '1'
+    try {
+        switch ($Kind) {
+            'sessions' { para _completion_sessions 2>$null }
+            'archived' { para list --archived --quiet 2>$null | ForEach-Object { ($_ -split '\s+')[0] } }
+            'branches' { para _completion_branches 2>$null }
+            'session-branches' { para _completion_branches --para 2>$null }
+            'remotes' { git remote 2>$null }
+            'config-keys' { para config keys --quiet 2>$null }
+        }
+    } finally {
+        Remove-Item Env:PARA_COMPLETION_SCRIPT -ErrorAction SilentlyContinue
+    }
+}
+
+function Get-ParaDynamicCompletions($CommandElements, [string]$WordToComplete) {
+    if ($CommandElements.Count -lt 2 -or $WordToComplete.StartsWith('-')) { return }
+    $subcommand = "$($CommandElements[1])"
+    $previousIndex = if ($WordToComplete) { $CommandElements.Count - 2 } else { $CommandElements.Count - 1 }
+    $previous = "$($CommandElements[$previousIndex])"
+
+    # -c <key>=<value>
+    if ($previous -in '-c', '--config-override') {
+        return Get-ParaCompletionValues 'config-keys' |
+            Where-Object { $_ -like "$WordToComplete*" } |
+            ForEach-Object {
+                [System.Management.Automation.CompletionResult]::new("$_=", $_, [System.Management.Automation.CompletionResultType]::ParameterValue, $_)
+            }
+    }
+
+    $kind = switch ($subcommand) {
+        { $_ -in 'resume', 'cancel' } {
+            if ($previous -in '--branch', '--session-branch') { 'session-branches' }
+            elseif ($previousIndex -eq 1) { 'sessions' }
+            break
+        }
+        'recover' {
+            if ($previousIndex -eq 1) { 'archived' }
+            break
+        }
+        'finish' {
+            # para finish <message> <session>
+            if ($previous -eq '--branch') { 'branches' }
+            elseif ($previous -eq '--session-branch') { 'session-branches' }
+            elseif ($previousIndex -ge 2 -and -not $previous.StartsWith('-')) { 'sessions' }
+            break
+        }
+        { $_ -in 'start', 'dispatch' } {
+            if ($previous -in '--base', '--apply-from') { 'branches' }
+            elseif ($previous -eq '--remote') { 'remotes' }
+            break
+        }
+        'config' {
+            # para config set|get <key>
+            if ($previousIndex -eq 2 -and $previous -in 'set', 'get') { 'config-keys' }
+            break
+        }
+    }
+    if (-not $kind) { return }
+
+    Get-ParaCompletionValues $kind |
+        Where-Object { $_ -like "$WordToComplete*" } |
+        ForEach-Object {
+            [System.Management.Automation.CompletionResult]::new($_, $_, [System.Management.Automation.CompletionResultType]::ParameterValue, $_)
+        }
+}
+
+Register-ArgumentCompleter -Native -CommandName 'para' -ScriptBlock {
+    param($wordToComplete, $commandAst, $cursorPosition)
+
+    $commandElements = $commandAst.CommandElements
+    $command = @(
+        'para'
+        for ($i = 1; $i -lt $commandElements.Count; $i++) {
+            $element = $commandElements[$i]
+            if ($element -isnot [StringConstantExpressionAst] -or
+                $element.StringConstantType -ne [StringConstantType]::BareWord -or
+                $element.Value.StartsWith('-') -or
+                $element.Value -eq $wordToComplete) {
+                break
+        }
+        $element.Value
+    }) -join ';'
+
+    $dynamic = @(Get-ParaDynamicCompletions $commandElements $wordToComplete)
+    if ($dynamic.Count -gt 0) {
+        return $dynamic
+    }
+
+    $completions = @(switch ($command) {
+        'para' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Override a config value for this command only (repeatable, not saved)')
+            [CompletionResult]::new('--config-override', '--config-override', [CompletionResultType]::ParameterName, 'Override a config value for this command only (repeatable, not saved)')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('-V', '-V ', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('--version', '--version', [CompletionResultType]::ParameterName, 'Print version')
+            [CompletionResult]::new('start', 'start', [CompletionResultType]::ParameterValue, 'Create new para sessions (interactive or AI-assisted)')
+            [CompletionResult]::new('finish', 'finish', [CompletionResultType]::ParameterValue, 'Complete session and create feature branch for review')
+            [CompletionResult]::new('rollback-integration', 'rollback-integration', [CompletionResultType]::ParameterValue, 'Undo finishing a session onto another session''s branch')
+            [CompletionResult]::new('cancel', 'cancel', [CompletionResultType]::ParameterValue, 'Cancel session (moves to archive)')
+            [CompletionResult]::new('clean', 'clean', [CompletionResultType]::ParameterValue, 'Remove stale para artifacts (and optionally all active sessions)')
+            [CompletionResult]::new('restore-trash', 'restore-trash', [CompletionResultType]::ParameterValue, 'Put an item `para clean` moved to the trash back in place')
+            [CompletionResult]::new('gc', 'gc', [CompletionResultType]::ParameterValue, 'Compact logs and remove leftover files in the state directory')
+            [CompletionResult]::new('doctor', 'doctor', [CompletionResultType]::ParameterValue, 'Report version skew between the CLI, daemon, config and sessions')
+            [CompletionResult]::new('upgrade-state', 'upgrade-state', [CompletionResultType]::ParameterValue, 'Upgrade all session state files to the current format, or restore a backup')
+            [CompletionResult]::new('list', 'list', [CompletionResultType]::ParameterValue, 'List active sessions')
+            [CompletionResult]::new('resume', 'resume', [CompletionResultType]::ParameterValue, 'Resume session in IDE')
+            [CompletionResult]::new('recover', 'recover', [CompletionResultType]::ParameterValue, 'Recover cancelled session from archive')
+            [CompletionResult]::new('adopt', 'adopt', [CompletionResultType]::ParameterValue, 'Turn an existing branch into a session')
+            [CompletionResult]::new('checkpoint', 'checkpoint', [CompletionResultType]::ParameterValue, 'Commit pending work in a session as a WIP checkpoint')
+            [CompletionResult]::new('sync', 'sync', [CompletionResultType]::ParameterValue, 'Rebase a session onto the latest commit of the branch it was started from')
+            [CompletionResult]::new('note', 'note', [CompletionResultType]::ParameterValue, 'Add, edit or show notes about a session')
+            [CompletionResult]::new('link', 'link', [CompletionResultType]::ParameterValue, 'Attach links to PRs, tickets and docs to a session')
+            [CompletionResult]::new('pin', 'pin', [CompletionResultType]::ParameterValue, 'Keep a session out of stale and idle detection and bulk cleanups')
+            [CompletionResult]::new('unpin', 'unpin', [CompletionResultType]::ParameterValue, 'Undo `para pin`')
+            [CompletionResult]::new('launch', 'launch', [CompletionResultType]::ParameterValue, 'Launch Claude in a session prepared with --plan-only')
+            [CompletionResult]::new('self-update', 'self-update', [CompletionResultType]::ParameterValue, 'Check for a newer para release and update to it')
+            [CompletionResult]::new('audit', 'audit', [CompletionResultType]::ParameterValue, 'Show the git commands para ran on the repository')
+            [CompletionResult]::new('conflicts', 'conflicts', [CompletionResultType]::ParameterValue, 'Show how earlier merge conflicts were resolved')
+            [CompletionResult]::new('which', 'which', [CompletionResultType]::ParameterValue, 'Print the session the current directory belongs to')
+            [CompletionResult]::new('statusline', 'statusline', [CompletionResultType]::ParameterValue, 'Print a one-line summary of the current session for status bars')
+            [CompletionResult]::new('summary', 'summary', [CompletionResultType]::ParameterValue, 'Summarize a session''s task, commits, changes and status history')
+            [CompletionResult]::new('logs', 'logs', [CompletionResultType]::ParameterValue, 'Show the setup script, container and daemon logs of a session')
+            [CompletionResult]::new('examples', 'examples', [CompletionResultType]::ParameterValue, 'Show example command lines for common workflows')
+            [CompletionResult]::new('config', 'config', [CompletionResultType]::ParameterValue, 'Setup configuration')
+            [CompletionResult]::new('completion', 'completion', [CompletionResultType]::ParameterValue, 'Generate shell completion script')
+            [CompletionResult]::new('init', 'init', [CompletionResultType]::ParameterValue, 'Initialize shell completions automatically')
+            [CompletionResult]::new('mcp', 'mcp', [CompletionResultType]::ParameterValue, 'Setup Model Context Protocol (MCP) integration')
+            [CompletionResult]::new('_completion_sessions', '_completion_sessions', [CompletionResultType]::ParameterValue, 'Legacy completion endpoint for sessions (hidden)')
+            [CompletionResult]::new('_completion_branches', '_completion_branches', [CompletionResultType]::ParameterValue, 'Legacy completion endpoint for branches (hidden)')
+            [CompletionResult]::new('monitor', 'monitor', [CompletionResultType]::ParameterValue, 'Monitor and manage active sessions in real-time (interactive TUI with mouse support)')
+            [CompletionResult]::new('status', 'status', [CompletionResultType]::ParameterValue, 'Update session status (for agents to communicate progress)')
+            [CompletionResult]::new('auth', 'auth', [CompletionResultType]::ParameterValue, 'Manage Docker container authentication')
+            [CompletionResult]::new('daemon', 'daemon', [CompletionResultType]::ParameterValue, 'Manage para daemon (internal use)')
+            [CompletionResult]::new('proxy', 'proxy', [CompletionResultType]::ParameterValue, 'Run network proxy for sandboxing (internal use)')
+            [CompletionResult]::new('selftest', 'selftest', [CompletionResultType]::ParameterValue, 'Run the session lifecycle in a throwaway repository to check the installation')
+            [CompletionResult]::new('integrate', 'integrate', [CompletionResultType]::ParameterValue, '[deprecated] Finish the current session; use `para finish`')
+            [CompletionResult]::new('continue', 'continue', [CompletionResultType]::ParameterValue, '[deprecated] Carry on with a sync stopped on conflicts; use `git rebase --continue` and `para sync`')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'para;start' {
+            [CompletionResult]::new('-p', '-p', [CompletionResultType]::ParameterName, 'Prompt for AI-assisted session')
+            [CompletionResult]::new('--prompt', '--prompt', [CompletionResultType]::ParameterName, 'Prompt for AI-assisted session')
+            [CompletionResult]::new('-f', '-f', [CompletionResultType]::ParameterName, 'Read prompt or context from specified file')
+            [CompletionResult]::new('--file', '--file', [CompletionResultType]::ParameterName, 'Read prompt or context from specified file')
+            [CompletionResult]::new('--prompt-file', '--prompt-file', [CompletionResultType]::ParameterName, 'Read prompt or context from specified file')
+            [CompletionResult]::new('--allow-domains', '--allow-domains', [CompletionResultType]::ParameterName, 'Enable network isolation and allow access to specified domains (comma-separated)')
+            [CompletionResult]::new('--docker-args', '--docker-args', [CompletionResultType]::ParameterName, 'Additional Docker arguments to pass through')
+            [CompletionResult]::new('--setup-script', '--setup-script', [CompletionResultType]::ParameterName, 'Path to setup script to run after session creation')
+            [CompletionResult]::new('--docker-image', '--docker-image', [CompletionResultType]::ParameterName, 'Custom Docker image to use (e.g., ''ubuntu:22.04'')')
+            [CompletionResult]::new('--copy', '--copy', [CompletionResultType]::ParameterName, 'Copy untracked files matching GLOB (e.g. ''.env'', ''**/local.settings.json'') from the main worktree into the session (repeatable)')
+            [CompletionResult]::new('--merge-mode', '--merge-mode', [CompletionResultType]::ParameterName, 'Merge mode recorded for the session and used by finish (default: git.default_merge_mode or squash)')
+            [CompletionResult]::new('--apply-patch', '--apply-patch', [CompletionResultType]::ParameterName, 'Apply this patch file (git apply --3way) to the new session as uncommitted work')
+            [CompletionResult]::new('--apply-from', '--apply-from', [CompletionResultType]::ParameterName, 'Apply the diff of BRANCH against the session''s base to the new session as uncommitted work')
+            [CompletionResult]::new('--base', '--base', [CompletionResultType]::ParameterName, 'Create the session from this branch, commit or session instead of the current branch (a session name stacks the new session on it)')
+            [CompletionResult]::new('--remote', '--remote', [CompletionResultType]::ParameterName, 'Remote to fetch --base from (default: git.default_remote, then origin)')
+            [CompletionResult]::new('--retry', '--retry', [CompletionResultType]::ParameterName, 'Start a new session with the task and settings of SESSION (active or cancelled); the positional argument or --prompt adds extra instructions')
+            [CompletionResult]::new('--sandbox-profile', '--sandbox-profile', [CompletionResultType]::ParameterName, 'Sandbox profile to use: permissive (default) or restrictive')
+            [CompletionResult]::new('--allowed-domains', '--allowed-domains', [CompletionResultType]::ParameterName, 'Additional domains allowed through network proxy (e.g., npmjs.org,pypi.org)')
+            [CompletionResult]::new('--template', '--template', [CompletionResultType]::ParameterName, 'Use the options of a template from the config as defaults (see ''para config templates list'')')
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--prompt-is-file', '--prompt-is-file', [CompletionResultType]::ParameterName, 'Read the prompt from the file named by --prompt, which is otherwise taken as text')
+            [CompletionResult]::new('--no-stdin', '--no-stdin', [CompletionResultType]::ParameterName, 'Ignore piped stdin, for scripts that cannot control their stdin')
+            [CompletionResult]::new('--allow-secrets', '--allow-secrets', [CompletionResultType]::ParameterName, 'Don''t warn about or block prompts that look like they contain secrets (tokens, keys, passwords)')
+            [CompletionResult]::new('-d', '-d', [CompletionResultType]::ParameterName, 'Skip IDE permission warnings (dangerous)')
+            [CompletionResult]::new('--dangerously-skip-permissions', '--dangerously-skip-permissions', [CompletionResultType]::ParameterName, 'Skip IDE permission warnings (dangerous)')
+            [CompletionResult]::new('-c', '-c', [CompletionResultType]::ParameterName, 'Run session in Docker container')
+            [CompletionResult]::new('--container', '--container', [CompletionResultType]::ParameterName, 'Run session in Docker container')
+            [CompletionResult]::new('--no-forward-keys', '--no-forward-keys', [CompletionResultType]::ParameterName, 'Disable automatic API key forwarding to Docker containers')
+            [CompletionResult]::new('--allow-empty-repo', '--allow-empty-repo', [CompletionResultType]::ParameterName, 'Create an empty initial commit if the repository has no commits yet')
+            [CompletionResult]::new('--copy-large', '--copy-large', [CompletionResultType]::ParameterName, 'Also copy gitignored files larger than 1 MiB when copying untracked files')
+            [CompletionResult]::new('--no-hook-fixup', '--no-hook-fixup', [CompletionResultType]::ParameterName, 'Don''t point an absolute core.hooksPath inside the repository at the new worktree')
+            [CompletionResult]::new('--no-auto-commit', '--no-auto-commit', [CompletionResultType]::ParameterName, 'Disable automatic WIP checkpoint commits for this session')
+            [CompletionResult]::new('--fetch', '--fetch', [CompletionResultType]::ParameterName, 'Fetch --base from the remote first and start from the fetched commit')
+            [CompletionResult]::new('--cancel-old', '--cancel-old', [CompletionResultType]::ParameterName, 'Cancel the --retry session after the new session has started')
+            [CompletionResult]::new('--plan-only', '--plan-only', [CompletionResultType]::ParameterName, 'Create the worktree, state and task file but do not launch Claude; start it later with ''para launch''')
+            [CompletionResult]::new('--defer-setup', '--defer-setup', [CompletionResultType]::ParameterName, 'With --plan-only, run the setup script when the session is launched instead of now')
+            [CompletionResult]::new('-s', '-s', [CompletionResultType]::ParameterName, 'Enable sandboxing for Claude CLI (overrides config)')
+            [CompletionResult]::new('--sandbox', '--sandbox', [CompletionResultType]::ParameterName, 'Enable sandboxing for Claude CLI (overrides config)')
+            [CompletionResult]::new('--no-sandbox', '--no-sandbox', [CompletionResultType]::ParameterName, 'Disable sandboxing for Claude CLI (overrides config)')
+            [CompletionResult]::new('--sandbox-no-network', '--sandbox-no-network', [CompletionResultType]::ParameterName, 'Enable sandboxing with network isolation via proxy')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;finish' {
+            [CompletionResult]::new('-b', '-b', [CompletionResultType]::ParameterName, 'Rename feature branch to specified name')
+            [CompletionResult]::new('--branch', '--branch', [CompletionResultType]::ParameterName, 'Rename feature branch to specified name')
+            [CompletionResult]::new('--session-branch', '--session-branch', [CompletionResultType]::ParameterName, 'Finish the session whose branch is BRANCH (--branch renames the branch here)')
+            [CompletionResult]::new('--merge-mode', '--merge-mode', [CompletionResultType]::ParameterName, 'Shape the session''s commits this way instead of using its recorded merge mode')
+            [CompletionResult]::new('--onto-session', '--onto-session', [CompletionResultType]::ParameterName, 'Commit the session''s changes onto the branch of active session SESSION (stacked sessions)')
+            [CompletionResult]::new('--remote-branch', '--remote-branch', [CompletionResultType]::ParameterName, 'Finish branch BRANCH from origin in a plain clone (e.g. in CI) and push it and --base')
+            [CompletionResult]::new('--base', '--base', [CompletionResultType]::ParameterName, 'Branch on origin that --remote-branch is finished onto and pushed to')
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'If another git process holds the repository''s index.lock, wait up to SECONDS for it instead of failing')
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--force-rename', '--force-rename', [CompletionResultType]::ParameterName, 'Temporarily detach other worktrees that have the session branch checked out')
+            [CompletionResult]::new('--allow-protected', '--allow-protected', [CompletionResultType]::ParameterName, 'Proceed even if a protected branch (e.g. main) is checked out in the session worktree')
+            [CompletionResult]::new('--check', '--check', [CompletionResultType]::ParameterName, 'Check whether the session merges cleanly into its base branch without changing anything (exit code 6 on conflicts)')
+            [CompletionResult]::new('--staged-only', '--staged-only', [CompletionResultType]::ParameterName, 'Commit only the staged changes; unstaged and untracked changes stay in the worktree')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Print the result as one JSON object on stdout; everything else goes to stderr')
+            [CompletionResult]::new('--unshallow', '--unshallow', [CompletionResultType]::ParameterName, 'In a shallow clone, fetch the missing history from the remote first instead of failing')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;rollback-integration' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;cancel' {
+            [CompletionResult]::new('--branch', '--branch', [CompletionResultType]::ParameterName, 'Cancel the session whose branch is BRANCH')
+            [CompletionResult]::new('--session-branch', '--session-branch', [CompletionResultType]::ParameterName, 'Cancel the session whose branch is BRANCH')
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('-f', '-f', [CompletionResultType]::ParameterName, 'Force cancellation even with uncommitted changes, active stacked sessions or a pin (destructive)')
+            [CompletionResult]::new('--force', '--force', [CompletionResultType]::ParameterName, 'Force cancellation even with uncommitted changes, active stacked sessions or a pin (destructive)')
+            [CompletionResult]::new('--force-rename', '--force-rename', [CompletionResultType]::ParameterName, 'Temporarily detach other worktrees that have the session branch checked out')
+            [CompletionResult]::new('--allow-protected', '--allow-protected', [CompletionResultType]::ParameterName, 'Proceed even if a protected branch (e.g. main) is checked out in the session worktree')
+            [CompletionResult]::new('--close-ide', '--close-ide', [CompletionResultType]::ParameterName, 'Close the IDE window first if it still has the session open')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Check whether the session can be cancelled and show its archive branch without changing anything')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Print the result as one JSON object on stdout; everything else goes to stderr')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;clean' {
+            [CompletionResult]::new('--only', '--only', [CompletionResultType]::ParameterName, 'Only remove these kinds of items this run (comma-separated, overrides cleanup.enabled_kinds)')
+            [CompletionResult]::new('--skip', '--skip', [CompletionResultType]::ParameterName, 'Leave these kinds of items alone this run (comma-separated)')
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('-f', '-f', [CompletionResultType]::ParameterName, 'Skip confirmation prompts')
+            [CompletionResult]::new('--force', '--force', [CompletionResultType]::ParameterName, 'Skip confirmation prompts')
+            [CompletionResult]::new('-i', '-i', [CompletionResultType]::ParameterName, 'Choose the items to clean from a checklist instead of confirming all of them')
+            [CompletionResult]::new('--interactive', '--interactive', [CompletionResultType]::ParameterName, 'Choose the items to clean from a checklist instead of confirming all of them')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Only show what would be cleaned (dry run)')
+            [CompletionResult]::new('--backups', '--backups', [CompletionResultType]::ParameterName, 'Also remove archived sessions')
+            [CompletionResult]::new('--containers', '--containers', [CompletionResultType]::ParameterName, 'Clean orphaned Docker containers')
+            [CompletionResult]::new('--docker', '--docker', [CompletionResultType]::ParameterName, 'Also prune dangling para-labeled Docker volumes, networks and images')
+            [CompletionResult]::new('--sessions', '--sessions', [CompletionResultType]::ParameterName, 'Also cancel and archive every active session that is not pinned (requires typing ''yes'', or --force)')
+            [CompletionResult]::new('--close-ide', '--close-ide', [CompletionResultType]::ParameterName, 'Close the IDE window of sessions that are still open before cancelling them')
+            [CompletionResult]::new('--empty-trash', '--empty-trash', [CompletionResultType]::ParameterName, 'Permanently delete everything in the trash now, whatever its age')
+            [CompletionResult]::new('--list-trash', '--list-trash', [CompletionResultType]::ParameterName, 'List trashed state files and archive branches and when they expire')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;restore-trash' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;gc' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;doctor' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;upgrade-state' {
+            [CompletionResult]::new('--restore', '--restore', [CompletionResultType]::ParameterName, 'Replace the state directory with a backup made before a migration')
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Only show which state files would be upgraded and the available backups')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;list' {
+            [CompletionResult]::new('--format', '--format', [CompletionResultType]::ParameterName, 'Format each session with a template, e.g. ''{name}\t{branch}\t{path}'' Placeholders: {name}, {branch}, {base}, {status}, {path}, {last_modified}, {type}, {note}')
+            [CompletionResult]::new('--sort', '--sort', [CompletionResultType]::ParameterName, 'Sort sessions by name, creation time, most recent activity or status')
+            [CompletionResult]::new('--since', '--since', [CompletionResultType]::ParameterName, 'Only show sessions created since a duration ago (30m, 12h, 3d, 2w) or a date (2024-03-01)')
+            [CompletionResult]::new('--until', '--until', [CompletionResultType]::ParameterName, 'Only show sessions created until a duration ago (30m, 12h, 3d, 2w) or a date (2024-03-01)')
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('-v', '-v', [CompletionResultType]::ParameterName, 'Show verbose session information')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Show verbose session information')
+            [CompletionResult]::new('-a', '-a', [CompletionResultType]::ParameterName, 'Show archived sessions')
+            [CompletionResult]::new('--archived', '--archived', [CompletionResultType]::ParameterName, 'Show archived sessions')
+            [CompletionResult]::new('--orphaned', '--orphaned', [CompletionResultType]::ParameterName, 'Show para branches that have no session or archive entry')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Quiet output for completion')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Quiet output for completion')
+            [CompletionResult]::new('--reverse', '--reverse', [CompletionResultType]::ParameterName, 'Reverse the sort order')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable colors (also disabled by NO_COLOR or when not on a terminal)')
+            [CompletionResult]::new('--full-paths', '--full-paths', [CompletionResultType]::ParameterName, 'Show worktree paths in full instead of shortening them to fit the terminal')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;resume' {
+            [CompletionResult]::new('--branch', '--branch', [CompletionResultType]::ParameterName, 'Resume the session whose branch is BRANCH')
+            [CompletionResult]::new('--session-branch', '--session-branch', [CompletionResultType]::ParameterName, 'Resume the session whose branch is BRANCH')
+            [CompletionResult]::new('-p', '-p', [CompletionResultType]::ParameterName, 'Additional prompt or instructions for the resumed session')
+            [CompletionResult]::new('--prompt', '--prompt', [CompletionResultType]::ParameterName, 'Additional prompt or instructions for the resumed session')
+            [CompletionResult]::new('-f', '-f', [CompletionResultType]::ParameterName, 'Read additional instructions from specified file')
+            [CompletionResult]::new('--file', '--file', [CompletionResultType]::ParameterName, 'Read additional instructions from specified file')
+            [CompletionResult]::new('--run', '--run', [CompletionResultType]::ParameterName, 'Run CMD in the session before opening it, after session.on_resume (repeatable)')
+            [CompletionResult]::new('--sandbox-profile', '--sandbox-profile', [CompletionResultType]::ParameterName, 'Sandbox profile to use: permissive (default) or restrictive')
+            [CompletionResult]::new('--allowed-domains', '--allowed-domains', [CompletionResultType]::ParameterName, 'Additional domains allowed through network proxy (e.g., npmjs.org,pypi.org)')
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--dangerously-skip-permissions', '--dangerously-skip-permissions', [CompletionResultType]::ParameterName, 'Skip IDE permission warnings (DANGEROUS: Only use for automated scripts)')
+            [CompletionResult]::new('--allow-secrets', '--allow-secrets', [CompletionResultType]::ParameterName, 'Don''t warn about or block prompts that look like they contain secrets (tokens, keys, passwords)')
+            [CompletionResult]::new('--run-ignore-errors', '--run-ignore-errors', [CompletionResultType]::ParameterName, 'Open the session even if a resume command fails')
+            [CompletionResult]::new('-s', '-s', [CompletionResultType]::ParameterName, 'Enable sandboxing for Claude CLI (overrides config)')
+            [CompletionResult]::new('--sandbox', '--sandbox', [CompletionResultType]::ParameterName, 'Enable sandboxing for Claude CLI (overrides config)')
+            [CompletionResult]::new('--no-sandbox', '--no-sandbox', [CompletionResultType]::ParameterName, 'Disable sandboxing for Claude CLI (overrides config)')
+            [CompletionResult]::new('--sandbox-no-network', '--sandbox-no-network', [CompletionResultType]::ParameterName, 'Enable sandboxing with network isolation via proxy')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;recover' {
+            [CompletionResult]::new('--since', '--since', [CompletionResultType]::ParameterName, 'Only recover sessions archived within a duration (30m, 12h, 3d, 2w) or since a date (2024-03-01)')
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--all', '--all', [CompletionResultType]::ParameterName, 'Recover every archived session')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Only show what would be recovered (dry run)')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Restore without asking for confirmation')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Restore without asking for confirmation')
+            [CompletionResult]::new('--replace', '--replace', [CompletionResultType]::ParameterName, 'When a branch with the session''s name has diverged from the archive, archive it and restore under the original name instead of <name>-recovered-N')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Print the result as one JSON object on stdout; everything else goes to stderr')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;adopt' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;checkpoint' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;sync' {
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'If another git process holds the repository''s index.lock, wait up to SECONDS for it instead of failing')
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--unshallow', '--unshallow', [CompletionResultType]::ParameterName, 'In a shallow clone, fetch the missing history from the remote first instead of failing')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;note' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--show', '--show', [CompletionResultType]::ParameterName, 'Print the session''s notes instead of adding one')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;link' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('add', 'add', [CompletionResultType]::ParameterValue, 'Add a link to a session, or point an existing label at a new URL')
+            [CompletionResult]::new('remove', 'remove', [CompletionResultType]::ParameterValue, 'Remove a link by label or URL')
+            [CompletionResult]::new('list', 'list', [CompletionResultType]::ParameterValue, 'List a session''s links')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'para;link;add' {
+            [CompletionResult]::new('-l', '-l', [CompletionResultType]::ParameterName, 'Label for the link, e.g. PR or JIRA-123')
+            [CompletionResult]::new('--label', '--label', [CompletionResultType]::ParameterName, 'Label for the link, e.g. PR or JIRA-123')
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;link;remove' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;link;list' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Output as JSON')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;link;help' {
+            [CompletionResult]::new('add', 'add', [CompletionResultType]::ParameterValue, 'Add a link to a session, or point an existing label at a new URL')
+            [CompletionResult]::new('remove', 'remove', [CompletionResultType]::ParameterValue, 'Remove a link by label or URL')
+            [CompletionResult]::new('list', 'list', [CompletionResultType]::ParameterValue, 'List a session''s links')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'para;link;help;add' {
+            break
+        }
+        'para;link;help;remove' {
+            break
+        }
+        'para;link;help;list' {
+            break
+        }
+        'para;link;help;help' {
+            break
+        }
+        'para;pin' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;unpin' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;launch' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;self-update' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--check', '--check', [CompletionResultType]::ParameterName, 'Only report whether a newer release exists and how to install it')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;audit' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('show', 'show', [CompletionResultType]::ParameterValue, 'Show logged git commands for a session')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'para;audit;show' {
+            [CompletionResult]::new('--since', '--since', [CompletionResultType]::ParameterName, 'Only show commands run within a duration (30m, 12h, 3d, 2w) or since a date (2024-03-01)')
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;audit;help' {
+            [CompletionResult]::new('show', 'show', [CompletionResultType]::ParameterValue, 'Show logged git commands for a session')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'para;audit;help;show' {
+            break
+        }
+        'para;audit;help;help' {
+            break
+        }
+        'para;conflicts' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('show', 'show', [CompletionResultType]::ParameterValue, 'Show recorded conflict resolutions, oldest first')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'para;conflicts;show' {
+            [CompletionResult]::new('--file', '--file', [CompletionResultType]::ParameterName, 'Only show conflicts in this file, relative to the repository root')
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;conflicts;help' {
+            [CompletionResult]::new('show', 'show', [CompletionResultType]::ParameterValue, 'Show recorded conflict resolutions, oldest first')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'para;conflicts;help;show' {
+            break
+        }
+        'para;conflicts;help;help' {
+            break
+        }
+        'para;which' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--manifest', '--manifest', [CompletionResultType]::ParameterName, 'Print the session''s .para/session.json manifest')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;statusline' {
+            [CompletionResult]::new('--format', '--format', [CompletionResultType]::ParameterName, 'Format the line with a template (default: ''{name}{dirty} {ahead}{behind} {tests} {blocked}'') Placeholders: {name}, {branch}, {dirty}, {ahead}, {behind}, {tests}, {blocked}')
+            [CompletionResult]::new('--max-width', '--max-width', [CompletionResultType]::ParameterName, 'Cut the line to N characters, ending in …')
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;summary' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Print the summary as JSON instead of Markdown')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;logs' {
+            [CompletionResult]::new('--source', '--source', [CompletionResultType]::ParameterName, 'Only show this source; repeat for several')
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('-f', '-f', [CompletionResultType]::ParameterName, 'Keep printing new output until interrupted')
+            [CompletionResult]::new('--follow', '--follow', [CompletionResultType]::ParameterName, 'Keep printing new output until interrupted')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;examples' {
+            [CompletionResult]::new('--search', '--search', [CompletionResultType]::ParameterName, 'Search the titles and descriptions of all examples; close spellings match too')
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--no-color', '--no-color', [CompletionResultType]::ParameterName, 'Disable colors (also disabled by NO_COLOR or when not on a terminal)')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;config' {
+            [CompletionResult]::new('--section', '--section', [CompletionResultType]::ParameterName, 'Only run this wizard section against the current config (repeatable)')
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('setup', 'setup', [CompletionResultType]::ParameterValue, 'Interactive configuration wizard')
+            [CompletionResult]::new('auto', 'auto', [CompletionResultType]::ParameterValue, 'Auto-detect and configure IDE')
+            [CompletionResult]::new('show', 'show', [CompletionResultType]::ParameterValue, 'Show current configuration')
+            [CompletionResult]::new('edit', 'edit', [CompletionResultType]::ParameterValue, 'Edit configuration file')
+            [CompletionResult]::new('reset', 'reset', [CompletionResultType]::ParameterValue, 'Reset configuration to defaults')
+            [CompletionResult]::new('set', 'set', [CompletionResultType]::ParameterValue, 'Set configuration value using JSON path')
+            [CompletionResult]::new('get', 'get', [CompletionResultType]::ParameterValue, 'Print one configuration value')
+            [CompletionResult]::new('keys', 'keys', [CompletionResultType]::ParameterValue, 'List every configuration key with its type')
+            [CompletionResult]::new('session', 'session', [CompletionResultType]::ParameterValue, 'Show or change settings recorded for one session')
+            [CompletionResult]::new('project', 'project', [CompletionResultType]::ParameterValue, 'Manage project-level configuration')
+            [CompletionResult]::new('notify', 'notify', [CompletionResultType]::ParameterValue, 'Manage session event notifications')
+            [CompletionResult]::new('templates', 'templates', [CompletionResultType]::ParameterValue, 'List or show session templates for ''para start --template''')
+            [CompletionResult]::new('path', 'path', [CompletionResultType]::ParameterValue, 'Print where the user config is read from and why')
+            [CompletionResult]::new('doctor', 'doctor', [CompletionResultType]::ParameterValue, 'Check the config file for unknown, renamed and missing keys')
+            [CompletionResult]::new('export', 'export', [CompletionResultType]::ParameterValue, 'Export configuration with portable paths for use on another machine')
+            [CompletionResult]::new('import', 'import', [CompletionResultType]::ParameterValue, 'Import configuration exported with ''para config export''')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'para;config;setup' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;config;auto' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;config;show' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;config;edit' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;config;reset' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;config;set' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;config;get' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;config;keys' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('-q', '-q', [CompletionResultType]::ParameterName, 'Print only the key names')
+            [CompletionResult]::new('--quiet', '--quiet', [CompletionResultType]::ParameterName, 'Print only the key names')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;config;session' {
+            [CompletionResult]::new('--merge-mode', '--merge-mode', [CompletionResultType]::ParameterName, 'Change the merge mode finish uses for this session')
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;config;project' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('init', 'init', [CompletionResultType]::ParameterValue, 'Initialize project configuration')
+            [CompletionResult]::new('show', 'show', [CompletionResultType]::ParameterValue, 'Show project configuration')
+            [CompletionResult]::new('edit', 'edit', [CompletionResultType]::ParameterValue, 'Edit project configuration')
+            [CompletionResult]::new('set', 'set', [CompletionResultType]::ParameterValue, 'Set project configuration value')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'para;config;project;init' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;config;project;show' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;config;project;edit' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;config;project;set' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;config;project;help' {
+            [CompletionResult]::new('init', 'init', [CompletionResultType]::ParameterValue, 'Initialize project configuration')
+            [CompletionResult]::new('show', 'show', [CompletionResultType]::ParameterValue, 'Show project configuration')
+            [CompletionResult]::new('edit', 'edit', [CompletionResultType]::ParameterValue, 'Edit project configuration')
+            [CompletionResult]::new('set', 'set', [CompletionResultType]::ParameterValue, 'Set project configuration value')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'para;config;project;help;init' {
+            break
+        }
+        'para;config;project;help;show' {
+            break
+        }
+        'para;config;project;help;edit' {
+            break
+        }
+        'para;config;project;help;set' {
+            break
+        }
+        'para;config;project;help;help' {
+            break
+        }
+        'para;config;notify' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('test', 'test', [CompletionResultType]::ParameterValue, 'Run the configured notification command with a test event')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'para;config;notify;test' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;config;notify;help' {
+            [CompletionResult]::new('test', 'test', [CompletionResultType]::ParameterValue, 'Run the configured notification command with a test event')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'para;config;notify;help;test' {
+            break
+        }
+        'para;config;notify;help;help' {
+            break
+        }
+        'para;config;templates' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('list', 'list', [CompletionResultType]::ParameterValue, 'List the templates from the user and project config')
+            [CompletionResult]::new('show', 'show', [CompletionResultType]::ParameterValue, 'Show the options a template sets')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'para;config;templates;list' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;config;templates;show' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;config;templates;help' {
+            [CompletionResult]::new('list', 'list', [CompletionResultType]::ParameterValue, 'List the templates from the user and project config')
+            [CompletionResult]::new('show', 'show', [CompletionResultType]::ParameterValue, 'Show the options a template sets')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'para;config;templates;help;list' {
+            break
+        }
+        'para;config;templates;help;show' {
+            break
+        }
+        'para;config;templates;help;help' {
+            break
+        }
+        'para;config;path' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;config;doctor' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--fix', '--fix', [CompletionResultType]::ParameterName, 'Repair what can be repaired safely')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print the repairs without making them')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;config;export' {
+            [CompletionResult]::new('-o', '-o', [CompletionResultType]::ParameterName, 'Write to a file instead of stdout')
+            [CompletionResult]::new('--output', '--output', [CompletionResultType]::ParameterName, 'Write to a file instead of stdout')
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;config;import' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('-y', '-y', [CompletionResultType]::ParameterName, 'Apply without confirmation')
+            [CompletionResult]::new('--yes', '--yes', [CompletionResultType]::ParameterName, 'Apply without confirmation')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;config;help' {
+            [CompletionResult]::new('setup', 'setup', [CompletionResultType]::ParameterValue, 'Interactive configuration wizard')
+            [CompletionResult]::new('auto', 'auto', [CompletionResultType]::ParameterValue, 'Auto-detect and configure IDE')
+            [CompletionResult]::new('show', 'show', [CompletionResultType]::ParameterValue, 'Show current configuration')
+            [CompletionResult]::new('edit', 'edit', [CompletionResultType]::ParameterValue, 'Edit configuration file')
+            [CompletionResult]::new('reset', 'reset', [CompletionResultType]::ParameterValue, 'Reset configuration to defaults')
+            [CompletionResult]::new('set', 'set', [CompletionResultType]::ParameterValue, 'Set configuration value using JSON path')
+            [CompletionResult]::new('get', 'get', [CompletionResultType]::ParameterValue, 'Print one configuration value')
+            [CompletionResult]::new('keys', 'keys', [CompletionResultType]::ParameterValue, 'List every configuration key with its type')
+            [CompletionResult]::new('session', 'session', [CompletionResultType]::ParameterValue, 'Show or change settings recorded for one session')
+            [CompletionResult]::new('project', 'project', [CompletionResultType]::ParameterValue, 'Manage project-level configuration')
+            [CompletionResult]::new('notify', 'notify', [CompletionResultType]::ParameterValue, 'Manage session event notifications')
+            [CompletionResult]::new('templates', 'templates', [CompletionResultType]::ParameterValue, 'List or show session templates for ''para start --template''')
+            [CompletionResult]::new('path', 'path', [CompletionResultType]::ParameterValue, 'Print where the user config is read from and why')
+            [CompletionResult]::new('doctor', 'doctor', [CompletionResultType]::ParameterValue, 'Check the config file for unknown, renamed and missing keys')
+            [CompletionResult]::new('export', 'export', [CompletionResultType]::ParameterValue, 'Export configuration with portable paths for use on another machine')
+            [CompletionResult]::new('import', 'import', [CompletionResultType]::ParameterValue, 'Import configuration exported with ''para config export''')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'para;config;help;setup' {
+            break
+        }
+        'para;config;help;auto' {
+            break
+        }
+        'para;config;help;show' {
+            break
+        }
+        'para;config;help;edit' {
+            break
+        }
+        'para;config;help;reset' {
+            break
+        }
+        'para;config;help;set' {
+            break
+        }
+        'para;config;help;get' {
+            break
+        }
+        'para;config;help;keys' {
+            break
+        }
+        'para;config;help;session' {
+            break
+        }
+        'para;config;help;project' {
+            [CompletionResult]::new('init', 'init', [CompletionResultType]::ParameterValue, 'Initialize project configuration')
+            [CompletionResult]::new('show', 'show', [CompletionResultType]::ParameterValue, 'Show project configuration')
+            [CompletionResult]::new('edit', 'edit', [CompletionResultType]::ParameterValue, 'Edit project configuration')
+            [CompletionResult]::new('set', 'set', [CompletionResultType]::ParameterValue, 'Set project configuration value')
+            break
+        }
+        'para;config;help;project;init' {
+            break
+        }
+        'para;config;help;project;show' {
+            break
+        }
+        'para;config;help;project;edit' {
+            break
+        }
+        'para;config;help;project;set' {
+            break
+        }
+        'para;config;help;notify' {
+            [CompletionResult]::new('test', 'test', [CompletionResultType]::ParameterValue, 'Run the configured notification command with a test event')
+            break
+        }
+        'para;config;help;notify;test' {
+            break
+        }
+        'para;config;help;templates' {
+            [CompletionResult]::new('list', 'list', [CompletionResultType]::ParameterValue, 'List the templates from the user and project config')
+            [CompletionResult]::new('show', 'show', [CompletionResultType]::ParameterValue, 'Show the options a template sets')
+            break
+        }
+        'para;config;help;templates;list' {
+            break
+        }
+        'para;config;help;templates;show' {
+            break
+        }
+        'para;config;help;path' {
+            break
+        }
+        'para;config;help;doctor' {
+            break
+        }
+        'para;config;help;export' {
+            break
+        }
+        'para;config;help;import' {
+            break
+        }
+        'para;config;help;help' {
+            break
+        }
+        'para;completion' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('generate', 'generate', [CompletionResultType]::ParameterValue, 'Print the completion script for a shell')
+            [CompletionResult]::new('install', 'install', [CompletionResultType]::ParameterValue, 'Write the completion script to the shell''s per-user completion directory')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'para;completion;generate' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;completion;install' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;completion;help' {
+            [CompletionResult]::new('generate', 'generate', [CompletionResultType]::ParameterValue, 'Print the completion script for a shell')
+            [CompletionResult]::new('install', 'install', [CompletionResultType]::ParameterValue, 'Write the completion script to the shell''s per-user completion directory')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'para;completion;help;generate' {
+            break
+        }
+        'para;completion;help;install' {
+            break
+        }
+        'para;completion;help;help' {
+            break
+        }
+        'para;init' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;mcp' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('init', 'init', [CompletionResultType]::ParameterValue, 'Initialize MCP integration for Para')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'para;mcp;init' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--claude-desktop', '--claude-desktop', [CompletionResultType]::ParameterName, 'Register the server in Claude Desktop''s global config instead of .mcp.json')
+            [CompletionResult]::new('--cursor', '--cursor', [CompletionResultType]::ParameterName, 'Register the server in Cursor''s global mcp.json instead of .mcp.json')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;mcp;help' {
+            [CompletionResult]::new('init', 'init', [CompletionResultType]::ParameterValue, 'Initialize MCP integration for Para')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'para;mcp;help;init' {
+            break
+        }
+        'para;mcp;help;help' {
+            break
+        }
+        'para;_completion_sessions' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;_completion_branches' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--para', '--para', [CompletionResultType]::ParameterName, 'List the branches of active para sessions instead of other branches')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;monitor' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--all-repos', '--all-repos', [CompletionResultType]::ParameterName, 'Show sessions from all repositories recorded in the para config directory')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;status' {
+            [CompletionResult]::new('--tests', '--tests', [CompletionResultType]::ParameterName, 'Test status: passed, failed, or unknown')
+            [CompletionResult]::new('--todos', '--todos', [CompletionResultType]::ParameterName, 'Todo list as comma-separated items (''[x] '' marks done items) or progress as ''completed/total'' (e.g., ''3/7'')')
+            [CompletionResult]::new('--session', '--session', [CompletionResultType]::ParameterName, 'Session name (auto-detected if not provided)')
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--blocked', '--blocked', [CompletionResultType]::ParameterName, 'Mark session as blocked')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('show', 'show', [CompletionResultType]::ParameterValue, 'Show status of one or all sessions')
+            [CompletionResult]::new('summary', 'summary', [CompletionResultType]::ParameterValue, 'Generate a summary of all status files')
+            [CompletionResult]::new('cleanup', 'cleanup', [CompletionResultType]::ParameterValue, 'Clean up stale status files')
+            [CompletionResult]::new('todo', 'todo', [CompletionResultType]::ParameterValue, 'Add, complete and list the session''s todos')
+            [CompletionResult]::new('wait', 'wait', [CompletionResultType]::ParameterValue, 'Block until a session''s status meets a condition')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'para;status;show' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Output as JSON')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;status;summary' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Output as JSON')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;status;cleanup' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Show what would be cleaned without removing')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;status;todo' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('add', 'add', [CompletionResultType]::ParameterValue, 'Add a todo to the session''s list')
+            [CompletionResult]::new('done', 'done', [CompletionResultType]::ParameterValue, 'Mark a todo as done')
+            [CompletionResult]::new('list', 'list', [CompletionResultType]::ParameterValue, 'List the session''s todos')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'para;status;todo;add' {
+            [CompletionResult]::new('--session', '--session', [CompletionResultType]::ParameterName, 'Session name (auto-detected if not provided)')
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;status;todo;done' {
+            [CompletionResult]::new('--session', '--session', [CompletionResultType]::ParameterName, 'Session name (auto-detected if not provided)')
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;status;todo;list' {
+            [CompletionResult]::new('--session', '--session', [CompletionResultType]::ParameterName, 'Session name (auto-detected if not provided)')
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--json', '--json', [CompletionResultType]::ParameterName, 'Output as JSON')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;status;todo;help' {
+            [CompletionResult]::new('add', 'add', [CompletionResultType]::ParameterValue, 'Add a todo to the session''s list')
+            [CompletionResult]::new('done', 'done', [CompletionResultType]::ParameterValue, 'Mark a todo as done')
+            [CompletionResult]::new('list', 'list', [CompletionResultType]::ParameterValue, 'List the session''s todos')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'para;status;todo;help;add' {
+            break
+        }
+        'para;status;todo;help;done' {
+            break
+        }
+        'para;status;todo;help;list' {
+            break
+        }
+        'para;status;todo;help;help' {
+            break
+        }
+        'para;status;wait' {
+            [CompletionResult]::new('--until', '--until', [CompletionResultType]::ParameterName, 'tests-passed, blocked, idle-for:<minutes> or task-contains:<text>')
+            [CompletionResult]::new('--interval', '--interval', [CompletionResultType]::ParameterName, 'Seconds between checks')
+            [CompletionResult]::new('--timeout', '--timeout', [CompletionResultType]::ParameterName, 'Give up after this many seconds (exits with code 124)')
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;status;help' {
+            [CompletionResult]::new('show', 'show', [CompletionResultType]::ParameterValue, 'Show status of one or all sessions')
+            [CompletionResult]::new('summary', 'summary', [CompletionResultType]::ParameterValue, 'Generate a summary of all status files')
+            [CompletionResult]::new('cleanup', 'cleanup', [CompletionResultType]::ParameterValue, 'Clean up stale status files')
+            [CompletionResult]::new('todo', 'todo', [CompletionResultType]::ParameterValue, 'Add, complete and list the session''s todos')
+            [CompletionResult]::new('wait', 'wait', [CompletionResultType]::ParameterValue, 'Block until a session''s status meets a condition')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'para;status;help;show' {
+            break
+        }
+        'para;status;help;summary' {
+            break
+        }
+        'para;status;help;cleanup' {
+            break
+        }
+        'para;status;help;todo' {
+            [CompletionResult]::new('add', 'add', [CompletionResultType]::ParameterValue, 'Add a todo to the session''s list')
+            [CompletionResult]::new('done', 'done', [CompletionResultType]::ParameterValue, 'Mark a todo as done')
+            [CompletionResult]::new('list', 'list', [CompletionResultType]::ParameterValue, 'List the session''s todos')
+            break
+        }
+        'para;status;help;todo;add' {
+            break
+        }
+        'para;status;help;todo;done' {
+            break
+        }
+        'para;status;help;todo;list' {
+            break
+        }
+        'para;status;help;wait' {
+            break
+        }
+        'para;status;help;help' {
+            break
+        }
+        'para;auth' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('setup', 'setup', [CompletionResultType]::ParameterValue, 'Set up container authentication interactively')
+            [CompletionResult]::new('cleanup', 'cleanup', [CompletionResultType]::ParameterValue, 'Remove authentication artifacts')
+            [CompletionResult]::new('status', 'status', [CompletionResultType]::ParameterValue, 'Check authentication status')
+            [CompletionResult]::new('reauth', 'reauth', [CompletionResultType]::ParameterValue, 'Re-authenticate (cleanup and setup in one command)')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'para;auth;setup' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--force', '--force', [CompletionResultType]::ParameterName, 'Force re-authentication even if credentials exist')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;auth;cleanup' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Show what would be removed without actually removing')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;auth;status' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--verbose', '--verbose', [CompletionResultType]::ParameterName, 'Show detailed authentication information')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;auth;reauth' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;auth;help' {
+            [CompletionResult]::new('setup', 'setup', [CompletionResultType]::ParameterValue, 'Set up container authentication interactively')
+            [CompletionResult]::new('cleanup', 'cleanup', [CompletionResultType]::ParameterValue, 'Remove authentication artifacts')
+            [CompletionResult]::new('status', 'status', [CompletionResultType]::ParameterValue, 'Check authentication status')
+            [CompletionResult]::new('reauth', 'reauth', [CompletionResultType]::ParameterValue, 'Re-authenticate (cleanup and setup in one command)')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'para;auth;help;setup' {
+            break
+        }
+        'para;auth;help;cleanup' {
+            break
+        }
+        'para;auth;help;status' {
+            break
+        }
+        'para;auth;help;reauth' {
+            break
+        }
+        'para;auth;help;help' {
+            break
+        }
+        'para;daemon' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('start', 'start', [CompletionResultType]::ParameterValue, 'Start the daemon')
+            [CompletionResult]::new('stop', 'stop', [CompletionResultType]::ParameterValue, 'Stop the daemon')
+            [CompletionResult]::new('status', 'status', [CompletionResultType]::ParameterValue, 'Check daemon status')
+            [CompletionResult]::new('prefetch', 'prefetch', [CompletionResultType]::ParameterValue, 'Fetch the base branches of this repository''s sessions now')
+            [CompletionResult]::new('cleanup', 'cleanup', [CompletionResultType]::ParameterValue, 'Remove orphaned para containers and their volumes and networks')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'para;daemon;start' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;daemon;stop' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;daemon;status' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;daemon;prefetch' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;daemon;cleanup' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--now', '--now', [CompletionResultType]::ParameterName, 'Run even if the cleanup interval has not elapsed')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;daemon;help' {
+            [CompletionResult]::new('start', 'start', [CompletionResultType]::ParameterValue, 'Start the daemon')
+            [CompletionResult]::new('stop', 'stop', [CompletionResultType]::ParameterValue, 'Stop the daemon')
+            [CompletionResult]::new('status', 'status', [CompletionResultType]::ParameterValue, 'Check daemon status')
+            [CompletionResult]::new('prefetch', 'prefetch', [CompletionResultType]::ParameterValue, 'Fetch the base branches of this repository''s sessions now')
+            [CompletionResult]::new('cleanup', 'cleanup', [CompletionResultType]::ParameterValue, 'Remove orphaned para containers and their volumes and networks')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'para;daemon;help;start' {
+            break
+        }
+        'para;daemon;help;stop' {
+            break
+        }
+        'para;daemon;help;status' {
+            break
+        }
+        'para;daemon;help;prefetch' {
+            break
+        }
+        'para;daemon;help;cleanup' {
+            break
+        }
+        'para;daemon;help;help' {
+            break
+        }
+        'para;proxy' {
+            [CompletionResult]::new('--port', '--port', [CompletionResultType]::ParameterName, 'Port to run the proxy on')
+            [CompletionResult]::new('--allowed-domains', '--allowed-domains', [CompletionResultType]::ParameterName, 'Additional domains to allow (comma-separated)')
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;selftest' {
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;integrate' {
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'If another git process holds the repository''s index.lock, wait up to SECONDS for it instead of failing')
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--unshallow', '--unshallow', [CompletionResultType]::ParameterName, 'In a shallow clone, fetch the missing history from the remote first instead of failing')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;continue' {
+            [CompletionResult]::new('--wait', '--wait', [CompletionResultType]::ParameterName, 'If another git process holds the repository''s index.lock, wait up to SECONDS for it instead of failing')
+            [CompletionResult]::new('--ci', '--ci', [CompletionResultType]::ParameterName, 'Add CI workflow commands to the output: github (default in GitHub Actions) or none')
+            [CompletionResult]::new('--state-dir', '--state-dir', [CompletionResultType]::ParameterName, 'Keep session state in PATH instead of the configured state directory')
+            [CompletionResult]::new('--config', '--config', [CompletionResultType]::ParameterName, 'Read and write the user config at PATH instead of the default location')
+            [CompletionResult]::new('--unshallow', '--unshallow', [CompletionResultType]::ParameterName, 'In a shallow clone, fetch the missing history from the remote first instead of failing')
+            [CompletionResult]::new('--offline', '--offline', [CompletionResultType]::ParameterName, 'Skip fetches and image pulls; fail fast on operations that need the network')
+            [CompletionResult]::new('--ignore-version', '--ignore-version', [CompletionResultType]::ParameterName, 'Change sessions written by a newer version of para anyway')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'para;help' {
+            [CompletionResult]::new('start', 'start', [CompletionResultType]::ParameterValue, 'Create new para sessions (interactive or AI-assisted)')
+            [CompletionResult]::new('finish', 'finish', [CompletionResultType]::ParameterValue, 'Complete session and create feature branch for review')
+            [CompletionResult]::new('rollback-integration', 'rollback-integration', [CompletionResultType]::ParameterValue, 'Undo finishing a session onto another session''s branch')
+            [CompletionResult]::new('cancel', 'cancel', [CompletionResultType]::ParameterValue, 'Cancel session (moves to archive)')
+            [CompletionResult]::new('clean', 'clean', [CompletionResultType]::ParameterValue, 'Remove stale para artifacts (and optionally all active sessions)')
+            [CompletionResult]::new('restore-trash', 'restore-trash', [CompletionResultType]::ParameterValue, 'Put an item `para clean` moved to the trash back in place')
+            [CompletionResult]::new('gc', 'gc', [CompletionResultType]::ParameterValue, 'Compact logs and remove leftover files in the state directory')
+            [CompletionResult]::new('doctor', 'doctor', [CompletionResultType]::ParameterValue, 'Report version skew between the CLI, daemon, config and sessions')
+            [CompletionResult]::new('upgrade-state', 'upgrade-state', [CompletionResultType]::ParameterValue, 'Upgrade all session state files to the current format, or restore a backup')
+            [CompletionResult]::new('list', 'list', [CompletionResultType]::ParameterValue, 'List active sessions')
+            [CompletionResult]::new('resume', 'resume', [CompletionResultType]::ParameterValue, 'Resume session in IDE')
+            [CompletionResult]::new('recover', 'recover', [CompletionResultType]::ParameterValue, 'Recover cancelled session from archive')
+            [CompletionResult]::new('adopt', 'adopt', [CompletionResultType]::ParameterValue, 'Turn an existing branch into a session')
+            [CompletionResult]::new('checkpoint', 'checkpoint', [CompletionResultType]::ParameterValue, 'Commit pending work in a session as a WIP checkpoint')
+            [CompletionResult]::new('sync', 'sync', [CompletionResultType]::ParameterValue, 'Rebase a session onto the latest commit of the branch it was started from')
+            [CompletionResult]::new('note', 'note', [CompletionResultType]::ParameterValue, 'Add, edit or show notes about a session')
+            [CompletionResult]::new('link', 'link', [CompletionResultType]::ParameterValue, 'Attach links to PRs, tickets and docs to a session')
+            [CompletionResult]::new('pin', 'pin', [CompletionResultType]::ParameterValue, 'Keep a session out of stale and idle detection and bulk cleanups')
+            [CompletionResult]::new('unpin', 'unpin', [CompletionResultType]::ParameterValue, 'Undo `para pin`')
+            [CompletionResult]::new('launch', 'launch', [CompletionResultType]::ParameterValue, 'Launch Claude in a session prepared with --plan-only')
+            [CompletionResult]::new('self-update', 'self-update', [CompletionResultType]::ParameterValue, 'Check for a newer para release and update to it')
+            [CompletionResult]::new('audit', 'audit', [CompletionResultType]::ParameterValue, 'Show the git commands para ran on the repository')
+            [CompletionResult]::new('conflicts', 'conflicts', [CompletionResultType]::ParameterValue, 'Show how earlier merge conflicts were resolved')
+            [CompletionResult]::new('which', 'which', [CompletionResultType]::ParameterValue, 'Print the session the current directory belongs to')
+            [CompletionResult]::new('statusline', 'statusline', [CompletionResultType]::ParameterValue, 'Print a one-line summary of the current session for status bars')
+            [CompletionResult]::new('summary', 'summary', [CompletionResultType]::ParameterValue, 'Summarize a session''s task, commits, changes and status history')
+            [CompletionResult]::new('logs', 'logs', [CompletionResultType]::ParameterValue, 'Show the setup script, container and daemon logs of a session')
+            [CompletionResult]::new('examples', 'examples', [CompletionResultType]::ParameterValue, 'Show example command lines for common workflows')
+            [CompletionResult]::new('config', 'config', [CompletionResultType]::ParameterValue, 'Setup configuration')
+            [CompletionResult]::new('completion', 'completion', [CompletionResultType]::ParameterValue, 'Generate shell completion script')
+            [CompletionResult]::new('init', 'init', [CompletionResultType]::ParameterValue, 'Initialize shell completions automatically')
+            [CompletionResult]::new('mcp', 'mcp', [CompletionResultType]::ParameterValue, 'Setup Model Context Protocol (MCP) integration')
+            [CompletionResult]::new('_completion_sessions', '_completion_sessions', [CompletionResultType]::ParameterValue, 'Legacy completion endpoint for sessions (hidden)')
+            [CompletionResult]::new('_completion_branches', '_completion_branches', [CompletionResultType]::ParameterValue, 'Legacy completion endpoint for branches (hidden)')
+            [CompletionResult]::new('monitor', 'monitor', [CompletionResultType]::ParameterValue, 'Monitor and manage active sessions in real-time (interactive TUI with mouse support)')
+            [CompletionResult]::new('status', 'status', [CompletionResultType]::ParameterValue, 'Update session status (for agents to communicate progress)')
+            [CompletionResult]::new('auth', 'auth', [CompletionResultType]::ParameterValue, 'Manage Docker container authentication')
+            [CompletionResult]::new('daemon', 'daemon', [CompletionResultType]::ParameterValue, 'Manage para daemon (internal use)')
+            [CompletionResult]::new('proxy', 'proxy', [CompletionResultType]::ParameterValue, 'Run network proxy for sandboxing (internal use)')
+            [CompletionResult]::new('selftest', 'selftest', [CompletionResultType]::ParameterValue, 'Run the session lifecycle in a throwaway repository to check the installation')
+            [CompletionResult]::new('integrate', 'integrate', [CompletionResultType]::ParameterValue, '[deprecated] Finish the current session; use `para finish`')
+            [CompletionResult]::new('continue', 'continue', [CompletionResultType]::ParameterValue, '[deprecated] Carry on with a sync stopped on conflicts; use `git rebase --continue` and `para sync`')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'para;help;start' {
+            break
+        }
+        'para;help;finish' {
+            break
+        }
+        'para;help;rollback-integration' {
+            break
+        }
+        'para;help;cancel' {
+            break
+        }
+        'para;help;clean' {
+            break
+        }
+        'para;help;restore-trash' {
+            break
+        }
+        'para;help;gc' {
+            break
+        }
+        'para;help;doctor' {
+            break
+        }
+        'para;help;upgrade-state' {
+            break
+        }
+        'para;help;list' {
+            break
+        }
+        'para;help;resume' {
+            break
+        }
+        'para;help;recover' {
+            break
+        }
+        'para;help;adopt' {
+            break
+        }
+        'para;help;checkpoint' {
+            break
+        }
+        'para;help;sync' {
+            break
+        }
+        'para;help;note' {
+            break
+        }
+        'para;help;link' {
+            [CompletionResult]::new('add', 'add', [CompletionResultType]::ParameterValue, 'Add a link to a session, or point an existing label at a new URL')
+            [CompletionResult]::new('remove', 'remove', [CompletionResultType]::ParameterValue, 'Remove a link by label or URL')
+            [CompletionResult]::new('list', 'list', [CompletionResultType]::ParameterValue, 'List a session''s links')
+            break
+        }
+        'para;help;link;add' {
+            break
+        }
+        'para;help;link;remove' {
+            break
+        }
+        'para;help;link;list' {
+            break
+        }
+        'para;help;pin' {
+            break
+        }
+        'para;help;unpin' {
+            break
+        }
+        'para;help;launch' {
+            break
+        }
+        'para;help;self-update' {
+            break
+        }
+        'para;help;audit' {
+            [CompletionResult]::new('show', 'show', [CompletionResultType]::ParameterValue, 'Show logged git commands for a session')
+            break
+        }
+        'para;help;audit;show' {
+            break
+        }
+        'para;help;conflicts' {
+            [CompletionResult]::new('show', 'show', [CompletionResultType]::ParameterValue, 'Show recorded conflict resolutions, oldest first')
+            break
+        }
+        'para;help;conflicts;show' {
+            break
+        }
+        'para;help;which' {
+            break
+        }
+        'para;help;statusline' {
+            break
+        }
+        'para;help;summary' {
+            break
+        }
+        'para;help;logs' {
+            break
+        }
+        'para;help;examples' {
+            break
+        }
+        'para;help;config' {
+            [CompletionResult]::new('setup', 'setup', [CompletionResultType]::ParameterValue, 'Interactive configuration wizard')
+            [CompletionResult]::new('auto', 'auto', [CompletionResultType]::ParameterValue, 'Auto-detect and configure IDE')
+            [CompletionResult]::new('show', 'show', [CompletionResultType]::ParameterValue, 'Show current configuration')
+            [CompletionResult]::new('edit', 'edit', [CompletionResultType]::ParameterValue, 'Edit configuration file')
+            [CompletionResult]::new('reset', 'reset', [CompletionResultType]::ParameterValue, 'Reset configuration to defaults')
+            [CompletionResult]::new('set', 'set', [CompletionResultType]::ParameterValue, 'Set configuration value using JSON path')
+            [CompletionResult]::new('get', 'get', [CompletionResultType]::ParameterValue, 'Print one configuration value')
+            [CompletionResult]::new('keys', 'keys', [CompletionResultType]::ParameterValue, 'List every configuration key with its type')
+            [CompletionResult]::new('session', 'session', [CompletionResultType]::ParameterValue, 'Show or change settings recorded for one session')
+            [CompletionResult]::new('project', 'project', [CompletionResultType]::ParameterValue, 'Manage project-level configuration')
+            [CompletionResult]::new('notify', 'notify', [CompletionResultType]::ParameterValue, 'Manage session event notifications')
+            [CompletionResult]::new('templates', 'templates', [CompletionResultType]::ParameterValue, 'List or show session templates for ''para start --template''')
+            [CompletionResult]::new('path', 'path', [CompletionResultType]::ParameterValue, 'Print where the user config is read from and why')
+            [CompletionResult]::new('doctor', 'doctor', [CompletionResultType]::ParameterValue, 'Check the config file for unknown, renamed and missing keys')
+            [CompletionResult]::new('export', 'export', [CompletionResultType]::ParameterValue, 'Export configuration with portable paths for use on another machine')
+            [CompletionResult]::new('import', 'import', [CompletionResultType]::ParameterValue, 'Import configuration exported with ''para config export''')
+            break
+        }
+        'para;help;config;setup' {
+            break
+        }
+        'para;help;config;auto' {
+            break
+        }
+        'para;help;config;show' {
+            break
+        }
+        'para;help;config;edit' {
+            break
+        }
+        'para;help;config;reset' {
+            break
+        }
+        'para;help;config;set' {
+            break
+        }
+        'para;help;config;get' {
+            break
+        }
+        'para;help;config;keys' {
+            break
+        }
+        'para;help;config;session' {
+            break
+        }
+        'para;help;config;project' {
+            [CompletionResult]::new('init', 'init', [CompletionResultType]::ParameterValue, 'Initialize project configuration')
+            [CompletionResult]::new('show', 'show', [CompletionResultType]::ParameterValue, 'Show project configuration')
+            [CompletionResult]::new('edit', 'edit', [CompletionResultType]::ParameterValue, 'Edit project configuration')
+            [CompletionResult]::new('set', 'set', [CompletionResultType]::ParameterValue, 'Set project configuration value')
+            break
+        }
+        'para;help;config;project;init' {
+            break
+        }
+        'para;help;config;project;show' {
+            break
+        }
+        'para;help;config;project;edit' {
+            break
+        }
+        'para;help;config;project;set' {
+            break
+        }
+        'para;help;config;notify' {
+            [CompletionResult]::new('test', 'test', [CompletionResultType]::ParameterValue, 'Run the configured notification command with a test event')
+            break
+        }
+        'para;help;config;notify;test' {
+            break
+        }
+        'para;help;config;templates' {
+            [CompletionResult]::new('list', 'list', [CompletionResultType]::ParameterValue, 'List the templates from the user and project config')
+            [CompletionResult]::new('show', 'show', [CompletionResultType]::ParameterValue, 'Show the options a template sets')
+            break
+        }
+        'para;help;config;templates;list' {
+            break
+        }
+        'para;help;config;templates;show' {
+            break
+        }
+        'para;help;config;path' {
+            break
+        }
+        'para;help;config;doctor' {
+            break
+        }
+        'para;help;config;export' {
+            break
+        }
+        'para;help;config;import' {
+            break
+        }
+        'para;help;completion' {
+            [CompletionResult]::new('generate', 'generate', [CompletionResultType]::ParameterValue, 'Print the completion script for a shell')
+            [CompletionResult]::new('install', 'install', [CompletionResultType]::ParameterValue, 'Write the completion script to the shell''s per-user completion directory')
+            break
+        }
+        'para;help;completion;generate' {
+            break
+        }
+        'para;help;completion;install' {
+            break
+        }
+        'para;help;init' {
+            break
+        }
+        'para;help;mcp' {
+            [CompletionResult]::new('init', 'init', [CompletionResultType]::ParameterValue, 'Initialize MCP integration for Para')
+            break
+        }
+        'para;help;mcp;init' {
+            break
+        }
+        'para;help;_completion_sessions' {
+            break
+        }
+        'para;help;_completion_branches' {
+            break
+        }
+        'para;help;monitor' {
+            break
+        }
+        'para;help;status' {
+            [CompletionResult]::new('show', 'show', [CompletionResultType]::ParameterValue, 'Show status of one or all sessions')
+            [CompletionResult]::new('summary', 'summary', [CompletionResultType]::ParameterValue, 'Generate a summary of all status files')
+            [CompletionResult]::new('cleanup', 'cleanup', [CompletionResultType]::ParameterValue, 'Clean up stale status files')
+            [CompletionResult]::new('todo', 'todo', [CompletionResultType]::ParameterValue, 'Add, complete and list the session''s todos')
+            [CompletionResult]::new('wait', 'wait', [CompletionResultType]::ParameterValue, 'Block until a session''s status meets a condition')
+            break
+        }
+        'para;help;status;show' {
+            break
+        }
+        'para;help;status;summary' {
+            break
+        }
+        'para;help;status;cleanup' {
+            break
+        }
+        'para;help;status;todo' {
+            [CompletionResult]::new('add', 'add', [CompletionResultType]::ParameterValue, 'Add a todo to the session''s list')
+            [CompletionResult]::new('done', 'done', [CompletionResultType]::ParameterValue, 'Mark a todo as done')
+            [CompletionResult]::new('list', 'list', [CompletionResultType]::ParameterValue, 'List the session''s todos')
+            break
+        }
+        'para;help;status;todo;add' {
+            break
+        }
+        'para;help;status;todo;done' {
+            break
+        }
+        'para;help;status;todo;list' {
+            break
+        }
+        'para;help;status;wait' {
+            break
+        }
+        'para;help;auth' {
+            [CompletionResult]::new('setup', 'setup', [CompletionResultType]::ParameterValue, 'Set up container authentication interactively')
+            [CompletionResult]::new('cleanup', 'cleanup', [CompletionResultType]::ParameterValue, 'Remove authentication artifacts')
+            [CompletionResult]::new('status', 'status', [CompletionResultType]::ParameterValue, 'Check authentication status')
+            [CompletionResult]::new('reauth', 'reauth', [CompletionResultType]::ParameterValue, 'Re-authenticate (cleanup and setup in one command)')
+            break
+        }
+        'para;help;auth;setup' {
+            break
+        }
+        'para;help;auth;cleanup' {
+            break
+        }
+        'para;help;auth;status' {
+            break
+        }
+        'para;help;auth;reauth' {
+            break
+        }
+        'para;help;daemon' {
+            [CompletionResult]::new('start', 'start', [CompletionResultType]::ParameterValue, 'Start the daemon')
+            [CompletionResult]::new('stop', 'stop', [CompletionResultType]::ParameterValue, 'Stop the daemon')
+            [CompletionResult]::new('status', 'status', [CompletionResultType]::ParameterValue, 'Check daemon status')
+            [CompletionResult]::new('prefetch', 'prefetch', [CompletionResultType]::ParameterValue, 'Fetch the base branches of this repository''s sessions now')
+            [CompletionResult]::new('cleanup', 'cleanup', [CompletionResultType]::ParameterValue, 'Remove orphaned para containers and their volumes and networks')
+            break
+        }
+        'para;help;daemon;start' {
+            break
+        }
+        'para;help;daemon;stop' {
+            break
+        }
+        'para;help;daemon;status' {
+            break
+        }
+        'para;help;daemon;prefetch' {
+            break
+        }
+        'para;help;daemon;cleanup' {
+            break
+        }
+        'para;help;proxy' {
+            break
+        }
+        'para;help;selftest' {
+            break
+        }
+        'para;help;integrate' {
+            break
+        }
+        'para;help;continue' {
+            break
+        }
+        'para;help;help' {
+            break
+        }
+    })
+
+    $completions.Where{ $_.CompletionText -like "$wordToComplete*" } |
+        Sort-Object -Property ListItemText
+}
@@ -31,6 +31,7 @@ mod generator_tests {
         let bash_script = enhanced_bash.unwrap();
         assert!(bash_script.contains("para"));
         assert!(bash_script.contains("_para_complete_sessions"));
+        assert!(bash_script.contains("para _completion_branches --para"));
 
         let enhanced_zsh = ShellCompletionGenerator::generate_enhanced_completion(Shell::Zsh);
         assert!(enhanced_zsh.is_ok());
@@ -67,15 +68,19 @@ mod generator_tests {
     }
 
     #[test]
-    fn test_completion_excludes_removed_commands() {
-        // Test that the completion script no longer includes integrate/continue commands
+    fn test_completion_marks_deprecated_commands() {
         let fish_completion = ShellCompletionGenerator::generate_enhanced_completion(Shell::Fish);
         assert!(fish_completion.is_ok());
         let fish_script = fish_completion.unwrap();
 
-        // Should not contain references to removed commands
-        assert!(!fish_script.contains("integrate"));
-        assert!(!fish_script.contains("continue"));
+        // The older verbs are still completed, described as deprecated
+        for name in ["integrate", "continue"] {
+            let line = fish_script
+                .lines()
+                .find(|line| line.contains(&format!("-a \"{name}\"")))
+                .unwrap_or_else(|| panic!("{name} is not completed"));
+            assert!(line.contains("[deprecated]"), "{line}");
+        }
 
         // Should contain existing commands
         assert!(fish_script.contains("start"));
@@ -106,7 +111,8 @@ mod completion_command_tests {
     #[test]
     fn test_completion_init_suggestion() {
         let args = CompletionArgs {
-            shell: "init".to_string(),
+            command: None,
+            shell: Some("init".to_string()),
         };
 
         // This should not panic and should provide helpful guidance
@@ -117,7 +123,8 @@ mod completion_command_tests {
     #[test]
     fn test_completion_unsupported_shell() {
         let args = CompletionArgs {
-            shell: "unsupported".to_string(),
+            command: None,
+            shell: Some("unsupported".to_string()),
         };
 
         // This should handle unsupported shells gracefully
@@ -129,7 +136,8 @@ mod completion_command_tests {
     fn test_supported_shells() {
         for shell_name in ["bash", "zsh", "fish"] {
             let args = CompletionArgs {
-                shell: shell_name.to_string(),
+                command: None,
+                shell: Some(shell_name.to_string()),
             };
 
             let result = completion::execute(args);
@@ -137,3 +145,105 @@ mod completion_command_tests {
         }
     }
 }
+
+#[cfg(test)]
+mod dynamic_hook_tests {
+    use crate::cli::completion::generators::ShellCompletionGenerator;
+    use crate::cli::parser::Shell;
+    use std::fs;
+    use std::path::PathBuf;
+
+    /// Compare `actual` with `snapshots/<name>`. Run the tests with
+    /// `PARA_UPDATE_SNAPSHOTS=1` to accept a changed script.
+    fn assert_snapshot(name: &str, actual: &str) {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("src/cli/completion/snapshots")
+            .join(name);
+        if std::env::var("PARA_UPDATE_SNAPSHOTS").is_ok() {
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(&path, actual).unwrap();
+            return;
+        }
+        let expected = fs::read_to_string(&path).unwrap_or_default();
+        assert!(
+            expected == actual,
+            "{name} changed; rerun with PARA_UPDATE_SNAPSHOTS=1 and review the diff"
+        );
+    }
+
+    #[test]
+    fn test_fish_script_snapshot_and_hooks() {
+        let script = ShellCompletionGenerator::generate_enhanced_completion(Shell::Fish).unwrap();
+        assert_snapshot("para.fish", &script);
+
+        assert!(script.contains("para _completion_sessions"));
+        assert!(script.contains("para _completion_branches --para"));
+        for subcommand in ["resume", "cancel"] {
+            assert!(script.contains(&format!(
+                "__fish_para_using_subcommand {subcommand}\" -a \"(__para_sessions)\""
+            )));
+        }
+        assert!(script.contains(
+            "\"__fish_para_using_subcommand recover\" -a \"(__para_archived_sessions)\""
+        ));
+        assert!(script.contains("__para_finish_needs_session\" -a \"(__para_sessions)\""));
+        assert!(script
+            .contains("\"__fish_para_using_subcommand start\" -l base -a \"(__para_branches)\""));
+        assert!(script.contains("-l session-branch -a \"(__para_session_branches)\""));
+        assert!(script.contains(
+            "\"__fish_para_using_subcommand start dispatch\" -l remote -a \"(git remote 2>/dev/null)\""
+        ));
+    }
+
+    #[test]
+    fn test_powershell_script_snapshot_and_hooks() {
+        let script =
+            ShellCompletionGenerator::generate_enhanced_completion(Shell::PowerShell).unwrap();
+        assert_snapshot("para.ps1", &script);
+
+        // One completer, with the dynamic lookup ahead of clap's static completions
+        assert_eq!(script.matches("Register-ArgumentCompleter").count(), 1);
+        let using = script.rfind("using namespace").unwrap();
+        let helpers = script.find("function Get-ParaDynamicCompletions").unwrap();
+        let register = script.find("Register-ArgumentCompleter").unwrap();
+        let lookup = script
+            .find("Get-ParaDynamicCompletions $commandElements $wordToComplete")
+            .unwrap();
+        let static_completions = script.find("$completions = @(switch ($command)").unwrap();
+        assert!(using < helpers && helpers < register);
+        assert!(register < lookup && lookup < static_completions);
+
+        assert!(script.contains("para _completion_sessions"));
+        assert!(script.contains("para _completion_branches --para"));
+        for subcommand in ["'resume', 'cancel'", "'recover'", "'finish'", "'start'"] {
+            assert!(script.contains(subcommand), "missing hook for {subcommand}");
+        }
+        assert!(script.contains("elseif ($previous -eq '--remote') { 'remotes' }"));
+    }
+
+    #[test]
+    fn test_config_key_hooks_in_every_shell() {
+        for shell in [Shell::Bash, Shell::Zsh, Shell::Fish, Shell::PowerShell] {
+            let script = ShellCompletionGenerator::generate_enhanced_completion(shell).unwrap();
+            assert!(script.contains("para config keys --quiet"));
+        }
+
+        let bash = ShellCompletionGenerator::generate_enhanced_completion(Shell::Bash).unwrap();
+        assert!(bash.contains(
+            "-c|--config-override)\n            _para_complete_config_keys \"${cur}\" \"=\""
+        ));
+        assert!(bash.contains("set|get)\n                    if [[ $cword -eq 3 ]]; then\n                        _para_complete_config_keys"));
+
+        let zsh = ShellCompletionGenerator::generate_enhanced_completion(Shell::Zsh).unwrap();
+        assert!(zsh.contains("_para_config_keys -S '='"));
+
+        let fish = ShellCompletionGenerator::generate_enhanced_completion(Shell::Fish).unwrap();
+        assert!(fish.contains("__para_config_needs_key\" -a \"(__para_config_keys)\""));
+        assert!(fish.contains("-s c -l config-override -a \"(__para_config_keys"));
+
+        let ps1 =
+            ShellCompletionGenerator::generate_enhanced_completion(Shell::PowerShell).unwrap();
+        assert!(ps1.contains("if ($previous -in '-c', '--config-override')"));
+        assert!(ps1.contains("$previous -in 'set', 'get') { 'config-keys' }"));
+    }
+}
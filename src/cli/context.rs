@@ -0,0 +1,76 @@
+//! State shared by everything one CLI invocation runs
+//!
+//! Finding the repository spawns git and walks the filesystem, and a command
+//! used to do it again in every helper that needed it. The context finds it
+//! once, on first use since some commands never touch a repository, and hands
+//! out the same [`GitService`] and session manager from then on.
+
+use crate::config::Config;
+use crate::core::git::GitService;
+use crate::core::session::SessionManager;
+use crate::core::vcs::{VcsBackend, VcsKind};
+use crate::utils::{ParaError, Result};
+use std::cell::OnceCell;
+use std::env;
+use std::path::{Path, PathBuf};
+
+pub struct CommandContext {
+    config: Config,
+    current_dir: PathBuf,
+    git_service: OnceCell<GitService>,
+    session_manager: OnceCell<SessionManager>,
+}
+
+impl CommandContext {
+    /// Context for a command run from the current directory
+    pub fn new(config: Config) -> Result<Self> {
+        let current_dir = env::current_dir()
+            .map_err(|e| ParaError::fs_error(format!("Failed to get current directory: {e}")))?;
+        Ok(Self::in_dir(config, current_dir))
+    }
+
+    /// Context for a command run from `current_dir`
+    pub fn in_dir(config: Config, current_dir: PathBuf) -> Self {
+        Self {
+            config,
+            current_dir,
+            git_service: OnceCell::new(),
+            session_manager: OnceCell::new(),
+        }
+    }
+
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    pub fn current_dir(&self) -> &Path {
+        &self.current_dir
+    }
+
+    /// Repository containing the current directory, discovered on first call
+    pub fn git_service(&self) -> Result<&GitService> {
+        if let Some(git_service) = self.git_service.get() {
+            return Ok(git_service);
+        }
+        let git_service = GitService::discover_from(&self.current_dir)?;
+        Ok(self.git_service.get_or_init(|| git_service))
+    }
+
+    /// Backend named by `vcs.backend`, on the repository from [`Self::git_service`]
+    pub fn vcs(&self) -> Result<Box<dyn VcsBackend>> {
+        match self.config.get_vcs_backend() {
+            VcsKind::Git => Ok(Box::new(self.git_service()?.clone())),
+        }
+    }
+
+    /// Session manager for the current repository. Outside a repository it
+    /// falls back to the state directory the config resolves to.
+    pub fn session_manager(&self) -> SessionManager {
+        self.session_manager
+            .get_or_init(|| match self.git_service() {
+                Ok(git_service) => SessionManager::for_git_service(&self.config, git_service),
+                Err(_) => SessionManager::new(&self.config),
+            })
+            .clone()
+    }
+}
@@ -0,0 +1,68 @@
+//! `--json` results for commands automation wraps
+//!
+//! Commands print progress and prose as they go, from shared helpers and from
+//! git itself. With `--json`, stdout points at stderr for the whole run so all
+//! of that stays human-facing, and the single JSON object with the result is
+//! written to the real stdout at the end.
+
+use crate::utils::Result;
+use serde::Serialize;
+use std::io::Write;
+use std::os::unix::io::RawFd;
+
+/// Version of the JSON results; bumped when a field changes meaning or goes away
+pub const SCHEMA_VERSION: u32 = 1;
+
+pub struct JsonOutput {
+    enabled: bool,
+    /// Duplicate of the original stdout while it is redirected
+    saved_stdout: Option<RawFd>,
+}
+
+impl JsonOutput {
+    /// Send stdout to stderr until the result is written, if `enabled`
+    pub fn start(enabled: bool) -> Self {
+        let mut output = Self {
+            enabled,
+            saved_stdout: None,
+        };
+        if enabled {
+            let _ = std::io::stdout().flush();
+            let saved = unsafe { libc::dup(libc::STDOUT_FILENO) };
+            if saved >= 0 && unsafe { libc::dup2(libc::STDERR_FILENO, libc::STDOUT_FILENO) } >= 0 {
+                output.saved_stdout = Some(saved);
+            }
+        }
+        output
+    }
+
+    fn restore(&mut self) {
+        if let Some(saved) = self.saved_stdout.take() {
+            let _ = std::io::stdout().flush();
+            unsafe {
+                libc::dup2(saved, libc::STDOUT_FILENO);
+                libc::close(saved);
+            }
+        }
+    }
+
+    /// Print `result` on the real stdout; does nothing without `--json`
+    pub fn finish<T: Serialize>(mut self, result: &T) -> Result<()> {
+        self.restore();
+        if self.enabled {
+            println!("{}", render(result)?);
+        }
+        Ok(())
+    }
+}
+
+impl Drop for JsonOutput {
+    fn drop(&mut self) {
+        self.restore();
+    }
+}
+
+/// The line [`JsonOutput::finish`] prints for `result`
+pub fn render<T: Serialize>(result: &T) -> Result<String> {
+    Ok(serde_json::to_string(result)?)
+}
@@ -1,14 +1,23 @@
 pub mod commands;
 pub mod completion;
+pub mod context;
+pub mod json_output;
 pub mod parser;
 
 #[cfg(test)]
 mod tests;
 
+pub use context::CommandContext;
 pub use parser::{Cli, Commands};
 
-use crate::config::ConfigManager;
+use crate::config::overrides::{apply_overrides, ConfigOverride};
+use crate::config::path::export_config_flag;
+use crate::config::state_dir::{export_state_dir, notice_stray_state_dir};
+use crate::config::{Config, ConfigManager};
 use crate::core::docker::cleanup::ContainerCleaner;
+use crate::core::git::audit::{audit_dir, export_git_audit_dir};
+use crate::core::git::command::export_git_command_timeout;
+use crate::core::network::NetworkContext;
 use crate::utils::{ParaError, Result};
 
 pub fn execute_command(cli: Cli) -> Result<()> {
@@ -16,20 +25,48 @@ pub fn execute_command(cli: Cli) -> Result<()> {
     if std::env::var("PARA_COMPLETION_SCRIPT").is_ok() {
         crate::utils::debug_log("Running in completion script mode");
     }
-    execute_command_with_config(cli, None)
+    if cli.offline {
+        NetworkContext::offline().export();
+    }
+    crate::utils::ci::init(cli.ci);
+    if let Some(ref state_dir) = cli.state_dir {
+        export_state_dir(state_dir);
+    }
+    if let Some(ref config_path) = cli.config_path {
+        export_config_flag(config_path);
+    }
+    if cli.ignore_version {
+        crate::core::version::export_ignore_version();
+    }
+    // The self-test must leave no trace of itself outside its temp directory,
+    // and the status line runs too often to write anything
+    let records_repo = !matches!(
+        cli.command,
+        Some(Commands::Selftest) | Some(Commands::Statusline(_))
+    );
+    let result = execute_command_with_config(cli, None);
+    if result.is_ok() && records_repo {
+        crate::core::repo_registry::record_current_repo();
+    }
+    result
 }
 
 pub fn execute_command_with_config(
     cli: Cli,
     test_config: Option<crate::config::Config>,
 ) -> Result<()> {
+    let from_disk = test_config.is_none();
     let config = match cli.command {
         Some(Commands::Config(_))
         | Some(Commands::Completion(_))
         | Some(Commands::Init)
         | Some(Commands::Auth(_))
         | Some(Commands::CompletionSessions)
-        | Some(Commands::CompletionBranches) => None,
+        | Some(Commands::CompletionBranches { .. })
+        | Some(Commands::Selftest)
+        | Some(Commands::Statusline(_))
+        | Some(Commands::Examples(_))
+        | Some(Commands::SelfUpdate(_)) => None,
         Some(Commands::Monitor(_)) | None => match test_config {
             Some(cfg) => Some(cfg),
             None => Some(
@@ -45,6 +82,24 @@ pub fn execute_command_with_config(
             ),
         },
     };
+    let config = apply_config_overrides(config, &cli.config_overrides)?;
+
+    if let Some(config) = config.as_ref().filter(|_| from_disk) {
+        export_git_command_timeout(config.git.command_timeout_secs);
+        if let Ok(repo_root) = crate::utils::get_main_repository_root() {
+            // A bare repository needs `directories.bare_root` before anything is created
+            let work_root = config.directories.work_root(&repo_root)?;
+            crate::config::validation::validate_directory_location(
+                &config.directories,
+                &work_root,
+            )?;
+        }
+        crate::ui::theme::init(config.get_ui_theme());
+        if config.git.audit_log {
+            export_git_audit_dir(Some(&audit_dir(&config.resolved_state_dir())));
+        }
+        notice_stray_state_dir(&config.directories.state_dir, &config.resolved_state_dir());
+    }
 
     // Ensure daemon is running for any command that might need it
     // Skip daemon check for commands that don't need it
@@ -55,8 +110,14 @@ pub fn execute_command_with_config(
             | Some(Commands::Init)
             | Some(Commands::Auth(_))
             | Some(Commands::CompletionSessions)
-            | Some(Commands::CompletionBranches)
+            | Some(Commands::CompletionBranches { .. })
             | Some(Commands::Daemon(_))
+            | Some(Commands::Doctor)
+            | Some(Commands::Examples(_))
+            | Some(Commands::Selftest)
+            | Some(Commands::Statusline(_))
+            | Some(Commands::SelfUpdate(_))
+            | Some(Commands::UpgradeState(_))
     );
 
     if should_start_daemon {
@@ -67,16 +128,19 @@ pub fn execute_command_with_config(
         }
     }
 
+    let ctx = config.map(CommandContext::new).transpose()?;
+
     // Trigger automatic container cleanup for common commands
-    if let Some(ref config) = config {
+    if let Some(ref ctx) = ctx {
         match &cli.command {
             Some(Commands::Start(_))
             | Some(Commands::Resume(_))
             | Some(Commands::List(_))
             | Some(Commands::Status(_))
-            | Some(Commands::Finish(_)) => {
+            | Some(Commands::Finish(_))
+            | Some(Commands::Integrate(_)) => {
                 // Run cleanup in background, ignore errors
-                let cleaner = ContainerCleaner::new(config.clone());
+                let cleaner = ContainerCleaner::new(ctx.config().clone());
                 cleaner.maybe_cleanup_async().ok();
             }
             _ => {}
@@ -86,27 +150,63 @@ pub fn execute_command_with_config(
     match cli.command {
         Some(Commands::Start(args)) => {
             args.validate()?;
-            commands::unified_start::execute(config.unwrap(), args)
+            commands::unified_start::execute(&ctx.unwrap(), args)
         }
         Some(Commands::Finish(args)) => {
             args.validate()?;
-            commands::finish::execute(config.unwrap(), args)
+            commands::finish::execute(&ctx.unwrap(), args)
         }
-        Some(Commands::Cancel(args)) => commands::cancel::execute(config.unwrap(), args),
-        Some(Commands::Clean(args)) => commands::clean::execute(config.unwrap(), args),
-        Some(Commands::List(args)) => commands::list::execute(config.unwrap(), args),
-        Some(Commands::Resume(args)) => commands::resume::execute(config.unwrap(), args),
-        Some(Commands::Recover(args)) => commands::recover::execute(config.unwrap(), args),
+        Some(Commands::RollbackIntegration(args)) => {
+            commands::rollback_integration::execute(&ctx.unwrap(), args)
+        }
+        Some(Commands::Cancel(args)) => commands::cancel::execute(&ctx.unwrap(), args),
+        Some(Commands::Clean(args)) => commands::clean::execute(&ctx.unwrap(), args),
+        Some(Commands::RestoreTrash(args)) => commands::restore_trash::execute(&ctx.unwrap(), args),
+        Some(Commands::Gc) => commands::gc::execute(&ctx.unwrap()),
+        Some(Commands::Doctor) => commands::doctor::execute(&ctx.unwrap()),
+        Some(Commands::UpgradeState(args)) => commands::upgrade_state::execute(&ctx.unwrap(), args),
+        Some(Commands::List(args)) => commands::list::execute(&ctx.unwrap(), args),
+        Some(Commands::Resume(args)) => commands::resume::execute(&ctx.unwrap(), args),
+        Some(Commands::Recover(args)) => commands::recover::execute(&ctx.unwrap(), args),
+        Some(Commands::Adopt(args)) => commands::adopt::execute(&ctx.unwrap(), args),
+        Some(Commands::Checkpoint(args)) => commands::checkpoint::execute(&ctx.unwrap(), args),
+        Some(Commands::Sync(args)) => commands::sync::execute(&ctx.unwrap(), args),
+        Some(Commands::Note(args)) => commands::note::execute(&ctx.unwrap(), args),
+        Some(Commands::Link(args)) => commands::link::execute(&ctx.unwrap(), args),
+        Some(Commands::Pin(args)) => commands::pin::execute(&ctx.unwrap(), args, true),
+        Some(Commands::Unpin(args)) => commands::pin::execute(&ctx.unwrap(), args, false),
+        Some(Commands::Launch(args)) => commands::launch::execute(&ctx.unwrap(), args),
+        Some(Commands::SelfUpdate(args)) => commands::self_update::execute(args),
+        Some(Commands::Audit(args)) => commands::audit::execute(&ctx.unwrap(), args),
+        Some(Commands::Conflicts(args)) => commands::conflicts::execute(&ctx.unwrap(), args),
+        Some(Commands::Which(args)) => commands::which::execute(&ctx.unwrap(), args),
+        Some(Commands::Statusline(args)) => commands::statusline::execute(args),
+        Some(Commands::Summary(args)) => commands::summary::execute(&ctx.unwrap(), args),
+        Some(Commands::Logs(args)) => commands::logs::execute(&ctx.unwrap(), args),
+        Some(Commands::Examples(args)) => commands::examples::execute(args),
         Some(Commands::Config(args)) => commands::config::execute(args),
         Some(Commands::Completion(args)) => commands::completion::execute(args),
         Some(Commands::Init) => commands::init::execute(),
         Some(Commands::Mcp(args)) => commands::mcp::handle_mcp_command(args),
         Some(Commands::CompletionSessions) => commands::completion_sessions::execute(),
-        Some(Commands::CompletionBranches) => commands::completion_branches::execute(),
-        Some(Commands::Monitor(args)) => commands::monitor::execute(config.unwrap(), args),
-        Some(Commands::Status(args)) => commands::status::execute(config.unwrap(), args),
+        Some(Commands::CompletionBranches { para }) => {
+            if para {
+                commands::completion_branches::execute_para_branches()
+            } else {
+                commands::completion_branches::execute()
+            }
+        }
+        Some(Commands::Monitor(args)) => commands::monitor::execute(&ctx.unwrap(), args),
+        Some(Commands::Status(args)) => commands::status::execute(&ctx.unwrap(), args),
         Some(Commands::Auth(args)) => commands::auth::execute(args),
-        Some(Commands::Daemon(args)) => commands::daemon::execute(args),
+        Some(Commands::Selftest) => commands::selftest::execute(),
+        Some(Commands::Integrate(args)) => {
+            commands::deprecated::execute_integrate(&ctx.unwrap(), args)
+        }
+        Some(Commands::Continue(args)) => {
+            commands::deprecated::execute_continue(&ctx.unwrap(), args)
+        }
+        Some(Commands::Daemon(args)) => commands::daemon::execute(&ctx.unwrap(), args),
         Some(Commands::Proxy(args)) => commands::proxy::execute(
             args.port,
             args.allowed_domains
@@ -115,6 +215,28 @@ pub fn execute_command_with_config(
                 .map(|s| s.trim().to_string())
                 .collect(),
         ),
-        None => commands::monitor::execute(config.unwrap(), crate::cli::parser::MonitorArgs {}),
+        None => commands::monitor::execute(
+            &ctx.unwrap(),
+            crate::cli::parser::MonitorArgs { all_repos: false },
+        ),
+    }
+}
+
+/// Apply `-c key=value` overrides to the config the command runs with
+fn apply_config_overrides(
+    config: Option<Config>,
+    overrides: &[ConfigOverride],
+) -> Result<Option<Config>> {
+    if overrides.is_empty() {
+        return Ok(config);
+    }
+    match config {
+        Some(config) => apply_overrides(&config, overrides)
+            .map(Some)
+            .map_err(|e| ParaError::config_error(e.to_string())),
+        None => {
+            eprintln!("Warning: --config-override has no effect on this command");
+            Ok(None)
+        }
     }
 }
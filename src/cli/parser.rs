@@ -1,5 +1,12 @@
+use crate::config::overrides::ConfigOverride;
+use crate::config::wizard::WizardSection;
+use crate::core::cleanup::CleanupKind;
+use crate::core::git::validation::PreflightOptions;
+use crate::core::git::MergeMode;
+use crate::utils::validate_session_name;
 use clap::{Args, Parser, Subcommand, ValueEnum};
 use std::path::PathBuf;
+use std::time::Duration;
 
 /// Common sandbox arguments shared across multiple commands
 #[derive(Args, Debug, Clone)]
@@ -49,23 +56,89 @@ pub struct SandboxArgs {
 #[command(about = "Parallel IDE Workflow Helper")]
 #[command(
     version,
-    long_about = "When run without any command, opens the monitor view to manage active sessions"
+    long_about = "When run without any command, opens the monitor view to manage active sessions",
+    after_help = DEPRECATED_HELP
 )]
 pub struct Cli {
     #[command(subcommand)]
     pub command: Option<Commands>,
+
+    /// Skip network operations (also enabled by PARA_OFFLINE=1)
+    #[arg(
+        long,
+        global = true,
+        help = "Skip fetches and image pulls; fail fast on operations that need the network"
+    )]
+    pub offline: bool,
+
+    /// Output for CI systems; GitHub Actions is detected from GITHUB_ACTIONS=true
+    #[arg(
+        long,
+        global = true,
+        value_enum,
+        value_name = "MODE",
+        help = "Add CI workflow commands to the output: github (default in GitHub Actions) or none"
+    )]
+    pub ci: Option<crate::utils::ci::CiMode>,
+
+    /// Override `directories.state_dir` (also settable with PARA_STATE_DIR)
+    #[arg(
+        long,
+        global = true,
+        value_name = "PATH",
+        help = "Keep session state in PATH instead of the configured state directory"
+    )]
+    pub state_dir: Option<PathBuf>,
+
+    /// Use this user config file (also settable with PARA_CONFIG_PATH)
+    #[arg(
+        long = "config",
+        global = true,
+        value_name = "PATH",
+        help = "Read and write the user config at PATH instead of the default location"
+    )]
+    pub config_path: Option<PathBuf>,
+
+    /// Let commands change sessions written by a newer para (also PARA_IGNORE_VERSION=1)
+    #[arg(
+        long,
+        global = true,
+        help = "Change sessions written by a newer version of para anyway"
+    )]
+    pub ignore_version: bool,
+
+    /// Override a config value for this command only, e.g. `-c git.auto_stage=false`
+    #[arg(
+        short = 'c',
+        long = "config-override",
+        value_name = "KEY=VALUE",
+        help = "Override a config value for this command only (repeatable, not saved)"
+    )]
+    pub config_overrides: Vec<ConfigOverride>,
 }
 
+// Parsed once per run, so boxing the start options buys nothing
+#[allow(clippy::large_enum_variant)]
 #[derive(Subcommand)]
 pub enum Commands {
     /// Create new para sessions (interactive or AI-assisted)
     Start(UnifiedStartArgs),
     /// Complete session and create feature branch for review
     Finish(FinishArgs),
+    /// Undo finishing a session onto another session's branch
+    RollbackIntegration(RollbackIntegrationArgs),
     /// Cancel session (moves to archive)
     Cancel(CancelArgs),
-    /// Remove all active sessions
+    /// Remove stale para artifacts (and optionally all active sessions)
     Clean(CleanArgs),
+    /// Put an item `para clean` moved to the trash back in place
+    RestoreTrash(RestoreTrashArgs),
+    /// Compact logs and remove leftover files in the state directory
+    Gc,
+    /// Report version skew between the CLI, daemon, config and sessions
+    Doctor,
+    /// Upgrade all session state files to the current format, or restore a backup
+    UpgradeState(UpgradeStateArgs),
     /// List active sessions
     #[command(alias = "ls")]
     List(ListArgs),
@@ -73,6 +146,38 @@ pub enum Commands {
     Resume(ResumeArgs),
     /// Recover cancelled session from archive
     Recover(RecoverArgs),
+    /// Turn an existing branch into a session
+    Adopt(AdoptArgs),
+    /// Commit pending work in a session as a WIP checkpoint
+    Checkpoint(CheckpointArgs),
+    /// Rebase a session onto the latest commit of the branch it was started from
+    Sync(SyncArgs),
+    /// Add, edit or show notes about a session
+    Note(NoteArgs),
+    /// Attach links to PRs, tickets and docs to a session
+    Link(LinkArgs),
+    /// Keep a session out of stale and idle detection and bulk cleanups
+    Pin(PinArgs),
+    /// Undo `para pin`
+    Unpin(PinArgs),
+    /// Launch Claude in a session prepared with --plan-only
+    Launch(LaunchArgs),
+    /// Check for a newer para release and update to it
+    SelfUpdate(SelfUpdateArgs),
+    /// Show the git commands para ran on the repository
+    Audit(AuditArgs),
+    /// Show how earlier merge conflicts were resolved
+    Conflicts(ConflictsArgs),
+    /// Print the session the current directory belongs to
+    Which(WhichArgs),
+    /// Print a one-line summary of the current session for status bars
+    Statusline(StatuslineArgs),
+    /// Summarize a session's task, commits, changes and status history
+    Summary(SummaryArgs),
+    /// Show the setup script, container and daemon logs of a session
+    Logs(LogsArgs),
+    /// Show example command lines for common workflows
+    Examples(ExamplesArgs),
     /// Setup configuration
     Config(ConfigArgs),
     /// Generate shell completion script
@@ -86,7 +191,11 @@ pub enum Commands {
     CompletionSessions,
     /// Legacy completion endpoint for branches (hidden)
     #[command(name = "_completion_branches", hide = true)]
-    CompletionBranches,
+    CompletionBranches {
+        /// List the branches of active para sessions instead of other branches
+        #[arg(long)]
+        para: bool,
+    },
     /// Monitor and manage active sessions in real-time (interactive TUI with mouse support)
     Monitor(MonitorArgs),
     /// Update session status (for agents to communicate progress)
@@ -99,8 +208,27 @@ pub enum Commands {
     /// Run network proxy for sandboxing (internal use)
     #[command(hide = true)]
     Proxy(ProxyArgs),
+    /// Run the session lifecycle in a throwaway repository to check the installation
+    #[command(hide = true)]
+    Selftest,
+    /// [deprecated] Finish the current session; use `para finish`
+    #[command(hide = true)]
+    Integrate(IntegrateArgs),
+    /// [deprecated] Carry on with a sync stopped on conflicts; use `git rebase --continue` and `para sync`
+    #[command(hide = true)]
+    Continue(SyncArgs),
 }
 
+/// Verbs of the older CLI that still work. They are left out of the command
+/// list in `--help`, which names them under [`DEPRECATED_HELP`] instead, but
+/// shell completion offers them.
+pub const DEPRECATED_COMMANDS: &[&str] = &["integrate", "continue"];
+
+const DEPRECATED_HELP: &str = "\
+Deprecated commands:
+  integrate  Finish the current session; use `para finish`
+  continue   Carry on with a sync stopped on conflicts; use `git rebase --continue` and `para sync`";
+
 /// Internal args struct for delegation to start command (not exposed in CLI)
 #[derive(Args, Debug)]
 pub struct StartArgs {
@@ -152,9 +280,75 @@ pub struct StartArgs {
     )]
     pub no_forward_keys: bool,
 
+    /// Create an empty initial commit when the repository has none
+    #[arg(
+        long,
+        help = "Create an empty initial commit if the repository has no commits yet"
+    )]
+    pub allow_empty_repo: bool,
+
+    /// Copy untracked files matching these globs into the new worktree
+    #[arg(
+        long = "copy",
+        value_name = "GLOB",
+        help = "Copy untracked files matching GLOB (e.g. '.env', '**/local.settings.json') from the main worktree into the session (repeatable)"
+    )]
+    pub copy: Vec<String>,
+
+    /// Allow copying gitignored files above the size limit
+    #[arg(
+        long,
+        help = "Also copy gitignored files larger than 1 MiB when copying untracked files"
+    )]
+    pub copy_large: bool,
+
+    /// Keep an absolute core.hooksPath pointing at the main checkout
+    #[arg(
+        long,
+        help = "Don't point an absolute core.hooksPath inside the repository at the new worktree"
+    )]
+    pub no_hook_fixup: bool,
+
+    /// Opt this session out of automatic WIP checkpoints
+    #[arg(
+        long,
+        help = "Disable automatic WIP checkpoint commits for this session"
+    )]
+    pub no_auto_commit: bool,
+
+    /// How finish shapes this session's commits
+    #[arg(
+        long,
+        value_enum,
+        help = "Merge mode recorded for the session and used by finish (default: git.default_merge_mode or squash)"
+    )]
+    pub merge_mode: Option<MergeMode>,
+
+    /// Start the session with this patch applied as uncommitted work
+    #[arg(
+        long,
+        value_name = "FILE",
+        conflicts_with_all = ["apply_from", "container"],
+        help = "Apply this patch file (git apply --3way) to the new session as uncommitted work"
+    )]
+    pub apply_patch: Option<PathBuf>,
+
+    /// Start the session with another branch's changes as uncommitted work
+    #[arg(
+        long,
+        value_name = "BRANCH",
+        conflicts_with = "container",
+        help = "Apply the diff of BRANCH against the session's base to the new session as uncommitted work"
+    )]
+    pub apply_from: Option<String>,
+
     /// Sandbox configuration
     #[command(flatten)]
     pub sandbox_args: SandboxArgs,
+
+    /// Template the session was started from, recorded in its state
+    #[arg(skip)]
+    pub template: Option<String>,
 }
 
 /// Internal args struct for delegation to dispatch command (not exposed in CLI)
@@ -170,6 +364,28 @@ pub struct DispatchArgs {
     #[arg(long, short = 'f', help = "Read prompt from specified file")]
     pub file: Option<PathBuf>,
 
+    /// Take a path-like prompt argument for a file even if it does not exist
+    #[arg(long, help = "Treat a path-like prompt argument as a file path")]
+    pub prompt_is_file: bool,
+
+    /// The prompt argument came from `--prompt` and is never a file path
+    #[arg(skip)]
+    pub literal_prompt: bool,
+
+    /// Ignore piped stdin
+    #[arg(
+        long,
+        help = "Ignore piped stdin, for scripts that cannot control their stdin"
+    )]
+    pub no_stdin: bool,
+
+    /// Send the prompt even if it looks like it contains secrets
+    #[arg(
+        long,
+        help = "Don't warn about or block prompts that look like they contain secrets (tokens, keys, passwords)"
+    )]
+    pub allow_secrets: bool,
+
     /// Skip IDE permission warnings (dangerous)
     #[arg(long, short = 'd', help = "Skip IDE permission warnings (dangerous)")]
     pub dangerously_skip_permissions: bool,
@@ -215,9 +431,137 @@ pub struct DispatchArgs {
     )]
     pub no_forward_keys: bool,
 
+    /// Create an empty initial commit when the repository has none
+    #[arg(
+        long,
+        help = "Create an empty initial commit if the repository has no commits yet"
+    )]
+    pub allow_empty_repo: bool,
+
+    /// Copy untracked files matching these globs into the new worktree
+    #[arg(
+        long = "copy",
+        value_name = "GLOB",
+        help = "Copy untracked files matching GLOB (e.g. '.env', '**/local.settings.json') from the main worktree into the session (repeatable)"
+    )]
+    pub copy: Vec<String>,
+
+    /// Allow copying gitignored files above the size limit
+    #[arg(
+        long,
+        help = "Also copy gitignored files larger than 1 MiB when copying untracked files"
+    )]
+    pub copy_large: bool,
+
+    /// Keep an absolute core.hooksPath pointing at the main checkout
+    #[arg(
+        long,
+        help = "Don't point an absolute core.hooksPath inside the repository at the new worktree"
+    )]
+    pub no_hook_fixup: bool,
+
+    /// Opt this session out of automatic WIP checkpoints
+    #[arg(
+        long,
+        help = "Disable automatic WIP checkpoint commits for this session"
+    )]
+    pub no_auto_commit: bool,
+
+    /// How finish shapes this session's commits
+    #[arg(
+        long,
+        value_enum,
+        help = "Merge mode recorded for the session and used by finish (default: git.default_merge_mode or squash)"
+    )]
+    pub merge_mode: Option<MergeMode>,
+
+    /// Start the session with this patch applied as uncommitted work
+    #[arg(
+        long,
+        value_name = "FILE",
+        conflicts_with_all = ["apply_from", "container"],
+        help = "Apply this patch file (git apply --3way) to the new session as uncommitted work"
+    )]
+    pub apply_patch: Option<PathBuf>,
+
+    /// Start the session with another branch's changes as uncommitted work
+    #[arg(
+        long,
+        value_name = "BRANCH",
+        conflicts_with = "container",
+        help = "Apply the diff of BRANCH against the session's base to the new session as uncommitted work"
+    )]
+    pub apply_from: Option<String>,
+
+    /// Create the session from this ref or session instead of the current branch
+    #[arg(
+        long,
+        value_name = "REF",
+        help = "Create the session from this branch, commit or session instead of the current branch (a session name stacks the new session on it)"
+    )]
+    pub base: Option<String>,
+
+    /// Fetch the base ref from the remote before creating the session
+    #[arg(
+        long,
+        requires = "base",
+        help = "Fetch --base from the remote first and start from the fetched commit"
+    )]
+    pub fetch: bool,
+
+    /// Remote to fetch --base from
+    #[arg(
+        long,
+        value_name = "NAME",
+        requires = "fetch",
+        help = "Remote to fetch --base from (default: git.default_remote, then origin)"
+    )]
+    pub remote: Option<String>,
+
+    /// Rerun another session's task in a new session
+    #[arg(
+        long,
+        value_name = "SESSION",
+        conflicts_with = "file",
+        help = "Start a new session with the task and settings of SESSION (active or cancelled); positional text is appended as extra instructions"
+    )]
+    pub retry: Option<String>,
+
+    /// Cancel the retried session once the new one is created
+    #[arg(
+        long,
+        requires = "retry",
+        help = "Cancel the --retry session after the new session has started"
+    )]
+    pub cancel_old: bool,
+
+    /// Prepare the session without launching Claude
+    #[arg(
+        long,
+        conflicts_with = "container",
+        help = "Create the worktree, state and task file but do not launch Claude; start it later with 'para launch'"
+    )]
+    pub plan_only: bool,
+
+    /// Leave the setup script for `para launch`
+    #[arg(
+        long,
+        requires = "plan_only",
+        help = "With --plan-only, run the setup script when the session is launched instead of now"
+    )]
+    pub defer_setup: bool,
+
     /// Sandbox configuration
     #[command(flatten)]
     pub sandbox_args: SandboxArgs,
+
+    /// Template the session was started from, recorded in its state
+    #[arg(skip)]
+    pub template: Option<String>,
+
+    /// Text placed before the prompt, from the template's prompt_prefix
+    #[arg(skip)]
+    pub prompt_prefix: Option<String>,
 }
 
 #[derive(Args, Debug)]
@@ -232,11 +576,24 @@ pub struct DispatchArgs {
     para resume my-feature --prompt \"add error handling\"
     
     # Resume with instructions from file
-    para resume my-feature --file new-requirements.txt")]
+    para resume my-feature --file new-requirements.txt
+
+    # Run commands in the session before it opens
+    para resume my-feature --run \"docker compose up -d\" --run \"make migrate\"")]
 pub struct ResumeArgs {
     /// Session ID to resume (optional, auto-detects from current directory if not provided)
     pub session: Option<String>,
 
+    /// Select the session by its branch instead of its name
+    #[arg(
+        long = "branch",
+        visible_alias = "session-branch",
+        value_name = "BRANCH",
+        conflicts_with = "session",
+        help = "Resume the session whose branch is BRANCH"
+    )]
+    pub session_branch: Option<String>,
+
     /// Additional prompt or instructions for the resumed session
     #[arg(long, short)]
     pub prompt: Option<String>,
@@ -245,89 +602,757 @@ pub struct ResumeArgs {
     #[arg(long, short)]
     pub file: Option<PathBuf>,
 
-    /// Skip IDE permission warnings (DANGEROUS: Only use for automated scripts)
+    /// Skip IDE permission warnings (DANGEROUS: Only use for automated scripts)
+    #[arg(
+        long,
+        help = "Skip IDE permission warnings (DANGEROUS: Only use for automated scripts)"
+    )]
+    pub dangerously_skip_permissions: bool,
+
+    /// Send the prompt even if it looks like it contains secrets
+    #[arg(
+        long,
+        help = "Don't warn about or block prompts that look like they contain secrets (tokens, keys, passwords)"
+    )]
+    pub allow_secrets: bool,
+
+    /// Shell command to run in the session before it opens (repeatable)
+    #[arg(
+        long = "run",
+        value_name = "CMD",
+        help = "Run CMD in the session before opening it, after session.on_resume (repeatable)"
+    )]
+    pub run: Vec<String>,
+
+    /// Open the session even if a resume command fails
+    #[arg(long, help = "Open the session even if a resume command fails")]
+    pub run_ignore_errors: bool,
+
+    /// Sandbox configuration
+    #[command(flatten)]
+    pub sandbox_args: SandboxArgs,
+}
+
+#[derive(Args, Debug)]
+pub struct FinishArgs {
+    /// Commit message
+    #[arg(required_unless_present = "check", default_value = "")]
+    pub message: String,
+
+    /// Custom branch name after finishing
+    #[arg(long, short = 'b', help = "Rename feature branch to specified name")]
+    pub branch: Option<String>,
+
+    /// Session ID (optional, auto-detects if not provided)
+    pub session: Option<String>,
+
+    /// Select the session by its branch instead of its name
+    #[arg(
+        long,
+        value_name = "BRANCH",
+        conflicts_with = "session",
+        help = "Finish the session whose branch is BRANCH (--branch renames the branch here)"
+    )]
+    pub session_branch: Option<String>,
+
+    /// Detach other worktrees on the session branch while it is renamed
+    #[arg(
+        long,
+        help = "Temporarily detach other worktrees that have the session branch checked out"
+    )]
+    pub force_rename: bool,
+
+    /// Finish even if a protected branch is checked out in the session worktree
+    #[arg(
+        long,
+        help = "Proceed even if a protected branch (e.g. main) is checked out in the session worktree"
+    )]
+    pub allow_protected: bool,
+
+    /// Only report whether integrating the session into its base would conflict
+    #[arg(
+        long,
+        conflicts_with_all = ["branch", "force_rename", "allow_protected"],
+        help = "Check whether the session merges cleanly into its base branch without changing anything (exit code 6 on conflicts)"
+    )]
+    pub check: bool,
+
+    /// Override the merge mode recorded for the session
+    #[arg(
+        long,
+        value_enum,
+        conflicts_with = "check",
+        help = "Shape the session's commits this way instead of using its recorded merge mode"
+    )]
+    pub merge_mode: Option<MergeMode>,
+
+    /// Integrate the session into another session's branch
+    #[arg(
+        long,
+        value_name = "SESSION",
+        conflicts_with = "check",
+        help = "Commit the session's changes onto the branch of active session SESSION (stacked sessions)"
+    )]
+    pub onto_session: Option<String>,
+
+    /// Finish a session branch pushed to origin, without a session or worktree
+    #[arg(
+        long,
+        value_name = "BRANCH",
+        requires = "base",
+        conflicts_with_all = ["session", "session_branch", "branch", "force_rename", "allow_protected", "check", "onto_session"],
+        help = "Finish branch BRANCH from origin in a plain clone (e.g. in CI) and push it and --base"
+    )]
+    pub remote_branch: Option<String>,
+
+    /// Branch on origin that --remote-branch is finished onto
+    #[arg(
+        long,
+        value_name = "BRANCH",
+        requires = "remote_branch",
+        help = "Branch on origin that --remote-branch is finished onto and pushed to"
+    )]
+    pub base: Option<String>,
+
+    /// Commit only what is staged, leaving the rest in the worktree
+    #[arg(
+        long,
+        conflicts_with = "remote_branch",
+        help = "Commit only the staged changes; unstaged and untracked changes stay in the worktree"
+    )]
+    pub staged_only: bool,
+
+    /// Print the result as JSON
+    #[arg(
+        long,
+        help = "Print the result as one JSON object on stdout; everything else goes to stderr"
+    )]
+    pub json: bool,
+
+    #[command(flatten)]
+    pub preflight: PreflightArgs,
+}
+
+#[derive(Args, Debug)]
+pub struct CancelArgs {
+    /// Session ID (optional, auto-detects if not provided)
+    pub session: Option<String>,
+
+    /// Select the session by its branch instead of its name
+    #[arg(
+        long = "branch",
+        visible_alias = "session-branch",
+        value_name = "BRANCH",
+        conflicts_with = "session",
+        help = "Cancel the session whose branch is BRANCH"
+    )]
+    pub session_branch: Option<String>,
+
+    /// Force cancellation even with uncommitted changes, stacked sessions or a pin (destructive)
+    #[arg(
+        long,
+        short,
+        help = "Force cancellation even with uncommitted changes, active stacked sessions or a pin (destructive)"
+    )]
+    pub force: bool,
+
+    /// Detach other worktrees on the session branch while it is archived
+    #[arg(
+        long,
+        help = "Temporarily detach other worktrees that have the session branch checked out"
+    )]
+    pub force_rename: bool,
+
+    /// Cancel even if a protected branch is checked out in the session worktree
+    #[arg(
+        long,
+        help = "Proceed even if a protected branch (e.g. main) is checked out in the session worktree"
+    )]
+    pub allow_protected: bool,
+
+    /// Close the IDE window if it still has the session open
+    #[arg(
+        long,
+        help = "Close the IDE window first if it still has the session open"
+    )]
+    pub close_ide: bool,
+
+    /// Only check the cancel and show the archive it would create
+    #[arg(
+        long,
+        conflicts_with = "force_rename",
+        help = "Check whether the session can be cancelled and show its archive branch without changing anything"
+    )]
+    pub dry_run: bool,
+
+    /// Print the result as JSON
+    #[arg(
+        long,
+        help = "Print the result as one JSON object on stdout; everything else goes to stderr"
+    )]
+    pub json: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct CleanArgs {
+    /// Skip confirmation prompts
+    #[arg(long, short, help = "Skip confirmation prompts")]
+    pub force: bool,
+
+    /// Pick the items to remove from a checklist
+    #[arg(
+        long,
+        short,
+        conflicts_with_all = ["force", "dry_run"],
+        help = "Choose the items to clean from a checklist instead of confirming all of them"
+    )]
+    pub interactive: bool,
+
+    /// Only show what would be cleaned (dry run)
+    #[arg(long, help = "Only show what would be cleaned (dry run)")]
+    pub dry_run: bool,
+
+    /// Also clean backup archives (deprecated, use default behavior)
+    #[arg(long, help = "Also remove archived sessions", hide = true)]
+    pub backups: bool,
+
+    /// Clean orphaned Docker containers
+    #[arg(long, help = "Clean orphaned Docker containers")]
+    pub containers: bool,
+
+    /// Prune dangling para-labeled Docker volumes, networks and images
+    #[arg(
+        long,
+        help = "Also prune dangling para-labeled Docker volumes, networks and images"
+    )]
+    pub docker: bool,
+
+    /// Also cancel and archive every active session
+    #[arg(
+        long,
+        help = "Also cancel and archive every active session that is not pinned (requires typing 'yes', or --force)"
+    )]
+    pub sessions: bool,
+
+    /// Close IDE windows that still have a cancelled session open
+    #[arg(
+        long,
+        requires = "sessions",
+        help = "Close the IDE window of sessions that are still open before cancelling them"
+    )]
+    pub close_ide: bool,
+
+    /// Only remove these kinds of items, ignoring cleanup.enabled_kinds
+    #[arg(
+        long,
+        value_enum,
+        value_delimiter = ',',
+        value_name = "KIND",
+        help = "Only remove these kinds of items this run (comma-separated, overrides cleanup.enabled_kinds)"
+    )]
+    pub only: Vec<CleanupKind>,
+
+    /// Never remove these kinds of items
+    #[arg(
+        long,
+        value_enum,
+        value_delimiter = ',',
+        value_name = "KIND",
+        help = "Leave these kinds of items alone this run (comma-separated)"
+    )]
+    pub skip: Vec<CleanupKind>,
+
+    /// Delete everything in the trash now
+    #[arg(
+        long,
+        conflicts_with = "list_trash",
+        help = "Permanently delete everything in the trash now, whatever its age"
+    )]
+    pub empty_trash: bool,
+
+    /// List the trash
+    #[arg(
+        long,
+        help = "List trashed state files and archive branches and when they expire"
+    )]
+    pub list_trash: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct RestoreTrashArgs {
+    /// Trash id (`<timestamp>/<name>`), file or branch name, or session name
+    pub item: String,
+}
+
+#[derive(Args, Debug)]
+pub struct ListArgs {
+    /// Show additional session details
+    #[arg(long, short = 'v', help = "Show verbose session information")]
+    pub verbose: bool,
+
+    /// Show archived sessions
+    #[arg(long, short = 'a', help = "Show archived sessions")]
+    pub archived: bool,
+
+    /// Show para branches without a session or archive entry
+    #[arg(
+        long,
+        conflicts_with_all = ["archived", "format"],
+        help = "Show para branches that have no session or archive entry"
+    )]
+    pub orphaned: bool,
+
+    /// Quiet output (minimal formatting for completion)
+    #[arg(long, short = 'q', help = "Quiet output for completion")]
+    pub quiet: bool,
+
+    /// Custom output template, one line per session
+    #[arg(
+        long,
+        value_name = "TEMPLATE",
+        conflicts_with_all = ["verbose", "quiet"],
+        help = "Format each session with a template, e.g. '{name}\\t{branch}\\t{path}'\n\
+                Placeholders: {name}, {branch}, {base}, {status}, {path}, {last_modified}, {type}, {note}"
+    )]
+    pub format: Option<String>,
+
+    /// Order of the listed sessions
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = ListSort::Created,
+        help = "Sort sessions by name, creation time, most recent activity or status"
+    )]
+    pub sort: ListSort,
+
+    /// Reverse the sort order
+    #[arg(long, help = "Reverse the sort order")]
+    pub reverse: bool,
+
+    /// Only show sessions created at or after this time
+    #[arg(
+        long,
+        value_name = "WHEN",
+        help = "Only show sessions created since a duration ago (30m, 12h, 3d, 2w) or a date (2024-03-01)"
+    )]
+    pub since: Option<String>,
+
+    /// Only show sessions created at or before this time
+    #[arg(
+        long,
+        value_name = "WHEN",
+        help = "Only show sessions created until a duration ago (30m, 12h, 3d, 2w) or a date (2024-03-01)"
+    )]
+    pub until: Option<String>,
+
+    /// Never color the table
+    #[arg(
+        long,
+        help = "Disable colors (also disabled by NO_COLOR or when not on a terminal)"
+    )]
+    pub no_color: bool,
+
+    /// Show worktree paths in full
+    #[arg(
+        long,
+        help = "Show worktree paths in full instead of shortening them to fit the terminal"
+    )]
+    pub full_paths: bool,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Default)]
+pub enum ListSort {
+    /// Alphabetically by session name
+    Name,
+    /// Newest session first
+    #[default]
+    Created,
+    /// Most recent file change, commit or status update first
+    Activity,
+    /// Sessions needing attention (dirty, diverged, missing) first
+    Status,
+}
+
+#[derive(Args, Debug)]
+pub struct CheckpointArgs {
+    /// Session ID (optional, auto-detects if not provided)
+    pub session: Option<String>,
+}
+
+#[derive(Args, Debug)]
+pub struct SyncArgs {
+    /// Session ID (optional, auto-detects if not provided)
+    pub session: Option<String>,
+
+    #[command(flatten)]
+    pub preflight: PreflightArgs,
+}
+
+/// What to do when the repository is locked by another git process or is a
+/// shallow clone
+#[derive(Args, Debug, Clone, Default)]
+pub struct PreflightArgs {
+    /// Seconds to wait for another git process to release index.lock
+    #[arg(
+        long,
+        value_name = "SECONDS",
+        help = "If another git process holds the repository's index.lock, wait up to SECONDS for it instead of failing"
+    )]
+    pub wait: Option<u64>,
+
+    /// Deepen a shallow clone before starting
+    #[arg(
+        long,
+        help = "In a shallow clone, fetch the missing history from the remote first instead of failing"
+    )]
+    pub unshallow: bool,
+}
+
+impl PreflightArgs {
+    pub fn options(&self) -> PreflightOptions {
+        PreflightOptions {
+            wait: self.wait.map(Duration::from_secs),
+            unshallow: self.unshallow,
+        }
+    }
+}
+
+#[derive(Args, Debug)]
+pub struct IntegrateArgs {
+    /// Commit message
+    pub message: String,
+
+    /// Session ID (optional, auto-detects if not provided)
+    pub session: Option<String>,
+
+    #[command(flatten)]
+    pub preflight: PreflightArgs,
+}
+
+impl IntegrateArgs {
+    /// The `para finish` this stands for
+    pub fn into_finish_args(self) -> FinishArgs {
+        FinishArgs {
+            message: self.message,
+            branch: None,
+            session: self.session,
+            session_branch: None,
+            force_rename: false,
+            allow_protected: false,
+            check: false,
+            merge_mode: None,
+            onto_session: None,
+            remote_branch: None,
+            base: None,
+            json: false,
+            preflight: self.preflight,
+            staged_only: false,
+        }
+    }
+}
+
+#[derive(Args, Debug)]
+pub struct NoteArgs {
+    /// Session name
+    pub session: String,
+
+    /// Note to append; opens $EDITOR on the notes file when omitted
+    #[arg(trailing_var_arg = true, conflicts_with = "show")]
+    pub text: Vec<String>,
+
+    /// Print the session's notes
+    #[arg(long, help = "Print the session's notes instead of adding one")]
+    pub show: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct SelfUpdateArgs {
+    /// Only report whether a newer release exists
+    #[arg(
+        long,
+        help = "Only report whether a newer release exists and how to install it"
+    )]
+    pub check: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct LaunchArgs {
+    /// Session prepared with `--plan-only`
+    pub session: String,
+}
+
+#[derive(Args, Debug)]
+pub struct PinArgs {
+    /// Session name
+    pub session: String,
+}
+
+#[derive(Args, Debug)]
+pub struct LinkArgs {
+    #[command(subcommand)]
+    pub command: LinkCommands,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum LinkCommands {
+    /// Add a link to a session, or point an existing label at a new URL
+    Add {
+        /// Session name
+        session: String,
+
+        /// Absolute http(s) URL
+        url: String,
+
+        /// Label shown for the link, e.g. PR or the ticket key
+        #[arg(long, short = 'l', help = "Label for the link, e.g. PR or JIRA-123")]
+        label: String,
+    },
+    /// Remove a link by label or URL
+    Remove {
+        /// Session name
+        session: String,
+
+        /// Label or URL of the link
+        link: String,
+    },
+    /// List a session's links
+    List {
+        /// Session name
+        session: String,
+
+        /// Output format
+        #[arg(long, help = "Output as JSON")]
+        json: bool,
+    },
+}
+
+#[derive(Args, Debug)]
+pub struct ExamplesArgs {
+    /// Topic to show, or words to search for
+    #[arg(
+        help = "Topic to show (see `para examples` for the list), or words to search the examples for"
+    )]
+    pub topic: Option<String>,
+
+    /// Words to search the examples for
+    #[arg(
+        long,
+        value_name = "TERM",
+        conflicts_with = "topic",
+        help = "Search the titles and descriptions of all examples; close spellings match too"
+    )]
+    pub search: Option<String>,
+
+    /// Never color the command lines
+    #[arg(
+        long,
+        help = "Disable colors (also disabled by NO_COLOR or when not on a terminal)"
+    )]
+    pub no_color: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct WhichArgs {
+    /// Print the session manifest instead of the session name
+    #[arg(long, help = "Print the session's .para/session.json manifest")]
+    pub manifest: bool,
+}
+
+#[derive(Args, Debug)]
+#[command(
+    after_help = "Prints nothing outside a session. Git fields are left out when git takes \
+longer than 100ms.
+
+EXAMPLES:
+    # tmux: show the session of the active pane, refreshed every 5 seconds
+    set -g status-interval 5
+    set -g status-right '#(cd \"#{pane_current_path}\" && para statusline --max-width 40)'
+
+    # Only the name and test status
+    para statusline --format '{name} {tests}'"
+)]
+pub struct StatuslineArgs {
+    /// Template for the line
     #[arg(
         long,
-        help = "Skip IDE permission warnings (DANGEROUS: Only use for automated scripts)"
+        value_name = "TEMPLATE",
+        help = "Format the line with a template (default: '{name}{dirty} {ahead}{behind} {tests} {blocked}')\n\
+                Placeholders: {name}, {branch}, {dirty}, {ahead}, {behind}, {tests}, {blocked}"
     )]
-    pub dangerously_skip_permissions: bool,
+    pub format: Option<String>,
 
-    /// Sandbox configuration
-    #[command(flatten)]
-    pub sandbox_args: SandboxArgs,
+    /// Longest line to print, in characters
+    #[arg(
+        long,
+        value_name = "N",
+        help = "Cut the line to N characters, ending in …"
+    )]
+    pub max_width: Option<usize>,
 }
 
 #[derive(Args, Debug)]
-pub struct FinishArgs {
-    /// Commit message
-    pub message: String,
+pub struct SummaryArgs {
+    /// Session name (defaults to the session of the current directory)
+    pub session: Option<String>,
 
-    /// Custom branch name after finishing
-    #[arg(long, short = 'b', help = "Rename feature branch to specified name")]
-    pub branch: Option<String>,
+    /// Print the summary as JSON instead of Markdown
+    #[arg(long, help = "Print the summary as JSON instead of Markdown")]
+    pub json: bool,
+}
 
-    /// Session ID (optional, auto-detects if not provided)
+#[derive(Args, Debug)]
+pub struct LogsArgs {
+    /// Session name (defaults to the session of the current directory)
     pub session: Option<String>,
+
+    /// Only show this source; repeat for several
+    #[arg(long, value_enum, value_name = "SOURCE")]
+    pub source: Vec<crate::core::logs::LogSource>,
+
+    /// Keep printing new output until interrupted
+    #[arg(short, long)]
+    pub follow: bool,
 }
 
 #[derive(Args, Debug)]
-pub struct CancelArgs {
-    /// Session ID (optional, auto-detects if not provided)
-    pub session: Option<String>,
+pub struct ConflictsArgs {
+    #[command(subcommand)]
+    pub command: ConflictsCommands,
+}
 
-    /// Force cancellation even with uncommitted changes (destructive)
-    #[arg(
-        long,
-        short,
-        help = "Force cancellation even with uncommitted changes (destructive)"
-    )]
-    pub force: bool,
+#[derive(Subcommand, Debug)]
+pub enum ConflictsCommands {
+    /// Show recorded conflict resolutions, oldest first
+    Show {
+        /// Only show conflicts in this file, relative to the repository root
+        #[arg(long, value_name = "PATH")]
+        file: Option<String>,
+    },
 }
 
 #[derive(Args, Debug)]
-pub struct CleanArgs {
-    /// Skip confirmation prompts
-    #[arg(long, short, help = "Skip confirmation prompts")]
-    pub force: bool,
+pub struct AuditArgs {
+    #[command(subcommand)]
+    pub command: AuditCommands,
+}
 
-    /// Only show what would be cleaned (dry run)
-    #[arg(long, help = "Only show what would be cleaned (dry run)")]
-    pub dry_run: bool,
+#[derive(Subcommand, Debug)]
+pub enum AuditCommands {
+    /// Show logged git commands for a session
+    Show {
+        /// Session name; shows commands run on the main repository when omitted
+        session: Option<String>,
 
-    /// Also clean backup archives (deprecated, use default behavior)
-    #[arg(long, help = "Also remove archived sessions", hide = true)]
-    pub backups: bool,
+        /// Only show commands newer than a duration or date
+        #[arg(
+            long,
+            value_name = "DURATION|DATE",
+            help = "Only show commands run within a duration (30m, 12h, 3d, 2w) or since a date (2024-03-01)"
+        )]
+        since: Option<String>,
+    },
+}
 
-    /// Clean orphaned Docker containers
-    #[arg(long, help = "Clean orphaned Docker containers")]
-    pub containers: bool,
+#[derive(Args, Debug)]
+pub struct UpgradeStateArgs {
+    /// Only show which state files would be upgraded
+    #[arg(
+        long,
+        help = "Only show which state files would be upgraded and the available backups"
+    )]
+    pub dry_run: bool,
+
+    /// Replace the state directory with a backup
+    #[arg(
+        long,
+        value_name = "BACKUP",
+        conflicts_with = "dry_run",
+        help = "Replace the state directory with a backup made before a migration"
+    )]
+    pub restore: Option<String>,
 }
 
 #[derive(Args, Debug)]
-pub struct ListArgs {
-    /// Show additional session details
-    #[arg(long, short = 'v', help = "Show verbose session information")]
-    pub verbose: bool,
+pub struct RollbackIntegrationArgs {
+    /// Session that was finished with --onto-session (defaults to the latest integration)
+    pub session: Option<String>,
+}
 
-    /// Show archived sessions
-    #[arg(long, short = 'a', help = "Show archived sessions")]
-    pub archived: bool,
+#[derive(Args, Debug)]
+pub struct AdoptArgs {
+    /// Existing branch to create the session for
+    pub branch: String,
 
-    /// Quiet output (minimal formatting for completion)
-    #[arg(long, short = 'q', help = "Quiet output for completion")]
-    pub quiet: bool,
+    /// Session name (defaults to the branch name without the para prefix)
+    pub name: Option<String>,
 }
 
 #[derive(Args, Debug)]
 pub struct RecoverArgs {
     /// Session ID to recover from archive (optional, shows list if not provided)
+    #[arg(conflicts_with = "all")]
     pub session: Option<String>,
+
+    /// Recover every archived session
+    #[arg(long, help = "Recover every archived session")]
+    pub all: bool,
+
+    /// Only recover archives newer than a duration or date
+    #[arg(
+        long,
+        requires = "all",
+        value_name = "DURATION|DATE",
+        help = "Only recover sessions archived within a duration (30m, 12h, 3d, 2w) or since a date (2024-03-01)"
+    )]
+    pub since: Option<String>,
+
+    /// Only show what would be recovered (dry run)
+    #[arg(
+        long,
+        conflicts_with = "yes",
+        help = "Only show what would be recovered (dry run)"
+    )]
+    pub dry_run: bool,
+
+    /// Skip the confirmation before restoring
+    #[arg(
+        long,
+        short = 'y',
+        conflicts_with = "all",
+        help = "Restore without asking for confirmation"
+    )]
+    pub yes: bool,
+
+    /// Archive a diverged live branch with the session's name first
+    #[arg(
+        long,
+        conflicts_with = "all",
+        help = "When a branch with the session's name has diverged from the archive, archive it and restore under the original name instead of <name>-recovered-N"
+    )]
+    pub replace: bool,
+
+    /// Print the result as JSON
+    #[arg(
+        long,
+        requires = "session",
+        help = "Print the result as one JSON object on stdout; everything else goes to stderr"
+    )]
+    pub json: bool,
 }
 
 #[derive(Args, Debug)]
+#[command(args_conflicts_with_subcommands = true)]
 pub struct ConfigArgs {
     #[command(subcommand)]
     pub command: Option<ConfigCommands>,
+
+    /// Only run these wizard sections against the current config
+    #[arg(
+        long,
+        value_enum,
+        help = "Only run this wizard section against the current config (repeatable)"
+    )]
+    pub section: Vec<WizardSection>,
 }
 
 #[derive(Subcommand, Debug)]
@@ -349,11 +1374,89 @@ pub enum ConfigCommands {
         /// Value to set
         value: String,
     },
+    /// Print one configuration value
+    Get {
+        /// JSON path using dot notation (e.g., git.branch_prefix)
+        path: String,
+    },
+    /// List every configuration key with its type
+    Keys {
+        /// Print only the key names
+        #[arg(long, short = 'q', help = "Print only the key names")]
+        quiet: bool,
+    },
+    /// Show or change settings recorded for one session
+    Session {
+        /// Session name
+        session: String,
+
+        /// Merge mode finish uses for this session
+        #[arg(
+            long,
+            value_enum,
+            help = "Change the merge mode finish uses for this session"
+        )]
+        merge_mode: Option<MergeMode>,
+    },
     /// Manage project-level configuration
     Project {
         #[command(subcommand)]
         command: Option<ProjectConfigCommands>,
     },
+    /// Manage session event notifications
+    Notify {
+        #[command(subcommand)]
+        command: NotifyCommands,
+    },
+    /// List or show session templates for 'para start --template'
+    Templates {
+        #[command(subcommand)]
+        command: TemplateCommands,
+    },
+    /// Print where the user config is read from and why
+    Path,
+    /// Check the config file for unknown, renamed and missing keys
+    Doctor {
+        /// Repair what can be repaired safely, after backing up the file
+        #[arg(long, help = "Repair what can be repaired safely")]
+        fix: bool,
+
+        /// Print the repairs without making them
+        #[arg(long, requires = "fix", help = "Print the repairs without making them")]
+        dry_run: bool,
+    },
+    /// Export configuration with portable paths for use on another machine
+    Export {
+        /// Write to a file instead of stdout
+        #[arg(long, short = 'o', help = "Write to a file instead of stdout")]
+        output: Option<PathBuf>,
+    },
+    /// Import configuration exported with 'para config export'
+    Import {
+        /// Exported configuration file
+        file: PathBuf,
+
+        /// Apply without confirmation
+        #[arg(long, short = 'y', help = "Apply without confirmation")]
+        yes: bool,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum TemplateCommands {
+    /// List the templates from the user and project config
+    List,
+    /// Show the options a template sets
+    Show {
+        /// Template name
+        name: String,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum NotifyCommands {
+    /// Run the configured notification command with a test event
+    Test,
 }
 
 #[derive(Subcommand, Debug)]
@@ -375,12 +1478,36 @@ pub enum ProjectConfigCommands {
 
 #[derive(Args, Debug)]
 pub struct CompletionArgs {
+    #[command(subcommand)]
+    pub command: Option<CompletionCommands>,
+
     /// Shell to generate completion for, or 'init' for automatic setup
-    pub shell: String,
+    pub shell: Option<String>,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum CompletionCommands {
+    /// Print the completion script for a shell
+    Generate {
+        /// Shell to generate completion for (bash, zsh, fish, powershell)
+        shell: Shell,
+    },
+    /// Write the completion script to the shell's per-user completion directory
+    Install {
+        /// Shell to install completion for (detected from $SHELL if omitted)
+        shell: Option<Shell>,
+    },
 }
 
 #[derive(Args, Debug)]
-pub struct MonitorArgs {}
+pub struct MonitorArgs {
+    /// Show sessions from every repository para has been used in
+    #[arg(
+        long,
+        help = "Show sessions from all repositories recorded in the para config directory"
+    )]
+    pub all_repos: bool,
+}
 
 #[derive(Args, Debug)]
 pub struct StatusArgs {
@@ -394,8 +1521,11 @@ pub struct StatusArgs {
     #[arg(long, help = "Test status: passed, failed, or unknown")]
     pub tests: Option<String>,
 
-    /// Todo progress (format: completed/total, e.g., 3/7)
-    #[arg(long, help = "Todo progress in format 'completed/total' (e.g., '3/7')")]
+    /// Todo list: comma-separated items, or progress as completed/total
+    #[arg(
+        long,
+        help = "Todo list as comma-separated items ('[x] ' marks done items) or progress as 'completed/total' (e.g., '3/7')"
+    )]
     pub todos: Option<String>,
 
     /// Mark as blocked
@@ -424,11 +1554,26 @@ pub struct StatusArgs {
     para start --file context.md
     para start feature-xyz -f tasks.md
     
+    # Pipe a prompt, optionally combined with a prompt and file
+    cat design.md | para start feature-xyz
+    cat design.md | para start feature-xyz -f coding-guidelines.md
+    
     # Docker container sessions
     para start --container -p \"implement feature\"
-    para start --container --allow-domains github.com,api.example.com -p \"fetch data\"")]
+    para start --container --allow-domains github.com,api.example.com -p \"fetch data\"
+
+    # Rerun a session's task in a new session (auth-retry-2)
+    para start --retry auth \"keep the existing session store\"
+
+PROMPT SOURCES:
+    Without piped input, --file takes precedence over --prompt.
+    With piped input, all sources are combined in the order --prompt, --file,
+    stdin (last), each under a '--- <source> ---' header. Empty piped input is
+    ignored when another source is given. Use --no-stdin to ignore stdin.
+    --prompt is always text; read it from a file with --prompt-is-file.
+    With piped input, a session name that is an existing file is read as one.")]
 pub struct UnifiedStartArgs {
-    /// Session name (optional)
+    /// Session name (optional), or extra instructions with --retry
     pub name: Option<String>,
 
     /// Prompt for AI-assisted session
@@ -436,9 +1581,36 @@ pub struct UnifiedStartArgs {
     pub prompt: Option<String>,
 
     /// Read prompt/context from file
-    #[arg(long, short = 'f', help = "Read prompt or context from specified file")]
+    #[arg(
+        long,
+        short = 'f',
+        visible_alias = "prompt-file",
+        help = "Read prompt or context from specified file"
+    )]
     pub file: Option<PathBuf>,
 
+    /// The --prompt value is a path to the prompt file
+    #[arg(
+        long,
+        requires = "prompt",
+        help = "Read the prompt from the file named by --prompt, which is otherwise taken as text"
+    )]
+    pub prompt_is_file: bool,
+
+    /// Ignore piped stdin
+    #[arg(
+        long,
+        help = "Ignore piped stdin, for scripts that cannot control their stdin"
+    )]
+    pub no_stdin: bool,
+
+    /// Send the prompt even if it looks like it contains secrets
+    #[arg(
+        long,
+        help = "Don't warn about or block prompts that look like they contain secrets (tokens, keys, passwords)"
+    )]
+    pub allow_secrets: bool,
+
     /// Skip IDE permission warnings (dangerous)
     #[arg(long, short = 'd', help = "Skip IDE permission warnings (dangerous)")]
     pub dangerously_skip_permissions: bool,
@@ -480,9 +1652,137 @@ pub struct UnifiedStartArgs {
     )]
     pub no_forward_keys: bool,
 
+    /// Create an empty initial commit when the repository has none
+    #[arg(
+        long,
+        help = "Create an empty initial commit if the repository has no commits yet"
+    )]
+    pub allow_empty_repo: bool,
+
+    /// Copy untracked files matching these globs into the new worktree
+    #[arg(
+        long = "copy",
+        value_name = "GLOB",
+        help = "Copy untracked files matching GLOB (e.g. '.env', '**/local.settings.json') from the main worktree into the session (repeatable)"
+    )]
+    pub copy: Vec<String>,
+
+    /// Allow copying gitignored files above the size limit
+    #[arg(
+        long,
+        help = "Also copy gitignored files larger than 1 MiB when copying untracked files"
+    )]
+    pub copy_large: bool,
+
+    /// Keep an absolute core.hooksPath pointing at the main checkout
+    #[arg(
+        long,
+        help = "Don't point an absolute core.hooksPath inside the repository at the new worktree"
+    )]
+    pub no_hook_fixup: bool,
+
+    /// Opt this session out of automatic WIP checkpoints
+    #[arg(
+        long,
+        help = "Disable automatic WIP checkpoint commits for this session"
+    )]
+    pub no_auto_commit: bool,
+
+    /// How finish shapes this session's commits
+    #[arg(
+        long,
+        value_enum,
+        help = "Merge mode recorded for the session and used by finish (default: git.default_merge_mode or squash)"
+    )]
+    pub merge_mode: Option<MergeMode>,
+
+    /// Start the session with this patch applied as uncommitted work
+    #[arg(
+        long,
+        value_name = "FILE",
+        conflicts_with_all = ["apply_from", "container"],
+        help = "Apply this patch file (git apply --3way) to the new session as uncommitted work"
+    )]
+    pub apply_patch: Option<PathBuf>,
+
+    /// Start the session with another branch's changes as uncommitted work
+    #[arg(
+        long,
+        value_name = "BRANCH",
+        conflicts_with = "container",
+        help = "Apply the diff of BRANCH against the session's base to the new session as uncommitted work"
+    )]
+    pub apply_from: Option<String>,
+
+    /// Create the session from this ref or session instead of the current branch
+    #[arg(
+        long,
+        value_name = "REF",
+        help = "Create the session from this branch, commit or session instead of the current branch (a session name stacks the new session on it)"
+    )]
+    pub base: Option<String>,
+
+    /// Fetch the base ref from the remote before creating the session
+    #[arg(
+        long,
+        requires = "base",
+        help = "Fetch --base from the remote first and start from the fetched commit"
+    )]
+    pub fetch: bool,
+
+    /// Remote to fetch --base from
+    #[arg(
+        long,
+        value_name = "NAME",
+        requires = "fetch",
+        help = "Remote to fetch --base from (default: git.default_remote, then origin)"
+    )]
+    pub remote: Option<String>,
+
+    /// Rerun another session's task in a new session
+    #[arg(
+        long,
+        value_name = "SESSION",
+        conflicts_with_all = ["file", "template"],
+        help = "Start a new session with the task and settings of SESSION (active or cancelled); the positional argument or --prompt adds extra instructions"
+    )]
+    pub retry: Option<String>,
+
+    /// Cancel the retried session once the new one is created
+    #[arg(
+        long,
+        requires = "retry",
+        help = "Cancel the --retry session after the new session has started"
+    )]
+    pub cancel_old: bool,
+
+    /// Prepare the session without launching Claude
+    #[arg(
+        long,
+        conflicts_with = "container",
+        help = "Create the worktree, state and task file but do not launch Claude; start it later with 'para launch'"
+    )]
+    pub plan_only: bool,
+
+    /// Leave the setup script for `para launch`
+    #[arg(
+        long,
+        requires = "plan_only",
+        help = "With --plan-only, run the setup script when the session is launched instead of now"
+    )]
+    pub defer_setup: bool,
+
     /// Sandbox configuration
     #[command(flatten)]
     pub sandbox_args: SandboxArgs,
+
+    /// Template providing defaults for the other options
+    #[arg(
+        long,
+        value_name = "NAME",
+        help = "Use the options of a template from the config as defaults (see 'para config templates list')"
+    )]
+    pub template: Option<String>,
 }
 
 #[derive(Subcommand, Debug)]
@@ -508,6 +1808,64 @@ pub enum StatusCommands {
         #[arg(long, help = "Show what would be cleaned without removing")]
         dry_run: bool,
     },
+    /// Add, complete and list the session's todos
+    Todo {
+        #[command(subcommand)]
+        command: TodoCommands,
+    },
+    /// Block until a session's status meets a condition
+    Wait {
+        /// Session name
+        session: String,
+
+        /// Condition to wait for
+        #[arg(
+            long,
+            value_name = "CONDITION",
+            help = "tests-passed, blocked, idle-for:<minutes> or task-contains:<text>"
+        )]
+        until: String,
+
+        /// Seconds between checks
+        #[arg(long, default_value_t = 5, help = "Seconds between checks")]
+        interval: u64,
+
+        /// Give up after this many seconds
+        #[arg(long, help = "Give up after this many seconds (exits with code 124)")]
+        timeout: Option<u64>,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum TodoCommands {
+    /// Add a todo to the session's list
+    Add {
+        /// Todo text
+        text: String,
+
+        /// Session name (optional, auto-detects from current directory)
+        #[arg(long, help = "Session name (auto-detected if not provided)")]
+        session: Option<String>,
+    },
+    /// Mark a todo as done
+    Done {
+        /// Id shown by 'para status todo list'
+        id: u32,
+
+        /// Session name (optional, auto-detects from current directory)
+        #[arg(long, help = "Session name (auto-detected if not provided)")]
+        session: Option<String>,
+    },
+    /// List the session's todos
+    List {
+        /// Session name (optional, auto-detects from current directory)
+        #[arg(long, help = "Session name (auto-detected if not provided)")]
+        session: Option<String>,
+
+        /// Output format
+        #[arg(long, help = "Output as JSON")]
+        json: bool,
+    },
 }
 
 #[derive(Args, Debug)]
@@ -546,10 +1904,16 @@ pub enum Shell {
     Bash,
     Zsh,
     Fish,
+    #[value(name = "powershell", alias = "pwsh")]
+    PowerShell,
 }
 
 impl FinishArgs {
     pub fn validate(&self) -> crate::utils::Result<()> {
+        if self.check {
+            return Ok(());
+        }
+
         if self.message.trim().is_empty() {
             return Err(crate::utils::ParaError::invalid_args(
                 "Commit message cannot be empty",
@@ -562,31 +1926,16 @@ impl FinishArgs {
 
         Ok(())
     }
-}
-
-pub fn validate_session_name(name: &str) -> crate::utils::Result<()> {
-    if name.is_empty() {
-        return Err(crate::utils::ParaError::invalid_args(
-            "Session name cannot be empty",
-        ));
-    }
-
-    if name.len() > 50 {
-        return Err(crate::utils::ParaError::invalid_args(
-            "Session name too long (max 50 characters)",
-        ));
-    }
 
-    if !name
-        .chars()
-        .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
-    {
-        return Err(crate::utils::ParaError::invalid_args(
-            "Session name can only contain alphanumeric characters, hyphens, and underscores",
-        ));
+    /// The session named on the command line. With `--check` there is no
+    /// commit message, so a single positional argument names the session.
+    pub fn session_id(&self) -> Option<String> {
+        match &self.session {
+            Some(session) => Some(session.clone()),
+            None if self.check && !self.message.is_empty() => Some(self.message.clone()),
+            None => None,
+        }
     }
-
-    Ok(())
 }
 
 pub fn validate_branch_name(name: &str) -> crate::utils::Result<()> {
@@ -637,13 +1986,21 @@ pub enum DaemonCommands {
     Stop,
     /// Check daemon status
     Status,
+    /// Fetch the base branches of this repository's sessions now
+    Prefetch,
+    /// Remove orphaned para containers and their volumes and networks
+    Cleanup {
+        /// Run even if the cleanup interval has not elapsed
+        #[arg(long)]
+        now: bool,
+    },
 }
 
 impl UnifiedStartArgs {
     /// Validate the unified start arguments
     pub fn validate(&self) -> crate::utils::Result<()> {
-        // Validate session name if provided
-        if let Some(ref name) = self.name {
+        // Validate session name if provided; retries derive their name
+        if let Some(name) = self.name.as_ref().filter(|_| self.retry.is_none()) {
             validate_session_name(name)?;
         }
 
@@ -670,17 +2027,31 @@ impl UnifiedStartArgs {
             setup_script: self.setup_script.clone(),
             docker_image: self.docker_image.clone(),
             no_forward_keys: self.no_forward_keys,
+            allow_empty_repo: self.allow_empty_repo,
+            copy: self.copy.clone(),
+            copy_large: self.copy_large,
+            no_hook_fixup: self.no_hook_fixup,
+            no_auto_commit: self.no_auto_commit,
+            merge_mode: self.merge_mode,
+            apply_patch: self.apply_patch.clone(),
+            apply_from: self.apply_from.clone(),
             sandbox_args: self.sandbox_args.clone(),
+            template: self.template.clone(),
         }
     }
 
     /// Convert to DispatchArgs for delegating to existing dispatch command
     pub fn to_dispatch_args(&self, name: Option<String>, prompt: Option<String>) -> DispatchArgs {
         let has_name = name.is_some();
+        let literal_prompt = !self.prompt_is_file && prompt.is_some();
         DispatchArgs {
             name_or_prompt: name.or(prompt.clone()),
             prompt: if has_name { prompt } else { None },
+            literal_prompt,
             file: self.file.clone(),
+            prompt_is_file: self.prompt_is_file,
+            no_stdin: self.no_stdin,
+            allow_secrets: self.allow_secrets,
             dangerously_skip_permissions: self.dangerously_skip_permissions,
             container: self.container,
             allow_domains: self.allow_domains.clone(),
@@ -688,7 +2059,24 @@ impl UnifiedStartArgs {
             setup_script: self.setup_script.clone(),
             docker_image: self.docker_image.clone(),
             no_forward_keys: self.no_forward_keys,
+            allow_empty_repo: self.allow_empty_repo,
+            copy: self.copy.clone(),
+            copy_large: self.copy_large,
+            no_hook_fixup: self.no_hook_fixup,
+            no_auto_commit: self.no_auto_commit,
+            merge_mode: self.merge_mode,
+            apply_patch: self.apply_patch.clone(),
+            apply_from: self.apply_from.clone(),
+            base: self.base.clone(),
+            fetch: self.fetch,
+            remote: self.remote.clone(),
+            retry: self.retry.clone(),
+            cancel_old: self.cancel_old,
+            plan_only: self.plan_only,
+            defer_setup: self.defer_setup,
             sandbox_args: self.sandbox_args.clone(),
+            template: self.template.clone(),
+            prompt_prefix: None,
         }
     }
 }
@@ -717,9 +2105,11 @@ impl DispatchArgs {
 
     fn validate_args(&self) -> crate::utils::Result<()> {
         match (&self.name_or_prompt, &self.prompt, &self.file) {
-            (None, None, None) => Err(crate::utils::ParaError::invalid_args(
-                "dispatch requires a prompt text or file path",
-            )),
+            (None, None, None) if self.retry.is_none() => {
+                Err(crate::utils::ParaError::invalid_args(
+                    "dispatch requires a prompt text or file path",
+                ))
+            }
             _ => Ok(()),
         }
     }
@@ -96,6 +96,22 @@ mod cli_tests {
         }
     }
 
+    #[test]
+    fn test_finish_check_does_not_need_message() {
+        let cli = Cli::try_parse_from(["para", "finish", "--check", "my-session"]).unwrap();
+        match cli.command.unwrap() {
+            Commands::Finish(args) => {
+                assert!(args.check);
+                assert_eq!(args.session_id(), Some("my-session".to_string()));
+                assert!(args.validate().is_ok());
+            }
+            _ => panic!("Expected Finish command"),
+        }
+
+        assert!(Cli::try_parse_from(["para", "finish"]).is_err());
+        assert!(Cli::try_parse_from(["para", "finish", "--check", "--branch", "x"]).is_err());
+    }
+
     #[test]
     fn test_finish_command_with_branch() {
         let cli = Cli::try_parse_from([
@@ -153,15 +169,40 @@ mod cli_tests {
         let cli = Cli::try_parse_from(["para", "completion", "bash"]).unwrap();
         match cli.command.unwrap() {
             Commands::Completion(args) => {
-                assert_eq!(args.shell, "bash");
+                assert_eq!(args.shell.as_deref(), Some("bash"));
             }
             _ => panic!("Expected Completion command"),
         }
     }
 
+    #[test]
+    fn test_completion_generate_and_install_commands() {
+        use crate::cli::parser::{CompletionCommands, Shell};
+
+        let cli = Cli::try_parse_from(["para", "completion", "generate", "pwsh"]).unwrap();
+        match cli.command.unwrap() {
+            Commands::Completion(args) => assert!(matches!(
+                args.command,
+                Some(CompletionCommands::Generate {
+                    shell: Shell::PowerShell
+                })
+            )),
+            _ => panic!("Expected Completion command"),
+        }
+
+        let cli = Cli::try_parse_from(["para", "completion", "install"]).unwrap();
+        match cli.command.unwrap() {
+            Commands::Completion(args) => assert!(matches!(
+                args.command,
+                Some(CompletionCommands::Install { shell: None })
+            )),
+            _ => panic!("Expected Completion command"),
+        }
+    }
+
     #[test]
     fn test_session_name_validation() {
-        use crate::cli::parser::validate_session_name;
+        use crate::utils::validate_session_name;
 
         assert!(validate_session_name("valid-name").is_ok());
         assert!(validate_session_name("valid_name").is_ok());
@@ -171,7 +212,7 @@ mod cli_tests {
         assert!(validate_session_name("invalid name").is_err());
         assert!(validate_session_name("invalid@name").is_err());
 
-        let long_name = "a".repeat(51);
+        let long_name = "a".repeat(101);
         assert!(validate_session_name(&long_name).is_err());
     }
 
@@ -195,6 +236,17 @@ mod cli_tests {
             message: "".to_string(),
             branch: None,
             session: None,
+            force_rename: false,
+            allow_protected: false,
+            session_branch: None,
+            check: false,
+            merge_mode: None,
+            onto_session: None,
+            json: false,
+            remote_branch: None,
+            base: None,
+            preflight: Default::default(),
+            staged_only: false,
         };
         assert!(args.validate().is_err());
 
@@ -202,6 +254,17 @@ mod cli_tests {
             message: "Valid commit message".to_string(),
             branch: None,
             session: None,
+            force_rename: false,
+            allow_protected: false,
+            session_branch: None,
+            check: false,
+            merge_mode: None,
+            onto_session: None,
+            json: false,
+            remote_branch: None,
+            base: None,
+            preflight: Default::default(),
+            staged_only: false,
         };
         assert!(args.validate().is_ok());
 
@@ -209,6 +272,17 @@ mod cli_tests {
             message: "Valid commit message".to_string(),
             branch: Some("-invalid".to_string()),
             session: None,
+            force_rename: false,
+            allow_protected: false,
+            session_branch: None,
+            check: false,
+            merge_mode: None,
+            onto_session: None,
+            json: false,
+            remote_branch: None,
+            base: None,
+            preflight: Default::default(),
+            staged_only: false,
         };
         assert!(args.validate().is_err());
     }
@@ -226,11 +300,53 @@ mod cli_tests {
     fn test_completion_branches_command() {
         let cli = Cli::try_parse_from(["para", "_completion_branches"]).unwrap();
         match cli.command.unwrap() {
-            Commands::CompletionBranches => {}
+            Commands::CompletionBranches { para } => assert!(!para),
+            _ => panic!("Expected CompletionBranches command"),
+        }
+
+        let cli = Cli::try_parse_from(["para", "_completion_branches", "--para"]).unwrap();
+        match cli.command.unwrap() {
+            Commands::CompletionBranches { para } => assert!(para),
             _ => panic!("Expected CompletionBranches command"),
         }
     }
 
+    #[test]
+    fn test_deprecated_commands_map_onto_their_replacements() {
+        let cli = Cli::try_parse_from(["para", "integrate", "Add login", "auth"]).unwrap();
+        match cli.command.unwrap() {
+            Commands::Integrate(args) => {
+                let finish = args.into_finish_args();
+                assert_eq!(finish.message, "Add login");
+                assert_eq!(finish.session.as_deref(), Some("auth"));
+                assert!(finish.branch.is_none() && !finish.check && finish.merge_mode.is_none());
+                assert!(finish.validate().is_ok());
+            }
+            _ => panic!("Expected Integrate command"),
+        }
+        assert!(Cli::try_parse_from(["para", "integrate"]).is_err());
+
+        let cli = Cli::try_parse_from(["para", "continue"]).unwrap();
+        match cli.command.unwrap() {
+            Commands::Continue(args) => assert!(args.session.is_none()),
+            _ => panic!("Expected Continue command"),
+        }
+    }
+
+    #[test]
+    fn test_help_lists_deprecated_commands_separately() {
+        use clap::CommandFactory;
+        let mut app = Cli::command();
+        let help = app.render_help().to_string();
+
+        let (commands, deprecated) = help.split_once("Deprecated commands:").unwrap();
+        for name in DEPRECATED_COMMANDS {
+            assert!(app.find_subcommand(name).unwrap().is_hide_set());
+            assert!(!commands.contains(&format!("  {name} ")));
+            assert!(deprecated.contains(&format!("  {name} ")));
+        }
+    }
+
     #[test]
     fn test_completion_commands_are_hidden() {
         use clap::CommandFactory;
@@ -312,6 +428,25 @@ mod cli_tests {
                 sandbox_no_network: false,
                 allowed_domains: vec![],
             },
+            base: None,
+            fetch: false,
+            allow_empty_repo: false,
+            no_stdin: false,
+            copy: vec![],
+            copy_large: false,
+            no_auto_commit: false,
+            merge_mode: None,
+            remote: None,
+            apply_patch: None,
+            apply_from: None,
+            template: None,
+            allow_secrets: false,
+            retry: None,
+            cancel_old: false,
+            no_hook_fixup: false,
+            plan_only: false,
+            defer_setup: false,
+            prompt_is_file: false,
         };
         assert!(args.validate().is_ok());
 
@@ -334,6 +469,25 @@ mod cli_tests {
                 sandbox_no_network: false,
                 allowed_domains: vec![],
             },
+            base: None,
+            fetch: false,
+            allow_empty_repo: false,
+            no_stdin: false,
+            copy: vec![],
+            copy_large: false,
+            no_auto_commit: false,
+            merge_mode: None,
+            remote: None,
+            apply_patch: None,
+            apply_from: None,
+            template: None,
+            allow_secrets: false,
+            retry: None,
+            cancel_old: false,
+            no_hook_fixup: false,
+            plan_only: false,
+            defer_setup: false,
+            prompt_is_file: false,
         };
         assert!(args.validate().is_err());
 
@@ -356,6 +510,25 @@ mod cli_tests {
                 sandbox_no_network: false,
                 allowed_domains: vec![],
             },
+            base: None,
+            fetch: false,
+            allow_empty_repo: false,
+            no_stdin: false,
+            copy: vec![],
+            copy_large: false,
+            no_auto_commit: false,
+            merge_mode: None,
+            remote: None,
+            apply_patch: None,
+            apply_from: None,
+            template: None,
+            allow_secrets: false,
+            retry: None,
+            cancel_old: false,
+            no_hook_fixup: false,
+            plan_only: false,
+            defer_setup: false,
+            prompt_is_file: false,
         };
         assert!(args.validate().is_ok());
     }
@@ -376,7 +549,7 @@ mod cli_tests {
         match cli.command.unwrap() {
             Commands::Completion(args) => {
                 // Verify that "init" was parsed as the shell string
-                assert_eq!(args.shell, "init");
+                assert_eq!(args.shell.as_deref(), Some("init"));
             }
             _ => panic!("Expected Completion command"),
         }
@@ -0,0 +1,219 @@
+//! Optimistic locking for writes to the user config
+//!
+//! A command that edits the config takes a [`ConfigSnapshot`] of the file when
+//! it loads it. Before writing, the file is compared with the snapshot; if
+//! another para process saved in between, the edit is replayed on top of that
+//! save as a three-way merge of the JSON values. Keys changed on both sides to
+//! different values abort the write with [`ConfigError::ConcurrentModification`].
+
+use super::{ConfigError, Result};
+use serde_json::{Map, Value};
+use std::collections::hash_map::DefaultHasher;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// What identifies one version of the config file
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Fingerprint {
+    modified: Option<SystemTime>,
+    hash: u64,
+}
+
+/// The config file as a command loaded it
+#[derive(Debug, Clone)]
+pub struct ConfigSnapshot {
+    path: PathBuf,
+    /// `None` while the file does not exist
+    fingerprint: Option<Fingerprint>,
+    value: Value,
+}
+
+impl ConfigSnapshot {
+    pub fn read(path: &Path) -> Result<Self> {
+        let (fingerprint, value) = match fs::read(path) {
+            Ok(content) => {
+                let mut hasher = DefaultHasher::new();
+                content.hash(&mut hasher);
+                let fingerprint = Fingerprint {
+                    modified: fs::metadata(path).and_then(|m| m.modified()).ok(),
+                    hash: hasher.finish(),
+                };
+                (Some(fingerprint), serde_json::from_slice(&content)?)
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => (None, Value::Null),
+            Err(e) => return Err(e.into()),
+        };
+        Ok(Self {
+            path: path.to_path_buf(),
+            fingerprint,
+            value,
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The config as JSON when it was loaded; `null` if there was no file
+    pub fn value(&self) -> &Value {
+        &self.value
+    }
+
+    /// `ours`, with whatever was saved since this snapshot merged in.
+    ///
+    /// Returns `ours` unchanged when the file is still the one loaded.
+    pub fn rebase(&self, ours: Value) -> Result<Value> {
+        let current = Self::read(&self.path)?;
+        if current.fingerprint == self.fingerprint {
+            return Ok(ours);
+        }
+        merge(&self.value, &ours, &current.value).map_err(ConfigError::ConcurrentModification)
+    }
+}
+
+/// Three-way merge of `ours` and `theirs`, both edited from `base`.
+///
+/// Objects are merged key by key; any other value, and a key being added or
+/// removed, counts as a change of the whole value. On failure returns the
+/// dotted paths both sides changed differently.
+pub fn merge(
+    base: &Value,
+    ours: &Value,
+    theirs: &Value,
+) -> std::result::Result<Value, Vec<String>> {
+    let mut conflicts = Vec::new();
+    let merged = merge_entry(Some(base), Some(ours), Some(theirs), "", &mut conflicts);
+    if conflicts.is_empty() {
+        Ok(merged.unwrap_or(Value::Null))
+    } else {
+        Err(conflicts)
+    }
+}
+
+fn merge_entry(
+    base: Option<&Value>,
+    ours: Option<&Value>,
+    theirs: Option<&Value>,
+    path: &str,
+    conflicts: &mut Vec<String>,
+) -> Option<Value> {
+    if ours == theirs || theirs == base {
+        return ours.cloned();
+    }
+    if ours == base {
+        return theirs.cloned();
+    }
+
+    let (Some(Value::Object(ours)), Some(Value::Object(theirs))) = (ours, theirs) else {
+        conflicts.push(if path.is_empty() {
+            "<root>".to_string()
+        } else {
+            path.to_string()
+        });
+        return ours.cloned();
+    };
+    let base = base.and_then(Value::as_object);
+
+    let mut keys: Vec<&String> = ours.keys().chain(theirs.keys()).collect();
+    keys.sort();
+    keys.dedup();
+
+    let mut merged = Map::new();
+    for key in keys {
+        let child_path = if path.is_empty() {
+            key.clone()
+        } else {
+            format!("{path}.{key}")
+        };
+        if let Some(value) = merge_entry(
+            base.and_then(|base| base.get(key)),
+            ours.get(key),
+            theirs.get(key),
+            &child_path,
+            conflicts,
+        ) {
+            merged.insert(key.clone(), value);
+        }
+    }
+    Some(Value::Object(merged))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_merge_non_overlapping_changes() {
+        let base = json!({"ide": {"name": "claude"}, "git": {"auto_stage": true}, "old": 1});
+        let ours = json!({"ide": {"name": "cursor"}, "git": {"auto_stage": true}, "old": 1});
+        let theirs = json!({"ide": {"name": "claude"}, "git": {"auto_stage": false}, "new": 2});
+
+        assert_eq!(
+            merge(&base, &ours, &theirs).unwrap(),
+            json!({"ide": {"name": "cursor"}, "git": {"auto_stage": false}, "new": 2})
+        );
+        // Both sides making the same change is not a conflict
+        assert_eq!(merge(&base, &ours, &ours).unwrap(), ours);
+    }
+
+    #[test]
+    fn test_merge_reports_conflicting_keys() {
+        let base = json!({"ide": {"name": "claude", "command": "claude"}, "x": [1]});
+        let ours = json!({"ide": {"name": "cursor", "command": "claude"}, "x": [1, 2]});
+        let theirs = json!({"ide": {"name": "code", "command": "code"}, "x": [3]});
+
+        assert_eq!(
+            merge(&base, &ours, &theirs).unwrap_err(),
+            vec!["ide.name".to_string(), "x".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_rebase_fast_path_and_conflicts() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("config.json");
+        fs::write(
+            &path,
+            r#"{"ide": {"name": "claude"}, "git": {"auto_stage": true}}"#,
+        )
+        .unwrap();
+        let snapshot = ConfigSnapshot::read(&path).unwrap();
+
+        // Unchanged file: our value is written as is, even where it drops keys
+        let ours = json!({"ide": {"name": "cursor"}});
+        assert_eq!(snapshot.rebase(ours.clone()).unwrap(), ours);
+
+        fs::write(
+            &path,
+            r#"{"ide": {"name": "claude"}, "git": {"auto_stage": false}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            snapshot
+                .rebase(json!({"ide": {"name": "cursor"}, "git": {"auto_stage": true}}))
+                .unwrap(),
+            json!({"ide": {"name": "cursor"}, "git": {"auto_stage": false}})
+        );
+
+        fs::write(
+            &path,
+            r#"{"ide": {"name": "code"}, "git": {"auto_stage": true}}"#,
+        )
+        .unwrap();
+        let error = snapshot
+            .rebase(json!({"ide": {"name": "cursor"}, "git": {"auto_stage": true}}))
+            .unwrap_err();
+        assert!(matches!(
+            &error,
+            ConfigError::ConcurrentModification(keys) if keys == &["ide.name".to_string()]
+        ));
+        assert!(error.to_string().contains("ide.name"));
+
+        let missing = ConfigSnapshot::read(&dir.path().join("missing.json")).unwrap();
+        assert!(missing.value().is_null());
+    }
+}
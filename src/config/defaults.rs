@@ -9,6 +9,13 @@ pub fn default_config() -> Config {
         docker: None,
         setup_script: None,
         sandbox: None,
+        notifications: None,
+        security: None,
+        status: None,
+        templates: Default::default(),
+        cleanup: None,
+        vcs: None,
+        ui: None,
     }
 }
 
@@ -33,6 +40,7 @@ pub fn default_ide_config() -> IdeConfig {
             name: wrapper_command.to_string(),
             command: wrapper_command.to_string(),
         },
+        remote: None,
     }
 }
 
@@ -40,6 +48,8 @@ pub fn default_directory_config() -> DirectoryConfig {
     DirectoryConfig {
         subtrees_dir: ".para/worktrees".to_string(),
         state_dir: ".para/state".to_string(),
+        bare_root: None,
+        layout: Default::default(),
     }
 }
 
@@ -48,6 +58,18 @@ pub fn default_git_config() -> GitConfig {
         branch_prefix: "para".to_string(),
         auto_stage: true,
         auto_commit: true,
+        author_name: None,
+        author_email: None,
+        protected_branches: Vec::new(),
+        auto_commit_interval_minutes: None,
+        default_merge_mode: None,
+        command_timeout_secs: None,
+        audit_log: false,
+        default_remote: None,
+        auto_fetch: false,
+        auto_fetch_interval_minutes: None,
+        apply_whitespace: Default::default(),
+        behind_warning_commits: None,
     }
 }
 
@@ -56,6 +78,12 @@ pub fn default_session_config() -> SessionConfig {
         default_name_format: "%Y%m%d-%H%M%S".to_string(),
         preserve_on_finish: false,
         auto_cleanup_days: Some(30),
+        retention: None,
+        copy_untracked: Vec::new(),
+        idle_alert_hours: None,
+        idle_action: Default::default(),
+        conflict_hotspots: false,
+        on_resume: Vec::new(),
     }
 }
 
@@ -87,17 +115,18 @@ pub fn is_command_available(command: &str) -> bool {
     }
 }
 
+/// Directory holding the user config file and para's other per-user files
 pub fn get_default_config_dir() -> std::path::PathBuf {
-    if let Some(proj_dirs) = directories::ProjectDirs::from("", "", "para") {
-        proj_dirs.config_dir().to_path_buf()
-    } else {
-        // Fallback for rare case where directories crate fails
-        std::path::PathBuf::from(".").join(".config").join("para")
-    }
+    let config_file = get_config_file_path();
+    config_file
+        .parent()
+        .map(std::path::Path::to_path_buf)
+        .unwrap_or(config_file)
 }
 
+/// The user config file, resolved as described in [`super::path`]
 pub fn get_config_file_path() -> std::path::PathBuf {
-    get_default_config_dir().join("config.json")
+    super::path::config_file_path()
 }
 
 #[cfg(test)]
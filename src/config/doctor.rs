@@ -0,0 +1,482 @@
+//! `para config doctor`: drift in the user config file
+//!
+//! The file is checked as raw JSON rather than as a parsed [`Config`], since
+//! parsing silently ignores keys it does not know and fails outright on a
+//! missing required value. Keys are compared with the registry in
+//! [`super::keys`]: unknown keys, keys older versions wrote under another
+//! name, required values that are missing, IDE commands that are not on the
+//! PATH and absolute directories that do not exist are reported. Each problem
+//! carries the repair `--fix` applies to the JSON, if there is a safe one;
+//! creating directories is left to the caller.
+
+use super::defaults::default_config;
+use super::keys;
+use super::Config;
+use serde_json::{Map, Value};
+use std::fmt;
+use std::path::PathBuf;
+
+/// Keys older versions wrote, with the key that replaced them
+pub const RENAMED_KEYS: &[(&str, &str)] = &[
+    ("ide.wrapper_enabled", "ide.wrapper.enabled"),
+    ("ide.wrapper_command", "ide.wrapper.command"),
+    ("directories.worktrees_dir", "directories.subtrees_dir"),
+    (
+        "git.auto_commit_interval",
+        "git.auto_commit_interval_minutes",
+    ),
+    ("docker.cleanup_interval", "docker.cleanup_interval_minutes"),
+];
+
+/// Top-level keys para writes itself and that are not settings
+const BOOKKEEPING_KEYS: &[&str] = &["para_version"];
+
+/// Wrapper IDEs tried when the configured one is missing, in the order the
+/// defaults prefer them
+const WRAPPER_CANDIDATES: &[&str] = &["code", "cursor"];
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Problem {
+    UnknownKey {
+        path: String,
+        suggestion: Option<&'static str>,
+    },
+    RenamedKey {
+        from: &'static str,
+        to: &'static str,
+    },
+    MissingValue {
+        path: &'static str,
+        default: Value,
+    },
+    CommandNotFound {
+        path: &'static str,
+        command: String,
+        /// Detected command to use instead
+        replacement: Option<String>,
+    },
+    MissingDirectory {
+        path: &'static str,
+        dir: PathBuf,
+    },
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Problem::UnknownKey {
+                path,
+                suggestion: Some(suggestion),
+            } => write!(f, "Unknown key '{path}' (did you mean {suggestion}?)"),
+            Problem::UnknownKey { path, .. } => write!(f, "Unknown key '{path}'"),
+            Problem::RenamedKey { from, to } => {
+                write!(f, "Key '{from}' has been renamed to '{to}'")
+            }
+            Problem::MissingValue { path, .. } => write!(f, "Required key '{path}' is missing"),
+            Problem::CommandNotFound { path, command, .. } => {
+                write!(f, "Command '{command}' of '{path}' is not on the PATH")
+            }
+            Problem::MissingDirectory { path, dir } => {
+                write!(
+                    f,
+                    "Directory '{}' of '{path}' does not exist",
+                    dir.display()
+                )
+            }
+        }
+    }
+}
+
+impl Problem {
+    /// The repair `--fix` makes, or `None` when it has to be fixed by hand
+    pub fn fix(&self) -> Option<String> {
+        match self {
+            Problem::UnknownKey { path, .. } => Some(format!("remove '{path}'")),
+            Problem::RenamedKey { from, to } => Some(format!("move '{from}' to '{to}'")),
+            Problem::MissingValue { path, default } => {
+                Some(format!("set '{path}' to the default {default}"))
+            }
+            Problem::CommandNotFound {
+                path,
+                replacement: Some(replacement),
+                ..
+            } => Some(format!("set '{path}' to detected '{replacement}'")),
+            Problem::CommandNotFound { .. } => None,
+            Problem::MissingDirectory { dir, .. } => Some(format!("create '{}'", dir.display())),
+        }
+    }
+}
+
+/// Problems of the config in `json`; `command_available` says whether a
+/// command is on the PATH
+pub fn diagnose(json: &Value, command_available: &dyn Fn(&str) -> bool) -> Vec<Problem> {
+    let mut problems = Vec::new();
+    if let Some(fields) = json.as_object() {
+        key_problems(fields, "", &mut problems);
+    }
+    missing_values(json, &mut problems);
+    command_problems(json, command_available, &mut problems);
+    directory_problems(json, &mut problems);
+    problems
+}
+
+/// Apply the fixes of `problems` to `json`, except creating directories
+pub fn apply_fixes(json: &mut Value, problems: &[Problem]) {
+    for problem in problems {
+        match problem {
+            Problem::UnknownKey { path, .. } => {
+                remove(json, path);
+            }
+            Problem::RenamedKey { from, to } => {
+                if let Some(value) = remove(json, from) {
+                    // A value already under the new name wins
+                    if get(json, to).is_none() {
+                        set(json, to, value);
+                    }
+                }
+            }
+            Problem::MissingValue { path, default } => set(json, path, default.clone()),
+            Problem::CommandNotFound {
+                path,
+                replacement: Some(replacement),
+                ..
+            } => {
+                set(json, path, Value::from(replacement.as_str()));
+                if *path == "ide.wrapper.command" {
+                    set(json, "ide.wrapper.name", Value::from(replacement.as_str()));
+                }
+            }
+            Problem::CommandNotFound { .. } | Problem::MissingDirectory { .. } => {}
+        }
+    }
+}
+
+fn key_problems(fields: &Map<String, Value>, prefix: &str, problems: &mut Vec<Problem>) {
+    for (name, value) in fields {
+        let path = if prefix.is_empty() {
+            name.clone()
+        } else {
+            format!("{prefix}.{name}")
+        };
+        if prefix.is_empty() && BOOKKEEPING_KEYS.contains(&name.as_str()) {
+            continue;
+        }
+        if let Some(&(from, to)) = RENAMED_KEYS.iter().find(|(from, _)| *from == path) {
+            problems.push(Problem::RenamedKey { from, to });
+        } else if keys::is_known(&path) || keys::OPEN_SECTIONS.contains(&path.as_str()) {
+            continue;
+        } else if is_section(&path) {
+            if let Some(section) = value.as_object() {
+                key_problems(section, &path, problems);
+            }
+        } else {
+            problems.push(Problem::UnknownKey {
+                suggestion: keys::suggest(&path, 1).first().copied(),
+                path,
+            });
+        }
+    }
+}
+
+/// Whether `path` holds registered keys, such as `ide.wrapper`
+fn is_section(path: &str) -> bool {
+    let prefix = format!("{path}.");
+    keys::CONFIG_KEYS
+        .iter()
+        .any(|key| key.path.starts_with(&prefix))
+}
+
+/// Registered keys the config cannot be parsed without, with their defaults
+fn required_keys() -> Vec<(&'static str, Value)> {
+    let Ok(defaults) = serde_json::to_value(default_config()) else {
+        return Vec::new();
+    };
+    keys::CONFIG_KEYS
+        .iter()
+        .filter_map(|key| {
+            let default = get(&defaults, key.path)?.clone();
+            let mut without = defaults.clone();
+            remove(&mut without, key.path);
+            serde_json::from_value::<Config>(without)
+                .is_err()
+                .then_some((key.path, default))
+        })
+        .collect()
+}
+
+fn missing_values(json: &Value, problems: &mut Vec<Problem>) {
+    let renamed_to = |path: &str| {
+        RENAMED_KEYS
+            .iter()
+            .any(|(from, to)| *to == path && get(json, from).is_some())
+    };
+    for (path, default) in required_keys() {
+        // Moving a renamed key fills it
+        if get(json, path).is_none() && !renamed_to(path) {
+            problems.push(Problem::MissingValue { path, default });
+        }
+    }
+}
+
+fn command_problems(
+    json: &Value,
+    command_available: &dyn Fn(&str) -> bool,
+    problems: &mut Vec<Problem>,
+) {
+    if let Some(command) = get(json, "ide.command").and_then(Value::as_str) {
+        if !command.is_empty() && !command_available(command) {
+            let (_, detected) = super::defaults::detect_ide();
+            problems.push(Problem::CommandNotFound {
+                path: "ide.command",
+                command: command.to_string(),
+                replacement: (detected != command && command_available(&detected))
+                    .then_some(detected),
+            });
+        }
+    }
+
+    let wrapper_enabled = get(json, "ide.wrapper.enabled")
+        .and_then(Value::as_bool)
+        .unwrap_or(false);
+    let wrapper_command = get(json, "ide.wrapper.command")
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+    if wrapper_enabled && !command_available(wrapper_command) {
+        problems.push(Problem::CommandNotFound {
+            path: "ide.wrapper.command",
+            command: wrapper_command.to_string(),
+            replacement: WRAPPER_CANDIDATES
+                .iter()
+                .find(|candidate| **candidate != wrapper_command && command_available(candidate))
+                .map(|candidate| candidate.to_string()),
+        });
+    }
+}
+
+/// Absolute directories that must exist; relative ones are resolved per
+/// repository and created when first used
+fn directory_problems(json: &Value, problems: &mut Vec<Problem>) {
+    for path in [
+        "directories.state_dir",
+        "directories.subtrees_dir",
+        "ide.user_data_dir",
+    ] {
+        let Some(dir) = get(json, path).and_then(Value::as_str) else {
+            continue;
+        };
+        let dir = PathBuf::from(dir);
+        if dir.is_absolute() && !dir.exists() {
+            problems.push(Problem::MissingDirectory { path, dir });
+        }
+    }
+}
+
+fn get<'a>(json: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.')
+        .try_fold(json, |value, segment| value.get(segment))
+        .filter(|value| !value.is_null())
+}
+
+fn remove(json: &mut Value, path: &str) -> Option<Value> {
+    let (parent, leaf) = match path.rsplit_once('.') {
+        Some((parent, leaf)) => (
+            parent
+                .split('.')
+                .try_fold(&mut *json, |value, segment| value.get_mut(segment))?,
+            leaf,
+        ),
+        None => (json, path),
+    };
+    parent.as_object_mut()?.remove(leaf)
+}
+
+fn set(json: &mut Value, path: &str, new: Value) {
+    let mut current = json;
+    let mut segments = path.split('.').peekable();
+    while let Some(segment) = segments.next() {
+        if !current.is_object() {
+            *current = Value::Object(Map::new());
+        }
+        let Some(fields) = current.as_object_mut() else {
+            return;
+        };
+        if segments.peek().is_none() {
+            fields.insert(segment.to_string(), new);
+            return;
+        }
+        current = fields
+            .entry(segment)
+            .or_insert_with(|| Value::Object(Map::new()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tempfile::TempDir;
+
+    fn fixture() -> Value {
+        let mut json = serde_json::to_value(default_config()).unwrap();
+        set(&mut json, "ide.command", json!("claude"));
+        set(&mut json, "ide.wrapper.command", json!("cursor"));
+        set(&mut json, "ide.wrapper.name", json!("cursor"));
+        json
+    }
+
+    fn available(commands: &'static [&'static str]) -> impl Fn(&str) -> bool {
+        move |command| commands.contains(&command)
+    }
+
+    #[test]
+    fn test_clean_config_has_no_problems() {
+        let mut json = fixture();
+        set(&mut json, "para_version", json!("1.1.38"));
+        set(&mut json, "templates.review.prompt_prefix", json!("x"));
+        assert_eq!(diagnose(&json, &available(&["claude", "cursor"])), vec![]);
+    }
+
+    #[test]
+    fn test_unknown_keys_are_reported_and_dropped() {
+        let mut json = fixture();
+        set(&mut json, "git.branch_prefx", json!("para"));
+        set(
+            &mut json,
+            "slack",
+            json!({"webhook": "https://example.com"}),
+        );
+
+        let problems = diagnose(&json, &available(&["claude", "cursor"]));
+        assert_eq!(
+            problems,
+            vec![
+                Problem::UnknownKey {
+                    path: "git.branch_prefx".to_string(),
+                    suggestion: Some("git.branch_prefix"),
+                },
+                Problem::UnknownKey {
+                    path: "slack".to_string(),
+                    suggestion: None,
+                },
+            ]
+        );
+        assert_eq!(
+            problems[0].to_string(),
+            "Unknown key 'git.branch_prefx' (did you mean git.branch_prefix?)"
+        );
+
+        apply_fixes(&mut json, &problems);
+        assert_eq!(json, fixture());
+    }
+
+    #[test]
+    fn test_renamed_keys_move_to_their_new_name() {
+        let mut json = fixture();
+        remove(&mut json, "directories.subtrees_dir");
+        set(&mut json, "directories.worktrees_dir", json!("trees"));
+        set(&mut json, "docker.cleanup_interval", json!(15));
+
+        let problems = diagnose(&json, &available(&["claude", "cursor"]));
+        assert_eq!(
+            problems,
+            vec![
+                Problem::RenamedKey {
+                    from: "directories.worktrees_dir",
+                    to: "directories.subtrees_dir",
+                },
+                Problem::RenamedKey {
+                    from: "docker.cleanup_interval",
+                    to: "docker.cleanup_interval_minutes",
+                },
+            ]
+        );
+
+        apply_fixes(&mut json, &problems);
+        assert_eq!(json["directories"]["subtrees_dir"], "trees");
+        assert_eq!(json["docker"], json!({"cleanup_interval_minutes": 15}));
+        assert!(serde_json::from_value::<Config>(json).is_ok());
+    }
+
+    #[test]
+    fn test_missing_required_values_get_defaults() {
+        let mut json = fixture();
+        remove(&mut json, "git.branch_prefix");
+        remove(&mut json, "session.default_name_format");
+        // Optional keys are not required
+        remove(&mut json, "directories.layout");
+        assert!(serde_json::from_value::<Config>(json.clone()).is_err());
+
+        let problems = diagnose(&json, &available(&["claude", "cursor"]));
+        assert_eq!(
+            problems,
+            vec![
+                Problem::MissingValue {
+                    path: "git.branch_prefix",
+                    default: json!("para"),
+                },
+                Problem::MissingValue {
+                    path: "session.default_name_format",
+                    default: json!("%Y%m%d-%H%M%S"),
+                },
+            ]
+        );
+
+        apply_fixes(&mut json, &problems);
+        let config: Config = serde_json::from_value(json).unwrap();
+        assert_eq!(config.git.branch_prefix, "para");
+    }
+
+    #[test]
+    fn test_missing_commands_are_detected_again() {
+        let mut json = fixture();
+        set(&mut json, "ide.command", json!("/opt/old/claude"));
+
+        let problems = diagnose(&json, &available(&["claude", "code"]));
+        assert_eq!(
+            problems,
+            vec![
+                Problem::CommandNotFound {
+                    path: "ide.command",
+                    command: "/opt/old/claude".to_string(),
+                    replacement: Some("claude".to_string()),
+                },
+                Problem::CommandNotFound {
+                    path: "ide.wrapper.command",
+                    command: "cursor".to_string(),
+                    replacement: Some("code".to_string()),
+                },
+            ]
+        );
+        apply_fixes(&mut json, &problems);
+        assert_eq!(json["ide"]["command"], "claude");
+        assert_eq!(json["ide"]["wrapper"]["command"], "code");
+        assert_eq!(json["ide"]["wrapper"]["name"], "code");
+
+        // Nothing to switch to
+        let problems = diagnose(&fixture(), &available(&["claude"]));
+        assert_eq!(problems.len(), 1);
+        assert_eq!(problems[0].fix(), None);
+    }
+
+    #[test]
+    fn test_missing_absolute_directories_are_reported() {
+        let temp = TempDir::new().unwrap();
+        let gone = temp.path().join("volume/state");
+        let mut json = fixture();
+        set(&mut json, "directories.state_dir", json!(gone));
+        set(&mut json, "ide.user_data_dir", json!(temp.path()));
+
+        let problems = diagnose(&json, &available(&["claude", "cursor"]));
+        assert_eq!(
+            problems,
+            vec![Problem::MissingDirectory {
+                path: "directories.state_dir",
+                dir: gone.clone(),
+            }]
+        );
+        assert_eq!(
+            problems[0].fix(),
+            Some(format!("create '{}'", gone.display()))
+        );
+    }
+}
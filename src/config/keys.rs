@@ -0,0 +1,329 @@
+//! Every key `para config set`, `para config get` and `-c key=value` accept
+//!
+//! The list is maintained by hand next to the config structs; a test
+//! serializes a fully populated `Config` and fails when a leaf path is
+//! missing here, or when a key here no longer exists in the config.
+
+use crate::utils::names::edit_distance;
+use std::fmt;
+
+/// Value type of a config key, as accepted on the command line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyType {
+    Bool,
+    Integer,
+    String,
+    /// Comma-separated list of strings
+    List,
+    /// One of a fixed set of values
+    Enum(&'static [&'static str]),
+}
+
+impl fmt::Display for KeyType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeyType::Bool => write!(f, "bool"),
+            KeyType::Integer => write!(f, "integer"),
+            KeyType::String => write!(f, "string"),
+            KeyType::List => write!(f, "list"),
+            KeyType::Enum(values) => write!(f, "{}", values.join("|")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConfigKey {
+    /// Dotted path, e.g. `git.branch_prefix`
+    pub path: &'static str,
+    pub kind: KeyType,
+    /// Whether `.para/config.json` in the repository can set it too
+    pub repo_overridable: bool,
+}
+
+const fn key(path: &'static str, kind: KeyType) -> ConfigKey {
+    ConfigKey {
+        path,
+        kind,
+        repo_overridable: false,
+    }
+}
+
+const fn repo_key(path: &'static str, kind: KeyType) -> ConfigKey {
+    ConfigKey {
+        path,
+        kind,
+        repo_overridable: true,
+    }
+}
+
+use KeyType::{Bool, Enum, Integer, List, String as Str};
+
+pub const CONFIG_KEYS: &[ConfigKey] = &[
+    key("ide.name", Str),
+    key("ide.command", Str),
+    key("ide.user_data_dir", Str),
+    key("ide.wrapper.enabled", Bool),
+    key("ide.wrapper.name", Str),
+    key("ide.wrapper.command", Str),
+    key("ide.remote.host", Str),
+    key("ide.remote.mode", Enum(&["ssh-remote"])),
+    key("directories.subtrees_dir", Str),
+    key("directories.bare_root", Str),
+    key("directories.state_dir", Str),
+    key("directories.layout", Enum(&["flat", "by-prefix"])),
+    key("git.branch_prefix", Str),
+    key("git.auto_stage", Bool),
+    key("git.auto_commit", Bool),
+    repo_key("git.author_name", Str),
+    repo_key("git.author_email", Str),
+    key("git.protected_branches", List),
+    key("git.auto_commit_interval_minutes", Integer),
+    key(
+        "git.default_merge_mode",
+        Enum(&["squash", "preserve_commits", "rebase"]),
+    ),
+    key("git.command_timeout_secs", Integer),
+    key("git.default_remote", Str),
+    key("git.audit_log", Bool),
+    key("git.auto_fetch", Bool),
+    key("git.auto_fetch_interval_minutes", Integer),
+    key(
+        "git.apply_whitespace",
+        Enum(&["nowarn", "warn", "fix", "error", "error-all"]),
+    ),
+    key("git.behind_warning_commits", Integer),
+    key("session.default_name_format", Str),
+    key("session.preserve_on_finish", Bool),
+    key("session.auto_cleanup_days", Integer),
+    key("session.retention.max_entries", Integer),
+    key("session.retention.max_age_days", Integer),
+    key("session.retention.log_max_bytes", Integer),
+    key("session.copy_untracked", List),
+    key("session.idle_alert_hours", Integer),
+    key("session.idle_action", Enum(&["none", "notify", "cancel"])),
+    key("session.conflict_hotspots", Bool),
+    key("session.on_resume", List),
+    key("docker.setup_script", Str),
+    key("docker.default_image", Str),
+    key("docker.forward_env_keys", List),
+    key("docker.cleanup_interval_minutes", Integer),
+    key("setup_script", Str),
+    repo_key("sandbox.enabled", Bool),
+    repo_key("sandbox.profile", Str),
+    repo_key("sandbox.allowed_domains", List),
+    key("notifications.command", Str),
+    key("notifications.timeout_secs", Integer),
+    key("security.forbid_skip_permissions", Bool),
+    key("security.block_secrets_in_prompts", Bool),
+    key("status.stale_after_minutes", Integer),
+    key("cleanup.enabled_kinds", List),
+    key("cleanup.trash_retention_days", Integer),
+    key("vcs.backend", Enum(&["git"])),
+    key("ui.theme", Enum(&["emoji", "ascii", "none"])),
+    key("ui.hide_deprecations", Bool),
+];
+
+/// Sections whose keys are names chosen by the user, such as `templates.<name>`
+pub const OPEN_SECTIONS: &[&str] = &["templates"];
+
+pub fn find(path: &str) -> Option<&'static ConfigKey> {
+    CONFIG_KEYS.iter().find(|key| key.path == path)
+}
+
+/// Whether `path` is a registered key or lies in an open section
+pub fn is_known(path: &str) -> bool {
+    find(path).is_some()
+        || OPEN_SECTIONS.iter().any(|section| {
+            path.strip_prefix(section)
+                .is_some_and(|rest| rest.starts_with('.'))
+        })
+}
+
+/// Keys close to `path`, closest first. A typo in the last segment alone,
+/// or a missing section, counts as close.
+pub fn suggest(path: &str, limit: usize) -> Vec<&'static str> {
+    let needle = path.to_lowercase();
+    let needle_leaf = needle.rsplit('.').next().unwrap_or(&needle);
+    let path_threshold = (needle.len() / 5).max(2);
+    let leaf_threshold = (needle_leaf.len() / 5).max(1);
+
+    // Ranked by the closer of both distances, then by the whole path
+    let mut candidates: Vec<(usize, usize, &'static str)> = CONFIG_KEYS
+        .iter()
+        .filter_map(|key| {
+            let leaf = key.path.rsplit('.').next().unwrap_or(key.path);
+            let path_distance = edit_distance(&needle, key.path);
+            let leaf_distance = edit_distance(needle_leaf, leaf);
+            (path_distance <= path_threshold || leaf_distance <= leaf_threshold).then_some((
+                path_distance.min(leaf_distance),
+                path_distance,
+                key.path,
+            ))
+        })
+        .collect();
+    candidates.sort();
+    candidates
+        .into_iter()
+        .map(|(_, _, path)| path)
+        .take(limit)
+        .collect()
+}
+
+/// Error message for a key that is not in the registry
+pub fn unknown_key_message(path: &str) -> String {
+    let suggestions = suggest(path, 3);
+    match suggestions.as_slice() {
+        [] => format!("Unknown config key '{path}'. Run 'para config keys' to list all keys"),
+        [only] => format!("Unknown config key '{path}'. Did you mean {only}?"),
+        many => format!(
+            "Unknown config key '{path}'. Did you mean one of: {}?",
+            many.join(", ")
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{
+        CleanupConfig, Config, DockerConfig, NotificationConfig, RemoteConfig, RetentionConfig,
+        SecurityConfig, StatusConfig, UiConfig, VcsConfig,
+    };
+    use crate::core::sandbox::SandboxConfig;
+    use crate::test_utils::test_helpers::create_test_config;
+    use serde_json::Value;
+
+    /// A config with every optional section and value set, so that all keys serialize
+    fn fully_populated_config() -> Config {
+        let mut config = create_test_config();
+        config.ide.user_data_dir = Some("profile".to_string());
+        config.directories.bare_root = Some("{repo}.para".to_string());
+        config.directories.layout = crate::config::WorktreeLayout::ByPrefix;
+        config.ide.remote = Some(RemoteConfig {
+            host: "devbox".to_string(),
+            mode: Default::default(),
+        });
+        config.git.author_name = Some("Para".to_string());
+        config.git.author_email = Some("para@example.com".to_string());
+        config.git.protected_branches = vec!["release".to_string()];
+        config.git.auto_commit_interval_minutes = Some(10);
+        config.git.default_merge_mode = Some(Default::default());
+        config.git.command_timeout_secs = Some(60);
+        config.git.default_remote = Some("origin".to_string());
+        config.git.audit_log = true;
+        config.git.auto_fetch = true;
+        config.git.auto_fetch_interval_minutes = Some(5);
+        config.git.apply_whitespace = crate::core::git::apply::ApplyWhitespace::Fix;
+        config.git.behind_warning_commits = Some(20);
+        config.session.auto_cleanup_days = Some(7);
+        config.session.retention = Some(RetentionConfig {
+            max_entries: Some(10),
+            max_age_days: Some(10),
+            log_max_bytes: Some(10),
+        });
+        config.session.copy_untracked = vec![".env".to_string()];
+        config.session.idle_alert_hours = Some(4);
+        config.session.idle_action = crate::core::idle::IdleAction::Cancel;
+        config.session.conflict_hotspots = true;
+        config.session.on_resume = vec!["npm install".to_string()];
+        config.docker = Some(DockerConfig {
+            setup_script: Some("setup.sh".to_string()),
+            default_image: Some("image".to_string()),
+            forward_env_keys: Some(vec!["KEY".to_string()]),
+            cleanup_interval_minutes: Some(30),
+        });
+        config.setup_script = Some("setup.sh".to_string());
+        config.sandbox = Some(SandboxConfig {
+            allowed_domains: vec!["example.com".to_string()],
+            ..Default::default()
+        });
+        config.notifications = Some(NotificationConfig {
+            command: Some("notify".to_string()),
+            timeout_secs: Some(5),
+        });
+        config.security = Some(SecurityConfig::default());
+        config.status = Some(StatusConfig {
+            stale_after_minutes: Some(60),
+        });
+        config.cleanup = Some(CleanupConfig {
+            enabled_kinds: Some(vec!["stale_branch".to_string()]),
+            trash_retention_days: Some(14),
+        });
+        config.vcs = Some(VcsConfig::default());
+        config.ui = Some(UiConfig::default());
+        config
+    }
+
+    fn leaf_paths(value: &Value, prefix: &str, paths: &mut Vec<String>) {
+        match value {
+            Value::Object(object) => {
+                for (name, child) in object {
+                    let path = if prefix.is_empty() {
+                        name.clone()
+                    } else {
+                        format!("{prefix}.{name}")
+                    };
+                    if !OPEN_SECTIONS.contains(&path.as_str()) {
+                        leaf_paths(child, &path, paths);
+                    }
+                }
+            }
+            _ => paths.push(prefix.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_registry_matches_config_struct() {
+        let json = serde_json::to_value(fully_populated_config()).unwrap();
+        let mut paths = Vec::new();
+        leaf_paths(&json, "", &mut paths);
+
+        let missing: Vec<&String> = paths.iter().filter(|path| find(path).is_none()).collect();
+        assert!(
+            missing.is_empty(),
+            "Config keys missing from CONFIG_KEYS: {missing:?}"
+        );
+        let stale: Vec<&str> = CONFIG_KEYS
+            .iter()
+            .map(|key| key.path)
+            .filter(|path| !paths.iter().any(|leaf| leaf == path))
+            .collect();
+        assert!(
+            stale.is_empty(),
+            "CONFIG_KEYS lists removed keys: {stale:?}"
+        );
+
+        // The default config only produces registered keys as well
+        let mut default_paths = Vec::new();
+        leaf_paths(
+            &serde_json::to_value(create_test_config()).unwrap(),
+            "",
+            &mut default_paths,
+        );
+        assert!(default_paths.iter().all(|path| find(path).is_some()));
+    }
+
+    #[test]
+    fn test_suggestions_for_common_typos() {
+        assert_eq!(suggest("git.branch_prefx", 3)[0], "git.branch_prefix");
+        assert_eq!(suggest("branch_prefix", 3)[0], "git.branch_prefix");
+        assert_eq!(suggest("git.autostage", 3)[0], "git.auto_stage");
+        assert_eq!(suggest("ide.wraper.name", 3)[0], "ide.wrapper.name");
+        assert_eq!(suggest("sandbox.enable", 3)[0], "sandbox.enabled");
+        assert!(suggest("completely.unrelated", 3).is_empty());
+
+        assert_eq!(
+            unknown_key_message("git.branch_prefx"),
+            "Unknown config key 'git.branch_prefx'. Did you mean git.branch_prefix?"
+        );
+        assert!(unknown_key_message("nope").contains("para config keys"));
+    }
+
+    #[test]
+    fn test_open_sections_accept_any_name() {
+        assert!(is_known("git.auto_stage"));
+        assert!(is_known("templates.review.labels"));
+        assert!(!is_known("templates"));
+        assert!(!is_known("git.auto_stag"));
+    }
+}
@@ -1,5 +1,7 @@
+use super::concurrent::ConfigSnapshot;
 use super::defaults::{default_config, get_config_file_path};
 use super::{Config, ProjectConfig, Result};
+use crate::core::version::PARA_VERSION;
 use std::fs;
 use std::io::Write;
 use std::path::{Path, PathBuf};
@@ -34,6 +36,7 @@ impl ConfigManager {
             Some(path) => {
                 let content = fs::read_to_string(&path)?;
                 let config: ProjectConfig = serde_json::from_str(&content)?;
+                super::templates::validate_templates(&config.templates)?;
                 Ok(Some(config))
             }
             None => Ok(None),
@@ -52,8 +55,14 @@ impl ConfigManager {
         // Load project config if available
         let project_config = Self::load_project_config()?;
 
-        // Merge and return
-        Ok(Self::merge_configs(user_config, project_config))
+        let mut config = Self::merge_configs(user_config, project_config);
+
+        // `--state-dir` / PARA_STATE_DIR beats every config file
+        if let Some(state_dir) = super::state_dir::state_dir_override() {
+            config.directories.state_dir = state_dir;
+            config.directories.normalize();
+        }
+        Ok(config)
     }
 
     pub fn load_or_create_with_path(config_path: Option<&Path>) -> Result<Config> {
@@ -86,6 +95,7 @@ impl ConfigManager {
             eprintln!("✅ Configuration migrated successfully");
         }
 
+        config.directories.normalize();
         config.validate()?;
         Ok(config)
     }
@@ -103,6 +113,7 @@ impl ConfigManager {
         }
 
         // Use test validation that skips command availability checks
+        config.directories.normalize();
         super::validation::validate_ide_config_no_cmd_check(&config.ide)?;
         super::validation::validate_directory_config(&config.directories)?;
         super::validation::validate_git_config(&config.git)?;
@@ -137,14 +148,46 @@ impl ConfigManager {
         Self::save_to_path(config, &get_config_file_path())
     }
 
+    /// Load the user config together with a snapshot for [`Self::save_checked`]
+    pub fn load_for_update() -> Result<(Config, ConfigSnapshot)> {
+        let config_path = get_config_file_path();
+        let config = Self::load_or_create_with_path(Some(&config_path))?;
+        Ok((config, ConfigSnapshot::read(&config_path)?))
+    }
+
+    /// Save `config` over the file `snapshot` was taken of, merging in
+    /// anything another process saved there since
+    pub fn save_checked(config: &Config, snapshot: &ConfigSnapshot) -> Result<()> {
+        config.validate()?;
+        let merged: Config =
+            serde_json::from_value(snapshot.rebase(serde_json::to_value(config)?)?)?;
+        Self::save_to_path(&merged, snapshot.path())
+    }
+
+    /// [`Self::save_checked`] for an edit made on the raw JSON of the config
+    pub fn save_value_checked(value: serde_json::Value, snapshot: &ConfigSnapshot) -> Result<()> {
+        let merged = snapshot.rebase(value)?;
+        Self::write_json(merged, snapshot.path())
+    }
+
     pub fn save_to_path(config: &Config, path: &Path) -> Result<()> {
         config.validate()?;
+        Self::write_json(serde_json::to_value(config)?, path)
+    }
 
+    /// Write the config, stamped with the para version that wrote it
+    fn write_json(mut value: serde_json::Value, path: &Path) -> Result<()> {
         if let Some(parent) = path.parent() {
             fs::create_dir_all(parent)?;
         }
+        if let Some(fields) = value.as_object_mut() {
+            fields.insert(
+                "para_version".to_string(),
+                serde_json::Value::from(PARA_VERSION),
+            );
+        }
 
-        let json = serde_json::to_string_pretty(config)?;
+        let json = serde_json::to_string_pretty(&value)?;
         let mut file = fs::File::create(path)?;
         file.write_all(json.as_bytes())?;
         file.sync_all()?;
@@ -152,6 +195,16 @@ impl ConfigManager {
         Ok(())
     }
 
+    /// Para version that last saved the config at `path`, if recorded
+    pub fn written_by(path: &Path) -> Option<String> {
+        let value: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(path).ok()?).ok()?;
+        value
+            .get("para_version")
+            .and_then(serde_json::Value::as_str)
+            .map(str::to_string)
+    }
+
     /// Save project configuration
     pub fn save_project_config(config: &ProjectConfig, path: &Path) -> Result<()> {
         if let Some(parent) = path.parent() {
@@ -200,6 +253,18 @@ impl ConfigManager {
                     config.ide.name = preferred;
                 }
             }
+
+            // Merge commit identity overrides
+            if let Some(project_git) = project.git {
+                if project_git.author_name.is_some() {
+                    config.git.author_name = project_git.author_name;
+                }
+                if project_git.author_email.is_some() {
+                    config.git.author_email = project_git.author_email;
+                }
+            }
+
+            config.templates.extend(project.templates);
         }
 
         config
@@ -223,27 +288,92 @@ mod tests {
                     name: String::new(),
                     command: String::new(),
                 },
+                remote: None,
             },
             directories: super::super::DirectoryConfig {
                 subtrees_dir: "test_subtrees".to_string(),
                 state_dir: "test_state".to_string(),
+                bare_root: None,
+                layout: Default::default(),
             },
             git: super::super::GitConfig {
                 branch_prefix: "test".to_string(),
                 auto_stage: true,
                 auto_commit: false,
+                author_email: None,
+                author_name: None,
+                protected_branches: Vec::new(),
+                auto_commit_interval_minutes: None,
+                default_merge_mode: None,
+                command_timeout_secs: None,
+                audit_log: false,
+                default_remote: None,
+                auto_fetch_interval_minutes: None,
+                auto_fetch: false,
+                apply_whitespace: Default::default(),
+                behind_warning_commits: None,
             },
             session: super::super::SessionConfig {
                 default_name_format: "%Y%m%d-%H%M%S".to_string(),
                 preserve_on_finish: false,
                 auto_cleanup_days: Some(7),
+                retention: None,
+                copy_untracked: Vec::new(),
+                idle_action: Default::default(),
+                idle_alert_hours: None,
+                conflict_hotspots: false,
+                on_resume: Vec::new(),
             },
             docker: None,
             setup_script: None,
             sandbox: None,
+            notifications: None,
+            security: None,
+            status: None,
+            templates: Default::default(),
+            cleanup: None,
+            vcs: None,
+            ui: None,
         }
     }
 
+    #[test]
+    fn test_save_checked_keeps_a_concurrent_save() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.json");
+        let original = create_test_config();
+        fs::write(&config_path, serde_json::to_string(&original).unwrap()).unwrap();
+        let snapshot = ConfigSnapshot::read(&config_path).unwrap();
+
+        let mut theirs = original.clone();
+        theirs.git.auto_stage = !original.git.auto_stage;
+        ConfigManager::save_to_path(&theirs, &config_path).unwrap();
+
+        let mut ours = original.clone();
+        ours.session.auto_cleanup_days = Some(3);
+        ConfigManager::save_checked(&ours, &snapshot).unwrap();
+        let saved: Config =
+            serde_json::from_str(&fs::read_to_string(&config_path).unwrap()).unwrap();
+        assert_eq!(saved.git.auto_stage, theirs.git.auto_stage);
+        assert_eq!(saved.session.auto_cleanup_days, Some(3));
+
+        // Changing what the other process changed is refused
+        let snapshot = ConfigSnapshot::read(&config_path).unwrap();
+        let mut other = saved.clone();
+        other.session.auto_cleanup_days = Some(1);
+        ConfigManager::save_to_path(&other, &config_path).unwrap();
+        let mut conflicting = saved.clone();
+        conflicting.session.auto_cleanup_days = Some(9);
+        let error = ConfigManager::save_checked(&conflicting, &snapshot).unwrap_err();
+        assert!(
+            error.to_string().contains("session.auto_cleanup_days"),
+            "{error}"
+        );
+        let kept: Config =
+            serde_json::from_str(&fs::read_to_string(&config_path).unwrap()).unwrap();
+        assert_eq!(kept.session.auto_cleanup_days, Some(1));
+    }
+
     #[test]
     fn test_save_and_load_config() {
         let temp_dir = TempDir::new().unwrap();
@@ -296,6 +426,21 @@ mod tests {
         assert!(test_config_path.exists());
     }
 
+    #[test]
+    fn test_save_records_para_version() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.json");
+        assert_eq!(ConfigManager::written_by(&config_path), None);
+
+        ConfigManager::save_to_path(&create_test_config(), &config_path).unwrap();
+        assert_eq!(
+            ConfigManager::written_by(&config_path).as_deref(),
+            Some(PARA_VERSION)
+        );
+        // The stamp is not a config key and does not get in the way of loading
+        assert!(ConfigManager::load_from_file(&config_path).is_ok());
+    }
+
     #[test]
     fn test_get_config_path() {
         // This test just verifies the method returns a valid path
@@ -389,24 +534,52 @@ mod tests {
                     name: String::new(),
                     command: String::new(),
                 },
+                remote: None,
             },
             directories: super::super::DirectoryConfig {
                 subtrees_dir: "test_subtrees".to_string(),
                 state_dir: "test_state".to_string(),
+                bare_root: None,
+                layout: Default::default(),
             },
             git: super::super::GitConfig {
                 branch_prefix: "test".to_string(),
                 auto_stage: true,
                 auto_commit: false,
+                author_email: None,
+                author_name: None,
+                protected_branches: Vec::new(),
+                auto_commit_interval_minutes: None,
+                default_merge_mode: None,
+                command_timeout_secs: None,
+                audit_log: false,
+                default_remote: None,
+                auto_fetch_interval_minutes: None,
+                auto_fetch: false,
+                apply_whitespace: Default::default(),
+                behind_warning_commits: None,
             },
             session: super::super::SessionConfig {
                 default_name_format: "%Y%m%d-%H%M%S".to_string(),
                 preserve_on_finish: false,
                 auto_cleanup_days: Some(7),
+                retention: None,
+                copy_untracked: Vec::new(),
+                idle_action: Default::default(),
+                idle_alert_hours: None,
+                conflict_hotspots: false,
+                on_resume: Vec::new(),
             },
             docker: None,
             setup_script: None,
             sandbox: None,
+            notifications: None,
+            security: None,
+            status: None,
+            templates: Default::default(),
+            cleanup: None,
+            vcs: None,
+            ui: None,
         };
 
         let json = serde_json::to_string_pretty(&claude_config).unwrap();
@@ -439,6 +612,7 @@ mod tests {
                     name: "cursor".to_string(),
                     command: "cursor".to_string(),
                 },
+                remote: None,
             },
             directories: default_directory_config(),
             git: default_git_config(),
@@ -450,6 +624,13 @@ mod tests {
                 profile: "permissive".to_string(),
                 allowed_domains: vec!["github.com".to_string()],
             }),
+            notifications: None,
+            security: None,
+            status: None,
+            templates: Default::default(),
+            cleanup: None,
+            vcs: None,
+            ui: None,
         };
 
         let project_config = Some(super::super::ProjectConfig {
@@ -459,6 +640,8 @@ mod tests {
                 allowed_domains: vec!["api.internal.com".to_string(), "github.com".to_string()],
             }),
             ide: None,
+            git: None,
+            templates: Default::default(),
         });
 
         let merged = ConfigManager::merge_configs(user_config, project_config);
@@ -490,6 +673,7 @@ mod tests {
                     name: "cursor".to_string(),
                     command: "cursor".to_string(),
                 },
+                remote: None,
             },
             directories: default_directory_config(),
             git: default_git_config(),
@@ -497,6 +681,13 @@ mod tests {
             docker: None,
             setup_script: None,
             sandbox: None,
+            notifications: None,
+            security: None,
+            status: None,
+            templates: Default::default(),
+            cleanup: None,
+            vcs: None,
+            ui: None,
         };
 
         let project_config = Some(super::super::ProjectConfig {
@@ -504,6 +695,8 @@ mod tests {
             ide: Some(crate::config::ProjectIdeConfig {
                 preferred: Some("claude".to_string()),
             }),
+            git: None,
+            templates: Default::default(),
         });
 
         let merged = ConfigManager::merge_configs(user_config, project_config);
@@ -514,6 +707,36 @@ mod tests {
         assert_eq!(merged.ide.command, "cursor");
     }
 
+    #[test]
+    fn test_merge_configs_project_templates_replace_user_templates() {
+        use super::super::defaults::default_config;
+        use super::super::templates::SessionTemplate;
+
+        let template = |base: &str| SessionTemplate {
+            base: Some(base.to_string()),
+            ..Default::default()
+        };
+        let mut user_config = default_config();
+        user_config
+            .templates
+            .insert("bugfix".to_string(), template("main"));
+        user_config
+            .templates
+            .insert("spike".to_string(), template("develop"));
+
+        let project_config = Some(super::super::ProjectConfig {
+            sandbox: None,
+            ide: None,
+            git: None,
+            templates: [("bugfix".to_string(), template("release"))].into(),
+        });
+
+        let merged = ConfigManager::merge_configs(user_config, project_config);
+        assert_eq!(merged.templates.len(), 2);
+        assert_eq!(merged.templates["bugfix"].base.as_deref(), Some("release"));
+        assert_eq!(merged.templates["spike"].base.as_deref(), Some("develop"));
+    }
+
     #[test]
     fn test_merge_configs_no_project() {
         use super::super::defaults::default_config;
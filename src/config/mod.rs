@@ -1,16 +1,31 @@
 use serde::{Deserialize, Serialize};
 
+pub mod concurrent;
 pub mod defaults;
+pub mod doctor;
+pub mod keys;
 pub mod manager;
 pub mod migration;
+pub mod overrides;
 pub mod path;
+pub mod portable;
+pub mod state_dir;
+pub mod templates;
 pub mod validation;
 pub mod wizard;
 
 pub use manager::ConfigManager;
 pub use wizard::{run_config_wizard, run_quick_setup};
 
+use crate::core::git::apply::ApplyWhitespace;
+use crate::core::git::MergeMode;
+use crate::core::idle::IdleAction;
 use crate::core::sandbox::SandboxConfig;
+use std::collections::BTreeMap;
+use templates::SessionTemplate;
+
+/// Minutes between the monitor's fetches when `git.auto_fetch_interval_minutes` is unset
+pub const DEFAULT_AUTO_FETCH_INTERVAL_MINUTES: u64 = 5;
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct ProjectConfig {
@@ -18,6 +33,19 @@ pub struct ProjectConfig {
     pub sandbox: Option<SandboxConfig>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ide: Option<ProjectIdeConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub git: Option<ProjectGitConfig>,
+    /// Session templates shared with the team; they replace user templates of the same name
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub templates: BTreeMap<String, SessionTemplate>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ProjectGitConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author_email: Option<String>,
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -38,6 +66,21 @@ pub struct Config {
     pub setup_script: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sandbox: Option<SandboxConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notifications: Option<NotificationConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub security: Option<SecurityConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<StatusConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cleanup: Option<CleanupConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vcs: Option<VcsConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ui: Option<UiConfig>,
+    /// Named option bundles for `para start --template`
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub templates: BTreeMap<String, SessionTemplate>,
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -46,6 +89,35 @@ pub struct IdeConfig {
     pub command: String,
     pub user_data_dir: Option<String>,
     pub wrapper: WrapperConfig,
+    /// Open the wrapper IDE on another machine, connected back to this host
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remote: Option<RemoteConfig>,
+}
+
+/// How the IDE window reaches the host para runs on
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct RemoteConfig {
+    /// SSH host as the IDE's remote extension knows it, e.g. an `~/.ssh/config` alias
+    pub host: String,
+    #[serde(default)]
+    pub mode: RemoteMode,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum RemoteMode {
+    /// VS Code Remote-SSH and Cursor's equivalent
+    #[default]
+    SshRemote,
+}
+
+impl RemoteConfig {
+    /// Remote authority passed to `--remote`, e.g. `ssh-remote+devbox`
+    pub fn authority(&self) -> String {
+        match self.mode {
+            RemoteMode::SshRemote => format!("ssh-remote+{}", self.host),
+        }
+    }
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -59,6 +131,46 @@ pub struct WrapperConfig {
 pub struct DirectoryConfig {
     pub subtrees_dir: String,
     pub state_dir: String,
+    /// Where relative directories go for a bare repository, which has no
+    /// working tree to hold them; `{repo}` stands for its name without `.git`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bare_root: Option<String>,
+    /// How session worktrees are arranged below `subtrees_dir`
+    #[serde(default, skip_serializing_if = "WorktreeLayout::is_default")]
+    pub layout: WorktreeLayout,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum WorktreeLayout {
+    /// `<subtrees_dir>/<session>`
+    #[default]
+    Flat,
+    /// `<subtrees_dir>/<branch_prefix>/<session>`
+    ByPrefix,
+}
+
+impl WorktreeLayout {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl DirectoryConfig {
+    /// Strip trailing slashes and unify separators so paths compare reliably
+    pub fn normalize(&mut self) {
+        self.subtrees_dir = validation::normalize_directory(&self.subtrees_dir);
+        self.state_dir = validation::normalize_directory(&self.state_dir);
+    }
+
+    /// Directory relative `subtrees_dir` and `state_dir` are taken from for
+    /// the repository at `repo_root`
+    pub fn work_root(
+        &self,
+        repo_root: &std::path::Path,
+    ) -> crate::utils::Result<std::path::PathBuf> {
+        state_dir::work_root(repo_root, self.bare_root.as_deref())
+    }
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -66,6 +178,43 @@ pub struct GitConfig {
     pub branch_prefix: String,
     pub auto_stage: bool,
     pub auto_commit: bool,
+    /// Commit author name set on each new session worktree
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author_name: Option<String>,
+    /// Commit author email set on each new session worktree
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author_email: Option<String>,
+    /// Branches finish and cancel refuse to operate on, in addition to the default branch
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub protected_branches: Vec<String>,
+    /// Minutes between automatic WIP checkpoints in session worktrees; only
+    /// used together with `auto_commit`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_commit_interval_minutes: Option<u64>,
+    /// Merge mode recorded on new sessions that don't pass `--merge-mode`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_merge_mode: Option<MergeMode>,
+    /// Seconds a git command may run before para kills it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub command_timeout_secs: Option<u64>,
+    /// Remote that `--fetch` uses when neither `--remote` nor `--base` name one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_remote: Option<String>,
+    /// Log every git command that changes the repository to `<state_dir>/audit/`
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub audit_log: bool,
+    /// Fetch the base branch before starting sessions and periodically in the monitor
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub auto_fetch: bool,
+    /// Minutes between the monitor's automatic fetches
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_fetch_interval_minutes: Option<u64>,
+    /// `--whitespace` action for applying patches and integrating stacked sessions
+    #[serde(default, skip_serializing_if = "ApplyWhitespace::is_default")]
+    pub apply_whitespace: ApplyWhitespace,
+    /// Commits behind its base before the monitor marks a session as behind
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub behind_warning_commits: Option<usize>,
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -73,6 +222,37 @@ pub struct SessionConfig {
     pub default_name_format: String,
     pub preserve_on_finish: bool,
     pub auto_cleanup_days: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retention: Option<RetentionConfig>,
+    /// Glob patterns of untracked files copied from the main worktree into new sessions
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub copy_untracked: Vec<String>,
+    /// Hours without activity after which a session counts as idle
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub idle_alert_hours: Option<u64>,
+    /// What the daemon does with idle sessions
+    #[serde(default, skip_serializing_if = "IdleAction::is_default")]
+    pub idle_action: IdleAction,
+    /// List files with recorded merge conflicts in CLAUDE.local.md of new sessions
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub conflict_hotspots: bool,
+    /// Shell commands run in the worktree by every `para resume`, before `--run`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub on_resume: Vec<String>,
+}
+
+/// Limits applied by `para gc` to files in the state directory
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct RetentionConfig {
+    /// Maximum number of entries kept in each JSONL log
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_entries: Option<usize>,
+    /// JSONL entries older than this many days are dropped
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_age_days: Option<u32>,
+    /// Size in bytes after which para.log is rotated
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_max_bytes: Option<u64>,
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -83,6 +263,63 @@ pub struct DockerConfig {
     pub default_image: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub forward_env_keys: Option<Vec<String>>,
+    /// Minutes between automatic orphaned container cleanups (default 30)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cleanup_interval_minutes: Option<u64>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct NotificationConfig {
+    /// Shell command run (via `sh -c`) when a notable session event occurs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>,
+    /// Seconds before a running notification command is killed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout_secs: Option<u64>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct SecurityConfig {
+    /// Reject `--dangerously-skip-permissions` for every command
+    #[serde(default)]
+    pub forbid_skip_permissions: bool,
+    /// Refuse prompts that look like they contain credentials instead of warning
+    #[serde(default)]
+    pub block_secrets_in_prompts: bool,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct StatusConfig {
+    /// Minutes after which an agent status is shown as stale (default 60)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stale_after_minutes: Option<u64>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct CleanupConfig {
+    /// Kinds `para clean --force` may remove, e.g. `stale_branch`; all when unset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled_kinds: Option<Vec<String>>,
+    /// Days trashed items are kept before a clean deletes them (default 7)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trash_retention_days: Option<u32>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct VcsConfig {
+    /// Version control backend; only `git` for now
+    #[serde(default)]
+    pub backend: crate::core::vcs::VcsKind,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct UiConfig {
+    /// Glyphs in front of messages: `emoji`, `ascii` or `none`
+    #[serde(default)]
+    pub theme: crate::ui::theme::ThemePreset,
+    /// Leave out the note printed by `para integrate` and `para continue`
+    #[serde(default)]
+    pub hide_deprecations: bool,
 }
 
 pub type Result<T> = std::result::Result<T, ConfigError>;
@@ -92,6 +329,8 @@ pub enum ConfigError {
     Io(std::io::Error),
     Json(serde_json::Error),
     Validation(String),
+    /// Keys another process changed while the config was being edited
+    ConcurrentModification(Vec<String>),
 }
 
 impl std::fmt::Display for ConfigError {
@@ -100,6 +339,13 @@ impl std::fmt::Display for ConfigError {
             ConfigError::Io(e) => write!(f, "IO error: {e}"),
             ConfigError::Json(e) => write!(f, "JSON error: {e}"),
             ConfigError::Validation(msg) => write!(f, "Validation error: {msg}"),
+            ConfigError::ConcurrentModification(keys) => write!(
+                f,
+                "The configuration was changed by another process while it was being edited \
+                 (conflicting keys: {}). Nothing was saved; run the command again to edit \
+                 the current configuration",
+                keys.join(", ")
+            ),
         }
     }
 }
@@ -148,6 +394,43 @@ impl Config {
         &self.directories.state_dir
     }
 
+    /// Directories of the repository at `repo_root` that para creates and may
+    /// delete things in: the subtrees directory and the state directory
+    pub fn owned_directories(&self, repo_root: &std::path::Path) -> Vec<std::path::PathBuf> {
+        let root = self
+            .directories
+            .work_root(repo_root)
+            .unwrap_or_else(|_| repo_root.to_path_buf());
+        vec![
+            root.join(&self.directories.subtrees_dir),
+            root.join(&self.directories.state_dir),
+        ]
+    }
+
+    /// Where the worktrees of sessions in the repository at `repo_root` go
+    pub fn subtrees_path(
+        &self,
+        repo_root: &std::path::Path,
+    ) -> crate::utils::Result<std::path::PathBuf> {
+        Ok(self
+            .directories
+            .work_root(repo_root)?
+            .join(&self.directories.subtrees_dir))
+    }
+
+    /// Absolute state directory of the repository containing the current directory
+    pub fn resolved_state_dir(&self) -> std::path::PathBuf {
+        state_dir::resolve_state_dir(
+            &self.directories.state_dir,
+            self.directories.bare_root.as_deref(),
+            None,
+        )
+    }
+
+    pub fn template(&self, name: &str) -> Option<&SessionTemplate> {
+        self.templates.get(name)
+    }
+
     pub fn should_auto_stage(&self) -> bool {
         self.git.auto_stage
     }
@@ -166,6 +449,99 @@ impl Config {
             .and_then(|d| d.default_image.as_deref())
     }
 
+    pub fn get_container_cleanup_interval(&self) -> std::time::Duration {
+        const DEFAULT_CLEANUP_INTERVAL_MINUTES: u64 = 30;
+
+        let minutes = self
+            .docker
+            .as_ref()
+            .and_then(|d| d.cleanup_interval_minutes)
+            .unwrap_or(DEFAULT_CLEANUP_INTERVAL_MINUTES);
+        std::time::Duration::from_secs(minutes.saturating_mul(60))
+    }
+
+    /// Interval for automatic WIP checkpoints, when `git.auto_commit` and
+    /// `git.auto_commit_interval_minutes` are both set
+    pub fn get_auto_checkpoint_interval(&self) -> Option<u64> {
+        self.git
+            .auto_commit_interval_minutes
+            .filter(|minutes| self.git.auto_commit && *minutes > 0)
+    }
+
+    /// Interval between the monitor's automatic fetches, when `git.auto_fetch` is on
+    pub fn get_auto_fetch_interval(&self) -> Option<std::time::Duration> {
+        let minutes = self
+            .git
+            .auto_fetch_interval_minutes
+            .unwrap_or(DEFAULT_AUTO_FETCH_INTERVAL_MINUTES)
+            .max(1);
+        self.git
+            .auto_fetch
+            .then(|| std::time::Duration::from_secs(minutes * 60))
+    }
+
+    pub fn get_default_merge_mode(&self) -> MergeMode {
+        self.git.default_merge_mode.unwrap_or_default()
+    }
+
+    pub fn get_notification_command(&self) -> Option<&str> {
+        self.notifications
+            .as_ref()
+            .and_then(|n| n.command.as_deref())
+            .filter(|c| !c.trim().is_empty())
+    }
+
+    pub fn forbids_skip_permissions(&self) -> bool {
+        self.security
+            .as_ref()
+            .is_some_and(|s| s.forbid_skip_permissions)
+    }
+
+    pub fn blocks_secrets_in_prompts(&self) -> bool {
+        self.security
+            .as_ref()
+            .is_some_and(|s| s.block_secrets_in_prompts)
+    }
+
+    pub fn get_behind_warning_commits(&self) -> usize {
+        self.git
+            .behind_warning_commits
+            .unwrap_or(crate::core::session::sync::DEFAULT_BEHIND_WARNING_COMMITS)
+    }
+
+    pub fn get_status_stale_after_minutes(&self) -> u64 {
+        self.status
+            .as_ref()
+            .and_then(|s| s.stale_after_minutes)
+            .unwrap_or(crate::core::status::DEFAULT_STALE_AFTER_MINUTES)
+    }
+
+    /// Kinds forced cleanups are limited to, if the config restricts them
+    pub fn get_cleanup_enabled_kinds(&self) -> Option<&[String]> {
+        self.cleanup
+            .as_ref()
+            .and_then(|c| c.enabled_kinds.as_deref())
+    }
+
+    pub fn get_trash_retention_days(&self) -> u32 {
+        self.cleanup
+            .as_ref()
+            .and_then(|c| c.trash_retention_days)
+            .unwrap_or(crate::core::trash::DEFAULT_RETENTION_DAYS)
+    }
+
+    pub fn get_vcs_backend(&self) -> crate::core::vcs::VcsKind {
+        self.vcs.as_ref().map(|v| v.backend).unwrap_or_default()
+    }
+
+    pub fn get_ui_theme(&self) -> crate::ui::theme::ThemePreset {
+        self.ui.as_ref().map(|u| u.theme).unwrap_or_default()
+    }
+
+    pub fn should_hide_deprecations(&self) -> bool {
+        self.ui.as_ref().is_some_and(|u| u.hide_deprecations)
+    }
+
     pub fn get_forward_env_keys(&self) -> Vec<String> {
         // Default API keys that are commonly used
         const DEFAULT_KEYS: &[&str] = &[
@@ -210,24 +586,52 @@ mod tests {
                     name: "test-wrapper".to_string(),
                     command: "echo".to_string(),
                 },
+                remote: None,
             },
             directories: DirectoryConfig {
                 subtrees_dir: "custom/subtrees".to_string(),
                 state_dir: "custom/state".to_string(),
+                bare_root: None,
+                layout: Default::default(),
             },
             git: GitConfig {
                 branch_prefix: "feature".to_string(),
                 auto_stage: false,
                 auto_commit: true,
+                author_email: None,
+                author_name: None,
+                protected_branches: Vec::new(),
+                auto_commit_interval_minutes: None,
+                default_merge_mode: None,
+                command_timeout_secs: None,
+                audit_log: false,
+                default_remote: None,
+                auto_fetch_interval_minutes: None,
+                auto_fetch: false,
+                apply_whitespace: Default::default(),
+                behind_warning_commits: None,
             },
             session: SessionConfig {
                 default_name_format: "%Y-%m-%d".to_string(),
                 preserve_on_finish: true,
                 auto_cleanup_days: Some(14),
+                retention: None,
+                copy_untracked: Vec::new(),
+                idle_action: Default::default(),
+                idle_alert_hours: None,
+                conflict_hotspots: false,
+                on_resume: Vec::new(),
             },
             docker: None,
             setup_script: None,
             sandbox: None,
+            notifications: None,
+            security: None,
+            status: None,
+            templates: Default::default(),
+            cleanup: None,
+            vcs: None,
+            ui: None,
         };
 
         assert_eq!(config.get_branch_prefix(), "feature");
@@ -250,6 +654,7 @@ mod tests {
             setup_script: None,
             default_image: None,
             forward_env_keys: None,
+            cleanup_interval_minutes: None,
         });
         assert_eq!(config.get_docker_image(), None);
 
@@ -258,6 +663,7 @@ mod tests {
             setup_script: None,
             default_image: Some("custom:latest".to_string()),
             forward_env_keys: None,
+            cleanup_interval_minutes: None,
         });
         assert_eq!(config.get_docker_image(), Some("custom:latest"));
     }
@@ -279,6 +685,7 @@ mod tests {
             setup_script: None,
             default_image: None,
             forward_env_keys: Some(vec!["CUSTOM_KEY".to_string(), "ANOTHER_KEY".to_string()]),
+            cleanup_interval_minutes: None,
         });
         let keys = config.get_forward_env_keys();
         assert_eq!(keys.len(), 2);
@@ -321,24 +728,52 @@ mod tests {
                     name: String::new(),
                     command: String::new(),
                 },
+                remote: None,
             },
             directories: DirectoryConfig {
                 subtrees_dir: "subtrees".to_string(),
                 state_dir: "state".to_string(),
+                bare_root: None,
+                layout: Default::default(),
             },
             git: GitConfig {
                 branch_prefix: "test".to_string(),
                 auto_stage: true,
                 auto_commit: false,
+                author_email: None,
+                author_name: None,
+                protected_branches: Vec::new(),
+                auto_commit_interval_minutes: None,
+                default_merge_mode: None,
+                command_timeout_secs: None,
+                audit_log: false,
+                default_remote: None,
+                auto_fetch_interval_minutes: None,
+                auto_fetch: false,
+                apply_whitespace: Default::default(),
+                behind_warning_commits: None,
             },
             session: SessionConfig {
                 default_name_format: "%Y%m%d".to_string(),
                 preserve_on_finish: false,
                 auto_cleanup_days: Some(7),
+                retention: None,
+                copy_untracked: Vec::new(),
+                idle_action: Default::default(),
+                idle_alert_hours: None,
+                conflict_hotspots: false,
+                on_resume: Vec::new(),
             },
             docker: None,
             setup_script: None,
             sandbox: None,
+            notifications: None,
+            security: None,
+            status: None,
+            templates: Default::default(),
+            cleanup: None,
+            vcs: None,
+            ui: None,
         };
         assert!(valid_config.validate().is_ok());
 
@@ -363,24 +798,52 @@ mod tests {
                     name: String::new(),
                     command: String::new(),
                 },
+                remote: None,
             },
             directories: DirectoryConfig {
                 subtrees_dir: "subtrees".to_string(),
                 state_dir: "state".to_string(),
+                bare_root: None,
+                layout: Default::default(),
             },
             git: GitConfig {
                 branch_prefix: "test".to_string(),
                 auto_stage: true,
                 auto_commit: false,
+                author_email: None,
+                author_name: None,
+                protected_branches: Vec::new(),
+                auto_commit_interval_minutes: None,
+                default_merge_mode: None,
+                command_timeout_secs: None,
+                audit_log: false,
+                default_remote: None,
+                auto_fetch_interval_minutes: None,
+                auto_fetch: false,
+                apply_whitespace: Default::default(),
+                behind_warning_commits: None,
             },
             session: SessionConfig {
                 default_name_format: "%Y%m%d".to_string(),
                 preserve_on_finish: false,
                 auto_cleanup_days: None,
+                retention: None,
+                copy_untracked: Vec::new(),
+                idle_action: Default::default(),
+                idle_alert_hours: None,
+                conflict_hotspots: false,
+                on_resume: Vec::new(),
             },
             docker: None,
             setup_script: None,
             sandbox: None,
+            notifications: None,
+            security: None,
+            status: None,
+            templates: Default::default(),
+            cleanup: None,
+            vcs: None,
+            ui: None,
         };
         assert!(config_wrapper_disabled.validate().is_ok());
 
@@ -420,6 +883,7 @@ mod tests {
                     name: String::new(),
                     command: String::new(),
                 },
+                remote: None,
             },
             directories: defaults::default_directory_config(),
             git: defaults::default_git_config(),
@@ -427,6 +891,13 @@ mod tests {
             docker: None,
             setup_script: None,
             sandbox: None,
+            notifications: None,
+            security: None,
+            status: None,
+            templates: Default::default(),
+            cleanup: None,
+            vcs: None,
+            ui: None,
         };
         let config_json = serde_json::to_string_pretty(&test_config).unwrap();
         std::fs::write(&custom_config_path, config_json).unwrap();
@@ -457,6 +928,7 @@ mod tests {
                     name: String::new(),
                     command: String::new(),
                 },
+                remote: None,
             },
             directories: defaults::default_directory_config(),
             git: defaults::default_git_config(),
@@ -464,6 +936,13 @@ mod tests {
             docker: None,
             setup_script: None,
             sandbox: None,
+            notifications: None,
+            security: None,
+            status: None,
+            templates: Default::default(),
+            cleanup: None,
+            vcs: None,
+            ui: None,
         };
 
         // Test 1: Manually save config and verify it can be loaded
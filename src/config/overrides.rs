@@ -0,0 +1,226 @@
+//! One-off config changes from `para -c key=value <command>`
+//!
+//! Overrides use the dotted key paths of `para config set` and are applied to
+//! the loaded configuration after the user config, project config and
+//! `PARA_STATE_DIR` have been merged. They only live in memory and are never
+//! written back to the config file.
+
+use super::defaults::default_config;
+use super::keys;
+use super::{Config, ConfigError, Result};
+use serde_json::Value;
+use std::str::FromStr;
+
+/// A single `key=value` given with `-c/--config-override`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigOverride {
+    pub key: String,
+    pub value: String,
+}
+
+impl FromStr for ConfigOverride {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let (key, value) = s
+            .split_once('=')
+            .ok_or_else(|| format!("expected key=value, got '{s}'"))?;
+        let key = key.trim();
+        if key.is_empty() || key.split('.').any(str::is_empty) {
+            return Err(format!("invalid config key '{key}'"));
+        }
+        Ok(Self {
+            key: key.to_string(),
+            value: value.to_string(),
+        })
+    }
+}
+
+/// Convert a command-line value with simple heuristics: booleans, then
+/// numbers, otherwise a string
+pub fn parse_config_value(value: &str) -> Value {
+    match value {
+        "true" => Value::Bool(true),
+        "false" => Value::Bool(false),
+        _ => {
+            if let Ok(int_val) = value.parse::<i64>() {
+                Value::Number(serde_json::Number::from(int_val))
+            } else if let Ok(float_val) = value.parse::<f64>() {
+                serde_json::Number::from_f64(float_val)
+                    .map(Value::Number)
+                    .unwrap_or_else(|| Value::String(value.to_string()))
+            } else {
+                Value::String(value.to_string())
+            }
+        }
+    }
+}
+
+/// Apply `overrides` in order and validate the result
+pub fn apply_overrides(config: &Config, overrides: &[ConfigOverride]) -> Result<Config> {
+    if overrides.is_empty() {
+        return Ok(config.clone());
+    }
+
+    let mut json = serde_json::to_value(config)?;
+    for config_override in overrides {
+        set_override(&mut json, config_override)?;
+    }
+
+    let updated: Config = serde_json::from_value(json.clone())
+        .map_err(|e| ConfigError::Validation(format!("Invalid config override: {e}")))?;
+
+    // Keys serde does not know are dropped on the way through, so anything
+    // missing after a round trip was not a config key
+    let round_trip = serde_json::to_value(&updated)?;
+    for config_override in overrides {
+        if lookup(&round_trip, &config_override.key).is_none() {
+            return Err(unknown_key(&config_override.key));
+        }
+    }
+
+    let mut checked = updated.clone();
+    if checked.directories.state_dir == config.directories.state_dir {
+        // Accepted when loading, and `--state-dir` may be absolute unlike the file value
+        checked.directories.state_dir = default_config().directories.state_dir;
+    }
+    checked.validate()?;
+    Ok(updated)
+}
+
+fn set_override(json: &mut Value, config_override: &ConfigOverride) -> Result<()> {
+    let key = config_override.key.as_str();
+    let parts: Vec<&str> = key.split('.').collect();
+    let (last, parents) = parts.split_last().expect("keys are never empty");
+
+    let mut current = json;
+    for part in parents {
+        let object = current.as_object_mut().ok_or_else(|| unknown_key(key))?;
+        // Optional sections that are unset are missing from the serialized config
+        current = object
+            .entry(part.to_string())
+            .or_insert_with(|| Value::Object(Default::default()));
+        if current.is_null() {
+            *current = Value::Object(Default::default());
+        }
+    }
+
+    let object = current.as_object_mut().ok_or_else(|| unknown_key(key))?;
+    let value = match object.get(*last) {
+        Some(existing) => coerce(key, existing, &config_override.value)?,
+        None => parse_config_value(&config_override.value),
+    };
+    object.insert(last.to_string(), value);
+    Ok(())
+}
+
+/// Parse `raw` as the type of the value it replaces
+fn coerce(key: &str, existing: &Value, raw: &str) -> Result<Value> {
+    let mismatch = |expected: &str| {
+        ConfigError::Validation(format!(
+            "Config key '{key}' expects {expected}, got '{raw}'"
+        ))
+    };
+
+    match existing {
+        Value::Bool(_) => match raw {
+            "true" => Ok(Value::Bool(true)),
+            "false" => Ok(Value::Bool(false)),
+            _ => Err(mismatch("true or false")),
+        },
+        Value::Number(_) => match parse_config_value(raw) {
+            number @ Value::Number(_) => Ok(number),
+            _ => Err(mismatch("a number")),
+        },
+        Value::String(_) => Ok(Value::String(raw.to_string())),
+        Value::Array(_) => Ok(Value::Array(
+            raw.split(',')
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+                .map(|item| Value::String(item.to_string()))
+                .collect(),
+        )),
+        Value::Object(object) => {
+            let keys: Vec<&str> = object.keys().map(String::as_str).collect();
+            Err(ConfigError::Validation(format!(
+                "Config key '{key}' is a section; set one of its keys instead: {}",
+                keys.join(", ")
+            )))
+        }
+        Value::Null => Ok(parse_config_value(raw)),
+    }
+}
+
+fn lookup<'a>(json: &'a Value, key: &str) -> Option<&'a Value> {
+    key.split('.')
+        .try_fold(json, |current, part| current.get(part))
+}
+
+fn unknown_key(key: &str) -> ConfigError {
+    ConfigError::Validation(keys::unknown_key_message(key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::test_helpers::create_test_config;
+
+    fn overrides(values: &[&str]) -> Vec<ConfigOverride> {
+        values.iter().map(|v| v.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_parse_override() {
+        let parsed: ConfigOverride = "git.branch_prefix=team/feat=x".parse().unwrap();
+        assert_eq!(parsed.key, "git.branch_prefix");
+        assert_eq!(parsed.value, "team/feat=x");
+
+        assert!("git.auto_stage".parse::<ConfigOverride>().is_err());
+        assert!("=true".parse::<ConfigOverride>().is_err());
+        assert!("git..auto_stage=true".parse::<ConfigOverride>().is_err());
+    }
+
+    #[test]
+    fn test_apply_overrides_changes_only_the_copy() {
+        let config = create_test_config();
+        let updated = apply_overrides(
+            &config,
+            &overrides(&[
+                "git.auto_stage=false",
+                "git.branch_prefix=spike",
+                "git.author_name=Release Bot",
+            ]),
+        )
+        .unwrap();
+
+        assert!(!updated.git.auto_stage);
+        assert_eq!(updated.git.branch_prefix, "spike");
+        assert_eq!(updated.git.author_name.as_deref(), Some("Release Bot"));
+        assert!(config.git.auto_stage);
+    }
+
+    #[test]
+    fn test_apply_overrides_rejects_unknown_keys_and_bad_types() {
+        let config = create_test_config();
+
+        let err = apply_overrides(&config, &overrides(&["git.auto_stag=false"]))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Unknown config key 'git.auto_stag'"), "{err}");
+
+        let err = apply_overrides(&config, &overrides(&["nope.key=1"]))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Unknown config key 'nope.key'"), "{err}");
+
+        let err = apply_overrides(&config, &overrides(&["git.auto_stage=maybe"]))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("expects true or false"), "{err}");
+
+        let err = apply_overrides(&config, &overrides(&["git=false"]))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("is a section"), "{err}");
+    }
+}
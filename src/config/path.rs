@@ -1,2 +1,332 @@
-// This module is reserved for future config path injection functionality
-// For now, we use environment variables in tests via TestEnvironmentGuard
+//! Where the user config lives
+//!
+//! Every read and write of the user config goes through [`config_file_path`],
+//! which picks the first of:
+//!
+//! 1. the global `--config <PATH>` flag
+//! 2. the `PARA_CONFIG_PATH` environment variable
+//! 3. `$XDG_CONFIG_HOME/para/config.json`
+//! 4. `~/.config/para/config.json`
+//!
+//! A config that only exists where older versions kept it (`~/.config/para`
+//! while `XDG_CONFIG_HOME` points elsewhere, or the platform config directory
+//! such as `~/Library/Application Support/para` on macOS) is copied to the
+//! XDG location once, and the user is told about it.
+
+use std::ffi::OsString;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// Environment variable naming the config file
+pub const CONFIG_PATH_ENV: &str = "PARA_CONFIG_PATH";
+
+const CONFIG_FILE: &str = "config.json";
+
+/// The `--config` flag of this process, kept apart from `PARA_CONFIG_PATH` so
+/// `para config path` can tell the two sources apart
+static CONFIG_FLAG: OnceLock<PathBuf> = OnceLock::new();
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigSource {
+    /// `--config <PATH>`
+    Flag,
+    /// `PARA_CONFIG_PATH`
+    Env,
+    /// `$XDG_CONFIG_HOME/para/config.json`
+    XdgConfigHome,
+    /// `~/.config/para/config.json`
+    Home,
+    /// A location used by older versions that has not been copied yet
+    Legacy,
+}
+
+impl fmt::Display for ConfigSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            ConfigSource::Flag => "--config flag",
+            ConfigSource::Env => CONFIG_PATH_ENV,
+            ConfigSource::XdgConfigHome => "XDG_CONFIG_HOME",
+            ConfigSource::Home => "default location",
+            ConfigSource::Legacy => "legacy location",
+        };
+        f.write_str(name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigLocation {
+    pub path: PathBuf,
+    pub source: ConfigSource,
+    /// Where a legacy config is copied to; set when `source` is `Legacy`
+    pub migrate_to: Option<PathBuf>,
+}
+
+/// The inputs of the resolution, read from the process environment by
+/// [`ConfigEnv::current`] and built directly in tests
+#[derive(Debug, Clone, Default)]
+pub struct ConfigEnv {
+    pub flag: Option<PathBuf>,
+    pub env_path: Option<PathBuf>,
+    pub xdg_config_home: Option<PathBuf>,
+    pub home: Option<PathBuf>,
+    /// Platform config directory for para from older versions
+    pub platform_config_dir: Option<PathBuf>,
+}
+
+impl ConfigEnv {
+    pub fn current() -> Self {
+        let non_empty =
+            |value: Option<OsString>| value.filter(|v| !v.is_empty()).map(PathBuf::from);
+        Self {
+            flag: CONFIG_FLAG.get().cloned(),
+            env_path: non_empty(std::env::var_os(CONFIG_PATH_ENV)),
+            xdg_config_home: non_empty(std::env::var_os("XDG_CONFIG_HOME")),
+            home: non_empty(std::env::var_os("HOME")),
+            platform_config_dir: directories::ProjectDirs::from("", "", "para")
+                .map(|dirs| dirs.config_dir().to_path_buf()),
+        }
+    }
+
+    /// The XDG config file and whether it came from `XDG_CONFIG_HOME`
+    fn xdg_path(&self) -> (PathBuf, ConfigSource) {
+        // The spec says relative values are invalid and must be ignored
+        match self
+            .xdg_config_home
+            .as_ref()
+            .filter(|dir| dir.is_absolute())
+        {
+            Some(dir) => (
+                dir.join("para").join(CONFIG_FILE),
+                ConfigSource::XdgConfigHome,
+            ),
+            None => (self.home_path(), ConfigSource::Home),
+        }
+    }
+
+    fn home_path(&self) -> PathBuf {
+        self.home
+            .clone()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join(".config")
+            .join("para")
+            .join(CONFIG_FILE)
+    }
+
+    fn legacy_paths(&self) -> Vec<PathBuf> {
+        let mut paths = vec![self.home_path()];
+        if let Some(dir) = &self.platform_config_dir {
+            paths.push(dir.join(CONFIG_FILE));
+        }
+        paths
+    }
+
+    /// Resolve the config location without touching the filesystem beyond
+    /// checking which files exist
+    pub fn resolve(&self) -> ConfigLocation {
+        let explicit = |path: &PathBuf, source| ConfigLocation {
+            path: absolute(path),
+            source,
+            migrate_to: None,
+        };
+        if let Some(path) = &self.flag {
+            return explicit(path, ConfigSource::Flag);
+        }
+        if let Some(path) = &self.env_path {
+            return explicit(path, ConfigSource::Env);
+        }
+
+        let (xdg_path, source) = self.xdg_path();
+        if !xdg_path.exists() {
+            let legacy = self
+                .legacy_paths()
+                .into_iter()
+                .find(|path| *path != xdg_path && path.is_file());
+            if let Some(path) = legacy {
+                return ConfigLocation {
+                    path,
+                    source: ConfigSource::Legacy,
+                    migrate_to: Some(xdg_path),
+                };
+            }
+        }
+        ConfigLocation {
+            path: xdg_path,
+            source,
+            migrate_to: None,
+        }
+    }
+
+    /// Resolve the config location, copying a legacy config to the XDG
+    /// location first. If the copy fails the legacy file keeps being used.
+    pub fn resolve_and_migrate(&self) -> ConfigLocation {
+        let location = self.resolve();
+        let Some(target) = location.migrate_to.clone() else {
+            return location;
+        };
+        match copy_config(&location.path, &target) {
+            Ok(()) => {
+                eprintln!(
+                    "📦 Copied your para config from {} to {}; the old file is no longer read",
+                    location.path.display(),
+                    target.display()
+                );
+                self.resolve()
+            }
+            Err(e) => {
+                eprintln!(
+                    "Warning: Failed to copy the para config from {} to {}: {e}",
+                    location.path.display(),
+                    target.display()
+                );
+                location
+            }
+        }
+    }
+}
+
+fn copy_config(from: &Path, to: &Path) -> std::io::Result<()> {
+    if let Some(parent) = to.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::copy(from, to).map(|_| ())
+}
+
+fn absolute(path: &Path) -> PathBuf {
+    if path.is_absolute() {
+        return path.to_path_buf();
+    }
+    std::env::current_dir()
+        .map(|cwd| cwd.join(path))
+        .unwrap_or_else(|_| path.to_path_buf())
+}
+
+/// Make `--config` the config file for this process and its children
+pub fn export_config_flag(path: &Path) {
+    let path = absolute(path);
+    std::env::set_var(CONFIG_PATH_ENV, &path);
+    let _ = CONFIG_FLAG.set(path);
+}
+
+/// The config file every read and write uses
+pub fn config_file_path() -> PathBuf {
+    ConfigEnv::current().resolve_and_migrate().path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn test_env(temp_dir: &TempDir) -> ConfigEnv {
+        ConfigEnv {
+            flag: Some(temp_dir.path().join("flag.json")),
+            env_path: Some(temp_dir.path().join("env.json")),
+            xdg_config_home: Some(temp_dir.path().join("xdg")),
+            home: Some(temp_dir.path().join("home")),
+            platform_config_dir: Some(temp_dir.path().join("platform").join("para")),
+        }
+    }
+
+    #[test]
+    fn test_resolution_precedence() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut env = test_env(&temp_dir);
+        let winner = |env: &ConfigEnv| {
+            let location = env.resolve();
+            (location.path, location.source)
+        };
+
+        assert_eq!(
+            winner(&env),
+            (temp_dir.path().join("flag.json"), ConfigSource::Flag)
+        );
+
+        env.flag = None;
+        assert_eq!(
+            winner(&env),
+            (temp_dir.path().join("env.json"), ConfigSource::Env)
+        );
+
+        env.env_path = None;
+        assert_eq!(
+            winner(&env),
+            (
+                temp_dir.path().join("xdg/para/config.json"),
+                ConfigSource::XdgConfigHome
+            )
+        );
+
+        env.xdg_config_home = Some(PathBuf::from("relative/xdg"));
+        assert_eq!(
+            winner(&env),
+            (
+                temp_dir.path().join("home/.config/para/config.json"),
+                ConfigSource::Home
+            )
+        );
+
+        env.xdg_config_home = None;
+        assert_eq!(
+            winner(&env),
+            (
+                temp_dir.path().join("home/.config/para/config.json"),
+                ConfigSource::Home
+            )
+        );
+    }
+
+    #[test]
+    fn test_legacy_config_is_copied_to_xdg_location() {
+        let temp_dir = TempDir::new().unwrap();
+        let env = ConfigEnv {
+            flag: None,
+            env_path: None,
+            ..test_env(&temp_dir)
+        };
+        let legacy = temp_dir.path().join("home/.config/para/config.json");
+        fs::create_dir_all(legacy.parent().unwrap()).unwrap();
+        fs::write(&legacy, r#"{"legacy": true}"#).unwrap();
+
+        let xdg = temp_dir.path().join("xdg/para/config.json");
+        let pending = env.resolve();
+        assert_eq!(pending.source, ConfigSource::Legacy);
+        assert_eq!(pending.path, legacy);
+        assert_eq!(pending.migrate_to.as_deref(), Some(xdg.as_path()));
+        assert!(!xdg.exists(), "resolve must not copy anything");
+
+        let location = env.resolve_and_migrate();
+        assert_eq!(location.path, xdg);
+        assert_eq!(location.source, ConfigSource::XdgConfigHome);
+        assert_eq!(fs::read_to_string(&xdg).unwrap(), r#"{"legacy": true}"#);
+        assert!(legacy.exists());
+
+        // An existing XDG config wins over every legacy file
+        fs::write(&xdg, r#"{"xdg": true}"#).unwrap();
+        assert_eq!(env.resolve_and_migrate().path, xdg);
+        assert_eq!(fs::read_to_string(&xdg).unwrap(), r#"{"xdg": true}"#);
+    }
+
+    #[test]
+    fn test_platform_config_dir_is_a_legacy_location() {
+        let temp_dir = TempDir::new().unwrap();
+        let env = ConfigEnv {
+            flag: None,
+            env_path: None,
+            xdg_config_home: None,
+            ..test_env(&temp_dir)
+        };
+        let platform = temp_dir.path().join("platform/para/config.json");
+        fs::create_dir_all(platform.parent().unwrap()).unwrap();
+        fs::write(&platform, "{}").unwrap();
+
+        let location = env.resolve_and_migrate();
+        assert_eq!(
+            location.path,
+            temp_dir.path().join("home/.config/para/config.json")
+        );
+        assert_eq!(location.source, ConfigSource::Home);
+        assert!(location.path.exists());
+    }
+}
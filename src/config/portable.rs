@@ -0,0 +1,372 @@
+//! Portable config files for `para config export` / `para config import`
+//!
+//! Exported configs replace machine-specific absolute paths with placeholders:
+//! paths inside the current repository become `$REPO/...` and paths inside the
+//! home directory become `~/...`. Import expands them again for the local
+//! machine. Values that look like credentials are never exported, and on
+//! import any setting missing from the file keeps its local value.
+
+use super::{Config, ConfigError, Result};
+use serde_json::{Map, Value};
+use std::path::{Path, PathBuf};
+
+pub const REPO_PLACEHOLDER: &str = "$REPO";
+pub const HOME_PLACEHOLDER: &str = "~";
+
+/// Key fragments treated as secrets, matched case-insensitively
+const SENSITIVE_KEY_PATTERNS: &[&str] = &[
+    "token",
+    "secret",
+    "password",
+    "passwd",
+    "api_key",
+    "apikey",
+    "credential",
+    "private_key",
+];
+
+/// Value prefixes of common API tokens
+const SENSITIVE_VALUE_PREFIXES: &[&str] = &["sk-", "ghp_", "gho_", "github_pat_", "xoxb-", "xoxp-"];
+
+/// Local directories that placeholders stand for
+#[derive(Debug, Clone, Default)]
+pub struct PortablePaths {
+    pub home: Option<PathBuf>,
+    pub repo: Option<PathBuf>,
+}
+
+impl PortablePaths {
+    /// Home directory and, when run inside a git repository, its main root
+    pub fn detect() -> Self {
+        Self {
+            home: directories::BaseDirs::new().map(|d| d.home_dir().to_path_buf()),
+            repo: crate::utils::get_main_repository_root().ok(),
+        }
+    }
+
+    fn to_portable(&self, value: &str) -> String {
+        let path = Path::new(value);
+        if !path.is_absolute() {
+            return value.to_string();
+        }
+
+        let candidates = [
+            (self.repo.as_deref(), REPO_PLACEHOLDER),
+            (self.home.as_deref(), HOME_PLACEHOLDER),
+        ];
+        for (base, placeholder) in candidates {
+            let Some(base) = base else { continue };
+            if let Ok(rest) = path.strip_prefix(base) {
+                return if rest.as_os_str().is_empty() {
+                    placeholder.to_string()
+                } else {
+                    format!("{placeholder}/{}", rest.display())
+                };
+            }
+        }
+        value.to_string()
+    }
+
+    fn expand(&self, value: &str) -> Result<String> {
+        let candidates = [
+            (REPO_PLACEHOLDER, self.repo.as_deref(), "a git repository"),
+            (HOME_PLACEHOLDER, self.home.as_deref(), "a home directory"),
+        ];
+        for (placeholder, base, needs) in candidates {
+            let rest = if value == placeholder {
+                Some("")
+            } else {
+                value
+                    .strip_prefix(placeholder)
+                    .and_then(|r| r.strip_prefix('/'))
+            };
+            let Some(rest) = rest else { continue };
+
+            let base = base.ok_or_else(|| {
+                ConfigError::Validation(format!(
+                    "'{value}' uses {placeholder}, which needs {needs} to expand"
+                ))
+            })?;
+            let expanded = if rest.is_empty() {
+                base.to_path_buf()
+            } else {
+                base.join(rest)
+            };
+            return Ok(expanded.to_string_lossy().to_string());
+        }
+        Ok(value.to_string())
+    }
+}
+
+/// A config prepared for export
+#[derive(Debug, Clone)]
+pub struct ExportedConfig {
+    pub json: Value,
+    /// Dotted paths of values left out because they look sensitive
+    pub excluded: Vec<String>,
+}
+
+/// Convert `config` into portable JSON
+pub fn export_config(config: &Config, paths: &PortablePaths) -> Result<ExportedConfig> {
+    let mut json = serde_json::to_value(config)?;
+    let mut excluded = Vec::new();
+    make_portable(&mut json, "", paths, &mut excluded);
+    Ok(ExportedConfig { json, excluded })
+}
+
+fn make_portable(value: &mut Value, path: &str, paths: &PortablePaths, excluded: &mut Vec<String>) {
+    match value {
+        Value::Object(map) => {
+            let keys: Vec<String> = map.keys().cloned().collect();
+            for key in keys {
+                let child_path = join_path(path, &key);
+                let sensitive =
+                    is_sensitive_key(&key) || map.get(&key).is_some_and(is_sensitive_value);
+                if sensitive {
+                    map.remove(&key);
+                    excluded.push(child_path);
+                } else if let Some(child) = map.get_mut(&key) {
+                    make_portable(child, &child_path, paths, excluded);
+                }
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                make_portable(item, path, paths, excluded);
+            }
+        }
+        Value::String(s) => *s = paths.to_portable(s),
+        _ => {}
+    }
+}
+
+fn is_sensitive_key(key: &str) -> bool {
+    let key = key.to_lowercase();
+    SENSITIVE_KEY_PATTERNS.iter().any(|p| key.contains(p))
+}
+
+fn is_sensitive_value(value: &Value) -> bool {
+    value
+        .as_str()
+        .is_some_and(|s| SENSITIVE_VALUE_PREFIXES.iter().any(|p| s.starts_with(p)))
+}
+
+/// Build the config that importing `imported` on top of `current` produces
+pub fn import_config(current: &Config, imported: Value, paths: &PortablePaths) -> Result<Config> {
+    if !imported.is_object() {
+        return Err(ConfigError::Validation(
+            "Imported config must be a JSON object".to_string(),
+        ));
+    }
+
+    let mut imported = imported;
+    expand_placeholders(&mut imported, paths)?;
+
+    let mut merged = serde_json::to_value(current)?;
+    merge_json(&mut merged, imported);
+    Ok(serde_json::from_value(merged)?)
+}
+
+fn expand_placeholders(value: &mut Value, paths: &PortablePaths) -> Result<()> {
+    match value {
+        Value::Object(map) => map
+            .values_mut()
+            .try_for_each(|v| expand_placeholders(v, paths)),
+        Value::Array(items) => items
+            .iter_mut()
+            .try_for_each(|v| expand_placeholders(v, paths)),
+        Value::String(s) => {
+            *s = paths.expand(s)?;
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Objects merge key by key, everything else is replaced
+fn merge_json(base: &mut Value, overlay: Value) {
+    match (base, overlay) {
+        (Value::Object(base), Value::Object(overlay)) => {
+            for (key, value) in overlay {
+                match base.get_mut(&key) {
+                    Some(existing) => merge_json(existing, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, overlay) => *base = overlay,
+    }
+}
+
+/// Human-readable differences between two configs, one line per changed setting
+pub fn diff_configs(old: &Config, new: &Config) -> Result<Vec<String>> {
+    let mut old_values = Map::new();
+    let mut new_values = Map::new();
+    flatten(&serde_json::to_value(old)?, "", &mut old_values);
+    flatten(&serde_json::to_value(new)?, "", &mut new_values);
+
+    let mut keys: Vec<&String> = old_values.keys().chain(new_values.keys()).collect();
+    keys.sort();
+    keys.dedup();
+
+    Ok(keys
+        .into_iter()
+        .filter_map(|key| match (old_values.get(key), new_values.get(key)) {
+            (Some(a), Some(b)) if a == b => None,
+            (Some(a), Some(b)) => Some(format!("~ {key}: {a} -> {b}")),
+            (Some(a), None) => Some(format!("- {key}: {a}")),
+            (None, Some(b)) => Some(format!("+ {key}: {b}")),
+            (None, None) => None,
+        })
+        .collect())
+}
+
+fn flatten(value: &Value, path: &str, out: &mut Map<String, Value>) {
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map {
+                flatten(child, &join_path(path, key), out);
+            }
+        }
+        Value::Null => {}
+        other => {
+            out.insert(path.to_string(), other.clone());
+        }
+    }
+}
+
+fn join_path(parent: &str, key: &str) -> String {
+    if parent.is_empty() {
+        key.to_string()
+    } else {
+        format!("{parent}.{key}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::test_helpers::create_test_config;
+
+    fn machine(home: &str, repo: &str) -> PortablePaths {
+        PortablePaths {
+            home: Some(PathBuf::from(home)),
+            repo: Some(PathBuf::from(repo)),
+        }
+    }
+
+    #[test]
+    fn test_export_import_round_trip_re_expands_paths() {
+        let mut config = create_test_config();
+        config.directories.state_dir = "/home/alice/work/app/.para/custom-state".to_string();
+        config.ide.user_data_dir = Some("/home/alice/.config/cursor-para".to_string());
+        config.setup_script = Some("/opt/scripts/setup.sh".to_string());
+
+        let exported =
+            export_config(&config, &machine("/home/alice", "/home/alice/work/app")).unwrap();
+        assert_eq!(
+            exported.json["directories"]["state_dir"],
+            "$REPO/.para/custom-state"
+        );
+        assert_eq!(
+            exported.json["ide"]["user_data_dir"],
+            "~/.config/cursor-para"
+        );
+        assert_eq!(exported.json["setup_script"], "/opt/scripts/setup.sh");
+
+        let text = serde_json::to_string_pretty(&exported.json).unwrap();
+        let on_other_machine = machine("/Users/bob", "/Users/bob/src/app");
+        let imported = import_config(
+            &create_test_config(),
+            serde_json::from_str(&text).unwrap(),
+            &on_other_machine,
+        )
+        .unwrap();
+
+        assert_eq!(
+            imported.directories.state_dir,
+            "/Users/bob/src/app/.para/custom-state"
+        );
+        assert_eq!(
+            imported.ide.user_data_dir.as_deref(),
+            Some("/Users/bob/.config/cursor-para")
+        );
+        assert_eq!(
+            imported.setup_script.as_deref(),
+            Some("/opt/scripts/setup.sh")
+        );
+        assert_eq!(imported.git.branch_prefix, config.git.branch_prefix);
+    }
+
+    #[test]
+    fn test_sensitive_values_are_excluded_and_kept_locally_on_import() {
+        let config = create_test_config();
+        let mut json = serde_json::to_value(&config).unwrap();
+        json["notifications"] = serde_json::json!({
+            "command": "notify-send",
+            "slack_token": "xoxb-123",
+            "webhook": "sk-live-abc",
+        });
+
+        let mut excluded = Vec::new();
+        make_portable(&mut json, "", &PortablePaths::default(), &mut excluded);
+        excluded.sort();
+        assert_eq!(
+            excluded,
+            vec![
+                "notifications.slack_token".to_string(),
+                "notifications.webhook".to_string()
+            ]
+        );
+        assert_eq!(json["notifications"]["command"], "notify-send");
+
+        // Settings missing from the imported file keep their local values
+        let mut local = create_test_config();
+        local.git.author_email = Some("me@example.com".to_string());
+        let imported = import_config(
+            &local,
+            serde_json::json!({ "git": { "branch_prefix": "team" } }),
+            &PortablePaths::default(),
+        )
+        .unwrap();
+        assert_eq!(imported.git.branch_prefix, "team");
+        assert_eq!(imported.git.author_email.as_deref(), Some("me@example.com"));
+    }
+
+    #[test]
+    fn test_placeholder_without_local_repo_fails() {
+        let paths = PortablePaths {
+            home: Some(PathBuf::from("/home/bob")),
+            repo: None,
+        };
+        let err = import_config(
+            &create_test_config(),
+            serde_json::json!({ "directories": { "state_dir": "$REPO/.para/state" } }),
+            &paths,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("$REPO"));
+    }
+
+    #[test]
+    fn test_diff_configs_lists_changed_settings() {
+        let old = create_test_config();
+        let mut new = create_test_config();
+        new.git.branch_prefix = "team".to_string();
+        new.git.author_name = Some("Agent".to_string());
+
+        let diff = diff_configs(&old, &new).unwrap();
+        assert_eq!(
+            diff,
+            vec![
+                "+ git.author_name: \"Agent\"".to_string(),
+                format!(
+                    "~ git.branch_prefix: \"{}\" -> \"team\"",
+                    old.git.branch_prefix
+                ),
+            ]
+        );
+    }
+}
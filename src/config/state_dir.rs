@@ -0,0 +1,232 @@
+//! Where para keeps session state
+//!
+//! `directories.state_dir` may be absolute or relative. Relative values are
+//! taken from the main repository root, like `subtrees_dir`, so every command
+//! finds the same state no matter which subdirectory it runs in. `--state-dir`
+//! (exported as `PARA_STATE_DIR`) overrides the configured value.
+//!
+//! A bare repository has no working tree to hold either directory, and para
+//! must not write into the repository itself. For one, relative directories
+//! are taken from `directories.bare_root` instead, a directory next to the
+//! bare repository that has to be configured before para can be used there.
+
+use crate::utils::{get_main_repository_root_from, is_bare_repository, ParaError};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Environment variable overriding `directories.state_dir`
+pub const STATE_DIR_ENV: &str = "PARA_STATE_DIR";
+
+/// Marker left in a state directory that was created relative to the current
+/// directory by older versions, so its relocation notice is shown only once
+const RELOCATED_MARKER: &str = ".relocated";
+
+/// The `PARA_STATE_DIR` override, if set
+pub fn state_dir_override() -> Option<String> {
+    std::env::var(STATE_DIR_ENV)
+        .ok()
+        .filter(|value| !value.trim().is_empty())
+}
+
+/// Make `--state-dir` visible to config loading here and in child processes.
+/// Relative paths are taken from the current directory, like any CLI path.
+pub fn export_state_dir(path: &Path) {
+    let path = if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        std::env::current_dir()
+            .map(|cwd| cwd.join(path))
+            .unwrap_or_else(|_| path.to_path_buf())
+    };
+    std::env::set_var(STATE_DIR_ENV, path);
+}
+
+/// Placeholder in `directories.bare_root` for the bare repository's name
+const REPO_PLACEHOLDER: &str = "{repo}";
+
+/// Directory relative `subtrees_dir` and `state_dir` are taken from: the main
+/// repository root, or `bare_root` for a bare repository. A relative
+/// `bare_root` is taken from the directory containing the bare repository.
+pub fn work_root(repo_root: &Path, bare_root: Option<&str>) -> crate::utils::Result<PathBuf> {
+    if !is_bare_repository(repo_root) {
+        return Ok(repo_root.to_path_buf());
+    }
+    let Some(bare_root) = bare_root.filter(|value| !value.trim().is_empty()) else {
+        return Err(ParaError::config_error(format!(
+            "{} is a bare repository, so worktrees and session state cannot go inside it. \
+             Set directories.bare_root to a directory next to it, e.g. \
+             para config set directories.bare_root '{REPO_PLACEHOLDER}.para'",
+            repo_root.display()
+        )));
+    };
+
+    let name = repo_root
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let name = name.strip_suffix(".git").unwrap_or(&name);
+    let bare_root = bare_root.replace(REPO_PLACEHOLDER, name);
+    let root = match repo_root.parent() {
+        Some(parent) if Path::new(&bare_root).is_relative() => parent.join(bare_root),
+        _ => PathBuf::from(bare_root),
+    };
+    if root.starts_with(repo_root) {
+        return Err(ParaError::config_error(format!(
+            "directories.bare_root '{}' must not be inside the bare repository {}",
+            root.display(),
+            repo_root.display()
+        )));
+    }
+    Ok(root)
+}
+
+/// Resolve `state_dir` against the main repository containing `from` (or the
+/// current directory). Outside a repository the current directory is used.
+pub fn resolve_state_dir(state_dir: &str, bare_root: Option<&str>, from: Option<&Path>) -> PathBuf {
+    let path = Path::new(state_dir);
+    if path.is_absolute() {
+        return path.to_path_buf();
+    }
+
+    match get_main_repository_root_from(from) {
+        // Without a usable `bare_root` commands fail before using the state
+        Ok(repo_root) => work_root(&repo_root, bare_root)
+            .unwrap_or(repo_root)
+            .join(path),
+        Err(_) => std::env::current_dir()
+            .map(|cwd| cwd.join(path))
+            .unwrap_or_else(|_| path.to_path_buf()),
+    }
+}
+
+/// State directory that older versions would have used: `state_dir` relative to
+/// `cwd`. Returned when it holds sessions and is not `resolved` itself.
+pub fn stray_state_dir(state_dir: &str, cwd: &Path, resolved: &Path) -> Option<PathBuf> {
+    if Path::new(state_dir).is_absolute() {
+        return None;
+    }
+    let stray = cwd.join(state_dir);
+    if stray == resolved || !stray.is_dir() || stray.join(RELOCATED_MARKER).exists() {
+        return None;
+    }
+    let has_sessions = fs::read_dir(&stray)
+        .ok()?
+        .flatten()
+        .any(|entry| entry.path().extension().is_some_and(|ext| ext == "state"));
+    has_sessions.then_some(stray)
+}
+
+/// Tell the user once about a state directory left in the current directory
+/// by older versions, which resolved relative paths against it
+pub fn notice_stray_state_dir(state_dir: &str, resolved: &Path) {
+    let Ok(cwd) = std::env::current_dir() else {
+        return;
+    };
+    let Some(stray) = stray_state_dir(state_dir, &cwd, resolved) else {
+        return;
+    };
+
+    eprintln!(
+        "Note: Found session state in {}, but para now keeps it in {}.",
+        stray.display(),
+        resolved.display()
+    );
+    eprintln!("      Move the files there to keep using those sessions; this note is shown once.");
+    let _ = fs::write(
+        stray.join(RELOCATED_MARKER),
+        format!("{}\n", resolved.display()),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::test_helpers::setup_test_repo;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_relative_state_dir_resolves_from_repository_root() {
+        let (git_temp, _git_service) = setup_test_repo();
+        let nested = git_temp.path().join("src").join("deep");
+        fs::create_dir_all(&nested).unwrap();
+
+        let from_root = resolve_state_dir(".para_state", None, Some(git_temp.path()));
+        let from_nested = resolve_state_dir(".para_state", None, Some(&nested));
+        assert_eq!(from_root, from_nested);
+        assert_eq!(
+            from_root.canonicalize().unwrap_or(from_root.clone()),
+            git_temp.path().canonicalize().unwrap().join(".para_state")
+        );
+
+        assert_eq!(
+            resolve_state_dir("/var/para", None, Some(&nested)),
+            PathBuf::from("/var/para")
+        );
+    }
+
+    #[test]
+    fn test_bare_repository_uses_bare_root() {
+        let temp = TempDir::new().unwrap();
+        let bare = temp.path().join("app.git");
+        let status = std::process::Command::new("git")
+            .args(["init", "--quiet", "--bare"])
+            .arg(&bare)
+            .status()
+            .unwrap();
+        assert!(status.success());
+
+        let err = work_root(&bare, None).unwrap_err().to_string();
+        assert!(err.contains("directories.bare_root"), "{err}");
+        assert_eq!(
+            work_root(&bare, Some("{repo}.para")).unwrap(),
+            temp.path().join("app.para")
+        );
+        assert_eq!(
+            work_root(&bare, Some("/srv/para")).unwrap(),
+            PathBuf::from("/srv/para")
+        );
+        assert!(work_root(&bare, Some("app.git/para")).is_err());
+        assert_eq!(
+            resolve_state_dir(".para/state", Some("{repo}.para"), Some(&bare)),
+            temp.path().join("app.para/.para/state")
+        );
+
+        // A repository with a working tree ignores the setting
+        let (git_temp, _git_service) = setup_test_repo();
+        assert_eq!(
+            work_root(git_temp.path(), Some("{repo}.para")).unwrap(),
+            git_temp.path()
+        );
+    }
+
+    #[test]
+    fn test_stray_state_dir_is_reported_once() {
+        let cwd = TempDir::new().unwrap();
+        let resolved = TempDir::new().unwrap();
+        let stray = cwd.path().join(".para_state");
+        fs::create_dir_all(&stray).unwrap();
+
+        // An empty leftover directory is not worth a notice
+        assert_eq!(
+            stray_state_dir(".para_state", cwd.path(), resolved.path()),
+            None
+        );
+
+        fs::write(stray.join("auth.state"), "{}").unwrap();
+        assert_eq!(
+            stray_state_dir(".para_state", cwd.path(), resolved.path()),
+            Some(stray.clone())
+        );
+        assert_eq!(stray_state_dir(".para_state", cwd.path(), &stray), None);
+        assert_eq!(
+            stray_state_dir("/abs/state", cwd.path(), resolved.path()),
+            None
+        );
+
+        fs::write(stray.join(RELOCATED_MARKER), "").unwrap();
+        assert_eq!(
+            stray_state_dir(".para_state", cwd.path(), resolved.path()),
+            None
+        );
+    }
+}
@@ -0,0 +1,140 @@
+//! Session templates: named bundles of `para start` options
+//!
+//! Templates live under `templates` in the user config and in the project's
+//! `.para/config.json`, where a template replaces a user template of the same
+//! name. `para start --template <name>` uses a template's values as defaults
+//! for the flags that were not given on the command line.
+
+use super::{ConfigError, Result};
+use crate::core::git::MergeMode;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+/// Options a template can preset. Every field is optional; unknown fields are
+/// rejected when the config is loaded.
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct SessionTemplate {
+    /// Shown by `para config templates list`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Text placed before the prompt of AI-assisted sessions
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prompt_prefix: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fetch: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remote: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub merge_mode: Option<MergeMode>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub setup_script: Option<PathBuf>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub copy: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub no_auto_commit: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dangerously_skip_permissions: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub container: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub docker_image: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub docker_args: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_domains: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub no_forward_keys: Option<bool>,
+    /// `true` behaves like `--sandbox`, `false` like `--no-sandbox`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sandbox: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sandbox_profile: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sandbox_no_network: Option<bool>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allowed_domains: Vec<String>,
+}
+
+/// `prompt` with a template's prompt prefix in front of it
+pub fn prefix_prompt(prefix: Option<&str>, prompt: String) -> String {
+    match prefix.map(str::trim_end) {
+        Some(prefix) if !prefix.is_empty() => format!("{prefix}\n\n{prompt}"),
+        _ => prompt,
+    }
+}
+
+/// Template names follow the session name rules so they are easy to type
+pub fn validate_templates(templates: &BTreeMap<String, SessionTemplate>) -> Result<()> {
+    for (name, template) in templates {
+        let valid_name = !name.is_empty()
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        if !valid_name {
+            return Err(ConfigError::Validation(format!(
+                "Invalid template name '{name}'. Use letters, digits, '-' and '_'"
+            )));
+        }
+        if template.fetch == Some(true) && template.base.is_none() {
+            return Err(ConfigError::Validation(format!(
+                "Template '{name}' sets fetch without a base"
+            )));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_templates_reject_unknown_fields() {
+        let parsed: std::result::Result<SessionTemplate, _> =
+            serde_json::from_str(r#"{"base": "main", "lables": ["bug"]}"#);
+        let err = parsed.unwrap_err().to_string();
+        assert!(err.contains("unknown field `lables`"), "{err}");
+
+        let parsed: SessionTemplate =
+            serde_json::from_str(r#"{"base": "main", "merge_mode": "preserve"}"#).unwrap();
+        assert_eq!(parsed.base.as_deref(), Some("main"));
+        assert_eq!(parsed.merge_mode, Some(MergeMode::PreserveCommits));
+    }
+
+    #[test]
+    fn test_validate_templates() {
+        let mut templates = BTreeMap::new();
+        templates.insert("bugfix".to_string(), SessionTemplate::default());
+        assert!(validate_templates(&templates).is_ok());
+
+        templates.insert(
+            "no-base".to_string(),
+            SessionTemplate {
+                fetch: Some(true),
+                ..Default::default()
+            },
+        );
+        assert!(validate_templates(&templates).is_err());
+
+        let mut templates = BTreeMap::new();
+        templates.insert("bug fix".to_string(), SessionTemplate::default());
+        assert!(validate_templates(&templates).is_err());
+    }
+
+    #[test]
+    fn test_prefix_prompt() {
+        assert_eq!(
+            prefix_prompt(
+                Some("Reproduce the bug with a test first.\n"),
+                "Fix the login crash".to_string()
+            ),
+            "Reproduce the bug with a test first.\n\nFix the login crash"
+        );
+        assert_eq!(prefix_prompt(None, "as is".to_string()), "as is");
+        assert_eq!(prefix_prompt(Some("  "), "as is".to_string()), "as is");
+    }
+}
@@ -6,6 +6,22 @@ pub fn validate_config(config: &Config) -> Result<()> {
     validate_directory_config(&config.directories)?;
     validate_git_config(&config.git)?;
     validate_session_config(&config.session)?;
+    super::templates::validate_templates(&config.templates)?;
+    if let Some(kinds) = config.get_cleanup_enabled_kinds() {
+        validate_cleanup_kinds(kinds)?;
+    }
+    Ok(())
+}
+
+pub fn validate_cleanup_kinds(kinds: &[String]) -> Result<()> {
+    for kind in kinds {
+        crate::core::cleanup::CleanupKind::parse(kind).map_err(|_| {
+            ConfigError::Validation(format!(
+                "Unknown cleanup kind '{kind}' in cleanup.enabled_kinds. Valid kinds: {}",
+                crate::core::cleanup::valid_kinds()
+            ))
+        })?;
+    }
     Ok(())
 }
 
@@ -99,39 +115,124 @@ fn validate_ide_config_with_checks(
         }
     }
 
+    if let Some(remote) = &ide.remote {
+        if remote.host.is_empty()
+            || remote
+                .host
+                .chars()
+                .any(|c| c.is_whitespace() || c == '+' || c == '/')
+        {
+            return Err(ConfigError::Validation(format!(
+                "Invalid remote host '{}'. Use the SSH host name or alias the IDE connects to",
+                remote.host
+            )));
+        }
+    }
+
     Ok(())
 }
 
+/// Reject directories that would let cleanup delete things para does not own.
+/// Neither directory may be empty, `.`, the filesystem root or contain `..`
+/// components, and a relative `state_dir` must not be inside a relative
+/// `subtrees_dir`. Where absolute directories end up is checked against the
+/// repository with `validate_directory_location`. Expects values normalized
+/// with `normalize_directory`.
 pub fn validate_directory_config(dirs: &super::DirectoryConfig) -> Result<()> {
-    if dirs.subtrees_dir.is_empty() {
-        return Err(ConfigError::Validation(
-            "Subtrees directory cannot be empty".to_string(),
-        ));
+    let subtrees = dirs.subtrees_dir.as_str();
+    let state = dirs.state_dir.as_str();
+
+    for (label, value) in [("Subtrees", subtrees), ("State", state)] {
+        if value.is_empty() {
+            return Err(ConfigError::Validation(format!(
+                "{label} directory cannot be empty"
+            )));
+        }
+        if value == "/" {
+            return Err(ConfigError::Validation(format!(
+                "{label} directory cannot be the filesystem root"
+            )));
+        }
+        if value == "." {
+            return Err(ConfigError::Validation(format!(
+                "{label} directory cannot be '.', the repository root itself"
+            )));
+        }
+        if !is_valid_directory_name(value.trim_start_matches('/')) {
+            return Err(ConfigError::Validation(format!(
+                "{label} directory '{value}' must not contain '..' components"
+            )));
+        }
     }
 
-    if dirs.state_dir.is_empty() {
-        return Err(ConfigError::Validation(
-            "State directory cannot be empty".to_string(),
-        ));
+    if !is_absolute_directory(subtrees)
+        && !is_absolute_directory(state)
+        && Path::new(state).starts_with(subtrees)
+    {
+        return Err(ConfigError::Validation(format!(
+            "State directory '{state}' cannot be inside the subtrees directory '{subtrees}'"
+        )));
     }
 
-    if !is_valid_directory_name(&dirs.subtrees_dir) {
+    Ok(())
+}
+
+/// Check where `dirs` end up in the repository whose relative directories are
+/// taken from `work_root`: the subtrees directory must be below it, and the
+/// state directory must be neither `work_root` itself nor inside the subtrees
+/// directory
+pub fn validate_directory_location(dirs: &super::DirectoryConfig, work_root: &Path) -> Result<()> {
+    let subtrees = work_root.join(&dirs.subtrees_dir);
+    if subtrees == work_root || !subtrees.starts_with(work_root) {
         return Err(ConfigError::Validation(format!(
-            "Invalid subtrees directory name '{}'. Must be a relative path without '..' components",
-            dirs.subtrees_dir
+            "Subtrees directory '{}' must be inside the repository at '{}'",
+            dirs.subtrees_dir,
+            work_root.display()
         )));
     }
 
-    if !is_valid_directory_name(&dirs.state_dir) {
+    let state = work_root.join(&dirs.state_dir);
+    if state == work_root {
         return Err(ConfigError::Validation(format!(
-            "Invalid state directory name '{}'. Must be a relative path without '..' components",
+            "State directory '{}' cannot be the repository root",
             dirs.state_dir
         )));
     }
-
+    if state.starts_with(&subtrees) {
+        return Err(ConfigError::Validation(format!(
+            "State directory '{}' cannot be inside the subtrees directory '{}'",
+            dirs.state_dir, dirs.subtrees_dir
+        )));
+    }
     Ok(())
 }
 
+/// `value` with unified `/` separators, without repeated separators, `.`
+/// components or a trailing slash, so directories compare reliably.
+/// `.` on its own is kept.
+pub fn normalize_directory(value: &str) -> String {
+    let unified = value.trim().replace('\\', "/");
+    let absolute = unified.starts_with('/');
+    let components: Vec<&str> = unified
+        .split('/')
+        .filter(|component| !component.is_empty() && *component != ".")
+        .collect();
+
+    match (absolute, components.is_empty()) {
+        (true, _) => format!("/{}", components.join("/")),
+        (false, true) if unified.is_empty() => String::new(),
+        (false, true) => ".".to_string(),
+        (false, false) => components.join("/"),
+    }
+}
+
+fn is_absolute_directory(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    // `C:/...` from configs written on Windows
+    let has_drive = bytes.len() >= 2 && bytes[1] == b':' && bytes[0].is_ascii_alphabetic();
+    value.starts_with('/') || has_drive
+}
+
 pub fn validate_git_config(git: &super::GitConfig) -> Result<()> {
     if git.branch_prefix.is_empty() {
         return Err(ConfigError::Validation(
@@ -169,6 +270,23 @@ pub fn validate_session_config(session: &super::SessionConfig) -> Result<()> {
         }
     }
 
+    if session.idle_alert_hours == Some(0) {
+        return Err(ConfigError::Validation(
+            "Idle alert hours must be greater than 0".to_string(),
+        ));
+    }
+
+    if let Some(retention) = &session.retention {
+        if retention.max_entries == Some(0)
+            || retention.max_age_days == Some(0)
+            || retention.log_max_bytes == Some(0)
+        {
+            return Err(ConfigError::Validation(
+                "Retention limits must be greater than 0".to_string(),
+            ));
+        }
+    }
+
     Ok(())
 }
 
@@ -248,6 +366,18 @@ mod tests {
         assert!(!is_valid_ide_name("ide/name"));
     }
 
+    #[test]
+    fn test_cleanup_kinds_validation() {
+        let kinds = vec!["stale_branch".to_string(), "old_archive".to_string()];
+        assert!(validate_cleanup_kinds(&kinds).is_ok());
+
+        let error = validate_cleanup_kinds(&["stale_branches".to_string()])
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("Unknown cleanup kind 'stale_branches'"));
+        assert!(error.contains("Valid kinds: stale_branch, orphaned_state_file"));
+    }
+
     #[test]
     fn test_valid_directory_names() {
         assert!(is_valid_directory_name("subtrees"));
@@ -295,6 +425,7 @@ mod tests {
                 name: "cursor".to_string(),
                 command: "cursor".to_string(),
             },
+            remote: None,
         };
         assert!(validate_ide_config_no_cmd_check(&valid_config).is_ok());
 
@@ -308,6 +439,7 @@ mod tests {
                 name: "cursor".to_string(),
                 command: "cursor".to_string(),
             },
+            remote: None,
         };
         assert!(validate_ide_config_no_cmd_check(&invalid_config).is_err());
 
@@ -321,8 +453,21 @@ mod tests {
                 name: String::new(),
                 command: String::new(),
             },
+            remote: None,
         };
         assert!(validate_ide_config_no_cmd_check(&invalid_no_wrapper).is_err());
+
+        // Remote hosts must be a plain SSH host name or alias
+        let mut remote_config = valid_config.clone();
+        remote_config.remote = Some(super::super::RemoteConfig {
+            host: "devbox".to_string(),
+            mode: Default::default(),
+        });
+        assert!(validate_ide_config_no_cmd_check(&remote_config).is_ok());
+        for host in ["", "dev box", "ssh-remote+devbox"] {
+            remote_config.remote.as_mut().unwrap().host = host.to_string();
+            assert!(validate_ide_config_no_cmd_check(&remote_config).is_err());
+        }
     }
 
     #[test]
@@ -330,22 +475,176 @@ mod tests {
         let valid_config = DirectoryConfig {
             subtrees_dir: "subtrees/para".to_string(),
             state_dir: ".para_state".to_string(),
+            bare_root: None,
+            layout: Default::default(),
         };
         assert!(validate_directory_config(&valid_config).is_ok());
 
         let invalid_config = DirectoryConfig {
-            subtrees_dir: "/absolute/path".to_string(),
+            subtrees_dir: "../path".to_string(),
             state_dir: ".para_state".to_string(),
+            bare_root: None,
+            layout: Default::default(),
         };
         assert!(validate_directory_config(&invalid_config).is_err());
     }
 
+    #[test]
+    fn test_directory_config_rejects_dangerous_values() {
+        let cases = [
+            ("", ".para/state", "Subtrees directory cannot be empty"),
+            (
+                "/",
+                ".para/state",
+                "Subtrees directory cannot be the filesystem root",
+            ),
+            (
+                "/work/repo/../other-repo",
+                ".para/state",
+                "must not contain '..' components",
+            ),
+            (
+                "..",
+                ".para/state",
+                "Subtrees directory '..' must not contain '..'",
+            ),
+            (
+                "subtrees/../..",
+                ".para/state",
+                "must not contain '..' components",
+            ),
+            (".", ".para/state", "Subtrees directory cannot be '.'"),
+            (".para/worktrees", "", "State directory cannot be empty"),
+            (
+                ".para/worktrees",
+                "/",
+                "State directory cannot be the filesystem root",
+            ),
+            (".para/worktrees", ".", "State directory cannot be '.'"),
+            (
+                ".para/worktrees",
+                "../state",
+                "State directory '../state' must not contain '..'",
+            ),
+            (
+                ".para/worktrees",
+                ".para/worktrees/state",
+                "State directory '.para/worktrees/state' cannot be inside the subtrees directory",
+            ),
+            (
+                ".para/worktrees",
+                ".para/worktrees",
+                "cannot be inside the subtrees directory",
+            ),
+        ];
+
+        for (subtrees_dir, state_dir, message) in cases {
+            let mut dirs = DirectoryConfig {
+                subtrees_dir: subtrees_dir.to_string(),
+                state_dir: state_dir.to_string(),
+                bare_root: None,
+                layout: Default::default(),
+            };
+            dirs.normalize();
+            let err = validate_directory_config(&dirs).unwrap_err().to_string();
+            assert!(
+                err.contains(message),
+                "({subtrees_dir:?}, {state_dir:?}): expected '{message}', got '{err}'"
+            );
+        }
+
+        // Absolute state directories are fine outside the repository
+        let dirs = DirectoryConfig {
+            subtrees_dir: ".para/worktrees".to_string(),
+            state_dir: "/var/lib/para".to_string(),
+            bare_root: None,
+            layout: Default::default(),
+        };
+        assert!(validate_directory_config(&dirs).is_ok());
+    }
+
+    #[test]
+    fn test_directory_location_checked_against_repository() {
+        let repo_root = Path::new("/work/repo");
+        let dirs = |subtrees_dir: &str, state_dir: &str| DirectoryConfig {
+            subtrees_dir: subtrees_dir.to_string(),
+            state_dir: state_dir.to_string(),
+            bare_root: None,
+            layout: Default::default(),
+        };
+        let cases = [
+            (
+                "/home/user/other-repo",
+                ".para/state",
+                "Subtrees directory '/home/user/other-repo' must be inside the repository",
+            ),
+            ("/work/repo", ".para/state", "must be inside the repository"),
+            (
+                ".para/worktrees",
+                "/work/repo",
+                "cannot be the repository root",
+            ),
+            (
+                ".para/worktrees",
+                "/work/repo/.para/worktrees/state",
+                "cannot be inside the subtrees directory",
+            ),
+            (
+                "/work/repo/.para/worktrees",
+                ".para/worktrees/state",
+                "cannot be inside the subtrees directory",
+            ),
+        ];
+
+        for (subtrees_dir, state_dir, message) in cases {
+            let err = validate_directory_location(&dirs(subtrees_dir, state_dir), repo_root)
+                .unwrap_err()
+                .to_string();
+            assert!(
+                err.contains(message),
+                "({subtrees_dir:?}, {state_dir:?}): expected '{message}', got '{err}'"
+            );
+        }
+
+        for (subtrees_dir, state_dir) in [
+            (".para/worktrees", "/work/repo/.para/state"),
+            ("/work/repo/.para/worktrees", "/var/lib/para"),
+        ] {
+            let dirs = dirs(subtrees_dir, state_dir);
+            assert!(validate_directory_config(&dirs).is_ok());
+            assert!(validate_directory_location(&dirs, repo_root).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_normalize_directory() {
+        assert_eq!(normalize_directory(".para/worktrees/"), ".para/worktrees");
+        assert_eq!(normalize_directory("./.para//state"), ".para/state");
+        assert_eq!(normalize_directory(".para\\worktrees\\"), ".para/worktrees");
+        assert_eq!(normalize_directory("/var/lib/para/"), "/var/lib/para");
+        assert_eq!(normalize_directory("/"), "/");
+        assert_eq!(normalize_directory("./"), ".");
+        assert_eq!(normalize_directory(""), "");
+    }
+
     #[test]
     fn test_git_config_validation() {
         let valid_config = GitConfig {
             branch_prefix: "para".to_string(),
             auto_stage: true,
             auto_commit: true,
+            author_email: None,
+            author_name: None,
+            protected_branches: Vec::new(),
+            auto_commit_interval_minutes: None,
+            default_merge_mode: None,
+            command_timeout_secs: None,
+            audit_log: false,
+            default_remote: None,
+            auto_fetch_interval_minutes: None,
+            auto_fetch: false,
+            apply_whitespace: Default::default(),
+            behind_warning_commits: None,
         };
         assert!(validate_git_config(&valid_config).is_ok());
 
@@ -353,6 +652,18 @@ mod tests {
             branch_prefix: "my branch".to_string(),
             auto_stage: true,
             auto_commit: true,
+            author_email: None,
+            author_name: None,
+            protected_branches: Vec::new(),
+            auto_commit_interval_minutes: None,
+            default_merge_mode: None,
+            command_timeout_secs: None,
+            audit_log: false,
+            default_remote: None,
+            auto_fetch_interval_minutes: None,
+            auto_fetch: false,
+            apply_whitespace: Default::default(),
+            behind_warning_commits: None,
         };
         assert!(validate_git_config(&invalid_config).is_err());
     }
@@ -363,6 +674,12 @@ mod tests {
             default_name_format: "%Y%m%d-%H%M%S".to_string(),
             preserve_on_finish: true,
             auto_cleanup_days: Some(30),
+            retention: None,
+            copy_untracked: Vec::new(),
+            idle_action: Default::default(),
+            idle_alert_hours: None,
+            conflict_hotspots: false,
+            on_resume: Vec::new(),
         };
         assert!(validate_session_config(&valid_config).is_ok());
 
@@ -370,6 +687,12 @@ mod tests {
             default_name_format: "".to_string(),
             preserve_on_finish: true,
             auto_cleanup_days: Some(0),
+            retention: None,
+            copy_untracked: Vec::new(),
+            idle_action: Default::default(),
+            idle_alert_hours: None,
+            conflict_hotspots: false,
+            on_resume: Vec::new(),
         };
         assert!(validate_session_config(&invalid_config).is_err());
     }
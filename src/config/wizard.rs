@@ -1,153 +1,370 @@
-use super::defaults::{default_config, get_available_ides};
-use super::{Config, ConfigError, Result};
+//! `para config` wizard
+//!
+//! The wizard is split into sections that each ask about one part of the
+//! config. Every prompt starts from the value already configured, and the
+//! config is saved after each completed section, so an interrupted wizard
+//! keeps the answers given so far. `para config --section git` asks only the
+//! chosen sections.
+
+use super::concurrent::ConfigSnapshot;
+use super::defaults::{default_config, get_available_ides, get_config_file_path};
+use super::validation;
+use super::{
+    Config, ConfigError, DirectoryConfig, DockerConfig, GitConfig, IdeConfig, Result, SessionConfig,
+};
+use crate::core::sandbox::SandboxConfig;
+use crate::ui::theme;
 use dialoguer::{theme::ColorfulTheme, Confirm, Input, Select};
+use std::path::Path;
+use std::str::FromStr;
+
+/// Part of the config the wizard asks about
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum WizardSection {
+    /// IDE wrapper Claude Code runs in
+    Ide,
+    /// Subtrees and state directories
+    Directories,
+    /// Branch prefix and staging
+    Git,
+    /// Preserving and cleaning up finished sessions
+    Session,
+    /// Sandboxing of Claude Code
+    Sandbox,
+    /// Docker image and setup script
+    Docker,
+}
+
+impl WizardSection {
+    /// Every section, in the order the full wizard asks them
+    pub const ALL: [WizardSection; 6] = [
+        WizardSection::Ide,
+        WizardSection::Directories,
+        WizardSection::Git,
+        WizardSection::Session,
+        WizardSection::Sandbox,
+        WizardSection::Docker,
+    ];
+
+    fn ask(self, config: &mut Config, prompter: &mut dyn Prompter) -> Result<()> {
+        match self {
+            WizardSection::Ide => config.ide = configure_ide(config.ide.clone(), prompter)?,
+            WizardSection::Directories => {
+                config.directories = configure_directories(config.directories.clone(), prompter)?
+            }
+            WizardSection::Git => config.git = configure_git(config.git.clone(), prompter)?,
+            WizardSection::Session => {
+                config.session = configure_session(config.session.clone(), prompter)?
+            }
+            WizardSection::Sandbox => {
+                config.sandbox = configure_sandbox(config.sandbox.clone(), prompter)?
+            }
+            WizardSection::Docker => {
+                config.docker = configure_docker(config.docker.clone(), prompter)?
+            }
+        }
+        Ok(())
+    }
+
+    fn validate(self, config: &Config) -> Result<()> {
+        match self {
+            WizardSection::Ide => validation::validate_ide_config(&config.ide),
+            WizardSection::Directories => {
+                validation::validate_directory_config(&config.directories)
+            }
+            WizardSection::Git => validation::validate_git_config(&config.git),
+            WizardSection::Session => validation::validate_session_config(&config.session),
+            WizardSection::Sandbox | WizardSection::Docker => Ok(()),
+        }
+    }
+}
+
+/// Where the wizard's answers come from
+pub trait Prompter {
+    fn confirm(&mut self, prompt: &str, default: bool) -> Result<bool>;
+    /// Free text, prefilled with `default`
+    fn input(&mut self, prompt: &str, default: &str) -> Result<String>;
+    /// Index of the chosen item
+    fn select(&mut self, prompt: &str, items: &[&str], default: usize) -> Result<usize>;
+}
+
+/// Prompts on the terminal
+pub struct TerminalPrompter;
+
+fn read_error(e: dialoguer::Error) -> ConfigError {
+    ConfigError::Validation(format!("Failed to read input: {e}"))
+}
 
-pub fn run_config_wizard() -> Result<Config> {
-    println!("🔧 Para Configuration Wizard");
+impl Prompter for TerminalPrompter {
+    fn confirm(&mut self, prompt: &str, default: bool) -> Result<bool> {
+        Confirm::with_theme(&ColorfulTheme::default())
+            .with_prompt(prompt)
+            .default(default)
+            .interact()
+            .map_err(read_error)
+    }
+
+    fn input(&mut self, prompt: &str, default: &str) -> Result<String> {
+        let answer = Input::<String>::with_theme(&ColorfulTheme::default())
+            .with_prompt(prompt)
+            .allow_empty(true)
+            .with_initial_text(default)
+            .interact_text()
+            .map_err(read_error)?;
+        Ok(answer.trim().to_string())
+    }
+
+    fn select(&mut self, prompt: &str, items: &[&str], default: usize) -> Result<usize> {
+        Select::with_theme(&ColorfulTheme::default())
+            .with_prompt(prompt)
+            .items(items)
+            .default(default)
+            .interact()
+            .map_err(read_error)
+    }
+}
+
+/// Run the wizard for `sections`, or all of them when empty, against the
+/// saved config
+pub fn run_config_wizard(sections: &[WizardSection]) -> Result<Config> {
+    println!("{}", theme::action("Para Configuration Wizard"));
     println!();
 
-    let mut config = default_config();
+    let config_path = get_config_file_path();
+    // Saving checks this so a config saved by another para while the
+    // prompts are open is merged instead of overwritten
+    let mut snapshot = ConfigSnapshot::read(&config_path)?;
+    let sections = if sections.is_empty() {
+        &WizardSection::ALL[..]
+    } else {
+        sections
+    };
 
-    config.ide = configure_ide_simple()?;
-    config.directories = configure_directories_simple(config.directories)?;
-    config.session = configure_session_simple(config.session)?;
-    config.sandbox = configure_sandbox_simple()?;
+    let config = run_sections(
+        load_current(&config_path),
+        sections,
+        &mut TerminalPrompter,
+        |config| {
+            super::ConfigManager::save_checked(config, &snapshot)?;
+            snapshot = ConfigSnapshot::read(&config_path)?;
+            Ok(())
+        },
+    )?;
+    config.validate()?;
 
     println!("\n📋 Configuration Summary:");
     display_config_summary(&config);
+    println!("{}", theme::success("Configuration saved successfully!"));
 
-    if Confirm::with_theme(&ColorfulTheme::default())
-        .with_prompt("Save this configuration?")
-        .default(true)
-        .interact()
-        .map_err(|e| ConfigError::Validation(format!("Failed to read input: {e}")))?
-    {
-        super::ConfigManager::save(&config)?;
-        println!("✅ Configuration saved successfully!");
-    } else {
-        println!("❌ Configuration not saved.");
-        return Err(ConfigError::Validation(
-            "Configuration cancelled by user".to_string(),
-        ));
+    Ok(config)
+}
+
+/// The saved config the wizard starts from; defaults when there is none or
+/// it can't be loaded
+fn load_current(config_path: &Path) -> Config {
+    if !config_path.exists() {
+        return default_config();
     }
+    super::ConfigManager::load_from_file(config_path).unwrap_or_else(|e| {
+        eprintln!(
+            "{}",
+            theme::warning(format!(
+                "Starting from defaults, the saved config failed to load: {e}"
+            ))
+        );
+        default_config()
+    })
+}
 
+/// Ask `sections` in order, starting from `config`. A section is asked again
+/// until its answers are valid, and `persist` gets the config after each one.
+pub fn run_sections(
+    mut config: Config,
+    sections: &[WizardSection],
+    prompter: &mut dyn Prompter,
+    mut persist: impl FnMut(&Config) -> Result<()>,
+) -> Result<Config> {
+    let mut asked = Vec::new();
+    for &section in sections {
+        if asked.contains(&section) {
+            continue;
+        }
+        asked.push(section);
+
+        let mut draft = config.clone();
+        loop {
+            section.ask(&mut draft, prompter)?;
+            match section.validate(&draft) {
+                Ok(()) => break,
+                Err(e) => eprintln!("{}", theme::warning(e)),
+            }
+        }
+        config = draft;
+        persist(&config)?;
+    }
     Ok(config)
 }
 
-fn configure_ide_simple() -> Result<super::IdeConfig> {
+/// Ask until the answer parses as a `T`
+fn input_parsed<T: FromStr + ToString>(
+    prompter: &mut dyn Prompter,
+    prompt: &str,
+    default: T,
+) -> Result<T> {
+    let default = default.to_string();
+    loop {
+        match prompter.input(prompt, &default)?.parse() {
+            Ok(value) => return Ok(value),
+            Err(_) => eprintln!("{}", theme::warning("Please enter a number")),
+        }
+    }
+}
+
+/// `None` for an empty answer
+fn optional(answer: String) -> Option<String> {
+    Some(answer).filter(|answer| !answer.is_empty())
+}
+
+const WRAPPERS: [(&str, &str); 2] = [
+    ("code", "code (VS Code)"),
+    ("cursor", "cursor (Cursor IDE)"),
+];
+
+pub fn configure_ide(mut ide: IdeConfig, prompter: &mut dyn Prompter) -> Result<IdeConfig> {
     println!("🖥️  IDE Configuration");
     println!("Para works with Claude Code in cloud-based wrapper mode.");
 
-    let ide_name = "claude".to_string();
-    let ide_command = "claude".to_string();
+    let labels: Vec<&str> = WRAPPERS.iter().map(|(_, label)| *label).collect();
+    let current = WRAPPERS
+        .iter()
+        .position(|(command, _)| *command == ide.wrapper.command)
+        .unwrap_or(0);
+    let selection = prompter.select(
+        "Which IDE wrapper would you like to use for Claude Code?",
+        &labels,
+        current,
+    )?;
+    let wrapper = WRAPPERS[selection].0;
+
+    ide.name = "claude".to_string();
+    ide.command = "claude".to_string();
+    ide.wrapper.enabled = true;
+    ide.wrapper.name = wrapper.to_string();
+    ide.wrapper.command = wrapper.to_string();
+    Ok(ide)
+}
 
-    let wrapper_config = configure_wrapper_mode_simple()?;
+pub fn configure_directories(
+    mut dirs: DirectoryConfig,
+    prompter: &mut dyn Prompter,
+) -> Result<DirectoryConfig> {
+    println!("\n📁 Directories");
 
-    Ok(super::IdeConfig {
-        name: ide_name,
-        command: ide_command,
-        user_data_dir: None,
-        wrapper: wrapper_config,
-    })
+    dirs.subtrees_dir = prompter.input("Subtrees directory", &dirs.subtrees_dir)?;
+    dirs.state_dir = prompter.input("State directory", &dirs.state_dir)?;
+    dirs.normalize();
+    Ok(dirs)
 }
 
-fn configure_wrapper_mode_simple() -> Result<super::WrapperConfig> {
-    let wrapper_options = vec!["code (VS Code)", "cursor (Cursor IDE)"];
+pub fn configure_git(mut git: GitConfig, prompter: &mut dyn Prompter) -> Result<GitConfig> {
+    println!("\n🌿 Git");
 
-    let wrapper_selection = Select::with_theme(&ColorfulTheme::default())
-        .with_prompt("Which IDE wrapper would you like to use for Claude Code?")
-        .items(&wrapper_options)
-        .default(0)
-        .interact()
-        .map_err(|e| ConfigError::Validation(format!("Failed to read input: {e}")))?;
+    git.branch_prefix = prompter.input("Branch prefix", &git.branch_prefix)?;
+    git.auto_stage = prompter.confirm("Stage all changes when finishing?", git.auto_stage)?;
+    git.auto_commit = prompter.confirm(
+        "Commit staged changes automatically when finishing?",
+        git.auto_commit,
+    )?;
+    Ok(git)
+}
 
-    let (wrapper_name, wrapper_command) = match wrapper_selection {
-        0 => ("code".to_string(), "code".to_string()),
-        1 => ("cursor".to_string(), "cursor".to_string()),
-        _ => unreachable!(),
+pub fn configure_session(
+    mut session: SessionConfig,
+    prompter: &mut dyn Prompter,
+) -> Result<SessionConfig> {
+    println!("\n🗂️  Session Management");
+
+    session.preserve_on_finish = prompter.confirm(
+        "Preserve worktrees after finishing sessions?",
+        session.preserve_on_finish,
+    )?;
+
+    session.auto_cleanup_days = if prompter.confirm(
+        "Clean up preserved sessions automatically?",
+        session.auto_cleanup_days.is_some(),
+    )? {
+        Some(input_parsed(
+            prompter,
+            "Auto-cleanup preserved sessions after (days)",
+            session.auto_cleanup_days.unwrap_or(30),
+        )?)
+    } else {
+        None
     };
-
-    Ok(super::WrapperConfig {
-        enabled: true,
-        name: wrapper_name,
-        command: wrapper_command,
-    })
+    Ok(session)
 }
 
-fn configure_directories_simple(
-    mut config: super::DirectoryConfig,
-) -> Result<super::DirectoryConfig> {
-    println!("\n📁 Directories (optional customization)");
-
-    config.subtrees_dir = Input::<String>::with_theme(&ColorfulTheme::default())
-        .with_prompt("Subtrees directory")
-        .default(config.subtrees_dir)
-        .interact()
-        .map_err(|e| ConfigError::Validation(format!("Failed to read input: {e}")))?;
+const SANDBOX_PROFILES: [&str; 2] = ["standard", "standard-proxied"];
 
-    config.state_dir = Input::<String>::with_theme(&ColorfulTheme::default())
-        .with_prompt("State directory")
-        .default(config.state_dir)
-        .interact()
-        .map_err(|e| ConfigError::Validation(format!("Failed to read input: {e}")))?;
-
-    Ok(config)
-}
+pub fn configure_sandbox(
+    sandbox: Option<SandboxConfig>,
+    prompter: &mut dyn Prompter,
+) -> Result<Option<SandboxConfig>> {
+    println!("\n🔒 Sandbox Security");
+    println!("Sandboxing limits file write access to protect against prompt injection.");
 
-fn configure_session_simple(mut config: super::SessionConfig) -> Result<super::SessionConfig> {
-    println!("\n🗂️  Session Management (optional customization)");
-
-    config.preserve_on_finish = Confirm::with_theme(&ColorfulTheme::default())
-        .with_prompt("Preserve worktrees after finishing sessions?")
-        .default(config.preserve_on_finish)
-        .interact()
-        .map_err(|e| ConfigError::Validation(format!("Failed to read input: {e}")))?;
-
-    if let Some(days) = config.auto_cleanup_days {
-        let cleanup_days = Input::<u32>::with_theme(&ColorfulTheme::default())
-            .with_prompt("Auto-cleanup preserved sessions after (days)")
-            .default(days)
-            .validate_with(|input: &u32| {
-                if *input > 0 && *input <= 365 {
-                    Ok(())
-                } else {
-                    Err("Please enter a value between 1 and 365 days")
-                }
-            })
-            .interact()
-            .map_err(|e| ConfigError::Validation(format!("Failed to read input: {e}")))?;
-        config.auto_cleanup_days = Some(cleanup_days);
+    let enabled = prompter.confirm(
+        "Enable sandboxing by default?",
+        sandbox.as_ref().is_some_and(|sandbox| sandbox.enabled),
+    )?;
+    if !enabled {
+        // Keep the profile and domains around for when it is enabled again
+        return Ok(sandbox.map(|sandbox| SandboxConfig {
+            enabled: false,
+            ..sandbox
+        }));
     }
 
-    Ok(config)
+    let mut sandbox = sandbox.unwrap_or_default();
+    let current = SANDBOX_PROFILES
+        .iter()
+        .position(|profile| *profile == sandbox.profile)
+        .unwrap_or(0);
+    let selection = prompter.select(
+        "Sandbox profile (standard-proxied also limits network access)",
+        &SANDBOX_PROFILES,
+        current,
+    )?;
+    sandbox.enabled = true;
+    sandbox.profile = SANDBOX_PROFILES[selection].to_string();
+    Ok(Some(sandbox))
 }
 
-fn configure_sandbox_simple() -> Result<Option<crate::core::sandbox::SandboxConfig>> {
-    println!("\n🔒 Sandbox Security (optional)");
-    println!("Sandboxing limits file write access to protect against prompt injection.");
-
-    let enable_sandbox = Confirm::with_theme(&ColorfulTheme::default())
-        .with_prompt("Enable sandboxing by default?")
-        .default(false)
-        .interact()
-        .map_err(|e| ConfigError::Validation(format!("Failed to read input: {e}")))?;
+pub fn configure_docker(
+    docker: Option<DockerConfig>,
+    prompter: &mut dyn Prompter,
+) -> Result<Option<DockerConfig>> {
+    println!("\n🐳 Docker");
 
-    if !enable_sandbox {
+    if !prompter.confirm("Set Docker defaults for containers?", docker.is_some())? {
         return Ok(None);
     }
 
-    println!("Sandbox enabled. Using standard profile that allows writes to:");
-    println!("  • Project directory");
-    println!("  • Temporary files");
-    println!("  • Claude configuration (~/.claude)");
-    println!("  • Git configuration (~/.gitconfig)");
-    println!("  • Cache directories (~/.cache)");
-
-    Ok(Some(crate::core::sandbox::SandboxConfig {
-        enabled: true,
-        profile: "standard".to_string(),
-        allowed_domains: Vec::new(),
-    }))
+    let mut docker = docker.unwrap_or(DockerConfig {
+        setup_script: None,
+        default_image: None,
+        forward_env_keys: None,
+        cleanup_interval_minutes: None,
+    });
+    docker.default_image = optional(prompter.input(
+        "Default image (empty for para's image)",
+        docker.default_image.as_deref().unwrap_or_default(),
+    )?);
+    docker.setup_script = optional(prompter.input(
+        "Setup script run in new containers (empty for none)",
+        docker.setup_script.as_deref().unwrap_or_default(),
+    )?);
+    Ok(Some(docker))
 }
 
 fn display_config_summary(config: &Config) {
@@ -205,7 +422,212 @@ pub fn run_quick_setup() -> Result<Config> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config::{DirectoryConfig, GitConfig, IdeConfig, SessionConfig, WrapperConfig};
+    use crate::config::WrapperConfig;
+    use std::collections::VecDeque;
+
+    enum Answer {
+        /// Accept what the prompt offers
+        Keep,
+        Yes,
+        No,
+        Text(&'static str),
+        Pick(usize),
+    }
+
+    /// Answers given in order; records the default each prompt offered
+    struct Scripted {
+        answers: VecDeque<Answer>,
+        offered: Vec<String>,
+    }
+
+    impl Scripted {
+        fn new(answers: impl IntoIterator<Item = Answer>) -> Self {
+            Self {
+                answers: answers.into_iter().collect(),
+                offered: Vec::new(),
+            }
+        }
+
+        fn next(&mut self, default: String) -> Result<Answer> {
+            self.offered.push(default);
+            self.answers
+                .pop_front()
+                .ok_or_else(|| ConfigError::Validation("Failed to read input: interrupted".into()))
+        }
+    }
+
+    impl Prompter for Scripted {
+        fn confirm(&mut self, _prompt: &str, default: bool) -> Result<bool> {
+            Ok(match self.next(default.to_string())? {
+                Answer::Yes => true,
+                Answer::No => false,
+                _ => default,
+            })
+        }
+
+        fn input(&mut self, _prompt: &str, default: &str) -> Result<String> {
+            Ok(match self.next(default.to_string())? {
+                Answer::Text(text) => text.to_string(),
+                _ => default.to_string(),
+            })
+        }
+
+        fn select(&mut self, _prompt: &str, items: &[&str], default: usize) -> Result<usize> {
+            Ok(match self.next(items[default].to_string())? {
+                Answer::Pick(index) => index,
+                _ => default,
+            })
+        }
+    }
+
+    fn existing_config() -> Config {
+        let mut config = default_config();
+        config.ide.wrapper.name = "cursor".to_string();
+        config.ide.wrapper.command = "cursor".to_string();
+        config.directories.subtrees_dir = ".trees".to_string();
+        config.git.branch_prefix = "work".to_string();
+        config.git.auto_stage = false;
+        config.session.preserve_on_finish = true;
+        config.session.auto_cleanup_days = Some(7);
+        config.sandbox = Some(SandboxConfig {
+            enabled: true,
+            profile: "standard-proxied".to_string(),
+            allowed_domains: vec!["example.com".to_string()],
+        });
+        config.docker = Some(DockerConfig {
+            setup_script: None,
+            default_image: Some("para-dev:latest".to_string()),
+            forward_env_keys: Some(vec!["NPM_TOKEN".to_string()]),
+            cleanup_interval_minutes: None,
+        });
+        config
+    }
+
+    #[test]
+    fn test_sections_offer_existing_values() {
+        let existing = existing_config();
+        let mut prompter = Scripted::new(std::iter::repeat_with(|| Answer::Keep).take(20));
+        // The IDE section checks that the wrapper is installed
+        let sections = &WizardSection::ALL[1..];
+        let config = run_sections(existing.clone(), sections, &mut prompter, |_| Ok(())).unwrap();
+
+        assert_eq!(
+            prompter.offered,
+            [
+                ".trees",
+                existing.directories.state_dir.as_str(),
+                "work",
+                "false",
+                "true",
+                "true",
+                "true",
+                "7",
+                "true",
+                "standard-proxied",
+                "true",
+                "para-dev:latest",
+                "",
+            ]
+        );
+        assert_eq!(
+            serde_json::to_value(&config).unwrap(),
+            serde_json::to_value(&existing).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_section_answers_change_only_their_section() {
+        let mut prompter = Scripted::new([
+            Answer::Text("feature"),
+            Answer::Yes,
+            Answer::No,
+            Answer::Keep,
+            Answer::No,
+        ]);
+        let git = configure_git(existing_config().git, &mut prompter).unwrap();
+        assert_eq!(git.branch_prefix, "feature");
+        assert!(git.auto_stage);
+        assert!(!git.auto_commit);
+
+        let session = configure_session(existing_config().session, &mut prompter).unwrap();
+        assert!(session.preserve_on_finish);
+        assert_eq!(session.auto_cleanup_days, None);
+
+        // Turning cleanup back on offers the default period
+        let mut prompter = Scripted::new([Answer::Keep, Answer::Yes, Answer::Keep]);
+        let session = configure_session(session, &mut prompter).unwrap();
+        assert_eq!(session.auto_cleanup_days, Some(30));
+
+        let mut prompter = Scripted::new([Answer::Pick(0)]);
+        let ide = configure_ide(existing_config().ide, &mut prompter).unwrap();
+        assert_eq!(ide.wrapper.command, "code");
+        assert!(ide.wrapper.enabled);
+    }
+
+    #[test]
+    fn test_disabling_sandbox_and_docker() {
+        let mut prompter = Scripted::new([Answer::No, Answer::No]);
+        let sandbox = configure_sandbox(existing_config().sandbox, &mut prompter)
+            .unwrap()
+            .unwrap();
+        assert!(!sandbox.enabled);
+        assert_eq!(sandbox.profile, "standard-proxied");
+        assert_eq!(sandbox.allowed_domains, ["example.com"]);
+        assert!(configure_docker(existing_config().docker, &mut prompter)
+            .unwrap()
+            .is_none());
+
+        let mut prompter = Scripted::new([Answer::Yes, Answer::Keep]);
+        let sandbox = configure_sandbox(None, &mut prompter).unwrap().unwrap();
+        assert!(sandbox.enabled);
+        assert_eq!(sandbox.profile, "standard");
+
+        // Clearing the image falls back to para's image, other keys are kept
+        let mut prompter =
+            Scripted::new([Answer::Keep, Answer::Text(""), Answer::Text("./setup.sh")]);
+        let docker = configure_docker(existing_config().docker, &mut prompter)
+            .unwrap()
+            .unwrap();
+        assert_eq!(docker.default_image, None);
+        assert_eq!(docker.setup_script.as_deref(), Some("./setup.sh"));
+        assert_eq!(docker.forward_env_keys, Some(vec!["NPM_TOKEN".to_string()]));
+    }
+
+    #[test]
+    fn test_run_sections_reasks_invalid_section_and_persists_each() {
+        let mut prompter = Scripted::new([
+            // git: an invalid prefix is asked again, starting from the bad answer
+            Answer::Text("bad..prefix"),
+            Answer::Keep,
+            Answer::Keep,
+            Answer::Text("team"),
+            Answer::Keep,
+            Answer::Keep,
+            // session, interrupted after the first prompt
+            Answer::No,
+        ]);
+        let mut persisted = Vec::new();
+        let result = run_sections(
+            existing_config(),
+            &[
+                WizardSection::Git,
+                WizardSection::Git,
+                WizardSection::Session,
+            ],
+            &mut prompter,
+            |config| {
+                persisted.push(config.clone());
+                Ok(())
+            },
+        );
+
+        assert!(result.is_err());
+        assert_eq!(prompter.offered[3], "bad..prefix");
+        // The git answers were saved before the session section was interrupted
+        assert_eq!(persisted.len(), 1);
+        assert_eq!(persisted[0].git.branch_prefix, "team");
+        assert!(persisted[0].session.preserve_on_finish);
+    }
 
     #[test]
     fn test_config_summary_display() {
@@ -233,20 +655,41 @@ mod tests {
                     name: "wrapper-ide".to_string(),
                     command: "wrapper-command".to_string(),
                 },
+                remote: None,
             },
             directories: DirectoryConfig {
                 subtrees_dir: "test-subtrees".to_string(),
                 state_dir: "test-state".to_string(),
+                bare_root: None,
+                layout: Default::default(),
             },
             git: GitConfig {
                 branch_prefix: "test-prefix".to_string(),
                 auto_stage: false,
                 auto_commit: false,
+                author_email: None,
+                author_name: None,
+                protected_branches: Vec::new(),
+                auto_commit_interval_minutes: None,
+                default_merge_mode: None,
+                command_timeout_secs: None,
+                audit_log: false,
+                default_remote: None,
+                auto_fetch_interval_minutes: None,
+                auto_fetch: false,
+                apply_whitespace: Default::default(),
+                behind_warning_commits: None,
             },
             session: SessionConfig {
                 default_name_format: "%Y%m%d".to_string(),
                 preserve_on_finish: false,
                 auto_cleanup_days: None,
+                retention: None,
+                copy_untracked: Vec::new(),
+                idle_action: Default::default(),
+                idle_alert_hours: None,
+                conflict_hotspots: false,
+                on_resume: Vec::new(),
             },
             docker: None,
             setup_script: None,
@@ -255,6 +698,13 @@ mod tests {
                 profile: "restrictive".to_string(),
                 allowed_domains: Vec::new(),
             }),
+            notifications: None,
+            security: None,
+            status: None,
+            templates: Default::default(),
+            cleanup: None,
+            vcs: None,
+            ui: None,
         };
 
         display_config_summary(&config);
@@ -276,24 +726,52 @@ mod tests {
                     name: "cursor".to_string(),
                     command: "cursor".to_string(),
                 },
+                remote: None,
             },
             directories: DirectoryConfig {
                 subtrees_dir: "subtrees/para".to_string(),
                 state_dir: ".para_state".to_string(),
+                bare_root: None,
+                layout: Default::default(),
             },
             git: GitConfig {
                 branch_prefix: "para".to_string(),
                 auto_stage: true,
                 auto_commit: true,
+                author_email: None,
+                author_name: None,
+                protected_branches: Vec::new(),
+                auto_commit_interval_minutes: None,
+                default_merge_mode: None,
+                command_timeout_secs: None,
+                audit_log: false,
+                default_remote: None,
+                auto_fetch_interval_minutes: None,
+                auto_fetch: false,
+                apply_whitespace: Default::default(),
+                behind_warning_commits: None,
             },
             session: SessionConfig {
                 default_name_format: "%Y%m%d-%H%M%S".to_string(),
                 preserve_on_finish: false,
                 auto_cleanup_days: Some(30),
+                retention: None,
+                copy_untracked: Vec::new(),
+                idle_action: Default::default(),
+                idle_alert_hours: None,
+                conflict_hotspots: false,
+                on_resume: Vec::new(),
             },
             docker: None,
             setup_script: None,
             sandbox: None,
+            notifications: None,
+            security: None,
+            status: None,
+            templates: Default::default(),
+            cleanup: None,
+            vcs: None,
+            ui: None,
         };
 
         assert!(
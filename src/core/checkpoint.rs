@@ -0,0 +1,285 @@
+//! WIP checkpoints: commits of pending work inside session worktrees
+//!
+//! With `git.auto_commit` and `git.auto_commit_interval_minutes` set, the daemon
+//! commits each registered session's changes once per interval. A checkpoint is
+//! only taken when no file in the worktree changed during the last minute, so it
+//! never races the agent's editor. `finish` squashes these commits along with the
+//! rest of the session.
+
+use crate::config::defaults::default_config;
+use crate::core::git::GitRepository;
+use crate::core::session::{SessionManager, SessionStatus};
+use crate::ui::monitor::activity::{newest_file_mtime, MAX_SCANNED_ENTRIES};
+use crate::utils::{ParaError, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::thread;
+use std::time::Duration;
+
+/// Seconds without file changes before an automatic checkpoint may be taken
+pub const QUIESCENCE_SECS: i64 = 60;
+
+/// How often a registered session checks whether a checkpoint is due
+const POLL_INTERVAL: Duration = Duration::from_secs(15);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CheckpointOutcome {
+    /// A commit was created with this message
+    Created(String),
+    /// Nothing to commit
+    Clean,
+    /// Files changed too recently; try again later
+    Busy,
+}
+
+pub fn checkpoint_message(now: DateTime<Utc>) -> String {
+    format!("wip: auto-checkpoint {}", now.format("%Y-%m-%dT%H:%M"))
+}
+
+/// Whether no file in the worktree was modified in the last `QUIESCENCE_SECS`
+pub fn is_quiescent(worktree_path: &Path, now: DateTime<Utc>) -> bool {
+    newest_file_mtime(worktree_path, MAX_SCANNED_ENTRIES).is_none_or(|modified| {
+        now.signed_duration_since(modified) >= chrono::Duration::seconds(QUIESCENCE_SECS)
+    })
+}
+
+/// Commit all pending changes in the worktree. With `require_quiescence` the
+/// commit is skipped while files are still being written.
+pub fn create_checkpoint(
+    worktree_path: &Path,
+    now: DateTime<Utc>,
+    require_quiescence: bool,
+) -> Result<CheckpointOutcome> {
+    let repo = GitRepository::discover_from(worktree_path)?;
+    if !repo.has_uncommitted_changes()? {
+        return Ok(CheckpointOutcome::Clean);
+    }
+    if require_quiescence && !is_quiescent(worktree_path, now) {
+        return Ok(CheckpointOutcome::Busy);
+    }
+
+    let message = checkpoint_message(now);
+    repo.stage_all_changes()?;
+    repo.commit(&message)?;
+    Ok(CheckpointOutcome::Created(message))
+}
+
+/// Tracks when the next automatic checkpoint of one session is due
+#[derive(Debug, Clone)]
+pub struct CheckpointSchedule {
+    interval: chrono::Duration,
+    last_checkpoint: DateTime<Utc>,
+}
+
+impl CheckpointSchedule {
+    pub fn new(interval_minutes: u64, now: DateTime<Utc>) -> Self {
+        let minutes = i64::try_from(interval_minutes).unwrap_or(i64::MAX / 60_000);
+        Self {
+            interval: chrono::Duration::try_minutes(minutes).unwrap_or(chrono::Duration::MAX),
+            last_checkpoint: now,
+        }
+    }
+
+    /// Take a checkpoint once the interval has passed. A busy worktree is
+    /// retried on the next tick instead of waiting another full interval.
+    pub fn tick(
+        &mut self,
+        worktree_path: &Path,
+        now: DateTime<Utc>,
+    ) -> Result<Option<CheckpointOutcome>> {
+        if now.signed_duration_since(self.last_checkpoint) < self.interval {
+            return Ok(None);
+        }
+
+        let outcome = create_checkpoint(worktree_path, now, true)?;
+        if outcome != CheckpointOutcome::Busy {
+            self.last_checkpoint = now;
+        }
+        Ok(Some(outcome))
+    }
+}
+
+/// A session the daemon takes automatic checkpoints for
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckpointRegistration {
+    pub session_name: String,
+    pub worktree_path: PathBuf,
+    /// Absolute state directory, used to notice opt-outs and finished sessions
+    pub state_dir: PathBuf,
+    pub interval_minutes: u64,
+}
+
+impl CheckpointRegistration {
+    /// Whether the session still exists, is active and has not opted out
+    pub fn wants_checkpoints(&self) -> bool {
+        if !self.worktree_path.exists() {
+            return false;
+        }
+        let mut config = default_config();
+        config.directories.state_dir = self.state_dir.to_string_lossy().to_string();
+        SessionManager::new(&config)
+            .load_state(&self.session_name)
+            .is_ok_and(|state| {
+                matches!(state.status, SessionStatus::Active) && !state.skips_auto_commit()
+            })
+    }
+}
+
+/// Handle to stop a session's checkpoint thread
+pub struct CheckpointHandle {
+    stop_tx: Sender<()>,
+    thread_handle: Option<thread::JoinHandle<()>>,
+}
+
+impl CheckpointHandle {
+    pub fn stop(mut self) -> Result<()> {
+        let _ = self.stop_tx.send(());
+        if let Some(handle) = self.thread_handle.take() {
+            handle
+                .join()
+                .map_err(|_| ParaError::worktree_operation("Checkpoint thread panicked"))?;
+        }
+        Ok(())
+    }
+}
+
+/// Run automatic checkpoints for a session until it is stopped, finished,
+/// cancelled or opts out
+pub fn spawn_checkpointer(registration: CheckpointRegistration) -> CheckpointHandle {
+    let (stop_tx, stop_rx) = mpsc::channel();
+
+    let thread_handle = thread::spawn(move || {
+        let mut schedule = CheckpointSchedule::new(registration.interval_minutes, Utc::now());
+        loop {
+            match stop_rx.recv_timeout(POLL_INTERVAL) {
+                Err(RecvTimeoutError::Timeout) => {}
+                Ok(()) | Err(RecvTimeoutError::Disconnected) => return,
+            }
+            if !registration.wants_checkpoints() {
+                println!(
+                    "Stopped checkpoints for session: {}",
+                    registration.session_name
+                );
+                return;
+            }
+
+            match schedule.tick(&registration.worktree_path, Utc::now()) {
+                Ok(Some(CheckpointOutcome::Created(message))) => {
+                    println!("{}: {message}", registration.session_name);
+                }
+                Ok(_) => {}
+                Err(e) => eprintln!(
+                    "Checkpoint failed for session {}: {e}",
+                    registration.session_name
+                ),
+            }
+        }
+    });
+
+    CheckpointHandle {
+        stop_tx,
+        thread_handle: Some(thread_handle),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::git::repository::execute_git_command;
+    use crate::test_utils::test_helpers::*;
+    use filetime::FileTime;
+    use std::fs;
+
+    fn set_mtime(path: &Path, at: DateTime<Utc>) {
+        filetime::set_file_mtime(path, FileTime::from_unix_time(at.timestamp(), 0)).unwrap();
+    }
+
+    fn commit_count(repo: &GitRepository) -> usize {
+        execute_git_command(repo, &["rev-list", "--count", "HEAD"])
+            .unwrap()
+            .parse()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_quiescence_boundary() {
+        let (temp, _git_service) = setup_test_repo();
+        let file = temp.path().join("work.txt");
+        fs::write(&file, "edit").unwrap();
+
+        let modified = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        set_mtime(&file, modified);
+        for entry in fs::read_dir(temp.path()).unwrap().flatten() {
+            if entry.file_type().unwrap().is_file() {
+                set_mtime(&entry.path(), modified);
+            }
+        }
+
+        let after = |secs| modified + chrono::Duration::seconds(secs);
+        assert!(!is_quiescent(temp.path(), after(0)));
+        assert!(!is_quiescent(temp.path(), after(QUIESCENCE_SECS - 1)));
+        assert!(is_quiescent(temp.path(), after(QUIESCENCE_SECS)));
+    }
+
+    #[test]
+    fn test_schedule_creates_checkpoint_when_due_and_quiet() {
+        let (temp, git_service) = setup_test_repo();
+        let repo = git_service.repository();
+        let start = Utc::now();
+        let mut schedule = CheckpointSchedule::new(10, start);
+        let commits = commit_count(repo);
+
+        // Not due yet, nothing changed
+        let minutes = chrono::Duration::minutes;
+        assert_eq!(
+            schedule.tick(temp.path(), start + minutes(5)).unwrap(),
+            None
+        );
+        assert_eq!(
+            schedule.tick(temp.path(), start + minutes(10)).unwrap(),
+            Some(CheckpointOutcome::Clean)
+        );
+
+        // The agent is still writing: retry on the next tick
+        let file = temp.path().join("feature.rs");
+        fs::write(&file, "fn main() {}").unwrap();
+        let now = start + minutes(20);
+        set_mtime(&file, now - chrono::Duration::seconds(10));
+        assert_eq!(
+            schedule.tick(temp.path(), now).unwrap(),
+            Some(CheckpointOutcome::Busy)
+        );
+        assert_eq!(commit_count(repo), commits);
+
+        let later = now + chrono::Duration::seconds(QUIESCENCE_SECS);
+        assert_eq!(
+            schedule.tick(temp.path(), later).unwrap(),
+            Some(CheckpointOutcome::Created(checkpoint_message(later)))
+        );
+        assert_eq!(commit_count(repo), commits + 1);
+        assert!(!repo.has_uncommitted_changes().unwrap());
+        assert_eq!(
+            execute_git_command(repo, &["log", "-1", "--format=%s"]).unwrap(),
+            checkpoint_message(later)
+        );
+
+        // The interval restarts after a checkpoint
+        assert_eq!(
+            schedule.tick(temp.path(), later + minutes(1)).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_checkpoint_message_format() {
+        let at = DateTime::parse_from_rfc3339("2024-06-11T13:20:45Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(
+            checkpoint_message(at),
+            "wip: auto-checkpoint 2024-06-11T13:20"
+        );
+    }
+}
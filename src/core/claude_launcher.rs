@@ -1,4 +1,6 @@
 use crate::config::Config;
+use crate::core::ide::launch_tasks::{claude_command, LaunchBackend, TASK_LABEL};
+use crate::core::ide::wrapper_launch_args;
 use crate::core::sandbox::config::SandboxResolver;
 use crate::core::sandbox::launcher::{
     generate_network_sandbox_wrapper, is_sandbox_available, wrap_command_with_sandbox,
@@ -33,9 +35,9 @@ pub fn launch_claude_with_context(
     session_path: &Path,
     options: ClaudeLaunchOptions,
 ) -> Result<()> {
-    let vscode_dir = session_path.join(".vscode");
-    fs::create_dir_all(&vscode_dir)
-        .map_err(|e| ParaError::fs_error(format!("Failed to create .vscode directory: {e}")))?;
+    let backend = LaunchBackend::for_config(config);
+    let launch_dir = session_path.join(backend.dir());
+    fs::create_dir_all(&launch_dir).map_err(|e| ParaError::from_io("create", &launch_dir, e))?;
 
     // Resolve sandbox settings using the resolver
     let resolver = SandboxResolver::new(config);
@@ -56,12 +58,6 @@ pub fn launch_claude_with_context(
         );
     }
 
-    // Build base command
-    let mut base_cmd = config.ide.command.clone();
-    if options.skip_permissions {
-        base_cmd.push_str(" --dangerously-skip-permissions");
-    }
-
     // Handle prompt content via temporary file
     let temp_prompt_file = session_path.join(".claude_prompt_temp");
     if let Some(ref content) = options.prompt_content {
@@ -71,60 +67,13 @@ pub fn launch_claude_with_context(
             })?;
         }
     }
-
-    // Build Claude command based on continuation mode
-    let claude_task_cmd = if let Some(ref session_id) = options.session_id {
-        if !session_id.is_empty() {
-            // Resume existing session with optional prompt
-            if temp_prompt_file.exists() {
-                format!(
-                    "{} -r \"{}\" \"$(cat '{}'; rm '{}')\"",
-                    base_cmd,
-                    session_id,
-                    temp_prompt_file.display(),
-                    temp_prompt_file.display()
-                )
-            } else {
-                format!("{base_cmd} -r \"{session_id}\"")
-            }
-        } else {
-            // Empty session ID, fall back to -c
-            if temp_prompt_file.exists() {
-                format!(
-                    "{} -c \"$(cat '{}'; rm '{}')\"",
-                    base_cmd,
-                    temp_prompt_file.display(),
-                    temp_prompt_file.display()
-                )
-            } else {
-                format!("{base_cmd} -c")
-            }
-        }
-    } else if options.continue_conversation {
-        // Continue conversation mode
-        if temp_prompt_file.exists() {
-            format!(
-                "{} -c \"$(cat '{}'; rm '{}')\"",
-                base_cmd,
-                temp_prompt_file.display(),
-                temp_prompt_file.display()
-            )
-        } else {
-            format!("{base_cmd} -c")
-        }
-    } else {
-        // New session with optional prompt
-        if temp_prompt_file.exists() {
-            format!(
-                "{} \"$(cat '{}'; rm '{}')\"",
-                base_cmd,
-                temp_prompt_file.display(),
-                temp_prompt_file.display()
-            )
-        } else {
-            base_cmd
-        }
-    };
+    let claude_task_cmd = claude_command(
+        &config.ide.command,
+        &options,
+        temp_prompt_file
+            .exists()
+            .then_some(temp_prompt_file.as_path()),
+    );
 
     // Check if we need sandboxing
     let should_sandbox = sandbox_settings.enabled && cfg!(target_os = "macos");
@@ -188,7 +137,7 @@ pub fn launch_claude_with_context(
     // Handle wrapper script or direct command
     let task_command = if needs_wrapper_script {
         // Write the wrapper script
-        let script_path = vscode_dir.join("para-sandbox-launcher.sh");
+        let script_path = launch_dir.join("para-sandbox-launcher.sh");
         fs::write(&script_path, &final_command)
             .map_err(|e| ParaError::fs_error(format!("Failed to write launcher script: {e}")))?;
 
@@ -202,36 +151,37 @@ pub fn launch_claude_with_context(
         }
 
         println!("\n📝 Network sandboxing configured");
-        println!("   VS Code will run the sandboxed Claude when you allow the task");
+        println!("   The IDE will run the sandboxed Claude when you start the task");
         println!("   The launcher script will self-delete after execution");
 
         // The task will run the script
-        "./.vscode/para-sandbox-launcher.sh".to_string()
+        format!("./{}/para-sandbox-launcher.sh", backend.dir())
     } else {
         final_command
     };
 
-    // Create tasks.json with the command
-    let tasks_json = create_claude_task_json(&task_command);
-    let tasks_file = vscode_dir.join("tasks.json");
-    fs::write(&tasks_file, tasks_json)
-        .map_err(|e| ParaError::fs_error(format!("Failed to write tasks.json: {e}")))?;
+    let tasks_file = backend.write(session_path, &task_command)?;
 
-    // Add .vscode/tasks.json to gitignore to prevent it from showing in git status
-    // This handles the case where VS Code might recreate the file after our cleanup
+    // Keep the launch file out of git status, also when the IDE recreates it
+    // after our cleanup
     let gitignore_manager = GitignoreManager::new(session_path.to_str().unwrap_or("."));
-
-    // Add the entry to gitignore (the manager will handle comments appropriately)
-    if let Err(e) = gitignore_manager.add_entry(".vscode/tasks.json") {
+    if let Err(e) = gitignore_manager.add_entry(backend.relative_path()) {
         // Log warning but don't fail - this is a best-effort operation
-        eprintln!("Warning: Failed to add .vscode/tasks.json to gitignore: {e}");
+        eprintln!(
+            "Warning: Failed to add {} to gitignore: {e}",
+            backend.relative_path()
+        );
     }
 
     // Launch IDE wrapper
     let (ide_command, ide_name) = (&config.ide.wrapper.command, &config.ide.wrapper.name);
     let mut cmd = Command::new(ide_command);
     cmd.current_dir(session_path);
-    cmd.arg(session_path);
+    cmd.args(wrapper_launch_args(
+        ide_name,
+        session_path,
+        config.ide.remote.as_ref(),
+    ));
 
     // Detach the IDE process
     cmd.stdin(std::process::Stdio::null());
@@ -239,15 +189,23 @@ pub fn launch_claude_with_context(
     cmd.stderr(std::process::Stdio::null());
 
     match cmd.spawn() {
-        Ok(_) => {
-            println!(
-                "✅ VS Code opened - {} will start automatically",
-                config.ide.name
-            );
-
-            // Spawn a background cleanup task for the tasks.json file
-            spawn_tasks_cleanup(tasks_file);
-        }
+        Ok(_) => match backend {
+            LaunchBackend::VsCodeTasks => {
+                println!(
+                    "✅ VS Code opened - {} will start automatically",
+                    config.ide.name
+                );
+
+                // Spawn a background cleanup task for the tasks.json file
+                spawn_tasks_cleanup(tasks_file);
+            }
+            LaunchBackend::JetBrainsRunConfig => {
+                println!(
+                    "✅ {ide_name} opened - run the '{TASK_LABEL}' run configuration to start {}",
+                    config.ide.name
+                );
+            }
+        },
         Err(e) => {
             return Err(ParaError::ide_error(format!(
                 "Failed to launch {ide_name}: {e}. Check that '{ide_command}' is installed and accessible."
@@ -258,42 +216,6 @@ pub fn launch_claude_with_context(
     Ok(())
 }
 
-/// Create tasks.json for Claude with proper escaping
-fn create_claude_task_json(command: &str) -> String {
-    format!(
-        r#"{{
-    "version": "2.0.0",
-    "tasks": [
-        {{
-            "label": "Start claude",
-            "type": "shell",
-            "command": "{}",
-            "group": "build",
-            "options": {{
-                "env": {{
-                    "FORCE_COLOR": "1",
-                    "COLORTERM": "truecolor",
-                    "TERM": "xterm-256color"
-                }}
-            }},
-            "presentation": {{
-                "echo": true,
-                "reveal": "always",
-                "focus": true,
-                "panel": "new",
-                "showReuseMessage": false,
-                "clear": false
-            }},
-            "runOptions": {{
-                "runOn": "folderOpen"
-            }}
-        }}
-    ]
-}}"#,
-        command.replace('"', "\\\"")
-    )
-}
-
 /// Spawn a background thread to clean up the tasks.json file after VS Code has had time to read it
 fn spawn_tasks_cleanup(tasks_file: PathBuf) {
     thread::spawn(move || {
@@ -377,61 +299,6 @@ mod tests {
         );
     }
 
-    #[test]
-    fn test_create_claude_task_json_basic() {
-        let command = "claude --version";
-        let json = create_claude_task_json(command);
-
-        // Should be valid JSON structure
-        assert!(json.contains(r#""version": "2.0.0""#));
-        assert!(json.contains(r#""label": "Start claude""#));
-        assert!(json.contains(r#""type": "shell""#));
-        assert!(json.contains(r#""command": "claude --version""#));
-        assert!(json.contains(r#""runOn": "folderOpen""#));
-
-        // Should contain environment variables for color support
-        assert!(json.contains(r#""options""#));
-        assert!(json.contains(r#""env""#));
-        assert!(json.contains(r#""FORCE_COLOR": "1""#));
-        assert!(json.contains(r#""COLORTERM": "truecolor""#));
-        assert!(json.contains(r#""TERM": "xterm-256color""#));
-    }
-
-    #[test]
-    fn test_create_claude_task_json_escaping() {
-        let command = r#"claude -r "session-id" "prompt with \"quotes\"""#;
-        let json = create_claude_task_json(command);
-
-        // Should escape inner quotes correctly
-        let expected_in_json = command.replace('"', "\\\"");
-        assert!(json.contains(&expected_in_json));
-
-        // Should be valid JSON structure
-        assert!(json.contains(r#""version": "2.0.0""#));
-        assert!(json.contains(r#""label": "Start claude""#));
-    }
-
-    #[test]
-    fn test_create_claude_task_json_complex_escaping() {
-        let command = r#"claude -c "test with 'single' and \"double\" quotes""#;
-        let json = create_claude_task_json(command);
-
-        // The command.replace('"', "\\\"") will convert:
-        // claude -c "test with 'single' and \"double\" quotes"
-        // to:
-        // claude -c \"test with 'single' and \\\"double\\\" quotes\"
-        let expected_escaped = command.replace('"', "\\\"");
-        assert!(json.contains(&expected_escaped));
-
-        // Verify JSON structure is intact
-        assert!(json.starts_with("{\n"));
-        assert!(json.ends_with("}"));
-        assert!(json.contains(r#""tasks": ["#));
-
-        // Check for single task
-        assert_eq!(json.matches(r#""label":"#).count(), 1);
-    }
-
     #[test]
     fn test_launch_claude_with_context_basic() {
         let temp_dir = TempDir::new().unwrap();
@@ -733,28 +600,6 @@ mod tests {
         assert!(tasks_content.contains(".claude_prompt_temp"));
     }
 
-    #[test]
-    fn test_create_claude_task_json_formatting() {
-        let command = "test-command";
-        let json = create_claude_task_json(command);
-
-        // Check proper formatting and indentation
-        assert!(json.starts_with("{\n"));
-        assert!(json.contains("    \"version\": \"2.0.0\",\n"));
-        assert!(json.contains("    \"tasks\": [\n"));
-        assert!(json.contains("        {\n"));
-        assert!(json.contains("            \"label\": \"Start claude\",\n"));
-        assert!(json.ends_with("}"));
-
-        // Check all required fields are present
-        assert!(json.contains("\"type\": \"shell\""));
-        assert!(json.contains("\"group\": \"build\""));
-        assert!(json.contains("\"presentation\""));
-        assert!(json.contains("\"runOptions\""));
-        assert!(json.contains("\"runOn\": \"folderOpen\""));
-        // Simple task doesn't have problemMatcher or dependsOrder
-    }
-
     #[test]
     fn test_launch_claude_with_sandbox_enabled() {
         // Skip test on non-macOS platforms
@@ -954,6 +799,35 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_launch_claude_writes_jetbrains_run_configuration() {
+        let temp_dir = TempDir::new().unwrap();
+        let session_path = temp_dir.path().join("test-session");
+        fs::create_dir_all(&session_path).unwrap();
+
+        let mut config = create_test_config();
+        config.ide.wrapper.enabled = true;
+        config.ide.wrapper.name = "idea".to_string();
+        let options = ClaudeLaunchOptions {
+            skip_permissions: true,
+            prompt_content: Some("Add tests".to_string()),
+            ..Default::default()
+        };
+
+        launch_claude_with_context(&config, &session_path, options).unwrap();
+
+        assert!(!session_path.join(".vscode/tasks.json").exists());
+        let xml = fs::read_to_string(session_path.join(".run/para-claude.run.xml")).unwrap();
+        let run_config = crate::core::ide::launch_tasks::RunConfiguration::from_xml(&xml).unwrap();
+        assert!(run_config
+            .command
+            .starts_with("echo --dangerously-skip-permissions \"$(cat"));
+        assert!(run_config.command.contains(".claude_prompt_temp"));
+
+        let gitignore = fs::read_to_string(session_path.join(".gitignore")).unwrap();
+        assert!(gitignore.contains(".run/para-claude.run.xml"));
+    }
+
     #[test]
     fn test_launch_claude_handles_duplicate_gitignore_entry() {
         let temp_dir = TempDir::new().unwrap();
@@ -0,0 +1,172 @@
+//! Kinds of items `para clean` removes, and which of them a run may touch
+//!
+//! With `cleanup.enabled_kinds` set, `para clean --force` only removes the
+//! listed kinds, so new cleanup analyzers never act unattended until they are
+//! opted in. Interactive runs still offer every kind, since each is confirmed.
+//! `--only` and `--skip` override the config for a single run.
+
+use crate::utils::{ParaError, Result};
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, clap::ValueEnum)]
+#[value(rename_all = "snake_case")]
+pub enum CleanupKind {
+    /// Para branch without a session or archive entry
+    StaleBranch,
+    /// State file whose session branch is gone
+    OrphanedStateFile,
+    /// Archived session older than `session.auto_cleanup_days`
+    OldArchive,
+    /// Agent status file that has not been updated for a day
+    StaleStatusFile,
+    /// Para container without a session (`--containers`)
+    OrphanedContainer,
+    /// Dangling para-labeled volume, network or image (`--docker`)
+    DockerResource,
+    /// Active session to cancel and archive (`--sessions`)
+    ActiveSession,
+}
+
+impl CleanupKind {
+    pub const ALL: [CleanupKind; 7] = [
+        CleanupKind::StaleBranch,
+        CleanupKind::OrphanedStateFile,
+        CleanupKind::OldArchive,
+        CleanupKind::StaleStatusFile,
+        CleanupKind::OrphanedContainer,
+        CleanupKind::DockerResource,
+        CleanupKind::ActiveSession,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            CleanupKind::StaleBranch => "stale_branch",
+            CleanupKind::OrphanedStateFile => "orphaned_state_file",
+            CleanupKind::OldArchive => "old_archive",
+            CleanupKind::StaleStatusFile => "stale_status_file",
+            CleanupKind::OrphanedContainer => "orphaned_container",
+            CleanupKind::DockerResource => "docker_resource",
+            CleanupKind::ActiveSession => "active_session",
+        }
+    }
+
+    pub fn parse(name: &str) -> Result<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|kind| kind.name() == name)
+            .ok_or_else(|| {
+                ParaError::invalid_args(format!(
+                    "Unknown cleanup kind '{name}'. Valid kinds: {}",
+                    valid_kinds()
+                ))
+            })
+    }
+}
+
+impl fmt::Display for CleanupKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Comma-separated names of all kinds
+pub fn valid_kinds() -> String {
+    CleanupKind::ALL
+        .iter()
+        .map(CleanupKind::name)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Kinds a clean run may remove. `enabled_kinds` (from the config) only limits
+/// forced runs; `only` replaces it and `skip` is taken out of the result.
+pub fn allowed_kinds(
+    enabled_kinds: Option<&[String]>,
+    force: bool,
+    only: &[CleanupKind],
+    skip: &[CleanupKind],
+) -> Result<Vec<CleanupKind>> {
+    let base = if !only.is_empty() {
+        only.to_vec()
+    } else {
+        match enabled_kinds {
+            Some(names) if force => names
+                .iter()
+                .map(|name| CleanupKind::parse(name))
+                .collect::<Result<Vec<_>>>()?,
+            _ => CleanupKind::ALL.to_vec(),
+        }
+    };
+
+    Ok(CleanupKind::ALL
+        .into_iter()
+        .filter(|kind| base.contains(kind) && !skip.contains(kind))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use CleanupKind::*;
+
+    fn names(kinds: &[&str]) -> Vec<String> {
+        kinds.iter().map(|kind| kind.to_string()).collect()
+    }
+
+    #[test]
+    fn test_names_round_trip() {
+        for kind in CleanupKind::ALL {
+            assert_eq!(CleanupKind::parse(kind.name()).unwrap(), kind);
+        }
+        let error = CleanupKind::parse("old_archives").unwrap_err().to_string();
+        assert!(error.contains("Unknown cleanup kind 'old_archives'"));
+        assert!(error.contains("stale_branch, orphaned_state_file, old_archive"));
+    }
+
+    #[test]
+    fn test_config_only_limits_forced_runs() {
+        let enabled = names(&["stale_branch", "old_archive"]);
+
+        assert_eq!(
+            allowed_kinds(Some(&enabled), true, &[], &[]).unwrap(),
+            vec![StaleBranch, OldArchive]
+        );
+        assert_eq!(
+            allowed_kinds(Some(&enabled), false, &[], &[]).unwrap(),
+            CleanupKind::ALL.to_vec()
+        );
+        assert_eq!(
+            allowed_kinds(None, true, &[], &[]).unwrap(),
+            CleanupKind::ALL.to_vec()
+        );
+        assert!(allowed_kinds(Some(&names(&["bogus"])), true, &[], &[]).is_err());
+    }
+
+    #[test]
+    fn test_only_and_skip_override_config() {
+        let enabled = names(&["stale_branch"]);
+
+        assert_eq!(
+            allowed_kinds(Some(&enabled), true, &[OldArchive, StaleStatusFile], &[]).unwrap(),
+            vec![OldArchive, StaleStatusFile]
+        );
+        assert_eq!(
+            allowed_kinds(Some(&enabled), true, &[], &[StaleBranch]).unwrap(),
+            Vec::<CleanupKind>::new()
+        );
+        assert_eq!(
+            allowed_kinds(None, false, &[], &[ActiveSession, DockerResource]).unwrap(),
+            vec![
+                StaleBranch,
+                OrphanedStateFile,
+                OldArchive,
+                StaleStatusFile,
+                OrphanedContainer
+            ]
+        );
+        assert_eq!(
+            allowed_kinds(None, true, &[StaleBranch, OldArchive], &[OldArchive]).unwrap(),
+            vec![StaleBranch]
+        );
+    }
+}
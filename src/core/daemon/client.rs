@@ -2,6 +2,7 @@
 
 use super::{daemon_socket_path, DaemonCommand, DaemonResponse};
 use crate::config::Config;
+use crate::core::checkpoint::CheckpointRegistration;
 use anyhow::Result;
 use std::io::{Read, Write};
 use std::os::unix::net::UnixStream;
@@ -29,8 +30,56 @@ pub fn register_container_session(
         repo_root,
     };
 
-    // Try to send command, start daemon if needed
-    match send_command(&command) {
+    send_with_autostart(&command)
+}
+
+/// Register a session for automatic WIP checkpoints every `interval_minutes`
+pub fn register_checkpoint_session(
+    session_name: &str,
+    worktree_path: &Path,
+    state_dir: &Path,
+    interval_minutes: u64,
+) -> Result<()> {
+    let command = DaemonCommand::RegisterCheckpointSession(CheckpointRegistration {
+        session_name: session_name.to_string(),
+        worktree_path: worktree_path.to_path_buf(),
+        state_dir: state_dir.to_path_buf(),
+        interval_minutes,
+    });
+
+    send_with_autostart(&command)
+}
+
+/// Ask the daemon to fetch the base branches of `repo_root`'s sessions now,
+/// starting it if needed
+pub fn prefetch_now(repo_root: &Path) -> Result<()> {
+    send_with_autostart(&DaemonCommand::PrefetchNow {
+        repo_root: repo_root.to_path_buf(),
+    })
+}
+
+/// Stop watching and checkpointing a session. A daemon that is not running,
+/// or does not know the session, has nothing to stop.
+pub fn unregister_session(session_name: &str) -> Result<()> {
+    unregister_session_at(&daemon_socket_path(), session_name)
+}
+
+fn unregister_session_at(socket_path: &Path, session_name: &str) -> Result<()> {
+    let command = DaemonCommand::UnregisterSession {
+        session_name: session_name.to_string(),
+    };
+
+    match send_command_to(socket_path, &command) {
+        Ok(DaemonResponse::Ok) | Err(_) => Ok(()),
+        Ok(DaemonResponse::Error(e)) if e.starts_with("Session not found") => Ok(()),
+        Ok(DaemonResponse::Error(e)) => Err(anyhow::anyhow!("Daemon error: {}", e)),
+        Ok(_) => Err(anyhow::anyhow!("Unexpected daemon response")),
+    }
+}
+
+/// Send a command, starting the daemon first if it is not running
+fn send_with_autostart(command: &DaemonCommand) -> Result<()> {
+    match send_command(command) {
         Ok(DaemonResponse::Ok) => Ok(()),
         Ok(DaemonResponse::Error(e)) => Err(anyhow::anyhow!("Daemon error: {}", e)),
         Ok(_) => Err(anyhow::anyhow!("Unexpected daemon response")),
@@ -39,7 +88,7 @@ pub fn register_container_session(
             start_daemon_if_needed()?;
 
             // Retry sending command
-            match send_command(&command) {
+            match send_command(command) {
                 Ok(DaemonResponse::Ok) => Ok(()),
                 Ok(DaemonResponse::Error(e)) => Err(anyhow::anyhow!("Daemon error: {}", e)),
                 _ => Err(anyhow::anyhow!("Failed to communicate with daemon")),
@@ -50,14 +99,17 @@ pub fn register_container_session(
 
 /// Send a command to the daemon
 fn send_command(command: &DaemonCommand) -> Result<DaemonResponse> {
-    let socket_path = daemon_socket_path();
+    send_command_to(&daemon_socket_path(), command)
+}
 
+/// Send a command to the daemon listening on `socket_path`
+fn send_command_to(socket_path: &Path, command: &DaemonCommand) -> Result<DaemonResponse> {
     // Check if socket exists to avoid 5-second connection timeout
     if !socket_path.exists() {
         return Err(anyhow::anyhow!("Daemon socket does not exist"));
     }
 
-    let mut stream = UnixStream::connect(&socket_path)?;
+    let mut stream = UnixStream::connect(socket_path)?;
 
     // Set timeout
     stream.set_read_timeout(Some(Duration::from_secs(5)))?;
@@ -174,3 +226,82 @@ fn find_repo_root(worktree_path: &Path) -> Result<std::path::PathBuf> {
 
     Err(anyhow::anyhow!("Could not find repository root"))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader};
+    use std::os::unix::net::UnixListener;
+    use std::path::PathBuf;
+    use std::thread;
+    use tempfile::TempDir;
+
+    /// Answer one command on a socket in `dir` with `response`, returning the
+    /// socket path and the command that was received
+    fn stub_daemon(
+        dir: &TempDir,
+        response: DaemonResponse,
+    ) -> (PathBuf, thread::JoinHandle<DaemonCommand>) {
+        let socket_path = dir.path().join("daemon.sock");
+        let listener = UnixListener::bind(&socket_path).unwrap();
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut line = String::new();
+            BufReader::new(stream.try_clone().unwrap())
+                .read_line(&mut line)
+                .unwrap();
+            let reply = serde_json::to_string(&response).unwrap();
+            stream.write_all(format!("{reply}\n").as_bytes()).unwrap();
+            serde_json::from_str(line.trim()).unwrap()
+        });
+        (socket_path, handle)
+    }
+
+    #[test]
+    fn test_unregister_session_sends_command() {
+        let dir = TempDir::new().unwrap();
+        let (socket_path, daemon) = stub_daemon(&dir, DaemonResponse::Ok);
+
+        unregister_session_at(&socket_path, "my-session").unwrap();
+        match daemon.join().unwrap() {
+            DaemonCommand::UnregisterSession { session_name } => {
+                assert_eq!(session_name, "my-session")
+            }
+            other => panic!("unexpected command: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_unregister_unknown_session_is_ok() {
+        let dir = TempDir::new().unwrap();
+        let (socket_path, daemon) = stub_daemon(
+            &dir,
+            DaemonResponse::Error("Session not found: my-session".to_string()),
+        );
+
+        unregister_session_at(&socket_path, "my-session").unwrap();
+        daemon.join().unwrap();
+    }
+
+    #[test]
+    fn test_unregister_reports_daemon_errors() {
+        let dir = TempDir::new().unwrap();
+        let (socket_path, daemon) =
+            stub_daemon(&dir, DaemonResponse::Error("watcher panicked".to_string()));
+
+        let err = unregister_session_at(&socket_path, "my-session").unwrap_err();
+        assert!(err.to_string().contains("watcher panicked"));
+        daemon.join().unwrap();
+    }
+
+    #[test]
+    fn test_unregister_without_daemon_is_silent() {
+        let dir = TempDir::new().unwrap();
+        let socket_path = dir.path().join("daemon.sock");
+        unregister_session_at(&socket_path, "my-session").unwrap();
+
+        // A socket left behind by a crashed daemon refuses connections
+        drop(UnixListener::bind(&socket_path).unwrap());
+        unregister_session_at(&socket_path, "my-session").unwrap();
+    }
+}
@@ -1,11 +1,15 @@
 //! Para daemon for managing background tasks like signal file watchers
 //!
-//! The daemon runs as a single process and manages watchers for all repositories.
+//! The daemon runs as a single process and manages watchers and automatic
+//! checkpoints for all repositories.
 //! It uses Unix domain sockets for IPC.
 
 pub mod client;
+pub mod prefetch;
+pub mod registry;
 pub mod server;
 
+use crate::core::checkpoint::CheckpointRegistration;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
@@ -18,8 +22,12 @@ pub enum DaemonCommand {
         worktree_path: PathBuf,
         repo_root: PathBuf,
     },
-    /// Stop watching a specific session
+    /// Take periodic WIP checkpoints of a session's worktree
+    RegisterCheckpointSession(CheckpointRegistration),
+    /// Stop watching and checkpointing a specific session
     UnregisterSession { session_name: String },
+    /// Fetch the base branches of a repository's sessions now
+    PrefetchNow { repo_root: PathBuf },
     /// Check if daemon is alive
     Ping,
     /// Get daemon version
@@ -60,3 +68,74 @@ pub fn daemon_pid_path() -> PathBuf {
 
     runtime_dir.join("para-daemon.pid")
 }
+
+/// Get the path to the file of sessions registered with the daemon
+pub fn daemon_registry_path() -> PathBuf {
+    daemon_pid_path().with_file_name("para-daemon-sessions.json")
+}
+
+/// What the daemon PID file records: the daemon's PID on the first line and,
+/// since para started recording it, the para version it runs on the second
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DaemonPidFile {
+    pub pid: u32,
+    pub version: Option<String>,
+}
+
+impl DaemonPidFile {
+    pub fn current() -> Self {
+        Self {
+            pid: std::process::id(),
+            version: Some(crate::core::version::PARA_VERSION.to_string()),
+        }
+    }
+
+    pub fn parse(content: &str) -> Option<Self> {
+        let mut lines = content.lines();
+        let pid = lines.next()?.trim().parse().ok()?;
+        let version = lines
+            .next()
+            .map(str::trim)
+            .filter(|version| !version.is_empty())
+            .map(str::to_string);
+        Some(Self { pid, version })
+    }
+
+    pub fn read() -> Option<Self> {
+        Self::parse(&std::fs::read_to_string(daemon_pid_path()).ok()?)
+    }
+
+    pub fn write(&self) -> std::io::Result<()> {
+        let mut content = self.pid.to_string();
+        if let Some(ref version) = self.version {
+            content.push('\n');
+            content.push_str(version);
+        }
+        std::fs::write(daemon_pid_path(), content)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pid_file_with_and_without_version() {
+        assert_eq!(
+            DaemonPidFile::parse("4242\n1.2.3\n"),
+            Some(DaemonPidFile {
+                pid: 4242,
+                version: Some("1.2.3".to_string())
+            })
+        );
+        // Written before the version was recorded
+        assert_eq!(
+            DaemonPidFile::parse("4242"),
+            Some(DaemonPidFile {
+                pid: 4242,
+                version: None
+            })
+        );
+        assert_eq!(DaemonPidFile::parse("not a pid"), None);
+    }
+}
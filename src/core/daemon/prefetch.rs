@@ -0,0 +1,617 @@
+//! Background fetches of the base branches registered sessions depend on
+//!
+//! With `git.auto_fetch` the daemon keeps the base branch of every session it
+//! watches or checkpoints fetched, once per `git.auto_fetch_interval_minutes`
+//! and never while para runs offline. Fetches run one at a time on a single
+//! thread, so a repository never sees two at once. A failing fetch is retried
+//! after a delay that doubles with each failure, up to [`MAX_BACKOFF_MINUTES`].
+//!
+//! Every outcome is written to `para-daemon-prefetch.json` next to the PID
+//! file. `para start` skips its own `git.auto_fetch` when the base was fetched
+//! there within the interval, and `para daemon status` shows how fresh each
+//! base is.
+
+use super::daemon_pid_path;
+use super::registry::Registration;
+use crate::config::{Config, ConfigManager};
+use crate::core::git::GitRepository;
+use crate::core::network::NetworkContext;
+use crate::core::session::summary::format_duration;
+use crate::core::session::SessionManager;
+use crate::utils::get_main_repository_root_from;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::Arc;
+use std::thread;
+
+/// Delay before retrying a failed fetch the first time
+const RETRY_AFTER_MINUTES: i64 = 1;
+
+/// Longest delay between retries of a failing fetch
+pub const MAX_BACKOFF_MINUTES: i64 = 60;
+
+/// How often the daemon checks whether a fetch is due
+const POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(30);
+
+/// Get the path to the file of the daemon's fetch outcomes
+pub fn daemon_prefetch_path() -> PathBuf {
+    daemon_pid_path().with_file_name("para-daemon-prefetch.json")
+}
+
+/// A base branch on a remote of one repository
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct PrefetchTarget {
+    /// Main repository root, the same for all its worktrees
+    pub repo_root: PathBuf,
+    pub remote: String,
+    pub branch: String,
+}
+
+impl PrefetchTarget {
+    /// The base branch of a registered session, if it has one on a remote
+    pub fn for_registration(registration: &Registration, config: &Config) -> Option<Self> {
+        let (session_name, worktree_path, state_dir) = match registration {
+            Registration::Container {
+                session_name,
+                worktree_path,
+                ..
+            } => (
+                session_name,
+                worktree_path,
+                crate::config::state_dir::resolve_state_dir(
+                    &config.directories.state_dir,
+                    config.directories.bare_root.as_deref(),
+                    Some(worktree_path),
+                ),
+            ),
+            Registration::Checkpoint(checkpoint) => (
+                &checkpoint.session_name,
+                &checkpoint.worktree_path,
+                checkpoint.state_dir.clone(),
+            ),
+        };
+
+        let mut config = config.clone();
+        config.directories.state_dir = state_dir.to_string_lossy().to_string();
+        let state = SessionManager::new(&config).load_state(session_name).ok()?;
+        let repo = GitRepository::discover_from(worktree_path).ok()?;
+        let base = match state.parent_branch {
+            Some(parent) => parent,
+            None => repo.get_main_branch().ok()?,
+        };
+
+        let remotes = repo.list_remotes().ok()?;
+        let (remote, branch) = match base
+            .split_once('/')
+            .filter(|(prefix, _)| remotes.iter().any(|r| r == prefix))
+        {
+            Some((remote, branch)) => (remote.to_string(), branch.to_string()),
+            None => (
+                config
+                    .git
+                    .default_remote
+                    .clone()
+                    .unwrap_or_else(|| "origin".to_string()),
+                base,
+            ),
+        };
+        // Local-only bases, such as the branch of a stacked session's parent,
+        // have nothing to fetch
+        repo.resolve_commit(&format!("{remote}/{branch}")).ok()?;
+
+        Some(Self {
+            repo_root: get_main_repository_root_from(Some(worktree_path)).ok()?,
+            remote,
+            branch,
+        })
+    }
+}
+
+/// What the daemon knows about fetching one target
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PrefetchStatus {
+    pub target: PrefetchTarget,
+    /// Last successful fetch
+    pub fetched_at: Option<DateTime<Utc>>,
+    /// Until when that fetch counts as fresh: one interval after it
+    pub fresh_until: Option<DateTime<Utc>>,
+    /// Failed attempts since the last success
+    pub failures: u32,
+    pub last_error: Option<String>,
+    pub next_attempt: DateTime<Utc>,
+}
+
+impl PrefetchStatus {
+    fn new(target: PrefetchTarget, now: DateTime<Utc>) -> Self {
+        Self {
+            target,
+            fetched_at: None,
+            fresh_until: None,
+            failures: 0,
+            last_error: None,
+            next_attempt: now,
+        }
+    }
+
+    pub fn is_fresh(&self, now: DateTime<Utc>) -> bool {
+        self.fresh_until.is_some_and(|until| now < until)
+    }
+
+    /// One line for `para daemon status`
+    pub fn describe(&self, now: DateTime<Utc>) -> String {
+        let target = &self.target;
+        let name = format!(
+            "{}/{} in {}",
+            target.remote,
+            target.branch,
+            target.repo_root.display()
+        );
+        let fetched = match self.fetched_at {
+            Some(at) => format!("fetched {} ago", format_duration(now - at)),
+            None => "never fetched".to_string(),
+        };
+        match &self.last_error {
+            Some(error) => format!(
+                "{name}: {fetched}, {} failed attempt{}, retrying in {}: {error}",
+                self.failures,
+                if self.failures == 1 { "" } else { "s" },
+                format_duration(self.next_attempt - now)
+            ),
+            None => format!("{name}: {fetched}"),
+        }
+    }
+}
+
+/// When each target is fetched next
+#[derive(Debug, Clone)]
+pub struct PrefetchSchedule {
+    interval: Duration,
+    statuses: Vec<PrefetchStatus>,
+}
+
+impl PrefetchSchedule {
+    pub fn new(interval: Duration, statuses: Vec<PrefetchStatus>) -> Self {
+        Self { interval, statuses }
+    }
+
+    pub fn statuses(&self) -> &[PrefetchStatus] {
+        &self.statuses
+    }
+
+    pub fn set_interval(&mut self, interval: Duration) {
+        self.interval = interval;
+    }
+
+    /// Keep exactly `targets`: new ones are due now, dropped ones are forgotten
+    pub fn retain_targets(&mut self, mut targets: Vec<PrefetchTarget>, now: DateTime<Utc>) {
+        targets.sort();
+        targets.dedup();
+        self.statuses
+            .retain(|status| targets.contains(&status.target));
+        for target in targets {
+            if !self.statuses.iter().any(|status| status.target == target) {
+                self.statuses.push(PrefetchStatus::new(target, now));
+            }
+        }
+        self.statuses.sort_by(|a, b| a.target.cmp(&b.target));
+    }
+
+    /// Targets whose next attempt has come
+    pub fn due(&self, now: DateTime<Utc>) -> Vec<PrefetchTarget> {
+        self.statuses
+            .iter()
+            .filter(|status| status.next_attempt <= now)
+            .map(|status| status.target.clone())
+            .collect()
+    }
+
+    /// Make every target of `repo_root` due now, keeping their failure counts
+    pub fn request_now(&mut self, repo_root: &Path, now: DateTime<Utc>) {
+        for status in &mut self.statuses {
+            if status.target.repo_root == repo_root {
+                status.next_attempt = now;
+            }
+        }
+    }
+
+    /// Record the outcome of fetching `target` at `now`
+    pub fn record(
+        &mut self,
+        target: &PrefetchTarget,
+        outcome: std::result::Result<(), String>,
+        now: DateTime<Utc>,
+    ) {
+        let interval = self.interval;
+        let Some(status) = self.statuses.iter_mut().find(|s| s.target == *target) else {
+            return;
+        };
+        match outcome {
+            Ok(()) => {
+                status.fetched_at = Some(now);
+                status.fresh_until = Some(now + interval);
+                status.failures = 0;
+                status.last_error = None;
+                status.next_attempt = now + interval;
+            }
+            Err(error) => {
+                status.failures += 1;
+                status.last_error = Some(error);
+                status.next_attempt = now + backoff(status.failures);
+            }
+        }
+    }
+}
+
+/// Delay after the `failures`-th failure in a row: 1, 2, 4 ... minutes
+pub fn backoff(failures: u32) -> Duration {
+    let minutes = RETRY_AFTER_MINUTES
+        .checked_shl(failures.saturating_sub(1))
+        .filter(|minutes| *minutes > 0)
+        .map_or(MAX_BACKOFF_MINUTES, |minutes| {
+            minutes.min(MAX_BACKOFF_MINUTES)
+        });
+    Duration::minutes(minutes)
+}
+
+/// The statuses saved at `path`. A missing or unreadable file is empty.
+pub fn load_statuses(path: &Path) -> Vec<PrefetchStatus> {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save_statuses(path: &Path, statuses: &[PrefetchStatus]) -> anyhow::Result<()> {
+    if statuses.is_empty() {
+        if path.exists() {
+            std::fs::remove_file(path)?;
+        }
+        return Ok(());
+    }
+    std::fs::write(path, serde_json::to_string_pretty(statuses)?)?;
+    Ok(())
+}
+
+/// Whether the daemon fetched `branch` from `remote` in the repository of
+/// `repo_root` recently enough that a command can skip its own fetch
+pub fn is_fresh(repo_root: &Path, remote: &str, branch: &str) -> bool {
+    is_fresh_at(
+        &daemon_prefetch_path(),
+        repo_root,
+        remote,
+        branch,
+        Utc::now(),
+    )
+}
+
+fn is_fresh_at(
+    path: &Path,
+    repo_root: &Path,
+    remote: &str,
+    branch: &str,
+    now: DateTime<Utc>,
+) -> bool {
+    let Ok(repo_root) = get_main_repository_root_from(Some(repo_root)) else {
+        return false;
+    };
+    load_statuses(path).iter().any(|status| {
+        status.target.repo_root == repo_root
+            && status.target.remote == remote
+            && status.target.branch == branch
+            && status.is_fresh(now)
+    })
+}
+
+type Clock = Arc<dyn Fn() -> DateTime<Utc> + Send + Sync>;
+type FetchFn = Arc<dyn Fn(&PrefetchTarget) -> std::result::Result<(), String> + Send + Sync>;
+
+/// Runs the fetches of a [`PrefetchSchedule`] and saves their outcomes
+pub struct Prefetcher {
+    schedule: PrefetchSchedule,
+    path: PathBuf,
+    clock: Clock,
+    fetch: FetchFn,
+}
+
+impl Prefetcher {
+    fn new(path: &Path, clock: Clock, fetch: FetchFn) -> Self {
+        Self {
+            schedule: PrefetchSchedule::new(Duration::zero(), load_statuses(path)),
+            path: path.to_path_buf(),
+            clock,
+            fetch,
+        }
+    }
+
+    /// Bring the schedule in line with `targets` and run every fetch that is
+    /// due, one after another. Returns the targets fetched.
+    pub fn run_due(
+        &mut self,
+        targets: Vec<PrefetchTarget>,
+        interval: Duration,
+    ) -> Vec<PrefetchTarget> {
+        self.schedule.set_interval(interval);
+        self.schedule.retain_targets(targets, (self.clock)());
+
+        let due = self.schedule.due((self.clock)());
+        for target in &due {
+            let outcome = (self.fetch)(target);
+            if let Err(ref e) = outcome {
+                eprintln!(
+                    "Prefetch of {}/{} in {} failed: {e}",
+                    target.remote,
+                    target.branch,
+                    target.repo_root.display()
+                );
+            }
+            self.schedule.record(target, outcome, (self.clock)());
+        }
+        if let Err(e) = save_statuses(&self.path, self.schedule.statuses()) {
+            eprintln!("Failed to save prefetch status: {e}");
+        }
+        due
+    }
+
+    pub fn request_now(&mut self, repo_root: &Path) {
+        let now = (self.clock)();
+        self.schedule.request_now(repo_root, now);
+    }
+}
+
+/// Handle to ask the prefetch thread for an immediate fetch
+#[derive(Clone)]
+pub struct PrefetchHandle {
+    requests: Sender<PathBuf>,
+}
+
+impl PrefetchHandle {
+    /// Fetch the base branches of `repo_root` on the next pass instead of
+    /// waiting for their interval
+    pub fn prefetch_now(&self, repo_root: &Path) -> anyhow::Result<()> {
+        let repo_root = get_main_repository_root_from(Some(repo_root))
+            .unwrap_or_else(|_| repo_root.to_path_buf());
+        self.requests
+            .send(repo_root)
+            .map_err(|_| anyhow::anyhow!("Prefetch thread is not running"))
+    }
+}
+
+/// Fetch the base branches of the sessions `registrations` returns for as
+/// long as the daemon runs
+pub fn spawn_prefetcher(
+    registrations: impl Fn() -> Vec<Registration> + Send + 'static,
+) -> PrefetchHandle {
+    let (requests, request_rx) = mpsc::channel::<PathBuf>();
+
+    thread::spawn(move || {
+        let mut prefetcher = Prefetcher::new(
+            &daemon_prefetch_path(),
+            Arc::new(Utc::now),
+            Arc::new(|target| fetch_target(target).map_err(|e| e.to_string())),
+        );
+        loop {
+            match request_rx.recv_timeout(POLL_INTERVAL) {
+                Ok(repo_root) => prefetcher.request_now(&repo_root),
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => return,
+            }
+
+            // The config is read every pass so `git.auto_fetch` and offline
+            // mode take effect without restarting the daemon
+            let Ok(config) = ConfigManager::load_or_create() else {
+                continue;
+            };
+            let Some(interval) = config.get_auto_fetch_interval() else {
+                continue;
+            };
+            if NetworkContext::current().is_offline() {
+                continue;
+            }
+            let targets = registrations()
+                .iter()
+                .filter_map(|registration| PrefetchTarget::for_registration(registration, &config))
+                .collect();
+            prefetcher.run_due(
+                targets,
+                Duration::from_std(interval).unwrap_or(Duration::MAX),
+            );
+        }
+    });
+
+    PrefetchHandle { requests }
+}
+
+fn fetch_target(target: &PrefetchTarget) -> crate::utils::Result<()> {
+    let repo = GitRepository::discover_from(&target.repo_root)?;
+    repo.fetch_prune(&target.remote, &target.branch)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+    use tempfile::TempDir;
+
+    fn target(repo: &Path, branch: &str) -> PrefetchTarget {
+        PrefetchTarget {
+            repo_root: repo.to_path_buf(),
+            remote: "origin".to_string(),
+            branch: branch.to_string(),
+        }
+    }
+
+    struct Fixture {
+        _temp: TempDir,
+        repo: PathBuf,
+        path: PathBuf,
+        now: Arc<Mutex<DateTime<Utc>>>,
+        fetched: Arc<Mutex<Vec<String>>>,
+        failing: Arc<Mutex<Vec<String>>>,
+        prefetcher: Prefetcher,
+    }
+
+    impl Fixture {
+        fn new() -> Self {
+            let temp = TempDir::new().unwrap();
+            let repo = temp.path().join("repo");
+            std::fs::create_dir(&repo).unwrap();
+            crate::core::git::repository::execute_git_command(
+                &GitRepository {
+                    root: repo.clone(),
+                    git_dir: repo.join(".git"),
+                    is_bare: false,
+                },
+                &["init", "--quiet"],
+            )
+            .unwrap();
+            let repo = repo.canonicalize().unwrap();
+            let path = temp.path().join("prefetch.json");
+
+            let now = Arc::new(Mutex::new(
+                DateTime::from_timestamp(1_700_000_000, 0).unwrap(),
+            ));
+            let fetched = Arc::new(Mutex::new(Vec::new()));
+            let failing: Arc<Mutex<Vec<String>>> = Arc::new(Mutex::new(Vec::new()));
+            let clock = Arc::clone(&now);
+            let (record, fail) = (Arc::clone(&fetched), Arc::clone(&failing));
+            let prefetcher = Prefetcher::new(
+                &path,
+                Arc::new(move || *clock.lock().unwrap()),
+                Arc::new(move |target: &PrefetchTarget| {
+                    record.lock().unwrap().push(target.branch.clone());
+                    if fail.lock().unwrap().contains(&target.branch) {
+                        Err("could not read from remote".to_string())
+                    } else {
+                        Ok(())
+                    }
+                }),
+            );
+            Self {
+                _temp: temp,
+                repo,
+                path,
+                now,
+                fetched,
+                failing,
+                prefetcher,
+            }
+        }
+
+        fn advance(&self, minutes: i64) {
+            *self.now.lock().unwrap() += Duration::minutes(minutes);
+        }
+
+        /// Run a pass over `branches` and return the branches fetched in it
+        fn run(&mut self, branches: &[&str]) -> Vec<String> {
+            let targets = branches.iter().map(|b| target(&self.repo, b)).collect();
+            self.prefetcher.run_due(targets, Duration::minutes(5));
+            std::mem::take(&mut *self.fetched.lock().unwrap())
+        }
+    }
+
+    #[test]
+    fn test_targets_are_fetched_once_per_interval() {
+        let mut fixture = Fixture::new();
+        assert_eq!(fixture.run(&["main", "develop"]), ["develop", "main"]);
+
+        fixture.advance(4);
+        assert!(fixture.run(&["main", "develop"]).is_empty());
+
+        // A base that appears is due at once
+        assert_eq!(fixture.run(&["main", "develop", "release"]), ["release"]);
+
+        fixture.advance(1);
+        assert_eq!(fixture.run(&["main", "release"]), ["main"]);
+        assert_eq!(fixture.prefetcher.schedule.statuses().len(), 2);
+    }
+
+    #[test]
+    fn test_failing_fetches_back_off_exponentially() {
+        let mut fixture = Fixture::new();
+        fixture.failing.lock().unwrap().push("main".to_string());
+
+        let mut retried_after = Vec::new();
+        let mut waited = 0;
+        while retried_after.len() < 4 {
+            if !fixture.run(&["main"]).is_empty() {
+                retried_after.push(waited);
+                waited = 0;
+            }
+            fixture.advance(1);
+            waited += 1;
+        }
+        assert_eq!(retried_after, [0, 1, 2, 4]);
+
+        let status = &fixture.prefetcher.schedule.statuses()[0];
+        assert_eq!(status.failures, 4);
+        assert_eq!(
+            status.last_error.as_deref(),
+            Some("could not read from remote")
+        );
+
+        // Success resets the backoff
+        fixture.failing.lock().unwrap().clear();
+        fixture.advance(8);
+        assert_eq!(fixture.run(&["main"]), ["main"]);
+        assert_eq!(fixture.prefetcher.schedule.statuses()[0].failures, 0);
+        fixture.advance(5);
+        assert_eq!(fixture.run(&["main"]), ["main"]);
+    }
+
+    #[test]
+    fn test_backoff_is_capped() {
+        assert_eq!(backoff(1), Duration::minutes(1));
+        assert_eq!(backoff(3), Duration::minutes(4));
+        assert_eq!(backoff(7), Duration::minutes(MAX_BACKOFF_MINUTES));
+        assert_eq!(backoff(200), Duration::minutes(MAX_BACKOFF_MINUTES));
+    }
+
+    #[test]
+    fn test_prefetch_now_makes_a_repository_due() {
+        let mut fixture = Fixture::new();
+        assert_eq!(fixture.run(&["main"]), ["main"]);
+
+        fixture.advance(1);
+        let repo = fixture.repo.clone();
+        fixture.prefetcher.request_now(&repo);
+        assert_eq!(fixture.run(&["main"]), ["main"]);
+
+        fixture
+            .prefetcher
+            .request_now(Path::new("/some/other/repo"));
+        assert!(fixture.run(&["main"]).is_empty());
+    }
+
+    #[test]
+    fn test_freshness_is_read_back_from_the_saved_statuses() {
+        let mut fixture = Fixture::new();
+        fixture.failing.lock().unwrap().push("develop".to_string());
+        fixture.run(&["main", "develop"]);
+
+        let now = *fixture.now.lock().unwrap();
+        let fresh = |branch, at| is_fresh_at(&fixture.path, &fixture.repo, "origin", branch, at);
+        assert!(fresh("main", now + Duration::minutes(4)));
+        assert!(!fresh("main", now + Duration::minutes(5)));
+        assert!(!fresh("develop", now));
+        assert!(!is_fresh_at(
+            &fixture.path,
+            &fixture.repo,
+            "upstream",
+            "main",
+            now
+        ));
+
+        let statuses = load_statuses(&fixture.path);
+        let repo = fixture.repo.display();
+        assert_eq!(
+            statuses[0].describe(now),
+            format!("origin/develop in {repo}: never fetched, 1 failed attempt, retrying in 1m: could not read from remote")
+        );
+        assert_eq!(
+            statuses[1].describe(now + Duration::minutes(3)),
+            format!("origin/main in {repo}: fetched 3m ago")
+        );
+    }
+}
@@ -0,0 +1,210 @@
+//! Sessions registered with the daemon, kept on disk across restarts
+//!
+//! Every registration is written to `para-daemon-sessions.json` next to the
+//! socket and removed again when the session is unregistered. When the daemon
+//! starts, the saved registrations are reconciled against the sessions and
+//! containers that still exist, so a crashed or restarted daemon picks up live
+//! sessions and forgets the ones that were cancelled or finished meanwhile.
+
+use crate::core::checkpoint::CheckpointRegistration;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::Mutex;
+
+/// One session the daemon watches or checkpoints
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Registration {
+    Container {
+        session_name: String,
+        worktree_path: PathBuf,
+        repo_root: PathBuf,
+    },
+    Checkpoint(CheckpointRegistration),
+}
+
+impl Registration {
+    pub fn session_name(&self) -> &str {
+        match self {
+            Registration::Container { session_name, .. } => session_name,
+            Registration::Checkpoint(registration) => &registration.session_name,
+        }
+    }
+
+    fn same_target(&self, other: &Registration) -> bool {
+        match (self, other) {
+            (
+                Registration::Container { session_name, .. },
+                Registration::Container {
+                    session_name: other,
+                    ..
+                },
+            ) => session_name == other,
+            (Registration::Checkpoint(a), Registration::Checkpoint(b)) => {
+                a.worktree_path == b.worktree_path
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether the session behind this registration still exists
+    pub fn is_live(&self) -> bool {
+        match self {
+            Registration::Container {
+                session_name,
+                worktree_path,
+                ..
+            } => worktree_path.exists() && container_exists(&format!("para-{session_name}")),
+            Registration::Checkpoint(registration) => registration.wants_checkpoints(),
+        }
+    }
+}
+
+fn container_exists(container_name: &str) -> bool {
+    Command::new("docker")
+        .args(["container", "inspect", container_name])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .is_ok_and(|status| status.success())
+}
+
+/// Split `registrations` into the ones `is_live` keeps and the ones to drop
+pub fn reconcile(
+    registrations: Vec<Registration>,
+    is_live: impl Fn(&Registration) -> bool,
+) -> (Vec<Registration>, Vec<Registration>) {
+    registrations.into_iter().partition(|r| is_live(r))
+}
+
+/// The registrations saved at `path`, kept in sync with the daemon's watchers
+pub struct Registry {
+    path: PathBuf,
+    entries: Mutex<Vec<Registration>>,
+}
+
+impl Registry {
+    /// Open the registry at `path`. A missing or unreadable file is empty.
+    pub fn load(path: &Path) -> Self {
+        let entries = std::fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        Self {
+            path: path.to_path_buf(),
+            entries: Mutex::new(entries),
+        }
+    }
+
+    pub fn entries(&self) -> Vec<Registration> {
+        self.entries.lock().unwrap().clone()
+    }
+
+    /// Replace every saved registration with `entries`
+    pub fn replace(&self, entries: Vec<Registration>) -> anyhow::Result<()> {
+        let mut guard = self.entries.lock().unwrap();
+        *guard = entries;
+        self.save(&guard)
+    }
+
+    /// Save `registration`, replacing an earlier one for the same target
+    pub fn record(&self, registration: Registration) -> anyhow::Result<()> {
+        let mut guard = self.entries.lock().unwrap();
+        guard.retain(|existing| !existing.same_target(&registration));
+        guard.push(registration);
+        self.save(&guard)
+    }
+
+    /// Drop every registration of `session_name`
+    pub fn forget(&self, session_name: &str) -> anyhow::Result<()> {
+        let mut guard = self.entries.lock().unwrap();
+        let before = guard.len();
+        guard.retain(|existing| existing.session_name() != session_name);
+        if guard.len() == before {
+            return Ok(());
+        }
+        self.save(&guard)
+    }
+
+    fn save(&self, entries: &[Registration]) -> anyhow::Result<()> {
+        if entries.is_empty() {
+            if self.path.exists() {
+                std::fs::remove_file(&self.path)?;
+            }
+            return Ok(());
+        }
+        std::fs::write(&self.path, serde_json::to_string_pretty(entries)?)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn container(name: &str) -> Registration {
+        Registration::Container {
+            session_name: name.to_string(),
+            worktree_path: PathBuf::from(format!("/worktrees/{name}")),
+            repo_root: PathBuf::from("/repo"),
+        }
+    }
+
+    fn checkpoint(name: &str) -> Registration {
+        Registration::Checkpoint(CheckpointRegistration {
+            session_name: name.to_string(),
+            worktree_path: PathBuf::from(format!("/worktrees/{name}")),
+            state_dir: PathBuf::from("/repo/.para/state"),
+            interval_minutes: 10,
+        })
+    }
+
+    fn names(registrations: &[Registration]) -> Vec<&str> {
+        registrations.iter().map(|r| r.session_name()).collect()
+    }
+
+    #[test]
+    fn test_reconcile_drops_sessions_that_no_longer_exist() {
+        let registrations = vec![
+            container("alive"),
+            container("cancelled"),
+            checkpoint("alive"),
+            checkpoint("finished"),
+        ];
+
+        let (kept, dropped) = reconcile(registrations, |r| r.session_name() == "alive");
+        assert_eq!(names(&kept), vec!["alive", "alive"]);
+        assert_eq!(names(&dropped), vec!["cancelled", "finished"]);
+    }
+
+    #[test]
+    fn test_registry_survives_restarts() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("sessions.json");
+
+        let registry = Registry::load(&path);
+        registry.record(container("one")).unwrap();
+        registry.record(checkpoint("one")).unwrap();
+        registry.record(container("two")).unwrap();
+        // Registering again replaces the earlier entry
+        registry.record(container("two")).unwrap();
+        assert_eq!(
+            names(&Registry::load(&path).entries()),
+            vec!["one", "one", "two"]
+        );
+
+        registry.forget("one").unwrap();
+        assert_eq!(names(&Registry::load(&path).entries()), vec!["two"]);
+
+        registry.forget("two").unwrap();
+        assert!(!path.exists());
+        assert!(Registry::load(&path).entries().is_empty());
+    }
+
+    #[test]
+    fn test_live_checks_missing_worktrees() {
+        assert!(!container("gone").is_live());
+        assert!(!checkpoint("gone").is_live());
+    }
+}
@@ -1,8 +1,15 @@
 //! Para daemon server implementation
 
-use super::{daemon_pid_path, daemon_socket_path, DaemonCommand, DaemonResponse};
+use super::prefetch::{spawn_prefetcher, PrefetchHandle};
+use super::registry::{reconcile, Registration, Registry};
+use super::{
+    daemon_pid_path, daemon_registry_path, daemon_socket_path, DaemonCommand, DaemonPidFile,
+    DaemonResponse,
+};
 use crate::config::ConfigManager;
+use crate::core::checkpoint::{spawn_checkpointer, CheckpointHandle, CheckpointRegistration};
 use crate::core::docker::watcher::{SignalFileWatcher, WatcherHandle};
+use crate::core::idle::spawn_idle_monitor;
 use std::collections::HashMap;
 use std::io::{BufRead, BufReader, Write};
 use std::os::unix::net::{UnixListener, UnixStream};
@@ -10,16 +17,25 @@ use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
 use std::thread;
 
-/// The daemon server that manages all watchers
+/// Map of session_name -> (repo_root, watcher_handle)
+type Watchers = Arc<Mutex<HashMap<String, (PathBuf, WatcherHandle)>>>;
+
+/// Map of worktree_path -> (session_name, checkpoint_handle)
+type Checkpoints = Arc<Mutex<HashMap<PathBuf, (String, CheckpointHandle)>>>;
+
+/// The daemon server that manages all watchers and checkpoint schedules
 pub struct DaemonServer {
-    /// Map of session_name -> (repo_root, watcher_handle)
-    watchers: Arc<Mutex<HashMap<String, (PathBuf, WatcherHandle)>>>,
+    watchers: Watchers,
+    checkpoints: Checkpoints,
+    registry: Arc<Registry>,
 }
 
 impl DaemonServer {
     pub fn new() -> Self {
         Self {
             watchers: Arc::new(Mutex::new(HashMap::new())),
+            checkpoints: Arc::new(Mutex::new(HashMap::new())),
+            registry: Arc::new(Registry::load(&daemon_registry_path())),
         }
     }
 }
@@ -40,20 +56,31 @@ impl DaemonServer {
         }
 
         // Write PID file
-        let pid = std::process::id();
-        std::fs::write(daemon_pid_path(), pid.to_string())?;
+        let pid_file = DaemonPidFile::current();
+        pid_file.write()?;
+        let pid = pid_file.pid;
 
         // Create Unix socket
         let listener = UnixListener::bind(&socket_path)?;
         println!("Para daemon started (PID: {pid})");
 
+        self.restore_registrations();
+        spawn_idle_monitor();
+        let registry = self.registry.clone();
+        let prefetch = spawn_prefetcher(move || registry.entries());
+
         // Handle incoming connections
         for stream in listener.incoming() {
             match stream {
                 Ok(stream) => {
                     let watchers = self.watchers.clone();
+                    let checkpoints = self.checkpoints.clone();
+                    let registry = self.registry.clone();
+                    let prefetch = prefetch.clone();
                     thread::spawn(move || {
-                        if let Err(e) = handle_client(stream, watchers) {
+                        if let Err(e) =
+                            handle_client(stream, watchers, checkpoints, registry, prefetch)
+                        {
                             eprintln!("Error handling client: {e}");
                         }
                     });
@@ -64,12 +91,53 @@ impl DaemonServer {
 
         Ok(())
     }
+
+    /// Resume the sessions registered before the daemon last stopped, dropping
+    /// the ones that were cancelled or finished in the meantime
+    fn restore_registrations(&self) {
+        let (kept, dropped) = reconcile(self.registry.entries(), Registration::is_live);
+        for registration in &dropped {
+            println!(
+                "Dropped registration for missing session: {}",
+                registration.session_name()
+            );
+        }
+
+        let mut restored = Vec::new();
+        for registration in kept {
+            match &registration {
+                Registration::Container {
+                    session_name,
+                    worktree_path,
+                    repo_root,
+                } => {
+                    if let Err(e) =
+                        register_watcher(session_name, worktree_path, repo_root, &self.watchers)
+                    {
+                        eprintln!("Failed to restore watcher for {session_name}: {e}");
+                        continue;
+                    }
+                }
+                Registration::Checkpoint(checkpoint) => {
+                    register_checkpoints(checkpoint.clone(), &self.checkpoints);
+                }
+            }
+            restored.push(registration);
+        }
+
+        if let Err(e) = self.registry.replace(restored) {
+            eprintln!("Failed to save daemon registrations: {e}");
+        }
+    }
 }
 
 /// Handle a client connection
 fn handle_client(
     stream: UnixStream,
-    watchers: Arc<Mutex<HashMap<String, (PathBuf, WatcherHandle)>>>,
+    watchers: Watchers,
+    checkpoints: Checkpoints,
+    registry: Arc<Registry>,
+    prefetch: PrefetchHandle,
 ) -> anyhow::Result<()> {
     let reader = BufReader::new(stream.try_clone()?);
     let mut stream = stream;
@@ -84,24 +152,52 @@ fn handle_client(
                 worktree_path,
                 repo_root,
             } => match register_watcher(&session_name, &worktree_path, &repo_root, &watchers) {
-                Ok(()) => DaemonResponse::Ok,
+                Ok(()) => {
+                    save_registration(
+                        &registry,
+                        Registration::Container {
+                            session_name,
+                            worktree_path,
+                            repo_root,
+                        },
+                    );
+                    DaemonResponse::Ok
+                }
                 Err(e) => DaemonResponse::Error(e.to_string()),
             },
+            DaemonCommand::RegisterCheckpointSession(registration) => {
+                register_checkpoints(registration.clone(), &checkpoints);
+                save_registration(&registry, Registration::Checkpoint(registration));
+                DaemonResponse::Ok
+            }
             DaemonCommand::UnregisterSession { session_name } => {
+                if let Err(e) = registry.forget(&session_name) {
+                    eprintln!("Failed to save daemon registrations: {e}");
+                }
+                let stopped_checkpoints = unregister_checkpoints(&session_name, &checkpoints);
                 match unregister_watcher(&session_name, &watchers) {
                     Ok(()) => DaemonResponse::Ok,
+                    Err(_) if stopped_checkpoints => DaemonResponse::Ok,
                     Err(e) => DaemonResponse::Error(e.to_string()),
                 }
             }
+            DaemonCommand::PrefetchNow { repo_root } => match prefetch.prefetch_now(&repo_root) {
+                Ok(()) => DaemonResponse::Ok,
+                Err(e) => DaemonResponse::Error(e.to_string()),
+            },
             DaemonCommand::Ping => DaemonResponse::Pong,
             DaemonCommand::Version => {
                 DaemonResponse::Version(env!("CARGO_PKG_VERSION").to_string())
             }
             DaemonCommand::Shutdown => {
-                // Clean up all watchers
+                // Clean up all watchers. Registrations stay saved so the next
+                // daemon resumes them.
                 if let Ok(mut watchers_guard) = watchers.lock() {
                     watchers_guard.clear();
                 }
+                if let Ok(mut checkpoints_guard) = checkpoints.lock() {
+                    checkpoints_guard.clear();
+                }
 
                 // Remove PID file
                 let _ = std::fs::remove_file(daemon_pid_path());
@@ -127,12 +223,18 @@ fn handle_client(
     Ok(())
 }
 
+fn save_registration(registry: &Registry, registration: Registration) {
+    if let Err(e) = registry.record(registration) {
+        eprintln!("Failed to save daemon registrations: {e}");
+    }
+}
+
 /// Register a new watcher for a container session
 fn register_watcher(
     session_name: &str,
     worktree_path: &Path,
     repo_root: &Path,
-    watchers: &Arc<Mutex<HashMap<String, (PathBuf, WatcherHandle)>>>,
+    watchers: &Watchers,
 ) -> anyhow::Result<()> {
     // Load config for this repository
     let config_path = repo_root.join(".para/config.json");
@@ -167,11 +269,49 @@ fn register_watcher(
     Ok(())
 }
 
+/// Start checkpointing a session, replacing any earlier schedule for its worktree
+fn register_checkpoints(registration: CheckpointRegistration, checkpoints: &Checkpoints) {
+    let worktree_path = registration.worktree_path.clone();
+    let session_name = registration.session_name.clone();
+    let interval = registration.interval_minutes;
+    let handle = spawn_checkpointer(registration);
+
+    let previous = checkpoints
+        .lock()
+        .unwrap()
+        .insert(worktree_path, (session_name.clone(), handle));
+    if let Some((_, previous)) = previous {
+        let _ = previous.stop();
+    }
+
+    println!("Checkpointing session {session_name} every {interval} minutes");
+}
+
+/// Stop checkpointing every worktree of a session. Returns whether any was registered.
+fn unregister_checkpoints(session_name: &str, checkpoints: &Checkpoints) -> bool {
+    let stopped: Vec<CheckpointHandle> = {
+        let mut checkpoints_guard = checkpoints.lock().unwrap();
+        let paths: Vec<PathBuf> = checkpoints_guard
+            .iter()
+            .filter(|(_, (name, _))| name == session_name)
+            .map(|(path, _)| path.clone())
+            .collect();
+        paths
+            .iter()
+            .filter_map(|path| checkpoints_guard.remove(path))
+            .map(|(_, handle)| handle)
+            .collect()
+    };
+
+    let any = !stopped.is_empty();
+    for handle in stopped {
+        let _ = handle.stop();
+    }
+    any
+}
+
 /// Unregister and stop a watcher
-fn unregister_watcher(
-    session_name: &str,
-    watchers: &Arc<Mutex<HashMap<String, (PathBuf, WatcherHandle)>>>,
-) -> anyhow::Result<()> {
+fn unregister_watcher(session_name: &str, watchers: &Watchers) -> anyhow::Result<()> {
     let mut watchers_guard = watchers.lock().unwrap();
 
     if let Some((_, handle)) = watchers_guard.remove(session_name) {
@@ -185,17 +325,10 @@ fn unregister_watcher(
 
 /// Check if the daemon is already running
 pub fn is_daemon_running() -> bool {
-    let pid_path = daemon_pid_path();
-
-    if let Ok(pid_str) = std::fs::read_to_string(&pid_path) {
-        if let Ok(pid) = pid_str.trim().parse::<u32>() {
-            // Check if process is still alive
-            // On Unix, we can use kill with signal 0
-            unsafe { libc::kill(pid as i32, 0) == 0 }
-        } else {
-            false
-        }
-    } else {
-        false
+    match DaemonPidFile::read() {
+        // Check if process is still alive
+        // On Unix, we can use kill with signal 0
+        Some(pid_file) => unsafe { libc::kill(pid_file.pid as i32, 0) == 0 },
+        None => false,
     }
 }
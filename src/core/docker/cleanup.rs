@@ -1,12 +1,43 @@
 use anyhow::Result;
+use chrono::{DateTime, Utc};
+use fs2::FileExt;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
 use std::path::PathBuf;
 use std::process::Command;
 use std::thread;
-use std::time::{Duration, SystemTime};
 
+use super::resources::{self, SessionCleanupReport, SystemDockerCli};
+use super::DockerResult;
 use crate::config::Config;
+use crate::core::logs::LOG_FILE;
 use crate::core::session::SessionManager;
 
+const CLEANUP_MARKER: &str = ".last_container_cleanup";
+const CLEANUP_LOCK: &str = ".container_cleanup.lock";
+
+/// What a cleanup run removed
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ContainerCleanupReport {
+    pub containers_removed: Vec<String>,
+    pub volumes_removed: Vec<String>,
+    pub networks_removed: Vec<String>,
+}
+
+impl ContainerCleanupReport {
+    pub fn is_empty(&self) -> bool {
+        self.containers_removed.is_empty()
+            && self.volumes_removed.is_empty()
+            && self.networks_removed.is_empty()
+    }
+}
+
+/// Exclusive claim on running a cleanup, shared across processes through a
+/// lock file in the state directory. Released on drop.
+pub struct CleanupLock {
+    _file: File,
+}
+
 /// Manages automatic cleanup of orphaned Docker containers
 pub struct ContainerCleaner {
     config: Config,
@@ -17,54 +48,151 @@ impl ContainerCleaner {
         Self { config }
     }
 
-    /// Check if cleanup should run based on marker file age
-    pub fn should_run_cleanup(&self) -> Result<bool> {
-        let cleanup_marker =
-            PathBuf::from(&self.config.directories.state_dir).join(".last_container_cleanup");
-
-        match cleanup_marker.metadata() {
-            Ok(metadata) => {
-                let age = SystemTime::now()
-                    .duration_since(metadata.modified()?)
-                    .unwrap_or(Duration::MAX);
-                Ok(age > Duration::from_secs(3600)) // 1 hour
-            }
-            Err(_) => Ok(true), // File doesn't exist, should run
+    fn state_dir(&self) -> PathBuf {
+        self.config.resolved_state_dir()
+    }
+
+    /// When the last cleanup started, as persisted in the marker file.
+    ///
+    /// Markers written before the timestamp was recorded are empty; their
+    /// modification time is used instead.
+    pub fn last_cleanup(&self) -> Option<DateTime<Utc>> {
+        let marker = self.state_dir().join(CLEANUP_MARKER);
+        let content = std::fs::read_to_string(&marker).ok()?;
+
+        match DateTime::parse_from_rfc3339(content.trim()) {
+            Ok(timestamp) => Some(timestamp.with_timezone(&Utc)),
+            Err(_) => marker
+                .metadata()
+                .and_then(|m| m.modified())
+                .ok()
+                .map(Into::into),
         }
     }
 
+    /// Check if cleanup should run based on the configured interval
+    pub fn should_run_cleanup(&self) -> Result<bool> {
+        Ok(self.should_run_cleanup_at(Utc::now()))
+    }
+
+    pub fn should_run_cleanup_at(&self, now: DateTime<Utc>) -> bool {
+        let Some(last) = self.last_cleanup() else {
+            return true; // Never ran
+        };
+
+        let interval = chrono::Duration::from_std(self.config.get_container_cleanup_interval())
+            .unwrap_or(chrono::Duration::MAX);
+        now.signed_duration_since(last) >= interval
+    }
+
     /// Update the cleanup marker file
     pub fn update_cleanup_marker(&self) -> Result<()> {
-        let cleanup_marker =
-            PathBuf::from(&self.config.directories.state_dir).join(".last_container_cleanup");
-        std::fs::write(&cleanup_marker, "")?;
+        self.update_cleanup_marker_at(Utc::now())
+    }
+
+    fn update_cleanup_marker_at(&self, now: DateTime<Utc>) -> Result<()> {
+        std::fs::write(self.state_dir().join(CLEANUP_MARKER), now.to_rfc3339())?;
         Ok(())
     }
 
-    /// Trigger cleanup if needed, runs in background
+    /// Claim the next cleanup run if the interval has elapsed at `now` and no
+    /// other process is cleaning. Records `now` as the last run so concurrent
+    /// callers skip until the next interval.
+    pub fn try_claim_cleanup(&self, now: DateTime<Utc>) -> Option<CleanupLock> {
+        if !self.should_run_cleanup_at(now) {
+            return None;
+        }
+        self.claim_cleanup(now, false)
+    }
+
+    fn claim_cleanup(&self, now: DateTime<Utc>, force: bool) -> Option<CleanupLock> {
+        let state_dir = self.state_dir();
+        std::fs::create_dir_all(&state_dir).ok()?;
+
+        let file = OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(state_dir.join(CLEANUP_LOCK))
+            .ok()?;
+        file.try_lock_exclusive().ok()?;
+
+        // Another process may have finished a run between our check and the lock
+        if !force && !self.should_run_cleanup_at(now) {
+            return None;
+        }
+
+        self.update_cleanup_marker_at(now).ok()?;
+        Some(CleanupLock { _file: file })
+    }
+
+    /// Trigger cleanup if the configured interval has elapsed, runs in background.
+    ///
+    /// A no-op between intervals, so frequent CLI invocations do not scan docker.
     pub fn maybe_cleanup_async(&self) -> Result<()> {
-        if !self.should_run_cleanup()? {
+        let Some(lock) = self.try_claim_cleanup(Utc::now()) else {
             return Ok(());
-        }
+        };
 
         // Clone config for the background thread
         let config = self.config.clone();
 
-        // Spawn background cleanup
+        // Spawn background cleanup holding the lock until it completes
         thread::spawn(move || {
             let cleaner = ContainerCleaner::new(config);
-            if let Err(e) = cleaner.cleanup_orphaned_containers() {
-                eprintln!("Background container cleanup error: {e}");
+            match cleaner.cleanup_orphaned_containers() {
+                Ok(report) => cleaner.log_cleanup(&report),
+                Err(e) => eprintln!("Background container cleanup error: {e}"),
             }
+            drop(lock);
         });
 
-        // Update marker immediately to prevent multiple runs
-        self.update_cleanup_marker()?;
         Ok(())
     }
 
+    /// Run a cleanup now in the foreground, ignoring the interval.
+    ///
+    /// Returns `None` when another process is already cleaning.
+    pub fn cleanup_now(&self) -> Result<Option<ContainerCleanupReport>> {
+        let Some(_lock) = self.claim_cleanup(Utc::now(), true) else {
+            return Ok(None);
+        };
+
+        let report = self.cleanup_orphaned_containers()?;
+        self.log_cleanup(&report);
+        Ok(Some(report))
+    }
+
+    /// Append what a cleanup removed to para.log in the state directory
+    fn log_cleanup(&self, report: &ContainerCleanupReport) {
+        if report.is_empty() {
+            return;
+        }
+
+        let mut line = format!("{} container cleanup:", Utc::now().to_rfc3339());
+        for (label, names) in [
+            ("containers", &report.containers_removed),
+            ("volumes", &report.volumes_removed),
+            ("networks", &report.networks_removed),
+        ] {
+            if !names.is_empty() {
+                line.push_str(&format!(" removed {label} {};", names.join(", ")));
+            }
+        }
+
+        if let Ok(mut log) = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.state_dir().join(LOG_FILE))
+        {
+            let _ = writeln!(log, "{}", line.trim_end_matches(';'));
+        }
+    }
+
     /// Cleanup orphaned containers
-    pub fn cleanup_orphaned_containers(&self) -> Result<()> {
+    pub fn cleanup_orphaned_containers(&self) -> Result<ContainerCleanupReport> {
+        let mut report = ContainerCleanupReport::default();
+
         // List all para containers
         let output = Command::new("docker")
             .args([
@@ -79,7 +207,7 @@ impl ContainerCleaner {
 
         if !output.status.success() {
             // Docker not available or command failed, skip silently
-            return Ok(());
+            return Ok(report);
         }
 
         let container_names = String::from_utf8_lossy(&output.stdout);
@@ -89,23 +217,37 @@ impl ContainerCleaner {
             if let Some(session_name) = container_name.strip_prefix("para-") {
                 // Check if session exists
                 if !session_manager.session_exists(session_name) {
-                    // Session doesn't exist, remove container
-                    self.remove_container(container_name);
+                    // Session doesn't exist, remove container and what it left behind
+                    if self.remove_container(container_name) {
+                        report.containers_removed.push(container_name.to_string());
+                    }
+                    if let Ok(resources) = self.cleanup_session_resources(session_name) {
+                        report.volumes_removed.extend(resources.volumes_removed);
+                        report.networks_removed.extend(resources.networks_removed);
+                    }
                 }
             }
         }
 
-        Ok(())
+        Ok(report)
     }
 
-    /// Remove a single container (fire and forget)
-    fn remove_container(&self, container_name: &str) {
+    /// Remove a single container and its anonymous volumes
+    fn remove_container(&self, container_name: &str) -> bool {
         Command::new("docker")
-            .args(["rm", "-f", container_name])
+            .args(["rm", "-f", "-v", container_name])
             .stdout(std::process::Stdio::null())
             .stderr(std::process::Stdio::null())
-            .spawn()
-            .ok(); // Don't fail if this doesn't work
+            .status()
+            .is_ok_and(|status| status.success()) // Don't fail if this doesn't work
+    }
+
+    /// Remove the volumes and network labeled with `session_name`
+    pub fn cleanup_session_resources(
+        &self,
+        session_name: &str,
+    ) -> DockerResult<SessionCleanupReport> {
+        resources::cleanup_session_resources(&SystemDockerCli, session_name)
     }
 
     /// Parse session name from container name
@@ -183,4 +325,81 @@ mod tests {
             PathBuf::from(&cleaner.config.directories.state_dir).join(".last_container_cleanup");
         assert!(marker_path.exists());
     }
+
+    fn cleaner_with_interval(temp_dir: &TempDir, minutes: u64) -> ContainerCleaner {
+        let mut config = crate::test_utils::test_helpers::create_test_config();
+        config.directories.state_dir = temp_dir.path().to_string_lossy().to_string();
+        config.docker = Some(crate::config::DockerConfig {
+            setup_script: None,
+            default_image: None,
+            forward_env_keys: None,
+            cleanup_interval_minutes: Some(minutes),
+        });
+        ContainerCleaner::new(config)
+    }
+
+    #[test]
+    fn test_cleanup_is_rate_limited_by_interval() {
+        let temp_dir = TempDir::new().unwrap();
+        let cleaner = cleaner_with_interval(&temp_dir, 30);
+        let start = "2024-03-01T12:00:00Z".parse::<DateTime<Utc>>().unwrap();
+
+        let lock = cleaner.try_claim_cleanup(start);
+        assert!(lock.is_some());
+        drop(lock);
+        assert_eq!(cleaner.last_cleanup(), Some(start));
+
+        let later = start + chrono::Duration::minutes(29);
+        assert!(cleaner.try_claim_cleanup(later).is_none());
+        assert_eq!(cleaner.last_cleanup(), Some(start));
+
+        let after_interval = start + chrono::Duration::minutes(30);
+        assert!(cleaner.try_claim_cleanup(after_interval).is_some());
+        assert_eq!(cleaner.last_cleanup(), Some(after_interval));
+    }
+
+    #[test]
+    fn test_concurrent_claims_result_in_single_cleanup() {
+        let temp_dir = TempDir::new().unwrap();
+        let now = Utc::now();
+        let barrier = std::sync::Arc::new(std::sync::Barrier::new(8));
+
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let cleaner = cleaner_with_interval(&temp_dir, 30);
+                let barrier = barrier.clone();
+                thread::spawn(move || {
+                    barrier.wait();
+                    // Hold the claim briefly, like a running cleanup would
+                    let lock = cleaner.try_claim_cleanup(now);
+                    thread::sleep(std::time::Duration::from_millis(50));
+                    lock.is_some()
+                })
+            })
+            .collect();
+
+        let claimed = handles
+            .into_iter()
+            .map(|h| h.join().unwrap())
+            .filter(|claimed| *claimed)
+            .count();
+        assert_eq!(claimed, 1);
+    }
+
+    #[test]
+    fn test_cleanup_log_lists_removed_resources() {
+        let temp_dir = TempDir::new().unwrap();
+        let cleaner = cleaner_with_interval(&temp_dir, 30);
+
+        cleaner.log_cleanup(&ContainerCleanupReport::default());
+        assert!(!temp_dir.path().join(LOG_FILE).exists());
+
+        cleaner.log_cleanup(&ContainerCleanupReport {
+            containers_removed: vec!["para-gone".to_string()],
+            volumes_removed: vec!["para-gone-cache".to_string()],
+            networks_removed: vec![],
+        });
+        let log = std::fs::read_to_string(temp_dir.path().join(LOG_FILE)).unwrap();
+        assert!(log.contains("removed containers para-gone; removed volumes para-gone-cache\n"));
+    }
 }
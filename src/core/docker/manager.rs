@@ -4,9 +4,11 @@
 
 use super::{ContainerPool, DockerError, DockerIdeIntegration, DockerResult, DockerService};
 use crate::config::Config;
+use crate::core::docker::resources::{self, SessionCleanupReport, SystemDockerCli};
 use crate::core::docker::service::ContainerOptions;
 use crate::core::docker::session::ContainerSession;
-use crate::core::session::{SessionState, SessionType};
+use crate::core::network::NetworkContext;
+use crate::core::session::{ContainerConfig, SessionState, SessionType};
 use std::process::Command;
 use std::sync::Arc;
 
@@ -66,6 +68,35 @@ impl DockerManager {
         }
     }
 
+    /// Manager for an existing container session, with the options it was
+    /// dispatched with. Sessions without recorded options get
+    /// [`ContainerConfig::conservative`].
+    pub fn for_session(config: Config, session: &SessionState) -> Self {
+        let container = session
+            .container
+            .clone()
+            .unwrap_or_else(ContainerConfig::conservative);
+        Self::with_options(
+            config,
+            container.network_isolation,
+            container.allowed_domains,
+            container.image,
+            container.forward_keys,
+        )
+    }
+
+    /// The options this manager creates containers with, as recorded in the
+    /// session state
+    pub fn container_config(&self, docker_args: &[String]) -> ContainerConfig {
+        ContainerConfig {
+            image: self.docker_image.clone(),
+            network_isolation: self.network_isolation,
+            allowed_domains: self.allowed_domains.clone(),
+            forward_keys: self.forward_keys,
+            docker_args: docker_args.to_vec(),
+        }
+    }
+
     /// Get the appropriate Docker image name based on priority
     fn get_docker_image(&self) -> DockerResult<String> {
         // Priority order:
@@ -92,6 +123,11 @@ impl DockerManager {
                     "The 'para-authenticated:latest' image is not available. Please build it first with authentication credentials baked in.\n\
                      Alternatively, you can specify a custom image using --docker-image flag."
                 )));
+            } else if NetworkContext::current().skips(&format!("docker pull of '{image}'")) {
+                return Err(DockerError::Other(anyhow::anyhow!(
+                    "Docker image '{image}' is not available locally and cannot be pulled in offline mode.\n\
+                     Pull or build it while online, or pass a local image with --docker-image."
+                )));
             } else {
                 // Try to pull the custom image
                 println!("🐳 Image '{image}' not found locally. Attempting to pull...");
@@ -174,10 +210,14 @@ impl DockerManager {
         // Setup workspace in container
         self.setup_container_workspace(&container_id, session)?;
 
-        // Update session to track container
+        // Update session to track container and the options it was created with
         session.session_type = SessionType::Container {
             container_id: Some(container_id.clone()),
         };
+        session.container = Some(ContainerConfig {
+            image: Some(docker_image),
+            ..self.container_config(docker_args)
+        });
 
         println!("✅ Container ready: {container_id}");
         Ok(())
@@ -250,11 +290,26 @@ impl DockerManager {
         }
     }
 
+    /// Start a session's existing container again, checking that it still has
+    /// the network isolation it was created with
+    pub fn start_session_container(&self, session: &SessionState) -> DockerResult<()> {
+        self.service
+            .start_container_with_verification(&session.name, self.network_isolation)
+    }
+
     /// Stop and remove a container for a session
     pub fn stop_container(&self, session_name: &str) -> DockerResult<()> {
         self.service.stop_container(session_name)
     }
 
+    /// Remove the volumes and network left behind by a session's container
+    pub fn cleanup_session_resources(
+        &self,
+        session_name: &str,
+    ) -> DockerResult<SessionCleanupReport> {
+        resources::cleanup_session_resources(&SystemDockerCli, session_name)
+    }
+
     /// Setup workspace in a container for a session
     fn setup_container_workspace(
         &self,
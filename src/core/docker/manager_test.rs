@@ -16,28 +16,57 @@ mod tests {
                     name: String::new(),
                     command: String::new(),
                 },
+                remote: None,
             },
             directories: DirectoryConfig {
                 subtrees_dir: ".para/worktrees".to_string(),
                 state_dir: ".para/state".to_string(),
+                bare_root: None,
+                layout: Default::default(),
             },
             git: GitConfig {
                 branch_prefix: "para".to_string(),
                 auto_stage: true,
                 auto_commit: false,
+                author_email: None,
+                author_name: None,
+                protected_branches: Vec::new(),
+                auto_commit_interval_minutes: None,
+                default_merge_mode: None,
+                command_timeout_secs: None,
+                audit_log: false,
+                default_remote: None,
+                auto_fetch_interval_minutes: None,
+                auto_fetch: false,
+                apply_whitespace: Default::default(),
+                behind_warning_commits: None,
             },
             session: SessionConfig {
                 default_name_format: "%Y%m%d".to_string(),
                 preserve_on_finish: false,
                 auto_cleanup_days: Some(7),
+                retention: None,
+                copy_untracked: Vec::new(),
+                idle_action: Default::default(),
+                idle_alert_hours: None,
+                conflict_hotspots: false,
+                on_resume: Vec::new(),
             },
             docker: docker_image.map(|image| DockerConfig {
                 setup_script: None,
                 default_image: Some(image),
                 forward_env_keys: None,
+                cleanup_interval_minutes: None,
             }),
             setup_script: None,
             sandbox: None,
+            notifications: None,
+            security: None,
+            status: None,
+            templates: Default::default(),
+            cleanup: None,
+            vcs: None,
+            ui: None,
         }
     }
 
@@ -103,4 +132,50 @@ mod tests {
 
         assert!(!manager3.forward_keys);
     }
+
+    #[test]
+    fn test_container_config_round_trips_through_session_state() {
+        use crate::core::session::{SessionManager, SessionState};
+        use crate::test_utils::test_helpers::create_test_config_with_dir;
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let config = create_test_config_with_dir(&temp_dir);
+        let docker_args = vec!["--memory".to_string(), "2g".to_string()];
+        let dispatched = DockerManager::with_options(
+            config.clone(),
+            true,
+            vec!["api.example.com".to_string()],
+            Some("custom:1.0".to_string()),
+            false,
+        );
+
+        let mut session = SessionState::new_container_with_parent_branch_and_flags(
+            "boxed".to_string(),
+            "para/boxed".to_string(),
+            temp_dir.path().join("boxed"),
+            None,
+            "main".to_string(),
+            false,
+        );
+        session.container = Some(dispatched.container_config(&docker_args));
+        let session_manager = SessionManager::new(&config);
+        session_manager.save_state(&session).unwrap();
+
+        let loaded = session_manager.load_state("boxed").unwrap();
+        let resumed = DockerManager::for_session(config.clone(), &loaded);
+        assert_eq!(
+            resumed.container_config(&docker_args),
+            dispatched.container_config(&docker_args)
+        );
+        assert!(resumed.network_isolation);
+        assert!(!resumed.forward_keys);
+
+        // Sessions without recorded options fall back to the conservative config
+        session.container = None;
+        let fallback = DockerManager::for_session(config, &session);
+        assert_eq!(
+            fallback.container_config(&[]),
+            crate::core::session::ContainerConfig::conservative()
+        );
+    }
 }
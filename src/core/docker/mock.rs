@@ -1,3 +1,5 @@
+use super::resources::DockerCli;
+use super::{DockerError, DockerResult};
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 
@@ -6,6 +8,7 @@ pub struct MockVolume {
     pub name: String,
     pub mount_point: String,
     pub exists: bool,
+    pub labels: Vec<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -15,12 +18,23 @@ pub struct MockContainer {
     pub volumes: Vec<MockVolume>,
     pub volumes_from: Vec<String>,
     pub running: bool,
+    pub restarting: bool,
+    pub exit_code: i32,
+    /// CPU percentage and `MemUsage` reported by `docker stats` while running
+    pub stats: Option<(f64, String)>,
 }
 
 #[derive(Clone)]
 pub struct MockDockerClient {
     volumes: Arc<Mutex<HashMap<String, MockVolume>>>,
     containers: Arc<Mutex<HashMap<String, MockContainer>>>,
+    /// Network name to labels
+    networks: Arc<Mutex<HashMap<String, Vec<String>>>>,
+    /// Every command issued through [`DockerCli`], joined with spaces
+    calls: Arc<Mutex<Vec<String>>>,
+    available: Arc<Mutex<bool>>,
+    /// Container name to the output `docker logs` prints
+    container_logs: Arc<Mutex<HashMap<String, String>>>,
 }
 
 impl Default for MockDockerClient {
@@ -28,6 +42,10 @@ impl Default for MockDockerClient {
         Self {
             volumes: Arc::new(Mutex::new(HashMap::new())),
             containers: Arc::new(Mutex::new(HashMap::new())),
+            networks: Arc::new(Mutex::new(HashMap::new())),
+            calls: Arc::new(Mutex::new(Vec::new())),
+            available: Arc::new(Mutex::new(true)),
+            container_logs: Arc::new(Mutex::new(HashMap::new())),
         }
     }
 }
@@ -37,7 +55,33 @@ impl MockDockerClient {
         Self::default()
     }
 
+    /// Output `docker logs <name>` prints; containers without any are unknown
+    pub fn set_container_logs(&self, name: &str, output: &str) {
+        self.container_logs
+            .lock()
+            .unwrap()
+            .insert(name.to_string(), output.to_string());
+    }
+
     pub fn create_volume(&self, name: &str) -> Result<MockVolume, String> {
+        self.create_volume_with_labels(name, Vec::new())
+    }
+
+    pub fn create_labeled_volume(&self, name: &str, label: &str) -> Result<MockVolume, String> {
+        self.create_volume_with_labels(
+            name,
+            vec![
+                super::resources::MANAGED_LABEL.to_string(),
+                label.to_string(),
+            ],
+        )
+    }
+
+    fn create_volume_with_labels(
+        &self,
+        name: &str,
+        labels: Vec<String>,
+    ) -> Result<MockVolume, String> {
         let mut volumes = self.volumes.lock().unwrap();
 
         if volumes.contains_key(name) {
@@ -48,6 +92,7 @@ impl MockDockerClient {
             name: name.to_string(),
             mount_point: format!("/var/lib/docker/volumes/{name}"),
             exists: true,
+            labels,
         };
 
         volumes.insert(name.to_string(), volume.clone());
@@ -117,6 +162,9 @@ impl MockDockerClient {
             volumes,
             volumes_from,
             running: false,
+            restarting: false,
+            exit_code: 0,
+            stats: None,
         };
 
         containers.insert(name.to_string(), container.clone());
@@ -182,6 +230,206 @@ impl MockDockerClient {
         let containers = self.containers.lock().unwrap();
         containers.values().cloned().collect()
     }
+
+    /// Stats `docker stats` reports for a running container
+    pub fn set_container_stats(&self, name: &str, cpu_percent: f64, mem_usage: &str) {
+        if let Some(container) = self.containers.lock().unwrap().get_mut(name) {
+            container.stats = Some((cpu_percent, mem_usage.to_string()));
+        }
+    }
+
+    /// Simulate the container's process exiting with `exit_code`
+    pub fn exit_container(&self, name: &str, exit_code: i32) {
+        if let Some(container) = self.containers.lock().unwrap().get_mut(name) {
+            container.running = false;
+            container.exit_code = exit_code;
+        }
+    }
+
+    /// Simulate a restart policy bringing the container back after `exit_code`
+    pub fn restart_container(&self, name: &str, exit_code: i32) {
+        self.exit_container(name, exit_code);
+        if let Some(container) = self.containers.lock().unwrap().get_mut(name) {
+            container.restarting = true;
+        }
+    }
+
+    /// Network as created by a session started with network isolation
+    pub fn create_labeled_network(&self, name: &str, label: &str) {
+        self.networks.lock().unwrap().insert(
+            name.to_string(),
+            vec![
+                super::resources::MANAGED_LABEL.to_string(),
+                label.to_string(),
+            ],
+        );
+    }
+
+    pub fn network_exists(&self, name: &str) -> bool {
+        self.networks.lock().unwrap().contains_key(name)
+    }
+
+    /// Simulate the docker daemon being unreachable
+    pub fn set_available(&self, available: bool) {
+        *self.available.lock().unwrap() = available;
+    }
+
+    pub fn calls(&self) -> Vec<String> {
+        self.calls.lock().unwrap().clone()
+    }
+
+    fn volume_in_use(&self, name: &str) -> bool {
+        let containers = self.containers.lock().unwrap();
+        containers
+            .values()
+            .any(|container| container.volumes.iter().any(|v| v.name == name))
+    }
+
+    fn list_volume_names(&self, filters: &[&str]) -> String {
+        let volumes = self.volumes.lock().unwrap().clone();
+        let mut names: Vec<String> = volumes
+            .values()
+            .filter(|volume| {
+                filters.iter().all(|filter| match filter.split_once('=') {
+                    Some(("label", label)) => volume.labels.iter().any(|l| l == label),
+                    Some(("dangling", "true")) => !self.volume_in_use(&volume.name),
+                    _ => true,
+                })
+            })
+            .map(|volume| volume.name.clone())
+            .collect();
+        names.sort();
+        names.join("\n")
+    }
+
+    fn list_network_names(&self, filters: &[&str]) -> String {
+        let networks = self.networks.lock().unwrap();
+        let mut names: Vec<String> = networks
+            .iter()
+            .filter(|(_, labels)| {
+                filters.iter().all(|filter| match filter.split_once('=') {
+                    Some(("label", label)) => labels.iter().any(|l| l == label),
+                    // Mock containers never join networks, so every network is dangling
+                    _ => true,
+                })
+            })
+            .map(|(name, _)| name.clone())
+            .collect();
+        names.sort();
+        names.join("\n")
+    }
+}
+
+/// Values following each `--filter` and `--label` flag, plus the last positional argument
+fn parse_flags<'a>(args: &[&'a str]) -> (Vec<&'a str>, Vec<&'a str>, Option<&'a str>) {
+    let mut filters = Vec::new();
+    let mut labels = Vec::new();
+    let mut positional = None;
+    let mut iter = args.iter();
+
+    while let Some(arg) = iter.next() {
+        match *arg {
+            "--filter" => filters.extend(iter.next()),
+            "--label" => labels.extend(iter.next()),
+            "--format" => {
+                iter.next();
+            }
+            flag if flag.starts_with('-') => {}
+            value => positional = Some(value),
+        }
+    }
+
+    (filters, labels, positional)
+}
+
+impl DockerCli for MockDockerClient {
+    fn run(&self, args: &[&str]) -> DockerResult<String> {
+        self.calls.lock().unwrap().push(args.join(" "));
+
+        if !*self.available.lock().unwrap() {
+            return Err(DockerError::DaemonNotAvailable(
+                "mock docker is unavailable".to_string(),
+            ));
+        }
+
+        let (filters, labels, name) = parse_flags(&args[args.len().min(2)..]);
+        let failed = |msg: String| Err(DockerError::CommandFailed(msg));
+
+        match (args.first().copied(), args.get(1).copied()) {
+            (Some("version"), _) => Ok("mock".to_string()),
+            (Some("volume"), Some("ls")) => Ok(self.list_volume_names(&filters)),
+            (Some("volume"), Some("rm")) => self
+                .remove_volume(name.unwrap_or_default())
+                .map(|_| String::new())
+                .or_else(failed),
+            (Some("network"), Some("ls")) => Ok(self.list_network_names(&filters)),
+            (Some("network"), Some("create")) => {
+                let name = name.unwrap_or_default().to_string();
+                let mut networks = self.networks.lock().unwrap();
+                if networks.contains_key(&name) {
+                    return failed(format!("network with name {name} already exists"));
+                }
+                networks.insert(name, labels.iter().map(|l| l.to_string()).collect());
+                Ok(String::new())
+            }
+            (Some("network"), Some("rm")) => {
+                let name = name.unwrap_or_default();
+                match self.networks.lock().unwrap().remove(name) {
+                    Some(_) => Ok(String::new()),
+                    None => failed(format!("network {name} not found")),
+                }
+            }
+            (Some("images"), _) => Ok(String::new()),
+            (Some("logs"), Some(name)) => match self.container_logs.lock().unwrap().get(name) {
+                Some(output) => Ok(output.clone()),
+                None => failed(format!("No such container: {name}")),
+            },
+            (Some("stats"), _) => {
+                let containers = self.containers.lock().unwrap();
+                let lines: Vec<String> = containers
+                    .values()
+                    .filter(|container| container.running)
+                    .filter_map(|container| {
+                        let (cpu, mem_usage) = container.stats.as_ref()?;
+                        Some(
+                            serde_json::json!({
+                                "Name": container.name,
+                                "CPUPerc": format!("{cpu:.2}%"),
+                                "MemUsage": mem_usage,
+                            })
+                            .to_string(),
+                        )
+                    })
+                    .collect();
+                Ok(lines.join("\n"))
+            }
+            (Some("inspect"), _) => {
+                let name = name.unwrap_or_default();
+                match self.containers.lock().unwrap().get(name) {
+                    Some(container) => {
+                        let status = if container.restarting {
+                            "restarting"
+                        } else if container.running {
+                            "running"
+                        } else {
+                            "exited"
+                        };
+                        Ok(format!("{status} {}", container.exit_code))
+                    }
+                    None => failed(format!("No such object: {name}")),
+                }
+            }
+            (Some("system"), Some("df")) => {
+                let volumes = self.volumes.lock().unwrap();
+                let sizes: Vec<_> = volumes
+                    .keys()
+                    .map(|name| serde_json::json!({ "Name": name, "Size": "0B" }))
+                    .collect();
+                Ok(serde_json::Value::from(sizes).to_string())
+            }
+            _ => failed(format!("unsupported mock command: {}", args.join(" "))),
+        }
+    }
 }
 
 #[cfg(test)]
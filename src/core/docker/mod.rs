@@ -7,9 +7,11 @@ pub mod error;
 pub mod ide_integration;
 pub mod manager;
 pub mod pool;
+pub mod resources;
 pub mod service;
 pub mod session;
 pub mod signal_files;
+pub mod stats;
 pub mod watcher;
 
 #[cfg(test)]
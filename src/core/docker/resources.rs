@@ -0,0 +1,358 @@
+//! Per-session Docker resources and their cleanup
+//!
+//! Session containers carry the `para.managed=true` label plus a
+//! `para.session=<name>` label. Volumes and networks created for a session
+//! (for example through `--docker-args`) use the same labels, so everything
+//! that belongs to a session can be found again when it is cancelled or its
+//! container exits. Anonymous volumes are removed together with the container.
+//!
+//! All commands go through [`DockerCli`] so the cleanup logic can be exercised
+//! against the docker mock in tests.
+
+use super::{DockerError, DockerResult};
+use std::process::Command;
+
+/// Label applied to every Docker resource para creates
+pub const MANAGED_LABEL: &str = "para.managed=true";
+/// Label key identifying the session a resource belongs to
+pub const SESSION_LABEL_KEY: &str = "para.session";
+
+/// Minimal interface for running docker CLI commands
+pub trait DockerCli {
+    /// Run `docker <args>` and return its trimmed stdout
+    fn run(&self, args: &[&str]) -> DockerResult<String>;
+
+    /// Whether the docker daemon can be reached
+    fn is_available(&self) -> bool {
+        self.run(&["version", "--format", "{{.Server.Version}}"])
+            .is_ok()
+    }
+}
+
+/// [`DockerCli`] backed by the `docker` binary
+pub struct SystemDockerCli;
+
+impl DockerCli for SystemDockerCli {
+    fn run(&self, args: &[&str]) -> DockerResult<String> {
+        let output = Command::new("docker")
+            .args(args)
+            .output()
+            .map_err(|e| DockerError::DaemonNotAvailable(e.to_string()))?;
+
+        if !output.status.success() {
+            return Err(DockerError::CommandFailed(format!(
+                "docker {}: {}",
+                args.join(" "),
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+}
+
+pub fn session_label(session_name: &str) -> String {
+    format!("{SESSION_LABEL_KEY}={session_name}")
+}
+
+/// Arguments that label a container, volume or network as belonging to a session
+pub fn session_label_args(session_name: &str) -> Vec<String> {
+    vec![
+        "--label".to_string(),
+        MANAGED_LABEL.to_string(),
+        "--label".to_string(),
+        session_label(session_name),
+    ]
+}
+
+/// What was removed for a single session
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct SessionCleanupReport {
+    pub volumes_removed: Vec<String>,
+    pub networks_removed: Vec<String>,
+    /// Set when cleanup was skipped, e.g. because docker is unavailable
+    pub skipped: Option<String>,
+}
+
+/// Remove the volumes and network that belong to `session_name`.
+///
+/// The container itself must already be removed; resources still in use are
+/// reported as errors by docker. Skips with a note when docker is unavailable.
+pub fn cleanup_session_resources(
+    docker: &dyn DockerCli,
+    session_name: &str,
+) -> DockerResult<SessionCleanupReport> {
+    let mut report = SessionCleanupReport::default();
+
+    if !docker.is_available() {
+        report.skipped = Some("Docker is not available".to_string());
+        return Ok(report);
+    }
+
+    let label_filter = format!("label={}", session_label(session_name));
+
+    let volumes = docker.run(&["volume", "ls", "-q", "--filter", &label_filter])?;
+    for volume in volumes.lines().filter(|v| !v.is_empty()) {
+        docker.run(&["volume", "rm", "-f", volume])?;
+        report.volumes_removed.push(volume.to_string());
+    }
+
+    let networks = docker.run(&[
+        "network",
+        "ls",
+        "--filter",
+        &label_filter,
+        "--format",
+        "{{.Name}}",
+    ])?;
+    for network in networks.lines().filter(|n| !n.is_empty()) {
+        docker.run(&["network", "rm", network])?;
+        report.networks_removed.push(network.to_string());
+    }
+
+    Ok(report)
+}
+
+/// Print a note when cleanup was skipped and a warning when it failed.
+///
+/// Resource cleanup never blocks cancelling or cleaning a session.
+pub fn report_session_cleanup(session_name: &str, result: DockerResult<SessionCleanupReport>) {
+    match result {
+        Ok(SessionCleanupReport {
+            skipped: Some(reason),
+            ..
+        }) => {
+            eprintln!("Note: Skipped Docker resource cleanup for '{session_name}': {reason}");
+        }
+        Ok(_) => {}
+        Err(e) => {
+            eprintln!("Warning: Failed to remove Docker resources for '{session_name}': {e}");
+        }
+    }
+}
+
+/// A para-labeled resource that is no longer used by any container
+#[derive(Debug, Clone, PartialEq)]
+pub struct DanglingResource {
+    pub kind: ResourceKind,
+    pub name: String,
+    /// Human readable size as reported by docker, when known
+    pub size: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResourceKind {
+    Volume,
+    Network,
+    Image,
+}
+
+impl ResourceKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ResourceKind::Volume => "volume",
+            ResourceKind::Network => "network",
+            ResourceKind::Image => "image",
+        }
+    }
+}
+
+/// List dangling para-labeled volumes, networks and images with their sizes
+pub fn find_dangling_resources(docker: &dyn DockerCli) -> DockerResult<Vec<DanglingResource>> {
+    let managed_filter = format!("label={MANAGED_LABEL}");
+    let mut resources = Vec::new();
+
+    let volume_sizes = volume_sizes(docker);
+    let volumes = docker.run(&[
+        "volume",
+        "ls",
+        "-q",
+        "--filter",
+        &managed_filter,
+        "--filter",
+        "dangling=true",
+    ])?;
+    for name in volumes.lines().filter(|v| !v.is_empty()) {
+        resources.push(DanglingResource {
+            kind: ResourceKind::Volume,
+            name: name.to_string(),
+            size: volume_sizes
+                .iter()
+                .find(|(volume, _)| volume == name)
+                .map(|(_, size)| size.clone()),
+        });
+    }
+
+    let networks = docker.run(&[
+        "network",
+        "ls",
+        "--filter",
+        &managed_filter,
+        "--filter",
+        "dangling=true",
+        "--format",
+        "{{.Name}}",
+    ])?;
+    for name in networks.lines().filter(|n| !n.is_empty()) {
+        resources.push(DanglingResource {
+            kind: ResourceKind::Network,
+            name: name.to_string(),
+            size: None,
+        });
+    }
+
+    let images = docker.run(&[
+        "images",
+        "--filter",
+        &managed_filter,
+        "--filter",
+        "dangling=true",
+        "--format",
+        "{{.ID}}\t{{.Size}}",
+    ])?;
+    for line in images.lines().filter(|l| !l.is_empty()) {
+        let (id, size) = line.split_once('\t').unwrap_or((line, ""));
+        resources.push(DanglingResource {
+            kind: ResourceKind::Image,
+            name: id.to_string(),
+            size: (!size.is_empty()).then(|| size.to_string()),
+        });
+    }
+
+    Ok(resources)
+}
+
+/// Volume sizes from `docker system df -v`; empty when docker cannot report them
+fn volume_sizes(docker: &dyn DockerCli) -> Vec<(String, String)> {
+    let Ok(output) = docker.run(&["system", "df", "-v", "--format", "{{json .Volumes}}"]) else {
+        return Vec::new();
+    };
+
+    serde_json::from_str::<Vec<serde_json::Value>>(&output)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|volume| {
+            Some((
+                volume.get("Name")?.as_str()?.to_string(),
+                volume.get("Size")?.as_str()?.to_string(),
+            ))
+        })
+        .collect()
+}
+
+/// Remove the given dangling resources, returning how many were removed and
+/// the errors for those that could not be
+pub fn remove_dangling_resources(
+    docker: &dyn DockerCli,
+    resources: &[DanglingResource],
+) -> (usize, Vec<String>) {
+    let mut removed = 0;
+    let mut errors = Vec::new();
+
+    for resource in resources {
+        let args: [&str; 3] = match resource.kind {
+            ResourceKind::Volume => ["volume", "rm", &resource.name],
+            ResourceKind::Network => ["network", "rm", &resource.name],
+            ResourceKind::Image => ["image", "rm", &resource.name],
+        };
+        match docker.run(&args) {
+            Ok(_) => removed += 1,
+            Err(e) => errors.push(format!(
+                "Failed to remove {} {}: {e}",
+                resource.kind.label(),
+                resource.name
+            )),
+        }
+    }
+
+    (removed, errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::docker::mock::MockDockerClient;
+
+    fn removal_calls(docker: &MockDockerClient) -> Vec<String> {
+        docker
+            .calls()
+            .into_iter()
+            .filter(|call| call.contains(" rm "))
+            .collect()
+    }
+
+    #[test]
+    fn test_cleanup_isolated_session_removes_volumes_and_network() {
+        let docker = MockDockerClient::new();
+        docker.create_labeled_network("para-isolated-net", &session_label("isolated"));
+        docker
+            .create_labeled_volume("para-isolated-cache", &session_label("isolated"))
+            .unwrap();
+        docker
+            .create_labeled_volume("para-other-cache", &session_label("other"))
+            .unwrap();
+
+        let report = cleanup_session_resources(&docker, "isolated").unwrap();
+
+        assert_eq!(report.volumes_removed, vec!["para-isolated-cache"]);
+        assert_eq!(report.networks_removed, vec!["para-isolated-net"]);
+        assert_eq!(
+            removal_calls(&docker),
+            vec![
+                "volume rm -f para-isolated-cache",
+                "network rm para-isolated-net"
+            ]
+        );
+        assert!(docker.volume_exists("para-other-cache"));
+    }
+
+    #[test]
+    fn test_cleanup_session_without_isolation_leaves_networks_alone() {
+        let docker = MockDockerClient::new();
+        docker.create_labeled_network("para-someone-else-net", &session_label("someone-else"));
+        docker
+            .create_labeled_volume("para-plain-cache", &session_label("plain"))
+            .unwrap();
+
+        let report = cleanup_session_resources(&docker, "plain").unwrap();
+
+        assert!(report.networks_removed.is_empty());
+        assert_eq!(
+            removal_calls(&docker),
+            vec!["volume rm -f para-plain-cache"]
+        );
+        assert!(docker.network_exists("para-someone-else-net"));
+    }
+
+    #[test]
+    fn test_cleanup_skips_when_docker_unavailable() {
+        let docker = MockDockerClient::new();
+        docker.set_available(false);
+
+        let report = cleanup_session_resources(&docker, "any").unwrap();
+
+        assert!(report.skipped.is_some());
+        assert!(removal_calls(&docker).is_empty());
+    }
+
+    #[test]
+    fn test_find_and_remove_dangling_resources() {
+        let docker = MockDockerClient::new();
+        docker
+            .create_labeled_volume("para-gone-cache", &session_label("gone"))
+            .unwrap();
+        docker.create_labeled_network("para-gone-net", &session_label("gone"));
+        docker.create_volume("unrelated").unwrap();
+
+        let dangling = find_dangling_resources(&docker).unwrap();
+        let names: Vec<_> = dangling.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, vec!["para-gone-cache", "para-gone-net"]);
+        assert_eq!(dangling[0].size.as_deref(), Some("0B"));
+
+        let (removed, errors) = remove_dangling_resources(&docker, &dangling);
+        assert_eq!(removed, 2);
+        assert!(errors.is_empty());
+        assert!(!docker.volume_exists("para-gone-cache"));
+        assert!(docker.volume_exists("unrelated"));
+    }
+}
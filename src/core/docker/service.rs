@@ -2,6 +2,7 @@
 //!
 //! This provides core Docker functionality for the container pool system.
 
+use super::resources;
 use super::session::ContainerSession;
 use super::{DockerError, DockerResult};
 use std::path::Path;
@@ -32,6 +33,7 @@ impl DockerService {
             "--name".to_string(),
             container_name.clone(),
         ];
+        docker_cmd_args.extend(resources::session_label_args(options.session_name));
 
         // Insert user-provided Docker args before the standard args
         docker_cmd_args.extend_from_slice(options.docker_args);
@@ -433,9 +435,9 @@ impl DockerService {
             }
         }
 
-        // Remove the container
+        // Remove the container together with its anonymous volumes
         let rm_output = Command::new("docker")
-            .args(["rm", &container_name])
+            .args(["rm", "-v", &container_name])
             .output()
             .map_err(|e| DockerError::CommandFailed(format!("Failed to remove container: {e}")))?;
 
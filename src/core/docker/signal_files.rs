@@ -4,6 +4,7 @@
 //! which enables containers to communicate with the host system for operations
 //! like finish, cancel, and status updates.
 
+use crate::core::status::{deserialize_todo_list, TodoItem};
 use crate::utils::Result;
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
@@ -29,8 +30,13 @@ pub struct ContainerStatus {
     pub task: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tests: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub todos: Option<String>,
+    /// Structured items, or the `completed/total` string the shim writes
+    #[serde(
+        default,
+        deserialize_with = "deserialize_todo_list",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub todos: Vec<TodoItem>,
     #[serde(default)]
     pub blocked: bool,
     pub timestamp: String,
@@ -136,6 +142,7 @@ pub fn delete_signal_file(path: &Path) -> Result<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::status::parse_todo_list;
     use tempfile::TempDir;
 
     #[test]
@@ -187,7 +194,7 @@ mod tests {
         let status = ContainerStatus {
             task: "Implementing authentication".to_string(),
             tests: Some("failed".to_string()),
-            todos: Some("3/5".to_string()),
+            todos: parse_todo_list("3/5").unwrap(),
             blocked: false,
             timestamp: "2024-01-20T10:30:00Z".to_string(),
         };
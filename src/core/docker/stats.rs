@@ -0,0 +1,251 @@
+//! CPU and memory of session containers
+//!
+//! Running containers are sampled with one `docker stats --no-stream` call.
+//! Containers missing from it are looked up with `docker inspect` to tell a
+//! restarting or exited container (and its exit code) from one that is gone.
+
+use super::resources::DockerCli;
+use super::DockerResult;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// Prefix of every session container name
+pub const CONTAINER_PREFIX: &str = "para-";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContainerStats {
+    pub cpu_percent: f64,
+    pub mem_usage: u64,
+    pub mem_limit: u64,
+}
+
+impl ContainerStats {
+    pub fn mem_percent(&self) -> f64 {
+        if self.mem_limit == 0 {
+            0.0
+        } else {
+            self.mem_usage as f64 / self.mem_limit as f64 * 100.0
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ContainerHealth {
+    Running(ContainerStats),
+    Restarting {
+        exit_code: i32,
+    },
+    Exited {
+        exit_code: i32,
+    },
+    /// Any other state (created, paused, or running without stats yet)
+    Other {
+        status: String,
+    },
+    /// The session has no container (anymore)
+    Missing,
+}
+
+/// One line of `docker stats --format '{{json .}}'`
+#[derive(Deserialize)]
+struct StatsLine {
+    #[serde(rename = "Name")]
+    name: String,
+    #[serde(rename = "CPUPerc")]
+    cpu_perc: String,
+    #[serde(rename = "MemUsage")]
+    mem_usage: String,
+}
+
+/// Parse a `docker stats` JSON line into the container name and its stats
+pub fn parse_stats_line(line: &str) -> Option<(String, ContainerStats)> {
+    let line: StatsLine = serde_json::from_str(line).ok()?;
+    let cpu_percent = line.cpu_perc.trim().trim_end_matches('%').parse().ok()?;
+    let (usage, limit) = line.mem_usage.split_once('/')?;
+    Some((
+        line.name,
+        ContainerStats {
+            cpu_percent,
+            mem_usage: parse_size(usage)?,
+            mem_limit: parse_size(limit)?,
+        },
+    ))
+}
+
+/// Parse a docker size such as `512MiB`, `1.944GiB` or `12kB` into bytes
+pub fn parse_size(size: &str) -> Option<u64> {
+    let size = size.trim();
+    let split = size
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(size.len());
+    let (number, unit) = size.split_at(split);
+    let multiplier: f64 = match unit.trim() {
+        "" | "B" => 1.0,
+        "kB" | "KB" => 1e3,
+        "MB" => 1e6,
+        "GB" => 1e9,
+        "TB" => 1e12,
+        "KiB" => 1024.0,
+        "MiB" => 1024.0 * 1024.0,
+        "GiB" => 1024.0 * 1024.0 * 1024.0,
+        "TiB" => 1024.0 * 1024.0 * 1024.0 * 1024.0,
+        _ => return None,
+    };
+    let number: f64 = number.parse().ok()?;
+    Some((number * multiplier).round() as u64)
+}
+
+/// Short binary size, e.g. `512MiB` or `1.9GiB`
+pub fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{bytes}B");
+    }
+    let mut value = bytes as f64;
+    let mut unit = "B";
+    for next in UNITS {
+        if value < 1024.0 {
+            break;
+        }
+        value /= 1024.0;
+        unit = next;
+    }
+    if value < 10.0 {
+        format!("{value:.1}{unit}")
+    } else {
+        format!("{value:.0}{unit}")
+    }
+}
+
+fn parse_inspect(output: &str) -> Option<ContainerHealth> {
+    let mut parts = output.split_whitespace();
+    let status = parts.next()?;
+    let exit_code = parts.next()?.parse().ok()?;
+    Some(match status {
+        "restarting" => ContainerHealth::Restarting { exit_code },
+        "exited" | "dead" => ContainerHealth::Exited { exit_code },
+        status => ContainerHealth::Other {
+            status: status.to_string(),
+        },
+    })
+}
+
+/// Health of the containers of `session_names`, keyed by session name.
+/// Fails only when docker itself cannot be reached.
+pub fn sample_container_health(
+    docker: &dyn DockerCli,
+    session_names: &[String],
+) -> DockerResult<HashMap<String, ContainerHealth>> {
+    let output = docker.run(&["stats", "--no-stream", "--format", "{{json .}}"])?;
+    let mut running: HashMap<String, ContainerStats> = output
+        .lines()
+        .filter_map(parse_stats_line)
+        .filter_map(|(name, stats)| {
+            name.strip_prefix(CONTAINER_PREFIX)
+                .map(|session| (session.to_string(), stats))
+        })
+        .collect();
+
+    Ok(session_names
+        .iter()
+        .map(|session| {
+            let health = match running.remove(session) {
+                Some(stats) => ContainerHealth::Running(stats),
+                None => docker
+                    .run(&[
+                        "inspect",
+                        "--format",
+                        "{{.State.Status}} {{.State.ExitCode}}",
+                        &format!("{CONTAINER_PREFIX}{session}"),
+                    ])
+                    .ok()
+                    .and_then(|output| parse_inspect(&output))
+                    .unwrap_or(ContainerHealth::Missing),
+            };
+            (session.clone(), health)
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::docker::mock::MockDockerClient;
+
+    #[test]
+    fn test_parse_stats_line() {
+        let line = r#"{"BlockIO":"0B / 0B","CPUPerc":"87.35%","Container":"3f2a","ID":"3f2a","MemPerc":"25.00%","MemUsage":"512MiB / 2GiB","Name":"para-auth","NetIO":"1kB / 0B","PIDs":"12"}"#;
+        let (name, stats) = parse_stats_line(line).unwrap();
+        assert_eq!(name, "para-auth");
+        assert_eq!(stats.cpu_percent, 87.35);
+        assert_eq!(stats.mem_usage, 512 * 1024 * 1024);
+        assert_eq!(stats.mem_limit, 2 * 1024 * 1024 * 1024);
+        assert_eq!(stats.mem_percent(), 25.0);
+
+        assert!(parse_stats_line("not json").is_none());
+        let placeholder = r#"{"CPUPerc":"--","MemUsage":"-- / --","Name":"para-auth"}"#;
+        assert!(parse_stats_line(placeholder).is_none());
+    }
+
+    #[test]
+    fn test_parse_and_format_sizes() {
+        assert_eq!(parse_size("0B"), Some(0));
+        assert_eq!(parse_size("12kB"), Some(12_000));
+        assert_eq!(parse_size(" 1.5GiB "), Some(1_610_612_736));
+        assert_eq!(parse_size("7.8MB"), Some(7_800_000));
+        assert_eq!(parse_size("lots"), None);
+
+        assert_eq!(format_size(512), "512B");
+        assert_eq!(format_size(512 * 1024 * 1024), "512MiB");
+        assert_eq!(format_size(2_040_109_465), "1.9GiB");
+    }
+
+    #[test]
+    fn test_sample_container_health() {
+        let docker = MockDockerClient::new();
+        for name in [
+            "para-busy",
+            "para-crashed",
+            "para-looping",
+            "para-warming",
+            "other",
+        ] {
+            docker.create_container(name, vec![], vec![]).unwrap();
+            docker.start_container(name).unwrap();
+        }
+        docker.set_container_stats("para-busy", 99.5, "1.9GiB / 2GiB");
+        docker.set_container_stats("other", 1.0, "1MiB / 2GiB");
+        docker.exit_container("para-crashed", 137);
+        docker.restart_container("para-looping", 1);
+
+        let sessions: Vec<String> = ["busy", "crashed", "looping", "warming", "gone"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let health = sample_container_health(&docker, &sessions).unwrap();
+
+        assert!(matches!(
+            &health["busy"],
+            ContainerHealth::Running(stats) if stats.cpu_percent == 99.5
+        ));
+        assert_eq!(
+            health["crashed"],
+            ContainerHealth::Exited { exit_code: 137 }
+        );
+        assert_eq!(
+            health["looping"],
+            ContainerHealth::Restarting { exit_code: 1 }
+        );
+        assert_eq!(
+            health["warming"],
+            ContainerHealth::Other {
+                status: "running".to_string()
+            }
+        );
+        assert_eq!(health["gone"], ContainerHealth::Missing);
+        assert_eq!(health.len(), 5);
+
+        docker.set_available(false);
+        assert!(sample_container_health(&docker, &sessions).is_err());
+    }
+}
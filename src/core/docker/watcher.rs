@@ -4,15 +4,19 @@
 //! created by containers and processes them accordingly.
 
 use crate::config::Config;
+use crate::core::docker::resources::report_session_cleanup;
 use crate::core::docker::signal_files::{
     delete_signal_file, read_signal_file, CancelSignal, ContainerStatus, FinishSignal,
     SignalFilePaths,
 };
 use crate::core::docker::DockerManager;
-use crate::core::git::{FinishRequest, GitOperations, GitService};
+use crate::core::git::{GitOperations, GitService};
+use crate::core::logs;
+use crate::core::notifications::{notify, NotificationEvent};
+use crate::core::session::finish_report::{self, FinishReport};
 #[cfg(test)]
 use crate::core::session::SessionState;
-use crate::core::session::{SessionManager, SessionStatus};
+use crate::core::session::{SessionManager, SessionOperation, SessionStatus};
 use crate::utils::{ParaError, Result};
 use std::path::PathBuf;
 use std::sync::mpsc::{self, Receiver, Sender};
@@ -90,8 +94,15 @@ impl SignalFileWatcher {
         };
 
         let thread_handle = thread::spawn(move || {
+            let session_name = watcher.session_name.clone();
+            let state_dir = watcher.state_dir();
             if let Err(e) = watcher.run() {
                 eprintln!("Signal file watcher error: {e}");
+                logs::append_session_entry(
+                    &state_dir,
+                    &session_name,
+                    &format!("signal file watcher error: {e}"),
+                );
             }
         });
 
@@ -103,6 +114,16 @@ impl SignalFileWatcher {
         }
     }
 
+    fn state_dir(&self) -> PathBuf {
+        SessionManager::new(&self.config).state_dir().clone()
+    }
+
+    /// Append `message` to para.log, where `para logs` finds it; the daemon
+    /// running the watcher has no terminal
+    fn log(&self, message: &str) {
+        logs::append_session_entry(&self.state_dir(), &self.session_name, message);
+    }
+
     /// Main watcher loop
     fn run(self) -> Result<()> {
         let signal_paths = SignalFilePaths::new(&self.worktree_path);
@@ -147,10 +168,29 @@ impl SignalFileWatcher {
 
     /// Handle finish signal from container
     fn handle_finish_signal(&self, signal: FinishSignal) -> Result<()> {
+        // The agent in the container reads this to learn what became of its finish
+        finish_report::clear(&self.worktree_path)?;
+        let result = self.finish_from_signal(signal);
+        let report = match &result {
+            Ok(final_branch) => FinishReport::success(Some(&self.session_name), final_branch),
+            Err(e) => FinishReport::failed(Some(&self.session_name), e),
+        };
+        if let Err(e) = finish_report::write(&self.worktree_path, &report) {
+            eprintln!("Warning: Failed to write finish report: {e}");
+        }
+        result.map(|_| ())
+    }
+
+    /// Finish the session as the signal asks; the branch it was finished as
+    fn finish_from_signal(&self, signal: FinishSignal) -> Result<String> {
         println!(
             "📦 Container finish signal received: {}",
             signal.commit_message
         );
+        self.log(&format!(
+            "finish signal received: {}",
+            signal.commit_message
+        ));
 
         // Discover git repository from worktree
         let git_service = GitService::discover_from(&self.worktree_path)?;
@@ -162,12 +202,10 @@ impl SignalFileWatcher {
         // Create finish request
         let mut session_manager = SessionManager::new(&self.config);
         let session = session_manager.load_state(&self.session_name)?;
+        let _operation =
+            session_manager.begin_operation(&self.session_name, SessionOperation::Finishing)?;
 
-        let finish_request = FinishRequest {
-            feature_branch: session.branch.clone(),
-            commit_message: signal.commit_message.clone(),
-            target_branch_name: signal.branch,
-        };
+        let finish_request = session.finish_request(signal.commit_message.clone(), signal.branch);
 
         // Perform git finish
         let result = git_service.finish_session(finish_request)?;
@@ -176,28 +214,35 @@ impl SignalFileWatcher {
         session_manager.update_session_status(&self.session_name, SessionStatus::Review)?;
 
         // Stop the container
-        let docker_manager = DockerManager::new(self.config.clone(), false, vec![]);
-        if let Err(e) = docker_manager.stop_container(&self.session_name) {
-            eprintln!("Warning: Failed to stop container: {e}");
-        }
+        self.stop_container("session finished");
 
         match result {
             crate::core::git::FinishResult::Success { final_branch } => {
                 println!("✓ Container session finished successfully");
                 println!("  Feature branch: {final_branch}");
+                self.log(&format!("session finished on branch {final_branch}"));
                 println!("  Commit message: {}", signal.commit_message);
+                notify(
+                    &self.config,
+                    NotificationEvent::Finished {
+                        session: self.session_name.clone(),
+                        branch: final_branch.clone(),
+                    },
+                );
+                Ok(final_branch)
             }
         }
-
-        Ok(())
     }
 
     /// Handle cancel signal from container
     fn handle_cancel_signal(&self, signal: CancelSignal) -> Result<()> {
         println!("📦 Container cancel signal received");
+        self.log("cancel signal received");
 
         let mut session_manager = SessionManager::new(&self.config);
         let session = session_manager.load_state(&self.session_name)?;
+        let _operation =
+            session_manager.begin_operation(&self.session_name, SessionOperation::Cancelling)?;
 
         // Cancel the session
         if signal.force {
@@ -214,16 +259,45 @@ impl SignalFileWatcher {
         }
 
         // Stop the container
-        let docker_manager = DockerManager::new(self.config.clone(), false, vec![]);
-        if let Err(e) = docker_manager.stop_container(&self.session_name) {
-            eprintln!("Warning: Failed to stop container: {e}");
-        }
+        self.stop_container("session cancelled");
 
         println!("✓ Container session cancelled");
+        self.log("session cancelled");
+        notify(
+            &self.config,
+            NotificationEvent::Cancelled {
+                session: self.session_name.clone(),
+            },
+        );
 
         Ok(())
     }
 
+    /// Stop the session container, raising a notification once it has exited
+    fn stop_container(&self, reason: &str) {
+        let docker_manager = DockerManager::new(self.config.clone(), false, vec![]);
+        match docker_manager.stop_container(&self.session_name) {
+            Ok(()) => {
+                self.log(&format!("container stopped: {reason}"));
+                report_session_cleanup(
+                    &self.session_name,
+                    docker_manager.cleanup_session_resources(&self.session_name),
+                );
+                notify(
+                    &self.config,
+                    NotificationEvent::ContainerExited {
+                        session: self.session_name.clone(),
+                        reason: reason.to_string(),
+                    },
+                )
+            }
+            Err(e) => {
+                eprintln!("Warning: Failed to stop container: {e}");
+                self.log(&format!("failed to stop container: {e}"));
+            }
+        }
+    }
+
     /// Handle status update from container
     fn handle_status_update(&self, container_status: ContainerStatus) -> Result<()> {
         use crate::core::status::{Status, TestStatus};
@@ -236,13 +310,6 @@ impl SignalFileWatcher {
             .and_then(|s| Status::parse_test_status(s).ok())
             .unwrap_or(TestStatus::Unknown);
 
-        let (todos_completed, todos_total) = container_status
-            .todos
-            .as_ref()
-            .and_then(|s| Status::parse_todos(s).ok())
-            .map(|(completed, total)| (Some(completed), Some(total)))
-            .unwrap_or((None, None));
-
         // Create status update
         let mut status = Status::new(
             self.session_name.clone(),
@@ -251,9 +318,8 @@ impl SignalFileWatcher {
         );
 
         status.is_blocked = container_status.blocked;
-        status.todos_completed = todos_completed;
-        status.todos_total = todos_total;
-        status.last_update = Utc::now();
+        status.set_todos(container_status.todos.clone());
+        status.updated_at = Utc::now();
 
         // Calculate diff stats from the worktree
         // This runs on the host so it can access the worktree files
@@ -282,10 +348,26 @@ impl SignalFileWatcher {
             }
         };
 
+        // The status file is re-read on every poll, so only notify on the transition
+        let was_blocked = Status::load(&state_dir, &self.session_name)
+            .ok()
+            .flatten()
+            .is_some_and(|previous| previous.is_blocked);
+
         status
             .save(&state_dir)
             .map_err(|e| ParaError::fs_error(format!("Failed to save container status: {e}")))?;
 
+        if status.is_blocked && !was_blocked {
+            notify(
+                &self.config,
+                NotificationEvent::Blocked {
+                    session: self.session_name.clone(),
+                    reason: status.current_task.clone(),
+                },
+            );
+        }
+
         Ok(())
     }
 }
@@ -386,7 +468,11 @@ mod tests {
         let (_git_temp, git_service) = setup_test_repo();
 
         let config = create_test_config_with_dir(&temp_dir);
-        let worktree_path = git_temp.path().join("test-worktree");
+        let worktree_path = git_service
+            .repository()
+            .root
+            .join(&config.directories.subtrees_dir)
+            .join("test-worktree");
 
         // Create worktree
         git_service
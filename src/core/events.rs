@@ -0,0 +1,332 @@
+//! Notifications of session changes between para processes
+//!
+//! Whatever changes a session's state files also drops a small event file
+//! into `<state_dir>/events/`. Each file is written under a hidden temporary
+//! name and renamed into place, so a file watcher only ever sees complete
+//! events, and its name starts with the time it was written in nanoseconds,
+//! so names sort in the order the events happened. [`subscribe`] watches the
+//! directory and hands out the events, debounced so that a burst of writes to
+//! one session arrives as one event. Event files are pruned after a minute.
+
+use crate::utils::{ParaError, Result};
+use chrono::{DateTime, Utc};
+use notify::event::ModifyKind;
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
+
+/// Quiet time after an event before the subscriber passes on what arrived
+pub const DEBOUNCE: Duration = Duration::from_millis(100);
+
+/// Event files older than this are removed when the next event is written
+const RETENTION: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SessionEventKind {
+    Created,
+    Updated,
+    /// The agent reported a new status with `para status`
+    StatusChanged,
+    /// The session was finished and is waiting for review
+    Finished,
+    /// The session was cancelled or its state removed
+    Cancelled,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionEvent {
+    pub session: String,
+    pub kind: SessionEventKind,
+    pub at: DateTime<Utc>,
+}
+
+pub fn events_dir(state_dir: &Path) -> PathBuf {
+    state_dir.join("events")
+}
+
+/// Record that `session` changed. Never fails the change itself, and writes
+/// nothing while the state directory does not exist.
+pub fn publish(state_dir: &Path, session: &str, kind: SessionEventKind) {
+    if !state_dir.is_dir() {
+        return;
+    }
+    let event = SessionEvent {
+        session: session.to_string(),
+        kind,
+        at: Utc::now(),
+    };
+    let _ = write_event(&events_dir(state_dir), &event);
+}
+
+fn write_event(dir: &Path, event: &SessionEvent) -> io::Result<()> {
+    static SEQUENCE: AtomicU64 = AtomicU64::new(0);
+
+    fs::create_dir_all(dir)?;
+    prune(dir, SystemTime::now());
+
+    let name = format!(
+        "{:020}-{}-{}",
+        event.at.timestamp_nanos_opt().unwrap_or_default(),
+        std::process::id(),
+        SEQUENCE.fetch_add(1, Ordering::Relaxed)
+    );
+    let temp = dir.join(format!(".{name}.tmp"));
+    fs::write(&temp, serde_json::to_vec(event).map_err(io::Error::other)?)?;
+    fs::rename(&temp, dir.join(format!("{name}.json")))
+}
+
+fn prune(dir: &Path, now: SystemTime) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let expired = entry
+            .metadata()
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .and_then(|modified| now.duration_since(modified).ok())
+            .is_some_and(|age| age > RETENTION);
+        if expired {
+            let _ = fs::remove_file(entry.path());
+        }
+    }
+}
+
+/// Events still kept in the state directory, oldest first
+pub fn recent(state_dir: &Path) -> Vec<SessionEvent> {
+    let Ok(entries) = fs::read_dir(events_dir(state_dir)) else {
+        return Vec::new();
+    };
+    let mut paths: Vec<PathBuf> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| is_event_file(path))
+        .collect();
+    paths.sort();
+    paths.iter().filter_map(|path| read_event(path)).collect()
+}
+
+fn is_event_file(path: &Path) -> bool {
+    let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+        return false;
+    };
+    !name.starts_with('.') && name.ends_with(".json")
+}
+
+fn read_event(path: &Path) -> Option<SessionEvent> {
+    serde_json::from_slice(&fs::read(path).ok()?).ok()
+}
+
+/// Session events of one state directory as they happen
+pub struct EventSubscription {
+    events: Receiver<SessionEvent>,
+    failed: Arc<AtomicBool>,
+    _watcher: RecommendedWatcher,
+}
+
+impl EventSubscription {
+    /// Events passed on since the last poll, in the order they happened
+    pub fn poll(&self) -> Vec<SessionEvent> {
+        self.events.try_iter().collect()
+    }
+
+    pub fn recv_timeout(&self, timeout: Duration) -> Option<SessionEvent> {
+        self.events.recv_timeout(timeout).ok()
+    }
+
+    /// The watcher reported an error, so events may have been missed
+    pub fn failed(&self) -> bool {
+        self.failed.load(Ordering::SeqCst)
+    }
+}
+
+/// Watch `<state_dir>/events/`, creating it if needed. Fails when the state
+/// directory itself does not exist.
+pub fn subscribe(state_dir: &Path) -> Result<EventSubscription> {
+    if !state_dir.is_dir() {
+        return Err(ParaError::fs_error(format!(
+            "State directory {} does not exist",
+            state_dir.display()
+        )));
+    }
+    let dir = events_dir(state_dir);
+    fs::create_dir_all(&dir).map_err(|e| ParaError::from_io("create", &dir, e))?;
+
+    let (raw_sender, raw) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(move |event| {
+        let _ = raw_sender.send(event);
+    })
+    .map_err(|e| ParaError::fs_error(format!("Failed to watch {}: {e}", dir.display())))?;
+    watcher
+        .watch(&dir, RecursiveMode::NonRecursive)
+        .map_err(|e| ParaError::fs_error(format!("Failed to watch {}: {e}", dir.display())))?;
+
+    let (sender, events) = mpsc::channel();
+    let failed = Arc::new(AtomicBool::new(false));
+    let worker_failed = Arc::clone(&failed);
+    thread::spawn(move || debounce(raw, sender, worker_failed));
+
+    Ok(EventSubscription {
+        events,
+        failed,
+        _watcher: watcher,
+    })
+}
+
+/// Collect events until none arrived for [`DEBOUNCE`], then pass them on
+/// with repeats of the same kind for the same session dropped
+fn debounce(
+    raw: Receiver<notify::Result<notify::Event>>,
+    events: Sender<SessionEvent>,
+    failed: Arc<AtomicBool>,
+) {
+    let mut pending: Vec<SessionEvent> = Vec::new();
+    let mut deadline = Instant::now();
+    // Files already read; the watcher can report one file more than once
+    let mut seen: HashSet<PathBuf> = HashSet::new();
+    loop {
+        if !pending.is_empty() && Instant::now() >= deadline {
+            for event in pending.drain(..) {
+                if events.send(event).is_err() {
+                    return;
+                }
+            }
+            if seen.len() > 1024 {
+                seen.clear();
+            }
+        }
+        let received = if pending.is_empty() {
+            raw.recv().map_err(|_| RecvTimeoutError::Disconnected)
+        } else {
+            raw.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+        };
+        match received {
+            Ok(Ok(event)) => {
+                // Reading the files makes the watcher report accesses, which
+                // must not hold back what is pending
+                if !matches!(
+                    event.kind,
+                    EventKind::Create(_) | EventKind::Modify(ModifyKind::Name(_))
+                ) {
+                    continue;
+                }
+                for path in event.paths {
+                    if !is_event_file(&path) || seen.contains(&path) {
+                        continue;
+                    }
+                    let Some(event) = read_event(&path) else {
+                        continue;
+                    };
+                    seen.insert(path);
+                    deadline = Instant::now() + DEBOUNCE;
+                    let repeated = pending
+                        .iter()
+                        .any(|p| p.session == event.session && p.kind == event.kind);
+                    if !repeated {
+                        pending.push(event);
+                    }
+                }
+            }
+            Ok(Err(_)) => failed.store(true, Ordering::SeqCst),
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => return,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    /// Write an event file the way `publish` does, under a chosen name
+    fn write_synthetic(state_dir: &Path, name: &str, content: &str) {
+        let dir = events_dir(state_dir);
+        let temp = dir.join(format!(".{name}.tmp"));
+        fs::write(&temp, content).unwrap();
+        fs::rename(&temp, dir.join(format!("{name}.json"))).unwrap();
+    }
+
+    fn event_json(session: &str, kind: &str) -> String {
+        format!(r#"{{"session": "{session}", "kind": "{kind}", "at": "2026-01-01T00:00:00Z"}}"#)
+    }
+
+    /// Everything the subscription passes on until it stays quiet
+    fn collect(subscription: &EventSubscription) -> Vec<(String, SessionEventKind)> {
+        let mut events = Vec::new();
+        while let Some(event) = subscription.recv_timeout(Duration::from_secs(2)) {
+            events.push((event.session, event.kind));
+            if events.len() >= 3 {
+                break;
+            }
+        }
+        events
+    }
+
+    #[test]
+    fn test_subscriber_debounces_typed_events() {
+        let temp_dir = TempDir::new().unwrap();
+        let subscription = subscribe(temp_dir.path()).unwrap();
+
+        write_synthetic(temp_dir.path(), "1", &event_json("auth", "updated"));
+        write_synthetic(temp_dir.path(), "2", &event_json("auth", "updated"));
+        write_synthetic(temp_dir.path(), "3", "not an event");
+        write_synthetic(temp_dir.path(), "4", &event_json("auth", "status_changed"));
+        publish(temp_dir.path(), "api", SessionEventKind::Created);
+        publish(temp_dir.path(), "auth", SessionEventKind::Updated);
+
+        assert_eq!(
+            collect(&subscription),
+            vec![
+                ("auth".to_string(), SessionEventKind::Updated),
+                ("auth".to_string(), SessionEventKind::StatusChanged),
+                ("api".to_string(), SessionEventKind::Created),
+            ]
+        );
+        assert!(subscription.poll().is_empty());
+        assert!(!subscription.failed());
+
+        // A later burst is passed on by itself
+        publish(temp_dir.path(), "auth", SessionEventKind::Finished);
+        assert_eq!(
+            subscription
+                .recv_timeout(Duration::from_secs(2))
+                .map(|event| event.kind),
+            Some(SessionEventKind::Finished)
+        );
+    }
+
+    #[test]
+    fn test_publish_keeps_recent_events_in_order() {
+        let temp_dir = TempDir::new().unwrap();
+        publish(temp_dir.path(), "auth", SessionEventKind::Created);
+        publish(temp_dir.path(), "auth", SessionEventKind::Cancelled);
+        let kinds: Vec<_> = recent(temp_dir.path()).iter().map(|e| e.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![SessionEventKind::Created, SessionEventKind::Cancelled]
+        );
+
+        // Pruning drops events past the retention time
+        prune(
+            &events_dir(temp_dir.path()),
+            SystemTime::now() + RETENTION + Duration::from_secs(1),
+        );
+        assert!(recent(temp_dir.path()).is_empty());
+
+        // Nothing is written for a state directory that does not exist
+        let missing = temp_dir.path().join("missing");
+        publish(&missing, "auth", SessionEventKind::Created);
+        assert!(subscribe(&missing).is_err());
+        assert!(!missing.exists());
+    }
+}
@@ -0,0 +1,448 @@
+//! Garbage collection for the para state directory
+//!
+//! Append-only logs (`events.jsonl`, `*.status.history.jsonl` and the conflict
+//! records in `conflicts/`) are trimmed to the configured retention, `para.log` is rotated once it grows past the
+//! size threshold and `.task`/`.launch` files of sessions that no longer exist
+//! are removed. JSONL files are rewritten with the same lock, temp file and
+//! rename pattern used for status saves, so concurrent writers never observe a
+//! partially written file.
+
+use crate::config::Config;
+use crate::core::logs::{LOG_FILE, ROTATED_LOG_FILE};
+use crate::core::session::conflicts;
+use crate::utils::{ParaError, Result};
+use chrono::{DateTime, Duration, Utc};
+use fs2::FileExt;
+use std::fs::{self, File, OpenOptions};
+use std::io::{Read, Write};
+use std::path::Path;
+
+/// Default number of entries kept per JSONL log
+pub const DEFAULT_MAX_ENTRIES: usize = 1000;
+/// Default age in days after which JSONL entries are dropped
+pub const DEFAULT_MAX_AGE_DAYS: u32 = 30;
+/// Default size after which para.log is rotated (5 MiB)
+pub const DEFAULT_LOG_MAX_BYTES: u64 = 5 * 1024 * 1024;
+
+const EVENTS_FILE: &str = "events.jsonl";
+const STATUS_HISTORY_SUFFIX: &str = ".status.history.jsonl";
+const SESSION_FILE_SUFFIXES: &[&str] = &[".task", ".launch", ".op", ".setup.log"];
+const TIMESTAMP_KEYS: &[&str] = &["timestamp", "updated_at", "last_update"];
+
+/// Effective retention limits, resolved from `session.retention`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetentionPolicy {
+    pub max_entries: usize,
+    pub max_age_days: u32,
+    pub log_max_bytes: u64,
+}
+
+impl Default for RetentionPolicy {
+    fn default() -> Self {
+        Self {
+            max_entries: DEFAULT_MAX_ENTRIES,
+            max_age_days: DEFAULT_MAX_AGE_DAYS,
+            log_max_bytes: DEFAULT_LOG_MAX_BYTES,
+        }
+    }
+}
+
+impl RetentionPolicy {
+    pub fn from_config(config: &Config) -> Self {
+        let defaults = Self::default();
+        let Some(retention) = config.session.retention.as_ref() else {
+            return defaults;
+        };
+
+        Self {
+            max_entries: retention.max_entries.unwrap_or(defaults.max_entries),
+            max_age_days: retention.max_age_days.unwrap_or(defaults.max_age_days),
+            log_max_bytes: retention.log_max_bytes.unwrap_or(defaults.log_max_bytes),
+        }
+    }
+}
+
+/// Summary of what a garbage collection run changed
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct GcReport {
+    pub logs_truncated: usize,
+    pub entries_removed: usize,
+    pub logs_rotated: usize,
+    pub orphaned_files_removed: usize,
+    pub bytes_reclaimed: u64,
+}
+
+impl GcReport {
+    pub fn is_empty(&self) -> bool {
+        self.logs_truncated == 0
+            && self.logs_rotated == 0
+            && self.orphaned_files_removed == 0
+            && self.bytes_reclaimed == 0
+    }
+}
+
+/// Apply `policy` to every garbage-collectable file in `state_dir`
+pub fn collect_garbage(state_dir: &Path, policy: &RetentionPolicy) -> Result<GcReport> {
+    let mut report = GcReport::default();
+
+    if !state_dir.exists() {
+        return Ok(report);
+    }
+
+    let cutoff = Utc::now() - Duration::days(i64::from(policy.max_age_days));
+
+    for entry in fs::read_dir(state_dir)? {
+        let path = entry?.path();
+        let Some(file_name) = path.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+
+        if file_name == EVENTS_FILE || file_name.ends_with(STATUS_HISTORY_SUFFIX) {
+            if let Some((removed, reclaimed)) = truncate_jsonl(&path, policy.max_entries, cutoff)? {
+                report.logs_truncated += 1;
+                report.entries_removed += removed;
+                report.bytes_reclaimed += reclaimed;
+            }
+        } else if let Some(session_name) = SESSION_FILE_SUFFIXES
+            .iter()
+            .find_map(|suffix| file_name.strip_suffix(suffix))
+        {
+            if !state_dir.join(format!("{session_name}.state")).exists() {
+                let size = fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+                fs::remove_file(&path).map_err(|e| {
+                    ParaError::fs_error(format!("Failed to remove {}: {e}", path.display()))
+                })?;
+                report.orphaned_files_removed += 1;
+                report.bytes_reclaimed += size;
+            }
+        }
+    }
+
+    for log in conflicts::record_logs(state_dir) {
+        let max_entries = policy.max_entries.min(conflicts::MAX_RECORDS_PER_FILE);
+        if let Some((removed, reclaimed)) = truncate_jsonl(&log, max_entries, cutoff)? {
+            report.logs_truncated += 1;
+            report.entries_removed += removed;
+            report.bytes_reclaimed += reclaimed;
+            // A file whose records all aged out is no longer a hotspot
+            if fs::metadata(&log).is_ok_and(|m| m.len() == 0) {
+                fs::remove_file(&log).map_err(|e| {
+                    ParaError::fs_error(format!("Failed to remove {}: {e}", log.display()))
+                })?;
+            }
+        }
+    }
+
+    if let Some(reclaimed) = rotate_log(state_dir, policy.log_max_bytes)? {
+        report.logs_rotated += 1;
+        report.bytes_reclaimed += reclaimed;
+    }
+
+    Ok(report)
+}
+
+/// Run garbage collection for the configured state directory, ignoring errors.
+///
+/// Used by commands such as `para clean` that compact the state directory as
+/// a side effect.
+pub fn collect_garbage_quietly(config: &Config) -> Option<GcReport> {
+    let session_manager = crate::core::session::SessionManager::new(config);
+    match collect_garbage(
+        session_manager.state_dir(),
+        &RetentionPolicy::from_config(config),
+    ) {
+        Ok(report) => Some(report),
+        Err(e) => {
+            crate::utils::debug_log(&format!("State directory garbage collection failed: {e}"));
+            None
+        }
+    }
+}
+
+/// Format a byte count for display, e.g. `1.5 MiB`
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: &[&str] = &["KiB", "MiB", "GiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+/// Keep the newest valid entries of a JSONL file that fall within the
+/// retention window. Returns the number of removed lines and bytes reclaimed,
+/// or `None` when the file was left untouched.
+fn truncate_jsonl(
+    path: &Path,
+    max_entries: usize,
+    cutoff: DateTime<Utc>,
+) -> Result<Option<(usize, u64)>> {
+    let mut file = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open(path)
+        .map_err(|e| ParaError::fs_error(format!("Failed to open {}: {e}", path.display())))?;
+
+    // Hold the lock on the original file until the replacement is in place
+    file.lock_exclusive()
+        .map_err(|e| ParaError::fs_error(format!("Failed to lock {}: {e}", path.display())))?;
+
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .map_err(|e| ParaError::fs_error(format!("Failed to read {}: {e}", path.display())))?;
+
+    let lines: Vec<&str> = content.lines().filter(|l| !l.trim().is_empty()).collect();
+    let valid: Vec<&str> = lines
+        .iter()
+        .copied()
+        .filter(|line| {
+            serde_json::from_str::<serde_json::Value>(line)
+                .is_ok_and(|entry| !is_expired(&entry, cutoff))
+        })
+        .collect();
+    let kept = &valid[valid.len().saturating_sub(max_entries)..];
+
+    // Rewrite when anything is dropped, including malformed lines
+    if kept.len() == lines.len() && (content.is_empty() || content.ends_with('\n')) {
+        return Ok(None);
+    }
+
+    let mut new_content = kept.join("\n");
+    if !new_content.is_empty() {
+        new_content.push('\n');
+    }
+
+    write_atomically(path, &new_content)?;
+    drop(file);
+
+    let reclaimed = (content.len() as u64).saturating_sub(new_content.len() as u64);
+    Ok(Some((lines.len() - kept.len(), reclaimed)))
+}
+
+fn is_expired(entry: &serde_json::Value, cutoff: DateTime<Utc>) -> bool {
+    TIMESTAMP_KEYS
+        .iter()
+        .find_map(|key| entry.get(key).and_then(|v| v.as_str()))
+        .and_then(|ts| DateTime::parse_from_rfc3339(ts).ok())
+        .is_some_and(|ts| ts.with_timezone(&Utc) < cutoff)
+}
+
+fn write_atomically(path: &Path, content: &str) -> Result<()> {
+    let temp_file = path.with_extension("jsonl.tmp");
+
+    let mut file = File::create(&temp_file)
+        .map_err(|e| ParaError::fs_error(format!("Failed to create temp file: {e}")))?;
+    file.write_all(content.as_bytes())
+        .map_err(|e| ParaError::fs_error(format!("Failed to write temp file: {e}")))?;
+    file.sync_all()
+        .map_err(|e| ParaError::fs_error(format!("Failed to sync temp file: {e}")))?;
+    drop(file);
+
+    fs::rename(&temp_file, path)
+        .map_err(|e| ParaError::fs_error(format!("Failed to replace {}: {e}", path.display())))
+}
+
+/// Rotate para.log to para.log.old once it exceeds `max_bytes`. Returns the
+/// size of the previous rotated log, which is the space reclaimed.
+fn rotate_log(state_dir: &Path, max_bytes: u64) -> Result<Option<u64>> {
+    let log_file = state_dir.join(LOG_FILE);
+    let size = match fs::metadata(&log_file) {
+        Ok(metadata) => metadata.len(),
+        Err(_) => return Ok(None),
+    };
+
+    if size <= max_bytes {
+        return Ok(None);
+    }
+
+    let rotated = state_dir.join(ROTATED_LOG_FILE);
+    let previous = fs::metadata(&rotated).map(|m| m.len()).unwrap_or(0);
+    fs::rename(&log_file, &rotated)
+        .map_err(|e| ParaError::fs_error(format!("Failed to rotate {LOG_FILE}: {e}")))?;
+
+    Ok(Some(previous))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn entry(index: usize, timestamp: DateTime<Utc>) -> String {
+        serde_json::json!({
+            "index": index,
+            "timestamp": timestamp.to_rfc3339(),
+        })
+        .to_string()
+    }
+
+    fn write_lines(path: &Path, lines: &[String]) {
+        let mut content = lines.join("\n");
+        content.push('\n');
+        fs::write(path, content).unwrap();
+    }
+
+    fn read_entries(path: &Path) -> Vec<serde_json::Value> {
+        let content = fs::read_to_string(path).unwrap();
+        assert!(content.is_empty() || content.ends_with('\n'));
+        content
+            .lines()
+            .map(|line| serde_json::from_str(line).expect("every line must be valid JSON"))
+            .collect()
+    }
+
+    fn policy(max_entries: usize) -> RetentionPolicy {
+        RetentionPolicy {
+            max_entries,
+            ..RetentionPolicy::default()
+        }
+    }
+
+    #[test]
+    fn test_truncates_history_to_newest_entries() {
+        let temp_dir = TempDir::new().unwrap();
+        let history = temp_dir.path().join("feature.status.history.jsonl");
+        let events = temp_dir.path().join(EVENTS_FILE);
+        let lines: Vec<String> = (0..200).map(|i| entry(i, Utc::now())).collect();
+        write_lines(&history, &lines);
+        write_lines(&events, &lines);
+
+        let report = collect_garbage(temp_dir.path(), &policy(25)).unwrap();
+        assert_eq!(report.logs_truncated, 2);
+        assert_eq!(report.entries_removed, 350);
+        assert!(report.bytes_reclaimed > 0);
+
+        for path in [&history, &events] {
+            let entries = read_entries(path);
+            assert_eq!(entries.len(), 25);
+            assert_eq!(entries[0]["index"], 175);
+            assert_eq!(entries[24]["index"], 199);
+        }
+
+        // A second run has nothing left to do
+        let report = collect_garbage(temp_dir.path(), &policy(25)).unwrap();
+        assert!(report.is_empty());
+    }
+
+    #[test]
+    fn test_drops_expired_and_malformed_entries() {
+        let temp_dir = TempDir::new().unwrap();
+        let events = temp_dir.path().join(EVENTS_FILE);
+        let old = Utc::now() - Duration::days(90);
+        let lines = vec![
+            entry(0, old),
+            entry(1, old),
+            "{\"index\": 2, \"trunc".to_string(),
+            entry(3, Utc::now()),
+            serde_json::json!({ "index": 4 }).to_string(),
+        ];
+        write_lines(&events, &lines);
+
+        let report = collect_garbage(temp_dir.path(), &RetentionPolicy::default()).unwrap();
+        assert_eq!(report.entries_removed, 3);
+
+        let entries = read_entries(&events);
+        let indices: Vec<_> = entries
+            .iter()
+            .map(|e| e["index"].as_u64().unwrap())
+            .collect();
+        assert_eq!(indices, vec![3, 4]);
+    }
+
+    #[test]
+    fn test_ages_out_conflict_records() {
+        let temp_dir = TempDir::new().unwrap();
+        let conflicts_dir = conflicts::conflicts_dir(temp_dir.path());
+        fs::create_dir_all(&conflicts_dir).unwrap();
+        let old = Utc::now() - Duration::days(90);
+        let stale = conflicts::record_log_path(temp_dir.path(), "src/old.rs");
+        write_lines(&stale, &[entry(0, old)]);
+        let busy = conflicts::record_log_path(temp_dir.path(), "src/lib.rs");
+        let lines: Vec<String> = (0..30).map(|i| entry(i, Utc::now())).collect();
+        write_lines(&busy, &lines);
+
+        let report = collect_garbage(temp_dir.path(), &RetentionPolicy::default()).unwrap();
+        assert_eq!(report.logs_truncated, 2);
+        assert!(!stale.exists());
+        let entries = read_entries(&busy);
+        assert_eq!(entries.len(), conflicts::MAX_RECORDS_PER_FILE);
+        assert_eq!(entries[0]["index"], 10);
+    }
+
+    #[test]
+    fn test_rotates_oversized_log_keeping_one_old() {
+        let temp_dir = TempDir::new().unwrap();
+        let log = temp_dir.path().join(LOG_FILE);
+        let rotated = temp_dir.path().join(ROTATED_LOG_FILE);
+        fs::write(&rotated, "a".repeat(64)).unwrap();
+        fs::write(&log, "b".repeat(200)).unwrap();
+
+        let limits = RetentionPolicy {
+            log_max_bytes: 100,
+            ..RetentionPolicy::default()
+        };
+        let report = collect_garbage(temp_dir.path(), &limits).unwrap();
+        assert_eq!(report.logs_rotated, 1);
+        assert_eq!(report.bytes_reclaimed, 64);
+        assert!(!log.exists());
+        assert_eq!(fs::read_to_string(&rotated).unwrap(), "b".repeat(200));
+
+        // Small logs are left alone
+        fs::write(&log, "small").unwrap();
+        let report = collect_garbage(temp_dir.path(), &limits).unwrap();
+        assert_eq!(report.logs_rotated, 0);
+        assert!(log.exists());
+    }
+
+    #[test]
+    fn test_removes_files_of_missing_sessions() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path();
+        fs::write(dir.join("alive.state"), "{}").unwrap();
+        fs::write(dir.join("alive.task"), "keep").unwrap();
+        fs::write(dir.join("alive.launch"), "keep").unwrap();
+        fs::write(dir.join("gone.task"), "remove me").unwrap();
+        fs::write(dir.join("gone.launch"), "remove").unwrap();
+
+        let report = collect_garbage(dir, &RetentionPolicy::default()).unwrap();
+        assert_eq!(report.orphaned_files_removed, 2);
+        assert_eq!(report.bytes_reclaimed, 15);
+        assert!(dir.join("alive.task").exists());
+        assert!(dir.join("alive.launch").exists());
+        assert!(!dir.join("gone.task").exists());
+        assert!(!dir.join("gone.launch").exists());
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(5 * 1024 * 1024), "5.0 MiB");
+    }
+
+    #[test]
+    fn test_policy_from_config() {
+        let mut config = crate::test_utils::test_helpers::create_test_config();
+        assert_eq!(
+            RetentionPolicy::from_config(&config),
+            RetentionPolicy::default()
+        );
+
+        config.session.retention = Some(crate::config::RetentionConfig {
+            max_entries: Some(50),
+            max_age_days: None,
+            log_max_bytes: Some(1024),
+        });
+        let policy = RetentionPolicy::from_config(&config);
+        assert_eq!(policy.max_entries, 50);
+        assert_eq!(policy.max_age_days, DEFAULT_MAX_AGE_DAYS);
+        assert_eq!(policy.log_max_bytes, 1024);
+    }
+}
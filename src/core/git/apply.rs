@@ -0,0 +1,275 @@
+//! Seed a worktree with uncommitted changes from a patch or another branch
+
+use super::command::{self, GitCommandOptions};
+use crate::utils::{ParaError, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Where a new session's starting changes come from
+#[derive(Debug, Clone, PartialEq)]
+pub enum StartingChanges {
+    /// A patch file, as written by `git diff` or `git format-patch`
+    Patch(PathBuf),
+    /// Everything `branch` changed since it forked from the session's base
+    Branch(String),
+}
+
+impl StartingChanges {
+    pub fn from_args(apply_patch: Option<&Path>, apply_from: Option<&str>) -> Option<Self> {
+        match (apply_patch, apply_from) {
+            (Some(patch), _) => Some(Self::Patch(patch.to_path_buf())),
+            (None, Some(branch)) => Some(Self::Branch(branch.to_string())),
+            (None, None) => None,
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        match self {
+            Self::Patch(patch) => patch.display().to_string(),
+            Self::Branch(branch) => format!("branch '{branch}'"),
+        }
+    }
+}
+
+/// What `git apply` does about whitespace errors in a patch (`git.apply_whitespace`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ApplyWhitespace {
+    /// Apply the patch as it is without mentioning whitespace errors
+    #[default]
+    Nowarn,
+    Warn,
+    /// Fix whitespace errors in the lines the patch adds
+    Fix,
+    /// Refuse patches that add whitespace errors
+    Error,
+    ErrorAll,
+}
+
+impl ApplyWhitespace {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    fn as_arg(&self) -> &'static str {
+        match self {
+            Self::Nowarn => "--whitespace=nowarn",
+            Self::Warn => "--whitespace=warn",
+            Self::Fix => "--whitespace=fix",
+            Self::Error => "--whitespace=error",
+            Self::ErrorAll => "--whitespace=error-all",
+        }
+    }
+}
+
+/// How the changes were applied
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Applied {
+    /// The patch only applied on a second attempt with `--ignore-whitespace`,
+    /// usually because of differing line endings
+    pub ignored_whitespace: bool,
+}
+
+/// Apply `changes` to the worktree at `worktree` without committing them.
+/// `base` is the commit the session started from.
+pub fn apply_starting_changes(
+    worktree: &Path,
+    base: &str,
+    changes: &StartingChanges,
+    whitespace: ApplyWhitespace,
+) -> Result<Applied> {
+    match changes {
+        StartingChanges::Patch(patch) => {
+            if !patch.is_file() {
+                return Err(ParaError::file_not_found(patch.display().to_string()));
+            }
+            apply_patch(worktree, patch, whitespace)
+        }
+        StartingChanges::Branch(branch) => {
+            let patch = branch_diff(worktree, base, branch)?;
+            let result = if is_empty(&patch) {
+                Ok(Applied::default())
+            } else {
+                apply_patch(worktree, &patch, whitespace)
+            };
+            let _ = fs::remove_file(&patch);
+            result
+        }
+    }
+}
+
+/// Write the diff of `branch` since its merge base with `base` to a temporary file
+fn branch_diff(worktree: &Path, base: &str, branch: &str) -> Result<PathBuf> {
+    let output = git(worktree, &["rev-parse", "--verify", "--quiet", branch])?;
+    if !output.status.success() {
+        return Err(ParaError::git_operation(format!(
+            "Branch '{branch}' does not exist"
+        )));
+    }
+
+    let patch = std::env::temp_dir().join(format!("para-apply-{}.patch", uuid::Uuid::new_v4()));
+    let output = git(
+        worktree,
+        &[
+            "diff",
+            "--binary",
+            &format!("--output={}", patch.display()),
+            &format!("{base}...{branch}"),
+        ],
+    )?;
+    if !output.status.success() {
+        let _ = fs::remove_file(&patch);
+        return Err(ParaError::git_operation(format!(
+            "Failed to diff '{branch}' against the session base: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    Ok(patch)
+}
+
+fn is_empty(patch: &Path) -> bool {
+    fs::metadata(patch).map(|m| m.len() == 0).unwrap_or(true)
+}
+
+fn apply_patch(worktree: &Path, patch: &Path, whitespace: ApplyWhitespace) -> Result<Applied> {
+    let patch = patch.canonicalize().unwrap_or_else(|_| patch.to_path_buf());
+    let patch_arg = patch.to_string_lossy();
+    let output = git(
+        worktree,
+        &["apply", "--3way", whitespace.as_arg(), &patch_arg],
+    )?;
+    if output.status.success() {
+        return Ok(Applied::default());
+    }
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    // A failed `git apply` leaves the worktree untouched unless it got as far
+    // as a three-way merge, so only then is a second attempt safe
+    if is_whitespace_failure(&stderr) && !stderr.lines().any(|line| line.starts_with("U ")) {
+        let retry = git(
+            worktree,
+            &[
+                "apply",
+                "--3way",
+                "--ignore-whitespace",
+                whitespace.as_arg(),
+                &patch_arg,
+            ],
+        )?;
+        if retry.status.success() {
+            return Ok(Applied {
+                ignored_whitespace: true,
+            });
+        }
+    }
+
+    let conflicts = conflicting_hunks(&stderr);
+    let details = if conflicts.is_empty() {
+        stderr.trim().to_string()
+    } else {
+        conflicts
+            .iter()
+            .map(|hunk| format!("  {hunk}"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+    Err(ParaError::git_operation(format!(
+        "Failed to apply {}:\n{details}",
+        patch.display()
+    )))
+}
+
+/// Whether `git apply` failed on whitespace, which includes CRLF line endings
+/// that differ between the patch and the worktree
+fn is_whitespace_failure(stderr: &str) -> bool {
+    ["corrupt patch", "whitespace error", "patch does not apply"]
+        .iter()
+        .any(|text| stderr.contains(text))
+}
+
+/// Hunks `git apply` could not place, as `file:line`, plus files left with conflicts
+fn conflicting_hunks(stderr: &str) -> Vec<String> {
+    let mut hunks = Vec::new();
+    for line in stderr.lines() {
+        if let Some(hunk) = line.strip_prefix("error: patch failed: ") {
+            hunks.push(hunk.trim().to_string());
+        } else if let Some(file) = line.strip_prefix("U ") {
+            hunks.push(format!("{} (conflicts with the session base)", file.trim()));
+        }
+    }
+    hunks
+}
+
+fn git(dir: &Path, args: &[&str]) -> Result<std::process::Output> {
+    command::output(dir, args, GitCommandOptions::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::git::repository::execute_git_command;
+    use crate::test_utils::test_helpers::*;
+
+    #[test]
+    fn test_conflicting_hunks_from_apply_output() {
+        let stderr = "error: patch failed: src/lib.rs:12\n\
+                      Falling back to three-way merge...\n\
+                      Applied patch to 'src/lib.rs' with conflicts.\n\
+                      U src/lib.rs\n";
+        assert_eq!(
+            conflicting_hunks(stderr),
+            vec![
+                "src/lib.rs:12".to_string(),
+                "src/lib.rs (conflicts with the session base)".to_string()
+            ]
+        );
+        assert!(conflicting_hunks("fatal: not a git repository").is_empty());
+    }
+
+    #[test]
+    fn test_patch_without_carriage_returns_is_retried_ignoring_whitespace() {
+        let (git_temp, git_service) = setup_test_repo();
+        let repo = git_service.repository();
+        let file = git_temp.path().join("windows.txt");
+        fs::write(&file, b"one\r\ntwo\r\nthree\r\n").unwrap();
+        execute_git_command(repo, &["add", "windows.txt"]).unwrap();
+        execute_git_command(repo, &["commit", "-q", "-m", "Add CRLF file"]).unwrap();
+        execute_git_command(repo, &["config", "core.autocrlf", "input"]).unwrap();
+
+        // The patch lost its carriage returns on the way, e.g. in an email client
+        let patch = git_temp.path().join("lf.patch");
+        fs::write(
+            &patch,
+            "diff --git a/windows.txt b/windows.txt\n\
+             --- a/windows.txt\n\
+             +++ b/windows.txt\n\
+             @@ -1,3 +1,3 @@\n \
+             one\n\
+             -two\n\
+             +TWO\n \
+             three\n",
+        )
+        .unwrap();
+
+        let applied = apply_starting_changes(
+            git_temp.path(),
+            "HEAD",
+            &StartingChanges::Patch(patch),
+            ApplyWhitespace::default(),
+        )
+        .unwrap();
+        assert!(applied.ignored_whitespace);
+        assert_eq!(fs::read(&file).unwrap(), b"one\r\nTWO\nthree\r\n");
+    }
+
+    #[test]
+    fn test_whitespace_failures_are_recognized() {
+        assert!(is_whitespace_failure(
+            "error: patch failed: a.txt:1\nerror: a.txt: patch does not apply"
+        ));
+        assert!(is_whitespace_failure("error: corrupt patch at line 7"));
+        assert!(!is_whitespace_failure("fatal: not a git repository"));
+        assert_eq!(ApplyWhitespace::ErrorAll.as_arg(), "--whitespace=error-all");
+    }
+}
@@ -0,0 +1,253 @@
+//! Append-only log of the git commands para runs
+//!
+//! With `git.audit_log` enabled every git command that may change the
+//! repository is appended to `<state_dir>/audit/<session>.log`, or to
+//! `repo.log` when it did not run inside a session worktree. Entries are JSON
+//! lines. Recording is best effort: a failed write never fails the git
+//! operation itself.
+
+use crate::core::session::state::SessionState;
+use crate::utils::{debug_log, ParaError, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Carries the audit directory to this process and child processes
+pub const GIT_AUDIT_DIR_ENV: &str = "PARA_GIT_AUDIT_DIR";
+
+/// Log for commands that did not run inside a session worktree
+pub const REPO_LOG_NAME: &str = "repo";
+
+/// Subcommands that never change the repository
+const READ_ONLY_COMMANDS: &[&str] = &[
+    "blame",
+    "cat-file",
+    "describe",
+    "diff",
+    "diff-files",
+    "diff-index",
+    "diff-tree",
+    "for-each-ref",
+    "grep",
+    "log",
+    "ls-files",
+    "ls-remote",
+    "ls-tree",
+    "merge-base",
+    "rev-list",
+    "rev-parse",
+    "shortlog",
+    "show",
+    "show-ref",
+    "status",
+    "var",
+    "version",
+];
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditEntry {
+    pub timestamp: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session: Option<String>,
+    pub cwd: PathBuf,
+    pub argv: Vec<String>,
+    /// `None` when git could not be started or was killed
+    pub exit_code: Option<i32>,
+    pub duration_ms: u64,
+}
+
+pub fn audit_dir(state_dir: &Path) -> PathBuf {
+    state_dir.join("audit")
+}
+
+pub fn audit_log_file(audit_dir: &Path, session_name: &str) -> PathBuf {
+    audit_dir.join(format!("{session_name}.log"))
+}
+
+/// Enable the audit log for git commands run by this process and its children
+pub fn export_git_audit_dir(dir: Option<&Path>) {
+    if let Some(dir) = dir {
+        std::env::set_var(GIT_AUDIT_DIR_ENV, dir);
+    }
+}
+
+#[cfg(test)]
+thread_local! {
+    /// Lets a test audit its own git commands without touching other tests
+    static TEST_AUDIT_DIR: std::cell::RefCell<Option<PathBuf>> =
+        const { std::cell::RefCell::new(None) };
+}
+
+#[cfg(test)]
+pub(crate) fn set_test_audit_dir(dir: Option<PathBuf>) {
+    TEST_AUDIT_DIR.with(|current| *current.borrow_mut() = dir);
+}
+
+fn current_audit_dir() -> Option<PathBuf> {
+    #[cfg(test)]
+    {
+        TEST_AUDIT_DIR.with(|current| current.borrow().clone())
+    }
+    #[cfg(not(test))]
+    {
+        std::env::var_os(GIT_AUDIT_DIR_ENV)
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from)
+    }
+}
+
+/// Whether a git invocation only reads from the repository
+pub fn is_read_only(args: &[&str]) -> bool {
+    let mut args = args.iter().copied();
+    let subcommand = loop {
+        match args.next() {
+            // Global options taking a value, e.g. `-c core.askPass=`
+            Some("-c" | "-C") => {
+                args.next();
+            }
+            Some(arg) if arg.starts_with('-') => {}
+            Some(arg) => break arg,
+            None => return true,
+        }
+    };
+    let rest: Vec<&str> = args.collect();
+    let first = rest.first().copied();
+
+    match subcommand {
+        "branch" | "tag" => rest.iter().all(|arg| {
+            matches!(
+                *arg,
+                "-v" | "-vv" | "-a" | "-r" | "-l" | "--list" | "--show-current"
+            ) || arg.starts_with("--format")
+                || arg.starts_with("--contains")
+                || arg.starts_with("--merged")
+        }),
+        "worktree" => first == Some("list"),
+        "stash" => first == Some("list") || first == Some("show"),
+        "remote" => rest.is_empty() || matches!(first, Some("-v" | "get-url" | "show")),
+        "config" => rest
+            .iter()
+            .any(|arg| arg.starts_with("--get") || *arg == "--list" || *arg == "-l"),
+        "symbolic-ref" => rest.iter().filter(|arg| !arg.starts_with('-')).count() <= 1,
+        _ => READ_ONLY_COMMANDS.contains(&subcommand),
+    }
+}
+
+/// Record a finished git command if the audit log is enabled
+pub(crate) fn record(cwd: &Path, args: &[&str], exit_code: Option<i32>, duration: Duration) {
+    let Some(dir) = current_audit_dir() else {
+        return;
+    };
+    if is_read_only(args) {
+        return;
+    }
+
+    let session = dir
+        .parent()
+        .and_then(|state_dir| session_for_path(state_dir, cwd));
+    let entry = AuditEntry {
+        timestamp: Utc::now(),
+        session: session.clone(),
+        cwd: cwd.to_path_buf(),
+        argv: std::iter::once("git")
+            .chain(args.iter().copied())
+            .map(str::to_string)
+            .collect(),
+        exit_code,
+        duration_ms: duration.as_millis() as u64,
+    };
+    let log = audit_log_file(&dir, session.as_deref().unwrap_or(REPO_LOG_NAME));
+    if let Err(e) = append_entry(&log, &entry) {
+        debug_log(&format!("Failed to write git audit log: {e}"));
+    }
+}
+
+fn append_entry(log: &Path, entry: &AuditEntry) -> Result<()> {
+    if let Some(parent) = log.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| ParaError::fs_error(format!("Failed to create audit directory: {e}")))?;
+    }
+    let line = serde_json::to_string(entry)
+        .map_err(|e| ParaError::fs_error(format!("Failed to serialize audit entry: {e}")))?;
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(log)
+        .map_err(|e| ParaError::fs_error(format!("Failed to open {}: {e}", log.display())))?;
+    writeln!(file, "{line}")
+        .map_err(|e| ParaError::fs_error(format!("Failed to write {}: {e}", log.display())))
+}
+
+/// Name of the session whose worktree contains `path`
+fn session_for_path(state_dir: &Path, path: &Path) -> Option<String> {
+    let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    fs::read_dir(state_dir)
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|file| file.extension().is_some_and(|ext| ext == "state"))
+        .filter_map(|file| fs::read_to_string(file).ok())
+        .filter_map(|content| serde_json::from_str::<SessionState>(&content).ok())
+        .find(|session| {
+            let worktree = session
+                .worktree_path
+                .canonicalize()
+                .unwrap_or_else(|_| session.worktree_path.clone());
+            path.starts_with(worktree)
+        })
+        .map(|session| session.name)
+}
+
+/// Entries of one log, oldest first, optionally only those after `since`
+pub fn read_entries(
+    audit_dir: &Path,
+    name: &str,
+    since: Option<DateTime<Utc>>,
+) -> Result<Vec<AuditEntry>> {
+    let log = audit_log_file(audit_dir, name);
+    if !log.exists() {
+        return Ok(Vec::new());
+    }
+    let content = fs::read_to_string(&log)
+        .map_err(|e| ParaError::fs_error(format!("Failed to read {}: {e}", log.display())))?;
+
+    Ok(content
+        .lines()
+        .filter_map(|line| serde_json::from_str::<AuditEntry>(line).ok())
+        .filter(|entry| since.is_none_or(|since| entry.timestamp >= since))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_only_commands_are_not_audited() {
+        for args in [
+            &["status", "--porcelain"][..],
+            &["rev-parse", "HEAD"],
+            &["diff", "--cached"],
+            &["-c", "core.askPass=", "log", "-1"],
+            &["branch", "--show-current"],
+            &["worktree", "list", "--porcelain"],
+            &["config", "--get", "user.name"],
+        ] {
+            assert!(is_read_only(args), "{args:?} should be read-only");
+        }
+
+        for args in [
+            &["add", "-A"][..],
+            &["commit", "-m", "msg"],
+            &["reset", "--soft", "HEAD~1"],
+            &["branch", "-m", "old", "new"],
+            &["worktree", "add", "path", "branch"],
+            &["config", "user.name", "para"],
+        ] {
+            assert!(!is_read_only(args), "{args:?} should be audited");
+        }
+    }
+}
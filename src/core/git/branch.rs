@@ -1,12 +1,47 @@
 use super::repository::{execute_git_command, execute_git_command_with_status, GitRepository};
+use super::shared_branch::other_worktrees_on_branch;
 use super::validation::GitValidator;
 use crate::utils::error::{ParaError, Result};
+use crate::utils::names::edit_distance;
+use crate::utils::{ArchiveBranchParser, ARCHIVE_TIMESTAMP_FORMAT};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
 
 #[derive(Debug, Clone)]
 pub struct BranchInfo {
     pub name: String,
 }
 
+/// A session branch kept as `<prefix>/archived/<timestamp>/<session>`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArchiveRef {
+    /// Full name of the archived branch
+    pub branch: String,
+    pub timestamp: String,
+    pub session: String,
+}
+
+impl ArchiveRef {
+    pub fn new(prefix: &str, timestamp: &str, session: &str) -> Self {
+        Self {
+            branch: format!("{prefix}/archived/{timestamp}/{session}"),
+            timestamp: timestamp.to_string(),
+            session: session.to_string(),
+        }
+    }
+
+    /// The archive `branch` names, `None` for a branch outside `prefix/archived/`
+    pub fn parse(branch: &str, prefix: &str) -> Result<Option<Self>> {
+        Ok(
+            ArchiveBranchParser::parse_archive_branch(branch, prefix)?.map(|info| Self {
+                branch: info.full_branch_name,
+                timestamp: info.timestamp,
+                session: info.session_name,
+            }),
+        )
+    }
+}
+
 pub struct BranchManager<'a> {
     repo: &'a GitRepository,
 }
@@ -22,6 +57,13 @@ impl<'a> BranchManager<'a> {
         execute_git_command_with_status(self.repo, &["checkout", "-b", name, base])
     }
 
+    /// Create `name` pointing at `commit` without checking it out
+    pub fn create_branch_from_commit(&self, name: &str, commit: &str) -> Result<()> {
+        self.validate_branch_name(name)?;
+
+        execute_git_command(self.repo, &["branch", name, commit]).map(|_| ())
+    }
+
     pub fn delete_branch(&self, name: &str, force: bool) -> Result<()> {
         self.validate_branch_name(name)?;
 
@@ -77,59 +119,118 @@ impl<'a> BranchManager<'a> {
     }
 
     pub fn move_to_archive(&self, branch: &str, prefix: &str) -> Result<String> {
-        let timestamp = chrono::Utc::now().format("%Y%m%d-%H%M%S");
+        let timestamp = chrono::Utc::now().format(ARCHIVE_TIMESTAMP_FORMAT);
         let archived_name = format!("{prefix}/archived/{timestamp}/{branch}");
         self.archive_branch_with_name(branch, &archived_name)
     }
 
-    pub fn move_to_archive_with_session_name(
+    /// Check that `branch` can be archived for `session_name` and return the
+    /// archive it would become, without changing anything.
+    ///
+    /// The branch must exist, the archive name must be free, and no worktree
+    /// but `session_worktree` may have the branch checked out, since renaming
+    /// it would silently move that worktree along.
+    pub fn archive_dry_run(
         &self,
         branch: &str,
         session_name: &str,
         prefix: &str,
-    ) -> Result<String> {
-        let timestamp = chrono::Utc::now().format("%Y%m%d-%H%M%S");
-        let archived_name = format!("{prefix}/archived/{timestamp}/{session_name}");
-        self.archive_branch_with_name(branch, &archived_name)
-    }
-
-    pub fn restore_from_archive(&self, archived_branch: &str, prefix: &str) -> Result<String> {
-        self.validate_branch_name(archived_branch)?;
+        session_worktree: Option<&Path>,
+    ) -> Result<ArchiveRef> {
+        self.validate_branch_name(branch)?;
+        if !self.branch_exists(branch)? {
+            return Err(ParaError::git_operation(format!(
+                "Branch '{branch}' does not exist"
+            )));
+        }
 
-        if !self.branch_exists(archived_branch)? {
+        let timestamp = chrono::Utc::now()
+            .format(ARCHIVE_TIMESTAMP_FORMAT)
+            .to_string();
+        let archive = ArchiveRef::new(prefix, &timestamp, session_name);
+        self.validate_branch_name(&archive.branch)?;
+        if self.branch_exists(&archive.branch)? {
             return Err(ParaError::git_operation(format!(
-                "Archived branch '{archived_branch}' does not exist"
+                "Archived branch '{}' already exists",
+                archive.branch
             )));
         }
+        self.ensure_not_checked_out(branch, session_worktree)?;
+        Ok(archive)
+    }
 
-        let archive_prefix = format!("{prefix}/archived/");
-        if !archived_branch.starts_with(&archive_prefix) {
+    /// Move `branch` into the archive of `session_name`, see [`Self::archive_dry_run`]
+    pub fn archive(
+        &self,
+        branch: &str,
+        session_name: &str,
+        prefix: &str,
+        session_worktree: Option<&Path>,
+    ) -> Result<ArchiveRef> {
+        let archive = self.archive_dry_run(branch, session_name, prefix, session_worktree)?;
+        execute_git_command_with_status(self.repo, &["branch", "-m", branch, &archive.branch])?;
+        Ok(archive)
+    }
+
+    /// Check that `archive` can be restored and return the branch it would
+    /// become, without changing anything.
+    ///
+    /// The archived branch must exist and no worktree but `session_worktree`
+    /// may have it checked out. It is restored as `new_name`, which must be
+    /// free, or else as its session's name, made unique if that is taken.
+    pub fn restore_dry_run(
+        &self,
+        archive: &ArchiveRef,
+        new_name: Option<&str>,
+        session_worktree: Option<&Path>,
+    ) -> Result<String> {
+        self.validate_branch_name(&archive.branch)?;
+        if !self.branch_exists(&archive.branch)? {
             return Err(ParaError::git_operation(format!(
-                "Branch '{archived_branch}' is not an archived branch with prefix '{prefix}'"
+                "Archived branch '{}' does not exist",
+                archive.branch
             )));
         }
 
-        let original_name = archived_branch
-            .strip_prefix(&archive_prefix)
-            .and_then(|s| s.split('/').next_back())
-            .ok_or_else(|| {
-                ParaError::git_operation(
-                    "Cannot determine original branch name from archive".to_string(),
-                )
-            })?;
-
-        let restored_name = if self.branch_exists(original_name)? {
-            self.generate_unique_branch_name(original_name)?
-        } else {
-            original_name.to_string()
+        let restored = match new_name {
+            Some(name) => {
+                self.validate_branch_name(name)?;
+                if self.branch_exists(name)? {
+                    return Err(ParaError::git_operation(format!(
+                        "Branch '{name}' already exists"
+                    )));
+                }
+                name.to_string()
+            }
+            None => self.generate_unique_branch_name(&archive.session)?,
         };
+        self.ensure_not_checked_out(&archive.branch, session_worktree)?;
+        Ok(restored)
+    }
 
-        execute_git_command_with_status(
-            self.repo,
-            &["branch", "-m", archived_branch, &restored_name],
-        )?;
+    /// Restore `archive` to a regular branch, see [`Self::restore_dry_run`]
+    pub fn restore(
+        &self,
+        archive: &ArchiveRef,
+        new_name: Option<&str>,
+        session_worktree: Option<&Path>,
+    ) -> Result<String> {
+        let restored = self.restore_dry_run(archive, new_name, session_worktree)?;
+        execute_git_command_with_status(self.repo, &["branch", "-m", &archive.branch, &restored])?;
+        Ok(restored)
+    }
 
-        Ok(restored_name)
+    fn ensure_not_checked_out(&self, branch: &str, session_worktree: Option<&Path>) -> Result<()> {
+        // A worktree whose directory is gone only lingers until the next
+        // prune and doesn't keep the branch from being renamed
+        let others = other_worktrees_on_branch(self.repo, branch, session_worktree)?;
+        match others.iter().find(|path| path.exists()) {
+            Some(path) => Err(ParaError::git_operation(format!(
+                "Branch '{branch}' is checked out in the worktree at {}",
+                path.display()
+            ))),
+            None => Ok(()),
+        }
     }
 
     pub fn list_archived_branches(&self, prefix: &str) -> Result<Vec<String>> {
@@ -170,17 +271,51 @@ impl<'a> BranchManager<'a> {
         execute_git_command(self.repo, &["rev-parse", branch])
     }
 
+    /// Local and remote-tracking branches whose names resemble `name`, closest first
+    pub fn similar_branches(&self, name: &str, limit: usize) -> Result<Vec<String>> {
+        let output = execute_git_command(
+            self.repo,
+            &[
+                "for-each-ref",
+                "--format=%(refname:short)",
+                "refs/heads",
+                "refs/remotes",
+            ],
+        )?;
+
+        let needle = name.to_lowercase();
+        let mut candidates: Vec<(usize, String)> = output
+            .lines()
+            .filter(|branch| !branch.ends_with("/HEAD"))
+            .filter_map(|branch| {
+                let candidate = branch.to_lowercase();
+                let short = candidate.rsplit('/').next().unwrap_or(&candidate);
+                let distance =
+                    edit_distance(&needle, &candidate).min(edit_distance(&needle, short));
+                let related = candidate.contains(&needle) || needle.contains(short);
+                (related || distance <= 2).then(|| (distance, branch.to_string()))
+            })
+            .collect();
+
+        candidates.sort();
+        Ok(candidates
+            .into_iter()
+            .map(|(_, branch)| branch)
+            .take(limit)
+            .collect())
+    }
+
     fn parse_branch_line(&self, line: &str) -> Result<Option<BranchInfo>> {
         let line = line.trim();
         if line.is_empty() {
             return Ok(None);
         }
 
-        let line = if line.starts_with('*') {
-            line.strip_prefix("* ").unwrap_or(line)
-        } else {
-            line.strip_prefix("  ").unwrap_or(line)
-        };
+        // `*` marks the current branch, `+` one checked out in another worktree
+        let line = line
+            .strip_prefix("* ")
+            .or_else(|| line.strip_prefix("+ "))
+            .unwrap_or(line);
 
         let parts: Vec<&str> = line.split_whitespace().collect();
         if parts.len() < 2 {
@@ -196,6 +331,7 @@ impl<'a> BranchManager<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::git::GitOperations;
     use crate::test_utils::test_helpers::*;
 
     #[test]
@@ -255,32 +391,166 @@ mod tests {
             .checkout_branch(&initial_branch)
             .expect("Failed to checkout initial branch");
 
-        let archived_name = manager
-            .move_to_archive("feature-branch", "para")
+        let archive = manager
+            .archive("feature-branch", "feature", "para", None)
             .expect("Failed to archive branch");
 
-        assert!(archived_name.starts_with("para/archived/"));
-        assert!(archived_name.ends_with("feature-branch"));
+        assert!(archive.branch.starts_with("para/archived/"));
+        assert!(archive.branch.ends_with("/feature"));
         assert!(!manager
             .branch_exists("feature-branch")
             .expect("Failed to check branch"));
         assert!(manager
-            .branch_exists(&archived_name)
+            .branch_exists(&archive.branch)
             .expect("Failed to check archived branch"));
+        // The typed result reads back the same through the archive format parser
+        assert_eq!(
+            ArchiveRef::parse(&archive.branch, "para").unwrap(),
+            Some(archive.clone())
+        );
+        assert_eq!(ArchiveRef::parse("feature-branch", "para").unwrap(), None);
 
         let restored_name = manager
-            .restore_from_archive(&archived_name, "para")
+            .restore(&archive, None, None)
             .expect("Failed to restore branch");
 
-        assert_eq!(restored_name, "feature-branch");
+        assert_eq!(restored_name, "feature");
         assert!(manager
-            .branch_exists("feature-branch")
+            .branch_exists("feature")
             .expect("Failed to check restored branch"));
         assert!(!manager
-            .branch_exists(&archived_name)
+            .branch_exists(&archive.branch)
             .expect("Failed to check archived branch"));
     }
 
+    #[test]
+    fn test_archive_dry_run_preconditions() {
+        let (temp_dir, git_service) = setup_test_repo();
+        let repo = git_service.repository();
+        let manager = BranchManager::new(repo);
+        let initial_branch = repo.get_current_branch().unwrap();
+        manager
+            .create_branch_from_commit("para/ok", "HEAD")
+            .unwrap();
+        manager
+            .create_branch_from_commit("para/shared", "HEAD")
+            .unwrap();
+        let worktree = temp_dir.path().join("shared-worktree");
+        execute_git_command(
+            repo,
+            &[
+                "worktree",
+                "add",
+                &worktree.to_string_lossy(),
+                "para/shared",
+            ],
+        )
+        .unwrap();
+
+        let plan = manager
+            .archive_dry_run("para/ok", "ok", "para", None)
+            .unwrap();
+        assert_eq!(
+            ArchiveRef::parse(&plan.branch, "para").unwrap(),
+            Some(plan.clone())
+        );
+        // Nothing moved
+        assert!(manager.branch_exists("para/ok").unwrap());
+        assert!(!manager.branch_exists(&plan.branch).unwrap());
+
+        // Missing branch
+        assert!(manager
+            .archive_dry_run("para/missing", "missing", "para", None)
+            .is_err());
+        // Archive name already taken, for this second and the next
+        let now = chrono::Utc::now();
+        for at in [now, now + chrono::Duration::seconds(1)] {
+            let timestamp = at.format("%Y%m%d-%H%M%S").to_string();
+            let taken = ArchiveRef::new("para", &timestamp, "ok");
+            manager
+                .create_branch_from_commit(&taken.branch, "HEAD")
+                .unwrap();
+        }
+        let err = manager
+            .archive_dry_run("para/ok", "ok", "para", None)
+            .unwrap_err();
+        assert!(err.to_string().contains("already exists"), "{err}");
+        // Checked out in a worktree, unless it is the session's own
+        let err = manager
+            .archive_dry_run("para/shared", "shared", "para", None)
+            .unwrap_err();
+        assert!(err.to_string().contains("checked out"), "{err}");
+        assert!(manager
+            .archive_dry_run("para/shared", "shared", "para", Some(&worktree))
+            .is_ok());
+        // The main checkout counts as a worktree too
+        assert!(manager
+            .archive_dry_run(&initial_branch, "main", "para", None)
+            .is_err());
+    }
+
+    #[test]
+    fn test_restore_dry_run_preconditions() {
+        let (temp_dir, git_service) = setup_test_repo();
+        let repo = git_service.repository();
+        let manager = BranchManager::new(repo);
+        let archive = ArchiveRef::new("para", "20240301-120000", "feature");
+        let missing = ArchiveRef::new("para", "20240301-120000", "missing");
+        manager
+            .create_branch_from_commit(&archive.branch, "HEAD")
+            .unwrap();
+
+        assert_eq!(
+            manager.restore_dry_run(&archive, None, None).unwrap(),
+            "feature"
+        );
+        assert_eq!(
+            manager
+                .restore_dry_run(&archive, Some("renamed"), None)
+                .unwrap(),
+            "renamed"
+        );
+        assert!(manager.branch_exists(&archive.branch).unwrap());
+        assert!(manager.restore_dry_run(&missing, None, None).is_err());
+
+        // A taken session name is made unique, a taken new name is refused
+        manager
+            .create_branch_from_commit("feature", "HEAD")
+            .unwrap();
+        assert_eq!(
+            manager.restore_dry_run(&archive, None, None).unwrap(),
+            "feature-1"
+        );
+        let err = manager
+            .restore_dry_run(&archive, Some("feature"), None)
+            .unwrap_err();
+        assert!(err.to_string().contains("already exists"), "{err}");
+        assert!(manager
+            .restore_dry_run(&archive, Some("bad..name"), None)
+            .is_err());
+
+        // An archive checked out in a worktree stays where it is
+        let worktree = temp_dir.path().join("archive-worktree");
+        execute_git_command(
+            repo,
+            &[
+                "worktree",
+                "add",
+                &worktree.to_string_lossy(),
+                &archive.branch,
+            ],
+        )
+        .unwrap();
+        assert!(manager.restore_dry_run(&archive, None, None).is_err());
+        assert_eq!(
+            manager
+                .restore(&archive, Some("renamed"), Some(&worktree))
+                .unwrap(),
+            "renamed"
+        );
+        assert!(!manager.branch_exists(&archive.branch).unwrap());
+    }
+
     #[test]
     fn test_list_archived_branches() {
         let (_temp_dir, git_service) = setup_test_repo();
@@ -374,4 +644,58 @@ mod tests {
             .expect("Failed to generate unique name");
         assert_eq!(unique_name, "existing-branch-1");
     }
+
+    #[test]
+    fn test_create_branch_from_commit_keeps_current_branch() {
+        let (_temp_dir, git_service) = setup_test_repo();
+        let repo = git_service.repository();
+        let manager = BranchManager::new(repo);
+
+        let commit = repo.resolve_commit("main").unwrap();
+        manager
+            .create_branch_from_commit("pinned", &commit)
+            .expect("Failed to create branch from commit");
+
+        assert_eq!(repo.get_current_branch().unwrap(), "main");
+        assert_eq!(manager.get_branch_commit("pinned").unwrap(), commit);
+    }
+
+    #[test]
+    fn test_list_branches_includes_branches_checked_out_elsewhere() {
+        let (temp_dir, git_service) = setup_test_repo();
+        let worktree = temp_dir.path().join("wt");
+        git_service
+            .create_worktree("para/elsewhere", &worktree)
+            .unwrap();
+
+        let names: Vec<String> = BranchManager::new(git_service.repository())
+            .list_branches()
+            .unwrap()
+            .into_iter()
+            .map(|branch| branch.name)
+            .collect();
+        assert!(names.contains(&"main".to_string()));
+        assert!(names.contains(&"para/elsewhere".to_string()));
+        assert!(!names.contains(&"+".to_string()));
+    }
+
+    #[test]
+    fn test_similar_branches() {
+        let (_temp_dir, git_service) = setup_test_repo();
+        let manager = BranchManager::new(git_service.repository());
+        let commit = git_service.repository().resolve_commit("HEAD").unwrap();
+        for name in ["develop", "feature/login", "release"] {
+            manager.create_branch_from_commit(name, &commit).unwrap();
+        }
+
+        assert_eq!(manager.similar_branches("mian", 3).unwrap(), vec!["main"]);
+        assert_eq!(
+            manager.similar_branches("login", 3).unwrap(),
+            vec!["feature/login"]
+        );
+        assert!(manager
+            .similar_branches("zzzzzzzzzz", 3)
+            .unwrap()
+            .is_empty());
+    }
 }
@@ -0,0 +1,383 @@
+//! Running git subprocesses without hanging forever
+//!
+//! A credential prompt, a pager or a stuck hook inherited by git would block
+//! para with no hint of what it is waiting on. Every git command therefore runs
+//! non-interactively by default and is killed, together with anything it
+//! spawned, once it exceeds `git.command_timeout_secs`. Commands that may sign
+//! or run hooks stay in the terminal's foreground process group, since GPG and
+//! SSH prompt on the terminal directly, and only git itself is killed.
+
+use super::audit;
+use crate::utils::{ParaError, Result};
+use std::os::unix::process::CommandExt;
+use std::path::Path;
+use std::process::{Child, Command, ExitStatus, Output, Stdio};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+pub const DEFAULT_GIT_TIMEOUT_SECS: u64 = 120;
+
+/// Carries `git.command_timeout_secs` to this process and child processes
+pub const GIT_TIMEOUT_ENV: &str = "PARA_GIT_TIMEOUT_SECS";
+
+/// Operations such as `worktree add` on large repositories get this many
+/// times the regular timeout
+const SLOW_TIMEOUT_FACTOR: u32 = 5;
+
+/// Subcommands that may sign commits or tags or run hooks such as
+/// `pre-commit` and `pre-push`, which can prompt on the terminal
+const TERMINAL_COMMANDS: &[&str] = &[
+    "am",
+    "cherry-pick",
+    "commit",
+    "merge",
+    "push",
+    "rebase",
+    "revert",
+    "tag",
+];
+
+/// How long to wait for git's output after killing it on timeout. Children
+/// outside git's process group may still hold its pipes open.
+const KILL_GRACE: Duration = Duration::from_secs(5);
+
+/// How a single git command is run
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GitCommandOptions {
+    pub timeout: Duration,
+    /// Allow credential prompts and the pager, for commands the user asked for
+    pub interactive: bool,
+}
+
+impl Default for GitCommandOptions {
+    fn default() -> Self {
+        Self {
+            timeout: git_command_timeout(),
+            interactive: false,
+        }
+    }
+}
+
+impl GitCommandOptions {
+    /// Longer timeout for operations known to be slow on large repositories
+    pub fn slow() -> Self {
+        Self {
+            timeout: git_command_timeout() * SLOW_TIMEOUT_FACTOR,
+            ..Self::default()
+        }
+    }
+
+    /// Let git prompt on the terminal, e.g. for credentials
+    pub fn interactive() -> Self {
+        Self {
+            interactive: true,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_timeout(self, timeout: Duration) -> Self {
+        Self { timeout, ..self }
+    }
+}
+
+/// Timeout for git commands in this process, from `git.command_timeout_secs`
+pub fn git_command_timeout() -> Duration {
+    let secs = std::env::var(GIT_TIMEOUT_ENV)
+        .ok()
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .filter(|secs| *secs > 0)
+        .unwrap_or(DEFAULT_GIT_TIMEOUT_SECS);
+    Duration::from_secs(secs)
+}
+
+/// Make `git.command_timeout_secs` visible to `git_command_timeout()` here and
+/// in child processes such as the daemon
+pub fn export_git_command_timeout(secs: Option<u64>) {
+    if let Some(secs) = secs.filter(|secs| *secs > 0) {
+        std::env::set_var(GIT_TIMEOUT_ENV, secs.to_string());
+    }
+}
+
+/// The git subcommand in `args`, after global options such as `-c key=value`
+fn subcommand<'a>(args: &[&'a str]) -> Option<&'a str> {
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match *arg {
+            "-c" | "-C" | "--git-dir" | "--work-tree" => {
+                args.next();
+            }
+            arg if arg.starts_with('-') => {}
+            arg => return Some(arg),
+        }
+    }
+    None
+}
+
+/// Whether git runs in a process group of its own, so that hooks and helpers
+/// die with it on timeout. Outside the terminal's foreground group anything
+/// reading the terminal is stopped, so commands that may prompt stay in it.
+fn own_process_group(args: &[&str], options: GitCommandOptions) -> bool {
+    !options.interactive && !subcommand(args).is_some_and(|sub| TERMINAL_COMMANDS.contains(&sub))
+}
+
+fn git_command(program: &str, dir: &Path, args: &[&str], options: GitCommandOptions) -> Command {
+    let mut command = Command::new(program);
+    command.current_dir(dir);
+    if !options.interactive {
+        command
+            // Paths in git's output come as they are instead of octal escapes
+            .args([
+                "--no-pager",
+                "-c",
+                "core.askPass=",
+                "-c",
+                "core.quotePath=false",
+            ])
+            .env("GIT_TERMINAL_PROMPT", "0")
+            .stdin(Stdio::null());
+    }
+    if own_process_group(args, options) {
+        command.process_group(0);
+    }
+    command.args(args);
+    command
+}
+
+/// Run git and capture its output
+pub(crate) fn output(dir: &Path, args: &[&str], options: GitCommandOptions) -> Result<Output> {
+    output_with_env(dir, args, &[], options)
+}
+
+/// [`output`] with extra environment variables, e.g. to give git a scratch
+/// index or object directory
+pub(crate) fn output_with_env(
+    dir: &Path,
+    args: &[&str],
+    env: &[(&str, &Path)],
+    options: GitCommandOptions,
+) -> Result<Output> {
+    let started = Instant::now();
+    let result = output_of("git", dir, args, env, options);
+    let exit_code = result.as_ref().ok().and_then(|output| output.status.code());
+    audit::record(dir, args, exit_code, started.elapsed());
+    result
+}
+
+/// Run git with output going to the terminal
+pub(crate) fn status(dir: &Path, args: &[&str], options: GitCommandOptions) -> Result<ExitStatus> {
+    let started = Instant::now();
+    let result = status_of("git", dir, args, options);
+    let exit_code = result.as_ref().ok().and_then(|status| status.code());
+    audit::record(dir, args, exit_code, started.elapsed());
+    result
+}
+
+fn output_of(
+    program: &str,
+    dir: &Path,
+    args: &[&str],
+    env: &[(&str, &Path)],
+    options: GitCommandOptions,
+) -> Result<Output> {
+    let child = git_command(program, dir, args, options)
+        .envs(env.iter().copied())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| ParaError::git_operation(format!("Failed to execute git: {e}")))?;
+    with_timeout(child, args, options, Child::wait_with_output)
+}
+
+fn status_of(
+    program: &str,
+    dir: &Path,
+    args: &[&str],
+    options: GitCommandOptions,
+) -> Result<ExitStatus> {
+    let child = git_command(program, dir, args, options)
+        .spawn()
+        .map_err(|e| ParaError::git_operation(format!("Failed to execute git: {e}")))?;
+    with_timeout(child, args, options, |mut child| child.wait())
+}
+
+/// Wait for `child` and kill it once the timeout expires. Without a process
+/// group of its own only git is killed; should a hook it started keep git's
+/// output open, para stops waiting for it after [`KILL_GRACE`].
+fn with_timeout<T: Send + 'static>(
+    child: Child,
+    args: &[&str],
+    options: GitCommandOptions,
+    wait: impl FnOnce(Child) -> std::io::Result<T> + Send + 'static,
+) -> Result<T> {
+    let pid = child.id() as libc::pid_t;
+    // A negative pid signals the whole process group
+    let target = if own_process_group(args, options) {
+        -pid
+    } else {
+        pid
+    };
+    let (done, finished) = mpsc::channel();
+    thread::spawn(move || {
+        let _ = done.send(wait(child));
+    });
+
+    match finished.recv_timeout(options.timeout) {
+        Ok(result) => {
+            result.map_err(|e| ParaError::git_operation(format!("Failed to execute git: {e}")))
+        }
+        Err(_) => {
+            unsafe { libc::kill(target, libc::SIGKILL) };
+            let _ = finished.recv_timeout(KILL_GRACE);
+            Err(ParaError::git_timeout(
+                args.join(" "),
+                options.timeout.as_secs(),
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    /// A "git" that hangs waiting for a child which holds on to its stdout
+    fn hanging_git(dir: &Path) -> String {
+        let script = dir.join("git");
+        fs::write(
+            &script,
+            "#!/bin/sh\nsleep 600 &\necho $! > \"$(dirname \"$0\")/sleep.pid\"\nwait\n",
+        )
+        .unwrap();
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&script, fs::Permissions::from_mode(0o755)).unwrap();
+        script.to_string_lossy().to_string()
+    }
+
+    fn is_running(pid: &str) -> bool {
+        fs::read_to_string(format!("/proc/{pid}/stat"))
+            .map(|stat| !stat.contains(") Z "))
+            .unwrap_or(false)
+    }
+
+    /// A killed process closes its pipes before it turns into a zombie
+    fn exits_soon(pid: &str) -> bool {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while is_running(pid) {
+            if Instant::now() > deadline {
+                return false;
+            }
+            thread::sleep(Duration::from_millis(20));
+        }
+        true
+    }
+
+    #[test]
+    fn test_hanging_command_is_killed_with_its_children() {
+        let temp = TempDir::new().unwrap();
+        let git = hanging_git(temp.path());
+        let options = GitCommandOptions::default().with_timeout(Duration::from_secs(1));
+
+        let started = Instant::now();
+        let err = output_of(&git, temp.path(), &["fetch", "origin"], &[], options).unwrap_err();
+
+        // The pipes only close once the sleeping grandchild is gone as well
+        assert!(started.elapsed() < Duration::from_secs(30));
+        match &err {
+            ParaError::GitTimeout { command, seconds } => {
+                assert_eq!(command, "fetch origin");
+                assert_eq!(*seconds, 1);
+            }
+            other => panic!("expected a git timeout, got {other:?}"),
+        }
+        assert!(err.to_string().contains("git fetch origin"));
+        assert_eq!(err.exit_code(), crate::utils::error::TIMEOUT_EXIT_CODE);
+
+        let pid = fs::read_to_string(temp.path().join("sleep.pid")).unwrap();
+        assert!(exits_soon(pid.trim()));
+    }
+
+    #[test]
+    fn test_status_commands_time_out_too() {
+        let temp = TempDir::new().unwrap();
+        let git = hanging_git(temp.path());
+        let options = GitCommandOptions::default().with_timeout(Duration::from_secs(1));
+
+        let err = status_of(&git, temp.path(), &["worktree", "add"], options).unwrap_err();
+        assert!(matches!(err, ParaError::GitTimeout { .. }));
+    }
+
+    #[test]
+    fn test_commands_that_may_prompt_stay_in_the_foreground() {
+        let options = GitCommandOptions::default();
+        assert!(own_process_group(&["fetch", "origin"], options));
+        assert!(own_process_group(&["worktree", "add", "x"], options));
+        assert!(!own_process_group(&["commit", "-m", "Add"], options));
+        assert!(!own_process_group(
+            &["-c", "user.name=x", "commit"],
+            options
+        ));
+        assert!(!own_process_group(&["push", "origin", "main"], options));
+        assert!(!own_process_group(
+            &["status"],
+            GitCommandOptions::interactive()
+        ));
+        assert_eq!(
+            subcommand(&["-C", "dir", "-c", "a=b", "rebase"]),
+            Some("rebase")
+        );
+    }
+
+    #[test]
+    fn test_foreground_command_is_killed_on_timeout() {
+        let temp = TempDir::new().unwrap();
+        let git = hanging_git(temp.path());
+        let options = GitCommandOptions::default().with_timeout(Duration::from_secs(1));
+
+        // The sleeping child keeps the pipes open; para stops waiting anyway
+        let started = Instant::now();
+        let err = output_of(&git, temp.path(), &["commit", "-m", "x"], &[], options).unwrap_err();
+        assert!(started.elapsed() < Duration::from_secs(30));
+        assert!(matches!(err, ParaError::GitTimeout { .. }));
+
+        let pid = fs::read_to_string(temp.path().join("sleep.pid")).unwrap();
+        let pid: libc::pid_t = pid.trim().parse().unwrap();
+        unsafe { libc::kill(pid, libc::SIGKILL) };
+    }
+
+    #[test]
+    fn test_non_interactive_git_never_prompts() {
+        let temp = TempDir::new().unwrap();
+        let command = git_command(
+            "git",
+            temp.path(),
+            &["status"],
+            GitCommandOptions::default(),
+        );
+        let args: Vec<_> = command.get_args().collect();
+        assert_eq!(
+            args,
+            [
+                "--no-pager",
+                "-c",
+                "core.askPass=",
+                "-c",
+                "core.quotePath=false",
+                "status"
+            ]
+        );
+        assert!(command
+            .get_envs()
+            .any(|(key, value)| key == "GIT_TERMINAL_PROMPT" && value == Some("0".as_ref())));
+
+        let interactive = git_command(
+            "git",
+            temp.path(),
+            &["status"],
+            GitCommandOptions::interactive(),
+        );
+        assert_eq!(interactive.get_args().collect::<Vec<_>>(), ["status"]);
+    }
+}
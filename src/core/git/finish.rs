@@ -1,12 +1,54 @@
+use crate::core::git::repository::execute_git_command;
 use crate::core::git::{branch::BranchManager, GitRepository};
-use crate::utils::Result;
+use crate::core::interrupt::CancelToken;
+use crate::utils::{ParaError, Result};
 use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// How a session's commits are shaped when it is finished
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "snake_case")]
+pub enum MergeMode {
+    /// Collapse everything since the session base into one commit
+    #[default]
+    Squash,
+    /// Keep the session's commits as they are
+    #[serde(alias = "preserve")]
+    #[value(name = "preserve", alias = "preserve-commits")]
+    PreserveCommits,
+    /// Keep the commits but replay them on top of the parent branch
+    Rebase,
+}
+
+impl fmt::Display for MergeMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            MergeMode::Squash => "squash",
+            MergeMode::PreserveCommits => "preserve",
+            MergeMode::Rebase => "rebase",
+        };
+        f.write_str(name)
+    }
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FinishRequest {
     pub feature_branch: String,
     pub commit_message: String,
     pub target_branch_name: Option<String>,
+    pub merge_mode: MergeMode,
+    /// Branch the session was started from; rebase target and squash fallback
+    pub parent_branch: Option<String>,
+    /// Commit the session branch was created from
+    pub base_commit: Option<String>,
+    /// Stage every change, untracked files included, before committing.
+    /// Otherwise only what is already staged ends up in the commit.
+    #[serde(default = "stage_all_by_default")]
+    pub stage_all: bool,
+}
+
+fn stage_all_by_default() -> bool {
+    true
 }
 
 #[derive(Debug)]
@@ -16,11 +58,21 @@ pub enum FinishResult {
 
 pub struct FinishManager<'a> {
     repo: &'a GitRepository,
+    cancel: CancelToken,
 }
 
 impl<'a> FinishManager<'a> {
     pub fn new(repo: &'a GitRepository) -> Self {
-        Self { repo }
+        Self {
+            repo,
+            cancel: CancelToken::global().clone(),
+        }
+    }
+
+    /// Stop between steps when `cancel` is cancelled instead of on Ctrl-C
+    pub fn with_cancel_token(mut self, cancel: CancelToken) -> Self {
+        self.cancel = cancel;
+        self
     }
 
     pub fn finish_session(&self, request: FinishRequest) -> Result<FinishResult> {
@@ -28,11 +80,37 @@ impl<'a> FinishManager<'a> {
         if current_branch != request.feature_branch {
             self.repo.checkout_branch(&request.feature_branch)?;
         }
+        self.cancel.checkpoint()?;
+
+        // Changes left out of the commit stay in the worktree, where they
+        // would stop the rebase
+        if !request.stage_all
+            && request.merge_mode == MergeMode::Rebase
+            && execute_git_command(self.repo, &["diff", "--quiet"]).is_err()
+        {
+            return Err(ParaError::invalid_args(
+                "Merge mode 'rebase' cannot run with unstaged changes left in the worktree. \
+                 Stage or stash them, or finish with --merge-mode squash",
+            ));
+        }
 
-        if self.repo.has_uncommitted_changes()? {
-            self.repo.stage_all_changes()?;
-            self.repo.commit(&request.commit_message)?;
+        match request.merge_mode {
+            MergeMode::Squash => self.squash(&request)?,
+            MergeMode::PreserveCommits => self.commit_pending(&request)?,
+            MergeMode::Rebase => {
+                self.commit_pending(&request)?;
+                self.cancel.checkpoint_keeping(&format!(
+                    "Interrupted after the pending changes were committed to '{}'; \
+                     the branch was not rebased",
+                    request.feature_branch
+                ))?;
+                self.rebase(&request)?;
+            }
         }
+        self.cancel.checkpoint_keeping(&format!(
+            "Interrupted after the session's changes were committed to '{}'",
+            request.feature_branch
+        ))?;
 
         let final_branch = if let Some(ref target_name) = request.target_branch_name {
             target_name.clone()
@@ -61,12 +139,113 @@ impl<'a> FinishManager<'a> {
 
         Ok(FinishResult::Success { final_branch })
     }
+
+    fn commit_pending(&self, request: &FinishRequest) -> Result<()> {
+        if request.stage_all && self.repo.has_uncommitted_changes()? {
+            self.repo.stage_all_changes()?;
+        }
+        if self.has_staged_changes()? {
+            self.repo.commit(&request.commit_message)?;
+        }
+        Ok(())
+    }
+
+    fn has_staged_changes(&self) -> Result<bool> {
+        let staged = execute_git_command(self.repo, &["diff", "--cached", "--name-only"])?;
+        Ok(!staged.trim().is_empty())
+    }
+
+    /// Replace the session's commits and pending changes with a single commit
+    /// on top of its fork point. Without a known base only pending changes are
+    /// committed.
+    fn squash(&self, request: &FinishRequest) -> Result<()> {
+        let Some(fork_point) = self.fork_point(request)? else {
+            return self.commit_pending(request);
+        };
+        let head = self.repo.resolve_commit("HEAD")?;
+        if fork_point == head {
+            return self.commit_pending(request);
+        }
+
+        // Interrupted between the reset and the commit, the session's commits
+        // are put back; its changes stay staged
+        let repo = self.repo.clone();
+        let tip = head.clone();
+        let step = self.cancel.on_abort(move || {
+            let _ = execute_git_command(&repo, &["reset", "--soft", &tip]);
+        });
+        if request.stage_all {
+            self.repo.stage_all_changes()?;
+        }
+        // A soft reset moves only the branch. Should the index change anyway,
+        // the commit would not be what was staged, so the commits are put back.
+        let index = execute_git_command(self.repo, &["ls-files", "--stage"])?;
+        execute_git_command(self.repo, &["reset", "--soft", &fork_point])?;
+        if execute_git_command(self.repo, &["ls-files", "--stage"])? != index {
+            let _ = execute_git_command(self.repo, &["reset", "--soft", &head]);
+            return Err(ParaError::git_operation(
+                "The index changed while squashing; the session's commits were left as they were",
+            ));
+        }
+        self.cancel.checkpoint()?;
+        if self.has_staged_changes()? {
+            self.repo.commit(&request.commit_message)?;
+        }
+        step.complete();
+        Ok(())
+    }
+
+    fn fork_point(&self, request: &FinishRequest) -> Result<Option<String>> {
+        let base = match (&request.base_commit, &request.parent_branch) {
+            (Some(commit), _) => commit,
+            (None, Some(parent)) => parent,
+            (None, None) => return Ok(None),
+        };
+        execute_git_command(self.repo, &["merge-base", base, "HEAD"]).map(Some)
+    }
+
+    fn rebase(&self, request: &FinishRequest) -> Result<()> {
+        let parent = request.parent_branch.as_deref().ok_or_else(|| {
+            ParaError::invalid_args(
+                "Merge mode 'rebase' needs the session's parent branch, which was not recorded",
+            )
+        })?;
+        // Interrupted during the rebase or right after it, the branch is put
+        // back where it was
+        let repo = self.repo.clone();
+        let head = self.repo.resolve_commit("HEAD")?;
+        let step = self.cancel.on_abort(move || {
+            let _ = execute_git_command(&repo, &["rebase", "--abort"]);
+            let _ = execute_git_command(&repo, &["reset", "--keep", &head]);
+        });
+        if execute_git_command(self.repo, &["rebase", parent]).is_err() {
+            // Git may have stopped because of the same Ctrl-C
+            self.cancel.checkpoint()?;
+            let conflicted =
+                execute_git_command(self.repo, &["diff", "--name-only", "--diff-filter=U"])
+                    .unwrap_or_default();
+            let _ = execute_git_command(self.repo, &["rebase", "--abort"]);
+            return Err(ParaError::integration_conflict_in(
+                format!(
+                    "Rebasing '{}' onto '{parent}' hit conflicts; the branch was left as it was. \
+                     Rebase it manually or finish with --merge-mode preserve",
+                    request.feature_branch
+                ),
+                conflicted.lines().map(str::to_string).collect(),
+            ));
+        }
+        self.cancel.checkpoint()?;
+        step.complete();
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::git::audit;
     use crate::core::git::branch::BranchManager;
+    use crate::core::session::SessionState;
     use crate::test_utils::test_helpers::*;
     use std::fs;
 
@@ -92,6 +271,10 @@ mod tests {
             feature_branch: "feature".to_string(),
             commit_message: "Add new feature".to_string(),
             target_branch_name: None,
+            merge_mode: crate::core::git::MergeMode::default(),
+            parent_branch: None,
+            base_commit: None,
+            stage_all: true,
         };
 
         let result = manager
@@ -136,6 +319,10 @@ mod tests {
             feature_branch: "feature-msg-test".to_string(),
             commit_message: custom_message.to_string(),
             target_branch_name: None,
+            merge_mode: crate::core::git::MergeMode::default(),
+            parent_branch: None,
+            base_commit: None,
+            stage_all: true,
         };
 
         let result = manager
@@ -183,6 +370,10 @@ mod tests {
             feature_branch: "temp-feature".to_string(),
             commit_message: "Implement feature".to_string(),
             target_branch_name: Some("final-feature".to_string()),
+            merge_mode: crate::core::git::MergeMode::default(),
+            parent_branch: None,
+            base_commit: None,
+            stage_all: true,
         };
 
         let result = manager
@@ -238,6 +429,10 @@ mod tests {
             feature_branch: "temp-feature".to_string(),
             commit_message: "Implement feature".to_string(),
             target_branch_name: Some("existing-target".to_string()),
+            merge_mode: crate::core::git::MergeMode::default(),
+            parent_branch: None,
+            base_commit: None,
+            stage_all: true,
         };
 
         let result = manager.finish_session(request);
@@ -286,6 +481,10 @@ mod tests {
             feature_branch: "staged-feature".to_string(),
             commit_message: "Auto-commit uncommitted changes".to_string(),
             target_branch_name: None,
+            merge_mode: crate::core::git::MergeMode::default(),
+            parent_branch: None,
+            base_commit: None,
+            stage_all: true,
         };
 
         let result = manager
@@ -308,4 +507,294 @@ mod tests {
             }
         }
     }
+
+    /// Session `para/work` with two commits on top of main, checked out
+    fn session_with_two_commits(
+        temp_repo_dir: &tempfile::TempDir,
+        git_service: &crate::core::git::GitService,
+        merge_mode: MergeMode,
+    ) -> SessionState {
+        let repo = git_service.repository();
+        let main_branch = repo.get_current_branch().unwrap();
+        BranchManager::new(repo)
+            .create_branch("para/work", &main_branch)
+            .unwrap();
+        repo.checkout_branch("para/work").unwrap();
+
+        let mut session = SessionState::new(
+            "work".to_string(),
+            "para/work".to_string(),
+            temp_repo_dir.path().to_path_buf(),
+        );
+        session.parent_branch = Some(main_branch.clone());
+        session.base_commit = Some(repo.resolve_commit(&main_branch).unwrap());
+        session.merge_mode = merge_mode;
+
+        for step in ["one", "two"] {
+            fs::write(temp_repo_dir.path().join(format!("{step}.txt")), step).unwrap();
+            repo.stage_all_changes().unwrap();
+            repo.commit(&format!("wip: {step}")).unwrap();
+        }
+        session
+    }
+
+    fn commits_since(repo: &GitRepository, base: &str) -> usize {
+        execute_git_command(repo, &["rev-list", "--count", &format!("{base}..HEAD")])
+            .unwrap()
+            .parse()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_recorded_merge_mode_decides_commit_shape() {
+        let expected = [(MergeMode::Squash, 1), (MergeMode::PreserveCommits, 3)];
+        for (merge_mode, commit_count) in expected {
+            let (temp_repo_dir, git_service) = setup_test_repo();
+            let session = session_with_two_commits(&temp_repo_dir, &git_service, merge_mode);
+            fs::write(temp_repo_dir.path().join("three.txt"), "three").unwrap();
+
+            FinishManager::new(git_service.repository())
+                .finish_session(session.finish_request("Add work".to_string(), None))
+                .unwrap();
+
+            let repo = git_service.repository();
+            let base = session.base_commit.as_deref().unwrap();
+            assert_eq!(commits_since(repo, base), commit_count, "{merge_mode}");
+            assert!(!repo.has_uncommitted_changes().unwrap());
+            for file in ["one.txt", "two.txt", "three.txt"] {
+                assert!(temp_repo_dir.path().join(file).exists());
+            }
+        }
+    }
+
+    #[test]
+    fn test_audit_log_records_finish_commands_in_order() {
+        let (temp_repo_dir, git_service) = setup_test_repo();
+        let session = session_with_two_commits(&temp_repo_dir, &git_service, MergeMode::Squash);
+        fs::write(temp_repo_dir.path().join("three.txt"), "three").unwrap();
+
+        let state_dir = tempfile::TempDir::new().unwrap();
+        fs::write(
+            state_dir.path().join("work.state"),
+            serde_json::to_string(&session).unwrap(),
+        )
+        .unwrap();
+        let audit_dir = audit::audit_dir(state_dir.path());
+        audit::set_test_audit_dir(Some(audit_dir.clone()));
+
+        let result = FinishManager::new(git_service.repository())
+            .finish_session(session.finish_request("Add work".to_string(), None));
+        audit::set_test_audit_dir(None);
+        result.unwrap();
+
+        let entries = audit::read_entries(&audit_dir, "work", None).unwrap();
+        let subcommands: Vec<&str> = entries.iter().map(|e| e.argv[1].as_str()).collect();
+        assert_eq!(subcommands, ["add", "reset", "commit"]);
+        assert!(entries.iter().all(|e| e.exit_code == Some(0)));
+        assert!(entries.iter().all(|e| e.session.as_deref() == Some("work")));
+        assert!(!audit::audit_log_file(&audit_dir, audit::REPO_LOG_NAME).exists());
+    }
+
+    #[test]
+    fn test_rebase_mode_replays_commits_onto_parent() {
+        let (temp_repo_dir, git_service) = setup_test_repo();
+        let session = session_behind_parent(&temp_repo_dir, &git_service);
+        let repo = git_service.repository();
+        let parent = session.parent_branch.clone().unwrap();
+
+        FinishManager::new(repo)
+            .finish_session(session.finish_request("Add work".to_string(), None))
+            .unwrap();
+
+        assert_eq!(commits_since(repo, &parent), 2);
+        assert!(temp_repo_dir.path().join("upstream.txt").exists());
+    }
+
+    #[test]
+    fn test_rebase_conflict_leaves_branch_untouched() {
+        let (temp_repo_dir, git_service) = setup_test_repo();
+        let session = session_with_two_commits(&temp_repo_dir, &git_service, MergeMode::Rebase);
+        let repo = git_service.repository();
+        let parent = session.parent_branch.clone().unwrap();
+        let head_before = repo.resolve_commit("HEAD").unwrap();
+
+        repo.checkout_branch(&parent).unwrap();
+        fs::write(temp_repo_dir.path().join("one.txt"), "conflicting").unwrap();
+        repo.stage_all_changes().unwrap();
+        repo.commit("Conflicting change").unwrap();
+        repo.checkout_branch("para/work").unwrap();
+
+        let err = FinishManager::new(repo)
+            .finish_session(session.finish_request("Add work".to_string(), None))
+            .unwrap_err();
+
+        assert!(err.to_string().contains("--merge-mode preserve"));
+        assert_eq!(err.paths(), ["one.txt"]);
+        assert_eq!(repo.resolve_commit("HEAD").unwrap(), head_before);
+        assert!(!repo.has_uncommitted_changes().unwrap());
+    }
+
+    #[test]
+    fn test_squash_of_staged_changes_keeps_the_rest_in_the_worktree() {
+        let (temp_repo_dir, git_service) = setup_test_repo();
+        let session = session_with_two_commits(&temp_repo_dir, &git_service, MergeMode::Squash);
+        let repo = git_service.repository();
+        let path = |name: &str| temp_repo_dir.path().join(name);
+
+        fs::write(path("staged.txt"), "staged").unwrap();
+        fs::write(path("one.txt"), "one, staged").unwrap();
+        execute_git_command(repo, &["add", "staged.txt", "one.txt"]).unwrap();
+        fs::write(path("one.txt"), "one, staged, then edited").unwrap();
+        fs::write(path("README.md"), "scratch edit").unwrap();
+        fs::write(path("scratch.txt"), "untracked").unwrap();
+
+        let mut request = session.finish_request("Add work".to_string(), None);
+        request.stage_all = false;
+        FinishManager::new(repo).finish_session(request).unwrap();
+
+        let base = session.base_commit.as_deref().unwrap();
+        assert_eq!(commits_since(repo, base), 1);
+        let files =
+            execute_git_command(repo, &["show", "--name-only", "--format=", "HEAD"]).unwrap();
+        assert_eq!(files, "one.txt\nstaged.txt\ntwo.txt");
+        assert_eq!(
+            execute_git_command(repo, &["show", "HEAD:one.txt"]).unwrap(),
+            "one, staged"
+        );
+
+        assert_eq!(
+            fs::read_to_string(path("one.txt")).unwrap(),
+            "one, staged, then edited"
+        );
+        assert_eq!(
+            fs::read_to_string(path("README.md")).unwrap(),
+            "scratch edit"
+        );
+        assert!(path("scratch.txt").exists());
+        let split = repo.staged_split().unwrap();
+        assert!(split.staged.is_empty());
+        assert_eq!(split.left_behind, ["README.md", "one.txt", "scratch.txt"]);
+    }
+
+    #[test]
+    fn test_rebase_refuses_to_run_over_unstaged_changes() {
+        let (temp_repo_dir, git_service) = setup_test_repo();
+        let session = session_with_two_commits(&temp_repo_dir, &git_service, MergeMode::Rebase);
+        let repo = git_service.repository();
+        let head_before = repo.resolve_commit("HEAD").unwrap();
+        fs::write(temp_repo_dir.path().join("one.txt"), "scratch").unwrap();
+
+        let mut request = session.finish_request("Add work".to_string(), None);
+        request.stage_all = false;
+        let err = FinishManager::new(repo)
+            .finish_session(request)
+            .unwrap_err();
+
+        assert!(err.to_string().contains("--merge-mode squash"), "{err}");
+        assert_eq!(repo.resolve_commit("HEAD").unwrap(), head_before);
+        assert_eq!(
+            fs::read_to_string(temp_repo_dir.path().join("one.txt")).unwrap(),
+            "scratch"
+        );
+    }
+
+    #[test]
+    fn test_interrupted_squash_puts_the_commits_back() {
+        let (temp_repo_dir, git_service) = setup_test_repo();
+        let session = session_with_two_commits(&temp_repo_dir, &git_service, MergeMode::Squash);
+        let repo = git_service.repository();
+        let head_before = repo.resolve_commit("HEAD").unwrap();
+
+        // Interrupted right after the reset, before the squashed commit
+        let cancel = CancelToken::new();
+        cancel.cancel_at_checkpoint(2);
+        let err = FinishManager::new(repo)
+            .with_cancel_token(cancel.clone())
+            .finish_session(session.finish_request("Add work".to_string(), None))
+            .unwrap_err();
+
+        assert!(matches!(err, ParaError::Interrupted { .. }));
+        assert!(!cancel.in_progress());
+        assert_eq!(repo.resolve_commit("HEAD").unwrap(), head_before);
+        assert_eq!(
+            commits_since(repo, session.base_commit.as_deref().unwrap()),
+            2
+        );
+        assert!(!repo.has_uncommitted_changes().unwrap());
+    }
+
+    /// A rebase-mode session whose parent branch moved on since
+    fn session_behind_parent(
+        temp_repo_dir: &tempfile::TempDir,
+        git_service: &crate::core::git::GitService,
+    ) -> SessionState {
+        let session = session_with_two_commits(temp_repo_dir, git_service, MergeMode::Rebase);
+        let repo = git_service.repository();
+        let parent = session.parent_branch.clone().unwrap();
+        repo.checkout_branch(&parent).unwrap();
+        fs::write(temp_repo_dir.path().join("upstream.txt"), "upstream").unwrap();
+        repo.stage_all_changes().unwrap();
+        repo.commit("Upstream change").unwrap();
+        repo.checkout_branch("para/work").unwrap();
+        session
+    }
+
+    #[test]
+    fn test_interrupted_rebase_puts_the_branch_back() {
+        let (temp_repo_dir, git_service) = setup_test_repo();
+        let session = session_behind_parent(&temp_repo_dir, &git_service);
+        let repo = git_service.repository();
+        fs::write(temp_repo_dir.path().join("pending.txt"), "pending").unwrap();
+
+        // Interrupted while rebasing, after the pending changes were committed
+        let cancel = CancelToken::new();
+        cancel.cancel_at_checkpoint(3);
+        let err = FinishManager::new(repo)
+            .with_cancel_token(cancel.clone())
+            .finish_session(session.finish_request("Add work".to_string(), None))
+            .unwrap_err();
+
+        assert!(matches!(err, ParaError::Interrupted { .. }));
+        assert!(err.to_string().contains("undone"), "{err}");
+        assert!(!cancel.in_progress());
+        assert!(!temp_repo_dir.path().join("upstream.txt").exists());
+        assert!(temp_repo_dir.path().join("pending.txt").exists());
+        assert_eq!(
+            commits_since(repo, session.base_commit.as_deref().unwrap()),
+            3
+        );
+        assert!(!repo.has_uncommitted_changes().unwrap());
+        let git_dir = execute_git_command(repo, &["rev-parse", "--git-dir"]).unwrap();
+        assert!(!temp_repo_dir
+            .path()
+            .join(git_dir)
+            .join("rebase-merge")
+            .exists());
+    }
+
+    #[test]
+    fn test_interrupt_before_rebase_keeps_the_commit() {
+        let (temp_repo_dir, git_service) = setup_test_repo();
+        let session = session_behind_parent(&temp_repo_dir, &git_service);
+        let repo = git_service.repository();
+        fs::write(temp_repo_dir.path().join("pending.txt"), "pending").unwrap();
+
+        let cancel = CancelToken::new();
+        cancel.cancel_at_checkpoint(2);
+        let err = FinishManager::new(repo)
+            .with_cancel_token(cancel)
+            .finish_session(session.finish_request("Add work".to_string(), None))
+            .unwrap_err();
+
+        assert!(
+            err.to_string()
+                .contains("committed to 'para/work'; the branch was not rebased"),
+            "{err}"
+        );
+        assert!(!temp_repo_dir.path().join("upstream.txt").exists());
+        assert_eq!(
+            commits_since(repo, session.base_commit.as_deref().unwrap()),
+            3
+        );
+    }
 }
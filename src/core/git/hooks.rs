@@ -0,0 +1,198 @@
+//! `core.hooksPath` for session worktrees
+//!
+//! Repositories that check in their hooks (`.githooks`, husky) often set
+//! `core.hooksPath` to an absolute path inside the main checkout. In a session
+//! worktree that path still points at the main checkout, so hooks run from
+//! the wrong tree. After a worktree is created, such a path is overridden with
+//! worktree-level config pointing at the same directory inside the worktree.
+
+use super::identity::ensure_worktree_config_enabled;
+use super::repository::{execute_git_command, GitRepository};
+use crate::utils::Result;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HooksPathFixup {
+    /// No absolute `core.hooksPath` that depends on the checkout
+    Unchanged,
+    /// Overridden for the worktree with this directory
+    Rewritten(PathBuf),
+    /// Left pointing at `hooks_path`, for the reason given
+    LeftAlone { hooks_path: PathBuf, reason: String },
+}
+
+impl HooksPathFixup {
+    /// Note for the user, if there is anything to say
+    pub fn message(&self) -> Option<String> {
+        match self {
+            HooksPathFixup::Unchanged => None,
+            HooksPathFixup::Rewritten(path) => Some(format!(
+                "Pointed core.hooksPath at {} for this worktree",
+                path.display()
+            )),
+            HooksPathFixup::LeftAlone { hooks_path, reason } => Some(format!(
+                "Note: core.hooksPath still points at {} ({reason})",
+                hooks_path.display()
+            )),
+        }
+    }
+}
+
+fn canonical(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
+
+/// Point an absolute `core.hooksPath` inside `main_root` at the equivalent
+/// directory in the worktree at `worktree_path`
+pub fn fixup_hooks_path(main_root: &Path, worktree_path: &Path) -> Result<HooksPathFixup> {
+    let worktree = GitRepository::discover_from(worktree_path)?;
+    let Ok(configured) = execute_git_command(&worktree, &["config", "core.hooksPath"]) else {
+        return Ok(HooksPathFixup::Unchanged);
+    };
+    let hooks_path = PathBuf::from(configured.trim());
+    // Relative paths are resolved against each worktree already
+    if !hooks_path.is_absolute() {
+        return Ok(HooksPathFixup::Unchanged);
+    }
+
+    let hooks_path = canonical(&hooks_path);
+    let worktree_root = canonical(worktree_path);
+    if hooks_path.starts_with(&worktree_root) {
+        return Ok(HooksPathFixup::Unchanged);
+    }
+    // Hooks kept in the git directory are shared by all worktrees
+    if let Ok(common_dir) = execute_git_command(&worktree, &["rev-parse", "--git-common-dir"]) {
+        if hooks_path.starts_with(canonical(&worktree.root.join(common_dir.trim()))) {
+            return Ok(HooksPathFixup::Unchanged);
+        }
+    }
+
+    let Ok(relative) = hooks_path.strip_prefix(canonical(main_root)) else {
+        return Ok(HooksPathFixup::LeftAlone {
+            hooks_path,
+            reason: "it is outside the repository".to_string(),
+        });
+    };
+    let target = worktree_root.join(relative);
+    if !target.is_dir() {
+        return Ok(HooksPathFixup::LeftAlone {
+            reason: format!("{} does not exist in the worktree", relative.display()),
+            hooks_path,
+        });
+    }
+
+    if let Err(e) = ensure_worktree_config_enabled(&worktree) {
+        return Ok(HooksPathFixup::LeftAlone {
+            hooks_path,
+            reason: e.to_string(),
+        });
+    }
+    let target_str = target.to_string_lossy();
+    execute_git_command(
+        &worktree,
+        &["config", "--worktree", "core.hooksPath", &target_str],
+    )?;
+    Ok(HooksPathFixup::Rewritten(target))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::git::GitOperations;
+    use crate::test_utils::test_helpers::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[cfg(unix)]
+    #[test]
+    fn test_absolute_hooks_path_follows_worktree() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let (git_temp, git_service) = setup_test_repo();
+        let main_root = git_temp.path();
+        let marker_dir = TempDir::new().unwrap();
+        let marker = marker_dir.path().join("ran-from");
+
+        let hooks_dir = main_root.join(".githooks");
+        fs::create_dir_all(&hooks_dir).unwrap();
+        let hook = hooks_dir.join("pre-commit");
+        fs::write(&hook, format!("#!/bin/sh\npwd > '{}'\n", marker.display())).unwrap();
+        fs::set_permissions(&hook, fs::Permissions::from_mode(0o755)).unwrap();
+        git(main_root, &["add", ".githooks"]);
+        git(main_root, &["commit", "-m", "Add hooks", "--no-verify"]);
+        let absolute_hooks = main_root.canonicalize().unwrap().join(".githooks");
+        git(
+            main_root,
+            &[
+                "config",
+                "core.hooksPath",
+                &absolute_hooks.to_string_lossy(),
+            ],
+        );
+
+        let worktrees = TempDir::new().unwrap();
+        let worktree_path = worktrees.path().join("hooks");
+        git_service
+            .create_worktree("para/hooks", &worktree_path)
+            .unwrap();
+
+        let expected = worktree_path.canonicalize().unwrap().join(".githooks");
+        assert_eq!(
+            fixup_hooks_path(main_root, &worktree_path).unwrap(),
+            HooksPathFixup::Rewritten(expected.clone())
+        );
+        assert_eq!(
+            git(&worktree_path, &["config", "--worktree", "core.hooksPath"]),
+            expected.to_string_lossy()
+        );
+        // The main checkout keeps its own setting
+        assert_eq!(
+            git(main_root, &["config", "core.hooksPath"]),
+            absolute_hooks.to_string_lossy()
+        );
+
+        commit_file(&worktree_path, "work.txt", "work", "Work");
+        let ran_from = fs::read_to_string(&marker).unwrap();
+        assert_eq!(
+            canonical(Path::new(ran_from.trim())),
+            worktree_path.canonicalize().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_hooks_path_left_alone_when_missing_or_relative() {
+        let (git_temp, git_service) = setup_test_repo();
+        let main_root = git_temp.path();
+        let worktrees = TempDir::new().unwrap();
+        let worktree_path = worktrees.path().join("plain");
+        git_service
+            .create_worktree("para/plain", &worktree_path)
+            .unwrap();
+
+        assert_eq!(
+            fixup_hooks_path(main_root, &worktree_path).unwrap(),
+            HooksPathFixup::Unchanged
+        );
+
+        git(main_root, &["config", "core.hooksPath", ".githooks"]);
+        assert_eq!(
+            fixup_hooks_path(main_root, &worktree_path).unwrap(),
+            HooksPathFixup::Unchanged
+        );
+
+        // Not checked in, so the worktree has no copy of it
+        let untracked_hooks = main_root.canonicalize().unwrap().join("local-hooks");
+        fs::create_dir_all(&untracked_hooks).unwrap();
+        git(
+            main_root,
+            &[
+                "config",
+                "core.hooksPath",
+                &untracked_hooks.to_string_lossy(),
+            ],
+        );
+        let fixup = fixup_hooks_path(main_root, &worktree_path).unwrap();
+        assert!(matches!(fixup, HooksPathFixup::LeftAlone { .. }));
+        assert!(fixup.message().unwrap().contains("local-hooks"));
+    }
+}
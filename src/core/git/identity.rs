@@ -0,0 +1,179 @@
+//! Per-worktree commit identity for session worktrees
+//!
+//! When `git.author_name` / `git.author_email` are configured, every new
+//! session worktree gets them as `user.name` / `user.email` through
+//! `git config --worktree`. Commits made in the worktree, by finish or by the
+//! agent itself, carry that identity while the main repository keeps its own.
+//! Worktree-level config needs `extensions.worktreeConfig`, which is enabled
+//! once per repository on first use.
+
+use super::command::{self, GitCommandOptions};
+use super::repository::{execute_git_command, GitRepository};
+use crate::utils::error::{ParaError, Result};
+use std::path::Path;
+
+/// First git release with `git config --worktree`
+pub const MIN_WORKTREE_CONFIG_VERSION: (u32, u32) = (2, 20);
+
+/// Parse `git version 2.39.2` (or `git version 2.39.2.windows.1`) into (major, minor)
+pub fn parse_git_version(output: &str) -> Option<(u32, u32)> {
+    let version = output.trim().strip_prefix("git version ")?;
+    let mut parts = version.split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    Some((major, minor))
+}
+
+/// (major, minor) of the installed git
+pub fn installed_git_version() -> Result<(u32, u32)> {
+    let output = command::output(Path::new("."), &["--version"], GitCommandOptions::default())?;
+    let version = String::from_utf8_lossy(&output.stdout);
+
+    parse_git_version(&version).ok_or_else(|| {
+        ParaError::git_operation(format!(
+            "Could not parse git version from '{}'",
+            version.trim()
+        ))
+    })
+}
+
+/// Whether the installed git supports worktree-level config
+pub fn supports_worktree_config() -> Result<bool> {
+    Ok(installed_git_version()? >= MIN_WORKTREE_CONFIG_VERSION)
+}
+
+/// Turn on `extensions.worktreeConfig` in the shared repository config.
+///
+/// Returns `true` when it had to be enabled by this call.
+pub fn ensure_worktree_config_enabled(repo: &GitRepository) -> Result<bool> {
+    let enabled = execute_git_command(repo, &["config", "--bool", "extensions.worktreeConfig"])
+        .map(|value| value == "true")
+        .unwrap_or(false);
+    if enabled {
+        return Ok(false);
+    }
+
+    if !supports_worktree_config()? {
+        let (major, minor) = MIN_WORKTREE_CONFIG_VERSION;
+        return Err(ParaError::git_operation(format!(
+            "Worktree-level config needs git {major}.{minor} or newer"
+        )));
+    }
+
+    execute_git_command(repo, &["config", "extensions.worktreeConfig", "true"])?;
+    Ok(true)
+}
+
+/// Apply the configured author identity to the worktree at `worktree_path`.
+///
+/// Does nothing when neither name nor email is set.
+pub fn apply_worktree_identity(
+    worktree_path: &Path,
+    author_name: Option<&str>,
+    author_email: Option<&str>,
+) -> Result<()> {
+    if author_name.is_none() && author_email.is_none() {
+        return Ok(());
+    }
+
+    let worktree = GitRepository::discover_from(worktree_path)?;
+    if ensure_worktree_config_enabled(&worktree)? {
+        println!(
+            "Note: Enabled extensions.worktreeConfig in this repository so sessions can use their own commit identity"
+        );
+    }
+
+    if let Some(name) = author_name {
+        execute_git_command(&worktree, &["config", "--worktree", "user.name", name])?;
+    }
+    if let Some(email) = author_email {
+        execute_git_command(&worktree, &["config", "--worktree", "user.email", email])?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::git::{FinishRequest, GitOperations};
+    use crate::test_utils::test_helpers::*;
+    use tempfile::TempDir;
+
+    fn last_author(path: &Path) -> String {
+        let repo = GitRepository::discover_from(path).unwrap();
+        execute_git_command(&repo, &["log", "-1", "--format=%an <%ae>"]).unwrap()
+    }
+
+    #[test]
+    fn test_parse_git_version() {
+        assert_eq!(parse_git_version("git version 2.39.2\n"), Some((2, 39)));
+        assert_eq!(
+            parse_git_version("git version 2.45.1.windows.1"),
+            Some((2, 45))
+        );
+        assert_eq!(parse_git_version("git version 2.20.0"), Some((2, 20)));
+        assert_eq!(parse_git_version("not git"), None);
+    }
+
+    #[test]
+    fn test_finish_commit_uses_worktree_identity() {
+        let (_git_temp, git_service) = setup_test_repo();
+        let worktrees = TempDir::new().unwrap();
+        let worktree_path = worktrees.path().join("agent");
+        let main_author = last_author(&git_service.repository().root);
+
+        git_service
+            .create_worktree("para/agent", &worktree_path)
+            .unwrap();
+        apply_worktree_identity(
+            &worktree_path,
+            Some("Para Agent (alice)"),
+            Some("agent@example.com"),
+        )
+        .unwrap();
+
+        std::fs::write(worktree_path.join("feature.txt"), "agent work").unwrap();
+        let worktree_service = crate::core::git::GitService::discover_from(&worktree_path).unwrap();
+        worktree_service
+            .finish_session(FinishRequest {
+                feature_branch: "para/agent".to_string(),
+                commit_message: "Agent change".to_string(),
+                target_branch_name: None,
+                merge_mode: crate::core::git::MergeMode::default(),
+                parent_branch: None,
+                base_commit: None,
+                stage_all: true,
+            })
+            .unwrap();
+
+        assert_eq!(
+            last_author(&worktree_path),
+            "Para Agent (alice) <agent@example.com>"
+        );
+
+        // The main repository keeps its own identity
+        let main_repo = git_service.repository();
+        std::fs::write(main_repo.root.join("main.txt"), "main work").unwrap();
+        main_repo.stage_all_changes().unwrap();
+        main_repo.commit("Main change").unwrap();
+        assert_eq!(last_author(&main_repo.root), main_author);
+    }
+
+    #[test]
+    fn test_no_identity_leaves_repository_config_alone() {
+        let (_git_temp, git_service) = setup_test_repo();
+        let worktrees = TempDir::new().unwrap();
+        let worktree_path = worktrees.path().join("plain");
+        git_service
+            .create_worktree("para/plain", &worktree_path)
+            .unwrap();
+
+        apply_worktree_identity(&worktree_path, None, None).unwrap();
+
+        let enabled = execute_git_command(
+            git_service.repository(),
+            &["config", "--bool", "extensions.worktreeConfig"],
+        );
+        assert!(enabled.is_err() || enabled.unwrap() != "true");
+    }
+}
@@ -0,0 +1,306 @@
+//! Preview whether integrating a session branch into its base would conflict
+//!
+//! The merge is simulated with `git merge-tree --write-tree` (git 2.38+). Older
+//! git falls back to checking the session's diff against the base with
+//! `git apply --cached --check` on a throwaway index. Either way refs, the
+//! index and the worktree are left alone, and any objects git has to write go
+//! to a scratch object directory that is removed afterwards.
+
+use super::command::{self, GitCommandOptions};
+use super::identity::installed_git_version;
+use super::repository::{execute_git_command, GitRepository};
+use crate::utils::error::{ParaError, Result};
+use std::path::PathBuf;
+use std::process::Output;
+
+/// First git release with `git merge-tree --write-tree`
+pub const MIN_MERGE_TREE_VERSION: (u32, u32) = (2, 38);
+
+/// Outcome of an integration preview
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IntegrationPreview {
+    Clean,
+    /// Paths that would conflict, sorted
+    Conflicts(Vec<String>),
+}
+
+impl IntegrationPreview {
+    pub fn is_clean(&self) -> bool {
+        matches!(self, IntegrationPreview::Clean)
+    }
+}
+
+/// Simulate merging `branch` into `base` without touching the repository
+pub fn preview_integration(
+    repo: &GitRepository,
+    branch: &str,
+    base: &str,
+) -> Result<IntegrationPreview> {
+    let use_merge_tree = installed_git_version()
+        .map(|v| v >= MIN_MERGE_TREE_VERSION)
+        .unwrap_or(false);
+    if use_merge_tree {
+        preview_with_merge_tree(repo, branch, base)
+    } else {
+        preview_with_apply_check(repo, branch, base)
+    }
+}
+
+fn preview_with_merge_tree(
+    repo: &GitRepository,
+    branch: &str,
+    base: &str,
+) -> Result<IntegrationPreview> {
+    let scratch = ScratchDir::new(repo)?;
+    let output = scratch.git(
+        repo,
+        &[
+            "merge-tree",
+            "--write-tree",
+            "--name-only",
+            "--no-messages",
+            base,
+            branch,
+        ],
+    )?;
+
+    match output.status.code() {
+        Some(0) => Ok(IntegrationPreview::Clean),
+        // The first line is the tree id, the rest are conflicted paths
+        Some(1) => Ok(conflicts(
+            String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .skip(1)
+                .map(str::to_string),
+        )),
+        _ => Err(ParaError::git_operation(format!(
+            "git merge-tree failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ))),
+    }
+}
+
+/// Fallback for git without `merge-tree --write-tree`: apply the branch's
+/// changes since the merge base onto a temporary index of `base`
+fn preview_with_apply_check(
+    repo: &GitRepository,
+    branch: &str,
+    base: &str,
+) -> Result<IntegrationPreview> {
+    let merge_base = execute_git_command(repo, &["merge-base", base, branch])?;
+    let diff = execute_git_command_raw(repo, &["diff", "--binary", &merge_base, branch])?;
+    if diff.is_empty() {
+        return Ok(IntegrationPreview::Clean);
+    }
+
+    let scratch = ScratchDir::new(repo)?;
+    let read_tree = scratch.git(repo, &["read-tree", base])?;
+    if !read_tree.status.success() {
+        return Err(ParaError::git_operation(format!(
+            "git read-tree {base} failed: {}",
+            String::from_utf8_lossy(&read_tree.stderr).trim()
+        )));
+    }
+
+    let patch = scratch.write_patch(&diff)?;
+    let patch = patch.to_string_lossy();
+    let output = scratch.git(repo, &["apply", "--cached", "--check", &patch])?;
+    if output.status.success() {
+        return Ok(IntegrationPreview::Clean);
+    }
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let paths: Vec<String> = stderr.lines().filter_map(apply_error_path).collect();
+    if paths.is_empty() {
+        return Err(ParaError::git_operation(format!(
+            "git apply --check failed: {}",
+            stderr.trim()
+        )));
+    }
+    Ok(conflicts(paths.into_iter()))
+}
+
+/// The path named in a `git apply` error line
+fn apply_error_path(line: &str) -> Option<String> {
+    let message = line.strip_prefix("error: ")?;
+    if let Some(rest) = message.strip_prefix("patch failed: ") {
+        return rest.rsplit_once(':').map(|(path, _)| path.to_string());
+    }
+    let (path, reason) = message.split_once(": ")?;
+    let known = [
+        "patch does not apply",
+        "does not exist in index",
+        "already exists in index",
+    ];
+    known
+        .iter()
+        .any(|r| reason.contains(r))
+        .then(|| path.to_string())
+}
+
+fn conflicts(paths: impl Iterator<Item = String>) -> IntegrationPreview {
+    let mut paths: Vec<String> = paths.filter(|p| !p.is_empty()).collect();
+    paths.sort();
+    paths.dedup();
+    IntegrationPreview::Conflicts(paths)
+}
+
+fn execute_git_command_raw(repo: &GitRepository, args: &[&str]) -> Result<Vec<u8>> {
+    let output = command::output(&repo.root, args, GitCommandOptions::default())?;
+    if !output.status.success() {
+        return Err(ParaError::git_operation(format!(
+            "Git command failed ({}): {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    Ok(output.stdout)
+}
+
+/// Temporary object directory and index that git writes to instead of the repository
+struct ScratchDir {
+    path: PathBuf,
+    objects: PathBuf,
+}
+
+impl ScratchDir {
+    fn new(repo: &GitRepository) -> Result<Self> {
+        let objects = execute_git_command(repo, &["rev-parse", "--git-path", "objects"])?;
+        let objects = repo.root.join(objects);
+
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.subsec_nanos())
+            .unwrap_or_default();
+        let path = std::env::temp_dir().join(format!(
+            "para-integration-preview-{}-{nanos}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(path.join("objects")).map_err(|e| {
+            ParaError::fs_error(format!("Failed to create {}: {e}", path.display()))
+        })?;
+        Ok(Self { path, objects })
+    }
+
+    fn git(&self, repo: &GitRepository, args: &[&str]) -> Result<Output> {
+        command::output_with_env(
+            &repo.root,
+            args,
+            &[
+                ("GIT_OBJECT_DIRECTORY", &self.path.join("objects")),
+                ("GIT_ALTERNATE_OBJECT_DIRECTORIES", &self.objects),
+                ("GIT_INDEX_FILE", &self.path.join("index")),
+            ],
+            GitCommandOptions::default(),
+        )
+    }
+
+    /// Write `patch` into the scratch directory for `git apply` to read
+    fn write_patch(&self, patch: &[u8]) -> Result<PathBuf> {
+        let path = self.path.join("patch");
+        std::fs::write(&path, patch).map_err(|e| ParaError::from_io("write", &path, e))?;
+        Ok(path)
+    }
+}
+
+impl Drop for ScratchDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::test_helpers::*;
+    use std::fs;
+    use std::path::Path;
+
+    /// Every file under the git dir and the worktree, to compare before and after
+    fn repository_fingerprint(repo: &GitRepository, worktree: &Path) -> String {
+        use std::collections::BTreeMap;
+
+        let git_dir = execute_git_command(repo, &["rev-parse", "--absolute-git-dir"]).unwrap();
+        let mut files = BTreeMap::new();
+        let mut stack = vec![PathBuf::from(git_dir), worktree.to_path_buf()];
+        while let Some(dir) = stack.pop() {
+            for entry in fs::read_dir(&dir).unwrap().flatten() {
+                let path = entry.path();
+                if path.is_dir() {
+                    stack.push(path);
+                } else if let Ok(content) = fs::read(&path) {
+                    files.insert(path, content);
+                }
+            }
+        }
+        format!("{files:?}")
+    }
+
+    /// main and para/feature both edit shared.txt; para/feature also adds new.txt
+    fn diverged_repo() -> (tempfile::TempDir, GitRepository) {
+        let (temp, git_service) = setup_test_repo();
+        let repo = git_service.repository().clone();
+        commit_file(&repo.root, "shared.txt", "line\n", "Add shared file");
+
+        execute_git_command(&repo, &["checkout", "-b", "para/feature"]).unwrap();
+        commit_file(&repo.root, "shared.txt", "feature\n", "Change on feature");
+        commit_file(&repo.root, "new.txt", "new\n", "Add new file");
+
+        execute_git_command(&repo, &["checkout", "main"]).unwrap();
+        (temp, repo)
+    }
+
+    #[test]
+    fn test_merge_tree_preview_reports_conflicts_without_side_effects() {
+        let (temp, repo) = diverged_repo();
+        assert_eq!(
+            preview_integration(&repo, "para/feature", "main").unwrap(),
+            IntegrationPreview::Clean
+        );
+
+        commit_file(&repo.root, "shared.txt", "main\n", "Change on main");
+        let before = repository_fingerprint(&repo, temp.path());
+        let preview = preview_with_merge_tree(&repo, "para/feature", "main").unwrap();
+        assert_eq!(before, repository_fingerprint(&repo, temp.path()));
+
+        assert_eq!(
+            preview,
+            IntegrationPreview::Conflicts(vec!["shared.txt".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_apply_check_fallback_matches_merge_tree() {
+        let (temp, repo) = diverged_repo();
+        assert!(preview_with_apply_check(&repo, "para/feature", "main")
+            .unwrap()
+            .is_clean());
+
+        commit_file(&repo.root, "shared.txt", "main\n", "Change on main");
+        let before = repository_fingerprint(&repo, temp.path());
+        let preview = preview_with_apply_check(&repo, "para/feature", "main").unwrap();
+        assert_eq!(before, repository_fingerprint(&repo, temp.path()));
+
+        assert_eq!(
+            preview,
+            IntegrationPreview::Conflicts(vec!["shared.txt".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_apply_error_path_parsing() {
+        assert_eq!(
+            apply_error_path("error: patch failed: src/a:b.rs:12"),
+            Some("src/a:b.rs".to_string())
+        );
+        assert_eq!(
+            apply_error_path("error: src/lib.rs: patch does not apply"),
+            Some("src/lib.rs".to_string())
+        );
+        assert_eq!(
+            apply_error_path("error: new.txt: already exists in index"),
+            Some("new.txt".to_string())
+        );
+        assert_eq!(apply_error_path("Checking patch src/lib.rs..."), None);
+    }
+}
@@ -1,23 +1,34 @@
 use crate::utils::error::Result;
 use std::path::{Path, PathBuf};
 
+pub mod apply;
 pub mod archive_branch_iterator;
+pub mod audit;
 pub mod branch;
+pub mod command;
 pub mod diff;
 pub mod finish;
+pub mod hooks;
+pub mod identity;
+pub mod integration_preview;
+pub mod protected;
+pub mod remote_finish;
 pub mod repository;
+pub mod shared_branch;
 pub mod validation;
 pub mod worktree;
+pub mod worktree_status;
 
 pub use archive_branch_iterator::{ArchiveBranchIterator, HasTimestamp};
-pub use branch::{BranchInfo, BranchManager};
+pub use branch::{ArchiveRef, BranchInfo, BranchManager};
 pub use diff::calculate_diff_stats;
-pub use finish::{FinishManager, FinishRequest, FinishResult};
-pub use repository::GitRepository;
+pub use finish::{FinishManager, FinishRequest, FinishResult, MergeMode};
+pub use repository::{GitRepository, WorktreeRef};
 pub use worktree::{WorktreeInfo, WorktreeManager};
+pub use worktree_status::{StagedSplit, WorktreeStatus};
 
 pub trait GitOperations {
-    fn create_worktree(&self, branch: &str, path: &Path) -> Result<()>;
+    fn create_worktree(&self, branch: &str, path: &Path) -> Result<PathBuf>;
     fn remove_worktree(&self, path: &Path) -> Result<()>;
     fn finish_session(&self, request: FinishRequest) -> Result<FinishResult>;
     fn list_worktrees(&self) -> Result<Vec<WorktreeInfo>>;
@@ -29,18 +40,34 @@ pub trait GitOperations {
     fn is_clean_working_tree(&self) -> Result<bool>;
     fn stage_all_changes(&self) -> Result<()>;
     fn archive_branch(&self, branch: &str, prefix: &str) -> Result<String>;
-    fn archive_branch_with_session_name(
+    /// See [`BranchManager::archive_dry_run`]
+    fn archive_session_branch_dry_run(
         &self,
         branch: &str,
         session_name: &str,
         prefix: &str,
+        session_worktree: Option<&Path>,
+    ) -> Result<ArchiveRef>;
+    /// See [`BranchManager::archive`]
+    fn archive_session_branch(
+        &self,
+        branch: &str,
+        session_name: &str,
+        prefix: &str,
+        session_worktree: Option<&Path>,
+    ) -> Result<ArchiveRef>;
+    /// See [`BranchManager::restore`]
+    fn restore_archived_branch(
+        &self,
+        archive: &ArchiveRef,
+        new_name: Option<&str>,
+        session_worktree: Option<&Path>,
     ) -> Result<String>;
-    fn restore_archived_branch(&self, archived_branch: &str, prefix: &str) -> Result<String>;
     fn cleanup_stale_worktrees(&self) -> Result<Vec<PathBuf>>;
 }
 
 impl GitOperations for GitRepository {
-    fn create_worktree(&self, branch: &str, path: &Path) -> Result<()> {
+    fn create_worktree(&self, branch: &str, path: &Path) -> Result<PathBuf> {
         let manager = WorktreeManager::new(self);
         manager.create_worktree(branch, path)
     }
@@ -97,19 +124,36 @@ impl GitOperations for GitRepository {
         manager.move_to_archive(branch, prefix)
     }
 
-    fn archive_branch_with_session_name(
+    fn archive_session_branch_dry_run(
         &self,
         branch: &str,
         session_name: &str,
         prefix: &str,
-    ) -> Result<String> {
+        session_worktree: Option<&Path>,
+    ) -> Result<ArchiveRef> {
+        let manager = BranchManager::new(self);
+        manager.archive_dry_run(branch, session_name, prefix, session_worktree)
+    }
+
+    fn archive_session_branch(
+        &self,
+        branch: &str,
+        session_name: &str,
+        prefix: &str,
+        session_worktree: Option<&Path>,
+    ) -> Result<ArchiveRef> {
         let manager = BranchManager::new(self);
-        manager.move_to_archive_with_session_name(branch, session_name, prefix)
+        manager.archive(branch, session_name, prefix, session_worktree)
     }
 
-    fn restore_archived_branch(&self, archived_branch: &str, prefix: &str) -> Result<String> {
+    fn restore_archived_branch(
+        &self,
+        archive: &ArchiveRef,
+        new_name: Option<&str>,
+        session_worktree: Option<&Path>,
+    ) -> Result<String> {
         let manager = BranchManager::new(self);
-        manager.restore_from_archive(archived_branch, prefix)
+        manager.restore(archive, new_name, session_worktree)
     }
 
     fn cleanup_stale_worktrees(&self) -> Result<Vec<PathBuf>> {
@@ -118,6 +162,42 @@ impl GitOperations for GitRepository {
     }
 }
 
+/// Roots [`GitService`] was discovered at on this thread, so tests can check
+/// how often a command looks up its repository
+#[cfg(test)]
+pub(crate) mod discoveries {
+    use std::cell::RefCell;
+    use std::path::{Path, PathBuf};
+
+    thread_local! {
+        static ROOTS: RefCell<Vec<PathBuf>> = const { RefCell::new(Vec::new()) };
+    }
+
+    pub(super) fn record(root: &Path) {
+        let root = root.canonicalize().unwrap_or_else(|_| root.to_path_buf());
+        ROOTS.with(|roots| roots.borrow_mut().push(root));
+    }
+
+    /// Roots discovered since the last call
+    pub fn take() -> Vec<PathBuf> {
+        ROOTS.with(|roots| std::mem::take(&mut *roots.borrow_mut()))
+    }
+
+    /// Like [`take`], failing if a root was discovered more than once
+    pub fn take_distinct() -> Vec<PathBuf> {
+        let roots = take();
+        for (i, root) in roots.iter().enumerate() {
+            assert!(
+                !roots[..i].contains(root),
+                "{} was discovered more than once: {roots:?}",
+                root.display()
+            );
+        }
+        roots
+    }
+}
+
+#[derive(Clone)]
 pub struct GitService {
     repo: GitRepository,
 }
@@ -126,12 +206,16 @@ impl GitService {
     pub fn discover() -> Result<Self> {
         let repo = GitRepository::discover()?;
         repo.validate()?;
+        #[cfg(test)]
+        discoveries::record(&repo.root);
         Ok(Self { repo })
     }
 
     pub fn discover_from(path: &Path) -> Result<Self> {
         let repo = GitRepository::discover_from(path)?;
         repo.validate()?;
+        #[cfg(test)]
+        discoveries::record(&repo.root);
         Ok(Self { repo })
     }
 
@@ -139,49 +223,32 @@ impl GitService {
         &self.repo
     }
 
-    pub fn worktree_manager(&self) -> WorktreeManager {
+    pub fn worktree_manager(&self) -> WorktreeManager<'_> {
         WorktreeManager::new(&self.repo)
     }
 
-    pub fn branch_manager(&self) -> BranchManager {
+    pub fn branch_manager(&self) -> BranchManager<'_> {
         BranchManager::new(&self.repo)
     }
 
     pub fn validate_session_environment(&self, session_path: &Path) -> Result<SessionEnvironment> {
-        let worktree_manager = self.worktree_manager();
-
-        let repo_root = match GitRepository::discover_from(session_path) {
-            Ok(repo) => repo.root,
-            Err(_) => return Ok(SessionEnvironment::Invalid),
-        };
-
-        let git_entry = repo_root.join(".git");
-        let is_linked_worktree_root = git_entry.is_file();
-
-        if is_linked_worktree_root {
-            let branch = worktree_manager.get_worktree_branch(&repo_root)?;
-            return Ok(SessionEnvironment::Worktree { branch });
-        }
-
-        let is_worktree = worktree_manager.is_worktree_path(session_path);
-        let is_main_repo = repo_root == self.repo.root;
-
-        if !is_worktree && !is_main_repo {
+        let Ok(session_repo) = GitRepository::discover_from(session_path) else {
             return Ok(SessionEnvironment::Invalid);
-        }
+        };
 
-        if is_main_repo {
+        // A linked worktree's `.git` is a file
+        let is_linked_worktree_root = session_repo.root.join(".git").is_file();
+        if !is_linked_worktree_root && session_repo.root == self.repo.root {
             return Ok(SessionEnvironment::MainRepository);
         }
 
-        let branch = worktree_manager.get_worktree_branch(session_path)?;
-
+        let branch = session_repo.get_current_branch()?;
         Ok(SessionEnvironment::Worktree { branch })
     }
 }
 
 impl GitOperations for GitService {
-    fn create_worktree(&self, branch: &str, path: &Path) -> Result<()> {
+    fn create_worktree(&self, branch: &str, path: &Path) -> Result<PathBuf> {
         self.repo.create_worktree(branch, path)
     }
 
@@ -229,18 +296,36 @@ impl GitOperations for GitService {
         self.repo.archive_branch(branch, prefix)
     }
 
-    fn archive_branch_with_session_name(
+    fn archive_session_branch_dry_run(
         &self,
         branch: &str,
         session_name: &str,
         prefix: &str,
-    ) -> Result<String> {
+        session_worktree: Option<&Path>,
+    ) -> Result<ArchiveRef> {
+        self.repo
+            .archive_session_branch_dry_run(branch, session_name, prefix, session_worktree)
+    }
+
+    fn archive_session_branch(
+        &self,
+        branch: &str,
+        session_name: &str,
+        prefix: &str,
+        session_worktree: Option<&Path>,
+    ) -> Result<ArchiveRef> {
         self.repo
-            .archive_branch_with_session_name(branch, session_name, prefix)
+            .archive_session_branch(branch, session_name, prefix, session_worktree)
     }
 
-    fn restore_archived_branch(&self, archived_branch: &str, prefix: &str) -> Result<String> {
-        self.repo.restore_archived_branch(archived_branch, prefix)
+    fn restore_archived_branch(
+        &self,
+        archive: &ArchiveRef,
+        new_name: Option<&str>,
+        session_worktree: Option<&Path>,
+    ) -> Result<String> {
+        self.repo
+            .restore_archived_branch(archive, new_name, session_worktree)
     }
 
     fn cleanup_stale_worktrees(&self) -> Result<Vec<PathBuf>> {
@@ -0,0 +1,158 @@
+//! Guard against finishing or cancelling a protected branch
+//!
+//! A session worktree normally has its own session branch checked out. If an
+//! agent or user checks out the default branch there instead, finish would
+//! commit onto it and cancel would archive the worktree's work under the
+//! session name. Commands that modify the session branch check the branch
+//! actually checked out in the worktree against the protected branches first.
+//! Finish also refuses a worktree whose HEAD is detached, since there is no
+//! branch to integrate.
+
+use super::repository::{execute_git_command, short_sha, GitRepository, WorktreeRef};
+use crate::utils::error::{ParaError, Result};
+use std::path::Path;
+
+/// The repository's default branch plus the configured `git.protected_branches`
+pub fn protected_branches(repo: &GitRepository, configured: &[String]) -> Vec<String> {
+    let default_branch = execute_git_command(repo, &["symbolic-ref", "refs/remotes/origin/HEAD"])
+        .ok()
+        .and_then(|r| r.strip_prefix("refs/remotes/origin/").map(str::to_string))
+        .or_else(|| repo.get_main_branch().ok());
+
+    let mut branches: Vec<String> = default_branch.into_iter().collect();
+    for branch in configured {
+        if !branches.contains(branch) {
+            branches.push(branch.clone());
+        }
+    }
+    branches
+}
+
+/// Refuse `operation` when the worktree at `worktree_path` has a protected branch
+/// checked out, unless `allow_protected` is set.
+pub fn ensure_not_protected(
+    worktree_path: &Path,
+    configured: &[String],
+    allow_protected: bool,
+    operation: &str,
+) -> Result<()> {
+    if allow_protected || !worktree_path.exists() {
+        return Ok(());
+    }
+
+    let Ok(worktree) = GitRepository::discover_from(worktree_path) else {
+        return Ok(());
+    };
+    let Ok(current) = worktree.get_current_branch() else {
+        return Ok(());
+    };
+
+    if protected_branches(&worktree, configured).contains(&current) {
+        return Err(ParaError::invalid_args(format!(
+            "Refusing to {operation}: the session worktree {} has the protected branch '{current}' \
+             checked out instead of its session branch. Check out the session branch again, or \
+             rerun with --allow-protected if this is really intended.",
+            worktree_path.display()
+        )));
+    }
+    Ok(())
+}
+
+/// Refuse `operation` when HEAD of the worktree at `worktree_path` is detached,
+/// with instructions to re-attach it to `branch`.
+pub fn ensure_not_detached(
+    worktree_path: &Path,
+    session: &str,
+    branch: &str,
+    operation: &str,
+) -> Result<()> {
+    if !worktree_path.exists() {
+        return Ok(());
+    }
+    let Ok(worktree) = GitRepository::discover_from(worktree_path) else {
+        return Ok(());
+    };
+    if let Ok(WorktreeRef::Detached(sha)) = worktree.head_ref() {
+        return Err(ParaError::invalid_args(format!(
+            "Refusing to {operation}: HEAD of the session worktree {} is detached at {}. \
+             Run 'para resume {session}' to re-attach it, or 'git switch {branch}' in the \
+             worktree ('git switch -c <new-branch>' keeps commits made while detached).",
+            worktree_path.display(),
+            short_sha(&sha)
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::git::GitOperations;
+    use crate::test_utils::test_helpers::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_protected_branches_include_default_and_configured() {
+        let (_git_temp, git_service) = setup_test_repo();
+        let branches = protected_branches(
+            git_service.repository(),
+            &["release".to_string(), "main".to_string()],
+        );
+        assert_eq!(branches, vec!["main".to_string(), "release".to_string()]);
+    }
+
+    #[test]
+    fn test_guard_refuses_protected_branch_in_worktree() {
+        let (_git_temp, git_service) = setup_test_repo();
+        let worktrees = TempDir::new().unwrap();
+        let worktree_path = worktrees.path().join("session");
+        git_service
+            .create_worktree("para/guarded", &worktree_path)
+            .unwrap();
+
+        assert!(ensure_not_protected(&worktree_path, &[], false, "finish").is_ok());
+
+        // main is checked out in the main repo too, as an agent could force it
+        let worktree = GitRepository::discover_from(&worktree_path).unwrap();
+        execute_git_command(&worktree, &["checkout", "--ignore-other-worktrees", "main"]).unwrap();
+        let err = ensure_not_protected(&worktree_path, &[], false, "finish")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("protected branch 'main'"));
+
+        execute_git_command(&worktree, &["checkout", "-b", "release"]).unwrap();
+        assert!(ensure_not_protected(&worktree_path, &[], false, "finish").is_ok());
+
+        let err = ensure_not_protected(&worktree_path, &["release".to_string()], false, "finish")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Refusing to finish"));
+        assert!(err.contains("'release'"));
+        assert!(err.contains("--allow-protected"));
+
+        assert!(
+            ensure_not_protected(&worktree_path, &["release".to_string()], true, "finish").is_ok()
+        );
+    }
+
+    #[test]
+    fn test_guard_refuses_detached_head() {
+        let (_git_temp, git_service) = setup_test_repo();
+        let worktrees = TempDir::new().unwrap();
+        let worktree_path = worktrees.path().join("session");
+        git_service
+            .create_worktree("para/detached", &worktree_path)
+            .unwrap();
+        assert!(ensure_not_detached(&worktree_path, "detached", "para/detached", "finish").is_ok());
+
+        let worktree = GitRepository::discover_from(&worktree_path).unwrap();
+        execute_git_command(&worktree, &["checkout", "--detach"]).unwrap();
+        let err = ensure_not_detached(&worktree_path, "detached", "para/detached", "finish")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Refusing to finish"));
+        assert!(err.contains("is detached at"));
+        assert!(err.contains("para resume detached"));
+        assert!(err.contains("git switch para/detached"));
+    }
+}
@@ -0,0 +1,290 @@
+//! Finish a pushed session branch in a plain clone
+//!
+//! CI can run the integration step of a session without its worktree or
+//! state: the session branch and its base are fetched, a temporary local
+//! branch shapes the session's commits with [`FinishManager`] as `para
+//! finish` would, the result is replayed onto the base, and the base is
+//! fast-forwarded to it and pushed together with the session branch. The
+//! clone ends up on the branch it had checked out, without the temporary
+//! branch, whether the finish succeeded, conflicted or failed.
+
+use super::finish::{FinishManager, FinishRequest, MergeMode};
+use super::integration_preview::{preview_integration, IntegrationPreview};
+use super::repository::{execute_git_command, GitRepository, WorktreeRef};
+use crate::utils::{ParaError, Result};
+
+/// Remote the session branch is fetched from and pushed to
+pub const REMOTE: &str = "origin";
+
+/// Local branch the session's commits are shaped on
+const TEMP_BRANCH_PREFIX: &str = "para-remote-finish";
+
+#[derive(Debug, Clone)]
+pub struct RemoteFinishRequest {
+    /// Session branch on [`REMOTE`]
+    pub branch: String,
+    /// Branch on [`REMOTE`] the session is integrated into
+    pub base: String,
+    pub commit_message: String,
+    pub merge_mode: MergeMode,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RemoteFinishOutcome {
+    /// The base and the session branch now point at `commit` on the remote
+    Finished { commit: String },
+    /// Nothing was pushed; paths that conflict with the base, sorted
+    Conflicts(Vec<String>),
+}
+
+pub fn finish_remote_branch(
+    repo: &GitRepository,
+    request: &RemoteFinishRequest,
+) -> Result<RemoteFinishOutcome> {
+    repo.require_work_tree("finish --remote-branch")?;
+    if repo.has_uncommitted_changes()? {
+        return Err(ParaError::invalid_args(
+            "finish --remote-branch needs a clone without uncommitted changes",
+        ));
+    }
+    repo.require_remote(REMOTE)?;
+    for branch in [&request.branch, &request.base] {
+        repo.fetch(
+            REMOTE,
+            &format!("+refs/heads/{branch}:refs/remotes/{REMOTE}/{branch}"),
+        )?;
+    }
+    let branch_ref = format!("{REMOTE}/{}", request.branch);
+    let base_ref = format!("{REMOTE}/{}", request.base);
+
+    if let IntegrationPreview::Conflicts(paths) = preview_integration(repo, &branch_ref, &base_ref)?
+    {
+        return Ok(RemoteFinishOutcome::Conflicts(paths));
+    }
+
+    let original = repo.head_ref()?;
+    let temp = format!("{TEMP_BRANCH_PREFIX}/{}", request.branch);
+    execute_git_command(repo, &["checkout", "--quiet", "-B", &temp, &branch_ref])?;
+
+    let result = finish_on(repo, &temp, &branch_ref, &base_ref, request);
+
+    // Whatever happened, put the clone back as it was
+    let _ = execute_git_command(repo, &["rebase", "--abort"]);
+    let _ = match &original {
+        WorktreeRef::Branch(branch) => {
+            execute_git_command(repo, &["checkout", "--quiet", "--force", branch])
+        }
+        WorktreeRef::Detached(sha) => {
+            execute_git_command(repo, &["checkout", "--quiet", "--force", "--detach", sha])
+        }
+    };
+    let _ = execute_git_command(repo, &["branch", "-D", &temp]);
+
+    if let Ok(RemoteFinishOutcome::Finished { ref commit }) = result {
+        fast_forward_local_base(repo, &request.base, commit, &original);
+    }
+    result
+}
+
+fn finish_on(
+    repo: &GitRepository,
+    temp: &str,
+    branch_ref: &str,
+    base_ref: &str,
+    request: &RemoteFinishRequest,
+) -> Result<RemoteFinishOutcome> {
+    let pushed_branch = repo.resolve_commit(branch_ref)?;
+    FinishManager::new(repo).finish_session(FinishRequest {
+        feature_branch: temp.to_string(),
+        commit_message: request.commit_message.clone(),
+        target_branch_name: None,
+        merge_mode: request.merge_mode,
+        parent_branch: Some(base_ref.to_string()),
+        base_commit: None,
+        stage_all: false,
+    })?;
+
+    // The base only fast-forwards to commits on top of it
+    let on_base =
+        execute_git_command(repo, &["merge-base", "--is-ancestor", base_ref, "HEAD"]).is_ok();
+    if !on_base && execute_git_command(repo, &["rebase", "--quiet", base_ref]).is_err() {
+        let conflicts = execute_git_command(repo, &["diff", "--name-only", "--diff-filter=U"])
+            .unwrap_or_default();
+        let mut paths: Vec<String> = conflicts.lines().map(str::to_string).collect();
+        paths.sort();
+        return Ok(RemoteFinishOutcome::Conflicts(paths));
+    }
+
+    let commit = repo.resolve_commit("HEAD")?;
+    // Both or neither: the base must fast-forward, and the session branch is
+    // only replaced if nobody pushed to it since it was fetched
+    execute_git_command(
+        repo,
+        &[
+            "push",
+            "--quiet",
+            "--atomic",
+            &format!(
+                "--force-with-lease=refs/heads/{}:{pushed_branch}",
+                request.branch
+            ),
+            REMOTE,
+            &format!("{commit}:refs/heads/{}", request.base),
+            &format!("{commit}:refs/heads/{}", request.branch),
+        ],
+    )
+    .map_err(|e| {
+        ParaError::git_operation(format!(
+            "Failed to push '{}' and '{}' to '{REMOTE}': {e}",
+            request.base, request.branch
+        ))
+    })?;
+    Ok(RemoteFinishOutcome::Finished { commit })
+}
+
+/// Move a local base branch along when it has nothing the remote lacks
+fn fast_forward_local_base(repo: &GitRepository, base: &str, commit: &str, original: &WorktreeRef) {
+    let local = format!("refs/heads/{base}");
+    if execute_git_command(repo, &["merge-base", "--is-ancestor", &local, commit]).is_err() {
+        return;
+    }
+    let _ = if matches!(original, WorktreeRef::Branch(branch) if branch == base) {
+        execute_git_command(repo, &["merge", "--quiet", "--ff-only", commit])
+    } else {
+        execute_git_command(repo, &["update-ref", &local, commit])
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::git::GitService;
+    use crate::test_utils::test_helpers::{commit_file, git};
+    use std::path::Path;
+    use tempfile::TempDir;
+
+    fn clone(bare: &Path, into: &Path) {
+        git(
+            bare.parent().unwrap(),
+            &[
+                "clone",
+                "--quiet",
+                &bare.to_string_lossy(),
+                &into.to_string_lossy(),
+            ],
+        );
+        git(into, &["config", "user.name", "Test"]);
+        git(into, &["config", "user.email", "test@example.com"]);
+    }
+
+    /// A bare remote with one commit on main, a clone the agent pushed a
+    /// two-commit session branch from, and a fresh clone for CI
+    fn agent_pushed_session() -> (TempDir, std::path::PathBuf, std::path::PathBuf) {
+        let temp = TempDir::new().unwrap();
+        let bare = temp.path().join("remote.git");
+        git(
+            temp.path(),
+            &[
+                "init",
+                "--quiet",
+                "--bare",
+                "-b",
+                "main",
+                &bare.to_string_lossy(),
+            ],
+        );
+
+        let agent = temp.path().join("agent");
+        clone(&bare, &agent);
+        git(&agent, &["checkout", "--quiet", "-b", "main"]);
+        commit_file(&agent, "README.md", "readme\n", "Initial commit");
+        git(&agent, &["push", "--quiet", "origin", "main"]);
+        git(&agent, &["checkout", "--quiet", "-b", "para/feature"]);
+        commit_file(&agent, "feature.txt", "one\n", "WIP one");
+        commit_file(&agent, "feature.txt", "one\ntwo\n", "WIP two");
+        git(&agent, &["push", "--quiet", "origin", "para/feature"]);
+
+        let ci = temp.path().join("ci");
+        clone(&bare, &ci);
+        (temp, bare, ci)
+    }
+
+    fn request() -> RemoteFinishRequest {
+        RemoteFinishRequest {
+            branch: "para/feature".to_string(),
+            base: "main".to_string(),
+            commit_message: "Add the feature".to_string(),
+            merge_mode: MergeMode::Squash,
+        }
+    }
+
+    fn assert_clean_clone(ci: &Path) {
+        assert_eq!(git(ci, &["rev-parse", "--abbrev-ref", "HEAD"]), "main");
+        assert_eq!(git(ci, &["status", "--porcelain"]), "");
+        assert!(!ci.join(".git/rebase-merge").exists());
+        assert!(!ci.join(".git/rebase-apply").exists());
+        assert_eq!(git(ci, &["branch", "--list", "para-remote-finish/*"]), "");
+    }
+
+    #[test]
+    fn test_ci_clone_squashes_and_pushes_onto_moved_base() {
+        let (temp, bare, ci) = agent_pushed_session();
+
+        // main moves on after the agent pushed
+        let other = temp.path().join("other");
+        clone(&bare, &other);
+        commit_file(&other, "other.txt", "other\n", "Unrelated change");
+        git(&other, &["push", "--quiet", "origin", "main"]);
+
+        let repo = GitService::discover_from(&ci).unwrap();
+        let outcome = finish_remote_branch(repo.repository(), &request()).unwrap();
+        let RemoteFinishOutcome::Finished { commit } = outcome else {
+            panic!("expected the finish to succeed, got {outcome:?}");
+        };
+
+        assert_eq!(git(&bare, &["rev-parse", "main"]), commit);
+        assert_eq!(git(&bare, &["rev-parse", "para/feature"]), commit);
+        assert_eq!(
+            git(&bare, &["log", "-1", "--format=%s", "main"]),
+            "Add the feature"
+        );
+        assert_eq!(
+            git(&bare, &["log", "-1", "--format=%s", "main~1"]),
+            "Unrelated change"
+        );
+        assert_eq!(git(&bare, &["show", "main:feature.txt"]), "one\ntwo");
+
+        assert_clean_clone(&ci);
+        assert_eq!(git(&ci, &["rev-parse", "main"]), commit);
+    }
+
+    #[test]
+    fn test_conflicting_base_pushes_nothing_and_leaves_clone_clean() {
+        let (temp, bare, ci) = agent_pushed_session();
+        let other = temp.path().join("other");
+        clone(&bare, &other);
+        commit_file(&other, "feature.txt", "conflicting\n", "Conflicting change");
+        git(&other, &["push", "--quiet", "origin", "main"]);
+        let main_before = git(&bare, &["rev-parse", "main"]);
+        let branch_before = git(&bare, &["rev-parse", "para/feature"]);
+
+        let repo = GitService::discover_from(&ci).unwrap();
+        let outcome = finish_remote_branch(repo.repository(), &request()).unwrap();
+        assert_eq!(
+            outcome,
+            RemoteFinishOutcome::Conflicts(vec!["feature.txt".to_string()])
+        );
+
+        assert_eq!(git(&bare, &["rev-parse", "main"]), main_before);
+        assert_eq!(git(&bare, &["rev-parse", "para/feature"]), branch_before);
+        assert_clean_clone(&ci);
+
+        // Replaying the commits one by one conflicts too; still nothing is left behind
+        let mut preserve = request();
+        preserve.merge_mode = MergeMode::PreserveCommits;
+        let outcome = finish_remote_branch(repo.repository(), &preserve).unwrap();
+        assert!(matches!(outcome, RemoteFinishOutcome::Conflicts(_)));
+        assert_eq!(git(&bare, &["rev-parse", "main"]), main_before);
+        assert_clean_clone(&ci);
+    }
+}
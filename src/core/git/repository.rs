@@ -1,11 +1,39 @@
+use crate::core::git::command::{self, GitCommandOptions};
+use crate::core::git::worktree_status::{StagedSplit, WorktreeStatus};
 use crate::utils::error::{ParaError, Result};
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
+/// What HEAD of a checkout refers to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WorktreeRef {
+    Branch(String),
+    /// HEAD points straight at a commit, e.g. after `git checkout <sha>`
+    Detached(String),
+}
+
+impl WorktreeRef {
+    /// Branch name, or `detached@<short-sha>`
+    pub fn label(&self) -> String {
+        match self {
+            WorktreeRef::Branch(branch) => branch.clone(),
+            WorktreeRef::Detached(sha) => format!("detached@{}", short_sha(sha)),
+        }
+    }
+}
+
+/// First 7 characters of a commit hash
+pub fn short_sha(sha: &str) -> &str {
+    sha.get(..7).unwrap_or(sha)
+}
+
 #[derive(Debug, Clone)]
 pub struct GitRepository {
+    /// Top of the working tree, or the repository directory itself when bare
     pub root: PathBuf,
     pub git_dir: PathBuf,
+    /// A bare repository such as `repo.git`, with all work done in worktrees
+    pub is_bare: bool,
 }
 
 impl GitRepository {
@@ -18,6 +46,14 @@ impl GitRepository {
     }
 
     pub fn discover_from(path: &Path) -> Result<Self> {
+        if let Some(git_dir) = Self::bare_git_dir(path) {
+            return Ok(Self {
+                root: git_dir.clone(),
+                git_dir,
+                is_bare: true,
+            });
+        }
+
         let output = Command::new("git")
             .current_dir(path)
             .args(["rev-parse", "--show-toplevel"])
@@ -37,7 +73,39 @@ impl GitRepository {
 
         let git_dir = Self::get_git_dir(&root)?;
 
-        Ok(Self { root, git_dir })
+        Ok(Self {
+            root,
+            git_dir,
+            is_bare: false,
+        })
+    }
+
+    /// The repository directory when `path` is inside a bare repository
+    fn bare_git_dir(path: &Path) -> Option<PathBuf> {
+        let output = command::output(
+            path,
+            &["rev-parse", "--is-bare-repository", "--absolute-git-dir"],
+            GitCommandOptions::default(),
+        )
+        .ok()?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let mut lines = stdout.lines();
+        if !output.status.success() || lines.next() != Some("true") {
+            return None;
+        }
+        lines.next().map(PathBuf::from)
+    }
+
+    /// Fail with a clear error when `operation` needs the main working tree
+    /// but the repository is bare
+    pub fn require_work_tree(&self, operation: &str) -> Result<()> {
+        if self.is_bare {
+            return Err(ParaError::invalid_args(format!(
+                "'{operation}' needs a working tree, but {} is a bare repository. Run it from one of its worktrees",
+                self.root.display()
+            )));
+        }
+        Ok(())
     }
 
     pub fn validate(&self) -> Result<()> {
@@ -53,6 +121,11 @@ impl GitRepository {
             ));
         }
 
+        // A bare repository has no status to check
+        if self.is_bare {
+            return Ok(());
+        }
+
         let output = Command::new("git")
             .current_dir(&self.root)
             .args(["status", "--porcelain"])
@@ -69,7 +142,39 @@ impl GitRepository {
     }
 
     pub fn get_current_branch(&self) -> Result<String> {
-        execute_git_command(self, &["rev-parse", "--abbrev-ref", "HEAD"])
+        execute_git_command(self, &["rev-parse", "--abbrev-ref", "HEAD"]).or_else(|e| {
+            // rev-parse fails on an unborn branch, but HEAD still names it
+            execute_git_command(self, &["symbolic-ref", "--short", "HEAD"]).map_err(|_| e)
+        })
+    }
+
+    /// The branch checked out, or the commit HEAD is detached at
+    pub fn head_ref(&self) -> Result<WorktreeRef> {
+        if let Ok(branch) = execute_git_command(self, &["symbolic-ref", "-q", "--short", "HEAD"]) {
+            return Ok(WorktreeRef::Branch(branch));
+        }
+        let sha = execute_git_command(self, &["rev-parse", "HEAD"])?;
+        Ok(WorktreeRef::Detached(sha))
+    }
+
+    /// Whether HEAD points at a commit (false in a freshly initialized repository)
+    pub fn has_commits(&self) -> bool {
+        execute_git_command(self, &["rev-parse", "--verify", "--quiet", "HEAD"]).is_ok()
+    }
+
+    /// Create an empty initial commit so branches and worktrees have something to start from
+    pub fn create_initial_commit(&self) -> Result<()> {
+        for key in ["user.name", "user.email"] {
+            let value = execute_git_command(self, &["config", "--get", key]).unwrap_or_default();
+            if value.is_empty() {
+                return Err(ParaError::git_operation(format!(
+                    "Cannot create the initial commit because git {key} is not set.\n\
+                    Configure it with: git config --global {key} \"<value>\""
+                )));
+            }
+        }
+
+        execute_git_command(self, &["commit", "--allow-empty", "-m", "Initial commit"]).map(|_| ())
     }
 
     pub fn get_main_branch(&self) -> Result<String> {
@@ -112,8 +217,19 @@ impl GitRepository {
     }
 
     pub fn has_uncommitted_changes(&self) -> Result<bool> {
-        let output = execute_git_command(self, &["status", "--porcelain"])?;
-        Ok(!output.trim().is_empty())
+        Ok(!self.worktree_status()?.is_clean())
+    }
+
+    /// Modified, staged, untracked and conflicted paths in the worktree
+    pub fn worktree_status(&self) -> Result<WorktreeStatus> {
+        let output = execute_git_command(self, &["status", "--porcelain=v2"])?;
+        Ok(WorktreeStatus::parse(&output))
+    }
+
+    /// Staged paths, and the ones a commit of the index would leave behind
+    pub fn staged_split(&self) -> Result<StagedSplit> {
+        let output = execute_git_command(self, &["status", "--porcelain=v2"])?;
+        Ok(StagedSplit::parse(&output))
     }
 
     pub fn is_clean_working_tree(&self) -> Result<bool> {
@@ -160,9 +276,71 @@ impl GitRepository {
     }
 
     pub fn checkout_branch(&self, branch: &str) -> Result<()> {
+        self.require_work_tree("git checkout")?;
         execute_git_command_with_status(self, &["checkout", branch])
     }
 
+    /// Fetch `refspec` from `remote` without touching local branches
+    pub fn fetch(&self, remote: &str, refspec: &str) -> Result<()> {
+        // The user asked for the fetch, so let git ask for credentials
+        execute_git_command_with_options(
+            self,
+            &["fetch", "--quiet", remote, refspec],
+            GitCommandOptions::interactive(),
+        )
+        .map(|_| ())
+        .map_err(|e| {
+            ParaError::git_operation(format!("Failed to fetch '{refspec}' from '{remote}': {e}"))
+        })
+    }
+
+    /// Fetch `refspec` from `remote` and prune deleted remote branches, for
+    /// fetches para runs on its own: git never prompts and the command timeout applies
+    pub fn fetch_prune(&self, remote: &str, refspec: &str) -> Result<()> {
+        execute_git_command(self, &["fetch", "--quiet", "--prune", remote, refspec])
+            .map(|_| ())
+            .map_err(|e| {
+                ParaError::git_operation(format!(
+                    "Failed to fetch '{refspec}' from '{remote}': {e}"
+                ))
+            })
+    }
+
+    pub fn list_remotes(&self) -> Result<Vec<String>> {
+        let output = execute_git_command(self, &["remote"])?;
+        Ok(output.lines().map(|line| line.trim().to_string()).collect())
+    }
+
+    /// Fail unless `name` is one of the repository's remotes
+    pub fn require_remote(&self, name: &str) -> Result<()> {
+        let remotes = self.list_remotes()?;
+        if remotes.iter().any(|remote| remote == name) {
+            return Ok(());
+        }
+        let configured = if remotes.is_empty() {
+            "none".to_string()
+        } else {
+            remotes.join(", ")
+        };
+        Err(ParaError::invalid_args(format!(
+            "Unknown remote '{name}'. Configured remotes: {configured}"
+        )))
+    }
+
+    /// Resolve any committish (branch, remote branch, tag or SHA) to a full commit SHA
+    pub fn resolve_commit(&self, committish: &str) -> Result<String> {
+        execute_git_command(
+            self,
+            &[
+                "rev-parse",
+                "--verify",
+                "--quiet",
+                &format!("{committish}^{{commit}}"),
+            ],
+        )
+        .map_err(|_| ParaError::git_operation(format!("'{committish}' is not a known commit")))
+    }
+
     fn get_git_dir(repo_root: &Path) -> Result<PathBuf> {
         let output = Command::new("git")
             .current_dir(repo_root)
@@ -188,11 +366,15 @@ impl GitRepository {
 }
 
 pub fn execute_git_command(repo: &GitRepository, args: &[&str]) -> Result<String> {
-    let output = Command::new("git")
-        .current_dir(&repo.root)
-        .args(args)
-        .output()
-        .map_err(|e| ParaError::git_operation(format!("Failed to execute git: {e}")))?;
+    execute_git_command_with_options(repo, args, GitCommandOptions::default())
+}
+
+pub fn execute_git_command_with_options(
+    repo: &GitRepository,
+    args: &[&str],
+    options: GitCommandOptions,
+) -> Result<String> {
+    let output = command::output(&repo.root, args, options)?;
 
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
@@ -208,11 +390,15 @@ pub fn execute_git_command(repo: &GitRepository, args: &[&str]) -> Result<String
 }
 
 pub fn execute_git_command_with_status(repo: &GitRepository, args: &[&str]) -> Result<()> {
-    let status = Command::new("git")
-        .current_dir(&repo.root)
-        .args(args)
-        .status()
-        .map_err(|e| ParaError::git_operation(format!("Failed to execute git: {e}")))?;
+    execute_git_command_with_status_and_options(repo, args, GitCommandOptions::default())
+}
+
+pub fn execute_git_command_with_status_and_options(
+    repo: &GitRepository,
+    args: &[&str],
+    options: GitCommandOptions,
+) -> Result<()> {
+    let status = command::status(&repo.root, args, options)?;
 
     if !status.success() {
         return Err(ParaError::git_operation(format!(
@@ -266,6 +452,22 @@ mod tests {
         assert!(branch == "main");
     }
 
+    #[test]
+    fn test_head_ref_detects_detached_head() {
+        let (_temp_dir, git_service) = setup_test_repo();
+        let repo = git_service.repository();
+        assert_eq!(
+            repo.head_ref().unwrap(),
+            WorktreeRef::Branch("main".to_string())
+        );
+
+        let sha = execute_git_command(repo, &["rev-parse", "HEAD"]).unwrap();
+        execute_git_command(repo, &["checkout", "--quiet", &sha]).unwrap();
+        let head = repo.head_ref().unwrap();
+        assert_eq!(head, WorktreeRef::Detached(sha.clone()));
+        assert_eq!(head.label(), format!("detached@{}", &sha[..7]));
+    }
+
     #[test]
     fn test_clean_working_tree() {
         let (_temp_dir, git_service) = setup_test_repo();
@@ -357,4 +559,18 @@ mod tests {
         assert!(status.contains("A  file1.txt"));
         assert!(status.contains("A  file2.txt"));
     }
+
+    #[test]
+    fn test_unborn_repository() {
+        let (_temp_dir, git_service) = setup_empty_test_repo();
+        let repo = git_service.repository();
+
+        assert!(!repo.has_commits());
+        assert_eq!(repo.get_current_branch().unwrap(), "main");
+
+        repo.create_initial_commit()
+            .expect("Failed to create initial commit");
+        assert!(repo.has_commits());
+        assert_eq!(repo.get_current_branch().unwrap(), "main");
+    }
 }
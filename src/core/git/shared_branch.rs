@@ -0,0 +1,199 @@
+//! Protection for session branches that are checked out in extra worktrees
+//!
+//! Users sometimes add a second worktree on a session branch by hand. Renaming
+//! or archiving the branch then either fails or silently moves that worktree
+//! onto the new name, so finish and cancel check for such worktrees up front.
+//! With `--force-rename` the extra worktrees are detached for the duration of
+//! the operation and re-attached to the resulting branch afterwards.
+
+use super::command::{self, GitCommandOptions};
+use super::repository::GitRepository;
+use super::worktree::WorktreeManager;
+use crate::utils::error::{ParaError, Result};
+use std::path::{Path, PathBuf};
+
+/// Worktrees other than `session_worktree` that have `branch` checked out
+pub fn other_worktrees_on_branch(
+    repo: &GitRepository,
+    branch: &str,
+    session_worktree: Option<&Path>,
+) -> Result<Vec<PathBuf>> {
+    let session_worktree = session_worktree.map(canonical);
+
+    Ok(WorktreeManager::new(repo)
+        .list_worktrees()?
+        .into_iter()
+        .filter(|worktree| worktree.branch == branch)
+        .map(|worktree| worktree.path)
+        .filter(|path| session_worktree.as_ref() != Some(&canonical(path)))
+        .collect())
+}
+
+fn canonical(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
+
+/// Extra worktrees detached while their branch is renamed or archived
+#[derive(Debug, Default)]
+pub struct DetachedWorktrees {
+    paths: Vec<PathBuf>,
+}
+
+impl DetachedWorktrees {
+    /// Make sure no worktree besides the session's own has `branch` checked out.
+    ///
+    /// Fails naming the other worktrees unless `force_rename` is set, in which
+    /// case they are detached at their current commit.
+    pub fn release(
+        repo: &GitRepository,
+        branch: &str,
+        session_worktree: Option<&Path>,
+        force_rename: bool,
+    ) -> Result<Self> {
+        let paths = other_worktrees_on_branch(repo, branch, session_worktree)?;
+        if paths.is_empty() {
+            return Ok(Self::default());
+        }
+
+        if !force_rename {
+            let list = paths
+                .iter()
+                .map(|p| format!("  {}", p.display()))
+                .collect::<Vec<_>>()
+                .join("\n");
+            return Err(ParaError::git_operation(format!(
+                "Branch '{branch}' is also checked out in another worktree:\n{list}\n\
+                 Switch that worktree to a different branch, remove it with \
+                 'git worktree remove <path>', or rerun with --force-rename to detach \
+                 it temporarily."
+            )));
+        }
+
+        let mut detached = Self::default();
+        for path in paths {
+            if let Err(e) = run_git_in(&path, &["checkout", "--detach"]) {
+                // Put back the ones already detached before giving up
+                detached.reattach(branch);
+                return Err(ParaError::git_operation(format!(
+                    "Failed to detach worktree {}: {e}",
+                    path.display()
+                )));
+            }
+            println!("Detached worktree {} from '{branch}'", path.display());
+            detached.paths.push(path);
+        }
+        Ok(detached)
+    }
+
+    pub fn paths(&self) -> &[PathBuf] {
+        &self.paths
+    }
+
+    /// Check out `branch` again in every detached worktree.
+    ///
+    /// Failures are reported as warnings and leave that worktree detached.
+    pub fn reattach(self, branch: &str) {
+        for path in &self.paths {
+            match run_git_in(path, &["checkout", "--ignore-other-worktrees", branch]) {
+                Ok(()) => println!("Re-attached worktree {} to '{branch}'", path.display()),
+                Err(e) => eprintln!(
+                    "Warning: Could not re-attach worktree {} to '{branch}', it stays detached: {e}",
+                    path.display()
+                ),
+            }
+        }
+    }
+}
+
+fn run_git_in(path: &Path, args: &[&str]) -> Result<()> {
+    let output = command::output(path, args, GitCommandOptions::default())?;
+    if !output.status.success() {
+        return Err(ParaError::git_operation(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::git::branch::BranchManager;
+    use crate::test_utils::test_helpers::*;
+    use tempfile::TempDir;
+
+    fn add_worktree(repo: &GitRepository, path: &Path, branch: &str, new_branch: bool) {
+        let mut args = vec!["worktree", "add", "-f"];
+        if new_branch {
+            args.extend(["-b", branch]);
+        }
+        let path_str = path.to_string_lossy().to_string();
+        args.push(&path_str);
+        if !new_branch {
+            args.push(branch);
+        }
+        let output = command::output(&repo.root, &args, GitCommandOptions::default()).unwrap();
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    fn current_branch(path: &Path) -> String {
+        GitRepository::discover_from(path)
+            .unwrap()
+            .get_current_branch()
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn test_shared_branch_fails_early_naming_other_worktree() {
+        let (_git_temp, git_service) = setup_test_repo();
+        let repo = git_service.repository();
+        let worktrees = TempDir::new().unwrap();
+        let session = worktrees.path().join("session");
+        let extra = worktrees.path().join("extra");
+
+        add_worktree(repo, &session, "para/shared", true);
+        assert!(
+            other_worktrees_on_branch(repo, "para/shared", Some(&session))
+                .unwrap()
+                .is_empty()
+        );
+
+        add_worktree(repo, &extra, "para/shared", false);
+        let err = DetachedWorktrees::release(repo, "para/shared", Some(&session), false)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("also checked out in another worktree"));
+        assert!(err.contains(&extra.canonicalize().unwrap().display().to_string()));
+        assert!(err.contains("--force-rename"));
+        assert_eq!(current_branch(&extra), "para/shared");
+    }
+
+    #[test]
+    fn test_force_rename_detaches_and_reattaches_to_renamed_branch() {
+        let (_git_temp, git_service) = setup_test_repo();
+        let repo = git_service.repository();
+        let worktrees = TempDir::new().unwrap();
+        let session = worktrees.path().join("session");
+        let extra = worktrees.path().join("extra");
+
+        add_worktree(repo, &session, "para/shared", true);
+        add_worktree(repo, &extra, "para/shared", false);
+
+        let detached =
+            DetachedWorktrees::release(repo, "para/shared", Some(&session), true).unwrap();
+        assert_eq!(detached.paths().len(), 1);
+        assert_ne!(current_branch(&extra), "para/shared");
+
+        let archived = BranchManager::new(repo)
+            .move_to_archive("para/shared", "para")
+            .unwrap();
+        assert_eq!(current_branch(&session), archived);
+
+        detached.reattach(&archived);
+        assert_eq!(current_branch(&extra), archived);
+    }
+}
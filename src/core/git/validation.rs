@@ -1,6 +1,11 @@
+use super::repository::{execute_git_command, GitRepository};
+use crate::core::ide::workspace::processes_holding;
+use crate::core::network::NetworkContext;
 use crate::utils::error::{ParaError, Result};
 use regex::Regex;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant};
 
 /// Centralized Git-related validation utilities
 pub struct GitValidator;
@@ -21,7 +26,12 @@ impl GitValidator {
         let invalid_patterns = vec![
             r"\.\.+",              // Contains ..
             r"^-",                 // Starts with -
+            r"^/",                 // Starts with /
             r"/$",                 // Ends with /
+            r"//",                 // Contains an empty component
+            r"^\.",                // Starts with .
+            r"\.$",                // Ends with .
+            r"\.lock(/|$)",        // A component ends with .lock
             r"\x00",               // Contains null byte
             r"[ \t]",              // Contains whitespace
             r"[\x00-\x1f\x7f]",    // Contains control characters
@@ -71,9 +81,178 @@ impl GitValidator {
     }
 }
 
+/// What para is about to do with a repository, for [`preflight`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RepoOperation {
+    Finish,
+    Sync,
+    CreateWorktree,
+}
+
+impl RepoOperation {
+    fn describe(self) -> &'static str {
+        match self {
+            RepoOperation::Finish => "finish the session",
+            RepoOperation::Sync => "sync the session",
+            RepoOperation::CreateWorktree => "create the worktree",
+        }
+    }
+
+    /// Why the operation needs the full history, if it does
+    fn needs_full_history(self) -> Option<&'static str> {
+        match self {
+            RepoOperation::Finish => Some(
+                "finishing squashes the session's commits since its merge base with the base branch",
+            ),
+            RepoOperation::Sync => {
+                Some("syncing rebases the session's commits from their merge base with the base branch")
+            }
+            RepoOperation::CreateWorktree => None,
+        }
+    }
+
+    /// Whether the command offers `--wait` and `--unshallow`
+    fn has_preflight_flags(self) -> bool {
+        self != RepoOperation::CreateWorktree
+    }
+}
+
+/// How [`preflight`] deals with a locked index or a shallow clone
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PreflightOptions {
+    /// How long to wait for another git process to release `index.lock`
+    pub wait: Option<Duration>,
+    /// Fetch the missing history of a shallow clone instead of failing
+    pub unshallow: bool,
+}
+
+/// Fail before changing anything, with an explanation, when `operation`
+/// can't work on `repo`: another git process holds its index, or it is a
+/// shallow clone and the operation needs the full history
+pub fn preflight(
+    repo: &GitRepository,
+    operation: RepoOperation,
+    options: PreflightOptions,
+) -> Result<()> {
+    wait_for_index_lock(repo, operation, options.wait)?;
+    if let Some(reason) = operation.needs_full_history() {
+        ensure_full_history(repo, operation, reason, options.unshallow)?;
+    }
+    Ok(())
+}
+
+/// The lock file git creates while it changes the index of `repo`
+pub fn index_lock_path(repo: &GitRepository) -> Result<PathBuf> {
+    let path = execute_git_command(repo, &["rev-parse", "--git-path", "index.lock"])?;
+    Ok(repo.root.join(path.trim()))
+}
+
+pub fn is_shallow(repo: &GitRepository) -> Result<bool> {
+    Ok(execute_git_command(repo, &["rev-parse", "--is-shallow-repository"])?.trim() == "true")
+}
+
+fn wait_for_index_lock(
+    repo: &GitRepository,
+    operation: RepoOperation,
+    wait: Option<Duration>,
+) -> Result<()> {
+    let lock = index_lock_path(repo)?;
+    let started = Instant::now();
+    let wait = wait.unwrap_or_default();
+    let mut delay = Duration::from_millis(100);
+
+    while lock.exists() {
+        let waited = started.elapsed();
+        if waited >= wait {
+            return Err(index_locked_error(&lock, operation));
+        }
+        if waited.is_zero() {
+            eprintln!(
+                "Waiting up to {}s for another git process to release {}",
+                wait.as_secs(),
+                lock.display()
+            );
+        }
+        thread::sleep(delay.min(wait - waited));
+        delay = (delay * 2).min(Duration::from_secs(2));
+    }
+    Ok(())
+}
+
+fn index_locked_error(lock: &Path, operation: RepoOperation) -> ParaError {
+    let holders = processes_holding(lock).unwrap_or_default();
+    let (holder, advice) = if holders.is_empty() {
+        (
+            "another git process".to_string(),
+            format!(
+                "If no git command is running, one that crashed left it behind: remove {}",
+                lock.display()
+            ),
+        )
+    } else {
+        let names: Vec<String> = holders
+            .iter()
+            .map(|process| format!("{} (pid {})", process.name, process.pid))
+            .collect();
+        (names.join(", "), "Wait for it to finish".to_string())
+    };
+    let retry = if operation.has_preflight_flags() {
+        " or pass --wait <SECONDS>"
+    } else {
+        ""
+    };
+    ParaError::git_operation(format!(
+        "Cannot {}: {} is using the repository ({} exists). {advice}{retry}",
+        operation.describe(),
+        holder,
+        lock.display()
+    ))
+}
+
+fn ensure_full_history(
+    repo: &GitRepository,
+    operation: RepoOperation,
+    reason: &str,
+    unshallow: bool,
+) -> Result<()> {
+    if !is_shallow(repo)? {
+        return Ok(());
+    }
+
+    let remotes = repo.list_remotes()?;
+    let remote = remotes
+        .iter()
+        .find(|remote| *remote == "origin")
+        .or(remotes.first());
+    let Some(remote) = remote.filter(|_| unshallow) else {
+        let hint = match remote {
+            Some(remote) => format!(
+                "Pass --unshallow to fetch the missing history from '{remote}' first, or run 'git fetch --unshallow'"
+            ),
+            None => "The repository has no remote to fetch the missing history from; clone it again without --depth".to_string(),
+        };
+        return Err(ParaError::git_operation(format!(
+            "Cannot {} in a shallow clone: {reason}, which needs the full history. {hint}",
+            operation.describe()
+        )));
+    };
+
+    NetworkContext::current().require_online("Fetching the missing history of a shallow clone")?;
+    eprintln!("Fetching the missing history from '{remote}'...");
+    execute_git_command(repo, &["fetch", "--quiet", "--unshallow", remote]).map_err(|e| {
+        ParaError::git_operation(format!(
+            "Failed to fetch the missing history from '{remote}': {e}"
+        ))
+    })?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_utils::test_helpers::setup_test_repo;
+    use std::fs;
+    use std::process::Command;
 
     #[test]
     fn test_validate_branch_name_valid() {
@@ -95,6 +274,12 @@ mod tests {
             "branch@{",
             "branch~1",
             "refs/heads/test",
+            "/leading",
+            "para//double",
+            ".hidden",
+            "para/ends.",
+            "para/x.lock",
+            "para/x.lock/y",
         ];
 
         for invalid_name in invalid_names {
@@ -110,4 +295,76 @@ mod tests {
         let long_name = "a".repeat(251);
         assert!(GitValidator::validate_branch_name(&long_name).is_err());
     }
+
+    #[test]
+    fn test_preflight_waits_for_index_lock() {
+        let (_temp, git_service) = setup_test_repo();
+        let repo = git_service.repository();
+        let lock = index_lock_path(repo).unwrap();
+        assert_eq!(lock, repo.root.join(".git/index.lock"));
+        fs::write(&lock, "").unwrap();
+
+        let error = preflight(repo, RepoOperation::Finish, PreflightOptions::default())
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("Cannot finish the session"), "{error}");
+        assert!(error.contains(&lock.display().to_string()), "{error}");
+        assert!(error.contains("--wait <SECONDS>"), "{error}");
+        let error = preflight(repo, RepoOperation::CreateWorktree, Default::default())
+            .unwrap_err()
+            .to_string();
+        assert!(!error.contains("--wait"), "{error}");
+
+        let releaser = {
+            let lock = lock.clone();
+            thread::spawn(move || {
+                thread::sleep(Duration::from_millis(150));
+                fs::remove_file(lock).unwrap();
+            })
+        };
+        let options = PreflightOptions {
+            wait: Some(Duration::from_secs(10)),
+            unshallow: false,
+        };
+        preflight(repo, RepoOperation::Finish, options).unwrap();
+        releaser.join().unwrap();
+    }
+
+    #[test]
+    fn test_preflight_on_shallow_clone() {
+        let (source_temp, source) = setup_test_repo();
+        for n in 1..=3 {
+            fs::write(source.repository().root.join("file.txt"), n.to_string()).unwrap();
+            source.repository().stage_all_changes().unwrap();
+            source.repository().commit(&format!("Commit {n}")).unwrap();
+        }
+        let clone_path = source_temp.path().join("shallow");
+        let status = Command::new("git")
+            .args(["clone", "--quiet", "--depth", "1"])
+            .arg(format!("file://{}", source.repository().root.display()))
+            .arg(&clone_path)
+            .status()
+            .unwrap();
+        assert!(status.success());
+        let clone = GitRepository::discover_from(&clone_path).unwrap();
+        assert!(is_shallow(&clone).unwrap());
+        assert!(!is_shallow(source.repository()).unwrap());
+
+        // Creating a worktree does not need the history, finishing does
+        preflight(&clone, RepoOperation::CreateWorktree, Default::default()).unwrap();
+        let error = preflight(&clone, RepoOperation::Finish, Default::default())
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("shallow clone"), "{error}");
+        assert!(error.contains("merge base"), "{error}");
+        assert!(error.contains("--unshallow"), "{error}");
+        assert!(is_shallow(&clone).unwrap());
+
+        let options = PreflightOptions {
+            wait: None,
+            unshallow: true,
+        };
+        preflight(&clone, RepoOperation::Sync, options).unwrap();
+        assert!(!is_shallow(&clone).unwrap());
+    }
 }
@@ -1,5 +1,9 @@
-use super::repository::{execute_git_command, execute_git_command_with_status, GitRepository};
-use super::validation::GitValidator;
+use super::command::GitCommandOptions;
+use super::repository::{
+    execute_git_command, execute_git_command_with_status,
+    execute_git_command_with_status_and_options, GitRepository,
+};
+use super::validation::{preflight, GitValidator, RepoOperation};
 use crate::utils::error::{ParaError, Result};
 use std::path::{Path, PathBuf};
 
@@ -20,23 +24,74 @@ impl<'a> WorktreeManager<'a> {
         Self { repo }
     }
 
-    pub fn create_worktree(&self, branch_name: &str, path: &Path) -> Result<()> {
+    /// Create a worktree for `branch_name` at `path` and return where it ended up.
+    ///
+    /// A leftover directory at `path` is handled instead of failing: an empty
+    /// one is reused, a worktree already on `branch_name` (from an earlier run
+    /// that failed halfway) is adopted, and anything else is left alone while
+    /// the worktree goes to the first free `<path>-N`.
+    pub fn create_worktree(&self, branch_name: &str, path: &Path) -> Result<PathBuf> {
         self.validate_branch_name(branch_name)?;
         self.validate_worktree_path(path)?;
 
-        if path.exists() {
-            return Err(ParaError::git_operation(format!(
-                "Worktree path already exists: {}",
-                path.display()
-            )));
-        }
+        let path = match self.existing_path(branch_name, path) {
+            ExistingPath::Missing => path.to_path_buf(),
+            ExistingPath::Empty => {
+                std::fs::remove_dir(path).map_err(|e| {
+                    ParaError::git_operation(format!(
+                        "Failed to reuse empty directory {}: {e}",
+                        path.display()
+                    ))
+                })?;
+                path.to_path_buf()
+            }
+            ExistingPath::WorktreeForBranch => {
+                println!(
+                    "♻️  Adopting existing worktree for {branch_name} at {}",
+                    path.display()
+                );
+                return Ok(path.to_path_buf());
+            }
+            ExistingPath::Occupied => {
+                let suffixed = Self::free_suffixed_path(path);
+                println!(
+                    "⚠️  {} already exists and is not empty; creating the worktree at {} instead",
+                    path.display(),
+                    suffixed.display()
+                );
+                suffixed
+            }
+        };
+        preflight(self.repo, RepoOperation::CreateWorktree, Default::default())?;
+        self.add_worktree(branch_name, &path)?;
+        Ok(path)
+    }
 
+    fn add_worktree(&self, branch_name: &str, path: &Path) -> Result<()> {
         if let Some(parent) = path.parent() {
             std::fs::create_dir_all(parent).map_err(|e| {
                 ParaError::git_operation(format!("Failed to create parent directory: {e}"))
             })?;
         }
 
+        if let Err(e) = self.run_worktree_add(branch_name, path) {
+            if !is_concurrent_metadata_error(&e.to_string()) {
+                return Err(e);
+            }
+            // Another `git worktree add` held a lock on the repository metadata;
+            // drop whatever the failed attempt left behind and try once more
+            std::thread::sleep(CONCURRENT_ADD_RETRY_DELAY);
+            if path.exists() && self.validate_worktree(path).is_err() {
+                let _ = std::fs::remove_dir_all(path);
+            }
+            self.run_worktree_add(branch_name, path)?;
+        }
+
+        self.validate_worktree(path)?;
+        Ok(())
+    }
+
+    fn run_worktree_add(&self, branch_name: &str, path: &Path) -> Result<()> {
         let path_str = path.to_string_lossy();
 
         let branch_exists = execute_git_command(
@@ -50,21 +105,52 @@ impl<'a> WorktreeManager<'a> {
         .is_ok();
 
         if branch_exists {
-            execute_git_command_with_status(
+            execute_git_command_with_status_and_options(
                 self.repo,
                 &["worktree", "add", &path_str, branch_name],
+                GitCommandOptions::slow(),
             )?;
         } else {
-            execute_git_command_with_status(
+            execute_git_command_with_status_and_options(
                 self.repo,
                 &["worktree", "add", "-b", branch_name, &path_str, "HEAD"],
+                GitCommandOptions::slow(),
             )?;
         }
-
-        self.validate_worktree(path)?;
         Ok(())
     }
 
+    fn existing_path(&self, branch_name: &str, path: &Path) -> ExistingPath {
+        if !path.exists() {
+            return ExistingPath::Missing;
+        }
+        if std::fs::read_dir(path).is_ok_and(|mut entries| entries.next().is_none()) {
+            return ExistingPath::Empty;
+        }
+
+        let registered = self.list_worktrees().is_ok_and(|worktrees| {
+            worktrees
+                .iter()
+                .any(|w| w.branch == branch_name && same_path(&w.path, path))
+        });
+        if registered && self.validate_worktree(path).is_ok() {
+            ExistingPath::WorktreeForBranch
+        } else {
+            ExistingPath::Occupied
+        }
+    }
+
+    fn free_suffixed_path(path: &Path) -> PathBuf {
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        (2..)
+            .map(|n| path.with_file_name(format!("{name}-{n}")))
+            .find(|candidate| !candidate.exists())
+            .expect("unbounded suffix range")
+    }
+
     pub fn remove_worktree(&self, path: &Path) -> Result<()> {
         if !path.exists() {
             return Err(ParaError::git_operation(format!(
@@ -117,6 +203,31 @@ impl<'a> WorktreeManager<'a> {
     }
 }
 
+/// What is already at the path a new worktree should go to
+enum ExistingPath {
+    Missing,
+    Empty,
+    WorktreeForBranch,
+    Occupied,
+}
+
+/// How long to wait before retrying a `git worktree add` that lost a race
+const CONCURRENT_ADD_RETRY_DELAY: std::time::Duration = std::time::Duration::from_millis(200);
+
+/// Whether git failed because another process was updating the same
+/// repository metadata (config, refs or worktree administrative files)
+fn is_concurrent_metadata_error(message: &str) -> bool {
+    message.contains("could not lock config file")
+        || (message.contains(".lock") && message.contains("File exists"))
+}
+
+fn same_path(a: &Path, b: &Path) -> bool {
+    match (a.canonicalize(), b.canonicalize()) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a == b,
+    }
+}
+
 /// Dedicated parser for git worktree porcelain output
 struct WorktreePorcelainParser {
     worktrees: Vec<WorktreeInfo>,
@@ -298,6 +409,23 @@ mod tests {
     use crate::test_utils::test_helpers::*;
     use std::fs;
 
+    #[test]
+    fn test_concurrent_metadata_errors_are_recognized() {
+        assert!(is_concurrent_metadata_error(
+            "Git command failed (worktree add): error: could not lock config file .git/config: File exists"
+        ));
+        assert!(is_concurrent_metadata_error(
+            "fatal: Unable to create '/repo/.git/worktrees/x/index.lock': File exists."
+        ));
+        // Another process created the branch; retrying would check out its work
+        assert!(!is_concurrent_metadata_error(
+            "fatal: cannot lock ref 'refs/heads/para/x': reference already exists"
+        ));
+        assert!(!is_concurrent_metadata_error(
+            "fatal: 'para/x' is already checked out at '/repo/.para/worktrees/x'"
+        ));
+    }
+
     #[test]
     fn test_create_and_remove_worktree() {
         let (temp_dir, git_service) = setup_test_repo();
@@ -324,6 +452,71 @@ mod tests {
         assert!(!worktree_path.exists());
     }
 
+    #[test]
+    fn test_create_worktree_reuses_empty_directory() {
+        let (temp_dir, git_service) = setup_test_repo();
+        let manager = WorktreeManager::new(git_service.repository());
+        let worktree_path = temp_dir.path().join("subtrees").join("empty");
+        fs::create_dir_all(&worktree_path).unwrap();
+
+        let created = manager
+            .create_worktree("para/empty", &worktree_path)
+            .unwrap();
+
+        assert_eq!(created, worktree_path);
+        assert_eq!(manager.get_worktree_branch(&created).unwrap(), "para/empty");
+    }
+
+    #[test]
+    fn test_create_worktree_adopts_worktree_left_by_failed_run() {
+        let (temp_dir, git_service) = setup_test_repo();
+        let manager = WorktreeManager::new(git_service.repository());
+        let worktree_path = temp_dir.path().join("subtrees").join("crashed");
+
+        // A previous run created the worktree but died before saving session state
+        manager
+            .create_worktree("para/crashed", &worktree_path)
+            .unwrap();
+        fs::write(worktree_path.join("work.txt"), "in progress").unwrap();
+
+        let adopted = manager
+            .create_worktree("para/crashed", &worktree_path)
+            .unwrap();
+
+        assert_eq!(adopted, worktree_path);
+        assert!(adopted.join("work.txt").exists());
+        let registered = manager
+            .list_worktrees()
+            .unwrap()
+            .into_iter()
+            .filter(|w| w.branch == "para/crashed")
+            .count();
+        assert_eq!(registered, 1);
+    }
+
+    #[test]
+    fn test_create_worktree_suffixes_occupied_directory() {
+        let (temp_dir, git_service) = setup_test_repo();
+        let manager = WorktreeManager::new(git_service.repository());
+        let subtrees = temp_dir.path().join("subtrees");
+        let worktree_path = subtrees.join("busy");
+        fs::create_dir_all(&worktree_path).unwrap();
+        fs::write(worktree_path.join("notes.txt"), "user data").unwrap();
+        fs::create_dir_all(subtrees.join("busy-2").join("more")).unwrap();
+
+        let created = manager
+            .create_worktree("para/busy", &worktree_path)
+            .unwrap();
+
+        assert_eq!(created, subtrees.join("busy-3"));
+        assert_eq!(manager.get_worktree_branch(&created).unwrap(), "para/busy");
+        assert_eq!(
+            fs::read_to_string(worktree_path.join("notes.txt")).unwrap(),
+            "user data"
+        );
+        assert!(subtrees.join("busy-2").join("more").exists());
+    }
+
     #[test]
     fn test_list_worktrees() {
         let (temp_dir, git_service) = setup_test_repo();
@@ -0,0 +1,206 @@
+//! What is uncommitted in a worktree, from one `git status --porcelain=v2`
+
+/// Counts of changed paths in a worktree. A file that is staged and changed
+/// again afterwards counts as both staged and modified.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WorktreeStatus {
+    /// Tracked files with changes that are not staged
+    pub modified: usize,
+    /// Tracked files with staged changes, including renames and additions
+    pub staged: usize,
+    /// Untracked files and directories, as git status lists them
+    pub untracked: usize,
+    /// Files with unresolved merge conflicts
+    pub conflicted: usize,
+}
+
+impl WorktreeStatus {
+    /// Parse the output of `git status --porcelain=v2`
+    pub fn parse(porcelain_v2: &str) -> Self {
+        let mut status = Self::default();
+        for line in porcelain_v2.lines() {
+            let mut fields = line.split(' ');
+            match fields.next() {
+                // Ordinary (1) and renamed or copied (2) entries: `<kind> <XY> ...`
+                Some("1") | Some("2") => {
+                    let xy = fields.next().unwrap_or("..").as_bytes();
+                    if xy.first().is_some_and(|&x| x != b'.') {
+                        status.staged += 1;
+                    }
+                    if xy.get(1).is_some_and(|&y| y != b'.') {
+                        status.modified += 1;
+                    }
+                }
+                Some("u") => status.conflicted += 1,
+                Some("?") => status.untracked += 1,
+                _ => {}
+            }
+        }
+        status
+    }
+
+    pub fn is_clean(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Changes to tracked files, as opposed to only new untracked files
+    pub fn has_tracked_changes(&self) -> bool {
+        self.modified + self.staged + self.conflicted > 0
+    }
+
+    /// Only untracked files, e.g. build output the agent produced
+    pub fn is_untracked_only(&self) -> bool {
+        self.untracked > 0 && !self.has_tracked_changes()
+    }
+
+    /// `3 modified, 1 staged, 12 untracked`, leaving out zero counts
+    pub fn summary(&self) -> String {
+        let parts: Vec<String> = [
+            (self.conflicted, "conflicted"),
+            (self.modified, "modified"),
+            (self.staged, "staged"),
+            (self.untracked, "untracked"),
+        ]
+        .into_iter()
+        .filter(|(count, _)| *count > 0)
+        .map(|(count, label)| format!("{count} {label}"))
+        .collect();
+        if parts.is_empty() {
+            "clean".to_string()
+        } else {
+            parts.join(", ")
+        }
+    }
+}
+
+/// Paths of a worktree split by what a commit of the index would take: the
+/// staged ones, and the unstaged, untracked or conflicted ones it leaves
+/// behind. A file with staged changes that was edited again is in both.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StagedSplit {
+    pub staged: Vec<String>,
+    pub left_behind: Vec<String>,
+}
+
+impl StagedSplit {
+    /// Parse the output of `git status --porcelain=v2`
+    pub fn parse(porcelain_v2: &str) -> Self {
+        let mut split = Self::default();
+        for line in porcelain_v2.lines() {
+            let (kind, rest) = line.split_once(' ').unwrap_or((line, ""));
+            // Fields before the path: `<XY> <sub> <mH> <mI> <mW> <hH> <hI>`,
+            // then the rename score for renames and three more for conflicts
+            let (xy, path) = match kind {
+                "1" => (rest.get(..2), rest.splitn(8, ' ').nth(7)),
+                "2" => (
+                    rest.get(..2),
+                    rest.splitn(9, ' ')
+                        .nth(8)
+                        .map(|paths| paths.split('\t').next().unwrap_or(paths)),
+                ),
+                "u" => {
+                    if let Some(path) = rest.splitn(10, ' ').nth(9) {
+                        split.left_behind.push(path.to_string());
+                    }
+                    continue;
+                }
+                "?" => {
+                    split.left_behind.push(rest.to_string());
+                    continue;
+                }
+                _ => continue,
+            };
+            let (Some(xy), Some(path)) = (xy.map(str::as_bytes), path) else {
+                continue;
+            };
+            if xy[0] != b'.' {
+                split.staged.push(path.to_string());
+            }
+            if xy[1] != b'.' {
+                split.left_behind.push(path.to_string());
+            }
+        }
+        split
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_porcelain_v2() {
+        let output = "\
+# branch.oid 1234567890abcdef1234567890abcdef12345678
+# branch.head para/auth
+1 .M N... 100644 100644 100644 aaaaaaa aaaaaaa src/lib.rs
+1 M. N... 100644 100644 100644 aaaaaaa bbbbbbb src/main.rs
+1 MM N... 100644 100644 100644 aaaaaaa bbbbbbb README.md
+1 A. N... 000000 100644 100644 0000000 bbbbbbb src/new.rs
+2 R. N... 100644 100644 100644 aaaaaaa aaaaaaa R100 src/renamed.rs\tsrc/old.rs
+2 RM N... 100644 100644 100644 aaaaaaa aaaaaaa R087 docs/b.md\tdocs/a.md
+u UU N... 100644 100644 100644 100644 aaaaaaa bbbbbbb ccccccc src/conflict.rs
+? target/
+? notes with spaces.txt
+! ignored.log
+";
+        let status = WorktreeStatus::parse(output);
+        assert_eq!(
+            status,
+            WorktreeStatus {
+                modified: 3,
+                staged: 5,
+                untracked: 2,
+                conflicted: 1,
+            }
+        );
+        assert!(status.has_tracked_changes());
+        assert_eq!(
+            status.summary(),
+            "1 conflicted, 3 modified, 5 staged, 2 untracked"
+        );
+    }
+
+    #[test]
+    fn test_untracked_only_and_clean() {
+        let status = WorktreeStatus::parse("? build/\n? out.log\n");
+        assert!(status.is_untracked_only());
+        assert!(!status.is_clean());
+        assert_eq!(status.summary(), "2 untracked");
+
+        let clean = WorktreeStatus::parse("# branch.head main\n");
+        assert!(clean.is_clean());
+        assert!(!clean.is_untracked_only());
+        assert_eq!(clean.summary(), "clean");
+    }
+
+    #[test]
+    fn test_staged_split_lists_paths() {
+        let output = "\
+# branch.head para/auth
+1 .M N... 100644 100644 100644 aaaaaaa aaaaaaa src/lib.rs
+1 M. N... 100644 100644 100644 aaaaaaa bbbbbbb src/main file.rs
+1 MM N... 100644 100644 100644 aaaaaaa bbbbbbb README.md
+2 R. N... 100644 100644 100644 aaaaaaa aaaaaaa R100 src/renamed.rs\tsrc/old.rs
+u UU N... 100644 100644 100644 100644 aaaaaaa bbbbbbb ccccccc src/conflict.rs
+? scratch.txt
+! ignored.log
+";
+        assert_eq!(
+            StagedSplit::parse(output),
+            StagedSplit {
+                staged: vec![
+                    "src/main file.rs".to_string(),
+                    "README.md".to_string(),
+                    "src/renamed.rs".to_string(),
+                ],
+                left_behind: vec![
+                    "src/lib.rs".to_string(),
+                    "README.md".to_string(),
+                    "src/conflict.rs".to_string(),
+                    "scratch.txt".to_string(),
+                ],
+            }
+        );
+    }
+}
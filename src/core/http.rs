@@ -0,0 +1,83 @@
+//! HTTP requests for the few commands that talk to a web API
+//!
+//! Requests run through `curl` rather than an HTTP library, the same way git
+//! and docker are driven through their CLIs. The transport is a trait so tests
+//! can answer requests without a network.
+
+use crate::utils::{ParaError, Result};
+use serde::de::DeserializeOwned;
+use std::process::Command;
+
+/// Sends GET requests and returns the response body
+pub trait HttpTransport {
+    fn get(&self, url: &str) -> Result<Vec<u8>>;
+}
+
+/// [`HttpTransport`] backed by the `curl` command
+pub struct CurlTransport {
+    pub timeout_secs: u64,
+}
+
+impl Default for CurlTransport {
+    fn default() -> Self {
+        Self { timeout_secs: 30 }
+    }
+}
+
+impl HttpTransport for CurlTransport {
+    fn get(&self, url: &str) -> Result<Vec<u8>> {
+        let output = Command::new("curl")
+            .args(["--fail", "--silent", "--show-error", "--location"])
+            .args(["--max-time", &self.timeout_secs.to_string()])
+            .args(["--header", "Accept: application/vnd.github+json"])
+            .args(["--user-agent", concat!("para/", env!("CARGO_PKG_VERSION"))])
+            .arg(url)
+            .output()
+            .map_err(|e| ParaError::network_error(format!("Failed to run curl: {e}")))?;
+
+        if !output.status.success() {
+            return Err(ParaError::network_error(format!(
+                "GET {url}: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+        Ok(output.stdout)
+    }
+}
+
+pub struct HttpClient {
+    transport: Box<dyn HttpTransport>,
+}
+
+impl HttpClient {
+    pub fn new(transport: impl HttpTransport + 'static) -> Self {
+        Self {
+            transport: Box::new(transport),
+        }
+    }
+
+    /// The response body
+    pub fn get(&self, url: &str) -> Result<Vec<u8>> {
+        self.transport.get(url)
+    }
+
+    pub fn get_json<T: DeserializeOwned>(&self, url: &str) -> Result<T> {
+        let body = self.transport.get(url)?;
+        serde_json::from_slice(&body)
+            .map_err(|e| ParaError::network_error(format!("Unexpected response from {url}: {e}")))
+    }
+}
+
+/// Answers requests from a fixed table of URLs; anything else is a 404
+#[cfg(test)]
+pub struct MockTransport(pub std::collections::HashMap<String, Vec<u8>>);
+
+#[cfg(test)]
+impl HttpTransport for MockTransport {
+    fn get(&self, url: &str) -> Result<Vec<u8>> {
+        self.0
+            .get(url)
+            .cloned()
+            .ok_or_else(|| ParaError::network_error(format!("GET {url}: 404")))
+    }
+}
@@ -1,13 +1,167 @@
-use crate::config::{Config, IdeConfig};
+use crate::config::{Config, IdeConfig, RemoteConfig};
 use crate::core::sandbox::launcher::{
     generate_network_sandbox_wrapper, is_sandbox_available, wrap_command_with_sandbox,
     SandboxOptions,
 };
 use crate::core::sandbox::proxy::DEFAULT_PROXY_PORT;
-use crate::utils::{ParaError, Result};
+use crate::platform::{get_platform_manager, PlatformManager};
+use crate::utils::{shell, ParaError, Result};
 use std::fs;
 use std::path::Path;
 use std::process::Command;
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+pub mod launch_tasks;
+pub mod workspace;
+
+pub use workspace::is_workspace_open;
+
+/// Launch behaviour of a supported wrapper IDE
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IdeCapabilities {
+    /// Wrapper name as configured in `ide.wrapper.name`
+    pub name: &'static str,
+    /// Name used in user-facing messages
+    pub display_name: &'static str,
+    /// Application process name used when looking up windows
+    pub app_name: &'static str,
+    /// Flag that forces the worktree into a fresh window instead of reusing one
+    pub new_window_flag: Option<&'static str>,
+    /// Flag that opens the worktree through a remote authority such as `ssh-remote+host`
+    pub remote_flag: Option<&'static str>,
+}
+
+const IDE_CAPABILITIES: &[IdeCapabilities] = &[
+    IdeCapabilities {
+        name: "cursor",
+        display_name: "Cursor",
+        app_name: "Cursor",
+        new_window_flag: Some("--new-window"),
+        remote_flag: Some("--remote"),
+    },
+    IdeCapabilities {
+        name: "code",
+        display_name: "VS Code",
+        app_name: "Code",
+        new_window_flag: Some("--new-window"),
+        remote_flag: Some("--remote"),
+    },
+];
+
+/// Look up the launch capabilities for a wrapper IDE by name
+pub fn ide_capabilities(name: &str) -> Option<&'static IdeCapabilities> {
+    IDE_CAPABILITIES.iter().find(|caps| caps.name == name)
+}
+
+/// Arguments passed to the wrapper CLI to open a worktree. With a remote
+/// configured, the path is the one on the remote host.
+pub fn wrapper_launch_args(
+    wrapper_name: &str,
+    path: &Path,
+    remote: Option<&RemoteConfig>,
+) -> Vec<String> {
+    let capabilities = ide_capabilities(wrapper_name);
+    let mut args = Vec::new();
+    if let Some(flag) = capabilities.and_then(|caps| caps.new_window_flag) {
+        args.push(flag.to_string());
+    }
+    if let (Some(remote), Some(flag)) = (remote, capabilities.and_then(|caps| caps.remote_flag)) {
+        args.push(flag.to_string());
+        args.push(remote.authority());
+    }
+    args.push(path.to_string_lossy().to_string());
+    args
+}
+
+/// Container sessions run the agent in Docker, which remote IDE mode can't reach yet
+pub fn ensure_remote_supports_container(config: &Config, container: bool) -> Result<()> {
+    match &config.ide.remote {
+        Some(remote) if container => Err(ParaError::invalid_args(format!(
+            "Remote IDE mode (ide.remote, host '{}') does not support container sessions yet. \
+             Start the session without --container or remove ide.remote from the config.",
+            remote.host
+        ))),
+        _ => Ok(()),
+    }
+}
+
+const WINDOW_CHECK_ATTEMPTS: u32 = 10;
+const WINDOW_CHECK_INTERVAL: Duration = Duration::from_millis(500);
+const WORKTREE_WARNING_HEADER: &str = "## ⚠️ Working Directory";
+
+/// Outcome of checking that the IDE opened a window for the worktree
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindowVerification {
+    Found,
+    Missing,
+    /// Window lookup is not available on this platform
+    Unknown,
+}
+
+/// Poll the platform manager until a window whose title contains the
+/// worktree folder name shows up, or the attempts run out.
+pub fn verify_worktree_window(
+    platform: &dyn PlatformManager,
+    capabilities: &IdeCapabilities,
+    path: &Path,
+    attempts: u32,
+    interval: Duration,
+) -> WindowVerification {
+    let Some(folder_name) = path.file_name().map(|n| n.to_string_lossy().to_string()) else {
+        return WindowVerification::Unknown;
+    };
+
+    for attempt in 0..attempts {
+        match platform.find_ide_windows(capabilities.app_name, &folder_name) {
+            Ok(Some(windows)) if !windows.is_empty() => return WindowVerification::Found,
+            Ok(Some(_)) => {}
+            Ok(None) | Err(_) => return WindowVerification::Unknown,
+        }
+
+        if attempt + 1 < attempts {
+            thread::sleep(interval);
+        }
+    }
+
+    WindowVerification::Missing
+}
+
+/// Warn that the worktree window could not be confirmed and record the
+/// expected working directory in the worktree's CLAUDE.local.md.
+pub fn warn_unverified_window(capabilities: &IdeCapabilities, path: &Path) -> Result<()> {
+    eprintln!();
+    eprintln!(
+        "⚠️  WARNING: Could not confirm that {} opened a new window for this session.",
+        capabilities.display_name
+    );
+    eprintln!("   The IDE may have reused an existing window for the main repository.");
+    eprintln!("   The agent must work in: {}", path.display());
+    eprintln!();
+
+    let claude_local_path = path.join("CLAUDE.local.md");
+    let mut content = fs::read_to_string(&claude_local_path).unwrap_or_default();
+    if content.contains(WORKTREE_WARNING_HEADER) {
+        return Ok(());
+    }
+
+    if !content.is_empty() && !content.ends_with('\n') {
+        content.push('\n');
+    }
+    content.push_str(&format!(
+        "\n{WORKTREE_WARNING_HEADER}\n\n\
+         Para could not confirm that {} opened this worktree in its own window.\n\
+         All edits and commands for this session MUST happen inside:\n\n\
+         `{}`\n\n\
+         Do not modify files in the main repository checkout.\n",
+        capabilities.display_name,
+        path.display()
+    ));
+
+    fs::write(&claude_local_path, content)
+        .map_err(|e| ParaError::fs_error(format!("Failed to write CLAUDE.local.md: {e}")))
+}
 
 /// Options for launching IDE with specific features
 #[derive(Debug, Clone, Default)]
@@ -25,6 +179,8 @@ pub struct LaunchOptions {
 pub struct IdeManager {
     ide_config: IdeConfig,
     sandbox_config: Option<crate::core::sandbox::SandboxConfig>,
+    /// Looks up the window the wrapper opened for the worktree
+    platform: Arc<dyn PlatformManager>,
 }
 
 impl IdeManager {
@@ -32,9 +188,16 @@ impl IdeManager {
         Self {
             ide_config: config.ide.clone(),
             sandbox_config: config.sandbox.clone(),
+            platform: Arc::from(get_platform_manager()),
         }
     }
 
+    #[cfg(test)]
+    fn with_platform(mut self, platform: Arc<dyn PlatformManager>) -> Self {
+        self.platform = platform;
+        self
+    }
+
     pub fn launch(&self, path: &Path, skip_permissions: bool) -> Result<()> {
         let options = LaunchOptions {
             skip_permissions,
@@ -60,7 +223,7 @@ impl IdeManager {
 
     fn is_wrapper_test_mode(&self) -> bool {
         let wrapper_cmd = &self.ide_config.wrapper.command;
-        wrapper_cmd == "true" || wrapper_cmd.starts_with("echo ")
+        matches!(wrapper_cmd.as_str(), "true" | "echo") || wrapper_cmd.starts_with("echo ")
     }
 
     fn launch_wrapper_with_options(&self, path: &Path, options: LaunchOptions) -> Result<()> {
@@ -95,7 +258,7 @@ impl IdeManager {
         if wrapper_cmd.starts_with("echo ") {
             let mut cmd = Command::new("sh");
             cmd.arg("-c")
-                .arg(format!("{} \"{}\"", wrapper_cmd, path.display()));
+                .arg(format!("{wrapper_cmd} {}", shell::quote_path(path)));
             cmd.output().map_err(|e| {
                 ParaError::ide_error(format!("Failed to run wrapper test stub: {e}"))
             })?;
@@ -113,7 +276,11 @@ impl IdeManager {
             self.ide_config.name
         );
         let mut cmd = Command::new(wrapper_cmd);
-        cmd.arg(path.to_string_lossy().as_ref());
+        cmd.args(wrapper_launch_args(
+            "cursor",
+            path,
+            self.ide_config.remote.as_ref(),
+        ));
 
         // Detach the IDE process from the parent by redirecting stdio
         cmd.stdin(std::process::Stdio::null());
@@ -127,6 +294,7 @@ impl IdeManager {
             self.ide_config.name
         );
 
+        self.check_worktree_window("cursor", path);
         Ok(())
     }
 
@@ -151,7 +319,7 @@ impl IdeManager {
         if wrapper_cmd.starts_with("echo ") {
             let mut cmd = Command::new("sh");
             cmd.arg("-c")
-                .arg(format!("{} \"{}\"", wrapper_cmd, path.display()));
+                .arg(format!("{wrapper_cmd} {}", shell::quote_path(path)));
             cmd.output().map_err(|e| {
                 ParaError::ide_error(format!("Failed to run wrapper test stub: {e}"))
             })?;
@@ -165,7 +333,11 @@ impl IdeManager {
         }
 
         let mut cmd = Command::new(&self.ide_config.wrapper.command);
-        cmd.arg(path.to_string_lossy().as_ref());
+        cmd.args(wrapper_launch_args(
+            "code",
+            path,
+            self.ide_config.remote.as_ref(),
+        ));
 
         // Detach the IDE process from the parent by redirecting stdio
         cmd.stdin(std::process::Stdio::null());
@@ -183,9 +355,38 @@ impl IdeManager {
             self.ide_config.name
         );
 
+        self.check_worktree_window("code", path);
         Ok(())
     }
 
+    /// Warn if no window for `path` shows up. The check runs in the
+    /// background so the launch does not wait for the IDE; a para process
+    /// that exits before it is done skips the warning.
+    fn check_worktree_window(&self, wrapper_name: &str, path: &Path) -> Option<JoinHandle<()>> {
+        let capabilities = ide_capabilities(wrapper_name)?;
+        // A remote window opens on another machine, out of reach of the lookup
+        if self.ide_config.remote.is_some() || self.is_wrapper_test_mode() {
+            return None;
+        }
+
+        let platform = Arc::clone(&self.platform);
+        let path = path.to_path_buf();
+        Some(thread::spawn(move || {
+            let verification = verify_worktree_window(
+                platform.as_ref(),
+                capabilities,
+                &path,
+                WINDOW_CHECK_ATTEMPTS,
+                WINDOW_CHECK_INTERVAL,
+            );
+            if verification == WindowVerification::Missing {
+                if let Err(e) = warn_unverified_window(capabilities, &path) {
+                    eprintln!("Warning: {e}");
+                }
+            }
+        }))
+    }
+
     fn write_autorun_task_with_options(&self, path: &Path, options: &LaunchOptions) -> Result<()> {
         let vscode_dir = path.join(".vscode");
         fs::create_dir_all(&vscode_dir).map_err(|e| {
@@ -204,6 +405,13 @@ impl IdeManager {
             docker: temp_config.docker,
             setup_script: temp_config.setup_script,
             sandbox: self.sandbox_config.clone(),
+            notifications: None,
+            security: None,
+            status: None,
+            templates: Default::default(),
+            cleanup: None,
+            vcs: None,
+            ui: None,
         });
 
         let settings = resolver.resolve_with_network(
@@ -383,6 +591,7 @@ impl IdeManager {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_utils::mock_platform::MockPlatformManager;
     use tempfile::TempDir;
 
     fn create_test_config(ide_name: &str, ide_command: &str) -> Config {
@@ -396,24 +605,52 @@ mod tests {
                     name: String::new(),
                     command: String::new(),
                 },
+                remote: None,
             },
             directories: crate::config::DirectoryConfig {
                 subtrees_dir: "subtrees".to_string(),
                 state_dir: ".para_state".to_string(),
+                bare_root: None,
+                layout: Default::default(),
             },
             git: crate::config::GitConfig {
                 branch_prefix: "test".to_string(),
                 auto_stage: true,
                 auto_commit: false,
+                author_email: None,
+                author_name: None,
+                protected_branches: Vec::new(),
+                auto_commit_interval_minutes: None,
+                default_merge_mode: None,
+                command_timeout_secs: None,
+                audit_log: false,
+                default_remote: None,
+                auto_fetch_interval_minutes: None,
+                auto_fetch: false,
+                apply_whitespace: Default::default(),
+                behind_warning_commits: None,
             },
             session: crate::config::SessionConfig {
                 default_name_format: "%Y%m%d-%H%M%S".to_string(),
                 preserve_on_finish: false,
                 auto_cleanup_days: Some(7),
+                retention: None,
+                copy_untracked: Vec::new(),
+                idle_action: Default::default(),
+                idle_alert_hours: None,
+                conflict_hotspots: false,
+                on_resume: Vec::new(),
             },
             docker: None,
             setup_script: None,
             sandbox: None,
+            notifications: None,
+            security: None,
+            status: None,
+            templates: Default::default(),
+            cleanup: None,
+            vcs: None,
+            ui: None,
         }
     }
 
@@ -639,4 +876,148 @@ mod tests {
         assert_eq!(cmd, "claude -r \"12345678-1234-1234-1234-123456789012\"");
         assert!(!cmd.contains(" -c")); // Should not contain -c flag
     }
+
+    #[test]
+    fn test_new_window_flag_per_ide() {
+        let path = Path::new("/tmp/worktrees/para-feature");
+
+        assert_eq!(
+            wrapper_launch_args("cursor", path, None),
+            vec!["--new-window", "/tmp/worktrees/para-feature"]
+        );
+        assert_eq!(
+            wrapper_launch_args("code", path, None),
+            vec!["--new-window", "/tmp/worktrees/para-feature"]
+        );
+
+        // Unknown IDEs get no extra flags
+        assert!(ide_capabilities("claude").is_none());
+        assert_eq!(
+            wrapper_launch_args("unknown-ide", path, None),
+            vec!["/tmp/worktrees/para-feature"]
+        );
+    }
+
+    #[test]
+    fn test_remote_launch_args() {
+        let path = Path::new("/home/dev/repo/.para/worktrees/feature");
+        let remote = RemoteConfig {
+            host: "devbox".to_string(),
+            mode: Default::default(),
+        };
+
+        for wrapper in ["code", "cursor"] {
+            assert_eq!(
+                wrapper_launch_args(wrapper, path, Some(&remote)),
+                vec![
+                    "--new-window",
+                    "--remote",
+                    "ssh-remote+devbox",
+                    "/home/dev/repo/.para/worktrees/feature"
+                ]
+            );
+        }
+
+        // IDEs without remote support open the path as is
+        assert_eq!(
+            wrapper_launch_args("unknown-ide", path, Some(&remote)),
+            vec!["/home/dev/repo/.para/worktrees/feature"]
+        );
+    }
+
+    #[test]
+    fn test_remote_mode_rejects_container_sessions() {
+        let mut config = create_test_config("claude", "claude");
+        assert!(ensure_remote_supports_container(&config, true).is_ok());
+
+        config.ide.remote = Some(RemoteConfig {
+            host: "devbox".to_string(),
+            mode: Default::default(),
+        });
+        assert!(ensure_remote_supports_container(&config, false).is_ok());
+        let err = ensure_remote_supports_container(&config, true).unwrap_err();
+        assert!(err.to_string().contains("--container"));
+    }
+
+    #[test]
+    fn test_verify_worktree_window_found() {
+        let platform = MockPlatformManager::with_windows(vec![
+            "main.rs — para".to_string(),
+            "lib.rs — para-feature".to_string(),
+        ]);
+        let caps = ide_capabilities("cursor").unwrap();
+
+        let result = verify_worktree_window(
+            &platform,
+            caps,
+            Path::new("/tmp/worktrees/para-feature"),
+            3,
+            Duration::ZERO,
+        );
+        assert_eq!(result, WindowVerification::Found);
+
+        // Platforms without window lookup cannot verify anything
+        let result = verify_worktree_window(
+            &MockPlatformManager::new(),
+            caps,
+            Path::new("/tmp/worktrees/para-feature"),
+            3,
+            Duration::ZERO,
+        );
+        assert_eq!(result, WindowVerification::Unknown);
+    }
+
+    #[test]
+    fn test_missing_window_writes_warning_to_claude_local_md() {
+        let temp_dir = TempDir::new().unwrap();
+        let worktree = temp_dir.path().join("para-feature");
+        fs::create_dir_all(&worktree).unwrap();
+        fs::write(
+            worktree.join("CLAUDE.local.md"),
+            "# Existing instructions\n",
+        )
+        .unwrap();
+
+        let platform = MockPlatformManager::with_windows(vec![]);
+        let caps = ide_capabilities("code").unwrap();
+
+        let result = verify_worktree_window(&platform, caps, &worktree, 3, Duration::ZERO);
+        assert_eq!(result, WindowVerification::Missing);
+
+        warn_unverified_window(caps, &worktree).unwrap();
+        warn_unverified_window(caps, &worktree).unwrap();
+
+        let content = fs::read_to_string(worktree.join("CLAUDE.local.md")).unwrap();
+        assert!(content.starts_with("# Existing instructions"));
+        assert!(content.contains(&worktree.display().to_string()));
+        assert!(content.contains("VS Code"));
+        assert_eq!(content.matches(WORKTREE_WARNING_HEADER).count(), 1);
+    }
+
+    #[test]
+    fn test_worktree_window_check_uses_the_injected_platform() {
+        let temp_dir = TempDir::new().unwrap();
+        let worktree = temp_dir.path().join("para-feature");
+        fs::create_dir_all(&worktree).unwrap();
+        let mut config = create_test_config("claude", "claude");
+        config.ide.wrapper.enabled = true;
+        config.ide.wrapper.name = "code".to_string();
+        config.ide.wrapper.command = "code".to_string();
+        let platform = Arc::new(MockPlatformManager::with_windows(vec![
+            "lib.rs — para-feature".to_string(),
+        ]));
+
+        let manager = IdeManager::new(&config).with_platform(platform);
+        manager
+            .check_worktree_window("code", &worktree)
+            .expect("the check runs for a real wrapper")
+            .join()
+            .unwrap();
+        assert!(!worktree.join("CLAUDE.local.md").exists());
+
+        // Test stubs open no window, so there is nothing to look for
+        config.ide.wrapper.command = "echo".to_string();
+        let manager = IdeManager::new(&config).with_platform(Arc::new(MockPlatformManager::new()));
+        assert!(manager.check_worktree_window("code", &worktree).is_none());
+    }
 }
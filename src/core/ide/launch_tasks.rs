@@ -0,0 +1,936 @@
+//! Tasks that start Claude when the wrapper IDE opens a worktree
+//!
+//! VS Code and Cursor run a `.vscode/tasks.json` task on folder open.
+//! JetBrains IDEs have no such hook, so they get a shell run configuration
+//! in `.run/para-claude.run.xml` running the same command instead. The
+//! command is built from [`ClaudeLaunchOptions`], and resuming a session
+//! rewrites it to continue the last conversation.
+
+use crate::config::Config;
+use crate::core::claude_launcher::ClaudeLaunchOptions;
+use crate::utils::{shell, ParaError, Result};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Name of the task and run configuration
+pub const TASK_LABEL: &str = "Start claude";
+
+/// Wrapper names of the JetBrains IDEs
+const JETBRAINS_IDES: &[&str] = &[
+    "idea",
+    "intellij",
+    "pycharm",
+    "webstorm",
+    "goland",
+    "clion",
+    "rider",
+    "rustrover",
+    "phpstorm",
+    "rubymine",
+    "datagrip",
+    "jetbrains",
+];
+
+/// Environment for a colored Claude terminal
+const TERMINAL_ENV: &[(&str, &str)] = &[
+    ("FORCE_COLOR", "1"),
+    ("COLORTERM", "truecolor"),
+    ("TERM", "xterm-256color"),
+];
+
+/// How the wrapper IDE is told to start Claude
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LaunchBackend {
+    /// `.vscode/tasks.json` task that runs on folder open
+    VsCodeTasks,
+    /// `.run/para-claude.run.xml` shell run configuration
+    JetBrainsRunConfig,
+}
+
+impl LaunchBackend {
+    pub fn for_ide(name: &str) -> Self {
+        if JETBRAINS_IDES.contains(&name.to_lowercase().as_str()) {
+            Self::JetBrainsRunConfig
+        } else {
+            Self::VsCodeTasks
+        }
+    }
+
+    /// Backend of the IDE that hosts Claude: the wrapper in wrapper mode
+    pub fn for_config(config: &Config) -> Self {
+        if config.is_wrapper_enabled() {
+            Self::for_ide(&config.ide.wrapper.name)
+        } else {
+            Self::for_ide(&config.ide.name)
+        }
+    }
+
+    /// Directory of the launch file, relative to the worktree
+    pub fn dir(self) -> &'static str {
+        match self {
+            Self::VsCodeTasks => ".vscode",
+            Self::JetBrainsRunConfig => ".run",
+        }
+    }
+
+    /// Launch file relative to the worktree, also used as its gitignore entry
+    pub fn relative_path(self) -> &'static str {
+        match self {
+            Self::VsCodeTasks => ".vscode/tasks.json",
+            Self::JetBrainsRunConfig => ".run/para-claude.run.xml",
+        }
+    }
+
+    pub fn path(self, worktree: &Path) -> PathBuf {
+        worktree.join(self.relative_path())
+    }
+
+    /// Write the launch file running `command`, replacing an earlier one
+    pub fn write(self, worktree: &Path, command: &str) -> Result<PathBuf> {
+        let path = self.path(worktree);
+        let content = match self {
+            Self::VsCodeTasks => TasksFile::claude(command).to_json()?,
+            Self::JetBrainsRunConfig => RunConfiguration::claude(command).to_xml(),
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| ParaError::from_io("create", dir, e))?;
+        }
+        fs::write(&path, content).map_err(|e| ParaError::from_io("write", &path, e))?;
+        Ok(path)
+    }
+
+    /// Make an existing launch file continue the last conversation instead
+    /// of sending its prompt again. Running it twice changes nothing more.
+    pub fn transform_for_resume(self, worktree: &Path) -> Result<()> {
+        let path = self.path(worktree);
+        if !path.exists() {
+            return Ok(());
+        }
+
+        match self {
+            Self::VsCodeTasks => {
+                let mut tasks = TasksFile::load(&path)?;
+                let transformation =
+                    determine_transformation(&detect_task_configuration(tasks.commands()));
+                if tasks.apply(&transformation) {
+                    tasks.save(&path)?;
+                }
+            }
+            Self::JetBrainsRunConfig => {
+                let content =
+                    fs::read_to_string(&path).map_err(|e| ParaError::from_io("read", &path, e))?;
+                let mut run_config = RunConfiguration::from_xml(&content)?;
+                let current = [run_config.command.as_str()];
+                let transformation = determine_transformation(&detect_task_configuration(current));
+                let command = transformation.apply(&run_config.command);
+                if command != run_config.command {
+                    run_config.command = command;
+                    fs::write(&path, run_config.to_xml())
+                        .map_err(|e| ParaError::from_io("write", &path, e))?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The Claude command for `options`. A prompt is read from `prompt_file`,
+/// which the command deletes once read.
+pub fn claude_command(
+    base_command: &str,
+    options: &ClaudeLaunchOptions,
+    prompt_file: Option<&Path>,
+) -> String {
+    let mut command = base_command.to_string();
+    if options.skip_permissions {
+        command.push_str(" --dangerously-skip-permissions");
+    }
+
+    match options.session_id.as_deref() {
+        Some(session_id) if !session_id.is_empty() => {
+            command.push_str(&format!(" -r \"{session_id}\""))
+        }
+        // An empty session ID falls back to continuing the last conversation
+        Some(_) => command.push_str(" -c"),
+        None if options.continue_conversation => command.push_str(" -c"),
+        None => {}
+    }
+
+    if let Some(prompt_file) = prompt_file {
+        let prompt_file = shell::quote_path(prompt_file);
+        command.push_str(&format!(" \"$(cat {prompt_file}; rm {prompt_file})\""));
+    }
+    command
+}
+
+/// `.vscode/tasks.json`. Fields para does not know are kept as they are.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TasksFile {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tasks: Vec<Task>,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Task {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub command: Option<TaskCommand>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub options: Option<TaskOptions>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub presentation: Option<Presentation>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub run_options: Option<RunOptions>,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+/// A task's command: a shell command line, or another form such as an
+/// argument array, which para leaves alone
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum TaskCommand {
+    Line(String),
+    Other(Value),
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TaskOptions {
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub env: BTreeMap<String, String>,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Presentation {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub echo: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reveal: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub focus: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub panel: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub show_reuse_message: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub clear: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunOptions {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub run_on: Option<String>,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+impl TasksFile {
+    /// A single task running `command` in a new terminal on folder open
+    pub fn claude(command: &str) -> Self {
+        Self {
+            version: Some("2.0.0".to_string()),
+            tasks: vec![Task {
+                label: Some(TASK_LABEL.to_string()),
+                kind: Some("shell".to_string()),
+                command: Some(TaskCommand::Line(command.to_string())),
+                group: Some(Value::String("build".to_string())),
+                options: Some(TaskOptions {
+                    env: terminal_env(),
+                    other: Map::new(),
+                }),
+                presentation: Some(Presentation {
+                    echo: Some(true),
+                    reveal: Some("always".to_string()),
+                    focus: Some(true),
+                    panel: Some("new".to_string()),
+                    show_reuse_message: Some(false),
+                    clear: Some(false),
+                }),
+                run_options: Some(RunOptions {
+                    run_on: Some("folderOpen".to_string()),
+                    other: Map::new(),
+                }),
+                other: Map::new(),
+            }],
+            other: Map::new(),
+        }
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path).map_err(|e| ParaError::from_io("read", path, e))?;
+        serde_json::from_str(&content)
+            .map_err(|e| ParaError::fs_error(format!("Failed to parse {}: {e}", path.display())))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, self.to_json()?).map_err(|e| ParaError::from_io("write", path, e))
+    }
+
+    /// Pretty JSON indented by four spaces, like VS Code writes it
+    pub fn to_json(&self) -> Result<String> {
+        let mut buffer = Vec::new();
+        let formatter = serde_json::ser::PrettyFormatter::with_indent(b"    ");
+        let mut serializer = serde_json::Serializer::with_formatter(&mut buffer, formatter);
+        self.serialize(&mut serializer)?;
+        String::from_utf8(buffer)
+            .map_err(|e| ParaError::fs_error(format!("Failed to serialize tasks.json: {e}")))
+    }
+
+    /// Command lines of all tasks
+    pub fn commands(&self) -> impl Iterator<Item = &str> {
+        self.tasks.iter().filter_map(|task| match &task.command {
+            Some(TaskCommand::Line(command)) => Some(command.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Apply `transformation` to every command line; returns whether any changed
+    pub fn apply(&mut self, transformation: &TaskTransformation) -> bool {
+        let mut changed = false;
+        for task in &mut self.tasks {
+            if let Some(TaskCommand::Line(command)) = &mut task.command {
+                let updated = transformation.apply(command);
+                if updated != *command {
+                    *command = updated;
+                    changed = true;
+                }
+            }
+        }
+        changed
+    }
+}
+
+fn terminal_env() -> BTreeMap<String, String> {
+    TERMINAL_ENV
+        .iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect()
+}
+
+/// A JetBrains shell run configuration that runs `command` in the terminal
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunConfiguration {
+    pub name: String,
+    pub command: String,
+    pub env: BTreeMap<String, String>,
+}
+
+impl RunConfiguration {
+    pub fn claude(command: &str) -> Self {
+        Self {
+            name: TASK_LABEL.to_string(),
+            command: command.to_string(),
+            env: terminal_env(),
+        }
+    }
+
+    pub fn to_xml(&self) -> String {
+        let envs: String = self
+            .env
+            .iter()
+            .map(|(name, value)| {
+                format!(
+                    "      <env name=\"{}\" value=\"{}\" />\n",
+                    xml_escape(name),
+                    xml_escape(value)
+                )
+            })
+            .collect();
+        format!(
+            r#"<component name="ProjectRunConfigurationManager">
+  <configuration default="false" name="{name}" type="ShConfigurationType">
+    <option name="SCRIPT_TEXT" value="{command}" />
+    <option name="INDEPENDENT_SCRIPT_PATH" value="true" />
+    <option name="SCRIPT_PATH" value="" />
+    <option name="SCRIPT_OPTIONS" value="" />
+    <option name="INDEPENDENT_SCRIPT_WORKING_DIRECTORY" value="true" />
+    <option name="SCRIPT_WORKING_DIRECTORY" value="$PROJECT_DIR$" />
+    <option name="INDEPENDENT_INTERPRETER_PATH" value="true" />
+    <option name="INTERPRETER_PATH" value="/bin/sh" />
+    <option name="INTERPRETER_OPTIONS" value="" />
+    <option name="EXECUTE_IN_TERMINAL" value="true" />
+    <option name="EXECUTE_SCRIPT_FILE" value="false" />
+    <envs>
+{envs}    </envs>
+    <method v="2" />
+  </configuration>
+</component>
+"#,
+            name = xml_escape(&self.name),
+            command = xml_escape(&self.command),
+        )
+    }
+
+    /// Read back a run configuration written by [`RunConfiguration::to_xml`]
+    pub fn from_xml(xml: &str) -> Result<Self> {
+        let attribute = |pattern: &str| {
+            Regex::new(pattern)
+                .expect("valid pattern")
+                .captures(xml)
+                .map(|captures| xml_unescape(&captures[1]))
+        };
+        let invalid = || ParaError::fs_error("Run configuration has no SCRIPT_TEXT option");
+
+        let name = attribute(r#"<configuration[^>]*\sname="([^"]*)""#)
+            .unwrap_or_else(|| TASK_LABEL.to_string());
+        let command =
+            attribute(r#"<option name="SCRIPT_TEXT" value="([^"]*)""#).ok_or_else(invalid)?;
+        let env = Regex::new(r#"<env name="([^"]*)" value="([^"]*)""#)
+            .expect("valid pattern")
+            .captures_iter(xml)
+            .map(|captures| (xml_unescape(&captures[1]), xml_unescape(&captures[2])))
+            .collect();
+        Ok(Self { name, command, env })
+    }
+}
+
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('\n', "&#10;")
+}
+
+fn xml_unescape(text: &str) -> String {
+    text.replace("&#10;", "\n")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+/// What an existing launch command does, as far as resume is concerned
+#[derive(Debug, PartialEq)]
+pub enum TaskConfiguration {
+    HasPromptFile { has_skip_permissions: bool },
+    HasContinueFlag { has_skip_permissions: bool },
+    NeedsTransformation { has_skip_permissions: bool },
+}
+
+#[derive(Debug, PartialEq)]
+pub enum TaskTransformation {
+    RemovePromptFileAndAddContinue { has_skip_permissions: bool },
+    AddContinueFlag { has_skip_permissions: bool },
+    NoChange,
+}
+
+fn has_prompt_file(command: &str) -> bool {
+    command.contains(".claude_prompt_temp")
+        || (command.contains("$(cat") && command.contains("rm "))
+}
+
+pub fn detect_task_configuration<'a>(
+    commands: impl IntoIterator<Item = &'a str>,
+) -> TaskConfiguration {
+    let commands: Vec<&str> = commands.into_iter().collect();
+    let any = |check: &dyn Fn(&str) -> bool| commands.iter().any(|command| check(command));
+
+    let has_skip_permissions = any(&|command| command.contains("--dangerously-skip-permissions"));
+    if any(&has_prompt_file) {
+        TaskConfiguration::HasPromptFile {
+            has_skip_permissions,
+        }
+    } else if any(&|command| command.contains(" -c")) {
+        TaskConfiguration::HasContinueFlag {
+            has_skip_permissions,
+        }
+    } else {
+        TaskConfiguration::NeedsTransformation {
+            has_skip_permissions,
+        }
+    }
+}
+
+pub fn determine_transformation(config: &TaskConfiguration) -> TaskTransformation {
+    match config {
+        TaskConfiguration::HasPromptFile {
+            has_skip_permissions,
+        } => TaskTransformation::RemovePromptFileAndAddContinue {
+            has_skip_permissions: *has_skip_permissions,
+        },
+        TaskConfiguration::HasContinueFlag { .. } => TaskTransformation::NoChange,
+        TaskConfiguration::NeedsTransformation {
+            has_skip_permissions,
+        } => TaskTransformation::AddContinueFlag {
+            has_skip_permissions: *has_skip_permissions,
+        },
+    }
+}
+
+impl TaskTransformation {
+    /// The transformed command line; commands it does not apply to are returned as-is
+    pub fn apply(&self, command: &str) -> String {
+        match self {
+            Self::NoChange => command.to_string(),
+            Self::RemovePromptFileAndAddContinue {
+                has_skip_permissions,
+            } => {
+                if !has_prompt_file(command) {
+                    command.to_string()
+                } else if *has_skip_permissions {
+                    "claude --dangerously-skip-permissions -c".to_string()
+                } else {
+                    "claude -c".to_string()
+                }
+            }
+            Self::AddContinueFlag {
+                has_skip_permissions,
+            } => transform_claude_command(command, *has_skip_permissions),
+        }
+    }
+}
+
+/// Checks if a command needs the continue flag added
+fn needs_continue_flag(command: &str) -> bool {
+    !command.contains("-c")
+}
+
+/// Transforms a Claude command to include the continue flag
+fn transform_claude_command(command: &str, has_skip_permissions: bool) -> String {
+    if has_skip_permissions {
+        transform_claude_command_with_skip_permissions(command)
+    } else {
+        transform_claude_command_regular(command)
+    }
+}
+
+/// Transforms Claude commands with --dangerously-skip-permissions flag
+fn transform_claude_command_with_skip_permissions(command: &str) -> String {
+    if command.contains("claude --dangerously-skip-permissions") && needs_continue_flag(command) {
+        command.replace(
+            "claude --dangerously-skip-permissions",
+            "claude --dangerously-skip-permissions -c",
+        )
+    } else {
+        command.to_string()
+    }
+}
+
+/// Transforms regular Claude commands (without --dangerously-skip-permissions)
+fn transform_claude_command_regular(command: &str) -> String {
+    if command == "claude" {
+        "claude -c".to_string()
+    } else if command.starts_with("claude ") && needs_continue_flag(command) {
+        command.replace("claude ", "claude -c ")
+    } else {
+        command.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn tasks_with(commands: &[&str]) -> TasksFile {
+        TasksFile {
+            tasks: commands
+                .iter()
+                .map(|command| Task {
+                    command: Some(TaskCommand::Line(command.to_string())),
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    fn resumed(commands: &[&str]) -> Vec<String> {
+        let mut tasks = tasks_with(commands);
+        let transformation = determine_transformation(&detect_task_configuration(tasks.commands()));
+        tasks.apply(&transformation);
+        tasks.commands().map(str::to_string).collect()
+    }
+
+    #[test]
+    fn test_backend_is_selected_by_ide_name() {
+        assert_eq!(LaunchBackend::for_ide("code"), LaunchBackend::VsCodeTasks);
+        assert_eq!(LaunchBackend::for_ide("cursor"), LaunchBackend::VsCodeTasks);
+        assert_eq!(
+            LaunchBackend::for_ide("idea"),
+            LaunchBackend::JetBrainsRunConfig
+        );
+        assert_eq!(
+            LaunchBackend::for_ide("PyCharm"),
+            LaunchBackend::JetBrainsRunConfig
+        );
+    }
+
+    #[test]
+    fn test_claude_command_for_launch_options() {
+        let prompt = Path::new("/wt/.claude_prompt_temp");
+        let new_session = ClaudeLaunchOptions::default();
+        assert_eq!(claude_command("claude", &new_session, None), "claude");
+        assert_eq!(
+            claude_command("claude", &new_session, Some(prompt)),
+            "claude \"$(cat '/wt/.claude_prompt_temp'; rm '/wt/.claude_prompt_temp')\""
+        );
+
+        let resumed = ClaudeLaunchOptions {
+            skip_permissions: true,
+            session_id: Some("abc-123".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            claude_command("claude", &resumed, None),
+            "claude --dangerously-skip-permissions -r \"abc-123\""
+        );
+
+        let empty_id = ClaudeLaunchOptions {
+            session_id: Some(String::new()),
+            ..Default::default()
+        };
+        assert_eq!(claude_command("claude", &empty_id, None), "claude -c");
+
+        let continued = ClaudeLaunchOptions {
+            continue_conversation: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            claude_command("claude", &continued, Some(prompt)),
+            "claude -c \"$(cat '/wt/.claude_prompt_temp'; rm '/wt/.claude_prompt_temp')\""
+        );
+    }
+
+    #[test]
+    fn test_vscode_tasks_generation() {
+        let command = r#"claude -c "test with 'single' and "double" quotes""#;
+        let json = TasksFile::claude(command).to_json().unwrap();
+
+        assert!(json.starts_with("{\n    \"version\": \"2.0.0\",\n    \"tasks\": [\n"));
+        assert!(json.contains("            \"label\": \"Start claude\",\n"));
+        assert!(json.contains(r#""type": "shell""#));
+        assert!(json.contains(r#""group": "build""#));
+        assert!(json.contains(r#""FORCE_COLOR": "1""#));
+        assert!(json.contains(r#""COLORTERM": "truecolor""#));
+        assert!(json.contains(r#""TERM": "xterm-256color""#));
+        assert!(json.contains(r#""showReuseMessage": false"#));
+        assert!(json.contains(r#""runOn": "folderOpen""#));
+        assert!(json.contains(&format!(
+            "\"command\": \"{}\"",
+            command.replace('"', "\\\"")
+        )));
+
+        let parsed: TasksFile = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, TasksFile::claude(command));
+        assert_eq!(parsed.commands().collect::<Vec<_>>(), vec![command]);
+    }
+
+    #[test]
+    fn test_jetbrains_run_config_generation() {
+        let command = r#"claude "$(cat '/wt/.claude_prompt_temp'; rm '/wt/.claude_prompt_temp')" && echo <done>"#;
+        let xml = RunConfiguration::claude(command).to_xml();
+
+        assert!(xml.starts_with("<component name=\"ProjectRunConfigurationManager\">"));
+        assert!(xml.contains(r#"name="Start claude" type="ShConfigurationType""#));
+        assert!(xml.contains(r#"<option name="EXECUTE_IN_TERMINAL" value="true" />"#));
+        assert!(xml.contains(r#"<env name="FORCE_COLOR" value="1" />"#));
+        assert!(xml.contains("&quot;$(cat"));
+        assert!(xml.contains("&amp;&amp; echo &lt;done&gt;"));
+
+        assert_eq!(
+            RunConfiguration::from_xml(&xml).unwrap(),
+            RunConfiguration::claude(command)
+        );
+        assert!(RunConfiguration::from_xml("<component />").is_err());
+    }
+
+    #[test]
+    fn test_write_creates_launch_file_per_backend() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let path = LaunchBackend::VsCodeTasks
+            .write(temp_dir.path(), "claude")
+            .unwrap();
+        assert_eq!(path, temp_dir.path().join(".vscode/tasks.json"));
+        assert!(TasksFile::load(&path).is_ok());
+
+        let path = LaunchBackend::JetBrainsRunConfig
+            .write(temp_dir.path(), "claude")
+            .unwrap();
+        assert_eq!(path, temp_dir.path().join(".run/para-claude.run.xml"));
+        let xml = fs::read_to_string(path).unwrap();
+        assert_eq!(RunConfiguration::from_xml(&xml).unwrap().command, "claude");
+    }
+
+    #[test]
+    fn test_resume_transformation_is_idempotent_for_both_backends() {
+        let temp_dir = TempDir::new().unwrap();
+        let command = "claude --dangerously-skip-permissions \"$(cat '/wt/.claude_prompt_temp'; rm '/wt/.claude_prompt_temp')\"";
+
+        for backend in [
+            LaunchBackend::VsCodeTasks,
+            LaunchBackend::JetBrainsRunConfig,
+        ] {
+            let path = backend.write(temp_dir.path(), command).unwrap();
+            backend.transform_for_resume(temp_dir.path()).unwrap();
+            let once = fs::read_to_string(&path).unwrap();
+            assert!(once.contains("claude --dangerously-skip-permissions -c"));
+            assert!(!once.contains(".claude_prompt_temp"));
+
+            backend.transform_for_resume(temp_dir.path()).unwrap();
+            assert_eq!(fs::read_to_string(&path).unwrap(), once);
+        }
+
+        // Nothing to do without a launch file
+        let empty = TempDir::new().unwrap();
+        assert!(LaunchBackend::VsCodeTasks
+            .transform_for_resume(empty.path())
+            .is_ok());
+        assert!(!LaunchBackend::VsCodeTasks.path(empty.path()).exists());
+    }
+
+    #[test]
+    fn test_detect_task_configuration() {
+        assert_eq!(
+            detect_task_configuration([
+                "claude --dangerously-skip-permissions \"$(cat '/path/.claude_prompt_temp'; rm '/path/.claude_prompt_temp')\""
+            ]),
+            TaskConfiguration::HasPromptFile {
+                has_skip_permissions: true
+            }
+        );
+        assert_eq!(
+            detect_task_configuration([
+                "claude \"$(cat '/path/to/prompt'; rm '/path/to/prompt')\""
+            ]),
+            TaskConfiguration::HasPromptFile {
+                has_skip_permissions: false
+            }
+        );
+        assert_eq!(
+            detect_task_configuration(["claude --dangerously-skip-permissions -c"]),
+            TaskConfiguration::HasContinueFlag {
+                has_skip_permissions: true
+            }
+        );
+        assert_eq!(
+            detect_task_configuration(["claude -c"]),
+            TaskConfiguration::HasContinueFlag {
+                has_skip_permissions: false
+            }
+        );
+        assert_eq!(
+            detect_task_configuration(["claude --dangerously-skip-permissions"]),
+            TaskConfiguration::NeedsTransformation {
+                has_skip_permissions: true
+            }
+        );
+        assert_eq!(
+            detect_task_configuration(["claude"]),
+            TaskConfiguration::NeedsTransformation {
+                has_skip_permissions: false
+            }
+        );
+        assert_eq!(
+            detect_task_configuration([]),
+            TaskConfiguration::NeedsTransformation {
+                has_skip_permissions: false
+            }
+        );
+    }
+
+    #[test]
+    fn test_determine_transformation() {
+        assert_eq!(
+            determine_transformation(&TaskConfiguration::HasPromptFile {
+                has_skip_permissions: true,
+            }),
+            TaskTransformation::RemovePromptFileAndAddContinue {
+                has_skip_permissions: true
+            }
+        );
+        assert_eq!(
+            determine_transformation(&TaskConfiguration::HasContinueFlag {
+                has_skip_permissions: true,
+            }),
+            TaskTransformation::NoChange
+        );
+        assert_eq!(
+            determine_transformation(&TaskConfiguration::NeedsTransformation {
+                has_skip_permissions: false,
+            }),
+            TaskTransformation::AddContinueFlag {
+                has_skip_permissions: false
+            }
+        );
+    }
+
+    #[test]
+    fn test_remove_prompt_file_transformation() {
+        assert_eq!(
+            resumed(&[
+                "claude --dangerously-skip-permissions \"$(cat '/tmp/.claude_prompt_temp'; rm '/tmp/.claude_prompt_temp')\"",
+                "echo hello",
+            ]),
+            vec!["claude --dangerously-skip-permissions -c", "echo hello"]
+        );
+        assert_eq!(
+            resumed(&["claude \"$(cat '/tmp/prompt'; rm '/tmp/prompt')\""]),
+            vec!["claude -c"]
+        );
+    }
+
+    #[test]
+    fn test_add_continue_flag_transformation() {
+        assert_eq!(
+            resumed(&[
+                "claude --dangerously-skip-permissions",
+                "claude --dangerously-skip-permissions some args",
+                "echo hello",
+            ]),
+            vec![
+                "claude --dangerously-skip-permissions -c",
+                "claude --dangerously-skip-permissions -c some args",
+                "echo hello",
+            ]
+        );
+        assert_eq!(
+            resumed(&["claude", "claude some args", ""]),
+            vec!["claude -c", "claude -c some args", ""]
+        );
+        assert_eq!(
+            resumed(&[
+                "claude --help",
+                "claude --verbose --other-flag",
+                "other-command"
+            ]),
+            vec![
+                "claude -c --help",
+                "claude -c --verbose --other-flag",
+                "other-command"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_add_continue_flag_with_skip_permissions_variants() {
+        let transformation = TaskTransformation::AddContinueFlag {
+            has_skip_permissions: true,
+        };
+        assert_eq!(
+            transformation.apply("claude --dangerously-skip-permissions --verbose"),
+            "claude --dangerously-skip-permissions -c --verbose"
+        );
+        assert_eq!(
+            transformation.apply("claude --dangerously-skip-permissions -c already-has"),
+            "claude --dangerously-skip-permissions -c already-has"
+        );
+        // Only the exact `claude --dangerously-skip-permissions` prefix is rewritten
+        assert_eq!(
+            transformation.apply("claude --other-flag --dangerously-skip-permissions"),
+            "claude --other-flag --dangerously-skip-permissions"
+        );
+        assert_eq!(transformation.apply("claude"), "claude");
+    }
+
+    #[test]
+    fn test_tasks_with_continue_flag_are_unchanged() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = LaunchBackend::VsCodeTasks.path(temp_dir.path());
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        let content = r#"{"tasks":[{"command":"claude -c"},{"command":"claude --dangerously-skip-permissions -c"}]}"#;
+        fs::write(&path, content).unwrap();
+
+        LaunchBackend::VsCodeTasks
+            .transform_for_resume(temp_dir.path())
+            .unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), content);
+    }
+
+    #[test]
+    fn test_unknown_task_fields_and_commands_survive() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = LaunchBackend::VsCodeTasks.path(temp_dir.path());
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(
+            &path,
+            r#"{
+  "version": "2.0.0",
+  "inputs": [],
+  "tasks": [
+    {"command": ["array", "command"], "problemMatcher": []},
+    {"label": "Claude", "command": "claude", "group": {"kind": "build", "isDefault": true}}
+  ]
+}"#,
+        )
+        .unwrap();
+
+        LaunchBackend::VsCodeTasks
+            .transform_for_resume(temp_dir.path())
+            .unwrap();
+        let tasks = TasksFile::load(&path).unwrap();
+        assert!(tasks.other.contains_key("inputs"));
+        assert_eq!(
+            tasks.tasks[0].command,
+            Some(TaskCommand::Other(serde_json::json!(["array", "command"])))
+        );
+        assert!(tasks.tasks[0].other.contains_key("problemMatcher"));
+        assert_eq!(tasks.commands().collect::<Vec<_>>(), vec!["claude -c"]);
+        assert_eq!(
+            tasks.tasks[1].group,
+            Some(serde_json::json!({"kind": "build", "isDefault": true}))
+        );
+    }
+
+    #[test]
+    fn test_malformed_or_missing_tasks() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = LaunchBackend::VsCodeTasks.path(temp_dir.path());
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+
+        fs::write(&path, r#"{ "tasks": [ invalid json }"#).unwrap();
+        assert!(LaunchBackend::VsCodeTasks
+            .transform_for_resume(temp_dir.path())
+            .is_err());
+
+        fs::write(&path, r#"{ "version": "2.0.0" }"#).unwrap();
+        assert!(LaunchBackend::VsCodeTasks
+            .transform_for_resume(temp_dir.path())
+            .is_ok());
+        assert_eq!(TasksFile::load(&path).unwrap().tasks, vec![]);
+
+        assert!(TasksFile::load(&temp_dir.path().join("missing.json")).is_err());
+    }
+
+    #[test]
+    fn test_command_helpers() {
+        assert!(needs_continue_flag("claude --verbose"));
+        assert!(!needs_continue_flag("claude -c --verbose"));
+
+        assert_eq!(transform_claude_command_regular("claude"), "claude -c");
+        assert_eq!(
+            transform_claude_command_regular("claude -c --verbose"),
+            "claude -c --verbose"
+        );
+        assert_eq!(transform_claude_command_regular("claudetest"), "claudetest");
+        assert_eq!(transform_claude_command_regular(""), "");
+
+        assert_eq!(
+            transform_claude_command("claude --dangerously-skip-permissions", true),
+            "claude --dangerously-skip-permissions -c"
+        );
+        assert_eq!(transform_claude_command("echo hello", true), "echo hello");
+        assert_eq!(transform_claude_command("echo hello", false), "echo hello");
+    }
+}
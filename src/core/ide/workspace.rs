@@ -0,0 +1,269 @@
+//! Detect IDE windows and other processes that still have a worktree open
+//!
+//! On macOS the IDE windows are matched by title through the platform
+//! manager. Elsewhere `lsof +D` lists the processes whose working directory or
+//! open files are under the worktree. Both checks are best-effort: when
+//! neither is available the worktree is assumed to be closed.
+
+use super::IDE_CAPABILITIES;
+use crate::platform::{get_platform_manager, PlatformManager};
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+const LSOF_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// A process that has files under a worktree open
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkspaceProcess {
+    pub pid: u32,
+    pub name: String,
+}
+
+/// Seam over the process listing so tests can stub it
+pub trait ProcessLister {
+    /// Processes with their working directory or an open file under `path`.
+    ///
+    /// Returns `None` when the listing is not available.
+    fn processes_using(&self, path: &Path) -> Option<Vec<WorkspaceProcess>>;
+}
+
+/// Lists processes with `lsof +D`, giving up after a short timeout
+pub struct LsofProcessLister {
+    pub timeout: Duration,
+}
+
+impl Default for LsofProcessLister {
+    fn default() -> Self {
+        Self {
+            timeout: LSOF_TIMEOUT,
+        }
+    }
+}
+
+impl ProcessLister for LsofProcessLister {
+    fn processes_using(&self, path: &Path) -> Option<Vec<WorkspaceProcess>> {
+        run_lsof(&["+D"], path, self.timeout)
+    }
+}
+
+/// Processes that have the file at `path` open, such as the git process
+/// holding a lock file; `None` when `lsof` is not available
+pub fn processes_holding(path: &Path) -> Option<Vec<WorkspaceProcess>> {
+    run_lsof(&[], path, LSOF_TIMEOUT)
+}
+
+fn run_lsof(options: &[&str], path: &Path, timeout: Duration) -> Option<Vec<WorkspaceProcess>> {
+    list_with("lsof", options, path, timeout)
+}
+
+fn list_with(
+    program: &str,
+    options: &[&str],
+    path: &Path,
+    timeout: Duration,
+) -> Option<Vec<WorkspaceProcess>> {
+    let child = Command::new(program)
+        .args(["-w", "-F", "pc"])
+        .args(options)
+        .arg(path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .ok()?;
+
+    // A watchdog kills lsof once the timeout expires, while its output is read
+    // as it comes, so a worktree with many open files cannot fill the pipe
+    let pid = child.id() as libc::pid_t;
+    let (done, finished) = mpsc::channel::<()>();
+    let watchdog = thread::spawn(move || match finished.recv_timeout(timeout) {
+        Err(mpsc::RecvTimeoutError::Timeout) => {
+            unsafe { libc::kill(pid, libc::SIGKILL) };
+            true
+        }
+        _ => false,
+    });
+    let output = child.wait_with_output();
+    let _ = done.send(());
+    if watchdog.join().unwrap_or(true) {
+        return None;
+    }
+
+    // lsof exits non-zero when nothing matches, so only the output counts
+    Some(parse_lsof_fields(&String::from_utf8_lossy(
+        &output.ok()?.stdout,
+    )))
+}
+
+/// Parse `lsof -F pc` output: a `p<pid>` line followed by a `c<command>` line
+pub fn parse_lsof_fields(output: &str) -> Vec<WorkspaceProcess> {
+    let mut processes: Vec<WorkspaceProcess> = Vec::new();
+    let mut pid = None;
+    for line in output.lines() {
+        if let Some(value) = line.strip_prefix('p') {
+            pid = value.trim().parse().ok();
+        } else if let (Some(name), Some(current)) = (line.strip_prefix('c'), pid) {
+            if !processes.iter().any(|p| p.pid == current) {
+                processes.push(WorkspaceProcess {
+                    pid: current,
+                    name: name.trim().to_string(),
+                });
+            }
+        }
+    }
+    processes
+}
+
+/// Names of the IDEs or processes holding `path` open, ignoring the processes
+/// in `ignored_pids`. Empty when nothing holds it open or nothing could be checked.
+pub fn workspace_holders(
+    platform: &dyn PlatformManager,
+    lister: &dyn ProcessLister,
+    path: &Path,
+    ignored_pids: &[u32],
+) -> Vec<String> {
+    let Some(folder_name) = path.file_name().map(|n| n.to_string_lossy().to_string()) else {
+        return Vec::new();
+    };
+
+    let mut window_lookup_supported = false;
+    let mut holders = Vec::new();
+    for capabilities in IDE_CAPABILITIES {
+        if let Ok(Some(windows)) = platform.find_ide_windows(capabilities.app_name, &folder_name) {
+            window_lookup_supported = true;
+            if !windows.is_empty() {
+                holders.push(capabilities.display_name.to_string());
+            }
+        }
+    }
+    if window_lookup_supported {
+        return holders;
+    }
+
+    for process in lister.processes_using(path).unwrap_or_default() {
+        if !ignored_pids.contains(&process.pid) && !holders.contains(&process.name) {
+            holders.push(process.name);
+        }
+    }
+    holders
+}
+
+/// Names of the IDEs or processes that appear to have the worktree at `path`
+/// open. The running para process and the shell that started it are ignored.
+pub fn is_workspace_open(path: &Path) -> Option<Vec<String>> {
+    let own_pids = [std::process::id(), parent_pid()];
+    let holders = workspace_holders(
+        get_platform_manager().as_ref(),
+        &LsofProcessLister::default(),
+        path,
+        &own_pids,
+    );
+    (!holders.is_empty()).then_some(holders)
+}
+
+fn parent_pid() -> u32 {
+    // SAFETY: getppid has no preconditions and cannot fail
+    unsafe { libc::getppid() as u32 }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock_platform::MockPlatformManager;
+    use std::path::PathBuf;
+
+    struct StubLister(Option<Vec<WorkspaceProcess>>);
+
+    impl ProcessLister for StubLister {
+        fn processes_using(&self, _path: &Path) -> Option<Vec<WorkspaceProcess>> {
+            self.0.clone()
+        }
+    }
+
+    fn process(pid: u32, name: &str) -> WorkspaceProcess {
+        WorkspaceProcess {
+            pid,
+            name: name.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_parse_lsof_fields() {
+        let output = "p101\ncnode\nfcwd\np202\nccursor\nf12\nf13\np101\ncnode\n";
+        assert_eq!(
+            parse_lsof_fields(output),
+            vec![process(101, "node"), process(202, "cursor")]
+        );
+        assert!(parse_lsof_fields("").is_empty());
+    }
+
+    #[test]
+    fn test_long_listing_is_read_in_full() {
+        let temp = tempfile::TempDir::new().unwrap();
+        // Far more output than a pipe buffer holds
+        let script = temp.path().join("lsof");
+        std::fs::write(
+            &script,
+            "#!/bin/sh\ni=1\nwhile [ $i -le 20000 ]; do echo p$i; echo cCode; i=$((i+1)); done\n",
+        )
+        .unwrap();
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let processes = list_with(
+            &script.to_string_lossy(),
+            &["+D"],
+            temp.path(),
+            Duration::from_secs(20),
+        )
+        .unwrap();
+        assert_eq!(processes.len(), 20000);
+
+        let hanging = temp.path().join("hanging-lsof");
+        std::fs::write(&hanging, "#!/bin/sh\nexec sleep 600\n").unwrap();
+        std::fs::set_permissions(&hanging, std::fs::Permissions::from_mode(0o755)).unwrap();
+        let listing = list_with(
+            &hanging.to_string_lossy(),
+            &[],
+            temp.path(),
+            Duration::from_millis(200),
+        );
+        assert!(listing.is_none());
+    }
+
+    #[test]
+    fn test_window_matching_takes_precedence_over_processes() {
+        let path = PathBuf::from("/repo/.para/worktrees/para-auth");
+        let platform = MockPlatformManager::with_windows(vec![
+            "main.rs — para-auth".to_string(),
+            "lib.rs — other".to_string(),
+        ]);
+        let lister = StubLister(Some(vec![process(7, "node")]));
+
+        let holders = workspace_holders(&platform, &lister, &path, &[]);
+        assert_eq!(holders, vec!["Cursor", "VS Code"]);
+
+        let closed = MockPlatformManager::with_windows(vec!["lib.rs — other".to_string()]);
+        assert!(workspace_holders(&closed, &lister, &path, &[]).is_empty());
+    }
+
+    #[test]
+    fn test_process_listing_without_window_lookup() {
+        let path = PathBuf::from("/repo/.para/worktrees/para-auth");
+        let platform = MockPlatformManager::new();
+        let lister = StubLister(Some(vec![
+            process(1, "zsh"),
+            process(2, "code"),
+            process(3, "code"),
+        ]));
+
+        assert_eq!(
+            workspace_holders(&platform, &lister, &path, &[1]),
+            vec!["code"]
+        );
+        assert!(workspace_holders(&platform, &StubLister(None), &path, &[]).is_empty());
+    }
+}
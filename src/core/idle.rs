@@ -0,0 +1,359 @@
+//! Idle sessions: agents that stopped working without finishing
+//!
+//! With `session.idle_alert_hours` set, a session counts as idle once its last
+//! activity (file changes, commits on its branch or status updates, measured
+//! like the monitor's last activity column) is at least that old. The daemon
+//! checks every registered repository periodically and, depending on
+//! `session.idle_action`, raises an `idle` notification or cancels the
+//! session. Sessions with uncommitted changes or unmerged commits are never
+//! cancelled; they get a notification instead. Pinned sessions are skipped.
+
+use crate::config::{Config, ConfigManager};
+use crate::core::git::repository::execute_git_command;
+use crate::core::git::GitRepository;
+use crate::core::notifications::{notify, NotificationEvent};
+use crate::core::session::{SessionManager, SessionState, SessionStatus};
+use crate::core::status::Status;
+use crate::ui::monitor::activity::detect_session_activity;
+use crate::ui::monitor::service::config_for_repo;
+use crate::utils::{ParaError, Result};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread;
+
+/// How often the daemon looks for idle sessions
+const SWEEP_INTERVAL: std::time::Duration = std::time::Duration::from_secs(10 * 60);
+
+/// What the daemon does once a session has been idle for `idle_alert_hours`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IdleAction {
+    /// Only show the idle badge in `para list`
+    None,
+    /// Raise an `idle` notification
+    #[default]
+    Notify,
+    /// Cancel sessions that have no work to lose, notify about the rest
+    Cancel,
+}
+
+impl IdleAction {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Work in a session that cancelling it could lose
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WorkState {
+    pub uncommitted_changes: bool,
+    /// Commits on the session branch that are not on the main branch
+    pub unmerged_commits: usize,
+}
+
+impl WorkState {
+    fn is_empty(&self) -> bool {
+        !self.uncommitted_changes && self.unmerged_commits == 0
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdleDecision {
+    /// Activity within the threshold
+    Active,
+    /// Idle, but `idle_action` is `none`
+    Idle {
+        idle_for: Duration,
+    },
+    Notify {
+        idle_for: Duration,
+    },
+    Cancel {
+        idle_for: Duration,
+    },
+}
+
+/// How long the session has been idle, once that reaches `threshold_hours`
+pub fn idle_for(
+    last_activity: DateTime<Utc>,
+    threshold_hours: u64,
+    now: DateTime<Utc>,
+) -> Option<Duration> {
+    let threshold = Duration::try_hours(i64::try_from(threshold_hours).ok()?)?;
+    let idle = now.signed_duration_since(last_activity);
+    (idle >= threshold).then_some(idle)
+}
+
+/// Decide what to do with a session. `work` is `None` when it could not be
+/// determined, which counts as having work to lose.
+pub fn decide(
+    last_activity: DateTime<Utc>,
+    threshold_hours: u64,
+    action: IdleAction,
+    work: Option<WorkState>,
+    now: DateTime<Utc>,
+) -> IdleDecision {
+    let Some(idle_for) = idle_for(last_activity, threshold_hours, now) else {
+        return IdleDecision::Active;
+    };
+    match action {
+        IdleAction::None => IdleDecision::Idle { idle_for },
+        IdleAction::Notify => IdleDecision::Notify { idle_for },
+        IdleAction::Cancel if work.is_some_and(|work| work.is_empty()) => {
+            IdleDecision::Cancel { idle_for }
+        }
+        IdleAction::Cancel => IdleDecision::Notify { idle_for },
+    }
+}
+
+/// Compact idle duration for badges, e.g. `5h` or `3d`
+pub fn format_idle_duration(idle_for: Duration) -> String {
+    if idle_for < Duration::days(2) {
+        format!("{}h", idle_for.num_hours())
+    } else {
+        format!("{}d", idle_for.num_days())
+    }
+}
+
+/// Whether the daemon acts on the session once it is idle
+fn is_watched(session: &SessionState) -> bool {
+    matches!(session.status, SessionStatus::Active) && !session.pinned
+}
+
+/// Newest activity of a session, falling back to its creation time
+pub fn session_last_activity(session: &SessionState, state_dir: &Path) -> DateTime<Utc> {
+    let status_updated = Status::load(state_dir, &session.name)
+        .ok()
+        .flatten()
+        .map(|status| status.updated_at);
+    detect_session_activity(&session.worktree_path, &session.branch, status_updated)
+        .map(|activity| activity.at)
+        .unwrap_or(session.created_at)
+}
+
+fn work_state(repo_root: &Path, session: &SessionState) -> Result<WorkState> {
+    let worktree = GitRepository::discover_from(&session.worktree_path)?;
+    let repo = GitRepository::discover_from(repo_root)?;
+    let main_branch = repo.get_main_branch()?;
+    let unmerged = execute_git_command(
+        &repo,
+        &[
+            "rev-list",
+            "--count",
+            &format!("{main_branch}..{}", session.branch),
+        ],
+    )?;
+    Ok(WorkState {
+        uncommitted_changes: worktree.has_uncommitted_changes()?,
+        unmerged_commits: unmerged.parse().unwrap_or(usize::MAX),
+    })
+}
+
+/// Cancel through the CLI so the session is archived exactly like `para cancel`
+fn cancel_session(repo_root: &Path, session_name: &str) -> Result<()> {
+    let exe = std::env::current_exe()?;
+    let output = Command::new(exe)
+        .args(["cancel", session_name])
+        .current_dir(repo_root)
+        .env("PARA_NON_INTERACTIVE", "1")
+        .output()?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(ParaError::worktree_operation(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ))
+    }
+}
+
+/// Acts on idle sessions, once per idle stretch of each session
+#[derive(Debug, Default)]
+pub struct IdleMonitor {
+    /// Worktree -> last activity of the idle stretch already handled
+    handled: HashMap<PathBuf, DateTime<Utc>>,
+}
+
+impl IdleMonitor {
+    pub fn sweep(&mut self, config: &Config, repos: &[PathBuf], now: DateTime<Utc>) {
+        for repo_root in repos.iter().filter(|repo| repo.exists()) {
+            if let Err(e) = self.check_repo(config, repo_root, now) {
+                eprintln!("Idle check failed for {}: {e}", repo_root.display());
+            }
+        }
+    }
+
+    fn check_repo(&mut self, config: &Config, repo_root: &Path, now: DateTime<Utc>) -> Result<()> {
+        let Some(threshold) = config.session.idle_alert_hours else {
+            return Ok(());
+        };
+        let action = config.session.idle_action;
+        let config = config_for_repo(config, repo_root);
+        let session_manager = SessionManager::new(&config);
+
+        for session in session_manager.list_sessions()? {
+            if !is_watched(&session) {
+                continue;
+            }
+            let last_activity = session_last_activity(&session, session_manager.state_dir());
+            if idle_for(last_activity, threshold, now).is_none() {
+                self.handled.remove(&session.worktree_path);
+                continue;
+            }
+            if self.handled.get(&session.worktree_path) == Some(&last_activity) {
+                continue;
+            }
+
+            let work = match action {
+                IdleAction::Cancel => work_state(repo_root, &session).ok(),
+                _ => None,
+            };
+            match decide(last_activity, threshold, action, work, now) {
+                IdleDecision::Active | IdleDecision::Idle { .. } => {}
+                IdleDecision::Notify { idle_for } => {
+                    println!(
+                        "Session {} idle for {}",
+                        session.name,
+                        format_idle_duration(idle_for)
+                    );
+                    notify(
+                        &config,
+                        NotificationEvent::Idle {
+                            session: session.name.clone(),
+                            idle_hours: idle_for.num_hours(),
+                        },
+                    );
+                }
+                IdleDecision::Cancel { idle_for } => {
+                    match cancel_session(repo_root, &session.name) {
+                        Ok(()) => println!(
+                            "Cancelled session {} after {} without activity",
+                            session.name,
+                            format_idle_duration(idle_for)
+                        ),
+                        Err(e) => eprintln!("Failed to cancel idle session {}: {e}", session.name),
+                    }
+                }
+            }
+            self.handled
+                .insert(session.worktree_path.clone(), last_activity);
+        }
+        Ok(())
+    }
+}
+
+/// Check the registered repositories for idle sessions until the daemon exits.
+/// The user config is reloaded on every sweep so changes apply without a restart.
+pub fn spawn_idle_monitor() -> thread::JoinHandle<()> {
+    thread::spawn(|| {
+        let mut monitor = IdleMonitor::default();
+        loop {
+            thread::sleep(SWEEP_INTERVAL);
+            let Ok(config) = ConfigManager::load_or_create() else {
+                continue;
+            };
+            if config.session.idle_alert_hours.is_none() {
+                continue;
+            }
+            let repos = crate::core::repo_registry::load_repos(
+                &crate::core::repo_registry::registry_path(),
+            );
+            monitor.sweep(&config, &repos, Utc::now());
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CLEAN: WorkState = WorkState {
+        uncommitted_changes: false,
+        unmerged_commits: 0,
+    };
+
+    fn start() -> DateTime<Utc> {
+        DateTime::from_timestamp(1_700_000_000, 0).unwrap()
+    }
+
+    #[test]
+    fn test_decide_threshold_and_actions() {
+        let hours = Duration::hours;
+        let decide_after =
+            |elapsed, action| decide(start(), 24, action, Some(CLEAN), start() + elapsed);
+
+        assert_eq!(
+            decide_after(hours(23), IdleAction::Cancel),
+            IdleDecision::Active
+        );
+        assert_eq!(
+            decide_after(hours(24), IdleAction::None),
+            IdleDecision::Idle {
+                idle_for: hours(24)
+            }
+        );
+        assert_eq!(
+            decide_after(hours(30), IdleAction::Notify),
+            IdleDecision::Notify {
+                idle_for: hours(30)
+            }
+        );
+        assert_eq!(
+            decide_after(hours(72), IdleAction::Cancel),
+            IdleDecision::Cancel {
+                idle_for: hours(72)
+            }
+        );
+    }
+
+    #[test]
+    fn test_cancel_never_destroys_work() {
+        let now = start() + Duration::days(5);
+        let notify = IdleDecision::Notify {
+            idle_for: Duration::days(5),
+        };
+
+        let dirty = WorkState {
+            uncommitted_changes: true,
+            unmerged_commits: 0,
+        };
+        let unmerged = WorkState {
+            uncommitted_changes: false,
+            unmerged_commits: 2,
+        };
+        assert_eq!(
+            decide(start(), 24, IdleAction::Cancel, Some(dirty), now),
+            notify
+        );
+        assert_eq!(
+            decide(start(), 24, IdleAction::Cancel, Some(unmerged), now),
+            notify
+        );
+        // Unknown work state is treated as work to keep
+        assert_eq!(decide(start(), 24, IdleAction::Cancel, None, now), notify);
+    }
+
+    #[test]
+    fn test_pinned_and_inactive_sessions_are_not_watched() {
+        let mut session = SessionState::new(
+            "perf".to_string(),
+            "para/perf".to_string(),
+            PathBuf::from("/perf"),
+        );
+        assert!(is_watched(&session));
+        session.pinned = true;
+        assert!(!is_watched(&session));
+        session.pinned = false;
+        session.status = SessionStatus::Review;
+        assert!(!is_watched(&session));
+    }
+
+    #[test]
+    fn test_format_idle_duration() {
+        assert_eq!(format_idle_duration(Duration::hours(5)), "5h");
+        assert_eq!(format_idle_duration(Duration::hours(47)), "47h");
+        assert_eq!(format_idle_duration(Duration::days(3)), "3d");
+    }
+}
@@ -0,0 +1,252 @@
+//! Interrupting commands without leaving a repository half changed
+//!
+//! Ctrl-C cancels [`CancelToken::global`]. While no step is in progress the
+//! process exits right away as before. Steps that change a repository in
+//! several parts (squashing a finished session, integrating it into another
+//! session, creating a worktree) register how to undo themselves with
+//! [`CancelToken::on_abort`] and call [`CancelToken::checkpoint`] between
+//! their parts. Once cancelled, the next checkpoint runs the registered
+//! aborts, newest first, and fails with [`ParaError::Interrupted`]. A step
+//! whose git process was killed by the same Ctrl-C runs its abort when its
+//! guard is dropped, and a step that completes despite the interrupt exits
+//! the process afterwards. A second Ctrl-C still exits at once.
+
+use crate::utils::{ParaError, Result, INTERRUPTED_EXIT_CODE};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+
+type Abort = Box<dyn FnOnce() + Send>;
+
+#[derive(Clone, Default)]
+pub struct CancelToken {
+    inner: Arc<Inner>,
+}
+
+#[derive(Default)]
+struct Inner {
+    cancelled: AtomicBool,
+    /// Exit once the last step in progress completed after a cancellation
+    exit_when_idle: bool,
+    next_id: AtomicU64,
+    /// Aborts of the steps in progress, in the order they were registered
+    aborts: Mutex<Vec<(u64, Abort)>>,
+    #[cfg(test)]
+    cancel_at_checkpoint: Mutex<Option<usize>>,
+}
+
+impl CancelToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The token the Ctrl-C handler cancels
+    pub fn global() -> &'static CancelToken {
+        static TOKEN: OnceLock<CancelToken> = OnceLock::new();
+        TOKEN.get_or_init(|| CancelToken {
+            inner: Arc::new(Inner {
+                exit_when_idle: true,
+                ..Inner::default()
+            }),
+        })
+    }
+
+    pub fn cancel(&self) {
+        self.inner.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Whether a step that must not be cut off is running
+    pub fn in_progress(&self) -> bool {
+        !self.aborts().is_empty()
+    }
+
+    /// Start a step that `abort` undoes. Complete the returned guard once
+    /// the step is done; until then the step is aborted when the token is
+    /// cancelled.
+    pub fn on_abort(&self, abort: impl FnOnce() + Send + 'static) -> AbortGuard {
+        let id = self.inner.next_id.fetch_add(1, Ordering::SeqCst);
+        self.aborts().push((id, Box::new(abort)));
+        AbortGuard {
+            token: self.clone(),
+            id,
+        }
+    }
+
+    /// Start a step that cleans up after itself when it fails, e.g. through
+    /// a rollback that runs on drop
+    pub fn step(&self) -> AbortGuard {
+        self.on_abort(|| {})
+    }
+
+    /// A point between two parts of a step. Fails after running the aborts
+    /// of every step in progress once the token was cancelled.
+    pub fn checkpoint(&self) -> Result<()> {
+        self.checkpoint_keeping("Interrupted between two steps; the steps already done were kept")
+    }
+
+    /// [`checkpoint`](Self::checkpoint) after parts that are not undone;
+    /// `kept` tells what was done when no step in progress needed undoing
+    pub fn checkpoint_keeping(&self, kept: &str) -> Result<()> {
+        #[cfg(test)]
+        self.count_checkpoint();
+
+        if !self.is_cancelled() {
+            return Ok(());
+        }
+        let aborts = std::mem::take(&mut *self.aborts());
+        if aborts.is_empty() {
+            return Err(ParaError::interrupted(kept));
+        }
+        for (_, abort) in aborts.into_iter().rev() {
+            abort();
+        }
+        Err(ParaError::interrupted(
+            "The step in progress was undone and the repository left as it was",
+        ))
+    }
+
+    /// Cancel the token when the `n`th checkpoint from now is reached, as if
+    /// Ctrl-C was pressed right before it
+    #[cfg(test)]
+    pub fn cancel_at_checkpoint(&self, n: usize) {
+        *self.inner.cancel_at_checkpoint.lock().unwrap() = Some(n);
+    }
+
+    #[cfg(test)]
+    fn count_checkpoint(&self) {
+        let mut remaining = self.inner.cancel_at_checkpoint.lock().unwrap();
+        match *remaining {
+            Some(1) => {
+                *remaining = None;
+                self.cancel();
+            }
+            Some(n) => *remaining = Some(n - 1),
+            None => {}
+        }
+    }
+
+    fn aborts(&self) -> std::sync::MutexGuard<'_, Vec<(u64, Abort)>> {
+        // An abort that panicked leaves nothing inconsistent in the list
+        self.inner
+            .aborts
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn take_abort(&self, id: u64) -> Option<Abort> {
+        let mut aborts = self.aborts();
+        let index = aborts.iter().position(|(other, _)| *other == id)?;
+        Some(aborts.remove(index).1)
+    }
+}
+
+/// A step registered with [`CancelToken::on_abort`]
+#[must_use = "dropping the guard ends the step"]
+pub struct AbortGuard {
+    token: CancelToken,
+    id: u64,
+}
+
+impl AbortGuard {
+    /// The step is done and no longer needs to be undone
+    pub fn complete(self) {
+        self.token.take_abort(self.id);
+        if self.token.is_cancelled() && self.token.inner.exit_when_idle && !self.token.in_progress()
+        {
+            eprintln!("Interrupted after the step in progress completed");
+            std::process::exit(INTERRUPTED_EXIT_CODE);
+        }
+    }
+}
+
+impl Drop for AbortGuard {
+    /// A step that ends without completing failed; undo it if that is
+    /// because it was interrupted
+    fn drop(&mut self) {
+        if let Some(abort) = self.token.take_abort(self.id) {
+            if self.token.is_cancelled() {
+                abort();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recording_abort(log: &Arc<Mutex<Vec<&'static str>>>, name: &'static str) -> Abort {
+        let log = Arc::clone(log);
+        Box::new(move || log.lock().unwrap().push(name))
+    }
+
+    #[test]
+    fn test_checkpoint_runs_registered_aborts_newest_first() {
+        let token = CancelToken::new();
+        let log = Arc::new(Mutex::new(Vec::new()));
+
+        let outer = token.on_abort(recording_abort(&log, "outer"));
+        let done = token.on_abort(recording_abort(&log, "done"));
+        done.complete();
+        let inner = token.on_abort(recording_abort(&log, "inner"));
+        assert!(token.checkpoint().is_ok());
+        assert!(token.in_progress());
+
+        token.cancel();
+        let error = token.checkpoint().unwrap_err();
+        assert!(matches!(error, ParaError::Interrupted { .. }));
+        assert_eq!(error.exit_code(), INTERRUPTED_EXIT_CODE);
+        assert_eq!(*log.lock().unwrap(), vec!["inner", "outer"]);
+        assert!(!token.in_progress());
+
+        // The aborts already ran; dropping the guards does not repeat them
+        drop(inner);
+        drop(outer);
+        assert_eq!(log.lock().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_dropped_guard_aborts_only_when_cancelled() {
+        let token = CancelToken::new();
+        let log = Arc::new(Mutex::new(Vec::new()));
+
+        // A step that fails on its own cleans up through its usual path
+        drop(token.on_abort(recording_abort(&log, "failed")));
+        assert!(log.lock().unwrap().is_empty());
+
+        // A step that fails because of the interrupt is undone
+        let killed = token.on_abort(recording_abort(&log, "killed"));
+        token.cancel();
+        drop(killed);
+        assert_eq!(*log.lock().unwrap(), vec!["killed"]);
+    }
+
+    #[test]
+    fn test_cancel_at_checkpoint() {
+        let token = CancelToken::new();
+        token.cancel_at_checkpoint(2);
+        assert!(token.checkpoint().is_ok());
+        assert!(!token.is_cancelled());
+        assert!(token.checkpoint().is_err());
+        assert!(token.is_cancelled());
+    }
+
+    #[test]
+    fn test_checkpoint_without_steps_in_progress_says_what_was_kept() {
+        let token = CancelToken::new();
+        token.cancel();
+        let error = token
+            .checkpoint_keeping("Interrupted after committing")
+            .unwrap_err();
+        assert!(error.to_string().contains("Interrupted after committing"));
+
+        let _step = token.step();
+        let error = token
+            .checkpoint_keeping("Interrupted after committing")
+            .unwrap_err();
+        assert!(error.to_string().contains("undone"), "{error}");
+    }
+}
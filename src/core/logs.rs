@@ -0,0 +1,429 @@
+//! Logs of a session, gathered for `para logs`
+//!
+//! A session can leave output in three places: the setup script's stdout and
+//! stderr, captured to `<state_dir>/<session>.setup.log` while it runs; the
+//! output of its container, read with `docker logs para-<session>`; and the
+//! lines the daemon appends to `<state_dir>/para.log` about the session's
+//! container. A source that does not exist for a session is reported as
+//! absent with the reason rather than failing the whole command.
+
+use crate::core::docker::resources::DockerCli;
+use crate::core::session::{SessionState, SessionType};
+use chrono::Utc;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus, Stdio};
+use std::sync::mpsc::Sender;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+/// Log the daemon and container cleanup append to, in the state directory
+pub const LOG_FILE: &str = "para.log";
+/// `para.log` after `para gc` rotated it
+pub const ROTATED_LOG_FILE: &str = "para.log.old";
+
+/// How often `--follow` checks log files for new lines
+const FOLLOW_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum LogSource {
+    /// Output of the setup script
+    Setup,
+    /// Output of the session container
+    Container,
+    /// Daemon entries about the session in para.log
+    Daemon,
+}
+
+impl LogSource {
+    pub const ALL: [LogSource; 3] = [LogSource::Setup, LogSource::Container, LogSource::Daemon];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            LogSource::Setup => "setup",
+            LogSource::Container => "container",
+            LogSource::Daemon => "daemon",
+        }
+    }
+}
+
+/// What one source holds for a session
+#[derive(Debug, Clone, PartialEq)]
+pub enum LogContent {
+    Text(String),
+    /// The source does not exist for the session, and why
+    Absent(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogSection {
+    pub source: LogSource,
+    pub content: LogContent,
+}
+
+/// Where the setup script output of `session` is captured
+pub fn setup_log_path(state_dir: &Path, session: &str) -> PathBuf {
+    state_dir.join(format!("{session}.setup.log"))
+}
+
+fn container_name(session: &str) -> String {
+    format!("para-{session}")
+}
+
+/// Append a line about `session` to para.log in `state_dir`
+pub fn append_session_entry(state_dir: &Path, session: &str, message: &str) {
+    if let Ok(mut log) = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(state_dir.join(LOG_FILE))
+    {
+        let _ = writeln!(log, "{} [{session}] {message}", Utc::now().to_rfc3339());
+    }
+}
+
+/// Whether a para.log line is about `session`: written for it by the daemon,
+/// or naming its container, e.g. in a container cleanup
+pub fn is_session_entry(line: &str, session: &str) -> bool {
+    let container = container_name(session);
+    line.contains(&format!("[{session}]"))
+        || line
+            .split(|c: char| c.is_whitespace() || c == ',' || c == ';')
+            .any(|word| word == container)
+}
+
+/// The lines of para.log (and its rotated predecessor) about `session`
+pub fn daemon_entries(state_dir: &Path, session: &str) -> Option<String> {
+    let mut found = false;
+    let mut entries = String::new();
+    for name in [ROTATED_LOG_FILE, LOG_FILE] {
+        let Ok(text) = fs::read_to_string(state_dir.join(name)) else {
+            continue;
+        };
+        found = true;
+        for line in text.lines().filter(|line| is_session_entry(line, session)) {
+            entries.push_str(line);
+            entries.push('\n');
+        }
+    }
+    found.then_some(entries)
+}
+
+/// Gather `sources` for `session`, in the order given
+pub fn collect(
+    state_dir: &Path,
+    session: &SessionState,
+    sources: &[LogSource],
+    docker: &dyn DockerCli,
+) -> Vec<LogSection> {
+    sources
+        .iter()
+        .map(|&source| LogSection {
+            source,
+            content: read_source(state_dir, session, source, docker),
+        })
+        .collect()
+}
+
+fn read_source(
+    state_dir: &Path,
+    session: &SessionState,
+    source: LogSource,
+    docker: &dyn DockerCli,
+) -> LogContent {
+    match source {
+        LogSource::Setup => {
+            let path = setup_log_path(state_dir, &session.name);
+            match fs::read_to_string(&path) {
+                Ok(text) => LogContent::Text(text),
+                Err(_) => {
+                    LogContent::Absent(format!("no setup script output at {}", path.display()))
+                }
+            }
+        }
+        LogSource::Container => {
+            if !matches!(session.session_type, SessionType::Container { .. }) {
+                return LogContent::Absent("not a container session".to_string());
+            }
+            match docker.run(&["logs", &container_name(&session.name)]) {
+                Ok(text) => LogContent::Text(text),
+                Err(e) => LogContent::Absent(format!("docker logs failed: {e}")),
+            }
+        }
+        LogSource::Daemon => match daemon_entries(state_dir, &session.name) {
+            Some(text) if !text.is_empty() => LogContent::Text(text),
+            Some(_) => LogContent::Absent(format!("no entries in {LOG_FILE}")),
+            None => LogContent::Absent(format!("no {LOG_FILE} in {}", state_dir.display())),
+        },
+    }
+}
+
+/// Run `command`, passing its stdout and stderr through to ours and copying
+/// both to `log_path`, which is replaced
+pub fn run_logged(command: &mut Command, log_path: &Path) -> io::Result<ExitStatus> {
+    if let Some(parent) = log_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    run_logged_to(command, &File::create(log_path)?)
+}
+
+/// Like [`run_logged`], but copy the output to the end of `log`, which is
+/// already open
+pub fn run_logged_to(command: &mut Command, log: &File) -> io::Result<ExitStatus> {
+    let log = Arc::new(Mutex::new(log.try_clone()?));
+    let mut child = command
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    let stderr = child.stderr.take().map(|stderr| {
+        let log = Arc::clone(&log);
+        thread::spawn(move || tee(stderr, io::stderr(), &log))
+    });
+    if let Some(stdout) = child.stdout.take() {
+        tee(stdout, io::stdout(), &log);
+    }
+    if let Some(handle) = stderr {
+        let _ = handle.join();
+    }
+    child.wait()
+}
+
+fn tee(mut input: impl Read, mut output: impl Write, log: &Mutex<File>) {
+    let mut buffer = [0u8; 8192];
+    loop {
+        match input.read(&mut buffer) {
+            Ok(0) | Err(_) => break,
+            Ok(read) => {
+                let _ = output.write_all(&buffer[..read]);
+                let _ = output.flush();
+                if let Ok(mut log) = log.lock() {
+                    let _ = log.write_all(&buffer[..read]);
+                }
+            }
+        }
+    }
+}
+
+/// Send lines appended to `path` after byte `offset` until the receiver is gone
+pub fn follow_file(
+    path: PathBuf,
+    mut offset: u64,
+    filter: impl Fn(&str) -> bool,
+    lines: Sender<String>,
+) {
+    let mut partial = String::new();
+    loop {
+        if let Ok(mut file) = File::open(&path) {
+            let len = file.metadata().map(|meta| meta.len()).unwrap_or(0);
+            // Rotated or rewritten: start over from the beginning
+            if len < offset {
+                offset = 0;
+            }
+            let mut appended = String::new();
+            if file.seek(SeekFrom::Start(offset)).is_ok()
+                && file.read_to_string(&mut appended).is_ok()
+            {
+                offset += appended.len() as u64;
+                partial.push_str(&appended);
+                while let Some(end) = partial.find('\n') {
+                    let line: String = partial.drain(..=end).collect();
+                    let line = line.trim_end_matches('\n');
+                    if filter(line) && lines.send(line.to_string()).is_err() {
+                        return;
+                    }
+                }
+            }
+        }
+        thread::sleep(FOLLOW_POLL_INTERVAL);
+    }
+}
+
+/// Send the container output of `session` written from now on
+pub fn follow_container(session: &str, lines: Sender<String>) {
+    let Ok(mut child) = Command::new("docker")
+        .args(["logs", "--follow", "--tail", "0", &container_name(session)])
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+    else {
+        return;
+    };
+    let stderr = child.stderr.take().map(|stderr| {
+        let lines = lines.clone();
+        thread::spawn(move || send_lines(stderr, &lines))
+    });
+    if let Some(stdout) = child.stdout.take() {
+        send_lines(stdout, &lines);
+    }
+    if let Some(handle) = stderr {
+        let _ = handle.join();
+    }
+    let _ = child.wait();
+}
+
+fn send_lines(input: impl Read, lines: &Sender<String>) {
+    use std::io::BufRead;
+    for line in io::BufReader::new(input).lines() {
+        let Ok(line) = line else { break };
+        if lines.send(line).is_err() {
+            break;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::docker::mock::MockDockerClient;
+    use std::sync::mpsc;
+    use tempfile::TempDir;
+
+    fn session(name: &str, container: bool) -> SessionState {
+        let mut session = SessionState::new(
+            name.to_string(),
+            format!("para/{name}"),
+            PathBuf::from("/tmp/worktree"),
+        );
+        if container {
+            session.session_type = SessionType::Container {
+                container_id: Some("abc123".to_string()),
+            };
+        }
+        session
+    }
+
+    #[test]
+    fn test_daemon_entries_are_filtered_by_session() {
+        let temp = TempDir::new().unwrap();
+        fs::write(
+            temp.path().join(ROTATED_LOG_FILE),
+            "2024-01-01T00:00:00Z [auth] finish signal received: Add login\n",
+        )
+        .unwrap();
+        fs::write(
+            temp.path().join(LOG_FILE),
+            "2024-01-02T00:00:00Z [auth-v2] cancel signal received\n\
+             2024-01-02T00:00:01Z container cleanup: removed containers para-auth, para-ui\n\
+             2024-01-02T00:00:02Z container cleanup: removed containers para-auth-v2\n",
+        )
+        .unwrap();
+        append_session_entry(temp.path(), "auth", "container stopped: session finished");
+
+        let entries = daemon_entries(temp.path(), "auth").unwrap();
+        let lines: Vec<&str> = entries.lines().collect();
+        assert_eq!(lines.len(), 3, "{entries}");
+        assert!(lines[0].ends_with("[auth] finish signal received: Add login"));
+        assert!(lines[1].contains("removed containers para-auth, para-ui"));
+        assert!(lines[2].ends_with("[auth] container stopped: session finished"));
+
+        assert_eq!(daemon_entries(&temp.path().join("missing"), "auth"), None);
+    }
+
+    #[test]
+    fn test_collect_reports_absent_sources() {
+        let temp = TempDir::new().unwrap();
+        fs::write(setup_log_path(temp.path(), "auth"), "npm install\ndone\n").unwrap();
+        let docker = MockDockerClient::new();
+
+        let sections = collect(
+            temp.path(),
+            &session("auth", false),
+            &LogSource::ALL,
+            &docker,
+        );
+        assert_eq!(
+            sections[0].content,
+            LogContent::Text("npm install\ndone\n".to_string())
+        );
+        assert_eq!(
+            sections[1].content,
+            LogContent::Absent("not a container session".to_string())
+        );
+        assert!(
+            matches!(&sections[2].content, LogContent::Absent(reason) if reason.starts_with("no para.log"))
+        );
+
+        let sections = collect(
+            temp.path(),
+            &session("ui", false),
+            &[LogSource::Setup],
+            &docker,
+        );
+        assert_eq!(sections.len(), 1);
+        assert!(
+            matches!(&sections[0].content, LogContent::Absent(reason) if reason.starts_with("no setup script output"))
+        );
+    }
+
+    #[test]
+    fn test_container_logs_come_from_docker() {
+        let temp = TempDir::new().unwrap();
+        let docker = MockDockerClient::new();
+        docker.set_container_logs("para-auth", "claude started\nrunning tests");
+
+        let sections = collect(
+            temp.path(),
+            &session("auth", true),
+            &[LogSource::Container],
+            &docker,
+        );
+        assert_eq!(
+            sections,
+            vec![LogSection {
+                source: LogSource::Container,
+                content: LogContent::Text("claude started\nrunning tests".to_string()),
+            }]
+        );
+
+        let sections = collect(
+            temp.path(),
+            &session("gone", true),
+            &[LogSource::Container],
+            &docker,
+        );
+        assert!(
+            matches!(&sections[0].content, LogContent::Absent(reason) if reason.starts_with("docker logs failed"))
+        );
+    }
+
+    #[test]
+    fn test_run_logged_captures_stdout_and_stderr() {
+        let temp = TempDir::new().unwrap();
+        let log = temp.path().join("state").join("auth.setup.log");
+        let mut command = Command::new("sh");
+        command.args(["-c", "echo out; echo err >&2; exit 3"]);
+
+        let status = run_logged(&mut command, &log).unwrap();
+        assert_eq!(status.code(), Some(3));
+        let captured = fs::read_to_string(&log).unwrap();
+        assert!(captured.contains("out\n"));
+        assert!(captured.contains("err\n"));
+    }
+
+    #[test]
+    fn test_follow_file_sends_appended_lines() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join(LOG_FILE);
+        fs::write(&path, "old [auth] line\n").unwrap();
+        let offset = fs::metadata(&path).unwrap().len();
+
+        let (sender, receiver) = mpsc::channel();
+        let followed = path.clone();
+        thread::spawn(move || {
+            follow_file(
+                followed,
+                offset,
+                |line| is_session_entry(line, "auth"),
+                sender,
+            )
+        });
+        let mut log = OpenOptions::new().append(true).open(&path).unwrap();
+        writeln!(log, "new [ui] line\nnew [auth] line").unwrap();
+
+        let line = receiver.recv_timeout(Duration::from_secs(5)).unwrap();
+        assert_eq!(line, "new [auth] line");
+    }
+}
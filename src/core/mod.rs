@@ -1,11 +1,27 @@
+pub mod checkpoint;
 pub mod claude_launcher;
+pub mod cleanup;
 pub mod daemon;
 pub mod docker;
+pub mod events;
+pub mod gc;
 pub mod git;
+pub mod http;
 pub mod ide;
+pub mod idle;
+pub mod interrupt;
+pub mod logs;
+pub mod network;
+pub mod notifications;
+pub mod repo_registry;
 pub mod sandbox;
+pub mod self_update;
 pub mod session;
 pub mod status;
+pub mod statusline;
+pub mod trash;
+pub mod vcs;
+pub mod version;
 
 // Docker module will be conditionally compiled once feature is added to Cargo.toml
 // #[cfg(feature = "docker")]
@@ -0,0 +1,66 @@
+//! Offline mode for planes and locked-down networks
+//!
+//! `--offline` or `PARA_OFFLINE=1` makes para skip optional network steps and
+//! refuse operations that cannot work without one, instead of stalling on
+//! timeouts. The flag is exported as `PARA_OFFLINE` so child processes such as
+//! the daemon see the same setting.
+
+use crate::utils::{ParaError, Result};
+
+pub const OFFLINE_ENV: &str = "PARA_OFFLINE";
+
+/// Whether commands may use the network
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NetworkContext {
+    offline: bool,
+}
+
+impl NetworkContext {
+    pub fn online() -> Self {
+        Self { offline: false }
+    }
+
+    pub fn offline() -> Self {
+        Self { offline: true }
+    }
+
+    /// The setting for this process, from `--offline` or `PARA_OFFLINE`
+    pub fn current() -> Self {
+        let offline = std::env::var(OFFLINE_ENV).is_ok_and(|value| {
+            matches!(
+                value.trim().to_ascii_lowercase().as_str(),
+                "1" | "true" | "yes"
+            )
+        });
+        Self { offline }
+    }
+
+    /// Make `--offline` visible to `current()` here and in child processes
+    pub fn export(self) {
+        if self.offline {
+            std::env::set_var(OFFLINE_ENV, "1");
+        }
+    }
+
+    pub fn is_offline(&self) -> bool {
+        self.offline
+    }
+
+    /// True when an optional network step should be skipped; says so when it is
+    pub fn skips(&self, operation: &str) -> bool {
+        if self.offline {
+            eprintln!("ℹ️  Offline mode: skipping {operation}");
+        }
+        self.offline
+    }
+
+    /// Refuse an operation that needs the network
+    pub fn require_online(&self, operation: &str) -> Result<()> {
+        if self.offline {
+            return Err(ParaError::invalid_args(format!(
+                "{operation} needs network access, which is disabled in offline mode (--offline / {OFFLINE_ENV})"
+            )));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,297 @@
+//! Opt-in notification hooks for notable session events
+//!
+//! When `notifications.command` is configured, para runs it through `sh -c`
+//! whenever a session finishes, is cancelled, reports itself blocked, goes
+//! idle, or its container exits. Event details are passed to the command via the
+//! `PARA_EVENT`, `PARA_SESSION` and `PARA_DETAIL` environment variables.
+//!
+//! Notifications are strictly best-effort: failures are only reported through
+//! debug logging and never affect the operation that raised the event.
+
+use crate::config::Config;
+use crate::utils::{debug_log, ParaError, Result};
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Default number of seconds a notification command may run before it is killed
+pub const DEFAULT_TIMEOUT_SECS: u64 = 10;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// A notable session event that can trigger the notification command
+#[derive(Debug, Clone, PartialEq)]
+pub enum NotificationEvent {
+    /// Session was finished and its branch is ready for review
+    Finished { session: String, branch: String },
+    /// Session was cancelled and archived
+    Cancelled { session: String },
+    /// Agent reported the session as blocked
+    Blocked { session: String, reason: String },
+    /// Container backing a docker session stopped
+    ContainerExited { session: String, reason: String },
+    /// Session had no activity for `session.idle_alert_hours`
+    Idle { session: String, idle_hours: i64 },
+    /// Manually triggered via `para config notify test`
+    Test,
+}
+
+impl NotificationEvent {
+    /// Event name exposed as `PARA_EVENT`
+    pub fn kind(&self) -> &'static str {
+        match self {
+            NotificationEvent::Finished { .. } => "finished",
+            NotificationEvent::Cancelled { .. } => "cancelled",
+            NotificationEvent::Blocked { .. } => "blocked",
+            NotificationEvent::ContainerExited { .. } => "container_exited",
+            NotificationEvent::Idle { .. } => "idle",
+            NotificationEvent::Test => "test",
+        }
+    }
+
+    /// Session name exposed as `PARA_SESSION`
+    pub fn session(&self) -> &str {
+        match self {
+            NotificationEvent::Finished { session, .. }
+            | NotificationEvent::Cancelled { session }
+            | NotificationEvent::Blocked { session, .. }
+            | NotificationEvent::ContainerExited { session, .. }
+            | NotificationEvent::Idle { session, .. } => session,
+            NotificationEvent::Test => "",
+        }
+    }
+
+    /// Human readable details exposed as `PARA_DETAIL`
+    pub fn detail(&self) -> String {
+        match self {
+            NotificationEvent::Finished { session, branch } => {
+                format!("Session '{session}' finished on branch '{branch}'")
+            }
+            NotificationEvent::Cancelled { session } => {
+                format!("Session '{session}' was cancelled")
+            }
+            NotificationEvent::Blocked { session, reason } => {
+                format!("Session '{session}' is blocked: {reason}")
+            }
+            NotificationEvent::ContainerExited { session, reason } => {
+                format!("Container for session '{session}' exited: {reason}")
+            }
+            NotificationEvent::Idle {
+                session,
+                idle_hours,
+            } => {
+                format!("Session '{session}' has had no activity for {idle_hours} hours")
+            }
+            NotificationEvent::Test => "Test notification from para".to_string(),
+        }
+    }
+}
+
+/// Raise a notification for `event` if a notification command is configured.
+///
+/// The command is spawned immediately and supervised by a background thread
+/// that kills it once the configured timeout elapses. Errors are swallowed.
+pub fn notify(config: &Config, event: NotificationEvent) {
+    let Some(command) = config.get_notification_command() else {
+        return;
+    };
+
+    match spawn_command(command, &event, false) {
+        Ok(child) => {
+            let timeout = timeout_for(config);
+            thread::spawn(move || {
+                if let Err(e) = wait_with_timeout(child, timeout) {
+                    debug_log(&format!("Notification command failed: {e}"));
+                }
+            });
+        }
+        Err(e) => debug_log(&format!(
+            "Failed to run notification command for '{}': {e}",
+            event.kind()
+        )),
+    }
+}
+
+/// Run the notification command for `event` and wait for it to complete.
+///
+/// Used by `para config notify test` so users can verify their hook setup.
+pub fn notify_blocking(config: &Config, event: NotificationEvent) -> Result<ExitStatus> {
+    let command = config.get_notification_command().ok_or_else(|| {
+        ParaError::config_error(
+            "No notification command configured. Add \"notifications\": { \"command\": \"...\" } via 'para config edit'",
+        )
+    })?;
+
+    let child = spawn_command(command, &event, true)?;
+    wait_with_timeout(child, timeout_for(config))
+}
+
+fn timeout_for(config: &Config) -> Duration {
+    let secs = config
+        .notifications
+        .as_ref()
+        .and_then(|n| n.timeout_secs)
+        .unwrap_or(DEFAULT_TIMEOUT_SECS);
+    Duration::from_secs(secs)
+}
+
+fn spawn_command(command: &str, event: &NotificationEvent, inherit_output: bool) -> Result<Child> {
+    let output = || {
+        if inherit_output {
+            Stdio::inherit()
+        } else {
+            Stdio::null()
+        }
+    };
+
+    Command::new("sh")
+        .arg("-c")
+        .arg(command)
+        .env("PARA_EVENT", event.kind())
+        .env("PARA_SESSION", event.session())
+        .env("PARA_DETAIL", event.detail())
+        .stdin(Stdio::null())
+        .stdout(output())
+        .stderr(output())
+        .spawn()
+        .map_err(|e| ParaError::config_error(format!("Failed to spawn notification command: {e}")))
+}
+
+fn wait_with_timeout(mut child: Child, timeout: Duration) -> Result<ExitStatus> {
+    let started = Instant::now();
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(status);
+        }
+
+        if started.elapsed() >= timeout {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(ParaError::config_error(format!(
+                "Notification command timed out after {}s",
+                timeout.as_secs()
+            )));
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::NotificationConfig;
+    use crate::test_utils::test_helpers::create_test_config;
+    use std::fs;
+    use std::path::Path;
+    use tempfile::TempDir;
+
+    fn config_with_command(command: String, timeout_secs: Option<u64>) -> Config {
+        let mut config = create_test_config();
+        config.notifications = Some(NotificationConfig {
+            command: Some(command),
+            timeout_secs,
+        });
+        config
+    }
+
+    fn recording_command(output: &Path) -> String {
+        format!(
+            "printf '%s|%s|%s' \"$PARA_EVENT\" \"$PARA_SESSION\" \"$PARA_DETAIL\" > '{}'",
+            output.display()
+        )
+    }
+
+    fn wait_for_file(path: &Path) -> String {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while Instant::now() < deadline {
+            if let Ok(content) = fs::read_to_string(path) {
+                if !content.is_empty() {
+                    return content;
+                }
+            }
+            thread::sleep(Duration::from_millis(20));
+        }
+        panic!("Notification command did not write {}", path.display());
+    }
+
+    #[test]
+    fn test_event_env_values() {
+        let event = NotificationEvent::Blocked {
+            session: "auth".to_string(),
+            reason: "needs API key".to_string(),
+        };
+        assert_eq!(event.kind(), "blocked");
+        assert_eq!(event.session(), "auth");
+        assert_eq!(event.detail(), "Session 'auth' is blocked: needs API key");
+
+        assert_eq!(NotificationEvent::Test.kind(), "test");
+        assert_eq!(NotificationEvent::Test.session(), "");
+    }
+
+    #[test]
+    fn test_notify_blocking_passes_event_env() {
+        let temp_dir = TempDir::new().unwrap();
+        let output = temp_dir.path().join("event.txt");
+        let config = config_with_command(recording_command(&output), None);
+
+        let status = notify_blocking(
+            &config,
+            NotificationEvent::Finished {
+                session: "feature-x".to_string(),
+                branch: "para/feature-x".to_string(),
+            },
+        )
+        .unwrap();
+        assert!(status.success());
+
+        let content = fs::read_to_string(&output).unwrap();
+        assert_eq!(
+            content,
+            "finished|feature-x|Session 'feature-x' finished on branch 'para/feature-x'"
+        );
+    }
+
+    #[test]
+    fn test_notify_runs_in_background() {
+        let temp_dir = TempDir::new().unwrap();
+        let output = temp_dir.path().join("event.txt");
+        let config = config_with_command(recording_command(&output), None);
+
+        notify(
+            &config,
+            NotificationEvent::Cancelled {
+                session: "old-work".to_string(),
+            },
+        );
+
+        let content = wait_for_file(&output);
+        assert!(content.starts_with("cancelled|old-work|"));
+    }
+
+    #[test]
+    fn test_notify_without_command_is_noop() {
+        let config = create_test_config();
+        notify(&config, NotificationEvent::Test);
+        assert!(notify_blocking(&config, NotificationEvent::Test).is_err());
+    }
+
+    #[test]
+    fn test_failing_command_does_not_panic() {
+        let config = config_with_command("exit 3".to_string(), None);
+        notify(&config, NotificationEvent::Test);
+
+        let status = notify_blocking(&config, NotificationEvent::Test).unwrap();
+        assert_eq!(status.code(), Some(3));
+    }
+
+    #[test]
+    fn test_command_timeout_kills_process() {
+        let config = config_with_command("sleep 5".to_string(), Some(0));
+        let started = Instant::now();
+        let result = notify_blocking(&config, NotificationEvent::Test);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("timed out"));
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+}
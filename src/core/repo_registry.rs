@@ -0,0 +1,94 @@
+//! Registry of repositories para has been used in, kept in `<config_dir>/repos.json`
+//!
+//! Every successful command records its repository so `para monitor --all-repos`
+//! can show sessions from all of them.
+
+use crate::utils::{ParaError, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const REPOS_FILE: &str = "repos.json";
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct RegistryFile {
+    #[serde(default)]
+    repos: Vec<PathBuf>,
+}
+
+/// Default registry location in the user config directory
+pub fn registry_path() -> PathBuf {
+    crate::config::defaults::get_default_config_dir().join(REPOS_FILE)
+}
+
+/// Registered repository roots in the order they were first seen. A missing or
+/// unreadable registry is treated as empty.
+pub fn load_repos(registry: &Path) -> Vec<PathBuf> {
+    fs::read_to_string(registry)
+        .ok()
+        .and_then(|content| serde_json::from_str::<RegistryFile>(&content).ok())
+        .unwrap_or_default()
+        .repos
+}
+
+/// Append `repo_root` to the registry unless it is already there. Returns
+/// whether the registry changed.
+pub fn register_repo(registry: &Path, repo_root: &Path) -> Result<bool> {
+    let mut repos = load_repos(registry);
+    if repos.iter().any(|r| r == repo_root) {
+        return Ok(false);
+    }
+    repos.push(repo_root.to_path_buf());
+
+    if let Some(parent) = registry.parent() {
+        fs::create_dir_all(parent).map_err(|e| {
+            ParaError::fs_error(format!("Failed to create {}: {e}", parent.display()))
+        })?;
+    }
+    let json = serde_json::to_string_pretty(&RegistryFile { repos }).map_err(|e| {
+        ParaError::file_operation(format!("Failed to encode repository registry: {e}"))
+    })?;
+
+    // Write to a temporary file first so a crash never leaves a truncated file
+    let tmp = registry.with_extension("json.tmp");
+    fs::write(&tmp, json)
+        .and_then(|_| fs::rename(&tmp, registry))
+        .map_err(|e| ParaError::fs_error(format!("Failed to write {}: {e}", registry.display())))?;
+    Ok(true)
+}
+
+/// Record the repository of the current directory, if any. Best effort: a
+/// failure here never affects the command that just ran.
+pub fn record_current_repo() {
+    let Ok(repo_root) = crate::utils::get_main_repository_root() else {
+        return;
+    };
+    if let Err(e) = register_repo(&registry_path(), &repo_root) {
+        crate::utils::debug_log(&format!("Failed to record repository: {e}"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_register_repo_appends_once() {
+        let temp = TempDir::new().unwrap();
+        let registry = temp.path().join("config").join(REPOS_FILE);
+        assert!(load_repos(&registry).is_empty());
+
+        assert!(register_repo(&registry, Path::new("/work/one")).unwrap());
+        assert!(register_repo(&registry, Path::new("/work/two")).unwrap());
+        assert!(!register_repo(&registry, Path::new("/work/one")).unwrap());
+
+        assert_eq!(
+            load_repos(&registry),
+            vec![PathBuf::from("/work/one"), PathBuf::from("/work/two")]
+        );
+
+        fs::write(&registry, "not json").unwrap();
+        assert!(load_repos(&registry).is_empty());
+    }
+}
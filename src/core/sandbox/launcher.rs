@@ -58,6 +58,7 @@ pub fn wrap_command_with_sandbox(
     #[cfg(target_os = "macos")]
     {
         use super::profiles::extract_profile;
+        use crate::utils::shell;
         use anyhow::Context;
 
         let profile_path =
@@ -87,9 +88,6 @@ pub fn wrap_command_with_sandbox(
             .trim_end_matches('/')
             .to_string();
 
-        // Escape the command for shell
-        let escaped_command = command.replace('\'', "'\\''");
-
         // Get the main repository directory (parent of .para/worktrees)
         let main_repo_dir = if worktree_path_str.contains("/.para/worktrees/") {
             // Extract main repo from worktree path
@@ -104,25 +102,27 @@ pub fn wrap_command_with_sandbox(
         };
 
         // Build the sandbox-exec command
-        let mut sandbox_cmd = format!(
-            "sandbox-exec \
-             -D 'TARGET_DIR={worktree_path_str}' \
-             -D 'TMP_DIR={temp_dir_str}' \
-             -D 'HOME_DIR={home_dir_str}' \
-             -D 'CACHE_DIR={cache_dir_str}' \
-             -D 'MAIN_REPO_DIR={main_repo_dir}'"
-        );
-
+        let mut sandbox_cmd = "sandbox-exec".to_string();
+        let mut parameters = vec![
+            ("TARGET_DIR", worktree_path_str.as_str()),
+            ("TMP_DIR", temp_dir_str.as_str()),
+            ("HOME_DIR", home_dir_str.as_str()),
+            ("CACHE_DIR", cache_dir_str.as_str()),
+            ("MAIN_REPO_DIR", main_repo_dir.as_str()),
+        ];
         // Add proxy address parameter if provided
         if let Some(ref proxy_addr) = options.proxy_address {
-            sandbox_cmd.push_str(&format!(" -D 'PROXY_ADDR={proxy_addr}'"));
+            parameters.push(("PROXY_ADDR", proxy_addr.as_str()));
+        }
+        for (name, value) in parameters {
+            sandbox_cmd.push_str(&format!(" -D {}", shell::quote(&format!("{name}={value}"))));
         }
 
         // Add the profile and command
         sandbox_cmd.push_str(&format!(
-            " -f '{}' sh -c '{}'",
-            profile_path.display(),
-            escaped_command
+            " -f {} sh -c {}",
+            shell::quote_path(&profile_path),
+            shell::quote(command)
         ));
 
         // Determine if we need special handling for network proxy
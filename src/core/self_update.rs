@@ -0,0 +1,405 @@
+//! Checking for and installing newer para releases
+//!
+//! Nothing here runs in the background: the latest release is only looked up
+//! when `para self-update` is invoked. How para was installed decides how it is
+//! updated. A binary dropped into a writable directory is replaced in place
+//! by the release's binary for the platform, once it matches the SHA-256 the
+//! release publishes in `checksums.txt`. Homebrew and cargo installs are left
+//! to their package manager, whose command is printed instead.
+
+use crate::core::http::HttpClient;
+use crate::utils::{ParaError, Result};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const LATEST_RELEASE_URL: &str = "https://api.github.com/repos/2mawi2/para/releases/latest";
+const REPOSITORY_URL: &str = "https://github.com/2mawi2/para";
+const HOMEBREW_FORMULA: &str = "2mawi2/tap/para";
+/// Release asset listing the SHA-256 of every other asset, as `sha256sum` does
+const CHECKSUMS_ASSET: &str = "checksums.txt";
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Release {
+    pub tag_name: String,
+    pub html_url: String,
+    #[serde(default)]
+    pub assets: Vec<ReleaseAsset>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReleaseAsset {
+    pub name: String,
+    pub browser_download_url: String,
+}
+
+impl Release {
+    /// Version of the release, without the `v` of its tag
+    pub fn version(&self) -> &str {
+        self.tag_name.trim_start_matches('v')
+    }
+
+    /// Prebuilt binary for this platform, named `para-<arch>-<os>`
+    pub fn binary_asset(&self) -> Option<&ReleaseAsset> {
+        self.asset(&binary_asset_name())
+    }
+
+    fn asset(&self, name: &str) -> Option<&ReleaseAsset> {
+        self.assets.iter().find(|asset| asset.name == name)
+    }
+}
+
+/// Hash of `name` in the output of `sha256sum`
+pub fn published_checksum<'a>(checksums: &'a str, name: &str) -> Option<&'a str> {
+    checksums.lines().find_map(|line| {
+        let (hash, file) = line.split_once(char::is_whitespace)?;
+        // Files hashed in binary mode are marked with `*`
+        (file.trim_start().trim_start_matches('*') == name).then_some(hash)
+    })
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    Sha256::digest(bytes)
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+pub fn binary_asset_name() -> String {
+    format!("para-{}-{}", std::env::consts::ARCH, std::env::consts::OS)
+}
+
+/// `major.minor.patch` of a version such as `v1.2.3` or `1.2.3-beta.1`
+pub fn parse_version(version: &str) -> Option<(u64, u64, u64)> {
+    let version = version.trim().trim_start_matches('v');
+    let core = version.split(['-', '+']).next()?;
+    let mut parts = core.split('.').map(|part| part.parse::<u64>().ok());
+    let major = parts.next()??;
+    let minor = parts.next().unwrap_or(Some(0))?;
+    let patch = parts.next().unwrap_or(Some(0))?;
+    if parts.next().is_some() {
+        return None;
+    }
+    Some((major, minor, patch))
+}
+
+/// Whether `latest` is a newer version than `current`; unparseable versions
+/// never are
+pub fn is_newer(latest: &str, current: &str) -> bool {
+    match (parse_version(latest), parse_version(current)) {
+        (Some(latest), Some(current)) => latest > current,
+        _ => false,
+    }
+}
+
+/// How the running binary was installed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InstallMethod {
+    Homebrew,
+    Cargo,
+    /// A binary copied into place; it can only be replaced if its directory
+    /// is writable
+    Binary {
+        writable: bool,
+    },
+}
+
+impl InstallMethod {
+    /// Tell the install method from where the executable lives
+    pub fn detect(exe: &Path, writable: bool) -> Self {
+        let path = exe.to_string_lossy();
+        if path.contains("/Cellar/")
+            || path.starts_with("/opt/homebrew/")
+            || path.starts_with("/home/linuxbrew/.linuxbrew/")
+        {
+            InstallMethod::Homebrew
+        } else if path.contains("/.cargo/bin/") {
+            InstallMethod::Cargo
+        } else {
+            InstallMethod::Binary { writable }
+        }
+    }
+
+    /// The current executable's install method
+    pub fn current() -> Result<(Self, PathBuf)> {
+        let exe = std::env::current_exe()
+            .and_then(fs::canonicalize)
+            .map_err(|e| ParaError::fs_error(format!("Failed to locate the para binary: {e}")))?;
+        let writable = exe.parent().is_some_and(is_writable_dir);
+        Ok((Self::detect(&exe, writable), exe))
+    }
+
+    /// Command that updates this install to `release`
+    pub fn upgrade_command(&self, release: &Release) -> String {
+        match self {
+            InstallMethod::Homebrew => format!("brew upgrade {HOMEBREW_FORMULA}"),
+            InstallMethod::Cargo => format!(
+                "cargo install --git {REPOSITORY_URL} --tag {} --force",
+                release.tag_name
+            ),
+            InstallMethod::Binary { .. } => "para self-update".to_string(),
+        }
+    }
+}
+
+fn is_writable_dir(dir: &Path) -> bool {
+    use std::os::unix::ffi::OsStrExt;
+    let Ok(path) = std::ffi::CString::new(dir.as_os_str().as_bytes()) else {
+        return false;
+    };
+    unsafe { libc::access(path.as_ptr(), libc::W_OK) == 0 }
+}
+
+/// Result of comparing the running version with the latest release
+#[derive(Debug, Clone)]
+pub struct UpdateCheck {
+    pub current: String,
+    pub latest: Release,
+}
+
+impl UpdateCheck {
+    pub fn update_available(&self) -> bool {
+        is_newer(self.latest.version(), &self.current)
+    }
+}
+
+pub fn check_for_update(client: &HttpClient, current: &str) -> Result<UpdateCheck> {
+    let latest: Release = client.get_json(LATEST_RELEASE_URL)?;
+    Ok(UpdateCheck {
+        current: current.to_string(),
+        latest,
+    })
+}
+
+/// Replace the binary at `exe` with the release's binary for this platform.
+///
+/// The binary must match its hash in the release's `checksums.txt`. It is
+/// written next to `exe` first and renamed over it, so a failed or corrupt
+/// download leaves the installed binary untouched.
+pub fn install_release(client: &HttpClient, release: &Release, exe: &Path) -> Result<()> {
+    let asset = release.binary_asset().ok_or_else(|| {
+        ParaError::invalid_args(format!(
+            "Release {} has no prebuilt binary for this platform ({}). Download it from {}",
+            release.tag_name,
+            binary_asset_name(),
+            release.html_url
+        ))
+    })?;
+    let checksums = release.asset(CHECKSUMS_ASSET).ok_or_else(|| {
+        ParaError::invalid_args(format!(
+            "Release {} publishes no {CHECKSUMS_ASSET} to verify its binary with. Download it from {}",
+            release.tag_name, release.html_url
+        ))
+    })?;
+    let checksums =
+        String::from_utf8_lossy(&client.get(&checksums.browser_download_url)?).into_owned();
+    let expected = published_checksum(&checksums, &asset.name).ok_or_else(|| {
+        ParaError::invalid_args(format!(
+            "{CHECKSUMS_ASSET} of release {} lists no checksum for {}",
+            release.tag_name, asset.name
+        ))
+    })?;
+
+    let binary = client.get(&asset.browser_download_url)?;
+    if !sha256_hex(&binary).eq_ignore_ascii_case(expected) {
+        return Err(ParaError::network_error(format!(
+            "The downloaded {} does not match its checksum in release {}; {} was left as it was",
+            asset.name,
+            release.tag_name,
+            exe.display()
+        )));
+    }
+
+    let dir = exe
+        .parent()
+        .ok_or_else(|| ParaError::fs_error("The para binary has no parent directory"))?;
+    let download = dir.join(format!(".para-update-{}", std::process::id()));
+    let result = fs::write(&download, binary)
+        .map_err(|e| ParaError::from_io("write", &download, e))
+        .and_then(|()| {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(&download, fs::Permissions::from_mode(0o755))
+                .map_err(|e| ParaError::from_io("make executable", &download, e))
+        })
+        .and_then(|()| {
+            fs::rename(&download, exe).map_err(|e| ParaError::from_io("replace", exe, e))
+        });
+    if result.is_err() {
+        let _ = fs::remove_file(&download);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::http::MockTransport;
+    use std::collections::HashMap;
+    use tempfile::TempDir;
+
+    fn release_json(tag: &str, assets: &[(&str, &str)]) -> Vec<u8> {
+        let assets: Vec<_> = assets
+            .iter()
+            .map(|(name, url)| serde_json::json!({"name": name, "browser_download_url": url}))
+            .collect();
+        serde_json::json!({
+            "tag_name": tag,
+            "html_url": format!("https://github.com/2mawi2/para/releases/tag/{tag}"),
+            "assets": assets,
+        })
+        .to_string()
+        .into_bytes()
+    }
+
+    #[test]
+    fn test_version_comparison() {
+        assert_eq!(parse_version("v1.2.3"), Some((1, 2, 3)));
+        assert_eq!(parse_version("1.2"), Some((1, 2, 0)));
+        assert_eq!(parse_version("2.0.0-beta.1"), Some((2, 0, 0)));
+        assert_eq!(parse_version("1.2.3.4"), None);
+        assert_eq!(parse_version("latest"), None);
+
+        assert!(is_newer("v1.1.39", "1.1.38"));
+        assert!(is_newer("1.10.0", "1.9.9"));
+        assert!(is_newer("2.0.0", "1.99.99"));
+        assert!(!is_newer("1.1.38", "1.1.38"));
+        assert!(!is_newer("v1.1.37", "1.1.38"));
+        assert!(!is_newer("nightly", "1.1.38"));
+    }
+
+    #[test]
+    fn test_install_method_detection() {
+        let cases = [
+            ("/opt/homebrew/bin/para", true, InstallMethod::Homebrew),
+            (
+                "/usr/local/Cellar/para/1.1.38/bin/para",
+                false,
+                InstallMethod::Homebrew,
+            ),
+            (
+                "/home/linuxbrew/.linuxbrew/bin/para",
+                true,
+                InstallMethod::Homebrew,
+            ),
+            ("/home/dev/.cargo/bin/para", true, InstallMethod::Cargo),
+            (
+                "/usr/local/bin/para",
+                false,
+                InstallMethod::Binary { writable: false },
+            ),
+            (
+                "/home/dev/bin/para",
+                true,
+                InstallMethod::Binary { writable: true },
+            ),
+        ];
+        for (path, writable, expected) in cases {
+            assert_eq!(
+                InstallMethod::detect(Path::new(path), writable),
+                expected,
+                "{path}"
+            );
+        }
+    }
+
+    const ASSET_URL: &str = "https://example.com/para-binary";
+    const CHECKSUMS_URL: &str = "https://example.com/checksums.txt";
+
+    /// A release whose binary is `binary` and whose checksums list `hash` for it
+    fn release_with_binary(binary: &[u8], hash: &str) -> (HttpClient, Release) {
+        let checksums = format!(
+            "{}  para-1.0.0.tar.gz\n{hash}  {}\n",
+            sha256_hex(b"tarball"),
+            binary_asset_name()
+        );
+        let client = HttpClient::new(MockTransport(HashMap::from([
+            (ASSET_URL.to_string(), binary.to_vec()),
+            (CHECKSUMS_URL.to_string(), checksums.into_bytes()),
+        ])));
+        let release = serde_json::from_slice(&release_json(
+            "v9.0.0",
+            &[
+                (&binary_asset_name(), ASSET_URL),
+                (CHECKSUMS_ASSET, CHECKSUMS_URL),
+            ],
+        ))
+        .unwrap();
+        (client, release)
+    }
+
+    #[test]
+    fn test_install_release_replaces_the_binary() {
+        let temp_dir = TempDir::new().unwrap();
+        let exe = temp_dir.path().join("para");
+        fs::write(&exe, "old").unwrap();
+        let (client, release) = release_with_binary(b"new", &sha256_hex(b"new"));
+
+        install_release(&client, &release, &exe).unwrap();
+        assert_eq!(fs::read_to_string(&exe).unwrap(), "new");
+        assert_eq!(fs::read_dir(temp_dir.path()).unwrap().count(), 1);
+
+        let without_binary: Release = serde_json::from_slice(&release_json("v9.0.0", &[])).unwrap();
+        let error = install_release(&client, &without_binary, &exe).unwrap_err();
+        assert!(error.to_string().contains("no prebuilt binary"));
+    }
+
+    #[test]
+    fn test_install_release_refuses_a_binary_that_fails_its_checksum() {
+        let temp_dir = TempDir::new().unwrap();
+        let exe = temp_dir.path().join("para");
+        fs::write(&exe, "old").unwrap();
+
+        let (client, release) = release_with_binary(b"tampered", &sha256_hex(b"new"));
+        let error = install_release(&client, &release, &exe).unwrap_err();
+        assert!(
+            error.to_string().contains("does not match its checksum"),
+            "{error}"
+        );
+        assert_eq!(fs::read_to_string(&exe).unwrap(), "old");
+        assert_eq!(fs::read_dir(temp_dir.path()).unwrap().count(), 1);
+
+        // Without checksums there is nothing to verify the binary against
+        let unverifiable: Release = serde_json::from_slice(&release_json(
+            "v9.0.0",
+            &[(&binary_asset_name(), ASSET_URL)],
+        ))
+        .unwrap();
+        let error = install_release(&client, &unverifiable, &exe).unwrap_err();
+        assert!(error.to_string().contains("checksums.txt"), "{error}");
+        assert_eq!(fs::read_to_string(&exe).unwrap(), "old");
+    }
+
+    #[test]
+    fn test_published_checksum() {
+        let checksums = "abc123  para-1.0.0.tar.gz\ndef456 *para-x86_64-linux\n";
+        assert_eq!(
+            published_checksum(checksums, "para-x86_64-linux"),
+            Some("def456")
+        );
+        assert_eq!(
+            published_checksum(checksums, "para-1.0.0.tar.gz"),
+            Some("abc123")
+        );
+        assert_eq!(published_checksum(checksums, "para-aarch64-macos"), None);
+        assert_eq!(
+            sha256_hex(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn test_check_for_update_reads_the_latest_release() {
+        let client = HttpClient::new(MockTransport(HashMap::from([(
+            LATEST_RELEASE_URL.to_string(),
+            release_json("v1.2.0", &[]),
+        )])));
+
+        let check = check_for_update(&client, "1.1.38").unwrap();
+        assert_eq!(check.latest.version(), "1.2.0");
+        assert!(check.update_available());
+        assert!(!check_for_update(&client, "1.2.0")
+            .unwrap()
+            .update_available());
+    }
+}
@@ -1,7 +1,30 @@
 pub mod archive;
+pub mod conflicts;
+pub mod context;
+pub mod creation_lock;
+pub mod finish_report;
+pub mod integration_backup;
+pub mod links;
 pub mod manager;
+pub mod manifest;
+pub mod migration;
+pub mod notes;
+pub mod operation;
+pub mod orphans;
+pub mod paths;
+pub mod pin;
 pub mod recovery;
+pub mod retry;
+pub mod rollback;
+pub mod stack;
 pub mod state;
+pub mod summary;
+pub mod sync;
+pub mod untracked;
 
 pub use manager::SessionManager;
-pub use state::{SessionState, SessionStatus, SessionType};
+pub use operation::{OperationGuard, OperationRecord, SessionOperation};
+pub use paths::SessionPaths;
+pub use state::{
+    ContainerConfig, SessionState, SessionStatus, SessionType, SKIP_PERMISSIONS_MARKER,
+};
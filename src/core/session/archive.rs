@@ -1,12 +1,16 @@
+use super::{pin, SessionManager};
 use crate::config::Config;
 use crate::core::git::{ArchiveBranchIterator, GitService, HasTimestamp};
-use crate::utils::{ArchiveBranchParser, Result};
+use crate::utils::{ArchiveBranchParser, Result, ARCHIVE_TIMESTAMP_FORMAT};
 use chrono::Utc;
+use std::path::PathBuf;
 
 #[derive(Debug, Clone)]
 pub struct ArchiveEntry {
     pub session_name: String,
     pub archived_at: String,
+    /// The session was pinned when it was cancelled, so retention keeps it
+    pub pinned: bool,
 }
 
 impl HasTimestamp for ArchiveEntry {
@@ -18,6 +22,7 @@ impl HasTimestamp for ArchiveEntry {
 pub struct ArchiveManager<'a> {
     config: &'a Config,
     git_service: &'a GitService,
+    state_dir: PathBuf,
 }
 
 impl<'a> ArchiveManager<'a> {
@@ -25,6 +30,9 @@ impl<'a> ArchiveManager<'a> {
         Self {
             config,
             git_service,
+            state_dir: SessionManager::for_git_service(config, git_service)
+                .state_dir()
+                .clone(),
         }
     }
 
@@ -42,13 +50,13 @@ impl<'a> ArchiveManager<'a> {
         let archives = self.list_archives()?;
         let mut removed_count = 0;
 
-        for archive in archives {
+        for archive in archives.into_iter().filter(|archive| !archive.pinned) {
             if let Ok(archived_date) = chrono::DateTime::parse_from_rfc3339(&archive.archived_at) {
                 if archived_date.with_timezone(&chrono::Utc) < cutoff_date {
                     let archive_branch_name = format!(
                         "{}/archived/{}/{}",
                         self.config.get_branch_prefix(),
-                        archived_date.format("%Y%m%d-%H%M%S"),
+                        archived_date.format(ARCHIVE_TIMESTAMP_FORMAT),
                         archive.session_name
                     );
 
@@ -67,8 +75,14 @@ impl<'a> ArchiveManager<'a> {
         Ok(removed_count)
     }
 
+    /// Delete the oldest archives beyond `max_archives`; pinned archives
+    /// neither count towards the limit nor get deleted
     pub fn enforce_archive_limit(&self, max_archives: usize) -> Result<usize> {
-        let archives = self.list_archives()?;
+        let archives: Vec<ArchiveEntry> = self
+            .list_archives()?
+            .into_iter()
+            .filter(|archive| !archive.pinned)
+            .collect();
 
         if archives.len() <= max_archives {
             return Ok(0);
@@ -82,7 +96,7 @@ impl<'a> ArchiveManager<'a> {
                 let archive_branch_name = format!(
                     "{}/archived/{}/{}",
                     self.config.get_branch_prefix(),
-                    archived_date.format("%Y%m%d-%H%M%S"),
+                    archived_date.format(ARCHIVE_TIMESTAMP_FORMAT),
                     archive.session_name
                 );
 
@@ -115,9 +129,12 @@ impl<'a> ArchiveManager<'a> {
         match archive_info {
             Some(info) => {
                 let archived_at = self.parse_timestamp_to_rfc3339(&info.timestamp);
+                let pinned =
+                    pin::is_archive_pinned(&self.state_dir, &info.timestamp, &info.session_name);
                 Ok(Some(ArchiveEntry {
                     session_name: info.session_name,
                     archived_at,
+                    pinned,
                 }))
             }
             None => Ok(None),
@@ -359,6 +376,47 @@ mod tests {
         assert_eq!(archives_after[0].session_name, recent_session);
     }
 
+    #[test]
+    fn test_retention_keeps_archives_of_pinned_sessions() {
+        let temp_dir = TempDir::new().unwrap();
+        let (_git_temp, git_service) = setup_test_repo();
+
+        let mut config = create_test_config_with_dir(&temp_dir);
+        config.session.auto_cleanup_days = Some(1);
+        let archive_manager = ArchiveManager::new(&config, &git_service);
+        let branch_manager = git_service.branch_manager();
+        let initial_branch = git_service.repository().get_current_branch().unwrap();
+
+        let old_timestamp = "20230101-120000";
+        for name in ["perf", "scratch"] {
+            branch_manager
+                .create_branch(
+                    &format!(
+                        "{}/archived/{old_timestamp}/{name}",
+                        config.get_branch_prefix()
+                    ),
+                    &initial_branch,
+                )
+                .unwrap();
+        }
+        // Creating a branch checks it out
+        git_service
+            .repository()
+            .checkout_branch(&initial_branch)
+            .unwrap();
+        let state_dir = SessionManager::for_git_service(&config, &git_service)
+            .state_dir()
+            .clone();
+        pin::archive_pin(&state_dir, old_timestamp, "perf").unwrap();
+
+        assert_eq!(archive_manager.cleanup_old_archives().unwrap(), 1);
+        assert_eq!(archive_manager.enforce_archive_limit(0).unwrap(), 0);
+        let archives = archive_manager.list_archives().unwrap();
+        assert_eq!(archives.len(), 1);
+        assert_eq!(archives[0].session_name, "perf");
+        assert!(archives[0].pinned);
+    }
+
     #[test]
     fn test_auto_cleanup_combined() {
         let temp_dir = TempDir::new().unwrap();
@@ -0,0 +1,351 @@
+//! Records of resolved integration conflicts
+//!
+//! When a sync stops on conflicts, the conflicted content of each file is
+//! stored as a git blob and noted in `<state_dir>/conflicts/pending/`. Once
+//! `para continue` carries on with the resolved files, the difference
+//! between the conflicted and the resolved content is appended to
+//! `<state_dir>/conflicts/<file>.jsonl`, one log per conflicted path with
+//! `/` written as `%2F`. `para conflicts show` reads them back and dispatch
+//! can name the files as hotspots in CLAUDE.local.md. Each log keeps the
+//! newest [`MAX_RECORDS_PER_FILE`] records; `para gc` ages out older ones.
+
+use crate::core::git::repository::execute_git_command;
+use crate::core::git::GitRepository;
+use crate::utils::{ParaError, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const CONFLICTS_DIR: &str = "conflicts";
+/// Records kept for each file
+pub const MAX_RECORDS_PER_FILE: usize = 20;
+const PENDING_DIR: &str = "pending";
+
+/// A conflict that is still being resolved
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PendingConflict {
+    pub session: String,
+    pub base_branch: String,
+    pub base_sha: String,
+    /// Session commit whose replay conflicted
+    pub session_sha: String,
+    pub files: Vec<ConflictedFile>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConflictedFile {
+    pub path: String,
+    /// Blob of the file with its conflict markers
+    pub blob: String,
+}
+
+/// How a conflict in one file was resolved
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConflictRecord {
+    pub timestamp: DateTime<Utc>,
+    pub session: String,
+    pub base_branch: String,
+    pub base_sha: String,
+    pub session_sha: String,
+    pub file: String,
+    /// Every file that conflicted together with `file`
+    pub files: Vec<String>,
+    /// Diff from the conflicted to the resolved content; empty when the
+    /// file was deleted
+    pub resolution: String,
+}
+
+pub fn conflicts_dir(state_dir: &Path) -> PathBuf {
+    state_dir.join(CONFLICTS_DIR)
+}
+
+fn pending_path(state_dir: &Path, session_name: &str) -> PathBuf {
+    conflicts_dir(state_dir)
+        .join(PENDING_DIR)
+        .join(format!("{session_name}.json"))
+}
+
+/// Log of the records of `file`
+pub fn record_log_path(state_dir: &Path, file: &str) -> PathBuf {
+    let name = file.replace('%', "%25").replace('/', "%2F");
+    conflicts_dir(state_dir).join(format!("{name}.jsonl"))
+}
+
+fn file_of_log(path: &Path) -> Option<String> {
+    let name = path.file_name()?.to_str()?.strip_suffix(".jsonl")?;
+    Some(name.replace("%2F", "/").replace("%25", "%"))
+}
+
+/// Remember the conflicted content of `files` while a rebase of the session
+/// onto `base` is stopped
+pub fn capture_pending(
+    repo: &GitRepository,
+    state_dir: &Path,
+    session_name: &str,
+    base: &str,
+    files: &[String],
+) -> Result<()> {
+    let files = files
+        .iter()
+        .map(|path| {
+            Ok(ConflictedFile {
+                path: path.clone(),
+                blob: execute_git_command(repo, &["hash-object", "-w", "--", path])?,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let pending = PendingConflict {
+        session: session_name.to_string(),
+        base_branch: base.to_string(),
+        base_sha: repo.resolve_commit(base)?,
+        session_sha: execute_git_command(repo, &["rev-parse", "REBASE_HEAD"])?,
+        files,
+    };
+
+    let path = pending_path(state_dir, session_name);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| ParaError::from_io("create", parent, e))?;
+    }
+    fs::write(&path, serde_json::to_string_pretty(&pending)?)
+        .map_err(|e| ParaError::from_io("write", &path, e))
+}
+
+/// Turn the pending conflict of the session into records, comparing the
+/// captured content with the resolved files in the worktree. Returns the
+/// files recorded.
+pub fn record_resolutions(
+    repo: &GitRepository,
+    state_dir: &Path,
+    session_name: &str,
+) -> Result<Vec<String>> {
+    let path = pending_path(state_dir, session_name);
+    let Ok(content) = fs::read_to_string(&path) else {
+        return Ok(Vec::new());
+    };
+    let pending: PendingConflict = serde_json::from_str(&content)?;
+    let files: Vec<String> = pending.files.iter().map(|f| f.path.clone()).collect();
+    let timestamp = Utc::now();
+
+    for file in &pending.files {
+        let resolution = if repo.root.join(&file.path).exists() {
+            let resolved = execute_git_command(repo, &["hash-object", "-w", "--", &file.path])?;
+            execute_git_command(repo, &["diff", "--no-color", &file.blob, &resolved])?
+        } else {
+            String::new()
+        };
+        append_record(
+            state_dir,
+            &ConflictRecord {
+                timestamp,
+                session: pending.session.clone(),
+                base_branch: pending.base_branch.clone(),
+                base_sha: pending.base_sha.clone(),
+                session_sha: pending.session_sha.clone(),
+                file: file.path.clone(),
+                files: files.clone(),
+                resolution,
+            },
+        )?;
+    }
+
+    fs::remove_file(&path).map_err(|e| ParaError::from_io("remove", &path, e))?;
+    Ok(files)
+}
+
+/// Append `record` to the log of its file, keeping the newest
+/// [`MAX_RECORDS_PER_FILE`]
+fn append_record(state_dir: &Path, record: &ConflictRecord) -> Result<()> {
+    let path = record_log_path(state_dir, &record.file);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| ParaError::from_io("create", parent, e))?;
+    }
+    let existing = fs::read_to_string(&path).unwrap_or_default();
+    let mut lines: Vec<String> = existing
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(str::to_string)
+        .collect();
+    lines.push(serde_json::to_string(record)?);
+    let kept = &lines[lines.len().saturating_sub(MAX_RECORDS_PER_FILE)..];
+    fs::write(&path, format!("{}\n", kept.join("\n")))
+        .map_err(|e| ParaError::from_io("write", &path, e))
+}
+
+/// Records of `file`, or of every file, oldest first
+pub fn load_records(state_dir: &Path, file: Option<&str>) -> Vec<ConflictRecord> {
+    let logs: Vec<PathBuf> = match file {
+        Some(file) => vec![record_log_path(state_dir, file)],
+        None => record_logs(state_dir),
+    };
+    let mut records: Vec<ConflictRecord> = logs
+        .iter()
+        .filter_map(|path| fs::read_to_string(path).ok())
+        .flat_map(|content| {
+            content
+                .lines()
+                .filter_map(|line| serde_json::from_str(line).ok())
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    records.sort_by_key(|record| record.timestamp);
+    records
+}
+
+/// Every record log in the state directory
+pub fn record_logs(state_dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(conflicts_dir(state_dir)) else {
+        return Vec::new();
+    };
+    let mut logs: Vec<PathBuf> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file() && file_of_log(path).is_some())
+        .collect();
+    logs.sort();
+    logs
+}
+
+/// Files that conflicted before when syncing with `base_branch` and that
+/// exist in `worktree`
+pub fn hotspots(state_dir: &Path, base_branch: &str, worktree: &Path) -> Vec<String> {
+    record_logs(state_dir)
+        .iter()
+        .filter_map(|path| file_of_log(path))
+        .filter(|file| worktree.join(file).exists())
+        .filter(|file| {
+            load_records(state_dir, Some(file))
+                .iter()
+                .any(|record| record.base_branch == base_branch)
+        })
+        .collect()
+}
+
+/// Section for CLAUDE.local.md naming the `files` that conflicted before
+pub fn hotspot_section(files: &[String]) -> String {
+    let list: String = files.iter().map(|file| format!("- `{file}`\n")).collect();
+    format!(
+        "\n## Known conflict hotspots\n\n\
+        Earlier sessions ran into merge conflicts in these files. Keep changes to them small \
+        and sync often:\n\n{list}"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::test_helpers::{commit_file, git, setup_test_repo};
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_log_path_round_trip() {
+        let state = Path::new("/state");
+        let path = record_log_path(state, "src/100%/lib.rs");
+        assert_eq!(
+            path,
+            Path::new("/state/conflicts/src%2F100%25%2Flib.rs.jsonl")
+        );
+        assert_eq!(file_of_log(&path).as_deref(), Some("src/100%/lib.rs"));
+    }
+
+    #[test]
+    fn test_records_resolution_of_a_conflict() {
+        let (_git_temp, git_service) = setup_test_repo();
+        let repo = git_service.repository();
+        let state = TempDir::new().unwrap();
+
+        let root = &repo.root;
+        git(root, &["checkout", "-b", "para/clash"]);
+        commit_file(root, "src/shared.txt", "session\n", "Change on the session");
+        git(root, &["checkout", "main"]);
+        commit_file(root, "src/shared.txt", "main\n", "Change on main");
+        git(root, &["checkout", "para/clash"]);
+        let session_sha = git(root, &["rev-parse", "HEAD"]);
+        assert!(execute_git_command(repo, &["rebase", "main"]).is_err());
+
+        let files = vec!["src/shared.txt".to_string()];
+        capture_pending(repo, state.path(), "clash", "main", &files).unwrap();
+        fs::write(repo.root.join("src/shared.txt"), "main and session\n").unwrap();
+        assert_eq!(
+            record_resolutions(repo, state.path(), "clash").unwrap(),
+            files
+        );
+        assert!(!pending_path(state.path(), "clash").exists());
+
+        let records = load_records(state.path(), Some("src/shared.txt"));
+        assert_eq!(records.len(), 1);
+        let record = &records[0];
+        assert_eq!(record.session, "clash");
+        assert_eq!(record.base_branch, "main");
+        assert_eq!(record.base_sha, repo.resolve_commit("main").unwrap());
+        assert_eq!(record.session_sha, session_sha);
+        assert_eq!(record.files, files);
+        assert!(record.resolution.contains("-<<<<<<< "));
+        assert!(record.resolution.contains("+main and session"));
+        assert_eq!(load_records(state.path(), None), records);
+
+        // Nothing is pending anymore
+        assert!(record_resolutions(repo, state.path(), "clash")
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_logs_keep_newest_records() {
+        let state = TempDir::new().unwrap();
+        for index in 0..MAX_RECORDS_PER_FILE + 3 {
+            append_record(
+                state.path(),
+                &ConflictRecord {
+                    timestamp: Utc::now(),
+                    session: format!("s{index}"),
+                    base_branch: "main".to_string(),
+                    base_sha: "abc".to_string(),
+                    session_sha: "def".to_string(),
+                    file: "a.txt".to_string(),
+                    files: vec!["a.txt".to_string()],
+                    resolution: String::new(),
+                },
+            )
+            .unwrap();
+        }
+        let records = load_records(state.path(), Some("a.txt"));
+        assert_eq!(records.len(), MAX_RECORDS_PER_FILE);
+        assert_eq!(records[0].session, "s3");
+    }
+
+    #[test]
+    fn test_hotspots_match_base_and_worktree() {
+        let state = TempDir::new().unwrap();
+        let worktree = TempDir::new().unwrap();
+        fs::create_dir_all(worktree.path().join("src")).unwrap();
+        fs::write(worktree.path().join("src/lib.rs"), "").unwrap();
+        fs::write(worktree.path().join("README.md"), "").unwrap();
+        for (file, base) in [
+            ("src/lib.rs", "main"),
+            ("gone.rs", "main"),
+            ("README.md", "dev"),
+        ] {
+            append_record(
+                state.path(),
+                &ConflictRecord {
+                    timestamp: Utc::now(),
+                    session: "old".to_string(),
+                    base_branch: base.to_string(),
+                    base_sha: "abc".to_string(),
+                    session_sha: "def".to_string(),
+                    file: file.to_string(),
+                    files: vec![file.to_string()],
+                    resolution: String::new(),
+                },
+            )
+            .unwrap();
+        }
+
+        let files = hotspots(state.path(), "main", worktree.path());
+        assert_eq!(files, vec!["src/lib.rs".to_string()]);
+        let section = hotspot_section(&files);
+        assert!(section.contains("## Known conflict hotspots"));
+        assert!(section.contains("- `src/lib.rs`"));
+    }
+}
@@ -0,0 +1,204 @@
+//! Keeping a session's context files when its worktree goes away
+//!
+//! Resume contexts, task appendices and other per-session instructions live
+//! in `.para/sessions/<name>/` inside the worktree. Cancelling a session (or
+//! finishing it with `session.preserve_on_finish`) copies that directory to
+//! `<state_dir>/archive/<name>/context/`, and recovering the session copies it
+//! back into the recreated worktree.
+
+use crate::utils::{ParaError, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Context files above this size are not archived
+pub const CONTEXT_FILE_LIMIT_BYTES: u64 = 1024 * 1024;
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ContextCopyReport {
+    /// Copied files, relative to the context directory
+    pub copied: Vec<PathBuf>,
+    /// Files above [`CONTEXT_FILE_LIMIT_BYTES`] with their size
+    pub skipped_large: Vec<(PathBuf, u64)>,
+}
+
+impl ContextCopyReport {
+    pub fn print_skipped(&self) {
+        for (path, size) in &self.skipped_large {
+            eprintln!(
+                "Note: Session context file {} ({} bytes) is too large to keep and was not archived",
+                path.display(),
+                size
+            );
+        }
+    }
+}
+
+/// `.para/sessions/<name>` inside a worktree
+pub fn worktree_context_dir(worktree_path: &Path, session_name: &str) -> PathBuf {
+    worktree_path
+        .join(".para")
+        .join("sessions")
+        .join(session_name)
+}
+
+pub fn archived_context_dir(state_dir: &Path, session_name: &str) -> PathBuf {
+    state_dir.join("archive").join(session_name).join("context")
+}
+
+/// Copy the session's context directory out of its worktree, replacing any
+/// earlier copy. Returns `None` when the session has no context files.
+pub fn archive_context(
+    state_dir: &Path,
+    session_name: &str,
+    worktree_path: &Path,
+) -> Result<Option<ContextCopyReport>> {
+    let source = worktree_context_dir(worktree_path, session_name);
+    if !source.is_dir() {
+        return Ok(None);
+    }
+
+    let target = archived_context_dir(state_dir, session_name);
+    if target.exists() {
+        fs::remove_dir_all(&target).map_err(|e| {
+            ParaError::fs_error(format!("Failed to replace archived session context: {e}"))
+        })?;
+    }
+    let mut report = ContextCopyReport::default();
+    copy_dir(&source, &target, Path::new(""), &mut report)?;
+    Ok(Some(report))
+}
+
+/// [`archive_context`] for cancel and finish, which go ahead when it fails
+pub fn keep_context(state_dir: &Path, session_name: &str, worktree_path: &Path) {
+    match archive_context(state_dir, session_name, worktree_path) {
+        Ok(Some(report)) => report.print_skipped(),
+        Ok(None) => {}
+        Err(e) => eprintln!("Warning: Failed to archive session context: {e}"),
+    }
+}
+
+/// Copy the context archived for `archived_name` into the worktree of
+/// `session_name`. Files already present in the worktree are kept.
+pub fn restore_context(
+    state_dir: &Path,
+    archived_name: &str,
+    worktree_path: &Path,
+    session_name: &str,
+) -> Result<Option<ContextCopyReport>> {
+    let source = archived_context_dir(state_dir, archived_name);
+    if !source.is_dir() {
+        return Ok(None);
+    }
+
+    let target = worktree_context_dir(worktree_path, session_name);
+    let mut report = ContextCopyReport::default();
+    copy_dir(&source, &target, Path::new(""), &mut report)?;
+    Ok(Some(report))
+}
+
+/// [`restore_context`] for recovery, which keeps the recovered session when it fails
+pub fn bring_back_context(
+    state_dir: &Path,
+    archived_name: &str,
+    worktree_path: &Path,
+    session_name: &str,
+) {
+    match restore_context(state_dir, archived_name, worktree_path, session_name) {
+        Ok(Some(report)) if !report.copied.is_empty() => {
+            println!(
+                "📝 Restored {} session context file(s)",
+                report.copied.len()
+            )
+        }
+        Ok(_) => {}
+        Err(e) => eprintln!("Warning: Failed to restore session context: {e}"),
+    }
+}
+
+fn copy_dir(
+    source: &Path,
+    target: &Path,
+    relative: &Path,
+    report: &mut ContextCopyReport,
+) -> Result<()> {
+    let copy_error = |e: std::io::Error| {
+        ParaError::fs_error(format!(
+            "Failed to copy session context {}: {e}",
+            source.join(relative).display()
+        ))
+    };
+
+    fs::create_dir_all(target.join(relative)).map_err(copy_error)?;
+    for entry in fs::read_dir(source.join(relative)).map_err(copy_error)? {
+        let entry = entry.map_err(copy_error)?;
+        let path = relative.join(entry.file_name());
+        let file_type = entry.file_type().map_err(copy_error)?;
+        if file_type.is_dir() {
+            copy_dir(source, target, &path, report)?;
+        } else if file_type.is_file() {
+            let size = entry.metadata().map_err(copy_error)?.len();
+            if size > CONTEXT_FILE_LIMIT_BYTES {
+                report.skipped_large.push((path, size));
+            } else if !target.join(&path).exists() {
+                fs::copy(entry.path(), target.join(&path)).map_err(copy_error)?;
+                report.copied.push(path);
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_archive_skips_large_files_and_restore_keeps_existing() {
+        let state_dir = TempDir::new().unwrap();
+        let worktree = TempDir::new().unwrap();
+        let context = worktree_context_dir(worktree.path(), "auth");
+        fs::create_dir_all(context.join("notes")).unwrap();
+        fs::write(context.join("resume_context.md"), "# Resume Context").unwrap();
+        fs::write(context.join("notes/task.md"), "extra").unwrap();
+        fs::write(
+            context.join("dump.log"),
+            vec![b'x'; CONTEXT_FILE_LIMIT_BYTES as usize + 1],
+        )
+        .unwrap();
+
+        let report = archive_context(state_dir.path(), "auth", worktree.path())
+            .unwrap()
+            .unwrap();
+        assert_eq!(report.copied.len(), 2);
+        assert_eq!(
+            report.skipped_large,
+            vec![(PathBuf::from("dump.log"), CONTEXT_FILE_LIMIT_BYTES + 1)]
+        );
+        assert!(archived_context_dir(state_dir.path(), "auth")
+            .join("notes/task.md")
+            .exists());
+
+        let recreated = TempDir::new().unwrap();
+        let restored_dir = worktree_context_dir(recreated.path(), "auth-2");
+        fs::create_dir_all(&restored_dir).unwrap();
+        fs::write(restored_dir.join("resume_context.md"), "newer").unwrap();
+        let report = restore_context(state_dir.path(), "auth", recreated.path(), "auth-2")
+            .unwrap()
+            .unwrap();
+        assert_eq!(report.copied, vec![PathBuf::from("notes/task.md")]);
+        assert_eq!(
+            fs::read_to_string(restored_dir.join("resume_context.md")).unwrap(),
+            "newer"
+        );
+
+        assert!(archive_context(state_dir.path(), "none", worktree.path())
+            .unwrap()
+            .is_none());
+        assert!(
+            restore_context(state_dir.path(), "none", recreated.path(), "none")
+                .unwrap()
+                .is_none()
+        );
+    }
+}
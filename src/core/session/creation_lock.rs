@@ -0,0 +1,95 @@
+//! Serializing session creation within a repository
+//!
+//! Picking a session name, creating its branch and adding its worktree must
+//! happen as one step, or two concurrent `para dispatch` calls can pick the
+//! same generated name or run `git worktree add` over each other. The lock is
+//! a file lock in the repository's state directory, so it covers separate
+//! processes as well as threads. It is reentrant within a thread, so code that
+//! holds it can call session manager methods that take it again.
+
+use crate::utils::{ParaError, Result};
+use fs2::FileExt;
+use std::cell::Cell;
+use std::fs::{self, File, OpenOptions};
+use std::path::Path;
+
+const LOCK_FILE: &str = "creation.lock";
+
+thread_local! {
+    static HELD: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Held until dropped
+#[derive(Debug)]
+pub struct CreationLock {
+    /// `None` for a nested acquisition on a thread that already holds the lock
+    file: Option<File>,
+}
+
+impl CreationLock {
+    /// Block until no other process or thread is creating a session in `state_dir`
+    pub fn acquire(state_dir: &Path) -> Result<Self> {
+        if HELD.with(Cell::get) > 0 {
+            HELD.with(|held| held.set(held.get() + 1));
+            return Ok(Self { file: None });
+        }
+
+        fs::create_dir_all(state_dir)
+            .map_err(|e| ParaError::fs_error(format!("Failed to create state directory: {e}")))?;
+        let path = state_dir.join(LOCK_FILE);
+        let file = OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(&path)
+            .map_err(|e| ParaError::fs_error(format!("Failed to open {}: {e}", path.display())))?;
+        file.lock_exclusive()
+            .map_err(|e| ParaError::fs_error(format!("Failed to lock {}: {e}", path.display())))?;
+
+        HELD.with(|held| held.set(1));
+        Ok(Self { file: Some(file) })
+    }
+}
+
+impl Drop for CreationLock {
+    fn drop(&mut self) {
+        HELD.with(|held| held.set(held.get().saturating_sub(1)));
+        if let Some(file) = &self.file {
+            let _ = FileExt::unlock(file);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::Duration;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_lock_is_reentrant_and_excludes_other_threads() {
+        let state_dir = TempDir::new().unwrap();
+        let outer = CreationLock::acquire(state_dir.path()).unwrap();
+        let inner = CreationLock::acquire(state_dir.path()).unwrap();
+        drop(inner);
+
+        let acquired = Arc::new(AtomicBool::new(false));
+        let waiter = {
+            let acquired = Arc::clone(&acquired);
+            let path = state_dir.path().to_path_buf();
+            thread::spawn(move || {
+                let _lock = CreationLock::acquire(&path).unwrap();
+                acquired.store(true, Ordering::SeqCst);
+            })
+        };
+
+        thread::sleep(Duration::from_millis(100));
+        assert!(!acquired.load(Ordering::SeqCst));
+        drop(outer);
+        waiter.join().unwrap();
+        assert!(acquired.load(Ordering::SeqCst));
+    }
+}
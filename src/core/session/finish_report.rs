@@ -0,0 +1,174 @@
+//! Result of a non-interactive finish, for the agent that asked for it
+//!
+//! An agent that finishes through the MCP server or a container signal file
+//! cannot see the terminal, so it has no way to tell a finished session from
+//! one whose integration failed or conflicted. After such a finish, para
+//! writes `<worktree>/.para/finish_result.json` if the worktree is still
+//! there, and the MCP finish tool returns the same object. The file is
+//! removed when the next finish starts, so a report on disk always belongs to
+//! the latest attempt.
+
+use super::untracked::ensure_ignored_in_worktree;
+use crate::utils::{ParaError, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Version of [`FinishReport`]; bumped when a field changes meaning or goes away
+pub const FINISH_REPORT_VERSION: u32 = 1;
+const REPORT_FILE: &str = "finish_result.json";
+
+/// What became of one finish attempt
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FinishReport {
+    pub version: u32,
+    pub result: FinishReportResult,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub session: Option<String>,
+    /// Branch holding the finished work, also when integrating it failed
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub final_branch: Option<String>,
+    /// Why the finish or the integration after it failed
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub integration_error: Option<String>,
+    /// Files that conflicted, relative to the repository root
+    #[serde(default)]
+    pub conflicted_files: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FinishReportResult {
+    /// The session was finished as `final_branch`
+    Success,
+    /// The session was finished, but committing it onto another session
+    /// (`--onto-session`) failed
+    SuccessWithIntegrationFailure,
+    /// The branch conflicts with its base; nothing was finished
+    Conflicts,
+    /// The finish failed for another reason; nothing was finished
+    Failed,
+}
+
+impl FinishReport {
+    fn new(result: FinishReportResult, session: Option<&str>) -> Self {
+        Self {
+            version: FINISH_REPORT_VERSION,
+            result,
+            session: session.map(str::to_string),
+            final_branch: None,
+            integration_error: None,
+            conflicted_files: Vec::new(),
+        }
+    }
+
+    pub fn success(session: Option<&str>, final_branch: &str) -> Self {
+        Self {
+            final_branch: Some(final_branch.to_string()),
+            ..Self::new(FinishReportResult::Success, session)
+        }
+    }
+
+    pub fn integration_failed(
+        session: Option<&str>,
+        final_branch: &str,
+        error: &ParaError,
+    ) -> Self {
+        Self {
+            final_branch: Some(final_branch.to_string()),
+            integration_error: Some(error.to_string()),
+            ..Self::new(FinishReportResult::SuccessWithIntegrationFailure, session)
+        }
+    }
+
+    /// A finish that failed with `error`; conflicts keep their file list
+    pub fn failed(session: Option<&str>, error: &ParaError) -> Self {
+        let (result, conflicted_files) = match error {
+            ParaError::IntegrationConflict { files, .. } => {
+                (FinishReportResult::Conflicts, files.clone())
+            }
+            _ => (FinishReportResult::Failed, Vec::new()),
+        };
+        Self {
+            integration_error: Some(error.to_string()),
+            conflicted_files,
+            ..Self::new(result, session)
+        }
+    }
+}
+
+pub fn report_path(worktree: &Path) -> PathBuf {
+    worktree.join(".para").join(REPORT_FILE)
+}
+
+/// Write `report` for the agent working in `worktree`, out of sight of
+/// `git status`
+pub fn write(worktree: &Path, report: &FinishReport) -> Result<()> {
+    let path = report_path(worktree);
+    // The report must not end up in the next finish of a kept worktree;
+    // outside a repository there is nothing to ignore it in
+    let _ = ensure_ignored_in_worktree(worktree, &format!(".para/{REPORT_FILE}"));
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| ParaError::from_io("create", dir, e))?;
+    }
+    let json = serde_json::to_string_pretty(report)
+        .map_err(|e| ParaError::fs_error(format!("Failed to serialize finish report: {e}")))?;
+    fs::write(&path, json).map_err(|e| ParaError::from_io("write", &path, e))
+}
+
+/// Remove the report of the previous attempt
+pub fn clear(worktree: &Path) -> Result<()> {
+    let path = report_path(worktree);
+    match fs::remove_file(&path) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(ParaError::from_io("remove", &path, e)),
+    }
+}
+
+pub fn read(worktree: &Path) -> Result<Option<FinishReport>> {
+    let path = report_path(worktree);
+    match fs::read_to_string(&path) {
+        Ok(content) => Ok(Some(serde_json::from_str(&content)?)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(ParaError::from_io("read", &path, e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_report_round_trips_through_the_worktree() {
+        let worktree = TempDir::new().unwrap();
+        assert_eq!(read(worktree.path()).unwrap(), None);
+
+        let report = FinishReport::success(Some("auth"), "para/auth");
+        write(worktree.path(), &report).unwrap();
+        assert_eq!(read(worktree.path()).unwrap(), Some(report));
+
+        clear(worktree.path()).unwrap();
+        assert!(!report_path(worktree.path()).exists());
+        clear(worktree.path()).unwrap();
+    }
+
+    #[test]
+    fn test_conflicts_keep_the_file_list() {
+        let error = ParaError::integration_conflict_in(
+            "Rebasing 'para/auth' onto 'main' hit conflicts",
+            vec!["src/lib.rs".to_string()],
+        );
+        let json = serde_json::to_value(FinishReport::failed(Some("auth"), &error)).unwrap();
+
+        assert_eq!(json["version"], FINISH_REPORT_VERSION);
+        assert_eq!(json["result"], "conflicts");
+        assert_eq!(json["conflicted_files"], serde_json::json!(["src/lib.rs"]));
+        assert!(json.get("final_branch").is_none());
+
+        let other = FinishReport::failed(None, &ParaError::git_operation("index.lock exists"));
+        assert_eq!(other.result, FinishReportResult::Failed);
+        assert!(other.conflicted_files.is_empty());
+    }
+}
@@ -0,0 +1,411 @@
+//! Backups taken before a session is integrated into another session's branch
+//!
+//! `para finish --onto-session` commits the session's changes on the parent
+//! branch. Right before that, the parent branch is copied to
+//! `<branch>-pre-para-<timestamp>` and the integration is recorded in
+//! `<state_dir>/<session>.integration.json`. `para rollback-integration` uses
+//! the record to reset the parent branch and make the session active again.
+//! `para clean` deletes backups older than the retention's `max_age_days`.
+
+use super::stack::integrate_into;
+use super::{SessionManager, SessionState, SessionStatus};
+use crate::core::git::apply::{Applied, ApplyWhitespace};
+use crate::core::git::repository::execute_git_command;
+use crate::core::git::{GitRepository, GitService};
+use crate::core::interrupt::CancelToken;
+use crate::utils::names::resolve_session_name;
+use crate::utils::{ParaError, Result};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Marks backup branches, which are never para session branches
+pub const BACKUP_MARKER: &str = "-pre-para-";
+const RECORD_SUFFIX: &str = ".integration.json";
+const BACKUP_TIMESTAMP_FORMAT: &str = "%Y%m%d-%H%M%S";
+
+/// One integration of a session into a parent session's branch
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IntegrationRecord {
+    pub session: String,
+    /// Branch the session was finished as
+    pub session_branch: String,
+    pub base_session: String,
+    pub base_branch: String,
+    pub backup_branch: String,
+    pub pre_integration_sha: String,
+    pub post_integration_sha: String,
+    pub created_at: DateTime<Utc>,
+}
+
+pub fn is_backup_branch(name: &str) -> bool {
+    name.contains(BACKUP_MARKER)
+}
+
+fn record_path(state_dir: &Path, session: &str) -> PathBuf {
+    state_dir.join(format!("{session}{RECORD_SUFFIX}"))
+}
+
+/// Integrate `branch` into `parent` like `stack::integrate_into`, after
+/// backing up the parent branch. The backup is removed again if the
+/// integration fails.
+pub fn integrate_with_backup(
+    manager: &SessionManager,
+    repo: &GitRepository,
+    session: &str,
+    parent: &SessionState,
+    branch: &str,
+    message: &str,
+    whitespace: ApplyWhitespace,
+) -> Result<(Applied, IntegrationRecord)> {
+    let created_at = Utc::now();
+    let pre_integration_sha = repo.resolve_commit(&parent.branch)?;
+    let backup_branch = format!(
+        "{}{BACKUP_MARKER}{}",
+        parent.branch,
+        created_at.format(BACKUP_TIMESTAMP_FORMAT)
+    );
+    execute_git_command(repo, &["branch", &backup_branch, &pre_integration_sha])?;
+
+    let cancel = CancelToken::global();
+    let step = {
+        let (repo, worktree) = (repo.clone(), parent.worktree_path.clone());
+        let (backup_branch, pre_integration_sha) =
+            (backup_branch.clone(), pre_integration_sha.clone());
+        cancel.on_abort(move || {
+            // The parent worktree was clean before the integration started
+            if let Ok(worktree) = GitRepository::discover_from(&worktree) {
+                let _ = execute_git_command(&worktree, &["reset", "--hard", &pre_integration_sha]);
+            }
+            let _ = execute_git_command(&repo, &["branch", "-D", &backup_branch]);
+        })
+    };
+    let applied = match integrate_into(parent, branch, message, whitespace) {
+        Ok(applied) => applied,
+        Err(e) => {
+            let _ = execute_git_command(repo, &["branch", "-D", &backup_branch]);
+            return Err(e);
+        }
+    };
+    cancel.checkpoint()?;
+
+    let record = IntegrationRecord {
+        session: session.to_string(),
+        session_branch: branch.to_string(),
+        base_session: parent.name.clone(),
+        base_branch: parent.branch.clone(),
+        backup_branch,
+        pre_integration_sha,
+        post_integration_sha: repo.resolve_commit(&parent.branch)?,
+        created_at,
+    };
+    let path = record_path(manager.state_dir(), session);
+    let json = serde_json::to_string_pretty(&record)
+        .map_err(|e| ParaError::fs_error(format!("Failed to serialize integration record: {e}")))?;
+    fs::write(&path, json).map_err(|e| ParaError::from_io("write", &path, e))?;
+    step.complete();
+
+    Ok((applied, record))
+}
+
+/// Integration records in `state_dir`, oldest first
+pub fn list_records(state_dir: &Path) -> Result<Vec<IntegrationRecord>> {
+    let mut records = Vec::new();
+    let Ok(entries) = fs::read_dir(state_dir) else {
+        return Ok(records);
+    };
+    for entry in entries {
+        let path = entry?.path();
+        let is_record = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.ends_with(RECORD_SUFFIX));
+        if !is_record {
+            continue;
+        }
+        let content =
+            fs::read_to_string(&path).map_err(|e| ParaError::from_io("read", &path, e))?;
+        match serde_json::from_str(&content) {
+            Ok(record) => records.push(record),
+            Err(e) => eprintln!("Warning: Skipping unreadable {}: {e}", path.display()),
+        }
+    }
+    records.sort_by_key(|record: &IntegrationRecord| record.created_at);
+    Ok(records)
+}
+
+/// Reset the base branch of the integration of `session`, or of the latest
+/// integration, to its backup and make the integrated session active again.
+/// Refuses when the base branch moved on since the integration.
+pub fn rollback(
+    manager: &SessionManager,
+    git_service: &GitService,
+    session: Option<&str>,
+) -> Result<IntegrationRecord> {
+    let records = list_records(manager.state_dir())?;
+    let record = match session {
+        Some(query) if records.is_empty() => {
+            return Err(ParaError::invalid_args(format!(
+                "No integration of session '{query}' to roll back"
+            )))
+        }
+        Some(query) => {
+            let mut names: Vec<String> = records
+                .iter()
+                .map(|record| record.session.clone())
+                .collect();
+            names.sort();
+            names.dedup();
+            let name = resolve_session_name(query, &names)?;
+            records.into_iter().find(|record| record.session == name)
+        }
+        None => records.into_iter().last(),
+    }
+    .ok_or_else(|| ParaError::invalid_args("No integration to roll back"))?;
+
+    let repo = git_service.repository();
+    let base_tip = repo.resolve_commit(&record.base_branch)?;
+    if base_tip != record.post_integration_sha {
+        return Err(ParaError::invalid_args(format!(
+            "'{}' has new commits since '{}' was integrated ({} is now at {}, expected {}). \
+             Reset it by hand to '{}' if those commits should go as well",
+            record.base_branch,
+            record.session,
+            record.base_branch,
+            short(&base_tip),
+            short(&record.post_integration_sha),
+            record.backup_branch
+        )));
+    }
+    if repo.resolve_commit(&record.backup_branch).ok().as_deref()
+        != Some(record.pre_integration_sha.as_str())
+    {
+        return Err(ParaError::invalid_args(format!(
+            "Backup branch '{}' is missing or was moved; cannot roll back '{}'",
+            record.backup_branch, record.base_branch
+        )));
+    }
+
+    reset_base_branch(manager, repo, &record)?;
+    restore_session(manager, git_service, &record)?;
+
+    let _ = execute_git_command(repo, &["branch", "-D", &record.backup_branch]);
+    let path = record_path(manager.state_dir(), &record.session);
+    fs::remove_file(&path).map_err(|e| ParaError::from_io("remove", &path, e))?;
+    Ok(record)
+}
+
+/// Move the base branch back, through the base session's worktree when it
+/// has the branch checked out
+fn reset_base_branch(
+    manager: &SessionManager,
+    repo: &GitRepository,
+    record: &IntegrationRecord,
+) -> Result<()> {
+    let base_worktree = manager
+        .load_state(&record.base_session)
+        .ok()
+        .filter(|base| base.branch == record.base_branch && base.worktree_path.exists())
+        .and_then(|base| GitRepository::discover_from(&base.worktree_path).ok());
+
+    match base_worktree {
+        Some(worktree) => {
+            if worktree.has_uncommitted_changes()? {
+                return Err(ParaError::invalid_args(format!(
+                    "Session '{}' has uncommitted changes. Commit or stash them before rolling back",
+                    record.base_session
+                )));
+            }
+            execute_git_command(&worktree, &["reset", "--hard", &record.backup_branch])?;
+        }
+        None => {
+            execute_git_command(
+                repo,
+                &["branch", "-f", &record.base_branch, &record.backup_branch],
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Put the finished session back to work on the branch it was finished as
+fn restore_session(
+    manager: &SessionManager,
+    git_service: &GitService,
+    record: &IntegrationRecord,
+) -> Result<()> {
+    let Ok(mut session) = manager.load_state(&record.session) else {
+        eprintln!(
+            "Warning: Session '{}' no longer exists; adopt branch '{}' to continue working on it",
+            record.session, record.session_branch
+        );
+        return Ok(());
+    };
+
+    session.branch = record.session_branch.clone();
+    session.status = SessionStatus::Active;
+    if !session.worktree_path.exists() {
+        session.worktree_path = git_service
+            .worktree_manager()
+            .create_worktree(&session.branch, &session.worktree_path)?;
+    }
+    manager.save_state(&session)
+}
+
+/// Delete backups and records older than `max_age_days`. Returns the number
+/// of removed backups.
+pub fn prune_expired(state_dir: &Path, repo: &GitRepository, max_age_days: u32) -> Result<usize> {
+    let cutoff = Utc::now() - Duration::days(i64::from(max_age_days));
+    let mut removed = 0;
+    for record in list_records(state_dir)? {
+        if record.created_at >= cutoff {
+            continue;
+        }
+        let _ = execute_git_command(repo, &["branch", "-D", &record.backup_branch]);
+        let path = record_path(state_dir, &record.session);
+        fs::remove_file(&path).map_err(|e| ParaError::from_io("remove", &path, e))?;
+        removed += 1;
+    }
+    Ok(removed)
+}
+
+fn short(sha: &str) -> &str {
+    &sha[..sha.len().min(8)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::test_helpers::*;
+    use tempfile::TempDir;
+
+    /// Integrate `child` into `parent` and retire the child the way finish does
+    fn finish_onto(
+        git_service: &GitService,
+        manager: &SessionManager,
+        child: &SessionState,
+        parent: &SessionState,
+    ) -> IntegrationRecord {
+        let (_, record) = integrate_with_backup(
+            manager,
+            git_service.repository(),
+            &child.name,
+            parent,
+            &child.branch,
+            "Child work",
+            ApplyWhitespace::default(),
+        )
+        .unwrap();
+        execute_git_command(
+            git_service.repository(),
+            &[
+                "worktree",
+                "remove",
+                "--force",
+                &child.worktree_path.to_string_lossy(),
+            ],
+        )
+        .unwrap();
+        let mut finished = manager.load_state(&child.name).unwrap();
+        finished.status = SessionStatus::Review;
+        manager.save_state(&finished).unwrap();
+        record
+    }
+
+    #[test]
+    fn test_rollback_restores_base_and_session() {
+        let temp_dir = TempDir::new().unwrap();
+        let (_git_temp, git_service) = setup_test_repo();
+        let config = create_test_config_with_dir(&temp_dir);
+        let manager = SessionManager::new(&config);
+        let repo = git_service.repository();
+
+        let parent = start_session(&git_service, &manager, "parent", "main");
+        commit_file(
+            &parent.worktree_path,
+            "parent.txt",
+            "parent.txt",
+            "Add parent.txt",
+        );
+        let child = start_session(&git_service, &manager, "child", &parent.branch);
+        commit_file(
+            &child.worktree_path,
+            "child.txt",
+            "child.txt",
+            "Add child.txt",
+        );
+        let parent_before = repo.resolve_commit(&parent.branch).unwrap();
+        let child_before = repo.resolve_commit(&child.branch).unwrap();
+
+        let record = finish_onto(&git_service, &manager, &child, &parent);
+        assert!(is_backup_branch(&record.backup_branch));
+        assert_eq!(record.pre_integration_sha, parent_before);
+        assert_ne!(repo.resolve_commit(&parent.branch).unwrap(), parent_before);
+
+        let rolled_back = rollback(&manager, &git_service, None).unwrap();
+        assert_eq!(rolled_back, record);
+        assert_eq!(repo.resolve_commit(&parent.branch).unwrap(), parent_before);
+        assert_eq!(repo.resolve_commit(&child.branch).unwrap(), child_before);
+        assert!(!parent.worktree_path.join("child.txt").exists());
+
+        let child = manager.load_state("child").unwrap();
+        assert_eq!(child.status, SessionStatus::Active);
+        assert!(child.worktree_path.join("child.txt").exists());
+
+        // The backup and the record are gone
+        assert!(repo.resolve_commit(&record.backup_branch).is_err());
+        assert!(list_records(manager.state_dir()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_rollback_refuses_when_base_moved_on() {
+        let temp_dir = TempDir::new().unwrap();
+        let (_git_temp, git_service) = setup_test_repo();
+        let config = create_test_config_with_dir(&temp_dir);
+        let manager = SessionManager::new(&config);
+        let repo = git_service.repository();
+
+        let parent = start_session(&git_service, &manager, "parent", "main");
+        let child = start_session(&git_service, &manager, "child", &parent.branch);
+        commit_file(
+            &child.worktree_path,
+            "child.txt",
+            "child.txt",
+            "Add child.txt",
+        );
+        let record = finish_onto(&git_service, &manager, &child, &parent);
+
+        commit_file(
+            &parent.worktree_path,
+            "later.txt",
+            "later.txt",
+            "Add later.txt",
+        );
+        let parent_tip = repo.resolve_commit(&parent.branch).unwrap();
+
+        let err = rollback(&manager, &git_service, Some("child")).unwrap_err();
+        assert!(err.to_string().contains("new commits"));
+        assert_eq!(repo.resolve_commit(&parent.branch).unwrap(), parent_tip);
+        assert_eq!(
+            repo.resolve_commit(&record.backup_branch).unwrap(),
+            record.pre_integration_sha
+        );
+        assert_eq!(
+            manager.load_state("child").unwrap().status,
+            SessionStatus::Review
+        );
+
+        // Expired backups are pruned
+        assert_eq!(prune_expired(manager.state_dir(), repo, 30).unwrap(), 0);
+        let mut old = record.clone();
+        old.created_at = Utc::now() - Duration::days(31);
+        fs::write(
+            record_path(manager.state_dir(), "child"),
+            serde_json::to_string(&old).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(prune_expired(manager.state_dir(), repo, 30).unwrap(), 1);
+        assert!(repo.resolve_commit(&record.backup_branch).is_err());
+    }
+}
@@ -0,0 +1,152 @@
+//! References a session carries to PRs, tickets and docs
+//!
+//! Links live in the session state as `label`/`url` pairs. Labels are unique
+//! per session, so adding a link under a label that is already taken points
+//! that label at the new URL.
+
+use crate::utils::{ParaError, Result};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionLink {
+    pub label: String,
+    pub url: String,
+}
+
+/// What [`add_link`] did to the session's links
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinkChange {
+    Added,
+    /// The label pointed at another URL before
+    Updated,
+    /// The label already pointed at this URL
+    Unchanged,
+}
+
+/// Reject anything but an absolute http(s) URL with a host
+pub fn validate_url(url: &str) -> Result<()> {
+    let invalid = |reason: &str| {
+        Err(ParaError::invalid_args(format!(
+            "Invalid link URL '{url}': {reason}"
+        )))
+    };
+
+    let Some(rest) = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+    else {
+        return invalid("only absolute http:// and https:// URLs are supported");
+    };
+    if url.chars().any(char::is_whitespace) {
+        return invalid("URLs cannot contain whitespace");
+    }
+    let host = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    if host.is_empty() || host.starts_with(':') {
+        return invalid("the URL has no host");
+    }
+    Ok(())
+}
+
+fn validate_label(label: &str) -> Result<&str> {
+    let label = label.trim();
+    if label.is_empty() {
+        return Err(ParaError::invalid_args("Link label cannot be empty"));
+    }
+    Ok(label)
+}
+
+/// Point `label` at `url`
+pub fn add_link(links: &mut Vec<SessionLink>, label: &str, url: &str) -> Result<LinkChange> {
+    let label = validate_label(label)?;
+    validate_url(url)?;
+
+    match links.iter_mut().find(|link| link.label == label) {
+        Some(link) if link.url == url => Ok(LinkChange::Unchanged),
+        Some(link) => {
+            link.url = url.to_string();
+            Ok(LinkChange::Updated)
+        }
+        None => {
+            links.push(SessionLink {
+                label: label.to_string(),
+                url: url.to_string(),
+            });
+            Ok(LinkChange::Added)
+        }
+    }
+}
+
+/// Remove the links with this label or URL; returns how many went
+pub fn remove_link(links: &mut Vec<SessionLink>, label_or_url: &str) -> usize {
+    let before = links.len();
+    links.retain(|link| link.label != label_or_url && link.url != label_or_url);
+    before - links.len()
+}
+
+/// `PR, JIRA-12` for one-line displays
+pub fn labels(links: &[SessionLink]) -> String {
+    links
+        .iter()
+        .map(|link| link.label.as_str())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_and_remove_are_idempotent() {
+        let mut links = Vec::new();
+        let pr = "https://github.com/acme/app/pull/42";
+
+        assert_eq!(add_link(&mut links, "PR", pr).unwrap(), LinkChange::Added);
+        assert_eq!(
+            add_link(&mut links, " PR ", pr).unwrap(),
+            LinkChange::Unchanged
+        );
+        assert_eq!(
+            add_link(&mut links, "Ticket", "http://jira.local/browse/APP-7").unwrap(),
+            LinkChange::Added
+        );
+        assert_eq!(
+            add_link(&mut links, "PR", "https://github.com/acme/app/pull/43").unwrap(),
+            LinkChange::Updated
+        );
+        assert_eq!(links.len(), 2);
+        assert_eq!(labels(&links), "PR, Ticket");
+
+        assert_eq!(remove_link(&mut links, "PR"), 1);
+        assert_eq!(remove_link(&mut links, "PR"), 0);
+        assert_eq!(remove_link(&mut links, "http://jira.local/browse/APP-7"), 1);
+        assert!(links.is_empty());
+    }
+
+    #[test]
+    fn test_url_validation() {
+        for url in [
+            "https://example.com",
+            "http://localhost:8080/docs?page=2#top",
+        ] {
+            assert!(validate_url(url).is_ok(), "{url}");
+        }
+        for url in [
+            "example.com/pull/1",
+            "ftp://example.com/file",
+            "https://",
+            "https:///path",
+            "https://:443/",
+            "https://example.com/a b",
+            "/relative/path",
+        ] {
+            let error = validate_url(url).unwrap_err().to_string();
+            assert!(error.contains("Invalid link URL"), "{url}: {error}");
+        }
+
+        let mut links = Vec::new();
+        assert!(add_link(&mut links, "PR", "not a url").is_err());
+        assert!(add_link(&mut links, "  ", "https://example.com").is_err());
+        assert!(links.is_empty());
+    }
+}
@@ -1,37 +1,81 @@
+use super::creation_lock::CreationLock;
+use super::manifest;
+use super::migration::{self, StateUpgrade};
+use super::operation::{self, OperationGuard, OperationRecord, SessionOperation};
+use super::paths::SessionPaths;
+use super::rollback::CreationRollback;
 use super::state::{SessionState, SessionStatus};
+use crate::config::state_dir::resolve_state_dir;
 use crate::config::Config;
-use crate::core::git::{GitOperations, GitService};
-use crate::utils::{get_main_repository_root_from, GitignoreManager, ParaError, Result};
+use crate::core::events::{self, SessionEventKind};
+use crate::core::git::GitService;
+use crate::utils::fs::{check_writable_with_space, write_atomic, FsOps, RealFs, MIN_FREE_BYTES};
+use crate::utils::{GitignoreManager, ParaError, Result};
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
+#[derive(Clone)]
 pub struct SessionManager {
     state_dir: PathBuf,
     config: Config,
+    /// Repository to create sessions in instead of the one at the current directory
+    repository_root: Option<PathBuf>,
+    /// Service for `repository_root` found by the caller, so it is not
+    /// discovered again
+    git_service: Option<GitService>,
+    fs: Arc<dyn FsOps>,
 }
 
 impl SessionManager {
     pub fn new(config: &Config) -> Self {
-        let state_dir = Self::resolve_state_dir(config);
         Self {
-            state_dir,
+            state_dir: config.resolved_state_dir(),
             config: config.clone(),
+            repository_root: None,
+            git_service: None,
+            fs: Arc::new(RealFs),
         }
     }
 
-    fn resolve_state_dir(config: &Config) -> PathBuf {
-        let state_dir_path = config.get_state_dir();
+    /// Manage sessions of the repository at `repository_root`, regardless of
+    /// the current directory
+    pub fn for_repository(config: &Config, repository_root: &Path) -> Self {
+        Self {
+            state_dir: resolve_state_dir(
+                &config.directories.state_dir,
+                config.directories.bare_root.as_deref(),
+                Some(repository_root),
+            ),
+            config: config.clone(),
+            repository_root: Some(repository_root.to_path_buf()),
+            git_service: None,
+            fs: Arc::new(RealFs),
+        }
+    }
 
-        if Path::new(state_dir_path).is_absolute() {
-            return PathBuf::from(state_dir_path);
+    /// Manage sessions of the repository `git_service` was discovered in
+    pub fn for_git_service(config: &Config, git_service: &GitService) -> Self {
+        Self {
+            git_service: Some(git_service.clone()),
+            ..Self::for_repository(config, &git_service.repository().root)
         }
+    }
 
-        // Use the reliable git rev-parse method to find the main repository root
-        if let Ok(main_repo_root) = get_main_repository_root_from(None) {
-            main_repo_root.join(state_dir_path)
-        } else {
-            // Fallback to current directory if not in a git repository
-            PathBuf::from(state_dir_path)
+    /// Write state files through `fs` instead of the real filesystem
+    #[cfg(test)]
+    pub(crate) fn with_fs_ops(mut self, fs: Arc<dyn FsOps>) -> Self {
+        self.fs = fs;
+        self
+    }
+
+    fn git_service(&self) -> Result<GitService> {
+        if let Some(git_service) = &self.git_service {
+            return Ok(git_service.clone());
+        }
+        match &self.repository_root {
+            Some(root) => GitService::discover_from(root),
+            None => GitService::discover(),
         }
     }
 
@@ -89,10 +133,13 @@ impl SessionManager {
         sandbox_enabled: bool,
         sandbox_profile: Option<String>,
     ) -> Result<SessionState> {
-        let git_service = GitService::discover()
+        let git_service = self
+            .git_service()
             .map_err(|e| ParaError::git_error(format!("Failed to discover git repository: {e}")))?;
+        let _creation_lock = self.lock_creation()?;
 
         let repository_root = git_service.repository().root.clone();
+        self.check_storage(&repository_root)?;
 
         GitignoreManager::ensure_para_ignored_in_repository(&repository_root)?;
 
@@ -116,8 +163,9 @@ impl SessionManager {
             &final_session_name,
         );
 
-        let subtrees_path = repository_root.join(&self.config.directories.subtrees_dir);
-        let worktree_path = subtrees_path.join(&final_session_name);
+        let subtrees_path = self.config.subtrees_path(&repository_root)?;
+        let worktree_path =
+            SessionPaths::worktree_path(&self.config, &repository_root, &final_session_name)?;
 
         if !subtrees_path.exists() {
             fs::create_dir_all(&subtrees_path).map_err(|e| {
@@ -129,16 +177,21 @@ impl SessionManager {
             }
         }
 
-        if worktree_path.exists() {
-            return Err(ParaError::file_operation(format!(
-                "Worktree path already exists: {}",
-                worktree_path.display()
-            )));
-        }
+        let mut rollback =
+            CreationRollback::new(&git_service, &self.state_dir, &final_session_name);
+        rollback.track_state_files();
+        let worktree_path = rollback.create_worktree(&branch_name, &worktree_path)?;
+        rollback.checkpoint()?;
+        crate::core::git::identity::apply_worktree_identity(
+            &worktree_path,
+            self.config.git.author_name.as_deref(),
+            self.config.git.author_email.as_deref(),
+        )?;
 
-        git_service.create_worktree(&branch_name, &worktree_path)?;
+        // Pin the fork point so later rewrites of the parent branch don't move it
+        let base_commit = git_service.repository().resolve_commit(&branch_name).ok();
 
-        let session_state = match session_type {
+        let mut session_state = match session_type {
             Some(super::state::SessionType::Container { container_id }) => {
                 // TODO: Add container support for sandbox settings
                 SessionState::new_container_with_parent_branch_and_flags(
@@ -160,8 +213,100 @@ impl SessionManager {
                 sandbox_profile,
             ),
         };
+        session_state.base_commit = base_commit;
+        session_state.merge_mode = self.config.get_default_merge_mode();
+
+        self.save_state(&session_state)?;
+        rollback.commit();
+
+        Ok(session_state)
+    }
+
+    /// Create a session for an existing branch, such as an orphaned para
+    /// branch. The session is named after the branch unless `name` is given
+    /// and is based on the main branch.
+    pub fn adopt_branch(&mut self, branch: &str, name: Option<String>) -> Result<SessionState> {
+        let git_service = self
+            .git_service()
+            .map_err(|e| ParaError::git_error(format!("Failed to discover git repository: {e}")))?;
+        let _creation_lock = self.lock_creation()?;
+
+        let prefix = self.config.get_branch_prefix();
+        if !git_service.branch_manager().branch_exists(branch)? {
+            return Err(ParaError::invalid_args(format!(
+                "Branch '{branch}' does not exist"
+            )));
+        }
+        if branch.starts_with(&format!("{prefix}/archived/")) {
+            return Err(ParaError::invalid_args(format!(
+                "Branch '{branch}' is archived; use 'para recover' to restore it"
+            )));
+        }
+        if let Some(owner) = self
+            .list_sessions()?
+            .into_iter()
+            .find(|s| s.branch == branch && !matches!(s.status, SessionStatus::Cancelled))
+        {
+            return Err(ParaError::invalid_args(format!(
+                "Branch '{branch}' already belongs to session '{}'",
+                owner.name
+            )));
+        }
+
+        let session_name = name.unwrap_or_else(|| {
+            branch
+                .strip_prefix(&format!("{prefix}/"))
+                .unwrap_or(branch)
+                .replace('/', "-")
+        });
+        crate::utils::validate_session_name(&session_name)?;
+        if self.session_exists(&session_name) {
+            return Err(ParaError::session_exists(&session_name));
+        }
+
+        let repository_root = git_service.repository().root.clone();
+        self.check_storage(&repository_root)?;
+        GitignoreManager::ensure_para_ignored_in_repository(&repository_root)?;
+
+        let parent_branch = git_service
+            .repository()
+            .get_main_branch()
+            .unwrap_or_else(|_| "main".to_string());
+        let worktree_path =
+            SessionPaths::worktree_path(&self.config, &repository_root, &session_name)?;
+
+        let mut rollback = CreationRollback::new(&git_service, &self.state_dir, &session_name);
+        rollback.track_state_files();
+        let worktree_path = rollback.create_worktree(branch, &worktree_path)?;
+        rollback.checkpoint()?;
+        crate::core::git::identity::apply_worktree_identity(
+            &worktree_path,
+            self.config.git.author_name.as_deref(),
+            self.config.git.author_email.as_deref(),
+        )?;
+
+        // The branch forked from main somewhere in its history, not at its tip
+        let base_commit = crate::core::git::repository::execute_git_command(
+            git_service.repository(),
+            &["merge-base", &parent_branch, branch],
+        )
+        .ok()
+        .map(|commit| commit.trim().to_string());
+
+        let mut session_state = SessionState::with_all_flags(
+            session_name,
+            branch.to_string(),
+            worktree_path,
+            parent_branch,
+            false,
+            false,
+            None,
+        );
+        session_state.base_commit = base_commit;
+        session_state.merge_mode = self.config.get_default_merge_mode();
 
         self.save_state(&session_state)?;
+        rollback.commit();
 
         Ok(session_state)
     }
@@ -182,45 +327,118 @@ impl SessionManager {
             ))
         })?;
 
-        let mut session: SessionState = serde_json::from_str(&content).map_err(|e| {
+        let mut value: serde_json::Value = serde_json::from_str(&content).map_err(|e| {
             ParaError::state_corruption(format!(
                 "Failed to parse session state from {}: {}",
                 state_file.display(),
                 e
             ))
         })?;
+        if let Some(migration) = migration::migrate(&mut value)? {
+            for warning in &migration.warnings {
+                eprintln!("Warning: {warning}");
+            }
+            if let Err(e) = self.write_migrated(&state_file, &value) {
+                crate::utils::debug_log(&format!(
+                    "Keeping {} at its old schema version: {e}",
+                    state_file.display()
+                ));
+            }
+        }
+
+        serde_json::from_value(value).map_err(|e| {
+            ParaError::state_corruption(format!(
+                "Failed to parse session state from {}: {}",
+                state_file.display(),
+                e
+            ))
+        })
+    }
 
-        // Handle backward compatibility - migrate is_docker to session_type
-        if let Some(is_docker) = session.is_docker {
-            if is_docker {
-                session.session_type = super::state::SessionType::Container { container_id: None };
+    /// Upgrade every state file to the current schema version, backing up the
+    /// state directory first. With `dry_run` nothing is written.
+    pub fn upgrade_states(&self, dry_run: bool) -> Result<Vec<StateUpgrade>> {
+        let mut files = self.collect_session_files()?;
+        files.sort();
+
+        let mut upgrades = Vec::new();
+        for file in files {
+            let content = fs::read_to_string(&file)
+                .map_err(|e| ParaError::from_io("read session state", &file, e))?;
+            let mut value: serde_json::Value = serde_json::from_str(&content).map_err(|e| {
+                ParaError::state_corruption(format!(
+                    "Failed to parse session state from {}: {e}",
+                    file.display()
+                ))
+            })?;
+            let version = migration::schema_version(&value);
+            let migration = migration::migrate(&mut value)?;
+            if migration.is_some() && !dry_run {
+                self.write_migrated(&file, &value)?;
             }
-            session.is_docker = None;
+            upgrades.push(StateUpgrade {
+                file,
+                version,
+                migration,
+            });
         }
+        Ok(upgrades)
+    }
 
-        Ok(session)
+    /// Write a migrated state file, after backing up the state directory once per run
+    fn write_migrated(&self, state_file: &Path, value: &serde_json::Value) -> Result<()> {
+        if let Some(backup) = migration::backup_before_migration(&self.state_dir)? {
+            crate::utils::debug_log(&format!(
+                "Backed up {} to {} before migrating state files",
+                self.state_dir.display(),
+                backup.display()
+            ));
+        }
+        let json = serde_json::to_string_pretty(value)?;
+        write_atomic(self.fs.as_ref(), state_file, json.as_bytes())
+            .map_err(|e| ParaError::from_io("save migrated session state to", state_file, e))
     }
 
     pub fn save_state(&self, session: &SessionState) -> Result<()> {
         self.ensure_state_dir_exists()?;
 
         let state_file = self.state_dir.join(format!("{}.state", session.name));
+        let previous = fs::read_to_string(&state_file)
+            .ok()
+            .map(|json| serde_json::from_str::<SessionState>(&json).map(|s| s.status));
         let json = serde_json::to_string_pretty(session)?;
-        fs::write(&state_file, json).map_err(|e| {
-            ParaError::file_operation(format!(
-                "Failed to save session state to {}: {}",
-                state_file.display(),
-                e
-            ))
-        })?;
+        write_atomic(self.fs.as_ref(), &state_file, json.as_bytes())
+            .map_err(|e| ParaError::from_io("save session state to", &state_file, e))?;
+
+        let kind = match (previous, &session.status) {
+            (None, _) => SessionEventKind::Created,
+            (Some(Ok(old)), new) if old == *new => SessionEventKind::Updated,
+            (_, SessionStatus::Review | SessionStatus::Finished) => SessionEventKind::Finished,
+            (_, SessionStatus::Cancelled) => SessionEventKind::Cancelled,
+            _ => SessionEventKind::Updated,
+        };
+        events::publish(&self.state_dir, &session.name, kind);
 
+        if let Err(e) = manifest::sync(session, &self.state_dir) {
+            eprintln!("Warning: Failed to update the session manifest: {e}");
+        }
         Ok(())
     }
 
+    /// Fail early when the state directory or the subtrees directory of
+    /// `repository_root` is read-only or nearly full, before anything is created
+    pub fn check_storage(&self, repository_root: &Path) -> Result<()> {
+        check_writable_with_space(&self.state_dir, MIN_FREE_BYTES)?;
+        check_writable_with_space(&self.config.subtrees_path(repository_root)?, MIN_FREE_BYTES)
+    }
+
     pub fn delete_state(&self, session_name: &str) -> Result<()> {
         // Delete the main state file
         let state_file = self.state_dir.join(format!("{session_name}.state"));
         if state_file.exists() {
+            if let Ok(session) = self.load_state(session_name) {
+                let _ = manifest::remove(&session.worktree_path);
+            }
             fs::remove_file(&state_file).map_err(|e| {
                 ParaError::file_operation(format!(
                     "Failed to delete session state {}: {}",
@@ -228,18 +446,21 @@ impl SessionManager {
                     e
                 ))
             })?;
+            events::publish(&self.state_dir, session_name, SessionEventKind::Cancelled);
         }
 
-        // Delete the status file
-        let status_file = self.state_dir.join(format!("{session_name}.status.json"));
-        if status_file.exists() {
-            fs::remove_file(&status_file).map_err(|e| {
-                ParaError::file_operation(format!(
-                    "Failed to delete session status {}: {}",
-                    status_file.display(),
-                    e
-                ))
-            })?;
+        // Delete the status file and its history
+        for suffix in ["status.json", "status.history.jsonl"] {
+            let status_file = self.state_dir.join(format!("{session_name}.{suffix}"));
+            if status_file.exists() {
+                fs::remove_file(&status_file).map_err(|e| {
+                    ParaError::file_operation(format!(
+                        "Failed to delete session status {}: {}",
+                        status_file.display(),
+                        e
+                    ))
+                })?;
+            }
         }
         Ok(())
     }
@@ -260,7 +481,7 @@ impl SessionManager {
             .collect::<Vec<_>>();
 
         let mut sessions = sessions;
-        sessions.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        sessions.sort_by_key(|s| std::cmp::Reverse(s.created_at));
         crate::utils::debug_log(&format!("Found {} sessions", sessions.len()));
         Ok(sessions)
     }
@@ -359,7 +580,7 @@ impl SessionManager {
             .collect();
 
         // Sort by path length descending - longest (most specific) path first
-        matching_sessions.sort_by(|a, b| b.1.as_os_str().len().cmp(&a.1.as_os_str().len()));
+        matching_sessions.sort_by_key(|m| std::cmp::Reverse(m.1.as_os_str().len()));
 
         if let Some((session, _)) = matching_sessions.first() {
             crate::utils::debug_log(&format!("Found matching session: {}", session.name));
@@ -382,6 +603,47 @@ impl SessionManager {
         Ok(None)
     }
 
+    /// The session a user-typed `query` names: the exact name, else a unique
+    /// prefix or substring match. Fails listing the candidates when several
+    /// match, or with the closest names when none do.
+    pub fn resolve_session(&self, query: &str) -> Result<SessionState> {
+        if self.session_exists(query) {
+            return self.load_state(query);
+        }
+        let names: Vec<String> = self
+            .list_sessions()?
+            .into_iter()
+            .map(|session| session.name)
+            .collect();
+        let name = crate::utils::names::resolve_session_name(query, &names)?;
+        self.load_state(&name)
+    }
+
+    /// The one non-cancelled session whose branch is `branch`, for commands that
+    /// take `--branch` instead of a session name
+    pub fn resolve_session_by_branch(&self, branch: &str) -> Result<SessionState> {
+        let mut matches: Vec<SessionState> = self
+            .list_sessions()?
+            .into_iter()
+            .filter(|s| s.branch == branch && !matches!(s.status, SessionStatus::Cancelled))
+            .collect();
+
+        match matches.len() {
+            0 => Err(ParaError::invalid_args(format!(
+                "No session uses branch '{branch}'"
+            ))),
+            1 => Ok(matches.remove(0)),
+            _ => {
+                let mut names: Vec<&str> = matches.iter().map(|s| s.name.as_str()).collect();
+                names.sort();
+                Err(ParaError::invalid_args(format!(
+                    "Branch '{branch}' is used by several sessions: {}. Pass the session name instead.",
+                    names.join(", ")
+                )))
+            }
+        }
+    }
+
     pub fn update_session_status(
         &mut self,
         session_name: &str,
@@ -392,11 +654,63 @@ impl SessionManager {
         self.save_state(&session)
     }
 
+    /// Record `operation` as running on the session until the guard is dropped
+    pub fn begin_operation(
+        &self,
+        session_name: &str,
+        operation: SessionOperation,
+    ) -> Result<OperationGuard> {
+        OperationGuard::begin(&self.state_dir, session_name, operation)
+    }
+
+    /// The operation currently running on the session, ignoring crashed ones
+    pub fn current_operation(&self, session_name: &str) -> Option<OperationRecord> {
+        operation::current_operation(&self.state_dir, session_name)
+    }
+
     pub fn session_exists(&self, session_name: &str) -> bool {
         let state_file = self.state_dir.join(format!("{session_name}.state"));
         state_file.exists()
     }
 
+    /// Serialize session creation in this repository; see [`CreationLock`]
+    pub fn lock_creation(&self) -> Result<CreationLock> {
+        CreationLock::acquire(&self.state_dir)
+    }
+
+    /// A generated session name that no session, branch or worktree directory
+    /// uses yet. Only stays free while the creation lock is held.
+    pub fn generate_session_name(&self) -> Result<String> {
+        const MAX_ATTEMPTS: usize = 20;
+
+        let mut taken: Vec<String> = self.list_sessions()?.into_iter().map(|s| s.name).collect();
+
+        for _ in 0..MAX_ATTEMPTS {
+            let candidate = crate::utils::generate_unique_name(&taken);
+            if self.is_name_free(&candidate)? {
+                return Ok(candidate);
+            }
+            taken.push(candidate);
+        }
+        Err(ParaError::invalid_args(
+            "Could not find a free session name; pass one explicitly",
+        ))
+    }
+
+    /// Whether no session, branch or worktree directory uses `name` yet
+    pub fn is_name_free(&self, name: &str) -> Result<bool> {
+        if self.session_exists(name) {
+            return Ok(false);
+        }
+
+        let git_service = self.git_service()?;
+        let branch =
+            crate::utils::generate_friendly_branch_name(self.config.get_branch_prefix(), name);
+        let worktree_path =
+            SessionPaths::worktree_path(&self.config, &git_service.repository().root, name)?;
+        Ok(!git_service.branch_manager().branch_exists(&branch)? && !worktree_path.exists())
+    }
+
     fn resolve_session_name(&self, requested_name: String) -> Result<String> {
         if !self.session_exists(&requested_name) {
             return Ok(requested_name);
@@ -422,7 +736,7 @@ impl SessionManager {
                 ))
             })?;
 
-            if let Ok(git_service) = GitService::discover() {
+            if let Ok(git_service) = self.git_service() {
                 let repository_root = git_service.repository().root.clone();
                 GitignoreManager::ensure_para_ignored_in_repository(&repository_root)?;
             }
@@ -500,30 +814,53 @@ impl SessionManager {
         Ok(session_state)
     }
 
+    /// Fail unless `path` lies inside a directory para owns, so a corrupt state
+    /// file or config can never make cleanup delete anything else
+    pub fn ensure_deletable(&self, path: &Path) -> Result<()> {
+        // The worktree's own repository, which may not be the current one
+        let repo_roots = [self.repository_root.as_deref(), Some(path)]
+            .into_iter()
+            .flatten()
+            .filter_map(|from| crate::utils::get_main_repository_root_from(Some(from)).ok());
+
+        let mut owned = vec![self.state_dir.clone()];
+        for repo_root in repo_roots {
+            owned.extend(self.config.owned_directories(&repo_root));
+        }
+        crate::utils::path::ensure_deletable(path, &owned)
+    }
+
     pub fn cancel_session(&mut self, session_name: &str, force: bool) -> Result<()> {
         let session = self.load_state(session_name)?;
+        let remove_worktree = (force || session.is_container()) && session.worktree_path.exists();
+        if remove_worktree {
+            self.ensure_deletable(&session.worktree_path)?;
+        }
 
         // Clean up Docker container if it's a container session
         if session.is_container() {
-            let docker_manager = crate::core::docker::DockerManager::new(
-                self.config.clone(),
-                false,  // network_isolation doesn't matter for cleanup
-                vec![], // allowed_domains doesn't matter for cleanup
-            );
+            let docker_manager =
+                crate::core::docker::DockerManager::for_session(self.config.clone(), &session);
             if let Err(e) = docker_manager.stop_container(&session.name) {
                 eprintln!("Warning: Failed to stop Docker container: {e}");
             }
+            crate::core::docker::resources::report_session_cleanup(
+                &session.name,
+                docker_manager.cleanup_session_resources(&session.name),
+            );
         }
 
-        // Remove the session state file
+        // Remove the session state file, and the manifest of a kept worktree
+        let _ = manifest::remove(&session.worktree_path);
         let state_file = self.state_dir.join(format!("{session_name}.state"));
         if state_file.exists() {
             fs::remove_file(&state_file)
                 .map_err(|e| ParaError::fs_error(format!("Failed to remove state file: {e}")))?;
+            events::publish(&self.state_dir, session_name, SessionEventKind::Cancelled);
         }
 
         // Clean up the worktree if requested or if it's a Docker session
-        if (force || session.is_container()) && session.worktree_path.exists() {
+        if remove_worktree {
             fs::remove_dir_all(&session.worktree_path)
                 .map_err(|e| ParaError::fs_error(format!("Failed to remove worktree: {e}")))?;
         }
@@ -540,6 +877,8 @@ mod manager_mixed_tests;
 mod tests {
     use super::*;
     use crate::config::defaults::default_config;
+    use crate::core::git::GitOperations;
+    use crate::test_utils::failing_fs::FailingFs;
     use tempfile::TempDir;
 
     #[test]
@@ -562,6 +901,221 @@ mod tests {
         assert!(!manager.state_dir.exists());
     }
 
+    #[test]
+    fn test_cancel_refuses_to_delete_outside_owned_directories() {
+        let (git_temp, _git_service) = crate::test_utils::test_helpers::setup_test_repo();
+        let repo_root = git_temp.path().canonicalize().unwrap();
+        let outside = TempDir::new().unwrap();
+
+        let mut config = default_config();
+        config.directories.state_dir = ".para_state".to_string();
+        config.directories.subtrees_dir = ".para/worktrees".to_string();
+        let mut manager = SessionManager::for_repository(&config, &repo_root);
+
+        // A state file pointing at the repository root or an unrelated
+        // directory must never get them deleted
+        for (name, path) in [
+            ("root", repo_root.clone()),
+            ("outside", outside.path().to_path_buf()),
+        ] {
+            manager
+                .save_state(&SessionState::new(
+                    name.to_string(),
+                    format!("para/{name}"),
+                    path.clone(),
+                ))
+                .unwrap();
+            let err = manager.cancel_session(name, true).unwrap_err();
+            assert!(err.to_string().contains("Refusing to delete"), "{err}");
+            assert!(path.exists());
+            assert!(manager.session_exists(name));
+        }
+
+        let owned = repo_root.join(".para/worktrees/owned");
+        fs::create_dir_all(&owned).unwrap();
+        manager
+            .save_state(&SessionState::new(
+                "owned".to_string(),
+                "para/owned".to_string(),
+                owned.clone(),
+            ))
+            .unwrap();
+        manager.cancel_session("owned", true).unwrap();
+        assert!(!owned.exists());
+    }
+
+    #[test]
+    fn test_relative_state_dir_is_shared_by_subdirectories() {
+        let (git_temp, _git_service) = crate::test_utils::test_helpers::setup_test_repo();
+        let repo_root = git_temp.path().canonicalize().unwrap();
+        let nested = repo_root.join("src").join("nested");
+        fs::create_dir_all(&nested).unwrap();
+
+        let mut config = default_config();
+        config.directories.state_dir = ".para_state".to_string();
+
+        let from_root = SessionManager::for_repository(&config, &repo_root);
+        let from_nested = SessionManager::for_repository(&config, &nested);
+        assert_eq!(from_root.state_dir(), &repo_root.join(".para_state"));
+        assert_eq!(from_nested.state_dir(), from_root.state_dir());
+
+        from_nested
+            .save_state(&SessionState::new(
+                "nested".to_string(),
+                "para/nested".to_string(),
+                repo_root.join("worktree"),
+            ))
+            .unwrap();
+        assert!(repo_root.join(".para_state/nested.state").exists());
+        assert!(!nested.join(".para_state").exists());
+        assert!(from_root.session_exists("nested"));
+    }
+
+    #[test]
+    fn test_concurrent_creation_gets_unique_sessions() {
+        const DISPATCHES: usize = 6;
+
+        let (git_temp, git_service) = crate::test_utils::test_helpers::setup_test_repo();
+        let repo_root = git_temp.path().canonicalize().unwrap();
+        let mut config = default_config();
+        config.ide.command = "echo".to_string();
+        config.directories.state_dir = ".para_state".to_string();
+
+        // Each thread does what dispatch does: pick a name, then create under the lock
+        let handles: Vec<_> = (0..DISPATCHES)
+            .map(|_| {
+                let config = config.clone();
+                let repo_root = repo_root.clone();
+                std::thread::spawn(move || {
+                    let mut manager = SessionManager::for_repository(&config, &repo_root);
+                    let _lock = manager.lock_creation().unwrap();
+                    let name = manager.generate_session_name().unwrap();
+                    manager
+                        .create_session_with_all_flags(name, None, false, false, None)
+                        .unwrap()
+                })
+            })
+            .collect();
+        let sessions: Vec<SessionState> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+
+        let mut names: Vec<_> = sessions.iter().map(|s| s.name.clone()).collect();
+        let mut branches: Vec<_> = sessions.iter().map(|s| s.branch.clone()).collect();
+        names.sort();
+        names.dedup();
+        branches.sort();
+        branches.dedup();
+        assert_eq!(names.len(), DISPATCHES);
+        assert_eq!(branches.len(), DISPATCHES);
+
+        for session in &sessions {
+            assert!(session.worktree_path.join(".git").exists());
+            assert!(git_service
+                .branch_manager()
+                .branch_exists(&session.branch)
+                .unwrap());
+        }
+        let worktrees = git_service.list_worktrees().unwrap();
+        assert_eq!(worktrees.len(), DISPATCHES + 1);
+    }
+
+    #[test]
+    fn test_session_manifest_follows_state() {
+        let (git_temp, _git_service) = crate::test_utils::test_helpers::setup_test_repo();
+        let repo_root = git_temp.path().canonicalize().unwrap();
+        let mut config = default_config();
+        config.ide.command = "echo".to_string();
+        config.directories.state_dir = ".para_state".to_string();
+        let mut manager = SessionManager::for_repository(&config, &repo_root);
+
+        let mut session = manager
+            .create_session_with_all_flags("agent".to_string(), None, false, false, None)
+            .unwrap();
+        let read_manifest = |session: &SessionState| {
+            manifest::find(&session.worktree_path).expect("manifest should exist")
+        };
+        let written = read_manifest(&session);
+        assert_eq!(
+            written,
+            manifest::SessionManifest {
+                state_dir: Some(manager.state_dir().clone()),
+                ..manifest::SessionManifest::from_state(&session)
+            }
+        );
+        assert_eq!(written.name, "agent");
+        assert_eq!(written.branch, session.branch);
+        assert!(written.base_branch.is_some());
+
+        // The manifest never shows up as a change in the worktree
+        let worktree =
+            crate::core::git::GitRepository::discover_from(&session.worktree_path).unwrap();
+        assert!(worktree.worktree_status().unwrap().is_clean());
+
+        // Saving changed fields rewrites it
+        session.merge_mode = crate::core::git::MergeMode::Rebase;
+        manager.save_state(&session).unwrap();
+        assert_eq!(
+            read_manifest(&session).merge_mode,
+            crate::core::git::MergeMode::Rebase
+        );
+
+        // Finishing moves the session to review, which drops the manifest
+        manager
+            .update_session_status("agent", SessionStatus::Review)
+            .unwrap();
+        assert!(session.worktree_path.exists());
+        assert!(manifest::find(&session.worktree_path).is_none());
+
+        // Cancelling without removing the worktree drops it as well
+        let other = manager
+            .create_session_with_all_flags("other".to_string(), None, false, false, None)
+            .unwrap();
+        assert!(manifest::find(&other.worktree_path).is_some());
+        manager.cancel_session("other", false).unwrap();
+        assert!(other.worktree_path.exists());
+        assert!(manifest::find(&other.worktree_path).is_none());
+    }
+
+    #[test]
+    fn test_failed_state_write_rolls_back_creation() {
+        let (git_temp, git_service) = crate::test_utils::test_helpers::setup_test_repo();
+        let repo_root = git_temp.path().canonicalize().unwrap();
+        let mut config = default_config();
+        config.ide.command = "echo".to_string();
+        config.directories.state_dir = ".para_state".to_string();
+
+        let failing = Arc::new(FailingFs::on_write(1));
+        let mut manager =
+            SessionManager::for_repository(&config, &repo_root).with_fs_ops(failing.clone());
+        let err = manager
+            .create_session_with_all_flags("full-disk".to_string(), None, false, false, None)
+            .unwrap_err();
+        assert!(matches!(err, ParaError::StorageFull { .. }), "{err}");
+        assert_eq!(failing.writes(), 1);
+
+        let branch =
+            crate::utils::generate_friendly_branch_name(config.get_branch_prefix(), "full-disk");
+        assert!(!git_service.branch_exists(&branch).unwrap());
+        assert_eq!(git_service.list_worktrees().unwrap().len(), 1);
+        assert!(!repo_root
+            .join(&config.directories.subtrees_dir)
+            .join("full-disk")
+            .exists());
+        let state_files: Vec<_> = fs::read_dir(manager.state_dir())
+            .unwrap()
+            .flatten()
+            .map(|entry| entry.file_name())
+            .filter(|name| name.to_string_lossy().starts_with("full-disk"))
+            .collect();
+        assert!(state_files.is_empty(), "{state_files:?}");
+
+        // Nothing left behind, so the same name can be created once there is space
+        let mut manager = SessionManager::for_repository(&config, &repo_root);
+        let session = manager
+            .create_session_with_all_flags("full-disk".to_string(), None, false, false, None)
+            .unwrap();
+        assert_eq!(session.name, "full-disk");
+    }
+
     #[test]
     fn test_consolidated_directory_structure() {
         let temp_dir = TempDir::new().unwrap();
@@ -977,6 +1531,93 @@ mod tests {
         assert!(result.is_none());
     }
 
+    #[test]
+    fn test_resolve_session_loose_matching() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = default_config();
+        config.directories.state_dir = temp_dir
+            .path()
+            .join(".para/state")
+            .to_string_lossy()
+            .to_string();
+        let manager = SessionManager::new(&config);
+
+        for name in ["foo-auth-fix", "foo-docs", "login_20250611-101010"] {
+            let session = SessionState::new(
+                name.to_string(),
+                format!("para/{name}"),
+                temp_dir.path().join(name),
+            );
+            manager.save_state(&session).unwrap();
+        }
+
+        assert_eq!(
+            manager.resolve_session("foo-docs").unwrap().name,
+            "foo-docs"
+        );
+        assert_eq!(
+            manager.resolve_session("login").unwrap().name,
+            "login_20250611-101010"
+        );
+        assert_eq!(
+            manager.resolve_session("auth").unwrap().name,
+            "foo-auth-fix"
+        );
+
+        let err = manager.resolve_session("foo").unwrap_err().to_string();
+        assert!(err.contains("Several sessions start with 'foo': foo-auth-fix, foo-docs"));
+
+        match manager.resolve_session("foo-doc5") {
+            Err(ParaError::SessionNotFound { suggestions, .. }) => {
+                assert_eq!(suggestions[0], "foo-docs");
+            }
+            other => panic!("expected SessionNotFound, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_resolve_session_by_branch() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = default_config();
+        config.directories.state_dir = temp_dir
+            .path()
+            .join(".para/state")
+            .to_string_lossy()
+            .to_string();
+        let manager = SessionManager::new(&config);
+
+        for (name, branch) in [("first", "para/first"), ("second", "para/second")] {
+            let session = SessionState::new(
+                name.to_string(),
+                branch.to_string(),
+                temp_dir.path().join(name),
+            );
+            manager.save_state(&session).unwrap();
+        }
+
+        let found = manager.resolve_session_by_branch("para/second").unwrap();
+        assert_eq!(found.name, "second");
+
+        let err = manager
+            .resolve_session_by_branch("para/missing")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("No session uses branch 'para/missing'"));
+
+        // Point the second session at the first one's branch by hand
+        let state_file = manager.state_dir().join("second.state");
+        let edited = fs::read_to_string(&state_file)
+            .unwrap()
+            .replace("para/second", "para/first");
+        fs::write(&state_file, edited).unwrap();
+
+        let err = manager
+            .resolve_session_by_branch("para/first")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("used by several sessions: first, second"));
+    }
+
     #[test]
     fn test_find_session_by_branch() {
         let temp_dir = TempDir::new().unwrap();
@@ -1213,4 +1854,54 @@ mod tests {
         assert_eq!(loaded.dangerous_skip_permissions, None);
         assert_eq!(loaded.name, "legacy-session");
     }
+
+    #[test]
+    fn test_state_changes_publish_session_events() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = default_config();
+        config.directories.state_dir = temp_dir
+            .path()
+            .join(".para/state")
+            .to_string_lossy()
+            .to_string();
+        let manager = SessionManager::new(&config);
+
+        let mut session = SessionState::new(
+            "events".to_string(),
+            "para/events".to_string(),
+            temp_dir.path().join("worktree"),
+        );
+        manager.save_state(&session).unwrap();
+        session.task_description = Some("task".to_string());
+        manager.save_state(&session).unwrap();
+        crate::core::status::Status::new(
+            "events".to_string(),
+            "testing".to_string(),
+            crate::core::status::TestStatus::Unknown,
+        )
+        .save(&manager.state_dir)
+        .unwrap();
+        session.status = SessionStatus::Review;
+        manager.save_state(&session).unwrap();
+        manager.delete_state("events").unwrap();
+
+        let kinds: Vec<_> = events::recent(&manager.state_dir)
+            .into_iter()
+            .map(|event| (event.session, event.kind))
+            .collect();
+        let expected = [
+            SessionEventKind::Created,
+            SessionEventKind::Updated,
+            SessionEventKind::StatusChanged,
+            SessionEventKind::Finished,
+            SessionEventKind::Cancelled,
+        ];
+        assert_eq!(
+            kinds,
+            expected
+                .into_iter()
+                .map(|kind| ("events".to_string(), kind))
+                .collect::<Vec<_>>()
+        );
+    }
 }
@@ -334,6 +334,60 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_create_session_records_base_commit_despite_rewritten_parent() {
+        let git_temp = TempDir::new().unwrap();
+        let temp_dir = TempDir::new().unwrap();
+        let _guard = TestEnvironmentGuard::new(&git_temp, &temp_dir).unwrap();
+        let (_git_temp, git_service) = setup_test_repo();
+        let repo_path = git_service.repository().root.clone();
+
+        let state_dir = repo_path.join(".para").join("state");
+        std::fs::create_dir_all(&state_dir).unwrap();
+
+        let mut config = create_test_config();
+        config.directories.state_dir = state_dir.to_string_lossy().to_string();
+        config.directories.subtrees_dir = ".para/worktrees".to_string();
+
+        let _original_dir = std::env::current_dir().ok();
+        std::env::set_current_dir(&repo_path).unwrap();
+
+        let fork_point = git_service.repository().resolve_commit("HEAD").unwrap();
+
+        let mut manager = SessionManager::new(&config);
+        let session_state = manager
+            .create_session_with_type("pinned-base".to_string(), None, None, false)
+            .unwrap();
+        assert_eq!(
+            session_state.base_commit.as_deref(),
+            Some(fork_point.as_str())
+        );
+
+        // Rewrite the parent branch after the session was created
+        Command::new("git")
+            .current_dir(&repo_path)
+            .args(["commit", "--amend", "-m", "Rewritten initial commit"])
+            .output()
+            .expect("Failed to amend commit");
+        let rewritten = git_service.repository().resolve_commit("HEAD").unwrap();
+        assert_ne!(rewritten, fork_point);
+
+        let loaded = manager.load_state("pinned-base").unwrap();
+        assert_eq!(loaded.base_commit.as_deref(), Some(fork_point.as_str()));
+        assert_eq!(loaded.diff_base(), fork_point);
+
+        let is_ancestor = Command::new("git")
+            .current_dir(&repo_path)
+            .args(["merge-base", "--is-ancestor", &fork_point, &loaded.branch])
+            .status()
+            .unwrap();
+        assert!(is_ancestor.success());
+
+        if let Some(original_dir) = _original_dir {
+            let _ = std::env::set_current_dir(original_dir);
+        }
+    }
+
     #[test]
     fn test_create_container_session_captures_parent_branch() {
         // Create isolated test environment
@@ -0,0 +1,188 @@
+//! Session manifest inside the worktree
+//!
+//! Para writes `<worktree>/.para/session.json` whenever it saves the state of
+//! an active session, so agents can tell which session they are in, even in a
+//! container where the state directory is not mounted. The file is kept out
+//! of commits through `info/exclude` and is removed once the session is
+//! finished or cancelled.
+
+use crate::core::git::MergeMode;
+use crate::core::session::untracked::ensure_ignored_in_worktree;
+use crate::core::session::{SessionManager, SessionState, SessionStatus};
+use crate::utils::{ParaError, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Location of the manifest, relative to the worktree
+pub const MANIFEST_PATH: &str = ".para/session.json";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SessionManifest {
+    pub name: String,
+    pub branch: String,
+    /// Branch the session was started from
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub base_branch: Option<String>,
+    /// Commit the session branch was created from
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub base_sha: Option<String>,
+    pub merge_mode: MergeMode,
+    /// `worktree` or `container`
+    pub session_type: String,
+    pub sandbox: SandboxManifest,
+    pub skip_permissions: bool,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub template: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub stacked_on: Option<String>,
+    /// State directory of the session, so readers such as `para statusline`
+    /// find its status without loading config
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub state_dir: Option<PathBuf>,
+    /// Version of para that wrote the file
+    pub para_version: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SandboxManifest {
+    pub enabled: bool,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub profile: Option<String>,
+}
+
+impl SessionManifest {
+    pub fn from_state(session: &SessionState) -> Self {
+        Self {
+            name: session.name.clone(),
+            branch: session.branch.clone(),
+            base_branch: session.parent_branch.clone(),
+            base_sha: session.base_commit.clone(),
+            merge_mode: session.merge_mode,
+            session_type: if session.is_container() {
+                "container".to_string()
+            } else {
+                "worktree".to_string()
+            },
+            sandbox: SandboxManifest {
+                enabled: session.sandbox_enabled.unwrap_or(false),
+                profile: session.sandbox_profile.clone(),
+            },
+            skip_permissions: session.skips_permissions(),
+            template: session.template.clone(),
+            stacked_on: session.stacked_on.clone(),
+            state_dir: None,
+            para_version: env!("CARGO_PKG_VERSION").to_string(),
+        }
+    }
+}
+
+pub fn manifest_path(worktree_path: &Path) -> PathBuf {
+    worktree_path.join(MANIFEST_PATH)
+}
+
+/// Bring the manifest in line with `session`: written while the session is
+/// active and its worktree exists, removed otherwise. A main checkout used as
+/// the worktree is left alone, it is not specific to the session.
+pub fn sync(session: &SessionState, state_dir: &Path) -> Result<()> {
+    if !session.worktree_path.is_dir() || session.worktree_path.join(".git").is_dir() {
+        return Ok(());
+    }
+    if matches!(session.status, SessionStatus::Active) {
+        write(session, state_dir)
+    } else {
+        remove(&session.worktree_path)
+    }
+}
+
+pub fn write(session: &SessionState, state_dir: &Path) -> Result<()> {
+    let path = manifest_path(&session.worktree_path);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| ParaError::from_io("create", parent, e))?;
+    }
+    let manifest = SessionManifest {
+        state_dir: Some(state_dir.to_path_buf()),
+        ..SessionManifest::from_state(session)
+    };
+    let json = serde_json::to_string_pretty(&manifest)?;
+    fs::write(&path, format!("{json}\n")).map_err(|e| ParaError::from_io("write", &path, e))?;
+
+    // Linked worktrees have a `.git` file; other directories have nothing to commit
+    if session.worktree_path.join(".git").is_file() {
+        ensure_ignored_in_worktree(&session.worktree_path, MANIFEST_PATH)?;
+    }
+    Ok(())
+}
+
+pub fn remove(worktree_path: &Path) -> Result<()> {
+    let path = manifest_path(worktree_path);
+    match fs::remove_file(&path) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(ParaError::from_io("remove", &path, e)),
+    }
+}
+
+/// Path of the manifest of the worktree containing `dir`, if any
+pub fn find_path(dir: &Path) -> Option<PathBuf> {
+    dir.ancestors()
+        .map(manifest_path)
+        .find(|path| path.is_file())
+}
+
+/// The manifest of the worktree containing `dir`, if any
+pub fn find(dir: &Path) -> Option<SessionManifest> {
+    let content = fs::read_to_string(find_path(dir)?).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// Name of the session `dir` belongs to. The manifest decides, since it is
+/// also visible in containers; otherwise the session whose worktree contains
+/// `dir`.
+pub fn current_session(session_manager: &SessionManager, dir: &Path) -> Result<Option<String>> {
+    if let Some(manifest) = find(dir) {
+        return Ok(Some(manifest.name));
+    }
+    Ok(session_manager
+        .find_session_by_path(dir)?
+        .map(|session| session.name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_sync_writes_and_removes() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut session = SessionState::new(
+            "auth".to_string(),
+            "para/auth".to_string(),
+            temp_dir.path().to_path_buf(),
+        );
+        session.parent_branch = Some("main".to_string());
+        session.base_commit = Some("abc123".to_string());
+        session.sandbox_enabled = Some(true);
+        session.sandbox_profile = Some("standard".to_string());
+
+        let state_dir = temp_dir.path().join(".para_state");
+        sync(&session, &state_dir).unwrap();
+        let nested = temp_dir.path().join("src/deep");
+        fs::create_dir_all(&nested).unwrap();
+        let manifest = find(&nested).expect("manifest should be found from subdirectories");
+        assert_eq!(manifest.name, "auth");
+        assert_eq!(manifest.branch, "para/auth");
+        assert_eq!(manifest.base_branch.as_deref(), Some("main"));
+        assert_eq!(manifest.base_sha.as_deref(), Some("abc123"));
+        assert_eq!(manifest.session_type, "worktree");
+        assert!(manifest.sandbox.enabled);
+        assert_eq!(manifest.state_dir, Some(state_dir.clone()));
+        assert_eq!(manifest.para_version, env!("CARGO_PKG_VERSION"));
+
+        session.status = SessionStatus::Review;
+        sync(&session, &state_dir).unwrap();
+        assert!(!manifest_path(temp_dir.path()).exists());
+        assert!(find(&nested).is_none());
+    }
+}
@@ -0,0 +1,396 @@
+//! Versioned session state files
+//!
+//! Every state file records the layout it was written with as
+//! `schema_version`; files from before versioning count as version 1.
+//! `load_state` upgrades older files as it reads them and `para upgrade-state`
+//! upgrades all of them at once. Before the first upgrade that writes in a
+//! run, the whole state directory is copied to `<state_dir>.backup-<timestamp>`,
+//! keeping the newest [`MAX_BACKUPS`], so `para upgrade-state --restore` can
+//! roll a bad migration back.
+
+use super::state::ContainerConfig;
+use crate::core::version::PARA_VERSION;
+use crate::utils::{ParaError, Result};
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// Layout written by this version of para
+pub const STATE_SCHEMA_VERSION: u32 = 3;
+
+/// Version of state files that have no `schema_version`
+pub const LEGACY_SCHEMA_VERSION: u32 = 1;
+
+/// State directory backups kept next to the state directory
+pub const MAX_BACKUPS: usize = 3;
+
+/// State directories backed up by this process
+static BACKED_UP: Mutex<Vec<PathBuf>> = Mutex::new(Vec::new());
+
+/// What upgrading one state file changed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Migration {
+    pub from: u32,
+    pub to: u32,
+    pub changes: Vec<String>,
+    /// Guesses the user should review, printed as warnings
+    pub warnings: Vec<String>,
+}
+
+/// A state file looked at by `para upgrade-state`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StateUpgrade {
+    pub file: PathBuf,
+    /// Version the file had
+    pub version: u32,
+    /// `None` when the file was already current
+    pub migration: Option<Migration>,
+}
+
+/// The `schema_version` of a parsed state file
+pub fn schema_version(state: &Value) -> u32 {
+    state
+        .get("schema_version")
+        .and_then(Value::as_u64)
+        .map_or(LEGACY_SCHEMA_VERSION, |version| version as u32)
+}
+
+/// Upgrade a parsed state file to [`STATE_SCHEMA_VERSION`]. Returns `None`
+/// when it is already current.
+pub fn migrate(state: &mut Value) -> Result<Option<Migration>> {
+    let from = schema_version(state);
+    if from > STATE_SCHEMA_VERSION {
+        return Err(ParaError::state_corruption(format!(
+            "State file has schema version {from}, but this version of para only knows up to {STATE_SCHEMA_VERSION}; upgrade para"
+        )));
+    }
+    if from == STATE_SCHEMA_VERSION {
+        return Ok(None);
+    }
+    let Some(fields) = state.as_object_mut() else {
+        return Err(ParaError::state_corruption(
+            "State file does not contain a JSON object",
+        ));
+    };
+
+    let mut changes = Vec::new();
+    let mut warnings = Vec::new();
+    if from < 2 {
+        // Version 1 marked container sessions with `is_docker`
+        if let Some(is_docker) = fields.remove("is_docker") {
+            if is_docker.as_bool() == Some(true) {
+                fields.insert(
+                    "session_type".to_string(),
+                    serde_json::json!({ "Container": { "container_id": null } }),
+                );
+            }
+            changes.push("is_docker → session_type".to_string());
+        }
+    }
+    if from < 3 {
+        // Version 2 did not record the docker options of container sessions
+        let is_container = fields
+            .get("session_type")
+            .is_some_and(|session_type| session_type.get("Container").is_some());
+        if is_container && !fields.contains_key("container") {
+            fields.insert(
+                "container".to_string(),
+                serde_json::to_value(ContainerConfig::conservative())?,
+            );
+            changes.push("container options recorded".to_string());
+            let name = fields.get("name").and_then(Value::as_str).unwrap_or("?");
+            warnings.push(format!(
+                "Container session '{name}' predates recorded docker options; assuming network isolation without forwarded API keys. Recreate it if it needs other options"
+            ));
+        }
+    }
+    fields.insert(
+        "schema_version".to_string(),
+        Value::from(STATE_SCHEMA_VERSION),
+    );
+    fields.insert("para_version".to_string(), Value::from(PARA_VERSION));
+
+    Ok(Some(Migration {
+        from,
+        to: STATE_SCHEMA_VERSION,
+        changes,
+        warnings,
+    }))
+}
+
+/// Back up `state_dir` unless this process already did, before a migration
+/// writes to it
+pub fn backup_before_migration(state_dir: &Path) -> Result<Option<PathBuf>> {
+    let mut backed_up = BACKED_UP.lock().unwrap_or_else(|e| e.into_inner());
+    if backed_up.iter().any(|dir| dir == state_dir) {
+        return Ok(None);
+    }
+    let backup = backup_state_dir(state_dir)?;
+    backed_up.push(state_dir.to_path_buf());
+    Ok(Some(backup))
+}
+
+/// Copy `state_dir` to `<state_dir>.backup-<timestamp>` and drop all but the
+/// newest [`MAX_BACKUPS`] backups
+pub fn backup_state_dir(state_dir: &Path) -> Result<PathBuf> {
+    let timestamp = chrono::Utc::now().format("%Y%m%d-%H%M%S").to_string();
+    let mut backup = backup_path(state_dir, &timestamp);
+    for n in 2.. {
+        if !backup.exists() {
+            break;
+        }
+        backup = backup_path(state_dir, &format!("{timestamp}-{n:02}"));
+    }
+
+    copy_dir(state_dir, &backup)?;
+    for old in list_backups(state_dir).iter().rev().skip(MAX_BACKUPS) {
+        fs::remove_dir_all(old).map_err(|e| ParaError::from_io("remove old backup", old, e))?;
+    }
+    Ok(backup)
+}
+
+/// Backups of `state_dir`, oldest first
+pub fn list_backups(state_dir: &Path) -> Vec<PathBuf> {
+    let (Some(parent), Some(prefix)) = (state_dir.parent(), backup_prefix(state_dir)) else {
+        return Vec::new();
+    };
+    let mut backups: Vec<PathBuf> = fs::read_dir(parent)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .map(|entry| entry.path())
+                .filter(|path| {
+                    path.is_dir()
+                        && path
+                            .file_name()
+                            .and_then(|name| name.to_str())
+                            .is_some_and(|name| name.starts_with(&prefix))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    backups.sort();
+    backups
+}
+
+/// Replace `state_dir` with a copy of `backup`, which may be given as a path
+/// or as the name of one of [`list_backups`]. The state directory is swapped
+/// with a rename, so it is never left half restored.
+pub fn restore_backup(state_dir: &Path, backup: &str) -> Result<PathBuf> {
+    let backup = list_backups(state_dir)
+        .into_iter()
+        .find(|path| {
+            path.file_name().and_then(|name| name.to_str()) == Some(backup)
+                || path == Path::new(backup)
+        })
+        .ok_or_else(|| {
+            ParaError::invalid_args(format!(
+                "'{backup}' is not a backup of {}; see 'para upgrade-state --dry-run' for the available backups",
+                state_dir.display()
+            ))
+        })?;
+
+    let staged = sibling(state_dir, ".restoring");
+    let replaced = sibling(state_dir, ".replaced");
+    for leftover in [&staged, &replaced] {
+        if leftover.exists() {
+            fs::remove_dir_all(leftover)
+                .map_err(|e| ParaError::from_io("remove leftover", leftover, e))?;
+        }
+    }
+
+    copy_dir(&backup, &staged)?;
+    if state_dir.exists() {
+        fs::rename(state_dir, &replaced)
+            .map_err(|e| ParaError::from_io("move aside state directory", state_dir, e))?;
+    }
+    if let Err(e) = fs::rename(&staged, state_dir) {
+        let _ = fs::rename(&replaced, state_dir);
+        return Err(ParaError::from_io("restore state directory", state_dir, e));
+    }
+    if replaced.exists() {
+        fs::remove_dir_all(&replaced)
+            .map_err(|e| ParaError::from_io("remove replaced state directory", &replaced, e))?;
+    }
+    Ok(backup)
+}
+
+fn backup_prefix(state_dir: &Path) -> Option<String> {
+    let name = state_dir.file_name()?.to_str()?;
+    Some(format!("{name}.backup-"))
+}
+
+fn backup_path(state_dir: &Path, suffix: &str) -> PathBuf {
+    let prefix = backup_prefix(state_dir).unwrap_or_else(|| "state.backup-".to_string());
+    state_dir.with_file_name(format!("{prefix}{suffix}"))
+}
+
+fn sibling(state_dir: &Path, suffix: &str) -> PathBuf {
+    let name = state_dir
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| "state".to_string());
+    state_dir.with_file_name(format!("{name}{suffix}"))
+}
+
+/// Copy the files and directories under `from` to `to`; sockets, pipes and
+/// symlinks are skipped
+fn copy_dir(from: &Path, to: &Path) -> Result<()> {
+    fs::create_dir_all(to).map_err(|e| ParaError::from_io("create backup directory", to, e))?;
+    let entries = fs::read_dir(from).map_err(|e| ParaError::from_io("read", from, e))?;
+    for entry in entries {
+        let entry = entry.map_err(|e| ParaError::from_io("read", from, e))?;
+        let file_type = entry
+            .file_type()
+            .map_err(|e| ParaError::from_io("read", &entry.path(), e))?;
+        let target = to.join(entry.file_name());
+        if file_type.is_dir() {
+            copy_dir(&entry.path(), &target)?;
+        } else if file_type.is_file() {
+            fs::copy(entry.path(), &target)
+                .map_err(|e| ParaError::from_io("copy state file to", &target, e))?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::session::{SessionManager, SessionType};
+    use crate::test_utils::test_helpers::*;
+    use tempfile::TempDir;
+
+    const LEGACY_STATE: &str = r#"{
+  "name": "legacy",
+  "branch": "test/legacy",
+  "worktree_path": "/tmp/legacy",
+  "created_at": "2024-03-01T12:00:00Z",
+  "status": "Active",
+  "task_description": null,
+  "last_activity": null,
+  "git_stats": null,
+  "is_docker": true
+}"#;
+
+    #[test]
+    fn test_upgrade_then_restore_backup_byte_exact() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = create_test_config_with_dir(&temp_dir);
+        let manager = SessionManager::new(&config);
+        let state_dir = manager.state_dir().clone();
+        fs::create_dir_all(&state_dir).unwrap();
+        let legacy_file = state_dir.join("legacy.state");
+        fs::write(&legacy_file, LEGACY_STATE).unwrap();
+        fs::write(state_dir.join("legacy.task"), "Keep me\n").unwrap();
+
+        let dry_run = manager.upgrade_states(true).unwrap();
+        assert_eq!(dry_run.len(), 1);
+        assert_eq!(dry_run[0].version, LEGACY_SCHEMA_VERSION);
+        assert_eq!(
+            dry_run[0].migration.as_ref().unwrap().to,
+            STATE_SCHEMA_VERSION
+        );
+        assert_eq!(fs::read_to_string(&legacy_file).unwrap(), LEGACY_STATE);
+        assert!(list_backups(&state_dir).is_empty());
+
+        manager.upgrade_states(false).unwrap();
+        let upgraded = manager.load_state("legacy").unwrap();
+        assert_eq!(upgraded.schema_version, STATE_SCHEMA_VERSION);
+        assert_eq!(
+            upgraded.session_type,
+            SessionType::Container { container_id: None }
+        );
+        assert!(manager.upgrade_states(false).unwrap()[0]
+            .migration
+            .is_none());
+
+        // A migration gone wrong
+        fs::write(&legacy_file, "{ not json").unwrap();
+        fs::remove_file(state_dir.join("legacy.task")).unwrap();
+
+        let backups = list_backups(&state_dir);
+        assert_eq!(backups.len(), 1);
+        let name = backups[0]
+            .file_name()
+            .unwrap()
+            .to_string_lossy()
+            .to_string();
+        restore_backup(&state_dir, &name).unwrap();
+        assert_eq!(fs::read(&legacy_file).unwrap(), LEGACY_STATE.as_bytes());
+        assert_eq!(
+            fs::read_to_string(state_dir.join("legacy.task")).unwrap(),
+            "Keep me\n"
+        );
+        assert!(restore_backup(&state_dir, "no-such-backup").is_err());
+    }
+
+    #[test]
+    fn test_load_state_migrates_lazily_after_one_backup() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = create_test_config_with_dir(&temp_dir);
+        let manager = SessionManager::new(&config);
+        let state_dir = manager.state_dir().clone();
+        fs::create_dir_all(&state_dir).unwrap();
+        fs::write(state_dir.join("legacy.state"), LEGACY_STATE).unwrap();
+        fs::write(
+            state_dir.join("other.state"),
+            LEGACY_STATE.replace("\"legacy\"", "\"other\""),
+        )
+        .unwrap();
+
+        assert_eq!(manager.list_sessions().unwrap().len(), 2);
+        let backups = list_backups(&state_dir);
+        assert_eq!(backups.len(), 1);
+        assert_eq!(
+            fs::read_to_string(backups[0].join("legacy.state")).unwrap(),
+            LEGACY_STATE
+        );
+        let on_disk: Value =
+            serde_json::from_str(&fs::read_to_string(state_dir.join("other.state")).unwrap())
+                .unwrap();
+        assert_eq!(schema_version(&on_disk), STATE_SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn test_migrate_legacy_docker_flag() {
+        let mut state = serde_json::json!({ "name": "old", "is_docker": true });
+        let migration = migrate(&mut state).unwrap().unwrap();
+        assert_eq!((migration.from, migration.to), (1, STATE_SCHEMA_VERSION));
+        assert_eq!(
+            migration.changes,
+            vec!["is_docker → session_type", "container options recorded"]
+        );
+        assert_eq!(
+            state["session_type"],
+            serde_json::json!({ "Container": { "container_id": null } })
+        );
+        assert_eq!(migration.warnings.len(), 1);
+        assert_eq!(
+            state["container"],
+            serde_json::to_value(ContainerConfig::conservative()).unwrap()
+        );
+        assert!(state.get("is_docker").is_none());
+        assert!(migrate(&mut state).unwrap().is_none());
+
+        let mut future = serde_json::json!({ "schema_version": STATE_SCHEMA_VERSION + 1 });
+        assert!(migrate(&mut future).is_err());
+    }
+
+    #[test]
+    fn test_only_the_newest_backups_are_kept() {
+        let temp_dir = TempDir::new().unwrap();
+        let state_dir = temp_dir.path().join("state");
+        fs::create_dir_all(state_dir.join("archive")).unwrap();
+        fs::write(state_dir.join("a.state"), "{}").unwrap();
+
+        for _ in 0..MAX_BACKUPS + 2 {
+            backup_state_dir(&state_dir).unwrap();
+        }
+        let backups = list_backups(&state_dir);
+        assert_eq!(backups.len(), MAX_BACKUPS);
+        assert!(backups.iter().all(|b| b.join("a.state").is_file()));
+        assert!(backups.iter().all(|b| b.join("archive").is_dir()));
+    }
+}
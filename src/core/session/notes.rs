@@ -0,0 +1,164 @@
+//! Free-form notes about a session
+//!
+//! `para note` keeps notes in `<state_dir>/<session>.notes.md`, one
+//! timestamped bullet per note, so they survive resume and can be edited by
+//! hand. When the session is finished or cancelled a copy is archived under
+//! `<state_dir>/archive/<timestamp>/`, using the same timestamp as the
+//! archived branch.
+
+use crate::utils::{ParaError, Result};
+use chrono::{DateTime, Utc};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Timestamp format shared with archived branch names
+pub const ARCHIVE_TIMESTAMP_FORMAT: &str = "%Y%m%d-%H%M%S";
+
+pub fn notes_file(state_dir: &Path, session_name: &str) -> PathBuf {
+    state_dir.join(format!("{session_name}.notes.md"))
+}
+
+pub fn archived_notes_file(state_dir: &Path, timestamp: &str, session_name: &str) -> PathBuf {
+    state_dir
+        .join("archive")
+        .join(timestamp)
+        .join(format!("{session_name}.notes.md"))
+}
+
+/// Append `text` as a new note, after all existing ones
+pub fn append_note(
+    state_dir: &Path,
+    session_name: &str,
+    text: &str,
+    now: DateTime<Utc>,
+) -> Result<()> {
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if text.is_empty() {
+        return Err(ParaError::invalid_args("Note text cannot be empty"));
+    }
+
+    fs::create_dir_all(state_dir)
+        .map_err(|e| ParaError::fs_error(format!("Failed to create state directory: {e}")))?;
+    let path = notes_file(state_dir, session_name);
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .map_err(|e| ParaError::fs_error(format!("Failed to open {}: {e}", path.display())))?;
+    writeln!(file, "- {} {text}", now.format("%Y-%m-%d %H:%M"))
+        .map_err(|e| ParaError::fs_error(format!("Failed to write {}: {e}", path.display())))
+}
+
+pub fn read_notes(state_dir: &Path, session_name: &str) -> Option<String> {
+    fs::read_to_string(notes_file(state_dir, session_name)).ok()
+}
+
+/// The last non-empty line of the session's notes, without its bullet
+pub fn latest_note(state_dir: &Path, session_name: &str) -> Option<String> {
+    read_notes(state_dir, session_name).and_then(|notes| last_line(&notes))
+}
+
+/// The latest note archived with a finished or cancelled session
+pub fn latest_archived_note(
+    state_dir: &Path,
+    timestamp: &str,
+    session_name: &str,
+) -> Option<String> {
+    fs::read_to_string(archived_notes_file(state_dir, timestamp, session_name))
+        .ok()
+        .and_then(|notes| last_line(&notes))
+}
+
+fn last_line(notes: &str) -> Option<String> {
+    notes
+        .lines()
+        .map(str::trim)
+        .rfind(|line| !line.is_empty())
+        .map(|line| line.trim_start_matches("- ").to_string())
+}
+
+/// Copy the session's notes into the archive. Cancelled sessions pass
+/// `keep = false` so the live file goes away with the session state.
+pub fn archive_notes(
+    state_dir: &Path,
+    session_name: &str,
+    timestamp: &str,
+    keep: bool,
+) -> Result<Option<PathBuf>> {
+    let source = notes_file(state_dir, session_name);
+    if !source.exists() {
+        return Ok(None);
+    }
+
+    let target = archived_notes_file(state_dir, timestamp, session_name);
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| ParaError::fs_error(format!("Failed to create notes archive: {e}")))?;
+    }
+    fs::copy(&source, &target)
+        .map_err(|e| ParaError::fs_error(format!("Failed to archive notes: {e}")))?;
+    if !keep {
+        fs::remove_file(&source)
+            .map_err(|e| ParaError::fs_error(format!("Failed to remove notes: {e}")))?;
+    }
+    Ok(Some(target))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use tempfile::TempDir;
+
+    fn at(hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 5, 1, hour, 30, 0).unwrap()
+    }
+
+    #[test]
+    fn test_notes_are_appended_in_order() {
+        let temp = TempDir::new().unwrap();
+        append_note(temp.path(), "auth", "waiting on review", at(9)).unwrap();
+        append_note(temp.path(), "auth", "needs schema\nmigration", at(11)).unwrap();
+
+        assert_eq!(
+            read_notes(temp.path(), "auth").unwrap(),
+            "- 2024-05-01 09:30 waiting on review\n- 2024-05-01 11:30 needs schema migration\n"
+        );
+        assert_eq!(
+            latest_note(temp.path(), "auth").as_deref(),
+            Some("2024-05-01 11:30 needs schema migration")
+        );
+        assert!(append_note(temp.path(), "auth", "  ", at(12)).is_err());
+        assert_eq!(latest_note(temp.path(), "other"), None);
+    }
+
+    #[test]
+    fn test_archive_notes() {
+        let temp = TempDir::new().unwrap();
+        assert_eq!(
+            archive_notes(temp.path(), "auth", "20240501-120000", false).unwrap(),
+            None
+        );
+
+        append_note(temp.path(), "auth", "waiting on review", at(9)).unwrap();
+        let finished = archive_notes(temp.path(), "auth", "20240501-120000", true)
+            .unwrap()
+            .unwrap();
+        assert!(finished.exists());
+        assert!(notes_file(temp.path(), "auth").exists());
+
+        let cancelled = archive_notes(temp.path(), "auth", "20240502-120000", false)
+            .unwrap()
+            .unwrap();
+        assert!(!notes_file(temp.path(), "auth").exists());
+        assert_eq!(
+            latest_archived_note(temp.path(), "20240502-120000", "auth").as_deref(),
+            Some("2024-05-01 09:30 waiting on review")
+        );
+        assert_eq!(
+            fs::read_to_string(cancelled).unwrap(),
+            fs::read_to_string(finished).unwrap()
+        );
+    }
+}
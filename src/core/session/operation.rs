@@ -0,0 +1,264 @@
+//! Transient records for long-running session operations
+//!
+//! While `finish` or `cancel` runs, a `<session>.op` sidecar next to the
+//! session state records which operation is in progress, the pid running it
+//! and when it started. `para list` and the monitor render it, and other
+//! mutating commands refuse to start while a live process holds the record.
+//! Records left behind by a crashed process are detected through pid liveness
+//! and removed.
+
+use crate::utils::{ParaError, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SessionOperation {
+    Finishing,
+    Cancelling,
+}
+
+impl SessionOperation {
+    pub fn label(&self) -> &'static str {
+        match self {
+            SessionOperation::Finishing => "Finishing…",
+            SessionOperation::Cancelling => "Cancelling…",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OperationRecord {
+    pub operation: SessionOperation,
+    pub pid: u32,
+    pub started_at: DateTime<Utc>,
+}
+
+impl OperationRecord {
+    /// Label with the elapsed time, e.g. `Finishing… 12s`
+    pub fn describe(&self, now: DateTime<Utc>) -> String {
+        let elapsed = (now - self.started_at).num_seconds().max(0);
+        let elapsed = if elapsed >= 60 {
+            format!("{}m{:02}s", elapsed / 60, elapsed % 60)
+        } else {
+            format!("{elapsed}s")
+        };
+        format!("{} {elapsed}", self.operation.label())
+    }
+
+    fn is_alive(&self) -> bool {
+        process_is_alive(self.pid)
+    }
+}
+
+fn process_is_alive(pid: u32) -> bool {
+    if pid == std::process::id() {
+        return true;
+    }
+    // Signal 0 only checks whether the process exists
+    unsafe { libc::kill(pid as i32, 0) == 0 }
+}
+
+pub fn operation_file(state_dir: &Path, session_name: &str) -> PathBuf {
+    state_dir.join(format!("{session_name}.op"))
+}
+
+/// The operation currently running on a session, if any.
+///
+/// Records whose process is gone are removed and reported as `None`.
+pub fn current_operation(state_dir: &Path, session_name: &str) -> Option<OperationRecord> {
+    let path = operation_file(state_dir, session_name);
+    let content = fs::read_to_string(&path).ok()?;
+
+    match serde_json::from_str::<OperationRecord>(&content) {
+        Ok(record) if record.is_alive() => Some(record),
+        _ => {
+            let _ = fs::remove_file(&path);
+            None
+        }
+    }
+}
+
+/// Holds the `.op` record for the duration of an operation and removes it on drop,
+/// so the record is cleared whether the operation succeeds or fails.
+#[derive(Debug)]
+pub struct OperationGuard {
+    path: PathBuf,
+    owned: bool,
+}
+
+impl OperationGuard {
+    /// Record `operation` as running on `session_name`.
+    ///
+    /// Fails when another live process is already operating on the session. When
+    /// this process already holds the record (e.g. finish calling into cancel
+    /// helpers), the existing record is kept and the returned guard is a no-op.
+    pub fn begin(
+        state_dir: &Path,
+        session_name: &str,
+        operation: SessionOperation,
+    ) -> Result<Self> {
+        fs::create_dir_all(state_dir).map_err(|e| {
+            ParaError::fs_error(format!(
+                "Failed to create state directory {}: {e}",
+                state_dir.display()
+            ))
+        })?;
+
+        let path = operation_file(state_dir, session_name);
+
+        if let Some(existing) = current_operation(state_dir, session_name) {
+            if existing.pid == std::process::id() {
+                return Ok(Self { path, owned: false });
+            }
+            return Err(busy_error(session_name, &existing));
+        }
+
+        let record = OperationRecord {
+            operation,
+            pid: std::process::id(),
+            started_at: Utc::now(),
+        };
+        let json = serde_json::to_string(&record)
+            .map_err(|e| ParaError::file_operation(format!("Failed to encode operation: {e}")))?;
+
+        // create_new makes two processes racing for the same session fail cleanly
+        let mut file = match OpenOptions::new().write(true).create_new(true).open(&path) {
+            Ok(file) => file,
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {
+                return Err(match current_operation(state_dir, session_name) {
+                    Some(existing) => busy_error(session_name, &existing),
+                    None => ParaError::invalid_args(format!(
+                        "Another operation on session '{session_name}' just started. Try again."
+                    )),
+                });
+            }
+            Err(e) => {
+                return Err(ParaError::fs_error(format!(
+                    "Failed to write {}: {e}",
+                    path.display()
+                )))
+            }
+        };
+        file.write_all(json.as_bytes())
+            .and_then(|_| file.sync_all())
+            .map_err(|e| ParaError::fs_error(format!("Failed to write {}: {e}", path.display())))?;
+
+        Ok(Self { path, owned: true })
+    }
+}
+
+impl Drop for OperationGuard {
+    fn drop(&mut self) {
+        if self.owned {
+            let _ = fs::remove_file(&self.path);
+        }
+    }
+}
+
+fn busy_error(session_name: &str, existing: &OperationRecord) -> ParaError {
+    ParaError::invalid_args(format!(
+        "Session '{session_name}' is busy: {} (pid {}). Wait for it to complete.",
+        existing.describe(Utc::now()),
+        existing.pid
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn dead_pid() -> u32 {
+        let mut child = std::process::Command::new("true").spawn().unwrap();
+        let pid = child.id();
+        child.wait().unwrap();
+        pid
+    }
+
+    fn write_record(state_dir: &Path, session: &str, pid: u32) {
+        let record = OperationRecord {
+            operation: SessionOperation::Finishing,
+            pid,
+            started_at: Utc::now(),
+        };
+        fs::write(
+            operation_file(state_dir, session),
+            serde_json::to_string(&record).unwrap(),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_guard_records_and_clears_operation() {
+        let temp = TempDir::new().unwrap();
+
+        {
+            let _guard =
+                OperationGuard::begin(temp.path(), "alpha", SessionOperation::Cancelling).unwrap();
+            let record = current_operation(temp.path(), "alpha").unwrap();
+            assert_eq!(record.operation, SessionOperation::Cancelling);
+            assert_eq!(record.pid, std::process::id());
+        }
+
+        assert!(current_operation(temp.path(), "alpha").is_none());
+        assert!(!operation_file(temp.path(), "alpha").exists());
+    }
+
+    #[test]
+    fn test_stale_record_from_dead_pid_is_ignored_and_cleaned() {
+        let temp = TempDir::new().unwrap();
+        write_record(temp.path(), "crashed", dead_pid());
+
+        assert!(current_operation(temp.path(), "crashed").is_none());
+        assert!(!operation_file(temp.path(), "crashed").exists());
+
+        write_record(temp.path(), "crashed", dead_pid());
+        let guard = OperationGuard::begin(temp.path(), "crashed", SessionOperation::Finishing);
+        assert!(guard.is_ok());
+    }
+
+    #[test]
+    fn test_live_record_from_other_process_blocks() {
+        let temp = TempDir::new().unwrap();
+        let mut child = std::process::Command::new("sleep")
+            .arg("30")
+            .spawn()
+            .unwrap();
+        write_record(temp.path(), "busy", child.id());
+
+        let result = OperationGuard::begin(temp.path(), "busy", SessionOperation::Cancelling);
+        child.kill().unwrap();
+        child.wait().unwrap();
+
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("is busy"));
+        assert!(err.contains("Finishing…"));
+    }
+
+    #[test]
+    fn test_nested_guard_in_same_process_keeps_record() {
+        let temp = TempDir::new().unwrap();
+        let _outer =
+            OperationGuard::begin(temp.path(), "nested", SessionOperation::Finishing).unwrap();
+
+        drop(OperationGuard::begin(temp.path(), "nested", SessionOperation::Cancelling).unwrap());
+
+        let record = current_operation(temp.path(), "nested").unwrap();
+        assert_eq!(record.operation, SessionOperation::Finishing);
+    }
+
+    #[test]
+    fn test_describe_includes_elapsed_time() {
+        let now = Utc::now();
+        let record = OperationRecord {
+            operation: SessionOperation::Finishing,
+            pid: 1,
+            started_at: now - chrono::Duration::seconds(75),
+        };
+        assert_eq!(record.describe(now), "Finishing… 1m15s");
+    }
+}
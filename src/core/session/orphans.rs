@@ -0,0 +1,168 @@
+//! Para branches that no session or archive accounts for
+//!
+//! A `<prefix>/*` branch is orphaned when no session state refers to it and
+//! it is neither an archived nor a trashed branch. Such branches are left behind when a state
+//! file is lost or deleted by hand; `para clean` removes them and
+//! `para adopt` turns one back into a session.
+
+use super::integration_backup::is_backup_branch;
+use super::SessionManager;
+use crate::core::git::repository::execute_git_command;
+use crate::core::git::GitService;
+use crate::utils::Result;
+use chrono::{DateTime, Utc};
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrphanedBranch {
+    pub name: String,
+    /// Commit date of the branch tip
+    pub tip_date: Option<DateTime<Utc>>,
+    /// Commits on the branch that are not on the main branch
+    pub commit_count: usize,
+}
+
+/// All orphaned para branches, sorted by name
+pub fn find_orphaned_branches(
+    manager: &SessionManager,
+    git_service: &GitService,
+) -> Result<Vec<OrphanedBranch>> {
+    let prefix = format!("{}/", manager.config().get_branch_prefix());
+    let archive_prefix = format!("{prefix}archived/");
+    let trash_prefix = format!("{prefix}trash/");
+    let owned: HashSet<String> = manager
+        .list_sessions()?
+        .into_iter()
+        .map(|session| session.branch)
+        .collect();
+
+    let repo = git_service.repository();
+    let main_branch = repo.get_main_branch().ok();
+
+    let mut orphans: Vec<OrphanedBranch> = git_service
+        .branch_manager()
+        .list_branches()?
+        .into_iter()
+        .map(|branch| branch.name)
+        .filter(|name| {
+            name.starts_with(&prefix)
+                && !name.starts_with(&archive_prefix)
+                && !name.starts_with(&trash_prefix)
+        })
+        .filter(|name| !is_backup_branch(name))
+        .filter(|name| !owned.contains(name))
+        // A state file named after the branch still claims it, even if the
+        // branch recorded in it differs
+        .filter(|name| !manager.session_exists(&name[prefix.len()..]))
+        .map(|name| {
+            let tip_date = execute_git_command(repo, &["log", "-1", "--format=%cI", &name])
+                .ok()
+                .and_then(|date| DateTime::parse_from_rfc3339(date.trim()).ok())
+                .map(|date| date.with_timezone(&Utc));
+            let range = match &main_branch {
+                Some(main) => format!("{main}..{name}"),
+                None => name.clone(),
+            };
+            let commit_count = execute_git_command(repo, &["rev-list", "--count", &range])
+                .ok()
+                .and_then(|count| count.trim().parse().ok())
+                .unwrap_or(0);
+            OrphanedBranch {
+                name,
+                tip_date,
+                commit_count,
+            }
+        })
+        .collect();
+
+    orphans.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(orphans)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::session::SessionState;
+    use crate::test_utils::test_helpers::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_orphaned_branches_exclude_sessions_and_archives() {
+        let temp_dir = TempDir::new().unwrap();
+        let (git_temp, git_service) = setup_test_repo();
+        let config = create_test_config_with_dir(&temp_dir);
+        let manager = SessionManager::for_repository(&config, git_temp.path());
+        let repo = git_service.repository();
+
+        for branch in [
+            "test/owned",
+            "test/renamed-branch",
+            "test/lost",
+            "test/archived/20240101-120000/old",
+            "test/owned-pre-para-20240101-120000",
+            "feature/unrelated",
+        ] {
+            execute_git_command(repo, &["branch", branch]).unwrap();
+        }
+        manager
+            .save_state(&SessionState::new(
+                "owned".to_string(),
+                "test/owned".to_string(),
+                temp_dir.path().join("owned"),
+            ))
+            .unwrap();
+        manager
+            .save_state(&SessionState::new(
+                "renamed".to_string(),
+                "test/renamed-branch".to_string(),
+                temp_dir.path().join("renamed"),
+            ))
+            .unwrap();
+
+        // One commit that is not on main
+        execute_git_command(repo, &["checkout", "-q", "test/lost"]).unwrap();
+        fs::write(git_temp.path().join("lost.txt"), "work").unwrap();
+        execute_git_command(repo, &["add", "lost.txt"]).unwrap();
+        execute_git_command(repo, &["commit", "-q", "-m", "Lost work"]).unwrap();
+        execute_git_command(repo, &["checkout", "-q", "-"]).unwrap();
+
+        let orphans = find_orphaned_branches(&manager, &git_service).unwrap();
+        assert_eq!(orphans.len(), 1);
+        assert_eq!(orphans[0].name, "test/lost");
+        assert_eq!(orphans[0].commit_count, 1);
+        assert!(orphans[0].tip_date.is_some());
+    }
+
+    #[test]
+    fn test_adopted_branch_is_a_resumable_session() {
+        let temp_dir = TempDir::new().unwrap();
+        let (git_temp, git_service) = setup_test_repo();
+        let config = create_test_config_with_dir(&temp_dir);
+        let mut manager = SessionManager::for_repository(&config, git_temp.path());
+        let repo = git_service.repository();
+        execute_git_command(repo, &["branch", "test/lost"]).unwrap();
+
+        let session = manager.adopt_branch("test/lost", None).unwrap();
+        assert_eq!(session.name, "lost");
+        assert_eq!(session.branch, "test/lost");
+        assert!(session.base_commit.is_some());
+        assert_eq!(
+            git_service
+                .worktree_manager()
+                .get_worktree_branch(&session.worktree_path)
+                .unwrap(),
+            "test/lost"
+        );
+        assert_eq!(manager.load_state("lost").unwrap().branch, "test/lost");
+        assert!(find_orphaned_branches(&manager, &git_service)
+            .unwrap()
+            .is_empty());
+
+        // Owned and missing branches can't be adopted
+        assert!(manager
+            .adopt_branch("test/lost", Some("again".to_string()))
+            .is_err());
+        assert!(manager.adopt_branch("test/missing", None).is_err());
+    }
+}
@@ -0,0 +1,102 @@
+//! Where session worktrees live
+//!
+//! New worktrees go below `directories.subtrees_dir`, either directly as
+//! `<subtrees_dir>/<session>` or grouped by branch prefix as
+//! `<subtrees_dir>/<branch_prefix>/<session>`, depending on
+//! `directories.layout`. The layout only decides where new worktrees are
+//! created: an existing session keeps the path recorded in its state file,
+//! so changing the layout does not move anything.
+
+use crate::config::{Config, WorktreeLayout};
+use crate::utils::Result;
+use std::path::{Path, PathBuf};
+
+pub struct SessionPaths;
+
+impl SessionPaths {
+    /// Worktree directory of a new session named `session` in the repository
+    /// at `repo_root`
+    pub fn worktree_path(config: &Config, repo_root: &Path, session: &str) -> Result<PathBuf> {
+        Ok(Self::in_layout(
+            &config.subtrees_path(repo_root)?,
+            config.directories.layout,
+            config.get_branch_prefix(),
+            session,
+        ))
+    }
+
+    /// Where `session` goes below `subtrees` in `layout`
+    pub fn in_layout(
+        subtrees: &Path,
+        layout: WorktreeLayout,
+        branch_prefix: &str,
+        session: &str,
+    ) -> PathBuf {
+        match layout {
+            WorktreeLayout::Flat => subtrees.join(session),
+            WorktreeLayout::ByPrefix => subtrees.join(branch_prefix).join(session),
+        }
+    }
+
+    /// Where the worktree of `session` may be, in the configured layout first
+    /// and then in the other one
+    pub fn candidates(config: &Config, repo_root: &Path, session: &str) -> Result<Vec<PathBuf>> {
+        let subtrees = config.subtrees_path(repo_root)?;
+        let other = match config.directories.layout {
+            WorktreeLayout::Flat => WorktreeLayout::ByPrefix,
+            WorktreeLayout::ByPrefix => WorktreeLayout::Flat,
+        };
+        Ok([config.directories.layout, other]
+            .into_iter()
+            .map(|layout| Self::in_layout(&subtrees, layout, config.get_branch_prefix(), session))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::test_helpers::create_test_config;
+
+    #[test]
+    fn test_layouts_produce_documented_paths() {
+        let repo = Path::new("/repo");
+        let mut config = create_test_config();
+        config.directories.subtrees_dir = ".para/worktrees".to_string();
+        config.git.branch_prefix = "para".to_string();
+
+        assert_eq!(
+            SessionPaths::worktree_path(&config, repo, "auth").unwrap(),
+            PathBuf::from("/repo/.para/worktrees/auth")
+        );
+
+        config.directories.layout = WorktreeLayout::ByPrefix;
+        assert_eq!(
+            SessionPaths::worktree_path(&config, repo, "auth").unwrap(),
+            PathBuf::from("/repo/.para/worktrees/para/auth")
+        );
+        assert_eq!(
+            SessionPaths::candidates(&config, repo, "auth").unwrap(),
+            vec![
+                PathBuf::from("/repo/.para/worktrees/para/auth"),
+                PathBuf::from("/repo/.para/worktrees/auth"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_layout_is_read_from_config() {
+        let config: crate::config::DirectoryConfig = serde_json::from_str(
+            r#"{"subtrees_dir": "subtrees", "state_dir": ".para/state", "layout": "by-prefix"}"#,
+        )
+        .unwrap();
+        assert_eq!(config.layout, WorktreeLayout::ByPrefix);
+
+        // Older configs have no layout and keep the flat one
+        let config: crate::config::DirectoryConfig =
+            serde_json::from_str(r#"{"subtrees_dir": "subtrees", "state_dir": ".para/state"}"#)
+                .unwrap();
+        assert_eq!(config.layout, WorktreeLayout::Flat);
+        assert!(!serde_json::to_string(&config).unwrap().contains("layout"));
+    }
+}
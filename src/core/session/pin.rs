@@ -0,0 +1,88 @@
+//! Pinned sessions
+//!
+//! `para pin` marks a long-lived session as one to keep: it never shows up as
+//! stale or idle, the daemon's idle action and `para clean --sessions` leave
+//! it alone, and cancelling it asks first. A pinned session that is cancelled
+//! anyway leaves `<state_dir>/archive/<timestamp>/<session>.pinned` next to
+//! its archived notes, which keeps its archived branch out of archive
+//! retention.
+
+use super::SessionManager;
+use crate::utils::{ParaError, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Pin or unpin a session; `false` when it already was
+pub fn set_pinned(
+    session_manager: &SessionManager,
+    session_name: &str,
+    pinned: bool,
+) -> Result<bool> {
+    let mut state = session_manager.load_state(session_name)?;
+    if state.pinned == pinned {
+        return Ok(false);
+    }
+    state.pinned = pinned;
+    session_manager.save_state(&state)?;
+    Ok(true)
+}
+
+pub fn archived_pin_file(state_dir: &Path, timestamp: &str, session_name: &str) -> PathBuf {
+    state_dir
+        .join("archive")
+        .join(timestamp)
+        .join(format!("{session_name}.pinned"))
+}
+
+/// Remember that the session archived at `timestamp` was pinned
+pub fn archive_pin(state_dir: &Path, timestamp: &str, session_name: &str) -> Result<()> {
+    let marker = archived_pin_file(state_dir, timestamp, session_name);
+    if let Some(parent) = marker.parent() {
+        fs::create_dir_all(parent).map_err(|e| ParaError::from_io("create archive", parent, e))?;
+    }
+    fs::write(&marker, "").map_err(|e| ParaError::from_io("write pin marker", &marker, e))
+}
+
+/// Whether the archived branch of `session_name` from `timestamp` is kept
+/// regardless of archive retention
+pub fn is_archive_pinned(state_dir: &Path, timestamp: &str, session_name: &str) -> bool {
+    archived_pin_file(state_dir, timestamp, session_name).exists()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::session::SessionState;
+    use crate::test_utils::test_helpers::create_test_config_with_dir;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_pin_and_unpin() {
+        let temp = TempDir::new().unwrap();
+        let config = create_test_config_with_dir(&temp);
+        let session_manager = SessionManager::new(&config);
+        session_manager
+            .save_state(&SessionState::new(
+                "perf".to_string(),
+                "test/perf".to_string(),
+                temp.path().join("perf"),
+            ))
+            .unwrap();
+
+        assert!(set_pinned(&session_manager, "perf", true).unwrap());
+        assert!(session_manager.load_state("perf").unwrap().pinned);
+        assert!(!set_pinned(&session_manager, "perf", true).unwrap());
+        assert!(set_pinned(&session_manager, "perf", false).unwrap());
+        assert!(!session_manager.load_state("perf").unwrap().pinned);
+        assert!(set_pinned(&session_manager, "missing", true).is_err());
+    }
+
+    #[test]
+    fn test_archive_pin_marker() {
+        let temp = TempDir::new().unwrap();
+        assert!(!is_archive_pinned(temp.path(), "20240501-120000", "perf"));
+        archive_pin(temp.path(), "20240501-120000", "perf").unwrap();
+        assert!(is_archive_pinned(temp.path(), "20240501-120000", "perf"));
+        assert!(!is_archive_pinned(temp.path(), "20240502-120000", "perf"));
+    }
+}
@@ -1,13 +1,23 @@
 use crate::config::Config;
-use crate::core::git::{ArchiveBranchIterator, GitService, HasTimestamp};
-use crate::core::session::{SessionManager, SessionState};
-use crate::utils::{ArchiveBranchParser, ParaError, Result};
+use crate::core::git::repository::execute_git_command;
+use crate::core::git::{ArchiveBranchIterator, ArchiveRef, GitService, HasTimestamp};
+use crate::core::interrupt::CancelToken;
+use crate::core::session::rollback::CreationRollback;
+use crate::core::session::{context, SessionManager, SessionPaths, SessionState};
+use crate::core::status::format_status_age;
+use crate::utils::{ArchiveBranchParser, ParaError, Result, ARCHIVE_TIMESTAMP_FORMAT};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use std::path::PathBuf;
 
 #[derive(Debug, Clone)]
 pub struct RecoveryOptions {
     pub force_overwrite: bool,
     pub preserve_original_name: bool,
+    /// Archive a diverged live branch with the session's name and restore
+    /// under that name instead of a suffixed one
+    pub replace_live_branch: bool,
 }
 
 impl Default for RecoveryOptions {
@@ -15,6 +25,63 @@ impl Default for RecoveryOptions {
         Self {
             force_overwrite: false,
             preserve_original_name: true,
+            replace_live_branch: false,
+        }
+    }
+}
+
+/// How a live branch with the name an archive is restored under relates to
+/// the archive's tip
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LiveBranch {
+    Missing,
+    /// Points at the archive's tip
+    SameTip,
+    /// Has the archive's tip in its history
+    ContainsArchive,
+    /// Lacks commits of the archive
+    Diverged,
+}
+
+/// What recovering does with the archived branch
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum RestorePlan {
+    /// Restore under the original name, which is free
+    Original,
+    /// Leave the archive alone and recover on the live branch, which already
+    /// has every archived commit
+    UseLive,
+    /// Restore as `<name>-recovered-N` next to the live branch
+    Suffixed,
+    /// Archive the live branch, then restore under the original name
+    ReplaceLive,
+}
+
+impl RestorePlan {
+    /// Decide from how the live branch relates to the archive
+    pub fn decide(live: LiveBranch, replace: bool) -> Self {
+        match live {
+            LiveBranch::Missing => Self::Original,
+            LiveBranch::SameTip | LiveBranch::ContainsArchive => Self::UseLive,
+            LiveBranch::Diverged if replace => Self::ReplaceLive,
+            LiveBranch::Diverged => Self::Suffixed,
+        }
+    }
+
+    /// One line about the plan for a live branch named `branch`
+    pub fn describe(&self, branch: &str) -> Option<String> {
+        match self {
+            Self::Original => None,
+            Self::UseLive => Some(format!(
+                "Branch '{branch}' already contains the archived commits; recovering on it"
+            )),
+            Self::Suffixed => Some(format!(
+                "Branch '{branch}' has diverged from the archive; restoring next to it (--replace archives it instead)"
+            )),
+            Self::ReplaceLive => Some(format!(
+                "Branch '{branch}' has diverged from the archive; archiving it and restoring under its name"
+            )),
         }
     }
 }
@@ -32,17 +99,132 @@ impl HasTimestamp for RecoveryInfo {
     }
 }
 
+impl RecoveryInfo {
+    pub fn archive_ref(&self) -> ArchiveRef {
+        ArchiveRef {
+            branch: self.archived_branch.clone(),
+            timestamp: self.archived_timestamp.clone(),
+            session: self.original_session_name.clone(),
+        }
+    }
+
+    /// When the session was archived; archive timestamps are recorded in UTC
+    pub fn archived_at(&self) -> Option<DateTime<Utc>> {
+        NaiveDateTime::parse_from_str(&self.archived_timestamp, ARCHIVE_TIMESTAMP_FORMAT)
+            .ok()
+            .map(|dt| dt.and_utc())
+    }
+}
+
+/// Archives offered by the interactive `para recover` picker
+pub const PICKER_LIMIT: usize = 20;
+
+/// An archive with what the picker shows about it
+#[derive(Debug, Clone)]
+pub struct ArchiveCandidate {
+    pub info: RecoveryInfo,
+    /// Commits on the archive that the default branch doesn't have
+    pub commits_ahead: Option<usize>,
+    /// Subject of the archive's tip commit
+    pub subject: String,
+}
+
+impl ArchiveCandidate {
+    /// One picker line: name, age, commit count and tip subject
+    pub fn describe(&self, now: DateTime<Utc>) -> String {
+        let age = self
+            .info
+            .archived_at()
+            .map(|at| format_status_age(now - at))
+            .unwrap_or_else(|| "unknown age".to_string());
+        let commits = match self.commits_ahead {
+            Some(1) => "1 commit".to_string(),
+            Some(count) => format!("{count} commits"),
+            None => "? commits".to_string(),
+        };
+        format!(
+            "{:<30} {:<10} {:<12} {}",
+            self.info.original_session_name, age, commits, self.subject
+        )
+    }
+}
+
 #[derive(Debug)]
 pub struct RecoveryResult {
     pub session_name: String,
     pub branch_name: String,
     pub worktree_path: PathBuf,
+    /// How the archived branch was restored, `Original` for an active session
+    pub restore: RestorePlan,
+    /// Archive the live branch was moved to by [`RestorePlan::ReplaceLive`]
+    pub replaced_archive: Option<String>,
+}
+
+#[derive(Debug)]
+pub enum BulkRecoveryOutcome {
+    Restored(RecoveryResult),
+    /// Dry run: the archive would have been restored
+    WouldRestore,
+    Skipped(String),
+    Failed(String),
+}
+
+/// Result of recovering a single archive during `para recover --all`
+#[derive(Debug)]
+pub struct BulkRecoveryEntry {
+    pub session_name: String,
+    pub archived_branch: String,
+    pub archived_at: Option<DateTime<Utc>>,
+    pub outcome: BulkRecoveryOutcome,
+}
+
+/// Parse a `--since` value: a duration such as `30m`, `12h`, `3d` or `2w`, a
+/// date (`2024-03-01`, midnight UTC) or an RFC 3339 timestamp.
+pub fn parse_since(value: &str, now: DateTime<Utc>) -> Result<DateTime<Utc>> {
+    parse_time_bound("--since", value, now)
+}
+
+/// Parse a point in time given to `flag`, accepting the same formats as
+/// [`parse_since`]. Durations count back from `now`.
+pub fn parse_time_bound(flag: &str, value: &str, now: DateTime<Utc>) -> Result<DateTime<Utc>> {
+    let value = value.trim();
+
+    if let Ok(timestamp) = DateTime::parse_from_rfc3339(value) {
+        return Ok(timestamp.with_timezone(&Utc));
+    }
+
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        if let Some(midnight) = date.and_hms_opt(0, 0, 0) {
+            return Ok(midnight.and_utc());
+        }
+    }
+
+    let invalid = || {
+        ParaError::invalid_args(format!(
+            "Invalid {flag} value '{value}'. Use a duration like 30m, 12h, 3d or 2w, a date like 2024-03-01, or a timestamp like 2024-03-01T09:30:00Z"
+        ))
+    };
+
+    let split = value.len().saturating_sub(1);
+    let (amount, unit) = (value.get(..split).ok_or_else(invalid)?, &value[split..]);
+    let amount: i64 = amount.parse().map_err(|_| invalid())?;
+    let duration = match unit {
+        "m" => chrono::Duration::try_minutes(amount),
+        "h" => chrono::Duration::try_hours(amount),
+        "d" => chrono::Duration::try_days(amount),
+        "w" => chrono::Duration::try_weeks(amount),
+        _ => None,
+    }
+    .ok_or_else(invalid)?;
+
+    now.checked_sub_signed(duration).ok_or_else(invalid)
 }
 
 pub struct SessionRecovery<'a> {
     config: &'a Config,
     git_service: &'a GitService,
     session_manager: &'a SessionManager,
+    cancel: CancelToken,
 }
 
 impl<'a> SessionRecovery<'a> {
@@ -55,9 +237,16 @@ impl<'a> SessionRecovery<'a> {
             config,
             git_service,
             session_manager,
+            cancel: CancelToken::global().clone(),
         }
     }
 
+    /// Stop between steps when `cancel` is cancelled instead of on Ctrl-C
+    pub fn with_cancel_token(mut self, cancel: CancelToken) -> Self {
+        self.cancel = cancel;
+        self
+    }
+
     pub fn recover_active_session(&self, session_name: &str) -> Result<RecoveryResult> {
         let session_state = self.session_manager.load_state(session_name)?;
 
@@ -73,11 +262,16 @@ impl<'a> SessionRecovery<'a> {
                 session_name: session_name.to_string(),
                 branch_name: session_state.branch,
                 worktree_path: session_state.worktree_path,
+                restore: RestorePlan::Original,
+                replaced_archive: None,
             });
         }
 
         // Need to recover missing worktree
         if !worktree_exists && branch_exists {
+            self.session_manager
+                .check_storage(&self.git_service.repository().root)?;
+
             // Ensure parent directory exists
             if let Some(parent) = session_state.worktree_path.parent() {
                 std::fs::create_dir_all(parent)?;
@@ -87,11 +281,19 @@ impl<'a> SessionRecovery<'a> {
             self.git_service
                 .worktree_manager()
                 .create_worktree(&session_state.branch, &session_state.worktree_path)?;
+            context::bring_back_context(
+                self.session_manager.state_dir(),
+                session_name,
+                &session_state.worktree_path,
+                session_name,
+            );
 
             return Ok(RecoveryResult {
                 session_name: session_name.to_string(),
                 branch_name: session_state.branch,
                 worktree_path: session_state.worktree_path,
+                restore: RestorePlan::Original,
+                replaced_archive: None,
             });
         } else if !branch_exists {
             return Err(ParaError::session_not_found(format!(
@@ -105,6 +307,8 @@ impl<'a> SessionRecovery<'a> {
             session_name: session_name.to_string(),
             branch_name: session_state.branch,
             worktree_path: session_state.worktree_path,
+            restore: RestorePlan::Original,
+            replaced_archive: None,
         })
     }
 
@@ -132,23 +336,262 @@ impl<'a> SessionRecovery<'a> {
             .list_archived_entries(|archived_branch| self.parse_archived_branch(archived_branch))
     }
 
+    /// The `limit` newest archives with their commit count against the
+    /// default branch and tip subject. Names and subjects come from a single
+    /// `for-each-ref`; only the counts take a git call per archive.
+    pub fn archive_candidates(&self, limit: usize) -> Result<Vec<ArchiveCandidate>> {
+        let repo = self.git_service.repository();
+        let pattern = format!("refs/heads/{}/archived/", self.config.get_branch_prefix());
+        let refs = execute_git_command(
+            repo,
+            &[
+                "for-each-ref",
+                "--format=%(refname:short)%00%(subject)",
+                &pattern,
+            ],
+        )?;
+
+        let mut candidates = Vec::new();
+        for line in refs.lines() {
+            let (branch, subject) = line.split_once('\0').unwrap_or((line, ""));
+            let Some(archive) =
+                ArchiveBranchParser::parse_archive_branch(branch, self.config.get_branch_prefix())?
+            else {
+                continue;
+            };
+            candidates.push(ArchiveCandidate {
+                info: RecoveryInfo {
+                    archived_branch: archive.full_branch_name,
+                    original_session_name: archive.session_name,
+                    archived_timestamp: archive.timestamp,
+                },
+                commits_ahead: None,
+                subject: subject.to_string(),
+            });
+        }
+        candidates.sort_by(|a, b| b.info.archived_timestamp.cmp(&a.info.archived_timestamp));
+        candidates.truncate(limit);
+
+        let default_branch = repo.get_main_branch().ok();
+        for candidate in &mut candidates {
+            candidate.commits_ahead = default_branch.as_ref().and_then(|default_branch| {
+                let range = format!("{default_branch}..{}", candidate.info.archived_branch);
+                execute_git_command(repo, &["rev-list", "--count", &range])
+                    .ok()
+                    .and_then(|count| count.parse().ok())
+            });
+        }
+        Ok(candidates)
+    }
+
+    /// `git diff --stat` of what the archive changed since it left the default branch
+    pub fn archive_diff_stat(&self, candidate: &ArchiveCandidate) -> Result<String> {
+        let repo = self.git_service.repository();
+        let branch = &candidate.info.archived_branch;
+        match repo.get_main_branch() {
+            Ok(default_branch) => execute_git_command(
+                repo,
+                &["diff", "--stat", &format!("{default_branch}...{branch}")],
+            ),
+            Err(_) => execute_git_command(repo, &["show", "--stat", "--format=", branch]),
+        }
+    }
+
+    /// Restore exactly the archive described by `info`
+    pub fn recover_archive(
+        &self,
+        info: &RecoveryInfo,
+        options: RecoveryOptions,
+    ) -> Result<RecoveryResult> {
+        self.recover_from_info(info, options)
+    }
+
     pub fn recover_session(
         &self,
         session_name: &str,
         options: RecoveryOptions,
     ) -> Result<RecoveryResult> {
-        let recoverable_sessions = self.list_recoverable_sessions()?;
+        let recovery_info = self.find_recoverable(session_name)?;
+        self.recover_from_info(&recovery_info, options)
+    }
 
-        let recovery_info = recoverable_sessions
-            .iter()
+    /// What [`Self::recover_session`] would restore, checked without
+    /// changing anything
+    pub fn recover_session_dry_run(
+        &self,
+        session_name: &str,
+        options: RecoveryOptions,
+    ) -> Result<RecoveryResult> {
+        let recovery_info = self.find_recoverable(session_name)?;
+        self.check_recoverable(&recovery_info, &options)?;
+        let restore = self.restore_plan(&recovery_info, options.replace_live_branch)?;
+        let (restored_branch, replaced_archive) =
+            self.restore_branch(&recovery_info, &restore, true)?;
+
+        Ok(RecoveryResult {
+            session_name: if options.preserve_original_name {
+                recovery_info.original_session_name.clone()
+            } else {
+                restored_branch.clone()
+            },
+            worktree_path: self.get_target_worktree_path(&restored_branch)?,
+            branch_name: restored_branch,
+            restore,
+            replaced_archive,
+        })
+    }
+
+    /// What restoring the archive of `info` does about a live branch that
+    /// has the session's name
+    pub fn restore_plan(&self, info: &RecoveryInfo, replace: bool) -> Result<RestorePlan> {
+        let live = self.live_branch(&info.archived_branch, &info.original_session_name)?;
+        Ok(RestorePlan::decide(live, replace))
+    }
+
+    fn live_branch(&self, archive: &str, name: &str) -> Result<LiveBranch> {
+        let branch_manager = self.git_service.branch_manager();
+        if !branch_manager.branch_exists(name)? {
+            return Ok(LiveBranch::Missing);
+        }
+        if branch_manager.get_branch_commit(archive)? == branch_manager.get_branch_commit(name)? {
+            return Ok(LiveBranch::SameTip);
+        }
+        let repo = self.git_service.repository();
+        if execute_git_command(repo, &["merge-base", "--is-ancestor", archive, name]).is_ok() {
+            Ok(LiveBranch::ContainsArchive)
+        } else {
+            Ok(LiveBranch::Diverged)
+        }
+    }
+
+    /// Restore the archive of `info` as `plan` says and return the branch the
+    /// session is recovered on, with the archive the live branch was moved to.
+    /// With `dry_run` only check that it can be done.
+    fn restore_branch(
+        &self,
+        info: &RecoveryInfo,
+        plan: &RestorePlan,
+        dry_run: bool,
+    ) -> Result<(String, Option<String>)> {
+        let branch_manager = self.git_service.branch_manager();
+        let name = &info.original_session_name;
+        let archive = info.archive_ref();
+        let target = match plan {
+            RestorePlan::UseLive => return Ok((name.clone(), None)),
+            RestorePlan::Suffixed => self.recovered_branch_name(name)?,
+            RestorePlan::Original | RestorePlan::ReplaceLive => name.clone(),
+        };
+
+        let mut replaced_archive = None;
+        if *plan == RestorePlan::ReplaceLive {
+            let prefix = self.config.get_branch_prefix();
+            if dry_run {
+                let live_archive = branch_manager.archive_dry_run(name, name, prefix, None)?;
+                // The name is still taken, so only the archive itself is checked
+                branch_manager.restore_dry_run(&archive, None, None)?;
+                return Ok((target, Some(live_archive.branch)));
+            }
+            replaced_archive = Some(branch_manager.archive(name, name, prefix, None)?.branch);
+        }
+
+        let restored = if dry_run {
+            branch_manager.restore_dry_run(&archive, Some(&target), None)?
+        } else {
+            branch_manager.restore(&archive, Some(&target), None)?
+        };
+        Ok((restored, replaced_archive))
+    }
+
+    /// First free `<name>-recovered-N`, counting the live branch as the first
+    fn recovered_branch_name(&self, name: &str) -> Result<String> {
+        let branch_manager = self.git_service.branch_manager();
+        for i in 2..1000 {
+            let candidate = format!("{name}-recovered-{i}");
+            if !branch_manager.branch_exists(&candidate)? {
+                return Ok(candidate);
+            }
+        }
+        Err(ParaError::git_operation(format!(
+            "No free '{name}-recovered-N' branch name after 1000 attempts"
+        )))
+    }
+
+    fn find_recoverable(&self, session_name: &str) -> Result<RecoveryInfo> {
+        self.list_recoverable_sessions()?
+            .into_iter()
             .find(|info| info.original_session_name == session_name)
-            .ok_or_else(|| ParaError::session_not_found(session_name.to_string()))?;
+            .ok_or_else(|| ParaError::session_not_found(session_name.to_string()))
+    }
 
-        self.recover_from_info(recovery_info, options)
+    /// Recover every archived session, newest first, optionally limited to
+    /// archives created at or after `since`.
+    ///
+    /// Each archive goes through the same path as single recovery. Archives whose
+    /// session or branch name is already taken are skipped rather than renamed, and
+    /// only the newest archive of a session is considered.
+    pub fn recover_all(
+        &self,
+        since: Option<DateTime<Utc>>,
+        dry_run: bool,
+    ) -> Result<Vec<BulkRecoveryEntry>> {
+        let mut entries = Vec::new();
+        let mut selected = HashSet::new();
+
+        for info in self.list_recoverable_sessions()? {
+            let archived_at = info.archived_at();
+            if let Some(since) = since {
+                if archived_at.is_none_or(|at| at < since) {
+                    continue;
+                }
+            }
+
+            let outcome = if !selected.insert(info.original_session_name.clone()) {
+                BulkRecoveryOutcome::Skipped("a newer archive is selected".to_string())
+            } else if let Some(reason) = self.bulk_skip_reason(&info)? {
+                BulkRecoveryOutcome::Skipped(reason)
+            } else if dry_run {
+                match self.git_service.branch_manager().restore_dry_run(
+                    &info.archive_ref(),
+                    None,
+                    None,
+                ) {
+                    Ok(_) => BulkRecoveryOutcome::WouldRestore,
+                    Err(e) => BulkRecoveryOutcome::Skipped(e.to_string()),
+                }
+            } else {
+                match self.recover_from_info(&info, RecoveryOptions::default()) {
+                    Ok(result) => BulkRecoveryOutcome::Restored(result),
+                    Err(e) => BulkRecoveryOutcome::Failed(e.to_string()),
+                }
+            };
+
+            entries.push(BulkRecoveryEntry {
+                session_name: info.original_session_name,
+                archived_branch: info.archived_branch,
+                archived_at,
+                outcome,
+            });
+        }
+
+        Ok(entries)
+    }
+
+    fn bulk_skip_reason(&self, info: &RecoveryInfo) -> Result<Option<String>> {
+        let name = &info.original_session_name;
+
+        if self.session_manager.session_exists(name) {
+            return Ok(Some(format!("session '{name}' already exists")));
+        }
+
+        if self.git_service.branch_manager().branch_exists(name)? {
+            return Ok(Some(format!("branch '{name}' already exists")));
+        }
+
+        Ok(None)
     }
 
     pub fn validate_recovery(&self, session_name: &str) -> Result<RecoveryValidation> {
-        let _recovery_info = self
+        let recovery_info = self
             .list_recoverable_sessions()?
             .into_iter()
             .find(|info| info.original_session_name == session_name)
@@ -166,7 +609,7 @@ impl<'a> SessionRecovery<'a> {
                 .push(format!("Session '{session_name}' already exists"));
         }
 
-        let target_worktree_path = self.get_target_worktree_path(session_name);
+        let target_worktree_path = self.get_target_worktree_path(session_name)?;
         if target_worktree_path.exists() {
             validation.conflicts.push(format!(
                 "Worktree directory already exists: {}",
@@ -174,11 +617,12 @@ impl<'a> SessionRecovery<'a> {
             ));
         }
 
-        let branch_manager = self.git_service.branch_manager();
-        if branch_manager.branch_exists(session_name)? {
-            validation.warnings.push(format!(
-                "Branch '{session_name}' already exists, will create unique name"
-            ));
+        let restore = RestorePlan::decide(
+            self.live_branch(&recovery_info.archived_branch, session_name)?,
+            false,
+        );
+        if let Some(note) = restore.describe(session_name) {
+            validation.warnings.push(note);
         }
 
         if !validation.conflicts.is_empty() {
@@ -193,22 +637,15 @@ impl<'a> SessionRecovery<'a> {
         recovery_info: &RecoveryInfo,
         options: RecoveryOptions,
     ) -> Result<RecoveryResult> {
-        let validation = self.validate_recovery(&recovery_info.original_session_name)?;
+        self.check_recoverable(recovery_info, &options)?;
+        self.session_manager
+            .check_storage(&self.git_service.repository().root)?;
 
-        if !validation.can_recover && !options.force_overwrite {
-            return Err(ParaError::worktree_operation(format!(
-                "Cannot recover session due to conflicts: {}",
-                validation.conflicts.join(", ")
-            )));
-        }
-
-        let branch_manager = self.git_service.branch_manager();
         let worktree_manager = self.git_service.worktree_manager();
 
-        let restored_branch = branch_manager.restore_from_archive(
-            &recovery_info.archived_branch,
-            self.config.get_branch_prefix(),
-        )?;
+        let restore = self.restore_plan(recovery_info, options.replace_live_branch)?;
+        let (restored_branch, replaced_archive) =
+            self.restore_branch(recovery_info, &restore, false)?;
 
         let final_session_name = if options.preserve_original_name {
             recovery_info.original_session_name.clone()
@@ -216,10 +653,16 @@ impl<'a> SessionRecovery<'a> {
             restored_branch.clone()
         };
 
-        let worktree_path = self.get_target_worktree_path(&restored_branch);
+        let worktree_path = self.get_target_worktree_path(&restored_branch)?;
 
         if worktree_path.exists() {
             if options.force_overwrite {
+                crate::utils::path::ensure_deletable(
+                    &worktree_path,
+                    &self
+                        .config
+                        .owned_directories(&self.git_service.repository().root),
+                )?;
                 std::fs::remove_dir_all(&worktree_path)?;
             } else {
                 return Err(ParaError::worktree_operation(format!(
@@ -233,7 +676,26 @@ impl<'a> SessionRecovery<'a> {
             std::fs::create_dir_all(parent)?;
         }
 
+        // A force-cancelled session leaves its worktree registered after the
+        // directory is gone, which keeps git from checking the branch out again
+        worktree_manager.prune_worktrees()?;
+        // The restored branch holds the session's work, so only the worktree
+        // is removed if the state can't be saved or recovering is interrupted
+        let mut rollback = CreationRollback::new(
+            self.git_service,
+            self.session_manager.state_dir(),
+            &final_session_name,
+        )
+        .with_cancel_token(self.cancel.clone());
+        rollback.track_worktree(&worktree_path);
         worktree_manager.create_worktree(&restored_branch, &worktree_path)?;
+        rollback.checkpoint()?;
+        context::bring_back_context(
+            self.session_manager.state_dir(),
+            &recovery_info.original_session_name,
+            &worktree_path,
+            &final_session_name,
+        );
 
         let session_state = SessionState::new(
             final_session_name.clone(),
@@ -245,15 +707,35 @@ impl<'a> SessionRecovery<'a> {
             self.session_manager.delete_state(&final_session_name)?;
         }
 
+        rollback.checkpoint()?;
         self.session_manager.save_state(&session_state)?;
+        rollback.commit();
 
         Ok(RecoveryResult {
             session_name: final_session_name,
             branch_name: restored_branch,
             worktree_path,
+            restore,
+            replaced_archive,
         })
     }
 
+    fn check_recoverable(
+        &self,
+        recovery_info: &RecoveryInfo,
+        options: &RecoveryOptions,
+    ) -> Result<()> {
+        let validation = self.validate_recovery(&recovery_info.original_session_name)?;
+
+        if !validation.can_recover && !options.force_overwrite {
+            return Err(ParaError::worktree_operation(format!(
+                "Cannot recover session due to conflicts: {}",
+                validation.conflicts.join(", ")
+            )));
+        }
+        Ok(())
+    }
+
     fn parse_archived_branch(&self, archived_branch: &str) -> Result<Option<RecoveryInfo>> {
         let archive_info = ArchiveBranchParser::parse_archive_branch(
             archived_branch,
@@ -276,12 +758,12 @@ impl<'a> SessionRecovery<'a> {
         }
     }
 
-    fn get_target_worktree_path(&self, session_name: &str) -> PathBuf {
-        let repository_root = &self.git_service.repository().root;
-        let subtrees_path = repository_root.join(&self.config.directories.subtrees_dir);
-        subtrees_path
-            .join(self.config.get_branch_prefix())
-            .join(session_name)
+    fn get_target_worktree_path(&self, session_name: &str) -> Result<PathBuf> {
+        SessionPaths::worktree_path(
+            self.config,
+            &self.git_service.repository().root,
+            session_name,
+        )
     }
 }
 
@@ -350,12 +832,18 @@ mod tests {
         let _guard = TestEnvironmentGuard::new(&git_temp, &temp_dir).unwrap();
         let (_git_temp, git_service) = setup_test_repo();
 
-        let config = create_test_config_with_dir(&temp_dir);
+        let mut config = create_test_config_with_dir(&temp_dir);
         let session_manager = SessionManager::new(&config);
         let recovery = SessionRecovery::new(&config, &git_service, &session_manager);
 
-        let path = recovery.get_target_worktree_path("my-session");
-        assert!(path.to_string_lossy().contains("subtrees/test/my-session"));
+        // Recovered sessions go where start puts new ones
+        let path = recovery.get_target_worktree_path("my-session").unwrap();
+        assert!(path.ends_with("subtrees/my-session"));
+
+        config.directories.layout = crate::config::WorktreeLayout::ByPrefix;
+        let recovery = SessionRecovery::new(&config, &git_service, &session_manager);
+        let path = recovery.get_target_worktree_path("my-session").unwrap();
+        assert!(path.ends_with("subtrees/test/my-session"));
     }
 
     #[test]
@@ -400,16 +888,18 @@ mod tests {
 
         // Archive the session using the new session-name-based method
         branch_manager
-            .move_to_archive_with_session_name(
+            .archive(
                 "test-session",
                 "test-session",
                 config.get_branch_prefix(),
+                None,
             )
             .unwrap();
 
         let options = RecoveryOptions {
             force_overwrite: false,
             preserve_original_name: true,
+            replace_live_branch: false,
         };
 
         let result = recovery.recover_session("test-session", options).unwrap();
@@ -424,6 +914,241 @@ mod tests {
         assert!(session_manager.session_exists(&result.session_name));
     }
 
+    #[test]
+    fn test_interrupted_recovery_removes_the_new_worktree() {
+        let temp_dir = TempDir::new().unwrap();
+        let git_temp = TempDir::new().unwrap();
+        let _guard = TestEnvironmentGuard::new(&git_temp, &temp_dir).unwrap();
+        let (_git_temp, git_service) = setup_test_repo();
+
+        let config = create_test_config_with_dir(&temp_dir);
+        let session_manager = SessionManager::new(&config);
+        let branch_manager = git_service.branch_manager();
+        let initial_branch = git_service.repository().get_current_branch().unwrap();
+        branch_manager
+            .create_branch("test-session", &initial_branch)
+            .unwrap();
+        git_service
+            .repository()
+            .checkout_branch(&initial_branch)
+            .unwrap();
+        branch_manager
+            .archive(
+                "test-session",
+                "test-session",
+                config.get_branch_prefix(),
+                None,
+            )
+            .unwrap();
+
+        // Interrupted right after the worktree was created
+        let cancel = CancelToken::new();
+        cancel.cancel_at_checkpoint(1);
+        let recovery = SessionRecovery::new(&config, &git_service, &session_manager)
+            .with_cancel_token(cancel.clone());
+        let options = RecoveryOptions {
+            force_overwrite: false,
+            preserve_original_name: true,
+            replace_live_branch: false,
+        };
+        let err = recovery
+            .recover_session("test-session", options)
+            .unwrap_err();
+
+        assert!(matches!(err, ParaError::Interrupted { .. }));
+        assert!(!cancel.in_progress());
+        let worktrees = git_service.worktree_manager().list_worktrees().unwrap();
+        assert_eq!(worktrees.len(), 1);
+        assert!(!session_manager.session_exists("test-session"));
+    }
+
+    #[test]
+    fn test_restore_plan_follows_the_live_branch() {
+        assert_eq!(
+            RestorePlan::decide(LiveBranch::Missing, false),
+            RestorePlan::Original
+        );
+        assert_eq!(
+            RestorePlan::decide(LiveBranch::Missing, true),
+            RestorePlan::Original
+        );
+        assert_eq!(
+            RestorePlan::decide(LiveBranch::SameTip, true),
+            RestorePlan::UseLive
+        );
+        assert_eq!(
+            RestorePlan::decide(LiveBranch::ContainsArchive, false),
+            RestorePlan::UseLive
+        );
+        assert_eq!(
+            RestorePlan::decide(LiveBranch::ContainsArchive, true),
+            RestorePlan::UseLive
+        );
+        assert_eq!(
+            RestorePlan::decide(LiveBranch::Diverged, false),
+            RestorePlan::Suffixed
+        );
+        assert_eq!(
+            RestorePlan::decide(LiveBranch::Diverged, true),
+            RestorePlan::ReplaceLive
+        );
+    }
+
+    fn commit_on(git_service: &GitService, branch: &str, file: &str) {
+        let repo = git_service.repository();
+        let previous = repo.get_current_branch().unwrap();
+        repo.checkout_branch(branch).unwrap();
+        fs::write(repo.root.join(file), file).unwrap();
+        execute_git_command(repo, &["add", file]).unwrap();
+        execute_git_command(repo, &["commit", "-m", file]).unwrap();
+        repo.checkout_branch(&previous).unwrap();
+    }
+
+    #[test]
+    fn test_recover_next_to_a_diverged_live_branch() {
+        let temp_dir = TempDir::new().unwrap();
+        let git_temp = TempDir::new().unwrap();
+        let _guard = TestEnvironmentGuard::new(&git_temp, &temp_dir).unwrap();
+        let (_git_temp, git_service) = setup_test_repo();
+
+        let config = create_test_config_with_dir(&temp_dir);
+        let session_manager = SessionManager::new(&config);
+        let recovery = SessionRecovery::new(&config, &git_service, &session_manager);
+        let branch_manager = git_service.branch_manager();
+        let initial_branch = git_service.repository().get_current_branch().unwrap();
+
+        branch_manager
+            .create_branch_from_commit("auth", &initial_branch)
+            .unwrap();
+        commit_on(&git_service, "auth", "archived.txt");
+        let archive = branch_manager
+            .archive("auth", "auth", config.get_branch_prefix(), None)
+            .unwrap();
+
+        // A new branch with the session's name, without the archived commit
+        branch_manager
+            .create_branch_from_commit("auth", &initial_branch)
+            .unwrap();
+        commit_on(&git_service, "auth", "live.txt");
+        let live_tip = branch_manager.get_branch_commit("auth").unwrap();
+        let info = recovery.find_recoverable("auth").unwrap();
+        assert_eq!(
+            recovery.live_branch(&archive.branch, "auth").unwrap(),
+            LiveBranch::Diverged
+        );
+
+        let dry_run = recovery
+            .recover_session_dry_run("auth", RecoveryOptions::default())
+            .unwrap();
+        assert_eq!(dry_run.restore, RestorePlan::Suffixed);
+        assert_eq!(dry_run.branch_name, "auth-recovered-2");
+
+        let result = recovery
+            .recover_archive(&info, RecoveryOptions::default())
+            .unwrap();
+        assert_eq!(result.session_name, "auth");
+        assert_eq!(result.branch_name, "auth-recovered-2");
+        assert_eq!(result.restore, RestorePlan::Suffixed);
+        assert!(result.worktree_path.join("archived.txt").exists());
+        assert!(!result.worktree_path.join("live.txt").exists());
+        // The live branch is left as it was
+        assert_eq!(branch_manager.get_branch_commit("auth").unwrap(), live_tip);
+        assert!(!branch_manager.branch_exists(&archive.branch).unwrap());
+    }
+
+    #[test]
+    fn test_live_branch_containing_the_archive_is_used() {
+        let temp_dir = TempDir::new().unwrap();
+        let git_temp = TempDir::new().unwrap();
+        let _guard = TestEnvironmentGuard::new(&git_temp, &temp_dir).unwrap();
+        let (_git_temp, git_service) = setup_test_repo();
+
+        let config = create_test_config_with_dir(&temp_dir);
+        let session_manager = SessionManager::new(&config);
+        let recovery = SessionRecovery::new(&config, &git_service, &session_manager);
+        let branch_manager = git_service.branch_manager();
+        let initial_branch = git_service.repository().get_current_branch().unwrap();
+
+        branch_manager
+            .create_branch_from_commit("auth", &initial_branch)
+            .unwrap();
+        commit_on(&git_service, "auth", "archived.txt");
+        let archive = branch_manager
+            .archive("auth", "auth", config.get_branch_prefix(), None)
+            .unwrap();
+        assert_eq!(
+            recovery.live_branch(&archive.branch, "auth").unwrap(),
+            LiveBranch::Missing
+        );
+
+        branch_manager
+            .create_branch_from_commit("auth", &archive.branch)
+            .unwrap();
+        assert_eq!(
+            recovery.live_branch(&archive.branch, "auth").unwrap(),
+            LiveBranch::SameTip
+        );
+        commit_on(&git_service, "auth", "later.txt");
+        assert_eq!(
+            recovery.live_branch(&archive.branch, "auth").unwrap(),
+            LiveBranch::ContainsArchive
+        );
+
+        let result = recovery
+            .recover_session("auth", RecoveryOptions::default())
+            .unwrap();
+        assert_eq!(result.restore, RestorePlan::UseLive);
+        assert_eq!(result.branch_name, "auth");
+        assert!(result.worktree_path.join("later.txt").exists());
+        // Nothing was restored from the archive
+        assert!(branch_manager.branch_exists(&archive.branch).unwrap());
+    }
+
+    #[test]
+    fn test_replace_archives_the_live_branch_first() {
+        let temp_dir = TempDir::new().unwrap();
+        let git_temp = TempDir::new().unwrap();
+        let _guard = TestEnvironmentGuard::new(&git_temp, &temp_dir).unwrap();
+        let (_git_temp, git_service) = setup_test_repo();
+
+        let config = create_test_config_with_dir(&temp_dir);
+        let session_manager = SessionManager::new(&config);
+        let recovery = SessionRecovery::new(&config, &git_service, &session_manager);
+        let branch_manager = git_service.branch_manager();
+        let initial_branch = git_service.repository().get_current_branch().unwrap();
+
+        branch_manager
+            .create_branch_from_commit("auth", &initial_branch)
+            .unwrap();
+        commit_on(&git_service, "auth", "archived.txt");
+        let archive = ArchiveRef::new(config.get_branch_prefix(), "20240101-000000", "auth");
+        execute_git_command(
+            git_service.repository(),
+            &["branch", "-m", "auth", &archive.branch],
+        )
+        .unwrap();
+        branch_manager
+            .create_branch_from_commit("auth", &initial_branch)
+            .unwrap();
+        commit_on(&git_service, "auth", "live.txt");
+        let live_tip = branch_manager.get_branch_commit("auth").unwrap();
+
+        let options = RecoveryOptions {
+            replace_live_branch: true,
+            ..RecoveryOptions::default()
+        };
+        let result = recovery.recover_session("auth", options).unwrap();
+        assert_eq!(result.restore, RestorePlan::ReplaceLive);
+        assert_eq!(result.branch_name, "auth");
+        assert!(result.worktree_path.join("archived.txt").exists());
+        let replaced = result.replaced_archive.unwrap();
+        assert_ne!(replaced, archive.branch);
+        assert_eq!(
+            branch_manager.get_branch_commit(&replaced).unwrap(),
+            live_tip
+        );
+    }
+
     #[test]
     fn test_recovery_validation_with_conflicts() {
         let temp_dir = TempDir::new().unwrap();
@@ -507,6 +1232,7 @@ mod tests {
         let options = RecoveryOptions {
             force_overwrite: false,
             preserve_original_name: true,
+            replace_live_branch: false,
         };
 
         let result = recovery
@@ -515,4 +1241,218 @@ mod tests {
         assert_eq!(result.session_name, session_name);
         assert_eq!(result.branch_name, "test-branch");
     }
+
+    fn archive_at(git_service: &GitService, session: &str, archived_at: DateTime<Utc>) {
+        let head = git_service.repository().resolve_commit("HEAD").unwrap();
+        let branch = format!(
+            "test/archived/{}/{session}",
+            archived_at.format("%Y%m%d-%H%M%S")
+        );
+        git_service
+            .branch_manager()
+            .create_branch_from_commit(&branch, &head)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_parse_since() {
+        let now = "2024-03-10T12:00:00Z".parse::<DateTime<Utc>>().unwrap();
+
+        assert_eq!(
+            parse_since("3d", now).unwrap(),
+            now - chrono::Duration::days(3)
+        );
+        assert_eq!(
+            parse_since("90m", now).unwrap(),
+            now - chrono::Duration::minutes(90)
+        );
+        assert_eq!(
+            parse_since("2024-03-01", now).unwrap().to_rfc3339(),
+            "2024-03-01T00:00:00+00:00"
+        );
+        assert!(parse_since("yesterday", now).is_err());
+        assert!(parse_since("3x", now).is_err());
+        assert!(parse_since("", now).is_err());
+    }
+
+    #[test]
+    fn test_parse_time_bound_names_flag_and_formats() {
+        let now = "2024-03-10T12:00:00Z".parse::<DateTime<Utc>>().unwrap();
+
+        assert_eq!(
+            parse_time_bound("--until", "2w", now).unwrap(),
+            now - chrono::Duration::weeks(2)
+        );
+        assert_eq!(
+            parse_time_bound("--until", "2024-03-01T09:30:00+02:00", now)
+                .unwrap()
+                .to_rfc3339(),
+            "2024-03-01T07:30:00+00:00"
+        );
+
+        let message = parse_time_bound("--until", "last week", now)
+            .unwrap_err()
+            .to_string();
+        assert!(message.contains("Invalid --until value 'last week'"));
+        for format in ["3d", "2w", "2024-03-01", "2024-03-01T09:30:00Z"] {
+            assert!(message.contains(format), "{message}");
+        }
+    }
+
+    #[test]
+    fn test_recover_all_filters_by_since_and_skips_collisions() {
+        let temp_dir = TempDir::new().unwrap();
+        let git_temp = TempDir::new().unwrap();
+        let _guard = TestEnvironmentGuard::new(&git_temp, &temp_dir).unwrap();
+        let (_git_temp, git_service) = setup_test_repo();
+
+        let config = create_test_config_with_dir(&temp_dir);
+        let session_manager = SessionManager::new(&config);
+        let recovery = SessionRecovery::new(&config, &git_service, &session_manager);
+
+        let now = Utc::now();
+        archive_at(&git_service, "recent", now - chrono::Duration::hours(1));
+        archive_at(&git_service, "taken", now - chrono::Duration::days(1));
+        archive_at(&git_service, "ancient", now - chrono::Duration::days(30));
+
+        let head = git_service.repository().resolve_commit("HEAD").unwrap();
+        git_service
+            .branch_manager()
+            .create_branch_from_commit("taken", &head)
+            .unwrap();
+
+        let since = parse_since("7d", now).unwrap();
+
+        let dry_run = recovery.recover_all(Some(since), true).unwrap();
+        let names: Vec<_> = dry_run.iter().map(|e| e.session_name.as_str()).collect();
+        assert_eq!(names, vec!["recent", "taken"]);
+        assert!(matches!(
+            dry_run[0].outcome,
+            BulkRecoveryOutcome::WouldRestore
+        ));
+        assert!(!session_manager.session_exists("recent"));
+
+        let entries = recovery.recover_all(Some(since), false).unwrap();
+        assert_eq!(entries.len(), 2);
+        match &entries[0].outcome {
+            BulkRecoveryOutcome::Restored(result) => {
+                assert_eq!(result.session_name, "recent");
+                assert!(result.worktree_path.exists());
+            }
+            other => panic!("expected 'recent' to be restored, got {other:?}"),
+        }
+        match &entries[1].outcome {
+            BulkRecoveryOutcome::Skipped(reason) => {
+                assert!(reason.contains("branch 'taken' already exists"))
+            }
+            other => panic!("expected 'taken' to be skipped, got {other:?}"),
+        }
+
+        assert!(session_manager.session_exists("recent"));
+        assert!(!session_manager.session_exists("taken"));
+        assert!(!session_manager.session_exists("ancient"));
+
+        // Without --since the older archive is picked up, the restored one is gone
+        let entries = recovery.recover_all(None, true).unwrap();
+        let names: Vec<_> = entries.iter().map(|e| e.session_name.as_str()).collect();
+        assert_eq!(names, vec!["taken", "ancient"]);
+    }
+
+    /// Archive branch `test/archived/<timestamp>/<name>` with `commits` commits
+    /// on top of the current branch
+    fn create_archive(
+        repo: &std::path::Path,
+        timestamp: &str,
+        name: &str,
+        commits: usize,
+    ) -> String {
+        let git = |args: &[&str]| {
+            let output = std::process::Command::new("git")
+                .current_dir(repo)
+                .args(args)
+                .output()
+                .unwrap();
+            assert!(output.status.success(), "git {args:?}: {output:?}");
+        };
+        let branch = format!("test/archived/{timestamp}/{name}");
+        git(&["checkout", "-q", "-b", &branch]);
+        for i in 1..=commits {
+            fs::write(repo.join(format!("{name}-{i}.txt")), "work\n").unwrap();
+            git(&["add", "."]);
+            git(&["commit", "-q", "-m", &format!("{name} change {i}")]);
+        }
+        git(&["checkout", "-q", "-"]);
+        branch
+    }
+
+    #[test]
+    fn test_archive_candidates_describe_each_archive() {
+        let temp_dir = TempDir::new().unwrap();
+        let (git_temp, git_service) = setup_test_repo();
+        let config = create_test_config_with_dir(&temp_dir);
+        let session_manager = SessionManager::for_repository(&config, git_temp.path());
+        let recovery = SessionRecovery::new(&config, &git_service, &session_manager);
+
+        create_archive(git_temp.path(), "20240301-120000", "login", 2);
+        let newest = create_archive(git_temp.path(), "20240305-090000", "search", 1);
+        create_archive(git_temp.path(), "20240303-180000", "empty", 0);
+
+        let candidates = recovery.archive_candidates(PICKER_LIMIT).unwrap();
+        let summary: Vec<_> = candidates
+            .iter()
+            .map(|c| {
+                (
+                    c.info.original_session_name.as_str(),
+                    c.commits_ahead,
+                    c.subject.as_str(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("search", Some(1), "search change 1"),
+                ("empty", Some(0), "Initial commit"),
+                ("login", Some(2), "login change 2"),
+            ]
+        );
+        assert_eq!(candidates[0].info.archived_branch, newest);
+
+        let now = candidates[0].info.archived_at().unwrap() + chrono::Duration::days(2);
+        let line = candidates[0].describe(now);
+        assert!(line.contains("search"), "{line}");
+        assert!(line.contains("2d ago"), "{line}");
+        assert!(line.contains("1 commit "), "{line}");
+
+        let stat = recovery.archive_diff_stat(&candidates[2]).unwrap();
+        assert!(stat.contains("login-1.txt"), "{stat}");
+        assert!(stat.contains("2 files changed"), "{stat}");
+    }
+
+    #[test]
+    fn test_archive_candidates_keep_only_the_newest() {
+        let temp_dir = TempDir::new().unwrap();
+        let (git_temp, git_service) = setup_test_repo();
+        let config = create_test_config_with_dir(&temp_dir);
+        let session_manager = SessionManager::for_repository(&config, git_temp.path());
+        let recovery = SessionRecovery::new(&config, &git_service, &session_manager);
+
+        for day in 1..=5 {
+            create_archive(
+                git_temp.path(),
+                &format!("202403{day:02}-120000"),
+                &format!("session-{day}"),
+                0,
+            );
+        }
+
+        let names: Vec<_> = recovery
+            .archive_candidates(3)
+            .unwrap()
+            .into_iter()
+            .map(|c| c.info.original_session_name)
+            .collect();
+        assert_eq!(names, vec!["session-5", "session-4", "session-3"]);
+        assert_eq!(recovery.archive_candidates(PICKER_LIMIT).unwrap().len(), 5);
+    }
 }
@@ -0,0 +1,248 @@
+//! Rerunning a session's task in a new session
+//!
+//! `para start --retry <session>` starts `<session>-retry-2` (then `-retry-3`,
+//! ...) with the prompt and settings of the earlier session and records the
+//! lineage as `retry_of`. Cancelling a session keeps a copy of its state and
+//! task in `<state_dir>/archive/<name>/`, so cancelled sessions can be retried
+//! as well.
+
+use super::{SessionManager, SessionState};
+use crate::utils::{ParaError, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub fn archived_state_file(state_dir: &Path, session_name: &str) -> PathBuf {
+    state_dir
+        .join("archive")
+        .join(session_name)
+        .join("session.state")
+}
+
+pub fn archived_task_file(state_dir: &Path, session_name: &str) -> PathBuf {
+    state_dir.join("archive").join(session_name).join("task")
+}
+
+/// Copy the session's state and `.task` file into the archive, replacing
+/// any earlier copy
+pub fn archive_task(state_dir: &Path, session: &SessionState) -> Result<()> {
+    let state_file = archived_state_file(state_dir, &session.name);
+    if let Some(parent) = state_file.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| ParaError::from_io("create task archive", parent, e))?;
+    }
+    let json = serde_json::to_string_pretty(session)?;
+    fs::write(&state_file, json)
+        .map_err(|e| ParaError::from_io("archive session state to", &state_file, e))?;
+
+    let task_file = state_dir.join(format!("{}.task", session.name));
+    let archived_task = archived_task_file(state_dir, &session.name);
+    if task_file.exists() {
+        fs::copy(&task_file, &archived_task)
+            .map_err(|e| ParaError::from_io("archive task to", &archived_task, e))?;
+    } else if archived_task.exists() {
+        fs::remove_file(&archived_task)
+            .map_err(|e| ParaError::from_io("remove archived task", &archived_task, e))?;
+    }
+    Ok(())
+}
+
+/// [`archive_task`] for cancel, which goes ahead when it fails
+pub fn keep_task(state_dir: &Path, session: &SessionState) {
+    if let Err(e) = archive_task(state_dir, session) {
+        eprintln!("Warning: Failed to archive session task: {e}");
+    }
+}
+
+/// The session a retry starts from: its state and the prompt it was started with
+#[derive(Debug, Clone)]
+pub struct RetrySource {
+    pub state: SessionState,
+    pub task: String,
+}
+
+impl RetrySource {
+    /// Load an active session, or the archived copy of a cancelled one
+    pub fn load(manager: &SessionManager, session_name: &str) -> Result<Self> {
+        let state_dir = manager.state_dir();
+        let (state, task_file) = if manager.session_exists(session_name) {
+            (
+                manager.load_state(session_name)?,
+                state_dir.join(format!("{session_name}.task")),
+            )
+        } else {
+            let state_file = archived_state_file(state_dir, session_name);
+            if !state_file.exists() {
+                return Err(ParaError::session_not_found(session_name));
+            }
+            let content = fs::read_to_string(&state_file)
+                .map_err(|e| ParaError::from_io("read archived session state", &state_file, e))?;
+            let state = serde_json::from_str(&content).map_err(|e| {
+                ParaError::state_corruption(format!(
+                    "Failed to parse archived session state {}: {e}",
+                    state_file.display()
+                ))
+            })?;
+            (state, archived_task_file(state_dir, session_name))
+        };
+
+        // The task file holds the prompt exactly as it was sent
+        let task = fs::read_to_string(&task_file)
+            .ok()
+            .or_else(|| state.task_description.clone())
+            .filter(|task| !task.trim().is_empty())
+            .ok_or_else(|| {
+                ParaError::invalid_args(format!(
+                    "Session '{session_name}' has no recorded task to retry; it was not started with a prompt"
+                ))
+            })?;
+
+        Ok(Self { state, task })
+    }
+
+    /// The task with `instructions` appended
+    pub fn prompt(&self, instructions: Option<&str>) -> String {
+        match instructions.map(str::trim).filter(|text| !text.is_empty()) {
+            Some(instructions) => format!(
+                "{}\n\nAdditional instructions for this retry:\n{instructions}",
+                self.task.trim_end()
+            ),
+            None => self.task.clone(),
+        }
+    }
+}
+
+/// `<original>-retry-N` with the lowest N from 2 that no session, branch,
+/// worktree or archived session uses. Retries of retries count on from the
+/// first session's name.
+pub fn retry_name(manager: &SessionManager, original: &str) -> Result<String> {
+    let root = match original.rsplit_once("-retry-") {
+        Some((root, n)) if !root.is_empty() && n.parse::<u32>().is_ok() => root,
+        _ => original,
+    };
+    for n in 2.. {
+        let candidate = format!("{root}-retry-{n}");
+        if manager.is_name_free(&candidate)?
+            && !archived_state_file(manager.state_dir(), &candidate).exists()
+        {
+            return Ok(candidate);
+        }
+    }
+    unreachable!("retry numbers are unbounded")
+}
+
+/// Record `session` as a retry of `original`
+pub fn record(manager: &SessionManager, session: &str, original: &str) -> Result<()> {
+    let mut state = manager.load_state(session)?;
+    state.retry_of = Some(original.to_string());
+    manager.save_state(&state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::git::MergeMode;
+    use crate::test_utils::test_helpers::*;
+    use tempfile::TempDir;
+
+    fn started_session(manager: &SessionManager, name: &str, task: &str) -> SessionState {
+        let worktree = manager.state_dir().join("worktrees").join(name);
+        fs::create_dir_all(&worktree).unwrap();
+        let mut session = SessionState::new(name.to_string(), format!("test/{name}"), worktree);
+        session.task_description = Some(task.to_string());
+        session.merge_mode = MergeMode::Rebase;
+        session.sandbox_enabled = Some(true);
+        session.sandbox_profile = Some("standard".to_string());
+        session.dangerous_skip_permissions = Some(true);
+        session.template = Some("bugfix".to_string());
+        manager.save_state(&session).unwrap();
+        fs::write(
+            manager.state_dir().join(format!("{name}.task")),
+            format!("{task}\n"),
+        )
+        .unwrap();
+        session
+    }
+
+    #[test]
+    fn test_retry_of_active_session() {
+        let temp_dir = TempDir::new().unwrap();
+        let (git_temp, _git_service) = setup_test_repo();
+        let config = create_test_config_with_dir(&temp_dir);
+        let manager = SessionManager::for_repository(&config, git_temp.path());
+        started_session(&manager, "auth", "Add login");
+
+        let source = RetrySource::load(&manager, "auth").unwrap();
+        assert_eq!(source.task, "Add login\n");
+        assert_eq!(source.prompt(None), "Add login\n");
+        assert_eq!(
+            source.prompt(Some("Use the existing session store")),
+            "Add login\n\nAdditional instructions for this retry:\nUse the existing session store"
+        );
+        assert_eq!(source.state.merge_mode, MergeMode::Rebase);
+        assert_eq!(source.state.sandbox_profile.as_deref(), Some("standard"));
+        assert_eq!(retry_name(&manager, "auth").unwrap(), "auth-retry-2");
+
+        // The original session is left alone
+        assert!(manager.session_exists("auth"));
+    }
+
+    #[test]
+    fn test_retry_of_cancelled_session_reads_archive() {
+        let temp_dir = TempDir::new().unwrap();
+        let (git_temp, _git_service) = setup_test_repo();
+        let config = create_test_config_with_dir(&temp_dir);
+        let mut manager = SessionManager::for_repository(&config, git_temp.path());
+        let session = started_session(&manager, "auth", "Add login");
+
+        keep_task(manager.state_dir(), &session);
+        manager.cancel_session("auth", false).unwrap();
+        fs::remove_file(manager.state_dir().join("auth.task")).unwrap();
+        assert!(!manager.session_exists("auth"));
+
+        let source = RetrySource::load(&manager, "auth").unwrap();
+        assert_eq!(source.task, "Add login\n");
+        assert_eq!(source.state.template.as_deref(), Some("bugfix"));
+        assert_eq!(source.state.dangerous_skip_permissions, Some(true));
+
+        assert!(matches!(
+            RetrySource::load(&manager, "never-existed"),
+            Err(ParaError::SessionNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn test_retry_names_count_up_and_record_lineage() {
+        let temp_dir = TempDir::new().unwrap();
+        let (git_temp, _git_service) = setup_test_repo();
+        let config = create_test_config_with_dir(&temp_dir);
+        let manager = SessionManager::for_repository(&config, git_temp.path());
+        started_session(&manager, "auth", "Add login");
+        let retry = started_session(&manager, "auth-retry-2", "Add login");
+        record(&manager, &retry.name, "auth").unwrap();
+
+        assert_eq!(
+            manager
+                .load_state("auth-retry-2")
+                .unwrap()
+                .retry_of
+                .as_deref(),
+            Some("auth")
+        );
+        assert_eq!(retry_name(&manager, "auth").unwrap(), "auth-retry-3");
+        assert_eq!(
+            retry_name(&manager, "auth-retry-2").unwrap(),
+            "auth-retry-3"
+        );
+
+        // A cancelled retry keeps its number
+        keep_task(
+            manager.state_dir(),
+            &SessionState::new(
+                "auth-retry-3".to_string(),
+                "test/auth-retry-3".to_string(),
+                PathBuf::new(),
+            ),
+        );
+        assert_eq!(retry_name(&manager, "auth").unwrap(), "auth-retry-4");
+    }
+}
@@ -0,0 +1,174 @@
+//! Undoing a session creation that failed part of the way
+//!
+//! Creating a session adds a branch, a worktree and state files in separate
+//! steps. [`CreationRollback`] records what has been created so far and
+//! removes it again when dropped, unless the creation was completed with
+//! [`CreationRollback::commit`]. A disk that fills up half way through then
+//! leaves nothing behind for later commands to trip over, and neither does
+//! Ctrl-C: until the commit the creation is a step of the cancel token, and
+//! [`CreationRollback::checkpoint`] between the parts unwinds into the
+//! rollback once it was interrupted.
+
+use super::state::SessionState;
+use crate::core::git::{GitOperations, GitService};
+use crate::core::interrupt::{AbortGuard, CancelToken};
+use crate::utils::fs::temp_sibling;
+use crate::utils::Result;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Files in the state directory that belong to a session being created
+const SESSION_FILE_SUFFIXES: &[&str] = &["state", "task", "status.json"];
+
+pub struct CreationRollback<'a> {
+    git_service: &'a GitService,
+    state_dir: PathBuf,
+    session_name: String,
+    branch: Option<String>,
+    worktree: Option<PathBuf>,
+    state_files: bool,
+    armed: bool,
+    cancel: CancelToken,
+    step: Option<AbortGuard>,
+}
+
+impl<'a> CreationRollback<'a> {
+    pub fn new(git_service: &'a GitService, state_dir: &Path, session_name: &str) -> Self {
+        let cancel = CancelToken::global().clone();
+        Self {
+            git_service,
+            state_dir: state_dir.to_path_buf(),
+            session_name: session_name.to_string(),
+            branch: None,
+            worktree: None,
+            state_files: false,
+            armed: true,
+            step: Some(cancel.step()),
+            cancel,
+        }
+    }
+
+    /// Stop at checkpoints when `cancel` is cancelled instead of on Ctrl-C
+    pub fn with_cancel_token(mut self, cancel: CancelToken) -> Self {
+        self.step = Some(cancel.step());
+        self.cancel = cancel;
+        self
+    }
+
+    /// Take over a session that was just created, to remove it again if the
+    /// steps that finish setting it up fail
+    pub fn for_session(
+        git_service: &'a GitService,
+        state_dir: &Path,
+        session: &SessionState,
+    ) -> Self {
+        let mut rollback = Self::new(git_service, state_dir, &session.name);
+        rollback.branch = Some(session.branch.clone());
+        rollback.worktree = Some(session.worktree_path.clone());
+        rollback.state_files = true;
+        rollback
+    }
+
+    /// Delete `branch` on rollback. Call before the branch is created; a
+    /// branch that already exists is left alone.
+    pub fn track_branch(&mut self, branch: &str) {
+        if !self.git_service.branch_exists(branch).unwrap_or(true) {
+            self.branch = Some(branch.to_string());
+        }
+    }
+
+    /// Remove the worktree at `path` on rollback
+    pub fn track_worktree(&mut self, path: &Path) {
+        self.worktree = Some(path.to_path_buf());
+    }
+
+    /// Create the worktree for `branch` and track what it added. An existing
+    /// worktree that is adopted for the branch is not removed on rollback.
+    pub fn create_worktree(&mut self, branch: &str, path: &Path) -> Result<PathBuf> {
+        self.track_branch(branch);
+        let occupied = fs::read_dir(path).is_ok_and(|mut entries| entries.next().is_some());
+        let created = self.git_service.create_worktree(branch, path)?;
+        if !occupied || created != path {
+            self.track_worktree(&created);
+        }
+        Ok(created)
+    }
+
+    /// Remove the session's state, task and status files on rollback. Only
+    /// for new session names, whose files can't predate the creation.
+    pub fn track_state_files(&mut self) {
+        self.state_files = true;
+    }
+
+    /// A point between two parts of the creation. Fails once interrupted,
+    /// and the rollback then removes what was created when it is dropped.
+    pub fn checkpoint(&self) -> Result<()> {
+        self.cancel.checkpoint()
+    }
+
+    /// The session was created completely; keep everything
+    pub fn commit(mut self) {
+        self.armed = false;
+        if let Some(step) = self.step.take() {
+            step.complete();
+        }
+    }
+
+    fn roll_back(&self) {
+        let mut leftovers = Vec::new();
+
+        if let Some(worktree) = &self.worktree {
+            if let Err(e) = self
+                .git_service
+                .worktree_manager()
+                .force_remove_worktree(worktree)
+            {
+                leftovers.push(format!("worktree {}: {e}", worktree.display()));
+            }
+        }
+        // Steps that fail may have cleaned up after themselves already
+        let branch = self
+            .branch
+            .as_ref()
+            .filter(|branch| self.git_service.branch_exists(branch).unwrap_or(false));
+        if let Some(branch) = branch {
+            if let Err(e) = self.git_service.delete_branch(branch, true) {
+                leftovers.push(format!("branch {branch}: {e}"));
+            }
+        }
+        let suffixes = if self.state_files {
+            SESSION_FILE_SUFFIXES
+        } else {
+            &[]
+        };
+        for suffix in suffixes {
+            let path = self
+                .state_dir
+                .join(format!("{}.{suffix}", self.session_name));
+            for file in [temp_sibling(&path), path] {
+                match fs::remove_file(&file) {
+                    Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+                        leftovers.push(format!("{}: {e}", file.display()));
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        eprintln!(
+            "Removed the partially created session '{}'",
+            self.session_name
+        );
+        for leftover in leftovers {
+            eprintln!("Warning: Failed to clean up {leftover}");
+        }
+    }
+}
+
+impl Drop for CreationRollback<'_> {
+    fn drop(&mut self) {
+        if self.armed {
+            self.roll_back();
+        }
+    }
+}
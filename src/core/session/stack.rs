@@ -0,0 +1,329 @@
+//! Stacked sessions: sessions started from another session's branch
+//!
+//! `para start --base <session>` records the new session as stacked on the
+//! other one (`stacked_on` in the child, `stacked_children` in the parent).
+//! `para finish --onto-session <name>` applies the session's changes to the
+//! parent's worktree and commits them there, so the parent branch never has
+//! to be checked out a second time. Finishing a parent can rebase its active
+//! children onto the finished branch, and cancelling it needs `--force` while
+//! children are still active.
+
+use super::{SessionManager, SessionState, SessionStatus};
+use crate::core::git::apply::{apply_starting_changes, Applied, ApplyWhitespace, StartingChanges};
+use crate::core::git::repository::execute_git_command;
+use crate::core::git::GitRepository;
+use crate::utils::{ParaError, Result};
+
+/// The session named `name` if it can be stacked on: active and with its worktree
+pub fn stack_target(manager: &SessionManager, name: &str) -> Result<SessionState> {
+    let session = manager.load_state(name)?;
+    if session.status != SessionStatus::Active || !session.worktree_path.exists() {
+        return Err(ParaError::invalid_args(format!(
+            "Session '{name}' is not active; only active sessions can be stacked on"
+        )));
+    }
+    Ok(session)
+}
+
+/// Record `child` as stacked on `parent` in both state files
+pub fn link(manager: &SessionManager, parent: &str, child: &str) -> Result<()> {
+    let mut child_state = manager.load_state(child)?;
+    if child_state.stacked_on.as_deref() != Some(parent) {
+        child_state.stacked_on = Some(parent.to_string());
+        manager.save_state(&child_state)?;
+    }
+
+    let mut parent_state = manager.load_state(parent)?;
+    if !parent_state
+        .stacked_children
+        .iter()
+        .any(|name| name == child)
+    {
+        parent_state.stacked_children.push(child.to_string());
+        manager.save_state(&parent_state)?;
+    }
+    Ok(())
+}
+
+/// Children of `parent` that are still being worked on
+pub fn active_children(manager: &SessionManager, parent: &SessionState) -> Vec<SessionState> {
+    parent
+        .stacked_children
+        .iter()
+        .filter_map(|name| manager.load_state(name).ok())
+        .filter(|child| {
+            child.status == SessionStatus::Active
+                && child.stacked_on.as_deref() == Some(parent.name.as_str())
+        })
+        .collect()
+}
+
+/// Commit everything `branch` changed since it forked from the parent's branch
+/// in the parent's worktree. The worktree must be clean; if the changes do not
+/// apply it is reset to how it was.
+pub fn integrate_into(
+    parent: &SessionState,
+    branch: &str,
+    message: &str,
+    whitespace: ApplyWhitespace,
+) -> Result<Applied> {
+    let repo = GitRepository::discover_from(&parent.worktree_path)?;
+    if repo.has_uncommitted_changes()? {
+        return Err(ParaError::invalid_args(format!(
+            "Session '{}' has uncommitted changes. Commit or stash them before finishing onto it",
+            parent.name
+        )));
+    }
+
+    let changes = StartingChanges::Branch(branch.to_string());
+    let applied =
+        match apply_starting_changes(&parent.worktree_path, &parent.branch, &changes, whitespace) {
+            Ok(applied) => applied,
+            Err(e) => {
+                let _ = execute_git_command(&repo, &["reset", "--hard", "HEAD"]);
+                return Err(e);
+            }
+        };
+    if !repo.has_uncommitted_changes()? {
+        return Ok(applied);
+    }
+    repo.stage_all_changes()?;
+    repo.commit(message)?;
+    Ok(applied)
+}
+
+/// Replay the child's own commits onto `new_base`, the branch its parent was
+/// finished as, and record that as the child's new base
+pub fn rebase_child(manager: &SessionManager, child: &SessionState, new_base: &str) -> Result<()> {
+    let repo = GitRepository::discover_from(&child.worktree_path)?;
+    if repo.has_uncommitted_changes()? {
+        return Err(ParaError::invalid_args(format!(
+            "Session '{}' has uncommitted changes",
+            child.name
+        )));
+    }
+
+    let old_base = child.diff_base().to_string();
+    if execute_git_command(&repo, &["rebase", "--onto", new_base, &old_base]).is_err() {
+        let _ = execute_git_command(&repo, &["rebase", "--abort"]);
+        return Err(ParaError::git_operation(format!(
+            "Rebasing '{}' onto '{new_base}' hit conflicts; the branch was left as it was",
+            child.branch
+        )));
+    }
+
+    let mut rebased = child.clone();
+    rebased.parent_branch = Some(new_base.to_string());
+    rebased.base_commit = Some(repo.resolve_commit(new_base)?);
+    manager.save_state(&rebased)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::test_helpers::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn files_on(git_service: &crate::core::git::GitService, branch: &str) -> Vec<String> {
+        let listing = execute_git_command(
+            git_service.repository(),
+            &["ls-tree", "--name-only", branch],
+        )
+        .unwrap();
+        listing.lines().map(str::to_string).collect()
+    }
+
+    #[test]
+    fn test_two_level_stack_finishes_from_the_top() {
+        let temp_dir = TempDir::new().unwrap();
+        let (_git_temp, git_service) = setup_test_repo();
+        let config = create_test_config_with_dir(&temp_dir);
+        let manager = SessionManager::new(&config);
+
+        let parent = start_session(&git_service, &manager, "parent", "main");
+        commit_file(
+            &parent.worktree_path,
+            "parent.txt",
+            "parent",
+            "Add parent.txt",
+        );
+        let child = start_session(&git_service, &manager, "child", &parent.branch);
+        link(&manager, "parent", "child").unwrap();
+        commit_file(&child.worktree_path, "child.txt", "child", "Add child.txt");
+        let grandchild = start_session(&git_service, &manager, "grandchild", &child.branch);
+        link(&manager, "child", "grandchild").unwrap();
+        commit_file(
+            &grandchild.worktree_path,
+            "grandchild.txt",
+            "grandchild",
+            "Add grandchild.txt",
+        );
+
+        // Parent keeps moving while the child is worked on
+        commit_file(&parent.worktree_path, "later.txt", "later", "Add later.txt");
+
+        integrate_into(
+            &stack_target(&manager, "child").unwrap(),
+            &grandchild.branch,
+            "Grandchild work",
+            ApplyWhitespace::default(),
+        )
+        .unwrap();
+        integrate_into(
+            &stack_target(&manager, "parent").unwrap(),
+            &child.branch,
+            "Child work",
+            ApplyWhitespace::default(),
+        )
+        .unwrap();
+
+        let files = files_on(&git_service, &parent.branch);
+        for file in ["parent.txt", "later.txt", "child.txt", "grandchild.txt"] {
+            assert!(
+                files.contains(&file.to_string()),
+                "{file} missing: {files:?}"
+            );
+        }
+
+        let parent = manager.load_state("parent").unwrap();
+        assert_eq!(parent.stacked_children, vec!["child".to_string()]);
+        assert_eq!(
+            manager
+                .load_state("grandchild")
+                .unwrap()
+                .stacked_on
+                .as_deref(),
+            Some("child")
+        );
+        assert_eq!(active_children(&manager, &parent).len(), 1);
+    }
+
+    #[test]
+    fn test_failed_integration_leaves_parent_untouched() {
+        let temp_dir = TempDir::new().unwrap();
+        let (_git_temp, git_service) = setup_test_repo();
+        let config = create_test_config_with_dir(&temp_dir);
+        let manager = SessionManager::new(&config);
+
+        let parent = start_session(&git_service, &manager, "parent", "main");
+        let child = start_session(&git_service, &manager, "child", &parent.branch);
+        commit_file(
+            &child.worktree_path,
+            "shared.txt",
+            "child version\n",
+            "Add shared.txt",
+        );
+        commit_file(
+            &parent.worktree_path,
+            "shared.txt",
+            "parent version\n",
+            "Add shared.txt",
+        );
+        let parent_head = git_service
+            .repository()
+            .resolve_commit(&parent.branch)
+            .unwrap();
+
+        assert!(integrate_into(
+            &parent,
+            &child.branch,
+            "Child work",
+            ApplyWhitespace::default()
+        )
+        .is_err());
+        let repo = GitRepository::discover_from(&parent.worktree_path).unwrap();
+        assert!(!repo.has_uncommitted_changes().unwrap());
+        assert_eq!(repo.resolve_commit("HEAD").unwrap(), parent_head);
+    }
+
+    #[test]
+    fn test_crlf_and_binary_files_integrate_byte_exact_with_autocrlf_input() {
+        let temp_dir = TempDir::new().unwrap();
+        let (_git_temp, git_service) = setup_test_repo();
+        let config = create_test_config_with_dir(&temp_dir);
+        let manager = SessionManager::new(&config);
+        let repo = git_service.repository();
+
+        let parent = start_session(&git_service, &manager, "parent", "main");
+        fs::write(
+            parent.worktree_path.join("windows.txt"),
+            b"one\r\ntwo\r\nthree\r\n",
+        )
+        .unwrap();
+        fs::write(parent.worktree_path.join("data.bin"), b"\x00\x01\r\n").unwrap();
+        let parent_repo = GitRepository::discover_from(&parent.worktree_path).unwrap();
+        parent_repo.stage_all_changes().unwrap();
+        parent_repo.commit("Add CRLF and binary files").unwrap();
+
+        let child = start_session(&git_service, &manager, "child", &parent.branch);
+        let changed_text: &[u8] = b"one\r\nTWO\r\nthree\r\n";
+        let changed_binary: &[u8] = b"\x00\x02\r\n\x00";
+        fs::write(child.worktree_path.join("windows.txt"), changed_text).unwrap();
+        fs::write(child.worktree_path.join("data.bin"), changed_binary).unwrap();
+        let child_repo = GitRepository::discover_from(&child.worktree_path).unwrap();
+        child_repo.stage_all_changes().unwrap();
+        child_repo.commit("Change CRLF and binary files").unwrap();
+
+        execute_git_command(repo, &["config", "core.autocrlf", "input"]).unwrap();
+        let applied = integrate_into(
+            &parent,
+            &child.branch,
+            "Child work",
+            ApplyWhitespace::default(),
+        )
+        .unwrap();
+        assert!(!applied.ignored_whitespace);
+
+        assert_eq!(
+            fs::read(parent.worktree_path.join("windows.txt")).unwrap(),
+            changed_text
+        );
+        assert_eq!(
+            fs::read(parent.worktree_path.join("data.bin")).unwrap(),
+            changed_binary
+        );
+        for (file, expected) in [("windows.txt", changed_text), ("data.bin", changed_binary)] {
+            let committed = std::process::Command::new("git")
+                .current_dir(&parent.worktree_path)
+                .args(["show", &format!("HEAD:{file}")])
+                .output()
+                .unwrap();
+            assert_eq!(committed.stdout, expected, "{file} changed when committed");
+        }
+    }
+
+    #[test]
+    fn test_rebase_child_onto_finished_parent() {
+        let temp_dir = TempDir::new().unwrap();
+        let (_git_temp, git_service) = setup_test_repo();
+        let config = create_test_config_with_dir(&temp_dir);
+        let manager = SessionManager::new(&config);
+
+        let parent = start_session(&git_service, &manager, "parent", "main");
+        commit_file(
+            &parent.worktree_path,
+            "parent.txt",
+            "parent",
+            "Add parent.txt",
+        );
+        let child = start_session(&git_service, &manager, "child", &parent.branch);
+        commit_file(&child.worktree_path, "child.txt", "child", "Add child.txt");
+
+        // The parent was finished as a branch without its old commits
+        let repo = git_service.repository();
+        execute_git_command(repo, &["branch", "test/parent-final", "main"]).unwrap();
+
+        rebase_child(&manager, &child, "test/parent-final").unwrap();
+        let files = files_on(&git_service, &child.branch);
+        assert!(files.contains(&"child.txt".to_string()));
+        assert!(!files.contains(&"parent.txt".to_string()));
+
+        let rebased = manager.load_state("child").unwrap();
+        assert_eq!(rebased.parent_branch.as_deref(), Some("test/parent-final"));
+        assert_eq!(
+            rebased.base_commit,
+            Some(repo.resolve_commit("test/parent-final").unwrap())
+        );
+    }
+}
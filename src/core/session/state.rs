@@ -1,11 +1,26 @@
+use super::links::SessionLink;
+use super::migration::{LEGACY_SCHEMA_VERSION, STATE_SCHEMA_VERSION};
+use crate::core::git::{FinishRequest, MergeMode};
+use crate::core::version::PARA_VERSION;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
+/// Marker shown next to sessions launched with `--dangerously-skip-permissions`
+pub const SKIP_PERMISSIONS_MARKER: &str = "⚠";
+
 fn default_session_type() -> SessionType {
     SessionType::Worktree
 }
 
+fn legacy_schema_version() -> u32 {
+    LEGACY_SCHEMA_VERSION
+}
+
+fn unknown_created_at() -> DateTime<Utc> {
+    DateTime::UNIX_EPOCH
+}
+
 /// Type of session - either traditional worktree or Docker container
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum SessionType {
@@ -23,6 +38,8 @@ pub struct SessionState {
     pub name: String,
     pub branch: String,
     pub worktree_path: PathBuf,
+    // Missing from state files written by early versions
+    #[serde(default = "unknown_created_at")]
     pub created_at: DateTime<Utc>,
     pub status: SessionStatus,
 
@@ -39,6 +56,10 @@ pub struct SessionState {
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub parent_branch: Option<String>,
 
+    // Commit the session branch was created from, recorded when the worktree is created
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub base_commit: Option<String>,
+
     // Deprecated - use session_type instead
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub is_docker: Option<bool>,
@@ -54,6 +75,86 @@ pub struct SessionState {
     // Sandbox profile (permissive or restrictive)
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub sandbox_profile: Option<String>,
+
+    // Whether the session opted out of automatic WIP checkpoints with --no-auto-commit
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub no_auto_commit: Option<bool>,
+
+    // Template from the config the session was started with (--template)
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub template: Option<String>,
+
+    // How finish shapes the session's commits; sessions saved before this was
+    // recorded are squashed
+    #[serde(default)]
+    pub merge_mode: MergeMode,
+
+    // Session whose branch this session was started from (--base <session>)
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub stacked_on: Option<String>,
+
+    // Sessions started from this session's branch
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub stacked_children: Vec<String>,
+
+    // Session whose task this session reruns (dispatch --retry <session>)
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub retry_of: Option<String>,
+
+    // Docker options of a container session
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub container: Option<ContainerConfig>,
+
+    // References to PRs, tickets and docs added with `para link add`
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub links: Vec<SessionLink>,
+
+    // Kept out of stale and idle detection and bulk cleanups (`para pin`)
+    #[serde(skip_serializing_if = "std::ops::Not::not", default)]
+    pub pinned: bool,
+
+    // Setup script `dispatch --plan-only --defer-setup` left for `para launch`
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub deferred_setup_script: Option<PathBuf>,
+
+    // Layout version of the state file, see `migration`
+    #[serde(default = "legacy_schema_version")]
+    pub schema_version: u32,
+
+    // Para release that created the session or last migrated its state file;
+    // empty in state files written before this was recorded
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub para_version: String,
+}
+
+/// Docker options a container session was dispatched with, so that every
+/// later command rebuilds the same container setup
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ContainerConfig {
+    /// Image the container was created from
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub image: Option<String>,
+    pub network_isolation: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub allowed_domains: Vec<String>,
+    pub forward_keys: bool,
+    /// Extra `docker create` arguments
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub docker_args: Vec<String>,
+}
+
+impl ContainerConfig {
+    /// Assumed for container sessions created before the options were
+    /// recorded: network isolation on and no API keys forwarded
+    pub fn conservative() -> Self {
+        Self {
+            image: None,
+            network_isolation: true,
+            allowed_domains: Vec::new(),
+            forward_keys: false,
+            docker_args: Vec::new(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -65,6 +166,8 @@ pub struct GitStats {
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum SessionStatus {
+    /// Prepared with `dispatch --plan-only`; waits for `para launch`
+    Planned,
     Active,
     Review,
     Finished, // Deprecated - use Review instead
@@ -84,10 +187,23 @@ impl SessionState {
             git_stats: None,
             session_type: SessionType::Worktree,
             parent_branch: None,
+            base_commit: None,
             is_docker: None,
             dangerous_skip_permissions: None,
             sandbox_enabled: None,
             sandbox_profile: None,
+            no_auto_commit: None,
+            merge_mode: MergeMode::default(),
+            template: None,
+            stacked_on: None,
+            stacked_children: Vec::new(),
+            retry_of: None,
+            schema_version: STATE_SCHEMA_VERSION,
+            para_version: PARA_VERSION.to_string(),
+            container: None,
+            links: Vec::new(),
+            deferred_setup_script: None,
+            pinned: false,
         }
     }
 
@@ -110,6 +226,7 @@ impl SessionState {
             git_stats: None,
             session_type: SessionType::Worktree,
             parent_branch: Some(parent_branch),
+            base_commit: None,
             is_docker: None,
             dangerous_skip_permissions: if dangerous_skip_permissions {
                 Some(true)
@@ -118,6 +235,18 @@ impl SessionState {
             },
             sandbox_enabled: None,
             sandbox_profile: None,
+            no_auto_commit: None,
+            merge_mode: MergeMode::default(),
+            template: None,
+            stacked_children: Vec::new(),
+            stacked_on: None,
+            retry_of: None,
+            schema_version: STATE_SCHEMA_VERSION,
+            para_version: PARA_VERSION.to_string(),
+            container: None,
+            links: Vec::new(),
+            deferred_setup_script: None,
+            pinned: false,
         }
     }
 
@@ -141,6 +270,7 @@ impl SessionState {
             git_stats: None,
             session_type: SessionType::Container { container_id },
             parent_branch: Some(parent_branch),
+            base_commit: None,
             is_docker: None,
             dangerous_skip_permissions: if dangerous_skip_permissions {
                 Some(true)
@@ -149,6 +279,18 @@ impl SessionState {
             },
             sandbox_enabled: None,
             sandbox_profile: None,
+            no_auto_commit: None,
+            merge_mode: MergeMode::default(),
+            template: None,
+            stacked_on: None,
+            stacked_children: Vec::new(),
+            retry_of: None,
+            schema_version: STATE_SCHEMA_VERSION,
+            para_version: PARA_VERSION.to_string(),
+            container: None,
+            links: Vec::new(),
+            deferred_setup_script: None,
+            pinned: false,
         }
     }
 
@@ -173,6 +315,7 @@ impl SessionState {
             git_stats: None,
             session_type: SessionType::Worktree,
             parent_branch: Some(parent_branch),
+            base_commit: None,
             is_docker: None,
             dangerous_skip_permissions: if dangerous_skip_permissions {
                 Some(true)
@@ -181,6 +324,18 @@ impl SessionState {
             },
             sandbox_enabled: if sandbox_enabled { Some(true) } else { None },
             sandbox_profile,
+            no_auto_commit: None,
+            merge_mode: MergeMode::default(),
+            template: None,
+            stacked_on: None,
+            stacked_children: Vec::new(),
+            retry_of: None,
+            schema_version: STATE_SCHEMA_VERSION,
+            para_version: PARA_VERSION.to_string(),
+            container: None,
+            links: Vec::new(),
+            deferred_setup_script: None,
+            pinned: false,
         }
     }
 
@@ -189,6 +344,49 @@ impl SessionState {
         matches!(self.session_type, SessionType::Container { .. })
     }
 
+    /// Revision that session changes are compared against.
+    ///
+    /// Prefers the pinned base commit so the comparison point does not move
+    /// when the parent branch (e.g. `origin/main`) advances.
+    pub fn diff_base(&self) -> &str {
+        self.base_commit
+            .as_deref()
+            .or(self.parent_branch.as_deref())
+            .unwrap_or("main")
+    }
+
+    /// Check if the state recorded when the session was created
+    pub fn has_created_at(&self) -> bool {
+        self.created_at > DateTime::UNIX_EPOCH
+    }
+
+    /// Check if this session was launched with `--dangerously-skip-permissions`
+    pub fn skips_permissions(&self) -> bool {
+        self.dangerous_skip_permissions.unwrap_or(false)
+    }
+
+    /// Check if the session opted out of automatic WIP checkpoints
+    pub fn skips_auto_commit(&self) -> bool {
+        self.no_auto_commit.unwrap_or(false)
+    }
+
+    /// Finish request for this session using its recorded merge mode
+    pub fn finish_request(
+        &self,
+        commit_message: String,
+        target_branch_name: Option<String>,
+    ) -> FinishRequest {
+        FinishRequest {
+            feature_branch: self.branch.clone(),
+            commit_message,
+            target_branch_name,
+            merge_mode: self.merge_mode,
+            parent_branch: self.parent_branch.clone(),
+            base_commit: self.base_commit.clone(),
+            stage_all: true,
+        }
+    }
+
     pub fn update_status(&mut self, status: SessionStatus) {
         self.status = status;
     }
@@ -280,10 +478,23 @@ mod tests {
             git_stats: None,
             session_type: SessionType::Worktree,
             parent_branch: None,
+            base_commit: None,
             is_docker: None,
             dangerous_skip_permissions: None,
             sandbox_enabled: None,
             sandbox_profile: None,
+            no_auto_commit: None,
+            merge_mode: MergeMode::default(),
+            template: None,
+            stacked_children: Vec::new(),
+            stacked_on: None,
+            retry_of: None,
+            schema_version: STATE_SCHEMA_VERSION,
+            para_version: PARA_VERSION.to_string(),
+            container: None,
+            links: Vec::new(),
+            deferred_setup_script: None,
+            pinned: false,
         };
 
         // Should be able to serialize and deserialize Review status
@@ -600,4 +811,45 @@ mod tests {
         let deserialized: SessionState = serde_json::from_str(new_json).unwrap();
         assert_eq!(deserialized.dangerous_skip_permissions, Some(true));
     }
+
+    #[test]
+    fn test_diff_base_prefers_pinned_commit() {
+        let mut state = SessionState::with_parent_branch_and_flags(
+            "pinned".to_string(),
+            "para/pinned".to_string(),
+            PathBuf::from("/tmp/pinned"),
+            "origin/main".to_string(),
+            false,
+        );
+        assert_eq!(state.diff_base(), "origin/main");
+
+        state.base_commit = Some("abc123".to_string());
+        assert_eq!(state.diff_base(), "abc123");
+
+        let json = serde_json::to_string(&state).unwrap();
+        let restored: SessionState = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.base_commit.as_deref(), Some("abc123"));
+    }
+
+    #[test]
+    fn test_merge_mode_deserialization() {
+        // Sessions saved before merge modes were recorded keep being squashed
+        let old_json = r#"{
+            "name": "old-session",
+            "branch": "para/old-session",
+            "worktree_path": "/test",
+            "created_at": "2024-01-01T00:00:00Z",
+            "status": "Active",
+            "session_type": "Worktree"
+        }"#;
+        let deserialized: SessionState = serde_json::from_str(old_json).unwrap();
+        assert_eq!(deserialized.merge_mode, MergeMode::Squash);
+
+        let mut state = deserialized;
+        state.merge_mode = MergeMode::PreserveCommits;
+        let json = serde_json::to_string(&state).unwrap();
+        assert!(json.contains(r#""merge_mode":"preserve_commits""#));
+        let restored: SessionState = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.merge_mode, MergeMode::PreserveCommits);
+    }
 }
@@ -0,0 +1,429 @@
+//! End-of-session summary for PR descriptions and standups
+//!
+//! `gather` collects what the repository and state directory know about a
+//! session; the render functions only format that data, so the output can be
+//! tested without a repository. Before finishing, the commits are the
+//! session's own; afterwards the squashed commit stands in for them.
+
+use crate::core::git::repository::execute_git_command;
+use crate::core::git::GitRepository;
+use crate::core::session::links::SessionLink;
+use crate::core::session::{SessionState, SessionStatus};
+use crate::core::status::{Status, StatusHistoryEntry};
+use crate::utils::Result;
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+use std::path::Path;
+
+/// Status history entries shown in a summary
+pub const HISTORY_ENTRIES: usize = 5;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SessionSummary {
+    pub session: String,
+    pub branch: String,
+    /// Commit or branch the commits and stats are counted from
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub task: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub links: Vec<SessionLink>,
+    /// Commit subjects since the base, oldest first
+    pub commits: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stats: Option<ShortStat>,
+    pub started_at: DateTime<Utc>,
+    /// When the session was finished, or when the summary was made
+    pub ended_at: DateTime<Utc>,
+    pub finished: bool,
+    pub duration_secs: i64,
+    pub history: Vec<StatusHistoryEntry>,
+}
+
+/// Numbers from `git diff --shortstat`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct ShortStat {
+    pub files_changed: usize,
+    pub insertions: usize,
+    pub deletions: usize,
+}
+
+impl ShortStat {
+    /// Parse ` 3 files changed, 10 insertions(+), 2 deletions(-)`; parts may be missing
+    pub fn parse(output: &str) -> Self {
+        let mut stat = Self::default();
+        for part in output.split(',') {
+            let mut words = part.split_whitespace();
+            let (Some(count), Some(label)) = (words.next(), words.next()) else {
+                continue;
+            };
+            let Ok(count) = count.parse() else {
+                continue;
+            };
+            if label.starts_with("file") {
+                stat.files_changed = count;
+            } else if label.starts_with("insertion") {
+                stat.insertions = count;
+            } else if label.starts_with("deletion") {
+                stat.deletions = count;
+            }
+        }
+        stat
+    }
+}
+
+/// Collect the summary of `session`, counting commits on `branch`
+pub fn gather(
+    repo: &GitRepository,
+    state_dir: &Path,
+    session: &SessionState,
+    branch: &str,
+    now: DateTime<Utc>,
+) -> Result<SessionSummary> {
+    let base = match session
+        .base_commit
+        .clone()
+        .or_else(|| session.parent_branch.clone())
+    {
+        Some(base) => Some(base),
+        None => repo.get_main_branch().ok(),
+    };
+
+    let branch_ref = format!("refs/heads/{branch}");
+    let branch_exists =
+        execute_git_command(repo, &["rev-parse", "--verify", "--quiet", &branch_ref]).is_ok();
+    let (commits, stats) = match (&base, branch_exists) {
+        (Some(base), true) => {
+            let log = execute_git_command(
+                repo,
+                &[
+                    "log",
+                    "--reverse",
+                    "--format=%s",
+                    &format!("{base}..{branch_ref}"),
+                ],
+            )?;
+            let shortstat = execute_git_command(
+                repo,
+                &["diff", "--shortstat", &format!("{base}...{branch_ref}")],
+            )?;
+            let commits = log.lines().map(str::to_string).collect();
+            (commits, Some(ShortStat::parse(&shortstat)))
+        }
+        _ => (Vec::new(), None),
+    };
+
+    let finished = matches!(session.status, SessionStatus::Review);
+    // Finishing writes a last status update, which dates the finish
+    let ended_at = Status::load(state_dir, &session.name)
+        .ok()
+        .flatten()
+        .map(|status| status.updated_at)
+        .filter(|updated_at| finished && *updated_at > session.created_at)
+        .unwrap_or(now);
+
+    Ok(SessionSummary {
+        session: session.name.clone(),
+        branch: branch.to_string(),
+        base: base.map(|base| display_base(&base)),
+        task: session
+            .task_description
+            .clone()
+            .filter(|task| !task.trim().is_empty()),
+        links: session.links.clone(),
+        commits,
+        stats,
+        started_at: session.created_at,
+        ended_at,
+        finished,
+        duration_secs: (ended_at - session.created_at).num_seconds().max(0),
+        history: Status::load_history(state_dir, &session.name, HISTORY_ENTRIES),
+    })
+}
+
+/// Commits are shown abbreviated, branch names as they are
+fn display_base(base: &str) -> String {
+    let is_sha = base.len() == 40 && base.chars().all(|c| c.is_ascii_hexdigit());
+    if is_sha {
+        base[..7].to_string()
+    } else {
+        base.to_string()
+    }
+}
+
+/// `1h 20m`, `45m`, `2d 3h`; under a minute is `<1m`
+pub fn format_duration(duration: Duration) -> String {
+    let minutes = duration.num_minutes();
+    if minutes < 1 {
+        return "<1m".to_string();
+    }
+    let (days, hours, minutes) = (minutes / 1440, minutes / 60 % 24, minutes % 60);
+    match (days, hours) {
+        (0, 0) => format!("{minutes}m"),
+        (0, _) => format!("{hours}h {minutes}m"),
+        _ => format!("{days}d {hours}h"),
+    }
+}
+
+fn stats_text(stats: &ShortStat) -> String {
+    format!(
+        "{} file{} changed, +{} −{}",
+        stats.files_changed,
+        if stats.files_changed == 1 { "" } else { "s" },
+        stats.insertions,
+        stats.deletions
+    )
+}
+
+fn commits_text(count: usize) -> String {
+    format!("{count} commit{}", if count == 1 { "" } else { "s" })
+}
+
+/// Markdown for a PR description or a standup note
+pub fn render_markdown(summary: &SessionSummary) -> String {
+    let mut out = format!("## {}\n\n", summary.session);
+
+    if let Some(ref task) = summary.task {
+        out.push_str(&format!("### Task\n\n{}\n\n", task.trim()));
+    }
+
+    if !summary.links.is_empty() {
+        out.push_str("### Links\n\n");
+        for link in &summary.links {
+            out.push_str(&format!("- [{}]({})\n", link.label, link.url));
+        }
+        out.push('\n');
+    }
+
+    let since = summary
+        .base
+        .as_ref()
+        .map(|base| format!(" since `{base}`"))
+        .unwrap_or_default();
+    out.push_str(&format!(
+        "### Commits\n\n{} on `{}`{since}\n\n",
+        commits_text(summary.commits.len()),
+        summary.branch
+    ));
+    for subject in &summary.commits {
+        out.push_str(&format!("- {subject}\n"));
+    }
+    if !summary.commits.is_empty() {
+        out.push('\n');
+    }
+
+    out.push_str("### Stats\n\n");
+    if let Some(ref stats) = summary.stats {
+        out.push_str(&format!("- Changes: {}\n", stats_text(stats)));
+    }
+    let state = if summary.finished {
+        "finished"
+    } else {
+        "in progress"
+    };
+    out.push_str(&format!(
+        "- Duration: {} ({state})\n",
+        format_duration(Duration::seconds(summary.duration_secs))
+    ));
+
+    if !summary.history.is_empty() {
+        out.push_str("\n### Status history\n\n");
+        for entry in &summary.history {
+            out.push_str(&format!(
+                "- {} {} (tests: {})",
+                entry.updated_at.format("%Y-%m-%d %H:%M"),
+                entry.current_task,
+                entry.test_status.to_string().to_lowercase()
+            ));
+            if entry.is_blocked {
+                match entry.blocked_reason {
+                    Some(ref reason) => out.push_str(&format!(", blocked: {reason}")),
+                    None => out.push_str(", blocked"),
+                }
+            }
+            out.push('\n');
+        }
+    }
+    out
+}
+
+/// One line, as printed after `para finish`
+pub fn render_line(summary: &SessionSummary) -> String {
+    let mut parts = vec![commits_text(summary.commits.len())];
+    if let Some(ref stats) = summary.stats {
+        parts.push(stats_text(stats));
+    }
+    parts.push(format_duration(Duration::seconds(summary.duration_secs)));
+    format!("{}: {}", summary.session, parts.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::status::TestStatus;
+    use crate::test_utils::test_helpers::setup_test_repo;
+    use chrono::TimeZone;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn summary(commits: &[&str], history: Vec<StatusHistoryEntry>) -> SessionSummary {
+        let started_at = Utc.with_ymd_and_hms(2026, 3, 2, 9, 0, 0).unwrap();
+        SessionSummary {
+            session: "auth-fix".to_string(),
+            branch: "para/auth-fix".to_string(),
+            base: Some("main".to_string()),
+            task: Some("Fix the login redirect\n".to_string()),
+            links: Vec::new(),
+            commits: commits.iter().map(|c| c.to_string()).collect(),
+            stats: Some(ShortStat {
+                files_changed: 3,
+                insertions: 42,
+                deletions: 7,
+            }),
+            started_at,
+            ended_at: started_at + Duration::minutes(80),
+            finished: false,
+            duration_secs: 80 * 60,
+            history,
+        }
+    }
+
+    fn entry(task: &str, test_status: TestStatus, blocked: Option<&str>) -> StatusHistoryEntry {
+        StatusHistoryEntry {
+            updated_at: Utc.with_ymd_and_hms(2026, 3, 2, 9, 30, 0).unwrap(),
+            current_task: task.to_string(),
+            test_status,
+            is_blocked: blocked.is_some(),
+            blocked_reason: blocked.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_markdown_without_history() {
+        let markdown = render_markdown(&summary(&["Add redirect test", "Fix redirect"], vec![]));
+        assert_eq!(
+            markdown,
+            "## auth-fix\n\n\
+             ### Task\n\nFix the login redirect\n\n\
+             ### Commits\n\n2 commits on `para/auth-fix` since `main`\n\n\
+             - Add redirect test\n- Fix redirect\n\n\
+             ### Stats\n\n\
+             - Changes: 3 files changed, +42 −7\n\
+             - Duration: 1h 20m (in progress)\n"
+        );
+    }
+
+    #[test]
+    fn test_markdown_with_history() {
+        let history = vec![
+            entry("Writing tests", TestStatus::Failed, None),
+            entry(
+                "Waiting on API keys",
+                TestStatus::Unknown,
+                Some("no staging key"),
+            ),
+        ];
+        let mut with_links = summary(&["Fix redirect"], history);
+        with_links.links.push(SessionLink {
+            label: "PR".to_string(),
+            url: "https://github.com/acme/app/pull/42".to_string(),
+        });
+        let markdown = render_markdown(&with_links);
+        assert!(markdown.contains(
+            "### Task\n\nFix the login redirect\n\n\
+             ### Links\n\n- [PR](https://github.com/acme/app/pull/42)\n\n\
+             ### Commits\n\n1 commit on `para/auth-fix`"
+        ));
+        assert!(markdown.ends_with(
+            "### Status history\n\n\
+             - 2026-03-02 09:30 Writing tests (tests: failed)\n\
+             - 2026-03-02 09:30 Waiting on API keys (tests: unknown), blocked: no staging key\n"
+        ));
+    }
+
+    #[test]
+    fn test_one_line_and_json() {
+        let mut finished = summary(&["Fix the login redirect"], vec![]);
+        finished.finished = true;
+        finished.stats = None;
+        assert_eq!(render_line(&finished), "auth-fix: 1 commit, 1h 20m");
+        assert_eq!(
+            render_line(&summary(&["a", "b"], vec![])),
+            "auth-fix: 2 commits, 3 files changed, +42 −7, 1h 20m"
+        );
+
+        let json = serde_json::to_value(&finished).unwrap();
+        assert_eq!(json["finished"], true);
+        assert_eq!(json["duration_secs"], 4800);
+        assert!(json.get("stats").is_none());
+    }
+
+    #[test]
+    fn test_shortstat_and_duration() {
+        assert_eq!(
+            ShortStat::parse(" 1 file changed, 2 insertions(+)"),
+            ShortStat {
+                files_changed: 1,
+                insertions: 2,
+                deletions: 0
+            }
+        );
+        assert_eq!(ShortStat::parse(""), ShortStat::default());
+        assert_eq!(format_duration(Duration::seconds(30)), "<1m");
+        assert_eq!(format_duration(Duration::minutes(45)), "45m");
+        assert_eq!(format_duration(Duration::minutes(26 * 60 + 5)), "1d 2h");
+    }
+
+    #[test]
+    fn test_gather_before_and_after_squash() {
+        let (_git_temp, git_service) = setup_test_repo();
+        let repo = git_service.repository();
+        let state_dir = TempDir::new().unwrap();
+        let base = execute_git_command(repo, &["rev-parse", "HEAD"]).unwrap();
+
+        execute_git_command(repo, &["checkout", "-b", "para/auth-fix"]).unwrap();
+        for (file, subject) in [("a.txt", "Add redirect test"), ("b.txt", "Fix redirect")] {
+            fs::write(repo.root.join(file), "line\n").unwrap();
+            execute_git_command(repo, &["add", file]).unwrap();
+            execute_git_command(repo, &["commit", "-m", subject]).unwrap();
+        }
+
+        let mut session = SessionState::new(
+            "auth-fix".to_string(),
+            "para/auth-fix".to_string(),
+            repo.root.clone(),
+        );
+        session.base_commit = Some(base.clone());
+        session.task_description = Some("Fix the login redirect".to_string());
+        let now = session.created_at + Duration::minutes(10);
+
+        let before = gather(repo, state_dir.path(), &session, "para/auth-fix", now).unwrap();
+        assert_eq!(before.commits, vec!["Add redirect test", "Fix redirect"]);
+        assert_eq!(before.stats.unwrap().files_changed, 2);
+        assert_eq!(before.base.as_deref(), Some(&base[..7]));
+        assert!(!before.finished);
+        assert!(before.history.is_empty());
+        assert_eq!(before.duration_secs, 600);
+
+        // Finishing squashes the session into one commit and records a final status
+        execute_git_command(repo, &["reset", "--soft", &base]).unwrap();
+        execute_git_command(repo, &["commit", "-m", "Fix the login redirect"]).unwrap();
+        session.status = SessionStatus::Review;
+        let mut status = Status::new(
+            "auth-fix".to_string(),
+            "Finished".to_string(),
+            TestStatus::Passed,
+        );
+        status.updated_at = session.created_at + Duration::minutes(5);
+        status.save(state_dir.path()).unwrap();
+
+        let after = gather(repo, state_dir.path(), &session, "para/auth-fix", now).unwrap();
+        assert_eq!(after.commits, vec!["Fix the login redirect"]);
+        assert_eq!(after.stats.unwrap().insertions, 2);
+        assert!(after.finished);
+        assert_eq!(after.duration_secs, 300);
+        assert_eq!(after.history.len(), 1);
+        assert!(render_markdown(&after).contains("- Duration: 5m (finished)"));
+    }
+}
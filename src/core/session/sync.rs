@@ -0,0 +1,361 @@
+//! Bringing a session branch up to date with the branch it was started from
+//!
+//! `para sync` and the monitor's `u` key both run [`sync_session`], which
+//! rebases the session's own commits onto the current tip of its base. A
+//! rebase that conflicts is left stopped in the worktree so the conflicts can
+//! be resolved there; syncing again afterwards records the new base.
+
+use super::{conflicts, SessionManager, SessionState};
+use crate::core::git::repository::execute_git_command;
+use crate::core::git::GitRepository;
+use crate::utils::{ParaError, Result};
+
+/// Commits behind its base before the monitor flags a session
+pub const DEFAULT_BEHIND_WARNING_COMMITS: usize = 10;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SyncOutcome {
+    UpToDate {
+        base: String,
+    },
+    /// The branch was `behind` commits behind `base` and now sits on its tip
+    Rebased {
+        base: String,
+        behind: usize,
+    },
+    /// The rebase stopped on conflicts in `files`; it is still in progress
+    Conflicted {
+        base: String,
+        files: Vec<String>,
+    },
+}
+
+impl SyncOutcome {
+    pub fn describe(&self, session: &str) -> String {
+        match self {
+            SyncOutcome::UpToDate { base } => format!("{session} is up to date with {base}"),
+            SyncOutcome::Rebased { base, behind } => {
+                let commits = if *behind == 1 { "commit" } else { "commits" };
+                format!("Rebased {session} onto {base} ({behind} new {commits})")
+            }
+            SyncOutcome::Conflicted { base, files } => format!(
+                "Rebasing {session} onto {base} stopped on conflicts in {}. Resolve them in the worktree, run 'git rebase --continue', then 'para sync {session}' again",
+                files.join(", ")
+            ),
+        }
+    }
+}
+
+/// Branch the session is synced with: the one it was started from, or the
+/// repository's main branch for sessions that did not record one
+pub fn sync_base(repo: &GitRepository, session: &SessionState) -> Result<String> {
+    match &session.parent_branch {
+        Some(parent) => Ok(parent.clone()),
+        None => repo.get_main_branch(),
+    }
+}
+
+/// Commits the session branch is ahead of and behind the branch it was started from
+pub fn ahead_behind(repo: &GitRepository, session: &SessionState) -> Option<(usize, usize)> {
+    let parent = session
+        .parent_branch
+        .as_deref()
+        .unwrap_or(session.diff_base());
+    let counts = execute_git_command(
+        repo,
+        &[
+            "rev-list",
+            "--left-right",
+            "--count",
+            &format!("{}...{parent}", session.branch),
+        ],
+    )
+    .ok()?;
+    let (ahead, behind) = counts.split_once(char::is_whitespace)?;
+    Some((ahead.trim().parse().ok()?, behind.trim().parse().ok()?))
+}
+
+/// Rebase the session's branch onto the tip of its base and record that tip
+/// as the session's base commit
+pub fn sync_session(manager: &SessionManager, session: &SessionState) -> Result<SyncOutcome> {
+    if !session.worktree_path.exists() {
+        return Err(ParaError::fs_error(format!(
+            "Worktree of session '{}' does not exist: {}",
+            session.name,
+            session.worktree_path.display()
+        )));
+    }
+    let repo = GitRepository::discover_from(&session.worktree_path)?;
+    if rebase_in_progress(&repo)? {
+        return Err(ParaError::invalid_args(format!(
+            "Session '{}' is in the middle of a rebase. Finish it with 'git rebase --continue' or 'git rebase --abort' first",
+            session.name
+        )));
+    }
+    if repo.has_uncommitted_changes()? {
+        return Err(ParaError::invalid_args(format!(
+            "Session '{}' has uncommitted changes. Commit or stash them before syncing",
+            session.name
+        )));
+    }
+
+    let base = sync_base(&repo, session)?;
+    let behind = match ahead_behind(&repo, session) {
+        Some((_, behind)) => behind,
+        None => {
+            return Err(ParaError::git_operation(format!(
+                "Failed to compare '{}' with '{base}'",
+                session.branch
+            )))
+        }
+    };
+
+    if behind > 0 && execute_git_command(&repo, &["rebase", &base]).is_err() {
+        let files = conflicted_files(&repo)?;
+        if files.is_empty() {
+            let _ = execute_git_command(&repo, &["rebase", "--abort"]);
+            return Err(ParaError::git_operation(format!(
+                "Rebasing '{}' onto '{base}' failed; the branch was left as it was",
+                session.branch
+            )));
+        }
+        remember_conflict(manager, &repo, session, &base, &files);
+        return Ok(SyncOutcome::Conflicted { base, files });
+    }
+
+    let base_commit = repo.resolve_commit(&base)?;
+    if session.base_commit.as_deref() != Some(base_commit.as_str()) {
+        let mut synced = manager.load_state(&session.name)?;
+        synced.base_commit = Some(base_commit);
+        manager.save_state(&synced)?;
+    }
+
+    Ok(if behind == 0 {
+        SyncOutcome::UpToDate { base }
+    } else {
+        SyncOutcome::Rebased { base, behind }
+    })
+}
+
+/// Carry on with a sync that stopped on conflicts once they are resolved:
+/// stage the worktree, continue the rebase and record the new base. This is
+/// what the older `para continue` did.
+pub fn continue_sync(manager: &SessionManager, session: &SessionState) -> Result<SyncOutcome> {
+    let repo = GitRepository::discover_from(&session.worktree_path)?;
+    if !rebase_in_progress(&repo)? {
+        return Err(ParaError::invalid_args(format!(
+            "Session '{}' has no sync stopped on conflicts to continue",
+            session.name
+        )));
+    }
+
+    let unresolved: Vec<String> = conflicted_files(&repo)?
+        .into_iter()
+        .filter(|file| has_conflict_markers(&repo.root.join(file)))
+        .collect();
+    if !unresolved.is_empty() {
+        return Err(ParaError::invalid_args(format!(
+            "Resolve the conflicts in {} before continuing",
+            unresolved.join(", ")
+        )));
+    }
+
+    let base = sync_base(&repo, session)?;
+    if let Err(e) = conflicts::record_resolutions(&repo, manager.state_dir(), &session.name) {
+        crate::utils::debug_log(&format!("Failed to record conflict resolutions: {e}"));
+    }
+    execute_git_command(&repo, &["add", "-A"])?;
+    if execute_git_command(&repo, &["-c", "core.editor=true", "rebase", "--continue"]).is_err() {
+        let files = conflicted_files(&repo)?;
+        if files.is_empty() {
+            return Err(ParaError::git_operation(format!(
+                "Continuing the rebase of '{}' failed. Finish it with 'git rebase --continue' or 'git rebase --abort'",
+                session.branch
+            )));
+        }
+        // The next commit of the session conflicts as well
+        remember_conflict(manager, &repo, session, &base, &files);
+        return Ok(SyncOutcome::Conflicted { base, files });
+    }
+
+    sync_session(manager, session)
+}
+
+/// Keep the conflicted content so its resolution can be recorded; a failure
+/// only costs the record
+fn remember_conflict(
+    manager: &SessionManager,
+    repo: &GitRepository,
+    session: &SessionState,
+    base: &str,
+    files: &[String],
+) {
+    if let Err(e) =
+        conflicts::capture_pending(repo, manager.state_dir(), &session.name, base, files)
+    {
+        crate::utils::debug_log(&format!("Failed to capture conflict: {e}"));
+    }
+}
+
+fn conflicted_files(repo: &GitRepository) -> Result<Vec<String>> {
+    let conflicted = execute_git_command(repo, &["diff", "--name-only", "--diff-filter=U"])?;
+    Ok(conflicted.lines().map(str::to_string).collect())
+}
+
+fn has_conflict_markers(path: &std::path::Path) -> bool {
+    std::fs::read_to_string(path).is_ok_and(|content| {
+        content
+            .lines()
+            .any(|line| line.starts_with("<<<<<<< ") || line.starts_with(">>>>>>> "))
+    })
+}
+
+fn rebase_in_progress(repo: &GitRepository) -> Result<bool> {
+    for dir in ["rebase-merge", "rebase-apply"] {
+        let path = execute_git_command(repo, &["rev-parse", "--git-path", dir])?;
+        if repo.root.join(path.trim()).exists() {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::test_helpers::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_sync_rebases_onto_the_base() {
+        let temp_dir = TempDir::new().unwrap();
+        let (_git_temp, git_service) = setup_test_repo();
+        let config = create_test_config_with_dir(&temp_dir);
+        let manager = SessionManager::new(&config);
+        let root = git_service.repository().root.clone();
+
+        let session = start_session(&git_service, &manager, "feature", "main");
+        commit_file(
+            &session.worktree_path,
+            "feature.txt",
+            "feature",
+            "Change feature.txt",
+        );
+        assert_eq!(
+            sync_session(&manager, &session).unwrap(),
+            SyncOutcome::UpToDate {
+                base: "main".to_string()
+            }
+        );
+
+        commit_file(&root, "one.txt", "one", "Change one.txt");
+        commit_file(&root, "two.txt", "two", "Change two.txt");
+        assert_eq!(
+            ahead_behind(git_service.repository(), &session),
+            Some((1, 2))
+        );
+
+        let outcome = sync_session(&manager, &session).unwrap();
+        assert_eq!(
+            outcome,
+            SyncOutcome::Rebased {
+                base: "main".to_string(),
+                behind: 2
+            }
+        );
+        assert_eq!(
+            outcome.describe("feature"),
+            "Rebased feature onto main (2 new commits)"
+        );
+        assert_eq!(
+            ahead_behind(git_service.repository(), &session),
+            Some((1, 0))
+        );
+        assert_eq!(
+            manager.load_state("feature").unwrap().base_commit,
+            Some(git_service.repository().resolve_commit("main").unwrap())
+        );
+    }
+
+    #[test]
+    fn test_sync_leaves_conflicts_to_resolve() {
+        let temp_dir = TempDir::new().unwrap();
+        let (_git_temp, git_service) = setup_test_repo();
+        let config = create_test_config_with_dir(&temp_dir);
+        let manager = SessionManager::new(&config);
+        let root = git_service.repository().root.clone();
+
+        let session = start_session(&git_service, &manager, "clash", "main");
+        commit_file(
+            &session.worktree_path,
+            "shared.txt",
+            "session",
+            "Change shared.txt",
+        );
+        commit_file(&root, "shared.txt", "main", "Change shared.txt");
+
+        let outcome = sync_session(&manager, &session).unwrap();
+        assert_eq!(
+            outcome,
+            SyncOutcome::Conflicted {
+                base: "main".to_string(),
+                files: vec!["shared.txt".to_string()]
+            }
+        );
+
+        // A second sync does not start over a rebase that is still running
+        let error = sync_session(&manager, &session).unwrap_err();
+        assert!(error.to_string().contains("middle of a rebase"));
+    }
+
+    #[test]
+    fn test_continue_sync_after_resolving() {
+        let temp_dir = TempDir::new().unwrap();
+        let (_git_temp, git_service) = setup_test_repo();
+        let config = create_test_config_with_dir(&temp_dir);
+        let manager = SessionManager::new(&config);
+        let root = git_service.repository().root.clone();
+
+        let session = start_session(&git_service, &manager, "clash", "main");
+        let error = continue_sync(&manager, &session).unwrap_err();
+        assert!(error.to_string().contains("no sync stopped on conflicts"));
+
+        commit_file(
+            &session.worktree_path,
+            "shared.txt",
+            "session",
+            "Change shared.txt",
+        );
+        commit_file(&root, "shared.txt", "main", "Change shared.txt");
+        assert!(matches!(
+            sync_session(&manager, &session).unwrap(),
+            SyncOutcome::Conflicted { .. }
+        ));
+
+        let error = continue_sync(&manager, &session).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("Resolve the conflicts in shared.txt"));
+
+        fs::write(session.worktree_path.join("shared.txt"), "both").unwrap();
+        assert_eq!(
+            continue_sync(&manager, &session).unwrap(),
+            SyncOutcome::UpToDate {
+                base: "main".to_string()
+            }
+        );
+        let records = conflicts::load_records(manager.state_dir(), Some("shared.txt"));
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].session, "clash");
+        assert!(records[0].resolution.contains("+both"));
+        assert_eq!(
+            ahead_behind(git_service.repository(), &session),
+            Some((1, 0))
+        );
+        assert_eq!(
+            manager.load_state("clash").unwrap().base_commit,
+            Some(git_service.repository().resolve_commit("main").unwrap())
+        );
+    }
+}
@@ -0,0 +1,332 @@
+//! Copying untracked local files into new session worktrees
+//!
+//! Fresh worktrees only contain tracked files, so local configuration such as
+//! `.env` or `local.settings.json` is missing. Files matching the configured
+//! `session.copy_untracked` patterns (and `--copy` flags) are copied from the
+//! main working tree into the same relative paths of the new worktree.
+//!
+//! Patterns use git's `:(glob)` pathspec syntax relative to the repository
+//! root: `.env` only matches the root file, `**/.env` matches it in every
+//! directory.
+
+use crate::core::git::repository::execute_git_command;
+use crate::core::git::GitRepository;
+use crate::utils::{ParaError, Result};
+use std::collections::HashSet;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Gitignored files above this size are only copied with `--copy-large`
+pub const LARGE_FILE_LIMIT_BYTES: u64 = 1024 * 1024;
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CopyUntrackedReport {
+    pub copied: Vec<PathBuf>,
+    /// Files that already existed in the worktree and were left untouched
+    pub skipped_existing: Vec<PathBuf>,
+    /// Gitignored files above [`LARGE_FILE_LIMIT_BYTES`] with their size
+    pub skipped_large: Vec<(PathBuf, u64)>,
+    /// Copied files that are not gitignored and would be committed on finish
+    pub not_ignored: Vec<PathBuf>,
+}
+
+impl CopyUntrackedReport {
+    pub fn is_empty(&self) -> bool {
+        self.copied.is_empty() && self.skipped_existing.is_empty() && self.skipped_large.is_empty()
+    }
+
+    pub fn print_summary(&self) {
+        if self.is_empty() {
+            return;
+        }
+
+        if !self.copied.is_empty() {
+            println!("📋 Copied {} untracked file(s):", self.copied.len());
+            for path in &self.copied {
+                println!("   {}", path.display());
+            }
+        }
+        for path in &self.skipped_existing {
+            println!("   Skipped {} (already exists)", path.display());
+        }
+        for (path, size) in &self.skipped_large {
+            println!(
+                "   Skipped {} ({} bytes, use --copy-large to include)",
+                path.display(),
+                size
+            );
+        }
+        for path in &self.not_ignored {
+            eprintln!(
+                "Warning: {} is not gitignored and will be committed when the session finishes",
+                path.display()
+            );
+        }
+    }
+}
+
+/// Copy untracked files from `repo_root` matching `patterns` into `worktree_path`.
+///
+/// Existing files in the worktree are never overwritten. Gitignored files larger
+/// than [`LARGE_FILE_LIMIT_BYTES`] are skipped unless `allow_large` is set.
+pub fn copy_untracked_files(
+    repo_root: &Path,
+    worktree_path: &Path,
+    patterns: &[String],
+    allow_large: bool,
+) -> Result<CopyUntrackedReport> {
+    let mut report = CopyUntrackedReport::default();
+    if patterns.is_empty() {
+        return Ok(report);
+    }
+
+    let repo = GitRepository::discover_from(repo_root)?;
+    let pathspecs: Vec<String> = patterns.iter().map(|p| format!(":(glob){p}")).collect();
+
+    let untracked = list_paths(&repo, &["ls-files", "--others", "-z", "--"], &pathspecs)?;
+    let ignored: HashSet<String> = list_paths(
+        &repo,
+        &[
+            "ls-files",
+            "--others",
+            "--ignored",
+            "--exclude-standard",
+            "-z",
+            "--",
+        ],
+        &pathspecs,
+    )?
+    .into_iter()
+    .collect();
+
+    for relative in untracked {
+        let source = repo_root.join(&relative);
+        if source.starts_with(worktree_path) || !source.is_file() {
+            continue;
+        }
+
+        let target = worktree_path.join(&relative);
+        if target.symlink_metadata().is_ok() {
+            report.skipped_existing.push(PathBuf::from(&relative));
+            continue;
+        }
+
+        let is_ignored = ignored.contains(&relative);
+        let size = source.metadata().map(|m| m.len()).unwrap_or(0);
+        if is_ignored && size > LARGE_FILE_LIMIT_BYTES && !allow_large {
+            report.skipped_large.push((PathBuf::from(&relative), size));
+            continue;
+        }
+
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent).map_err(|e| {
+                ParaError::fs_error(format!(
+                    "Failed to create directory {}: {e}",
+                    parent.display()
+                ))
+            })?;
+        }
+        fs::copy(&source, &target).map_err(|e| {
+            ParaError::file_operation(format!("Failed to copy {relative} into worktree: {e}"))
+        })?;
+
+        if is_ignored {
+            ensure_ignored_in_worktree(worktree_path, &relative)?;
+        } else {
+            report.not_ignored.push(PathBuf::from(&relative));
+        }
+        report.copied.push(PathBuf::from(relative));
+    }
+
+    Ok(report)
+}
+
+fn list_paths(repo: &GitRepository, args: &[&str], pathspecs: &[String]) -> Result<Vec<String>> {
+    let mut full_args: Vec<&str> = args.to_vec();
+    full_args.extend(pathspecs.iter().map(String::as_str));
+
+    let output = execute_git_command(repo, &full_args)?;
+    Ok(output
+        .split('\0')
+        .filter(|p| !p.is_empty())
+        .map(str::to_string)
+        .collect())
+}
+
+/// Keep a file that is gitignored in the main tree ignored in the worktree.
+///
+/// Ignore rules normally come from tracked `.gitignore` files and the shared
+/// `info/exclude`, but rules from untracked `.gitignore` files are missing in a
+/// fresh worktree. Such paths are added to `info/exclude`.
+pub(crate) fn ensure_ignored_in_worktree(worktree_path: &Path, relative: &str) -> Result<()> {
+    let worktree = GitRepository::discover_from(worktree_path)?;
+    if execute_git_command(&worktree, &["check-ignore", "-q", "--", relative]).is_ok() {
+        return Ok(());
+    }
+
+    let common_dir = execute_git_command(&worktree, &["rev-parse", "--git-common-dir"])?;
+    let common_dir = PathBuf::from(common_dir);
+    let common_dir = if common_dir.is_absolute() {
+        common_dir
+    } else {
+        worktree_path.join(common_dir)
+    };
+
+    let info_dir = common_dir.join("info");
+    fs::create_dir_all(&info_dir).map_err(|e| {
+        ParaError::fs_error(format!(
+            "Failed to create directory {}: {e}",
+            info_dir.display()
+        ))
+    })?;
+
+    let exclude = info_dir.join("exclude");
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&exclude)
+        .map_err(|e| ParaError::fs_error(format!("Failed to open {}: {e}", exclude.display())))?;
+    writeln!(file, "/{relative}")
+        .map_err(|e| ParaError::fs_error(format!("Failed to write {}: {e}", exclude.display())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::test_helpers::*;
+    use std::process::Command;
+    use tempfile::TempDir;
+
+    fn add_worktree(repo_root: &Path, temp: &TempDir) -> PathBuf {
+        let worktree = temp.path().join("session-wt");
+        let status = Command::new("git")
+            .current_dir(repo_root)
+            .args(["worktree", "add", "-b", "copy-test"])
+            .arg(&worktree)
+            .output()
+            .unwrap();
+        assert!(status.status.success());
+        worktree
+    }
+
+    fn is_ignored(worktree: &Path, relative: &str) -> bool {
+        Command::new("git")
+            .current_dir(worktree)
+            .args(["check-ignore", "-q", relative])
+            .status()
+            .unwrap()
+            .success()
+    }
+
+    #[test]
+    fn test_copies_env_and_nested_config_without_overwriting() {
+        let (_git_temp, git_service) = setup_test_repo();
+        let worktree_temp = TempDir::new().unwrap();
+        let repo_root = git_service.repository().root.clone();
+
+        fs::write(repo_root.join(".gitignore"), ".env\n").unwrap();
+        Command::new("git")
+            .current_dir(&repo_root)
+            .args(["add", ".gitignore"])
+            .output()
+            .unwrap();
+        Command::new("git")
+            .current_dir(&repo_root)
+            .args(["commit", "-m", "Ignore .env"])
+            .output()
+            .unwrap();
+
+        fs::write(repo_root.join(".env"), "API_KEY=secret\n").unwrap();
+        fs::create_dir_all(repo_root.join("api/config")).unwrap();
+        fs::write(
+            repo_root.join("api/config/local.settings.json"),
+            "{\"port\": 7071}",
+        )
+        .unwrap();
+        fs::write(repo_root.join("notes.txt"), "not copied").unwrap();
+
+        let worktree = add_worktree(&repo_root, &worktree_temp);
+        fs::create_dir_all(worktree.join("api/config")).unwrap();
+        fs::write(worktree.join("api/config/local.settings.json"), "keep me").unwrap();
+
+        let patterns = vec![".env".to_string(), "**/local.settings.json".to_string()];
+        let report = copy_untracked_files(&repo_root, &worktree, &patterns, false).unwrap();
+
+        assert_eq!(report.copied, vec![PathBuf::from(".env")]);
+        assert_eq!(
+            report.skipped_existing,
+            vec![PathBuf::from("api/config/local.settings.json")]
+        );
+        assert_eq!(
+            fs::read_to_string(worktree.join(".env")).unwrap(),
+            "API_KEY=secret\n"
+        );
+        assert_eq!(
+            fs::read_to_string(worktree.join("api/config/local.settings.json")).unwrap(),
+            "keep me"
+        );
+        assert!(!worktree.join("notes.txt").exists());
+        assert!(is_ignored(&worktree, ".env"));
+
+        fs::remove_file(worktree.join("api/config/local.settings.json")).unwrap();
+        let report = copy_untracked_files(&repo_root, &worktree, &patterns, false).unwrap();
+        assert_eq!(
+            report.copied,
+            vec![PathBuf::from("api/config/local.settings.json")]
+        );
+        assert_eq!(
+            fs::read_to_string(worktree.join("api/config/local.settings.json")).unwrap(),
+            "{\"port\": 7071}"
+        );
+        assert_eq!(
+            report.not_ignored,
+            vec![PathBuf::from("api/config/local.settings.json")]
+        );
+    }
+
+    #[test]
+    fn test_large_ignored_files_require_copy_large() {
+        let (_git_temp, git_service) = setup_test_repo();
+        let worktree_temp = TempDir::new().unwrap();
+        let repo_root = git_service.repository().root.clone();
+
+        let info = repo_root.join(".git/info");
+        fs::create_dir_all(&info).unwrap();
+        fs::write(info.join("exclude"), "*.db\n").unwrap();
+        let large = vec![0u8; (LARGE_FILE_LIMIT_BYTES + 1) as usize];
+        fs::write(repo_root.join("local.db"), &large).unwrap();
+
+        let worktree = add_worktree(&repo_root, &worktree_temp);
+        let patterns = vec!["*.db".to_string()];
+
+        let report = copy_untracked_files(&repo_root, &worktree, &patterns, false).unwrap();
+        assert!(report.copied.is_empty());
+        assert_eq!(report.skipped_large.len(), 1);
+        assert!(!worktree.join("local.db").exists());
+
+        let report = copy_untracked_files(&repo_root, &worktree, &patterns, true).unwrap();
+        assert_eq!(report.copied, vec![PathBuf::from("local.db")]);
+        assert!(is_ignored(&worktree, "local.db"));
+    }
+
+    #[test]
+    fn test_untracked_gitignore_rules_are_kept_in_worktree() {
+        let (_git_temp, git_service) = setup_test_repo();
+        let worktree_temp = TempDir::new().unwrap();
+        let repo_root = git_service.repository().root.clone();
+
+        // The ignore rule lives in an untracked .gitignore that the worktree lacks
+        fs::create_dir_all(repo_root.join("local")).unwrap();
+        fs::write(repo_root.join("local/.gitignore"), "secrets.json\n").unwrap();
+        fs::write(repo_root.join("local/secrets.json"), "{}").unwrap();
+
+        let worktree = add_worktree(&repo_root, &worktree_temp);
+        let patterns = vec!["local/secrets.json".to_string()];
+
+        let report = copy_untracked_files(&repo_root, &worktree, &patterns, false).unwrap();
+        assert_eq!(report.copied, vec![PathBuf::from("local/secrets.json")]);
+        assert!(report.not_ignored.is_empty());
+        assert!(is_ignored(&worktree, "local/secrets.json"));
+    }
+}
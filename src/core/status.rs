@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use std::fs::{self, OpenOptions};
 use std::path::{Path, PathBuf};
 
+use crate::core::events::{self, SessionEventKind};
 use crate::utils::ParaError;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -28,6 +29,75 @@ impl std::fmt::Display for DiffStats {
     }
 }
 
+/// One entry of an agent's todo list. An item keeps its id across edits of
+/// the list.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TodoItem {
+    pub id: u32,
+    pub text: String,
+    #[serde(default)]
+    pub done: bool,
+}
+
+/// Todo lists arrive as structured items or, from older agents and
+/// containers, as a string (see [`parse_todo_list`])
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum TodoListInput {
+    Items(Vec<TodoItem>),
+    Legacy(String),
+}
+
+/// Deserialize a todo list given either as items or as a legacy string
+pub fn deserialize_todo_list<'de, D>(
+    deserializer: D,
+) -> std::result::Result<Vec<TodoItem>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    match TodoListInput::deserialize(deserializer)? {
+        TodoListInput::Items(items) => Ok(items),
+        TodoListInput::Legacy(s) => parse_todo_list(&s).map_err(serde::de::Error::custom),
+    }
+}
+
+/// Parse the string form of a todo list: either the old `completed/total`
+/// counts, which become numbered placeholder items, or comma-separated item
+/// texts where a leading `[x]` marks an item as done
+pub fn parse_todo_list(s: &str) -> Result<Vec<TodoItem>> {
+    if s.contains('/') && !s.contains(',') && s.trim().starts_with(|c: char| c.is_ascii_digit()) {
+        let (completed, total) = Status::parse_todos(s.trim())?;
+        return Ok(placeholder_todos(completed, total));
+    }
+
+    Ok(s.split(',')
+        .map(str::trim)
+        .filter(|text| !text.is_empty())
+        .zip(1..)
+        .map(|(text, id)| {
+            let (done, text) = match text.strip_prefix("[x]").or(text.strip_prefix("[X]")) {
+                Some(rest) => (true, rest),
+                None => (false, text.strip_prefix("[ ]").unwrap_or(text)),
+            };
+            TodoItem {
+                id,
+                text: text.trim().to_string(),
+                done,
+            }
+        })
+        .collect())
+}
+
+fn placeholder_todos(completed: u32, total: u32) -> Vec<TodoItem> {
+    (1..=total)
+        .map(|id| TodoItem {
+            id,
+            text: format!("Todo {id}"),
+            done: id <= completed,
+        })
+        .collect()
+}
+
 /// Represents the current status of a para session
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Status {
@@ -36,13 +106,66 @@ pub struct Status {
     pub test_status: TestStatus,
     pub is_blocked: bool,
     pub blocked_reason: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "deserialize_todo_list",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub todos: Vec<TodoItem>,
+    /// Counts of `todos`, still written for readers that predate the list
     #[serde(skip_serializing_if = "Option::is_none")]
     pub todos_completed: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub todos_total: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub diff_stats: Option<DiffStats>,
-    pub last_update: DateTime<Utc>,
+    /// When the agent last reported. Files written before this field existed
+    /// (or under its old name, `last_update`) without it load as the epoch,
+    /// which always counts as stale.
+    #[serde(alias = "last_update", default = "unknown_update_time")]
+    pub updated_at: DateTime<Utc>,
+}
+
+/// One report as kept in the session's status history
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StatusHistoryEntry {
+    pub updated_at: DateTime<Utc>,
+    pub current_task: String,
+    pub test_status: TestStatus,
+    pub is_blocked: bool,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub blocked_reason: Option<String>,
+}
+
+/// Minutes after which an agent status is shown as stale, unless
+/// `status.stale_after_minutes` is configured
+pub const DEFAULT_STALE_AFTER_MINUTES: u64 = 60;
+
+fn unknown_update_time() -> DateTime<Utc> {
+    DateTime::<Utc>::UNIX_EPOCH
+}
+
+/// Whether a status reported at `updated_at` is too old to trust at `now`
+pub fn is_status_outdated(
+    updated_at: DateTime<Utc>,
+    now: DateTime<Utc>,
+    stale_after_minutes: u64,
+) -> bool {
+    let stale_after = i64::try_from(stale_after_minutes).unwrap_or(i64::MAX);
+    now.signed_duration_since(updated_at).num_minutes() >= stale_after
+}
+
+/// Human readable age of a status, e.g. "3h ago"
+pub fn format_status_age(age: chrono::Duration) -> String {
+    if age < chrono::Duration::minutes(1) {
+        "just now".to_string()
+    } else if age < chrono::Duration::hours(1) {
+        format!("{}m ago", age.num_minutes())
+    } else if age < chrono::Duration::days(1) {
+        format!("{}h ago", age.num_hours())
+    } else {
+        format!("{}d ago", age.num_days())
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -79,10 +202,11 @@ impl Status {
             test_status,
             is_blocked: false,
             blocked_reason: None,
+            todos: Vec::new(),
             todos_completed: None,
             todos_total: None,
             diff_stats: None,
-            last_update: Utc::now(),
+            updated_at: Utc::now(),
         }
     }
 
@@ -92,12 +216,91 @@ impl Status {
         self
     }
 
-    pub fn with_todos(mut self, completed: u32, total: u32) -> Self {
-        self.todos_completed = Some(completed);
-        self.todos_total = Some(total);
+    pub fn with_todos(self, completed: u32, total: u32) -> Self {
+        self.with_todo_list(placeholder_todos(completed, total))
+    }
+
+    pub fn with_todo_list(mut self, todos: Vec<TodoItem>) -> Self {
+        self.set_todos(todos);
         self
     }
 
+    /// Replace the todo list, keeping the ids of items whose text is unchanged
+    /// and numbering new items after the highest id in use
+    pub fn set_todos(&mut self, todos: Vec<TodoItem>) {
+        let mut next_id = self.next_todo_id();
+        let mut previous = std::mem::take(&mut self.todos);
+        self.todos = todos
+            .into_iter()
+            .map(|item| {
+                let kept = previous.iter().position(|old| old.text == item.text);
+                let id = match kept {
+                    Some(index) => previous.remove(index).id,
+                    None => {
+                        next_id += 1;
+                        next_id - 1
+                    }
+                };
+                TodoItem { id, ..item }
+            })
+            .collect();
+        if self.todos.is_empty() {
+            self.todos_completed = None;
+            self.todos_total = None;
+        }
+        self.sync_todo_counts();
+    }
+
+    /// Append a todo and return its id
+    pub fn add_todo(&mut self, text: &str) -> u32 {
+        let id = self.next_todo_id();
+        self.todos.push(TodoItem {
+            id,
+            text: text.to_string(),
+            done: false,
+        });
+        self.sync_todo_counts();
+        id
+    }
+
+    /// Mark the todo with `id` as done
+    pub fn complete_todo(&mut self, id: u32) -> Result<&TodoItem> {
+        let index = self
+            .todos
+            .iter()
+            .position(|item| item.id == id)
+            .ok_or_else(|| ParaError::invalid_args(format!("No todo with id {id}")))?;
+        self.todos[index].done = true;
+        self.sync_todo_counts();
+        Ok(&self.todos[index])
+    }
+
+    /// Mark every todo as done
+    pub fn complete_all_todos(&mut self) {
+        for item in &mut self.todos {
+            item.done = true;
+        }
+        self.sync_todo_counts();
+    }
+
+    fn next_todo_id(&self) -> u32 {
+        self.todos.iter().map(|item| item.id).max().unwrap_or(0) + 1
+    }
+
+    /// Bring the todo list and its counts in line. Status files from before
+    /// the list only have counts; they get placeholder items.
+    fn sync_todo_counts(&mut self) {
+        if self.todos.is_empty() {
+            if let (Some(completed), Some(total)) = (self.todos_completed, self.todos_total) {
+                self.todos = placeholder_todos(completed.min(total), total);
+            }
+            return;
+        }
+        let completed = self.todos.iter().filter(|item| item.done).count();
+        self.todos_completed = Some(completed as u32);
+        self.todos_total = Some(self.todos.len() as u32);
+    }
+
     pub fn with_diff_stats(mut self, diff_stats: DiffStats) -> Self {
         self.diff_stats = Some(diff_stats);
         self
@@ -153,9 +356,61 @@ impl Status {
         fs::rename(temp_file, status_file)
             .map_err(|e| ParaError::fs_error(format!("Failed to rename status file: {e}")))?;
 
+        self.append_history(state_dir)?;
+        events::publish(
+            state_dir,
+            &self.session_name,
+            SessionEventKind::StatusChanged,
+        );
+        Ok(())
+    }
+
+    /// Every saved status is also appended to `<session>.status.history.jsonl`
+    pub fn history_file_path(state_dir: &Path, session_name: &str) -> PathBuf {
+        state_dir.join(format!("{session_name}.status.history.jsonl"))
+    }
+
+    fn append_history(&self, state_dir: &Path) -> Result<()> {
+        let entry = StatusHistoryEntry {
+            updated_at: self.updated_at,
+            current_task: self.current_task.clone(),
+            test_status: self.test_status.clone(),
+            is_blocked: self.is_blocked,
+            blocked_reason: self.blocked_reason.clone(),
+        };
+        let line = serde_json::to_string(&entry)
+            .map_err(|e| ParaError::config_error(format!("Failed to serialize status: {e}")))?;
+
+        let path = Self::history_file_path(state_dir, &self.session_name);
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .map_err(|e| ParaError::fs_error(format!("Failed to open status history: {e}")))?;
+        use std::io::Write;
+        writeln!(file, "{line}")
+            .map_err(|e| ParaError::fs_error(format!("Failed to write status history: {e}")))?;
         Ok(())
     }
 
+    /// The last `limit` entries of the session's status history, oldest first.
+    /// Lines that do not parse are skipped.
+    pub fn load_history(
+        state_dir: &Path,
+        session_name: &str,
+        limit: usize,
+    ) -> Vec<StatusHistoryEntry> {
+        let Ok(content) = fs::read_to_string(Self::history_file_path(state_dir, session_name))
+        else {
+            return Vec::new();
+        };
+        let entries: Vec<StatusHistoryEntry> = content
+            .lines()
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect();
+        entries[entries.len().saturating_sub(limit)..].to_vec()
+    }
+
     pub fn load(state_dir: &Path, session_name: &str) -> Result<Option<Self>> {
         let status_file = Self::status_file_path(state_dir, session_name);
 
@@ -182,8 +437,11 @@ impl Status {
                 match file.read_to_string(&mut json) {
                     Ok(_) => {
                         // Try to parse the content
-                        match serde_json::from_str(&json) {
-                            Ok(status) => Ok(Some(status)),
+                        match serde_json::from_str::<Status>(&json) {
+                            Ok(mut status) => {
+                                status.sync_todo_counts();
+                                Ok(Some(status))
+                            }
                             Err(e) => {
                                 // If parsing fails and the file is empty or contains partial data,
                                 // treat it as if the status doesn't exist yet
@@ -260,11 +518,8 @@ impl Status {
     ) -> Result<DiffStats> {
         use crate::core::git::calculate_diff_stats;
 
-        // Use the parent branch from session state, or default to "main"
-        let parent_branch = session_state.parent_branch.as_deref().unwrap_or("main");
-
-        // Calculate diff stats
-        calculate_diff_stats(&session_state.worktree_path, parent_branch)
+        // Calculate diff stats against the pinned base commit or parent branch
+        calculate_diff_stats(&session_state.worktree_path, session_state.diff_base())
             .map_err(|e| anyhow::anyhow!("Failed to calculate diff stats: {}", e))
     }
 
@@ -302,6 +557,14 @@ impl Status {
         }
     }
 
+    /// Todo completion as `completed/total`, e.g. `3/7`
+    pub fn todo_fraction(&self) -> Option<String> {
+        match (self.todos_completed, self.todos_total) {
+            (Some(completed), Some(total)) => Some(format!("{completed}/{total}")),
+            _ => None,
+        }
+    }
+
     pub fn format_todos(&self) -> Option<String> {
         match (self.todos_completed, self.todos_total) {
             (Some(completed), Some(total)) => {
@@ -312,10 +575,16 @@ impl Status {
         }
     }
 
+    /// Time since the agent last reported, never negative
+    pub fn age(&self, now: DateTime<Utc>) -> chrono::Duration {
+        now.signed_duration_since(self.updated_at)
+            .max(chrono::Duration::zero())
+    }
+
     /// Check if the status is stale based on the last update time
     pub fn is_stale(&self, stale_threshold_hours: u32) -> bool {
         let now = Utc::now();
-        let duration = now.signed_duration_since(self.last_update);
+        let duration = now.signed_duration_since(self.updated_at);
         duration.num_hours() >= stale_threshold_hours as i64
     }
 
@@ -437,6 +706,65 @@ impl std::fmt::Display for TestStatus {
     }
 }
 
+/// Condition awaited by `para status wait`
+#[derive(Debug, Clone, PartialEq)]
+pub enum WaitCondition {
+    TestsPassed,
+    Blocked,
+    /// No status update or git activity for this many minutes
+    IdleFor(u32),
+    TaskContains(String),
+}
+
+impl WaitCondition {
+    /// Parse `tests-passed`, `blocked`, `idle-for:<minutes>` or `task-contains:<substr>`
+    pub fn parse(s: &str) -> crate::utils::Result<Self> {
+        let invalid = || {
+            ParaError::invalid_args(format!(
+                "Invalid condition '{s}'. Use tests-passed, blocked, idle-for:<minutes> or task-contains:<text>"
+            ))
+        };
+
+        match s.split_once(':') {
+            None => match s {
+                "tests-passed" => Ok(Self::TestsPassed),
+                "blocked" => Ok(Self::Blocked),
+                _ => Err(invalid()),
+            },
+            Some(("idle-for", minutes)) => minutes
+                .trim()
+                .parse()
+                .map(Self::IdleFor)
+                .map_err(|_| invalid()),
+            Some(("task-contains", text)) if !text.is_empty() => {
+                Ok(Self::TaskContains(text.to_string()))
+            }
+            _ => Err(invalid()),
+        }
+    }
+
+    /// Whether the condition holds for `status` (if the session reported one yet)
+    /// and the last git activity in its worktree, evaluated at `now`.
+    pub fn is_met(
+        &self,
+        status: Option<&Status>,
+        last_activity: Option<DateTime<Utc>>,
+        now: DateTime<Utc>,
+    ) -> bool {
+        match self {
+            Self::TestsPassed => status.is_some_and(|s| s.test_status == TestStatus::Passed),
+            Self::Blocked => status.is_some_and(|s| s.is_blocked),
+            Self::TaskContains(text) => status.is_some_and(|s| s.current_task.contains(text)),
+            Self::IdleFor(minutes) => {
+                let last_seen = status.map(|s| s.updated_at).max(last_activity);
+                last_seen.is_some_and(|last| {
+                    now.signed_duration_since(last) >= chrono::Duration::minutes(*minutes as i64)
+                })
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -517,6 +845,29 @@ mod tests {
         assert_eq!(loaded.todos_total, status.todos_total);
     }
 
+    #[test]
+    fn test_save_appends_history() {
+        let temp_dir = TempDir::new().unwrap();
+        let state_dir = temp_dir.path();
+        assert!(Status::load_history(state_dir, "test-session", 5).is_empty());
+
+        for (task, test_status) in [
+            ("Reading code", TestStatus::Unknown),
+            ("Writing tests", TestStatus::Failed),
+            ("Fixing tests", TestStatus::Passed),
+        ] {
+            Status::new("test-session".to_string(), task.to_string(), test_status)
+                .save(state_dir)
+                .unwrap();
+        }
+
+        let history = Status::load_history(state_dir, "test-session", 2);
+        let tasks: Vec<&str> = history.iter().map(|e| e.current_task.as_str()).collect();
+        assert_eq!(tasks, vec!["Writing tests", "Fixing tests"]);
+        assert_eq!(history[1].test_status, TestStatus::Passed);
+        assert_eq!(Status::load_history(state_dir, "test-session", 10).len(), 3);
+    }
+
     #[test]
     fn test_load_missing_status() {
         let temp_dir = TempDir::new().unwrap();
@@ -596,6 +947,83 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_legacy_todos_become_items() {
+        let items = parse_todo_list("2/3").unwrap();
+        let summary: Vec<_> = items
+            .iter()
+            .map(|i| (i.id, i.text.as_str(), i.done))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (1, "Todo 1", true),
+                (2, "Todo 2", true),
+                (3, "Todo 3", false)
+            ]
+        );
+
+        let items = parse_todo_list("write tests, [x] fix login ,[ ] docs,").unwrap();
+        let summary: Vec<_> = items
+            .iter()
+            .map(|i| (i.id, i.text.as_str(), i.done))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (1, "write tests", false),
+                (2, "fix login", true),
+                (3, "docs", false)
+            ]
+        );
+        assert!(parse_todo_list("5/3").is_err());
+
+        // A status file written before the list existed only has counts
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            Status::status_file_path(temp_dir.path(), "old"),
+            r#"{"session_name":"old","current_task":"t","test_status":"passed","is_blocked":false,"blocked_reason":null,"todos_completed":1,"todos_total":2}"#,
+        )
+        .unwrap();
+        let status = Status::load(temp_dir.path(), "old").unwrap().unwrap();
+        assert_eq!(status.todos.len(), 2);
+        assert!(status.todos[0].done && !status.todos[1].done);
+        assert_eq!(status.todo_fraction(), Some("1/2".to_string()));
+
+        // The JSON form accepts both the legacy string and structured items
+        let status: Status = serde_json::from_str(
+            r#"{"session_name":"s","current_task":"t","test_status":"passed","is_blocked":false,"blocked_reason":null,"todos":[{"id":4,"text":"ship","done":true}]}"#,
+        )
+        .unwrap();
+        assert_eq!(status.todos[0].id, 4);
+    }
+
+    #[test]
+    fn test_todo_ids_are_stable_across_edits() {
+        let mut status = Status::new("s".to_string(), "t".to_string(), TestStatus::Unknown)
+            .with_todo_list(parse_todo_list("parse input,write tests").unwrap());
+        assert_eq!(status.add_todo("update docs"), 3);
+        status.complete_todo(2).unwrap();
+        assert!(status.complete_todo(9).is_err());
+        assert_eq!(status.todo_fraction(), Some("1/3".to_string()));
+
+        // Resending the list keeps the ids of known texts
+        status.set_todos(parse_todo_list("[x] write tests,release,update docs").unwrap());
+        let ids: Vec<_> = status
+            .todos
+            .iter()
+            .map(|i| (i.text.as_str(), i.id))
+            .collect();
+        assert_eq!(
+            ids,
+            vec![("write tests", 2), ("release", 4), ("update docs", 3)]
+        );
+        assert_eq!(status.todo_fraction(), Some("1/3".to_string()));
+
+        status.set_todos(Vec::new());
+        assert_eq!(status.todo_fraction(), None);
+    }
+
     #[test]
     fn test_status_json_serialization() {
         let status = Status::new(
@@ -634,6 +1062,72 @@ mod tests {
         assert!(json.contains("\"blocked_reason\":null"));
     }
 
+    #[test]
+    fn test_legacy_status_files_parse() {
+        let legacy = r#"{
+            "session_name": "old",
+            "current_task": "Old task",
+            "test_status": "passed",
+            "is_blocked": false,
+            "blocked_reason": null
+        }"#;
+        let status: Status = serde_json::from_str(legacy).unwrap();
+        assert_eq!(status.updated_at, DateTime::<Utc>::UNIX_EPOCH);
+        assert!(is_status_outdated(
+            status.updated_at,
+            Utc::now(),
+            60 * 24 * 365
+        ));
+
+        let renamed = r#"{
+            "session_name": "old",
+            "current_task": "Old task",
+            "test_status": "passed",
+            "is_blocked": false,
+            "blocked_reason": null,
+            "last_update": "2024-01-18T10:30:00Z"
+        }"#;
+        let status: Status = serde_json::from_str(renamed).unwrap();
+        assert_eq!(status.updated_at.to_rfc3339(), "2024-01-18T10:30:00+00:00");
+
+        let json = serde_json::to_value(&status).unwrap();
+        assert!(json["updated_at"].is_string());
+        assert!(json.get("last_update").is_none());
+    }
+
+    #[test]
+    fn test_format_status_age_boundaries() {
+        let age = chrono::Duration::seconds;
+        assert_eq!(format_status_age(age(0)), "just now");
+        assert_eq!(format_status_age(age(59)), "just now");
+        assert_eq!(format_status_age(age(60)), "1m ago");
+        assert_eq!(format_status_age(age(3599)), "59m ago");
+        assert_eq!(format_status_age(age(3600)), "1h ago");
+        assert_eq!(format_status_age(age(3 * 3600 + 59)), "3h ago");
+        assert_eq!(format_status_age(age(86_399)), "23h ago");
+        assert_eq!(format_status_age(age(86_400)), "1d ago");
+    }
+
+    #[test]
+    fn test_is_status_outdated_threshold() {
+        let now = Utc::now();
+        let minutes = chrono::Duration::minutes;
+
+        assert!(!is_status_outdated(now, now, 60));
+        assert!(!is_status_outdated(
+            now - minutes(60) + chrono::Duration::seconds(1),
+            now,
+            60
+        ));
+        assert!(is_status_outdated(now - minutes(60), now, 60));
+        assert!(is_status_outdated(now, now, 0));
+        assert!(!is_status_outdated(now - minutes(10_000), now, u64::MAX));
+
+        let mut status = Status::new("s".to_string(), "t".to_string(), TestStatus::Passed);
+        status.updated_at = now + minutes(5);
+        assert_eq!(status.age(now), chrono::Duration::zero());
+    }
+
     #[test]
     fn test_file_locking() {
         let temp_dir = TempDir::new().unwrap();
@@ -685,8 +1179,8 @@ mod tests {
         assert!(!status.is_stale(24));
         assert!(!status.is_stale(1));
 
-        // Set last_update to 25 hours ago
-        status.last_update = Utc::now() - chrono::Duration::hours(25);
+        // Set updated_at to 25 hours ago
+        status.updated_at = Utc::now() - chrono::Duration::hours(25);
         assert!(status.is_stale(24));
         assert!(!status.is_stale(48));
     }
@@ -752,7 +1246,7 @@ mod tests {
             "Stale task".to_string(),
             TestStatus::Failed,
         );
-        stale_status.last_update = Utc::now() - chrono::Duration::hours(48);
+        stale_status.updated_at = Utc::now() - chrono::Duration::hours(48);
         stale_status.save(state_dir).unwrap();
 
         // Verify both files exist
@@ -790,7 +1284,7 @@ mod tests {
                     "Stale task".to_string(),
                     TestStatus::Passed,
                 );
-                s.last_update = Utc::now() - chrono::Duration::hours(48);
+                s.updated_at = Utc::now() - chrono::Duration::hours(48);
                 s
             },
         ];
@@ -901,10 +1395,11 @@ mod tests {
             test_status: TestStatus::Unknown,
             is_blocked: false,
             blocked_reason: None,
+            todos: Vec::new(),
             todos_completed: Some(15), // More than total!
             todos_total: Some(10),
             diff_stats: None,
-            last_update: Utc::now(),
+            updated_at: Utc::now(),
         };
 
         // 15 is capped to 10, so 10/11 = 91%
@@ -960,4 +1455,55 @@ mod tests {
         // 7/11 ≈ 63.6% rounds to 64%
         assert_eq!(status.calculate_progress_with_finish(false), Some(64));
     }
+
+    #[test]
+    fn test_wait_condition_parse() {
+        assert_eq!(
+            WaitCondition::parse("tests-passed").unwrap(),
+            WaitCondition::TestsPassed
+        );
+        assert_eq!(
+            WaitCondition::parse("blocked").unwrap(),
+            WaitCondition::Blocked
+        );
+        assert_eq!(
+            WaitCondition::parse("idle-for:15").unwrap(),
+            WaitCondition::IdleFor(15)
+        );
+        assert_eq!(
+            WaitCondition::parse("task-contains:ready: review").unwrap(),
+            WaitCondition::TaskContains("ready: review".to_string())
+        );
+        assert!(WaitCondition::parse("idle-for:soon").is_err());
+        assert!(WaitCondition::parse("task-contains:").is_err());
+        assert!(WaitCondition::parse("finished").is_err());
+    }
+
+    #[test]
+    fn test_wait_condition_is_met() {
+        let now = Utc::now();
+        let mut status = Status::new(
+            "waiter".to_string(),
+            "Implementing parser".to_string(),
+            TestStatus::Failed,
+        );
+        status.updated_at = now - chrono::Duration::minutes(20);
+
+        assert!(!WaitCondition::TestsPassed.is_met(Some(&status), None, now));
+        assert!(!WaitCondition::TestsPassed.is_met(None, None, now));
+        assert!(!WaitCondition::Blocked.is_met(Some(&status), None, now));
+        assert!(WaitCondition::TaskContains("parser".to_string()).is_met(Some(&status), None, now));
+
+        // Recent git activity keeps the session from counting as idle
+        let idle = WaitCondition::IdleFor(10);
+        assert!(idle.is_met(Some(&status), None, now));
+        assert!(!idle.is_met(Some(&status), Some(now - chrono::Duration::minutes(2)), now));
+        assert!(idle.is_met(None, Some(now - chrono::Duration::minutes(11)), now));
+        assert!(!idle.is_met(None, None, now));
+
+        status.test_status = TestStatus::Passed;
+        let status = status.with_blocked(Some("Need credentials".to_string()));
+        assert!(WaitCondition::TestsPassed.is_met(Some(&status), None, now));
+        assert!(WaitCondition::Blocked.is_met(Some(&status), None, now));
+    }
 }
@@ -0,0 +1,257 @@
+//! State of the current session for status bars
+//!
+//! `para statusline` runs every few seconds from a tmux status bar, so it
+//! reads only what is cheap: the session manifest in the worktree, the
+//! session's latest status file and a single `git status`. Config is not
+//! loaded and the daemon is not contacted. Git gets whatever is left of the
+//! time budget; when it does not answer in time its fields are left out.
+
+use crate::core::session::manifest::{self, SessionManifest};
+use crate::core::status::{Status, TestStatus};
+use std::ffi::OsString;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Time `para statusline` may take before leaving out what is missing
+pub const DEFAULT_BUDGET: Duration = Duration::from_millis(100);
+
+pub struct StatuslineOptions {
+    /// Program run as `git`
+    pub git: OsString,
+    pub budget: Duration,
+}
+
+impl Default for StatuslineOptions {
+    fn default() -> Self {
+        Self {
+            git: OsString::from("git"),
+            budget: DEFAULT_BUDGET,
+        }
+    }
+}
+
+/// What the status line shows about a session
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SessionLine {
+    pub name: String,
+    pub branch: String,
+    /// `None` when git did not answer within the budget
+    pub git: Option<GitSummary>,
+    pub test_status: Option<TestStatus>,
+    pub blocked: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GitSummary {
+    pub dirty: bool,
+    pub ahead: u32,
+    pub behind: u32,
+}
+
+/// The session the worktree containing `dir` belongs to; `None` outside a
+/// session
+pub fn collect(dir: &Path, options: &StatuslineOptions) -> Option<SessionLine> {
+    let started = Instant::now();
+    let manifest_path = manifest::find_path(dir)?;
+    let manifest: SessionManifest =
+        serde_json::from_str(&std::fs::read_to_string(&manifest_path).ok()?).ok()?;
+    // `<worktree>/.para/session.json`
+    let worktree = manifest_path.parent()?.parent()?;
+
+    let status = state_dir(&manifest)
+        .and_then(|state_dir| Status::load(&state_dir, &manifest.name).ok().flatten());
+    let git = options
+        .budget
+        .checked_sub(started.elapsed())
+        .and_then(|remaining| git_summary(&options.git, worktree, remaining));
+
+    Some(SessionLine {
+        name: manifest.name,
+        branch: manifest.branch,
+        git,
+        test_status: status.as_ref().map(|status| status.test_status.clone()),
+        blocked: status.is_some_and(|status| status.is_blocked),
+    })
+}
+
+/// `PARA_STATE_DIR` when set, otherwise the state directory para recorded in
+/// the manifest
+fn state_dir(manifest: &SessionManifest) -> Option<PathBuf> {
+    crate::config::state_dir::state_dir_override()
+        .map(PathBuf::from)
+        .or_else(|| manifest.state_dir.clone())
+}
+
+/// Dirty state and distance to the upstream from one `git status`, given up
+/// after `timeout`
+fn git_summary(git: &OsString, worktree: &Path, timeout: Duration) -> Option<GitSummary> {
+    let mut child = Command::new(git)
+        .args([
+            "--no-optional-locks",
+            "status",
+            "--porcelain=v2",
+            "--branch",
+        ])
+        .current_dir(worktree)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .ok()?;
+
+    // Reading on another thread keeps a large status from filling the pipe
+    let mut stdout = child.stdout.take()?;
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let mut output = String::new();
+        let _ = sender.send(stdout.read_to_string(&mut output).map(|_| output));
+    });
+
+    match receiver.recv_timeout(timeout) {
+        Ok(Ok(output)) if child.wait().is_ok_and(|status| status.success()) => {
+            Some(parse_porcelain(&output))
+        }
+        Ok(_) => None,
+        Err(_) => {
+            let _ = child.kill();
+            let _ = child.wait();
+            None
+        }
+    }
+}
+
+/// Parse `git status --porcelain=v2 --branch`
+pub fn parse_porcelain(output: &str) -> GitSummary {
+    let mut summary = GitSummary::default();
+    for line in output.lines() {
+        if let Some(counts) = line.strip_prefix("# branch.ab ") {
+            for count in counts.split_whitespace() {
+                if let Some(ahead) = count.strip_prefix('+') {
+                    summary.ahead = ahead.parse().unwrap_or(0);
+                } else if let Some(behind) = count.strip_prefix('-') {
+                    summary.behind = behind.parse().unwrap_or(0);
+                }
+            }
+        } else if !line.starts_with('#') && !line.is_empty() {
+            summary.dirty = true;
+        }
+    }
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::session::SessionState;
+    use crate::test_utils::test_helpers::setup_test_repo;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn write_session(worktree: &Path, state_dir: &Path) {
+        let session = SessionState::new(
+            "auth".to_string(),
+            "para/auth".to_string(),
+            worktree.to_path_buf(),
+        );
+        manifest::write(&session, state_dir).unwrap();
+    }
+
+    #[cfg(unix)]
+    fn git_stub(dir: &Path, name: &str, script: &str) -> OsString {
+        use std::os::unix::fs::PermissionsExt;
+        let path = dir.join(name);
+        fs::write(&path, format!("#!/bin/sh\n{script}\n")).unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+        path.into_os_string()
+    }
+
+    #[test]
+    fn test_parse_porcelain() {
+        let clean = "# branch.oid abc\n# branch.head para/auth\n# branch.ab +2 -1\n";
+        assert_eq!(
+            parse_porcelain(clean),
+            GitSummary {
+                dirty: false,
+                ahead: 2,
+                behind: 1
+            }
+        );
+        let dirty = "# branch.head para/auth\n1 .M N... 100644 100644 100644 a b src/lib.rs\n";
+        assert_eq!(
+            parse_porcelain(dirty),
+            GitSummary {
+                dirty: true,
+                ahead: 0,
+                behind: 0
+            }
+        );
+        assert!(parse_porcelain("? notes.txt\n").dirty);
+    }
+
+    #[test]
+    fn test_collect_reads_manifest_status_and_git() {
+        let (repo, _git) = setup_test_repo();
+        let state = TempDir::new().unwrap();
+        write_session(repo.path(), state.path());
+        fs::write(repo.path().join("scratch.txt"), "wip").unwrap();
+        let mut status = Status::new(
+            "auth".to_string(),
+            "login form".to_string(),
+            TestStatus::Failed,
+        );
+        status.is_blocked = true;
+        status.save(state.path()).unwrap();
+
+        let options = StatuslineOptions {
+            budget: Duration::from_secs(10),
+            ..Default::default()
+        };
+        let line = collect(&repo.path().join(".para"), &options).unwrap();
+        assert_eq!(line.name, "auth");
+        assert_eq!(line.branch, "para/auth");
+        assert!(line.git.unwrap().dirty);
+        assert_eq!(line.test_status, Some(TestStatus::Failed));
+        assert!(line.blocked);
+
+        assert!(collect(state.path(), &options).is_none());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_slow_git_is_skipped() {
+        let temp = TempDir::new().unwrap();
+        let worktree = temp.path().join("auth");
+        fs::create_dir_all(&worktree).unwrap();
+        write_session(&worktree, &temp.path().join("state"));
+
+        let slow = git_stub(temp.path(), "slow-git", "sleep 2\necho '? late.txt'");
+        let started = Instant::now();
+        let line = collect(
+            &worktree,
+            &StatuslineOptions {
+                git: slow,
+                budget: Duration::from_millis(100),
+            },
+        )
+        .unwrap();
+        assert!(started.elapsed() < Duration::from_secs(1));
+        assert_eq!(line.name, "auth");
+        assert_eq!(line.git, None);
+        assert_eq!(line.test_status, None);
+
+        let fast = git_stub(temp.path(), "fast-git", "echo '# branch.ab +3 -0'");
+        let line = collect(
+            &worktree,
+            &StatuslineOptions {
+                git: fast,
+                budget: Duration::from_secs(10),
+            },
+        )
+        .unwrap();
+        assert_eq!(line.git.unwrap().ahead, 3);
+    }
+}
@@ -0,0 +1,335 @@
+//! Trash for items `para clean` removes
+//!
+//! Orphaned state files are moved to `<state_dir>/trash/<timestamp>/` and old
+//! archive branches are renamed to `<prefix>/trash/<timestamp>/<branch>`
+//! instead of being deleted. A later clean deletes trash older than
+//! `cleanup.trash_retention_days`; until then `para restore-trash` puts an
+//! item back where it came from.
+
+use crate::core::git::repository::execute_git_command_with_status;
+use crate::core::git::{GitOperations, GitService};
+use crate::utils::{ParaError, Result};
+use chrono::{Duration, NaiveDateTime};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Days trash is kept when `cleanup.trash_retention_days` is not set
+pub const DEFAULT_RETENTION_DAYS: u32 = 7;
+
+/// Directory inside the state directory that holds trashed files
+pub const TRASH_DIR: &str = "trash";
+
+const TIMESTAMP_FORMAT: &str = "%Y%m%d-%H%M%S";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TrashLocation {
+    /// File inside `<state_dir>/trash/<timestamp>/`
+    File(PathBuf),
+    /// Branch under `<prefix>/trash/<timestamp>/`
+    Branch(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrashEntry {
+    pub trashed_at: NaiveDateTime,
+    /// File name in the state directory, or the branch name
+    pub original: String,
+    pub location: TrashLocation,
+}
+
+impl TrashEntry {
+    /// `<timestamp>/<original>`, unique across the trash
+    pub fn id(&self) -> String {
+        format!(
+            "{}/{}",
+            self.trashed_at.format(TIMESTAMP_FORMAT),
+            self.original
+        )
+    }
+
+    pub fn kind_label(&self) -> &'static str {
+        match self.location {
+            TrashLocation::File(_) => "state file",
+            TrashLocation::Branch(_) => "branch",
+        }
+    }
+
+    /// Session the item belonged to
+    pub fn session(&self) -> &str {
+        match self.location {
+            TrashLocation::File(_) => self.original.split('.').next().unwrap_or(&self.original),
+            TrashLocation::Branch(_) => self.original.rsplit('/').next().unwrap_or(&self.original),
+        }
+    }
+
+    pub fn expires_at(&self, retention_days: u32) -> NaiveDateTime {
+        self.trashed_at + Duration::days(retention_days as i64)
+    }
+
+    pub fn is_expired(&self, now: NaiveDateTime, retention_days: u32) -> bool {
+        self.expires_at(retention_days) <= now
+    }
+}
+
+pub fn trash_dir(state_dir: &Path) -> PathBuf {
+    state_dir.join(TRASH_DIR)
+}
+
+fn branch_prefix(prefix: &str) -> String {
+    format!("{prefix}/trash/")
+}
+
+/// Move `file` from the state directory into the trash
+pub fn trash_file(state_dir: &Path, file: &Path, now: NaiveDateTime) -> Result<TrashEntry> {
+    let name = file
+        .file_name()
+        .and_then(|name| name.to_str())
+        .ok_or_else(|| ParaError::invalid_args(format!("Invalid file name: {}", file.display())))?
+        .to_string();
+    let dir = trash_dir(state_dir).join(now.format(TIMESTAMP_FORMAT).to_string());
+    fs::create_dir_all(&dir).map_err(|e| ParaError::from_io("create", &dir, e))?;
+    let destination = dir.join(&name);
+    fs::rename(file, &destination).map_err(|e| ParaError::from_io("move", file, e))?;
+
+    Ok(TrashEntry {
+        trashed_at: now,
+        original: name,
+        location: TrashLocation::File(destination),
+    })
+}
+
+/// Rename `branch` into the trash namespace
+pub fn trash_branch(
+    git_service: &GitService,
+    prefix: &str,
+    branch: &str,
+    now: NaiveDateTime,
+) -> Result<TrashEntry> {
+    let trashed = format!(
+        "{}{}/{branch}",
+        branch_prefix(prefix),
+        now.format(TIMESTAMP_FORMAT)
+    );
+    execute_git_command_with_status(
+        git_service.repository(),
+        &["branch", "-m", branch, &trashed],
+    )?;
+
+    Ok(TrashEntry {
+        trashed_at: now,
+        original: branch.to_string(),
+        location: TrashLocation::Branch(trashed),
+    })
+}
+
+/// Everything in the trash, oldest first
+pub fn list(state_dir: &Path, git_service: &GitService, prefix: &str) -> Result<Vec<TrashEntry>> {
+    let mut entries = Vec::new();
+
+    let root = trash_dir(state_dir);
+    if root.is_dir() {
+        for batch in fs::read_dir(&root).map_err(|e| ParaError::from_io("read", &root, e))? {
+            let batch = batch?.path();
+            let Some(trashed_at) = batch
+                .file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| NaiveDateTime::parse_from_str(name, TIMESTAMP_FORMAT).ok())
+            else {
+                continue;
+            };
+            if !batch.is_dir() {
+                continue;
+            }
+            for file in fs::read_dir(&batch).map_err(|e| ParaError::from_io("read", &batch, e))? {
+                let file = file?.path();
+                if let Some(name) = file.file_name().and_then(|name| name.to_str()) {
+                    entries.push(TrashEntry {
+                        trashed_at,
+                        original: name.to_string(),
+                        location: TrashLocation::File(file.clone()),
+                    });
+                }
+            }
+        }
+    }
+
+    let trash_prefix = branch_prefix(prefix);
+    for branch in git_service.branch_manager().list_branches()? {
+        let Some((timestamp, original)) = branch
+            .name
+            .strip_prefix(&trash_prefix)
+            .and_then(|rest| rest.split_once('/'))
+        else {
+            continue;
+        };
+        let Ok(trashed_at) = NaiveDateTime::parse_from_str(timestamp, TIMESTAMP_FORMAT) else {
+            continue;
+        };
+        entries.push(TrashEntry {
+            trashed_at,
+            original: original.to_string(),
+            location: TrashLocation::Branch(branch.name.clone()),
+        });
+    }
+
+    entries.sort_by(|a, b| (a.trashed_at, &a.original).cmp(&(b.trashed_at, &b.original)));
+    Ok(entries)
+}
+
+/// Delete an item for good
+pub fn purge(entry: &TrashEntry, git_service: &GitService) -> Result<()> {
+    match &entry.location {
+        TrashLocation::File(path) => {
+            fs::remove_file(path).map_err(|e| ParaError::from_io("remove", path, e))?;
+            remove_empty_batch(path);
+            Ok(())
+        }
+        TrashLocation::Branch(branch) => git_service.delete_branch(branch, true),
+    }
+}
+
+/// Put an item back where it was trashed from; returns that place
+pub fn restore(entry: &TrashEntry, state_dir: &Path, git_service: &GitService) -> Result<String> {
+    match &entry.location {
+        TrashLocation::File(path) => {
+            let destination = state_dir.join(&entry.original);
+            if destination.exists() {
+                return Err(ParaError::invalid_args(format!(
+                    "Cannot restore {}: {} already exists",
+                    entry.id(),
+                    destination.display()
+                )));
+            }
+            fs::rename(path, &destination).map_err(|e| ParaError::from_io("move", path, e))?;
+            remove_empty_batch(path);
+            Ok(destination.display().to_string())
+        }
+        TrashLocation::Branch(branch) => {
+            if git_service.branch_exists(&entry.original)? {
+                return Err(ParaError::invalid_args(format!(
+                    "Cannot restore {}: branch '{}' already exists",
+                    entry.id(),
+                    entry.original
+                )));
+            }
+            execute_git_command_with_status(
+                git_service.repository(),
+                &["branch", "-m", branch, &entry.original],
+            )?;
+            Ok(entry.original.clone())
+        }
+    }
+}
+
+/// The `<timestamp>` directory goes once its last file has
+fn remove_empty_batch(path: &Path) {
+    if let Some(batch) = path.parent() {
+        let _ = fs::remove_dir(batch);
+    }
+}
+
+/// Entries `item` refers to: an entry id, or the original name or session of
+/// items trashed by the same clean
+pub fn select<'a>(entries: &'a [TrashEntry], item: &str) -> Result<Vec<&'a TrashEntry>> {
+    if let Some(entry) = entries.iter().find(|entry| entry.id() == item) {
+        return Ok(vec![entry]);
+    }
+
+    let matches: Vec<&TrashEntry> = entries
+        .iter()
+        .filter(|entry| entry.original == item || entry.session() == item)
+        .collect();
+    let Some(first) = matches.first() else {
+        return Err(ParaError::invalid_args(format!(
+            "Nothing named '{item}' in the trash. Run 'para clean --list-trash' to see its contents"
+        )));
+    };
+    if matches
+        .iter()
+        .any(|entry| entry.trashed_at != first.trashed_at)
+    {
+        let ids: Vec<String> = matches.iter().map(|entry| entry.id()).collect();
+        return Err(ParaError::invalid_args(format!(
+            "'{item}' was trashed more than once; restore one of: {}",
+            ids.join(", ")
+        )));
+    }
+    Ok(matches)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::test_helpers::*;
+    use tempfile::TempDir;
+
+    fn at(timestamp: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(timestamp, TIMESTAMP_FORMAT).unwrap()
+    }
+
+    #[test]
+    fn test_trash_and_restore_round_trip() {
+        let state_dir = TempDir::new().unwrap();
+        let (_git_temp, git_service) = setup_test_repo();
+        let main = git_service.repository().get_current_branch().unwrap();
+        let archived = "para/archived/20240101-120000/auth";
+        git_service.create_branch(archived, &main).unwrap();
+        let state_file = state_dir.path().join("auth.state");
+        fs::write(&state_file, "{}").unwrap();
+
+        let now = at("20240301-090000");
+        trash_file(state_dir.path(), &state_file, now).unwrap();
+        trash_branch(&git_service, "para", archived, now).unwrap();
+        assert!(!state_file.exists());
+        assert!(!git_service.branch_exists(archived).unwrap());
+
+        let entries = list(state_dir.path(), &git_service, "para").unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].id(), "20240301-090000/auth.state");
+        assert_eq!(
+            entries[1].location,
+            TrashLocation::Branch(format!("para/trash/20240301-090000/{archived}"))
+        );
+        assert!(entries.iter().all(|entry| entry.session() == "auth"));
+        assert!(!entries[0].is_expired(at("20240307-090000"), 7));
+        assert!(entries[0].is_expired(at("20240308-090000"), 7));
+
+        let selected = select(&entries, "auth").unwrap();
+        assert_eq!(selected.len(), 2);
+        for entry in selected {
+            restore(entry, state_dir.path(), &git_service).unwrap();
+        }
+        assert!(state_file.exists());
+        assert!(git_service.branch_exists(archived).unwrap());
+        assert!(list(state_dir.path(), &git_service, "para")
+            .unwrap()
+            .is_empty());
+        assert!(!trash_dir(state_dir.path()).join("20240301-090000").exists());
+    }
+
+    #[test]
+    fn test_select_needs_an_id_when_trashed_twice() {
+        let state_dir = TempDir::new().unwrap();
+        let (_git_temp, git_service) = setup_test_repo();
+        for timestamp in ["20240301-090000", "20240302-090000"] {
+            let file = state_dir.path().join("auth.state");
+            fs::write(&file, "{}").unwrap();
+            trash_file(state_dir.path(), &file, at(timestamp)).unwrap();
+        }
+        let entries = list(state_dir.path(), &git_service, "para").unwrap();
+
+        let error = select(&entries, "auth").unwrap_err().to_string();
+        assert!(error.contains("20240301-090000/auth.state"));
+        assert_eq!(
+            select(&entries, "20240302-090000/auth.state").unwrap()[0].trashed_at,
+            at("20240302-090000")
+        );
+        assert!(select(&entries, "other").is_err());
+
+        purge(&entries[0], &git_service).unwrap();
+        assert_eq!(
+            list(state_dir.path(), &git_service, "para").unwrap().len(),
+            1
+        );
+    }
+}
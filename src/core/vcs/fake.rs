@@ -0,0 +1,360 @@
+//! In-memory backend for unit tests of commands
+//!
+//! Branches are lists of commit messages and worktrees are directories the
+//! backend creates and removes itself; nothing runs git. Only what commands
+//! rely on is modelled: a finish squashes everything since the parent branch
+//! into one commit, archiving renames the branch.
+
+use super::VcsBackend;
+use crate::core::git::{
+    ArchiveRef, BranchInfo, FinishRequest, FinishResult, GitOperations, GitService, MergeMode,
+    SessionEnvironment, WorktreeInfo, WorktreeStatus,
+};
+use crate::utils::{ParaError, Result};
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub struct FakeVcsBackend {
+    root: PathBuf,
+    state: RefCell<FakeRepo>,
+}
+
+struct FakeRepo {
+    /// Commit messages of each branch, oldest first
+    branches: BTreeMap<String, Vec<String>>,
+    current: String,
+    /// Linked worktrees; the main checkout is not listed
+    worktrees: Vec<WorktreeInfo>,
+    /// Uncommitted changes by worktree; clean when missing
+    statuses: HashMap<PathBuf, WorktreeStatus>,
+}
+
+impl FakeVcsBackend {
+    /// A repository at `root` with `main` checked out and one commit
+    pub fn new(root: &Path) -> Self {
+        let mut branches = BTreeMap::new();
+        branches.insert("main".to_string(), vec!["Initial commit".to_string()]);
+        Self {
+            root: root.to_path_buf(),
+            state: RefCell::new(FakeRepo {
+                branches,
+                current: "main".to_string(),
+                worktrees: Vec::new(),
+                statuses: HashMap::new(),
+            }),
+        }
+    }
+
+    /// Pretend the worktree at `path` has these uncommitted changes
+    pub fn set_status(&self, path: &Path, status: WorktreeStatus) {
+        self.state
+            .borrow_mut()
+            .statuses
+            .insert(path.to_path_buf(), status);
+    }
+
+    fn root_status(&self) -> WorktreeStatus {
+        self.worktree_status(&self.root).unwrap_or_default()
+    }
+
+    fn update_root_status(&self, update: impl FnOnce(&mut WorktreeStatus)) {
+        let mut state = self.state.borrow_mut();
+        update(state.statuses.entry(self.root.clone()).or_default());
+    }
+
+    /// Append a commit to `branch`
+    pub fn add_commit(&self, branch: &str, message: &str) {
+        self.state
+            .borrow_mut()
+            .branches
+            .entry(branch.to_string())
+            .or_default()
+            .push(message.to_string());
+    }
+
+    pub fn commits(&self, branch: &str) -> Option<Vec<String>> {
+        self.state.borrow().branches.get(branch).cloned()
+    }
+
+    pub fn branch_names(&self) -> Vec<String> {
+        self.state.borrow().branches.keys().cloned().collect()
+    }
+
+    fn rename_branch(&self, from: &str, to: &str) -> Result<()> {
+        let mut state = self.state.borrow_mut();
+        if state.branches.contains_key(to) {
+            return Err(ParaError::git_operation(format!(
+                "Branch '{to}' already exists"
+            )));
+        }
+        let commits = state
+            .branches
+            .remove(from)
+            .ok_or_else(|| ParaError::git_operation(format!("Branch '{from}' does not exist")))?;
+        state.branches.insert(to.to_string(), commits);
+        for worktree in state.worktrees.iter_mut().filter(|w| w.branch == from) {
+            worktree.branch = to.to_string();
+        }
+        if state.current == from {
+            state.current = to.to_string();
+        }
+        Ok(())
+    }
+}
+
+impl GitOperations for FakeVcsBackend {
+    fn create_worktree(&self, branch: &str, path: &Path) -> Result<PathBuf> {
+        if !self.branch_exists(branch)? {
+            let current = self.state.borrow().current.clone();
+            self.create_branch(branch, &current)?;
+        }
+        fs::create_dir_all(path).map_err(|e| ParaError::from_io("create", path, e))?;
+        self.state.borrow_mut().worktrees.push(WorktreeInfo {
+            path: path.to_path_buf(),
+            branch: branch.to_string(),
+            commit: String::new(),
+            is_bare: false,
+        });
+        Ok(path.to_path_buf())
+    }
+
+    fn remove_worktree(&self, path: &Path) -> Result<()> {
+        let mut state = self.state.borrow_mut();
+        let index = state
+            .worktrees
+            .iter()
+            .position(|w| w.path == path)
+            .ok_or_else(|| {
+                ParaError::worktree_operation(format!("'{}' is not a worktree", path.display()))
+            })?;
+        state.worktrees.remove(index);
+        if path.exists() {
+            fs::remove_dir_all(path).map_err(|e| ParaError::from_io("remove", path, e))?;
+        }
+        Ok(())
+    }
+
+    fn finish_session(&self, request: FinishRequest) -> Result<FinishResult> {
+        if !self.branch_exists(&request.feature_branch)? {
+            return Err(ParaError::git_operation(format!(
+                "Branch '{}' does not exist",
+                request.feature_branch
+            )));
+        }
+        if request.stage_all {
+            self.stage_all_changes()?;
+        }
+
+        let has_staged = self.root_status().staged > 0;
+        self.update_root_status(|status| status.staged = 0);
+        {
+            let mut state = self.state.borrow_mut();
+            let base = request
+                .parent_branch
+                .as_ref()
+                .and_then(|parent| state.branches.get(parent).cloned())
+                .unwrap_or_default();
+            let commits = state.branches.get_mut(&request.feature_branch).unwrap();
+            match request.merge_mode {
+                MergeMode::Squash => {
+                    if has_staged || commits.len() > base.len() {
+                        *commits = base;
+                        commits.push(request.commit_message.clone());
+                    }
+                }
+                MergeMode::PreserveCommits | MergeMode::Rebase => {
+                    if has_staged {
+                        commits.push(request.commit_message.clone());
+                    }
+                }
+            }
+        }
+
+        let final_branch = match request.target_branch_name {
+            Some(target) if target != request.feature_branch => {
+                self.rename_branch(&request.feature_branch, &target)?;
+                target
+            }
+            _ => request.feature_branch,
+        };
+        Ok(FinishResult::Success { final_branch })
+    }
+
+    fn list_worktrees(&self) -> Result<Vec<WorktreeInfo>> {
+        let state = self.state.borrow();
+        let main = WorktreeInfo {
+            path: self.root.clone(),
+            branch: state.current.clone(),
+            commit: String::new(),
+            is_bare: false,
+        };
+        Ok(std::iter::once(main)
+            .chain(state.worktrees.iter().cloned())
+            .collect())
+    }
+
+    fn list_branches(&self) -> Result<Vec<BranchInfo>> {
+        Ok(self
+            .branch_names()
+            .into_iter()
+            .map(|name| BranchInfo { name })
+            .collect())
+    }
+
+    fn create_branch(&self, name: &str, base: &str) -> Result<()> {
+        let mut state = self.state.borrow_mut();
+        if state.branches.contains_key(name) {
+            return Err(ParaError::git_operation(format!(
+                "Branch '{name}' already exists"
+            )));
+        }
+        let commits =
+            state.branches.get(base).cloned().ok_or_else(|| {
+                ParaError::git_operation(format!("Branch '{base}' does not exist"))
+            })?;
+        state.branches.insert(name.to_string(), commits);
+        Ok(())
+    }
+
+    fn delete_branch(&self, name: &str, _force: bool) -> Result<()> {
+        let mut state = self.state.borrow_mut();
+        if state.current == name || state.worktrees.iter().any(|w| w.branch == name) {
+            return Err(ParaError::git_operation(format!(
+                "Branch '{name}' is checked out"
+            )));
+        }
+        state
+            .branches
+            .remove(name)
+            .map(|_| ())
+            .ok_or_else(|| ParaError::git_operation(format!("Branch '{name}' does not exist")))
+    }
+
+    fn branch_exists(&self, name: &str) -> Result<bool> {
+        Ok(self.state.borrow().branches.contains_key(name))
+    }
+
+    fn has_uncommitted_changes(&self) -> Result<bool> {
+        Ok(!self.root_status().is_clean())
+    }
+
+    fn is_clean_working_tree(&self) -> Result<bool> {
+        Ok(self.root_status().is_clean())
+    }
+
+    fn stage_all_changes(&self) -> Result<()> {
+        self.update_root_status(|status| {
+            status.staged += status.modified + status.untracked;
+            status.modified = 0;
+            status.untracked = 0;
+        });
+        Ok(())
+    }
+
+    fn archive_branch(&self, branch: &str, prefix: &str) -> Result<String> {
+        self.archive_session_branch(branch, branch, prefix, None)
+            .map(|archive| archive.branch)
+    }
+
+    fn archive_session_branch_dry_run(
+        &self,
+        branch: &str,
+        session_name: &str,
+        prefix: &str,
+        _session_worktree: Option<&Path>,
+    ) -> Result<ArchiveRef> {
+        if !self.branch_exists(branch)? {
+            return Err(ParaError::git_operation(format!(
+                "Branch '{branch}' does not exist"
+            )));
+        }
+        let timestamp = chrono::Utc::now().format("%Y%m%d-%H%M%S").to_string();
+        Ok(ArchiveRef::new(prefix, &timestamp, session_name))
+    }
+
+    fn archive_session_branch(
+        &self,
+        branch: &str,
+        session_name: &str,
+        prefix: &str,
+        session_worktree: Option<&Path>,
+    ) -> Result<ArchiveRef> {
+        let archive =
+            self.archive_session_branch_dry_run(branch, session_name, prefix, session_worktree)?;
+        self.rename_branch(branch, &archive.branch)?;
+        Ok(archive)
+    }
+
+    fn restore_archived_branch(
+        &self,
+        archive: &ArchiveRef,
+        new_name: Option<&str>,
+        _session_worktree: Option<&Path>,
+    ) -> Result<String> {
+        let restored = new_name.unwrap_or(&archive.session).to_string();
+        self.rename_branch(&archive.branch, &restored)?;
+        Ok(restored)
+    }
+
+    fn cleanup_stale_worktrees(&self) -> Result<Vec<PathBuf>> {
+        let mut state = self.state.borrow_mut();
+        let (stale, kept): (Vec<_>, Vec<_>) = state
+            .worktrees
+            .drain(..)
+            .partition(|worktree| !worktree.path.exists());
+        state.worktrees = kept;
+        Ok(stale.into_iter().map(|worktree| worktree.path).collect())
+    }
+}
+
+impl VcsBackend for FakeVcsBackend {
+    fn root(&self) -> &Path {
+        &self.root
+    }
+
+    fn current_branch(&self) -> Result<String> {
+        Ok(self.state.borrow().current.clone())
+    }
+
+    fn commit(&self, message: &str) -> Result<()> {
+        if self.root_status().staged == 0 {
+            return Err(ParaError::git_operation("Nothing to commit"));
+        }
+        self.update_root_status(|status| status.staged = 0);
+        let current = self.current_branch()?;
+        self.add_commit(&current, message);
+        Ok(())
+    }
+
+    fn worktree_status(&self, path: &Path) -> Result<WorktreeStatus> {
+        Ok(self
+            .state
+            .borrow()
+            .statuses
+            .get(path)
+            .copied()
+            .unwrap_or_default())
+    }
+
+    fn session_environment(&self, path: &Path) -> Result<SessionEnvironment> {
+        if path == self.root {
+            return Ok(SessionEnvironment::MainRepository);
+        }
+        Ok(self
+            .state
+            .borrow()
+            .worktrees
+            .iter()
+            .find(|worktree| path.starts_with(&worktree.path))
+            .map(|worktree| SessionEnvironment::Worktree {
+                branch: worktree.branch.clone(),
+            })
+            .unwrap_or(SessionEnvironment::Invalid))
+    }
+
+    fn git(&self) -> Option<&GitService> {
+        None
+    }
+}
@@ -0,0 +1,35 @@
+//! The git backend, backed by [`GitService`]
+
+use super::VcsBackend;
+use crate::core::git::{GitRepository, GitService, SessionEnvironment, WorktreeStatus};
+use crate::utils::Result;
+use std::path::Path;
+
+impl VcsBackend for GitService {
+    fn root(&self) -> &Path {
+        &self.repository().root
+    }
+
+    fn current_branch(&self) -> Result<String> {
+        self.repository().get_current_branch()
+    }
+
+    fn commit(&self, message: &str) -> Result<()> {
+        self.repository().commit(message)
+    }
+
+    fn worktree_status(&self, path: &Path) -> Result<WorktreeStatus> {
+        if path == self.repository().root {
+            return self.repository().worktree_status();
+        }
+        GitRepository::discover_from(path)?.worktree_status()
+    }
+
+    fn session_environment(&self, path: &Path) -> Result<SessionEnvironment> {
+        self.validate_session_environment(path)
+    }
+
+    fn git(&self) -> Option<&GitService> {
+        Some(self)
+    }
+}
@@ -0,0 +1,103 @@
+//! Version control backend behind para's commands
+//!
+//! Commands that only need worktrees, branches, commits and status go
+//! through [`VcsBackend`] instead of naming git. Git is the only backend for
+//! now and is selected with `vcs.backend = "git"`; the seam is there so a
+//! jj or sapling backend can be added next to it. Features that have no
+//! backend-neutral form yet reach git through [`VcsBackend::git`].
+
+use crate::config::Config;
+use crate::core::git::{GitOperations, GitService, SessionEnvironment, WorktreeStatus};
+use crate::utils::{ParaError, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::Path;
+
+#[cfg(test)]
+pub mod fake;
+mod git;
+
+/// Backend named by `vcs.backend`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VcsKind {
+    #[default]
+    Git,
+}
+
+impl fmt::Display for VcsKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VcsKind::Git => write!(f, "git"),
+        }
+    }
+}
+
+/// Repository operations commands need, on top of [`GitOperations`]
+pub trait VcsBackend: GitOperations {
+    /// Root of the main checkout
+    fn root(&self) -> &Path;
+    fn current_branch(&self) -> Result<String>;
+    /// Commit what is staged in the main checkout
+    fn commit(&self, message: &str) -> Result<()>;
+    /// Modified, staged, untracked and conflicted paths in the worktree at `path`
+    fn worktree_status(&self, path: &Path) -> Result<WorktreeStatus>;
+    /// Whether `path` is the main checkout, a session worktree or neither
+    fn session_environment(&self, path: &Path) -> Result<SessionEnvironment>;
+    /// The git service, when this backend is git
+    fn git(&self) -> Option<&GitService>;
+}
+
+/// Backend for the repository containing the current directory
+pub fn discover(config: &Config) -> Result<Box<dyn VcsBackend>> {
+    let current_dir = std::env::current_dir()
+        .map_err(|e| ParaError::file_operation(format!("Failed to get current directory: {e}")))?;
+    discover_from(config, &current_dir)
+}
+
+/// Backend for the repository containing `path`
+pub fn discover_from(config: &Config, path: &Path) -> Result<Box<dyn VcsBackend>> {
+    match config.get_vcs_backend() {
+        VcsKind::Git => Ok(Box::new(GitService::discover_from(path)?)),
+    }
+}
+
+/// The git service behind `vcs`, or an error naming the `feature` that needs it
+pub fn require_git<'a>(vcs: &'a dyn VcsBackend, feature: &str) -> Result<&'a GitService> {
+    vcs.git().ok_or_else(|| {
+        ParaError::invalid_args(format!(
+            "{feature} is only supported with the git backend (vcs.backend = \"git\")"
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::test_helpers::*;
+
+    #[test]
+    fn test_git_backend_is_the_default() {
+        let (_git_temp, git_service) = setup_test_repo();
+        let config = create_test_config();
+        assert_eq!(config.get_vcs_backend(), VcsKind::Git);
+
+        let root = git_service.repository().root.clone();
+        let vcs = discover_from(&config, &root).unwrap();
+        assert_eq!(vcs.root(), root.as_path());
+        assert_eq!(vcs.current_branch().unwrap(), "main");
+        assert!(matches!(
+            vcs.session_environment(&root).unwrap(),
+            SessionEnvironment::MainRepository
+        ));
+        assert!(require_git(vcs.as_ref(), "--check").is_ok());
+    }
+
+    #[test]
+    fn test_backend_config_value() {
+        let kind: VcsKind = serde_json::from_str("\"git\"").unwrap();
+        assert_eq!(kind, VcsKind::Git);
+        assert_eq!(kind.to_string(), "git");
+        assert!(serde_json::from_str::<VcsKind>("\"svn\"").is_err());
+    }
+}
@@ -0,0 +1,133 @@
+//! Which para release wrote a session, the config and the daemon
+//!
+//! Session state files record the release that created or last migrated them
+//! as `para_version`; the user config and the daemon PID file record the
+//! release that last wrote them. After a downgrade, a session written by a
+//! newer para may hold settings this binary does not know about and would
+//! drop when saving it. Commands warn about such sessions, and commands that
+//! change a session refuse to unless `--ignore-version` is given.
+//! `para doctor` reports every version that differs from the running one.
+
+use crate::core::self_update::is_newer;
+use crate::core::session::SessionState;
+use crate::utils::{ParaError, Result};
+
+/// Release of the running binary
+pub const PARA_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Set by `--ignore-version` so that every command sees it
+pub const IGNORE_VERSION_ENV: &str = "PARA_IGNORE_VERSION";
+
+/// Make `--ignore-version` visible here and in child processes
+pub fn export_ignore_version() {
+    std::env::set_var(IGNORE_VERSION_ENV, "1");
+}
+
+fn ignores_version() -> bool {
+    std::env::var(IGNORE_VERSION_ENV).is_ok_and(|value| value == "1")
+}
+
+/// Whether `written_by` is a newer release than the running para
+pub fn is_newer_than_running(written_by: &str) -> bool {
+    is_newer(written_by, PARA_VERSION)
+}
+
+/// Warn when `session` was written by a newer para. A `mutating` command
+/// fails instead, unless `--ignore-version` was given.
+pub fn check_session(session: &SessionState, mutating: bool) -> Result<()> {
+    check_session_with(session, mutating, ignores_version())
+}
+
+fn check_session_with(session: &SessionState, mutating: bool, ignore: bool) -> Result<()> {
+    let written_by = &session.para_version;
+    if !is_newer_than_running(written_by) {
+        return Ok(());
+    }
+    if mutating && !ignore {
+        return Err(ParaError::invalid_args(format!(
+            "Session '{}' was written by para {written_by}, which is newer than this para {PARA_VERSION}. Upgrade para, or pass --ignore-version to change it anyway",
+            session.name
+        )));
+    }
+    eprintln!(
+        "Warning: Session '{}' was written by para {written_by}, which is newer than this para {PARA_VERSION}; settings it does not know about may be lost",
+        session.name
+    );
+    Ok(())
+}
+
+/// Versions `para doctor` reports: a daemon running another release than
+/// the CLI, and a config or sessions written by a newer one
+pub fn version_skew(
+    daemon: Option<&str>,
+    config: Option<&str>,
+    sessions: &[SessionState],
+) -> Vec<String> {
+    let mut problems = Vec::new();
+    if let Some(daemon) = daemon.filter(|daemon| *daemon != PARA_VERSION) {
+        problems.push(format!(
+            "The daemon runs para {daemon} but this is para {PARA_VERSION}; restart it with 'para daemon stop'"
+        ));
+    }
+    if let Some(config) = config.filter(|config| is_newer_than_running(config)) {
+        problems.push(format!(
+            "The config was saved by para {config}, which is newer than this para {PARA_VERSION}"
+        ));
+    }
+    for session in sessions {
+        if is_newer_than_running(&session.para_version) {
+            problems.push(format!(
+                "Session '{}' was written by para {}, which is newer than this para {PARA_VERSION}",
+                session.name, session.para_version
+            ));
+        }
+    }
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn session_written_by(version: &str) -> SessionState {
+        let mut session = SessionState::new(
+            "auth".to_string(),
+            "para/auth".to_string(),
+            PathBuf::from("/tmp/auth"),
+        );
+        session.para_version = version.to_string();
+        session
+    }
+
+    #[test]
+    fn test_newer_session_warns_and_refuses_changes() {
+        assert!(check_session_with(&session_written_by("999.0.0"), false, false).is_ok());
+
+        let error = check_session_with(&session_written_by("999.0.0"), true, false).unwrap_err();
+        assert!(error.to_string().contains("para 999.0.0"));
+        assert!(error.to_string().contains("--ignore-version"));
+        assert!(check_session_with(&session_written_by("999.0.0"), true, true).is_ok());
+
+        // Older and unrecorded versions are fine
+        assert!(check_session_with(&session_written_by("0.0.1"), true, false).is_ok());
+        assert!(check_session_with(&session_written_by(""), true, false).is_ok());
+        assert!(check_session_with(&session_written_by(PARA_VERSION), true, false).is_ok());
+    }
+
+    #[test]
+    fn test_version_skew() {
+        assert!(version_skew(Some(PARA_VERSION), Some("0.0.1"), &[]).is_empty());
+        assert!(version_skew(None, None, &[session_written_by("0.0.1")]).is_empty());
+
+        let problems = version_skew(
+            Some("0.0.1"),
+            Some("999.0.0"),
+            &[session_written_by("999.1.0"), session_written_by("")],
+        );
+        assert_eq!(problems.len(), 3);
+        assert!(problems[0].contains("daemon runs para 0.0.1"));
+        assert!(problems[1].contains("config was saved by para 999.0.0"));
+        assert!(problems[2].contains("Session 'auth' was written by para 999.1.0"));
+    }
+}
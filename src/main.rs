@@ -1,15 +1,6 @@
-mod cli;
-mod config;
-mod core;
-mod platform;
-mod ui;
-mod utils;
-
-#[cfg(test)]
-mod test_utils;
-
 use clap::Parser;
-use cli::{execute_command, Cli};
+use para::cli::{execute_command, Cli};
+use para::core::interrupt::CancelToken;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
@@ -25,11 +16,18 @@ fn setup_cleanup_handler() {
 
         ctrlc::set_handler(move || {
             if cleanup_flag_clone.load(Ordering::SeqCst) {
-                // Already cleaning up, force exit
+                // Already cleaning up or undoing a step, force exit
                 std::process::exit(1);
             }
             cleanup_flag_clone.store(true, Ordering::SeqCst);
+            let token = CancelToken::global();
+            token.cancel();
             cleanup_docker_containers();
+            if token.in_progress() {
+                // The main thread undoes or completes the step and exits
+                eprintln!("\nInterrupted, stopping safely (press Ctrl-C again to force quit)");
+                return;
+            }
             std::process::exit(0);
         })
         .expect("Error setting Ctrl-C handler");
@@ -61,7 +59,8 @@ fn main() {
     let cli = Cli::parse();
 
     if let Err(e) = execute_command(cli) {
+        para::utils::ci::annotate_error(&e);
         eprintln!("para: {e}");
-        std::process::exit(1);
+        std::process::exit(e.exit_code());
     }
 }
@@ -1,6 +1,14 @@
+//! Closing and finding IDE windows on macOS through AppleScript
+//!
+//! Session names end up in window titles, so they are never interpolated into
+//! script text: every value taken from a session is handed to `osascript` as
+//! an argument and read in the script as `item N of argv`. The only values
+//! written into scripts are fixed application names, and those still go
+//! through [`applescript_string`].
+
 use super::PlatformManager;
 use crate::platform::launch_file_parser::parse_ide_from_launch_contents;
-use crate::utils::Result;
+use crate::utils::{ParaError, Result};
 use std::process::Command;
 
 pub struct MacOSPlatform;
@@ -12,7 +20,58 @@ pub struct SessionInfo {
 
 pub trait IdeHandler {
     fn close_window(&self, session_info: &SessionInfo) -> Result<()>;
-    fn generate_applescript(&self, session_info: &SessionInfo) -> String;
+    fn close_window_invocation(&self, session_info: &SessionInfo) -> Result<OsascriptInvocation>;
+}
+
+/// One `osascript` run: a script and the values it reads as `item N of argv`
+#[derive(Debug, Clone, PartialEq)]
+pub struct OsascriptInvocation {
+    pub script: String,
+    pub argv: Vec<String>,
+}
+
+impl OsascriptInvocation {
+    /// Fails for values that cannot be passed as an argument
+    pub fn new(script: String, argv: Vec<String>) -> Result<Self> {
+        if let Some(value) = argv.iter().find(|value| value.contains('\0')) {
+            return Err(ParaError::ide_error(format!(
+                "Cannot pass {value:?} to AppleScript: it contains a NUL byte"
+            )));
+        }
+        Ok(Self { script, argv })
+    }
+
+    /// Arguments of `osascript`; the values follow the script, so AppleScript
+    /// receives them as data and never parses them
+    pub fn command_args(&self) -> Vec<&str> {
+        let mut args = vec!["-e", self.script.as_str()];
+        args.extend(self.argv.iter().map(String::as_str));
+        args
+    }
+
+    fn command(&self) -> Command {
+        let mut command = Command::new("osascript");
+        command.args(self.command_args());
+        command
+    }
+}
+
+/// `value` as an AppleScript string literal, quotes included
+pub fn applescript_string(value: &str) -> String {
+    let mut literal = String::with_capacity(value.len() + 2);
+    literal.push('"');
+    for c in value.chars() {
+        match c {
+            '\\' => literal.push_str("\\\\"),
+            '"' => literal.push_str("\\\""),
+            '\n' => literal.push_str("\\n"),
+            '\r' => literal.push_str("\\r"),
+            '\t' => literal.push_str("\\t"),
+            c => literal.push(c),
+        }
+    }
+    literal.push('"');
+    literal
 }
 
 pub struct CursorHandler;
@@ -27,13 +86,12 @@ impl IdeHandler for CursorHandler {
                 session_info.original_id
             );
         }
-        let script = self.generate_applescript(session_info);
-        execute_applescript(&script)
+        execute_applescript(&self.close_window_invocation(session_info)?)
     }
 
-    fn generate_applescript(&self, session_info: &SessionInfo) -> String {
+    fn close_window_invocation(&self, session_info: &SessionInfo) -> Result<OsascriptInvocation> {
         // Use original_id for collision-safe window matching, consistent with VSCode
-        generate_applescript_template("Cursor", &session_info.original_id)
+        close_window_invocation("Cursor", &session_info.original_id)
     }
 }
 
@@ -49,18 +107,16 @@ impl IdeHandler for VSCodeHandler {
                 session_info.original_id
             );
         }
-        let script = self.generate_applescript(session_info);
-        execute_applescript(&script)
+        execute_applescript(&self.close_window_invocation(session_info)?)
     }
 
-    fn generate_applescript(&self, session_info: &SessionInfo) -> String {
+    fn close_window_invocation(&self, session_info: &SessionInfo) -> Result<OsascriptInvocation> {
         // VS Code shows full worktree directory name in title
-        let search_fragment = &session_info.original_id;
-        generate_applescript_template("Code", search_fragment)
+        close_window_invocation("Code", &session_info.original_id)
     }
 }
 
-fn execute_applescript(script: &str) -> Result<()> {
+fn execute_applescript(invocation: &OsascriptInvocation) -> Result<()> {
     if cfg!(test) {
         panic!(
             "CRITICAL: execute_applescript called from test environment! \
@@ -68,7 +124,7 @@ fn execute_applescript(script: &str) -> Result<()> {
         );
     }
 
-    let output = Command::new("osascript").arg("-e").arg(script).output()?;
+    let output = invocation.command().output()?;
 
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
@@ -80,12 +136,17 @@ fn execute_applescript(script: &str) -> Result<()> {
     Ok(())
 }
 
-pub(crate) fn generate_applescript_template(app_name: &str, search_fragment: &str) -> String {
-    format!(
+/// Close the first window of `app_name` whose title contains `search_fragment`
+pub(crate) fn close_window_invocation(
+    app_name: &str,
+    search_fragment: &str,
+) -> Result<OsascriptInvocation> {
+    let app_name = applescript_string(app_name);
+    let script = format!(
         r#"
 on run argv
-  set appName to "{app_name}"
-  set windowTitleFragment to "{search_fragment}"
+  set appName to {app_name}
+  set windowTitleFragment to item 1 of argv
   
   log "AppleScript started for app: " & appName & " with title fragment: " & windowTitleFragment
   
@@ -127,7 +188,8 @@ on run argv
   end tell
 end run
         "#
-    )
+    );
+    OsascriptInvocation::new(script, vec![search_fragment.to_string()])
 }
 
 impl PlatformManager for MacOSPlatform {
@@ -157,6 +219,66 @@ impl PlatformManager for MacOSPlatform {
         let ide_handler = self.get_ide_handler(&actual_ide)?;
         ide_handler.close_window(&session_info)
     }
+
+    fn find_ide_windows(
+        &self,
+        app_name: &str,
+        title_fragment: &str,
+    ) -> Result<Option<Vec<String>>> {
+        if cfg!(test) {
+            panic!(
+                "CRITICAL: find_ide_windows called from test environment! \
+                 This indicates a test isolation failure. \
+                 App: {app_name}, Fragment: {title_fragment}"
+            );
+        }
+
+        let invocation = window_list_invocation(app_name, title_fragment)?;
+        let output = match invocation.command().output() {
+            Ok(output) => output,
+            Err(_) => return Ok(None),
+        };
+
+        // Failures are usually missing accessibility permissions - treat as unknown
+        if !output.status.success() {
+            return Ok(None);
+        }
+
+        let windows = String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(str::to_string)
+            .collect();
+        Ok(Some(windows))
+    }
+}
+
+/// Titles of the windows of `app_name` whose title contains `title_fragment`,
+/// one per line
+pub(crate) fn window_list_invocation(
+    app_name: &str,
+    title_fragment: &str,
+) -> Result<OsascriptInvocation> {
+    let app_name = applescript_string(app_name);
+    let script = format!(
+        r#"
+on run argv
+  set titleFragment to item 1 of argv
+  set matchingWindows to {{}}
+  tell application "System Events"
+    if exists process {app_name} then
+      tell process {app_name}
+        set matchingWindows to name of every window whose name contains titleFragment
+      end tell
+    end if
+  end tell
+  set AppleScript's text item delimiters to linefeed
+  return matchingWindows as text
+end run
+        "#
+    );
+    OsascriptInvocation::new(script, vec![title_fragment.to_string()])
 }
 
 impl MacOSPlatform {
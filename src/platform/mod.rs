@@ -5,8 +5,19 @@ mod tests;
 
 use crate::utils::Result;
 
-pub trait PlatformManager {
+pub trait PlatformManager: Send + Sync {
     fn close_ide_window(&self, session_id: &str, ide_name: &str, state_dir: &str) -> Result<()>;
+
+    /// Titles of open windows of `app_name` whose title contains `title_fragment`.
+    ///
+    /// Returns `Ok(None)` when window lookup is not supported on this platform.
+    fn find_ide_windows(
+        &self,
+        _app_name: &str,
+        _title_fragment: &str,
+    ) -> Result<Option<Vec<String>>> {
+        Ok(None)
+    }
 }
 
 pub fn get_platform_manager() -> Box<dyn PlatformManager> {
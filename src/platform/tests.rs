@@ -17,6 +17,86 @@ pub mod platform_tests {
         let _ = platform.close_ide_window("test-session", "cursor", "/tmp/state");
     }
 
+    // Scripts are only built here, never run, so these run on every platform
+    mod applescript_tests {
+        use crate::platform::macos::{
+            applescript_string, close_window_invocation, window_list_invocation, CursorHandler,
+            IdeHandler, OsascriptInvocation, SessionInfo,
+        };
+
+        const HOSTILE_NAMES: &[&str] = &[
+            r#"x"; do shell script "touch /tmp/pwned"; ""#,
+            "line one\nline two",
+            r"back\slash\",
+            "ünïcødé-セッション-🚀",
+            "end tell\rdo shell script \"id\"",
+        ];
+
+        #[test]
+        fn test_applescript_string_escapes_hostile_input() {
+            assert_eq!(applescript_string("Cursor"), r#""Cursor""#);
+            assert_eq!(
+                applescript_string(r#"x"; do shell script "rm -rf ~"; ""#),
+                r#""x\"; do shell script \"rm -rf ~\"; \"""#
+            );
+            assert_eq!(applescript_string(r"a\"), r#""a\\""#);
+            assert_eq!(applescript_string(r#"a\""#), r#""a\\\"""#);
+            assert_eq!(applescript_string("a\nb\r\tc"), r#""a\nb\r\tc""#);
+            assert_eq!(applescript_string("ünï 🚀"), "\"ünï 🚀\"");
+
+            // Every quote inside the literal is escaped, so it cannot end early
+            for name in HOSTILE_NAMES {
+                let literal = applescript_string(name);
+                let inner = &literal[1..literal.len() - 1];
+                let mut escaped = false;
+                for c in inner.chars() {
+                    assert!(escaped || c != '"', "unescaped quote in {literal}");
+                    assert!(c != '\n' && c != '\r', "raw line break in {literal}");
+                    escaped = !escaped && c == '\\';
+                }
+            }
+        }
+
+        #[test]
+        fn test_session_names_are_passed_as_argv() {
+            for name in HOSTILE_NAMES {
+                let invocation = close_window_invocation("Code", name).unwrap();
+                assert_eq!(invocation.argv, vec![name.to_string()]);
+                assert!(!invocation.script.contains(name));
+                assert!(invocation
+                    .script
+                    .contains("set windowTitleFragment to item 1 of argv"));
+                assert_eq!(
+                    invocation.command_args(),
+                    vec!["-e", invocation.script.as_str(), name]
+                );
+
+                let invocation = window_list_invocation("Cursor", name).unwrap();
+                assert_eq!(invocation.argv, vec![name.to_string()]);
+                assert!(!invocation.script.contains(name));
+                assert!(invocation
+                    .script
+                    .contains("set titleFragment to item 1 of argv"));
+                assert!(invocation.script.contains(r#"exists process "Cursor""#));
+            }
+
+            let session_info = SessionInfo {
+                original_id: HOSTILE_NAMES[0].to_string(),
+            };
+            let invocation = CursorHandler
+                .close_window_invocation(&session_info)
+                .unwrap();
+            assert_eq!(invocation.command_args()[2], HOSTILE_NAMES[0]);
+        }
+
+        #[test]
+        fn test_values_with_nul_bytes_are_rejected() {
+            let err = close_window_invocation("Code", "a\0b").unwrap_err();
+            assert!(err.to_string().contains("NUL byte"));
+            assert!(OsascriptInvocation::new(String::new(), vec!["ok".to_string()]).is_ok());
+        }
+    }
+
     #[cfg(target_os = "macos")]
     mod macos_tests {
         use crate::platform::{
@@ -82,8 +162,8 @@ pub mod platform_tests {
                 .parse_session_info("my-feature-20250615-123456")
                 .unwrap();
             let handler = crate::platform::macos::CursorHandler;
-            let script = handler.generate_applescript(&session_info);
-            assert!(script.contains("set windowTitleFragment to \"my-feature-20250615-123456\""));
+            let script = handler.close_window_invocation(&session_info).unwrap();
+            assert_eq!(script.argv, vec!["my-feature-20250615-123456"]);
         }
 
         #[test]
@@ -91,8 +171,8 @@ pub mod platform_tests {
             let platform = MacOSPlatform;
             let session_info = platform.parse_session_info("eager_phoenix").unwrap();
             let handler = crate::platform::macos::CursorHandler;
-            let script = handler.generate_applescript(&session_info);
-            assert!(script.contains("set windowTitleFragment to \"eager_phoenix\""));
+            let script = handler.close_window_invocation(&session_info).unwrap();
+            assert_eq!(script.argv, vec!["eager_phoenix"]);
         }
 
         #[test]
@@ -102,8 +182,8 @@ pub mod platform_tests {
                 .parse_session_info("my-feature-20250615-123456")
                 .unwrap();
             let handler = crate::platform::macos::VSCodeHandler;
-            let script = handler.generate_applescript(&session_info);
-            assert!(script.contains("set windowTitleFragment to \"my-feature-20250615-123456\""));
+            let script = handler.close_window_invocation(&session_info).unwrap();
+            assert_eq!(script.argv, vec!["my-feature-20250615-123456"]);
         }
 
         #[test]
@@ -113,42 +193,49 @@ pub mod platform_tests {
                 .parse_session_info("my-feature-20250615-123456")
                 .unwrap();
             let handler = crate::platform::macos::VSCodeHandler; // Default to VSCode behavior for non-cursor IDEs
-            let script = handler.generate_applescript(&session_info);
-            assert!(script.contains("set windowTitleFragment to \"my-feature-20250615-123456\""));
+            let script = handler.close_window_invocation(&session_info).unwrap();
+            assert_eq!(script.argv, vec!["my-feature-20250615-123456"]);
         }
 
         #[test]
         fn test_generate_applescript_contains_expected_elements() {
-            use crate::platform::macos::generate_applescript_template;
-            let script = generate_applescript_template("Cursor", "my-feature");
+            use crate::platform::macos::close_window_invocation;
+            let script = close_window_invocation("Cursor", "my-feature").unwrap();
 
             // Verify key elements are present in the generated script
-            assert!(script.contains("set appName to \"Cursor\""));
-            assert!(script.contains("set windowTitleFragment to \"my-feature\""));
-            assert!(script.contains("tell application \"System Events\""));
-            assert!(script.contains("every window whose name contains windowTitleFragment"));
-            assert!(script.contains("perform action \"AXRaise\" of targetWindow"));
-            assert!(script.contains("click (button 1 of targetWindow)"));
+            assert!(script.script.contains("set appName to \"Cursor\""));
+            assert_eq!(script.argv, vec!["my-feature"]);
+            assert!(script
+                .script
+                .contains("set windowTitleFragment to item 1 of argv"));
+            assert!(script.script.contains("tell application \"System Events\""));
+            assert!(script
+                .script
+                .contains("every window whose name contains windowTitleFragment"));
+            assert!(script
+                .script
+                .contains("perform action \"AXRaise\" of targetWindow"));
+            assert!(script.script.contains("click (button 1 of targetWindow)"));
         }
 
         #[test]
         fn test_generate_applescript_different_app_names() {
-            use crate::platform::macos::generate_applescript_template;
-            let script_cursor = generate_applescript_template("Cursor", "session");
-            let script_code = generate_applescript_template("Code", "session");
+            use crate::platform::macos::close_window_invocation;
+            let script_cursor = close_window_invocation("Cursor", "session").unwrap();
+            let script_code = close_window_invocation("Code", "session").unwrap();
 
-            assert!(script_cursor.contains("set appName to \"Cursor\""));
-            assert!(script_code.contains("set appName to \"Code\""));
+            assert!(script_cursor.script.contains("set appName to \"Cursor\""));
+            assert!(script_code.script.contains("set appName to \"Code\""));
         }
 
         #[test]
         fn test_generate_applescript_different_search_fragments() {
-            use crate::platform::macos::generate_applescript_template;
-            let script1 = generate_applescript_template("Cursor", "feature-branch");
-            let script2 = generate_applescript_template("Cursor", "eager_phoenix");
+            use crate::platform::macos::close_window_invocation;
+            let script1 = close_window_invocation("Cursor", "feature-branch").unwrap();
+            let script2 = close_window_invocation("Cursor", "eager_phoenix").unwrap();
 
-            assert!(script1.contains("set windowTitleFragment to \"feature-branch\""));
-            assert!(script2.contains("set windowTitleFragment to \"eager_phoenix\""));
+            assert_eq!(script1.argv, vec!["feature-branch"]);
+            assert_eq!(script2.argv, vec!["eager_phoenix"]);
         }
 
         #[test]
@@ -162,8 +249,8 @@ pub mod platform_tests {
 
             // Verify the handler generates correct AppleScript
             let handler = crate::platform::macos::CursorHandler;
-            let script = handler.generate_applescript(&result);
-            assert!(script.contains("set windowTitleFragment to \"my-feature-20250615-123456\""));
+            let script = handler.close_window_invocation(&result).unwrap();
+            assert_eq!(script.argv, vec!["my-feature-20250615-123456"]);
         }
 
         #[test]
@@ -175,8 +262,8 @@ pub mod platform_tests {
 
             // Verify the handler generates correct AppleScript
             let handler = crate::platform::macos::CursorHandler;
-            let script = handler.generate_applescript(&result);
-            assert!(script.contains("set windowTitleFragment to \"eager_phoenix\""));
+            let script = handler.close_window_invocation(&result).unwrap();
+            assert_eq!(script.argv, vec!["eager_phoenix"]);
         }
 
         #[test]
@@ -189,8 +276,8 @@ pub mod platform_tests {
 
             // Verify handler works with complex names
             let handler = crate::platform::macos::CursorHandler;
-            let script1 = handler.generate_applescript(&result1);
-            assert!(script1.contains("set windowTitleFragment to \"fix-issue-123_branch\""));
+            let script1 = handler.close_window_invocation(&result1).unwrap();
+            assert_eq!(script1.argv, vec!["fix-issue-123_branch"]);
 
             // Test with timestamp that has dashes in feature name
             let result2 = platform
@@ -199,8 +286,8 @@ pub mod platform_tests {
             assert_eq!(result2.original_id, "fix-bug-123-20250615-123456");
 
             // Verify handler works with timestamp format
-            let script2 = handler.generate_applescript(&result2);
-            assert!(script2.contains("set windowTitleFragment to \"fix-bug-123-20250615-123456\""));
+            let script2 = handler.close_window_invocation(&result2).unwrap();
+            assert_eq!(script2.argv, vec!["fix-bug-123-20250615-123456"]);
         }
 
         #[test]
@@ -237,9 +324,9 @@ pub mod platform_tests {
                 original_id: "my-feature-20250615-123456".to_string(),
             };
 
-            let script = handler.generate_applescript(&session_info);
-            assert!(script.contains("set appName to \"Cursor\""));
-            assert!(script.contains("set windowTitleFragment to \"my-feature-20250615-123456\""));
+            let script = handler.close_window_invocation(&session_info).unwrap();
+            assert!(script.script.contains("set appName to \"Cursor\""));
+            assert_eq!(script.argv, vec!["my-feature-20250615-123456"]);
         }
 
         #[test]
@@ -253,9 +340,9 @@ pub mod platform_tests {
                 original_id: "my-feature-20250615-123456".to_string(),
             };
 
-            let script = handler.generate_applescript(&session_info);
-            assert!(script.contains("set appName to \"Code\""));
-            assert!(script.contains("set windowTitleFragment to \"my-feature-20250615-123456\""));
+            let script = handler.close_window_invocation(&session_info).unwrap();
+            assert!(script.script.contains("set appName to \"Code\""));
+            assert_eq!(script.argv, vec!["my-feature-20250615-123456"]);
         }
 
         #[test]
@@ -267,15 +354,15 @@ pub mod platform_tests {
                 original_id: "my-feature-20250615-123456".to_string(),
             };
             let handler = CursorHandler;
-            let script = handler.generate_applescript(&session_info);
-            assert!(script.contains("set windowTitleFragment to \"my-feature-20250615-123456\""));
+            let script = handler.close_window_invocation(&session_info).unwrap();
+            assert_eq!(script.argv, vec!["my-feature-20250615-123456"]);
 
             // Test Cursor collision-safe behavior with Docker session
             let session_info2 = SessionInfo {
                 original_id: "eager_phoenix".to_string(),
             };
-            let script2 = handler.generate_applescript(&session_info2);
-            assert!(script2.contains("set windowTitleFragment to \"eager_phoenix\""));
+            let script2 = handler.close_window_invocation(&session_info2).unwrap();
+            assert_eq!(script2.argv, vec!["eager_phoenix"]);
         }
 
         #[test]
@@ -287,8 +374,8 @@ pub mod platform_tests {
                 original_id: "my-feature-20250615-123456".to_string(),
             };
             let handler = VSCodeHandler;
-            let script = handler.generate_applescript(&session_info);
-            assert!(script.contains("set windowTitleFragment to \"my-feature-20250615-123456\""));
+            let script = handler.close_window_invocation(&session_info).unwrap();
+            assert_eq!(script.argv, vec!["my-feature-20250615-123456"]);
         }
     }
 }
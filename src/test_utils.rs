@@ -1,3 +1,6 @@
+#[cfg(test)]
+pub mod failing_fs;
+
 #[cfg(test)]
 pub mod mock_platform;
 
@@ -7,7 +10,9 @@ pub mod test_safety;
 #[cfg(test)]
 pub mod test_helpers {
     use crate::config::Config;
-    use crate::core::git::GitService;
+    use crate::core::git::repository::execute_git_command;
+    use crate::core::git::{GitRepository, GitService};
+    use crate::core::session::{SessionManager, SessionState};
     use std::fs;
     use std::path::{Path, PathBuf};
     use std::process::Command;
@@ -68,6 +73,95 @@ pub mod test_helpers {
         (temp_dir, service)
     }
 
+    /// Freshly initialized repository without any commits (unborn `main`)
+    pub fn setup_empty_test_repo() -> (TempDir, GitService) {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+
+        Command::new("sh")
+            .current_dir(temp_dir.path())
+            .arg("-c")
+            .arg(
+                r#"
+                git init --initial-branch=main &&
+                git config user.name "Test User" &&
+                git config user.email "test@example.com"
+            "#,
+            )
+            .output()
+            .expect("Failed to init git repo");
+
+        let service = GitService::discover_from(temp_dir.path()).expect("Failed to discover repo");
+        (temp_dir, service)
+    }
+
+    /// A saved session with its own worktree on `test/<name>`, created from
+    /// `base`, which is recorded as its parent branch
+    pub fn start_session(
+        git_service: &GitService,
+        manager: &SessionManager,
+        name: &str,
+        base: &str,
+    ) -> SessionState {
+        let repo = git_service.repository();
+        let branch = format!("test/{name}");
+        let worktree = manager.state_dir().join("worktrees").join(name);
+        execute_git_command(
+            repo,
+            &[
+                "worktree",
+                "add",
+                "-b",
+                &branch,
+                &worktree.to_string_lossy(),
+                base,
+            ],
+        )
+        .unwrap();
+
+        let mut session = SessionState::new(name.to_string(), branch, worktree);
+        session.parent_branch = Some(base.to_string());
+        session.base_commit = Some(repo.resolve_commit(base).unwrap());
+        manager.save_state(&session).unwrap();
+        session
+    }
+
+    /// Run git in `dir` and return its trimmed output, failing the test if
+    /// git fails
+    pub fn git(dir: &Path, args: &[&str]) -> String {
+        let output = Command::new("git")
+            .current_dir(dir)
+            .args(args)
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "git {args:?} failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        String::from_utf8_lossy(&output.stdout).trim().to_string()
+    }
+
+    /// Write `content` to `file` in the repository or worktree at `dir` and
+    /// commit everything pending there
+    pub fn commit_file(dir: &Path, file: &str, content: &str, message: &str) {
+        let path = dir.join(file);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+        let repo = GitRepository::discover_from(dir).unwrap();
+        repo.stage_all_changes().unwrap();
+        repo.commit(message).unwrap();
+    }
+
+    /// Environment lookup that only sees `vars`, for code that takes its
+    /// environment as a function
+    pub fn env<'a>(vars: &'a [(&'a str, &'a str)]) -> impl Fn(&str) -> Option<String> + 'a {
+        move |name| {
+            vars.iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| value.to_string())
+        }
+    }
+
     pub fn setup_isolated_test_environment(temp_dir: &TempDir) -> PathBuf {
         // Create a test config that points to our temp state dir
         let config_dir = temp_dir.path().join(".config").join("para");
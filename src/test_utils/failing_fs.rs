@@ -0,0 +1,37 @@
+use crate::utils::fs::{FsOps, RealFs};
+use std::io;
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Writes like [`RealFs`] until the `n`th write, which fails as if the disk
+/// were full
+pub struct FailingFs {
+    fail_on: usize,
+    writes: AtomicUsize,
+}
+
+impl FailingFs {
+    pub fn on_write(n: usize) -> Self {
+        Self {
+            fail_on: n,
+            writes: AtomicUsize::new(0),
+        }
+    }
+
+    pub fn writes(&self) -> usize {
+        self.writes.load(Ordering::SeqCst)
+    }
+}
+
+impl FsOps for FailingFs {
+    fn write(&self, path: &Path, contents: &[u8]) -> io::Result<()> {
+        let write = self.writes.fetch_add(1, Ordering::SeqCst) + 1;
+        if write == self.fail_on {
+            return Err(io::Error::new(
+                io::ErrorKind::StorageFull,
+                "No space left on device",
+            ));
+        }
+        RealFs.write(path, contents)
+    }
+}
@@ -1,10 +1,10 @@
 use crate::platform::PlatformManager;
 use crate::utils::Result;
-use std::cell::RefCell;
-use std::rc::Rc;
+use std::sync::{Arc, Mutex};
 
 pub struct MockPlatformManager {
-    pub close_calls: Rc<RefCell<Vec<(String, String)>>>,
+    pub close_calls: Arc<Mutex<Vec<(String, String)>>>,
+    pub windows: Option<Vec<String>>,
 }
 
 impl Default for MockPlatformManager {
@@ -16,7 +16,16 @@ impl Default for MockPlatformManager {
 impl MockPlatformManager {
     pub fn new() -> Self {
         MockPlatformManager {
-            close_calls: Rc::new(RefCell::new(Vec::new())),
+            close_calls: Arc::new(Mutex::new(Vec::new())),
+            windows: None,
+        }
+    }
+
+    /// Mock that reports the given window titles from `find_ide_windows`
+    pub fn with_windows(windows: Vec<String>) -> Self {
+        MockPlatformManager {
+            windows: Some(windows),
+            ..Self::new()
         }
     }
 }
@@ -24,8 +33,23 @@ impl MockPlatformManager {
 impl PlatformManager for MockPlatformManager {
     fn close_ide_window(&self, session_id: &str, ide_name: &str, _state_dir: &str) -> Result<()> {
         self.close_calls
-            .borrow_mut()
+            .lock()
+            .unwrap()
             .push((session_id.to_string(), ide_name.to_string()));
         Ok(())
     }
+
+    fn find_ide_windows(
+        &self,
+        _app_name: &str,
+        title_fragment: &str,
+    ) -> Result<Option<Vec<String>>> {
+        Ok(self.windows.as_ref().map(|windows| {
+            windows
+                .iter()
+                .filter(|title| title.contains(title_fragment))
+                .cloned()
+                .collect()
+        }))
+    }
 }
@@ -1,20 +1,101 @@
-use crate::ui::monitor::actions::MonitorActions;
+use crate::ui::monitor::actions::{resume_subcommand, MonitorActions};
 use crate::ui::monitor::event_handler::{
-    DialogAction, NavigationAction, SessionAction, SystemAction, UiAction,
+    DialogAction, NavigationAction, SessionAction, SystemAction, UiAction, ViewAction,
+};
+use crate::ui::monitor::operation_queue::{
+    busy_message, operation_key, Operation, OperationKind, OperationQueue, OperationState,
 };
 use crate::ui::monitor::state::{ButtonClick, MonitorAppState};
 use crate::ui::monitor::SessionInfo;
 use crate::utils::Result;
 use copypasta::{ClipboardContext, ClipboardProvider};
+use std::collections::HashMap;
+use std::path::PathBuf;
 
 /// Dispatches and executes UI actions
 pub struct ActionDispatcher {
     actions: MonitorActions,
+    /// Actions for each repository when monitoring several, keyed by repository root
+    repo_actions: HashMap<PathBuf, MonitorActions>,
+    /// Finish, cancel and sync jobs, run off the UI thread
+    operations: OperationQueue,
 }
 
 impl ActionDispatcher {
     pub fn new(actions: MonitorActions) -> Self {
-        Self { actions }
+        Self::for_repos(actions, HashMap::new())
+    }
+
+    /// Dispatcher that runs each session's actions against its own repository
+    pub fn for_repos(
+        actions: MonitorActions,
+        repo_actions: HashMap<PathBuf, MonitorActions>,
+    ) -> Self {
+        Self {
+            actions,
+            repo_actions,
+            operations: OperationQueue::new(),
+        }
+    }
+
+    /// Show the outcomes of finished jobs and return whether any arrived,
+    /// in which case the sessions need a refresh
+    pub fn poll_operations(&mut self, state: &mut MonitorAppState) -> bool {
+        let outcomes = self.operations.poll();
+        for outcome in &outcomes {
+            match &outcome.result {
+                Ok(message) => state.show_feedback(message.clone()),
+                Err(e) if e.conflicted => {
+                    state.show_conflict(e.message.clone(), outcome.key.clone())
+                }
+                Err(e) => state.show_error(e.message.clone()),
+            }
+        }
+        state.operations = self.operations.snapshot();
+        !outcomes.is_empty()
+    }
+
+    fn submit(&mut self, operation: Operation, state: &mut MonitorAppState) {
+        let queued = format!("Queued {} of {}", operation.kind, operation.session);
+        match self.operations.submit(operation) {
+            Ok(()) => state.show_feedback(queued),
+            Err(message) => state.show_feedback(message),
+        }
+        state.operations = self.operations.snapshot();
+    }
+
+    /// Whether `session` already has a job, telling the user so. Cancelling
+    /// a session whose job has not started drops that job instead.
+    fn reject_busy(
+        &mut self,
+        session: &SessionInfo,
+        action: OperationKind,
+        state: &mut MonitorAppState,
+    ) -> bool {
+        let key = operation_key(session);
+        let Some(pending) = self.operations.pending(&key) else {
+            return false;
+        };
+        if action == OperationKind::Cancel && pending.state == OperationState::Queued {
+            if let Some(kind) = self.operations.cancel(&key) {
+                state.show_feedback(format!("Dropped queued {kind} of {}", session.name));
+                state.operations = self.operations.snapshot();
+                return true;
+            }
+        }
+        state.show_feedback(busy_message(&session.name, pending));
+        true
+    }
+
+    /// The actions for the repository `session` belongs to
+    fn actions_for(&self, session: &SessionInfo) -> std::result::Result<&MonitorActions, String> {
+        match &session.repo_root {
+            Some(root) if session.repo_missing => {
+                Err(format!("Repository {} no longer exists", root.display()))
+            }
+            Some(root) => Ok(self.repo_actions.get(root).unwrap_or(&self.actions)),
+            None => Ok(&self.actions),
+        }
     }
 
     /// Dispatch a UI action and execute it
@@ -35,6 +116,7 @@ impl ActionDispatcher {
             UiAction::Dialog(dialog_action) => {
                 self.execute_dialog_action(dialog_action, state, sessions)
             }
+            UiAction::View(view_action) => Ok(self.execute_view_action(view_action, state)),
             UiAction::System(system_action) => {
                 self.execute_system_action(system_action, state);
                 Ok(ActionResult::Continue)
@@ -48,14 +130,31 @@ impl ActionDispatcher {
         state: &mut MonitorAppState,
         sessions: &[SessionInfo],
     ) -> Result<ActionResult> {
+        let modifies_session = matches!(
+            action,
+            SessionAction::Integrate(_)
+                | SessionAction::Finish(_)
+                | SessionAction::Cancel(_)
+                | SessionAction::Sync(_)
+        );
+        if modifies_session && state.prefs.read_only {
+            state.show_feedback(READ_ONLY_FEEDBACK.to_string());
+            return Ok(ActionResult::Continue);
+        }
+
         match action {
             SessionAction::Resume(index) => {
                 if let Some(session) = sessions.get(index) {
                     // Register button click for visual feedback
                     state.register_button_click(ButtonClick::Resume(index));
 
-                    if let Err(e) = self.actions.resume_session(session) {
+                    let result = self.actions_for(session).and_then(|actions| {
+                        actions.resume_session(session).map_err(|e| e.to_string())
+                    });
+                    if let Err(e) = result {
                         state.show_error(format!("Failed to resume session: {e}"));
+                    } else if resume_subcommand(session) == "launch" {
+                        state.show_feedback(format!("Launching session: {}", session.name));
                     } else {
                         state.show_feedback(format!("Opening session: {}", session.name));
                     }
@@ -84,28 +183,52 @@ impl ActionDispatcher {
             }
             SessionAction::Integrate(index) => {
                 if let Some(session) = sessions.get(index) {
-                    self.actions.integrate_session(session)?;
+                    match self.actions_for(session) {
+                        Ok(actions) => actions.integrate_session(session)?,
+                        Err(e) => state.show_error(e),
+                    }
                 }
                 Ok(ActionResult::Continue)
             }
             SessionAction::Finish(index) => {
-                if let Some(_session) = sessions.get(index) {
+                if let Some(session) = sessions.get(index) {
                     // Register button click for visual feedback
                     state.register_button_click(ButtonClick::Finish(index));
+                    if self.reject_busy(session, OperationKind::Finish, state) {
+                        return Ok(ActionResult::Continue);
+                    }
                     // Start the finish dialog instead of directly finishing
                     state.start_finish();
                 }
                 Ok(ActionResult::Continue)
             }
             SessionAction::Cancel(index) => {
-                if let Some(_session) = sessions.get(index) {
+                if let Some(session) = sessions.get(index) {
                     // Register button click for visual feedback
                     state.register_button_click(ButtonClick::Cancel(index));
+                    if self.reject_busy(session, OperationKind::Cancel, state) {
+                        return Ok(ActionResult::Continue);
+                    }
                     // Start the cancel confirmation dialog instead of directly canceling
                     state.start_cancel();
                 }
                 Ok(ActionResult::Continue)
             }
+            SessionAction::Sync(index) => {
+                if let Some(session) = sessions.get(index) {
+                    if self.reject_busy(session, OperationKind::Sync, state) {
+                        return Ok(ActionResult::Continue);
+                    }
+                    match self.actions_for(session) {
+                        Ok(actions) => {
+                            let operation = actions.sync_operation(session);
+                            self.submit(operation, state);
+                        }
+                        Err(e) => state.show_error(e),
+                    }
+                }
+                Ok(ActionResult::Continue)
+            }
         }
     }
 
@@ -134,13 +257,36 @@ impl ActionDispatcher {
         state: &mut MonitorAppState,
         sessions: &[SessionInfo],
     ) -> Result<ActionResult> {
+        let modifies_session = matches!(
+            action,
+            DialogAction::StartFinish
+                | DialogAction::StartCancel
+                | DialogAction::ExecuteFinish
+                | DialogAction::ExecuteCancel
+        );
+        if modifies_session && state.prefs.read_only {
+            state.exit_dialog();
+            state.show_feedback(READ_ONLY_FEEDBACK.to_string());
+            return Ok(ActionResult::Continue);
+        }
+
         match action {
             DialogAction::StartFinish => {
-                state.start_finish();
+                let busy = state
+                    .get_selected_session(sessions)
+                    .is_some_and(|session| self.reject_busy(session, OperationKind::Finish, state));
+                if !busy {
+                    state.start_finish();
+                }
                 Ok(ActionResult::Continue)
             }
             DialogAction::StartCancel => {
-                state.start_cancel();
+                let busy = state
+                    .get_selected_session(sessions)
+                    .is_some_and(|session| self.reject_busy(session, OperationKind::Cancel, state));
+                if !busy {
+                    state.start_cancel();
+                }
                 Ok(ActionResult::Continue)
             }
             DialogAction::ExitDialog => {
@@ -158,18 +304,36 @@ impl ActionDispatcher {
             DialogAction::ExecuteFinish => {
                 if let Some(session) = state.get_selected_session(sessions) {
                     let message = state.take_input();
-                    self.actions.finish_session(session, message)?;
+                    let actions = match self.actions_for(session) {
+                        Ok(actions) => actions,
+                        Err(e) => {
+                            state.exit_dialog();
+                            state.show_error(e);
+                            return Ok(ActionResult::Continue);
+                        }
+                    };
+                    let operation = actions.finish_operation(session, message);
                     state.exit_dialog();
-                    Ok(ActionResult::RefreshSessions)
+                    self.submit(operation, state);
+                    Ok(ActionResult::Continue)
                 } else {
                     Ok(ActionResult::Continue)
                 }
             }
             DialogAction::ExecuteCancel => {
                 if let Some(session) = state.get_selected_session(sessions) {
-                    self.actions.cancel_session(session)?;
+                    let actions = match self.actions_for(session) {
+                        Ok(actions) => actions,
+                        Err(e) => {
+                            state.exit_dialog();
+                            state.show_error(e);
+                            return Ok(ActionResult::Continue);
+                        }
+                    };
+                    let operation = actions.cancel_operation(session);
                     state.exit_dialog();
-                    Ok(ActionResult::RefreshSessions)
+                    self.submit(operation, state);
+                    Ok(ActionResult::Continue)
                 } else {
                     state.exit_dialog();
                     Ok(ActionResult::Continue)
@@ -179,6 +343,91 @@ impl ActionDispatcher {
                 state.clear_error();
                 Ok(ActionResult::Continue)
             }
+            DialogAction::OpenConflict => {
+                let key = state.conflict.clone();
+                state.clear_error();
+                let Some(session) = key.and_then(|key| {
+                    sessions
+                        .iter()
+                        .find(|session| operation_key(session) == key)
+                }) else {
+                    return Ok(ActionResult::Continue);
+                };
+                let result = self
+                    .actions_for(session)
+                    .and_then(|actions| actions.resume_session(session).map_err(|e| e.to_string()));
+                match result {
+                    Ok(()) => state.show_feedback(format!("Opening session: {}", session.name)),
+                    Err(e) => state.show_error(format!("Failed to open session: {e}")),
+                }
+                Ok(ActionResult::Continue)
+            }
+        }
+    }
+
+    fn execute_view_action(&self, action: ViewAction, state: &mut MonitorAppState) -> ActionResult {
+        match action {
+            ViewAction::CycleSort => {
+                state.prefs.cycle_sort();
+                state.show_feedback(format!("Sort: {}", state.prefs.sort_label()));
+                ActionResult::PreferencesChanged
+            }
+            ViewAction::ReverseSort => {
+                state.prefs.reverse_sort();
+                state.show_feedback(format!("Sort: {}", state.prefs.sort_label()));
+                ActionResult::PreferencesChanged
+            }
+            ViewAction::ToggleReadOnly => {
+                state.prefs.read_only = !state.prefs.read_only;
+                let message = if state.prefs.read_only {
+                    "Read-only mode on"
+                } else {
+                    "Read-only mode off"
+                };
+                state.show_feedback(message.to_string());
+                ActionResult::PreferencesChanged
+            }
+            ViewAction::StartFilter => {
+                state.start_filter();
+                ActionResult::Continue
+            }
+            ViewAction::FilterChar(c) => {
+                state.prefs.filter.push(c);
+                ActionResult::RefreshSessions
+            }
+            ViewAction::FilterBackspace => {
+                state.prefs.filter.pop();
+                ActionResult::RefreshSessions
+            }
+            ViewAction::ApplyFilter => {
+                state.exit_dialog();
+                ActionResult::PreferencesChanged
+            }
+            ViewAction::ClearFilter => {
+                state.prefs.filter.clear();
+                state.exit_dialog();
+                ActionResult::PreferencesChanged
+            }
+            ViewAction::OpenColumnPicker => {
+                state.open_column_picker();
+                ActionResult::Continue
+            }
+            ViewAction::NextColumn => {
+                state.next_column();
+                ActionResult::Continue
+            }
+            ViewAction::PreviousColumn => {
+                state.previous_column();
+                ActionResult::Continue
+            }
+            ViewAction::ToggleColumn => {
+                state.toggle_selected_column();
+                ActionResult::PreferencesChanged
+            }
+            ViewAction::CloseColumnPicker => {
+                state.exit_dialog();
+                ActionResult::Continue
+            }
         }
     }
 
@@ -196,8 +445,12 @@ impl ActionDispatcher {
 pub enum ActionResult {
     Continue,
     RefreshSessions,
+    /// View preferences changed and should be saved before refreshing
+    PreferencesChanged,
 }
 
+const READ_ONLY_FEEDBACK: &str = "Read-only mode: press r to allow changes";
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -221,6 +474,23 @@ mod tests {
                 diff_stats: None,
                 todo_percentage: None,
                 is_blocked: false,
+                skip_permissions: false,
+                operation: None,
+                activity_source: None,
+                integration: None,
+                agent_status_stale: false,
+                repo_missing: false,
+                repo_root: None,
+                merge_mode: crate::core::git::MergeMode::default(),
+                note: None,
+                container: None,
+                todos: None,
+                stacked_on: None,
+                worktree_status: None,
+                detached_at: None,
+                links: Vec::new(),
+                ahead_behind: None,
+                pinned: false,
             },
             SessionInfo {
                 name: "session2".to_string(),
@@ -233,6 +503,23 @@ mod tests {
                 diff_stats: None,
                 todo_percentage: None,
                 is_blocked: false,
+                skip_permissions: false,
+                operation: None,
+                activity_source: None,
+                integration: None,
+                agent_status_stale: false,
+                repo_missing: false,
+                repo_root: None,
+                merge_mode: crate::core::git::MergeMode::default(),
+                note: None,
+                container: None,
+                todos: None,
+                stacked_on: None,
+                worktree_status: None,
+                detached_at: None,
+                links: Vec::new(),
+                ahead_behind: None,
+                pinned: false,
             },
         ]
     }
@@ -298,6 +585,217 @@ mod tests {
         assert_eq!(state.mode, crate::ui::monitor::AppMode::Normal);
     }
 
+    #[test]
+    fn test_read_only_blocks_session_changes() {
+        let config = create_test_config();
+        let actions = MonitorActions::new(config);
+        let mut dispatcher = ActionDispatcher::new(actions);
+        let mut state = MonitorAppState::new();
+        let sessions = create_test_sessions();
+
+        let result = dispatcher
+            .dispatch(
+                UiAction::View(ViewAction::ToggleReadOnly),
+                &mut state,
+                &sessions,
+            )
+            .unwrap();
+        assert_eq!(result, ActionResult::PreferencesChanged);
+        assert!(state.prefs.read_only);
+
+        for action in [
+            UiAction::Dialog(DialogAction::StartFinish),
+            UiAction::Dialog(DialogAction::StartCancel),
+            UiAction::Session(SessionAction::Cancel(0)),
+            UiAction::Session(SessionAction::Sync(0)),
+        ] {
+            let result = dispatcher.dispatch(action, &mut state, &sessions).unwrap();
+            assert_eq!(result, ActionResult::Continue);
+            assert_eq!(state.mode, crate::ui::monitor::AppMode::Normal);
+        }
+        assert_eq!(state.get_feedback_message(), Some(READ_ONLY_FEEDBACK));
+    }
+
+    #[test]
+    fn test_busy_sessions_reject_actions() {
+        let actions = MonitorActions::new(create_test_config());
+        let mut dispatcher = ActionDispatcher::new(actions);
+        let mut state = MonitorAppState::new();
+        let sessions = create_test_sessions();
+
+        let (release, released) = std::sync::mpsc::channel::<()>();
+        let running = Operation::new("session1", OperationKind::Finish, move || {
+            let _ = released.recv();
+            Ok("Finished session1".to_string())
+        })
+        .keyed(operation_key(&sessions[0]));
+        dispatcher.operations.submit(running).unwrap();
+        while dispatcher
+            .operations
+            .pending(&operation_key(&sessions[0]))
+            .map(|p| p.state)
+            != Some(OperationState::Running)
+        {
+            std::thread::sleep(std::time::Duration::from_millis(5));
+        }
+        let queued = Operation::new("session2", OperationKind::Cancel, || Ok(String::new()))
+            .keyed(operation_key(&sessions[1]));
+        dispatcher.operations.submit(queued).unwrap();
+
+        // A second action on a running session only shows a message
+        dispatcher
+            .dispatch(
+                UiAction::Dialog(DialogAction::StartFinish),
+                &mut state,
+                &sessions,
+            )
+            .unwrap();
+        assert_eq!(state.mode, crate::ui::monitor::AppMode::Normal);
+        assert_eq!(
+            state.get_feedback_message(),
+            Some("session1 is busy: finish in progress")
+        );
+
+        // x on a session whose job has not started drops the job
+        state.next_item(&sessions);
+        dispatcher
+            .dispatch(
+                UiAction::Dialog(DialogAction::StartCancel),
+                &mut state,
+                &sessions,
+            )
+            .unwrap();
+        assert_eq!(state.mode, crate::ui::monitor::AppMode::Normal);
+        assert_eq!(
+            state.get_feedback_message(),
+            Some("Dropped queued cancel of session2")
+        );
+        assert!(dispatcher
+            .operations
+            .pending(&operation_key(&sessions[1]))
+            .is_none());
+
+        release.send(()).unwrap();
+        while !dispatcher.poll_operations(&mut state) {
+            std::thread::sleep(std::time::Duration::from_millis(5));
+        }
+        assert_eq!(state.get_feedback_message(), Some("Finished session1"));
+        assert!(state.operations.is_empty());
+    }
+
+    /// Dispatch `u` on the first session and wait for its sync job
+    fn sync_first_session(dispatcher: &mut ActionDispatcher, state: &mut MonitorAppState) {
+        let sessions = create_test_sessions();
+        dispatcher
+            .dispatch(UiAction::Session(SessionAction::Sync(0)), state, &sessions)
+            .unwrap();
+        assert_eq!(
+            state.get_feedback_message(),
+            Some("Queued sync of session1")
+        );
+        while !dispatcher.poll_operations(state) {
+            std::thread::sleep(std::time::Duration::from_millis(5));
+        }
+    }
+
+    #[test]
+    fn test_sync_job_outcomes() {
+        use crate::core::session::sync::SyncOutcome;
+
+        let actions = MonitorActions::new(create_test_config()).with_sync(|_, name| {
+            assert_eq!(name, "session1");
+            Ok(SyncOutcome::Rebased {
+                base: "main".to_string(),
+                behind: 12,
+            })
+        });
+        let mut dispatcher = ActionDispatcher::new(actions);
+        let mut state = MonitorAppState::new();
+        sync_first_session(&mut dispatcher, &mut state);
+        assert_eq!(
+            state.get_feedback_message(),
+            Some("Rebased session1 onto main (12 new commits)")
+        );
+        assert_eq!(state.mode, crate::ui::monitor::AppMode::Normal);
+
+        let actions = MonitorActions::new(create_test_config()).with_sync(|_, _| {
+            Ok(SyncOutcome::Conflicted {
+                base: "main".to_string(),
+                files: vec!["src/lib.rs".to_string()],
+            })
+        });
+        let mut dispatcher = ActionDispatcher::new(actions);
+        let mut state = MonitorAppState::new();
+        sync_first_session(&mut dispatcher, &mut state);
+        assert_eq!(state.mode, crate::ui::monitor::AppMode::ErrorDialog);
+        assert!(state
+            .error_message
+            .as_deref()
+            .unwrap()
+            .contains("conflicts in src/lib.rs"));
+        assert_eq!(
+            state.conflict,
+            Some(operation_key(&create_test_sessions()[0]))
+        );
+
+        // Other failures are plain errors with nothing to open
+        let actions = MonitorActions::new(create_test_config())
+            .with_sync(|_, _| Err(crate::utils::ParaError::invalid_args("uncommitted changes")));
+        let mut dispatcher = ActionDispatcher::new(actions);
+        let mut state = MonitorAppState::new();
+        sync_first_session(&mut dispatcher, &mut state);
+        assert_eq!(
+            state.error_message.as_deref(),
+            Some("Failed to sync session1: Invalid arguments: uncommitted changes")
+        );
+        assert!(state.conflict.is_none());
+    }
+
+    #[test]
+    fn test_view_actions_update_prefs() {
+        let config = create_test_config();
+        let actions = MonitorActions::new(config);
+        let mut dispatcher = ActionDispatcher::new(actions);
+        let mut state = MonitorAppState::new();
+        let sessions = create_test_sessions();
+
+        let mut dispatch = |action: ViewAction, state: &mut MonitorAppState| {
+            dispatcher
+                .dispatch(UiAction::View(action), state, &sessions)
+                .unwrap()
+        };
+
+        assert_eq!(
+            dispatch(ViewAction::CycleSort, &mut state),
+            ActionResult::PreferencesChanged
+        );
+        assert_eq!(
+            state.prefs.sort_column,
+            crate::ui::monitor::prefs::SortColumn::Name
+        );
+
+        dispatch(ViewAction::StartFilter, &mut state);
+        assert_eq!(state.mode, crate::ui::monitor::AppMode::FilterInput);
+        assert_eq!(
+            dispatch(ViewAction::FilterChar('a'), &mut state),
+            ActionResult::RefreshSessions
+        );
+        assert_eq!(
+            dispatch(ViewAction::ApplyFilter, &mut state),
+            ActionResult::PreferencesChanged
+        );
+        assert_eq!(state.prefs.filter, "a");
+        assert_eq!(state.mode, crate::ui::monitor::AppMode::Normal);
+
+        dispatch(ViewAction::OpenColumnPicker, &mut state);
+        dispatch(ViewAction::NextColumn, &mut state);
+        dispatch(ViewAction::ToggleColumn, &mut state);
+        assert_eq!(
+            state.prefs.hidden_columns,
+            vec![crate::ui::monitor::prefs::Column::State]
+        );
+    }
+
     #[test]
     fn test_system_actions() {
         let config = create_test_config();
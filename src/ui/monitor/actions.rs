@@ -1,20 +1,56 @@
 use crate::config::Config;
-use crate::core::git::{GitOperations, GitService};
+use crate::core::git::{FinishResult, GitOperations, GitService};
+use crate::core::session::sync::{sync_session, SyncOutcome};
 use crate::core::session::SessionManager;
+use crate::ui::monitor::operation_queue::{
+    operation_key, Operation, OperationError, OperationKind,
+};
+use crate::ui::monitor::service::config_for_repo;
 use crate::ui::monitor::SessionInfo;
 use crate::utils::Result;
+use std::path::{Path, PathBuf};
 use std::process::Command;
+use std::sync::Arc;
+
+/// Syncs the named session, `sync_named_session` outside of tests
+type SyncFn = Arc<dyn Fn(&Config, &str) -> Result<SyncOutcome> + Send + Sync>;
 
 /// Business logic actions for the monitor UI
 pub struct MonitorActions {
     config: Config,
+    /// Repository commands run in, when monitoring several repositories
+    repo_root: Option<PathBuf>,
+    sync: SyncFn,
 }
 
 impl MonitorActions {
     pub fn new(config: Config) -> Self {
-        Self { config }
+        Self {
+            config,
+            repo_root: None,
+            sync: Arc::new(sync_named_session),
+        }
+    }
+
+    /// Actions for one of several monitored repositories
+    pub fn for_repo(config: &Config, repo_root: &Path) -> Self {
+        Self {
+            repo_root: Some(repo_root.to_path_buf()),
+            ..Self::new(config_for_repo(config, repo_root))
+        }
+    }
+
+    /// Actions that sync sessions with `sync` instead of rebasing them
+    #[cfg(test)]
+    pub fn with_sync(
+        mut self,
+        sync: impl Fn(&Config, &str) -> Result<SyncOutcome> + Send + Sync + 'static,
+    ) -> Self {
+        self.sync = Arc::new(sync);
+        self
     }
 
+    /// Resume the session, or launch it when it is still planned
     pub fn resume_session(&self, session: &SessionInfo) -> Result<()> {
         if !session.worktree_path.exists() {
             return Err(crate::utils::ParaError::file_operation(format!(
@@ -24,6 +60,8 @@ impl MonitorActions {
         }
 
         let session_name = session.name.clone();
+        let repo_root = self.repo_root.clone();
+        let subcommand = resume_subcommand(session);
 
         // Check if the session was created with dangerous flag
         let session_manager = SessionManager::new(&self.config);
@@ -38,10 +76,13 @@ impl MonitorActions {
             use std::process::{Command, Stdio};
 
             let mut cmd = Command::new("para");
-            cmd.arg("resume").arg(&session_name);
+            cmd.arg(subcommand).arg(&session_name);
+            if let Some(root) = repo_root {
+                cmd.current_dir(root);
+            }
 
             // Add dangerous flag if the session was originally created with it
-            if use_dangerous_flag {
+            if use_dangerous_flag && subcommand == "resume" {
                 cmd.arg("--dangerously-skip-permissions");
             }
 
@@ -55,48 +96,78 @@ impl MonitorActions {
         Ok(())
     }
 
-    pub fn finish_session(&self, session: &SessionInfo, message: String) -> Result<()> {
+    /// Job that finishes the session on the operation queue's worker
+    pub fn finish_operation(&self, session: &SessionInfo, message: String) -> Operation {
+        let config = self.config.clone();
+        let name = session.name.clone();
         let worktree_path = session.worktree_path.clone();
-        let branch = session.branch.clone();
 
-        std::thread::spawn(move || {
-            if let Ok(git_service) = GitService::discover_from(&worktree_path) {
-                let finish_request = crate::core::git::FinishRequest {
-                    feature_branch: branch,
-                    commit_message: message,
-                    target_branch_name: None,
-                };
-                let _ = git_service.finish_session(finish_request);
-            }
-        });
-
-        Ok(())
+        let key = operation_key(session);
+        Operation::new(session.name.clone(), OperationKind::Finish, move || {
+            let run = || -> Result<String> {
+                let session_state = SessionManager::new(&config).load_state(&name)?;
+                let finish_request = session_state.finish_request(message, None);
+                let git_service = GitService::discover_from(&worktree_path)?;
+                let FinishResult::Success { final_branch } =
+                    git_service.finish_session(finish_request)?;
+                Ok(format!("Finished {name} on {final_branch}"))
+            };
+            run().map_err(|e| format!("Failed to finish {name}: {e}"))
+        })
+        .keyed(key)
     }
 
-    pub fn cancel_session(&self, session: &SessionInfo) -> Result<()> {
-        let session_manager = SessionManager::new(&self.config);
-
-        if let Ok(session_state) = session_manager.load_state(&session.name) {
-            let worktree_path = session.worktree_path.clone();
-            let branch = session_state.branch.clone();
-            let name = session_state.name.clone();
-            let prefix = self.config.git.branch_prefix.clone();
-            let worktree_to_remove = session_state.worktree_path.clone();
+    /// Job that archives the session's branch, removes its worktree and
+    /// deletes its state on the operation queue's worker
+    pub fn cancel_operation(&self, session: &SessionInfo) -> Operation {
+        let config = self.config.clone();
+        let name = session.name.clone();
+        let worktree_path = session.worktree_path.clone();
 
-            std::thread::spawn(move || {
+        let key = operation_key(session);
+        Operation::new(session.name.clone(), OperationKind::Cancel, move || {
+            let run = || -> Result<String> {
+                let session_manager = SessionManager::new(&config);
+                let session_state = session_manager.load_state(&name)?;
                 if let Ok(git_service) = GitService::discover_from(&worktree_path) {
-                    let _ = git_service.archive_branch_with_session_name(&branch, &name, &prefix);
+                    let _ = git_service.archive_session_branch(
+                        &session_state.branch,
+                        &session_state.name,
+                        &config.git.branch_prefix,
+                        Some(&session_state.worktree_path),
+                    );
                     let _ = git_service
                         .worktree_manager()
-                        .force_remove_worktree(&worktree_to_remove);
+                        .force_remove_worktree(&session_state.worktree_path);
                 }
-            });
-
-            // Delete session state
-            session_manager.delete_state(&session_state.name)?;
-        }
+                session_manager.delete_state(&session_state.name)?;
+                Ok(format!("Cancelled {name}"))
+            };
+            run().map_err(|e| format!("Failed to cancel {name}: {e}"))
+        })
+        .keyed(key)
+    }
 
-        Ok(())
+    /// Job that rebases the session onto its base on the operation queue's
+    /// worker, the same way `para sync` does
+    pub fn sync_operation(&self, session: &SessionInfo) -> Operation {
+        let config = self.config.clone();
+        let name = session.name.clone();
+        let sync = Arc::clone(&self.sync);
+
+        let key = operation_key(session);
+        Operation::with_conflicts(
+            session.name.clone(),
+            OperationKind::Sync,
+            move || match sync(&config, &name) {
+                Ok(outcome @ SyncOutcome::Conflicted { .. }) => {
+                    Err(OperationError::conflict(outcome.describe(&name)))
+                }
+                Ok(outcome) => Ok(outcome.describe(&name)),
+                Err(e) => Err(format!("Failed to sync {name}: {e}").into()),
+            },
+        )
+        .keyed(key)
     }
 
     pub fn integrate_session(&self, session: &SessionInfo) -> Result<()> {
@@ -117,6 +188,21 @@ impl MonitorActions {
     }
 }
 
+fn sync_named_session(config: &Config, name: &str) -> Result<SyncOutcome> {
+    let session_manager = SessionManager::new(config);
+    let session_state = session_manager.load_state(name)?;
+    sync_session(&session_manager, &session_state)
+}
+
+/// `para` subcommand Enter runs on the session
+pub fn resume_subcommand(session: &SessionInfo) -> &'static str {
+    if matches!(session.status, crate::ui::monitor::SessionStatus::Planned) {
+        "launch"
+    } else {
+        "resume"
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -136,6 +222,23 @@ mod tests {
             diff_stats: None,
             todo_percentage: None,
             is_blocked: false,
+            skip_permissions: false,
+            operation: None,
+            activity_source: None,
+            integration: None,
+            agent_status_stale: false,
+            repo_missing: false,
+            repo_root: None,
+            merge_mode: crate::core::git::MergeMode::default(),
+            note: None,
+            container: None,
+            todos: None,
+            stacked_on: None,
+            worktree_status: None,
+            detached_at: None,
+            links: Vec::new(),
+            ahead_behind: None,
+            pinned: false,
         }
     }
 
@@ -222,6 +325,23 @@ mod tests {
             diff_stats: None,
             todo_percentage: None,
             is_blocked: false,
+            skip_permissions: false,
+            operation: None,
+            activity_source: None,
+            integration: None,
+            agent_status_stale: false,
+            repo_missing: false,
+            repo_root: None,
+            merge_mode: crate::core::git::MergeMode::default(),
+            note: None,
+            container: None,
+            todos: None,
+            stacked_on: None,
+            worktree_status: None,
+            detached_at: None,
+            links: Vec::new(),
+            ahead_behind: None,
+            pinned: false,
         };
 
         // The resume_session function should check the session state
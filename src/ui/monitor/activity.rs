@@ -1,4 +1,7 @@
+use crate::core::git::command::{self, GitCommandOptions};
+use crate::ui::monitor::cache::BackgroundCache;
 use chrono::{DateTime, Utc};
+use std::collections::VecDeque;
 use std::path::Path;
 use std::process::Command;
 use std::time::SystemTime;
@@ -216,6 +219,231 @@ fn system_time_to_datetime(time: SystemTime) -> Option<DateTime<Utc>> {
         .and_then(|duration| DateTime::from_timestamp(duration.as_secs() as i64, 0))
 }
 
+/// Where a session's last activity was observed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActivitySource {
+    /// A file in the worktree was modified
+    Files,
+    /// A commit landed on the session branch
+    Commit,
+    /// The agent updated its status file
+    Status,
+}
+
+impl ActivitySource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ActivitySource::Files => "files",
+            ActivitySource::Commit => "commit",
+            ActivitySource::Status => "status",
+        }
+    }
+}
+
+/// The newest activity across all sources and the source it came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Activity {
+    pub at: DateTime<Utc>,
+    pub source: ActivitySource,
+}
+
+/// Pick the newest of the per-source timestamps. On ties the earlier source in
+/// the order files, commit, status wins.
+pub fn combine_activity(
+    files: Option<DateTime<Utc>>,
+    commit: Option<DateTime<Utc>>,
+    status: Option<DateTime<Utc>>,
+) -> Option<Activity> {
+    [
+        (files, ActivitySource::Files),
+        (commit, ActivitySource::Commit),
+        (status, ActivitySource::Status),
+    ]
+    .into_iter()
+    .map(|(at, source)| at.map(|at| Activity { at, source }))
+    .fold(None, newest_activity)
+}
+
+/// The later of two activities, preferring `current` on ties
+fn newest_activity(current: Option<Activity>, candidate: Option<Activity>) -> Option<Activity> {
+    match (current, candidate) {
+        (Some(c), Some(n)) if n.at > c.at => Some(n),
+        (None, n) => n,
+        (c, _) => c,
+    }
+}
+
+fn status_activity(status_updated: Option<DateTime<Utc>>) -> Option<Activity> {
+    status_updated.map(|at| Activity {
+        at,
+        source: ActivitySource::Status,
+    })
+}
+
+/// Upper bound on directory entries visited per worktree scan
+pub const MAX_SCANNED_ENTRIES: usize = 5000;
+/// Directories deeper than this below the worktree root are not scanned
+pub const MAX_SCAN_DEPTH: usize = 12;
+/// Directories never worth scanning, whether or not they are gitignored
+const ALWAYS_SKIPPED_DIRS: &[&str] = &[".git", ".para", "node_modules", "target"];
+
+/// The subset of `.gitignore` syntax the activity scan honours: plain names,
+/// `name/` directories and `*.ext` suffixes from the worktree root file.
+#[derive(Debug, Default)]
+struct IgnoreRules {
+    names: Vec<String>,
+    suffixes: Vec<String>,
+}
+
+impl IgnoreRules {
+    fn load(worktree_path: &Path) -> Self {
+        let mut rules = Self::default();
+        let Ok(contents) = std::fs::read_to_string(worktree_path.join(".gitignore")) else {
+            return rules;
+        };
+
+        for line in contents.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') || line.starts_with('!') {
+                continue;
+            }
+            let pattern = line.trim_start_matches('/').trim_end_matches('/');
+            if let Some(suffix) = pattern.strip_prefix('*') {
+                if !suffix.is_empty() && !suffix.contains(['*', '?', '[', '/']) {
+                    rules.suffixes.push(suffix.to_string());
+                }
+            } else if !pattern.is_empty() && !pattern.contains(['*', '?', '[', '/']) {
+                rules.names.push(pattern.to_string());
+            }
+        }
+        rules
+    }
+
+    fn is_ignored(&self, name: &str) -> bool {
+        ALWAYS_SKIPPED_DIRS.contains(&name)
+            || self.names.iter().any(|n| n == name)
+            || self.suffixes.iter().any(|s| name.ends_with(s.as_str()))
+    }
+}
+
+/// Newest file mtime in the worktree, from a breadth-first walk that stops
+/// after `max_entries` entries and skips ignored paths and symlinks.
+pub fn newest_file_mtime(worktree_path: &Path, max_entries: usize) -> Option<DateTime<Utc>> {
+    let rules = IgnoreRules::load(worktree_path);
+    let mut queue = VecDeque::from([(worktree_path.to_path_buf(), 0usize)]);
+    let mut latest: Option<SystemTime> = None;
+    let mut visited = 0usize;
+
+    while let Some((dir, depth)) = queue.pop_front() {
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            if visited >= max_entries {
+                return latest.and_then(system_time_to_datetime);
+            }
+            visited += 1;
+
+            let name = entry.file_name();
+            if rules.is_ignored(&name.to_string_lossy()) {
+                continue;
+            }
+            let Ok(file_type) = entry.file_type() else {
+                continue;
+            };
+            if file_type.is_dir() {
+                if depth < MAX_SCAN_DEPTH {
+                    queue.push_back((entry.path(), depth + 1));
+                }
+            } else if file_type.is_file() {
+                if let Some(modified) = entry.metadata().ok().and_then(|m| m.modified().ok()) {
+                    update_latest_time(&mut latest, modified);
+                }
+            }
+        }
+    }
+
+    latest.and_then(system_time_to_datetime)
+}
+
+/// Commit time of the tip of `branch`, or of HEAD when `branch` is empty
+fn branch_commit_time(worktree_path: &Path, branch: &str) -> Option<DateTime<Utc>> {
+    let rev = if branch.is_empty() { "HEAD" } else { branch };
+    let output = command::output(
+        worktree_path,
+        &["log", "-1", "--format=%ct", rev, "--"],
+        GitCommandOptions::default(),
+    )
+    .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+    String::from_utf8_lossy(&output.stdout)
+        .trim()
+        .parse::<i64>()
+        .ok()
+        .and_then(|ts| DateTime::from_timestamp(ts, 0))
+}
+
+/// Activity from the worktree itself: sampled file mtimes and the branch tip
+pub fn detect_worktree_activity(worktree_path: &Path, branch: &str) -> Option<Activity> {
+    if !worktree_path.exists() {
+        return None;
+    }
+    combine_activity(
+        newest_file_mtime(worktree_path, MAX_SCANNED_ENTRIES),
+        branch_commit_time(worktree_path, branch),
+        None,
+    )
+}
+
+/// Full activity for a session, adding the status file's last update
+pub fn detect_session_activity(
+    worktree_path: &Path,
+    branch: &str,
+    status_updated: Option<DateTime<Utc>>,
+) -> Option<Activity> {
+    newest_activity(
+        detect_worktree_activity(worktree_path, branch),
+        status_activity(status_updated),
+    )
+}
+
+/// Tracks worktree activity for the monitor without blocking rendering.
+///
+/// Worktree scans are cached for a short TTL and refreshed on a background
+/// thread; until a scan finishes the last known value is returned. The status
+/// timestamp is cheap to read and is combined in on every call.
+#[derive(Clone)]
+pub struct ActivityTracker {
+    cache: BackgroundCache<Option<Activity>>,
+}
+
+impl ActivityTracker {
+    pub fn new(ttl_seconds: i64) -> Self {
+        Self {
+            cache: BackgroundCache::new(ttl_seconds),
+        }
+    }
+
+    pub fn last_activity(
+        &self,
+        worktree_path: &Path,
+        branch: &str,
+        status_updated: Option<DateTime<Utc>>,
+    ) -> Option<Activity> {
+        let path = worktree_path.to_path_buf();
+        let branch = branch.to_string();
+        let worktree = self
+            .cache
+            .get_or_refresh(worktree_path, move || {
+                detect_worktree_activity(&path, &branch)
+            })
+            .flatten();
+        newest_activity(worktree, status_activity(status_updated))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -492,4 +720,115 @@ mod tests {
             );
         }
     }
+
+    fn at(secs: i64) -> DateTime<Utc> {
+        DateTime::from_timestamp(secs, 0).unwrap()
+    }
+
+    fn write_with_mtime(path: &Path, secs: i64) {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).unwrap();
+        }
+        fs::write(path, "content").unwrap();
+        set_file_mtime(path, FileTime::from_unix_time(secs, 0)).unwrap();
+    }
+
+    #[test]
+    fn test_combine_activity_picks_newest_source() {
+        assert_eq!(combine_activity(None, None, None), None);
+
+        let activity = combine_activity(Some(at(100)), Some(at(300)), Some(at(200))).unwrap();
+        assert_eq!(activity.at, at(300));
+        assert_eq!(activity.source, ActivitySource::Commit);
+
+        let activity = combine_activity(Some(at(100)), None, Some(at(400))).unwrap();
+        assert_eq!(activity.source, ActivitySource::Status);
+
+        // Ties go to the earlier source
+        let activity = combine_activity(Some(at(500)), Some(at(500)), Some(at(500))).unwrap();
+        assert_eq!(activity.source, ActivitySource::Files);
+    }
+
+    #[test]
+    fn test_newest_file_mtime_skips_ignored_paths() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let root = dir.path();
+        fs::write(root.join(".gitignore"), "*.log\n/build/\n").unwrap();
+        set_file_mtime(root.join(".gitignore"), FileTime::from_unix_time(1_000, 0)).unwrap();
+
+        write_with_mtime(&root.join("src/lib.rs"), 2_000);
+        write_with_mtime(&root.join("src/deep/mod.rs"), 3_000);
+        write_with_mtime(&root.join("debug.log"), 9_000);
+        write_with_mtime(&root.join("build/out.bin"), 9_000);
+        write_with_mtime(&root.join("node_modules/pkg/index.js"), 9_000);
+
+        assert_eq!(
+            newest_file_mtime(root, MAX_SCANNED_ENTRIES),
+            Some(at(3_000))
+        );
+        assert_eq!(newest_file_mtime(root, 0), None);
+    }
+
+    #[test]
+    fn test_detect_session_activity_reports_contributing_source() {
+        let (git_temp, _git_service) = setup_test_repo();
+        let root = git_temp.path();
+        let commit_time = get_last_commit_time(root).unwrap();
+
+        // Everything in the worktree predates the commit
+        for entry in fs::read_dir(root).unwrap().flatten() {
+            if entry.file_type().unwrap().is_file() {
+                set_file_mtime(entry.path(), FileTime::from_unix_time(1_000, 0)).unwrap();
+            }
+        }
+        let activity = detect_session_activity(root, "main", None).unwrap();
+        assert_eq!(activity.source, ActivitySource::Commit);
+        assert_eq!(activity.at, commit_time);
+
+        let status_time = commit_time + chrono::Duration::minutes(5);
+        let activity = detect_session_activity(root, "main", Some(status_time)).unwrap();
+        assert_eq!(activity.source, ActivitySource::Status);
+        assert_eq!(activity.at, status_time);
+
+        let file_time = commit_time + chrono::Duration::minutes(10);
+        write_with_mtime(&root.join("notes.md"), file_time.timestamp());
+        let activity = detect_session_activity(root, "main", Some(status_time)).unwrap();
+        assert_eq!(activity.source, ActivitySource::Files);
+        assert_eq!(activity.at, file_time);
+    }
+
+    #[test]
+    fn test_tracker_computes_in_background_and_combines_status() {
+        let dir = tempfile::TempDir::new().unwrap();
+        write_with_mtime(&dir.path().join("main.rs"), 5_000);
+        let tracker = ActivityTracker::new(60);
+
+        // The first call only starts the scan; the status timestamp is used meanwhile
+        let first = tracker
+            .last_activity(dir.path(), "", Some(at(4_000)))
+            .unwrap();
+        assert!(first.at >= at(4_000));
+
+        let mut activity = Some(first);
+        for _ in 0..100 {
+            activity = tracker.last_activity(dir.path(), "", Some(at(4_000)));
+            if activity.is_some_and(|a| a.source == ActivitySource::Files) {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(20));
+        }
+        assert_eq!(
+            activity,
+            Some(Activity {
+                at: at(5_000),
+                source: ActivitySource::Files
+            })
+        );
+
+        // A newer status update wins without rescanning
+        let activity = tracker
+            .last_activity(dir.path(), "", Some(at(6_000)))
+            .unwrap();
+        assert_eq!(activity.source, ActivitySource::Status);
+    }
 }
@@ -1,21 +1,22 @@
 use chrono::{DateTime, Duration, Utc};
-use std::collections::HashMap;
-use std::path::PathBuf;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
 
 #[derive(Clone, Debug)]
-struct CacheEntry {
-    activity_time: Option<DateTime<Utc>>,
+struct CacheEntry<T> {
+    value: T,
     cached_at: DateTime<Utc>,
 }
 
+/// Per-worktree values that expire after a TTL
 #[derive(Clone)]
-pub struct ActivityCache {
-    entries: Arc<Mutex<HashMap<PathBuf, CacheEntry>>>,
+pub struct ActivityCache<T> {
+    entries: Arc<Mutex<HashMap<PathBuf, CacheEntry<T>>>>,
     ttl: Duration,
 }
 
-impl ActivityCache {
+impl<T: Clone> ActivityCache<T> {
     pub fn new(ttl_seconds: i64) -> Self {
         Self {
             entries: Arc::new(Mutex::new(HashMap::new())),
@@ -23,29 +24,88 @@ impl ActivityCache {
         }
     }
 
-    pub fn get(&self, path: &PathBuf) -> Option<Option<DateTime<Utc>>> {
+    pub fn get(&self, path: &PathBuf) -> Option<T> {
         let entries = self.entries.lock().unwrap();
         if let Some(entry) = entries.get(path) {
             let age = Utc::now() - entry.cached_at;
             if age < self.ttl {
-                return Some(entry.activity_time);
+                return Some(entry.value.clone());
             }
         }
         None
     }
 
-    pub fn set(&self, path: PathBuf, activity_time: Option<DateTime<Utc>>) {
+    /// Last stored value, even if it has expired
+    pub fn last_known(&self, path: &PathBuf) -> Option<T> {
+        let entries = self.entries.lock().unwrap();
+        entries.get(path).map(|entry| entry.value.clone())
+    }
+
+    /// Keep every value as last known but treat it as expired
+    pub fn expire_all(&self) {
+        let expired_at = Utc::now() - self.ttl;
+        for entry in self.entries.lock().unwrap().values_mut() {
+            entry.cached_at = expired_at;
+        }
+    }
+
+    pub fn set(&self, path: PathBuf, value: T) {
         let mut entries = self.entries.lock().unwrap();
         entries.insert(
             path,
             CacheEntry {
-                activity_time,
+                value,
                 cached_at: Utc::now(),
             },
         );
     }
 }
 
+/// A cache whose expired entries are recomputed on a background thread, so
+/// callers on the render path never wait for the computation
+#[derive(Clone)]
+pub struct BackgroundCache<T> {
+    cache: ActivityCache<T>,
+    pending: Arc<Mutex<HashSet<PathBuf>>>,
+}
+
+impl<T: Clone + Send + 'static> BackgroundCache<T> {
+    pub fn new(ttl_seconds: i64) -> Self {
+        Self {
+            cache: ActivityCache::new(ttl_seconds),
+            pending: Arc::new(Mutex::new(HashSet::new())),
+        }
+    }
+
+    /// The fresh value for `path`, or the last known one (if any) while
+    /// `compute` runs in the background
+    pub fn get_or_refresh<F>(&self, path: &Path, compute: F) -> Option<T>
+    where
+        F: FnOnce() -> T + Send + 'static,
+    {
+        let path = path.to_path_buf();
+        if let Some(fresh) = self.cache.get(&path) {
+            return Some(fresh);
+        }
+
+        if self.pending.lock().unwrap().insert(path.clone()) {
+            let cache = self.cache.clone();
+            let pending = Arc::clone(&self.pending);
+            let key = path.clone();
+            std::thread::spawn(move || {
+                cache.set(key.clone(), compute());
+                pending.lock().unwrap().remove(&key);
+            });
+        }
+        self.cache.last_known(&path)
+    }
+
+    /// Recompute every value on its next lookup, showing the old one meanwhile
+    pub fn expire_all(&self) {
+        self.cache.expire_all();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -84,7 +144,7 @@ mod tests {
 
     #[test]
     fn test_cache_none_values() {
-        let cache = ActivityCache::new(5);
+        let cache: ActivityCache<Option<DateTime<Utc>>> = ActivityCache::new(5);
         let path = PathBuf::from("/test/path");
 
         // Test caching None values
@@ -1,7 +1,9 @@
 use crate::config::Config;
+use crate::core::events::{self, EventSubscription};
 use crate::ui::monitor::action_dispatcher::{ActionDispatcher, ActionResult};
 use crate::ui::monitor::actions::MonitorActions;
 use crate::ui::monitor::event_handler::EventHandler;
+use crate::ui::monitor::fetch::AutoFetcher;
 use crate::ui::monitor::renderer::MonitorRenderer;
 use crate::ui::monitor::service::SessionService;
 use crate::ui::monitor::state::MonitorAppState;
@@ -10,6 +12,9 @@ use crate::ui::monitor::SessionInfo;
 use crate::utils::Result;
 use crossterm::event::{KeyEvent, MouseEvent, MouseEventKind};
 use ratatui::Frame;
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use std::time::Instant;
 
 /// High-level coordinator for the monitor UI that orchestrates components
 pub struct MonitorCoordinator {
@@ -19,6 +24,10 @@ pub struct MonitorCoordinator {
     event_handler: EventHandler,
     action_dispatcher: ActionDispatcher,
     state_manager: StateManager,
+    /// Fetches the base branches with `git.auto_fetch`
+    fetcher: Option<AutoFetcher>,
+    /// Session events of each state directory that could be watched
+    events: Vec<EventSubscription>,
 }
 
 impl MonitorCoordinator {
@@ -27,9 +36,57 @@ impl MonitorCoordinator {
         let actions = MonitorActions::new(config.clone());
         let service = SessionService::new(config.clone());
         let action_dispatcher = ActionDispatcher::new(actions);
-        let event_handler = EventHandler::new();
         let state_manager = StateManager::new(service);
-        let state = MonitorAppState::new();
+        let repo_roots = crate::core::git::GitService::discover()
+            .map(|git_service| vec![git_service.repository().root.clone()])
+            .unwrap_or_default();
+        let fetcher = AutoFetcher::for_repos(&config, repo_roots);
+        let mut coordinator =
+            Self::with_components(renderer, action_dispatcher, state_manager, fetcher);
+        coordinator.state.behind_warning_commits = config.get_behind_warning_commits();
+        coordinator
+    }
+
+    /// Coordinator showing the sessions of every repository in `repo_roots`,
+    /// with each session's actions routed to its own repository
+    pub fn for_repos(config: Config, repo_roots: &[PathBuf]) -> Self {
+        let renderer = MonitorRenderer::new(config.clone()).with_repo_column();
+        let repo_actions: HashMap<PathBuf, MonitorActions> = repo_roots
+            .iter()
+            .map(|root| (root.clone(), MonitorActions::for_repo(&config, root)))
+            .collect();
+        let action_dispatcher =
+            ActionDispatcher::for_repos(MonitorActions::new(config.clone()), repo_actions);
+        let services = repo_roots
+            .iter()
+            .map(|root| SessionService::for_repo(&config, root))
+            .collect();
+        let state_manager = StateManager::for_repos(services);
+        let fetcher = AutoFetcher::for_repos(&config, state_manager.repo_roots());
+        let mut coordinator =
+            Self::with_components(renderer, action_dispatcher, state_manager, fetcher);
+        coordinator.state.behind_warning_commits = config.get_behind_warning_commits();
+        coordinator
+    }
+
+    fn with_components(
+        renderer: MonitorRenderer,
+        action_dispatcher: ActionDispatcher,
+        state_manager: StateManager,
+        fetcher: Option<AutoFetcher>,
+    ) -> Self {
+        let event_handler = EventHandler::new();
+        let mut state = MonitorAppState::new();
+        state_manager.load_prefs(&mut state);
+
+        // Without events for every state directory the periodic refresh
+        // has to catch all changes
+        let state_dirs = state_manager.state_dirs();
+        let events: Vec<EventSubscription> = state_dirs
+            .iter()
+            .filter_map(|dir| events::subscribe(dir).ok())
+            .collect();
+        state.events_connected = events.len() == state_dirs.len();
 
         let mut coordinator = Self {
             state,
@@ -38,14 +95,60 @@ impl MonitorCoordinator {
             event_handler,
             action_dispatcher,
             state_manager,
+            fetcher,
+            events,
         };
 
         coordinator.refresh_sessions();
         coordinator
     }
 
+    /// Pick up finished jobs, refreshing the sessions when any finished.
+    /// Returns whether the screen needs a redraw, which is the case while
+    /// jobs are queued or running so their spinners move.
+    pub fn poll_operations(&mut self) -> bool {
+        let finished = self.action_dispatcher.poll_operations(&mut self.state);
+        if finished {
+            self.refresh_sessions();
+        }
+        finished || !self.state.operations.is_empty()
+    }
+
+    /// Reload the sessions that session events reported as changed.
+    /// Returns whether any were reloaded.
+    pub fn poll_events(&mut self) -> bool {
+        let names: HashSet<String> = self
+            .events
+            .iter()
+            .flat_map(|subscription| subscription.poll())
+            .map(|event| event.session)
+            .collect();
+        if self.events.iter().any(EventSubscription::failed) {
+            self.state.events_connected = false;
+        }
+        if names.is_empty() {
+            return false;
+        }
+
+        let sessions = std::mem::take(&mut self.sessions);
+        let sessions = self
+            .state_manager
+            .reload_sessions(&self.state, sessions, &names);
+        self.sessions = self
+            .state_manager
+            .update_sessions(&mut self.state, sessions);
+        true
+    }
+
     pub fn refresh_sessions(&mut self) {
-        let new_sessions = self.state_manager.load_sessions(self.state.show_stale);
+        if let Some(fetcher) = &mut self.fetcher {
+            if fetcher.poll(Instant::now()) {
+                self.state_manager.handle_fetch_finished();
+            }
+            self.state.fetch_failed = fetcher.failed();
+        }
+
+        let new_sessions = self.state_manager.load_sessions(&self.state);
         self.sessions = self
             .state_manager
             .update_sessions(&mut self.state, new_sessions);
@@ -112,6 +215,13 @@ impl MonitorCoordinator {
             ActionResult::RefreshSessions => {
                 self.refresh_sessions();
             }
+            ActionResult::PreferencesChanged => {
+                if let Err(e) = self.state_manager.save_prefs(&self.state) {
+                    self.state
+                        .show_error(format!("Failed to save monitor preferences: {e}"));
+                }
+                self.refresh_sessions();
+            }
             ActionResult::Continue => {
                 // No additional action needed
             }
@@ -259,7 +369,7 @@ mod tests {
 
         // Test stale toggle (now starts as true)
         assert!(coordinator.state.show_stale);
-        let stale_key = KeyEvent::new(KeyCode::Char('s'), KeyModifiers::NONE);
+        let stale_key = KeyEvent::new(KeyCode::Char('h'), KeyModifiers::NONE);
         assert!(coordinator.handle_key(stale_key).is_ok());
         assert!(!coordinator.state.show_stale);
     }
@@ -399,6 +509,23 @@ mod tests {
             diff_stats: None,
             todo_percentage: None,
             is_blocked: false,
+            skip_permissions: false,
+            operation: None,
+            activity_source: None,
+            integration: None,
+            agent_status_stale: false,
+            repo_missing: false,
+            repo_root: None,
+            merge_mode: crate::core::git::MergeMode::default(),
+            note: None,
+            container: None,
+            todos: None,
+            stacked_on: None,
+            worktree_status: None,
+            detached_at: None,
+            links: Vec::new(),
+            ahead_behind: None,
+            pinned: false,
         };
         coordinator.sessions = vec![mock_session];
 
@@ -448,6 +575,23 @@ mod tests {
             diff_stats: None,
             todo_percentage: None,
             is_blocked: false,
+            skip_permissions: false,
+            operation: None,
+            activity_source: None,
+            integration: None,
+            agent_status_stale: false,
+            repo_missing: false,
+            repo_root: None,
+            merge_mode: crate::core::git::MergeMode::default(),
+            note: None,
+            container: None,
+            todos: None,
+            stacked_on: None,
+            worktree_status: None,
+            detached_at: None,
+            links: Vec::new(),
+            ahead_behind: None,
+            pinned: false,
         };
         coordinator.sessions = vec![mock_session];
 
@@ -497,6 +641,23 @@ mod tests {
             diff_stats: None,
             todo_percentage: None,
             is_blocked: false,
+            skip_permissions: false,
+            operation: None,
+            activity_source: None,
+            integration: None,
+            agent_status_stale: false,
+            repo_missing: false,
+            repo_root: None,
+            merge_mode: crate::core::git::MergeMode::default(),
+            note: None,
+            container: None,
+            todos: None,
+            stacked_on: None,
+            worktree_status: None,
+            detached_at: None,
+            links: Vec::new(),
+            ahead_behind: None,
+            pinned: false,
         };
         coordinator.sessions = vec![mock_session];
 
@@ -564,6 +725,23 @@ mod tests {
             diff_stats: None,
             todo_percentage: None,
             is_blocked: false,
+            skip_permissions: false,
+            operation: None,
+            activity_source: None,
+            integration: None,
+            agent_status_stale: false,
+            repo_missing: false,
+            repo_root: None,
+            merge_mode: crate::core::git::MergeMode::default(),
+            note: None,
+            container: None,
+            todos: None,
+            stacked_on: None,
+            worktree_status: None,
+            detached_at: None,
+            links: Vec::new(),
+            ahead_behind: None,
+            pinned: false,
         };
         coordinator.sessions = vec![mock_session];
 
@@ -622,6 +800,23 @@ mod tests {
                 diff_stats: None,
                 todo_percentage: None,
                 is_blocked: false,
+                skip_permissions: false,
+                operation: None,
+                activity_source: None,
+                integration: None,
+                agent_status_stale: false,
+                repo_missing: false,
+                repo_root: None,
+                merge_mode: crate::core::git::MergeMode::default(),
+                note: None,
+                container: None,
+                todos: None,
+                stacked_on: None,
+                worktree_status: None,
+                detached_at: None,
+                links: Vec::new(),
+                ahead_behind: None,
+                pinned: false,
             },
             SessionInfo {
                 name: "session2".to_string(),
@@ -634,6 +829,23 @@ mod tests {
                 diff_stats: None,
                 todo_percentage: None,
                 is_blocked: false,
+                skip_permissions: false,
+                operation: None,
+                activity_source: None,
+                integration: None,
+                agent_status_stale: false,
+                repo_missing: false,
+                repo_root: None,
+                merge_mode: crate::core::git::MergeMode::default(),
+                note: None,
+                container: None,
+                todos: None,
+                stacked_on: None,
+                worktree_status: None,
+                detached_at: None,
+                links: Vec::new(),
+                ahead_behind: None,
+                pinned: false,
             },
             SessionInfo {
                 name: "session3".to_string(),
@@ -646,6 +858,23 @@ mod tests {
                 diff_stats: None,
                 todo_percentage: None,
                 is_blocked: false,
+                skip_permissions: false,
+                operation: None,
+                activity_source: None,
+                integration: None,
+                agent_status_stale: false,
+                repo_missing: false,
+                repo_root: None,
+                merge_mode: crate::core::git::MergeMode::default(),
+                note: None,
+                container: None,
+                todos: None,
+                stacked_on: None,
+                worktree_status: None,
+                detached_at: None,
+                links: Vec::new(),
+                ahead_behind: None,
+                pinned: false,
             },
         ];
         coordinator.sessions = sessions;
@@ -685,7 +914,7 @@ mod tests {
 
         // Test stale toggle
         let initial_stale = coordinator.state.show_stale;
-        let stale_toggle = KeyEvent::new(KeyCode::Char('s'), KeyModifiers::NONE);
+        let stale_toggle = KeyEvent::new(KeyCode::Char('h'), KeyModifiers::NONE);
         assert!(coordinator.handle_key(stale_toggle).is_ok());
         assert_eq!(coordinator.state.show_stale, !initial_stale);
 
@@ -726,6 +955,23 @@ mod tests {
             diff_stats: None,
             todo_percentage: None,
             is_blocked: false,
+            skip_permissions: false,
+            operation: None,
+            activity_source: None,
+            integration: None,
+            agent_status_stale: false,
+            repo_missing: false,
+            repo_root: None,
+            merge_mode: crate::core::git::MergeMode::default(),
+            note: None,
+            container: None,
+            todos: None,
+            stacked_on: None,
+            worktree_status: None,
+            detached_at: None,
+            links: Vec::new(),
+            ahead_behind: None,
+            pinned: false,
         };
         coordinator.sessions = vec![mock_session];
 
@@ -792,6 +1038,23 @@ mod tests {
             diff_stats: None,
             todo_percentage: None,
             is_blocked: false,
+            skip_permissions: false,
+            operation: None,
+            activity_source: None,
+            integration: None,
+            agent_status_stale: false,
+            repo_missing: false,
+            repo_root: None,
+            merge_mode: crate::core::git::MergeMode::default(),
+            note: None,
+            container: None,
+            todos: None,
+            stacked_on: None,
+            worktree_status: None,
+            detached_at: None,
+            links: Vec::new(),
+            ahead_behind: None,
+            pinned: false,
         };
         coordinator.sessions = vec![mock_session];
 
@@ -809,7 +1072,7 @@ mod tests {
         assert_eq!(coordinator.state.mode, AppMode::Normal);
 
         // Test switching to CancelConfirm mode
-        let cancel_key = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::NONE);
+        let cancel_key = KeyEvent::new(KeyCode::Char('x'), KeyModifiers::NONE);
         assert!(coordinator.handle_key(cancel_key).is_ok());
         assert_eq!(coordinator.state.mode, AppMode::CancelConfirm);
 
@@ -864,6 +1127,23 @@ mod tests {
             diff_stats: None,
             todo_percentage: None,
             is_blocked: false,
+            skip_permissions: false,
+            operation: None,
+            activity_source: None,
+            integration: None,
+            agent_status_stale: false,
+            repo_missing: false,
+            repo_root: None,
+            merge_mode: crate::core::git::MergeMode::default(),
+            note: None,
+            container: None,
+            todos: None,
+            stacked_on: None,
+            worktree_status: None,
+            detached_at: None,
+            links: Vec::new(),
+            ahead_behind: None,
+            pinned: false,
         };
         coordinator.sessions = vec![session1];
         coordinator
@@ -891,6 +1171,23 @@ mod tests {
             diff_stats: None,
             todo_percentage: None,
             is_blocked: false,
+            skip_permissions: false,
+            operation: None,
+            activity_source: None,
+            integration: None,
+            agent_status_stale: false,
+            repo_missing: false,
+            repo_root: None,
+            merge_mode: crate::core::git::MergeMode::default(),
+            note: None,
+            container: None,
+            todos: None,
+            stacked_on: None,
+            worktree_status: None,
+            detached_at: None,
+            links: Vec::new(),
+            ahead_behind: None,
+            pinned: false,
         };
         coordinator.sessions.push(session2);
 
@@ -978,6 +1275,23 @@ mod tests {
             diff_stats: None,
             todo_percentage: None,
             is_blocked: false,
+            skip_permissions: false,
+            operation: None,
+            activity_source: None,
+            integration: None,
+            agent_status_stale: false,
+            repo_missing: false,
+            repo_root: None,
+            merge_mode: crate::core::git::MergeMode::default(),
+            note: None,
+            container: None,
+            todos: None,
+            stacked_on: None,
+            worktree_status: None,
+            detached_at: None,
+            links: Vec::new(),
+            ahead_behind: None,
+            pinned: false,
         };
         coordinator.sessions = vec![mock_session];
 
@@ -1005,4 +1319,162 @@ mod tests {
         assert!(coordinator.handle_mouse(click_outside).is_ok());
         assert_eq!(coordinator.state.selected_index, prev_selection);
     }
+
+    #[test]
+    fn test_preferences_persist_between_runs() {
+        use crate::ui::monitor::prefs::{Column, SortColumn};
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let config = crate::test_utils::test_helpers::create_test_config_with_dir(&temp_dir);
+
+        let mut coordinator = MonitorCoordinator::new(config.clone());
+        let press = |coordinator: &mut MonitorCoordinator, code: KeyCode| {
+            coordinator
+                .handle_key(KeyEvent::new(code, KeyModifiers::NONE))
+                .unwrap();
+        };
+
+        press(&mut coordinator, KeyCode::Char('s'));
+        press(&mut coordinator, KeyCode::Char('S'));
+        press(&mut coordinator, KeyCode::Char('r'));
+        press(&mut coordinator, KeyCode::Char('c'));
+        press(&mut coordinator, KeyCode::Char(' '));
+        press(&mut coordinator, KeyCode::Esc);
+        press(&mut coordinator, KeyCode::Char('/'));
+        press(&mut coordinator, KeyCode::Char('a'));
+        press(&mut coordinator, KeyCode::Enter);
+        assert_eq!(coordinator.state.mode, AppMode::Normal);
+
+        let reopened = MonitorCoordinator::new(config);
+        let prefs = &reopened.state.prefs;
+        assert_eq!(prefs.sort_column, SortColumn::Name);
+        assert!(!prefs.sort_descending);
+        assert!(prefs.read_only);
+        assert_eq!(prefs.hidden_columns, vec![Column::Permissions]);
+        assert_eq!(prefs.filter, "a");
+    }
+
+    #[test]
+    fn test_session_events_reload_affected_sessions() {
+        use crate::core::session::{SessionManager, SessionState};
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let config = crate::test_utils::test_helpers::create_test_config_with_dir(&temp_dir);
+        let manager = SessionManager::new(&config);
+        let session = |name: &str| {
+            let worktree = temp_dir.path().join(name);
+            std::fs::create_dir_all(&worktree).unwrap();
+            SessionState::new(name.to_string(), format!("para/{name}"), worktree)
+        };
+        manager.save_state(&session("existing")).unwrap();
+
+        let mut coordinator = MonitorCoordinator::new(config.clone());
+        assert!(coordinator.state.events_connected);
+        assert!(!coordinator.poll_events());
+        let names = |coordinator: &MonitorCoordinator| {
+            let mut names: Vec<String> = coordinator
+                .sessions
+                .iter()
+                .map(|s| s.name.clone())
+                .collect();
+            names.sort();
+            names
+        };
+        assert_eq!(names(&coordinator), vec!["existing"]);
+
+        manager.save_state(&session("added")).unwrap();
+        let deadline = Instant::now() + std::time::Duration::from_secs(5);
+        while !coordinator.poll_events() && Instant::now() < deadline {
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+        assert_eq!(names(&coordinator), vec!["added", "existing"]);
+
+        manager.delete_state("existing").unwrap();
+        let deadline = Instant::now() + std::time::Duration::from_secs(5);
+        while !coordinator.poll_events() && Instant::now() < deadline {
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+        assert_eq!(names(&coordinator), vec!["added"]);
+    }
+
+    #[test]
+    fn test_all_repos_merges_sessions_and_routes_actions() {
+        use crate::core::session::{SessionManager, SessionState};
+        use crate::ui::monitor::event_handler::{DialogAction, UiAction};
+        use crate::ui::monitor::service::config_for_repo;
+
+        let temp = tempfile::TempDir::new().unwrap();
+        let config = create_test_config();
+        let repo_a = temp.path().join("repo-a");
+        let repo_b = temp.path().join("repo-b");
+        let gone = temp.path().join("deleted-repo");
+
+        // Both repositories have a session with the same name
+        for (root, branch) in [(&repo_a, "para/feature-a"), (&repo_b, "para/feature-b")] {
+            let worktree = root.join(".para/worktrees/feature");
+            std::fs::create_dir_all(&worktree).unwrap();
+            SessionManager::new(&config_for_repo(&config, root))
+                .save_state(&SessionState::new(
+                    "feature".to_string(),
+                    branch.to_string(),
+                    worktree,
+                ))
+                .unwrap();
+        }
+
+        let mut coordinator =
+            MonitorCoordinator::for_repos(config.clone(), &[repo_a.clone(), repo_b.clone(), gone]);
+        let mut branches: Vec<(&str, PathBuf)> = coordinator
+            .sessions
+            .iter()
+            .map(|s| (s.branch.as_str(), s.repo_root.clone().unwrap()))
+            .collect();
+        branches.sort();
+        assert_eq!(
+            branches,
+            vec![
+                ("para/feature-a", repo_a.clone()),
+                ("para/feature-b", repo_b.clone())
+            ]
+        );
+        assert!(coordinator.sessions.iter().all(|s| !s.repo_missing));
+
+        // Cancelling the session from repo-b must not touch repo-a's session
+        let index = coordinator
+            .sessions
+            .iter()
+            .position(|s| s.repo_root.as_deref() == Some(repo_b.as_path()))
+            .unwrap();
+        coordinator.state.selected_index = index;
+        coordinator
+            .process_action(UiAction::Dialog(DialogAction::ExecuteCancel))
+            .unwrap();
+        // The cancel runs on the operation queue's worker
+        while !coordinator
+            .action_dispatcher
+            .poll_operations(&mut coordinator.state)
+        {
+            std::thread::sleep(std::time::Duration::from_millis(5));
+        }
+        coordinator.refresh_sessions();
+
+        assert!(SessionManager::new(&config_for_repo(&config, &repo_a)).session_exists("feature"));
+        assert!(!SessionManager::new(&config_for_repo(&config, &repo_b)).session_exists("feature"));
+        assert_eq!(coordinator.sessions.len(), 1);
+        assert_eq!(coordinator.sessions[0].repo_root, Some(repo_a.clone()));
+
+        // Sessions of a repository that is gone are refused
+        coordinator.sessions[0].repo_missing = true;
+        coordinator.state.selected_index = 0;
+        coordinator
+            .process_action(UiAction::Dialog(DialogAction::ExecuteCancel))
+            .unwrap();
+        assert!(SessionManager::new(&config_for_repo(&config, &repo_a)).session_exists("feature"));
+        assert!(coordinator
+            .state
+            .error_message
+            .as_deref()
+            .is_some_and(|e| e.contains("no longer exists")));
+    }
 }
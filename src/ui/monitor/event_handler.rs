@@ -8,6 +8,7 @@ pub enum UiAction {
     Session(SessionAction),
     Navigation(NavigationAction),
     Dialog(DialogAction),
+    View(ViewAction),
     System(SystemAction),
 }
 
@@ -18,6 +19,7 @@ pub enum SessionAction {
     Integrate(usize),
     Finish(usize),
     Cancel(usize),
+    Sync(usize),
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -37,6 +39,26 @@ pub enum DialogAction {
     ExecuteFinish,
     ExecuteCancel,
     ClearError,
+    /// Open the session the error dialog reports conflicts in
+    OpenConflict,
+}
+
+/// Changes to the persisted view preferences
+#[derive(Debug, Clone, PartialEq)]
+pub enum ViewAction {
+    CycleSort,
+    ReverseSort,
+    ToggleReadOnly,
+    StartFilter,
+    FilterChar(char),
+    FilterBackspace,
+    ApplyFilter,
+    ClearFilter,
+    OpenColumnPicker,
+    NextColumn,
+    PreviousColumn,
+    ToggleColumn,
+    CloseColumnPicker,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -64,7 +86,9 @@ impl EventHandler {
             AppMode::Normal => self.handle_normal_key(key, state, sessions),
             AppMode::FinishPrompt => self.handle_finish_prompt_key(key, state),
             AppMode::CancelConfirm => self.handle_cancel_confirm_key(key),
-            AppMode::ErrorDialog => self.handle_error_dialog_key(key),
+            AppMode::ErrorDialog => self.handle_error_dialog_key(key, state),
+            AppMode::FilterInput => self.handle_filter_input_key(key),
+            AppMode::ColumnPicker => self.handle_column_picker_key(key),
         }
     }
 
@@ -77,7 +101,11 @@ impl EventHandler {
     ) -> Option<UiAction> {
         match state.mode {
             AppMode::Normal => self.handle_normal_mouse(mouse, state, sessions),
-            AppMode::FinishPrompt | AppMode::CancelConfirm | AppMode::ErrorDialog => {
+            AppMode::FinishPrompt
+            | AppMode::CancelConfirm
+            | AppMode::ErrorDialog
+            | AppMode::FilterInput
+            | AppMode::ColumnPicker => {
                 // Ignore mouse events in dialog modes
                 None
             }
@@ -97,7 +125,12 @@ impl EventHandler {
             KeyCode::Char('c') => {
                 if key.modifiers.contains(KeyModifiers::CONTROL) {
                     Some(UiAction::System(SystemAction::Quit))
-                } else if state.get_selected_session(sessions).is_some() {
+                } else {
+                    Some(UiAction::View(ViewAction::OpenColumnPicker))
+                }
+            }
+            KeyCode::Char('x') => {
+                if state.get_selected_session(sessions).is_some() {
                     Some(UiAction::Dialog(DialogAction::StartCancel))
                 } else {
                     None
@@ -111,7 +144,11 @@ impl EventHandler {
                     None
                 }
             }
-            KeyCode::Char('s') => Some(UiAction::Navigation(NavigationAction::ToggleStale)),
+            KeyCode::Char('s') => Some(UiAction::View(ViewAction::CycleSort)),
+            KeyCode::Char('S') => Some(UiAction::View(ViewAction::ReverseSort)),
+            KeyCode::Char('h') => Some(UiAction::Navigation(NavigationAction::ToggleStale)),
+            KeyCode::Char('r') => Some(UiAction::View(ViewAction::ToggleReadOnly)),
+            KeyCode::Char('/') => Some(UiAction::View(ViewAction::StartFilter)),
             KeyCode::Up | KeyCode::Char('k') => {
                 Some(UiAction::Navigation(NavigationAction::SelectPrevious))
             }
@@ -148,6 +185,13 @@ impl EventHandler {
                     None
                 }
             }
+            KeyCode::Char('u') => {
+                if state.get_selected_session(sessions).is_some() {
+                    Some(UiAction::Session(SessionAction::Sync(state.selected_index)))
+                } else {
+                    None
+                }
+            }
             _ => None,
         }
     }
@@ -186,10 +230,42 @@ impl EventHandler {
         }
     }
 
-    fn handle_error_dialog_key(&self, key: KeyEvent) -> Option<UiAction> {
+    fn handle_filter_input_key(&self, key: KeyEvent) -> Option<UiAction> {
+        use crossterm::event::{KeyCode, KeyModifiers};
+
+        match key.code {
+            KeyCode::Enter => Some(UiAction::View(ViewAction::ApplyFilter)),
+            KeyCode::Esc => Some(UiAction::View(ViewAction::ClearFilter)),
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                Some(UiAction::View(ViewAction::ClearFilter))
+            }
+            KeyCode::Backspace => Some(UiAction::View(ViewAction::FilterBackspace)),
+            KeyCode::Char(c) => Some(UiAction::View(ViewAction::FilterChar(c))),
+            _ => None,
+        }
+    }
+
+    fn handle_column_picker_key(&self, key: KeyEvent) -> Option<UiAction> {
+        use crossterm::event::KeyCode;
+
+        match key.code {
+            KeyCode::Up | KeyCode::Char('k') => Some(UiAction::View(ViewAction::PreviousColumn)),
+            KeyCode::Down | KeyCode::Char('j') => Some(UiAction::View(ViewAction::NextColumn)),
+            KeyCode::Char(' ') | KeyCode::Enter => Some(UiAction::View(ViewAction::ToggleColumn)),
+            KeyCode::Esc | KeyCode::Char('c') | KeyCode::Char('q') => {
+                Some(UiAction::View(ViewAction::CloseColumnPicker))
+            }
+            _ => None,
+        }
+    }
+
+    fn handle_error_dialog_key(&self, key: KeyEvent, state: &MonitorAppState) -> Option<UiAction> {
         use crossterm::event::{KeyCode, KeyModifiers};
 
         match key.code {
+            KeyCode::Char('o') if state.conflict.is_some() => {
+                Some(UiAction::Dialog(DialogAction::OpenConflict))
+            }
             KeyCode::Enter | KeyCode::Esc | KeyCode::Char(' ') => {
                 Some(UiAction::Dialog(DialogAction::ClearError))
             }
@@ -298,6 +374,23 @@ mod tests {
                 diff_stats: None,
                 todo_percentage: None,
                 is_blocked: false,
+                skip_permissions: false,
+                operation: None,
+                activity_source: None,
+                integration: None,
+                agent_status_stale: false,
+                repo_missing: false,
+                repo_root: None,
+                merge_mode: crate::core::git::MergeMode::default(),
+                note: None,
+                container: None,
+                todos: None,
+                stacked_on: None,
+                worktree_status: None,
+                detached_at: None,
+                links: Vec::new(),
+                ahead_behind: None,
+                pinned: false,
             },
             SessionInfo {
                 name: "session2".to_string(),
@@ -310,6 +403,23 @@ mod tests {
                 diff_stats: None,
                 todo_percentage: None,
                 is_blocked: false,
+                skip_permissions: false,
+                operation: None,
+                activity_source: None,
+                integration: None,
+                agent_status_stale: false,
+                repo_missing: false,
+                repo_root: None,
+                merge_mode: crate::core::git::MergeMode::default(),
+                note: None,
+                container: None,
+                todos: None,
+                stacked_on: None,
+                worktree_status: None,
+                detached_at: None,
+                links: Vec::new(),
+                ahead_behind: None,
+                pinned: false,
             },
         ]
     }
@@ -353,7 +463,7 @@ mod tests {
         );
 
         // Test stale toggle
-        let stale_toggle = KeyEvent::new(KeyCode::Char('s'), KeyModifiers::NONE);
+        let stale_toggle = KeyEvent::new(KeyCode::Char('h'), KeyModifiers::NONE);
         assert_eq!(
             event_handler.handle_key_event(stale_toggle, &state, &sessions),
             Some(UiAction::Navigation(NavigationAction::ToggleStale))
@@ -378,11 +488,75 @@ mod tests {
             Some(UiAction::Dialog(DialogAction::StartFinish))
         );
 
-        let cancel_key = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::NONE);
+        let cancel_key = KeyEvent::new(KeyCode::Char('x'), KeyModifiers::NONE);
         assert_eq!(
             event_handler.handle_key_event(cancel_key, &state, &sessions),
             Some(UiAction::Dialog(DialogAction::StartCancel))
         );
+
+        let sync_key = KeyEvent::new(KeyCode::Char('u'), KeyModifiers::NONE);
+        assert_eq!(
+            event_handler.handle_key_event(sync_key, &state, &sessions),
+            Some(UiAction::Session(SessionAction::Sync(0)))
+        );
+    }
+
+    #[test]
+    fn test_view_preference_keys() {
+        let event_handler = EventHandler::new();
+        let state = create_test_state();
+        let sessions = create_test_sessions();
+
+        let cases = [
+            (KeyCode::Char('s'), ViewAction::CycleSort),
+            (KeyCode::Char('S'), ViewAction::ReverseSort),
+            (KeyCode::Char('c'), ViewAction::OpenColumnPicker),
+            (KeyCode::Char('r'), ViewAction::ToggleReadOnly),
+            (KeyCode::Char('/'), ViewAction::StartFilter),
+        ];
+        for (code, expected) in cases {
+            let key = KeyEvent::new(code, KeyModifiers::NONE);
+            assert_eq!(
+                event_handler.handle_key_event(key, &state, &sessions),
+                Some(UiAction::View(expected))
+            );
+        }
+    }
+
+    #[test]
+    fn test_column_picker_and_filter_keys() {
+        let event_handler = EventHandler::new();
+        let mut state = create_test_state();
+        let sessions = create_test_sessions();
+
+        state.open_column_picker();
+        let toggle = KeyEvent::new(KeyCode::Char(' '), KeyModifiers::NONE);
+        assert_eq!(
+            event_handler.handle_key_event(toggle, &state, &sessions),
+            Some(UiAction::View(ViewAction::ToggleColumn))
+        );
+        let down = KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE);
+        assert_eq!(
+            event_handler.handle_key_event(down, &state, &sessions),
+            Some(UiAction::View(ViewAction::NextColumn))
+        );
+        let close = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::NONE);
+        assert_eq!(
+            event_handler.handle_key_event(close, &state, &sessions),
+            Some(UiAction::View(ViewAction::CloseColumnPicker))
+        );
+
+        state.start_filter();
+        let typed = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE);
+        assert_eq!(
+            event_handler.handle_key_event(typed, &state, &sessions),
+            Some(UiAction::View(ViewAction::FilterChar('q')))
+        );
+        let esc = KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE);
+        assert_eq!(
+            event_handler.handle_key_event(esc, &state, &sessions),
+            Some(UiAction::View(ViewAction::ClearFilter))
+        );
     }
 
     #[test]
@@ -450,6 +624,18 @@ mod tests {
             event_handler.handle_key_event(space_key, &state, &sessions),
             Some(UiAction::Dialog(DialogAction::ClearError))
         );
+
+        // o only opens a session when the error is about its conflicts
+        let open_key = KeyEvent::new(KeyCode::Char('o'), KeyModifiers::NONE);
+        assert_eq!(
+            event_handler.handle_key_event(open_key, &state, &sessions),
+            None
+        );
+        state.show_conflict("Conflicts".to_string(), "/tmp/session1".to_string());
+        assert_eq!(
+            event_handler.handle_key_event(open_key, &state, &sessions),
+            Some(UiAction::Dialog(DialogAction::OpenConflict))
+        );
     }
 
     #[test]
@@ -557,14 +743,14 @@ mod tests {
             None
         );
 
-        let cancel_key = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::NONE);
+        let cancel_key = KeyEvent::new(KeyCode::Char('x'), KeyModifiers::NONE);
         assert_eq!(
             event_handler.handle_key_event(cancel_key, &state, &empty_sessions),
             None
         );
 
         // Navigation actions should still work
-        let stale_toggle = KeyEvent::new(KeyCode::Char('s'), KeyModifiers::NONE);
+        let stale_toggle = KeyEvent::new(KeyCode::Char('h'), KeyModifiers::NONE);
         assert_eq!(
             event_handler.handle_key_event(stale_toggle, &state, &empty_sessions),
             Some(UiAction::Navigation(NavigationAction::ToggleStale))
@@ -0,0 +1,168 @@
+//! Periodic fetch of the monitored repositories' base branches
+//!
+//! With `git.auto_fetch` the monitor runs `git fetch --prune <remote> <main>`
+//! in every repository at most once per `git.auto_fetch_interval_minutes`, on
+//! a background thread so the UI never waits for the network. A failed fetch
+//! only shows up as an indicator in the footer.
+
+use crate::config::Config;
+use crate::core::git::GitRepository;
+use crate::core::network::NetworkContext;
+use crate::utils::Result;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+type FetchFn = Arc<dyn Fn() -> bool + Send + Sync>;
+
+pub struct AutoFetcher {
+    interval: Duration,
+    /// Fetches every repository; returns whether all fetches succeeded
+    fetch: FetchFn,
+    last_started: Option<Instant>,
+    running: Arc<AtomicBool>,
+    /// Outcome of a fetch that finished since the last poll
+    finished: Arc<Mutex<Option<bool>>>,
+    failed: bool,
+}
+
+impl AutoFetcher {
+    /// Fetcher for `repo_roots`, or `None` when `git.auto_fetch` is off or
+    /// para runs offline
+    pub fn for_repos(config: &Config, repo_roots: Vec<PathBuf>) -> Option<Self> {
+        let interval = config.get_auto_fetch_interval()?;
+        if NetworkContext::current()
+            .require_online("git.auto_fetch")
+            .is_err()
+        {
+            return None;
+        }
+
+        let default_remote = config.git.default_remote.clone();
+        let fetch: FetchFn = Arc::new(move || {
+            // Every repository is fetched, even after one fails
+            let results: Vec<Result<()>> = repo_roots
+                .iter()
+                .map(|root| fetch_base_branch(root, default_remote.as_deref()))
+                .collect();
+            results.iter().all(Result::is_ok)
+        });
+        Some(Self::with_fetch(interval, fetch))
+    }
+
+    fn with_fetch(interval: Duration, fetch: FetchFn) -> Self {
+        Self {
+            interval,
+            fetch,
+            last_started: None,
+            running: Arc::new(AtomicBool::new(false)),
+            finished: Arc::new(Mutex::new(None)),
+            failed: false,
+        }
+    }
+
+    /// Start a fetch if one is due and report whether a fetch finished since
+    /// the last poll, so the caller can refresh what depends on remote refs
+    pub fn poll(&mut self, now: Instant) -> bool {
+        let finished = self.finished.lock().unwrap().take();
+        if let Some(succeeded) = finished {
+            self.failed = !succeeded;
+        }
+
+        let due = self
+            .last_started
+            .is_none_or(|started| now.duration_since(started) >= self.interval);
+        if due && !self.running.swap(true, Ordering::SeqCst) {
+            self.last_started = Some(now);
+            let fetch = Arc::clone(&self.fetch);
+            let running = Arc::clone(&self.running);
+            let outcome = Arc::clone(&self.finished);
+            thread::spawn(move || {
+                let succeeded = fetch();
+                *outcome.lock().unwrap() = Some(succeeded);
+                running.store(false, Ordering::SeqCst);
+            });
+        }
+
+        finished.is_some()
+    }
+
+    /// Whether the last finished fetch failed, e.g. offline or without credentials
+    pub fn failed(&self) -> bool {
+        self.failed
+    }
+}
+
+/// Fetch the main branch from `default_remote` (or origin). Repositories
+/// without that remote have nothing to fetch.
+fn fetch_base_branch(repo_root: &Path, default_remote: Option<&str>) -> Result<()> {
+    let repo = GitRepository::discover_from(repo_root)?;
+    let remote = default_remote.unwrap_or("origin");
+    if !repo.list_remotes()?.iter().any(|name| name == remote) {
+        return Ok(());
+    }
+    let main_branch = repo.get_main_branch()?;
+    repo.fetch_prune(remote, &main_branch)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+
+    /// Poll until the running fetch has finished
+    fn wait_for_fetch(fetcher: &mut AutoFetcher, now: Instant) -> bool {
+        for _ in 0..200 {
+            if fetcher.poll(now) {
+                return true;
+            }
+            thread::sleep(Duration::from_millis(5));
+        }
+        false
+    }
+
+    #[test]
+    fn test_fetches_at_most_once_per_interval() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&calls);
+        let mut fetcher = AutoFetcher::with_fetch(
+            Duration::from_secs(300),
+            Arc::new(move || {
+                counter.fetch_add(1, Ordering::SeqCst);
+                true
+            }),
+        );
+
+        let start = Instant::now();
+        assert!(wait_for_fetch(&mut fetcher, start));
+        assert!(!fetcher.failed());
+        assert!(!fetcher.poll(start + Duration::from_secs(60)));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        assert!(wait_for_fetch(
+            &mut fetcher,
+            start + Duration::from_secs(300)
+        ));
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_failed_fetch_sets_indicator_until_next_success() {
+        let succeed = Arc::new(AtomicBool::new(false));
+        let outcome = Arc::clone(&succeed);
+        let mut fetcher = AutoFetcher::with_fetch(
+            Duration::from_secs(1),
+            Arc::new(move || outcome.load(Ordering::SeqCst)),
+        );
+
+        let start = Instant::now();
+        assert!(wait_for_fetch(&mut fetcher, start));
+        assert!(fetcher.failed());
+
+        succeed.store(true, Ordering::SeqCst);
+        assert!(wait_for_fetch(&mut fetcher, start + Duration::from_secs(1)));
+        assert!(!fetcher.failed());
+    }
+}
@@ -4,6 +4,9 @@ pub mod activity;
 pub mod cache;
 pub mod coordinator;
 pub mod event_handler;
+pub mod fetch;
+pub mod operation_queue;
+pub mod prefs;
 pub mod renderer;
 pub mod service;
 pub mod state;
@@ -0,0 +1,417 @@
+//! Background queue for the monitor's finish, cancel and sync actions
+//!
+//! Jobs run one at a time on a worker thread so the UI keeps drawing while
+//! git works. A session has at most one job queued or running; a job that
+//! has not started yet can be cancelled. Results come back over a channel
+//! and are picked up by `poll`.
+//!
+//! Jobs are keyed by the session's worktree path, since sessions of
+//! different repositories can share a name.
+
+use crate::ui::monitor::SessionInfo;
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread::{self, JoinHandle};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperationKind {
+    Finish,
+    Cancel,
+    Sync,
+}
+
+impl OperationKind {
+    /// Label shown in the session's row while the job is running
+    pub fn progress_label(&self) -> &'static str {
+        match self {
+            OperationKind::Finish => "finishing",
+            OperationKind::Cancel => "cancelling",
+            OperationKind::Sync => "syncing",
+        }
+    }
+}
+
+impl fmt::Display for OperationKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OperationKind::Finish => write!(f, "finish"),
+            OperationKind::Cancel => write!(f, "cancel"),
+            OperationKind::Sync => write!(f, "sync"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperationState {
+    Queued,
+    Running,
+}
+
+/// Job of a session that is queued or running
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PendingOperation {
+    pub kind: OperationKind,
+    pub state: OperationState,
+}
+
+/// Why a job failed
+#[derive(Debug, Clone, PartialEq)]
+pub struct OperationError {
+    pub message: String,
+    /// The job left conflicts in the session's worktree for the user to resolve
+    pub conflicted: bool,
+}
+
+impl OperationError {
+    pub fn conflict(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            conflicted: true,
+        }
+    }
+}
+
+impl From<String> for OperationError {
+    fn from(message: String) -> Self {
+        Self {
+            message,
+            conflicted: false,
+        }
+    }
+}
+
+type OperationFn = Box<dyn FnOnce() -> Result<String, OperationError> + Send>;
+
+/// A job for one session; it builds whatever services it needs itself
+pub struct Operation {
+    /// Identifies the session in the queue, see `operation_key`
+    pub key: String,
+    pub session: String,
+    pub kind: OperationKind,
+    run: OperationFn,
+}
+
+impl Operation {
+    /// `run` returns the message to show on success, or the error
+    pub fn new(
+        session: impl Into<String>,
+        kind: OperationKind,
+        run: impl FnOnce() -> Result<String, String> + Send + 'static,
+    ) -> Self {
+        Self::with_conflicts(session, kind, move || run().map_err(OperationError::from))
+    }
+
+    /// Like `new`, for jobs that can stop on conflicts
+    pub fn with_conflicts(
+        session: impl Into<String>,
+        kind: OperationKind,
+        run: impl FnOnce() -> Result<String, OperationError> + Send + 'static,
+    ) -> Self {
+        let session = session.into();
+        Self {
+            key: session.clone(),
+            session,
+            kind,
+            run: Box::new(run),
+        }
+    }
+
+    pub fn keyed(mut self, key: impl Into<String>) -> Self {
+        self.key = key.into();
+        self
+    }
+}
+
+/// Queue key of a session
+pub fn operation_key(session: &SessionInfo) -> String {
+    session.worktree_path.display().to_string()
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OperationOutcome {
+    pub key: String,
+    pub session: String,
+    pub kind: OperationKind,
+    pub result: Result<String, OperationError>,
+}
+
+#[derive(Default)]
+struct Jobs {
+    queued: VecDeque<Operation>,
+    /// Key and kind of the job the worker is running
+    running: Option<(String, OperationKind)>,
+    shutdown: bool,
+}
+
+type SharedJobs = Arc<(Mutex<Jobs>, Condvar)>;
+
+pub struct OperationQueue {
+    jobs: SharedJobs,
+    outcomes: Receiver<OperationOutcome>,
+    worker: Option<JoinHandle<()>>,
+}
+
+impl OperationQueue {
+    pub fn new() -> Self {
+        let jobs: SharedJobs = Arc::new((Mutex::new(Jobs::default()), Condvar::new()));
+        let (sender, outcomes) = mpsc::channel();
+        let worker_jobs = Arc::clone(&jobs);
+        let worker = thread::spawn(move || run_worker(worker_jobs, sender));
+        Self {
+            jobs,
+            outcomes,
+            worker: Some(worker),
+        }
+    }
+
+    /// Queue `operation`, unless its session already has a job queued or running
+    pub fn submit(&self, operation: Operation) -> Result<(), String> {
+        let (lock, ready) = &*self.jobs;
+        let mut jobs = lock.lock().unwrap();
+        if let Some(pending) = pending_for(&jobs, &operation.key) {
+            return Err(busy_message(&operation.session, pending));
+        }
+        jobs.queued.push_back(operation);
+        ready.notify_one();
+        Ok(())
+    }
+
+    /// Drop the session's job if it has not started yet; returns its kind
+    pub fn cancel(&self, key: &str) -> Option<OperationKind> {
+        let mut jobs = self.jobs.0.lock().unwrap();
+        let position = jobs.queued.iter().position(|job| job.key == key)?;
+        jobs.queued.remove(position).map(|job| job.kind)
+    }
+
+    pub fn pending(&self, key: &str) -> Option<PendingOperation> {
+        pending_for(&self.jobs.0.lock().unwrap(), key)
+    }
+
+    /// Every session with a job queued or running, by key
+    pub fn snapshot(&self) -> HashMap<String, PendingOperation> {
+        let jobs = self.jobs.0.lock().unwrap();
+        let running = jobs.running.iter().map(|(key, kind)| {
+            let pending = PendingOperation {
+                kind: *kind,
+                state: OperationState::Running,
+            };
+            (key.clone(), pending)
+        });
+        let queued = jobs.queued.iter().map(|job| {
+            let pending = PendingOperation {
+                kind: job.kind,
+                state: OperationState::Queued,
+            };
+            (job.key.clone(), pending)
+        });
+        running.chain(queued).collect()
+    }
+
+    /// Outcomes of the jobs that finished since the last poll, in order
+    pub fn poll(&self) -> Vec<OperationOutcome> {
+        self.outcomes.try_iter().collect()
+    }
+}
+
+impl Default for OperationQueue {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for OperationQueue {
+    /// Jobs still queued when the monitor quits run before it exits
+    fn drop(&mut self) {
+        let (lock, ready) = &*self.jobs;
+        lock.lock().unwrap().shutdown = true;
+        ready.notify_one();
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
+
+pub fn busy_message(session: &str, pending: PendingOperation) -> String {
+    match pending.state {
+        OperationState::Queued => format!("{session} is busy: {} queued", pending.kind),
+        OperationState::Running => {
+            format!("{session} is busy: {} in progress", pending.kind)
+        }
+    }
+}
+
+fn pending_for(jobs: &Jobs, key: &str) -> Option<PendingOperation> {
+    if let Some((_, kind)) = jobs.running.as_ref().filter(|(running, _)| running == key) {
+        return Some(PendingOperation {
+            kind: *kind,
+            state: OperationState::Running,
+        });
+    }
+    jobs.queued
+        .iter()
+        .find(|job| job.key == key)
+        .map(|job| PendingOperation {
+            kind: job.kind,
+            state: OperationState::Queued,
+        })
+}
+
+fn run_worker(jobs: SharedJobs, outcomes: Sender<OperationOutcome>) {
+    let (lock, ready) = &*jobs;
+    loop {
+        let operation = {
+            let mut guard = lock.lock().unwrap();
+            loop {
+                if let Some(operation) = guard.queued.pop_front() {
+                    guard.running = Some((operation.key.clone(), operation.kind));
+                    break operation;
+                }
+                if guard.shutdown {
+                    return;
+                }
+                guard = ready.wait(guard).unwrap();
+            }
+        };
+
+        let Operation {
+            key,
+            session,
+            kind,
+            run,
+        } = operation;
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(run))
+            .unwrap_or_else(|_| Err(format!("{kind} of {session} panicked").into()));
+        lock.lock().unwrap().running = None;
+        let _ = outcomes.send(OperationOutcome {
+            key,
+            session,
+            kind,
+            result,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::time::Duration;
+
+    /// Poll until `count` outcomes have arrived
+    fn wait_for_outcomes(queue: &OperationQueue, count: usize) -> Vec<OperationOutcome> {
+        let mut outcomes = Vec::new();
+        for _ in 0..400 {
+            outcomes.extend(queue.poll());
+            if outcomes.len() >= count {
+                break;
+            }
+            thread::sleep(Duration::from_millis(5));
+        }
+        outcomes
+    }
+
+    /// A job that runs until the returned sender is used or dropped
+    fn blocking_job(session: &str) -> (Operation, Sender<()>) {
+        let (release, released) = mpsc::channel::<()>();
+        let job = Operation::new(session, OperationKind::Finish, move || {
+            let _ = released.recv();
+            Ok("done".to_string())
+        });
+        (job, release)
+    }
+
+    fn wait_until_running(queue: &OperationQueue, session: &str) {
+        for _ in 0..400 {
+            if queue.pending(session).map(|pending| pending.state) == Some(OperationState::Running)
+            {
+                return;
+            }
+            thread::sleep(Duration::from_millis(5));
+        }
+        panic!("{session} never started");
+    }
+
+    #[test]
+    fn test_jobs_run_in_submission_order() {
+        let queue = OperationQueue::new();
+        let order = Arc::new(Mutex::new(Vec::new()));
+        for session in ["a", "b", "c"] {
+            let order = Arc::clone(&order);
+            let job = Operation::new(session, OperationKind::Cancel, move || {
+                order.lock().unwrap().push(session);
+                Ok(format!("Cancelled {session}"))
+            });
+            queue.submit(job).unwrap();
+        }
+
+        let outcomes = wait_for_outcomes(&queue, 3);
+        assert_eq!(*order.lock().unwrap(), vec!["a", "b", "c"]);
+        let sessions: Vec<&str> = outcomes.iter().map(|o| o.session.as_str()).collect();
+        assert_eq!(sessions, vec!["a", "b", "c"]);
+        assert_eq!(outcomes[1].result, Ok("Cancelled b".to_string()));
+        assert!(queue.snapshot().is_empty());
+    }
+
+    #[test]
+    fn test_one_job_per_session() {
+        let queue = OperationQueue::new();
+        let (job, release) = blocking_job("a");
+        queue.submit(job).unwrap();
+        wait_until_running(&queue, "a");
+
+        let second = Operation::new("a", OperationKind::Cancel, || Ok(String::new()));
+        let error = queue.submit(second).unwrap_err();
+        assert_eq!(error, "a is busy: finish in progress");
+
+        // Other sessions queue behind the running job
+        let other = Operation::new("b", OperationKind::Cancel, || Err("failed".to_string()));
+        queue.submit(other).unwrap();
+        assert_eq!(
+            queue.pending("b"),
+            Some(PendingOperation {
+                kind: OperationKind::Cancel,
+                state: OperationState::Queued,
+            })
+        );
+        assert_eq!(queue.snapshot().len(), 2);
+
+        release.send(()).unwrap();
+        let outcomes = wait_for_outcomes(&queue, 2);
+        assert_eq!(outcomes[0].result, Ok("done".to_string()));
+        assert_eq!(outcomes[1].result, Err("failed".to_string().into()));
+
+        // Once done, the session accepts a new job
+        let again = Operation::new("a", OperationKind::Cancel, || Ok(String::new()));
+        assert!(queue.submit(again).is_ok());
+    }
+
+    #[test]
+    fn test_cancel_before_start() {
+        let queue = OperationQueue::new();
+        let (job, release) = blocking_job("a");
+        queue.submit(job).unwrap();
+        wait_until_running(&queue, "a");
+
+        let ran = Arc::new(AtomicBool::new(false));
+        let flag = Arc::clone(&ran);
+        let queued = Operation::new("b", OperationKind::Finish, move || {
+            flag.store(true, Ordering::SeqCst);
+            Ok(String::new())
+        });
+        queue.submit(queued).unwrap();
+
+        // A running job cannot be cancelled, a queued one can
+        assert_eq!(queue.cancel("a"), None);
+        assert_eq!(queue.cancel("b"), Some(OperationKind::Finish));
+        assert_eq!(queue.pending("b"), None);
+
+        release.send(()).unwrap();
+        let outcomes = wait_for_outcomes(&queue, 1);
+        drop(queue);
+        assert_eq!(outcomes.len(), 1);
+        assert_eq!(outcomes[0].session, "a");
+        assert!(!ran.load(Ordering::SeqCst));
+    }
+}
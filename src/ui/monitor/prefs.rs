@@ -0,0 +1,429 @@
+//! Monitor UI preferences persisted between runs
+//!
+//! Stored as `<state_dir>/monitor.prefs.json`. A missing or unreadable file
+//! falls back to the defaults so a bad edit never keeps the monitor from starting.
+
+use crate::ui::monitor::SessionInfo;
+use crate::utils::{ParaError, Result};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+pub const PREFS_FILE: &str = "monitor.prefs.json";
+
+const MIN_REFRESH_INTERVAL_SECS: u64 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortColumn {
+    LastActivity,
+    Name,
+    State,
+    Progress,
+    Changes,
+}
+
+impl SortColumn {
+    const ORDER: [SortColumn; 5] = [
+        SortColumn::LastActivity,
+        SortColumn::Name,
+        SortColumn::State,
+        SortColumn::Progress,
+        SortColumn::Changes,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            SortColumn::LastActivity => "Last Modified",
+            SortColumn::Name => "Session",
+            SortColumn::State => "State",
+            SortColumn::Progress => "Progress",
+            SortColumn::Changes => "Changes",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        let index = Self::ORDER.iter().position(|c| c == self).unwrap_or(0);
+        Self::ORDER[(index + 1) % Self::ORDER.len()]
+    }
+
+    fn compare(&self, a: &SessionInfo, b: &SessionInfo) -> Ordering {
+        match self {
+            SortColumn::LastActivity => a.last_activity.cmp(&b.last_activity),
+            SortColumn::Name => a.name.cmp(&b.name),
+            SortColumn::State => state_rank(a).cmp(&state_rank(b)),
+            SortColumn::Progress => a.todo_percentage.cmp(&b.todo_percentage),
+            SortColumn::Changes => changed_lines(a).cmp(&changed_lines(b)),
+        }
+    }
+}
+
+fn state_rank(session: &SessionInfo) -> u8 {
+    use crate::ui::monitor::SessionStatus;
+    match session.status {
+        SessionStatus::Active => 0,
+        SessionStatus::Idle => 1,
+        SessionStatus::Review => 2,
+        SessionStatus::Ready => 3,
+        SessionStatus::Stale => 4,
+        SessionStatus::Planned => 5,
+    }
+}
+
+fn changed_lines(session: &SessionInfo) -> usize {
+    session
+        .diff_stats
+        .as_ref()
+        .map(|stats| stats.additions + stats.deletions)
+        .unwrap_or(0)
+}
+
+/// Table columns that can be hidden. Actions and Session are always shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Column {
+    Permissions,
+    State,
+    LastModified,
+    Task,
+    Tests,
+    Progress,
+    Changes,
+    Integration,
+    MergeMode,
+    Container,
+}
+
+impl Column {
+    pub const ALL: [Column; 10] = [
+        Column::Permissions,
+        Column::State,
+        Column::LastModified,
+        Column::Task,
+        Column::Tests,
+        Column::Progress,
+        Column::Changes,
+        Column::Integration,
+        Column::MergeMode,
+        Column::Container,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Column::Permissions => "Skip-permissions marker",
+            Column::State => "State",
+            Column::LastModified => "Last Modified",
+            Column::Task => "Current Task",
+            Column::Tests => "Tests",
+            Column::Progress => "Progress",
+            Column::Changes => "Changes",
+            Column::Integration => "Merge Check",
+            Column::MergeMode => "Merge Mode",
+            Column::Container => "Container CPU/Memory",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MonitorPrefs {
+    pub sort_column: SortColumn,
+    pub sort_descending: bool,
+    pub hidden_columns: Vec<Column>,
+    /// Only sessions whose name, branch or task contain this text are shown
+    pub filter: String,
+    /// Disables finish, cancel and integrate from the monitor
+    pub read_only: bool,
+    pub refresh_interval_secs: u64,
+}
+
+impl Default for MonitorPrefs {
+    fn default() -> Self {
+        Self {
+            sort_column: SortColumn::LastActivity,
+            sort_descending: true,
+            hidden_columns: Vec::new(),
+            filter: String::new(),
+            read_only: false,
+            refresh_interval_secs: 2,
+        }
+    }
+}
+
+impl MonitorPrefs {
+    pub fn path(state_dir: &Path) -> PathBuf {
+        state_dir.join(PREFS_FILE)
+    }
+
+    /// Load preferences, using the defaults when the file is missing or invalid
+    pub fn load(state_dir: &Path) -> Self {
+        let mut prefs = fs::read_to_string(Self::path(state_dir))
+            .ok()
+            .and_then(|content| serde_json::from_str::<MonitorPrefs>(&content).ok())
+            .unwrap_or_default();
+        prefs.refresh_interval_secs = prefs.refresh_interval_secs.max(MIN_REFRESH_INTERVAL_SECS);
+        prefs
+    }
+
+    pub fn save(&self, state_dir: &Path) -> Result<()> {
+        fs::create_dir_all(state_dir).map_err(|e| {
+            ParaError::fs_error(format!(
+                "Failed to create state directory {}: {e}",
+                state_dir.display()
+            ))
+        })?;
+
+        let json = serde_json::to_string_pretty(self).map_err(|e| {
+            ParaError::file_operation(format!("Failed to encode monitor preferences: {e}"))
+        })?;
+
+        // Write to a temporary file first so a crash never leaves a truncated file
+        let path = Self::path(state_dir);
+        let tmp = path.with_extension("json.tmp");
+        fs::write(&tmp, json)
+            .and_then(|_| fs::rename(&tmp, &path))
+            .map_err(|e| ParaError::fs_error(format!("Failed to write {}: {e}", path.display())))
+    }
+
+    pub fn refresh_interval(&self) -> Duration {
+        Duration::from_secs(self.refresh_interval_secs.max(MIN_REFRESH_INTERVAL_SECS))
+    }
+
+    pub fn is_visible(&self, column: Column) -> bool {
+        !self.hidden_columns.contains(&column)
+    }
+
+    pub fn toggle_column(&mut self, column: Column) {
+        if let Some(index) = self.hidden_columns.iter().position(|c| *c == column) {
+            self.hidden_columns.remove(index);
+        } else {
+            self.hidden_columns.push(column);
+        }
+    }
+
+    pub fn cycle_sort(&mut self) {
+        self.sort_column = self.sort_column.next();
+    }
+
+    pub fn reverse_sort(&mut self) {
+        self.sort_descending = !self.sort_descending;
+    }
+
+    pub fn sort_label(&self) -> String {
+        let arrow = if self.sort_descending { "↓" } else { "↑" };
+        format!("{} {arrow}", self.sort_column.label())
+    }
+
+    /// Filter and sort sessions according to these preferences.
+    ///
+    /// The sort is stable, so sessions with equal keys keep their incoming order.
+    pub fn apply(&self, mut sessions: Vec<SessionInfo>) -> Vec<SessionInfo> {
+        let filter = self.filter.trim().to_lowercase();
+        if !filter.is_empty() {
+            sessions.retain(|session| {
+                session.name.to_lowercase().contains(&filter)
+                    || session.branch.to_lowercase().contains(&filter)
+                    || session.task.to_lowercase().contains(&filter)
+            });
+        }
+
+        sessions.sort_by(|a, b| {
+            let ordering = self.sort_column.compare(a, b);
+            if self.sort_descending {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        });
+        sessions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::status::DiffStats;
+    use crate::ui::monitor::SessionStatus;
+    use chrono::{Duration as ChronoDuration, Utc};
+    use tempfile::TempDir;
+
+    fn session(name: &str, minutes_ago: i64, todo: Option<u8>) -> SessionInfo {
+        SessionInfo {
+            name: name.to_string(),
+            branch: format!("para/{name}"),
+            status: SessionStatus::Active,
+            last_activity: Utc::now() - ChronoDuration::minutes(minutes_ago),
+            task: format!("Task for {name}"),
+            worktree_path: PathBuf::from(format!("/tmp/{name}")),
+            test_status: None,
+            diff_stats: None,
+            todo_percentage: todo,
+            is_blocked: false,
+            skip_permissions: false,
+            operation: None,
+            activity_source: None,
+            integration: None,
+            agent_status_stale: false,
+            repo_missing: false,
+            repo_root: None,
+            merge_mode: crate::core::git::MergeMode::default(),
+            note: None,
+            container: None,
+            todos: None,
+            stacked_on: None,
+            worktree_status: None,
+            detached_at: None,
+            links: Vec::new(),
+            ahead_behind: None,
+            pinned: false,
+        }
+    }
+
+    fn names(sessions: &[SessionInfo]) -> Vec<&str> {
+        sessions.iter().map(|s| s.name.as_str()).collect()
+    }
+
+    #[test]
+    fn test_prefs_round_trip() {
+        let temp = TempDir::new().unwrap();
+        let prefs = MonitorPrefs {
+            sort_column: SortColumn::Progress,
+            sort_descending: false,
+            hidden_columns: vec![Column::Tests, Column::Permissions],
+            filter: "auth".to_string(),
+            read_only: true,
+            refresh_interval_secs: 5,
+        };
+
+        prefs.save(temp.path()).unwrap();
+
+        assert_eq!(MonitorPrefs::load(temp.path()), prefs);
+        assert!(!temp.path().join("monitor.prefs.json.tmp").exists());
+    }
+
+    #[test]
+    fn test_missing_or_invalid_prefs_fall_back_to_defaults() {
+        let temp = TempDir::new().unwrap();
+        assert_eq!(MonitorPrefs::load(temp.path()), MonitorPrefs::default());
+
+        fs::write(MonitorPrefs::path(temp.path()), "{ not json").unwrap();
+        assert_eq!(MonitorPrefs::load(temp.path()), MonitorPrefs::default());
+
+        fs::write(
+            MonitorPrefs::path(temp.path()),
+            r#"{"sort_column": "unknown_column"}"#,
+        )
+        .unwrap();
+        assert_eq!(MonitorPrefs::load(temp.path()), MonitorPrefs::default());
+    }
+
+    #[test]
+    fn test_partial_prefs_keep_defaults_and_clamp_interval() {
+        let temp = TempDir::new().unwrap();
+        fs::write(
+            MonitorPrefs::path(temp.path()),
+            r#"{"read_only": true, "refresh_interval_secs": 0}"#,
+        )
+        .unwrap();
+
+        let prefs = MonitorPrefs::load(temp.path());
+        assert!(prefs.read_only);
+        assert_eq!(prefs.sort_column, SortColumn::LastActivity);
+        assert_eq!(prefs.refresh_interval_secs, 1);
+    }
+
+    #[test]
+    fn test_sort_by_last_activity_descending_by_default() {
+        let sessions = vec![
+            session("old", 30, None),
+            session("new", 1, None),
+            session("mid", 10, None),
+        ];
+
+        let sorted = MonitorPrefs::default().apply(sessions);
+        assert_eq!(names(&sorted), vec!["new", "mid", "old"]);
+    }
+
+    #[test]
+    fn test_sort_is_stable_for_equal_keys() {
+        let sessions = vec![
+            session("first", 5, Some(50)),
+            session("second", 3, Some(50)),
+            session("third", 1, Some(10)),
+            session("fourth", 2, Some(50)),
+        ];
+
+        let mut prefs = MonitorPrefs {
+            sort_column: SortColumn::Progress,
+            ..Default::default()
+        };
+        let sorted = prefs.apply(sessions.clone());
+        assert_eq!(names(&sorted), vec!["first", "second", "fourth", "third"]);
+
+        prefs.reverse_sort();
+        let sorted = prefs.apply(sessions);
+        assert_eq!(names(&sorted), vec!["third", "first", "second", "fourth"]);
+    }
+
+    #[test]
+    fn test_sort_by_changes_and_name() {
+        let mut small = session("small", 1, None);
+        small.diff_stats = Some(DiffStats {
+            additions: 1,
+            deletions: 1,
+        });
+        let mut large = session("large", 2, None);
+        large.diff_stats = Some(DiffStats {
+            additions: 40,
+            deletions: 2,
+        });
+        let none = session("none", 3, None);
+
+        let mut prefs = MonitorPrefs {
+            sort_column: SortColumn::Changes,
+            ..Default::default()
+        };
+        let sorted = prefs.apply(vec![small.clone(), none.clone(), large.clone()]);
+        assert_eq!(names(&sorted), vec!["large", "small", "none"]);
+
+        prefs.sort_column = SortColumn::Name;
+        prefs.sort_descending = false;
+        let sorted = prefs.apply(vec![small, none, large]);
+        assert_eq!(names(&sorted), vec!["large", "none", "small"]);
+    }
+
+    #[test]
+    fn test_filter_matches_name_branch_and_task() {
+        let mut billing = session("billing", 1, None);
+        billing.task = "Fix AUTH redirect".to_string();
+        let sessions = vec![
+            session("auth-api", 2, None),
+            billing,
+            session("docs", 3, None),
+        ];
+
+        let prefs = MonitorPrefs {
+            filter: "auth".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(names(&prefs.apply(sessions)), vec!["billing", "auth-api"]);
+    }
+
+    #[test]
+    fn test_cycle_sort_and_toggle_columns() {
+        let mut prefs = MonitorPrefs::default();
+        for _ in 0..SortColumn::ORDER.len() {
+            prefs.cycle_sort();
+        }
+        assert_eq!(prefs.sort_column, SortColumn::LastActivity);
+        prefs.cycle_sort();
+        assert_eq!(prefs.sort_column, SortColumn::Name);
+
+        prefs.toggle_column(Column::Task);
+        assert!(!prefs.is_visible(Column::Task));
+        prefs.toggle_column(Column::Task);
+        assert!(prefs.is_visible(Column::Task));
+    }
+}
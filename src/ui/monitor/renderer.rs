@@ -1,7 +1,15 @@
 use crate::config::Config;
-use crate::core::session::SessionManager;
+use crate::core::docker::stats::{format_size, ContainerHealth};
+use crate::core::git::integration_preview::IntegrationPreview;
+use crate::core::git::WorktreeStatus;
+use crate::core::session::links::labels;
+use crate::core::session::{SessionManager, SKIP_PERMISSIONS_MARKER};
+use crate::ui::monitor::actions::resume_subcommand;
+use crate::ui::monitor::operation_queue::{operation_key, OperationState, PendingOperation};
+use crate::ui::monitor::prefs::{Column, MonitorPrefs};
 use crate::ui::monitor::state::{ButtonClick, MonitorAppState};
 use crate::ui::monitor::{centered_rect, format_activity, truncate_task, AppMode, SessionInfo};
+use crate::ui::theme;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
@@ -24,26 +32,180 @@ const COLOR_NORMAL_TEXT: Color = Color::Rgb(229, 231, 235);
 const COLOR_ORANGE: Color = Color::Rgb(245, 158, 11);
 const COLOR_BLACK: Color = Color::Rgb(0, 0, 0);
 
+/// Header, width and hideable column of each table column, in display order.
+/// Columns without a [`Column`] are always shown.
+const TABLE_COLUMNS: [(&str, Option<Column>, Constraint); 12] = [
+    ("Actions", None, Constraint::Length(17)), // Wider for 4 buttons
+    ("Session", None, Constraint::Min(20)),
+    (
+        SKIP_PERMISSIONS_MARKER,
+        Some(Column::Permissions),
+        Constraint::Length(1),
+    ),
+    ("State", Some(Column::State), Constraint::Length(10)),
+    (
+        "Last Modified",
+        Some(Column::LastModified),
+        Constraint::Length(14),
+    ),
+    ("Current Task", Some(Column::Task), Constraint::Min(30)),
+    ("Tests", Some(Column::Tests), Constraint::Length(14)),
+    ("Progress", Some(Column::Progress), Constraint::Length(13)),
+    ("Changes", Some(Column::Changes), Constraint::Length(12)),
+    ("Merge", Some(Column::Integration), Constraint::Length(11)),
+    ("Mode", Some(Column::MergeMode), Constraint::Length(8)),
+    ("Container", Some(Column::Container), Constraint::Length(13)),
+];
+
+/// Position of the repository column, right after the always visible session name
+const REPO_COLUMN_INDEX: usize = 2;
+const REPO_COLUMN_WIDTH: u16 = 16;
+
+fn is_column_visible(prefs: &MonitorPrefs, column: Option<Column>) -> bool {
+    column.is_none_or(|column| prefs.is_visible(column))
+}
+
+/// Keep only the entries belonging to visible columns
+fn visible_columns<T>(prefs: &MonitorPrefs, items: Vec<T>) -> Vec<T> {
+    items
+        .into_iter()
+        .zip(TABLE_COLUMNS.iter())
+        .filter(|(_, (_, column, _))| is_column_visible(prefs, *column))
+        .map(|(item, _)| item)
+        .collect()
+}
+
+/// Directory name of a session's repository
+fn repo_label(repo_root: Option<&std::path::Path>) -> String {
+    repo_root
+        .and_then(|root| root.file_name())
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| "-".to_string())
+}
+
+/// Stacked sessions are marked with an arrow pointing at their parent's row
+fn session_name_label(session: &SessionInfo) -> String {
+    let name = match session.stacked_on {
+        Some(_) => format!("↳ {}", session.name),
+        None => session.name.clone(),
+    };
+    if session.pinned {
+        format!("{name} {}", theme::pin_marker())
+    } else {
+        name
+    }
+}
+
+/// `●` for changes to tracked files, `○` when the worktree only has new
+/// untracked files such as build output
+fn worktree_marker(status: Option<&WorktreeStatus>) -> Option<&'static str> {
+    let status = status?;
+    if status.has_tracked_changes() {
+        Some("●")
+    } else if status.is_untracked_only() {
+        Some("○")
+    } else {
+        None
+    }
+}
+
+const SPINNER_FRAMES: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+/// Spinner frame for the current time, advancing every 100ms
+fn spinner_frame() -> &'static str {
+    let tick = chrono::Utc::now().timestamp_millis() / 100;
+    SPINNER_FRAMES[tick.rem_euclid(SPINNER_FRAMES.len() as i64) as usize]
+}
+
 fn create_progress_bar(percentage: u8) -> String {
-    const BAR_WIDTH: usize = 8;
-    let filled = (percentage as f32 / 100.0 * BAR_WIDTH as f32).round() as usize;
-    let filled = filled.min(BAR_WIDTH);
+    format!("{} {percentage}%", progress_blocks(percentage, 8))
+}
+
+/// Shorter bar followed by the todo fraction, so `12/15` still fits the column
+fn create_todo_progress_bar(percentage: u8, todos: &str) -> String {
+    format!("{} {todos}", progress_blocks(percentage, 5))
+}
+
+fn progress_blocks(percentage: u8, width: usize) -> String {
+    let filled = (percentage as f32 / 100.0 * width as f32).round() as usize;
+    let filled = filled.min(width);
 
-    let mut bar = String::with_capacity(BAR_WIDTH + 5);
+    let mut bar = String::with_capacity(width * 3);
 
     for _ in 0..filled {
         bar.push('█');
     }
 
-    for _ in filled..BAR_WIDTH {
+    for _ in filled..width {
         bar.push('░');
     }
-    bar.push(' ');
-    bar.push_str(&format!("{percentage}%"));
 
     bar
 }
 
+/// Color for a resource gauge filled to `percent`
+fn gauge_color(percent: f64) -> Color {
+    if percent >= 90.0 {
+        COLOR_RED
+    } else if percent >= 70.0 {
+        COLOR_ORANGE
+    } else {
+        COLOR_GREEN
+    }
+}
+
+/// Four-cell gauge, e.g. `██░░` for 50%
+fn mini_gauge(percent: f64) -> String {
+    const WIDTH: usize = 4;
+    let filled = ((percent.clamp(0.0, 100.0) / 100.0) * WIDTH as f64).round() as usize;
+    format!("{}{}", "█".repeat(filled), "░".repeat(WIDTH - filled))
+}
+
+/// Text and color of the container column: CPU and memory gauges while
+/// running, otherwise a status chip
+fn container_cell_spans(health: &ContainerHealth) -> Vec<(String, Color)> {
+    match health {
+        ContainerHealth::Running(stats) => vec![
+            (
+                format!("C{}", mini_gauge(stats.cpu_percent)),
+                gauge_color(stats.cpu_percent),
+            ),
+            (" ".to_string(), COLOR_GRAY),
+            (
+                format!("M{}", mini_gauge(stats.mem_percent())),
+                gauge_color(stats.mem_percent()),
+            ),
+        ],
+        ContainerHealth::Restarting { exit_code } => {
+            vec![(format!("● restart {exit_code}"), COLOR_RED)]
+        }
+        ContainerHealth::Exited { exit_code } => {
+            vec![(format!("● exited {exit_code}"), COLOR_RED)]
+        }
+        ContainerHealth::Other { status } => vec![(status.clone(), COLOR_GRAY)],
+        ContainerHealth::Missing => vec![("missing".to_string(), COLOR_GRAY)],
+    }
+}
+
+/// Full container numbers for the selected session's footer line
+fn container_details(health: &ContainerHealth) -> String {
+    match health {
+        ContainerHealth::Running(stats) => format!(
+            "🐳 CPU {:.1}% • Mem {} / {} ({:.0}%)",
+            stats.cpu_percent,
+            format_size(stats.mem_usage),
+            format_size(stats.mem_limit),
+            stats.mem_percent()
+        ),
+        ContainerHealth::Restarting { exit_code } => {
+            format!("🐳 restarting (last exit code {exit_code})")
+        }
+        ContainerHealth::Exited { exit_code } => format!("🐳 exited (exit code {exit_code})"),
+        ContainerHealth::Other { status } => format!("🐳 {status}"),
+        ContainerHealth::Missing => "🐳 container not found".to_string(),
+    }
+}
+
 // Helper functions to eliminate dialog duplication
 fn create_dialog_area(f: &mut Frame, width: u16, height: u16) -> Rect {
     let area = centered_rect(width, height, f.area());
@@ -51,7 +213,7 @@ fn create_dialog_area(f: &mut Frame, width: u16, height: u16) -> Rect {
     area
 }
 
-fn create_dialog_block(title: &str, border_color: Color) -> Block {
+fn create_dialog_block(title: &str, border_color: Color) -> Block<'_> {
     Block::default()
         .title(title)
         .borders(Borders::ALL)
@@ -72,7 +234,7 @@ fn create_control_buttons_line<'a>(confirm_text: &'a str, cancel_text: &'a str)
     ])
 }
 
-fn create_styled_span(text: &str, color: Color, bold: bool) -> Span {
+fn create_styled_span(text: &str, color: Color, bold: bool) -> Span<'_> {
     let mut style = Style::default().fg(color);
     if bold {
         style = style.add_modifier(Modifier::BOLD);
@@ -80,7 +242,7 @@ fn create_styled_span(text: &str, color: Color, bold: bool) -> Span {
     Span::styled(text, style)
 }
 
-fn create_default_cell_for_none(default_text: &str, is_stale: bool) -> Cell {
+fn create_default_cell_for_none(default_text: &str, is_stale: bool) -> Cell<'_> {
     let color = if is_stale {
         crate::ui::monitor::types::SessionStatus::dimmed_text_color()
     } else {
@@ -91,11 +253,30 @@ fn create_default_cell_for_none(default_text: &str, is_stale: bool) -> Cell {
 
 pub struct MonitorRenderer {
     config: Config,
+    /// Show which repository each session belongs to
+    show_repo_column: bool,
 }
 
 impl MonitorRenderer {
     pub fn new(config: Config) -> Self {
-        Self { config }
+        Self {
+            config,
+            show_repo_column: false,
+        }
+    }
+
+    /// Renderer for sessions from several repositories
+    pub fn with_repo_column(mut self) -> Self {
+        self.show_repo_column = true;
+        self
+    }
+
+    /// Insert the repository column after the session name when it is shown
+    fn with_repo<T>(&self, mut items: Vec<T>, repo: impl FnOnce() -> T) -> Vec<T> {
+        if self.show_repo_column {
+            items.insert(REPO_COLUMN_INDEX, repo());
+        }
+        items
     }
 
     pub fn render(&self, f: &mut Frame, sessions: &[SessionInfo], state: &mut MonitorAppState) {
@@ -113,7 +294,7 @@ impl MonitorRenderer {
             .margin(1)
             .split(f.area());
 
-        self.render_header(f, main_layout[0]);
+        self.render_header(f, main_layout[0], state);
         self.render_table(f, main_layout[1], sessions, state);
         self.render_footer(f, main_layout[2], sessions, state);
 
@@ -126,21 +307,39 @@ impl MonitorRenderer {
             AppMode::FinishPrompt => self.render_finish_prompt(f, state),
             AppMode::CancelConfirm => self.render_cancel_confirm(f),
             AppMode::ErrorDialog => self.render_error_dialog(f, state),
+            AppMode::FilterInput => self.render_filter_input(f, state),
+            AppMode::ColumnPicker => self.render_column_picker(f, state),
             _ => {}
         }
     }
 
-    fn render_header(&self, f: &mut Frame, area: Rect) {
+    fn render_header(&self, f: &mut Frame, area: Rect, state: &MonitorAppState) {
+        let prefs = &state.prefs;
+        let mut view_info = format!("Sort: {}", prefs.sort_label());
+        if !prefs.filter.is_empty() {
+            view_info.push_str(&format!(" • Filter: \"{}\"", prefs.filter));
+        }
+        view_info.push_str(&format!(
+            " • Auto-refresh: {}s",
+            prefs.refresh_interval().as_secs()
+        ));
+
+        let mut title_line = vec![
+            create_styled_span(
+                "Para Monitor - Interactive Session Control",
+                COLOR_WHITE,
+                true,
+            ),
+            Span::raw("                  "),
+            Span::styled(view_info, Style::default().fg(COLOR_LIGHT_GRAY)),
+        ];
+        if prefs.read_only {
+            title_line.push(Span::raw(" • "));
+            title_line.push(create_styled_span("READ-ONLY", COLOR_ORANGE, true));
+        }
+
         let header_text = vec![
-            Line::from(vec![
-                create_styled_span(
-                    "Para Monitor - Interactive Session Control",
-                    COLOR_WHITE,
-                    true,
-                ),
-                Span::raw("                  "),
-                Span::styled("Auto-refresh: 2s", Style::default().fg(COLOR_LIGHT_GRAY)),
-            ]),
+            Line::from(title_line),
             Line::from("─".repeat(area.width as usize)),
         ];
 
@@ -158,9 +357,9 @@ impl MonitorRenderer {
         sessions: &[SessionInfo],
         state: &mut MonitorAppState,
     ) {
-        let header = self.create_table_header();
+        let header = self.create_table_header(&state.prefs);
         let rows = self.create_table_rows(sessions, state);
-        let table = self.create_table_widget(rows, header);
+        let table = self.create_table_widget(rows, header, &state.prefs);
 
         // Store the table area for mouse click handling
         state.set_table_area(area);
@@ -168,23 +367,19 @@ impl MonitorRenderer {
         f.render_stateful_widget(table, area, &mut state.table_state.clone());
     }
 
-    fn create_table_header<'a>(&self) -> Row<'a> {
-        Row::new(vec![
-            Cell::from("Actions"),
-            Cell::from("Session"),
-            Cell::from("State"),
-            Cell::from("Last Modified"),
-            Cell::from("Current Task"),
-            Cell::from("Tests"),
-            Cell::from("Progress"),
-            Cell::from("Changes"),
-        ])
-        .style(
-            Style::default()
-                .fg(COLOR_LIGHT_GRAY)
-                .add_modifier(Modifier::BOLD),
-        )
-        .height(1)
+    fn create_table_header<'a>(&self, prefs: &MonitorPrefs) -> Row<'a> {
+        let cells = TABLE_COLUMNS
+            .iter()
+            .map(|(title, _, _)| Cell::from(*title))
+            .collect();
+        let cells = self.with_repo(visible_columns(prefs, cells), || Cell::from("Repo"));
+        Row::new(cells)
+            .style(
+                Style::default()
+                    .fg(COLOR_LIGHT_GRAY)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .height(1)
     }
 
     fn create_table_rows<'a>(
@@ -206,20 +401,37 @@ impl MonitorRenderer {
         state: &MonitorAppState,
     ) -> Row<'a> {
         let is_selected = index == state.selected_index;
-        let is_stale = session.status.should_dim();
+        let is_stale = session.status.should_dim() || session.repo_missing;
         let base_style = self.get_base_row_style(is_selected, is_stale);
+        let task = match &session.repo_root {
+            Some(root) if session.repo_missing => {
+                format!("repository no longer exists: {}", root.display())
+            }
+            _ => session.task.clone(),
+        };
 
-        Row::new(vec![
+        let cells = vec![
             self.create_action_buttons_cell(is_selected, index, state),
-            Cell::from(session.name.clone()).style(base_style.add_modifier(Modifier::BOLD)),
-            self.create_state_cell(session, is_stale),
+            Cell::from(session_name_label(session)).style(base_style.add_modifier(Modifier::BOLD)),
+            self.create_permissions_cell(session.skip_permissions),
+            self.create_state_cell(
+                session,
+                state.operations.get(&operation_key(session)),
+                state.behind_warning(session),
+            ),
             Cell::from(format_activity(&session.last_activity)).style(base_style),
-            Cell::from(truncate_task(&session.task, 40)).style(base_style),
-            self.create_test_cell(&session.test_status, is_stale),
-            self.create_progress_cell(session.todo_percentage, is_stale),
+            Cell::from(truncate_task(&task, 40)).style(base_style),
+            self.create_test_cell(&session.test_status, session.agent_status_stale, is_stale),
+            self.create_progress_cell(session.todo_percentage, session.todos.as_deref(), is_stale),
             self.create_diff_stats_cell(&session.diff_stats, is_stale),
-        ])
-        .height(1)
+            self.create_integration_cell(&session.integration, is_stale),
+            Cell::from(session.merge_mode.to_string()).style(base_style),
+            self.create_container_cell(&session.container, is_stale),
+        ];
+        let cells = self.with_repo(visible_columns(&state.prefs, cells), || {
+            Cell::from(repo_label(session.repo_root.as_deref())).style(base_style)
+        });
+        Row::new(cells).height(1)
     }
 
     fn create_action_buttons_cell<'a>(
@@ -345,28 +557,89 @@ impl MonitorRenderer {
         }
     }
 
-    fn create_state_cell<'a>(&self, session: &'a SessionInfo, _is_stale: bool) -> Cell<'a> {
+    fn create_permissions_cell<'a>(&self, skip_permissions: bool) -> Cell<'a> {
+        if skip_permissions {
+            Cell::from(SKIP_PERMISSIONS_MARKER).style(
+                Style::default()
+                    .fg(COLOR_ORANGE)
+                    .add_modifier(Modifier::BOLD),
+            )
+        } else {
+            Cell::from("")
+        }
+    }
+
+    fn create_state_cell<'a>(
+        &self,
+        session: &'a SessionInfo,
+        pending: Option<&PendingOperation>,
+        behind: Option<usize>,
+    ) -> Cell<'a> {
+        match pending {
+            Some(pending) if pending.state == OperationState::Running => {
+                let label = format!("{} {}", spinner_frame(), pending.kind.progress_label());
+                return Cell::from(label).style(
+                    Style::default()
+                        .fg(COLOR_ORANGE)
+                        .add_modifier(Modifier::BOLD),
+                );
+            }
+            Some(pending) => {
+                return Cell::from(format!("queued: {}", pending.kind))
+                    .style(Style::default().fg(COLOR_GRAY));
+            }
+            None => {}
+        }
+
+        if let Some(ref operation) = session.operation {
+            return Cell::from(operation.describe(chrono::Utc::now())).style(
+                Style::default()
+                    .fg(COLOR_ORANGE)
+                    .add_modifier(Modifier::BOLD),
+            );
+        }
+
         let state_text = if session.is_blocked {
-            "Blocked"
+            "Blocked".to_string()
+        } else if let Some(sha) = &session.detached_at {
+            format!("detached@{sha}")
         } else {
-            session.status.name()
+            session.status.name().to_string()
         };
 
         let state_style = if session.is_blocked {
             Style::default().fg(COLOR_RED)
+        } else if session.detached_at.is_some() {
+            Style::default().fg(COLOR_ORANGE)
         } else {
             Style::default().fg(session.status.color())
         };
 
-        Cell::from(state_text).style(state_style)
+        let mut spans = vec![Span::styled(state_text, state_style)];
+        if let Some(marker) = worktree_marker(session.worktree_status.as_ref()) {
+            spans.push(Span::styled(format!(" {marker}"), state_style));
+        }
+        if let Some(behind) = behind {
+            spans.push(Span::styled(
+                format!(" ⇣{behind}"),
+                Style::default().fg(COLOR_ORANGE),
+            ));
+        }
+        Cell::from(Line::from(spans))
     }
 
+    /// Test result cell; an outdated agent report is dimmed and marked stale
     fn create_test_cell<'a>(
         &self,
         test_status: &Option<crate::core::status::TestStatus>,
+        report_stale: bool,
         is_stale: bool,
     ) -> Cell<'a> {
         match test_status {
+            Some(status) if report_stale => {
+                let (text, color) = self.get_test_status_display(status, true);
+                Cell::from(format!("{text} stale")).style(Style::default().fg(color))
+            }
             Some(status) => {
                 let (text, color) = self.get_test_status_display(status, is_stale);
                 Cell::from(text).style(Style::default().fg(color))
@@ -400,10 +673,18 @@ impl MonitorRenderer {
         }
     }
 
-    fn create_progress_cell<'a>(&self, todo_percentage: Option<u8>, is_stale: bool) -> Cell<'a> {
+    fn create_progress_cell<'a>(
+        &self,
+        todo_percentage: Option<u8>,
+        todos: Option<&str>,
+        is_stale: bool,
+    ) -> Cell<'a> {
         match todo_percentage {
             Some(pct) => {
-                let progress_bar = create_progress_bar(pct);
+                let progress_bar = match todos {
+                    Some(todos) => create_todo_progress_bar(pct, todos),
+                    None => create_progress_bar(pct),
+                };
                 let color = self.get_progress_color(pct, is_stale);
                 Cell::from(progress_bar).style(Style::default().fg(color))
             }
@@ -456,22 +737,59 @@ impl MonitorRenderer {
         }
     }
 
-    fn create_table_widget<'a>(&self, rows: Vec<Row<'a>>, header: Row<'a>) -> Table<'a> {
-        Table::new(
-            rows,
-            [
-                Constraint::Length(17), // Actions column (wider for 4 buttons)
-                Constraint::Min(20),    // Session name
-                Constraint::Length(10), // State
-                Constraint::Length(14), // Last Modified
-                Constraint::Min(30),    // Current Task
-                Constraint::Length(10), // Tests
-                Constraint::Length(13), // Progress
-                Constraint::Length(12), // Changes
-            ],
-        )
-        .header(header)
-        .block(
+    fn create_integration_cell<'a>(
+        &self,
+        integration: &Option<IntegrationPreview>,
+        is_stale: bool,
+    ) -> Cell<'a> {
+        let (text, color) = match integration {
+            Some(IntegrationPreview::Clean) => ("✓ clean".to_string(), COLOR_GREEN),
+            Some(IntegrationPreview::Conflicts(paths)) => {
+                (format!("⚠ {} files", paths.len()), COLOR_RED)
+            }
+            None => return create_default_cell_for_none("-", is_stale),
+        };
+        let color = if is_stale {
+            crate::ui::monitor::types::SessionStatus::dimmed_text_color()
+        } else {
+            color
+        };
+        Cell::from(text).style(Style::default().fg(color))
+    }
+
+    fn create_container_cell<'a>(
+        &self,
+        container: &Option<ContainerHealth>,
+        is_stale: bool,
+    ) -> Cell<'a> {
+        let Some(health) = container else {
+            return create_default_cell_for_none("-", is_stale);
+        };
+        let spans = container_cell_spans(health)
+            .into_iter()
+            .map(|(text, color)| {
+                let color = if is_stale {
+                    crate::ui::monitor::types::SessionStatus::dimmed_text_color()
+                } else {
+                    color
+                };
+                Span::styled(text, Style::default().fg(color))
+            })
+            .collect::<Vec<_>>();
+        Cell::from(Line::from(spans))
+    }
+
+    fn create_table_widget<'a>(
+        &self,
+        rows: Vec<Row<'a>>,
+        header: Row<'a>,
+        prefs: &MonitorPrefs,
+    ) -> Table<'a> {
+        let widths = TABLE_COLUMNS.iter().map(|(_, _, width)| *width).collect();
+        let widths = self.with_repo(visible_columns(prefs, widths), || {
+            Constraint::Length(REPO_COLUMN_WIDTH)
+        });
+        Table::new(rows, widths).header(header).block(
             Block::default()
                 .borders(Borders::TOP | Borders::BOTTOM)
                 .border_style(Style::default().fg(COLOR_BORDER)),
@@ -494,6 +812,21 @@ impl MonitorRenderer {
             .get_selected_session(sessions)
             .map(|s| s.branch.as_str())
             .unwrap_or("");
+        let selected_note = state
+            .get_selected_session(sessions)
+            .and_then(|s| s.note.as_deref())
+            .map(|note| format!("📝 {} • ", truncate_task(note, 40)))
+            .unwrap_or_default();
+        let selected_links = state
+            .get_selected_session(sessions)
+            .filter(|s| !s.links.is_empty())
+            .map(|s| format!("🔗 {} • ", truncate_task(&labels(&s.links), 30)))
+            .unwrap_or_default();
+        let selected_container = state
+            .get_selected_session(sessions)
+            .and_then(|s| s.container.as_ref())
+            .map(|health| format!("{} • ", container_details(health)))
+            .unwrap_or_default();
 
         let current_dir = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
         let session_manager = SessionManager::new(&self.config);
@@ -504,21 +837,40 @@ impl MonitorRenderer {
                 false
             };
 
+        let enter_label = match state.get_selected_session(sessions).map(resume_subcommand) {
+            Some("launch") => " Launch • ",
+            _ => " Resume • ",
+        };
+
         let session_info = if is_current_session {
-            format!("{selected_session} • {selected_branch} • (CURRENT) • ")
+            format!("{selected_session} • {selected_branch} • (CURRENT) • {selected_container}{selected_note}{selected_links}")
         } else {
-            format!("{selected_session} • {selected_branch} • ")
+            format!("{selected_session} • {selected_branch} • {selected_container}{selected_note}{selected_links}")
+        };
+        let fetch_warning = if state.fetch_failed {
+            "⚠ fetch failed • "
+        } else {
+            ""
         };
         let controls = vec![Line::from(vec![
+            Span::styled(fetch_warning, Style::default().fg(COLOR_ORANGE)),
             Span::styled(session_info, Style::default().fg(COLOR_LIGHT_GRAY)),
             create_styled_span("[Enter]", COLOR_BLUE, true),
-            Span::raw(" Resume • "),
+            Span::raw(enter_label),
             create_styled_span("[f]", COLOR_BLUE, true),
             Span::raw(" Finish • "),
-            create_styled_span("[c]", COLOR_BLUE, true),
+            create_styled_span("[x]", COLOR_BLUE, true),
             Span::raw(" Cancel • "),
+            create_styled_span("[u]", COLOR_BLUE, true),
+            Span::raw(" Sync • "),
             create_styled_span("[y]", COLOR_BLUE, true),
             Span::raw(" Copy • "),
+            create_styled_span("[/]", COLOR_BLUE, true),
+            Span::raw(" Filter • "),
+            create_styled_span("[s]", COLOR_BLUE, true),
+            Span::raw(" Sort • "),
+            create_styled_span("[c]", COLOR_BLUE, true),
+            Span::raw(" Columns • "),
             create_styled_span("[q]", COLOR_BLUE, true),
             Span::raw(" Quit"),
         ])];
@@ -578,11 +930,92 @@ impl MonitorRenderer {
         f.render_widget(confirm, area);
     }
 
+    fn render_filter_input(&self, f: &mut Frame, state: &MonitorAppState) {
+        let area = create_dialog_area(f, 60, 25);
+
+        let filter = state.prefs.filter.as_str();
+        let prompt = Paragraph::new(vec![
+            Line::from("Show sessions whose name, branch or task contains:"),
+            Line::from(""),
+            Line::from(Span::styled(
+                if filter.is_empty() {
+                    "Type to filter..."
+                } else {
+                    filter
+                },
+                if filter.is_empty() {
+                    Style::default().fg(COLOR_GRAY)
+                } else {
+                    Style::default().fg(COLOR_WHITE)
+                },
+            )),
+            Line::from(""),
+            create_control_buttons_line("keep filter", "clear filter"),
+        ])
+        .block(create_dialog_block(" Filter Sessions ", COLOR_BLUE))
+        .style(create_dialog_style());
+
+        f.render_widget(prompt, area);
+    }
+
+    fn render_column_picker(&self, f: &mut Frame, state: &MonitorAppState) {
+        let area = create_dialog_area(f, 50, 45);
+
+        let mut lines: Vec<Line> = Column::ALL
+            .iter()
+            .enumerate()
+            .map(|(i, column)| {
+                let checkbox = if state.prefs.is_visible(*column) {
+                    "[x]"
+                } else {
+                    "[ ]"
+                };
+                let text = format!("{checkbox} {}", column.label());
+                if i == state.column_cursor {
+                    Line::from(Span::styled(
+                        text,
+                        Style::default()
+                            .fg(COLOR_WHITE)
+                            .bg(COLOR_SELECTED_BG)
+                            .add_modifier(Modifier::BOLD),
+                    ))
+                } else {
+                    Line::from(Span::raw(text))
+                }
+            })
+            .collect();
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            Span::styled("[Space]", Style::default().fg(COLOR_GREEN)),
+            Span::raw(" toggle • "),
+            Span::styled("[Esc]", Style::default().fg(COLOR_RED)),
+            Span::raw(" close"),
+        ]));
+
+        let picker = Paragraph::new(lines)
+            .block(create_dialog_block(" Visible Columns ", COLOR_BLUE))
+            .style(create_dialog_style());
+
+        f.render_widget(picker, area);
+    }
+
     fn render_error_dialog(&self, f: &mut Frame, state: &MonitorAppState) {
         let area = create_dialog_area(f, 60, 25);
 
         let error_message = state.error_message.as_deref().unwrap_or("Unknown error");
 
+        let mut controls = Vec::new();
+        if state.conflict.is_some() {
+            controls.push(Span::styled("[o]", Style::default().fg(COLOR_BLUE)));
+            controls.push(Span::raw(" open to resolve • "));
+        }
+        controls.extend([
+            Span::styled("[Enter]", Style::default().fg(COLOR_GREEN)),
+            Span::raw(" or "),
+            Span::styled("[Esc]", Style::default().fg(COLOR_GREEN)),
+            Span::raw(" to dismiss"),
+        ]);
+
         let error_popup = Paragraph::new(vec![
             Line::from(vec![
                 Span::styled("⚠️  ", Style::default().fg(COLOR_RED)),
@@ -591,12 +1024,7 @@ impl MonitorRenderer {
             Line::from(""),
             Line::from(Span::raw(error_message)),
             Line::from(""),
-            Line::from(vec![
-                Span::styled("[Enter]", Style::default().fg(COLOR_GREEN)),
-                Span::raw(" or "),
-                Span::styled("[Esc]", Style::default().fg(COLOR_GREEN)),
-                Span::raw(" to dismiss"),
-            ]),
+            Line::from(controls),
         ])
         .block(create_dialog_block(" Error ", COLOR_RED))
         .style(create_dialog_style())
@@ -672,6 +1100,23 @@ mod tests {
                 diff_stats: None,
                 todo_percentage: None,
                 is_blocked: false,
+                skip_permissions: false,
+                operation: None,
+                activity_source: None,
+                integration: None,
+                agent_status_stale: false,
+                repo_missing: false,
+                repo_root: None,
+                merge_mode: crate::core::git::MergeMode::default(),
+                note: None,
+                container: None,
+                todos: None,
+                stacked_on: None,
+                worktree_status: None,
+                detached_at: None,
+                links: Vec::new(),
+                ahead_behind: None,
+                pinned: false,
             },
             SessionInfo {
                 name: "session2".to_string(),
@@ -684,6 +1129,23 @@ mod tests {
                 diff_stats: None,
                 todo_percentage: None,
                 is_blocked: false,
+                skip_permissions: false,
+                operation: None,
+                activity_source: None,
+                integration: None,
+                agent_status_stale: false,
+                repo_missing: false,
+                repo_root: None,
+                merge_mode: crate::core::git::MergeMode::default(),
+                note: None,
+                container: None,
+                todos: None,
+                stacked_on: None,
+                worktree_status: None,
+                detached_at: None,
+                links: Vec::new(),
+                ahead_behind: None,
+                pinned: false,
             },
         ]
     }
@@ -712,6 +1174,87 @@ mod tests {
         assert_eq!(renderer.config.git.branch_prefix, "para");
     }
 
+    #[test]
+    fn test_visible_columns_honor_hidden_columns() {
+        let mut prefs = MonitorPrefs::default();
+        let titles = || TABLE_COLUMNS.iter().map(|(title, _, _)| *title).collect();
+        assert_eq!(visible_columns(&prefs, titles()).len(), TABLE_COLUMNS.len());
+
+        prefs.toggle_column(Column::Task);
+        prefs.toggle_column(Column::Tests);
+        let visible: Vec<&str> = visible_columns(&prefs, titles());
+        assert_eq!(
+            visible,
+            vec![
+                "Actions",
+                "Session",
+                SKIP_PERMISSIONS_MARKER,
+                "State",
+                "Last Modified",
+                "Progress",
+                "Changes",
+                "Merge",
+                "Mode",
+                "Container"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_container_cell_and_details() {
+        use crate::core::docker::stats::ContainerStats;
+
+        let running = ContainerHealth::Running(ContainerStats {
+            cpu_percent: 95.0,
+            mem_usage: 512 * 1024 * 1024,
+            mem_limit: 2 * 1024 * 1024 * 1024,
+        });
+        assert_eq!(
+            container_cell_spans(&running),
+            vec![
+                ("C████".to_string(), COLOR_RED),
+                (" ".to_string(), COLOR_GRAY),
+                ("M█░░░".to_string(), COLOR_GREEN),
+            ]
+        );
+        assert_eq!(
+            container_details(&running),
+            "🐳 CPU 95.0% • Mem 512MiB / 2.0GiB (25%)"
+        );
+
+        let exited = ContainerHealth::Exited { exit_code: 137 };
+        assert_eq!(
+            container_cell_spans(&exited),
+            vec![("● exited 137".to_string(), COLOR_RED)]
+        );
+        assert_eq!(container_details(&exited), "🐳 exited (exit code 137)");
+        assert_eq!(
+            container_cell_spans(&ContainerHealth::Restarting { exit_code: 1 }),
+            vec![("● restart 1".to_string(), COLOR_RED)]
+        );
+        assert_eq!(
+            container_cell_spans(&ContainerHealth::Missing),
+            vec![("missing".to_string(), COLOR_GRAY)]
+        );
+        assert_eq!(
+            container_details(&ContainerHealth::Missing),
+            "🐳 container not found"
+        );
+    }
+
+    #[test]
+    fn test_stacked_session_name_has_arrow() {
+        let mut session = create_test_sessions().remove(0);
+        assert_eq!(session_name_label(&session), "session1");
+        session.stacked_on = Some("parent".to_string());
+        assert_eq!(session_name_label(&session), "↳ session1");
+        session.pinned = true;
+        assert_eq!(
+            session_name_label(&session),
+            format!("↳ session1 {}", theme::pin_marker())
+        );
+    }
+
     #[test]
     fn test_create_progress_bar() {
         // Test empty progress (0%)
@@ -733,6 +1276,9 @@ mod tests {
         assert_eq!(create_progress_bar(1), "░░░░░░░░ 1%"); // Very small progress rounds to 0 blocks
         assert_eq!(create_progress_bar(13), "█░░░░░░░ 13%"); // 13% = 1.04 blocks ≈ 1 block
         assert_eq!(create_progress_bar(99), "████████ 99%"); // Almost complete rounds to full blocks
+
+        assert_eq!(create_todo_progress_bar(43, "3/7"), "██░░░ 3/7");
+        assert_eq!(create_todo_progress_bar(100, "12/12"), "█████ 12/12");
     }
 
     #[test]
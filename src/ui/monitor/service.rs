@@ -1,18 +1,45 @@
 use crate::config::Config;
+use crate::core::docker::resources::{DockerCli, SystemDockerCli};
+use crate::core::docker::stats::{sample_container_health, ContainerHealth};
+use crate::core::git::integration_preview::{preview_integration, IntegrationPreview};
+use crate::core::git::repository::short_sha;
+use crate::core::git::{GitRepository, WorktreeRef, WorktreeStatus};
+use crate::core::session::notes::latest_note;
+use crate::core::session::sync::ahead_behind;
 use crate::core::session::{SessionManager, SessionStatus as CoreSessionStatus};
-use crate::core::status::Status;
-use crate::ui::monitor::activity::detect_last_activity;
-use crate::ui::monitor::cache::ActivityCache;
+use crate::core::status::{is_status_outdated, Status};
+use crate::ui::monitor::activity::ActivityTracker;
+use crate::ui::monitor::cache::BackgroundCache;
+use crate::ui::monitor::prefs::MonitorPrefs;
 use crate::ui::monitor::{SessionInfo, SessionStatus};
-use crate::utils::{get_main_repository_root, Result};
+use crate::utils::Result;
 use chrono::{DateTime, Utc};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
 
+/// Merge previews are comparatively expensive, so they refresh less often than activity
+const INTEGRATION_CHECK_TTL_SECONDS: i64 = 30;
+
+/// `git status` runs in every worktree, so its result is reused for a few refreshes
+const WORKTREE_STATUS_TTL_SECONDS: i64 = 5;
+
+/// Container stats are resampled on every refresh; the TTL only keeps one
+/// `docker stats` call from starting while the previous one still runs
+const CONTAINER_STATS_TTL_SECONDS: i64 = 1;
+
 pub struct SessionService {
     config: Config,
-    activity_cache: ActivityCache,
+    /// Repository the sessions belong to, set when monitoring several repositories
+    repo_root: Option<PathBuf>,
+    activity_tracker: ActivityTracker,
+    integration_cache: BackgroundCache<Option<IntegrationPreview>>,
+    worktree_status_cache: BackgroundCache<Option<WorktreeStatus>>,
+    ahead_behind_cache: BackgroundCache<Option<(usize, usize)>>,
+    head_cache: BackgroundCache<Option<WorktreeRef>>,
+    /// Latest container sample keyed by session name, `None` while docker is unreachable
+    container_cache: BackgroundCache<Option<HashMap<String, ContainerHealth>>>,
+    docker: Arc<dyn DockerCli + Send + Sync>,
     task_cache: Arc<Mutex<HashMap<String, String>>>,
 }
 
@@ -20,18 +47,98 @@ impl SessionService {
     pub fn new(config: Config) -> Self {
         Self {
             config,
-            activity_cache: ActivityCache::new(5),
+            repo_root: None,
+            activity_tracker: ActivityTracker::new(5),
+            integration_cache: BackgroundCache::new(INTEGRATION_CHECK_TTL_SECONDS),
+            worktree_status_cache: BackgroundCache::new(WORKTREE_STATUS_TTL_SECONDS),
+            ahead_behind_cache: BackgroundCache::new(WORKTREE_STATUS_TTL_SECONDS),
+            head_cache: BackgroundCache::new(WORKTREE_STATUS_TTL_SECONDS),
+            container_cache: BackgroundCache::new(CONTAINER_STATS_TTL_SECONDS),
+            docker: Arc::new(SystemDockerCli),
             task_cache: Arc::new(Mutex::new(HashMap::new())),
         }
     }
 
-    pub fn load_sessions(&self, show_stale: bool) -> Result<Vec<SessionInfo>> {
-        let (sessions, current_session) = self.load_base_sessions()?;
-        let sessions = self.enrich_with_activity(sessions)?;
+    /// Service for one of several monitored repositories, with its state
+    /// directory resolved against `repo_root` instead of the current directory
+    pub fn for_repo(config: &Config, repo_root: &Path) -> Self {
+        let mut service = Self::new(config_for_repo(config, repo_root));
+        service.repo_root = Some(repo_root.to_path_buf());
+        service
+    }
+
+    /// Recheck merge previews and how far behind sessions are, e.g. after
+    /// fetching moved the base branches
+    pub fn expire_integration_previews(&self) {
+        self.integration_cache.expire_all();
+        self.ahead_behind_cache.expire_all();
+    }
+
+    pub fn repo_root(&self) -> Option<&Path> {
+        self.repo_root.as_deref()
+    }
+
+    pub fn load_sessions(
+        &self,
+        show_stale: bool,
+        prefs: &MonitorPrefs,
+    ) -> Result<Vec<SessionInfo>> {
+        let (sessions, current_worktree) = self.load_unordered_sessions(show_stale)?;
+        Ok(order_sessions(sessions, prefs, current_worktree.as_deref()))
+    }
+
+    /// Sessions without the user's filter and sort applied, along with the
+    /// worktree of the session the monitor runs in (if any)
+    pub fn load_unordered_sessions(
+        &self,
+        show_stale: bool,
+    ) -> Result<(Vec<SessionInfo>, Option<PathBuf>)> {
+        self.load_matching_sessions(show_stale, None)
+    }
+
+    /// Like [`Self::load_unordered_sessions`], but only for the sessions in
+    /// `names`, so unaffected sessions are not checked again
+    pub fn load_named_sessions(
+        &self,
+        show_stale: bool,
+        names: &HashSet<String>,
+    ) -> Result<(Vec<SessionInfo>, Option<PathBuf>)> {
+        self.load_matching_sessions(show_stale, Some(names))
+    }
+
+    fn load_matching_sessions(
+        &self,
+        show_stale: bool,
+        names: Option<&HashSet<String>>,
+    ) -> Result<(Vec<SessionInfo>, Option<PathBuf>)> {
+        let (mut sessions, current_session) = self.load_base_sessions()?;
+        if let Some(names) = names {
+            sessions.retain(|session| names.contains(&session.name));
+        }
+        let mut sessions = self.enrich_with_activity(sessions)?;
+        self.enrich_with_container_health(&mut sessions);
         let sessions = self.enrich_with_tasks(sessions)?;
-        let sessions = self.enrich_with_agent_status(sessions)?;
-        let sessions = self.apply_filtering_and_sorting(sessions, show_stale, &current_session)?;
-        Ok(sessions)
+        let mut sessions = self.enrich_with_agent_status(sessions)?;
+
+        // Filter out stale sessions if requested
+        if !show_stale {
+            sessions.retain(|session_info| !matches!(session_info.status, SessionStatus::Stale));
+        }
+
+        if let Some(ref root) = self.repo_root {
+            let missing = !root.exists();
+            for session in &mut sessions {
+                session.repo_root = Some(root.clone());
+                session.repo_missing = missing;
+            }
+        }
+
+        Ok((sessions, current_session.map(|s| s.worktree_path)))
+    }
+
+    /// Resolved state directory, relative paths are taken from the main repository root
+    pub fn state_dir(&self) -> PathBuf {
+        self.config.resolved_state_dir()
     }
 
     fn load_base_sessions(
@@ -55,6 +162,8 @@ impl SessionService {
         &self,
         sessions: Vec<crate::core::session::SessionState>,
     ) -> Result<Vec<(crate::core::session::SessionState, SessionInfo)>> {
+        let session_manager = SessionManager::new(&self.config);
+        let state_dir = self.state_dir();
         let mut enriched_sessions = Vec::new();
 
         for session in sessions {
@@ -62,19 +171,19 @@ impl SessionService {
                 continue;
             }
 
-            let last_activity = {
-                let path = session.worktree_path.clone();
-
-                if let Some(cached) = self.activity_cache.get(&path) {
-                    cached
-                } else {
-                    let detected = detect_last_activity(&path);
-                    self.activity_cache.set(path, detected);
-                    detected
-                }
-            }
-            .or(session.last_activity)
-            .unwrap_or(session.created_at);
+            let status_updated = Status::load(&state_dir, &session.name)
+                .ok()
+                .flatten()
+                .map(|status| status.updated_at);
+            let activity = self.activity_tracker.last_activity(
+                &session.worktree_path,
+                &session.branch,
+                status_updated,
+            );
+            let last_activity = activity
+                .map(|a| a.at)
+                .or(session.last_activity)
+                .unwrap_or(session.created_at);
 
             let status = detect_session_status(&session, &last_activity);
 
@@ -83,12 +192,29 @@ impl SessionService {
                 branch: session.branch.clone(),
                 status,
                 last_activity,
+                activity_source: activity.map(|a| a.source),
                 task: format!("Session: {}", &session.name), // Will be properly set in enrich_with_tasks
                 worktree_path: session.worktree_path.clone(),
                 test_status: None,
                 diff_stats: None,
                 todo_percentage: None,
                 is_blocked: false,
+                skip_permissions: session.skips_permissions(),
+                pinned: session.pinned,
+                merge_mode: session.merge_mode,
+                note: latest_note(&state_dir, &session.name),
+                links: session.links.clone(),
+                operation: session_manager.current_operation(&session.name),
+                integration: self.integration_preview(&session),
+                ahead_behind: self.ahead_behind(&session),
+                worktree_status: self.worktree_status(&session),
+                detached_at: self.detached_at(&session),
+                container: None,
+                agent_status_stale: false,
+                repo_root: None,
+                repo_missing: false,
+                todos: None,
+                stacked_on: session.stacked_on.clone(),
             };
 
             enriched_sessions.push((session, session_info));
@@ -97,6 +223,93 @@ impl SessionService {
         Ok(enriched_sessions)
     }
 
+    fn integration_preview(
+        &self,
+        session: &crate::core::session::SessionState,
+    ) -> Option<IntegrationPreview> {
+        let worktree = session.worktree_path.clone();
+        let branch = session.branch.clone();
+        let parent = session.parent_branch.clone();
+        self.integration_cache
+            .get_or_refresh(&session.worktree_path, move || {
+                let repo = GitRepository::discover_from(&worktree).ok()?;
+                let base = match parent {
+                    Some(parent) => parent,
+                    None => repo.get_main_branch().ok()?,
+                };
+                preview_integration(&repo, &branch, &base).ok()
+            })
+            .flatten()
+    }
+
+    fn worktree_status(
+        &self,
+        session: &crate::core::session::SessionState,
+    ) -> Option<WorktreeStatus> {
+        let worktree = session.worktree_path.clone();
+        self.worktree_status_cache
+            .get_or_refresh(&session.worktree_path, move || {
+                GitRepository::discover_from(&worktree)
+                    .ok()?
+                    .worktree_status()
+                    .ok()
+            })
+            .flatten()
+    }
+
+    fn ahead_behind(&self, session: &crate::core::session::SessionState) -> Option<(usize, usize)> {
+        let session = session.clone();
+        self.ahead_behind_cache
+            .get_or_refresh(&session.worktree_path.clone(), move || {
+                let repo = GitRepository::discover_from(&session.worktree_path).ok()?;
+                ahead_behind(&repo, &session)
+            })
+            .flatten()
+    }
+
+    /// Short commit hash the worktree's HEAD is detached at, if it is
+    fn detached_at(&self, session: &crate::core::session::SessionState) -> Option<String> {
+        let worktree = session.worktree_path.clone();
+        match self
+            .head_cache
+            .get_or_refresh(&session.worktree_path, move || {
+                GitRepository::discover_from(&worktree)
+                    .ok()?
+                    .head_ref()
+                    .ok()
+            })
+            .flatten()?
+        {
+            WorktreeRef::Detached(sha) => Some(short_sha(&sha).to_string()),
+            WorktreeRef::Branch(_) => None,
+        }
+    }
+
+    /// Attach the latest container sample to container sessions; sampling
+    /// runs off the UI thread and only when there are container sessions
+    fn enrich_with_container_health(
+        &self,
+        session_pairs: &mut [(crate::core::session::SessionState, SessionInfo)],
+    ) {
+        let names: Vec<String> = session_pairs
+            .iter()
+            .filter(|(session, _)| session.is_container())
+            .map(|(session, _)| session.name.clone())
+            .collect();
+        if names.is_empty() {
+            return;
+        }
+
+        let docker = Arc::clone(&self.docker);
+        let samples = self
+            .container_cache
+            .get_or_refresh(&self.state_dir(), move || {
+                sample_container_health(docker.as_ref(), &names).ok()
+            })
+            .flatten();
+        attach_container_health(session_pairs, samples.as_ref());
+    }
+
     fn enrich_with_tasks(
         &self,
         session_pairs: Vec<(crate::core::session::SessionState, SessionInfo)>,
@@ -130,16 +343,9 @@ impl SessionService {
 
     fn enrich_with_agent_status(&self, mut sessions: Vec<SessionInfo>) -> Result<Vec<SessionInfo>> {
         // Resolve state directory path correctly, same as status command
-        let state_dir = if Path::new(&self.config.directories.state_dir).is_absolute() {
-            PathBuf::from(&self.config.directories.state_dir)
-        } else {
-            // Get the main repository root and join the relative state directory
-            // If we can't find repository root, gracefully fall back to the relative path
-            match get_main_repository_root() {
-                Ok(repo_root) => repo_root.join(&self.config.directories.state_dir),
-                Err(_) => PathBuf::from(&self.config.directories.state_dir), // Graceful fallback
-            }
-        };
+        let state_dir = self.state_dir();
+        let stale_after_minutes = self.config.get_status_stale_after_minutes();
+        let now = Utc::now();
 
         for session_info in &mut sessions {
             let agent_status = Status::load(&state_dir, &session_info.name).ok().flatten();
@@ -150,19 +356,20 @@ impl SessionService {
                 SessionStatus::Review | SessionStatus::Ready
             );
 
-            let (test_status, diff_stats, todo_percentage, is_blocked, agent_task) =
+            let (test_status, diff_stats, todo_percentage, todos, is_blocked, agent_task) =
                 if let Some(ref status) = agent_status {
                     (
                         Some(status.test_status.clone()),
                         status.diff_stats.clone(),
                         status.calculate_progress_with_finish(is_finished),
+                        status.todo_fraction(),
                         status.is_blocked,
                         Some(status.current_task.clone()),
                     )
                 } else {
                     // No agent status - return progress based on finish status alone
                     let progress = if is_finished { Some(100) } else { Some(0) };
-                    (None, None, progress, false, None)
+                    (None, None, progress, None, false, None)
                 };
 
             // Agent task takes priority over session task
@@ -173,44 +380,73 @@ impl SessionService {
             session_info.test_status = test_status;
             session_info.diff_stats = diff_stats;
             session_info.todo_percentage = todo_percentage;
+            session_info.todos = todos;
             session_info.is_blocked = is_blocked;
+            session_info.agent_status_stale = agent_status
+                .as_ref()
+                .is_some_and(|s| is_status_outdated(s.updated_at, now, stale_after_minutes));
         }
 
         Ok(sessions)
     }
+}
 
-    fn apply_filtering_and_sorting(
-        &self,
-        mut sessions: Vec<SessionInfo>,
-        show_stale: bool,
-        current_session: &Option<crate::core::session::SessionState>,
-    ) -> Result<Vec<SessionInfo>> {
-        // Filter out stale sessions if requested
-        if !show_stale {
-            sessions.retain(|session_info| !matches!(session_info.status, SessionStatus::Stale));
-        }
+/// Copy each container session's entry of `samples` onto its row. Sessions
+/// the sample does not cover yet stay unknown rather than missing.
+fn attach_container_health(
+    session_pairs: &mut [(crate::core::session::SessionState, SessionInfo)],
+    samples: Option<&HashMap<String, ContainerHealth>>,
+) {
+    for (session, info) in session_pairs {
+        info.container = if session.is_container() {
+            samples.and_then(|samples| samples.get(&session.name).cloned())
+        } else {
+            None
+        };
+    }
+}
 
-        // Sort by current session first, then by last activity
-        sessions.sort_by(|a, b| {
-            if let Some(ref current) = current_session {
-                if a.name == current.name {
-                    return std::cmp::Ordering::Less;
-                }
-                if b.name == current.name {
-                    return std::cmp::Ordering::Greater;
-                }
-            }
-            b.last_activity.cmp(&a.last_activity)
-        });
+/// Apply the user's filter and sort, then keep the current session on top
+pub fn order_sessions(
+    sessions: Vec<SessionInfo>,
+    prefs: &MonitorPrefs,
+    current_worktree: Option<&Path>,
+) -> Vec<SessionInfo> {
+    let mut sessions = prefs.apply(sessions);
+    if let Some(current) = current_worktree {
+        sessions.sort_by_key(|session| session.worktree_path != current);
+    }
+    sessions
+}
 
-        Ok(sessions)
+/// `config` with the state directory made absolute for the repository at
+/// `repo_root`, taken from `bare_root` for a bare repository. `subtrees_dir`
+/// stays as configured, since worktree paths are always resolved against the
+/// repository they are created in.
+pub fn config_for_repo(config: &Config, repo_root: &Path) -> Config {
+    let mut config = config.clone();
+    let directories = &mut config.directories;
+    if !Path::new(&directories.state_dir).is_absolute() {
+        let root = directories
+            .work_root(repo_root)
+            .unwrap_or_else(|_| repo_root.to_path_buf());
+        directories.state_dir = root
+            .join(&directories.state_dir)
+            .to_string_lossy()
+            .to_string();
     }
+    config
 }
 
 fn detect_session_status(
     session: &crate::core::session::SessionState,
     last_activity: &DateTime<Utc>,
 ) -> SessionStatus {
+    // Planned sessions have no activity until they are launched
+    if matches!(session.status, CoreSessionStatus::Planned) {
+        return SessionStatus::Planned;
+    }
+
     // Check if session is marked as review
     if matches!(session.status, CoreSessionStatus::Review) {
         return SessionStatus::Review;
@@ -228,6 +464,8 @@ fn detect_session_status(
     match elapsed.num_minutes() {
         0..=5 => SessionStatus::Active,
         6..=1440 => SessionStatus::Idle, // 1440 minutes = 24 hours
+        // Pinned sessions are expected to sit untouched for weeks
+        _ if session.pinned => SessionStatus::Idle,
         _ => SessionStatus::Stale,
     }
 }
@@ -237,6 +475,7 @@ mod tests {
     use super::*;
     use crate::config::Config;
     use crate::core::session::SessionState;
+    use crate::test_utils::test_helpers::git;
 
     #[test]
     fn test_detect_session_status() {
@@ -267,6 +506,21 @@ mod tests {
         assert!(matches!(status, SessionStatus::Stale));
     }
 
+    #[test]
+    fn test_pinned_session_is_never_stale() {
+        let mut session = SessionState::new(
+            "perf".to_string(),
+            "test/perf".to_string(),
+            std::path::PathBuf::from("/perf"),
+        );
+        session.pinned = true;
+        let three_weeks_ago = chrono::Utc::now() - chrono::Duration::weeks(3);
+        assert!(matches!(
+            detect_session_status(&session, &three_weeks_ago),
+            SessionStatus::Idle
+        ));
+    }
+
     #[test]
     fn test_detect_session_status_ready() {
         let mut session = SessionState::new(
@@ -295,6 +549,20 @@ mod tests {
         assert!(matches!(status, SessionStatus::Review));
     }
 
+    #[test]
+    fn test_detect_session_status_planned() {
+        let mut session = SessionState::new(
+            "planned-session".to_string(),
+            "planned-branch".to_string(),
+            std::path::PathBuf::from("/test"),
+        );
+        session.update_status(CoreSessionStatus::Planned);
+
+        let long_ago = chrono::Utc::now() - chrono::Duration::days(3);
+        let status = detect_session_status(&session, &long_ago);
+        assert!(matches!(status, SessionStatus::Planned));
+    }
+
     #[test]
     fn test_service_activity_cache() {
         let config = create_test_config();
@@ -303,18 +571,21 @@ mod tests {
         // Mock path for testing
         let test_path = PathBuf::from("/test/worktree");
 
-        // First call should detect activity (mock None for testing)
-        let cached = service.activity_cache.get(&test_path);
-        assert_eq!(cached, None, "Should not be cached initially");
+        // Nothing known about a missing worktree yet
+        let activity = service.activity_tracker.last_activity(&test_path, "", None);
+        assert_eq!(activity, None, "Should not be cached initially");
 
-        // Simulate caching
-        service
-            .activity_cache
-            .set(test_path.clone(), Some(Utc::now()));
-
-        // Second call should return cached value
-        let cached = service.activity_cache.get(&test_path);
-        assert!(cached.is_some(), "Should return cached value");
+        // The status timestamp is used while no worktree activity is known
+        let now = Utc::now();
+        let activity = service
+            .activity_tracker
+            .last_activity(&test_path, "", Some(now))
+            .unwrap();
+        assert_eq!(activity.at, now);
+        assert_eq!(
+            activity.source,
+            crate::ui::monitor::activity::ActivitySource::Status
+        );
     }
 
     #[test]
@@ -546,6 +817,23 @@ mod tests {
             diff_stats: None,
             todo_percentage: agent_status.todo_percentage(),
             is_blocked: agent_status.is_blocked,
+            skip_permissions: false,
+            operation: None,
+            activity_source: None,
+            integration: None,
+            agent_status_stale: false,
+            repo_missing: false,
+            repo_root: None,
+            merge_mode: crate::core::git::MergeMode::default(),
+            note: None,
+            container: None,
+            todos: agent_status.todo_fraction(),
+            stacked_on: None,
+            worktree_status: None,
+            detached_at: None,
+            links: Vec::new(),
+            ahead_behind: None,
+            pinned: false,
         };
 
         // Verify agent status is properly integrated
@@ -553,6 +841,7 @@ mod tests {
         assert_eq!(session_info.task, "Complex integration task"); // Agent task priority
         assert_eq!(session_info.test_status, Some(TestStatus::Failed));
         assert_eq!(session_info.todo_percentage, Some(20)); // 2/10 = 20%
+        assert_eq!(session_info.todos.as_deref(), Some("2/10"));
         assert!(!session_info.is_blocked); // Agent status not blocked
     }
 
@@ -597,6 +886,23 @@ mod tests {
             diff_stats: None,
             todo_percentage: None,
             is_blocked: false,
+            skip_permissions: false,
+            operation: None,
+            activity_source: None,
+            integration: None,
+            agent_status_stale: false,
+            repo_missing: false,
+            repo_root: None,
+            merge_mode: crate::core::git::MergeMode::default(),
+            note: None,
+            container: None,
+            todos: None,
+            stacked_on: None,
+            worktree_status: None,
+            detached_at: None,
+            links: Vec::new(),
+            ahead_behind: None,
+            pinned: false,
         };
 
         let session2 = SessionInfo {
@@ -610,6 +916,23 @@ mod tests {
             diff_stats: None,
             todo_percentage: None,
             is_blocked: false,
+            skip_permissions: false,
+            operation: None,
+            activity_source: None,
+            integration: None,
+            agent_status_stale: false,
+            repo_missing: false,
+            repo_root: None,
+            merge_mode: crate::core::git::MergeMode::default(),
+            note: None,
+            container: None,
+            todos: None,
+            stacked_on: None,
+            worktree_status: None,
+            detached_at: None,
+            links: Vec::new(),
+            ahead_behind: None,
+            pinned: false,
         };
 
         let session3 = SessionInfo {
@@ -623,9 +946,26 @@ mod tests {
             diff_stats: None,
             todo_percentage: None,
             is_blocked: false,
+            skip_permissions: false,
+            operation: None,
+            activity_source: None,
+            integration: None,
+            agent_status_stale: false,
+            repo_missing: false,
+            repo_root: None,
+            merge_mode: crate::core::git::MergeMode::default(),
+            note: None,
+            container: None,
+            todos: None,
+            stacked_on: None,
+            worktree_status: None,
+            detached_at: None,
+            links: Vec::new(),
+            ahead_behind: None,
+            pinned: false,
         };
 
-        let mut sessions = vec![session1, session2, session3];
+        let mut sessions = [session1, session2, session3];
         let current_session_name = Some("current-session".to_string());
 
         // Test sorting logic from load_sessions
@@ -659,24 +999,52 @@ mod tests {
                     name: String::new(),
                     command: String::new(),
                 },
+                remote: None,
             },
             directories: crate::config::DirectoryConfig {
                 subtrees_dir: "/tmp/subtrees".to_string(),
                 state_dir: "/tmp/.para_state".to_string(),
+                bare_root: None,
+                layout: Default::default(),
             },
             git: crate::config::GitConfig {
                 branch_prefix: "para".to_string(),
                 auto_stage: true,
                 auto_commit: false,
+                author_email: None,
+                author_name: None,
+                protected_branches: Vec::new(),
+                auto_commit_interval_minutes: None,
+                default_merge_mode: None,
+                command_timeout_secs: None,
+                audit_log: false,
+                default_remote: None,
+                auto_fetch_interval_minutes: None,
+                auto_fetch: false,
+                apply_whitespace: Default::default(),
+                behind_warning_commits: None,
             },
             session: crate::config::SessionConfig {
                 default_name_format: "%Y%m%d-%H%M%S".to_string(),
                 preserve_on_finish: false,
                 auto_cleanup_days: Some(7),
+                retention: None,
+                copy_untracked: Vec::new(),
+                idle_action: Default::default(),
+                idle_alert_hours: None,
+                conflict_hotspots: false,
+                on_resume: Vec::new(),
             },
             docker: None,
             setup_script: None,
             sandbox: None,
+            notifications: None,
+            security: None,
+            status: None,
+            templates: Default::default(),
+            cleanup: None,
+            vcs: None,
+            ui: None,
         }
     }
 
@@ -692,34 +1060,62 @@ mod tests {
                     name: String::new(),
                     command: String::new(),
                 },
+                remote: None,
             },
             directories: crate::config::DirectoryConfig {
                 subtrees_dir: "/tmp/subtrees".to_string(),
                 state_dir: "/tmp/.para_state_test".to_string(),
+                bare_root: None,
+                layout: Default::default(),
             },
             git: crate::config::GitConfig {
                 branch_prefix: "para".to_string(),
                 auto_stage: true,
                 auto_commit: false,
+                author_email: None,
+                author_name: None,
+                protected_branches: Vec::new(),
+                auto_commit_interval_minutes: None,
+                default_merge_mode: None,
+                command_timeout_secs: None,
+                audit_log: false,
+                default_remote: None,
+                auto_fetch_interval_minutes: None,
+                auto_fetch: false,
+                apply_whitespace: Default::default(),
+                behind_warning_commits: None,
             },
             session: crate::config::SessionConfig {
                 default_name_format: "%Y%m%d-%H%M%S".to_string(),
                 preserve_on_finish: false,
                 auto_cleanup_days: Some(7),
+                retention: None,
+                copy_untracked: Vec::new(),
+                idle_action: Default::default(),
+                idle_alert_hours: None,
+                conflict_hotspots: false,
+                on_resume: Vec::new(),
             },
             docker: None,
             setup_script: None,
             sandbox: None,
+            notifications: None,
+            security: None,
+            status: None,
+            templates: Default::default(),
+            cleanup: None,
+            vcs: None,
+            ui: None,
         };
 
         let service = SessionService::new(config);
 
         // Test loading sessions (should handle missing directory gracefully)
-        let result = service.load_sessions(true);
+        let result = service.load_sessions(true, &MonitorPrefs::default());
         assert!(result.is_ok());
 
         // Test without stale sessions
-        let result = service.load_sessions(false);
+        let result = service.load_sessions(false, &MonitorPrefs::default());
         assert!(result.is_ok());
     }
 
@@ -760,6 +1156,23 @@ mod tests {
             diff_stats: None,
             todo_percentage: None,
             is_blocked: false,
+            skip_permissions: false,
+            operation: None,
+            activity_source: None,
+            integration: None,
+            agent_status_stale: false,
+            repo_missing: false,
+            repo_root: None,
+            merge_mode: crate::core::git::MergeMode::default(),
+            note: None,
+            container: None,
+            todos: None,
+            stacked_on: None,
+            worktree_status: None,
+            detached_at: None,
+            links: Vec::new(),
+            ahead_behind: None,
+            pinned: false,
         };
 
         // Test enrichment logic
@@ -836,6 +1249,23 @@ mod tests {
                 diff_stats: None,
                 todo_percentage: None,
                 is_blocked: false,
+                skip_permissions: false,
+                operation: None,
+                activity_source: None,
+                integration: None,
+                agent_status_stale: false,
+                repo_missing: false,
+                repo_root: None,
+                merge_mode: crate::core::git::MergeMode::default(),
+                note: None,
+                container: None,
+                todos: None,
+                stacked_on: None,
+                worktree_status: None,
+                detached_at: None,
+                links: Vec::new(),
+                ahead_behind: None,
+                pinned: false,
             },
             SessionInfo {
                 name: "no-status-review".to_string(),
@@ -848,6 +1278,23 @@ mod tests {
                 diff_stats: None,
                 todo_percentage: None,
                 is_blocked: false,
+                skip_permissions: false,
+                operation: None,
+                activity_source: None,
+                integration: None,
+                agent_status_stale: false,
+                repo_missing: false,
+                repo_root: None,
+                merge_mode: crate::core::git::MergeMode::default(),
+                note: None,
+                container: None,
+                todos: None,
+                stacked_on: None,
+                worktree_status: None,
+                detached_at: None,
+                links: Vec::new(),
+                ahead_behind: None,
+                pinned: false,
             },
         ];
 
@@ -860,4 +1307,118 @@ mod tests {
         // Review session with no agent status should show 100%
         assert_eq!(enriched[1].todo_percentage, Some(100));
     }
+
+    #[test]
+    fn test_attach_container_health_to_rows() {
+        use crate::core::docker::stats::ContainerStats;
+        use std::path::PathBuf;
+
+        let row = |name: &str| SessionInfo {
+            name: name.to_string(),
+            branch: format!("para/{name}"),
+            status: SessionStatus::Active,
+            last_activity: Utc::now(),
+            task: String::new(),
+            worktree_path: PathBuf::from(format!("/test/{name}")),
+            test_status: None,
+            diff_stats: None,
+            todo_percentage: None,
+            is_blocked: false,
+            skip_permissions: false,
+            operation: None,
+            activity_source: None,
+            integration: None,
+            agent_status_stale: false,
+            repo_missing: false,
+            repo_root: None,
+            merge_mode: crate::core::git::MergeMode::default(),
+            note: None,
+            container: Some(ContainerHealth::Missing),
+            todos: None,
+            stacked_on: None,
+            worktree_status: None,
+            detached_at: None,
+            links: Vec::new(),
+            ahead_behind: None,
+            pinned: false,
+        };
+        let container_session = |name: &str| {
+            SessionState::new_container_with_parent_branch_and_flags(
+                name.to_string(),
+                format!("para/{name}"),
+                PathBuf::from(format!("/test/{name}")),
+                None,
+                "main".to_string(),
+                false,
+            )
+        };
+        let worktree_session = SessionState::new(
+            "local".to_string(),
+            "para/local".to_string(),
+            "/test".into(),
+        );
+
+        let stats = ContainerStats {
+            cpu_percent: 42.0,
+            mem_usage: 1024,
+            mem_limit: 4096,
+        };
+        let samples = HashMap::from([
+            ("busy".to_string(), ContainerHealth::Running(stats.clone())),
+            ("gone".to_string(), ContainerHealth::Missing),
+        ]);
+        let mut pairs = vec![
+            (container_session("busy"), row("busy")),
+            (container_session("gone"), row("gone")),
+            (container_session("new"), row("new")),
+            (worktree_session, row("local")),
+        ];
+
+        attach_container_health(&mut pairs, Some(&samples));
+        assert_eq!(pairs[0].1.container, Some(ContainerHealth::Running(stats)));
+        assert_eq!(pairs[1].1.container, Some(ContainerHealth::Missing));
+        // Not sampled yet is unknown, not missing
+        assert_eq!(pairs[2].1.container, None);
+        assert_eq!(pairs[3].1.container, None);
+
+        // Docker unreachable: nothing is known about any container
+        attach_container_health(&mut pairs, None);
+        assert!(pairs.iter().all(|(_, info)| info.container.is_none()));
+    }
+
+    #[test]
+    fn test_config_for_repo_resolves_state_dir_against_bare_root() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let bare = temp.path().join("app.git");
+        git(temp.path(), &["init", "--quiet", "--bare", "app.git"]);
+
+        let mut config = create_test_config();
+        config.directories.subtrees_dir = ".para/worktrees".to_string();
+        config.directories.state_dir = ".para/state".to_string();
+        config.directories.bare_root = Some("{repo}.para".to_string());
+
+        let resolved = config_for_repo(&config, &bare);
+        assert_eq!(
+            Path::new(&resolved.directories.state_dir),
+            temp.path().join("app.para/.para/state")
+        );
+        assert_eq!(resolved.directories.subtrees_dir, ".para/worktrees");
+        assert_eq!(
+            resolved.subtrees_path(&bare).unwrap(),
+            temp.path().join("app.para/.para/worktrees")
+        );
+
+        // A repository with a working tree keeps both below its root
+        let repo = temp.path().join("app");
+        std::fs::create_dir(&repo).unwrap();
+        let resolved = config_for_repo(&config, &repo);
+        assert_eq!(
+            Path::new(&resolved.directories.state_dir),
+            repo.join(".para/state")
+        );
+        assert_eq!(
+            resolved.subtrees_path(&repo).unwrap(),
+            repo.join(".para/worktrees")
+        );
+    }
 }
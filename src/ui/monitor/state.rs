@@ -1,7 +1,15 @@
+use crate::core::session::sync::DEFAULT_BEHIND_WARNING_COMMITS;
+use crate::ui::monitor::operation_queue::PendingOperation;
+use crate::ui::monitor::prefs::{Column, MonitorPrefs};
 use crate::ui::monitor::{AppMode, SessionInfo};
 use ratatui::layout::Rect;
 use ratatui::widgets::TableState;
-use std::time::Instant;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Periodic refresh while session events arrive, which still catches git
+/// and activity changes that publish no event
+pub const EVENT_DRIVEN_REFRESH_INTERVAL: Duration = Duration::from_secs(15);
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ButtonClick {
@@ -23,6 +31,20 @@ pub struct MonitorAppState {
     pub table_area: Option<Rect>,
     pub feedback_message: Option<(String, Instant)>,
     pub button_click: Option<(ButtonClick, Instant)>,
+    pub prefs: MonitorPrefs,
+    /// Highlighted entry in the column picker
+    pub column_cursor: usize,
+    /// The last automatic fetch failed
+    pub fetch_failed: bool,
+    /// Finish, cancel and sync jobs queued or running, by `operation_key`
+    pub operations: HashMap<String, PendingOperation>,
+    /// Sessions further behind their base than this are flagged
+    pub behind_warning_commits: usize,
+    /// `operation_key` of the session whose conflicts the error dialog
+    /// offers to open
+    pub conflict: Option<String>,
+    /// Session events are being received, so the periodic refresh can be rare
+    pub events_connected: bool,
 }
 
 impl MonitorAppState {
@@ -42,6 +64,13 @@ impl MonitorAppState {
             table_area: None,
             feedback_message: None,
             button_click: None,
+            prefs: MonitorPrefs::default(),
+            column_cursor: 0,
+            fetch_failed: false,
+            operations: HashMap::new(),
+            behind_warning_commits: DEFAULT_BEHIND_WARNING_COMMITS,
+            conflict: None,
+            events_connected: false,
         }
     }
 
@@ -90,7 +119,14 @@ impl MonitorAppState {
     }
 
     pub fn should_refresh(&self) -> bool {
-        self.last_refresh.elapsed().as_secs() >= 2
+        let interval = if self.events_connected {
+            self.prefs
+                .refresh_interval()
+                .max(EVENT_DRIVEN_REFRESH_INTERVAL)
+        } else {
+            self.prefs.refresh_interval()
+        };
+        self.last_refresh.elapsed() >= interval
     }
 
     pub fn mark_refreshed(&mut self) {
@@ -101,6 +137,31 @@ impl MonitorAppState {
         self.show_stale = !self.show_stale;
     }
 
+    pub fn start_filter(&mut self) {
+        self.mode = AppMode::FilterInput;
+    }
+
+    pub fn open_column_picker(&mut self) {
+        self.mode = AppMode::ColumnPicker;
+        self.column_cursor = 0;
+    }
+
+    pub fn next_column(&mut self) {
+        if self.column_cursor + 1 < Column::ALL.len() {
+            self.column_cursor += 1;
+        }
+    }
+
+    pub fn previous_column(&mut self) {
+        self.column_cursor = self.column_cursor.saturating_sub(1);
+    }
+
+    pub fn toggle_selected_column(&mut self) {
+        if let Some(column) = Column::ALL.get(self.column_cursor) {
+            self.prefs.toggle_column(*column);
+        }
+    }
+
     pub fn add_char(&mut self, c: char) {
         self.input_buffer.push(c);
     }
@@ -122,11 +183,28 @@ impl MonitorAppState {
         self.mode = AppMode::ErrorDialog;
     }
 
+    /// Show an error about conflicts left in a session's worktree, with the
+    /// option to open the session and resolve them
+    pub fn show_conflict(&mut self, message: String, key: String) {
+        self.show_error(message);
+        self.conflict = Some(key);
+    }
+
     pub fn clear_error(&mut self) {
         self.error_message = None;
+        self.conflict = None;
         self.mode = AppMode::Normal;
     }
 
+    /// Commits `session` is behind its base, when that is more than
+    /// `behind_warning_commits`
+    pub fn behind_warning(&self, session: &SessionInfo) -> Option<usize> {
+        session
+            .ahead_behind
+            .map(|(_, behind)| behind)
+            .filter(|behind| *behind > self.behind_warning_commits)
+    }
+
     pub fn take_input(&mut self) -> String {
         let input = self.input_buffer.clone();
         self.input_buffer.clear();
@@ -217,6 +295,23 @@ mod tests {
                 diff_stats: None,
                 todo_percentage: None,
                 is_blocked: false,
+                skip_permissions: false,
+                operation: None,
+                activity_source: None,
+                integration: None,
+                agent_status_stale: false,
+                repo_missing: false,
+                repo_root: None,
+                merge_mode: crate::core::git::MergeMode::default(),
+                note: None,
+                container: None,
+                todos: None,
+                stacked_on: None,
+                worktree_status: None,
+                detached_at: None,
+                links: Vec::new(),
+                ahead_behind: None,
+                pinned: false,
             },
             SessionInfo {
                 name: "session2".to_string(),
@@ -229,6 +324,23 @@ mod tests {
                 diff_stats: None,
                 todo_percentage: None,
                 is_blocked: false,
+                skip_permissions: false,
+                operation: None,
+                activity_source: None,
+                integration: None,
+                agent_status_stale: false,
+                repo_missing: false,
+                repo_root: None,
+                merge_mode: crate::core::git::MergeMode::default(),
+                note: None,
+                container: None,
+                todos: None,
+                stacked_on: None,
+                worktree_status: None,
+                detached_at: None,
+                links: Vec::new(),
+                ahead_behind: None,
+                pinned: false,
             },
             SessionInfo {
                 name: "session3".to_string(),
@@ -241,6 +353,23 @@ mod tests {
                 diff_stats: None,
                 todo_percentage: None,
                 is_blocked: false,
+                skip_permissions: false,
+                operation: None,
+                activity_source: None,
+                integration: None,
+                agent_status_stale: false,
+                repo_missing: false,
+                repo_root: None,
+                merge_mode: crate::core::git::MergeMode::default(),
+                note: None,
+                container: None,
+                todos: None,
+                stacked_on: None,
+                worktree_status: None,
+                detached_at: None,
+                links: Vec::new(),
+                ahead_behind: None,
+                pinned: false,
             },
         ]
     }
@@ -330,6 +459,33 @@ mod tests {
         state.clear_error();
         assert_eq!(state.mode, AppMode::Normal);
         assert!(state.error_message.is_none());
+
+        state.show_conflict("Conflicts".to_string(), "/tmp/session1".to_string());
+        assert_eq!(state.mode, AppMode::ErrorDialog);
+        assert_eq!(state.conflict.as_deref(), Some("/tmp/session1"));
+        state.clear_error();
+        assert!(state.conflict.is_none());
+    }
+
+    #[test]
+    fn test_behind_warning_threshold() {
+        let mut state = MonitorAppState::new();
+        let mut session = create_test_sessions().remove(0);
+        assert_eq!(state.behind_warning(&session), None);
+
+        session.ahead_behind = Some((3, DEFAULT_BEHIND_WARNING_COMMITS));
+        assert_eq!(state.behind_warning(&session), None);
+        session.ahead_behind = Some((3, DEFAULT_BEHIND_WARNING_COMMITS + 1));
+        assert_eq!(
+            state.behind_warning(&session),
+            Some(DEFAULT_BEHIND_WARNING_COMMITS + 1)
+        );
+
+        state.behind_warning_commits = 0;
+        session.ahead_behind = Some((0, 1));
+        assert_eq!(state.behind_warning(&session), Some(1));
+        session.ahead_behind = Some((5, 0));
+        assert_eq!(state.behind_warning(&session), None);
     }
 
     #[test]
@@ -395,6 +551,18 @@ mod tests {
         assert!(!state.should_refresh());
     }
 
+    #[test]
+    fn test_refresh_is_rare_while_events_arrive() {
+        let mut state = MonitorAppState::new();
+        state.last_refresh = Instant::now() - state.prefs.refresh_interval();
+        assert!(state.should_refresh());
+
+        state.events_connected = true;
+        assert!(!state.should_refresh());
+        state.last_refresh = Instant::now() - EVENT_DRIVEN_REFRESH_INTERVAL;
+        assert!(state.should_refresh());
+    }
+
     #[test]
     fn test_toggles() {
         let mut state = MonitorAppState::new();
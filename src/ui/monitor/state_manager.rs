@@ -1,22 +1,141 @@
-use crate::ui::monitor::service::SessionService;
+use crate::ui::monitor::prefs::MonitorPrefs;
+use crate::ui::monitor::service::{order_sessions, SessionService};
 use crate::ui::monitor::state::MonitorAppState;
 use crate::ui::monitor::SessionInfo;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
 
 /// Manages application state and session data
 pub struct StateManager {
-    service: SessionService,
+    /// One service per monitored repository. The first one also holds the
+    /// view preferences.
+    services: Vec<SessionService>,
 }
 
 impl StateManager {
     pub fn new(service: SessionService) -> Self {
-        Self { service }
+        Self {
+            services: vec![service],
+        }
+    }
+
+    /// Manager for several repositories; `services` must not be empty
+    pub fn for_repos(services: Vec<SessionService>) -> Self {
+        assert!(!services.is_empty(), "at least one repository is required");
+        Self { services }
     }
 
     /// Load sessions from the service based on current state
-    pub fn load_sessions(&self, show_stale: bool) -> Vec<SessionInfo> {
-        self.service
-            .load_sessions(show_stale)
-            .unwrap_or_else(|_| Vec::new())
+    pub fn load_sessions(&self, state: &MonitorAppState) -> Vec<SessionInfo> {
+        if let [service] = self.services.as_slice() {
+            return service
+                .load_sessions(state.show_stale, &state.prefs)
+                .unwrap_or_else(|_| Vec::new());
+        }
+
+        let mut sessions = Vec::new();
+        let mut current_worktree = None;
+        let mut loaded_state_dirs = HashSet::new();
+        for service in &self.services {
+            // Repositories configured with the same absolute state directory
+            // share their sessions, so read each directory only once
+            if !loaded_state_dirs.insert(service.state_dir()) {
+                continue;
+            }
+            if let Ok((repo_sessions, current)) = service.load_unordered_sessions(state.show_stale)
+            {
+                sessions.extend(repo_sessions);
+                current_worktree = current_worktree.or(current);
+            }
+        }
+        self.attribute_shared_sessions(&mut sessions);
+
+        order_sessions(sessions, &state.prefs, current_worktree.as_deref())
+    }
+
+    /// Reload only the sessions in `names`, e.g. after session events,
+    /// keeping the others of `sessions` as they are
+    pub fn reload_sessions(
+        &self,
+        state: &MonitorAppState,
+        mut sessions: Vec<SessionInfo>,
+        names: &HashSet<String>,
+    ) -> Vec<SessionInfo> {
+        sessions.retain(|session| !names.contains(&session.name));
+
+        let mut current_worktree = None;
+        let mut loaded_state_dirs = HashSet::new();
+        for service in &self.services {
+            if !loaded_state_dirs.insert(service.state_dir()) {
+                continue;
+            }
+            if let Ok((reloaded, current)) = service.load_named_sessions(state.show_stale, names) {
+                sessions.extend(reloaded);
+                current_worktree = current_worktree.or(current);
+            }
+        }
+        self.attribute_shared_sessions(&mut sessions);
+
+        order_sessions(sessions, &state.prefs, current_worktree.as_deref())
+    }
+
+    /// State directories of the monitored repositories, each listed once
+    pub fn state_dirs(&self) -> Vec<PathBuf> {
+        let mut dirs: Vec<PathBuf> = Vec::new();
+        for service in &self.services {
+            let dir = service.state_dir();
+            if !dirs.contains(&dir) {
+                dirs.push(dir);
+            }
+        }
+        dirs
+    }
+
+    /// Move sessions read from a shared state directory to the repository
+    /// their worktree lives in
+    fn attribute_shared_sessions(&self, sessions: &mut [SessionInfo]) {
+        let roots: Vec<&Path> = self.services.iter().filter_map(|s| s.repo_root()).collect();
+        for session in sessions {
+            let in_own_repo = session
+                .repo_root
+                .as_ref()
+                .is_some_and(|root| session.worktree_path.starts_with(root));
+            if in_own_repo {
+                continue;
+            }
+            if let Some(root) = roots
+                .iter()
+                .find(|root| session.worktree_path.starts_with(root))
+            {
+                session.repo_root = Some(root.to_path_buf());
+                session.repo_missing = !root.exists();
+            }
+        }
+    }
+
+    /// Repositories being monitored, if the services know their roots
+    pub fn repo_roots(&self) -> Vec<PathBuf> {
+        self.services
+            .iter()
+            .filter_map(|service| service.repo_root().map(Path::to_path_buf))
+            .collect()
+    }
+
+    /// Recheck everything that depends on remote refs after a fetch
+    pub fn handle_fetch_finished(&self) {
+        for service in &self.services {
+            service.expire_integration_previews();
+        }
+    }
+
+    /// Load persisted view preferences into the state
+    pub fn load_prefs(&self, state: &mut MonitorAppState) {
+        state.prefs = MonitorPrefs::load(&self.services[0].state_dir());
+    }
+
+    /// Persist the current view preferences
+    pub fn save_prefs(&self, state: &MonitorAppState) -> crate::utils::Result<()> {
+        state.prefs.save(&self.services[0].state_dir())
     }
 
     /// Update the sessions list and adjust state accordingly
@@ -81,6 +200,23 @@ mod tests {
                 diff_stats: None,
                 todo_percentage: None,
                 is_blocked: false,
+                skip_permissions: false,
+                operation: None,
+                activity_source: None,
+                integration: None,
+                agent_status_stale: false,
+                repo_missing: false,
+                repo_root: None,
+                merge_mode: crate::core::git::MergeMode::default(),
+                note: None,
+                container: None,
+                todos: None,
+                stacked_on: None,
+                worktree_status: None,
+                detached_at: None,
+                links: Vec::new(),
+                ahead_behind: None,
+                pinned: false,
             },
             SessionInfo {
                 name: "session2".to_string(),
@@ -93,6 +229,23 @@ mod tests {
                 diff_stats: None,
                 todo_percentage: None,
                 is_blocked: false,
+                skip_permissions: false,
+                operation: None,
+                activity_source: None,
+                integration: None,
+                agent_status_stale: false,
+                repo_missing: false,
+                repo_root: None,
+                merge_mode: crate::core::git::MergeMode::default(),
+                note: None,
+                container: None,
+                todos: None,
+                stacked_on: None,
+                worktree_status: None,
+                detached_at: None,
+                links: Vec::new(),
+                ahead_behind: None,
+                pinned: false,
             },
             SessionInfo {
                 name: "session3".to_string(),
@@ -105,6 +258,23 @@ mod tests {
                 diff_stats: None,
                 todo_percentage: None,
                 is_blocked: false,
+                skip_permissions: false,
+                operation: None,
+                activity_source: None,
+                integration: None,
+                agent_status_stale: false,
+                repo_missing: false,
+                repo_root: None,
+                merge_mode: crate::core::git::MergeMode::default(),
+                note: None,
+                container: None,
+                todos: None,
+                stacked_on: None,
+                worktree_status: None,
+                detached_at: None,
+                links: Vec::new(),
+                ahead_behind: None,
+                pinned: false,
             },
         ]
     }
@@ -1,4 +1,10 @@
+use crate::core::docker::stats::ContainerHealth;
+use crate::core::git::integration_preview::IntegrationPreview;
+use crate::core::git::{MergeMode, WorktreeStatus};
+use crate::core::session::links::SessionLink;
+use crate::core::session::OperationRecord;
 use crate::core::status::{DiffStats, TestStatus};
+use crate::ui::monitor::activity::ActivitySource;
 use chrono::{DateTime, Utc};
 use ratatui::style::Color;
 use std::path::PathBuf;
@@ -9,22 +15,57 @@ pub struct SessionInfo {
     pub branch: String,
     pub status: SessionStatus,
     pub last_activity: DateTime<Utc>,
+    /// Which source `last_activity` came from, if it was detected
+    pub activity_source: Option<ActivitySource>,
     pub task: String,
     pub worktree_path: PathBuf,
     // Agent status fields
     pub test_status: Option<TestStatus>,
     pub diff_stats: Option<DiffStats>,
     pub todo_percentage: Option<u8>,
+    /// Completed todos, e.g. `3/7`
+    pub todos: Option<String>,
+    /// Session this one is stacked on
+    pub stacked_on: Option<String>,
     pub is_blocked: bool,
+    /// Launched with `--dangerously-skip-permissions`
+    pub skip_permissions: bool,
+    /// Kept with `para pin`
+    pub pinned: bool,
+    /// How finish will shape the session's commits
+    pub merge_mode: MergeMode,
+    /// Most recent line of the session's `para note` notes
+    pub note: Option<String>,
+    /// The session's `para link` links
+    pub links: Vec<SessionLink>,
+    /// Finish or cancel currently running on the session
+    pub operation: Option<OperationRecord>,
+    /// Whether the branch merges cleanly into its base, once checked
+    pub integration: Option<IntegrationPreview>,
+    /// Health of a container session's container, once sampled
+    pub container: Option<ContainerHealth>,
+    /// The agent's last status report is older than `status.stale_after_minutes`
+    pub agent_status_stale: bool,
+    /// Repository the session belongs to, when monitoring several repositories
+    pub repo_root: Option<PathBuf>,
+    /// The session's repository no longer exists on disk
+    pub repo_missing: bool,
+    /// Commits the branch is ahead of and behind its base, once checked
+    pub ahead_behind: Option<(usize, usize)>,
+    /// Uncommitted changes in the worktree, once checked
+    pub worktree_status: Option<WorktreeStatus>,
+    /// Short commit hash HEAD is detached at, once checked
+    pub detached_at: Option<String>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum SessionStatus {
-    Active, // 🟢 Recent activity (< 5 min)
-    Idle,   // 🟡 No activity (5-30 min)
-    Review, // 👀 Finished, ready for review
-    Ready,  // ✅ Finished, ready for review (legacy)
-    Stale,  // ⏸️  No activity (> 30 min)
+    Active,  // 🟢 Recent activity (< 5 min)
+    Idle,    // 🟡 No activity (5-30 min)
+    Review,  // 👀 Finished, ready for review
+    Ready,   // ✅ Finished, ready for review (legacy)
+    Stale,   // ⏸️  No activity (> 30 min)
+    Planned, // ◇ Prepared with `dispatch --plan-only`, not launched yet
 }
 
 impl SessionStatus {
@@ -35,6 +76,7 @@ impl SessionStatus {
             SessionStatus::Review => "Review",
             SessionStatus::Ready => "Ready",
             SessionStatus::Stale => "Stale",
+            SessionStatus::Planned => "Planned",
         }
     }
 
@@ -45,6 +87,7 @@ impl SessionStatus {
             SessionStatus::Review => Color::Rgb(147, 51, 234), // Purple
             SessionStatus::Ready => Color::Rgb(99, 102, 241), // Indigo
             SessionStatus::Stale => Color::Rgb(107, 114, 128), // Gray
+            SessionStatus::Planned => Color::Rgb(56, 189, 248), // Sky blue
         }
     }
 
@@ -65,6 +108,8 @@ pub enum AppMode {
     FinishPrompt,
     CancelConfirm,
     ErrorDialog,
+    FilterInput,
+    ColumnPicker,
 }
 
 #[cfg(test)]
@@ -78,6 +123,7 @@ mod tests {
         assert_eq!(SessionStatus::Review.name(), "Review");
         assert_eq!(SessionStatus::Ready.name(), "Ready");
         assert_eq!(SessionStatus::Stale.name(), "Stale");
+        assert_eq!(SessionStatus::Planned.name(), "Planned");
     }
 
     #[test]
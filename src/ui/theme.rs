@@ -0,0 +1,286 @@
+//! Glyphs that mark the kind of a message
+//!
+//! Commands prefix their messages with a glyph for what the message is about
+//! (success, warning, a branch, ...) through [`success`], [`warning`] and the
+//! other helpers here instead of writing emoji themselves. The glyphs come
+//! from a preset chosen with `ui.theme`: `emoji` (the default), `ascii` for
+//! terminals without emoji fonts, or `none` for plain messages. The emoji
+//! preset falls back to ascii when the locale or terminal cannot show UTF-8.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ThemePreset {
+    #[default]
+    Emoji,
+    Ascii,
+    None,
+}
+
+/// One glyph per kind of message
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Glyphs {
+    pub success: &'static str,
+    pub warning: &'static str,
+    pub error: &'static str,
+    /// Something para is about to run or start
+    pub action: &'static str,
+    /// Information and suggestions for the user
+    pub hint: &'static str,
+    pub branch: &'static str,
+    /// Something removed or tidied up
+    pub cleanup: &'static str,
+    pub container: &'static str,
+    /// A session kept with `para pin`
+    pub pinned: &'static str,
+}
+
+pub const EMOJI: Glyphs = Glyphs {
+    success: "✅",
+    warning: "⚠️",
+    error: "❌",
+    action: "🔧",
+    hint: "💡",
+    branch: "🌿",
+    cleanup: "🧹",
+    container: "🐳",
+    pinned: "📌",
+};
+
+pub const ASCII: Glyphs = Glyphs {
+    success: "[ok]",
+    warning: "[warn]",
+    error: "[error]",
+    action: "[run]",
+    hint: "[hint]",
+    branch: "[branch]",
+    cleanup: "[clean]",
+    container: "[container]",
+    pinned: "[pinned]",
+};
+
+pub const NONE: Glyphs = Glyphs {
+    success: "",
+    warning: "",
+    error: "",
+    action: "",
+    hint: "",
+    branch: "",
+    cleanup: "",
+    container: "",
+    pinned: "",
+};
+
+impl ThemePreset {
+    pub fn glyphs(self) -> &'static Glyphs {
+        match self {
+            ThemePreset::Emoji => &EMOJI,
+            ThemePreset::Ascii => &ASCII,
+            ThemePreset::None => &NONE,
+        }
+    }
+
+    /// The preset to use where UTF-8 may not be available
+    fn resolve(self, unicode: bool) -> ThemePreset {
+        match self {
+            ThemePreset::Emoji if !unicode => ThemePreset::Ascii,
+            preset => preset,
+        }
+    }
+}
+
+/// `0` until [`init`] was called, otherwise the preset's index plus one
+static ACTIVE: AtomicU8 = AtomicU8::new(0);
+
+/// Use `preset` for every message of this process
+pub fn init(preset: ThemePreset) {
+    let resolved = preset.resolve(supports_unicode(
+        |name| std::env::var(name).ok(),
+        cfg!(windows),
+    ));
+    ACTIVE.store(resolved as u8 + 1, Ordering::Relaxed);
+}
+
+/// Glyphs of the active preset; the default preset before [`init`]
+pub fn current() -> &'static Glyphs {
+    match ACTIVE.load(Ordering::Relaxed) {
+        1 => &EMOJI,
+        2 => &ASCII,
+        3 => &NONE,
+        _ => {
+            init(ThemePreset::default());
+            current()
+        }
+    }
+}
+
+/// Whether the terminal can show emoji: the first locale variable that is
+/// set names UTF-8, and the terminal is not a dumb or Linux console. Windows
+/// has no locale variables and its terminals render UTF-8.
+fn supports_unicode(var: impl Fn(&str) -> Option<String>, windows: bool) -> bool {
+    if matches!(var("TERM").as_deref(), Some("dumb") | Some("linux")) {
+        return false;
+    }
+    if windows {
+        return true;
+    }
+    ["LC_ALL", "LC_CTYPE", "LANG"]
+        .iter()
+        .filter_map(|name| var(name).filter(|value| !value.is_empty()))
+        .next()
+        .is_some_and(|locale| {
+            let locale = locale.to_lowercase();
+            locale.contains("utf-8") || locale.contains("utf8")
+        })
+}
+
+/// `message` prefixed with `glyph`, or `message` alone for an empty glyph
+pub fn themed(glyph: &str, message: impl fmt::Display) -> String {
+    if glyph.is_empty() {
+        message.to_string()
+    } else if glyph.ends_with('\u{FE0F}') {
+        // Emoji with a variation selector are drawn one cell narrower than
+        // their width, so they get a second space
+        format!("{glyph}  {message}")
+    } else {
+        format!("{glyph} {message}")
+    }
+}
+
+pub fn success(message: impl fmt::Display) -> String {
+    themed(current().success, message)
+}
+
+pub fn warning(message: impl fmt::Display) -> String {
+    themed(current().warning, message)
+}
+
+pub fn error(message: impl fmt::Display) -> String {
+    themed(current().error, message)
+}
+
+pub fn action(message: impl fmt::Display) -> String {
+    themed(current().action, message)
+}
+
+pub fn hint(message: impl fmt::Display) -> String {
+    themed(current().hint, message)
+}
+
+pub fn branch(message: impl fmt::Display) -> String {
+    themed(current().branch, message)
+}
+
+pub fn cleanup(message: impl fmt::Display) -> String {
+    themed(current().cleanup, message)
+}
+
+pub fn container(message: impl fmt::Display) -> String {
+    themed(current().container, message)
+}
+
+pub fn pinned(message: impl fmt::Display) -> String {
+    themed(current().pinned, message)
+}
+
+/// Marker next to a pinned session in listings, spelled out without glyphs
+pub fn pin_marker() -> &'static str {
+    match current().pinned {
+        "" => "pinned",
+        glyph => glyph,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::test_helpers::env;
+    use std::fs;
+    use std::path::PathBuf;
+
+    /// Messages of the kinds start, finish and clean print
+    fn render(glyphs: &Glyphs) -> String {
+        [
+            themed(glyphs.action, "Running setup script: .para/setup.sh"),
+            themed(glyphs.success, "Session 'auth' started successfully"),
+            themed(
+                glyphs.warning,
+                "'para/auth' would conflict with 'main' in 1 file(s):",
+            ),
+            themed(glyphs.error, "Failed to cancel auth: worktree is locked"),
+            themed(glyphs.hint, "To use Para tools:"),
+            themed(glyphs.pinned, "Pinned session 'perf'"),
+            themed(glyphs.cleanup, "Para Cleanup"),
+            format!("  {}", themed(glyphs.branch, "2 orphaned para branches")),
+            format!(
+                "  {}",
+                themed(glyphs.container, "1 orphaned Docker containers")
+            ),
+        ]
+        .join("\n")
+            + "\n"
+    }
+
+    /// Compare `actual` with `snapshots/<name>`. Run the tests with
+    /// `PARA_UPDATE_SNAPSHOTS=1` to accept a changed rendering.
+    fn assert_snapshot(name: &str, actual: &str) {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("src/ui/snapshots")
+            .join(name);
+        if std::env::var("PARA_UPDATE_SNAPSHOTS").is_ok() {
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(&path, actual).unwrap();
+            return;
+        }
+        let expected = fs::read_to_string(&path).unwrap_or_default();
+        assert!(
+            expected == actual,
+            "{name} changed; rerun with PARA_UPDATE_SNAPSHOTS=1 and review the diff"
+        );
+    }
+
+    #[test]
+    fn test_presets_render_snapshots() {
+        assert_snapshot("theme_emoji.txt", &render(ThemePreset::Emoji.glyphs()));
+        assert_snapshot("theme_ascii.txt", &render(ThemePreset::Ascii.glyphs()));
+        assert_snapshot("theme_none.txt", &render(ThemePreset::None.glyphs()));
+    }
+
+    #[test]
+    fn test_emoji_falls_back_to_ascii_without_utf8() {
+        assert!(supports_unicode(env(&[("LANG", "en_US.UTF-8")]), false));
+        assert!(supports_unicode(env(&[("LC_ALL", "C.utf8")]), false));
+        assert!(!supports_unicode(env(&[("LANG", "C")]), false));
+        assert!(!supports_unicode(env(&[]), false));
+        // LC_ALL wins over LANG, and an empty variable is skipped
+        assert!(!supports_unicode(
+            env(&[("LC_ALL", "POSIX"), ("LANG", "en_US.UTF-8")]),
+            false
+        ));
+        assert!(supports_unicode(
+            env(&[("LC_ALL", ""), ("LANG", "en_US.UTF-8")]),
+            false
+        ));
+        assert!(!supports_unicode(
+            env(&[("LANG", "en_US.UTF-8"), ("TERM", "linux")]),
+            false
+        ));
+        assert!(supports_unicode(env(&[]), true));
+        assert!(!supports_unicode(env(&[("TERM", "dumb")]), true));
+
+        assert_eq!(ThemePreset::Emoji.resolve(false), ThemePreset::Ascii);
+        assert_eq!(ThemePreset::Emoji.resolve(true), ThemePreset::Emoji);
+        assert_eq!(ThemePreset::None.resolve(false), ThemePreset::None);
+    }
+
+    #[test]
+    fn test_themed_spacing() {
+        assert_eq!(themed("✅", "done"), "✅ done");
+        assert_eq!(themed("⚠️", "careful"), "⚠️  careful");
+        assert_eq!(themed("[warn]", "careful"), "[warn] careful");
+        assert_eq!(themed("", "plain"), "plain");
+    }
+}
@@ -1,5 +1,8 @@
 use crate::utils::{ParaError, Result};
 
+/// Format of the timestamp in `<prefix>/archived/<timestamp>/<session>`, in UTC
+pub const ARCHIVE_TIMESTAMP_FORMAT: &str = "%Y%m%d-%H%M%S";
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct ArchiveBranchInfo {
     pub timestamp: String,
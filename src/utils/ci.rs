@@ -0,0 +1,220 @@
+//! Output for CI systems
+//!
+//! With `--ci github`, which is the default when `GITHUB_ACTIONS=true`, para
+//! adds GitHub Actions workflow commands to its normal output: progress steps
+//! are folded into `::group::` blocks, an error that names files becomes an
+//! `::error file=...` annotation for each of them, and dispatch and finish
+//! write the session name, branch and commit to `$GITHUB_OUTPUT`. Without a
+//! CI mode nothing here prints anything.
+
+use crate::utils::ParaError;
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU8, Ordering};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum CiMode {
+    /// GitHub Actions workflow commands
+    Github,
+    /// Plain output, also inside GitHub Actions
+    None,
+}
+
+/// `0` until [`init`] was called, otherwise `1` for plain output and `2` for GitHub
+static ACTIVE: AtomicU8 = AtomicU8::new(0);
+
+/// Use `flag` for this process, or GitHub output when running in GitHub
+/// Actions and no mode was given
+pub fn init(flag: Option<CiMode>) {
+    let mode = resolve(flag, |name| std::env::var(name).ok());
+    ACTIVE.store(
+        if mode == CiMode::Github { 2 } else { 1 },
+        Ordering::Relaxed,
+    );
+}
+
+fn resolve(flag: Option<CiMode>, var: impl Fn(&str) -> Option<String>) -> CiMode {
+    flag.unwrap_or(match var("GITHUB_ACTIONS").as_deref() {
+        Some("true") => CiMode::Github,
+        _ => CiMode::None,
+    })
+}
+
+/// Whether workflow commands are written
+pub fn github() -> bool {
+    match ACTIVE.load(Ordering::Relaxed) {
+        0 => {
+            init(None);
+            github()
+        }
+        active => active == 2,
+    }
+}
+
+/// Open a log group in GitHub mode
+pub fn group_start(title: &str) -> Option<String> {
+    github().then(|| format!("::group::{}", escape_data(title)))
+}
+
+/// Close the group opened by [`group_start`]
+pub fn group_end() -> Option<&'static str> {
+    github().then_some("::endgroup::")
+}
+
+/// Annotations for `error`: one `::error` per file it names, or a single
+/// one without a file
+pub fn error_annotations(error: &ParaError) -> Vec<String> {
+    let message = escape_data(&error.to_string());
+    let paths = error.paths();
+    if paths.is_empty() {
+        return vec![format!("::error::{message}")];
+    }
+    paths
+        .iter()
+        .map(|path| format!("::error file={}::{message}", escape_property(path)))
+        .collect()
+}
+
+/// Print the annotations of `error` in GitHub mode
+pub fn annotate_error(error: &ParaError) {
+    if github() {
+        for annotation in error_annotations(error) {
+            println!("{annotation}");
+        }
+    }
+}
+
+/// `::warning` annotation for `message`, pointing at `file` when given
+pub fn warning_annotation(message: &str, file: Option<&str>) -> String {
+    match file {
+        Some(file) => format!(
+            "::warning file={}::{}",
+            escape_property(file),
+            escape_data(message)
+        ),
+        None => format!("::warning::{}", escape_data(message)),
+    }
+}
+
+/// Print a warning annotation in GitHub mode
+pub fn annotate_warning(message: &str, file: Option<&str>) {
+    if github() {
+        println!("{}", warning_annotation(message, file));
+    }
+}
+
+/// Append `outputs` to the file named by `$GITHUB_OUTPUT` in GitHub mode
+pub fn set_outputs(outputs: &[(&str, &str)]) {
+    if !github() {
+        return;
+    }
+    let Some(path) = outputs_file(|name| std::env::var(name).ok()) else {
+        return;
+    };
+    if let Err(e) = write_outputs(&path, outputs) {
+        eprintln!(
+            "Warning: Failed to write step outputs to {}: {e}",
+            path.display()
+        );
+    }
+}
+
+fn outputs_file(var: impl Fn(&str) -> Option<String>) -> Option<PathBuf> {
+    var("GITHUB_OUTPUT")
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+}
+
+/// Append `name=value` lines to the outputs file at `path`
+pub fn write_outputs(path: &Path, outputs: &[(&str, &str)]) -> io::Result<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    for (name, value) in outputs {
+        // Values never span lines here, so the single-line form is enough
+        writeln!(file, "{name}={}", value.replace(['\r', '\n'], " "))?;
+    }
+    Ok(())
+}
+
+/// Escape the message of a workflow command
+fn escape_data(text: &str) -> String {
+    text.replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// Escape a property such as `file=` of a workflow command
+fn escape_property(text: &str) -> String {
+    escape_data(text).replace(':', "%3A").replace(',', "%2C")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::test_helpers::env;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_github_actions_enables_github_mode() {
+        assert_eq!(
+            resolve(None, env(&[("GITHUB_ACTIONS", "true")])),
+            CiMode::Github
+        );
+        assert_eq!(resolve(None, env(&[])), CiMode::None);
+        assert_eq!(
+            resolve(Some(CiMode::None), env(&[("GITHUB_ACTIONS", "true")])),
+            CiMode::None
+        );
+        assert_eq!(resolve(Some(CiMode::Github), env(&[])), CiMode::Github);
+    }
+
+    #[test]
+    fn test_conflict_error_annotates_each_file() {
+        let error = ParaError::integration_conflict_in(
+            "2 file(s) conflict with 'main'",
+            vec!["src/lib.rs".to_string(), "docs/a,b.md".to_string()],
+        );
+        assert_eq!(
+            error_annotations(&error),
+            vec![
+                "::error file=src/lib.rs::Integration would conflict: 2 file(s) conflict with 'main'",
+                "::error file=docs/a%2Cb.md::Integration would conflict: 2 file(s) conflict with 'main'",
+            ]
+        );
+
+        let error = ParaError::setup_script(Path::new("/repo/.para/setup.sh"), "exit code 3");
+        assert_eq!(
+            error_annotations(&error),
+            vec!["::error file=/repo/.para/setup.sh::Setup script /repo/.para/setup.sh failed: exit code 3"]
+        );
+
+        let error = ParaError::invalid_args("one\ntwo 100%");
+        assert_eq!(
+            error_annotations(&error),
+            vec!["::error::Invalid arguments: one%0Atwo 100%25"]
+        );
+        assert_eq!(
+            warning_annotation("check: it", Some("a:b")),
+            "::warning file=a%3Ab::check: it"
+        );
+    }
+
+    #[test]
+    fn test_outputs_are_appended_to_the_outputs_file() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("github_output");
+        let path_text = path.to_string_lossy().to_string();
+        let outputs = outputs_file(env(&[("GITHUB_OUTPUT", &path_text)])).unwrap();
+        assert_eq!(outputs, path);
+        assert_eq!(outputs_file(env(&[("GITHUB_OUTPUT", "")])), None);
+
+        fs::write(&path, "earlier=step\n").unwrap();
+        write_outputs(&outputs, &[("session", "auth"), ("branch", "para/auth")]).unwrap();
+        write_outputs(&outputs, &[("commit", "0123abc")]).unwrap();
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "earlier=step\nsession=auth\nbranch=para/auth\ncommit=0123abc\n"
+        );
+    }
+}
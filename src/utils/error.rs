@@ -1,4 +1,5 @@
-use std::path::PathBuf;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -6,8 +7,12 @@ pub enum ParaError {
     #[error("Git operation failed: {message}")]
     GitOperation { message: String },
 
-    #[error("Session '{session_id}' not found")]
-    SessionNotFound { session_id: String },
+    #[error("Session '{session_id}' not found{}", did_you_mean(.suggestions))]
+    SessionNotFound {
+        session_id: String,
+        /// Closest existing session names, closest first
+        suggestions: Vec<String>,
+    },
 
     #[error("Session '{session_id}' already exists")]
     SessionExists { session_id: String },
@@ -42,6 +47,37 @@ pub enum ParaError {
     #[error("Proxy operation failed: {message}")]
     ProxyOperation { message: String },
 
+    #[error("Timed out: {message}")]
+    Timeout { message: String },
+
+    #[error("Network request failed: {message}")]
+    Network { message: String },
+
+    #[error("Git command timed out after {seconds}s: git {command}\nIt may be waiting for credentials, a pager, a hook or a lock. Raise git.command_timeout_secs if it is just slow.")]
+    GitTimeout { command: String, seconds: u64 },
+
+    #[error("Integration would conflict: {message}")]
+    IntegrationConflict {
+        message: String,
+        /// Conflicting files, relative to the repository root
+        files: Vec<String>,
+    },
+
+    #[error("Setup script {path} failed: {message}")]
+    SetupScript { path: String, message: String },
+
+    #[error("Resume command `{command}` failed: {message}\nThe session was not opened. Pass --run-ignore-errors to open it anyway.")]
+    ResumeCommand { command: String, message: String },
+
+    #[error("No space left on the device holding {path}\nFree up disk space, for example by removing old sessions with `para clean`, and try again.")]
+    StorageFull { path: String },
+
+    #[error("{path} is on a read-only filesystem\nRemount it read-write or point directories.state_dir and directories.subtrees_dir at a writable location.")]
+    ReadOnlyFilesystem { path: String },
+
+    #[error("Interrupted: {message}")]
+    Interrupted { message: String },
+
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
 
@@ -54,7 +90,34 @@ pub enum ParaError {
 
 pub type Result<T> = std::result::Result<T, ParaError>;
 
+fn did_you_mean(suggestions: &[String]) -> String {
+    match suggestions {
+        [] => String::new(),
+        [only] => format!(". Did you mean {only}?"),
+        many => format!(". Did you mean one of: {}?", many.join(", ")),
+    }
+}
+
+/// Process exit code for [`ParaError::Timeout`], matching coreutils `timeout`
+pub const TIMEOUT_EXIT_CODE: i32 = 124;
+
+/// Process exit code for [`ParaError::IntegrationConflict`]
+pub const INTEGRATION_CONFLICT_EXIT_CODE: i32 = 6;
+
+/// Process exit code for [`ParaError::Interrupted`], as for a shell killed by SIGINT
+pub const INTERRUPTED_EXIT_CODE: i32 = 130;
+
 impl ParaError {
+    /// Exit code the CLI reports for this error
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Self::Timeout { .. } | Self::GitTimeout { .. } => TIMEOUT_EXIT_CODE,
+            Self::IntegrationConflict { .. } => INTEGRATION_CONFLICT_EXIT_CODE,
+            Self::Interrupted { .. } => INTERRUPTED_EXIT_CODE,
+            _ => 1,
+        }
+    }
+
     pub fn git_operation(message: impl Into<String>) -> Self {
         Self::GitOperation {
             message: message.into(),
@@ -62,8 +125,16 @@ impl ParaError {
     }
 
     pub fn session_not_found(session_id: impl Into<String>) -> Self {
+        Self::session_not_found_with_suggestions(session_id, Vec::new())
+    }
+
+    pub fn session_not_found_with_suggestions(
+        session_id: impl Into<String>,
+        suggestions: Vec<String>,
+    ) -> Self {
         Self::SessionNotFound {
             session_id: session_id.into(),
+            suggestions,
         }
     }
 
@@ -147,6 +218,89 @@ impl ParaError {
             message: message.into(),
         }
     }
+
+    pub fn timeout(message: impl Into<String>) -> Self {
+        Self::Timeout {
+            message: message.into(),
+        }
+    }
+
+    pub fn network_error(message: impl Into<String>) -> Self {
+        Self::Network {
+            message: message.into(),
+        }
+    }
+
+    pub fn git_timeout(command: impl Into<String>, seconds: u64) -> Self {
+        Self::GitTimeout {
+            command: command.into(),
+            seconds,
+        }
+    }
+
+    pub fn integration_conflict(message: impl Into<String>) -> Self {
+        Self::integration_conflict_in(message, Vec::new())
+    }
+
+    /// [`integration_conflict`](Self::integration_conflict) naming the conflicting files
+    pub fn integration_conflict_in(message: impl Into<String>, files: Vec<String>) -> Self {
+        Self::IntegrationConflict {
+            message: message.into(),
+            files,
+        }
+    }
+
+    pub fn setup_script(path: &Path, message: impl Into<String>) -> Self {
+        Self::SetupScript {
+            path: path.display().to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn resume_command(command: impl Into<String>, message: impl Into<String>) -> Self {
+        Self::ResumeCommand {
+            command: command.into(),
+            message: message.into(),
+        }
+    }
+
+    /// Files the error is about, for annotations that point at them
+    pub fn paths(&self) -> Vec<&str> {
+        match self {
+            Self::IntegrationConflict { files, .. } => files.iter().map(String::as_str).collect(),
+            Self::SetupScript { path, .. }
+            | Self::FileNotFound { path }
+            | Self::StorageFull { path }
+            | Self::ReadOnlyFilesystem { path } => vec![path.as_str()],
+            _ => Vec::new(),
+        }
+    }
+
+    pub fn interrupted(message: impl Into<String>) -> Self {
+        Self::Interrupted {
+            message: message.into(),
+        }
+    }
+
+    pub fn storage_full(path: impl Into<String>) -> Self {
+        Self::StorageFull { path: path.into() }
+    }
+
+    pub fn read_only_filesystem(path: impl Into<String>) -> Self {
+        Self::ReadOnlyFilesystem { path: path.into() }
+    }
+
+    /// Error for `error` raised while trying to `action` `path`. A full disk
+    /// and a read-only filesystem get their own errors saying what to do.
+    pub fn from_io(action: &str, path: &Path, error: std::io::Error) -> Self {
+        match error.kind() {
+            ErrorKind::StorageFull | ErrorKind::QuotaExceeded => {
+                Self::storage_full(path.display().to_string())
+            }
+            ErrorKind::ReadOnlyFilesystem => Self::read_only_filesystem(path.display().to_string()),
+            _ => Self::file_operation(format!("Failed to {action} {}: {error}", path.display())),
+        }
+    }
 }
 
 impl From<PathBuf> for ParaError {
@@ -219,4 +373,33 @@ mod tests {
         let para_err: ParaError = io_err.into();
         assert!(matches!(para_err, ParaError::Io(_)));
     }
+
+    #[test]
+    fn test_from_io_maps_full_and_read_only_filesystems() {
+        let path = Path::new("/repo/.para/state/demo.state");
+        let full = ParaError::from_io(
+            "save",
+            path,
+            std::io::Error::new(ErrorKind::StorageFull, "No space left on device"),
+        );
+        assert!(matches!(full, ParaError::StorageFull { .. }));
+        assert!(full.to_string().contains("para clean"));
+
+        let read_only = ParaError::from_io(
+            "save",
+            path,
+            std::io::Error::new(ErrorKind::ReadOnlyFilesystem, "Read-only file system"),
+        );
+        assert!(matches!(read_only, ParaError::ReadOnlyFilesystem { .. }));
+
+        let other = ParaError::from_io(
+            "save",
+            path,
+            std::io::Error::new(ErrorKind::PermissionDenied, "denied"),
+        );
+        assert_eq!(
+            other.to_string(),
+            "File operation failed: Failed to save /repo/.para/state/demo.state: denied"
+        );
+    }
 }
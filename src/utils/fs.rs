@@ -0,0 +1,126 @@
+//! Filesystem checks and writes for session creation
+//!
+//! Creating a session writes to the state directory and the subtrees
+//! directory in several steps. Both are checked for free space and
+//! writability up front, state files are replaced atomically so a full disk
+//! never leaves a truncated one behind, and writes go through [`FsOps`] so
+//! tests can make them fail part of the way through.
+
+use super::{ParaError, Result};
+use std::ffi::CString;
+use std::fs;
+use std::io;
+use std::os::unix::ffi::OsStrExt;
+use std::path::{Path, PathBuf};
+
+/// Free space required before a session is created: enough for state files,
+/// a worktree checkout of a small repository and its git metadata
+pub const MIN_FREE_BYTES: u64 = 64 * 1024 * 1024;
+
+/// File writes that can be swapped out in tests
+pub trait FsOps: Send + Sync {
+    fn write(&self, path: &Path, contents: &[u8]) -> io::Result<()>;
+}
+
+/// Writes to the real filesystem
+#[derive(Debug, Default, Clone, Copy)]
+pub struct RealFs;
+
+impl FsOps for RealFs {
+    fn write(&self, path: &Path, contents: &[u8]) -> io::Result<()> {
+        fs::write(path, contents)
+    }
+}
+
+/// Replace `path` with `contents` so readers see either the old or the new
+/// file. The content goes to a temporary sibling that is renamed over `path`.
+pub fn write_atomic(ops: &dyn FsOps, path: &Path, contents: &[u8]) -> io::Result<()> {
+    let temp = temp_sibling(path);
+    let result = ops
+        .write(&temp, contents)
+        .and_then(|()| fs::rename(&temp, path));
+    if result.is_err() {
+        let _ = fs::remove_file(&temp);
+    }
+    result
+}
+
+/// The temporary file [`write_atomic`] uses for `path`
+pub fn temp_sibling(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".tmp");
+    path.with_file_name(name)
+}
+
+/// Check that `path` (or, when it does not exist yet, the directory it would
+/// be created in) is on a writable filesystem with at least `min_bytes` free
+pub fn check_writable_with_space(path: &Path, min_bytes: u64) -> Result<()> {
+    let existing = nearest_existing(path);
+    let c_path = CString::new(existing.as_os_str().as_bytes())
+        .map_err(|_| ParaError::fs_error(format!("Invalid path: {}", existing.display())))?;
+
+    let mut stat: libc::statvfs = unsafe { std::mem::zeroed() };
+    if unsafe { libc::statvfs(c_path.as_ptr(), &mut stat) } != 0 {
+        return Err(ParaError::from_io(
+            "check free space on",
+            &existing,
+            io::Error::last_os_error(),
+        ));
+    }
+    if stat.f_flag & libc::ST_RDONLY != 0 {
+        return Err(ParaError::read_only_filesystem(path.display().to_string()));
+    }
+    if unsafe { libc::access(c_path.as_ptr(), libc::W_OK) } != 0 {
+        return Err(ParaError::from_io(
+            "write to",
+            &existing,
+            io::Error::last_os_error(),
+        ));
+    }
+
+    let available = stat.f_bavail as u64 * stat.f_frsize as u64;
+    if available < min_bytes {
+        return Err(ParaError::storage_full(path.display().to_string()));
+    }
+    Ok(())
+}
+
+fn nearest_existing(path: &Path) -> PathBuf {
+    path.ancestors()
+        .find(|ancestor| !ancestor.as_os_str().is_empty() && ancestor.exists())
+        .map(Path::to_path_buf)
+        .unwrap_or_else(|| PathBuf::from("."))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::failing_fs::FailingFs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_check_writable_with_space() {
+        let temp_dir = TempDir::new().unwrap();
+        let missing = temp_dir.path().join("not").join("yet");
+
+        assert!(check_writable_with_space(temp_dir.path(), 0).is_ok());
+        assert!(check_writable_with_space(&missing, 0).is_ok());
+
+        let err = check_writable_with_space(&missing, u64::MAX).unwrap_err();
+        assert!(matches!(err, ParaError::StorageFull { .. }), "{err}");
+        assert!(err.to_string().contains(&missing.display().to_string()));
+    }
+
+    #[test]
+    fn test_write_atomic_keeps_old_content_on_failure() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("session.state");
+        write_atomic(&RealFs, &path, b"old").unwrap();
+        assert_eq!(fs::read(&path).unwrap(), b"old");
+
+        let err = write_atomic(&FailingFs::on_write(1), &path, b"new").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::StorageFull);
+        assert_eq!(fs::read(&path).unwrap(), b"old");
+        assert!(!temp_sibling(&path).exists());
+    }
+}
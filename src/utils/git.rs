@@ -78,6 +78,15 @@ pub fn get_main_repository_root_from(path: Option<&Path>) -> Result<PathBuf> {
         .map_err(|e| ParaError::git_error(format!("Failed to canonicalize repository root: {e}")))
 }
 
+/// Whether `repo_root`, as returned by [`get_main_repository_root_from`], is
+/// a bare repository such as `repo.git` rather than a working tree
+pub fn is_bare_repository(repo_root: &Path) -> bool {
+    !repo_root.join(".git").exists()
+        && repo_root.join("HEAD").is_file()
+        && repo_root.join("objects").is_dir()
+        && repo_root.join("refs").is_dir()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -142,8 +151,7 @@ mod tests {
 
         // We can't make strong assertions about the result since it depends on the test environment
         // but we can at least verify it doesn't panic and returns a valid path
-        if result.is_ok() {
-            let repo_root = result.unwrap();
+        if let Ok(repo_root) = result {
             assert!(repo_root.is_absolute());
             assert!(repo_root.exists());
             // Since we canonicalize the path, it should not contain ".." or other relative components
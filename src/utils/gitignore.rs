@@ -81,6 +81,10 @@ impl GitignoreManager {
 
     /// Ensure that .para directory is ignored in the repository's main .gitignore
     pub fn ensure_para_ignored_in_repository(repo_root: &Path) -> Result<()> {
+        // The directories of a bare repository's sessions live outside it
+        if crate::utils::is_bare_repository(repo_root) {
+            return Ok(());
+        }
         let gitignore_path = repo_root.join(".gitignore");
 
         // Check if .para is already ignored
@@ -1,15 +1,21 @@
 pub mod archive;
+pub mod ci;
 pub mod error;
+pub mod fs;
 pub mod git;
 pub mod gitignore;
 pub mod names;
+pub mod output;
 pub mod path;
+pub mod progress;
+#[cfg(test)]
+mod proptests;
+pub mod secret_scan;
+pub mod shell;
 
-pub use archive::ArchiveBranchParser;
-pub use error::{ParaError, Result};
-pub use git::{get_main_repository_root, get_main_repository_root_from};
+pub use archive::{ArchiveBranchParser, ARCHIVE_TIMESTAMP_FORMAT};
+pub use error::{ParaError, Result, INTERRUPTED_EXIT_CODE};
+pub use git::{get_main_repository_root, get_main_repository_root_from, is_bare_repository};
 pub use gitignore::GitignoreManager;
-pub use names::{generate_friendly_branch_name, generate_unique_name};
+pub use names::{generate_friendly_branch_name, generate_unique_name, validate_session_name};
 pub use path::{debug_log, safe_resolve_path};
-pub use validation::validate_session_name;
-pub mod validation;
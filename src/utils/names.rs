@@ -3,7 +3,7 @@ use chrono::{DateTime, Utc};
 use rand::seq::SliceRandom;
 use regex::Regex;
 
-const ADJECTIVES: &[&str] = &[
+pub(crate) const ADJECTIVES: &[&str] = &[
     "agile",
     "bold",
     "calm",
@@ -67,7 +67,7 @@ const ADJECTIVES: &[&str] = &[
     "young",
 ];
 
-const NOUNS: &[&str] = &[
+pub(crate) const NOUNS: &[&str] = &[
     "alpha", "beta", "gamma", "delta", "omega", "sigma", "theta", "lambda", "aurora", "cosmos",
     "nebula", "quasar", "pulsar", "galaxy", "comet", "meteor", "planet", "stellar", "lunar",
     "solar", "crystal", "diamond", "emerald", "sapphire", "ruby", "amber", "pearl", "coral",
@@ -133,6 +133,12 @@ pub fn generate_unique_name(existing_names: &[String]) -> String {
     format!("{name}_{timestamp}")
 }
 
+/// Maximum length of a session name, chosen to keep branch and worktree paths readable
+pub const MAX_SESSION_NAME_LEN: usize = 100;
+
+/// Validates that a session name is usable as a branch and directory name.
+///
+/// This is the single validator used by every command that accepts a session name.
 pub fn validate_session_name(name: &str) -> Result<()> {
     if name.is_empty() {
         return Err(ParaError::invalid_session_name(
@@ -141,10 +147,10 @@ pub fn validate_session_name(name: &str) -> Result<()> {
         ));
     }
 
-    if name.len() > 100 {
+    if name.len() > MAX_SESSION_NAME_LEN {
         return Err(ParaError::invalid_session_name(
             name,
-            "Session name cannot be longer than 100 characters",
+            format!("Session name cannot be longer than {MAX_SESSION_NAME_LEN} characters"),
         ));
     }
 
@@ -168,7 +174,7 @@ pub fn validate_session_name(name: &str) -> Result<()> {
     } else if !valid_regex.is_match(name) {
         return Err(ParaError::invalid_session_name(
             name,
-            "Session name must start and end with alphanumeric characters and contain only letters, numbers, hyphens, and underscores"
+            "Session name can only contain letters, numbers, hyphens, and underscores, and must start and end with a letter or number"
         ));
     }
 
@@ -196,6 +202,73 @@ pub fn validate_session_name(name: &str) -> Result<()> {
     Ok(())
 }
 
+/// Number of "did you mean" names a failed session lookup offers
+const SESSION_SUGGESTIONS: usize = 3;
+
+/// Match `query` against `names`: the exact name, else the one name starting
+/// with `query`, else the one name containing it. Several matches in a tier
+/// are an error listing them rather than a guess; no match suggests the
+/// closest names.
+pub fn resolve_session_name(query: &str, names: &[String]) -> Result<String> {
+    if names.iter().any(|name| name == query) {
+        return Ok(query.to_string());
+    }
+
+    type Matcher = fn(&str, &str) -> bool;
+    let tiers: [(&str, Matcher); 2] = [
+        ("start with", |name, query| name.starts_with(query)),
+        ("contain", |name, query| name.contains(query)),
+    ];
+    for (description, matches) in tiers {
+        let mut candidates: Vec<&String> =
+            names.iter().filter(|name| matches(name, query)).collect();
+        match candidates.len() {
+            0 => continue,
+            1 => return Ok(candidates[0].clone()),
+            _ => {
+                candidates.sort();
+                let candidates: Vec<&str> = candidates.iter().map(|name| name.as_str()).collect();
+                return Err(ParaError::invalid_args(format!(
+                    "Several sessions {description} '{query}': {}. Pass the full session name.",
+                    candidates.join(", ")
+                )));
+            }
+        }
+    }
+
+    let mut closest: Vec<(usize, &String)> = names
+        .iter()
+        .map(|name| (edit_distance(query, name), name))
+        .collect();
+    closest.sort();
+    let suggestions = closest
+        .into_iter()
+        .take(SESSION_SUGGESTIONS)
+        .map(|(_, name)| name.clone())
+        .collect();
+    Err(ParaError::session_not_found_with_suggestions(
+        query,
+        suggestions,
+    ))
+}
+
+/// Levenshtein distance between `a` and `b`, counted in chars
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -228,6 +301,58 @@ mod tests {
         assert_eq!(branch_name2, "feature/awesome_robot");
     }
 
+    #[test]
+    fn test_resolve_session_name_tiers() {
+        let names: Vec<String> = ["auth", "auth-fix", "foo-auth-fix", "docs_20250611-101010"]
+            .iter()
+            .map(|name| name.to_string())
+            .collect();
+
+        // An exact name wins over longer names that start with it
+        assert_eq!(resolve_session_name("auth", &names).unwrap(), "auth");
+        assert_eq!(resolve_session_name("foo", &names).unwrap(), "foo-auth-fix");
+        assert_eq!(
+            resolve_session_name("docs", &names).unwrap(),
+            "docs_20250611-101010"
+        );
+        assert_eq!(
+            resolve_session_name("1010", &names).unwrap(),
+            "docs_20250611-101010"
+        );
+
+        assert_eq!(resolve_session_name("auth-", &names).unwrap(), "auth-fix");
+
+        // Ambiguous tiers list the candidates instead of picking one
+        let error = resolve_session_name("au", &names).unwrap_err().to_string();
+        assert!(error.contains("Several sessions start with 'au': auth, auth-fix"));
+        let error = resolve_session_name("fix", &names).unwrap_err().to_string();
+        assert!(error.contains("Several sessions contain 'fix': auth-fix, foo-auth-fix"));
+    }
+
+    #[test]
+    fn test_resolve_session_name_suggests_closest() {
+        let names: Vec<String> = ["alpha", "beta", "gamma", "delta"]
+            .iter()
+            .map(|name| name.to_string())
+            .collect();
+
+        let error = resolve_session_name("betta", &names).unwrap_err();
+        match &error {
+            ParaError::SessionNotFound { suggestions, .. } => {
+                assert_eq!(suggestions.len(), 3);
+                assert_eq!(suggestions[0], "beta");
+            }
+            other => panic!("unexpected error: {other}"),
+        }
+        assert!(error
+            .to_string()
+            .contains("not found. Did you mean one of: beta, "));
+        assert!(matches!(
+            resolve_session_name("x", &[]),
+            Err(ParaError::SessionNotFound { .. })
+        ));
+    }
+
     #[test]
     fn test_validate_session_name() {
         assert!(validate_session_name("valid-name").is_ok());
@@ -246,7 +371,9 @@ mod tests {
         assert!(validate_session_name("invalid name").is_err());
         assert!(validate_session_name("invalid@name").is_err());
 
-        let long_name = "a".repeat(101);
+        let max_name = "a".repeat(MAX_SESSION_NAME_LEN);
+        assert!(validate_session_name(&max_name).is_ok());
+        let long_name = "a".repeat(MAX_SESSION_NAME_LEN + 1);
         assert!(validate_session_name(&long_name).is_err());
     }
 
@@ -0,0 +1,131 @@
+//! Clickable links in terminal output
+//!
+//! Terminals that understand OSC 8 show text wrapped in its escape sequences
+//! as a link to a URL, e.g. a worktree path that opens the directory. Links
+//! are only written to a terminal known to support them, and never when
+//! colors are disabled; everywhere else the text is printed as is. Output
+//! meant for scripts (`--json`, `--quiet`, `--format`) does not use links.
+
+use std::path::Path;
+
+/// `1` turns links on in terminals missing from [`HYPERLINK_TERMINALS`],
+/// `0` turns them off everywhere
+pub const HYPERLINKS_ENV: &str = "PARA_HYPERLINKS";
+
+/// `TERM_PROGRAM` values of terminals that support OSC 8
+const HYPERLINK_TERMINALS: &[&str] = &[
+    "iTerm.app",
+    "WezTerm",
+    "vscode",
+    "ghostty",
+    "Hyper",
+    "Tabby",
+    "WarpTerminal",
+];
+
+/// Whether links go into stdout; `no_color` is the command's `--no-color`
+pub fn hyperlinks_enabled(no_color: bool) -> bool {
+    !no_color
+        && supports_hyperlinks(
+            |name| std::env::var(name).ok(),
+            atty::is(atty::Stream::Stdout),
+        )
+}
+
+fn supports_hyperlinks(var: impl Fn(&str) -> Option<String>, stdout_is_tty: bool) -> bool {
+    if !stdout_is_tty || var("NO_COLOR").is_some() {
+        return false;
+    }
+    match var(HYPERLINKS_ENV).as_deref() {
+        Some("1") => true,
+        Some("0") => false,
+        _ => var("TERM_PROGRAM")
+            .is_some_and(|program| HYPERLINK_TERMINALS.contains(&program.as_str())),
+    }
+}
+
+/// `text` linking to `target` when links are enabled, otherwise `text`
+pub fn link(text: &str, target: &str) -> String {
+    link_if(hyperlinks_enabled(false), text, target)
+}
+
+/// [`link`] for a path, linking to the path itself
+pub fn path_link(path: &Path) -> String {
+    link(&path.display().to_string(), &file_url(path))
+}
+
+pub fn link_if(enabled: bool, text: &str, target: &str) -> String {
+    if enabled {
+        format!("\x1b]8;;{target}\x1b\\{text}\x1b]8;;\x1b\\")
+    } else {
+        text.to_string()
+    }
+}
+
+/// `file://` URL of an absolute path, with characters that are not allowed
+/// in a URL percent-encoded
+pub fn file_url(path: &Path) -> String {
+    let mut url = String::from("file://");
+    for byte in path.to_string_lossy().bytes() {
+        if byte.is_ascii_alphanumeric() || b"/-._~".contains(&byte) {
+            url.push(byte as char);
+        } else {
+            url.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    url
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::test_helpers::env;
+
+    #[test]
+    fn test_link_escape_sequences() {
+        assert_eq!(
+            link_if(true, "auth", "file:///repo/auth"),
+            "\x1b]8;;file:///repo/auth\x1b\\auth\x1b]8;;\x1b\\"
+        );
+        assert_eq!(link_if(false, "auth", "file:///repo/auth"), "auth");
+        assert_eq!(
+            file_url(Path::new("/repo/my session/ü")),
+            "file:///repo/my%20session/%C3%BC"
+        );
+    }
+
+    #[test]
+    fn test_hyperlink_support_detection() {
+        assert!(supports_hyperlinks(
+            env(&[("TERM_PROGRAM", "iTerm.app")]),
+            true
+        ));
+        assert!(supports_hyperlinks(
+            env(&[("TERM_PROGRAM", "WezTerm")]),
+            true
+        ));
+        assert!(!supports_hyperlinks(
+            env(&[("TERM_PROGRAM", "Apple_Terminal")]),
+            true
+        ));
+        assert!(!supports_hyperlinks(env(&[]), true));
+
+        // Never into a pipe, and never when colors are off
+        assert!(!supports_hyperlinks(
+            env(&[("TERM_PROGRAM", "vscode")]),
+            false
+        ));
+        assert!(!supports_hyperlinks(
+            env(&[("TERM_PROGRAM", "vscode"), ("NO_COLOR", "1")]),
+            true
+        ));
+
+        // The override works both ways, but not into a pipe
+        assert!(supports_hyperlinks(env(&[(HYPERLINKS_ENV, "1")]), true));
+        assert!(!supports_hyperlinks(env(&[(HYPERLINKS_ENV, "1")]), false));
+        assert!(!supports_hyperlinks(
+            env(&[("TERM_PROGRAM", "ghostty"), (HYPERLINKS_ENV, "0")]),
+            true
+        ));
+    }
+}
@@ -1,3 +1,4 @@
+use super::{ParaError, Result};
 use std::path::{Path, PathBuf};
 use std::sync::mpsc;
 use std::thread;
@@ -5,8 +6,7 @@ use std::time::Duration;
 
 /// Non-blocking path normalization that doesn't follow symlinks
 /// This prevents hanging on broken symlinks or unresponsive network mounts
-#[cfg(test)]
-fn normalize_path(path: &Path) -> PathBuf {
+pub fn normalize_path(path: &Path) -> PathBuf {
     let mut components = vec![];
 
     for component in path.components() {
@@ -34,6 +34,32 @@ fn normalize_path(path: &Path) -> PathBuf {
     }
 }
 
+/// Refuse a recursive delete of `path` unless it lies strictly inside one of
+/// the `owned` directories, e.g. the subtrees and state directories from
+/// `Config::owned_directories`. Paths are compared after normalization, and
+/// again with symlinks resolved when they exist.
+pub fn ensure_deletable(path: &Path, owned: &[PathBuf]) -> Result<()> {
+    let inside = |path: &Path, dir: &Path| path != dir && path.starts_with(dir);
+    let normalized = normalize_path(path);
+    let canonical = path.canonicalize().ok();
+    let allowed = owned.iter().any(|dir| {
+        inside(&normalized, &normalize_path(dir))
+            || canonical
+                .as_deref()
+                .zip(dir.canonicalize().ok())
+                .is_some_and(|(path, dir)| inside(path, &dir))
+    });
+
+    if allowed {
+        Ok(())
+    } else {
+        Err(ParaError::fs_error(format!(
+            "Refusing to delete {}: it is outside para's subtrees and state directories",
+            path.display()
+        )))
+    }
+}
+
 /// Try to resolve a path with a timeout, falling back to normalization if it fails
 pub fn safe_resolve_path(path: &Path) -> PathBuf {
     safe_resolve_path_with_timeout(path, Duration::from_secs(2))
@@ -112,6 +138,32 @@ mod tests {
         assert_eq!(normalize_path(Path::new("")), PathBuf::from("."));
     }
 
+    #[test]
+    fn test_ensure_deletable_only_inside_owned_directories() {
+        let owned = [
+            PathBuf::from("/repo/.para/worktrees"),
+            PathBuf::from("/state/para"),
+        ];
+
+        assert!(ensure_deletable(Path::new("/repo/.para/worktrees/session"), &owned).is_ok());
+        assert!(ensure_deletable(Path::new("/state/para/context/session"), &owned).is_ok());
+
+        for path in [
+            "/",
+            "/repo",
+            "/repo/.para/worktrees",
+            "/repo/.para/worktrees/../../src",
+            "/repo/.para/worktrees-other/session",
+            "/state",
+        ] {
+            let err = ensure_deletable(Path::new(path), &owned).unwrap_err();
+            assert!(
+                err.to_string().contains("Refusing to delete"),
+                "{path}: {err}"
+            );
+        }
+    }
+
     #[test]
     fn test_safe_resolve_path() {
         let temp_dir = TempDir::new().unwrap();
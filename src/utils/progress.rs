@@ -0,0 +1,368 @@
+//! Step progress for long-running CLI operations
+//!
+//! [`StepReporter`] prints numbered steps such as `[2/5] Creating worktree…`
+//! together with the time each step took. When stderr is a terminal the
+//! running step is drawn with a spinner that is redrawn in place; otherwise
+//! every step start and completion is written as a plain line so logs stay
+//! readable. In GitHub Actions (see [`crate::utils::ci`]) each step is a
+//! collapsible `::group::` in the job log instead.
+
+use crate::utils::ci;
+use std::io::{self, IsTerminal, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+const SPINNER_FRAMES: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+const SPINNER_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Source of the current time, injectable so step timing can be tested
+pub trait Clock: Send + Sync {
+    fn now(&self) -> Instant;
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// Format a step duration: `0.4s`, `12.3s`, `1m05s`
+pub fn format_elapsed(elapsed: Duration) -> String {
+    let secs = elapsed.as_secs();
+    if secs >= 60 {
+        format!("{}m{:02}s", secs / 60, secs % 60)
+    } else {
+        format!("{:.1}s", elapsed.as_secs_f64())
+    }
+}
+
+/// How steps are drawn
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StepStyle {
+    /// A spinner redrawn in place, for terminals
+    Spinner,
+    /// A line for every step start and completion
+    Plain,
+    /// Plain lines, each step inside a GitHub Actions log group
+    GitHubGroups,
+}
+
+type SharedWriter = Arc<Mutex<Box<dyn Write + Send>>>;
+
+struct RunningStep {
+    number: usize,
+    label: String,
+    started_at: Instant,
+    spinner: Option<(Arc<AtomicBool>, JoinHandle<()>)>,
+}
+
+pub struct StepReporter {
+    total: usize,
+    completed: usize,
+    out: SharedWriter,
+    clock: Arc<dyn Clock>,
+    style: StepStyle,
+    current: Option<RunningStep>,
+}
+
+impl StepReporter {
+    /// Reporter writing to stderr, with a spinner when stderr is a terminal.
+    /// In GitHub mode it writes groups to stdout, where the runner reads
+    /// workflow commands.
+    pub fn new(total: usize) -> Self {
+        if ci::github() {
+            return Self::with_output(
+                total,
+                Box::new(io::stdout()),
+                Arc::new(SystemClock),
+                StepStyle::GitHubGroups,
+            );
+        }
+        let style = if io::stderr().is_terminal() {
+            StepStyle::Spinner
+        } else {
+            StepStyle::Plain
+        };
+        Self::with_output(total, Box::new(io::stderr()), Arc::new(SystemClock), style)
+    }
+
+    pub fn with_output(
+        total: usize,
+        out: Box<dyn Write + Send>,
+        clock: Arc<dyn Clock>,
+        style: StepStyle,
+    ) -> Self {
+        Self {
+            total,
+            completed: 0,
+            out: Arc::new(Mutex::new(out)),
+            clock,
+            style,
+            current: None,
+        }
+    }
+
+    /// Complete the running step, if any, and start the next one
+    pub fn step(&mut self, label: impl Into<String>) {
+        self.complete_current();
+
+        let step = RunningStep {
+            number: self.completed + 1,
+            label: label.into(),
+            started_at: self.clock.now(),
+            spinner: None,
+        };
+        let prefix = self.prefix(step.number, &step.label);
+
+        let spinner = match self.style {
+            StepStyle::Spinner => Some(self.spawn_spinner(prefix, step.started_at)),
+            StepStyle::Plain => {
+                self.write_line(&prefix);
+                None
+            }
+            StepStyle::GitHubGroups => {
+                self.write_line(&format!("::group::{prefix}"));
+                None
+            }
+        };
+
+        self.current = Some(RunningStep { spinner, ..step });
+    }
+
+    /// Complete the running step
+    pub fn finish(mut self) {
+        self.complete_current();
+    }
+
+    fn prefix(&self, number: usize, label: &str) -> String {
+        format!("[{number}/{}] {label}…", self.total.max(number))
+    }
+
+    fn complete_current(&mut self) {
+        let Some(step) = self.current.take() else {
+            return;
+        };
+        stop_spinner(step.spinner);
+
+        let elapsed = self.clock.now().saturating_duration_since(step.started_at);
+        let prefix = self.prefix(step.number, &step.label);
+        let line = if self.style == StepStyle::Spinner {
+            format!("\r\x1b[2K✓ {prefix} {}", format_elapsed(elapsed))
+        } else {
+            format!("{prefix} done in {}", format_elapsed(elapsed))
+        };
+        self.write_line(&line);
+        self.end_group();
+        self.completed += 1;
+    }
+
+    fn end_group(&self) {
+        if self.style == StepStyle::GitHubGroups {
+            self.write_line("::endgroup::");
+        }
+    }
+
+    fn write_line(&self, line: &str) {
+        if let Ok(mut out) = self.out.lock() {
+            let _ = writeln!(out, "{line}");
+            let _ = out.flush();
+        }
+    }
+
+    fn spawn_spinner(
+        &self,
+        prefix: String,
+        started_at: Instant,
+    ) -> (Arc<AtomicBool>, JoinHandle<()>) {
+        let stop = Arc::new(AtomicBool::new(false));
+        let out = Arc::clone(&self.out);
+        let clock = Arc::clone(&self.clock);
+        let thread_stop = Arc::clone(&stop);
+
+        let handle = thread::spawn(move || {
+            let mut frame = 0;
+            while !thread_stop.load(Ordering::Relaxed) {
+                let elapsed = clock.now().saturating_duration_since(started_at);
+                if let Ok(mut out) = out.lock() {
+                    let _ = write!(
+                        out,
+                        "\r\x1b[2K{} {prefix} {}",
+                        SPINNER_FRAMES[frame % SPINNER_FRAMES.len()],
+                        format_elapsed(elapsed)
+                    );
+                    let _ = out.flush();
+                }
+                frame += 1;
+                thread::sleep(SPINNER_INTERVAL);
+            }
+        });
+
+        (stop, handle)
+    }
+}
+
+fn stop_spinner(spinner: Option<(Arc<AtomicBool>, JoinHandle<()>)>) {
+    if let Some((stop, handle)) = spinner {
+        stop.store(true, Ordering::Relaxed);
+        let _ = handle.join();
+    }
+}
+
+impl Drop for StepReporter {
+    /// A step still running on drop was aborted by an error
+    fn drop(&mut self) {
+        let Some(step) = self.current.take() else {
+            return;
+        };
+        stop_spinner(step.spinner);
+
+        let elapsed = self.clock.now().saturating_duration_since(step.started_at);
+        let prefix = self.prefix(step.number, &step.label);
+        let line = if self.style == StepStyle::Spinner {
+            format!(
+                "\r\x1b[2K✗ {prefix} failed after {}",
+                format_elapsed(elapsed)
+            )
+        } else {
+            format!("{prefix} failed after {}", format_elapsed(elapsed))
+        };
+        self.write_line(&line);
+        self.end_group();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FakeClock {
+        start: Instant,
+        offset: Mutex<Duration>,
+    }
+
+    impl FakeClock {
+        fn new() -> Arc<Self> {
+            Arc::new(Self {
+                start: Instant::now(),
+                offset: Mutex::new(Duration::ZERO),
+            })
+        }
+
+        fn advance(&self, by: Duration) {
+            *self.offset.lock().unwrap() += by;
+        }
+    }
+
+    impl Clock for FakeClock {
+        fn now(&self) -> Instant {
+            self.start + *self.offset.lock().unwrap()
+        }
+    }
+
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl Buffer {
+        fn contents(&self) -> String {
+            String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+        }
+    }
+
+    #[test]
+    fn test_format_elapsed() {
+        assert_eq!(format_elapsed(Duration::from_millis(400)), "0.4s");
+        assert_eq!(format_elapsed(Duration::from_millis(12_340)), "12.3s");
+        assert_eq!(format_elapsed(Duration::from_secs(65)), "1m05s");
+        assert_eq!(format_elapsed(Duration::from_secs(600)), "10m00s");
+    }
+
+    #[test]
+    fn test_plain_output_numbers_steps_with_elapsed_time() {
+        let clock = FakeClock::new();
+        let buffer = Buffer::default();
+        let mut reporter =
+            StepReporter::with_output(3, Box::new(buffer.clone()), clock.clone(), StepStyle::Plain);
+
+        reporter.step("Creating worktree");
+        clock.advance(Duration::from_millis(1_500));
+        reporter.step("Preparing worktree");
+        clock.advance(Duration::from_secs(62));
+        reporter.step("Launching IDE");
+        clock.advance(Duration::from_millis(200));
+        reporter.finish();
+
+        assert_eq!(
+            buffer.contents(),
+            "[1/3] Creating worktree…\n\
+             [1/3] Creating worktree… done in 1.5s\n\
+             [2/3] Preparing worktree…\n\
+             [2/3] Preparing worktree… done in 1m02s\n\
+             [3/3] Launching IDE…\n\
+             [3/3] Launching IDE… done in 0.2s\n"
+        );
+    }
+
+    #[test]
+    fn test_github_groups_wrap_each_step() {
+        let clock = FakeClock::new();
+        let buffer = Buffer::default();
+        {
+            let mut reporter = StepReporter::with_output(
+                2,
+                Box::new(buffer.clone()),
+                clock.clone(),
+                StepStyle::GitHubGroups,
+            );
+            reporter.step("Creating worktree");
+            clock.advance(Duration::from_millis(1_500));
+            reporter.step("Launching IDE");
+            clock.advance(Duration::from_secs(2));
+        }
+
+        assert_eq!(
+            buffer.contents(),
+            "::group::[1/2] Creating worktree…\n\
+             [1/2] Creating worktree… done in 1.5s\n\
+             ::endgroup::\n\
+             ::group::[2/2] Launching IDE…\n\
+             [2/2] Launching IDE… failed after 2.0s\n\
+             ::endgroup::\n"
+        );
+    }
+
+    #[test]
+    fn test_dropped_step_is_reported_as_failed() {
+        let clock = FakeClock::new();
+        let buffer = Buffer::default();
+        {
+            let mut reporter = StepReporter::with_output(
+                2,
+                Box::new(buffer.clone()),
+                clock.clone(),
+                StepStyle::Plain,
+            );
+            reporter.step("Creating container");
+            clock.advance(Duration::from_secs(3));
+        }
+
+        assert_eq!(
+            buffer.contents(),
+            "[1/2] Creating container…\n[1/2] Creating container… failed after 3.0s\n"
+        );
+    }
+}
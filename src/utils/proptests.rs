@@ -0,0 +1,165 @@
+//! Property tests for the names para derives from user input
+//!
+//! Session names, branch names and archive branches are where odd strings
+//! meet git, so these check them against `git check-ref-format` for
+//! generated input rather than a few hand-picked cases. Runs use a fixed
+//! seed so a failure in CI reproduces locally; set `PARA_PROPTEST_SEED` to
+//! explore other inputs and `PROPTEST_CASES` to run more of them.
+
+use crate::core::git::validation::GitValidator;
+use crate::core::git::ArchiveRef;
+use crate::utils::names::{
+    generate_friendly_branch_name, generate_unique_name, validate_session_name, ADJECTIVES, NOUNS,
+};
+use crate::utils::ARCHIVE_TIMESTAMP_FORMAT;
+use chrono::{DateTime, NaiveDateTime};
+use proptest::prelude::*;
+use proptest::test_runner::{Config, RngSeed};
+use std::process::Command;
+
+const DEFAULT_SEED: u64 = 0x7061_7261;
+
+fn config(cases: u32) -> Config {
+    let seed = std::env::var("PARA_PROPTEST_SEED")
+        .ok()
+        .and_then(|seed| seed.parse().ok())
+        .unwrap_or(DEFAULT_SEED);
+    Config {
+        cases: std::env::var("PROPTEST_CASES")
+            .ok()
+            .and_then(|cases| cases.parse().ok())
+            .unwrap_or(cases),
+        rng_seed: RngSeed::Fixed(seed),
+        failure_persistence: None,
+        ..Config::default()
+    }
+}
+
+/// Whether git accepts `name` as a branch name
+fn git_accepts_branch(name: &str) -> bool {
+    Command::new("git")
+        .args(["check-ref-format", "--branch", name])
+        .output()
+        .map(|output| output.status.success())
+        .unwrap_or(false)
+}
+
+fn friendly_names() -> Vec<String> {
+    ADJECTIVES
+        .iter()
+        .flat_map(|adjective| NOUNS.iter().map(move |noun| format!("{adjective}_{noun}")))
+        .collect()
+}
+
+/// Names already taken, from none to nearly every friendly name, some with
+/// the suffixes `generate_unique_name` falls back to
+fn taken_names() -> impl Strategy<Value = Vec<String>> {
+    let all = friendly_names();
+    let count = all.len();
+    (
+        prop::sample::subsequence(all.clone(), 0..=count),
+        prop::collection::vec((prop::sample::select(all), 1u32..100), 0..200),
+    )
+        .prop_map(|(mut taken, suffixed)| {
+            taken.extend(
+                suffixed
+                    .into_iter()
+                    .map(|(name, suffix)| format!("{name}_{suffix}")),
+            );
+            taken
+        })
+}
+
+/// Session names as users type them: mostly plausible, some with the
+/// characters git treats specially, some arbitrary unicode
+fn user_session_names() -> impl Strategy<Value = String> {
+    prop_oneof![
+        "[a-zA-Z0-9][a-zA-Z0-9_-]{0,30}",
+        "[a-zA-Z0-9._/@{}~^:?*\\[\\] -]{1,24}",
+        "\\PC{1,16}",
+        any::<String>(),
+    ]
+}
+
+fn branch_prefixes() -> impl Strategy<Value = String> {
+    prop_oneof![
+        Just("para".to_string()),
+        Just("test".to_string()),
+        Just("team/feature".to_string()),
+        "[a-z][a-z0-9_-]{0,10}",
+    ]
+}
+
+proptest! {
+    #![proptest_config(config(64))]
+
+    #[test]
+    fn generated_names_are_valid_and_unused(taken in taken_names()) {
+        let name = generate_unique_name(&taken);
+        prop_assert!(
+            validate_session_name(&name).is_ok(),
+            "generated name {:?} fails session name validation",
+            name
+        );
+        prop_assert!(!taken.contains(&name), "generated name {:?} is taken", name);
+
+        let branch = generate_friendly_branch_name("para", &name);
+        prop_assert!(GitValidator::validate_branch_name(&branch).is_ok());
+        prop_assert!(git_accepts_branch(&branch), "git rejects branch {:?}", branch);
+    }
+
+    #[test]
+    fn branch_names_the_validator_accepts_are_valid_refs(
+        prefix in branch_prefixes(),
+        session in user_session_names(),
+    ) {
+        let branch = generate_friendly_branch_name(&prefix, &session);
+        if validate_session_name(&session).is_ok() {
+            prop_assert!(
+                GitValidator::validate_branch_name(&branch).is_ok(),
+                "valid session {:?} gives rejected branch {:?}",
+                session,
+                branch
+            );
+        }
+        if GitValidator::validate_branch_name(&branch).is_ok() {
+            prop_assert!(
+                git_accepts_branch(&branch),
+                "validator accepts {:?} but git does not",
+                branch
+            );
+        }
+    }
+
+    #[test]
+    fn archive_branches_round_trip(
+        prefix in branch_prefixes(),
+        seconds in 0i64..4_102_444_800,
+        session in "[^/]{1,40}",
+    ) {
+        let archived_at = DateTime::from_timestamp(seconds, 0).unwrap().naive_utc();
+        let timestamp = archived_at.format(ARCHIVE_TIMESTAMP_FORMAT).to_string();
+        let archive = ArchiveRef::new(&prefix, &timestamp, &session);
+
+        let parsed = ArchiveRef::parse(&archive.branch, &prefix).unwrap();
+        prop_assert_eq!(parsed.as_ref(), Some(&archive));
+        prop_assert_eq!(
+            NaiveDateTime::parse_from_str(&timestamp, ARCHIVE_TIMESTAMP_FORMAT).ok(),
+            Some(archived_at)
+        );
+    }
+
+    #[test]
+    fn parsed_archive_branches_format_back_unchanged(
+        prefix in branch_prefixes(),
+        rest in "[a-z0-9/_-]{0,40}",
+    ) {
+        let branch = format!("{prefix}/archived/{rest}");
+        if let Ok(Some(archive)) = ArchiveRef::parse(&branch, &prefix) {
+            prop_assert_eq!(
+                ArchiveRef::new(&prefix, &archive.timestamp, &archive.session).branch,
+                branch
+            );
+        }
+    }
+}
@@ -0,0 +1,54 @@
+//! Quoting for commands handed to a POSIX shell
+//!
+//! Most commands are run with an argument vector and need no quoting. Where a
+//! command line has to be a single string, such as a VS Code task or a test
+//! stub run through `sh -c`, every path in it goes through [`quote`] so that
+//! spaces, quotes and non-ASCII characters reach the command unchanged.
+
+use std::path::Path;
+
+/// `value` as a single shell word in single quotes
+pub fn quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
+
+/// [`quote`] for a path
+pub fn quote_path(path: &Path) -> String {
+    quote(&path.to_string_lossy())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process::Command;
+
+    fn printf(word: &str) -> String {
+        let output = Command::new("sh")
+            .arg("-c")
+            .arg(format!("printf %s {word}"))
+            .output()
+            .unwrap();
+        String::from_utf8(output.stdout).unwrap()
+    }
+
+    #[test]
+    fn test_quote() {
+        assert_eq!(quote("main"), "'main'");
+        assert_eq!(quote(""), "''");
+        assert_eq!(quote("dev repo"), "'dev repo'");
+        assert_eq!(quote("it's"), r"'it'\''s'");
+        assert_eq!(quote_path(Path::new("/wt/.prompt")), "'/wt/.prompt'");
+    }
+
+    #[test]
+    fn test_shell_reads_quoted_words_back() {
+        for value in [
+            "/home/José María/dev repo",
+            "it's \"quoted\"",
+            "$(false) `false` \\ * $HOME",
+            "",
+        ] {
+            assert_eq!(printf(&quote(value)), value);
+        }
+    }
+}